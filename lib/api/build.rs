@@ -138,17 +138,22 @@ fn configure_validation(builder: Builder) -> Builder {
             ("UpdateCollection.strict_mode_config", ""),
             ("CollectionParamsDiff.replication_factor", "range(min = 1)"),
             ("CollectionParamsDiff.write_consistency_factor", "range(min = 1)"),
+            ("CollectionParamsDiff.default_search_params", ""),
             ("DeleteCollection.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("DeleteCollection.timeout", "range(min = 1)"),
             ("CollectionParams.vectors_config", ""),
+            ("CollectionParams.default_search_params", ""),
             ("ChangeAliases.timeout", "range(min = 1)"),
             ("ListCollectionAliasesRequest.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("HnswConfigDiff.ef_construct", "range(min = 4)"),
+            ("HnswConfigDiff.m0", "range(min = 1, max = 2048)"),
+            ("HnswConfigDiff.ef_construct_upper", "range(min = 4, max = 10000)"),
             ("WalConfigDiff.wal_capacity_mb", "range(min = 1)"),
             ("WalConfigDiff.wal_retain_closed", "range(min = 1)"),
             ("OptimizersConfigDiff.deleted_threshold", "range(min = 0.0, max = 1.0)"),
             ("OptimizersConfigDiff.vacuum_min_vector_number", "range(min = 100)"),
             ("OptimizersConfigDiff.max_segment_size", "range(min = 1)"),
+            ("OptimizersConfigDiff.max_segment_number", "range(min = 1)"),
             ("VectorsConfig.config", ""),
             ("VectorsConfigDiff.config", ""),
             ("VectorParams.size", "range(min = 1, max = 65536)"),
@@ -168,6 +173,8 @@ fn configure_validation(builder: Builder) -> Builder {
             ("StrictModeConfig.max_points_count", "range(min = 1)"),
             ("StrictModeConfig.read_rate_limit", "range(min = 1)"),
             ("StrictModeConfig.write_rate_limit", "range(min = 1)"),
+            ("StrictModeConfig.max_update_queue_length", "range(min = 1)"),
+            ("StrictModeConfig.max_unindexed_segment_size_kb", "range(min = 1)"),
             ("StrictModeConfig.multivector_config", ""),
             ("StrictModeConfig.sparse_config", ""),
             ("StrictModeSparseConfig.sparse_config", ""),
@@ -204,6 +211,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("UpsertPoints.points", ""),
             ("UpsertPoints.update_filter", ""),
             ("DeletePoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("DeletePoints.return_ids", ""),
             ("UpdatePointVectors.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("UpdatePointVectors.points", ""),
             ("UpdatePointVectors.update_filter", ""),
@@ -214,10 +222,13 @@ fn configure_validation(builder: Builder) -> Builder {
             ("GetPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("SetPayloadPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("SetPayloadPoints.points_selector", ""),
+            ("SetPayloadPoints.return_ids", ""),
             ("DeletePayloadPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("DeletePayloadPoints.points_selector", ""),
             ("ClearPayloadPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("ClearPayloadPoints.points", ""),
+            ("ClearPayloadPoints.return_ids", ""),
+            ("ReturnIds.limit", "range(min = 1, max = 10000)"),
             ("UpdateBatchPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("UpdateBatchPoints.operations", "length(min = 1)"),
             ("CreateFieldIndexCollection.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
@@ -244,6 +255,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("SearchParams.quantization", ""),
             ("SearchParams.acorn", ""),
             ("QuantizationSearchParams.oversampling", "range(min = 1.0)"),
+            ("QuantizationSearchParams.max_rescore", "range(min = 1)"),
             ("ScrollPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("ScrollPoints.filter", ""),
             ("ScrollPoints.limit", "range(min = 1)"),
@@ -307,6 +319,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("NaiveFeedbackStrategy.b", "range(min = 0.0)"),
             ("Formula.expression", ""),
             ("Expression.variant", ""),
+            ("WasmExpression.args", ""),
             ("MultExpression.mult", ""),
             ("SumExpression.sum", ""),
             ("DivExpression.left", ""),
@@ -317,6 +330,11 @@ fn configure_validation(builder: Builder) -> Builder {
             ("DecayParamsExpression.target", ""),
             ("NearestInputWithMmr.nearest", ""),
             ("NearestInputWithMmr.mmr", ""),
+            ("NearestInputWithCandidates.nearest", ""),
+            ("NearestInputWithCandidates.candidates", "length(min = 1)"),
+            ("NearestInputWithCandidates.mmr", ""),
+            ("VectorSelectionInput.queries", ""),
+            ("VectorSelectionQuery.query", ""),
             ("Mmr.diversity", "range(min = 0.0, max = 1.0)"),
             ("Mmr.candidates_limit", "range(max = 16_384)"),
             ("Rrf.k", "range(min = 1)"),
@@ -11,14 +11,15 @@ use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use segment::common::operation_error::OperationError;
 use segment::data_types::index::{
-    BoolIndexType, DatetimeIndexType, FloatIndexType, GeoIndexType, IntegerIndexType,
-    KeywordIndexType, SnowballLanguage, TextIndexType, UuidIndexType,
+    BoolIndexType, DatetimeIndexType, FloatIndexType, GeoIndexType, GeoShapeIndexType,
+    IntegerIndexType, KeywordIndexType, SnowballLanguage, TextIndexType, UuidIndexType,
 };
 use segment::data_types::modifier::Modifier;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedMultiDenseVector, VectorInternal};
 use segment::data_types::{facets as segment_facets, vectors as segment_vectors};
+use segment::index::field_index::full_text_index::highlight as segment_highlight;
 use segment::index::query_optimization::rescore_formula::parsed_formula::{
-    DatetimeExpression, DecayKind, ParsedExpression, ParsedFormula,
+    DatetimeExpression, DecayKind, ParsedExpression, ParsedFormula, TextScoreQuery, WasmFunction,
 };
 use segment::types::{DateTimePayloadType, FloatPayloadType, default_quantization_ignore_value};
 use segment::vector_storage::query::{self as segment_query, NaiveFeedbackCoefficients};
@@ -29,12 +30,13 @@ use uuid::Uuid;
 use super::qdrant::{
     BinaryQuantization, BoolIndexParams, CompressionRatio, DatetimeIndexParams, DatetimeRange,
     Direction, FacetHit, FacetHitInternal, FacetValue, FacetValueInternal, FieldType,
-    FloatIndexParams, GeoIndexParams, GeoLineString, GroupId, HardwareUsage, HasVectorCondition,
+    FloatIndexParams, GeoIndexParams, GeoLineString, GeoShapeCondition, GeoShapeIndexParams,
+    GeoShapeRelation, GroupId, HardwareUsage, HasVectorCondition, HighlightParams,
     KeywordIndexParams, LookupLocation, MaxOptimizationThreads, MultiVectorComparator,
     MultiVectorConfig, OrderBy, OrderValue, Range, RawVector, RecommendStrategy, RetrievedPoint,
     SearchMatrixPair, SearchPointGroups, SearchPoints, ShardKeySelector, StartFrom,
     StrictModeMultivector, StrictModeMultivectorConfig, StrictModeSparse, StrictModeSparseConfig,
-    UuidIndexParams, VectorsOutput, WithLookup, raw_query, start_from,
+    ThenBy, UuidIndexParams, VectorsOutput, WithLookup, raw_query, start_from,
 };
 use super::stemming_algorithm::StemmingParams;
 use super::{Expression, Formula, RecoQuery, SnowballParams, StemmingAlgorithm, Usage};
@@ -46,21 +48,23 @@ use crate::grpc::qdrant::point_id::PointIdOptions;
 use crate::grpc::qdrant::with_payload_selector::SelectorOptions;
 use crate::grpc::qdrant::{
     AcornSearchParams, CollectionDescription, CollectionOperationResponse, Condition, Distance,
-    FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, HasIdCondition,
-    HealthCheckReply, HnswConfigDiff, IntegerIndexParams, IsEmptyCondition, IsNullCondition,
-    ListCollectionsResponse, ListShardKeysResponse, Match, MinShould, NamedVectors,
-    NestedCondition, PayloadExcludeSelector, PayloadIncludeSelector, PayloadIndexParams,
-    PayloadSchemaInfo, PayloadSchemaType, PointId, PointStruct, PointsOperationResponse,
-    PointsOperationResponseInternal, ProductQuantization, QuantizationConfig,
-    QuantizationSearchParams, QuantizationType, RepeatedIntegers, RepeatedStrings,
-    ScalarQuantization, ScoredPoint, SearchParams, ShardKey, ShardKeyDescription, StopwordsSet,
-    StrictModeConfig, TextIndexParams, TokenizerType, UpdateResult, UpdateResultInternal,
-    ValuesCount, VectorsSelector, WithPayloadSelector, WithVectorsSelector, shard_key,
-    with_vectors_selector,
+    FieldComparison, FieldCondition, Filter, FuzzyText, GeoBoundingBox, GeoPoint, GeoPolygon,
+    GeoRadius, HasIdCondition, HealthCheckReply, HnswConfigDiff, IntegerIndexParams,
+    IsEmptyCondition, IsNullCondition, ListCollectionsResponse, ListShardKeysResponse, Match,
+    MinShould, NamedVectors, NearText, NestedCondition, PayloadExcludeSelector,
+    PayloadIncludeSelector, PayloadIndexParams, PayloadSchemaInfo, PayloadSchemaType, PointId,
+    PointStruct, PointsOperationResponse, PointsOperationResponseInternal, ProductQuantization,
+    QuantizationConfig, QuantizationSearchParams, QuantizationType, RepeatedIntegers,
+    RepeatedStrings, ScalarQuantization, ScoredPoint, SearchParams, ShardKey, ShardKeyDescription,
+    StopwordsSet, StrictModeConfig, SynonymGroup, Synonyms, TextHighlight, TextHighlights,
+    TextIndexParams, TextOffset, TokenFilter, TokenFilters, TokenizerType, UpdateResult,
+    UpdateResultInternal, ValuesCount, VectorsSelector, WithPayloadSelector, WithVectorsSelector,
+    shard_key, with_vectors_selector,
 };
 use crate::grpc::{
     self, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding, DecayParamsExpression,
-    DivExpression, GeoDistance, MultExpression, PowExpression, SumExpression,
+    DivExpression, GeoDistance, MultExpression, PowExpression, SumExpression, TermBoost, TextScore,
+    WasmExpression,
 };
 use crate::rest::models::{CollectionsResponse, ShardKeysResponse, VersionInfo};
 use crate::rest::schema as rest;
@@ -183,24 +187,64 @@ impl From<segment::data_types::index::TokenizerType> for TokenizerType {
             segment::data_types::index::TokenizerType::Prefix => TokenizerType::Prefix,
             segment::data_types::index::TokenizerType::Whitespace => TokenizerType::Whitespace,
             segment::data_types::index::TokenizerType::Multilingual => TokenizerType::Multilingual,
+            segment::data_types::index::TokenizerType::Cjk => TokenizerType::Cjk,
             segment::data_types::index::TokenizerType::Word => TokenizerType::Word,
         }
     }
 }
 
+impl From<segment::data_types::index::TokenFilter> for TokenFilter {
+    fn from(filter: segment::data_types::index::TokenFilter) -> Self {
+        match filter {
+            segment::data_types::index::TokenFilter::Lowercase => TokenFilter::Lowercase,
+            segment::data_types::index::TokenFilter::AsciiFolding => TokenFilter::AsciiFolding,
+            segment::data_types::index::TokenFilter::UnicodeNormalization => {
+                TokenFilter::UnicodeNormalization
+            }
+            segment::data_types::index::TokenFilter::Stopwords => TokenFilter::Stopwords,
+            segment::data_types::index::TokenFilter::Stemmer => TokenFilter::Stemmer,
+        }
+    }
+}
+
+impl From<Vec<segment::data_types::index::TokenFilter>> for TokenFilters {
+    fn from(filters: Vec<segment::data_types::index::TokenFilter>) -> Self {
+        TokenFilters {
+            filters: filters
+                .into_iter()
+                .map(|filter| TokenFilter::from(filter) as i32)
+                .collect(),
+        }
+    }
+}
+
 impl From<segment::data_types::index::KeywordIndexParams> for PayloadIndexParams {
     fn from(params: segment::data_types::index::KeywordIndexParams) -> Self {
         let segment::data_types::index::KeywordIndexParams {
             r#type: _,
             is_tenant,
+            ascii_folding,
+            unicode_normalization,
+            lowercase,
             on_disk,
             enable_hnsw,
+            populate,
+            bloom_filter,
+            tenant_subgraphs,
+            tenant_subgraphs_max_points,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::KeywordIndexParams(KeywordIndexParams {
                 is_tenant,
                 on_disk,
                 enable_hnsw,
+                ascii_folding,
+                unicode_normalization,
+                populate,
+                tenant_subgraphs,
+                tenant_subgraphs_max_points: tenant_subgraphs_max_points.map(|x| x as u64),
+                lowercase,
+                bloom_filter,
             })),
         }
     }
@@ -215,6 +259,9 @@ impl From<segment::data_types::index::IntegerIndexParams> for PayloadIndexParams
             on_disk,
             is_principal,
             enable_hnsw,
+            populate,
+            histogram_buckets,
+            histogram_max_bucket_size,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::IntegerIndexParams(IntegerIndexParams {
@@ -223,6 +270,9 @@ impl From<segment::data_types::index::IntegerIndexParams> for PayloadIndexParams
                 is_principal,
                 on_disk,
                 enable_hnsw,
+                populate,
+                histogram_buckets: histogram_buckets.map(|x| x as u64),
+                histogram_max_bucket_size: histogram_max_bucket_size.map(|x| x as u64),
             })),
         }
     }
@@ -235,12 +285,18 @@ impl From<segment::data_types::index::FloatIndexParams> for PayloadIndexParams {
             on_disk,
             is_principal,
             enable_hnsw,
+            populate,
+            histogram_buckets,
+            histogram_max_bucket_size,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::FloatIndexParams(FloatIndexParams {
                 on_disk,
                 is_principal,
                 enable_hnsw,
+                populate,
+                histogram_buckets: histogram_buckets.map(|x| x as u64),
+                histogram_max_bucket_size: histogram_max_bucket_size.map(|x| x as u64),
             })),
         }
     }
@@ -252,11 +308,17 @@ impl From<segment::data_types::index::GeoIndexParams> for PayloadIndexParams {
             r#type: _,
             on_disk,
             enable_hnsw,
+            populate,
+            precision,
+            max_query_cells,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::GeoIndexParams(GeoIndexParams {
                 on_disk,
                 enable_hnsw,
+                populate,
+                precision: precision.map(|x| x as u64),
+                max_query_cells: max_query_cells.map(|x| x as u64),
             })),
         }
     }
@@ -271,11 +333,19 @@ impl From<segment::data_types::index::TextIndexParams> for PayloadIndexParams {
             max_token_len,
             lowercase,
             ascii_folding,
+            unicode_normalization,
             phrase_matching,
             on_disk,
             stopwords,
             stemmer,
+            language_detection,
+            detected_language_key,
             enable_hnsw,
+            populate,
+            synonyms,
+            shingles,
+            max_document_frequency,
+            filters,
         } = params;
         let tokenizer = TokenizerType::from(tokenizer);
 
@@ -296,6 +366,14 @@ impl From<segment::data_types::index::TextIndexParams> for PayloadIndexParams {
                 stopwords: stopwords_set,
                 stemmer: stemming_algo,
                 enable_hnsw,
+                unicode_normalization,
+                language_detection,
+                detected_language_key,
+                populate,
+                synonyms: synonyms.map(Synonyms::from),
+                filters: filters.map(TokenFilters::from),
+                shingles,
+                max_document_frequency: max_document_frequency.map(|OrderedFloat(x)| x),
             })),
         }
     }
@@ -307,11 +385,13 @@ impl From<segment::data_types::index::BoolIndexParams> for PayloadIndexParams {
             r#type: _,
             on_disk,
             enable_hnsw,
+            populate,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::BoolIndexParams(BoolIndexParams {
                 on_disk,
                 enable_hnsw,
+                populate,
             })),
         }
     }
@@ -324,12 +404,18 @@ impl From<segment::data_types::index::UuidIndexParams> for PayloadIndexParams {
             is_tenant,
             on_disk,
             enable_hnsw,
+            populate,
+            tenant_subgraphs,
+            tenant_subgraphs_max_points,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::UuidIndexParams(UuidIndexParams {
                 is_tenant,
                 on_disk,
                 enable_hnsw,
+                populate,
+                tenant_subgraphs,
+                tenant_subgraphs_max_points: tenant_subgraphs_max_points.map(|x| x as u64),
             })),
         }
     }
@@ -342,12 +428,32 @@ impl From<segment::data_types::index::DatetimeIndexParams> for PayloadIndexParam
             on_disk,
             is_principal,
             enable_hnsw,
+            populate,
+            histogram_buckets,
+            histogram_max_bucket_size,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::DatetimeIndexParams(DatetimeIndexParams {
                 on_disk,
                 is_principal,
                 enable_hnsw,
+                populate,
+                histogram_buckets: histogram_buckets.map(|x| x as u64),
+                histogram_max_bucket_size: histogram_max_bucket_size.map(|x| x as u64),
+            })),
+        }
+    }
+}
+
+impl From<segment::data_types::index::GeoShapeIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::index::GeoShapeIndexParams) -> Self {
+        let segment::data_types::index::GeoShapeIndexParams {
+            r#type: _,
+            enable_hnsw,
+        } = params;
+        PayloadIndexParams {
+            index_params: Some(IndexParams::GeoShapeIndexParams(GeoShapeIndexParams {
+                enable_hnsw,
             })),
         }
     }
@@ -359,11 +465,14 @@ impl From<segment::types::PayloadIndexInfo> for PayloadSchemaInfo {
             data_type,
             params,
             points,
+            distinct_values,
+            distinct_values_sketch: _,
         } = schema;
         PayloadSchemaInfo {
             data_type: PayloadSchemaType::from(data_type) as i32,
             params: params.map(|p| p.into()),
             points: Some(points as u64),
+            distinct_values: distinct_values.map(|distinct| distinct as u64),
         }
     }
 }
@@ -379,6 +488,7 @@ impl From<segment::types::PayloadSchemaType> for PayloadSchemaType {
             segment::types::PayloadSchemaType::Bool => PayloadSchemaType::Bool,
             segment::types::PayloadSchemaType::Datetime => PayloadSchemaType::Datetime,
             segment::types::PayloadSchemaType::Uuid => PayloadSchemaType::Uuid,
+            segment::types::PayloadSchemaType::GeoShape => PayloadSchemaType::GeoShape,
         }
     }
 }
@@ -394,6 +504,7 @@ impl From<segment::types::PayloadSchemaType> for FieldType {
             segment::types::PayloadSchemaType::Bool => FieldType::Bool,
             segment::types::PayloadSchemaType::Datetime => FieldType::Datetime,
             segment::types::PayloadSchemaType::Uuid => FieldType::Uuid,
+            segment::types::PayloadSchemaType::GeoShape => FieldType::GeoShape,
         }
     }
 }
@@ -428,6 +539,25 @@ impl From<segment::data_types::index::StopwordsInterface> for StopwordsSet {
     }
 }
 
+impl From<segment::data_types::index::SynonymsParams> for Synonyms {
+    fn from(params: segment::data_types::index::SynonymsParams) -> Self {
+        let segment::data_types::index::SynonymsParams {
+            groups,
+            file,
+            expand_on_index,
+        } = params;
+
+        Synonyms {
+            groups: groups
+                .into_iter()
+                .map(|words| SynonymGroup { words })
+                .collect(),
+            file,
+            expand_on_index,
+        }
+    }
+}
+
 impl From<segment::data_types::index::StemmingAlgorithm> for StemmingAlgorithm {
     fn from(value: segment::data_types::index::StemmingAlgorithm) -> Self {
         let stemming_params = match value {
@@ -456,12 +586,46 @@ impl TryFrom<TokenizerType> for segment::data_types::index::TokenizerType {
             TokenizerType::Multilingual => {
                 Ok(segment::data_types::index::TokenizerType::Multilingual)
             }
+            TokenizerType::Cjk => Ok(segment::data_types::index::TokenizerType::Cjk),
             TokenizerType::Whitespace => Ok(segment::data_types::index::TokenizerType::Whitespace),
             TokenizerType::Word => Ok(segment::data_types::index::TokenizerType::Word),
         }
     }
 }
 
+impl TryFrom<TokenFilter> for segment::data_types::index::TokenFilter {
+    type Error = Status;
+    fn try_from(filter: TokenFilter) -> Result<Self, Self::Error> {
+        match filter {
+            TokenFilter::UnknownTokenFilter => {
+                Err(Status::invalid_argument("unknown token filter"))
+            }
+            TokenFilter::Lowercase => Ok(segment::data_types::index::TokenFilter::Lowercase),
+            TokenFilter::AsciiFolding => Ok(segment::data_types::index::TokenFilter::AsciiFolding),
+            TokenFilter::UnicodeNormalization => {
+                Ok(segment::data_types::index::TokenFilter::UnicodeNormalization)
+            }
+            TokenFilter::Stopwords => Ok(segment::data_types::index::TokenFilter::Stopwords),
+            TokenFilter::Stemmer => Ok(segment::data_types::index::TokenFilter::Stemmer),
+        }
+    }
+}
+
+impl TryFrom<TokenFilters> for Vec<segment::data_types::index::TokenFilter> {
+    type Error = Status;
+    fn try_from(filters: TokenFilters) -> Result<Self, Self::Error> {
+        let TokenFilters { filters } = filters;
+        filters
+            .into_iter()
+            .map(|filter| {
+                TokenFilter::try_from(filter)
+                    .map_err(|_| Status::invalid_argument("unknown token filter"))
+                    .and_then(segment::data_types::index::TokenFilter::try_from)
+            })
+            .collect()
+    }
+}
+
 impl From<segment::types::PayloadSchemaParams> for PayloadIndexParams {
     fn from(params: segment::types::PayloadSchemaParams) -> Self {
         match params {
@@ -473,6 +637,7 @@ impl From<segment::types::PayloadSchemaParams> for PayloadIndexParams {
             segment::types::PayloadSchemaParams::Bool(p) => p.into(),
             segment::types::PayloadSchemaParams::Datetime(p) => p.into(),
             segment::types::PayloadSchemaParams::Uuid(p) => p.into(),
+            segment::types::PayloadSchemaParams::GeoShape(p) => p.into(),
         }
     }
 }
@@ -484,12 +649,26 @@ impl TryFrom<KeywordIndexParams> for segment::data_types::index::KeywordIndexPar
             is_tenant,
             on_disk,
             enable_hnsw,
+            ascii_folding,
+            unicode_normalization,
+            populate,
+            tenant_subgraphs,
+            tenant_subgraphs_max_points,
+            lowercase,
+            bloom_filter,
         } = params;
         Ok(segment::data_types::index::KeywordIndexParams {
             r#type: KeywordIndexType::Keyword,
             is_tenant,
+            ascii_folding,
+            unicode_normalization,
+            lowercase,
             on_disk,
             enable_hnsw,
+            populate,
+            bloom_filter,
+            tenant_subgraphs,
+            tenant_subgraphs_max_points: tenant_subgraphs_max_points.map(|x| x as usize),
         })
     }
 }
@@ -503,6 +682,9 @@ impl TryFrom<IntegerIndexParams> for segment::data_types::index::IntegerIndexPar
             is_principal,
             on_disk,
             enable_hnsw,
+            populate,
+            histogram_buckets,
+            histogram_max_bucket_size,
         } = params;
         Ok(segment::data_types::index::IntegerIndexParams {
             r#type: IntegerIndexType::Integer,
@@ -511,6 +693,9 @@ impl TryFrom<IntegerIndexParams> for segment::data_types::index::IntegerIndexPar
             is_principal,
             on_disk,
             enable_hnsw,
+            populate,
+            histogram_buckets: histogram_buckets.map(|x| x as usize),
+            histogram_max_bucket_size: histogram_max_bucket_size.map(|x| x as usize),
         })
     }
 }
@@ -522,12 +707,18 @@ impl TryFrom<FloatIndexParams> for segment::data_types::index::FloatIndexParams
             on_disk,
             is_principal,
             enable_hnsw,
+            populate,
+            histogram_buckets,
+            histogram_max_bucket_size,
         } = params;
         Ok(segment::data_types::index::FloatIndexParams {
             r#type: FloatIndexType::Float,
             on_disk,
             is_principal,
             enable_hnsw,
+            populate,
+            histogram_buckets: histogram_buckets.map(|x| x as usize),
+            histogram_max_bucket_size: histogram_max_bucket_size.map(|x| x as usize),
         })
     }
 }
@@ -538,15 +729,37 @@ impl TryFrom<GeoIndexParams> for segment::data_types::index::GeoIndexParams {
         let GeoIndexParams {
             on_disk,
             enable_hnsw,
+            populate,
+            precision,
+            max_query_cells,
         } = params;
         Ok(segment::data_types::index::GeoIndexParams {
             r#type: GeoIndexType::Geo,
             on_disk,
             enable_hnsw,
+            populate,
+            precision: precision.map(|x| x as usize),
+            max_query_cells: max_query_cells.map(|x| x as usize),
         })
     }
 }
 
+impl From<Synonyms> for segment::data_types::index::SynonymsParams {
+    fn from(value: Synonyms) -> Self {
+        let Synonyms {
+            groups,
+            file,
+            expand_on_index,
+        } = value;
+
+        segment::data_types::index::SynonymsParams {
+            groups: groups.into_iter().map(|group| group.words).collect(),
+            file,
+            expand_on_index,
+        }
+    }
+}
+
 impl TryFrom<StopwordsSet> for segment::data_types::index::StopwordsInterface {
     type Error = Status;
 
@@ -594,6 +807,14 @@ impl TryFrom<TextIndexParams> for segment::data_types::index::TextIndexParams {
             stopwords,
             stemmer,
             enable_hnsw,
+            unicode_normalization,
+            language_detection,
+            detected_language_key,
+            populate,
+            synonyms,
+            filters,
+            shingles,
+            max_document_frequency,
         } = params;
 
         // Convert stopwords if present
@@ -610,6 +831,8 @@ impl TryFrom<TextIndexParams> for segment::data_types::index::TextIndexParams {
             .map(segment::data_types::index::StemmingAlgorithm::try_from)
             .transpose()?;
 
+        let filters = filters.map(Vec::try_from).transpose()?;
+
         Ok(segment::data_types::index::TextIndexParams {
             r#type: TextIndexType::Text,
             tokenizer: TokenizerType::try_from(tokenizer)
@@ -617,13 +840,21 @@ impl TryFrom<TextIndexParams> for segment::data_types::index::TextIndexParams {
                 .unwrap_or_else(|_| Err(Status::invalid_argument("unknown tokenizer type")))?,
             lowercase,
             ascii_folding,
+            unicode_normalization,
             min_token_len: min_token_len.map(|x| x as usize),
             max_token_len: max_token_len.map(|x| x as usize),
             phrase_matching,
             on_disk,
             stopwords: stopwords_converted,
             stemmer,
+            language_detection,
+            detected_language_key,
             enable_hnsw,
+            populate,
+            synonyms: synonyms.map(segment::data_types::index::SynonymsParams::from),
+            shingles,
+            max_document_frequency: max_document_frequency.map(OrderedFloat),
+            filters,
         })
     }
 }
@@ -654,11 +885,13 @@ impl TryFrom<BoolIndexParams> for segment::data_types::index::BoolIndexParams {
         let BoolIndexParams {
             on_disk,
             enable_hnsw,
+            populate,
         } = params;
         Ok(segment::data_types::index::BoolIndexParams {
             r#type: BoolIndexType::Bool,
             on_disk,
             enable_hnsw,
+            populate,
         })
     }
 }
@@ -670,12 +903,18 @@ impl TryFrom<DatetimeIndexParams> for segment::data_types::index::DatetimeIndexP
             on_disk,
             is_principal,
             enable_hnsw,
+            populate,
+            histogram_buckets,
+            histogram_max_bucket_size,
         } = params;
         Ok(segment::data_types::index::DatetimeIndexParams {
             r#type: DatetimeIndexType::Datetime,
             on_disk,
             is_principal,
             enable_hnsw,
+            populate,
+            histogram_buckets: histogram_buckets.map(|x| x as usize),
+            histogram_max_bucket_size: histogram_max_bucket_size.map(|x| x as usize),
         })
     }
 }
@@ -687,12 +926,29 @@ impl TryFrom<UuidIndexParams> for segment::data_types::index::UuidIndexParams {
             is_tenant,
             on_disk,
             enable_hnsw,
+            populate,
+            tenant_subgraphs,
+            tenant_subgraphs_max_points,
         } = params;
         Ok(segment::data_types::index::UuidIndexParams {
             r#type: UuidIndexType::Uuid,
             is_tenant,
             on_disk,
             enable_hnsw,
+            populate,
+            tenant_subgraphs,
+            tenant_subgraphs_max_points: tenant_subgraphs_max_points.map(|x| x as usize),
+        })
+    }
+}
+
+impl TryFrom<GeoShapeIndexParams> for segment::data_types::index::GeoShapeIndexParams {
+    type Error = Status;
+    fn try_from(params: GeoShapeIndexParams) -> Result<Self, Self::Error> {
+        let GeoShapeIndexParams { enable_hnsw } = params;
+        Ok(segment::data_types::index::GeoShapeIndexParams {
+            r#type: GeoShapeIndexType::GeoShape,
+            enable_hnsw,
         })
     }
 }
@@ -726,6 +982,9 @@ impl TryFrom<IndexParams> for segment::types::PayloadSchemaParams {
             IndexParams::UuidIndexParams(p) => {
                 segment::types::PayloadSchemaParams::Uuid(p.try_into()?)
             }
+            IndexParams::GeoShapeIndexParams(p) => {
+                segment::types::PayloadSchemaParams::GeoShape(p.try_into()?)
+            }
         })
     }
 }
@@ -738,6 +997,7 @@ impl TryFrom<PayloadSchemaInfo> for segment::types::PayloadIndexInfo {
             data_type,
             params,
             points,
+            distinct_values,
         } = schema;
         let data_type = match PayloadSchemaType::try_from(data_type) {
             Err(_) => {
@@ -759,6 +1019,7 @@ impl TryFrom<PayloadSchemaInfo> for segment::types::PayloadIndexInfo {
                     ));
                 }
                 PayloadSchemaType::Uuid => segment::types::PayloadSchemaType::Uuid,
+                PayloadSchemaType::GeoShape => segment::types::PayloadSchemaType::GeoShape,
             },
         };
         let params = match params {
@@ -773,6 +1034,8 @@ impl TryFrom<PayloadSchemaInfo> for segment::types::PayloadIndexInfo {
             data_type,
             params,
             points: points.unwrap_or(0) as usize,
+            distinct_values: distinct_values.map(|distinct| distinct as usize),
+            distinct_values_sketch: None,
         })
     }
 }
@@ -859,11 +1122,15 @@ impl From<QuantizationSearchParams> for segment::types::QuantizationSearchParams
             ignore,
             rescore,
             oversampling,
+            max_rescore,
+            adaptive_rescore,
         } = params;
         Self {
             ignore: ignore.unwrap_or(default_quantization_ignore_value()),
             rescore,
             oversampling,
+            max_rescore: max_rescore.map(|max_rescore| max_rescore as usize),
+            adaptive_rescore,
         }
     }
 }
@@ -874,11 +1141,15 @@ impl From<segment::types::QuantizationSearchParams> for QuantizationSearchParams
             ignore,
             rescore,
             oversampling,
+            max_rescore,
+            adaptive_rescore,
         } = params;
         Self {
             ignore: Some(ignore),
             rescore,
             oversampling,
+            max_rescore: max_rescore.map(|max_rescore| max_rescore as u64),
+            adaptive_rescore,
         }
     }
 }
@@ -1070,6 +1341,7 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vectors: vector.map(VectorsOutput::from),
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(OrderValue::from),
+            highlight: HashMap::new(),
         }
     }
 }
@@ -1085,6 +1357,7 @@ impl TryFrom<rest::ScoredPoint> for ScoredPoint {
             vector,
             shard_key,
             order_value,
+            highlight,
         } = point;
         Ok(Self {
             id: Some(PointId::from(id)),
@@ -1094,10 +1367,52 @@ impl TryFrom<rest::ScoredPoint> for ScoredPoint {
             vectors: vector.map(VectorsOutput::try_from).transpose()?,
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(OrderValue::from),
+            highlight: highlight
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, highlights)| {
+                    let highlights = TextHighlights {
+                        highlights: highlights.into_iter().map(TextHighlight::from).collect(),
+                    };
+                    (key, highlights)
+                })
+                .collect(),
         })
     }
 }
 
+impl From<segment_highlight::TextHighlight> for TextHighlight {
+    fn from(value: segment_highlight::TextHighlight) -> Self {
+        let segment_highlight::TextHighlight { fragments, offsets } = value;
+        Self {
+            fragments,
+            offsets: offsets
+                .into_iter()
+                .map(|[start, end]| TextOffset {
+                    start: start as u64,
+                    end: end as u64,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<HighlightParams> for segment_highlight::HighlightParams {
+    fn from(value: HighlightParams) -> Self {
+        let HighlightParams {
+            pre_tag,
+            post_tag,
+            fragment_context,
+        } = value;
+        let default = segment_highlight::HighlightParams::default();
+        Self {
+            pre_tag: pre_tag.unwrap_or(default.pre_tag),
+            post_tag: post_tag.unwrap_or(default.post_tag),
+            fragment_context: fragment_context.map(|context| context as usize),
+        }
+    }
+}
+
 impl From<segment::data_types::groups::GroupId> for GroupId {
     fn from(key: segment::data_types::groups::GroupId) -> Self {
         match key {
@@ -1756,12 +2071,16 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
             datetime_range,
             is_empty,
             is_null,
+            geo_shape,
+            has_field,
+            compare,
         } = value;
 
         let geo_bounding_box =
             geo_bounding_box.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
         let geo_radius = geo_radius.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
         let geo_polygon = geo_polygon.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
+        let geo_shape = geo_shape.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
 
         let mut range = range.map(Into::into);
         if range.is_none() {
@@ -1777,9 +2096,12 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
             geo_bounding_box,
             geo_radius,
             geo_polygon,
+            geo_shape,
             values_count: values_count.map(Into::into),
             is_empty,
             is_null,
+            has_field,
+            compare: compare.map(TryInto::try_into).transpose()?,
         })
     }
 }
@@ -1793,9 +2115,12 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             geo_bounding_box,
             geo_radius,
             geo_polygon,
+            geo_shape,
             values_count,
             is_empty,
             is_null,
+            has_field,
+            compare,
         } = value;
 
         let (range, datetime_range) = match range {
@@ -1815,6 +2140,9 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             datetime_range,
             is_empty,
             is_null,
+            geo_shape: geo_shape.map(Into::into),
+            has_field,
+            compare: compare.map(Into::into),
         }
     }
 }
@@ -1912,6 +2240,58 @@ impl From<segment::types::GeoPolygon> for GeoPolygon {
     }
 }
 
+impl TryFrom<GeoShapeCondition> for segment::types::GeoShapeCondition {
+    type Error = Status;
+
+    fn try_from(value: GeoShapeCondition) -> Result<Self, Self::Error> {
+        let GeoShapeCondition {
+            relation,
+            geo_bounding_box,
+            geo_polygon,
+        } = value;
+
+        let relation = match GeoShapeRelation::try_from(relation) {
+            Ok(GeoShapeRelation::Intersects) => segment::types::GeoShapeRelation::Intersects,
+            Ok(GeoShapeRelation::Within) => segment::types::GeoShapeRelation::Within,
+            Ok(GeoShapeRelation::Contains) => segment::types::GeoShapeRelation::Contains,
+            Err(_) => {
+                return Err(Status::invalid_argument(format!(
+                    "unknown geo shape relation: {relation}"
+                )));
+            }
+        };
+
+        Ok(Self {
+            relation,
+            geo_bounding_box: geo_bounding_box
+                .map_or_else(|| Ok(None), |g| g.try_into().map(Some))?,
+            geo_polygon: geo_polygon.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?,
+        })
+    }
+}
+
+impl From<segment::types::GeoShapeCondition> for GeoShapeCondition {
+    fn from(value: segment::types::GeoShapeCondition) -> Self {
+        let segment::types::GeoShapeCondition {
+            relation,
+            geo_bounding_box,
+            geo_polygon,
+        } = value;
+
+        let relation = match relation {
+            segment::types::GeoShapeRelation::Intersects => GeoShapeRelation::Intersects,
+            segment::types::GeoShapeRelation::Within => GeoShapeRelation::Within,
+            segment::types::GeoShapeRelation::Contains => GeoShapeRelation::Contains,
+        };
+
+        Self {
+            relation: relation as i32,
+            geo_bounding_box: geo_bounding_box.map(Into::into),
+            geo_polygon: geo_polygon.map(Into::into),
+        }
+    }
+}
+
 impl From<GeoPoint> for segment::types::GeoPoint {
     fn from(value: GeoPoint) -> Self {
         let GeoPoint { lon, lat } = value;
@@ -2020,6 +2400,34 @@ impl From<segment::types::ValuesCount> for ValuesCount {
     }
 }
 
+impl TryFrom<FieldComparison> for segment::types::FieldComparison {
+    type Error = Status;
+
+    fn try_from(value: FieldComparison) -> Result<Self, Self::Error> {
+        let FieldComparison { lt, gt, gte, lte } = value;
+        let path = |key: Option<String>| key.as_deref().map(json::json_path_from_proto).transpose();
+        Ok(Self {
+            lt: path(lt)?,
+            gt: path(gt)?,
+            gte: path(gte)?,
+            lte: path(lte)?,
+        })
+    }
+}
+
+impl From<segment::types::FieldComparison> for FieldComparison {
+    fn from(value: segment::types::FieldComparison) -> Self {
+        let segment::types::FieldComparison { lt, gt, gte, lte } = value;
+        let key = |path: Option<segment::json_path::JsonPath>| path.map(|path| path.to_string());
+        Self {
+            lt: key(lt),
+            gt: key(gt),
+            gte: key(gte),
+            lte: key(lte),
+        }
+    }
+}
+
 impl TryFrom<Match> for segment::types::Match {
     type Error = Status;
 
@@ -2043,12 +2451,76 @@ impl TryFrom<Match> for segment::types::Match {
                 MatchValue::TextAny(text_any) => {
                     segment::types::Match::TextAny(segment::types::MatchTextAny { text_any })
                 }
+                MatchValue::Prefix(prefix) => segment::types::Match::Prefix(prefix.into()),
+                MatchValue::Near(NearText { text, within }) => {
+                    segment::types::Match::Near(segment::types::MatchNear { near: text, within })
+                }
+                MatchValue::Regex(regex) => {
+                    let match_regex = segment::types::MatchRegex::from(regex);
+                    match_regex.compile().map_err(|err| {
+                        Status::invalid_argument(format!("Invalid regular expression: {err}"))
+                    })?;
+                    segment::types::Match::Regex(match_regex)
+                }
+                MatchValue::FuzzyText(FuzzyText {
+                    text,
+                    fuzziness,
+                    min_token_len,
+                    max_token_len,
+                }) => {
+                    let fuzziness = u8::try_from(fuzziness).map_err(|_| {
+                        Status::invalid_argument(format!("Invalid fuzziness: {fuzziness}"))
+                    })?;
+                    segment::types::Match::Text(segment::types::MatchText {
+                        text,
+                        fuzziness: Some(fuzziness),
+                        min_token_len: min_token_len.map(|x| x as usize),
+                        max_token_len: max_token_len.map(|x| x as usize),
+                    })
+                }
             }),
             _ => Err(Status::invalid_argument("Malformed Match condition")),
         }
     }
 }
 
+impl TryFrom<grpc::VectorSelectionMapping> for (segment::types::ValueVariants, String) {
+    type Error = Status;
+
+    fn try_from(mapping: grpc::VectorSelectionMapping) -> Result<Self, Self::Error> {
+        use grpc::vector_selection_mapping::Value;
+
+        let grpc::VectorSelectionMapping { value, vector } = mapping;
+        let value = match value {
+            Some(Value::Keyword(keyword)) => segment::types::ValueVariants::String(keyword),
+            Some(Value::Integer(integer)) => segment::types::ValueVariants::Integer(integer),
+            Some(Value::Boolean(flag)) => segment::types::ValueVariants::Bool(flag),
+            None => {
+                return Err(Status::invalid_argument(
+                    "missing field: vector_selection.mapping.value",
+                ));
+            }
+        };
+        Ok((value, vector))
+    }
+}
+
+impl From<(segment::types::ValueVariants, String)> for grpc::VectorSelectionMapping {
+    fn from((value, vector): (segment::types::ValueVariants, String)) -> Self {
+        use grpc::vector_selection_mapping::Value;
+
+        let value = match value {
+            segment::types::ValueVariants::String(keyword) => Value::Keyword(keyword),
+            segment::types::ValueVariants::Integer(integer) => Value::Integer(integer),
+            segment::types::ValueVariants::Bool(flag) => Value::Boolean(flag),
+        };
+        Self {
+            value: Some(value),
+            vector,
+        }
+    }
+}
+
 impl From<segment::types::Match> for Match {
     fn from(value: segment::types::Match) -> Self {
         let match_value = match value {
@@ -2057,9 +2529,23 @@ impl From<segment::types::Match> for Match {
                 segment::types::ValueVariants::Integer(int) => MatchValue::Integer(int),
                 segment::types::ValueVariants::Bool(flag) => MatchValue::Boolean(flag),
             },
-            segment::types::Match::Text(segment::types::MatchText { text }) => {
-                MatchValue::Text(text)
-            }
+            segment::types::Match::Text(segment::types::MatchText {
+                text,
+                fuzziness: None,
+                min_token_len: None,
+                max_token_len: None,
+            }) => MatchValue::Text(text),
+            segment::types::Match::Text(segment::types::MatchText {
+                text,
+                fuzziness,
+                min_token_len,
+                max_token_len,
+            }) => MatchValue::FuzzyText(FuzzyText {
+                text,
+                fuzziness: u32::from(fuzziness.unwrap_or(0)),
+                min_token_len: min_token_len.map(|x| x as u64),
+                max_token_len: max_token_len.map(|x| x as u64),
+            }),
             segment::types::Match::Phrase(segment::types::MatchPhrase { phrase }) => {
                 MatchValue::Phrase(phrase)
             }
@@ -2086,6 +2572,15 @@ impl From<segment::types::Match> for Match {
             segment::types::Match::TextAny(segment::types::MatchTextAny { text_any }) => {
                 MatchValue::TextAny(text_any)
             }
+            segment::types::Match::Prefix(segment::types::MatchPrefix { prefix }) => {
+                MatchValue::Prefix(prefix)
+            }
+            segment::types::Match::Regex(segment::types::MatchRegex { regex }) => {
+                MatchValue::Regex(regex)
+            }
+            segment::types::Match::Near(segment::types::MatchNear { near, within }) => {
+                MatchValue::Near(NearText { text: near, within })
+            }
         };
         Self {
             match_value: Some(match_value),
@@ -2123,6 +2618,7 @@ impl TryFrom<OrderBy> for segment::data_types::order_by::OrderBy {
             key,
             direction,
             start_from,
+            then_by,
         } = value;
 
         let direction = direction
@@ -2153,21 +2649,56 @@ impl TryFrom<OrderBy> for segment::data_types::order_by::OrderBy {
             key: json::json_path_from_proto(&key)?,
             direction,
             start_from,
+            then_by: then_by
+                .into_iter()
+                .map(segment::data_types::order_by::ThenBy::try_from)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
 
+impl TryFrom<ThenBy> for segment::data_types::order_by::ThenBy {
+    type Error = Status;
+
+    fn try_from(value: ThenBy) -> Result<Self, Self::Error> {
+        let ThenBy { key, direction } = value;
+
+        let direction = direction
+            .and_then(|x|
+                // XXX: Invalid values silently converted to None
+                Direction::try_from(x).ok())
+            .map(segment::data_types::order_by::Direction::from);
+
+        Ok(Self {
+            key: json::json_path_from_proto(&key)?,
+            direction,
+        })
+    }
+}
+
+impl From<segment::data_types::order_by::ThenBy> for ThenBy {
+    fn from(value: segment::data_types::order_by::ThenBy) -> Self {
+        let segment::data_types::order_by::ThenBy { key, direction } = value;
+        Self {
+            key: key.to_string(),
+            direction: direction.map(|d| Direction::from(d) as i32),
+        }
+    }
+}
+
 impl From<segment::data_types::order_by::OrderBy> for OrderBy {
     fn from(value: segment::data_types::order_by::OrderBy) -> Self {
         let segment::data_types::order_by::OrderBy {
             key,
             direction,
             start_from,
+            then_by,
         } = value;
         Self {
             key: key.to_string(),
             direction: direction.map(|d| Direction::from(d) as i32),
             start_from: start_from.map(|start_from| start_from.into()),
+            then_by: then_by.into_iter().map(ThenBy::from).collect(),
         }
     }
 }
@@ -2271,6 +2802,10 @@ impl From<HnswConfigDiff> for segment::types::HnswConfig {
             on_disk,
             payload_m,
             inline_storage,
+            varint_links,
+            m0,
+            ef_construct_upper,
+            neighbor_selection,
         } = hnsw_config;
         Self {
             m: m.unwrap_or_default() as usize,
@@ -2280,6 +2815,12 @@ impl From<HnswConfigDiff> for segment::types::HnswConfig {
             on_disk,
             payload_m: payload_m.map(|x| x as usize),
             inline_storage,
+            varint_links,
+            m0: m0.map(|x| x as usize),
+            ef_construct_upper: ef_construct_upper.map(|x| x as usize),
+            neighbor_selection: neighbor_selection
+                .and_then(|x| grpc::HnswNeighborSelection::try_from(x).ok())
+                .map(segment::types::HnswNeighborSelection::from),
         }
     }
 }
@@ -2306,6 +2847,10 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfig {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            read_vectors_by_default,
+            max_response_vectors_bytes,
+            max_update_queue_length,
+            max_unindexed_segment_size_kb,
         } = value;
         Self {
             enabled,
@@ -2329,6 +2874,10 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfig {
                 .map(segment::types::StrictModeMultivectorConfig::from),
             sparse_config: sparse_config.map(segment::types::StrictModeSparseConfig::from),
             max_payload_index_count: max_payload_index_count.map(|i| i as usize),
+            read_vectors_by_default,
+            max_response_vectors_bytes: max_response_vectors_bytes.map(|i| i as usize),
+            max_update_queue_length: max_update_queue_length.map(|i| i as usize),
+            max_unindexed_segment_size_kb: max_unindexed_segment_size_kb.map(|i| i as usize),
         }
     }
 }
@@ -2431,6 +2980,10 @@ impl From<segment::types::StrictModeConfigOutput> for StrictModeConfig {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            read_vectors_by_default,
+            max_response_vectors_bytes,
+            max_update_queue_length,
+            max_unindexed_segment_size_kb,
         } = value;
         Self {
             enabled,
@@ -2452,6 +3005,10 @@ impl From<segment::types::StrictModeConfigOutput> for StrictModeConfig {
             sparse_config: sparse_config.map(StrictModeSparseConfig::from),
             max_points_count: max_points_count.map(|i| i as u64),
             max_payload_index_count: max_payload_index_count.map(|i| i as u64),
+            read_vectors_by_default,
+            max_response_vectors_bytes: max_response_vectors_bytes.map(|i| i as u64),
+            max_update_queue_length: max_update_queue_length.map(|i| i as u64),
+            max_unindexed_segment_size_kb: max_unindexed_segment_size_kb.map(|i| i as u64),
         }
     }
 }
@@ -2478,6 +3035,10 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfigOutput {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            read_vectors_by_default,
+            max_response_vectors_bytes,
+            max_update_queue_length,
+            max_unindexed_segment_size_kb,
         } = value;
         Self {
             enabled,
@@ -2501,6 +3062,10 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfigOutput {
                 .map(segment::types::StrictModeMultivectorConfigOutput::from),
             sparse_config: sparse_config.map(segment::types::StrictModeSparseConfigOutput::from),
             max_payload_index_count: max_payload_index_count.map(|i| i as usize),
+            read_vectors_by_default,
+            max_response_vectors_bytes: max_response_vectors_bytes.map(|i| i as usize),
+            max_update_queue_length: max_update_queue_length.map(|i| i as usize),
+            max_unindexed_segment_size_kb: max_unindexed_segment_size_kb.map(|i| i as usize),
         }
     }
 }
@@ -2710,10 +3275,14 @@ impl From<UpdateResultInternal> for UpdateResult {
             operation_id,
             status,
             clock_tag: _,
+            affected_ids,
+            next_page_offset,
         } = res;
         Self {
             operation_id,
             status,
+            affected_ids,
+            next_page_offset,
         }
     }
 }
@@ -2724,11 +3293,15 @@ impl From<UpdateResult> for UpdateResultInternal {
         let UpdateResult {
             operation_id,
             status,
+            affected_ids,
+            next_page_offset,
         } = res;
         Self {
             operation_id,
             status,
             clock_tag: None,
+            affected_ids,
+            next_page_offset,
         }
     }
 }
@@ -3305,10 +3878,12 @@ impl Formula {
             formula,
             payload_vars: _, // they are already in the expression
             conditions,
+            text_scores,
+            wasm_functions,
             defaults,
         } = value;
 
-        let expression = unparse_expression(formula, &conditions);
+        let expression = unparse_expression(formula, &conditions, &text_scores, &wasm_functions);
 
         let defaults = defaults
             .into_iter()
@@ -3325,6 +3900,8 @@ impl Formula {
 fn unparse_expression(
     expression: ParsedExpression,
     conditions: &Vec<segment::types::Condition>,
+    text_scores: &[TextScoreQuery],
+    wasm_functions: &[WasmFunction],
 ) -> Expression {
     use segment::index::query_optimization::rescore_formula::parsed_formula::VariableId;
 
@@ -3351,47 +3928,114 @@ fn unparse_expression(
                 Variant::DatetimeKey(json_path.to_string())
             }
         },
+        ParsedExpression::TextScore(text_score_idx) => {
+            let TextScoreQuery { key, text, boosts } = &text_scores[text_score_idx];
+            Variant::TextScore(TextScore {
+                key: key.to_string(),
+                text: text.clone(),
+                boosts: boosts
+                    .iter()
+                    .map(|boost| TermBoost {
+                        term: boost.term.clone(),
+                        boost: boost.boost.0,
+                    })
+                    .collect(),
+            })
+        }
+        ParsedExpression::Wasm { function, args } => {
+            let WasmFunction {
+                module, fuel_limit, ..
+            } = &wasm_functions[function];
+            Variant::Wasm(WasmExpression {
+                module: module.to_vec(),
+                args: args
+                    .into_iter()
+                    .map(|arg| unparse_expression(arg, conditions, text_scores, wasm_functions))
+                    .collect(),
+                fuel_limit: Some(*fuel_limit),
+            })
+        }
         ParsedExpression::Mult(exprs) => Variant::Mult(MultExpression {
             mult: exprs
                 .into_iter()
-                .map(|expr| unparse_expression(expr, conditions))
+                .map(|expr| unparse_expression(expr, conditions, text_scores, wasm_functions))
                 .collect(),
         }),
         ParsedExpression::Sum(exprs) => Variant::Sum(SumExpression {
             sum: exprs
                 .into_iter()
-                .map(|expr| unparse_expression(expr, conditions))
+                .map(|expr| unparse_expression(expr, conditions, text_scores, wasm_functions))
                 .collect(),
         }),
-        ParsedExpression::Neg(expr) => {
-            Variant::Neg(Box::new(unparse_expression(*expr, conditions)))
-        }
+        ParsedExpression::Neg(expr) => Variant::Neg(Box::new(unparse_expression(
+            *expr,
+            conditions,
+            text_scores,
+            wasm_functions,
+        ))),
         ParsedExpression::Div {
             left,
             right,
             by_zero_default,
         } => Variant::Div(Box::new(DivExpression {
-            left: Some(Box::new(unparse_expression(*left, conditions))),
-            right: Some(Box::new(unparse_expression(*right, conditions))),
+            left: Some(Box::new(unparse_expression(
+                *left,
+                conditions,
+                text_scores,
+                wasm_functions,
+            ))),
+            right: Some(Box::new(unparse_expression(
+                *right,
+                conditions,
+                text_scores,
+                wasm_functions,
+            ))),
             by_zero_default: by_zero_default.map(|v| v.0 as f32),
         })),
-        ParsedExpression::Sqrt(expr) => {
-            Variant::Sqrt(Box::new(unparse_expression(*expr, conditions)))
-        }
+        ParsedExpression::Sqrt(expr) => Variant::Sqrt(Box::new(unparse_expression(
+            *expr,
+            conditions,
+            text_scores,
+            wasm_functions,
+        ))),
         ParsedExpression::Pow { base, exponent } => Variant::Pow(Box::new(PowExpression {
-            base: Some(Box::new(unparse_expression(*base, conditions))),
-            exponent: Some(Box::new(unparse_expression(*exponent, conditions))),
+            base: Some(Box::new(unparse_expression(
+                *base,
+                conditions,
+                text_scores,
+                wasm_functions,
+            ))),
+            exponent: Some(Box::new(unparse_expression(
+                *exponent,
+                conditions,
+                text_scores,
+                wasm_functions,
+            ))),
         })),
-        ParsedExpression::Exp(expr) => {
-            Variant::Exp(Box::new(unparse_expression(*expr, conditions)))
-        }
-        ParsedExpression::Log10(expr) => {
-            Variant::Log10(Box::new(unparse_expression(*expr, conditions)))
-        }
-        ParsedExpression::Ln(expr) => Variant::Ln(Box::new(unparse_expression(*expr, conditions))),
-        ParsedExpression::Abs(expr) => {
-            Variant::Abs(Box::new(unparse_expression(*expr, conditions)))
-        }
+        ParsedExpression::Exp(expr) => Variant::Exp(Box::new(unparse_expression(
+            *expr,
+            conditions,
+            text_scores,
+            wasm_functions,
+        ))),
+        ParsedExpression::Log10(expr) => Variant::Log10(Box::new(unparse_expression(
+            *expr,
+            conditions,
+            text_scores,
+            wasm_functions,
+        ))),
+        ParsedExpression::Ln(expr) => Variant::Ln(Box::new(unparse_expression(
+            *expr,
+            conditions,
+            text_scores,
+            wasm_functions,
+        ))),
+        ParsedExpression::Abs(expr) => Variant::Abs(Box::new(unparse_expression(
+            *expr,
+            conditions,
+            text_scores,
+            wasm_functions,
+        ))),
         ParsedExpression::Decay {
             kind,
             target,
@@ -3400,8 +4044,20 @@ fn unparse_expression(
         } => {
             let (midpoint, scale) = ParsedExpression::decay_lambda_to_params(lambda.0, kind);
             let params = DecayParamsExpression {
-                x: Some(Box::new(unparse_expression(*x, conditions))),
-                target: target.map(|t| Box::new(unparse_expression(*t, conditions))),
+                x: Some(Box::new(unparse_expression(
+                    *x,
+                    conditions,
+                    text_scores,
+                    wasm_functions,
+                ))),
+                target: target.map(|t| {
+                    Box::new(unparse_expression(
+                        *t,
+                        conditions,
+                        text_scores,
+                        wasm_functions,
+                    ))
+                }),
                 midpoint: Some(midpoint),
                 scale: Some(scale),
             };
@@ -3418,6 +4074,26 @@ fn unparse_expression(
     }
 }
 
+impl From<grpc::HnswNeighborSelection> for segment::types::HnswNeighborSelection {
+    fn from(value: grpc::HnswNeighborSelection) -> Self {
+        match value {
+            grpc::HnswNeighborSelection::Heuristic => Self::Heuristic,
+            grpc::HnswNeighborSelection::HeuristicKeepPruned => Self::HeuristicKeepPruned,
+            grpc::HnswNeighborSelection::Nearest => Self::Nearest,
+        }
+    }
+}
+
+impl From<segment::types::HnswNeighborSelection> for grpc::HnswNeighborSelection {
+    fn from(value: segment::types::HnswNeighborSelection) -> Self {
+        match value {
+            segment::types::HnswNeighborSelection::Heuristic => Self::Heuristic,
+            segment::types::HnswNeighborSelection::HeuristicKeepPruned => Self::HeuristicKeepPruned,
+            segment::types::HnswNeighborSelection::Nearest => Self::Nearest,
+        }
+    }
+}
+
 impl From<grpc::Modifier> for Modifier {
     fn from(value: grpc::Modifier) -> Self {
         match value {
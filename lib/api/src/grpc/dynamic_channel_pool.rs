@@ -5,15 +5,30 @@ use tonic::transport::{Channel, ClientTlsConfig, Error as TonicError, Uri};
 
 use crate::grpc::dynamic_pool::{CountedItem, DynamicPool};
 
+/// HTTP/2 flow control settings of a gRPC channel
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Http2Settings {
+    /// Initial window size of a single stream, in bytes
+    pub initial_stream_window_size: Option<u32>,
+    /// Initial window size of the whole connection, in bytes
+    pub initial_connection_window_size: Option<u32>,
+    /// Adjust window sizes to the measured bandwidth-delay product, overrides the sizes above
+    pub adaptive_window: bool,
+}
+
 pub async fn make_grpc_channel(
     timeout: Duration,
     connection_timeout: Duration,
     uri: Uri,
     tls_config: Option<ClientTlsConfig>,
+    http2: Http2Settings,
 ) -> Result<Channel, TonicError> {
     let mut endpoint = Channel::builder(uri)
         .timeout(timeout)
-        .connect_timeout(connection_timeout);
+        .connect_timeout(connection_timeout)
+        .initial_stream_window_size(http2.initial_stream_window_size)
+        .initial_connection_window_size(http2.initial_connection_window_size)
+        .http2_adaptive_window(http2.adaptive_window);
     if let Some(config) = tls_config {
         endpoint = endpoint.tls_config(config)?;
     }
@@ -27,6 +42,7 @@ pub struct DynamicChannelPool {
     timeout: Duration,
     connection_timeout: Duration,
     tls_config: Option<ClientTlsConfig>,
+    http2: Http2Settings,
 }
 
 impl DynamicChannelPool {
@@ -35,14 +51,20 @@ impl DynamicChannelPool {
         timeout: Duration,
         connection_timeout: Duration,
         tls_config: Option<ClientTlsConfig>,
+        http2: Http2Settings,
         usage_per_channel: usize,
         min_channels: usize,
     ) -> Result<Self, TonicError> {
         let mut channels = Vec::with_capacity(min_channels);
         for _ in 0..min_channels {
-            let channel =
-                make_grpc_channel(timeout, connection_timeout, uri.clone(), tls_config.clone())
-                    .await?;
+            let channel = make_grpc_channel(
+                timeout,
+                connection_timeout,
+                uri.clone(),
+                tls_config.clone(),
+                http2,
+            )
+            .await?;
             channels.push(channel);
         }
 
@@ -53,6 +75,7 @@ impl DynamicChannelPool {
             timeout,
             connection_timeout,
             tls_config,
+            http2,
         })
     }
 
@@ -65,6 +88,7 @@ impl DynamicChannelPool {
                     self.connection_timeout,
                     self.uri.clone(),
                     self.tls_config.clone(),
+                    self.http2,
                 )
                 .await?;
                 self.pool.lock().add(channel)
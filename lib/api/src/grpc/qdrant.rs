@@ -267,12 +267,24 @@ pub struct FieldCondition {
     /// Check if field is null
     #[prost(bool, optional, tag = "10")]
     pub is_null: ::core::option::Option<bool>,
+    /// Check if geo shape relates to a given area
+    #[prost(message, optional, tag = "11")]
+    pub geo_shape: ::core::option::Option<GeoShapeCondition>,
+    /// Check if field is present, with any value including null and empty array
+    #[prost(bool, optional, tag = "12")]
+    pub has_field: ::core::option::Option<bool>,
+    /// Check if field is in the given order with other fields of the same point
+    #[prost(message, optional, tag = "13")]
+    pub compare: ::core::option::Option<FieldComparison>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Match {
-    #[prost(oneof = "r#match::MatchValue", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10")]
+    #[prost(
+        oneof = "r#match::MatchValue",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14"
+    )]
     pub match_value: ::core::option::Option<r#match::MatchValue>,
 }
 /// Nested message and enum types in `Match`.
@@ -311,11 +323,51 @@ pub mod r#match {
         /// Match any word in the text
         #[prost(string, tag = "10")]
         TextAny(::prost::alloc::string::String),
+        /// Match keywords starting with prefix
+        #[prost(string, tag = "11")]
+        Prefix(::prost::alloc::string::String),
+        /// Match text with tokens within edit distance
+        #[prost(message, tag = "12")]
+        FuzzyText(super::FuzzyText),
+        /// Match keywords containing a match of the regular expression
+        #[prost(string, tag = "13")]
+        Regex(::prost::alloc::string::String),
+        /// Match text with all tokens close to each other
+        #[prost(message, tag = "14")]
+        Near(super::NearText),
     }
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FuzzyText {
+    /// Text to match
+    #[prost(string, tag = "1")]
+    pub text: ::prost::alloc::string::String,
+    /// Maximum number of edits per token, up to 2
+    #[prost(uint32, tag = "2")]
+    pub fuzziness: u32,
+    /// Overrides the minimum token length of the index
+    #[prost(uint64, optional, tag = "3")]
+    pub min_token_len: ::core::option::Option<u64>,
+    /// Overrides the maximum token length of the index
+    #[prost(uint64, optional, tag = "4")]
+    pub max_token_len: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NearText {
+    /// Text to match
+    #[prost(string, tag = "1")]
+    pub text: ::prost::alloc::string::String,
+    /// Maximum number of other tokens between the matched tokens
+    #[prost(uint32, tag = "2")]
+    pub within: u32,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RepeatedStrings {
     #[prost(string, repeated, tag = "1")]
     pub strings: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
@@ -409,6 +461,19 @@ pub struct GeoPolygon {
     )]
     pub interiors: ::prost::alloc::vec::Vec<GeoLineString>,
 }
+/// Exactly one of `geo_bounding_box` or `geo_polygon` must be specified.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GeoShapeCondition {
+    /// Spatial relation between the stored shape and the area, default is `Intersects`
+    #[prost(enumeration = "GeoShapeRelation", tag = "1")]
+    pub relation: i32,
+    #[prost(message, optional, tag = "2")]
+    pub geo_bounding_box: ::core::option::Option<GeoBoundingBox>,
+    #[prost(message, optional, tag = "3")]
+    pub geo_polygon: ::core::option::Option<GeoPolygon>,
+}
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -422,6 +487,56 @@ pub struct ValuesCount {
     #[prost(uint64, optional, tag = "4")]
     pub lte: ::core::option::Option<u64>,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldComparison {
+    /// point.key < point.{lt}
+    #[prost(string, optional, tag = "1")]
+    pub lt: ::core::option::Option<::prost::alloc::string::String>,
+    /// point.key > point.{gt}
+    #[prost(string, optional, tag = "2")]
+    pub gt: ::core::option::Option<::prost::alloc::string::String>,
+    /// point.key >= point.{gte}
+    #[prost(string, optional, tag = "3")]
+    pub gte: ::core::option::Option<::prost::alloc::string::String>,
+    /// point.key <= point.{lte}
+    #[prost(string, optional, tag = "4")]
+    pub lte: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum GeoShapeRelation {
+    /// Shape and area have at least one point in common
+    Intersects = 0,
+    /// Shape lies completely inside the area
+    Within = 1,
+    /// Shape completely contains the area
+    Contains = 2,
+}
+impl GeoShapeRelation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            GeoShapeRelation::Intersects => "Intersects",
+            GeoShapeRelation::Within => "Within",
+            GeoShapeRelation::Contains => "Contains",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Intersects" => Some(Self::Intersects),
+            "Within" => Some(Self::Within),
+            "Contains" => Some(Self::Contains),
+            _ => None,
+        }
+    }
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -454,6 +569,14 @@ pub struct VectorParams {
     /// Configuration for multi-vector search
     #[prost(message, optional, tag = "7")]
     pub multivector_config: ::core::option::Option<MultiVectorConfig>,
+    /// If true - vectors are expected to be normalized by the client,
+    /// server-side normalization for cosine distance is skipped.
+    #[prost(bool, optional, tag = "8")]
+    pub skip_normalization: ::core::option::Option<bool>,
+    /// Embedding model producing the vectors.
+    /// If set - upserted vectors are checked to be produced by this model.
+    #[prost(message, optional, tag = "9")]
+    pub model: ::core::option::Option<EmbeddingModelConfig>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -566,6 +689,21 @@ pub struct MultiVectorConfig {
     #[prost(enumeration = "MultiVectorComparator", tag = "1")]
     pub comparator: i32,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmbeddingModelConfig {
+    /// Name of the model, must match the model of inference requests
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Version of the model, for reference only
+    #[prost(string, optional, tag = "2")]
+    pub version: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true - the model produces normalized vectors,
+    /// upserted vectors are checked to be normalized
+    #[prost(bool, optional, tag = "3")]
+    pub normalized: ::core::option::Option<bool>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -751,6 +889,23 @@ pub struct HnswConfigDiff {
     /// Requires quantized vectors to be enabled. Multi-vectors are not supported.
     #[prost(bool, optional, tag = "7")]
     pub inline_storage: ::core::option::Option<bool>,
+    /// Store HNSW graph links delta-encoded with varints instead of bitpacking. Default: false.
+    /// Reduces the size of the graph of large segments, at a small cost of decoding speed.
+    /// Has no effect if `inline_storage` is enabled.
+    #[prost(bool, optional, tag = "8")]
+    pub varint_links: ::core::option::Option<bool>,
+    /// Number of edges per node on level 0 of the index graph. If not set, `2 * m` is used.
+    #[prost(uint64, optional, tag = "9")]
+    #[validate(range(min = 1, max = 2048))]
+    pub m0: ::core::option::Option<u64>,
+    /// Number of neighbours to consider during the index building on levels above 0.
+    /// If not set, `ef_construct` is used.
+    #[prost(uint64, optional, tag = "10")]
+    #[validate(range(min = 4, max = 10000))]
+    pub ef_construct_upper: ::core::option::Option<u64>,
+    /// Strategy to select neighbours of a node during the index building. Default: `Heuristic`.
+    #[prost(enumeration = "HnswNeighborSelection", optional, tag = "11")]
+    pub neighbor_selection: ::core::option::Option<i32>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -862,6 +1017,16 @@ pub struct OptimizersConfigDiff {
     /// Default is disabled.
     #[prost(bool, optional, tag = "10")]
     pub prevent_unoptimized: ::core::option::Option<bool>,
+    /// Maximum number of segments per shard.
+    /// If exceeded, the merge optimizer merges segments regardless of `max_segment_size`
+    /// until the number of segments is back within the limit.
+    ///
+    /// Lower values reduce the number of segments each search has to visit,
+    /// at the cost of more indexing work.
+    /// If not set, the number of segments is only driven by `default_segment_number`.
+    #[prost(uint64, optional, tag = "11")]
+    #[validate(range(min = 1))]
+    pub max_segment_number: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1090,6 +1255,20 @@ pub struct StrictModeConfig {
     /// Max number of payload indexes in a collection
     #[prost(uint64, optional, tag = "19")]
     pub max_payload_index_count: ::core::option::Option<u64>,
+    /// Whether to return vectors by APIs, which return them unless requested otherwise. If false, vectors are only returned when requested.
+    #[prost(bool, optional, tag = "20")]
+    pub read_vectors_by_default: ::core::option::Option<bool>,
+    /// Max estimated size of dense vectors returned by a single read request, in bytes.
+    #[prost(uint64, optional, tag = "21")]
+    pub max_response_vectors_bytes: ::core::option::Option<u64>,
+    /// Max number of pending operations in the update queue of a replica. While exceeded, writes are rejected with a hint to retry later.
+    #[prost(uint64, optional, tag = "22")]
+    #[validate(range(min = 1))]
+    pub max_update_queue_length: ::core::option::Option<u64>,
+    /// Max size of vectors in a single unindexed segment of a replica, in kilobytes. Segments below the indexing threshold are not counted, as they are never indexed. While exceeded, writes are rejected with a hint to retry later.
+    #[prost(uint64, optional, tag = "23")]
+    #[validate(range(min = 1))]
+    pub max_unindexed_segment_size_kb: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1135,6 +1314,20 @@ pub struct StrictModeMultivector {
     #[validate(range(min = 1))]
     pub max_vectors: ::core::option::Option<u64>,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlacementConstraints {
+    /// Only place replicas on peers having all of these labels
+    #[prost(map = "string, string", tag = "1")]
+    pub require_labels: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Do not place replicas on peers holding replicas of any of these collections
+    #[prost(string, repeated, tag = "2")]
+    pub anti_affinity: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1200,6 +1393,9 @@ pub struct CreateCollection {
     /// Arbitrary JSON metadata for the collection
     #[prost(map = "string, message", tag = "18")]
     pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+    /// Constraints on which peers may hold replicas of the collection
+    #[prost(message, optional, tag = "19")]
+    pub placement: ::core::option::Option<PlacementConstraints>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1285,6 +1481,89 @@ pub struct CollectionOperationResponse {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuantizationSearchParams {
+    /// If set to true, search will ignore quantized vector data
+    #[prost(bool, optional, tag = "1")]
+    pub ignore: ::core::option::Option<bool>,
+    /// If true, use original vectors to re-score top-k results.
+    /// If ignored, qdrant decides automatically does rescore enabled or not.
+    #[prost(bool, optional, tag = "2")]
+    pub rescore: ::core::option::Option<bool>,
+    /// Oversampling factor for quantization.
+    ///
+    /// Defines how many extra vectors should be pre-selected using quantized index,
+    /// and then re-scored using original vectors.
+    ///
+    /// For example, if `oversampling` is 2.4 and `limit` is 100,
+    /// then 240 vectors will be pre-selected using quantized index,
+    /// and then top-100 will be returned after re-scoring.
+    #[prost(double, optional, tag = "3")]
+    #[validate(range(min = 1.0))]
+    pub oversampling: ::core::option::Option<f64>,
+    /// Max number of original vectors, read to re-score results in a segment.
+    /// Limits the number of pre-selected vectors, regardless of `oversampling`.
+    /// If `limit` is larger, results are not re-scored.
+    #[prost(uint64, optional, tag = "4")]
+    #[validate(range(min = 1))]
+    pub max_rescore: ::core::option::Option<u64>,
+    /// If true, re-scoring is skipped, when quantized and re-scored orders of results agree
+    /// on sampled queries. Default is false.
+    #[prost(bool, optional, tag = "5")]
+    pub adaptive_rescore: ::core::option::Option<bool>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcornSearchParams {
+    /// If true, then ACORN may be used for the HNSW search based on filters
+    /// selectivity.
+    ///
+    /// Improves search recall for searches with multiple low-selectivity
+    /// payload filters, at cost of performance.
+    #[prost(bool, optional, tag = "1")]
+    pub enable: ::core::option::Option<bool>,
+    /// Maximum selectivity of filters to enable ACORN.
+    ///
+    /// If estimated filters selectivity is higher than this value,
+    /// ACORN will not be used. Selectivity is estimated as:
+    /// `estimated number of points satisfying the filters / total number of points`.
+    ///
+    /// 0.0 for never, 1.0 for always. Default is 0.4.
+    #[prost(double, optional, tag = "2")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub max_selectivity: ::core::option::Option<f64>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchParams {
+    /// Params relevant to HNSW index. Size of the beam in a beam-search.
+    /// Larger the value - more accurate the result, more time required for search.
+    #[prost(uint64, optional, tag = "1")]
+    pub hnsw_ef: ::core::option::Option<u64>,
+    /// Search without approximation. If set to true, search may run long but with exact results.
+    #[prost(bool, optional, tag = "2")]
+    pub exact: ::core::option::Option<bool>,
+    /// If set to true, search will ignore quantized vector data
+    #[prost(message, optional, tag = "3")]
+    #[validate(nested)]
+    pub quantization: ::core::option::Option<QuantizationSearchParams>,
+    /// If enabled, the engine will only perform search among indexed or small segments.
+    /// Using this option prevents slow searches in case of delayed index, but does not
+    /// guarantee that all uploaded vectors will be included in search results
+    #[prost(bool, optional, tag = "4")]
+    pub indexed_only: ::core::option::Option<bool>,
+    /// ACORN search params
+    #[prost(message, optional, tag = "5")]
+    #[validate(nested)]
+    pub acorn: ::core::option::Option<AcornSearchParams>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectionParams {
     /// Number of shards in collection
     #[prost(uint32, tag = "3")]
@@ -1314,6 +1593,22 @@ pub struct CollectionParams {
     /// Define number of milliseconds to wait before attempting to read from another replica.
     #[prost(uint64, optional, tag = "11")]
     pub read_fan_out_delay_ms: ::core::option::Option<u64>,
+    /// Maximum number of cached query results, 0 disables the query cache
+    #[prost(uint64, optional, tag = "12")]
+    pub query_cache_max_entries: ::core::option::Option<u64>,
+    /// For how long a cached query result can be served, in seconds
+    #[prost(uint64, optional, tag = "13")]
+    pub query_cache_ttl_sec: ::core::option::Option<u64>,
+    /// Constraints on which peers may hold replicas of the collection
+    #[prost(message, optional, tag = "14")]
+    pub placement: ::core::option::Option<PlacementConstraints>,
+    /// How to handle NaN and infinite values in upserted and query vectors
+    #[prost(enumeration = "NonFiniteVectorPolicy", optional, tag = "15")]
+    pub non_finite_vector_policy: ::core::option::Option<i32>,
+    /// Search params, applied to search requests that don't set them
+    #[prost(message, optional, tag = "16")]
+    #[validate(nested)]
+    pub default_search_params: ::core::option::Option<SearchParams>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1337,6 +1632,19 @@ pub struct CollectionParamsDiff {
     /// Define number of milliseconds to wait before attempting to read from another replica.
     #[prost(uint64, optional, tag = "5")]
     pub read_fan_out_delay_ms: ::core::option::Option<u64>,
+    /// Maximum number of cached query results, 0 disables the query cache
+    #[prost(uint64, optional, tag = "6")]
+    pub query_cache_max_entries: ::core::option::Option<u64>,
+    /// For how long a cached query result can be served, in seconds
+    #[prost(uint64, optional, tag = "7")]
+    pub query_cache_ttl_sec: ::core::option::Option<u64>,
+    /// How to handle NaN and infinite values in upserted and query vectors
+    #[prost(enumeration = "NonFiniteVectorPolicy", optional, tag = "8")]
+    pub non_finite_vector_policy: ::core::option::Option<i32>,
+    /// Search params, applied to search requests that don't set them
+    #[prost(message, optional, tag = "9")]
+    #[validate(nested)]
+    pub default_search_params: ::core::option::Option<SearchParams>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1367,6 +1675,13 @@ pub struct CollectionConfig {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenFilters {
+    #[prost(enumeration = "TokenFilter", repeated, tag = "1")]
+    pub filters: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct KeywordIndexParams {
     /// If true - used for tenant optimization.
     #[prost(bool, optional, tag = "1")]
@@ -1379,6 +1694,34 @@ pub struct KeywordIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "3")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If true, fold accented characters of keywords to ASCII (e.g., "café" -> "cafe").
+    /// Default: false.
+    #[prost(bool, optional, tag = "4")]
+    pub ascii_folding: ::core::option::Option<bool>,
+    /// If true, apply Unicode NFKC normalization to keywords.
+    /// Default: false.
+    #[prost(bool, optional, tag = "5")]
+    pub unicode_normalization: ::core::option::Option<bool>,
+    /// If true - populate pages of the on-disk index into the page cache on load.
+    /// Default: false.
+    #[prost(bool, optional, tag = "6")]
+    pub populate: ::core::option::Option<bool>,
+    /// If true - build a dedicated HNSW subgraph for each value of this tenant field.
+    /// Requires `is_tenant` and `payload_m > 0`. Default: false.
+    #[prost(bool, optional, tag = "7")]
+    pub tenant_subgraphs: ::core::option::Option<bool>,
+    /// Values with more points than this do not get a dedicated subgraph.
+    /// Only used with `tenant_subgraphs`. Default: no limit.
+    #[prost(uint64, optional, tag = "8")]
+    pub tenant_subgraphs_max_points: ::core::option::Option<u64>,
+    /// If true, lowercase keywords, so that matching is case-insensitive.
+    /// Default: false.
+    #[prost(bool, optional, tag = "9")]
+    pub lowercase: ::core::option::Option<bool>,
+    /// If true - keep a bloom filter of the indexed keywords in RAM to skip disk reads
+    /// for missing keywords. Only applies to on-disk index. Default: false.
+    #[prost(bool, optional, tag = "10")]
+    pub bloom_filter: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1403,6 +1746,18 @@ pub struct IntegerIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "5")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If true - populate pages of the on-disk index into the page cache on load.
+    /// Default: false.
+    #[prost(bool, optional, tag = "6")]
+    pub populate: ::core::option::Option<bool>,
+    /// Target number of buckets in the histogram of values, used to estimate cardinality of range filters.
+    /// More buckets take more memory, but give more accurate estimations. Minimum is 2. Default: 100.
+    #[prost(uint64, optional, tag = "7")]
+    pub histogram_buckets: ::core::option::Option<u64>,
+    /// Max number of values in a bucket of the histogram, limits size of buckets in large segments.
+    /// Smaller buckets take more memory, but give more accurate estimations. Minimum is 10. Default: 10000.
+    #[prost(uint64, optional, tag = "8")]
+    pub histogram_max_bucket_size: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1420,6 +1775,18 @@ pub struct FloatIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "3")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If true - populate pages of the on-disk index into the page cache on load.
+    /// Default: false.
+    #[prost(bool, optional, tag = "4")]
+    pub populate: ::core::option::Option<bool>,
+    /// Target number of buckets in the histogram of values, used to estimate cardinality of range filters.
+    /// More buckets take more memory, but give more accurate estimations. Minimum is 2. Default: 100.
+    #[prost(uint64, optional, tag = "5")]
+    pub histogram_buckets: ::core::option::Option<u64>,
+    /// Max number of values in a bucket of the histogram, limits size of buckets in large segments.
+    /// Smaller buckets take more memory, but give more accurate estimations. Minimum is 10. Default: 10000.
+    #[prost(uint64, optional, tag = "6")]
+    pub histogram_max_bucket_size: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1433,6 +1800,30 @@ pub struct GeoIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "2")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If true - populate pages of the on-disk index into the page cache on load.
+    /// Default: false.
+    #[prost(bool, optional, tag = "3")]
+    pub populate: ::core::option::Option<bool>,
+    /// Length of geohashes stored in the index, from 1 to 12.
+    /// Shorter geohashes take less memory, but more points have to be checked exactly when filtering.
+    /// Default: 12.
+    #[prost(uint64, optional, tag = "4")]
+    pub precision: ::core::option::Option<u64>,
+    /// Maximum number of geohash cells to cover a filter region with.
+    /// More cells fit the region tighter, at the cost of more index lookups.
+    /// Default: 12.
+    #[prost(uint64, optional, tag = "5")]
+    pub max_query_cells: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GeoShapeIndexParams {
+    /// Enable HNSW graph building for this payload field.
+    /// If true, builds additional HNSW links (Need payload_m > 0).
+    /// Default: true.
+    #[prost(bool, optional, tag = "1")]
+    pub enable_hnsw: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1448,6 +1839,29 @@ pub struct StopwordsSet {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Synonyms {
+    /// Groups of synonyms, e.g. \["car", "automobile", "auto"\]
+    #[prost(message, repeated, tag = "1")]
+    pub groups: ::prost::alloc::vec::Vec<SynonymGroup>,
+    /// Path to a file with more groups of synonyms, one group of comma-separated words per line
+    #[prost(string, optional, tag = "2")]
+    pub file: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true, add synonyms to the tokens of indexed texts instead of expanding queries.
+    /// Ignored if phrase_matching is enabled. Default: false.
+    #[prost(bool, optional, tag = "3")]
+    pub expand_on_index: ::core::option::Option<bool>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SynonymGroup {
+    /// Words with the same meaning
+    #[prost(string, repeated, tag = "1")]
+    pub words: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TextIndexParams {
     /// Tokenizer type
     #[prost(enumeration = "TokenizerType", tag = "1")]
@@ -1482,6 +1896,37 @@ pub struct TextIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "10")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If true, detect the language of each text and apply stemming and stopwords of the detected language.
+    /// Only applies to the multilingual tokenizer. Default: false.
+    #[prost(bool, optional, tag = "11")]
+    pub language_detection: ::core::option::Option<bool>,
+    /// If set, store the ISO 639-1 code of the detected language (e.g. "en") in this payload key.
+    #[prost(string, optional, tag = "12")]
+    pub detected_language_key: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true, apply Unicode NFKC normalization to tokens (e.g., "ﬁ" -> "fi").
+    /// Default: false.
+    #[prost(bool, optional, tag = "13")]
+    pub unicode_normalization: ::core::option::Option<bool>,
+    /// If true - populate pages of the on-disk index into the page cache on load.
+    /// Default: false.
+    #[prost(bool, optional, tag = "14")]
+    pub populate: ::core::option::Option<bool>,
+    /// Groups of words with the same meaning. A word of a query matches any word of its group.
+    #[prost(message, optional, tag = "15")]
+    pub synonyms: ::core::option::Option<Synonyms>,
+    /// Ordered chain of filters, applied to each token both when indexing and querying.
+    /// If set, it replaces `lowercase`, `ascii_folding` and `unicode_normalization` flags,
+    /// and `stopwords` and `stemmer` are only applied at their position in the chain.
+    #[prost(message, optional, tag = "16")]
+    pub filters: ::core::option::Option<TokenFilters>,
+    /// If true, also index each pair of adjacent words as a single token, e.g. "new york".
+    /// Not applied with phrase matching or the prefix tokenizer. Default: false.
+    #[prost(bool, optional, tag = "17")]
+    pub shingles: ::core::option::Option<bool>,
+    /// Ratio of documents in (0, 1\], above which a term is ignored by text queries like a stopword.
+    /// Only applies to segments of at least 1000 documents. Default: disabled.
+    #[prost(double, optional, tag = "18")]
+    pub max_document_frequency: ::core::option::Option<f64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1521,6 +1966,10 @@ pub struct BoolIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "2")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If true - populate pages of the on-disk index into the page cache on load.
+    /// Default: false.
+    #[prost(bool, optional, tag = "3")]
+    pub populate: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1538,6 +1987,18 @@ pub struct DatetimeIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "3")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If true - populate pages of the on-disk index into the page cache on load.
+    /// Default: false.
+    #[prost(bool, optional, tag = "4")]
+    pub populate: ::core::option::Option<bool>,
+    /// Target number of buckets in the histogram of values, used to estimate cardinality of range filters.
+    /// More buckets take more memory, but give more accurate estimations. Minimum is 2. Default: 100.
+    #[prost(uint64, optional, tag = "5")]
+    pub histogram_buckets: ::core::option::Option<u64>,
+    /// Max number of values in a bucket of the histogram, limits size of buckets in large segments.
+    /// Smaller buckets take more memory, but give more accurate estimations. Minimum is 10. Default: 10000.
+    #[prost(uint64, optional, tag = "6")]
+    pub histogram_max_bucket_size: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1554,6 +2015,18 @@ pub struct UuidIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "3")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If true - populate pages of the on-disk index into the page cache on load.
+    /// Default: false.
+    #[prost(bool, optional, tag = "4")]
+    pub populate: ::core::option::Option<bool>,
+    /// If true - build a dedicated HNSW subgraph for each value of this tenant field.
+    /// Requires `is_tenant` and `payload_m > 0`. Default: false.
+    #[prost(bool, optional, tag = "5")]
+    pub tenant_subgraphs: ::core::option::Option<bool>,
+    /// Values with more points than this do not get a dedicated subgraph.
+    /// Only used with `tenant_subgraphs`. Default: no limit.
+    #[prost(uint64, optional, tag = "6")]
+    pub tenant_subgraphs_max_points: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1562,7 +2035,7 @@ pub struct UuidIndexParams {
 pub struct PayloadIndexParams {
     #[prost(
         oneof = "payload_index_params::IndexParams",
-        tags = "3, 2, 4, 5, 1, 6, 7, 8"
+        tags = "3, 2, 4, 5, 1, 6, 7, 8, 9"
     )]
     #[validate(nested)]
     pub index_params: ::core::option::Option<payload_index_params::IndexParams>,
@@ -1597,6 +2070,9 @@ pub mod payload_index_params {
         /// Parameters for uuid index
         #[prost(message, tag = "8")]
         UuidIndexParams(super::UuidIndexParams),
+        /// Parameters for geo shape index
+        #[prost(message, tag = "9")]
+        GeoShapeIndexParams(super::GeoShapeIndexParams),
     }
 }
 #[derive(serde::Serialize)]
@@ -1612,6 +2088,9 @@ pub struct PayloadSchemaInfo {
     /// Number of points indexed within this field
     #[prost(uint64, optional, tag = "3")]
     pub points: ::core::option::Option<u64>,
+    /// Approximate number of distinct indexed values
+    #[prost(uint64, optional, tag = "4")]
+    pub distinct_values: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1986,6 +2465,9 @@ pub struct CreateShardKey {
     /// Initial state of created replicas. Warning: use with care.
     #[prost(enumeration = "ReplicaState", optional, tag = "5")]
     pub initial_state: ::core::option::Option<i32>,
+    /// Constraints on which peers may hold replicas of the shard key, in addition to the ones of the collection
+    #[prost(message, optional, tag = "6")]
+    pub placement_constraints: ::core::option::Option<PlacementConstraints>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -2182,6 +2664,39 @@ impl Modifier {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum HnswNeighborSelection {
+    /// Skip candidates which are closer to an already selected neighbour than to the node itself
+    Heuristic = 0,
+    /// Same as `Heuristic`, but fill the remaining free links of a new node with the skipped candidates
+    HeuristicKeepPruned = 1,
+    /// Link to the closest candidates, without pruning
+    Nearest = 2,
+}
+impl HnswNeighborSelection {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            HnswNeighborSelection::Heuristic => "Heuristic",
+            HnswNeighborSelection::HeuristicKeepPruned => "HeuristicKeepPruned",
+            HnswNeighborSelection::Nearest => "Nearest",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Heuristic" => Some(Self::Heuristic),
+            "HeuristicKeepPruned" => Some(Self::HeuristicKeepPruned),
+            "Nearest" => Some(Self::Nearest),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum MultiVectorComparator {
     MaxSim = 0,
 }
@@ -2292,6 +2807,7 @@ pub enum PayloadSchemaType {
     Bool = 6,
     Datetime = 7,
     Uuid = 8,
+    GeoShape = 9,
 }
 impl PayloadSchemaType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2309,6 +2825,7 @@ impl PayloadSchemaType {
             PayloadSchemaType::Bool => "Bool",
             PayloadSchemaType::Datetime => "Datetime",
             PayloadSchemaType::Uuid => "Uuid",
+            PayloadSchemaType::GeoShape => "GeoShape",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2323,6 +2840,7 @@ impl PayloadSchemaType {
             "Bool" => Some(Self::Bool),
             "Datetime" => Some(Self::Datetime),
             "Uuid" => Some(Self::Uuid),
+            "GeoShape" => Some(Self::GeoShape),
             _ => None,
         }
     }
@@ -2429,22 +2947,55 @@ pub enum ShardingMethod {
     /// Shard by user-defined key
     Custom = 1,
 }
-impl ShardingMethod {
+impl ShardingMethod {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ShardingMethod::Auto => "Auto",
+            ShardingMethod::Custom => "Custom",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Auto" => Some(Self::Auto),
+            "Custom" => Some(Self::Custom),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NonFiniteVectorPolicy {
+    /// Reject vectors containing NaN or infinite values
+    Reject = 0,
+    /// Replace NaN and infinite values with zero
+    SanitizeToZero = 1,
+    /// Replace NaN with zero, and infinite values with the largest finite value of the same sign
+    Clamp = 2,
+}
+impl NonFiniteVectorPolicy {
     /// String value of the enum field names used in the ProtoBuf definition.
     ///
     /// The values are not transformed in any way and thus are considered stable
     /// (if the ProtoBuf definition does not change) and safe for programmatic use.
     pub fn as_str_name(&self) -> &'static str {
         match self {
-            ShardingMethod::Auto => "Auto",
-            ShardingMethod::Custom => "Custom",
+            NonFiniteVectorPolicy::Reject => "Reject",
+            NonFiniteVectorPolicy::SanitizeToZero => "SanitizeToZero",
+            NonFiniteVectorPolicy::Clamp => "Clamp",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
     pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
         match value {
-            "Auto" => Some(Self::Auto),
-            "Custom" => Some(Self::Custom),
+            "Reject" => Some(Self::Reject),
+            "SanitizeToZero" => Some(Self::SanitizeToZero),
+            "Clamp" => Some(Self::Clamp),
             _ => None,
         }
     }
@@ -2458,6 +3009,7 @@ pub enum TokenizerType {
     Whitespace = 2,
     Word = 3,
     Multilingual = 4,
+    Cjk = 5,
 }
 impl TokenizerType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2471,6 +3023,7 @@ impl TokenizerType {
             TokenizerType::Whitespace => "Whitespace",
             TokenizerType::Word => "Word",
             TokenizerType::Multilingual => "Multilingual",
+            TokenizerType::Cjk => "Cjk",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2481,6 +3034,51 @@ impl TokenizerType {
             "Whitespace" => Some(Self::Whitespace),
             "Word" => Some(Self::Word),
             "Multilingual" => Some(Self::Multilingual),
+            "Cjk" => Some(Self::Cjk),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TokenFilter {
+    UnknownTokenFilter = 0,
+    /// Lowercase the token
+    Lowercase = 1,
+    /// Fold accented characters to ASCII
+    AsciiFolding = 2,
+    /// Apply Unicode NFKC normalization
+    UnicodeNormalization = 3,
+    /// Drop the token, if it is one of the configured stopwords
+    Stopwords = 4,
+    /// Stem the token with the configured stemmer
+    Stemmer = 5,
+}
+impl TokenFilter {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TokenFilter::UnknownTokenFilter => "UnknownTokenFilter",
+            TokenFilter::Lowercase => "Lowercase",
+            TokenFilter::AsciiFolding => "AsciiFolding",
+            TokenFilter::UnicodeNormalization => "UnicodeNormalization",
+            TokenFilter::Stopwords => "Stopwords",
+            TokenFilter::Stemmer => "Stemmer",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UnknownTokenFilter" => Some(Self::UnknownTokenFilter),
+            "Lowercase" => Some(Self::Lowercase),
+            "AsciiFolding" => Some(Self::AsciiFolding),
+            "UnicodeNormalization" => Some(Self::UnicodeNormalization),
+            "Stopwords" => Some(Self::Stopwords),
+            "Stemmer" => Some(Self::Stemmer),
             _ => None,
         }
     }
@@ -4978,6 +5576,14 @@ pub struct UpsertPoints {
     /// Mode of the upsert operation: insert_only, upsert (default), update_only
     #[prost(enumeration = "UpdateMode", optional, tag = "8")]
     pub update_mode: ::core::option::Option<i32>,
+    /// Payload key holding a client-provided sequence number of each point.
+    /// Writes carrying the same or a lower sequence number than the stored point are ignored.
+    #[prost(string, optional, tag = "9")]
+    pub sequence_key: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true, dense vectors are stored as provided, without normalization.
+    /// The client is responsible for normalizing vectors of cosine distance.
+    #[prost(bool, optional, tag = "10")]
+    pub skip_normalization: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5006,6 +5612,10 @@ pub struct DeletePoints {
     /// Timeout for the request in seconds
     #[prost(uint64, optional, tag = "6")]
     pub timeout: ::core::option::Option<u64>,
+    /// Return ids of the points matching the filter, which are deleted
+    #[prost(message, optional, tag = "7")]
+    #[validate(nested)]
+    pub return_ids: ::core::option::Option<ReturnIds>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5070,6 +5680,9 @@ pub struct UpdatePointVectors {
     /// Timeout for the request in seconds
     #[prost(uint64, optional, tag = "7")]
     pub timeout: ::core::option::Option<u64>,
+    /// If true, append sub-vectors to the existing multivectors instead of replacing them
+    #[prost(bool, optional, tag = "8")]
+    pub append: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5150,6 +5763,10 @@ pub struct SetPayloadPoints {
     /// Timeout for the request in seconds
     #[prost(uint64, optional, tag = "9")]
     pub timeout: ::core::option::Option<u64>,
+    /// Return ids of the points matching the filter, which payload is assigned to
+    #[prost(message, optional, tag = "10")]
+    #[validate(nested)]
+    pub return_ids: ::core::option::Option<ReturnIds>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5211,6 +5828,23 @@ pub struct ClearPayloadPoints {
     /// Timeout for the request in seconds
     #[prost(uint64, optional, tag = "6")]
     pub timeout: ::core::option::Option<u64>,
+    /// Return ids of the points matching the filter, which payload is cleared
+    #[prost(message, optional, tag = "7")]
+    #[validate(nested)]
+    pub return_ids: ::core::option::Option<ReturnIds>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReturnIds {
+    /// Max number of ids to return, remaining ids are returned by repeating the request with `offset` of the next page
+    #[prost(uint64, tag = "1")]
+    #[validate(range(min = 1, max = 10000))]
+    pub limit: u64,
+    /// Id of the first point to return, `next_page_offset` of the previous page
+    #[prost(message, optional, tag = "2")]
+    pub offset: ::core::option::Option<PointId>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5401,79 +6035,6 @@ pub mod with_vectors_selector {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct QuantizationSearchParams {
-    /// If set to true, search will ignore quantized vector data
-    #[prost(bool, optional, tag = "1")]
-    pub ignore: ::core::option::Option<bool>,
-    /// If true, use original vectors to re-score top-k results.
-    /// If ignored, qdrant decides automatically does rescore enabled or not.
-    #[prost(bool, optional, tag = "2")]
-    pub rescore: ::core::option::Option<bool>,
-    /// Oversampling factor for quantization.
-    ///
-    /// Defines how many extra vectors should be pre-selected using quantized index,
-    /// and then re-scored using original vectors.
-    ///
-    /// For example, if `oversampling` is 2.4 and `limit` is 100,
-    /// then 240 vectors will be pre-selected using quantized index,
-    /// and then top-100 will be returned after re-scoring.
-    #[prost(double, optional, tag = "3")]
-    #[validate(range(min = 1.0))]
-    pub oversampling: ::core::option::Option<f64>,
-}
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AcornSearchParams {
-    /// If true, then ACORN may be used for the HNSW search based on filters
-    /// selectivity.
-    ///
-    /// Improves search recall for searches with multiple low-selectivity
-    /// payload filters, at cost of performance.
-    #[prost(bool, optional, tag = "1")]
-    pub enable: ::core::option::Option<bool>,
-    /// Maximum selectivity of filters to enable ACORN.
-    ///
-    /// If estimated filters selectivity is higher than this value,
-    /// ACORN will not be used. Selectivity is estimated as:
-    /// `estimated number of points satisfying the filters / total number of points`.
-    ///
-    /// 0.0 for never, 1.0 for always. Default is 0.4.
-    #[prost(double, optional, tag = "2")]
-    #[validate(range(min = 0.0, max = 1.0))]
-    pub max_selectivity: ::core::option::Option<f64>,
-}
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SearchParams {
-    /// Params relevant to HNSW index. Size of the beam in a beam-search.
-    /// Larger the value - more accurate the result, more time required for search.
-    #[prost(uint64, optional, tag = "1")]
-    pub hnsw_ef: ::core::option::Option<u64>,
-    /// Search without approximation. If set to true, search may run long but with exact results.
-    #[prost(bool, optional, tag = "2")]
-    pub exact: ::core::option::Option<bool>,
-    /// If set to true, search will ignore quantized vector data
-    #[prost(message, optional, tag = "3")]
-    #[validate(nested)]
-    pub quantization: ::core::option::Option<QuantizationSearchParams>,
-    /// If enabled, the engine will only perform search among indexed or small segments.
-    /// Using this option prevents slow searches in case of delayed index, but does not
-    /// guarantee that all uploaded vectors will be included in search results
-    #[prost(bool, optional, tag = "4")]
-    pub indexed_only: ::core::option::Option<bool>,
-    /// ACORN search params
-    #[prost(message, optional, tag = "5")]
-    #[validate(nested)]
-    pub acorn: ::core::option::Option<AcornSearchParams>,
-}
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchPoints {
     /// name of the collection
     #[prost(string, tag = "1")]
@@ -5663,6 +6224,20 @@ pub struct OrderBy {
     /// Start from this value
     #[prost(message, optional, tag = "3")]
     pub start_from: ::core::option::Option<StartFrom>,
+    /// Payload keys to order points with equal values of `key` by, in order of priority
+    #[prost(message, repeated, tag = "4")]
+    pub then_by: ::prost::alloc::vec::Vec<ThenBy>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ThenBy {
+    /// Payload key to order by
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Ascending or descending order
+    #[prost(enumeration = "Direction", optional, tag = "2")]
+    pub direction: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5704,6 +6279,12 @@ pub struct ScrollPoints {
     /// If set, overrides global timeout setting for this request. Unit is seconds.
     #[prost(uint64, optional, tag = "11")]
     pub timeout: ::core::option::Option<u64>,
+    /// If true, all pages of the scroll read points from the same set of segments. The first page must be requested without offset
+    #[prost(bool, optional, tag = "12")]
+    pub consistent: ::core::option::Option<bool>,
+    /// Identifier of a consistent scroll, returned with its first page. Must be specified to request the following pages
+    #[prost(string, optional, tag = "13")]
+    pub view_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6180,7 +6761,7 @@ pub struct Formula {
 pub struct Expression {
     #[prost(
         oneof = "expression::Variant",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21"
     )]
     #[validate(nested)]
     pub variant: ::core::option::Option<expression::Variant>,
@@ -6247,6 +6828,12 @@ pub mod expression {
         /// Linear decay
         #[prost(message, tag = "19")]
         LinDecay(::prost::alloc::boxed::Box<super::DecayParamsExpression>),
+        /// BM25 relevance of a full-text indexed payload field to a text
+        #[prost(message, tag = "20")]
+        TextScore(super::TextScore),
+        /// User-defined function, compiled to WebAssembly
+        #[prost(message, tag = "21")]
+        Wasm(super::WasmExpression),
     }
 }
 #[derive(serde::Serialize)]
@@ -6258,6 +6845,47 @@ pub struct GeoDistance {
     #[prost(string, tag = "2")]
     pub to: ::prost::alloc::string::String,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextScore {
+    /// Payload key with a full-text index
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Text to score the field values against
+    #[prost(string, tag = "2")]
+    pub text: ::prost::alloc::string::String,
+    /// Weights of individual terms of the text. Terms without a boost have weight 1
+    #[prost(message, repeated, tag = "3")]
+    pub boosts: ::prost::alloc::vec::Vec<TermBoost>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TermBoost {
+    /// Term of the text, tokenized like the text
+    #[prost(string, tag = "1")]
+    pub term: ::prost::alloc::string::String,
+    /// Weight of the term relevance, non-negative
+    #[prost(float, tag = "2")]
+    pub boost: f32,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WasmExpression {
+    /// WebAssembly module, which exports a `score` function taking one f64 per argument and returning f64
+    #[prost(bytes = "vec", tag = "1")]
+    pub module: ::prost::alloc::vec::Vec<u8>,
+    /// Arguments to call the function with
+    #[prost(message, repeated, tag = "2")]
+    #[validate(nested)]
+    pub args: ::prost::alloc::vec::Vec<Expression>,
+    /// Fuel available to the function for scoring a single point. Default is 100000, maximum is 10000000
+    #[prost(uint64, optional, tag = "3")]
+    pub fuel_limit: ::core::option::Option<u64>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6340,6 +6968,85 @@ pub struct NearestInputWithMmr {
     #[validate(nested)]
     pub mmr: ::core::option::Option<Mmr>,
 }
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NearestInputWithCandidates {
+    /// The vector to score the candidates against.
+    #[prost(message, optional, tag = "1")]
+    #[validate(nested)]
+    pub nearest: ::core::option::Option<VectorInput>,
+    /// Score only the points with these ids, instead of searching the whole collection.
+    /// Points are scored exactly, without traversing the vector index.
+    #[prost(message, repeated, tag = "2")]
+    #[validate(length(min = 1))]
+    pub candidates: ::prost::alloc::vec::Vec<PointId>,
+    /// Perform MMR (Maximal Marginal Relevance) reranking of the candidates,
+    /// using the same vector in this query to calculate relevance.
+    #[prost(message, optional, tag = "3")]
+    #[validate(nested)]
+    pub mmr: ::core::option::Option<Mmr>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VectorSelectionMapping {
+    /// Named vector to score points having this value.
+    #[prost(string, tag = "4")]
+    pub vector: ::prost::alloc::string::String,
+    /// Value of the payload field.
+    #[prost(oneof = "vector_selection_mapping::Value", tags = "1, 2, 3")]
+    pub value: ::core::option::Option<vector_selection_mapping::Value>,
+}
+/// Nested message and enum types in `VectorSelectionMapping`.
+pub mod vector_selection_mapping {
+    /// Value of the payload field.
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(string, tag = "1")]
+        Keyword(::prost::alloc::string::String),
+        #[prost(int64, tag = "2")]
+        Integer(i64),
+        #[prost(bool, tag = "3")]
+        Boolean(bool),
+    }
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VectorSelectionQuery {
+    /// Name of the vector to score with this query.
+    #[prost(string, tag = "1")]
+    pub vector: ::prost::alloc::string::String,
+    /// Query vector.
+    #[prost(message, optional, tag = "2")]
+    #[validate(nested)]
+    pub query: ::core::option::Option<VectorInput>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VectorSelectionInput {
+    /// Payload field which decides the vector to score a point with.
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Payload values and the named vectors they select.
+    #[prost(message, repeated, tag = "2")]
+    pub mapping: ::prost::alloc::vec::Vec<VectorSelectionMapping>,
+    /// Vector to score points, which don't have any of the mapped values.
+    /// If not set, such points are excluded from the results.
+    #[prost(string, optional, tag = "3")]
+    pub default: ::core::option::Option<::prost::alloc::string::String>,
+    /// Query for each of the selectable named vectors.
+    #[prost(message, repeated, tag = "4")]
+    #[validate(nested)]
+    pub queries: ::prost::alloc::vec::Vec<VectorSelectionQuery>,
+}
 /// Maximal Marginal Relevance (MMR) algorithm for re-ranking the points.
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6386,7 +7093,7 @@ pub struct Rrf {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Query {
-    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11")]
+    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13")]
     #[validate(nested)]
     pub variant: ::core::option::Option<query::Variant>,
 }
@@ -6429,6 +7136,12 @@ pub mod query {
         /// Search with feedback from some oracle.
         #[prost(message, tag = "11")]
         RelevanceFeedback(super::RelevanceFeedbackInput),
+        /// Score only the given points, e.g. to re-rank externally retrieved candidates.
+        #[prost(message, tag = "12")]
+        NearestWithCandidates(super::NearestInputWithCandidates),
+        /// Score each point with the named vector, selected by a value of its payload field.
+        #[prost(message, tag = "13")]
+        VectorSelection(super::VectorSelectionInput),
     }
 }
 #[derive(validator::Validate)]
@@ -6534,6 +7247,25 @@ pub struct QueryPoints {
     #[prost(uint64, optional, tag = "15")]
     #[validate(range(min = 1))]
     pub timeout: ::core::option::Option<u64>,
+    /// Return matches of full-text conditions of the filter in the payload.
+    /// Only fields with a full-text index, which are included into the returned payload, are highlighted.
+    #[prost(message, optional, tag = "16")]
+    pub highlight: ::core::option::Option<HighlightParams>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HighlightParams {
+    /// Inserted before each match in fragments. Default is `<em>`.
+    #[prost(string, optional, tag = "1")]
+    pub pre_tag: ::core::option::Option<::prost::alloc::string::String>,
+    /// Inserted after each match in fragments. Default is `</em>`.
+    #[prost(string, optional, tag = "2")]
+    pub post_tag: ::core::option::Option<::prost::alloc::string::String>,
+    /// Number of characters before and after matches to include into fragments.
+    /// If not set, each fragment is a whole text value.
+    #[prost(uint64, optional, tag = "3")]
+    pub fragment_context: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6808,6 +7540,14 @@ pub mod points_update_operation {
         /// Mode of the upsert operation: insert_only, upsert (default), update_only
         #[prost(enumeration = "super::UpdateMode", optional, tag = "4")]
         pub update_mode: ::core::option::Option<i32>,
+        /// Payload key holding a client-provided sequence number of each point.
+        /// Writes carrying the same or a lower sequence number than the stored point are ignored.
+        #[prost(string, optional, tag = "5")]
+        pub sequence_key: ::core::option::Option<::prost::alloc::string::String>,
+        /// If true, dense vectors are stored as provided, without normalization.
+        /// The client is responsible for normalizing vectors of cosine distance.
+        #[prost(bool, optional, tag = "6")]
+        pub skip_normalization: ::core::option::Option<bool>,
     }
     #[derive(serde::Serialize)]
     #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6873,6 +7613,9 @@ pub mod points_update_operation {
         /// If specified, only points that match this filter will be updated
         #[prost(message, optional, tag = "3")]
         pub update_filter: ::core::option::Option<super::Filter>,
+        /// If true, append sub-vectors to the existing multivectors instead of replacing them
+        #[prost(bool, optional, tag = "4")]
+        pub append: ::core::option::Option<bool>,
     }
     #[derive(serde::Serialize)]
     #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6984,6 +7727,12 @@ pub struct UpdateResult {
     /// Operation status
     #[prost(enumeration = "UpdateStatus", tag = "2")]
     pub status: i32,
+    /// Ids of the points updated by filter, if requested
+    #[prost(message, repeated, tag = "4")]
+    pub affected_ids: ::prost::alloc::vec::Vec<PointId>,
+    /// Offset of the next page of ids of the points matching the filter, if any
+    #[prost(message, optional, tag = "5")]
+    pub next_page_offset: ::core::option::Option<PointId>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7029,6 +7778,38 @@ pub struct ScoredPoint {
     /// Order by value
     #[prost(message, optional, tag = "8")]
     pub order_value: ::core::option::Option<OrderValue>,
+    /// Matches of full-text conditions of the filter, per payload field
+    #[prost(map = "string, message", tag = "9")]
+    pub highlight: ::std::collections::HashMap<::prost::alloc::string::String, TextHighlights>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextOffset {
+    /// Character offset of the start of a match
+    #[prost(uint64, tag = "1")]
+    pub start: u64,
+    /// Character offset of the end of a match, exclusive
+    #[prost(uint64, tag = "2")]
+    pub end: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextHighlight {
+    /// Parts of the text with matches, wrapped into pre- and post-tags
+    #[prost(string, repeated, tag = "1")]
+    pub fragments: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Character offsets of matches in the original text
+    #[prost(message, repeated, tag = "2")]
+    pub offsets: ::prost::alloc::vec::Vec<TextOffset>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextHighlights {
+    #[prost(message, repeated, tag = "1")]
+    pub highlights: ::prost::alloc::vec::Vec<TextHighlight>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7181,6 +7962,9 @@ pub struct ScrollResponse {
     pub time: f64,
     #[prost(message, optional, tag = "4")]
     pub usage: ::core::option::Option<Usage>,
+    /// Identifier of the consistent scroll, use it to request the next page
+    #[prost(string, optional, tag = "5")]
+    pub view_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7188,6 +7972,12 @@ pub struct ScrollResponse {
 pub struct CountResult {
     #[prost(uint64, tag = "1")]
     pub count: u64,
+    /// Lower bound of the number of points. Only returned for approximate counts
+    #[prost(uint64, optional, tag = "2")]
+    pub min: ::core::option::Option<u64>,
+    /// Upper bound of the number of points. Only returned for approximate counts
+    #[prost(uint64, optional, tag = "3")]
+    pub max: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7528,6 +8318,7 @@ pub enum FieldType {
     Bool = 5,
     Datetime = 6,
     Uuid = 7,
+    GeoShape = 8,
 }
 impl FieldType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -7544,6 +8335,7 @@ impl FieldType {
             FieldType::Bool => "FieldTypeBool",
             FieldType::Datetime => "FieldTypeDatetime",
             FieldType::Uuid => "FieldTypeUuid",
+            FieldType::GeoShape => "FieldTypeGeoShape",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -7557,6 +8349,7 @@ impl FieldType {
             "FieldTypeBool" => Some(Self::Bool),
             "FieldTypeDatetime" => Some(Self::Datetime),
             "FieldTypeUuid" => Some(Self::Uuid),
+            "FieldTypeGeoShape" => Some(Self::GeoShape),
             _ => None,
         }
     }
@@ -10378,6 +11171,12 @@ pub struct UpdateResultInternal {
     pub status: i32,
     #[prost(message, optional, tag = "3")]
     pub clock_tag: ::core::option::Option<ClockTag>,
+    /// Ids of the points updated by filter, if requested
+    #[prost(message, repeated, tag = "4")]
+    pub affected_ids: ::prost::alloc::vec::Vec<PointId>,
+    /// Offset of the next page of ids of the points matching the filter, if any
+    #[prost(message, optional, tag = "5")]
+    pub next_page_offset: ::core::option::Option<PointId>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -10728,6 +11527,36 @@ pub struct MmrInternal {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VectorSelectionInternal {
+    /// Payload field which decides the vector to score a point with
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Payload values and the named vectors they select
+    #[prost(message, repeated, tag = "2")]
+    pub mapping: ::prost::alloc::vec::Vec<VectorSelectionMapping>,
+    /// Vector to score points, which don't have any of the mapped values
+    #[prost(string, optional, tag = "3")]
+    pub default: ::core::option::Option<::prost::alloc::string::String>,
+    /// Query for each of the selectable named vectors
+    #[prost(message, repeated, tag = "4")]
+    pub queries: ::prost::alloc::vec::Vec<vector_selection_internal::Query>,
+}
+/// Nested message and enum types in `VectorSelectionInternal`.
+pub mod vector_selection_internal {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Query {
+        /// Name of the vector to score with this query
+        #[prost(string, tag = "1")]
+        pub vector: ::prost::alloc::string::String,
+        #[prost(message, optional, tag = "2")]
+        pub query: ::core::option::Option<super::RawVector>,
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryShardPoints {
     #[prost(message, repeated, tag = "1")]
     pub prefetch: ::prost::alloc::vec::Vec<query_shard_points::Prefetch>,
@@ -10757,7 +11586,7 @@ pub mod query_shard_points {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Query {
-        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7")]
+        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
         pub score: ::core::option::Option<query::Score>,
     }
     /// Nested message and enum types in `Query`.
@@ -10787,6 +11616,9 @@ pub mod query_shard_points {
             /// Parameterized RRF fusion
             #[prost(message, tag = "7")]
             Rrf(super::super::Rrf),
+            /// Score each point with the vector selected by its payload
+            #[prost(message, tag = "8")]
+            VectorSelection(super::super::VectorSelectionInternal),
         }
     }
     #[derive(serde::Serialize)]
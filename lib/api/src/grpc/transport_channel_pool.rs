@@ -1,19 +1,25 @@
 use std::collections::HashMap;
 use std::future::Future;
 use std::num::NonZeroUsize;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use common::fault_injection::{self, FaultPoint};
 use rand::{Rng, rng};
 use tokio::select;
+use tonic::codec::CompressionEncoding;
 use tonic::codegen::InterceptedService;
 use tonic::service::Interceptor;
 use tonic::transport::{Channel, ClientTlsConfig, Error as TonicError, Uri};
 use tonic::{Code, Request, Status};
 
-use crate::grpc::dynamic_channel_pool::DynamicChannelPool;
+use crate::grpc::dynamic_channel_pool::{DynamicChannelPool, Http2Settings};
 use crate::grpc::dynamic_pool::CountedItem;
 use crate::grpc::qdrant::HealthCheckRequest;
+use crate::grpc::qdrant::collections_internal_client::CollectionsInternalClient;
+use crate::grpc::qdrant::points_internal_client::PointsInternalClient;
 use crate::grpc::qdrant::qdrant_client::QdrantClient;
+use crate::grpc::qdrant::qdrant_internal_client::QdrantInternalClient;
+use crate::grpc::qdrant::shard_snapshots_client::ShardSnapshotsClient;
 
 /// Maximum lifetime of a gRPC channel.
 ///
@@ -29,7 +35,7 @@ pub const DEFAULT_POOL_SIZE: usize = 2;
 /// `http2_max_pending_accept_reset_streams` that we configure to minimize the chance of
 /// GOAWAY/ENHANCE_YOUR_CALM errors from occurring.
 /// More info: <https://github.com/qdrant/qdrant/issues/1907>
-const MAX_CONNECTIONS_PER_CHANNEL: usize = 1024;
+pub const DEFAULT_MAX_CONNECTIONS_PER_CHANNEL: usize = 1024;
 pub const DEFAULT_RETRIES: usize = 2;
 const DEFAULT_BACKOFF: Duration = Duration::from_millis(100);
 
@@ -43,6 +49,9 @@ const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
 /// Try to recreate channel, if there were no successful requests within this time
 const CHANNEL_TTL: Duration = Duration::from_secs(5);
 
+/// Weight of a new round-trip time sample in the moving average
+const RTT_SMOOTHING_FACTOR: f64 = 0.2;
+
 #[derive(thiserror::Error, Debug)]
 pub enum RequestError<E: std::error::Error> {
     #[error("Error in closure supplied to transport channel pool: {0}")]
@@ -92,6 +101,55 @@ impl Interceptor for AddTimeout {
     }
 }
 
+/// Tuning of the channels between peers
+#[derive(Debug, Clone, Copy)]
+pub struct ChannelTuning {
+    /// Compression of requests, and of responses if the server supports it
+    pub compression: Option<CompressionEncoding>,
+    /// Number of concurrent requests multiplexed over a single channel,
+    /// before another channel to the same URI is opened
+    pub max_connections_per_channel: usize,
+    pub http2: Http2Settings,
+}
+
+impl Default for ChannelTuning {
+    fn default() -> Self {
+        Self {
+            compression: None,
+            max_connections_per_channel: DEFAULT_MAX_CONNECTIONS_PER_CHANNEL,
+            http2: Http2Settings::default(),
+        }
+    }
+}
+
+/// Internal gRPC clients, which may compress their messages
+pub trait CompressedClient: Sized {
+    fn compressed(self, compression: Option<CompressionEncoding>) -> Self;
+}
+
+macro_rules! impl_compressed_client {
+    ($($client:ident),* $(,)?) => {
+        $(
+            impl CompressedClient for $client<InterceptedService<Channel, AddTimeout>> {
+                fn compressed(self, compression: Option<CompressionEncoding>) -> Self {
+                    match compression {
+                        Some(encoding) => self.send_compressed(encoding).accept_compressed(encoding),
+                        None => self,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_compressed_client!(
+    QdrantClient,
+    QdrantInternalClient,
+    PointsInternalClient,
+    CollectionsInternalClient,
+    ShardSnapshotsClient,
+);
+
 /// Holds a pool of channels established for a set of URIs.
 /// Channel are shared by cloning them.
 /// Make the `pool_size` larger to increase throughput.
@@ -101,16 +159,21 @@ pub struct TransportChannelPool {
     grpc_timeout: Duration,
     connection_timeout: Duration,
     tls_config: Option<ClientTlsConfig>,
+    tuning: ChannelTuning,
+    /// Smoothed round-trip time of health-check requests, per URI
+    uri_to_rtt: parking_lot::RwLock<HashMap<Uri, Duration>>,
 }
 
 impl Default for TransportChannelPool {
     fn default() -> Self {
         Self {
             uri_to_pool: tokio::sync::RwLock::new(HashMap::new()),
+            uri_to_rtt: Default::default(),
             pool_size: NonZeroUsize::new(DEFAULT_POOL_SIZE).unwrap(),
             grpc_timeout: DEFAULT_GRPC_TIMEOUT,
             connection_timeout: DEFAULT_CONNECT_TIMEOUT,
             tls_config: None,
+            tuning: ChannelTuning::default(),
         }
     }
 }
@@ -121,6 +184,7 @@ impl TransportChannelPool {
         connection_timeout: Duration,
         pool_size: usize,
         tls_config: Option<ClientTlsConfig>,
+        tuning: ChannelTuning,
     ) -> Self {
         Self {
             uri_to_pool: Default::default(),
@@ -128,6 +192,8 @@ impl TransportChannelPool {
             connection_timeout,
             pool_size: NonZeroUsize::new(pool_size).unwrap(),
             tls_config,
+            tuning,
+            uri_to_rtt: Default::default(),
         }
     }
 
@@ -137,7 +203,8 @@ impl TransportChannelPool {
             MAX_GRPC_CHANNEL_TIMEOUT,
             self.connection_timeout,
             self.tls_config.clone(),
-            MAX_CONNECTIONS_PER_CHANNEL,
+            self.tuning.http2,
+            self.tuning.max_connections_per_channel,
             self.pool_size.get(),
         )
         .await
@@ -161,6 +228,7 @@ impl TransportChannelPool {
     pub async fn drop_pool(&self, uri: &Uri) {
         let mut guard = self.uri_to_pool.write().await;
         guard.remove(uri);
+        self.uri_to_rtt.write().remove(uri);
     }
 
     pub async fn drop_channel(&self, uri: &Uri, channel: CountedItem<Channel>) {
@@ -207,6 +275,7 @@ impl TransportChannelPool {
                 Some(Ok(channel)) => {
                     let mut client = QdrantClient::new(channel.item().clone());
 
+                    let started_at = Instant::now();
                     let resp: Result<_, Status> = select! {
                         res = client.health_check(HealthCheckRequest {}) => {
                             res
@@ -225,6 +294,7 @@ impl TransportChannelPool {
                     match resp {
                         Ok(_) => {
                             channel.report_success();
+                            self.report_rtt(uri, started_at.elapsed());
                             // continue watching
                         }
                         Err(status) => return HealthCheckError::RequestError(status),
@@ -279,6 +349,43 @@ impl TransportChannelPool {
         Err(result)
     }
 
+    /// Send a health-check request to `uri`, and record its round-trip time.
+    pub async fn ping(&self, uri: &Uri) -> Result<Duration, RequestError<Status>> {
+        // Don't count establishing a connection into the round-trip time
+        let _ = self.get_or_create_pooled_channel(uri).await;
+
+        let started_at = Instant::now();
+
+        self.with_channel_timeout(
+            uri,
+            |channel| async move {
+                QdrantClient::new(channel)
+                    .health_check(HealthCheckRequest {})
+                    .await
+            },
+            Some(HEALTH_CHECK_TIMEOUT),
+            0,
+        )
+        .await?;
+
+        let rtt = started_at.elapsed();
+        self.report_rtt(uri, rtt);
+        Ok(rtt)
+    }
+
+    fn report_rtt(&self, uri: &Uri, sample: Duration) {
+        let mut guard = self.uri_to_rtt.write();
+        guard
+            .entry(uri.clone())
+            .and_modify(|rtt| *rtt = smooth_rtt(*rtt, sample))
+            .or_insert(sample);
+    }
+
+    /// Smoothed round-trip time to `uri`, if it was measured
+    pub fn rtt(&self, uri: &Uri) -> Option<Duration> {
+        self.uri_to_rtt.read().get(uri).copied()
+    }
+
     // Allows to use channel to `uri`. If there is no channels to specified uri - they will be created.
     pub async fn with_channel_timeout<T, O: Future<Output = Result<T, Status>>>(
         &self,
@@ -287,6 +394,12 @@ impl TransportChannelPool {
         timeout: Option<Duration>,
         retries: usize,
     ) -> Result<T, RequestError<Status>> {
+        if fault_injection::should_fail(FaultPoint::DropInternalRpc, uri) {
+            return Err(RequestError::FromClosure(Status::unavailable(format!(
+                "Request to peer {uri} dropped by injected fault"
+            ))));
+        }
+
         let mut retries_left = retries;
         let mut attempt = 0;
 
@@ -405,8 +518,18 @@ impl TransportChannelPool {
             .await
     }
 
+    /// Compression to use for requests to other peers
+    pub fn compression(&self) -> Option<CompressionEncoding> {
+        self.tuning.compression
+    }
+
     /// Default time to wait for a request to complete.
     pub fn request_timeout(&self) -> Duration {
         self.grpc_timeout
     }
 }
+
+/// Exponentially weighted moving average of round-trip times
+fn smooth_rtt(rtt: Duration, sample: Duration) -> Duration {
+    rtt.mul_f64(1.0 - RTT_SMOOTHING_FACTOR) + sample.mul_f64(RTT_SMOOTHING_FACTOR)
+}
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 
 use common::validation::{validate_range_generic, validate_shard_different_peers};
-use segment::data_types::index::validate_integer_index_params;
+use segment::data_types::index::{validate_histogram_params, validate_integer_index_params};
 use validator::{Validate, ValidationError, ValidationErrors};
 
 use super::qdrant as grpc;
@@ -249,9 +249,12 @@ impl Validate for grpc::FieldCondition {
             values_count,
             is_empty,
             is_null,
+            geo_shape,
+            has_field,
+            compare,
         } = self;
 
-        let all_fields_none = r#match.is_none()
+        let other_fields_none = r#match.is_none()
             && range.is_none()
             && datetime_range.is_none()
             && geo_bounding_box.is_none()
@@ -259,9 +262,30 @@ impl Validate for grpc::FieldCondition {
             && geo_polygon.is_none()
             && values_count.is_none()
             && is_empty.is_none()
-            && is_null.is_none();
+            && is_null.is_none()
+            && geo_shape.is_none()
+            && has_field.is_none();
+
+        if let Some(compare) = compare {
+            let grpc::FieldComparison { lt, gt, gte, lte } = compare;
+            let error = if !other_fields_none {
+                Some("compare can't be combined with other field conditions")
+            } else if lt.is_none() && gt.is_none() && gte.is_none() && lte.is_none() {
+                Some("at least one of lt, gt, gte or lte must be specified")
+            } else {
+                None
+            };
+            return match error {
+                Some(error) => {
+                    let mut errors = ValidationErrors::new();
+                    errors.add("compare", ValidationError::new(error));
+                    Err(errors)
+                }
+                None => Ok(()),
+            };
+        }
 
-        if all_fields_none {
+        if other_fields_none {
             let mut errors = ValidationErrors::new();
             errors.add(
                 "match",
@@ -362,12 +386,14 @@ impl Validate for super::qdrant::query::Variant {
         match self {
             grpc::query::Variant::Nearest(q) => q.validate(),
             grpc::query::Variant::NearestWithMmr(q) => q.validate(),
+            grpc::query::Variant::NearestWithCandidates(q) => q.validate(),
             grpc::query::Variant::Recommend(q) => q.validate(),
             grpc::query::Variant::Discover(q) => q.validate(),
             grpc::query::Variant::Context(q) => q.validate(),
             grpc::query::Variant::Formula(q) => q.validate(),
             grpc::query::Variant::Rrf(q) => q.validate(),
             grpc::query::Variant::RelevanceFeedback(q) => q.validate(),
+            grpc::query::Variant::VectorSelection(q) => q.validate(),
             grpc::query::Variant::Sample(_)
             | grpc::query::Variant::Fusion(_)
             | grpc::query::Variant::OrderBy(_) => Ok(()),
@@ -419,6 +445,8 @@ impl Validate for super::qdrant::expression::Variant {
             grpc::expression::Variant::LinDecay(decay_params_expression) => {
                 decay_params_expression.validate()
             }
+            grpc::expression::Variant::TextScore(_) => Ok(()),
+            grpc::expression::Variant::Wasm(wasm_expression) => wasm_expression.validate(),
         }
     }
 }
@@ -489,11 +517,25 @@ impl Validate for super::qdrant::payload_index_params::IndexParams {
             grpc::payload_index_params::IndexParams::IntegerIndexParams(integer_index_params) => {
                 integer_index_params.validate()
             }
-            grpc::payload_index_params::IndexParams::FloatIndexParams(_) => Ok(()),
+            grpc::payload_index_params::IndexParams::FloatIndexParams(float_index_params) => {
+                validate_histogram_params(
+                    &float_index_params.histogram_buckets.map(|x| x as usize),
+                    &float_index_params
+                        .histogram_max_bucket_size
+                        .map(|x| x as usize),
+                )
+            }
             grpc::payload_index_params::IndexParams::GeoIndexParams(_) => Ok(()),
             grpc::payload_index_params::IndexParams::TextIndexParams(_) => Ok(()),
             grpc::payload_index_params::IndexParams::BoolIndexParams(_) => Ok(()),
-            grpc::payload_index_params::IndexParams::DatetimeIndexParams(_) => Ok(()),
+            grpc::payload_index_params::IndexParams::DatetimeIndexParams(datetime_index_params) => {
+                validate_histogram_params(
+                    &datetime_index_params.histogram_buckets.map(|x| x as usize),
+                    &datetime_index_params
+                        .histogram_max_bucket_size
+                        .map(|x| x as usize),
+                )
+            }
             grpc::payload_index_params::IndexParams::UuidIndexParams(_) => Ok(()),
         }
     }
@@ -507,8 +549,15 @@ impl Validate for super::qdrant::IntegerIndexParams {
             is_principal: _,
             on_disk: _,
             enable_hnsw: _,
+            populate: _,
+            histogram_buckets,
+            histogram_max_bucket_size,
         } = &self;
-        validate_integer_index_params(lookup, range)
+        validate_integer_index_params(lookup, range)?;
+        validate_histogram_params(
+            &histogram_buckets.map(|x| x as usize),
+            &histogram_max_bucket_size.map(|x| x as usize),
+        )
     }
 }
 
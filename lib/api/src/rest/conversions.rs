@@ -2,12 +2,14 @@ use std::collections::HashMap;
 
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::{VectorInternal, VectorStructInternal};
+use segment::index::field_index::full_text_index::highlight::HighlightParams;
 use uuid::Uuid;
 
 use super::schema::{ScoredPoint, Vector};
 use super::{
-    FacetRequestInternal, FacetResponse, FacetValue, FacetValueHit, NearestQuery, OrderByInterface,
-    Query, QueryInterface, VectorOutput, VectorStructOutput,
+    FacetRequestInternal, FacetResponse, FacetValue, FacetValueHit, FacetValueRange,
+    HighlightRequest, NearestQuery, OrderByInterface, Query, QueryInterface, VectorOutput,
+    VectorStructOutput,
 };
 use crate::grpc;
 use crate::rest::models::InferenceUsage;
@@ -95,6 +97,23 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vector: vector.map(VectorStructOutput::from),
             shard_key,
             order_value,
+            highlight: None,
+        }
+    }
+}
+
+impl From<HighlightRequest> for HighlightParams {
+    fn from(value: HighlightRequest) -> Self {
+        let HighlightRequest {
+            pre_tag,
+            post_tag,
+            fragment_context,
+        } = value;
+        let default = HighlightParams::default();
+        HighlightParams {
+            pre_tag: pre_tag.unwrap_or(default.pre_tag),
+            post_tag: post_tag.unwrap_or(default.post_tag),
+            fragment_context,
         }
     }
 }
@@ -134,6 +153,7 @@ impl From<OrderByInterface> for OrderBy {
                 key,
                 direction: None,
                 start_from: None,
+                then_by: Vec::new(),
             },
             OrderByInterface::Struct(order_by) => order_by,
         }
@@ -146,6 +166,7 @@ impl From<QueryInterface> for Query {
             QueryInterface::Nearest(vector) => Query::Nearest(NearestQuery {
                 nearest: vector,
                 mmr: None,
+                candidates: None,
             }),
             QueryInterface::Query(query) => query,
         }
@@ -184,6 +205,23 @@ impl From<segment::data_types::facets::FacetResponse> for FacetResponse {
     }
 }
 
+impl From<Vec<segment::data_types::facets::FacetRangeHit>> for FacetResponse {
+    fn from(value: Vec<segment::data_types::facets::FacetRangeHit>) -> Self {
+        Self {
+            hits: value
+                .into_iter()
+                .map(|hit| {
+                    let segment::data_types::facets::FacetRangeHit { from, to, count } = hit;
+                    FacetValueHit {
+                        value: FacetValue::Range(FacetValueRange { from, to }),
+                        count,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
 impl From<FacetRequestInternal> for segment::data_types::facets::FacetParams {
     fn from(value: FacetRequestInternal) -> Self {
         let FacetRequestInternal {
@@ -191,6 +229,7 @@ impl From<FacetRequestInternal> for segment::data_types::facets::FacetParams {
             limit,
             filter,
             exact,
+            ranges: _,
         } = value;
         Self {
             key,
@@ -7,19 +7,21 @@ use common::validation::validate_multi_vector;
 use ordered_float::NotNan;
 use schemars::JsonSchema;
 use segment::common::utils::MaybeOneOrMany;
+use segment::data_types::facets::{FacetRangeEdge, FacetRanges};
 use segment::data_types::index::{StemmingAlgorithm, StopwordsInterface, TokenizerType};
 use segment::data_types::order_by::OrderBy;
+use segment::index::field_index::full_text_index::highlight::TextHighlight;
 use segment::json_path::JsonPath;
 use segment::types::{
     Condition, Filter, GeoPoint, IntPayloadType, Payload, PointIdType, SearchParams, ShardKey,
-    VectorNameBuf, WithPayloadInterface, WithVector,
+    ValueVariants, VectorNameBuf, WithPayloadInterface, WithVector,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sparse::common::sparse_vector::SparseVector;
 use validator::{Validate, ValidationErrors};
 
-use crate::rest::validate::validate_relevance_feedback_input;
+use crate::rest::validate::{validate_quantiles, validate_relevance_feedback_input};
 
 /// Type for dense vector
 pub type DenseVector = Vec<segment::data_types::vectors::VectorElementType>;
@@ -295,6 +297,25 @@ mod tests {
         // Bm25 option is used only for schema, actual deserialization will happen in specialized code
         assert!(matches!(options, DocumentOptions::Common(_)));
     }
+
+    #[test]
+    fn test_wasm_expression_module_is_base64() {
+        let json = r#"{"wasm": {"module": "AGFzbQEAAAA=", "args": ["$score", "boost"]}}"#;
+        let expression: Expression = serde_json::from_str(json).unwrap();
+
+        let Expression::Wasm(WasmExpression { wasm }) = &expression else {
+            panic!("expected wasm expression, got {expression:?}");
+        };
+        assert_eq!(wasm.module, b"\0asm\x01\0\0\0");
+        assert_eq!(wasm.args.len(), 2);
+        assert_eq!(wasm.fuel_limit, None);
+
+        let serialized = serde_json::to_value(&expression).unwrap();
+        assert_eq!(serialized["wasm"]["module"], "AGFzbQEAAAA=");
+
+        let invalid = r#"{"wasm": {"module": "not base64!", "args": []}}"#;
+        assert!(serde_json::from_str::<Expression>(invalid).is_err());
+    }
 }
 
 impl DocumentOptions {
@@ -464,6 +485,9 @@ pub struct ScoredPoint {
     /// Order-by value
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_value: Option<segment::data_types::order_by::OrderValue>,
+    /// Matches of full-text conditions of the filter, per payload field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<HashMap<String, Vec<TextHighlight>>>,
 }
 
 /// Point data
@@ -512,7 +536,9 @@ pub enum NamedVectorStruct {
 
 #[derive(Deserialize, Serialize, JsonSchema, Clone, Debug, PartialEq, Hash)]
 #[serde(untagged)]
-#[serde(expecting = "Expected a string, or an object with a key, direction and/or start_from")]
+#[serde(
+    expecting = "Expected a string, or an object with a key, direction, start_from and/or then_by"
+)]
 pub enum OrderByInterface {
     Key(JsonPath),
     Struct(OrderBy),
@@ -611,6 +637,22 @@ pub struct QueryRequest {
     #[serde(flatten)]
     pub internal: QueryRequestInternal,
     pub shard_key: Option<ShardKeySelector>,
+    /// Return matches of full-text conditions of the filter in the payload.
+    /// Only fields with a full-text index, which are included into the returned payload, are highlighted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<HighlightRequest>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct HighlightRequest {
+    /// Inserted before each match in fragments. Default is `<em>`.
+    pub pre_tag: Option<String>,
+    /// Inserted after each match in fragments. Default is `</em>`.
+    pub post_tag: Option<String>,
+    /// Number of characters before and after matches to include into fragments.
+    /// If not set, each fragment is a whole text value.
+    pub fragment_context: Option<usize>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -664,6 +706,9 @@ pub enum Query {
 
     /// Use feedback from an oracle to improve the results
     RelevanceFeedback(RelevanceFeedbackQuery),
+
+    /// Score each point with the named vector, selected by a value of its payload field.
+    VectorSelection(VectorSelectionQuery),
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -677,6 +722,12 @@ pub struct NearestQuery {
     /// using the same vector in this query to calculate relevance.
     #[validate(nested)]
     pub mmr: Option<Mmr>,
+
+    /// Score only the points with these ids, instead of searching the whole collection.
+    /// Points are scored exactly, without traversing the vector index.
+    /// Useful to re-rank candidates, retrieved by an external system.
+    #[validate(length(min = 1))]
+    pub candidates: Option<Vec<PointIdType>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -743,6 +794,45 @@ pub struct RelevanceFeedbackQuery {
     pub relevance_feedback: RelevanceFeedbackInput,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct VectorSelectionQuery {
+    #[validate(nested)]
+    pub vector_selection: VectorSelection,
+}
+
+/// Score each point with the named vector, selected by a value of its payload field.
+///
+/// Useful when points keep embeddings from different spaces under different named vectors,
+/// e.g. one vector per language, and the payload tells which one is meaningful for the point.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VectorSelection {
+    /// Payload field which decides the vector to score a point with.
+    pub key: JsonPath,
+
+    /// Payload values and the named vectors they select.
+    pub mapping: Vec<VectorSelectionMapping>,
+
+    /// Vector to score points, which don't have any of the mapped values.
+    /// If not set, such points are excluded from the results.
+    #[serde(default)]
+    pub default: Option<VectorNameBuf>,
+
+    /// Query for each of the selectable named vectors.
+    pub queries: HashMap<VectorNameBuf, VectorInput>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VectorSelectionMapping {
+    /// Value of the payload field.
+    pub value: ValueVariants,
+
+    /// Named vector to score points having this value.
+    pub vector: VectorNameBuf,
+}
+
 /// Maximal Marginal Relevance (MMR) algorithm for re-ranking the points.
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
@@ -935,6 +1025,8 @@ pub enum Expression {
     LinDecay(LinDecayExpression),
     ExpDecay(ExpDecayExpression),
     GaussDecay(GaussDecayExpression),
+    TextScore(TextScoreExpression),
+    Wasm(WasmExpression),
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -950,6 +1042,73 @@ pub struct GeoDistanceParams {
     pub to: JsonPath,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TextScoreExpression {
+    pub text_score: TextScoreParams,
+}
+
+/// BM25 relevance of a full-text indexed payload field to a text
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TextScoreParams {
+    /// Payload field with a full-text index
+    pub key: JsonPath,
+    /// Text to score the field values against
+    pub text: String,
+    /// Weights of individual terms of the text. Terms without a boost have weight 1
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub boosts: Vec<TermBoost>,
+}
+
+/// Weight of a term of a text score
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TermBoost {
+    /// Term of the text, tokenized like the text
+    pub term: String,
+    /// Weight of the term relevance, non-negative
+    pub boost: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct WasmExpression {
+    #[validate(nested)]
+    pub wasm: WasmParams,
+}
+
+/// User-defined scoring function, compiled to WebAssembly and executed in a sandbox.
+/// The module must export a `score` function, which takes one f64 per argument and returns f64.
+/// Modules can't import anything from the host.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct WasmParams {
+    /// Base64-encoded WebAssembly module
+    #[serde(with = "base64_bytes")]
+    #[schemars(with = "String")]
+    pub module: Vec<u8>,
+    /// Arguments to call the function with
+    #[validate(nested)]
+    pub args: Vec<Expression>,
+    /// Fuel available to the function for scoring a single point. Default is 100000, maximum is 10000000
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fuel_limit: Option<u64>,
+}
+
+/// (De)serialize binary data as a base64 string
+mod base64_bytes {
+    use base64::Engine as _;
+    use base64::prelude::BASE64_STANDARD;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64_STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        BASE64_STANDARD
+            .decode(encoded)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct DatetimeExpression {
     pub datetime: String,
@@ -1358,6 +1517,10 @@ pub struct FacetRequestInternal {
 
     /// Whether to do a more expensive exact count for each of the values in the facet. Default is false.
     pub exact: Option<bool>,
+
+    /// Count values of a numeric or datetime key in ranges instead of counting distinct values.
+    /// Requires an integer, float or datetime index.
+    pub ranges: Option<FacetRanges>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -1375,6 +1538,14 @@ pub enum FacetValue {
     String(String),
     Integer(IntPayloadType),
     Bool(bool),
+    Range(FacetValueRange),
+}
+
+/// Range of values, including its lower edge `from` and excluding the upper edge `to`
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FacetValueRange {
+    pub from: FacetRangeEdge,
+    pub to: FacetRangeEdge,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -1388,6 +1559,114 @@ pub struct FacetResponse {
     pub hits: Vec<FacetValueHit>,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct QuantilesRequest {
+    /// Payload key to estimate quantiles of. Requires an integer or float index.
+    pub key: JsonPath,
+    /// Quantiles to estimate, each from 0 to 1. Default is [0.5, 0.95].
+    #[validate(custom(function = "validate_quantiles"))]
+    pub quantiles: Option<Vec<f64>>,
+    /// Filter conditions - only consider points that satisfy these conditions.
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct ExplainFilterRequest {
+    /// Filter to explain the execution of
+    #[validate(nested)]
+    pub filter: Filter,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct VectorStatsRequest {
+    /// Name of the vector to compute statistics for. If missing, the default vector is used.
+    pub using: Option<VectorNameBuf>,
+    /// How many vectors to sample. Default is 1000.
+    #[validate(range(min = 1, max = 100_000))]
+    pub sample: Option<usize>,
+    /// Number of buckets in the histogram of vector norms. Default is 10.
+    #[validate(range(min = 1, max = 1000))]
+    pub histogram_buckets: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct TermStatsRequest {
+    /// Payload key to compute term statistics of. Requires a full-text index.
+    pub key: JsonPath,
+    /// How many of the most frequent terms to return. Default is 10.
+    #[validate(range(min = 1, max = 10_000))]
+    pub limit: Option<usize>,
+}
+
+/// Which points of a group of near-duplicates to delete
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicatePolicy {
+    /// Only report found duplicates, don't delete anything
+    #[default]
+    Report,
+    /// Keep the most recently updated points, delete their direct near-duplicates
+    KeepNewest,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct DeduplicationRequest {
+    /// Name of the vector to compare points by. If missing, the default vector is used.
+    pub using: Option<VectorNameBuf>,
+    /// Points scoring at least this threshold against each other are considered duplicates.
+    /// For distances where lower is better, such as Euclid, points closer than the threshold.
+    pub score_threshold: ScoreType,
+    /// Look for duplicates only among points matching this filter
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+    /// Maximum number of duplicates to look up for each point. Default is 10.
+    #[validate(range(min = 1, max = 1000))]
+    pub limit_per_point: Option<usize>,
+    /// What to do with found duplicates. Default is to only report them.
+    pub policy: Option<DuplicatePolicy>,
+}
+
+/// Background rewrite of a payload key across all points of a collection
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadMigrationRequest {
+    /// Move values of a payload key to another key, together with its payload index
+    RenameKey(RenamePayloadKey),
+    /// Delete a payload key from all points, together with its payload index
+    DropField(DropPayloadField),
+}
+
+impl Validate for PayloadMigrationRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            PayloadMigrationRequest::RenameKey(rename) => rename.validate(),
+            PayloadMigrationRequest::DropField(drop) => drop.validate(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct RenamePayloadKey {
+    /// Key to move values from, e.g. `a.b`
+    pub from: JsonPath,
+    /// Key to move values to. Existing values of this key are overwritten.
+    pub to: JsonPath,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct DropPayloadField {
+    /// Key to delete from all points, e.g. `a.b`
+    pub key: JsonPath,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
 pub struct PointStruct {
@@ -1434,6 +1713,16 @@ pub struct PointsBatch {
     /// Mode of the upsert operation: insert_only, upsert (default), update_only
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_mode: Option<UpdateMode>,
+
+    /// Payload key holding a client-provided sequence number of each point.
+    /// Writes carrying the same or a lower sequence number than the stored point are ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence_key: Option<JsonPath>,
+
+    /// If true, dense vectors are stored as provided, without normalization.
+    /// The client is responsible for normalizing vectors of cosine distance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_normalization: Option<bool>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
@@ -1456,6 +1745,11 @@ pub struct UpdateVectors {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[validate(nested)]
     pub update_filter: Option<Filter>,
+    /// If true, given multivectors are appended as sub-vectors to the existing multivectors of the points,
+    /// instead of replacing them. Points without the multivector get the given one.
+    /// Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub append: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, JsonSchema, Validate)]
@@ -1472,6 +1766,14 @@ pub struct PointsList {
     /// Mode of the upsert operation: insert_only, upsert (default), update_only
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_mode: Option<UpdateMode>,
+    /// Payload key holding a client-provided sequence number of each point.
+    /// Writes carrying the same or a lower sequence number than the stored point are ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence_key: Option<JsonPath>,
+    /// If true, dense vectors are stored as provided, without normalization.
+    /// The client is responsible for normalizing vectors of cosine distance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_normalization: Option<bool>,
 }
 
 impl<'de> serde::Deserialize<'de> for PointInsertOperations {
@@ -7,7 +7,7 @@ use validator::{Validate, ValidationError, ValidationErrors};
 use super::{
     Batch, BatchVectorStruct, ContextInput, Expression, FormulaQuery, Fusion, NamedVectorStruct,
     OrderByInterface, PointVectors, Query, QueryInterface, RecommendInput, RelevanceFeedbackInput,
-    Sample, VectorInput,
+    Sample, VectorInput, VectorSelection,
 };
 use crate::rest::FeedbackStrategy;
 
@@ -43,6 +43,7 @@ impl Validate for Query {
             Query::OrderBy(order_by) => order_by.validate(),
             Query::Sample(sample) => sample.validate(),
             Query::RelevanceFeedback(feedback) => feedback.validate(),
+            Query::VectorSelection(selection) => selection.validate(),
         }
     }
 }
@@ -144,6 +145,25 @@ impl Validate for FormulaQuery {
     }
 }
 
+impl Validate for VectorSelection {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        if self.mapping.is_empty() && self.default.is_none() {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "mapping",
+                ValidationError::new("At least one mapping or a default vector must be provided"),
+            );
+            return Err(errors);
+        }
+
+        for vector in self.queries.values() {
+            vector.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Validate for OrderByInterface {
     fn validate(&self) -> Result<(), validator::ValidationErrors> {
         match self {
@@ -280,6 +300,8 @@ impl Validate for Expression {
             Expression::LinDecay(lin_decay_expression) => lin_decay_expression.validate(),
             Expression::ExpDecay(exp_decay_expression) => exp_decay_expression.validate(),
             Expression::GaussDecay(gauss_decay_expression) => gauss_decay_expression.validate(),
+            Expression::TextScore(_) => Ok(()),
+            Expression::Wasm(wasm_expression) => wasm_expression.validate(),
         }
     }
 }
@@ -296,3 +318,23 @@ pub fn validate_relevance_feedback_input(
 
     Ok(())
 }
+
+/// Field level validation for quantiles of `QuantilesRequest`
+pub fn validate_quantiles(quantiles: &[f64]) -> Result<(), ValidationError> {
+    if quantiles.is_empty() {
+        let mut err = ValidationError::new("quantiles");
+        err.message = Some(Cow::from("quantiles must be non-empty"));
+        return Err(err);
+    }
+
+    if !quantiles
+        .iter()
+        .all(|quantile| (0.0..=1.0).contains(quantile))
+    {
+        let mut err = ValidationError::new("quantiles");
+        err.message = Some(Cow::from("quantiles must be between 0 and 1"));
+        return Err(err);
+    }
+
+    Ok(())
+}
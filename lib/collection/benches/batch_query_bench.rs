@@ -64,6 +64,7 @@ fn setup() -> (TempDir, LocalShard, Runtime) {
             flush_interval_sec: 30,
             max_optimization_threads: Some(2),
             prevent_unoptimized: None,
+            max_segment_number: None,
         },
         wal_config,
         hnsw_config: Default::default(),
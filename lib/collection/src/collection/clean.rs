@@ -387,6 +387,7 @@ impl Collection {
             operation_id: None,
             status,
             clock_tag: None,
+            affected_ids: None,
         })
     }
 
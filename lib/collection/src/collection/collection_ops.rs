@@ -1,4 +1,5 @@
 use std::cmp;
+use std::collections::hash_map::Entry;
 use std::sync::{Arc, LazyLock};
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
@@ -8,6 +9,7 @@ use semver::Version;
 use shard::count::CountRequestInternal;
 
 use super::Collection;
+use crate::common::query_cache::QueryCache;
 use crate::operations::config_diff::*;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::*;
@@ -38,6 +40,12 @@ impl Collection {
         {
             let mut config = self.collection_config.write().await;
             config.params = config.params.update(&params_diff);
+
+            if params_diff.query_cache_max_entries.is_some()
+                || params_diff.query_cache_ttl_sec.is_some()
+            {
+                *self.query_cache.write() = QueryCache::new(&config.params).map(Arc::new);
+            }
         }
         self.collection_config.read().await.save(&self.path)?;
         Ok(())
@@ -305,6 +313,24 @@ impl Collection {
         Ok(())
     }
 
+    /// Apply queued updates and flush WAL and segments of all local shards to disk
+    ///
+    /// ## Cancel safety
+    ///
+    /// This function is cancel safe, and will always run to completion.
+    pub async fn flush_local_shards(&self) -> CollectionResult<()> {
+        let shards_holder = self.shards_holder.clone();
+        tokio::task::spawn(async move {
+            let shard_holder = shards_holder.read().await;
+            let flushes = shard_holder
+                .all_shards()
+                .map(|replica_set| replica_set.flush_local());
+            future::try_join_all(flushes).await
+        })
+        .await??;
+        Ok(())
+    }
+
     pub async fn strict_mode_config(&self) -> Option<StrictModeConfig> {
         self.collection_config
             .read()
@@ -361,10 +387,12 @@ impl Collection {
                 info.update_queue = update_queue;
             }
             for (key, response_schema) in payload_schema {
-                info.payload_schema
-                    .entry(key)
-                    .and_modify(|info_schema| info_schema.points += response_schema.points)
-                    .or_insert(response_schema);
+                match info.payload_schema.entry(key) {
+                    Entry::Occupied(mut entry) => entry.get_mut().merge(response_schema),
+                    Entry::Vacant(entry) => {
+                        entry.insert(response_schema);
+                    }
+                }
             }
         }
 
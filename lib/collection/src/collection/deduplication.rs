@@ -0,0 +1,531 @@
+use std::cmp::Reverse;
+use std::sync::{Arc, Weak};
+
+use ahash::{AHashMap, AHashSet};
+use api::rest::{DeduplicationRequest, DuplicatePolicy};
+use cancel::{CancellationToken, DropGuard};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::types::ScoreType;
+use schemars::JsonSchema;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorStructInternal};
+use segment::types::{
+    Condition, Filter, HasVectorCondition, PointIdType, ScoredPoint, SeqNumberType, VectorNameBuf,
+    WithPayloadInterface, WithVector,
+};
+use serde::Serialize;
+use shard::scroll::ScrollRequestInternal;
+use tokio::sync::watch::{Receiver, Sender};
+use tokio::task::JoinHandle;
+
+use super::Collection;
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::{PointOperations, VectorStructPersisted, WriteOrdering};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::universal_query::collection_query::{
+    CollectionQueryRequest, Query, VectorInputInternal, VectorQuery,
+};
+
+/// Number of points, which duplicates are looked up in a single batch query
+const DEDUPLICATION_BATCH_SIZE: usize = 100;
+
+/// Internal representation of the deduplication request
+#[derive(Debug, Clone)]
+pub struct CollectionDeduplicationRequest {
+    /// Vector to compare points by
+    pub using: VectorNameBuf,
+    /// Points scoring at least this threshold against each other are considered duplicates.
+    /// For distances where lower is better, such as Euclid, points closer than the threshold.
+    pub score_threshold: ScoreType,
+    /// Look for duplicates only among points matching this filter
+    pub filter: Option<Filter>,
+    /// Maximum number of duplicates to look up for each point
+    pub limit_per_point: usize,
+    pub policy: DuplicatePolicy,
+}
+
+impl CollectionDeduplicationRequest {
+    pub const DEFAULT_LIMIT_PER_POINT: usize = 10;
+}
+
+impl From<DeduplicationRequest> for CollectionDeduplicationRequest {
+    fn from(request: DeduplicationRequest) -> Self {
+        let DeduplicationRequest {
+            using,
+            score_threshold,
+            filter,
+            limit_per_point,
+            policy,
+        } = request;
+        Self {
+            using: using.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned()),
+            score_threshold,
+            filter,
+            limit_per_point: limit_per_point.unwrap_or(Self::DEFAULT_LIMIT_PER_POINT),
+            policy: policy.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DeduplicationState {
+    Running,
+    Done,
+    Failed,
+    Cancelled,
+}
+
+/// Progress of the deduplication task, updated as the task goes
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DeduplicationStatus {
+    pub state: DeduplicationState,
+    /// Number of points, which were checked for duplicates
+    pub processed_points: usize,
+    /// Number of points having at least one near-duplicate, found so far
+    pub duplicate_points: usize,
+    /// Groups of points connected by near-duplicate pairs.
+    /// Available once all points are checked.
+    pub clusters: Vec<Vec<PointIdType>>,
+    /// Number of deleted duplicates, according to the policy
+    pub deleted_points: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl DeduplicationStatus {
+    fn started() -> Self {
+        Self {
+            state: DeduplicationState::Running,
+            processed_points: 0,
+            duplicate_points: 0,
+            clusters: vec![],
+            deleted_points: 0,
+            error: None,
+        }
+    }
+}
+
+/// Groups of points connected by the "is duplicate of" relation
+#[derive(Debug, Default)]
+struct DuplicateClusters {
+    cluster_of: AHashMap<PointIdType, usize>,
+    /// Merged clusters are left empty, to keep indices stable
+    clusters: Vec<Vec<PointIdType>>,
+    /// Direct near-duplicates of each clustered point
+    neighbours: AHashMap<PointIdType, AHashSet<PointIdType>>,
+    /// Latest known versions of the clustered points
+    versions: AHashMap<PointIdType, SeqNumberType>,
+}
+
+impl DuplicateClusters {
+    fn add_pair(&mut self, a: &ScoredPoint, b: &ScoredPoint) {
+        if a.id == b.id {
+            return;
+        }
+
+        self.versions.insert(a.id, a.version);
+        self.versions.insert(b.id, b.version);
+        self.neighbours.entry(a.id).or_default().insert(b.id);
+        self.neighbours.entry(b.id).or_default().insert(a.id);
+
+        match (
+            self.cluster_of.get(&a.id).copied(),
+            self.cluster_of.get(&b.id).copied(),
+        ) {
+            (None, None) => {
+                let cluster = self.clusters.len();
+                self.clusters.push(vec![a.id, b.id]);
+                self.cluster_of.insert(a.id, cluster);
+                self.cluster_of.insert(b.id, cluster);
+            }
+            (Some(cluster), None) => {
+                self.clusters[cluster].push(b.id);
+                self.cluster_of.insert(b.id, cluster);
+            }
+            (None, Some(cluster)) => {
+                self.clusters[cluster].push(a.id);
+                self.cluster_of.insert(a.id, cluster);
+            }
+            (Some(cluster_a), Some(cluster_b)) if cluster_a != cluster_b => {
+                // Move the smaller cluster into the larger one
+                let (into, from) =
+                    if self.clusters[cluster_a].len() >= self.clusters[cluster_b].len() {
+                        (cluster_a, cluster_b)
+                    } else {
+                        (cluster_b, cluster_a)
+                    };
+                let moved = std::mem::take(&mut self.clusters[from]);
+                for id in &moved {
+                    self.cluster_of.insert(*id, into);
+                }
+                self.clusters[into].extend(moved);
+            }
+            (Some(_), Some(_)) => {}
+        }
+    }
+
+    fn clusters(&self) -> Vec<Vec<PointIdType>> {
+        self.clusters
+            .iter()
+            .filter(|cluster| !cluster.is_empty())
+            .map(|cluster| {
+                let mut cluster = cluster.clone();
+                cluster.sort_unstable();
+                cluster
+            })
+            .collect()
+    }
+
+    /// Number of points having at least one near-duplicate
+    fn duplicate_points(&self) -> usize {
+        self.neighbours.len()
+    }
+
+    /// Points to delete to keep the newest points.
+    ///
+    /// Clusters may chain points which are not near-duplicates of each other,
+    /// so only direct near-duplicates of a kept point are deleted.
+    /// Points are kept from the newest to the oldest, so no near-duplicate pairs remain.
+    fn outdated_points(&self) -> Vec<PointIdType> {
+        let version = |id: &PointIdType| self.versions.get(id).copied().unwrap_or_default();
+
+        let mut points: Vec<_> = self.neighbours.keys().copied().collect();
+        points.sort_unstable_by_key(|id| Reverse((version(id), *id)));
+
+        let mut outdated = AHashSet::new();
+        for id in points {
+            if outdated.contains(&id) {
+                continue;
+            }
+            // Point is kept, its near-duplicates are older
+            outdated.extend(self.neighbours[&id].iter().copied());
+        }
+
+        outdated.into_iter().collect()
+    }
+}
+
+/// A background task searching for near-duplicate points of a collection
+pub(super) struct DeduplicationTask {
+    /// Handle of the deduplication task
+    handle: JoinHandle<()>,
+    /// Watch channel with current status of the task
+    status: Receiver<DeduplicationStatus>,
+    /// Cancellation token drop guard, cancels the task if this is dropped
+    cancel: DropGuard,
+}
+
+impl DeduplicationTask {
+    fn new(collection: Weak<Collection>, request: CollectionDeduplicationRequest) -> Self {
+        let (sender, receiver) = tokio::sync::watch::channel(DeduplicationStatus::started());
+        let cancel = CancellationToken::default();
+
+        let task = tokio::task::spawn(Self::task(collection, request, sender, cancel.clone()));
+
+        DeduplicationTask {
+            handle: task,
+            status: receiver,
+            cancel: cancel.drop_guard(),
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        self.status.borrow().state == DeduplicationState::Running
+    }
+
+    fn abort(self) -> JoinHandle<()> {
+        self.cancel.disarm().cancel();
+        self.handle
+    }
+
+    async fn task(
+        collection: Weak<Collection>,
+        request: CollectionDeduplicationRequest,
+        sender: Sender<DeduplicationStatus>,
+        cancel: CancellationToken,
+    ) {
+        let task = deduplication_task(collection, request, sender.clone());
+        let result = cancel.run_until_cancelled(task).await;
+
+        sender.send_modify(|status| match result {
+            Some(Ok(())) => status.state = DeduplicationState::Done,
+            Some(Err(err)) => {
+                log::error!("Background deduplication task failed: {err}");
+                status.state = DeduplicationState::Failed;
+                status.error = Some(err.to_string());
+            }
+            None => status.state = DeduplicationState::Cancelled,
+        });
+    }
+}
+
+async fn deduplication_task(
+    collection: Weak<Collection>,
+    request: CollectionDeduplicationRequest,
+    sender: Sender<DeduplicationStatus>,
+) -> CollectionResult<()> {
+    let CollectionDeduplicationRequest {
+        using,
+        score_threshold,
+        filter,
+        limit_per_point,
+        policy,
+    } = request;
+
+    // Only points having the vector can be compared
+    let has_vector = Filter::new_must(Condition::HasVector(HasVectorCondition::from(
+        using.clone(),
+    )));
+    let filter = filter
+        .map(|filter| filter.merge(&has_vector))
+        .unwrap_or(has_vector);
+
+    let mut clusters = DuplicateClusters::default();
+    let mut offset = None;
+
+    loop {
+        let Some(collection) = collection.upgrade() else {
+            return Err(CollectionError::not_found("Collection dropped"));
+        };
+
+        let scroll_request = ScrollRequestInternal {
+            offset,
+            limit: Some(DEDUPLICATION_BATCH_SIZE + 1),
+            filter: Some(filter.clone()),
+            with_payload: Some(WithPayloadInterface::Bool(false)),
+            with_vector: WithVector::Selector(vec![using.clone()]),
+            order_by: None,
+            consistent: None,
+            view_id: None,
+        };
+        let mut points = collection
+            .scroll_by(
+                scroll_request,
+                None,
+                &ShardSelectorInternal::All,
+                None,
+                HwMeasurementAcc::disposable(), // Internal operation, no measurement needed
+            )
+            .await?
+            .points;
+
+        offset = (points.len() > DEDUPLICATION_BATCH_SIZE).then(|| points.pop().unwrap().id);
+
+        let mut queries = Vec::with_capacity(points.len());
+        for point in &points {
+            let vector = point
+                .vector
+                .clone()
+                .map(VectorStructPersisted::from)
+                .map(VectorStructInternal::try_from)
+                .transpose()?
+                .and_then(|vector| vector.get(&using).map(|vector| vector.to_owned()))
+                .ok_or_else(|| {
+                    CollectionError::service_error(format!(
+                        "Vector `{using}` not found in point {}",
+                        point.id,
+                    ))
+                })?;
+
+            let query = CollectionQueryRequest {
+                prefetch: vec![],
+                query: Some(Query::Vector(VectorQuery::Nearest(
+                    VectorInputInternal::Vector(vector),
+                ))),
+                using: using.clone(),
+                filter: Some(filter.clone()),
+                score_threshold: Some(score_threshold),
+                limit: limit_per_point + 1, // +1 as the point finds itself
+                offset: 0,
+                params: None,
+                with_vector: WithVector::Bool(false),
+                with_payload: WithPayloadInterface::Bool(false),
+                lookup_from: None,
+            };
+            queries.push((query, ShardSelectorInternal::All));
+        }
+
+        // Queries don't use lookup_from, so no other collections are needed
+        let collection_by_name = |_name: String| async move { None };
+        let results = collection
+            .query_batch(
+                queries,
+                collection_by_name,
+                None,
+                None,
+                HwMeasurementAcc::disposable(),
+            )
+            .await?;
+
+        for (point, nearest) in points.iter().zip(&results) {
+            // The point itself is usually the top result, use it to learn the point version
+            let Some(this) = nearest.iter().find(|scored| scored.id == point.id) else {
+                continue;
+            };
+            for duplicate in nearest {
+                clusters.add_pair(this, duplicate);
+            }
+        }
+
+        let last_batch = offset.is_none();
+        sender.send_modify(|status| {
+            status.processed_points += points.len();
+            status.duplicate_points = clusters.duplicate_points();
+        });
+
+        if last_batch {
+            break;
+        }
+    }
+
+    let found_clusters = clusters.clusters();
+    sender.send_modify(|status| status.clusters = found_clusters);
+
+    if policy == DuplicatePolicy::KeepNewest {
+        let Some(collection) = collection.upgrade() else {
+            return Err(CollectionError::not_found("Collection dropped"));
+        };
+
+        let ids = clusters.outdated_points();
+        let deleted_points = ids.len();
+        if !ids.is_empty() {
+            collection
+                .update_from_client_simple(
+                    CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints {
+                        ids,
+                    }),
+                    true,
+                    None,
+                    WriteOrdering::default(),
+                    HwMeasurementAcc::disposable(),
+                )
+                .await?;
+        }
+
+        sender.send_modify(|status| status.deleted_points = deleted_points);
+    }
+
+    Ok(())
+}
+
+impl Collection {
+    /// Start a background search for near-duplicate points.
+    ///
+    /// Only one deduplication task may run at a time. Results are available through
+    /// [`Collection::deduplication_status`] while the task is running and after it's finished.
+    pub fn start_deduplication(
+        self: &Arc<Self>,
+        request: CollectionDeduplicationRequest,
+    ) -> CollectionResult<DeduplicationStatus> {
+        if request.limit_per_point == 0 {
+            return Err(CollectionError::bad_input(
+                "Deduplication limit per point must be positive",
+            ));
+        }
+
+        let mut task = self.deduplication_task.lock();
+        if task.as_ref().is_some_and(DeduplicationTask::is_running) {
+            return Err(CollectionError::bad_request(
+                "Deduplication is already running for this collection",
+            ));
+        }
+
+        let new_task = DeduplicationTask::new(Arc::downgrade(self), request);
+        let status = new_task.status.borrow().clone();
+        *task = Some(new_task);
+
+        Ok(status)
+    }
+
+    /// Status of the last started deduplication task, if any
+    pub fn deduplication_status(&self) -> Option<DeduplicationStatus> {
+        self.deduplication_task
+            .lock()
+            .as_ref()
+            .map(|task| task.status.borrow().clone())
+    }
+
+    /// Cancel the running deduplication task and wait for it to stop
+    pub async fn cancel_deduplication(&self) {
+        let Some(task) = self.deduplication_task.lock().take() else {
+            return;
+        };
+
+        if let Err(err) = task.abort().await {
+            log::error!("Failed to join deduplication task: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scored(id: u64, version: SeqNumberType) -> ScoredPoint {
+        ScoredPoint {
+            id: id.into(),
+            version,
+            score: 1.0,
+            payload: None,
+            vector: None,
+            shard_key: None,
+            order_value: None,
+        }
+    }
+
+    #[test]
+    fn test_duplicate_clusters() {
+        let mut clusters = DuplicateClusters::default();
+
+        clusters.add_pair(&scored(1, 1), &scored(1, 1));
+        assert!(clusters.clusters().is_empty());
+
+        clusters.add_pair(&scored(1, 1), &scored(2, 5));
+        clusters.add_pair(&scored(3, 2), &scored(4, 1));
+        clusters.add_pair(&scored(2, 5), &scored(1, 1));
+        assert_eq!(
+            clusters.clusters(),
+            vec![vec![1.into(), 2.into()], vec![3.into(), 4.into()]],
+        );
+
+        // Connecting point merges clusters
+        clusters.add_pair(&scored(5, 3), &scored(2, 5));
+        clusters.add_pair(&scored(5, 3), &scored(4, 1));
+        assert_eq!(
+            clusters.clusters(),
+            vec![vec![1.into(), 2.into(), 3.into(), 4.into(), 5.into()]],
+        );
+
+        // Point 3 is not a near-duplicate of the newest point 2, so it's kept
+        let mut outdated = clusters.outdated_points();
+        outdated.sort_unstable();
+        assert_eq!(outdated, vec![1.into(), 4.into(), 5.into()]);
+    }
+
+    #[test]
+    fn test_chained_cluster() {
+        let mut clusters = DuplicateClusters::default();
+
+        // 1 and 3 are both near-duplicates of 2, but not of each other
+        clusters.add_pair(&scored(1, 1), &scored(2, 2));
+        clusters.add_pair(&scored(2, 2), &scored(3, 3));
+        assert_eq!(
+            clusters.clusters(),
+            vec![vec![1.into(), 2.into(), 3.into()]]
+        );
+        assert_eq!(clusters.duplicate_points(), 3);
+
+        // Only the direct near-duplicate of the newest point is deleted
+        assert_eq!(clusters.outdated_points(), vec![2.into()]);
+
+        // Once the middle point is the newest, both ends are its direct near-duplicates
+        let mut clusters = DuplicateClusters::default();
+        clusters.add_pair(&scored(1, 1), &scored(2, 3));
+        clusters.add_pair(&scored(2, 3), &scored(3, 2));
+
+        let mut outdated = clusters.outdated_points();
+        outdated.sort_unstable();
+        assert_eq!(outdated, vec![1.into(), 3.into()]);
+    }
+}
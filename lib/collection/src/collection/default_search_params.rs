@@ -0,0 +1,160 @@
+use segment::types::{QuantizationSearchParams, SearchParams};
+use shard::search::CoreSearchRequestBatch;
+
+use super::Collection;
+use crate::operations::universal_query::shard_query::{ShardPrefetch, ShardQueryRequest};
+
+impl Collection {
+    async fn default_search_params(&self) -> Option<SearchParams> {
+        self.collection_config
+            .read()
+            .await
+            .params
+            .default_search_params
+    }
+
+    /// Fill search params, not set in the queries, with the collection defaults
+    pub(super) async fn apply_default_search_params_to_queries(
+        &self,
+        mut requests: Vec<ShardQueryRequest>,
+    ) -> Vec<ShardQueryRequest> {
+        let Some(defaults) = self.default_search_params().await else {
+            return requests;
+        };
+
+        for request in &mut requests {
+            request.params = with_defaults(request.params, &defaults);
+            prefetches_with_defaults(&mut request.prefetches, &defaults);
+        }
+
+        requests
+    }
+
+    /// Fill search params, not set in the searches, with the collection defaults
+    pub(super) async fn apply_default_search_params_to_searches(
+        &self,
+        mut request: CoreSearchRequestBatch,
+    ) -> CoreSearchRequestBatch {
+        let Some(defaults) = self.default_search_params().await else {
+            return request;
+        };
+
+        for search in &mut request.searches {
+            search.params = with_defaults(search.params, &defaults);
+        }
+
+        request
+    }
+}
+
+fn prefetches_with_defaults(prefetches: &mut [ShardPrefetch], defaults: &SearchParams) {
+    for prefetch in prefetches {
+        prefetch.params = with_defaults(prefetch.params, defaults);
+        prefetches_with_defaults(&mut prefetch.prefetches, defaults);
+    }
+}
+
+/// Take params, missing in the request, from the defaults.
+///
+/// `exact` and `indexed_only` can't be told apart from their default values,
+/// so they are only taken from the defaults if the request has no params at all.
+/// `max_rescore` of the defaults is a cap, the request can only lower it.
+fn with_defaults(params: Option<SearchParams>, defaults: &SearchParams) -> Option<SearchParams> {
+    let Some(params) = params else {
+        return Some(*defaults);
+    };
+
+    let SearchParams {
+        hnsw_ef,
+        exact,
+        quantization,
+        indexed_only,
+        acorn,
+    } = params;
+
+    let quantization = match (quantization, defaults.quantization) {
+        (Some(quantization), Some(default_quantization)) => {
+            let QuantizationSearchParams {
+                ignore,
+                rescore,
+                oversampling,
+                max_rescore,
+                adaptive_rescore,
+            } = quantization;
+            let max_rescore = match (max_rescore, default_quantization.max_rescore) {
+                (Some(max_rescore), Some(default_max_rescore)) => {
+                    Some(max_rescore.min(default_max_rescore))
+                }
+                (max_rescore, default_max_rescore) => max_rescore.or(default_max_rescore),
+            };
+            Some(QuantizationSearchParams {
+                ignore,
+                rescore: rescore.or(default_quantization.rescore),
+                oversampling: oversampling.or(default_quantization.oversampling),
+                max_rescore,
+                adaptive_rescore: adaptive_rescore.or(default_quantization.adaptive_rescore),
+            })
+        }
+        (quantization, default_quantization) => quantization.or(default_quantization),
+    };
+
+    Some(SearchParams {
+        hnsw_ef: hnsw_ef.or(defaults.hnsw_ef),
+        exact,
+        quantization,
+        indexed_only,
+        acorn: acorn.or(defaults.acorn),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_params_with_defaults() {
+        let defaults = SearchParams {
+            hnsw_ef: Some(256),
+            exact: false,
+            quantization: Some(QuantizationSearchParams {
+                ignore: false,
+                rescore: Some(true),
+                oversampling: Some(2.0),
+                max_rescore: Some(100),
+                adaptive_rescore: Some(true),
+            }),
+            indexed_only: true,
+            acorn: None,
+        };
+
+        assert_eq!(with_defaults(None, &defaults), Some(defaults));
+
+        let params = SearchParams {
+            hnsw_ef: Some(64),
+            quantization: Some(QuantizationSearchParams {
+                ignore: false,
+                rescore: Some(false),
+                oversampling: None,
+                max_rescore: Some(1000),
+                adaptive_rescore: None,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            with_defaults(Some(params), &defaults),
+            Some(SearchParams {
+                hnsw_ef: Some(64),
+                exact: false,
+                quantization: Some(QuantizationSearchParams {
+                    ignore: false,
+                    rescore: Some(false),
+                    oversampling: Some(2.0),
+                    max_rescore: Some(100),
+                    adaptive_rescore: Some(true),
+                }),
+                indexed_only: false,
+                acorn: None,
+            }),
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use segment::types::VectorName;
+
+use super::Collection;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+impl Collection {
+    /// Check inference inputs to use the embedding models configured for their vectors.
+    ///
+    /// Prevents vectors of another model, e.g. after a model upgrade on the client side,
+    /// from silently mixing into the collection.
+    pub async fn check_embedding_models<'a>(
+        &self,
+        models: impl IntoIterator<Item = (&'a VectorName, &'a str)>,
+    ) -> CollectionResult<()> {
+        let config = self.collection_config.read().await;
+
+        for (name, model) in models {
+            let Some(expected) = config
+                .params
+                .vectors
+                .get_params(name)
+                .and_then(|params| params.model.as_ref())
+            else {
+                continue;
+            };
+
+            if expected.name != model {
+                return Err(CollectionError::bad_input(format!(
+                    "Vector `{name}` must be produced by model `{}`, but model `{model}` is requested",
+                    expected.name,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
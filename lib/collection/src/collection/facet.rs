@@ -2,15 +2,37 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
+use api::rest::OrderByInterface;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::TryStreamExt;
+use futures::future::try_join_all;
 use futures::stream::FuturesUnordered;
-use segment::data_types::facets::{FacetParams, FacetResponse, FacetValue};
+use ordered_float::OrderedFloat;
+use segment::data_types::facets::{
+    FacetParams, FacetRangeEdge, FacetRangeHit, FacetRanges, FacetResponse, FacetValue,
+};
+use segment::data_types::order_by::{Direction, OrderBy, OrderValue};
+use segment::json_path::JsonPath;
+use segment::types::{
+    Condition, DateTimePayloadType, FieldCondition, Filter, PayloadSchemaParams, Range,
+    WithPayloadInterface, WithVector,
+};
 
 use super::Collection;
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
-use crate::operations::types::CollectionResult;
+use crate::operations::types::{
+    CollectionError, CollectionResult, CountRequestInternal, ScrollRequestInternal,
+};
+
+/// Request to count values of a numeric or datetime key in ranges
+#[derive(Debug, Clone)]
+pub struct CollectionFacetRangesRequest {
+    pub key: JsonPath,
+    pub ranges: FacetRanges,
+    pub filter: Option<Filter>,
+    pub exact: bool,
+}
 
 impl Collection {
     pub async fn facet(
@@ -54,4 +76,341 @@ impl Collection {
 
         Ok(FacetResponse::top_hits(aggregated_results, limit))
     }
+
+    /// Count points with values of a numeric or datetime key in each of the ranges.
+    ///
+    /// Ranges are counted in parallel, each by a count request over the range index of the key.
+    /// Points having values in multiple ranges are counted in each of them.
+    pub async fn facet_ranges(
+        &self,
+        request: CollectionFacetRangesRequest,
+        shard_selection: &ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<FacetRangeHit>> {
+        let CollectionFacetRangesRequest {
+            key,
+            ranges,
+            filter,
+            exact,
+        } = request;
+
+        let is_datetime = self.range_facet_key_is_datetime(&key)?;
+        // Histogram covers the highest value, explicit upper edges are excluded as usual
+        let is_histogram = matches!(ranges, FacetRanges::Histogram { .. });
+
+        let edges = match ranges {
+            FacetRanges::Edges(edges) => {
+                check_range_edges(&edges, is_datetime)?;
+                edges
+            }
+            FacetRanges::Histogram { buckets } => {
+                if buckets == 0 || buckets > FacetRanges::MAX_RANGES {
+                    return Err(CollectionError::bad_input(format!(
+                        "Number of histogram buckets must be from 1 to {}",
+                        FacetRanges::MAX_RANGES,
+                    )));
+                }
+
+                let bounds = self
+                    .facet_value_bounds(
+                        &key,
+                        filter.as_ref(),
+                        is_datetime,
+                        shard_selection,
+                        read_consistency,
+                        timeout,
+                        hw_measurement_acc.clone(),
+                    )
+                    .await?;
+                let Some((min, max)) = bounds else {
+                    return Ok(vec![]);
+                };
+                histogram_edges(min, max, buckets)
+            }
+        };
+
+        let last_range = edges.len() - 2;
+        let ranges = edges
+            .windows(2)
+            .enumerate()
+            .map(|(i, range)| {
+                let include_to = is_histogram && i == last_range;
+                let condition = range_condition(&key, range[0], range[1], include_to)?;
+                Ok((range[0], range[1], condition))
+            })
+            .collect::<CollectionResult<Vec<_>>>()?;
+
+        let counts = ranges.iter().map(|(_, _, condition)| {
+            let range_filter = Filter::new_must(condition.clone());
+            let request = CountRequestInternal {
+                filter: Some(match &filter {
+                    Some(filter) => filter.merge(&range_filter),
+                    None => range_filter,
+                }),
+                exact,
+            };
+            self.count(
+                request,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+        });
+        let counts = try_join_all(counts).await?;
+
+        Ok(ranges
+            .into_iter()
+            .zip(counts)
+            .map(|((from, to, _), count)| FacetRangeHit {
+                from,
+                to,
+                count: count.count,
+            })
+            .collect())
+    }
+
+    /// Whether the key has a datetime index, rather than a numeric one
+    fn range_facet_key_is_datetime(&self, key: &JsonPath) -> CollectionResult<bool> {
+        let params = self
+            .payload_key_index_schema(key)
+            .map(|schema| schema.expand().into_owned());
+
+        match params {
+            Some(PayloadSchemaParams::Integer(params)) if params.range != Some(false) => Ok(false),
+            Some(PayloadSchemaParams::Float(_)) => Ok(false),
+            Some(PayloadSchemaParams::Datetime(_)) => Ok(true),
+            _ => Err(CollectionError::bad_input(format!(
+                "Range facet requires an integer index with range support, \
+                 a float or a datetime index on `{key}`",
+            ))),
+        }
+    }
+
+    /// Lowest and highest values of the key, `None` if no points have it
+    #[expect(clippy::too_many_arguments)]
+    async fn facet_value_bounds(
+        &self,
+        key: &JsonPath,
+        filter: Option<&Filter>,
+        is_datetime: bool,
+        shard_selection: &ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Option<(FacetRangeEdge, FacetRangeEdge)>> {
+        let mut bounds = [None, None];
+        for (bound, direction) in bounds.iter_mut().zip([Direction::Asc, Direction::Desc]) {
+            let request = ScrollRequestInternal {
+                offset: None,
+                limit: Some(1),
+                filter: filter.cloned(),
+                with_payload: Some(WithPayloadInterface::Bool(false)),
+                with_vector: WithVector::Bool(false),
+                order_by: Some(OrderByInterface::Struct(OrderBy {
+                    key: key.clone(),
+                    direction: Some(direction),
+                    start_from: None,
+                    then_by: Vec::new(),
+                })),
+                consistent: None,
+                view_id: None,
+            };
+            let points = self
+                .scroll_by(
+                    request,
+                    read_consistency,
+                    shard_selection,
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+                .await?
+                .points;
+
+            *bound = points
+                .first()
+                .and_then(|point| point.order_value)
+                .map(|value| order_value_edge(value, is_datetime))
+                .transpose()?;
+        }
+
+        match bounds {
+            [Some(min), Some(max)] => Ok(Some((min, max))),
+            _ => Ok(None),
+        }
+    }
+}
+
+fn check_range_edges(edges: &[FacetRangeEdge], is_datetime: bool) -> CollectionResult<()> {
+    if edges.len() < 2 || edges.len() > FacetRanges::MAX_RANGES + 1 {
+        return Err(CollectionError::bad_input(format!(
+            "Number of range edges must be from 2 to {}",
+            FacetRanges::MAX_RANGES + 1,
+        )));
+    }
+
+    let expected_kind = if is_datetime { "datetimes" } else { "numbers" };
+    if edges
+        .iter()
+        .any(|edge| matches!(edge, FacetRangeEdge::DateTime(_)) != is_datetime)
+    {
+        return Err(CollectionError::bad_input(format!(
+            "Range edges must be {expected_kind}, according to the index of the key",
+        )));
+    }
+
+    if !edges.windows(2).all(|pair| pair[0] < pair[1]) {
+        return Err(CollectionError::bad_input(
+            "Range edges must be in strictly ascending order",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Edges of `buckets` equal-width ranges from `min` to `max`
+fn histogram_edges(
+    min: FacetRangeEdge,
+    max: FacetRangeEdge,
+    buckets: usize,
+) -> Vec<FacetRangeEdge> {
+    // All values are the same, a single range covers them
+    let buckets = if min < max { buckets } else { 1 };
+
+    (0..=buckets)
+        .map(|i| match (min, max) {
+            _ if i == buckets => max,
+            (FacetRangeEdge::Number(min), FacetRangeEdge::Number(max)) => {
+                FacetRangeEdge::Number(min + (max - min) * i as f64 / buckets as f64)
+            }
+            (FacetRangeEdge::DateTime(min), FacetRangeEdge::DateTime(max)) => {
+                let (from, to) = (i128::from(min.timestamp()), i128::from(max.timestamp()));
+                let timestamp = from + (to - from) * i as i128 / buckets as i128;
+                // Always valid, as it's between two valid timestamps
+                let edge = DateTimePayloadType::from_timestamp(timestamp as i64).unwrap_or(min);
+                FacetRangeEdge::DateTime(edge)
+            }
+            // Bounds are read from the same index, so they are of the same kind
+            _ => min,
+        })
+        .collect()
+}
+
+fn order_value_edge(value: OrderValue, is_datetime: bool) -> CollectionResult<FacetRangeEdge> {
+    match (value, is_datetime) {
+        (OrderValue::Int(timestamp), true) => DateTimePayloadType::from_timestamp(timestamp)
+            .map(FacetRangeEdge::DateTime)
+            .ok_or_else(|| {
+                CollectionError::service_error(format!("Invalid datetime timestamp {timestamp}"))
+            }),
+        (OrderValue::Float(_), true) => Err(CollectionError::service_error(
+            "Datetime index returned a float value",
+        )),
+        (OrderValue::Int(value), false) => Ok(FacetRangeEdge::Number(value as f64)),
+        (OrderValue::Float(value), false) => Ok(FacetRangeEdge::Number(value)),
+    }
+}
+
+fn range_condition(
+    key: &JsonPath,
+    from: FacetRangeEdge,
+    to: FacetRangeEdge,
+    include_to: bool,
+) -> CollectionResult<Condition> {
+    fn range<T>(from: T, to: T, include_to: bool) -> Range<T> {
+        let (lt, lte) = if include_to {
+            (None, Some(to))
+        } else {
+            (Some(to), None)
+        };
+        Range {
+            lt,
+            gt: None,
+            gte: Some(from),
+            lte,
+        }
+    }
+
+    let condition = match (from, to) {
+        (FacetRangeEdge::Number(from), FacetRangeEdge::Number(to)) => FieldCondition::new_range(
+            key.clone(),
+            range(OrderedFloat(from), OrderedFloat(to), include_to),
+        ),
+        (FacetRangeEdge::DateTime(from), FacetRangeEdge::DateTime(to)) => {
+            FieldCondition::new_datetime_range(key.clone(), range(from, to, include_to))
+        }
+        _ => {
+            return Err(CollectionError::bad_input(
+                "Range edges must be either all numbers or all datetimes",
+            ));
+        }
+    };
+
+    Ok(Condition::Field(condition))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn datetime(s: &str) -> FacetRangeEdge {
+        FacetRangeEdge::DateTime(DateTimePayloadType::from_str(s).unwrap())
+    }
+
+    #[test]
+    fn test_histogram_edges() {
+        let number = FacetRangeEdge::Number;
+
+        assert_eq!(
+            histogram_edges(number(0.0), number(10.0), 4),
+            vec![
+                number(0.0),
+                number(2.5),
+                number(5.0),
+                number(7.5),
+                number(10.0)
+            ],
+        );
+        assert_eq!(
+            histogram_edges(number(3.0), number(3.0), 4),
+            vec![number(3.0), number(3.0)],
+        );
+        assert_eq!(
+            histogram_edges(
+                datetime("2024-01-01T00:00:00Z"),
+                datetime("2024-01-03T00:00:00Z"),
+                2,
+            ),
+            vec![
+                datetime("2024-01-01T00:00:00Z"),
+                datetime("2024-01-02T00:00:00Z"),
+                datetime("2024-01-03T00:00:00Z"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_check_range_edges() {
+        let number = FacetRangeEdge::Number;
+
+        assert!(check_range_edges(&[number(0.0), number(1.0), number(5.0)], false).is_ok());
+        assert!(check_range_edges(&[number(0.0)], false).is_err());
+        assert!(check_range_edges(&[number(1.0), number(1.0)], false).is_err());
+        assert!(check_range_edges(&[number(2.0), number(1.0)], false).is_err());
+        assert!(check_range_edges(&[number(0.0), number(1.0)], true).is_err());
+        assert!(
+            check_range_edges(
+                &[
+                    datetime("2024-01-01T00:00:00Z"),
+                    datetime("2024-02-01T00:00:00Z"),
+                ],
+                true,
+            )
+            .is_ok()
+        );
+    }
 }
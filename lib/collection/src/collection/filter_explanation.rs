@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use futures::TryStreamExt;
+use futures::stream::FuturesUnordered;
+use segment::data_types::filter_explanation::FilterExplanation;
+use segment::types::Filter;
+
+use super::Collection;
+use crate::operations::types::CollectionResult;
+
+impl Collection {
+    /// Explain how points matching the filter are selected in every segment: the estimated
+    /// cardinality, primary clauses read from payload indexes and whether a full scan is used.
+    ///
+    /// Only replicas located on this peer are explained, so every shard must have an active
+    /// replica here.
+    pub async fn explain_filter(
+        &self,
+        filter: Filter,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<FilterExplanation> {
+        let timeout = timeout.unwrap_or(self.shared_storage_config.search_timeout);
+
+        let shard_holder = self.shards_holder.read().await;
+
+        let mut shard_explanations = shard_holder
+            .all_shards()
+            .map(|shard| {
+                let filter = filter.clone();
+                let hw_measurement_acc = hw_measurement_acc.clone();
+                shard.execute_local_aggregation(async move |local_shard, search_runtime| {
+                    local_shard
+                        .explain_filter(filter, search_runtime, timeout, hw_measurement_acc)
+                        .await
+                })
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        let mut explanation = FilterExplanation::default();
+        while let Some(shard_explanation) = shard_explanations.try_next().await? {
+            explanation.merge(shard_explanation);
+        }
+
+        Ok(explanation)
+    }
+}
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use segment::types::VectorNameBuf;
+use uuid::Uuid;
+
+use super::Collection;
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::shards::shard::ShardId;
+use crate::shards::shard_holder::shard_not_found_error;
+
+impl Collection {
+    /// HNSW graph of the vector in a segment of the shard, exported as JSON Lines,
+    /// see [`segment::data_types::hnsw_graph`] for the format.
+    ///
+    /// Only segments of the replica located on this peer can be exported.
+    pub async fn export_hnsw_graph(
+        &self,
+        shard_id: ShardId,
+        segment_id: Uuid,
+        vector_name: VectorNameBuf,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<Vec<u8>> {
+        let timeout = timeout.unwrap_or(self.shared_storage_config.search_timeout);
+
+        let shard_holder = self.shards_holder.read().await;
+        let shard = shard_holder
+            .get_shard(shard_id)
+            .ok_or_else(|| shard_not_found_error(shard_id))?;
+
+        let graph = shard
+            .execute_local_aggregation(async move |local_shard, search_runtime| {
+                local_shard
+                    .export_hnsw_graph(segment_id, vector_name, search_runtime, timeout)
+                    .await
+            })
+            .await?;
+
+        graph.ok_or_else(|| {
+            CollectionError::not_found(format!("Segment {segment_id} in shard {shard_id}"))
+        })
+    }
+}
@@ -0,0 +1,122 @@
+use segment::data_types::index::{TextIndexParams, TokenizerType};
+use segment::index::field_index::full_text_index::tokenizers::detect_language_code;
+use segment::json_path::JsonPath;
+use segment::types::{Payload, PayloadContainer, PayloadSchemaParams};
+use serde_json::Value;
+
+use super::Collection;
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::payload_ops::PayloadOps;
+use crate::operations::point_ops::{
+    ConditionalInsertOperationInternal, PointInsertOperationsInternal, PointOperations,
+};
+
+impl Collection {
+    /// Store the language detected in indexed texts into the payload of the upserted points,
+    /// for full-text indexes configured with `detected_language_key`.
+    pub(super) fn store_detected_languages(&self, operation: &mut CollectionUpdateOperations) {
+        let fields = self.detected_language_fields();
+        if fields.is_empty() {
+            return;
+        }
+
+        for payload in operation_payloads_mut(operation) {
+            store_detected_languages(payload, &fields);
+        }
+    }
+
+    /// Indexed text fields with the payload key to store detected language in
+    fn detected_language_fields(&self) -> Vec<(JsonPath, String)> {
+        self.payload_index_schema
+            .read()
+            .schema
+            .iter()
+            .filter_map(|(field, schema)| {
+                let params = schema.expand();
+                let PayloadSchemaParams::Text(TextIndexParams {
+                    tokenizer: TokenizerType::Multilingual,
+                    language_detection: Some(true),
+                    detected_language_key: Some(key),
+                    ..
+                }) = params.as_ref()
+                else {
+                    return None;
+                };
+                Some((field.clone(), key.clone()))
+            })
+            .collect()
+    }
+}
+
+fn operation_payloads_mut(
+    operation: &mut CollectionUpdateOperations,
+) -> Box<dyn Iterator<Item = &mut Payload> + '_> {
+    match operation {
+        CollectionUpdateOperations::PointOperation(
+            PointOperations::UpsertPoints(points_op)
+            | PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
+                points_op,
+                ..
+            }),
+        ) => match points_op {
+            PointInsertOperationsInternal::PointsBatch(batch) => {
+                Box::new(batch.payloads.iter_mut().flatten().flatten())
+            }
+            PointInsertOperationsInternal::PointsList(points) => {
+                Box::new(points.iter_mut().filter_map(|point| point.payload.as_mut()))
+            }
+        },
+        // Payload assigned to a nested key can't contain the whole indexed field
+        CollectionUpdateOperations::PayloadOperation(
+            PayloadOps::SetPayload(op) | PayloadOps::OverwritePayload(op),
+        ) if op.key.is_none() => Box::new(std::iter::once(&mut op.payload)),
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+fn store_detected_languages(payload: &mut Payload, fields: &[(JsonPath, String)]) {
+    for (field, key) in fields {
+        let text = payload
+            .get_value(field)
+            .iter()
+            .flat_map(|value| match value {
+                Value::String(text) => vec![text.as_str()],
+                Value::Array(values) => values.iter().filter_map(Value::as_str).collect(),
+                _ => vec![],
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if text.is_empty() {
+            continue;
+        }
+
+        if let Some(code) = detect_language_code(&text) {
+            payload
+                .0
+                .insert(key.clone(), Value::String(code.to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::payload_json;
+
+    use super::*;
+
+    #[test]
+    fn test_store_detected_languages() {
+        let fields = [("text".parse::<JsonPath>().unwrap(), "lang".to_string())];
+
+        let mut payload = payload_json! {
+            "text": "Die Katzen spielen im Garten und die Hunde schlafen in der Sonne.",
+        };
+        store_detected_languages(&mut payload, &fields);
+        assert_eq!(payload.0.get("lang"), Some(&Value::from("de")));
+
+        let mut payload = payload_json! { "other": "The cats are playing in the garden." };
+        store_detected_languages(&mut payload, &fields);
+        assert!(!payload.contains_key("lang"));
+    }
+}
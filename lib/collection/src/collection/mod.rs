@@ -1,11 +1,23 @@
 mod clean;
 mod collection_ops;
+pub mod deduplication;
+mod default_search_params;
 pub mod distance_matrix;
-mod facet;
+mod embedding_model;
+pub mod facet;
+mod filter_explanation;
+mod hnsw_graph;
+mod language_detection;
 pub mod mmr;
+mod non_finite_vectors;
+mod normalization_check;
+pub mod payload_index_compaction;
 pub mod payload_index_schema;
+pub mod payload_migration;
 mod point_ops;
+pub mod quantiles;
 pub mod query;
+pub mod quota;
 mod resharding;
 mod search;
 mod shard_transfer;
@@ -13,6 +25,8 @@ mod sharding_keys;
 mod snapshots;
 mod state_management;
 mod telemetry;
+pub mod term_stats;
+pub mod vector_stats;
 
 use std::collections::HashMap;
 use std::ops::Deref;
@@ -30,12 +44,17 @@ use tokio::runtime::Handle;
 use tokio::sync::{Mutex, RwLock};
 
 use crate::collection::collection_ops::ABORT_TRANSFERS_ON_SHARD_DROP_FIX_FROM_VERSION;
+use crate::collection::deduplication::DeduplicationTask;
 use crate::collection::payload_index_schema::PayloadIndexSchema;
+use crate::collection::payload_migration::PayloadMigrationTask;
 use crate::collection_state::{ShardInfo, State};
 use crate::common::collection_size_stats::{
     CollectionSizeAtomicStats, CollectionSizeStats, CollectionSizeStatsCache,
 };
 use crate::common::is_ready::IsReady;
+use crate::common::query_cache::QueryCache;
+use crate::common::query_coalescer::QueryCoalescer;
+use crate::common::score_drift::ScoreDriftDetector;
 use crate::config::{CollectionConfigInternal, ShardingMethod};
 use crate::operations::OperationWithClockTag;
 use crate::operations::config_diff::{DiffConfig, OptimizersConfigDiff};
@@ -88,6 +107,17 @@ pub struct Collection {
     collection_stats_cache: CollectionSizeStatsCache,
     // Background tasks to clean shards
     shard_clean_tasks: ShardCleanTasks,
+    // Cache of query results, invalidated on every write. `None` if disabled.
+    // Replaced when query cache params of the collection change.
+    query_cache: parking_lot::RwLock<Option<Arc<QueryCache>>>,
+    // Coalescer of identical concurrent queries. `None` if disabled.
+    query_coalescer: Option<QueryCoalescer>,
+    // Detector of top-1 score distribution changes. `None` if disabled.
+    score_drift: Option<ScoreDriftDetector>,
+    // Background task searching for near-duplicate points
+    deduplication_task: parking_lot::Mutex<Option<DeduplicationTask>>,
+    // Background task renaming or dropping a payload key
+    payload_migration_task: parking_lot::Mutex<Option<PayloadMigrationTask>>,
 }
 
 pub type RequestShardTransfer = Arc<dyn Fn(ShardTransfer) + Send + Sync>;
@@ -169,6 +199,13 @@ impl Collection {
             Self::estimate_collection_size_stats(&shared_shard_holder).await?,
         );
 
+        let query_cache = QueryCache::new(&collection_config.params).map(Arc::new);
+        let score_drift =
+            ScoreDriftDetector::new(name.clone(), &shared_storage_config.score_drift_config);
+        let query_coalescer = shared_storage_config
+            .coalesce_identical_queries
+            .then(QueryCoalescer::default);
+
         // Once the config is persisted - the collection is considered to be successfully created.
         CollectionVersion::save(path)?;
         collection_config.save(path)?;
@@ -195,6 +232,11 @@ impl Collection {
             optimizer_resource_budget,
             collection_stats_cache,
             shard_clean_tasks: Default::default(),
+            query_cache: parking_lot::RwLock::new(query_cache),
+            query_coalescer,
+            score_drift,
+            deduplication_task: Default::default(),
+            payload_migration_task: Default::default(),
         })
     }
 
@@ -290,6 +332,15 @@ impl Collection {
                 .expect("Failed to load collection size stats"),
         );
 
+        let query_cache = QueryCache::new(&collection_config.params).map(Arc::new);
+        let score_drift = ScoreDriftDetector::new(
+            collection_id.clone(),
+            &shared_storage_config.score_drift_config,
+        );
+        let query_coalescer = shared_storage_config
+            .coalesce_identical_queries
+            .then(QueryCoalescer::default);
+
         Self {
             id: collection_id.clone(),
             shards_holder: shared_shard_holder,
@@ -312,6 +363,11 @@ impl Collection {
             optimizer_resource_budget,
             collection_stats_cache,
             shard_clean_tasks: Default::default(),
+            query_cache: parking_lot::RwLock::new(query_cache),
+            query_coalescer,
+            score_drift,
+            deduplication_task: Default::default(),
+            payload_migration_task: Default::default(),
         }
     }
 
@@ -631,6 +687,7 @@ impl Collection {
 
     pub async fn sync_local_state(
         &self,
+        node_type: NodeType,
         on_transfer_failure: OnTransferFailure,
         on_transfer_success: OnTransferSuccess,
         on_finish_init: ChangePeerState,
@@ -706,7 +763,7 @@ impl Collection {
                 continue;
             }
 
-            if self.shared_storage_config.node_type == NodeType::Listener {
+            if node_type == NodeType::Listener {
                 // We probably should not switch node type during resharding, so we only check for `Active`,
                 // but not `ReshardingScaleDown` replica state here...
                 let is_last_active = peers.values().filter(|&&state| state == Active).count() == 1;
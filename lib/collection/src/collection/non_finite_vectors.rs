@@ -0,0 +1,241 @@
+use segment::common::operation_error::OperationResult;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NonFiniteVectorPolicy, VectorElementType};
+use segment::types::VectorName;
+use shard::search::CoreSearchRequestBatch;
+
+use super::Collection;
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::{
+    BatchVectorStructPersisted, ConditionalInsertOperationInternal, PointInsertOperationsInternal,
+    PointOperations, VectorPersisted, VectorStructPersisted,
+};
+use crate::operations::types::CollectionResult;
+use crate::operations::universal_query::shard_query::{
+    ScoringQuery, ShardPrefetch, ShardQueryRequest,
+};
+use crate::operations::vector_ops::VectorOperations;
+
+impl Collection {
+    async fn non_finite_vector_policy(&self) -> NonFiniteVectorPolicy {
+        self.collection_config
+            .read()
+            .await
+            .params
+            .non_finite_vector_policy
+            .unwrap_or_default()
+    }
+
+    /// Handle NaN and infinite values of the updated vectors according to the collection policy.
+    ///
+    /// Must be applied before the operation is sent to the replicas, so all of them store the
+    /// same sanitized vectors.
+    pub(super) async fn apply_non_finite_policy_to_update(
+        &self,
+        operation: &mut CollectionUpdateOperations,
+    ) -> CollectionResult<()> {
+        let policy = self.non_finite_vector_policy().await;
+        for_each_operation_vector(operation, |name, values| policy.apply(name, values))?;
+        Ok(())
+    }
+
+    /// Handle NaN and infinite values of the query vectors according to the collection policy
+    pub(super) async fn apply_non_finite_policy_to_queries(
+        &self,
+        requests: Vec<ShardQueryRequest>,
+    ) -> CollectionResult<Vec<ShardQueryRequest>> {
+        let policy = self.non_finite_vector_policy().await;
+
+        let requests = requests
+            .into_iter()
+            .map(|mut request| {
+                request.query = request
+                    .query
+                    .map(|query| scoring_query_non_finite_policy(query, policy))
+                    .transpose()?;
+                request.prefetches = prefetches_non_finite_policy(request.prefetches, policy)?;
+                Ok(request)
+            })
+            .collect::<OperationResult<_>>()?;
+
+        Ok(requests)
+    }
+
+    /// Handle NaN and infinite values of the search vectors according to the collection policy
+    pub(super) async fn apply_non_finite_policy_to_searches(
+        &self,
+        mut request: CoreSearchRequestBatch,
+    ) -> CollectionResult<CoreSearchRequestBatch> {
+        let policy = self.non_finite_vector_policy().await;
+
+        request.searches = request
+            .searches
+            .into_iter()
+            .map(|mut search| {
+                search.query = search.query.apply_non_finite_policy(policy)?;
+                Ok(search)
+            })
+            .collect::<OperationResult<_>>()?;
+
+        Ok(request)
+    }
+}
+
+fn scoring_query_non_finite_policy(
+    query: ScoringQuery,
+    policy: NonFiniteVectorPolicy,
+) -> OperationResult<ScoringQuery> {
+    match query {
+        ScoringQuery::Vector(query) => query
+            .apply_non_finite_policy(policy)
+            .map(ScoringQuery::Vector),
+        ScoringQuery::Mmr(mut mmr) => {
+            mmr.vector.apply_non_finite_policy(&mmr.using, policy)?;
+            Ok(ScoringQuery::Mmr(mmr))
+        }
+        ScoringQuery::VectorSelection(mut selection) => {
+            for query in &mut selection.queries {
+                let vector_name = query.using.as_deref().unwrap_or(DEFAULT_VECTOR_NAME);
+                query.query.apply_non_finite_policy(vector_name, policy)?;
+            }
+            Ok(ScoringQuery::VectorSelection(selection))
+        }
+        ScoringQuery::Fusion(_)
+        | ScoringQuery::OrderBy(_)
+        | ScoringQuery::Formula(_)
+        | ScoringQuery::Sample(_) => Ok(query),
+    }
+}
+
+fn prefetches_non_finite_policy(
+    prefetches: Vec<ShardPrefetch>,
+    policy: NonFiniteVectorPolicy,
+) -> OperationResult<Vec<ShardPrefetch>> {
+    prefetches
+        .into_iter()
+        .map(|mut prefetch| {
+            prefetch.query = prefetch
+                .query
+                .map(|query| scoring_query_non_finite_policy(query, policy))
+                .transpose()?;
+            prefetch.prefetches = prefetches_non_finite_policy(prefetch.prefetches, policy)?;
+            Ok(prefetch)
+        })
+        .collect()
+}
+
+fn for_each_operation_vector(
+    operation: &mut CollectionUpdateOperations,
+    mut f: impl FnMut(&VectorName, &mut [VectorElementType]) -> OperationResult<()>,
+) -> OperationResult<()> {
+    match operation {
+        CollectionUpdateOperations::PointOperation(
+            PointOperations::UpsertPoints(points_op)
+            | PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
+                points_op,
+                ..
+            }),
+        ) => match points_op {
+            PointInsertOperationsInternal::PointsBatch(batch) => match &mut batch.vectors {
+                BatchVectorStructPersisted::Single(vectors) => vectors
+                    .iter_mut()
+                    .try_for_each(|vector| f(DEFAULT_VECTOR_NAME, vector)),
+                BatchVectorStructPersisted::MultiDense(vectors) => {
+                    vectors.iter_mut().try_for_each(|multi_vector| {
+                        f(DEFAULT_VECTOR_NAME, &mut multi_vector.flattened_vectors)
+                    })
+                }
+                BatchVectorStructPersisted::Named(vectors) => {
+                    vectors.iter_mut().try_for_each(|(name, vectors)| {
+                        vectors
+                            .iter_mut()
+                            .try_for_each(|vector| persisted_vector_values(name, vector, &mut f))
+                    })
+                }
+            },
+            PointInsertOperationsInternal::PointsList(points) => points
+                .iter_mut()
+                .try_for_each(|point| struct_vector_values(&mut point.vector, &mut f)),
+        },
+        CollectionUpdateOperations::VectorOperation(
+            VectorOperations::UpdateVectors(op) | VectorOperations::AppendVectors(op),
+        ) => op
+            .points
+            .iter_mut()
+            .try_for_each(|point| struct_vector_values(&mut point.vector, &mut f)),
+        _ => Ok(()),
+    }
+}
+
+fn struct_vector_values(
+    vector: &mut VectorStructPersisted,
+    f: &mut impl FnMut(&VectorName, &mut [VectorElementType]) -> OperationResult<()>,
+) -> OperationResult<()> {
+    match vector {
+        VectorStructPersisted::Single(vector) => f(DEFAULT_VECTOR_NAME, vector),
+        VectorStructPersisted::MultiDense(multi_vector) => {
+            f(DEFAULT_VECTOR_NAME, &mut multi_vector.flattened_vectors)
+        }
+        VectorStructPersisted::Named(vectors) => vectors
+            .iter_mut()
+            .try_for_each(|(name, vector)| persisted_vector_values(name, vector, f)),
+    }
+}
+
+fn persisted_vector_values(
+    name: &VectorName,
+    vector: &mut VectorPersisted,
+    f: &mut impl FnMut(&VectorName, &mut [VectorElementType]) -> OperationResult<()>,
+) -> OperationResult<()> {
+    match vector {
+        VectorPersisted::Dense(vector) => f(name, vector),
+        VectorPersisted::Sparse(vector) => f(name, &mut vector.values),
+        VectorPersisted::MultiDense(multi_vector) => f(name, &mut multi_vector.flattened_vectors),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::PointIdType;
+
+    use super::*;
+    use crate::operations::point_ops::PointStructPersisted;
+
+    fn upsert(vector: Vec<VectorElementType>) -> CollectionUpdateOperations {
+        CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+            PointInsertOperationsInternal::PointsList(vec![PointStructPersisted {
+                id: PointIdType::NumId(1),
+                vector: VectorStructPersisted::Single(vector),
+                payload: None,
+            }]),
+        ))
+    }
+
+    fn upserted_vector(operation: &CollectionUpdateOperations) -> &[VectorElementType] {
+        match operation {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsList(points),
+            )) => match &points[0].vector {
+                VectorStructPersisted::Single(vector) => vector,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_operation_non_finite_policy() {
+        let mut operation = upsert(vec![1.0, f32::NAN, f32::INFINITY]);
+        let policy = NonFiniteVectorPolicy::Reject;
+        assert!(
+            for_each_operation_vector(&mut operation, |name, values| {
+                policy.apply(name, values)
+            })
+            .is_err()
+        );
+
+        let policy = NonFiniteVectorPolicy::SanitizeToZero;
+        for_each_operation_vector(&mut operation, |name, values| policy.apply(name, values))
+            .unwrap();
+        assert_eq!(upserted_vector(&operation), [1.0, 0.0, 0.0]);
+    }
+}
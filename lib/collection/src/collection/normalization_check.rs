@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorElementType};
+use segment::types::{Distance, VectorName, VectorNameBuf};
+
+use super::Collection;
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::{
+    BatchVectorStructPersisted, ConditionalInsertOperationInternal, PointInsertOperationsInternal,
+    PointOperations, VectorPersisted, VectorStructPersisted,
+};
+use crate::operations::types::{CollectionError, CollectionResult, Datatype};
+use crate::operations::vector_ops::VectorOperations;
+
+/// Only every n-th vector, which skips server-side normalization, is checked
+const NORMALIZATION_CHECK_STEP: usize = 16;
+
+/// Allowed deviation of the vector length from 1, to tolerate rounding of client-side normalization
+const NORMALIZATION_TOLERANCE: f32 = 1e-3;
+
+type DenseVectorRef<'a> = (&'a VectorName, &'a [VectorElementType]);
+
+impl Collection {
+    /// Check a sample of the updated vectors to be normalized,
+    /// for cosine vectors configured with `skip_normalization`,
+    /// or all cosine vectors if the operation itself skips normalization,
+    /// and for vectors of embedding models producing normalized vectors.
+    pub(super) async fn check_vectors_normalized(
+        &self,
+        operation: &CollectionUpdateOperations,
+    ) -> CollectionResult<()> {
+        let vector_names = self
+            .normalized_vectors(operation_skips_normalization(operation))
+            .await;
+        if vector_names.is_empty() {
+            return Ok(());
+        }
+
+        let not_normalized = operation_dense_vectors(operation)
+            .filter(|(name, _)| vector_names.contains_key(*name))
+            .step_by(NORMALIZATION_CHECK_STEP)
+            .find(|(_, vector)| !is_normalized(vector));
+
+        let Some((name, _)) = not_normalized else {
+            return Ok(());
+        };
+
+        let description = match &vector_names[name] {
+            Some(model) => format!(
+                "Vector `{name}` is not normalized, but model `{model}` produces normalized vectors. \
+                 Make sure the vector is produced by this model",
+            ),
+            None => {
+                format!("Vector `{name}` is not normalized, but normalization of it is skipped")
+            }
+        };
+        Err(CollectionError::bad_input(description))
+    }
+
+    /// Vectors expected to be normalized on ingest,
+    /// with the name of the embedding model if it's the model producing normalized vectors
+    async fn normalized_vectors(&self, skip_all: bool) -> HashMap<VectorNameBuf, Option<String>> {
+        self.collection_config
+            .read()
+            .await
+            .params
+            .vectors
+            .params_iter()
+            // Byte vectors are not normalized by the server anyway
+            .filter(|(_, params)| params.datatype != Some(Datatype::Uint8))
+            .filter_map(|(name, params)| {
+                let model = params
+                    .model
+                    .as_ref()
+                    .filter(|model| model.normalized.unwrap_or_default());
+                let skips_normalization = params.distance == Distance::Cosine
+                    && (skip_all || params.skip_normalization.unwrap_or_default());
+
+                match model {
+                    Some(model) => Some((name.to_owned(), Some(model.name.clone()))),
+                    None => skips_normalization.then(|| (name.to_owned(), None)),
+                }
+            })
+            .collect()
+    }
+}
+
+fn is_normalized(vector: &[VectorElementType]) -> bool {
+    let length = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    length < f32::EPSILON || (length - 1.0).abs() <= NORMALIZATION_TOLERANCE
+}
+
+fn operation_skips_normalization(operation: &CollectionUpdateOperations) -> bool {
+    match operation {
+        CollectionUpdateOperations::PointOperation(PointOperations::UpsertPointsConditional(
+            ConditionalInsertOperationInternal {
+                skip_normalization, ..
+            },
+        )) => skip_normalization.unwrap_or_default(),
+        _ => false,
+    }
+}
+
+fn operation_dense_vectors(
+    operation: &CollectionUpdateOperations,
+) -> Box<dyn Iterator<Item = DenseVectorRef<'_>> + '_> {
+    match operation {
+        CollectionUpdateOperations::PointOperation(
+            PointOperations::UpsertPoints(points_op)
+            | PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
+                points_op,
+                ..
+            }),
+        ) => match points_op {
+            PointInsertOperationsInternal::PointsBatch(batch) => match &batch.vectors {
+                BatchVectorStructPersisted::Single(vectors) => Box::new(
+                    vectors
+                        .iter()
+                        .map(|vector| (DEFAULT_VECTOR_NAME, vector.as_slice())),
+                ),
+                BatchVectorStructPersisted::MultiDense(vectors) => {
+                    Box::new(vectors.iter().flat_map(|multi_vector| {
+                        multi_vector
+                            .multi_vectors()
+                            .map(|vector| (DEFAULT_VECTOR_NAME, vector))
+                    }))
+                }
+                BatchVectorStructPersisted::Named(vectors) => {
+                    Box::new(vectors.iter().flat_map(|(name, vectors)| {
+                        vectors
+                            .iter()
+                            .flat_map(move |vector| persisted_dense_vectors(name, vector))
+                    }))
+                }
+            },
+            PointInsertOperationsInternal::PointsList(points) => Box::new(
+                points
+                    .iter()
+                    .flat_map(|point| struct_dense_vectors(&point.vector)),
+            ),
+        },
+        CollectionUpdateOperations::VectorOperation(
+            VectorOperations::UpdateVectors(op) | VectorOperations::AppendVectors(op),
+        ) => Box::new(
+            op.points
+                .iter()
+                .flat_map(|point| struct_dense_vectors(&point.vector)),
+        ),
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+fn struct_dense_vectors(
+    vector: &VectorStructPersisted,
+) -> Box<dyn Iterator<Item = DenseVectorRef<'_>> + '_> {
+    match vector {
+        VectorStructPersisted::Single(vector) => {
+            Box::new(std::iter::once((DEFAULT_VECTOR_NAME, vector.as_slice())))
+        }
+        VectorStructPersisted::MultiDense(multi_vector) => Box::new(
+            multi_vector
+                .multi_vectors()
+                .map(|vector| (DEFAULT_VECTOR_NAME, vector)),
+        ),
+        VectorStructPersisted::Named(vectors) => Box::new(
+            vectors
+                .iter()
+                .flat_map(|(name, vector)| persisted_dense_vectors(name, vector)),
+        ),
+    }
+}
+
+fn persisted_dense_vectors<'a>(
+    name: &'a VectorName,
+    vector: &'a VectorPersisted,
+) -> Box<dyn Iterator<Item = DenseVectorRef<'a>> + 'a> {
+    match vector {
+        VectorPersisted::Dense(vector) => Box::new(std::iter::once((name, vector.as_slice()))),
+        VectorPersisted::Sparse(_) => Box::new(std::iter::empty()),
+        VectorPersisted::MultiDense(multi_vector) => Box::new(
+            multi_vector
+                .multi_vectors()
+                .map(move |vector| (name, vector)),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_normalized() {
+        assert!(is_normalized(&[0.6, 0.8]));
+        assert!(is_normalized(&[0.0, 0.0]));
+        assert!(is_normalized(&[0.6001, 0.8]));
+        assert!(!is_normalized(&[3.0, 4.0]));
+        assert!(!is_normalized(&[0.5, 0.5]));
+    }
+}
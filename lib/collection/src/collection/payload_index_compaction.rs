@@ -0,0 +1,34 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::Collection;
+use crate::operations::types::CollectionResult;
+
+/// Outcome of compacting payload index storages
+#[derive(Debug, Clone, Copy, Serialize, JsonSchema)]
+pub struct PayloadIndexCompaction {
+    /// Number of bytes given back to the file system
+    pub reclaimed_bytes: usize,
+}
+
+impl Collection {
+    /// Compact fragmented storages of payload indexes in the replicas located on this peer.
+    ///
+    /// Only mutable map indexes on Gridstore get fragmented by deletes and updates,
+    /// their storages are compacted if the fragmentation ratio reaches the threshold.
+    pub async fn compact_payload_indexes(
+        &self,
+        min_fragmentation_ratio: f64,
+    ) -> CollectionResult<PayloadIndexCompaction> {
+        let shard_holder = self.shards_holder.read().await;
+
+        let mut reclaimed_bytes = 0;
+        for shard in shard_holder.all_shards() {
+            reclaimed_bytes += shard
+                .compact_local_payload_indexes(min_fragmentation_ratio)
+                .await?;
+        }
+
+        Ok(PayloadIndexCompaction { reclaimed_bytes })
+    }
+}
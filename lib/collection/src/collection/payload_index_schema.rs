@@ -2,6 +2,9 @@ use std::path::{Path, PathBuf};
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::save_on_disk::SaveOnDisk;
+use segment::index::field_index::full_text_index::highlight::{
+    HighlightParams, PayloadHighlighter,
+};
 use segment::json_path::JsonPath;
 use segment::types::{Filter, PayloadFieldSchema};
 pub use shard::payload_index_schema::PayloadIndexSchema;
@@ -95,6 +98,15 @@ impl Collection {
         self.payload_index_schema.read().schema.get(key).cloned()
     }
 
+    /// Highlighter for full-text conditions of `filter`, on fields with a full-text index
+    pub fn payload_highlighter(
+        &self,
+        filter: &Filter,
+        params: HighlightParams,
+    ) -> PayloadHighlighter {
+        PayloadHighlighter::new(filter, &self.payload_index_schema.read().schema, params)
+    }
+
     /// Returns an arbitrary payload key along with acceptable
     /// schemas used by `filter` which can be indexed but currently is not.
     /// If this function returns `None` all indexable keys in `filter` are indexed.
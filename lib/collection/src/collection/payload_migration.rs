@@ -0,0 +1,385 @@
+use std::sync::{Arc, Weak};
+
+use api::rest::{DropPayloadField, PayloadMigrationRequest, RenamePayloadKey};
+use cancel::{CancellationToken, DropGuard};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use schemars::JsonSchema;
+use segment::json_path::{JsonPath, JsonPathItem};
+use segment::types::{Payload, WithPayloadInterface, WithVector};
+use serde::Serialize;
+use serde_json::Value;
+use shard::scroll::ScrollRequestInternal;
+use tokio::sync::watch::{Receiver, Sender};
+use tokio::task::JoinHandle;
+
+use super::Collection;
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::payload_ops::{DeletePayloadOp, PayloadOps, SetPayloadOp};
+use crate::operations::point_ops::WriteOrdering;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+/// Number of points, which payload is rewritten in a single batch
+const PAYLOAD_MIGRATION_BATCH_SIZE: usize = 1000;
+
+/// Internal representation of the payload migration request
+#[derive(Debug, Clone)]
+pub enum PayloadMigration {
+    /// Move values of `from` key to `to` key
+    RenameKey { from: JsonPath, to: JsonPath },
+    /// Delete `key` from all points
+    DropField { key: JsonPath },
+}
+
+impl From<PayloadMigrationRequest> for PayloadMigration {
+    fn from(request: PayloadMigrationRequest) -> Self {
+        match request {
+            PayloadMigrationRequest::RenameKey(RenamePayloadKey { from, to }) => {
+                PayloadMigration::RenameKey { from, to }
+            }
+            PayloadMigrationRequest::DropField(DropPayloadField { key }) => {
+                PayloadMigration::DropField { key }
+            }
+        }
+    }
+}
+
+impl PayloadMigration {
+    /// Key, which values are read and removed by the migration
+    pub fn source_key(&self) -> &JsonPath {
+        match self {
+            PayloadMigration::RenameKey { from, to: _ } => from,
+            PayloadMigration::DropField { key } => key,
+        }
+    }
+
+    fn validate(&self) -> CollectionResult<()> {
+        match self {
+            PayloadMigration::RenameKey { from, to } => {
+                check_plain_key(from)?;
+                check_plain_key(to)?;
+                if from.compatible(to) {
+                    return Err(CollectionError::bad_input(format!(
+                        "Can't rename payload key `{from}` to `{to}`, one key contains the other",
+                    )));
+                }
+                Ok(())
+            }
+            PayloadMigration::DropField { key } => check_plain_key(key),
+        }
+    }
+}
+
+/// Only object keys can be migrated, array indices would leave holes in arrays
+fn check_plain_key(key: &JsonPath) -> CollectionResult<()> {
+    if key
+        .rest
+        .iter()
+        .all(|item| matches!(item, JsonPathItem::Key(_)))
+    {
+        Ok(())
+    } else {
+        Err(CollectionError::bad_input(format!(
+            "Payload key `{key}` must not contain array indices",
+        )))
+    }
+}
+
+/// Split the key into the path of its parent object, if any, and the name of the last key
+fn split_last_key(key: &JsonPath) -> (Option<JsonPath>, &str) {
+    match key.rest.split_last() {
+        Some((JsonPathItem::Key(last), parent)) => (
+            Some(JsonPath {
+                first_key: key.first_key.clone(),
+                rest: parent.to_vec(),
+            }),
+            last,
+        ),
+        // Keys are checked to contain no indices
+        Some((JsonPathItem::Index(_) | JsonPathItem::WildcardIndex, _)) | None => {
+            (None, &key.first_key)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadMigrationState {
+    Running,
+    Done,
+    Failed,
+    Cancelled,
+}
+
+/// Progress of the payload migration task, updated as the task goes
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct PayloadMigrationStatus {
+    pub state: PayloadMigrationState,
+    /// Number of points, which payload was checked
+    pub processed_points: usize,
+    /// Number of points having the migrated key, which payload was rewritten
+    pub updated_points: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PayloadMigrationStatus {
+    fn started() -> Self {
+        Self {
+            state: PayloadMigrationState::Running,
+            processed_points: 0,
+            updated_points: 0,
+            error: None,
+        }
+    }
+}
+
+/// A background task rewriting a payload key across all points of a collection
+pub(super) struct PayloadMigrationTask {
+    /// Handle of the migration task
+    handle: JoinHandle<()>,
+    /// Watch channel with current status of the task
+    status: Receiver<PayloadMigrationStatus>,
+    /// Cancellation token drop guard, cancels the task if this is dropped
+    cancel: DropGuard,
+}
+
+impl PayloadMigrationTask {
+    fn new(collection: Weak<Collection>, migration: PayloadMigration) -> Self {
+        let (sender, receiver) = tokio::sync::watch::channel(PayloadMigrationStatus::started());
+        let cancel = CancellationToken::default();
+
+        let task = tokio::task::spawn(Self::task(collection, migration, sender, cancel.clone()));
+
+        PayloadMigrationTask {
+            handle: task,
+            status: receiver,
+            cancel: cancel.drop_guard(),
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        self.status.borrow().state == PayloadMigrationState::Running
+    }
+
+    fn abort(self) -> JoinHandle<()> {
+        self.cancel.disarm().cancel();
+        self.handle
+    }
+
+    async fn task(
+        collection: Weak<Collection>,
+        migration: PayloadMigration,
+        sender: Sender<PayloadMigrationStatus>,
+        cancel: CancellationToken,
+    ) {
+        let task = payload_migration_task(collection, migration, sender.clone());
+        let result = cancel.run_until_cancelled(task).await;
+
+        sender.send_modify(|status| match result {
+            Some(Ok(())) => status.state = PayloadMigrationState::Done,
+            Some(Err(err)) => {
+                log::error!("Background payload migration task failed: {err}");
+                status.state = PayloadMigrationState::Failed;
+                status.error = Some(err.to_string());
+            }
+            None => status.state = PayloadMigrationState::Cancelled,
+        });
+    }
+}
+
+async fn payload_migration_task(
+    collection: Weak<Collection>,
+    migration: PayloadMigration,
+    sender: Sender<PayloadMigrationStatus>,
+) -> CollectionResult<()> {
+    let source_key = migration.source_key().clone();
+    let mut offset = None;
+
+    loop {
+        let Some(collection) = collection.upgrade() else {
+            return Err(CollectionError::not_found("Collection dropped"));
+        };
+
+        let scroll_request = ScrollRequestInternal {
+            offset,
+            limit: Some(PAYLOAD_MIGRATION_BATCH_SIZE + 1),
+            filter: None,
+            with_payload: Some(WithPayloadInterface::Fields(vec![source_key.clone()])),
+            with_vector: WithVector::Bool(false),
+            order_by: None,
+            consistent: None,
+            view_id: None,
+        };
+        let mut points = collection
+            .scroll_by(
+                scroll_request,
+                None,
+                &ShardSelectorInternal::All,
+                None,
+                HwMeasurementAcc::disposable(), // Internal operation, no measurement needed
+            )
+            .await?
+            .points;
+
+        offset = (points.len() > PAYLOAD_MIGRATION_BATCH_SIZE).then(|| points.pop().unwrap().id);
+
+        let mut ids = Vec::new();
+        for point in &points {
+            let Some(payload) = &point.payload else {
+                continue;
+            };
+            let values = source_key.value_get(&payload.0);
+            if values.is_empty() {
+                continue;
+            }
+            ids.push(point.id);
+
+            let PayloadMigration::RenameKey { from: _, to } = &migration else {
+                continue;
+            };
+
+            let value = match values.as_slice() {
+                [value] => (*value).clone(),
+                values => Value::Array(values.iter().map(|value| (*value).clone()).collect()),
+            };
+            let (parent, last_key) = split_last_key(to);
+            let mut payload = Payload::default();
+            payload.0.insert(last_key.to_owned(), value);
+
+            collection
+                .update_from_client_simple(
+                    CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(
+                        SetPayloadOp {
+                            payload,
+                            points: Some(vec![point.id]),
+                            filter: None,
+                            key: parent,
+                        },
+                    )),
+                    false,
+                    None,
+                    WriteOrdering::default(),
+                    HwMeasurementAcc::disposable(),
+                )
+                .await?;
+        }
+
+        let updated_points = ids.len();
+        if !ids.is_empty() {
+            // Applied after the new values are set, as operations on a point are ordered
+            collection
+                .update_from_client_simple(
+                    CollectionUpdateOperations::PayloadOperation(PayloadOps::DeletePayload(
+                        DeletePayloadOp {
+                            keys: vec![source_key.clone()],
+                            points: Some(ids),
+                            filter: None,
+                        },
+                    )),
+                    true,
+                    None,
+                    WriteOrdering::default(),
+                    HwMeasurementAcc::disposable(),
+                )
+                .await?;
+        }
+
+        sender.send_modify(|status| {
+            status.processed_points += points.len();
+            status.updated_points += updated_points;
+        });
+
+        if offset.is_none() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+impl Collection {
+    /// Start a background rename or removal of a payload key across all points.
+    ///
+    /// Only the payload is rewritten, payload indexes must be retargeted separately.
+    /// Only one migration may run at a time. Progress is available through
+    /// [`Collection::payload_migration_status`].
+    pub fn start_payload_migration(
+        self: &Arc<Self>,
+        migration: PayloadMigration,
+    ) -> CollectionResult<PayloadMigrationStatus> {
+        migration.validate()?;
+
+        let mut task = self.payload_migration_task.lock();
+        if task.as_ref().is_some_and(PayloadMigrationTask::is_running) {
+            return Err(CollectionError::bad_request(
+                "Payload migration is already running for this collection",
+            ));
+        }
+
+        let new_task = PayloadMigrationTask::new(Arc::downgrade(self), migration);
+        let status = new_task.status.borrow().clone();
+        *task = Some(new_task);
+
+        Ok(status)
+    }
+
+    /// Status of the last started payload migration task, if any
+    pub fn payload_migration_status(&self) -> Option<PayloadMigrationStatus> {
+        self.payload_migration_task
+            .lock()
+            .as_ref()
+            .map(|task| task.status.borrow().clone())
+    }
+
+    /// Cancel the running payload migration task and wait for it to stop
+    pub async fn cancel_payload_migration(&self) {
+        let Some(task) = self.payload_migration_task.lock().take() else {
+            return;
+        };
+
+        if let Err(err) = task.abort().await {
+            log::error!("Failed to join payload migration task: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename(from: &str, to: &str) -> PayloadMigration {
+        PayloadMigration::RenameKey {
+            from: JsonPath::new(from),
+            to: JsonPath::new(to),
+        }
+    }
+
+    #[test]
+    fn test_validate_migration() {
+        assert!(rename("a", "b").validate().is_ok());
+        assert!(rename("a.b", "a.c").validate().is_ok());
+
+        // Keys containing each other
+        assert!(rename("a", "a").validate().is_err());
+        assert!(rename("a", "a.b").validate().is_err());
+        assert!(rename("a.b", "a").validate().is_err());
+
+        // Array indices
+        assert!(rename("a[0]", "b").validate().is_err());
+        assert!(rename("a", "b[].c").validate().is_err());
+        let drop = PayloadMigration::DropField {
+            key: JsonPath::new("a[].b"),
+        };
+        assert!(drop.validate().is_err());
+    }
+
+    #[test]
+    fn test_split_last_key() {
+        assert_eq!(split_last_key(&JsonPath::new("a")), (None, "a"));
+        assert_eq!(
+            split_last_key(&JsonPath::new("a.b.c")),
+            (Some(JsonPath::new("a.b")), "c"),
+        );
+    }
+}
@@ -11,16 +11,24 @@ use segment::types::{ShardKey, WithPayload, WithPayloadInterface};
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
 use shard::scroll::ScrollRequestInternal;
+use uuid::Uuid;
 
 use super::Collection;
 use crate::operations::consistency_params::ReadConsistency;
-use crate::operations::point_ops::WriteOrdering;
+use crate::operations::point_ops::{ReturnIds, WriteOrdering};
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::*;
 use crate::operations::{CollectionUpdateOperations, OperationWithClockTag};
 use crate::shards::shard::ShardId;
 
 impl Collection {
+    /// Drop cached query results, must be called after any update of the points
+    fn invalidate_query_cache(&self) {
+        if let Some(query_cache) = self.query_cache.read().as_ref() {
+            query_cache.invalidate();
+        }
+    }
+
     /// Apply collection update operation to all local shards.
     /// Return None if there are no local shards
     ///
@@ -68,6 +76,8 @@ impl Collection {
             })
             .await?;
 
+        self.invalidate_query_cache();
+
         let mut result = None;
 
         for collection_result in results {
@@ -116,7 +126,7 @@ impl Collection {
                     }
 
                     shard
-                        .update_with_consistency(operation.operation, wait, timeout, ordering, false, hw_measurement_acc)
+                        .update_with_consistency(operation.operation, operation.return_ids, wait, timeout, ordering, false, hw_measurement_acc)
                         .await
                         .map(Some)
                 }
@@ -124,6 +134,8 @@ impl Collection {
         })
         .await??;
 
+        self.invalidate_query_cache();
+
         if let Some(result) = result {
             Ok(result)
         } else {
@@ -140,13 +152,20 @@ impl Collection {
     /// This method is cancel safe.
     pub async fn update_from_client(
         &self,
-        operation: CollectionUpdateOperations,
+        mut operation: CollectionUpdateOperations,
+        return_ids: Option<ReturnIds>,
         wait: bool,
         timeout: Option<Duration>,
         ordering: WriteOrdering,
         shard_keys_selection: Option<ShardKey>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<UpdateResult> {
+        self.apply_non_finite_policy_to_update(&mut operation)
+            .await?;
+        self.check_vectors_normalized(&operation).await?;
+        self.check_collection_quota(&operation).await?;
+        self.store_detected_languages(&mut operation);
+
         let shard_holder = self.shards_holder.clone().read_owned().await;
         let start_time = std::time::Instant::now();
 
@@ -160,17 +179,20 @@ impl Collection {
                     let operation = shard_holder.split_by_mode(shard.shard_id, operation);
 
                     let hw_acc = hw_measurement_acc.clone();
+                    let return_ids = return_ids.clone();
                     updates.push(async move {
                         let mut result = UpdateResult {
                             operation_id: None,
                             status: UpdateStatus::Acknowledged,
                             clock_tag: None,
+                            affected_ids: None,
                         };
 
                         for operation in operation.update_all {
                             result = shard
                                 .update_with_consistency(
                                     operation,
+                                    return_ids.clone(),
                                     wait,
                                     timeout,
                                     ordering,
@@ -184,6 +206,7 @@ impl Collection {
                             let res = shard
                                 .update_with_consistency(
                                     operation,
+                                    None,
                                     wait,
                                     timeout,
                                     ordering,
@@ -211,6 +234,8 @@ impl Collection {
             })
             .await??;
 
+        self.invalidate_query_cache();
+
         if results.is_empty() {
             return Err(CollectionError::bad_request(
                 "Empty update request".to_string(),
@@ -271,12 +296,23 @@ impl Collection {
                 });
             }
 
-            let max_operation_id = results.into_iter().map(|r| r.operation_id).max().unwrap(); // We checked that results is not empty above
+            let max_operation_id = results.iter().map(|r| r.operation_id).max().unwrap(); // We checked that results is not empty above
+
+            // Shards hold different points, so affected ids of all of them are collected
+            let affected_ids =
+                results
+                    .into_iter()
+                    .filter_map(|r| r.affected_ids)
+                    .reduce(|mut ids, other| {
+                        ids.extend(other);
+                        ids
+                    });
 
             Ok(UpdateResult {
                 operation_id: max_operation_id,
                 status,
                 clock_tag: None, // clock_tag is not used in the user response
+                affected_ids,
             })
         }
     }
@@ -292,8 +328,16 @@ impl Collection {
         ordering: WriteOrdering,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<UpdateResult> {
-        self.update_from_client(operation, wait, timeout, ordering, None, hw_measurement_acc)
-            .await
+        self.update_from_client(
+            operation,
+            None,
+            wait,
+            timeout,
+            ordering,
+            None,
+            hw_measurement_acc,
+        )
+        .await
     }
 
     pub async fn scroll_by(
@@ -318,6 +362,17 @@ impl Collection {
 
         let local_only = shard_selection.is_shard_id();
 
+        if request.consistent == Some(true) && request.view_id.is_none() {
+            if request.offset.is_some() {
+                return Err(CollectionError::bad_input(
+                    "Consistent scroll must start without `offset`, use `view_id` of the scroll to request the following pages".to_string(),
+                ));
+            }
+            // Start of a consistent scroll, shards pin their segments under this ID
+            request.view_id = Some(Uuid::new_v4());
+        }
+        let view_id = request.view_id;
+
         let order_by = request.order_by.clone().map(OrderBy::from);
 
         // `order_by` does not support offset
@@ -327,6 +382,18 @@ impl Collection {
             request.limit = Some(limit);
         }
 
+        // Points are ordered by `then_by` keys with their payload, which is cut after merging
+        let with_payload = request
+            .with_payload
+            .clone()
+            .unwrap_or_else(ScrollRequestInternal::default_with_payload);
+        if let Some(order_by) = order_by
+            .as_ref()
+            .filter(|order_by| !order_by.then_by.is_empty())
+        {
+            request.with_payload = Some(order_by.with_then_by_payload(&with_payload));
+        }
+
         let request = Arc::new(request);
 
         let retrieved_points: Vec<_> = {
@@ -368,7 +435,7 @@ impl Collection {
                 .map(api::rest::Record::from)
                 .collect_vec(),
             Some(order_by) => {
-                retrieved_iter
+                let merged = retrieved_iter
                     // Get top results
                     .kmerge_by(|a, b| match order_by.direction() {
                         Direction::Asc => (a.order_value, a.id) < (b.order_value, b.id),
@@ -376,10 +443,28 @@ impl Collection {
                     })
                     .dedup_by(|record_a, record_b| {
                         (record_a.order_value, record_a.id) == (record_b.order_value, record_b.id)
-                    })
-                    .map(api::rest::Record::from)
-                    .take(limit)
-                    .collect_vec()
+                    });
+
+                if order_by.then_by.is_empty() {
+                    merged
+                        .map(api::rest::Record::from)
+                        .take(limit)
+                        .collect_vec()
+                } else {
+                    let with_payload = WithPayload::from(with_payload);
+                    let merged =
+                        OrderBy::take_with_ties(merged, limit, |record| record.order_value);
+                    order_by
+                        .sort_by_then_by(merged, limit, |record| {
+                            (record.order_value, record.id, record.payload.as_ref())
+                        })
+                        .into_iter()
+                        .map(|mut record| {
+                            record.payload = with_payload.select(record.payload.take());
+                            api::rest::Record::from(record)
+                        })
+                        .collect_vec()
+                }
             }
         };
 
@@ -393,6 +478,7 @@ impl Collection {
         Ok(ScrollResult {
             points,
             next_page_offset,
+            view_id,
         })
     }
 
@@ -423,12 +509,15 @@ impl Collection {
             })
             .collect();
 
-        let mut count = 0;
+        let mut result: Option<CountResult> = None;
         while let Some(response) = requests.try_next().await? {
-            count += response.count;
+            result = Some(match result {
+                Some(result) => result.merge_disjoint(response),
+                None => response,
+            });
         }
 
-        Ok(CountResult { count })
+        Ok(result.unwrap_or_default())
     }
 
     pub async fn retrieve(
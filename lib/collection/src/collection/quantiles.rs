@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use api::rest::QuantilesRequest;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use futures::TryStreamExt;
+use futures::stream::FuturesUnordered;
+use segment::data_types::quantiles::{QuantileSketch, Quantiles};
+use segment::json_path::JsonPath;
+use segment::types::Filter;
+
+use super::Collection;
+use crate::operations::types::CollectionResult;
+
+/// Request to estimate quantiles of a numeric payload field
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollectionQuantilesRequest {
+    pub key: JsonPath,
+    pub quantiles: Vec<f64>,
+    pub filter: Option<Filter>,
+}
+
+impl CollectionQuantilesRequest {
+    pub const DEFAULT_QUANTILES: [f64; 2] = [0.5, 0.95];
+}
+
+impl From<QuantilesRequest> for CollectionQuantilesRequest {
+    fn from(request: QuantilesRequest) -> Self {
+        let QuantilesRequest {
+            key,
+            quantiles,
+            filter,
+        } = request;
+        Self {
+            key,
+            quantiles: quantiles.unwrap_or_else(|| Self::DEFAULT_QUANTILES.to_vec()),
+            filter,
+        }
+    }
+}
+
+impl Collection {
+    /// Estimate quantiles of the given numeric key from histograms of its payload index.
+    ///
+    /// Quantiles are estimated over replicas located on this peer only, so every shard must have
+    /// an active replica here.
+    pub async fn quantiles(
+        &self,
+        request: CollectionQuantilesRequest,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Quantiles> {
+        let CollectionQuantilesRequest {
+            key,
+            quantiles,
+            filter,
+        } = request;
+
+        let timeout = timeout.unwrap_or(self.shared_storage_config.search_timeout);
+
+        let shard_holder = self.shards_holder.read().await;
+
+        let mut shard_sketches = shard_holder
+            .all_shards()
+            .map(|shard| {
+                let key = key.clone();
+                let filter = filter.clone();
+                let hw_measurement_acc = hw_measurement_acc.clone();
+                shard.execute_local_aggregation(async move |local_shard, search_runtime| {
+                    local_shard
+                        .quantile_sketch(key, filter, search_runtime, timeout, hw_measurement_acc)
+                        .await
+                })
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        let mut sketch = QuantileSketch::default();
+        while let Some(shard_sketch) = shard_sketches.try_next().await? {
+            sketch.merge(shard_sketch);
+        }
+
+        Ok(sketch.finish(&quantiles))
+    }
+}
@@ -8,9 +8,11 @@ use itertools::{Either, Itertools};
 use rand::Rng;
 use segment::common::reciprocal_rank_fusion::rrf_scoring;
 use segment::common::score_fusion::{ScoreFusion, score_fusion};
+use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::VectorStructInternal;
-use segment::types::{Order, ScoredPoint, WithPayloadInterface, WithVector};
+use segment::types::{Order, ScoredPoint, WithPayload, WithPayloadInterface, WithVector};
 use segment::utils::scored_point_ties::ScoredPointTies;
+use shard::query::query_enum::QueryEnum;
 use tokio::time::Instant;
 
 use super::Collection;
@@ -20,6 +22,8 @@ use crate::common::batching::batch_requests;
 use crate::common::fetch_vectors::{
     build_vector_resolver_queries, resolve_referenced_vectors_batch,
 };
+use crate::common::query_cache::QueryCacheKey;
+use crate::common::query_coalescer::{CoalescedQuery, QueryFollower};
 use crate::common::retrieve_request_trait::RetrieveRequest;
 use crate::common::transpose_iterator::transposed_iter;
 use crate::operations::consistency_params::ReadConsistency;
@@ -54,7 +58,7 @@ impl Collection {
             return Ok(vec![]);
         }
         let results = self
-            .do_query_batch(
+            .do_query_batch_cached(
                 vec![request],
                 read_consistency,
                 shard_selection,
@@ -198,6 +202,184 @@ impl Collection {
         future::try_join_all(all_searches).await
     }
 
+    /// Serve queries from the result cache, if it is enabled, and query the collection for the rest.
+    async fn do_query_batch_cached(
+        &self,
+        requests_batch: Vec<ShardQueryRequest>,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
+        let requests_batch = self
+            .apply_default_search_params_to_queries(requests_batch)
+            .await;
+        let requests_batch = self
+            .apply_non_finite_policy_to_queries(requests_batch)
+            .await?;
+
+        let query_cache = self.query_cache.read().clone();
+        let Some(query_cache) = query_cache else {
+            return self
+                .do_query_batch_coalesced(
+                    requests_batch,
+                    read_consistency,
+                    shard_selection,
+                    timeout,
+                    hw_measurement_acc,
+                )
+                .await;
+        };
+
+        // Must be taken before querying, so results are not cached if a write happens meanwhile
+        let generation = query_cache.generation();
+
+        let mut results = Vec::with_capacity(requests_batch.len());
+        let mut missed = Vec::new();
+        let mut missed_requests = Vec::new();
+
+        for (idx, request) in requests_batch.into_iter().enumerate() {
+            let key = QueryCacheKey::new(&request, &shard_selection, read_consistency);
+            match key.as_ref().and_then(|key| query_cache.get(key)) {
+                Some(cached) => results.push(cached),
+                None => {
+                    results.push(Vec::new());
+                    missed.push((idx, key));
+                    missed_requests.push(request);
+                }
+            }
+        }
+
+        if missed_requests.is_empty() {
+            return Ok(results);
+        }
+
+        let missed_results = self
+            .do_query_batch_coalesced(
+                missed_requests,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await?;
+
+        for ((idx, key), points) in missed.into_iter().zip(missed_results) {
+            if let Some(key) = key {
+                query_cache.insert(key, generation, points.clone());
+            }
+            results[idx] = points;
+        }
+
+        Ok(results)
+    }
+
+    /// Wait for results of identical concurrent queries, if coalescing is enabled, and query the
+    /// collection for the rest.
+    async fn do_query_batch_coalesced(
+        &self,
+        requests_batch: Vec<ShardQueryRequest>,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
+        let Some(query_coalescer) = &self.query_coalescer else {
+            return self
+                .do_query_batch(
+                    requests_batch,
+                    read_consistency,
+                    shard_selection,
+                    timeout,
+                    hw_measurement_acc,
+                )
+                .await;
+        };
+
+        let mut results = vec![Vec::new(); requests_batch.len()];
+        let mut executed = Vec::new();
+        let mut executed_requests = Vec::new();
+        let mut waiting = Vec::new();
+        let mut followers = Vec::new();
+
+        for (idx, request) in requests_batch.into_iter().enumerate() {
+            let coalesced = QueryCacheKey::new(&request, &shard_selection, read_consistency)
+                .map(|key| query_coalescer.join(key));
+            match coalesced {
+                Some(CoalescedQuery::Follower(follower)) => {
+                    waiting.push((idx, request));
+                    followers.push(follower);
+                }
+                Some(CoalescedQuery::Leader(leader)) => {
+                    executed.push((idx, Some(leader)));
+                    executed_requests.push(request);
+                }
+                None => {
+                    executed.push((idx, None));
+                    executed_requests.push(request);
+                }
+            }
+        }
+
+        // Execute own queries before waiting for others, so queries waiting for each other
+        // can't deadlock
+        if !executed_requests.is_empty() {
+            let executed_results = self
+                .do_query_batch(
+                    executed_requests,
+                    read_consistency,
+                    shard_selection.clone(),
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+                .await?;
+
+            for ((idx, leader), points) in executed.into_iter().zip(executed_results) {
+                if let Some(leader) = leader {
+                    leader.finish(&points);
+                }
+                results[idx] = points;
+            }
+        }
+
+        if waiting.is_empty() {
+            return Ok(results);
+        }
+
+        let waited_results = future::join_all(followers.into_iter().map(QueryFollower::wait)).await;
+
+        // Identical queries failed or were cancelled, execute them here
+        let mut failed = Vec::new();
+        let mut failed_requests = Vec::new();
+        for ((idx, request), points) in waiting.into_iter().zip(waited_results) {
+            match points {
+                Some(points) => results[idx] = points,
+                None => {
+                    failed.push(idx);
+                    failed_requests.push(request);
+                }
+            }
+        }
+
+        if !failed_requests.is_empty() {
+            let failed_results = self
+                .do_query_batch(
+                    failed_requests,
+                    read_consistency,
+                    shard_selection,
+                    timeout,
+                    hw_measurement_acc,
+                )
+                .await?;
+
+            for (idx, points) in failed.into_iter().zip(failed_results) {
+                results[idx] = points;
+            }
+        }
+
+        Ok(results)
+    }
+
     /// This function is used to query the collection. It will return a list of scored points.
     async fn do_query_batch(
         &self,
@@ -301,6 +483,21 @@ impl Collection {
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
         let instant = Instant::now();
 
+        // Points are ordered by `then_by` keys with their payload, which is cut after merging
+        let mut requests_with_payload = Vec::with_capacity(requests_batch.len());
+        let requests_batch = requests_batch
+            .into_iter()
+            .map(|mut request| {
+                requests_with_payload.push(WithPayload::from(&request.with_payload));
+                if let Some(ScoringQuery::OrderBy(order_by)) = &request.query
+                    && !order_by.then_by.is_empty()
+                {
+                    request.with_payload = order_by.with_then_by_payload(&request.with_payload);
+                }
+                request
+            })
+            .collect_vec();
+
         let requests_batch = Arc::new(requests_batch);
 
         let all_shards_results = self
@@ -315,14 +512,15 @@ impl Collection {
 
         let results_f = transposed_iter(all_shards_results)
             .zip(requests_batch.iter())
-            .map(|(shards_results, request)| async {
+            .zip(requests_with_payload.iter())
+            .map(|((shards_results, request), with_payload)| async {
                 // shards_results shape: [num_shards, num_intermediate_results, num_points]
                 // merged_intermediates shape: [num_intermediate_results, num_points]
                 let merged_intermediates = self
                     .merge_intermediate_results_from_shards(request, shards_results)
                     .await?;
 
-                let result = self
+                let mut result = self
                     .intermediates_to_final_list(
                         merged_intermediates,
                         request,
@@ -331,9 +529,19 @@ impl Collection {
                     )
                     .await?;
 
+                if let Some(ScoringQuery::OrderBy(order_by)) = &request.query
+                    && !order_by.then_by.is_empty()
+                {
+                    for point in &mut result {
+                        point.payload = with_payload.select(point.payload.take());
+                    }
+                }
+
                 let filter_refs = request.filter_refs();
                 self.post_process_if_slow_request(instant.elapsed(), filter_refs);
 
+                self.observe_score_drift(request, &result);
+
                 Ok::<_, CollectionError>(result)
             });
         let results = future::try_join_all(results_f).await?;
@@ -341,6 +549,26 @@ impl Collection {
         Ok(results)
     }
 
+    /// Feed the best score of a nearest neighbors query into the score drift detector
+    fn observe_score_drift(&self, request: &ShardQueryRequest, result: &[ScoredPoint]) {
+        let Some(score_drift) = &self.score_drift else {
+            return;
+        };
+
+        // Scores of other queries are not comparable with plain similarity
+        let Some(ScoringQuery::Vector(QueryEnum::Nearest(query))) = &request.query else {
+            return;
+        };
+
+        if request.offset > 0 {
+            return;
+        }
+
+        if let Some(top) = result.first() {
+            score_drift.observe(query.get_name(), top.score);
+        }
+    }
+
     /// Resolves the final list of scored points from the intermediate results.
     ///
     /// Finalizes queries like fusion and mmr after collecting from all shards.
@@ -424,7 +652,8 @@ impl Collection {
             | Some(ScoringQuery::Vector(_))
             | Some(ScoringQuery::OrderBy(_))
             | Some(ScoringQuery::Formula(_))
-            | Some(ScoringQuery::Sample(_)) => {
+            | Some(ScoringQuery::Sample(_))
+            | Some(ScoringQuery::VectorSelection(_)) => {
                 // Otherwise, it will be a list with a single list of scored points.
                 debug_assert_eq!(intermediates.len(), 1);
                 intermediates.pop().ok_or_else(|| {
@@ -505,7 +734,7 @@ impl Collection {
                     return Ok(());
                 }
 
-                futures.push(self.do_query_batch(
+                futures.push(self.do_query_batch_cached(
                     shard_requests,
                     read_consistency,
                     shard_selection,
@@ -655,7 +884,7 @@ impl Collection {
             let intermediate_result = if let Some(order) = order {
                 let best_last_result = Self::get_best_last_shard_result(&shards_results, order);
 
-                let merged: Vec<_> = match order {
+                let merged = match order {
                     Order::LargeBetter => Either::Left(
                         shards_results
                             .into_iter()
@@ -667,9 +896,19 @@ impl Collection {
                             .kmerge_by(|a, b| ScoredPointTies(a) < ScoredPointTies(b)),
                     ),
                 }
-                .dedup()
-                .take(query_info.take)
-                .collect();
+                .dedup();
+
+                let merged: Vec<_> = match query_info.scoring_query {
+                    Some(ScoringQuery::OrderBy(order_by)) if !order_by.then_by.is_empty() => {
+                        let merged = OrderBy::take_with_ties(merged, query_info.take, |point| {
+                            point.order_value
+                        });
+                        order_by.sort_by_then_by(merged, query_info.take, |point| {
+                            (point.order_value, point.id, point.payload.as_ref())
+                        })
+                    }
+                    _ => merged.take(query_info.take).collect(),
+                };
 
                 // Prevents undersampling warning in case there are not enough data to merge.
                 let is_enough = merged.len() == query_info.take;
@@ -737,7 +976,8 @@ fn intermediate_query_infos(request: &ShardQueryRequest) -> Vec<IntermediateQuer
         | Some(ScoringQuery::Vector(_))
         | Some(ScoringQuery::OrderBy(_))
         | Some(ScoringQuery::Formula(_))
-        | Some(ScoringQuery::Sample(_)) => {
+        | Some(ScoringQuery::Sample(_))
+        | Some(ScoringQuery::VectorSelection(_)) => {
             // Otherwise, we expect the root result
             vec![IntermediateQueryInfo {
                 scoring_query: request.query.as_ref(),
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+use super::Collection;
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::PointOperations;
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::vector_ops::VectorOperations;
+
+/// Limits on the size of each collection, configured by the operator of the node.
+///
+/// Unlike strict mode limits, quotas can't be changed through the API, so they can be used
+/// to cap the growth of collections of a tenant.
+#[derive(Debug, Default, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CollectionQuotaConfig {
+    /// Max number of points in a collection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_points_count: Option<usize>,
+    /// Max size of all vectors in a collection, in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_vectors_size_bytes: Option<usize>,
+}
+
+impl CollectionQuotaConfig {
+    fn is_unlimited(&self) -> bool {
+        self.max_points_count.is_none() && self.max_vectors_size_bytes.is_none()
+    }
+
+    fn check(&self, points_count: usize, vectors_size_bytes: usize) -> CollectionResult<()> {
+        if let Some(max_points_count) = self.max_points_count
+            && points_count >= max_points_count
+        {
+            return Err(CollectionError::quota_exceeded(format!(
+                "Collection has {points_count} points, quota is {max_points_count} points",
+            )));
+        }
+
+        if let Some(max_vectors_size_bytes) = self.max_vectors_size_bytes
+            && vectors_size_bytes >= max_vectors_size_bytes
+        {
+            return Err(CollectionError::quota_exceeded(format!(
+                "Collection has {vectors_size_bytes} bytes of vectors, \
+                 quota is {max_vectors_size_bytes} bytes",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Collection {
+    /// Reject operations, which may grow the collection, once it has reached the quota.
+    ///
+    /// Size of the collection is estimated and cached, so the quota may be exceeded slightly.
+    pub(super) async fn check_collection_quota(
+        &self,
+        operation: &CollectionUpdateOperations,
+    ) -> CollectionResult<()> {
+        let quota = self.shared_storage_config.collection_quota;
+        if quota.is_unlimited() || !operation_may_grow(operation) {
+            return Ok(());
+        }
+
+        let Some(stats) = self.estimated_collection_stats().await? else {
+            return Ok(());
+        };
+
+        quota.check(stats.get_points_count(), stats.get_vector_storage_size())
+    }
+}
+
+/// Whether the operation may add points or vectors to the collection
+fn operation_may_grow(operation: &CollectionUpdateOperations) -> bool {
+    match operation {
+        CollectionUpdateOperations::PointOperation(operation) => match operation {
+            PointOperations::UpsertPoints(_)
+            | PointOperations::UpsertPointsConditional(_)
+            | PointOperations::SyncPoints(_) => true,
+            PointOperations::DeletePoints { .. } | PointOperations::DeletePointsByFilter(_) => {
+                false
+            }
+        },
+        CollectionUpdateOperations::VectorOperation(operation) => match operation {
+            VectorOperations::UpdateVectors(_) | VectorOperations::AppendVectors(_) => true,
+            VectorOperations::DeleteVectors(..) | VectorOperations::DeleteVectorsByFilter(..) => {
+                false
+            }
+        },
+        CollectionUpdateOperations::PayloadOperation(_)
+        | CollectionUpdateOperations::FieldIndexOperation(_) => false,
+        #[cfg(feature = "staging")]
+        CollectionUpdateOperations::StagingOperation(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collection_quota() {
+        assert!(CollectionQuotaConfig::default().is_unlimited());
+
+        let quota = CollectionQuotaConfig {
+            max_points_count: Some(100),
+            max_vectors_size_bytes: Some(4096),
+        };
+
+        assert!(quota.check(99, 4095).is_ok());
+        assert!(matches!(
+            quota.check(100, 0),
+            Err(CollectionError::QuotaExceeded { .. }),
+        ));
+        assert!(matches!(
+            quota.check(0, 4096),
+            Err(CollectionError::QuotaExceeded { .. }),
+        ));
+    }
+}
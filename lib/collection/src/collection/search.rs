@@ -151,7 +151,8 @@ impl Collection {
         timeout: Option<Duration>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
-        let request = Arc::new(request);
+        let request = self.apply_default_search_params_to_searches(request).await;
+        let request = Arc::new(self.apply_non_finite_policy_to_searches(request).await?);
 
         let instant = Instant::now();
 
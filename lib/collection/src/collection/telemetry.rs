@@ -1,9 +1,11 @@
 use std::time::Duration;
 
 use common::types::{DetailsLevel, TelemetryDetail};
+use segment::common::operation_time_statistics::OperationDurationStatistics;
 
 use crate::collection::Collection;
 use crate::operations::types::CollectionResult;
+use crate::shards::shard::ShardId;
 use crate::telemetry::{CollectionConfigTelemetry, CollectionTelemetry};
 
 impl Collection {
@@ -34,6 +36,11 @@ impl Collection {
         };
 
         let shard_clean_tasks = self.clean_local_shards_statuses();
+        let query_cache = self
+            .query_cache
+            .read()
+            .as_ref()
+            .map(|query_cache| query_cache.get_telemetry_data());
 
         Ok(CollectionTelemetry {
             id: self.name().to_string(),
@@ -45,6 +52,25 @@ impl Collection {
             transfers,
             resharding,
             shard_clean_tasks: (!shard_clean_tasks.is_empty()).then_some(shard_clean_tasks),
+            query_cache,
+            query_coalescer: self
+                .query_coalescer
+                .as_ref()
+                .map(|query_coalescer| query_coalescer.get_telemetry_data()),
+            score_drift: self
+                .score_drift
+                .as_ref()
+                .map(|score_drift| score_drift.get_telemetry_data()),
         })
     }
+
+    /// Statistics of read operations started on this peer, per shard
+    pub async fn get_read_statistics(&self) -> Vec<(ShardId, OperationDurationStatistics)> {
+        self.shards_holder
+            .read()
+            .await
+            .get_shards()
+            .map(|(shard_id, replica_set)| (shard_id, replica_set.get_read_statistics()))
+            .collect()
+    }
 }
@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use api::rest::TermStatsRequest;
+use futures::TryStreamExt;
+use futures::stream::FuturesUnordered;
+use segment::data_types::term_stats::{TermStats, TermStatsAggregator};
+use segment::json_path::JsonPath;
+
+use super::Collection;
+use crate::operations::types::CollectionResult;
+
+/// Request for term statistics of a full-text indexed payload field
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollectionTermStatsRequest {
+    pub key: JsonPath,
+    pub limit: usize,
+}
+
+impl CollectionTermStatsRequest {
+    pub const DEFAULT_LIMIT: usize = 10;
+}
+
+impl From<TermStatsRequest> for CollectionTermStatsRequest {
+    fn from(request: TermStatsRequest) -> Self {
+        let TermStatsRequest { key, limit } = request;
+        Self {
+            key,
+            limit: limit.unwrap_or(Self::DEFAULT_LIMIT),
+        }
+    }
+}
+
+impl Collection {
+    /// Most frequent terms and the distribution of document lengths of the given full-text key.
+    ///
+    /// Statistics are computed over replicas located on this peer only, so every shard must have
+    /// an active replica here.
+    pub async fn term_stats(
+        &self,
+        request: CollectionTermStatsRequest,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<TermStats> {
+        let CollectionTermStatsRequest { key, limit } = request;
+
+        let timeout = timeout.unwrap_or(self.shared_storage_config.search_timeout);
+
+        let shard_holder = self.shards_holder.read().await;
+
+        let mut shard_stats = shard_holder
+            .all_shards()
+            .map(|shard| {
+                let key = key.clone();
+                shard.execute_local_aggregation(async move |local_shard, search_runtime| {
+                    local_shard.term_stats(key, search_runtime, timeout).await
+                })
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        let mut stats = TermStatsAggregator::default();
+        while let Some(shard_stats) = shard_stats.try_next().await? {
+            stats.merge(shard_stats);
+        }
+
+        Ok(stats.finish(limit))
+    }
+}
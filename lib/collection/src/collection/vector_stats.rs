@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use api::rest::VectorStatsRequest;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use futures::TryStreamExt;
+use futures::stream::FuturesUnordered;
+use segment::data_types::vector_stats::{VectorStats, VectorStatsAccumulator};
+use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+use segment::types::VectorNameBuf;
+
+use super::Collection;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+/// Request to compute statistics over a random sample of vectors
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollectionVectorStatsRequest {
+    pub using: VectorNameBuf,
+    pub sample_size: usize,
+    pub histogram_buckets: usize,
+}
+
+impl CollectionVectorStatsRequest {
+    pub const DEFAULT_SAMPLE: usize = 1000;
+    pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 10;
+    pub const MAX_SAMPLE: usize = 100_000;
+}
+
+impl Default for CollectionVectorStatsRequest {
+    fn default() -> Self {
+        Self {
+            using: DEFAULT_VECTOR_NAME.to_owned(),
+            sample_size: Self::DEFAULT_SAMPLE,
+            histogram_buckets: Self::DEFAULT_HISTOGRAM_BUCKETS,
+        }
+    }
+}
+
+impl From<VectorStatsRequest> for CollectionVectorStatsRequest {
+    fn from(request: VectorStatsRequest) -> Self {
+        let VectorStatsRequest {
+            using,
+            sample,
+            histogram_buckets,
+        } = request;
+        Self {
+            using: using.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned()),
+            sample_size: sample.unwrap_or(Self::DEFAULT_SAMPLE),
+            histogram_buckets: histogram_buckets.unwrap_or(Self::DEFAULT_HISTOGRAM_BUCKETS),
+        }
+    }
+}
+
+impl Collection {
+    /// Per-dimension mean and variance, and the distribution of norms of the given vector.
+    ///
+    /// Statistics are computed over replicas located on this peer only, so every shard must have
+    /// an active replica here.
+    pub async fn vector_stats(
+        &self,
+        request: CollectionVectorStatsRequest,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<VectorStats> {
+        let CollectionVectorStatsRequest {
+            using,
+            sample_size,
+            histogram_buckets,
+        } = request;
+
+        if sample_size > CollectionVectorStatsRequest::MAX_SAMPLE {
+            return Err(CollectionError::bad_input(format!(
+                "Sample size must not exceed {}",
+                CollectionVectorStatsRequest::MAX_SAMPLE,
+            )));
+        }
+
+        self.collection_config
+            .read()
+            .await
+            .params
+            .check_vector_exists(&using)?;
+
+        let timeout = timeout.unwrap_or(self.shared_storage_config.search_timeout);
+
+        let shard_holder = self.shards_holder.read().await;
+        let shards = shard_holder.all_shards().collect::<Vec<_>>();
+        if shards.is_empty() {
+            return Ok(VectorStatsAccumulator::default().finish(histogram_buckets));
+        }
+
+        // Local shards are expected to be of similar size, so split the sample evenly
+        let shard_sample = sample_size.div_ceil(shards.len());
+
+        let mut shard_stats = shards
+            .into_iter()
+            .map(|shard| {
+                let using = using.clone();
+                let hw_measurement_acc = hw_measurement_acc.clone();
+                shard.execute_local_aggregation(async move |local_shard, search_runtime| {
+                    local_shard
+                        .sample_vector_stats(
+                            using,
+                            shard_sample,
+                            search_runtime,
+                            timeout,
+                            hw_measurement_acc,
+                        )
+                        .await
+                })
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        let mut stats = VectorStatsAccumulator::default();
+        while let Some(shard_stats) = shard_stats.try_next().await? {
+            stats.merge(shard_stats)?;
+        }
+
+        Ok(stats.finish(histogram_buckets))
+    }
+}
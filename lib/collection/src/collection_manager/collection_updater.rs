@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use common::counter::hardware_counter::HardwareCounterCell;
-use segment::types::SeqNumberType;
+use segment::common::operation_error::OperationResult;
+use segment::types::{PointIdType, SeqNumberType};
 use shard::segment_holder::locked::LockedSegmentHolder;
 use shard::update::*;
 
 use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::ReturnIds;
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::shards::update_tracker::UpdateTracker;
 
@@ -14,10 +16,10 @@ use crate::shards::update_tracker::UpdateTracker;
 pub struct CollectionUpdater {}
 
 impl CollectionUpdater {
-    fn handle_update_result(
+    fn handle_update_result<T>(
         segments: &LockedSegmentHolder,
         op_num: SeqNumberType,
-        operation_result: &CollectionResult<usize>,
+        operation_result: &CollectionResult<T>,
     ) {
         match operation_result {
             Ok(_) => {
@@ -46,8 +48,33 @@ impl CollectionUpdater {
         update_tracker: UpdateTracker,
         hw_counter: &HardwareCounterCell,
     ) -> CollectionResult<usize> {
+        Self::update_returning_ids(
+            segments,
+            op_num,
+            operation,
+            None,
+            update_operation_lock,
+            update_tracker,
+            hw_counter,
+        )
+        .map(|(updated, _)| updated)
+    }
+
+    /// Apply the operation, and select ids of the points matching its filter if requested.
+    ///
+    /// Ids are selected under the same locks as the operation is applied with,
+    /// so no concurrent update can change the set of affected points in between.
+    pub fn update_returning_ids(
+        segments: &LockedSegmentHolder,
+        op_num: SeqNumberType,
+        operation: CollectionUpdateOperations,
+        return_ids: Option<&ReturnIds>,
+        update_operation_lock: Arc<tokio::sync::RwLock<()>>,
+        update_tracker: UpdateTracker,
+        hw_counter: &HardwareCounterCell,
+    ) -> CollectionResult<(usize, Option<Vec<PointIdType>>)> {
         // Use block_in_place here to avoid blocking the current async executor
-        let operation_result = tokio::task::block_in_place(|| {
+        let operation_result = tokio::task::block_in_place(|| -> OperationResult<_> {
             // Allow only one update at a time, ensure no data races between segments.
             // let _update_lock = self.update_lock.lock().unwrap();
 
@@ -60,7 +87,13 @@ impl CollectionUpdater {
 
             let segments_guard = segments.read();
 
-            match operation {
+            let affected_ids = return_ids
+                .map(|return_ids| {
+                    select_affected_ids(&segments_guard, &operation, return_ids, hw_counter)
+                })
+                .transpose()?;
+
+            let updated = match operation {
                 CollectionUpdateOperations::PointOperation(point_operation) => {
                     process_point_operation(&segments_guard, op_num, point_operation, hw_counter)
                 }
@@ -91,7 +124,9 @@ impl CollectionUpdater {
                         staging_operation,
                     )
                 }
-            }
+            }?;
+
+            Ok((updated, affected_ids))
         });
 
         let operation_result = operation_result.map_err(CollectionError::from);
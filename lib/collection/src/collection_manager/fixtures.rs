@@ -230,6 +230,7 @@ pub(crate) fn get_merge_optimizer(
 ) -> MergeOptimizer {
     MergeOptimizer::new(
         5,
+        None,
         optimizer_thresholds.unwrap_or(OptimizerThresholds {
             max_segment_size_kb: 100_000,
             memmap_threshold_kb: 1_000_000,
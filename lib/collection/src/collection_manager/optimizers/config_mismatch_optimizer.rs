@@ -10,7 +10,7 @@ use segment::index::sparse_index::sparse_index_config::SparseIndexType;
 use segment::types::{HnswConfig, HnswGlobalConfig, Indexes, QuantizationConfig, VectorName};
 
 use crate::collection_manager::optimizers::segment_optimizer::{
-    OptimizationPlanner, OptimizerThresholds, SegmentOptimizer,
+    OptimizationPhaseDurations, OptimizationPlanner, OptimizerThresholds, SegmentOptimizer,
 };
 use crate::config::CollectionParams;
 use crate::operations::config_diff::DiffConfig;
@@ -29,6 +29,7 @@ pub struct ConfigMismatchOptimizer {
     hnsw_global_config: HnswGlobalConfig,
     quantization_config: Option<QuantizationConfig>,
     telemetry_durations_aggregator: Arc<Mutex<OperationDurationsAggregator>>,
+    phase_durations_aggregator: OptimizationPhaseDurations,
 }
 
 impl ConfigMismatchOptimizer {
@@ -50,6 +51,7 @@ impl ConfigMismatchOptimizer {
             hnsw_global_config,
             quantization_config,
             telemetry_durations_aggregator: OperationDurationsAggregator::new(),
+            phase_durations_aggregator: OptimizationPhaseDurations::default(),
         }
     }
 
@@ -80,6 +82,18 @@ impl ConfigMismatchOptimizer {
             return true; // Optimize segment due to payload storage mismatch
         }
 
+        // Group points by tenant keys, which were not known when the segment was built.
+        // Segments built before the keys were recorded are left as is.
+        if let Some(defragment_keys) = &segment_config.defragment_keys {
+            let has_new_tenant_key = segment
+                .get_indexed_fields()
+                .iter()
+                .any(|(key, schema)| schema.is_tenant() && !defragment_keys.contains(key));
+            if has_new_tenant_key {
+                return true;
+            }
+        }
+
         // Determine whether dense data in segment has mismatch
         let dense_has_mismatch =
             segment_config
@@ -228,16 +242,23 @@ impl SegmentOptimizer for ConfigMismatchOptimizer {
     fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator> {
         &self.telemetry_durations_aggregator
     }
+
+    fn get_phase_telemetry_counters(&self) -> &OptimizationPhaseDurations {
+        &self.phase_durations_aggregator
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
 
+    use common::counter::hardware_counter::HardwareCounterCell;
+    use segment::data_types::index::KeywordIndexParams;
     use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+    use segment::json_path::JsonPath;
     use segment::types::{
-        CompressionRatio, Distance, ProductQuantization, ProductQuantizationConfig,
-        ScalarQuantizationConfig, ScalarType,
+        CompressionRatio, Distance, PayloadFieldSchema, PayloadSchemaParams, ProductQuantization,
+        ProductQuantizationConfig, ScalarQuantizationConfig, ScalarType,
     };
     use shard::segment_holder::locked::LockedSegmentHolder;
     use tempfile::Builder;
@@ -301,6 +322,10 @@ mod tests {
             on_disk: None,
             payload_m: None,
             inline_storage: None,
+            varint_links: None,
+            m0: None,
+            ef_construct_upper: None,
+            neighbor_selection: None,
         };
 
         // Optimizers used in test
@@ -440,6 +465,10 @@ mod tests {
             on_disk: None,
             payload_m: None,
             inline_storage: None,
+            varint_links: None,
+            m0: None,
+            ef_construct_upper: None,
+            neighbor_selection: None,
         };
 
         // Optimizers used in test
@@ -675,4 +704,124 @@ mod tests {
                 );
             });
     }
+
+    /// This tests the config mismatch optimizer for a tenant index created after the segment was built
+    ///
+    /// In short, this is what happens in this test:
+    /// - create randomized segment as base
+    /// - use indexing optimizer to build index for our segment, no tenant keys are recorded
+    /// - test config mismatch condition: should not trigger yet
+    /// - create tenant index on the optimized segment
+    /// - test config mismatch condition: should trigger due to the new tenant key
+    /// - optimize segment with config mismatch optimizer
+    /// - test config mismatch condition: should not trigger, the tenant key is recorded now
+    #[test]
+    fn test_new_tenant_key_mismatch() {
+        // Collection configuration
+        let (point_count, dim) = (1000, 10);
+        let thresholds_config = OptimizerThresholds {
+            max_segment_size_kb: usize::MAX,
+            memmap_threshold_kb: usize::MAX,
+            indexing_threshold_kb: 10,
+        };
+        let collection_params = CollectionParams {
+            vectors: VectorsConfig::Single(
+                VectorParamsBuilder::new(dim as u64, Distance::Dot).build(),
+            ),
+            ..CollectionParams::empty()
+        };
+
+        // Base segment
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let mut holder = SegmentHolder::default();
+
+        let segment = random_segment(dir.path(), 100, point_count, dim as usize);
+
+        let segment_id = holder.add_new(segment);
+        let locked_holder = LockedSegmentHolder::new(holder);
+
+        // Optimizers used in test
+        let index_optimizer = IndexingOptimizer::new(
+            2,
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params.clone(),
+            HnswConfig::default(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+        let config_mismatch_optimizer = ConfigMismatchOptimizer::new(
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params,
+            HnswConfig::default(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+
+        let changed = index_optimizer.optimize_for_test(locked_holder.clone(), vec![segment_id]);
+        assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+        let (optimized_id, optimized_segment) = locked_holder
+            .read()
+            .iter_original()
+            .find(|(_, segment)| segment.read().config().defragment_keys.is_some())
+            .map(|(id, segment)| (id, segment.clone()))
+            .expect("optimized segment must record its defragmentation keys");
+
+        // No tenant keys yet, nothing to optimize
+        let suggested_to_optimize =
+            config_mismatch_optimizer.plan_optimizations_for_test(&locked_holder);
+        assert_eq!(suggested_to_optimize.len(), 0);
+
+        // Create a tenant index, which the optimized segment was not grouped by
+        let tenant_key = JsonPath::new("tenant");
+        let tenant_schema =
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(KeywordIndexParams {
+                is_tenant: Some(true),
+                ..Default::default()
+            }));
+        {
+            let mut segment = optimized_segment.write();
+            let op_num = segment.version() + 1;
+            segment
+                .create_field_index(
+                    op_num,
+                    &tenant_key,
+                    Some(&tenant_schema),
+                    &HardwareCounterCell::new(),
+                )
+                .unwrap();
+        }
+
+        // Segment is missing the new tenant key, it must be rebuilt
+        let suggested_to_optimize =
+            config_mismatch_optimizer.plan_optimizations_for_test(&locked_holder);
+        let suggested_to_optimize = suggested_to_optimize.into_iter().exactly_one().unwrap();
+        assert_eq!(suggested_to_optimize, vec![optimized_id]);
+
+        let changed = config_mismatch_optimizer
+            .optimize_for_test(locked_holder.clone(), suggested_to_optimize);
+        assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+        // Rebuilt segment has the tenant key already, nothing to optimize
+        locked_holder
+            .read()
+            .iter_original()
+            .map(|(_, segment)| segment.read())
+            .filter(|segment| segment.total_point_count() > 0)
+            .for_each(|segment| {
+                assert_eq!(
+                    segment.config().defragment_keys,
+                    Some(vec![tenant_key.clone()]),
+                    "segment must be grouped by the tenant key",
+                );
+            });
+        let suggested_to_optimize =
+            config_mismatch_optimizer.plan_optimizations_for_test(&locked_holder);
+        assert_eq!(suggested_to_optimize.len(), 0);
+    }
 }
@@ -10,7 +10,7 @@ use segment::types::{HnswConfig, HnswGlobalConfig, QuantizationConfig};
 
 use crate::collection_manager::holders::segment_holder::SegmentId;
 use crate::collection_manager::optimizers::segment_optimizer::{
-    OptimizationPlanner, OptimizerThresholds, SegmentOptimizer,
+    OptimizationPhaseDurations, OptimizationPlanner, OptimizerThresholds, SegmentOptimizer,
 };
 use crate::config::CollectionParams;
 
@@ -31,6 +31,7 @@ pub struct IndexingOptimizer {
     hnsw_global_config: HnswGlobalConfig,
     quantization_config: Option<QuantizationConfig>,
     telemetry_durations_aggregator: Arc<Mutex<OperationDurationsAggregator>>,
+    phase_durations_aggregator: OptimizationPhaseDurations,
 }
 
 impl IndexingOptimizer {
@@ -55,6 +56,7 @@ impl IndexingOptimizer {
             hnsw_global_config,
             quantization_config,
             telemetry_durations_aggregator: OperationDurationsAggregator::new(),
+            phase_durations_aggregator: OptimizationPhaseDurations::default(),
         }
     }
 
@@ -224,6 +226,10 @@ impl SegmentOptimizer for IndexingOptimizer {
     fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator> {
         &self.telemetry_durations_aggregator
     }
+
+    fn get_phase_telemetry_counters(&self) -> &OptimizationPhaseDurations {
+        &self.phase_durations_aggregator
+    }
 }
 
 #[cfg(test)]
@@ -771,6 +777,10 @@ mod tests {
             on_disk: None,
             payload_m: None,
             inline_storage: None,
+            varint_links: None,
+            m0: None,
+            ef_construct_upper: None,
+            neighbor_selection: None,
         };
 
         {
@@ -8,7 +8,7 @@ use segment::entry::NonAppendableSegmentEntry as _;
 use segment::types::{HnswConfig, HnswGlobalConfig, QuantizationConfig};
 
 use crate::collection_manager::optimizers::segment_optimizer::{
-    OptimizationPlanner, OptimizerThresholds, SegmentOptimizer,
+    OptimizationPhaseDurations, OptimizationPlanner, OptimizerThresholds, SegmentOptimizer,
 };
 use crate::config::CollectionParams;
 
@@ -42,8 +42,13 @@ const BYTES_IN_KB: usize = 1024;
 /// - good:  [A B C]      →  ∅ X    (one segment less)
 /// - good:  [A B] [C D]  →  ∅ X Y  (one segment less)
 /// ```
+///
+/// If there are more segments than the configured maximum, the size threshold
+/// is lifted, and the smallest segments are merged until the number of segments
+/// is back within the maximum.
 pub struct MergeOptimizer {
     default_segments_number: usize,
+    max_segments_number: Option<usize>,
     thresholds_config: OptimizerThresholds,
     segments_path: PathBuf,
     collection_temp_dir: PathBuf,
@@ -52,12 +57,14 @@ pub struct MergeOptimizer {
     hnsw_global_config: HnswGlobalConfig,
     quantization_config: Option<QuantizationConfig>,
     telemetry_durations_aggregator: Arc<Mutex<OperationDurationsAggregator>>,
+    phase_durations_aggregator: OptimizationPhaseDurations,
 }
 
 impl MergeOptimizer {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         default_segments_number: usize,
+        max_segments_number: Option<usize>,
         thresholds_config: OptimizerThresholds,
         segments_path: PathBuf,
         collection_temp_dir: PathBuf,
@@ -68,6 +75,7 @@ impl MergeOptimizer {
     ) -> Self {
         MergeOptimizer {
             default_segments_number,
+            max_segments_number,
             thresholds_config,
             segments_path,
             collection_temp_dir,
@@ -76,6 +84,7 @@ impl MergeOptimizer {
             hnsw_global_config,
             quantization_config,
             telemetry_durations_aggregator: OperationDurationsAggregator::new(),
+            phase_durations_aggregator: OptimizationPhaseDurations::default(),
         }
     }
 }
@@ -127,15 +136,25 @@ impl SegmentOptimizer for MergeOptimizer {
             .collect_vec();
 
         candidates.sort_by_key(|(_segment_id, size)| *size);
-        let threshold = self
+        let size_threshold = self
             .thresholds_config
             .max_segment_size_kb
             .saturating_mul(BYTES_IN_KB);
 
+        // Merge pressure: too many segments, merge them regardless of their size
+        let (target_segments_number, threshold) = match self.max_segments_number {
+            Some(max_segments_number)
+                if planner.expected_segments_number() > max_segments_number =>
+            {
+                (max_segments_number, usize::MAX)
+            }
+            _ => (self.default_segments_number, size_threshold),
+        };
+
         let mut first_batch = None;
         let mut taken_candidates = 0;
         let mut last_candidate =
-            (planner.expected_segments_number() + 2).saturating_sub(self.default_segments_number);
+            (planner.expected_segments_number() + 2).saturating_sub(target_segments_number);
         while taken_candidates < last_candidate.min(candidates.len()) {
             let batch = candidates[taken_candidates..last_candidate.min(candidates.len())]
                 .iter()
@@ -168,12 +187,17 @@ impl SegmentOptimizer for MergeOptimizer {
     fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator> {
         &self.telemetry_durations_aggregator
     }
+
+    fn get_phase_telemetry_counters(&self) -> &OptimizationPhaseDurations {
+        &self.phase_durations_aggregator
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
+    use common::types::TelemetryDetail;
     use rand::SeedableRng;
     use rand::rngs::StdRng;
     use rand::seq::SliceRandom;
@@ -218,6 +242,56 @@ mod tests {
         assert_eq!(check_result.len(), 3);
     }
 
+    #[test]
+    fn test_max_segments_number_merge_pressure() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+        let mut holder = SegmentHolder::default();
+        let dim = 256;
+
+        let segments_to_merge = [
+            holder.add_new(random_segment(dir.path(), 100, 40, dim)),
+            holder.add_new(random_segment(dir.path(), 100, 50, dim)),
+            holder.add_new(random_segment(dir.path(), 100, 60, dim)),
+        ];
+        let largest_segment = holder.add_new(random_segment(dir.path(), 100, 70, dim));
+
+        let mut merge_optimizer = get_merge_optimizer(dir.path(), temp_dir.path(), dim, None);
+        merge_optimizer.default_segments_number = 1;
+        merge_optimizer.thresholds_config.max_segment_size_kb = 100;
+
+        let locked_holder = LockedSegmentHolder::new(holder);
+
+        // Segments are too large to be merged
+        assert!(
+            merge_optimizer
+                .plan_optimizations_for_test(&locked_holder)
+                .is_empty(),
+        );
+
+        // Within the maximum, the size threshold still applies
+        merge_optimizer.max_segments_number = Some(4);
+        assert!(
+            merge_optimizer
+                .plan_optimizations_for_test(&locked_holder)
+                .is_empty(),
+        );
+
+        // Above the maximum, the smallest segments are merged regardless of their size
+        merge_optimizer.max_segments_number = Some(3);
+        let batch = merge_optimizer
+            .plan_optimizations_for_test(&locked_holder)
+            .into_iter()
+            .exactly_one()
+            .unwrap();
+        assert_eq!(batch.len(), 3);
+        for segment_id in &batch {
+            assert!(segments_to_merge.contains(segment_id));
+        }
+        assert!(!batch.contains(&largest_segment));
+    }
+
     #[test]
     fn test_merge_optimizer() {
         let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
@@ -283,6 +357,12 @@ mod tests {
 
         // Check if optimized segments removed from disk
         old_path.into_iter().for_each(|x| assert!(!x.exists()));
+
+        // Check both optimization phases are measured
+        let phases = merge_optimizer.get_phase_telemetry_counters();
+        let detail = TelemetryDetail::default();
+        assert_eq!(phases.build.lock().get_statistics(detail).count, 1);
+        assert_eq!(phases.finalize.lock().get_statistics(detail).count, 1);
     }
 
     #[rustfmt::skip]
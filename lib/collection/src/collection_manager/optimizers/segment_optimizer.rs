@@ -45,6 +45,23 @@ pub struct OptimizerThresholds {
     pub indexing_threshold_kb: usize,
 }
 
+/// Durations of the individual optimization phases
+pub struct OptimizationPhaseDurations {
+    /// Building the optimized segment, while updates are written into proxy segments
+    pub build: Arc<Mutex<OperationDurationsAggregator>>,
+    /// Applying pending changes and swapping segments, while updates are blocked
+    pub finalize: Arc<Mutex<OperationDurationsAggregator>>,
+}
+
+impl Default for OptimizationPhaseDurations {
+    fn default() -> Self {
+        Self {
+            build: OperationDurationsAggregator::new(),
+            finalize: OperationDurationsAggregator::new(),
+        }
+    }
+}
+
 /// SegmentOptimizer - trait implementing common functionality of the optimizers
 ///
 /// It provides functions which allow to re-build specified segments into a new, better one.
@@ -119,6 +136,9 @@ pub trait SegmentOptimizer {
 
     fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator>;
 
+    /// Get duration aggregators of the individual optimization phases
+    fn get_phase_telemetry_counters(&self) -> &OptimizationPhaseDurations;
+
     /// Build temp segment
     fn temp_segment(&self, save_version: bool) -> CollectionResult<LockedSegment> {
         let collection_params = self.collection_params();
@@ -127,6 +147,7 @@ pub trait SegmentOptimizer {
             vector_data: collection_params.to_base_vector_data(quantization_config.as_ref())?,
             sparse_vector_data: collection_params.to_sparse_vector_data()?,
             payload_storage_type: collection_params.payload_storage_type(),
+            defragment_keys: None,
         };
         Ok(LockedSegment::new(build_segment(
             self.segments_path(),
@@ -368,6 +389,7 @@ pub trait SegmentOptimizer {
             vector_data,
             sparse_vector_data,
             payload_storage_type: collection_params.payload_storage_type(),
+            defragment_keys: None,
         };
 
         Ok(SegmentBuilder::new(
@@ -619,6 +641,19 @@ pub trait SegmentOptimizer {
     ///
     /// It will merge multiple segments into a single new segment.
     ///
+    /// # Phases
+    ///
+    /// Optimization hands over the segments in two phases, each measured in
+    /// [`OptimizationPhaseDurations`]:
+    ///
+    /// 1. Build: optimized segments are wrapped into proxies, and the new segment is built
+    ///    from them. Updates are not blocked, they are written into another appendable segment,
+    ///    while proxies only record deletes and index changes. Vector indexes are built with
+    ///    all CPUs of the acquired resource permit.
+    /// 2. Finalize: updates are blocked, changes recorded by proxies are applied to the new
+    ///    segment, and the proxies are swapped with it. This only takes time proportional to
+    ///    the changes made during the build phase.
+    ///
     /// # Result
     ///
     /// New optimized segment should be added into `segments`.
@@ -759,6 +794,8 @@ pub trait SegmentOptimizer {
         };
 
         // SLOW PART: create single optimized segment and propagate all new changes to it
+        let mut build_timer =
+            ScopeDurationMeasurer::new(&self.get_phase_telemetry_counters().build);
         let result = self.optimize_segment_propagate_changes(
             input_segments,
             output_segment_uuid,
@@ -769,6 +806,8 @@ pub trait SegmentOptimizer {
             &hw_counter,
             progress,
         );
+        build_timer.set_success(result.is_ok());
+        drop(build_timer);
 
         let (optimized_segment, deleted_points) = match result {
             Ok(segment) => segment,
@@ -781,7 +820,9 @@ pub trait SegmentOptimizer {
         };
 
         // Fast part: blocks updates, propagates rest of the changes, swaps optimized segment
-        let points_count = match self.finish_optimization(
+        let mut finalize_timer =
+            ScopeDurationMeasurer::new(&self.get_phase_telemetry_counters().finalize);
+        let result = self.finish_optimization(
             &segment_holder,
             locked_proxies,
             optimized_segment,
@@ -790,7 +831,11 @@ pub trait SegmentOptimizer {
             cow_segment_id_opt,
             stopped,
             &hw_counter,
-        ) {
+        );
+        finalize_timer.set_success(result.is_ok());
+        drop(finalize_timer);
+
+        let points_count = match result {
             Ok(points_count) => points_count,
             Err(err) => {
                 // Properly cancel optimization on all error kinds
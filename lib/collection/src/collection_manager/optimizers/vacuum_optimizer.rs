@@ -12,7 +12,7 @@ use segment::types::{HnswConfig, HnswGlobalConfig, QuantizationConfig};
 use segment::vector_storage::VectorStorage;
 
 use crate::collection_manager::optimizers::segment_optimizer::{
-    OptimizationPlanner, OptimizerThresholds, SegmentOptimizer,
+    OptimizationPhaseDurations, OptimizationPlanner, OptimizerThresholds, SegmentOptimizer,
 };
 use crate::config::CollectionParams;
 
@@ -20,6 +20,8 @@ use crate::config::CollectionParams;
 ///
 /// Since the creation of a segment, a lot of points or vectors may have been soft-deleted. This
 /// results in the index slowly breaking apart, and unnecessary storage usage.
+/// Deletes and updates also fragment storages of mutable payload indexes, which are compacted by
+/// rebuilding the segment as well.
 ///
 /// This optimizer will look for the worst segment to rebuilt the index and minimize storage usage.
 pub struct VacuumOptimizer {
@@ -33,6 +35,7 @@ pub struct VacuumOptimizer {
     hnsw_global_config: HnswGlobalConfig,
     quantization_config: Option<QuantizationConfig>,
     telemetry_durations_aggregator: Arc<Mutex<OperationDurationsAggregator>>,
+    phase_durations_aggregator: OptimizationPhaseDurations,
 }
 
 impl VacuumOptimizer {
@@ -59,6 +62,7 @@ impl VacuumOptimizer {
             quantization_config,
             hnsw_global_config,
             telemetry_durations_aggregator: OperationDurationsAggregator::new(),
+            phase_durations_aggregator: OptimizationPhaseDurations::default(),
         }
     }
 
@@ -116,6 +120,17 @@ impl VacuumOptimizer {
             })
             .max_by_key(|ratio| OrderedFloat(*ratio))
     }
+
+    /// Calculate fragmentation ratio of payload index storages of the segment
+    ///
+    /// Returns `None` if fragmentation did not reach vacuum thresholds.
+    fn fragmented_payload_index_ratio(&self, segment: &Segment) -> Option<f64> {
+        let fragmentation_ratio = segment.payload_index.borrow().max_fragmentation_ratio()?;
+        let is_big = segment.total_point_count() >= self.min_vectors_number;
+        let is_fragmented = fragmentation_ratio > self.deleted_threshold;
+
+        (is_big && is_fragmented).then_some(fragmentation_ratio)
+    }
 }
 
 impl SegmentOptimizer for VacuumOptimizer {
@@ -159,8 +174,15 @@ impl SegmentOptimizer for VacuumOptimizer {
                 let segment = segment.read();
                 let littered_ratio_segment = self.littered_ratio_segment(&segment);
                 let littered_ratio_vectors = self.littered_vectors_index_ratio(&segment);
-                let worst_ratio = std::iter::chain(littered_ratio_segment, littered_ratio_vectors)
-                    .max_by_key(|ratio| OrderedFloat(*ratio));
+                let fragmented_ratio_payload_index = self.fragmented_payload_index_ratio(&segment);
+                let worst_ratio = [
+                    littered_ratio_segment,
+                    littered_ratio_vectors,
+                    fragmented_ratio_payload_index,
+                ]
+                .into_iter()
+                .flatten()
+                .max_by_key(|ratio| OrderedFloat(*ratio));
                 worst_ratio.map(|ratio| (segment_id, ratio))
             })
             .sorted_by_key(|(_, ratio)| OrderedFloat(-ratio))
@@ -173,6 +195,10 @@ impl SegmentOptimizer for VacuumOptimizer {
     fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator> {
         &self.telemetry_durations_aggregator
     }
+
+    fn get_phase_telemetry_counters(&self) -> &OptimizationPhaseDurations {
+        &self.phase_durations_aggregator
+    }
 }
 
 #[cfg(test)]
@@ -415,6 +441,10 @@ mod tests {
             on_disk: None,
             payload_m: None,
             inline_storage: None,
+            varint_links: None,
+            m0: None,
+            ef_construct_upper: None,
+            neighbor_selection: None,
         };
 
         // Optimizers used in test
@@ -4,6 +4,7 @@ use std::time::{Duration, Instant};
 
 use ahash::AHashMap;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::search_concurrency;
 use common::types::ScoreType;
 use futures::stream::FuturesUnordered;
 use futures::{FutureExt, TryStreamExt};
@@ -13,12 +14,16 @@ use segment::common::operation_error::OperationError;
 use segment::data_types::modifier::Modifier;
 use segment::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use segment::data_types::vectors::QueryVector;
+use segment::index::field_index::full_text_index::bm25::Bm25CorpusStats;
+use segment::index::query_optimization::rescore_formula::parsed_formula::TextScoreQuery;
 use segment::types::{
     Filter, Indexes, PointIdType, ScoredPoint, SearchParams, SegmentConfig, VectorName,
     WithPayload, WithPayloadInterface, WithVector,
 };
 use shard::common::stopping_guard::StoppingGuard;
-use shard::query::query_context::{fill_query_context, init_query_context};
+use shard::query::query_context::{
+    collect_text_score_stats, fill_query_context, init_query_context,
+};
 use shard::query::query_enum::QueryEnum;
 use shard::retrieve::record_internal::RecordInternal;
 use shard::retrieve::retrieve_blocking::retrieve_blocking;
@@ -447,6 +452,34 @@ impl SegmentsSearcher {
         AbortOnDropHandle::new(points).await?
     }
 
+    /// Collect corpus statistics of the text scores of a formula over all segments.
+    pub async fn collect_text_score_stats(
+        segments: LockedSegmentHolder,
+        text_scores: Vec<TextScoreQuery>,
+        search_runtime_handle: &Handle,
+        timeout: Duration,
+        is_stopped_guard: &StoppingGuard,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<Bm25CorpusStats>> {
+        if text_scores.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let is_stopped = is_stopped_guard.get_is_stopped();
+        // Do blocking calls in a blocking task: `segment.get().read()` calls might block async runtime
+        let stats = AbortOnDropHandle::new(search_runtime_handle.spawn_blocking(move || {
+            collect_text_score_stats(
+                &text_scores,
+                segments,
+                timeout,
+                &is_stopped,
+                &hw_measurement_acc,
+            )
+        }))
+        .await??;
+        Ok(stats)
+    }
+
     /// Rescore results with a formula that can reference payload values.
     ///
     /// Aggregates rescores from the segments.
@@ -539,6 +572,7 @@ struct BatchSearchParams<'a> {
     pub with_vector: WithVector,
     pub top: usize,
     pub params: Option<&'a SearchParams>,
+    pub score_threshold: Option<ScoreType>,
 }
 
 /// Returns suggested search sampling size for a given number of points and required limit.
@@ -593,6 +627,9 @@ fn search_in_segment(
     segment_query_context: &SegmentQueryContext,
     timeout: Duration,
 ) -> CollectionResult<(Vec<Vec<ScoredPoint>>, Vec<bool>)> {
+    // Wait for a slot, if the number of concurrent segment searches is tuned adaptively
+    let _permit = search_concurrency::acquire_search_permit();
+
     if segment_query_context.is_stopped() {
         return Err(CollectionError::cancelled(
             "Search in segment was cancelled",
@@ -620,6 +657,7 @@ fn search_in_segment(
             with_vector: search_query.with_vector.clone().unwrap_or_default(),
             top: search_query.limit + search_query.offset,
             params: search_query.params.as_ref(),
+            score_threshold: search_query.score_threshold,
         };
 
         let query = search_query.query.clone().into();
@@ -697,6 +735,11 @@ fn execute_batch_search(
         search_params.top
     };
 
+    // Let the segment skip points which can't satisfy the threshold
+    let segment_query_context = segment_query_context
+        .fork()
+        .with_score_threshold(search_params.score_threshold);
+
     let vectors_batch = &vectors_batch.iter().collect_vec();
     let res = read_segment.search_batch(
         search_params.vector_name,
@@ -706,7 +749,7 @@ fn execute_batch_search(
         search_params.filter,
         top,
         search_params.params,
-        segment_query_context,
+        &segment_query_context,
     )?;
 
     drop(read_segment);
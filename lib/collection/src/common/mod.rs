@@ -4,7 +4,10 @@ pub mod eta_calculator;
 pub mod fetch_vectors;
 pub mod file_utils;
 pub mod is_ready;
+pub mod query_cache;
+pub mod query_coalescer;
 pub mod retrieve_request_trait;
+pub mod score_drift;
 pub mod sha_256;
 pub mod snapshot_stream;
 pub mod snapshots_manager;
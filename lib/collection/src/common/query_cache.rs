@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use schemars::JsonSchema;
+use segment::common::anonymize::Anonymize;
+use segment::types::ScoredPoint;
+use serde::Serialize;
+
+use crate::config::CollectionParams;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::universal_query::shard_query::{
+    ScoringQuery, ShardPrefetch, ShardQueryRequest,
+};
+
+const DEFAULT_QUERY_CACHE_TTL_SEC: u64 = 60;
+
+/// Everything which determines results of a cached query
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct QueryCacheKey {
+    request: ShardQueryRequest,
+    shard_selection: ShardSelectorInternal,
+    read_consistency: Option<ReadConsistency>,
+}
+
+impl QueryCacheKey {
+    /// Key of the request, or `None` if results of the request must not be cached
+    pub fn new(
+        request: &ShardQueryRequest,
+        shard_selection: &ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+    ) -> Option<Self> {
+        // Random sampling is expected to give different results each time
+        if is_random_sampling(request.query.as_ref(), &request.prefetches) {
+            return None;
+        }
+
+        Some(Self {
+            request: request.clone(),
+            shard_selection: shard_selection.clone(),
+            read_consistency,
+        })
+    }
+}
+
+struct CachedResult {
+    key: QueryCacheKey,
+    points: Vec<ScoredPoint>,
+    inserted_at: Instant,
+}
+
+/// Cache of query results, keyed by the whole request.
+///
+/// Entries are stored by the hash of the key, the key itself is compared on lookup.
+/// On a hash collision the newer query replaces the older one.
+///
+/// Any write to the collection invalidates the whole cache.
+/// Results of queries which were running while the cache got invalidated are not stored,
+/// so the cache never returns data older than the last acknowledged write.
+///
+/// Note: writes with `wait=false` invalidate the cache when they are accepted, not when applied,
+/// so results of such writes may become visible only after the cached entry expires.
+pub struct QueryCache {
+    max_entries: usize,
+    ttl: Duration,
+    hasher: RandomState,
+    entries: Mutex<HashMap<u64, CachedResult>>,
+    /// Incremented on each invalidation
+    generation: AtomicU64,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
+#[anonymize(false)]
+pub struct QueryCacheTelemetry {
+    /// Number of currently cached query results
+    pub entries: usize,
+    /// Number of queries served from the cache
+    pub hits: usize,
+    /// Number of cacheable queries, which were not found in the cache
+    pub misses: usize,
+}
+
+impl QueryCache {
+    /// Create a new cache, or `None` if the cache is disabled in collection params
+    pub fn new(params: &CollectionParams) -> Option<Self> {
+        let max_entries = params
+            .query_cache_max_entries
+            .filter(|max_entries| *max_entries > 0)?;
+        let ttl = Duration::from_secs(
+            params
+                .query_cache_ttl_sec
+                .unwrap_or(DEFAULT_QUERY_CACHE_TTL_SEC),
+        );
+
+        Some(Self {
+            max_entries,
+            ttl,
+            hasher: RandomState::new(),
+            entries: Mutex::new(HashMap::new()),
+            generation: AtomicU64::new(0),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        })
+    }
+
+    /// Current generation of the cache, to be passed to [`QueryCache::insert`] once results are ready
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    pub fn get(&self, key: &QueryCacheKey) -> Option<Vec<ScoredPoint>> {
+        let hash = self.hasher.hash_one(key);
+        let mut entries = self.entries.lock();
+
+        let result = match entries.get(&hash) {
+            Some(cached) if cached.inserted_at.elapsed() >= self.ttl => {
+                entries.remove(&hash);
+                None
+            }
+            Some(cached) if cached.key == *key => Some(cached.points.clone()),
+            // Hash collision with another query
+            Some(_) | None => None,
+        };
+
+        let counter = if result.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        result
+    }
+
+    /// Store results of a query, which was started at the given cache `generation`
+    pub fn insert(&self, key: QueryCacheKey, generation: u64, points: Vec<ScoredPoint>) {
+        let hash = self.hasher.hash_one(&key);
+        let mut entries = self.entries.lock();
+
+        // Collection was updated while the query was running, results may be outdated
+        if generation != self.generation() {
+            return;
+        }
+
+        if entries.len() >= self.max_entries && !entries.contains_key(&hash) {
+            entries.retain(|_, cached| cached.inserted_at.elapsed() < self.ttl);
+        }
+
+        if entries.len() >= self.max_entries && !entries.contains_key(&hash) {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, cached)| cached.inserted_at)
+                .map(|(hash, _)| *hash);
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(
+            hash,
+            CachedResult {
+                key,
+                points,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Drop all cached results. Must be called on every write to the collection.
+    pub fn invalidate(&self) {
+        let mut entries = self.entries.lock();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        entries.clear();
+    }
+
+    pub fn get_telemetry_data(&self) -> QueryCacheTelemetry {
+        QueryCacheTelemetry {
+            entries: self.entries.lock().len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+fn is_random_sampling(query: Option<&ScoringQuery>, prefetches: &[ShardPrefetch]) -> bool {
+    matches!(query, Some(ScoringQuery::Sample(_)))
+        || prefetches
+            .iter()
+            .any(|prefetch| is_random_sampling(prefetch.query.as_ref(), &prefetch.prefetches))
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::{ExtendedPointId, WithPayloadInterface, WithVector};
+
+    use super::*;
+    use crate::operations::consistency_params::ReadConsistencyType;
+    use crate::operations::universal_query::shard_query::SampleInternal;
+
+    fn request(limit: usize) -> ShardQueryRequest {
+        ShardQueryRequest {
+            prefetches: vec![],
+            query: None,
+            filter: None,
+            score_threshold: None,
+            limit,
+            offset: 0,
+            params: None,
+            with_vector: WithVector::Bool(false),
+            with_payload: WithPayloadInterface::Bool(false),
+        }
+    }
+
+    fn key(limit: usize) -> QueryCacheKey {
+        QueryCacheKey::new(&request(limit), &ShardSelectorInternal::All, None).unwrap()
+    }
+
+    fn points(ids: &[u64]) -> Vec<ScoredPoint> {
+        ids.iter()
+            .map(|id| ScoredPoint {
+                id: ExtendedPointId::NumId(*id),
+                version: 0,
+                score: 1.0,
+                payload: None,
+                vector: None,
+                shard_key: None,
+                order_value: None,
+            })
+            .collect()
+    }
+
+    fn cache(max_entries: usize, ttl_sec: u64) -> QueryCache {
+        QueryCache::new(&CollectionParams {
+            query_cache_max_entries: Some(max_entries),
+            query_cache_ttl_sec: Some(ttl_sec),
+            ..CollectionParams::empty()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        assert!(QueryCache::new(&CollectionParams::empty()).is_none());
+    }
+
+    #[test]
+    fn test_key() {
+        let all = ShardSelectorInternal::All;
+        let majority = Some(ReadConsistency::Type(ReadConsistencyType::Majority));
+
+        let a = QueryCacheKey::new(&request(10), &all, None).unwrap();
+        let b = QueryCacheKey::new(&request(10), &all, None).unwrap();
+        let c = QueryCacheKey::new(&request(11), &all, None).unwrap();
+        let d = QueryCacheKey::new(&request(10), &ShardSelectorInternal::ShardId(1), None).unwrap();
+        let e = QueryCacheKey::new(&request(10), &all, majority).unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+        assert_ne!(a, e);
+
+        let mut random = request(10);
+        random.query = Some(ScoringQuery::Sample(SampleInternal::Random));
+        assert!(QueryCacheKey::new(&random, &all, None).is_none());
+    }
+
+    #[test]
+    fn test_hash_collision_is_a_miss() {
+        let cache = cache(10, 60);
+
+        // Store the result of one query under the hash of another one
+        let hash = cache.hasher.hash_one(key(10));
+        cache.entries.lock().insert(
+            hash,
+            CachedResult {
+                key: key(11),
+                points: points(&[1]),
+                inserted_at: Instant::now(),
+            },
+        );
+
+        assert!(cache.get(&key(10)).is_none());
+    }
+
+    #[test]
+    fn test_hit_miss_and_invalidation() {
+        let cache = cache(10, 60);
+
+        assert!(cache.get(&key(1)).is_none());
+
+        cache.insert(key(1), cache.generation(), points(&[1, 2]));
+        let cached = cache.get(&key(1)).unwrap();
+        assert_eq!(cached.len(), 2);
+
+        cache.invalidate();
+        assert!(cache.get(&key(1)).is_none());
+
+        let telemetry = cache.get_telemetry_data();
+        assert_eq!(telemetry.hits, 1);
+        assert_eq!(telemetry.misses, 2);
+        assert_eq!(telemetry.entries, 0);
+    }
+
+    #[test]
+    fn test_stale_generation_is_not_stored() {
+        let cache = cache(10, 60);
+
+        let generation = cache.generation();
+        // Write happens while the query is running
+        cache.invalidate();
+        cache.insert(key(1), generation, points(&[1]));
+
+        assert!(cache.get(&key(1)).is_none());
+    }
+
+    #[test]
+    fn test_ttl_and_capacity() {
+        let cache = cache(2, 0);
+        cache.insert(key(1), cache.generation(), points(&[1]));
+        // Zero TTL, entry is expired right away
+        assert!(cache.get(&key(1)).is_none());
+
+        let cache = self::cache(2, 60);
+        cache.insert(key(1), cache.generation(), points(&[1]));
+        cache.insert(key(2), cache.generation(), points(&[2]));
+        cache.insert(key(3), cache.generation(), points(&[3]));
+
+        // Oldest entry is evicted
+        assert!(cache.get(&key(1)).is_none());
+        assert!(cache.get(&key(2)).is_some());
+        assert!(cache.get(&key(3)).is_some());
+    }
+}
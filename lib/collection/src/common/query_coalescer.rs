@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use parking_lot::Mutex;
+use schemars::JsonSchema;
+use segment::common::anonymize::Anonymize;
+use segment::types::ScoredPoint;
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::common::query_cache::QueryCacheKey;
+
+/// Result of an in-flight query, `None` until the query is finished
+type SharedResult = Option<Arc<Vec<ScoredPoint>>>;
+
+struct InFlightQuery {
+    key: QueryCacheKey,
+    result: watch::Receiver<SharedResult>,
+}
+
+/// Coalesces identical concurrent queries.
+///
+/// The first caller of a query executes it, while callers of an identical query, arriving before
+/// it is finished, wait for its result instead of executing the query once more.
+/// Queries are identified the same way as in the query cache, so only queries which are allowed
+/// to share results are coalesced.
+///
+/// If the executing caller fails or is cancelled, the waiting callers execute the query themselves.
+#[derive(Default)]
+pub struct QueryCoalescer {
+    hasher: RandomState,
+    in_flight: Mutex<HashMap<u64, InFlightQuery>>,
+    executed: AtomicUsize,
+    coalesced: AtomicUsize,
+}
+
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
+#[anonymize(false)]
+pub struct QueryCoalescerTelemetry {
+    /// Number of queries currently executed, which identical queries can wait for
+    pub in_flight: usize,
+    /// Number of coalescable queries, which were executed
+    pub executed: usize,
+    /// Number of queries, which got the result of an identical concurrent query
+    pub coalesced: usize,
+}
+
+/// Role of a caller in the execution of a query
+pub enum CoalescedQuery<'a> {
+    /// The caller must execute the query, and publish its result
+    Leader(QueryLeader<'a>),
+    /// An identical query is already executed, the caller may wait for its result
+    Follower(QueryFollower<'a>),
+}
+
+/// Executor of a query, identical queries wait for its result until it is dropped
+pub struct QueryLeader<'a> {
+    coalescer: &'a QueryCoalescer,
+    hash: u64,
+    result: watch::Sender<SharedResult>,
+}
+
+pub struct QueryFollower<'a> {
+    coalescer: &'a QueryCoalescer,
+    result: watch::Receiver<SharedResult>,
+}
+
+impl QueryCoalescer {
+    /// Register the query, and find out whether the caller executes it or waits for the result
+    pub fn join(&self, key: QueryCacheKey) -> CoalescedQuery<'_> {
+        let hash = self.hasher.hash_one(&key);
+        let mut in_flight = self.in_flight.lock();
+
+        match in_flight.entry(hash) {
+            Entry::Occupied(entry) if entry.get().key == key => {
+                CoalescedQuery::Follower(QueryFollower {
+                    coalescer: self,
+                    result: entry.get().result.clone(),
+                })
+            }
+            // Hash collision with another query, execute without coalescing
+            Entry::Occupied(_) => {
+                let (result, _) = watch::channel(None);
+                self.executed.fetch_add(1, Ordering::Relaxed);
+                CoalescedQuery::Leader(QueryLeader {
+                    coalescer: self,
+                    hash,
+                    result,
+                })
+            }
+            Entry::Vacant(entry) => {
+                let (result, receiver) = watch::channel(None);
+                entry.insert(InFlightQuery {
+                    key,
+                    result: receiver,
+                });
+                self.executed.fetch_add(1, Ordering::Relaxed);
+                CoalescedQuery::Leader(QueryLeader {
+                    coalescer: self,
+                    hash,
+                    result,
+                })
+            }
+        }
+    }
+
+    pub fn get_telemetry_data(&self) -> QueryCoalescerTelemetry {
+        QueryCoalescerTelemetry {
+            in_flight: self.in_flight.lock().len(),
+            executed: self.executed.load(Ordering::Relaxed),
+            coalesced: self.coalesced.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl QueryLeader<'_> {
+    /// Publish the result of the query to the waiting callers
+    pub fn finish(self, points: &[ScoredPoint]) {
+        self.unregister();
+
+        // Only the waiting callers are left subscribed, don't copy the result if there are none
+        if self.result.receiver_count() > 0 {
+            self.result.send_replace(Some(Arc::new(points.to_vec())));
+        }
+    }
+
+    /// Stop accepting new callers of the query
+    fn unregister(&self) {
+        let mut in_flight = self.coalescer.in_flight.lock();
+        if let Entry::Occupied(entry) = in_flight.entry(self.hash)
+            && self.result.same_channel(&entry.get().result)
+        {
+            entry.remove();
+        }
+    }
+}
+
+impl Drop for QueryLeader<'_> {
+    fn drop(&mut self) {
+        // No-op if already finished
+        self.unregister();
+    }
+}
+
+impl QueryFollower<'_> {
+    /// Wait for the result of the identical query.
+    ///
+    /// Returns `None` if the query failed or was cancelled, so the caller must execute it itself.
+    pub async fn wait(mut self) -> Option<Vec<ScoredPoint>> {
+        let result = self
+            .result
+            .wait_for(Option::is_some)
+            .await
+            .ok()
+            .and_then(|result| result.clone())?;
+
+        self.coalescer.coalesced.fetch_add(1, Ordering::Relaxed);
+        Some(result.as_ref().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::{ExtendedPointId, WithPayloadInterface, WithVector};
+
+    use super::*;
+    use crate::operations::shard_selector_internal::ShardSelectorInternal;
+    use crate::operations::universal_query::shard_query::ShardQueryRequest;
+
+    fn key(limit: usize) -> QueryCacheKey {
+        let request = ShardQueryRequest {
+            prefetches: vec![],
+            query: None,
+            filter: None,
+            score_threshold: None,
+            limit,
+            offset: 0,
+            params: None,
+            with_vector: WithVector::Bool(false),
+            with_payload: WithPayloadInterface::Bool(false),
+        };
+        QueryCacheKey::new(&request, &ShardSelectorInternal::All, None).unwrap()
+    }
+
+    fn points(ids: &[u64]) -> Vec<ScoredPoint> {
+        ids.iter()
+            .map(|id| ScoredPoint {
+                id: ExtendedPointId::NumId(*id),
+                version: 0,
+                score: 1.0,
+                payload: None,
+                vector: None,
+                shard_key: None,
+                order_value: None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_identical_queries_are_coalesced() {
+        let coalescer = QueryCoalescer::default();
+
+        let CoalescedQuery::Leader(leader) = coalescer.join(key(10)) else {
+            panic!("first query must be executed");
+        };
+        let CoalescedQuery::Follower(follower) = coalescer.join(key(10)) else {
+            panic!("identical query must wait for the first one");
+        };
+        let CoalescedQuery::Leader(other) = coalescer.join(key(11)) else {
+            panic!("different query must be executed");
+        };
+        assert_eq!(coalescer.get_telemetry_data().in_flight, 2);
+
+        leader.finish(&points(&[1, 2]));
+        other.finish(&points(&[3]));
+        assert_eq!(follower.wait().await.unwrap(), points(&[1, 2]));
+
+        // Finished query is not waited for anymore
+        assert!(matches!(coalescer.join(key(10)), CoalescedQuery::Leader(_),));
+
+        let telemetry = coalescer.get_telemetry_data();
+        assert_eq!(telemetry.in_flight, 0);
+        assert_eq!(telemetry.executed, 3);
+        assert_eq!(telemetry.coalesced, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_query_is_not_shared() {
+        let coalescer = QueryCoalescer::default();
+
+        let CoalescedQuery::Leader(leader) = coalescer.join(key(10)) else {
+            panic!("first query must be executed");
+        };
+        let CoalescedQuery::Follower(follower) = coalescer.join(key(10)) else {
+            panic!("identical query must wait for the first one");
+        };
+
+        // Query failed or got cancelled
+        drop(leader);
+        assert!(follower.wait().await.is_none());
+
+        let telemetry = coalescer.get_telemetry_data();
+        assert_eq!(telemetry.in_flight, 0);
+        assert_eq!(telemetry.coalesced, 0);
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+use schemars::JsonSchema;
+use segment::common::anonymize::Anonymize;
+use segment::types::{VectorName, VectorNameBuf};
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_SCORE_DRIFT_THRESHOLD: f64 = 3.0;
+
+/// Standard error used instead of zero, if all scores in a window were the same
+const MIN_STANDARD_ERROR: f64 = 1e-6;
+
+/// Configuration of the top-1 score drift detection.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct ScoreDriftConfig {
+    /// Number of queries in a statistics window. Each window is compared with the previous one.
+    /// If 0 or not set - drift detection is disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_size: Option<usize>,
+    /// Alert if the mean top-1 score of a window differs from the previous window
+    /// by more than this number of standard errors of the previous window mean. Default is 3.0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f64>,
+}
+
+/// Mean and variance, computed with Welford's algorithm
+#[derive(Debug, Default, Clone, Copy)]
+struct RunningStats {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn stddev(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        (self.m2 / (self.count - 1) as f64).sqrt()
+    }
+
+    /// Standard error of the mean
+    fn standard_error(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.stddev() / (self.count as f64).sqrt()
+    }
+}
+
+#[derive(Debug, Default)]
+struct VectorScoreStats {
+    /// Statistics of the last complete window
+    baseline: Option<RunningStats>,
+    /// Statistics of the window being filled
+    current: RunningStats,
+    alerts: usize,
+}
+
+/// Detects sharp changes in the distribution of top-1 scores of nearest neighbors queries.
+///
+/// Such changes often mean that the data was ingested with a different embedding model
+/// than the one used for querying.
+#[derive(Debug)]
+pub struct ScoreDriftDetector {
+    collection_id: String,
+    window_size: usize,
+    threshold: f64,
+    stats: Mutex<HashMap<VectorNameBuf, VectorScoreStats>>,
+}
+
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
+#[anonymize(false)]
+pub struct ScoreDriftTelemetry {
+    /// Mean top-1 score of the last complete window
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline_mean: Option<f64>,
+    /// Standard deviation of top-1 scores of the last complete window
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline_stddev: Option<f64>,
+    /// Mean top-1 score of the window being filled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_mean: Option<f64>,
+    /// Number of queries in the window being filled
+    pub current_count: usize,
+    /// Number of windows, which drifted from the previous one
+    pub alerts: usize,
+}
+
+impl ScoreDriftDetector {
+    /// Create a new detector, or `None` if drift detection is disabled by config
+    pub fn new(collection_id: String, config: &ScoreDriftConfig) -> Option<Self> {
+        let window_size = config.window_size.filter(|window_size| *window_size > 0)?;
+        let threshold = config.threshold.unwrap_or(DEFAULT_SCORE_DRIFT_THRESHOLD);
+
+        Some(Self {
+            collection_id,
+            window_size,
+            threshold,
+            stats: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Record the best score of a query against the given vector.
+    ///
+    /// Returns `true` if the window got complete and drifted from the previous one.
+    pub fn observe(&self, vector_name: &VectorName, top_score: f32) -> bool {
+        let mut stats = self.stats.lock();
+        let stats = stats.entry(vector_name.to_owned()).or_default();
+
+        stats.current.push(f64::from(top_score));
+        if stats.current.count < self.window_size {
+            return false;
+        }
+
+        let current = std::mem::take(&mut stats.current);
+        let Some(baseline) = stats.baseline.replace(current) else {
+            return false;
+        };
+
+        // Means of windows are compared, so the spread of means matters, not of individual scores
+        let shift = (current.mean - baseline.mean).abs()
+            / baseline.standard_error().max(MIN_STANDARD_ERROR);
+        if shift <= self.threshold {
+            return false;
+        }
+
+        stats.alerts += 1;
+        log::warn!(
+            "Top-1 score distribution of vector `{vector_name}` in collection `{}` drifted: \
+             mean changed from {:.4} to {:.4} ({shift:.1} standard errors). \
+             Check that points and queries are embedded with the same model",
+            self.collection_id,
+            baseline.mean,
+            current.mean,
+        );
+        true
+    }
+
+    pub fn get_telemetry_data(&self) -> HashMap<VectorNameBuf, ScoreDriftTelemetry> {
+        self.stats
+            .lock()
+            .iter()
+            .map(|(vector_name, stats)| {
+                let telemetry = ScoreDriftTelemetry {
+                    baseline_mean: stats.baseline.map(|baseline| baseline.mean),
+                    baseline_stddev: stats.baseline.map(|baseline| baseline.stddev()),
+                    current_mean: (stats.current.count > 0).then_some(stats.current.mean),
+                    current_count: stats.current.count,
+                    alerts: stats.alerts,
+                };
+                (vector_name.clone(), telemetry)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detector(window_size: usize) -> ScoreDriftDetector {
+        ScoreDriftDetector::new(
+            "test".to_string(),
+            &ScoreDriftConfig {
+                window_size: Some(window_size),
+                threshold: None,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        assert!(
+            ScoreDriftDetector::new("test".to_string(), &ScoreDriftConfig::default()).is_none()
+        );
+    }
+
+    #[test]
+    fn test_stable_distribution() {
+        let detector = detector(10);
+        for i in 0..100 {
+            let score = 0.8 + (i % 5) as f32 * 0.01;
+            assert!(!detector.observe("", score));
+        }
+
+        let telemetry = &detector.get_telemetry_data()[""];
+        assert_eq!(telemetry.alerts, 0);
+        assert_eq!(telemetry.current_count, 0);
+        assert!((telemetry.baseline_mean.unwrap() - 0.82).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_drift_alert() {
+        let detector = detector(10);
+        let scores = |base: f32| (0..10).map(move |i| base + (i % 5) as f32 * 0.01);
+
+        for score in scores(0.8) {
+            detector.observe("", score);
+        }
+        // Scores of the other vector are tracked separately
+        for score in scores(0.1) {
+            detector.observe("other", score);
+        }
+
+        let alerts = scores(0.3)
+            .filter(|score| detector.observe("", *score))
+            .count();
+        assert_eq!(alerts, 1);
+
+        let telemetry = detector.get_telemetry_data();
+        assert_eq!(telemetry[""].alerts, 1);
+        assert_eq!(telemetry["other"].alerts, 0);
+    }
+
+    #[test]
+    fn test_shift_within_score_spread() {
+        // Uniformly spread scores around the base, with standard deviation of about 0.05
+        let scores =
+            |base: f32| (0..100).map(move |i| base + ((i * 37 % 100) as f32 / 100.0 - 0.5) * 0.17);
+
+        let detector = detector(100);
+        for _ in 0..5 {
+            assert!(scores(0.8).all(|score| !detector.observe("", score)));
+        }
+
+        // Shift is well within the spread of individual scores,
+        // but is 4 standard errors of the window mean
+        let alerts = scores(0.78)
+            .filter(|score| detector.observe("", *score))
+            .count();
+        assert_eq!(alerts, 1);
+
+        // Shift of about a single standard error is noise
+        let detector = self::detector(100);
+        assert!(scores(0.8).all(|score| !detector.observe("", score)));
+        assert!(scores(0.795).all(|score| !detector.observe("", score)));
+    }
+}
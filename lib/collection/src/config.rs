@@ -8,12 +8,12 @@ use atomicwrites::OverwriteBehavior::AllowOverwrite;
 use fs_err::File;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
-use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NonFiniteVectorPolicy};
 use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
 use segment::types::{
-    Distance, HnswConfig, Indexes, Payload, PayloadStorageType, QuantizationConfig, SegmentConfig,
-    SparseVectorDataConfig, StrictModeConfig, VectorDataConfig, VectorName, VectorNameBuf,
-    VectorStorageDatatype, VectorStorageType,
+    Distance, HnswConfig, Indexes, Payload, PayloadStorageType, QuantizationConfig, SearchParams,
+    SegmentConfig, SparseVectorDataConfig, StrictModeConfig, VectorDataConfig, VectorName,
+    VectorNameBuf, VectorStorageDatatype, VectorStorageType,
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -83,6 +83,29 @@ pub enum ShardingMethod {
     Custom,
 }
 
+/// Constraints on which peers may hold replicas of a collection or shard key.
+/// Honored when placing new shards and when adding replicas automatically.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq, Hash, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct PlacementConstraints {
+    /// Only place replicas on peers having all of these labels.
+    /// Peer labels are configured with `cluster.node_labels`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub require_labels: BTreeMap<String, String>,
+    /// Do not place replicas on peers holding replicas of any of these collections
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub anti_affinity: Vec<String>,
+}
+
+impl PlacementConstraints {
+    /// Whether a peer with the given labels satisfies the label requirements
+    pub fn matches_labels(&self, labels: &BTreeMap<String, String>) -> bool {
+        self.require_labels
+            .iter()
+            .all(|(key, value)| labels.get(key) == Some(value))
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub struct CollectionParams {
@@ -124,6 +147,17 @@ pub struct CollectionParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
     pub read_fan_out_delay_ms: Option<u64>,
+    /// Maximum number of query results to cache on this collection.
+    /// Any write to the collection drops all cached results.
+    /// Default is 0, which means the query cache is disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub query_cache_max_entries: Option<usize>,
+    /// For how long a cached query result can be served, in seconds.
+    /// Default is 60.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub query_cache_ttl_sec: Option<u64>,
     /// If true - point's payload will not be stored in memory.
     /// It will be read from the disk every time it is requested.
     /// This setting saves RAM by (slightly) increasing the response time.
@@ -136,6 +170,22 @@ pub struct CollectionParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[validate(nested)]
     pub sparse_vectors: Option<BTreeMap<VectorNameBuf, SparseVectorParams>>,
+    /// Constraints on which peers may hold replicas of this collection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[anonymize(value = None)]
+    pub placement: Option<PlacementConstraints>,
+    /// How to handle NaN and infinite values in upserted and query vectors.
+    /// Default is `reject`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub non_finite_vector_policy: Option<NonFiniteVectorPolicy>,
+    /// Search params, applied to search and query requests that don't set them.
+    /// `exact` and `indexed_only` only apply to requests without any search params.
+    /// `quantization.max_rescore` is a cap, requests can only lower it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    #[anonymize(false)]
+    pub default_search_params: Option<SearchParams>,
 }
 
 impl CollectionParams {
@@ -166,8 +216,13 @@ impl CollectionParams {
             write_consistency_factor: _, // May be changed
             read_fan_out_factor: _, // May be changed
             read_fan_out_delay_ms: _, // May be changed,
+            query_cache_max_entries: _, // May be changed
+            query_cache_ttl_sec: _, // May be changed
             on_disk_payload: _, // May be changed
             sparse_vectors,  // Parameters may be changes, but not the structure
+            placement: _,    // Only affects placement of new replicas
+            non_finite_vector_policy: _, // May be changed
+            default_search_params: _, // May be changed
         } = other;
 
         self.vectors.check_compatible(vectors)?;
@@ -333,8 +388,13 @@ impl CollectionParams {
             write_consistency_factor: default_write_consistency_factor(),
             read_fan_out_factor: None,
             read_fan_out_delay_ms: None,
+            query_cache_max_entries: None,
+            query_cache_ttl_sec: None,
             on_disk_payload: default_on_disk_payload(),
             sparse_vectors: None,
+            placement: None,
+            non_finite_vector_policy: None,
+            default_search_params: None,
         }
     }
 
@@ -567,6 +627,7 @@ impl CollectionParams {
                         },
                         multivector_config: params.multivector_config,
                         datatype: params.datatype.map(VectorStorageDatatype::from),
+                        skip_normalization: params.skip_normalization,
                     },
                 )
             })
@@ -636,6 +697,7 @@ impl CollectionParams {
             vector_data,
             sparse_vector_data,
             payload_storage_type,
+            defragment_keys: None,
         };
 
         Ok(segment_config)
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::{Validate, ValidationErrors};
 
+use crate::config::PlacementConstraints;
 use crate::shards::replica_set::replica_set_state::ReplicaState;
 use crate::shards::shard::{PeerId, ShardId};
 use crate::shards::transfer::ShardTransferMethod;
@@ -91,6 +92,10 @@ pub struct CreateShardingKey {
     /// If not specified, will be `Initializing` first and then `Active`
     /// Warning: do not change this unless you know what you are doing
     pub initial_state: Option<ReplicaState>,
+    /// Constraints on which peers may hold replicas of this key, in addition to the placement
+    /// constraints of the collection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement_constraints: Option<PlacementConstraints>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -6,8 +6,10 @@ use std::num::NonZeroU32;
 
 use api::rest::MaxOptimizationThreads;
 use schemars::JsonSchema;
+use segment::data_types::vectors::NonFiniteVectorPolicy;
 use segment::types::{
-    BinaryQuantization, HnswConfig, ProductQuantization, ScalarQuantization, StrictModeConfig,
+    BinaryQuantization, HnswConfig, HnswNeighborSelection, ProductQuantization, ScalarQuantization,
+    SearchParams, StrictModeConfig,
 };
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationErrors};
@@ -73,6 +75,23 @@ pub struct HnswConfigDiff {
     /// Requires quantized vectors to be enabled. Multi-vectors are not supported.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inline_storage: Option<bool>,
+    /// Store HNSW graph links delta-encoded with varints instead of bitpacking. Default: false.
+    /// Reduces the size of the graph of large segments, at a small cost of decoding speed.
+    /// Has no effect if `inline_storage` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub varint_links: Option<bool>,
+    /// Number of edges per node on level 0 of the index graph. If not set, `2 * m` is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, max = 2048))]
+    pub m0: Option<usize>,
+    /// Number of neighbours to consider during the index building on levels above 0.
+    /// If not set, `ef_construct` is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 4, max = 10000))]
+    pub ef_construct_upper: Option<usize>,
+    /// Strategy to select neighbours of a node during the index building. Default: `heuristic`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neighbor_selection: Option<HnswNeighborSelection>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
@@ -96,12 +115,22 @@ pub struct CollectionParamsDiff {
     pub read_fan_out_factor: Option<u32>,
     ///  Delay in milliseconds before sending read requests to remote nodes
     pub read_fan_out_delay_ms: Option<u64>,
+    /// Maximum number of cached query results. 0 disables the query cache.
+    pub query_cache_max_entries: Option<usize>,
+    /// For how long a cached query result can be served, in seconds
+    pub query_cache_ttl_sec: Option<u64>,
     /// If true - point's payload will not be stored in memory.
     /// It will be read from the disk every time it is requested.
     /// This setting saves RAM by (slightly) increasing the response time.
     /// Note: those payload values that are involved in filtering and are indexed - remain in RAM.
     #[serde(default)]
     pub on_disk_payload: Option<bool>,
+    /// How to handle NaN and infinite values in upserted and query vectors
+    #[serde(default)]
+    pub non_finite_vector_policy: Option<NonFiniteVectorPolicy>,
+    /// Search params, applied to search and query requests that don't set them
+    #[serde(default)]
+    pub default_search_params: Option<SearchParams>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
@@ -168,6 +197,16 @@ pub struct OptimizersConfigDiff {
     /// Default is disabled.
     #[serde(default)]
     pub prevent_unoptimized: Option<bool>,
+    /// Maximum number of segments per shard.
+    /// If exceeded, the merge optimizer merges segments regardless of `max_segment_size`
+    /// until the number of segments is back within the limit.
+    ///
+    /// Lower values reduce the number of segments each search has to visit,
+    /// at the cost of more indexing work.
+    /// If not set, the number of segments is only driven by `default_segment_number`.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_segment_number: Option<usize>,
 }
 
 impl std::hash::Hash for OptimizersConfigDiff {
@@ -183,6 +222,7 @@ impl std::hash::Hash for OptimizersConfigDiff {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            max_segment_number,
         } = self;
 
         deleted_threshold.map(f64::to_le_bytes).hash(state);
@@ -194,6 +234,7 @@ impl std::hash::Hash for OptimizersConfigDiff {
         flush_interval_sec.hash(state);
         max_optimization_threads.hash(state);
         prevent_unoptimized.hash(state);
+        max_segment_number.hash(state);
     }
 }
 
@@ -209,6 +250,10 @@ impl DiffConfig<HnswConfigDiff> for HnswConfig {
             on_disk,
             payload_m,
             inline_storage,
+            varint_links,
+            m0,
+            ef_construct_upper,
+            neighbor_selection,
         } = diff;
 
         HnswConfig {
@@ -219,6 +264,10 @@ impl DiffConfig<HnswConfigDiff> for HnswConfig {
             on_disk: on_disk.or(self.on_disk),
             payload_m: payload_m.or(self.payload_m),
             inline_storage: inline_storage.or(self.inline_storage),
+            varint_links: varint_links.or(self.varint_links),
+            m0: m0.or(self.m0),
+            ef_construct_upper: ef_construct_upper.or(self.ef_construct_upper),
+            neighbor_selection: neighbor_selection.or(self.neighbor_selection),
         }
     }
 }
@@ -233,6 +282,10 @@ impl DiffConfig<HnswConfigDiff> for HnswConfigDiff {
             on_disk,
             payload_m,
             inline_storage,
+            varint_links,
+            m0,
+            ef_construct_upper,
+            neighbor_selection,
         } = diff;
 
         HnswConfigDiff {
@@ -243,6 +296,10 @@ impl DiffConfig<HnswConfigDiff> for HnswConfigDiff {
             on_disk: on_disk.or(self.on_disk),
             payload_m: payload_m.or(self.payload_m),
             inline_storage: inline_storage.or(self.inline_storage),
+            varint_links: varint_links.or(self.varint_links),
+            m0: m0.or(self.m0),
+            ef_construct_upper: ef_construct_upper.or(self.ef_construct_upper),
+            neighbor_selection: neighbor_selection.or(self.neighbor_selection),
         }
     }
 }
@@ -259,6 +316,7 @@ impl DiffConfig<OptimizersConfigDiff> for OptimizersConfig {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            max_segment_number,
         } = diff;
 
         OptimizersConfig {
@@ -273,6 +331,7 @@ impl DiffConfig<OptimizersConfigDiff> for OptimizersConfig {
             max_optimization_threads: max_optimization_threads
                 .map_or(self.max_optimization_threads, From::from),
             prevent_unoptimized: prevent_unoptimized.or(self.prevent_unoptimized),
+            max_segment_number: max_segment_number.or(self.max_segment_number),
         }
     }
 }
@@ -300,7 +359,11 @@ impl DiffConfig<CollectionParamsDiff> for CollectionParams {
             write_consistency_factor,
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            query_cache_max_entries,
+            query_cache_ttl_sec,
             on_disk_payload,
+            non_finite_vector_policy,
+            default_search_params,
         } = diff;
 
         CollectionParams {
@@ -309,11 +372,16 @@ impl DiffConfig<CollectionParamsDiff> for CollectionParams {
                 .unwrap_or(self.write_consistency_factor),
             read_fan_out_factor: read_fan_out_factor.or(self.read_fan_out_factor),
             read_fan_out_delay_ms: read_fan_out_delay_ms.or(self.read_fan_out_delay_ms),
+            query_cache_max_entries: query_cache_max_entries.or(self.query_cache_max_entries),
+            query_cache_ttl_sec: query_cache_ttl_sec.or(self.query_cache_ttl_sec),
             on_disk_payload: on_disk_payload.unwrap_or(self.on_disk_payload),
             shard_number: self.shard_number,
             sharding_method: self.sharding_method,
             sparse_vectors: self.sparse_vectors.clone(),
             vectors: self.vectors.clone(),
+            placement: self.placement.clone(),
+            non_finite_vector_policy: non_finite_vector_policy.or(self.non_finite_vector_policy),
+            default_search_params: default_search_params.or(self.default_search_params),
         }
     }
 }
@@ -340,6 +408,10 @@ impl DiffConfig<StrictModeConfig> for StrictModeConfig {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            read_vectors_by_default,
+            max_response_vectors_bytes,
+            max_update_queue_length,
+            max_unindexed_segment_size_kb,
         } = diff;
 
         StrictModeConfig {
@@ -372,6 +444,12 @@ impl DiffConfig<StrictModeConfig> for StrictModeConfig {
                 .or(self.sparse_config.as_ref())
                 .cloned(),
             max_payload_index_count: max_payload_index_count.or(self.max_payload_index_count),
+            read_vectors_by_default: read_vectors_by_default.or(self.read_vectors_by_default),
+            max_response_vectors_bytes: max_response_vectors_bytes
+                .or(self.max_response_vectors_bytes),
+            max_update_queue_length: max_update_queue_length.or(self.max_update_queue_length),
+            max_unindexed_segment_size_kb: max_unindexed_segment_size_kb
+                .or(self.max_unindexed_segment_size_kb),
         }
     }
 }
@@ -386,6 +464,10 @@ impl From<HnswConfig> for HnswConfigDiff {
             on_disk,
             payload_m,
             inline_storage,
+            varint_links,
+            m0,
+            ef_construct_upper,
+            neighbor_selection,
         } = config;
 
         HnswConfigDiff {
@@ -396,6 +478,10 @@ impl From<HnswConfig> for HnswConfigDiff {
             on_disk,
             payload_m,
             inline_storage,
+            varint_links,
+            m0,
+            ef_construct_upper,
+            neighbor_selection,
         }
     }
 }
@@ -423,11 +509,16 @@ impl From<CollectionParams> for CollectionParamsDiff {
             write_consistency_factor,
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            query_cache_max_entries,
+            query_cache_ttl_sec,
             on_disk_payload,
             shard_number: _,
             sharding_method: _,
             sparse_vectors: _,
             vectors: _,
+            placement: _,
+            non_finite_vector_policy,
+            default_search_params,
         } = config;
 
         CollectionParamsDiff {
@@ -435,7 +526,11 @@ impl From<CollectionParams> for CollectionParamsDiff {
             write_consistency_factor: Some(write_consistency_factor),
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            query_cache_max_entries,
+            query_cache_ttl_sec,
             on_disk_payload: Some(on_disk_payload),
+            non_finite_vector_policy,
+            default_search_params,
         }
     }
 }
@@ -453,6 +548,7 @@ impl From<OptimizersConfig> for OptimizersConfigDiff {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            max_segment_number,
         } = config;
 
         Self {
@@ -466,6 +562,7 @@ impl From<OptimizersConfig> for OptimizersConfigDiff {
             flush_interval_sec: Some(flush_interval_sec),
             max_optimization_threads: max_optimization_threads.map(MaxOptimizationThreads::Threads),
             prevent_unoptimized,
+            max_segment_number,
         }
     }
 }
@@ -525,7 +622,11 @@ mod tests {
             write_consistency_factor: Some(NonZeroU32::new(2).unwrap()),
             read_fan_out_factor: None,
             read_fan_out_delay_ms: None,
+            query_cache_max_entries: None,
+            query_cache_ttl_sec: None,
             on_disk_payload: None,
+            non_finite_vector_policy: None,
+            default_search_params: None,
         };
 
         let new_params = params.update(&diff);
@@ -555,6 +656,7 @@ mod tests {
             flush_interval_sec: 30,
             max_optimization_threads: Some(1),
             prevent_unoptimized: None,
+            max_segment_number: None,
         };
         let update: OptimizersConfigDiff =
             serde_json::from_str(r#"{ "indexing_threshold": 10000 }"#).unwrap();
@@ -580,6 +682,7 @@ mod tests {
             flush_interval_sec: 30,
             max_optimization_threads: Some(1),
             prevent_unoptimized: None,
+            max_segment_number: None,
         };
 
         let update: OptimizersConfigDiff = serde_json::from_str(json_diff).unwrap();
@@ -21,7 +21,7 @@ use validator::{Validate, ValidationError as ValidatorError, ValidationErrors};
 /// * `all` - send requests to all nodes and return points which present on all of them
 ///
 /// Default value is `Factor(1)`
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ReadConsistency {
     // send N random request and return points, which present on all of them
@@ -146,7 +146,7 @@ where
 /// * `quorum` - send requests to all nodes and return points which present on majority of nodes
 ///
 /// * `all` - send requests to all nodes and return points which present on all nodes
-#[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum ReadConsistencyType {
     // send N/2+1 random request and return points, which present on all of them
@@ -19,10 +19,10 @@ use api::rest::{
 use itertools::Itertools;
 use segment::common::operation_error::OperationError;
 use segment::data_types::modifier::Modifier;
-use segment::data_types::vectors::{VectorInternal, VectorStructInternal};
+use segment::data_types::vectors::{NonFiniteVectorPolicy, VectorInternal, VectorStructInternal};
 use segment::types::{
-    Distance, Filter, HnswConfig, MultiVectorConfig, QuantizationConfig, StrictModeConfigOutput,
-    WithPayloadInterface,
+    Distance, Filter, HnswConfig, HnswNeighborSelection, MultiVectorConfig, PointIdType,
+    QuantizationConfig, SearchParams, StrictModeConfigOutput, WithPayloadInterface,
 };
 use shard::retrieve::record_internal::RecordInternal;
 use tonic::Status;
@@ -36,7 +36,7 @@ use super::types::{
     VectorsConfigDiff,
 };
 use crate::config::{
-    CollectionParams, ShardingMethod, WalConfig, default_replication_factor,
+    CollectionParams, PlacementConstraints, ShardingMethod, WalConfig, default_replication_factor,
     default_write_consistency_factor,
 };
 use crate::lookup::WithLookup;
@@ -52,12 +52,15 @@ use crate::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
 };
-use crate::operations::point_ops::{FilterSelector, PointIdsList, PointsSelector, WriteOrdering};
+use crate::operations::point_ops::{
+    FilterSelector, PointIdsList, PointsSelector, ReturnIds, WriteOrdering,
+};
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{
     AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionStatus, CollectionWarning,
-    CountResult, LocalShardInfo, OptimizersStatus, RecommendRequestInternal, RemoteShardInfo,
-    ShardTransferInfo, UpdateQueueInfo, UpdateResult, UpdateStatus, VectorParams, VectorsConfig,
+    CountResult, EmbeddingModelConfig, LocalShardInfo, OptimizersStatus, RecommendRequestInternal,
+    RemoteShardInfo, ShardTransferInfo, UpdateQueueInfo, UpdateResult, UpdateResultWithIds,
+    UpdateStatus, VectorParams, VectorsConfig,
 };
 use crate::operations::universal_query::collection_query::FeedbackStrategy;
 use crate::optimizers_builder::OptimizersConfig;
@@ -84,6 +87,31 @@ pub fn sharding_method_from_proto(sharding_method: i32) -> Result<ShardingMethod
     }
 }
 
+pub fn non_finite_vector_policy_to_proto(policy: NonFiniteVectorPolicy) -> i32 {
+    match policy {
+        NonFiniteVectorPolicy::Reject => api::grpc::qdrant::NonFiniteVectorPolicy::Reject as i32,
+        NonFiniteVectorPolicy::SanitizeToZero => {
+            api::grpc::qdrant::NonFiniteVectorPolicy::SanitizeToZero as i32
+        }
+        NonFiniteVectorPolicy::Clamp => api::grpc::qdrant::NonFiniteVectorPolicy::Clamp as i32,
+    }
+}
+
+pub fn non_finite_vector_policy_from_proto(policy: i32) -> Result<NonFiniteVectorPolicy, Status> {
+    let policy_grpc = api::grpc::qdrant::NonFiniteVectorPolicy::try_from(policy);
+
+    match policy_grpc {
+        Ok(api::grpc::qdrant::NonFiniteVectorPolicy::Reject) => Ok(NonFiniteVectorPolicy::Reject),
+        Ok(api::grpc::qdrant::NonFiniteVectorPolicy::SanitizeToZero) => {
+            Ok(NonFiniteVectorPolicy::SanitizeToZero)
+        }
+        Ok(api::grpc::qdrant::NonFiniteVectorPolicy::Clamp) => Ok(NonFiniteVectorPolicy::Clamp),
+        Err(err) => Err(Status::invalid_argument(format!(
+            "Cannot convert NonFiniteVectorPolicy: {policy}, error: {err}"
+        ))),
+    }
+}
+
 pub fn write_ordering_to_proto(ordering: WriteOrdering) -> api::grpc::qdrant::WriteOrdering {
     api::grpc::qdrant::WriteOrdering {
         r#type: match ordering {
@@ -247,6 +275,10 @@ impl From<api::grpc::qdrant::HnswConfigDiff> for HnswConfigDiff {
             on_disk,
             payload_m,
             inline_storage,
+            varint_links,
+            m0,
+            ef_construct_upper,
+            neighbor_selection,
         } = value;
         Self {
             m: m.map(|v| v as usize),
@@ -256,6 +288,14 @@ impl From<api::grpc::qdrant::HnswConfigDiff> for HnswConfigDiff {
             on_disk,
             payload_m: payload_m.map(|v| v as usize),
             inline_storage,
+            varint_links,
+            m0: m0.map(|v| v as usize),
+            ef_construct_upper: ef_construct_upper.map(|v| v as usize),
+            neighbor_selection: neighbor_selection
+                .and_then(|x|
+                    // XXX: Invalid values silently converted to None
+                    api::grpc::qdrant::HnswNeighborSelection::try_from(x).ok())
+                .map(HnswNeighborSelection::from),
         }
     }
 }
@@ -270,6 +310,10 @@ impl From<HnswConfigDiff> for api::grpc::qdrant::HnswConfigDiff {
             on_disk,
             payload_m,
             inline_storage,
+            varint_links,
+            m0,
+            ef_construct_upper,
+            neighbor_selection,
         } = value;
         Self {
             m: m.map(|v| v as u64),
@@ -279,6 +323,11 @@ impl From<HnswConfigDiff> for api::grpc::qdrant::HnswConfigDiff {
             on_disk,
             payload_m: payload_m.map(|v| v as u64),
             inline_storage,
+            varint_links,
+            m0: m0.map(|v| v as u64),
+            ef_construct_upper: ef_construct_upper.map(|v| v as u64),
+            neighbor_selection: neighbor_selection
+                .map(|x| api::grpc::qdrant::HnswNeighborSelection::from(x) as i32),
         }
     }
 }
@@ -308,6 +357,10 @@ impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
             read_fan_out_factor,
             on_disk_payload,
             read_fan_out_delay_ms,
+            query_cache_max_entries,
+            query_cache_ttl_sec,
+            non_finite_vector_policy,
+            default_search_params,
         } = value;
         Ok(Self {
             replication_factor: replication_factor
@@ -325,7 +378,13 @@ impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
                 .transpose()?,
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            query_cache_max_entries: query_cache_max_entries.map(|v| v as usize),
+            query_cache_ttl_sec,
             on_disk_payload,
+            non_finite_vector_policy: non_finite_vector_policy
+                .map(non_finite_vector_policy_from_proto)
+                .transpose()?,
+            default_search_params: default_search_params.map(SearchParams::from),
         })
     }
 }
@@ -345,6 +404,7 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfigDiff {
             deprecated_max_optimization_threads,
             max_optimization_threads,
             prevent_unoptimized,
+            max_segment_number,
         } = value;
         Ok(Self {
             deleted_threshold,
@@ -362,6 +422,7 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfigDiff {
                     .map(TryFrom::try_from)
                     .transpose()?),
             prevent_unoptimized,
+            max_segment_number: max_segment_number.map(|v| v as usize),
         })
     }
 }
@@ -420,6 +481,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            max_segment_number,
         } = optimizer_config;
 
         let HnswConfig {
@@ -430,6 +492,10 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             on_disk,
             payload_m,
             inline_storage,
+            varint_links,
+            m0,
+            ef_construct_upper,
+            neighbor_selection,
         } = hnsw_config;
 
         let CollectionParams {
@@ -440,8 +506,13 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             on_disk_payload,
             write_consistency_factor,
             read_fan_out_factor,
+            query_cache_max_entries,
+            query_cache_ttl_sec,
             sharding_method,
             sparse_vectors,
+            placement,
+            non_finite_vector_policy,
+            default_search_params,
         } = params;
 
         api::grpc::qdrant::CollectionInfo {
@@ -505,6 +576,12 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                         }
                     }),
                     read_fan_out_delay_ms,
+                    query_cache_max_entries: query_cache_max_entries.map(|v| v as u64),
+                    query_cache_ttl_sec,
+                    placement: placement.map(api::grpc::qdrant::PlacementConstraints::from),
+                    non_finite_vector_policy: non_finite_vector_policy
+                        .map(non_finite_vector_policy_to_proto),
+                    default_search_params: default_search_params.map(grpc::SearchParams::from),
                 }),
                 hnsw_config: Some(api::grpc::qdrant::HnswConfigDiff {
                     m: Some(m as u64),
@@ -514,6 +591,11 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     on_disk,
                     payload_m: payload_m.map(|v| v as u64),
                     inline_storage,
+                    varint_links,
+                    m0: m0.map(|v| v as u64),
+                    ef_construct_upper: ef_construct_upper.map(|v| v as u64),
+                    neighbor_selection: neighbor_selection
+                        .map(|x| api::grpc::qdrant::HnswNeighborSelection::from(x) as i32),
                 }),
                 optimizer_config: Some(api::grpc::qdrant::OptimizersConfigDiff {
                     deleted_threshold: Some(deleted_threshold),
@@ -526,6 +608,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     deprecated_max_optimization_threads: max_optimization_threads.map(|x| x as u64),
                     max_optimization_threads: Some(From::from(max_optimization_threads)),
                     prevent_unoptimized,
+                    max_segment_number: max_segment_number.map(|x| x as u64),
                 }),
                 wal_config: wal_config.map(|wal_config| {
                     let WalConfig {
@@ -631,6 +714,7 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfig {
             deprecated_max_optimization_threads,
             max_optimization_threads,
             prevent_unoptimized,
+            max_segment_number,
         } = optimizer_config;
 
         let converted_max_optimization_threads: Option<usize> =
@@ -653,6 +737,7 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfig {
             flush_interval_sec: flush_interval_sec.unwrap_or_default(),
             max_optimization_threads: converted_max_optimization_threads,
             prevent_unoptimized,
+            max_segment_number: max_segment_number.map(|x| x as usize),
         })
     }
 }
@@ -725,6 +810,8 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
             on_disk,
             datatype,
             multivector_config,
+            skip_normalization,
+            model,
         } = vector_params;
         Ok(Self {
             size: NonZeroU64::new(size).ok_or_else(|| {
@@ -740,6 +827,8 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
             multivector_config: multivector_config
                 .map(MultiVectorConfig::try_from)
                 .transpose()?,
+            skip_normalization,
+            model: model.map(EmbeddingModelConfig::try_from).transpose()?,
         })
     }
 }
@@ -932,24 +1021,73 @@ pub fn try_points_selector_from_grpc(
                 shard_key: shard_key_selector
                     .map(ShardKeySelector::try_from)
                     .transpose()?,
+                return_ids: None,
             }))
         }
         _ => Err(Status::invalid_argument("Malformed PointsSelector type")),
     }
 }
 
+impl TryFrom<api::grpc::qdrant::ReturnIds> for ReturnIds {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::ReturnIds) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::ReturnIds { limit, offset } = value;
+        Ok(Self {
+            limit: limit as usize,
+            offset: offset.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl From<ReturnIds> for api::grpc::qdrant::ReturnIds {
+    fn from(value: ReturnIds) -> Self {
+        let ReturnIds { limit, offset } = value;
+        Self {
+            limit: limit as u64,
+            offset: offset.map(Into::into),
+        }
+    }
+}
+
 impl From<UpdateResult> for api::grpc::qdrant::UpdateResultInternal {
     fn from(res: UpdateResult) -> Self {
         let UpdateResult {
             operation_id,
             status,
             clock_tag,
+            affected_ids,
         } = res;
         Self {
             operation_id,
             status: status.into(),
             clock_tag: clock_tag.map(Into::into),
+            affected_ids: affected_ids
+                .into_iter()
+                .flatten()
+                .map(api::grpc::qdrant::PointId::from)
+                .collect(),
+            next_page_offset: None,
+        }
+    }
+}
+
+impl From<UpdateResultWithIds> for api::grpc::qdrant::UpdateResultInternal {
+    fn from(res: UpdateResultWithIds) -> Self {
+        let UpdateResultWithIds {
+            result,
+            affected_ids,
+            next_page_offset,
+        } = res;
+        let mut res = Self::from(result);
+        if let Some(affected_ids) = affected_ids {
+            res.affected_ids = affected_ids
+                .into_iter()
+                .map(api::grpc::qdrant::PointId::from)
+                .collect();
         }
+        res.next_page_offset = next_page_offset.map(api::grpc::qdrant::PointId::from);
+        res
     }
 }
 
@@ -967,11 +1105,25 @@ impl TryFrom<api::grpc::qdrant::UpdateResultInternal> for UpdateResult {
             operation_id,
             status,
             clock_tag,
+            affected_ids,
+            next_page_offset: _,
         } = res;
+        // Ids of the points matching the filter, as returned by the shard of another peer
+        let affected_ids = if affected_ids.is_empty() {
+            None
+        } else {
+            Some(
+                affected_ids
+                    .into_iter()
+                    .map(PointIdType::try_from)
+                    .collect::<Result<_, _>>()?,
+            )
+        };
         let res = Self {
             operation_id,
             status: status.try_into()?,
             clock_tag: clock_tag.map(ClockTag::from),
+            affected_ids,
         };
 
         Ok(res)
@@ -1023,18 +1175,22 @@ impl TryFrom<i32> for UpdateStatus {
 
 impl From<api::grpc::qdrant::CountResult> for CountResult {
     fn from(value: api::grpc::qdrant::CountResult) -> Self {
-        let api::grpc::qdrant::CountResult { count } = value;
+        let api::grpc::qdrant::CountResult { count, min, max } = value;
         Self {
             count: count as usize,
+            min: min.map(|min| min as usize),
+            max: max.map(|max| max as usize),
         }
     }
 }
 
 impl From<CountResult> for api::grpc::qdrant::CountResult {
     fn from(value: CountResult) -> Self {
-        let CountResult { count } = value;
+        let CountResult { count, min, max } = value;
         Self {
             count: count as u64,
+            min: min.map(|min| min as u64),
+            max: max.map(|max| max as u64),
         }
     }
 }
@@ -1374,6 +1530,8 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             on_disk,
             datatype,
             multivector_config,
+            skip_normalization,
+            model,
         } = value;
         api::grpc::qdrant::VectorParams {
             size: size.get(),
@@ -1389,6 +1547,45 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             on_disk,
             datatype: datatype.map(|dt| api::grpc::qdrant::Datatype::from(dt).into()),
             multivector_config: multivector_config.map(api::grpc::qdrant::MultiVectorConfig::from),
+            skip_normalization,
+            model: model.map(api::grpc::qdrant::EmbeddingModelConfig::from),
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::EmbeddingModelConfig> for EmbeddingModelConfig {
+    type Error = Status;
+
+    fn try_from(model: api::grpc::qdrant::EmbeddingModelConfig) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::EmbeddingModelConfig {
+            name,
+            version,
+            normalized,
+        } = model;
+        if name.is_empty() {
+            return Err(Status::invalid_argument(
+                "EmbeddingModelConfig name must not be empty",
+            ));
+        }
+        Ok(Self {
+            name,
+            version,
+            normalized,
+        })
+    }
+}
+
+impl From<EmbeddingModelConfig> for api::grpc::qdrant::EmbeddingModelConfig {
+    fn from(model: EmbeddingModelConfig) -> Self {
+        let EmbeddingModelConfig {
+            name,
+            version,
+            normalized,
+        } = model;
+        Self {
+            name,
+            version,
+            normalized,
         }
     }
 }
@@ -1644,6 +1841,32 @@ impl From<ShardTransferMethod> for api::grpc::qdrant::ShardTransferMethod {
     }
 }
 
+impl From<PlacementConstraints> for api::grpc::qdrant::PlacementConstraints {
+    fn from(value: PlacementConstraints) -> Self {
+        let PlacementConstraints {
+            require_labels,
+            anti_affinity,
+        } = value;
+        Self {
+            require_labels: require_labels.into_iter().collect(),
+            anti_affinity,
+        }
+    }
+}
+
+impl From<api::grpc::qdrant::PlacementConstraints> for PlacementConstraints {
+    fn from(value: api::grpc::qdrant::PlacementConstraints) -> Self {
+        let api::grpc::qdrant::PlacementConstraints {
+            require_labels,
+            anti_affinity,
+        } = value;
+        Self {
+            require_labels: require_labels.into_iter().collect(),
+            anti_affinity,
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::CreateShardKey> for CreateShardingKey {
     type Error = Status;
 
@@ -1654,6 +1877,7 @@ impl TryFrom<api::grpc::qdrant::CreateShardKey> for CreateShardingKey {
             replication_factor,
             placement,
             initial_state,
+            placement_constraints,
         } = op;
 
         let res = CreateShardingKey {
@@ -1674,6 +1898,7 @@ impl TryFrom<api::grpc::qdrant::CreateShardKey> for CreateShardingKey {
                 })?,
             placement: (!placement.is_empty()).then_some(placement),
             initial_state: initial_state.map(ReplicaState::try_from).transpose()?,
+            placement_constraints: placement_constraints.map(PlacementConstraints::from),
         };
         Ok(res)
     }
@@ -1848,6 +2073,11 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                         sharding_method,
                         sparse_vectors_config,
                         read_fan_out_delay_ms,
+                        query_cache_max_entries,
+                        query_cache_ttl_sec,
+                        placement,
+                        non_finite_vector_policy,
+                        default_search_params,
                     } = params;
                     CollectionParams {
                         vectors: match vectors_config {
@@ -1905,6 +2135,13 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                             .map(sharding_method_from_proto)
                             .transpose()?,
                         read_fan_out_delay_ms,
+                        query_cache_max_entries: query_cache_max_entries.map(|v| v as usize),
+                        query_cache_ttl_sec,
+                        placement: placement.map(PlacementConstraints::from),
+                        non_finite_vector_policy: non_finite_vector_policy
+                            .map(non_finite_vector_policy_from_proto)
+                            .transpose()?,
+                        default_search_params: default_search_params.map(SearchParams::from),
                     }
                 }
             },
@@ -12,6 +12,8 @@ impl Generalizer for ScrollRequestInternal {
             with_payload,
             with_vector,
             order_by,
+            consistent,
+            view_id: _,
         } = self;
 
         Self {
@@ -21,6 +23,8 @@ impl Generalizer for ScrollRequestInternal {
             with_payload: with_payload.clone(),
             with_vector: with_vector.clone(),
             order_by: order_by.clone(),
+            consistent: *consistent,
+            view_id: None,
         }
     }
 }
@@ -4,6 +4,7 @@ use segment::vector_storage::query::{
     NaiveFeedbackQuery, RecoQuery,
 };
 use shard::query::query_enum::QueryEnum;
+use shard::query::vector_selection::VectorSelectionInternal;
 use sparse::common::sparse_vector::SparseVector;
 use sparse::common::types::DimId;
 
@@ -78,6 +79,27 @@ impl Generalizer for ScoringQuery {
             ScoringQuery::Formula(_) => self.clone(),
             ScoringQuery::Sample(_) => self.clone(),
             ScoringQuery::Mmr(mmr) => ScoringQuery::Mmr(mmr.remove_details()),
+            ScoringQuery::VectorSelection(selection) => {
+                ScoringQuery::VectorSelection(selection.remove_details())
+            }
+        }
+    }
+}
+
+impl Generalizer for VectorSelectionInternal {
+    fn remove_details(&self) -> Self {
+        let Self {
+            key,
+            mapping,
+            default,
+            queries,
+        } = self;
+
+        Self {
+            key: key.clone(),
+            mapping: mapping.clone(),
+            default: default.clone(),
+            queries: queries.iter().map(|query| query.remove_details()).collect(),
         }
     }
 }
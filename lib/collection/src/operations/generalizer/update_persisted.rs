@@ -110,12 +110,16 @@ impl Generalizer for ConditionalInsertOperationInternal {
             points_op,
             condition,
             update_mode,
+            sequence_key,
+            skip_normalization,
         } = self;
 
         Self {
             condition: condition.clone(),
             points_op: points_op.remove_details(),
             update_mode: *update_mode,
+            sequence_key: sequence_key.clone(),
+            skip_normalization: *skip_normalization,
         }
     }
 }
@@ -191,6 +195,9 @@ impl Generalizer for VectorOperations {
             VectorOperations::UpdateVectors(update_vectors) => {
                 VectorOperations::UpdateVectors(update_vectors.remove_details())
             }
+            VectorOperations::AppendVectors(append_vectors) => {
+                VectorOperations::AppendVectors(append_vectors.remove_details())
+            }
             VectorOperations::DeleteVectors(_, _) => self.clone(),
             VectorOperations::DeleteVectorsByFilter(_, _) => self.clone(),
         }
@@ -77,7 +77,8 @@ impl EstimateOperationEffectArea for point_ops::PointOperations {
 impl EstimateOperationEffectArea for vector_ops::VectorOperations {
     fn estimate_effect_area(&self) -> OperationEffectArea<'_> {
         match self {
-            vector_ops::VectorOperations::UpdateVectors(update_operation) => {
+            vector_ops::VectorOperations::UpdateVectors(update_operation)
+            | vector_ops::VectorOperations::AppendVectors(update_operation) => {
                 let ids = update_operation.points.iter().map(|p| p.id).collect();
                 OperationEffectArea::Points(Cow::Owned(ids))
             }
@@ -40,6 +40,10 @@ pub struct FilterSelector {
     pub filter: Filter,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Return ids of the points matching the filter, which are updated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub return_ids: Option<ReturnIds>,
 }
 
 /// Defines write ordering guarantees for collection operations
@@ -102,6 +106,8 @@ impl SplitByShard for ConditionalInsertOperationInternal {
             points_op,
             condition,
             update_mode,
+            sequence_key,
+            skip_normalization,
         } = self;
 
         let points_op = points_op.split_by_shard(ring);
@@ -116,6 +122,8 @@ impl SplitByShard for ConditionalInsertOperationInternal {
                                 points_op: upsert_operation,
                                 condition: condition.clone(),
                                 update_mode,
+                                sequence_key: sequence_key.clone(),
+                                skip_normalization,
                             },
                         )
                     })
@@ -125,6 +133,8 @@ impl SplitByShard for ConditionalInsertOperationInternal {
                 points_op: upsert_operation,
                 condition,
                 update_mode,
+                sequence_key,
+                skip_normalization,
             }),
         }
     }
@@ -399,6 +409,8 @@ mod tests {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            sequence_key: None,
+            skip_normalization: None,
         });
         assert!(batch.validate().is_err());
 
@@ -411,6 +423,8 @@ mod tests {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            sequence_key: None,
+            skip_normalization: None,
         });
         assert!(batch.validate().is_ok());
 
@@ -423,6 +437,8 @@ mod tests {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            sequence_key: None,
+            skip_normalization: None,
         });
         assert!(batch.validate().is_err());
     }
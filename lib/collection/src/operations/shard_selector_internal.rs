@@ -3,7 +3,7 @@ use segment::types::ShardKey;
 
 use crate::shards::shard::ShardId;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Hash)]
 pub enum ShardSelectorInternal {
     /// No shard key specified
     Empty,
@@ -5,7 +5,10 @@ use std::time::Duration;
 
 use common::load_concurrency::LoadConcurrencyConfig;
 use segment::types::HnswGlobalConfig;
+use shard::wal::WalBackendType;
 
+use crate::collection::quota::CollectionQuotaConfig;
+use crate::common::score_drift::ScoreDriftConfig;
 use crate::common::snapshots_manager::SnapshotsConfig;
 use crate::operations::types::NodeType;
 use crate::shards::transfer::ShardTransferMethod;
@@ -42,6 +45,10 @@ pub struct SharedStorageConfig {
     pub hnsw_global_config: HnswGlobalConfig,
     pub load_concurrency_config: LoadConcurrencyConfig,
     pub search_thread_count: usize,
+    pub score_drift_config: ScoreDriftConfig,
+    pub coalesce_identical_queries: bool,
+    pub wal_backend: WalBackendType,
+    pub collection_quota: CollectionQuotaConfig,
 }
 
 impl Default for SharedStorageConfig {
@@ -62,6 +69,10 @@ impl Default for SharedStorageConfig {
             hnsw_global_config: HnswGlobalConfig::default(),
             load_concurrency_config: LoadConcurrencyConfig::default(),
             search_thread_count: common::defaults::search_thread_count(common::cpu::get_num_cpus()),
+            score_drift_config: ScoreDriftConfig::default(),
+            coalesce_identical_queries: false,
+            wal_backend: WalBackendType::default(),
+            collection_quota: CollectionQuotaConfig::default(),
         }
     }
 }
@@ -84,6 +95,10 @@ impl SharedStorageConfig {
         hnsw_global_config: HnswGlobalConfig,
         load_concurrency_config: LoadConcurrencyConfig,
         search_thread_count: usize,
+        score_drift_config: ScoreDriftConfig,
+        coalesce_identical_queries: bool,
+        wal_backend: WalBackendType,
+        collection_quota: CollectionQuotaConfig,
     ) -> Self {
         let update_queue_size = update_queue_size.unwrap_or(match node_type {
             NodeType::Normal => DEFAULT_UPDATE_QUEUE_SIZE,
@@ -105,6 +120,10 @@ impl SharedStorageConfig {
             hnsw_global_config,
             load_concurrency_config,
             search_thread_count,
+            score_drift_config,
+            coalesce_identical_queries,
+            wal_backend,
+            collection_quota,
         }
     }
 }
@@ -25,6 +25,7 @@ use segment::common::operation_error::{CancelledError, OperationError};
 use segment::data_types::groups::GroupId;
 use segment::data_types::modifier::Modifier;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, DenseVector};
+use segment::index::field_index::CardinalityEstimation;
 use segment::types::{
     Distance, Filter, HnswConfig, MultiVectorConfig, Payload, PayloadIndexInfo, PayloadKeyType,
     PointIdType, QuantizationConfig, SearchParams, SeqNumberType, ShardKey,
@@ -32,8 +33,7 @@ use segment::types::{
     VectorStorageDatatype, WithPayloadInterface, WithVector,
 };
 use semver::Version;
-use serde;
-use serde::{Deserialize, Serialize};
+use serde::{self, Deserialize, Serialize};
 use serde_json::{Error as JsonError, Map, Value};
 pub use shard::count::CountRequestInternal;
 use shard::payload_index_schema::PayloadIndexSchema;
@@ -514,7 +514,7 @@ impl UpdateStatus {
     }
 }
 
-#[derive(Copy, Clone, Debug, Serialize, JsonSchema)]
+#[derive(Clone, Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct UpdateResult {
     /// Sequential number of the operation
@@ -528,6 +528,38 @@ pub struct UpdateResult {
     /// Provided if incoming update request also specify clock tick
     #[serde(skip)]
     pub clock_tag: Option<ClockTag>,
+
+    /// Ids of the points matching the filter of the operation, if requested.
+    /// Sorted, up to one more than the requested limit.
+    #[serde(skip)]
+    pub affected_ids: Option<Vec<PointIdType>>,
+}
+
+/// Update result with ids of the points, which were selected by filter and updated
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct UpdateResultWithIds {
+    #[serde(flatten)]
+    pub result: UpdateResult,
+
+    /// Ids of the updated points, if requested with `return_ids`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affected_ids: Option<Vec<PointIdType>>,
+
+    /// Offset to return the next page of ids of the points matching the filter.
+    /// None if there are no more points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_offset: Option<PointIdType>,
+}
+
+impl From<UpdateResult> for UpdateResultWithIds {
+    fn from(result: UpdateResult) -> Self {
+        Self {
+            result,
+            affected_ids: None,
+            next_page_offset: None,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -577,6 +609,9 @@ pub struct ScrollResult {
     pub points: Vec<api::rest::Record>,
     /// Offset which should be used to retrieve a next page result
     pub next_page_offset: Option<PointIdType>,
+    /// Identifier of the consistent scroll, which should be used to retrieve a next page result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -946,6 +981,43 @@ pub struct CountRequest {
 pub struct CountResult {
     /// Number of points which satisfy the conditions
     pub count: usize,
+    /// Lower bound of the number of points. Only returned for approximate counts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<usize>,
+    /// Upper bound of the number of points. Only returned for approximate counts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<usize>,
+}
+
+impl CountResult {
+    pub fn exact(count: usize) -> Self {
+        Self {
+            count,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Approximate count with bounds, taken from the payload indexes
+    pub fn approximate(estimation: &CardinalityEstimation) -> Self {
+        Self {
+            count: estimation.exp,
+            min: Some(estimation.min),
+            max: Some(estimation.max),
+        }
+    }
+
+    /// Count of the union of disjoint sets of points, e.g. of different shards.
+    ///
+    /// Bounds are only kept if both counts have them.
+    pub fn merge_disjoint(self, other: Self) -> Self {
+        let add_bounds = |a: Option<usize>, b: Option<usize>| Some(a? + b?);
+        Self {
+            count: self.count + other.count,
+            min: add_bounds(self.min, other.min),
+            max: add_bounds(self.max, other.max),
+        }
+    }
 }
 
 #[derive(Error, Debug, Clone, PartialEq)]
@@ -997,6 +1069,8 @@ pub enum CollectionError {
     },
     #[error("Shard temporarily unavailable: {description}")]
     ShardUnavailable { description: String },
+    #[error("Quota exceeded: {description}")]
+    QuotaExceeded { description: String },
 }
 
 impl CollectionError {
@@ -1118,6 +1192,12 @@ impl CollectionError {
         }
     }
 
+    pub fn quota_exceeded(description: impl Into<String>) -> Self {
+        Self::QuotaExceeded {
+            description: description.into(),
+        }
+    }
+
     /// Returns true if the error is transient and the operation can be retried.
     /// Returns false if the error is not transient and the operation should fail on all replicas.
     pub fn is_transient(&self) -> bool {
@@ -1141,6 +1221,7 @@ impl CollectionError {
             Self::StrictMode { .. } => false,
             Self::InferenceError { .. } => false,
             Self::RateLimitExceeded { .. } => false,
+            Self::QuotaExceeded { .. } => false,
         }
     }
 
@@ -1220,8 +1301,21 @@ impl From<OperationError> for CollectionError {
             },
             OperationError::MissingRangeIndexForOrderBy { .. } => Self::bad_input(format!("{err}")),
             OperationError::MissingMapIndexForFacet { .. } => Self::bad_input(format!("{err}")),
+            OperationError::MissingNumericIndexForQuantiles { .. } => {
+                Self::bad_input(format!("{err}"))
+            }
+            OperationError::MissingFullTextIndexForTermStats { .. } => {
+                Self::bad_input(format!("{err}"))
+            }
+            OperationError::MissingFullTextIndexForTextScore { .. } => {
+                Self::bad_input(format!("{err}"))
+            }
+            OperationError::MissingHnswIndexForGraphExport { .. } => {
+                Self::bad_input(format!("{err}"))
+            }
             OperationError::VariableTypeError { .. } => Self::bad_input(format!("{err}")),
             OperationError::NonFiniteNumber { .. } => Self::bad_input(format!("{err}")),
+            OperationError::NonFiniteVector { .. } => Self::bad_input(format!("{err}")),
             OperationError::RocksDbColumnFamilyNotFound { .. } => Self::ServiceError {
                 error: format!("{err}"),
                 backtrace: None,
@@ -1504,6 +1598,40 @@ pub struct VectorParams {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub multivector_config: Option<MultiVectorConfig>,
+
+    /// If true, vectors are expected to be normalized by the client and server-side
+    /// normalization for cosine distance is skipped on ingest, saving CPU.
+    /// A sample of the upserted vectors is still checked to be normalized.
+    /// Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_normalization: Option<bool>,
+
+    /// Embedding model producing the vectors. If set, upserted vectors are checked to be
+    /// produced by this model, so vectors of another model don't silently mix into the collection.
+    /// Dimension of the model is the `size` of the vectors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub model: Option<EmbeddingModelConfig>,
+}
+
+/// Metadata of the embedding model producing vectors
+#[derive(
+    Debug, Hash, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+#[anonymize(false)]
+pub struct EmbeddingModelConfig {
+    /// Name of the model. Inference requests for the vector must use this model.
+    #[validate(length(min = 1))]
+    pub name: String,
+    /// Version of the model, for reference only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// If true, the model produces normalized vectors.
+    /// A sample of the upserted vectors is checked to be normalized.
+    /// Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalized: Option<bool>,
 }
 
 /// Validate the value is in `[1, 65536]` or `None`.
@@ -1801,6 +1929,8 @@ impl From<&VectorParams> for VectorParamsBase {
             on_disk: _,
             datatype: _,
             multivector_config: _,
+            skip_normalization: _,
+            model: _,
         } = params;
         Self {
             size: size.get() as _, // TODO!?
@@ -1819,6 +1949,7 @@ impl From<&segment::types::VectorDataConfig> for VectorParamsBase {
             quantization_config: _,
             multivector_config: _,
             datatype: _,
+            skip_normalization: _,
         } = config;
         Self { size, distance }
     }
@@ -1953,15 +2084,23 @@ pub struct PeerMetadata {
     /// Peer Qdrant version
     #[schemars(schema_with = "String::json_schema")]
     pub(crate) version: Version,
+    /// Peer labels, used to honor placement constraints
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) labels: BTreeMap<String, String>,
 }
 
 impl PeerMetadata {
-    pub fn current() -> Self {
+    pub fn current(labels: BTreeMap<String, String>) -> Self {
         Self {
             version: defaults::QDRANT_VERSION.clone(),
+            labels,
         }
     }
 
+    pub fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
     /// Whether this metadata has a different version than our current Qdrant instance.
     pub fn is_different_version(&self) -> bool {
         self.version != *defaults::QDRANT_VERSION
@@ -8,14 +8,15 @@ use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorIntern
 use segment::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use segment::json_path::JsonPath;
 use segment::types::{
-    Condition, ExtendedPointId, Filter, HasIdCondition, PointIdType, SearchParams, VectorName,
-    VectorNameBuf, WithPayloadInterface, WithVector,
+    Condition, ExtendedPointId, Filter, HasIdCondition, PointIdType, SearchParams, ValueVariants,
+    VectorName, VectorNameBuf, WithPayloadInterface, WithVector,
 };
 use segment::vector_storage::query::{
     ContextPair, ContextQuery, DiscoveryQuery, FeedbackItem, NaiveFeedbackCoefficients, RecoQuery,
 };
 use serde::Serialize;
 use shard::query::query_enum::QueryEnum;
+use shard::query::vector_selection::VectorSelectionInternal;
 
 use super::formula::FormulaInternal;
 use super::shard_query::{
@@ -102,6 +103,9 @@ pub enum Query {
 
     /// Sample points
     Sample(SampleInternal),
+
+    /// Score each point with the vector selected by a value of its payload field
+    VectorSelection(VectorSelection),
 }
 
 impl Query {
@@ -126,11 +130,25 @@ impl Query {
             Query::OrderBy(order_by) => ScoringQuery::OrderBy(order_by),
             Query::Formula(formula) => ScoringQuery::Formula(ParsedFormula::try_from(formula)?),
             Query::Sample(sample) => ScoringQuery::Sample(sample),
+            Query::VectorSelection(selection) => selection.try_into_scoring_query()?,
         };
 
         Ok(scoring_query)
     }
 
+    /// Points, the query is limited to
+    pub fn candidates(&self) -> Option<&[PointIdType]> {
+        match self {
+            Query::Vector(VectorQuery::NearestAmongCandidates(query)) => Some(&query.candidates),
+            Query::Vector(_)
+            | Query::Fusion(_)
+            | Query::OrderBy(_)
+            | Query::Formula(_)
+            | Query::Sample(_)
+            | Query::VectorSelection(_) => None,
+        }
+    }
+
     pub fn get_referenced_ids(&self) -> Vec<PointIdType> {
         match self {
             Self::Vector(vector_query) => vector_query
@@ -138,8 +156,75 @@ impl Query {
                 .into_iter()
                 .copied()
                 .collect(),
-            Self::Fusion(_) | Self::OrderBy(_) | Self::Formula(_) | Self::Sample(_) => Vec::new(),
+            Self::Fusion(_)
+            | Self::OrderBy(_)
+            | Self::Formula(_)
+            | Self::Sample(_)
+            | Self::VectorSelection(_) => Vec::new(),
+        }
+    }
+}
+
+/// Selection of the vector to score a point with, based on a value of its payload field
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorSelection {
+    pub key: JsonPath,
+    /// Payload value -> name of the vector it selects
+    pub mapping: Vec<(ValueVariants, VectorNameBuf)>,
+    /// Vector for points without any of the mapped values
+    pub default: Option<VectorNameBuf>,
+    /// Query for each of the selectable vectors
+    pub queries: Vec<(VectorNameBuf, VectorInputInternal)>,
+}
+
+impl VectorSelection {
+    fn try_into_scoring_query(self) -> CollectionResult<ScoringQuery> {
+        let Self {
+            key,
+            mapping,
+            default,
+            queries,
+        } = self;
+
+        let queries = queries
+            .into_iter()
+            .map(|(vector_name, input)| match input {
+                VectorInputInternal::Vector(mut vector) => {
+                    vector.preprocess();
+                    Ok(NamedQuery::new(vector, vector_name))
+                }
+                // Each query would need a lookup of its own vector, which is not supported
+                VectorInputInternal::Id(_) => Err(CollectionError::bad_request(format!(
+                    "Query for vector `{vector_name}` of a vector selection must be a vector, not a point id",
+                ))),
+            })
+            .try_collect()?;
+
+        Ok(ScoringQuery::VectorSelection(VectorSelectionInternal {
+            key,
+            mapping,
+            default,
+            queries,
+        }))
+    }
+
+    fn validate(&self) -> CollectionResult<()> {
+        let selectable = self
+            .mapping
+            .iter()
+            .map(|(_, vector_name)| vector_name)
+            .chain(&self.default);
+
+        for vector_name in selectable {
+            if !self.queries.iter().any(|(name, _)| name == vector_name) {
+                return Err(CollectionError::bad_request(format!(
+                    "No query provided for vector `{vector_name}` selected by field `{}`",
+                    self.key,
+                )));
+            }
         }
+
+        Ok(())
     }
 }
 
@@ -162,6 +247,7 @@ impl VectorInputInternal {
 pub enum VectorQuery<T> {
     Nearest(T),
     NearestWithMmr(NearestWithMmr<T>),
+    NearestAmongCandidates(NearestAmongCandidates<T>),
     RecommendAverageVector(RecoQuery<T>),
     RecommendBestScore(RecoQuery<T>),
     RecommendSumScores(RecoQuery<T>),
@@ -176,6 +262,7 @@ impl<T> VectorQuery<T> {
         match self {
             VectorQuery::Nearest(input) => Box::new(std::iter::once(input)),
             VectorQuery::NearestWithMmr(query) => Box::new(std::iter::once(&query.nearest)),
+            VectorQuery::NearestAmongCandidates(query) => Box::new(std::iter::once(&query.nearest)),
             VectorQuery::RecommendAverageVector(query)
             | VectorQuery::RecommendBestScore(query)
             | VectorQuery::RecommendSumScores(query) => Box::new(query.flat_iter()),
@@ -192,6 +279,14 @@ pub struct NearestWithMmr<T> {
     pub mmr: Mmr,
 }
 
+/// Score only the given points against the vector, without traversing the vector index
+#[derive(Clone, Debug, PartialEq)]
+pub struct NearestAmongCandidates<T> {
+    pub nearest: T,
+    pub candidates: Vec<PointIdType>,
+    pub mmr: Option<Mmr>,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct Mmr {
     pub diversity: Option<f32>,
@@ -332,6 +427,23 @@ impl VectorQuery<VectorInputInternal> {
 
                 Ok(VectorQuery::NearestWithMmr(NearestWithMmr { nearest, mmr }))
             }
+            VectorQuery::NearestAmongCandidates(NearestAmongCandidates {
+                nearest,
+                candidates,
+                mmr,
+            }) => {
+                let nearest = ids_to_vectors
+                    .resolve_reference(lookup_collection, lookup_vector_name, nearest)
+                    .ok_or_else(|| vector_not_found_error(lookup_vector_name))?;
+
+                Ok(VectorQuery::NearestAmongCandidates(
+                    NearestAmongCandidates {
+                        nearest,
+                        candidates,
+                        mmr,
+                    },
+                ))
+            }
             VectorQuery::Feedback(FeedbackInternal {
                 target,
                 feedback,
@@ -433,6 +545,13 @@ impl VectorQuery<VectorInternal> {
             VectorQuery::NearestWithMmr(NearestWithMmr { nearest, mmr: _ }) => {
                 nearest.preprocess();
             }
+            VectorQuery::NearestAmongCandidates(NearestAmongCandidates {
+                nearest,
+                candidates: _,
+                mmr: _,
+            }) => {
+                nearest.preprocess();
+            }
             VectorQuery::Feedback(FeedbackInternal {
                 target,
                 feedback,
@@ -473,18 +592,16 @@ impl VectorQuery<VectorInternal> {
             }
             VectorQuery::Context(context) => QueryEnum::Context(NamedQuery::new(context, using)),
             VectorQuery::NearestWithMmr(NearestWithMmr { nearest, mmr }) => {
-                let Mmr {
-                    diversity,
-                    candidates_limit,
-                } = mmr;
-
-                return Ok(ScoringQuery::Mmr(MmrInternal {
-                    vector: nearest,
-                    using,
-                    lambda: OrderedFloat(diversity.map(|x| 1.0 - x).unwrap_or(DEFAULT_MMR_LAMBDA)),
-                    candidates_limit: candidates_limit.unwrap_or(request_limit),
-                }));
+                return Ok(mmr_scoring_query(nearest, mmr, using, request_limit));
             }
+            VectorQuery::NearestAmongCandidates(NearestAmongCandidates {
+                nearest,
+                candidates: _, // Applied to the filter of the request
+                mmr,
+            }) => match mmr {
+                Some(mmr) => return Ok(mmr_scoring_query(nearest, mmr, using, request_limit)),
+                None => QueryEnum::Nearest(NamedQuery::new(nearest, using)),
+            },
             VectorQuery::Feedback(FeedbackInternal {
                 target,
                 feedback,
@@ -509,6 +626,25 @@ impl VectorQuery<VectorInternal> {
     }
 }
 
+fn mmr_scoring_query(
+    nearest: VectorInternal,
+    mmr: Mmr,
+    using: VectorNameBuf,
+    request_limit: usize,
+) -> ScoringQuery {
+    let Mmr {
+        diversity,
+        candidates_limit,
+    } = mmr;
+
+    ScoringQuery::Mmr(MmrInternal {
+        vector: nearest,
+        using,
+        lambda: OrderedFloat(diversity.map(|x| 1.0 - x).unwrap_or(DEFAULT_MMR_LAMBDA)),
+        candidates_limit: candidates_limit.unwrap_or(request_limit),
+    })
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct CollectionPrefetch {
     pub prefetch: Vec<CollectionPrefetch>,
@@ -534,6 +670,30 @@ fn exclude_referenced_ids(ids: Vec<ExtendedPointId>, filter: Option<Filter>) ->
     Some(id_filter.merge_owned(filter.unwrap_or_default()))
 }
 
+/// Limit the search to the candidate points of the query by editing the filter and params.
+///
+/// There are few candidates, so they are scored exactly instead of traversing the vector index.
+fn restrict_to_candidates(
+    query: Option<&Query>,
+    filter: Option<Filter>,
+    params: Option<SearchParams>,
+) -> (Option<Filter>, Option<SearchParams>) {
+    let Some(candidates) = query.and_then(Query::candidates) else {
+        return (filter, params);
+    };
+
+    let ids: AHashSet<_> = candidates.iter().copied().collect();
+    let id_filter = Filter::new_must(Condition::HasId(HasIdCondition::from(ids)));
+    let filter = id_filter.merge_owned(filter.unwrap_or_default());
+
+    let params = SearchParams {
+        exact: true,
+        ..params.unwrap_or_default()
+    };
+
+    (Some(filter), Some(params))
+}
+
 impl CollectionPrefetch {
     fn get_lookup_collection(&self) -> Option<&String> {
         self.lookup_from.as_ref().map(|x| &x.collection)
@@ -584,6 +744,9 @@ impl CollectionPrefetch {
         let lookup_collection = self.get_lookup_collection().cloned();
         let using = self.using.clone();
 
+        let (filter, params) =
+            restrict_to_candidates(self.query.as_ref(), self.filter, self.params);
+
         let query = self
             .query
             .map(|query| {
@@ -606,10 +769,10 @@ impl CollectionPrefetch {
         Ok(ShardPrefetch {
             prefetches,
             query,
-            filter: self.filter,
+            filter,
             score_threshold: self.score_threshold,
             limit: self.limit,
-            params: self.params,
+            params,
         })
     }
 
@@ -706,6 +869,7 @@ impl CollectionQueryRequest {
         let referenced_point_ids = self.get_referenced_point_ids_on_collection(collection_name);
 
         let filter = exclude_referenced_ids(referenced_point_ids, self.filter);
+        let (filter, params) = restrict_to_candidates(self.query.as_ref(), filter, self.params);
 
         let query = self
             .query
@@ -733,7 +897,7 @@ impl CollectionQueryRequest {
             score_threshold: self.score_threshold.map(OrderedFloat),
             limit: self.limit,
             offset,
-            params: self.params,
+            params,
             with_vector: self.with_vector,
             with_payload: self.with_payload,
         })
@@ -778,6 +942,127 @@ impl CollectionQueryRequest {
             ));
         }
 
+        // Vector selection searches each of the selected vectors, there are no candidates to rescore
+        if let Some(Query::VectorSelection(selection)) = query {
+            if !prefetch.is_empty() {
+                return Err(CollectionError::bad_request(
+                    "Vector selection can't be applied over prefetches, use it as a query of a prefetch instead.",
+                ));
+            }
+            selection.validate()?;
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use segment::types::FieldCondition;
+
+    use super::*;
+
+    #[test]
+    fn test_restrict_to_candidates() {
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            "city".try_into().unwrap(),
+            "Berlin".to_string().into(),
+        )));
+
+        let nearest = Query::Vector(VectorQuery::Nearest(VectorInputInternal::Vector(
+            VectorInternal::from(vec![1.0, 2.0]),
+        )));
+        assert_eq!(
+            restrict_to_candidates(Some(&nearest), Some(filter.clone()), None),
+            (Some(filter.clone()), None),
+        );
+
+        let candidates = vec![PointIdType::NumId(1), PointIdType::NumId(2)];
+        let among_candidates = Query::Vector(VectorQuery::NearestAmongCandidates(
+            NearestAmongCandidates {
+                nearest: VectorInputInternal::Vector(VectorInternal::from(vec![1.0, 2.0])),
+                candidates: candidates.clone(),
+                mmr: None,
+            },
+        ));
+        let params = SearchParams {
+            hnsw_ef: Some(128),
+            ..Default::default()
+        };
+        let (restricted_filter, restricted_params) =
+            restrict_to_candidates(Some(&among_candidates), Some(filter.clone()), Some(params));
+
+        let id_filter = Filter::new_must(Condition::HasId(HasIdCondition::from(
+            candidates.into_iter().collect::<AHashSet<_>>(),
+        )));
+        assert_eq!(restricted_filter, Some(id_filter.merge_owned(filter)));
+        assert_eq!(
+            restricted_params,
+            Some(SearchParams {
+                hnsw_ef: Some(128),
+                exact: true,
+                ..Default::default()
+            }),
+        );
+    }
+
+    #[test]
+    fn test_vector_selection_validation() {
+        let vector = || VectorInputInternal::Vector(VectorInternal::from(vec![1.0, 2.0]));
+        let selection = |queries| {
+            Query::VectorSelection(VectorSelection {
+                key: "lang".try_into().unwrap(),
+                mapping: vec![
+                    (ValueVariants::String("en".into()), "english".into()),
+                    (ValueVariants::String("de".into()), "german".into()),
+                ],
+                default: None,
+                queries,
+            })
+        };
+        let validate = |query: &Query, prefetch: &[CollectionPrefetch]| {
+            CollectionQueryRequest::validation(
+                &Some(query.clone()),
+                &DEFAULT_VECTOR_NAME.to_owned(),
+                prefetch,
+                None,
+            )
+        };
+
+        let valid = selection(vec![
+            ("english".into(), vector()),
+            ("german".into(), vector()),
+        ]);
+        assert!(validate(&valid, &[]).is_ok());
+
+        // Every selectable vector needs a query
+        let missing_query = selection(vec![("english".into(), vector())]);
+        assert!(validate(&missing_query, &[]).is_err());
+
+        // Nothing to rescore with a vector selection
+        let prefetch = CollectionPrefetch {
+            prefetch: Vec::new(),
+            query: None,
+            using: DEFAULT_VECTOR_NAME.to_owned(),
+            filter: None,
+            score_threshold: None,
+            limit: 10,
+            params: None,
+            lookup_from: None,
+        };
+        assert!(validate(&valid, &[prefetch]).is_err());
+
+        // Point ids can't be resolved for each of the vectors
+        let by_id = selection(vec![
+            (
+                "english".into(),
+                VectorInputInternal::Id(PointIdType::NumId(1)),
+            ),
+            ("german".into(), vector()),
+        ]);
+        let Query::VectorSelection(by_id) = by_id else {
+            unreachable!()
+        };
+        assert!(by_id.try_into_scoring_query().is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use segment::types::Order;
+use shard::query::vector_selection::VectorSelectionInternal;
 pub use shard::query::*;
 
 use crate::config::CollectionParams;
@@ -35,6 +36,9 @@ pub fn query_result_order(
             ScoringQuery::Sample(SampleInternal::Random) => None,
             // MMR cannot be reordered
             ScoringQuery::Mmr(_) => None,
+            ScoringQuery::VectorSelection(selection) => {
+                Some(vector_selection_order(selection, collection_params)?)
+            }
         },
         None => {
             // Order by ID
@@ -43,3 +47,16 @@ pub fn query_result_order(
     };
     Ok(order)
 }
+
+/// Returns the order of scores, merged from all vectors of the selection
+pub fn vector_selection_order(
+    selection: &VectorSelectionInternal,
+    collection_params: &CollectionParams,
+) -> CollectionResult<Order> {
+    let distances: Vec<_> = selection
+        .selectable_vectors()
+        .map(|vector_name| collection_params.get_distance(vector_name))
+        .collect::<CollectionResult<_>>()?;
+
+    Ok(selection.order(distances)?)
+}
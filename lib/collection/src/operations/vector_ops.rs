@@ -10,6 +10,7 @@ use validator::Validate;
 
 use super::{OperationToShard, SplitByShard, point_to_shards, split_iter_by_shard};
 use crate::hash_ring::HashRingRouter;
+use crate::shards::shard::ShardId;
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate)]
 pub struct DeleteVectors {
@@ -35,35 +36,14 @@ impl SplitByShard for Vec<PointVectors> {
 impl SplitByShard for VectorOperations {
     fn split_by_shard(self, ring: &HashRingRouter) -> OperationToShard<Self> {
         match self {
-            VectorOperations::UpdateVectors(UpdateVectorsOp {
-                points,
-                update_filter,
-            }) => {
-                let shard_points = points
-                    .into_iter()
-                    .flat_map(|point| {
-                        point_to_shards(&point.id, ring)
-                            .into_iter()
-                            .map(move |shard_id| (shard_id, point.clone()))
-                    })
-                    .fold(
-                        AHashMap::new(),
-                        |mut map: AHashMap<u32, Vec<PointVectorsPersisted>>, (shard_id, points)| {
-                            map.entry(shard_id).or_default().push(points);
-                            map
-                        },
-                    );
-                let shard_ops = shard_points.into_iter().map(|(shard_id, points)| {
-                    (
-                        shard_id,
-                        VectorOperations::UpdateVectors(UpdateVectorsOp {
-                            points,
-                            update_filter: update_filter.clone(),
-                        }),
-                    )
-                });
-                OperationToShard::by_shard(shard_ops)
-            }
+            VectorOperations::UpdateVectors(update_vectors) => OperationToShard::by_shard(
+                split_update_vectors_op(update_vectors, ring)
+                    .map(|(shard_id, op)| (shard_id, VectorOperations::UpdateVectors(op))),
+            ),
+            VectorOperations::AppendVectors(append_vectors) => OperationToShard::by_shard(
+                split_update_vectors_op(append_vectors, ring)
+                    .map(|(shard_id, op)| (shard_id, VectorOperations::AppendVectors(op))),
+            ),
             VectorOperations::DeleteVectors(ids, vector_names) => {
                 split_iter_by_shard(ids.points, |id| *id, ring)
                     .map(|ids| VectorOperations::DeleteVectors(ids.into(), vector_names.clone()))
@@ -74,3 +54,38 @@ impl SplitByShard for VectorOperations {
         }
     }
 }
+
+fn split_update_vectors_op(
+    op: UpdateVectorsOp,
+    ring: &HashRingRouter,
+) -> impl Iterator<Item = (ShardId, UpdateVectorsOp)> {
+    let UpdateVectorsOp {
+        points,
+        update_filter,
+    } = op;
+
+    let shard_points = points
+        .into_iter()
+        .flat_map(|point| {
+            point_to_shards(&point.id, ring)
+                .into_iter()
+                .map(move |shard_id| (shard_id, point.clone()))
+        })
+        .fold(
+            AHashMap::new(),
+            |mut map: AHashMap<ShardId, Vec<PointVectorsPersisted>>, (shard_id, points)| {
+                map.entry(shard_id).or_default().push(points);
+                map
+            },
+        );
+
+    shard_points.into_iter().map(move |(shard_id, points)| {
+        (
+            shard_id,
+            UpdateVectorsOp {
+                points,
+                update_filter: update_filter.clone(),
+            },
+        )
+    })
+}
@@ -3,7 +3,7 @@ use std::num::NonZeroU64;
 use segment::types::{Distance, MultiVectorConfig, QuantizationConfig};
 
 use crate::operations::config_diff::HnswConfigDiff;
-use crate::operations::types::{Datatype, VectorParams};
+use crate::operations::types::{Datatype, EmbeddingModelConfig, VectorParams};
 
 pub struct VectorParamsBuilder {
     vector_params: VectorParams,
@@ -20,6 +20,8 @@ impl VectorParamsBuilder {
                 on_disk: None,
                 datatype: None,
                 multivector_config: None,
+                skip_normalization: None,
+                model: None,
             },
         }
     }
@@ -49,6 +51,16 @@ impl VectorParamsBuilder {
         self
     }
 
+    pub fn with_skip_normalization(mut self, skip_normalization: bool) -> Self {
+        self.vector_params.skip_normalization = Some(skip_normalization);
+        self
+    }
+
+    pub fn with_model(mut self, model: EmbeddingModelConfig) -> Self {
+        self.vector_params.model = Some(model);
+        self
+    }
+
     pub fn build(self) -> VectorParams {
         self.vector_params
     }
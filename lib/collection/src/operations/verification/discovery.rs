@@ -1,4 +1,4 @@
-use segment::types::{Filter, SearchParams, StrictModeConfig};
+use segment::types::{Filter, SearchParams, StrictModeConfig, WithVector};
 
 use super::StrictModeVerification;
 use crate::collection::Collection;
@@ -24,6 +24,12 @@ impl StrictModeVerification for DiscoverRequestInternal {
     fn request_search_params(&self) -> Option<&SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        self.with_vector
+            .as_ref()
+            .map(|with_vector| (with_vector, self.limit))
+    }
 }
 
 impl StrictModeVerification for DiscoverRequestBatch {
@@ -0,0 +1,27 @@
+use api::rest::ExplainFilterRequest;
+use segment::types::{Filter, SearchParams};
+
+use super::StrictModeVerification;
+
+impl StrictModeVerification for ExplainFilterRequest {
+    fn query_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        // Explaining unindexed filters is the purpose of the request, nothing is read
+        None
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+}
@@ -1,3 +1,4 @@
+use segment::types::WithVector;
 use shard::scroll::ScrollRequestInternal;
 
 use super::StrictModeVerification;
@@ -23,6 +24,13 @@ impl StrictModeVerification for ScrollRequestInternal {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         None
     }
+
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        let limit = self
+            .limit
+            .unwrap_or_else(ScrollRequestInternal::default_limit);
+        Some((&self.with_vector, limit))
+    }
 }
 
 impl StrictModeVerification for PointRequestInternal {
@@ -45,4 +53,8 @@ impl StrictModeVerification for PointRequestInternal {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         None
     }
+
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        Some((&self.with_vector, self.ids.len()))
+    }
 }
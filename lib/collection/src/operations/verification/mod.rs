@@ -1,8 +1,10 @@
 mod count;
 mod discovery;
 mod facet;
+mod filter_explanation;
 mod local_shard;
 mod matrix;
+mod quantiles;
 mod query;
 mod recommend;
 mod search;
@@ -12,7 +14,7 @@ use std::fmt::Display;
 
 use itertools::Itertools;
 use segment::json_path::JsonPath;
-use segment::types::{Filter, SearchParams, StrictModeConfig};
+use segment::types::{Filter, SearchParams, StrictModeConfig, WithVector};
 pub use shard::operation_rate_cost;
 
 use super::types::{CollectionError, CollectionResult};
@@ -63,6 +65,12 @@ pub trait StrictModeVerification {
 
     fn request_search_params(&self) -> Option<&SearchParams>;
 
+    /// Implement this to check the size of vectors returned by a request.
+    /// Returns the requested vectors, and the max number of points returned with them.
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        None
+    }
+
     /// Checks the 'exact' parameter.
     fn check_request_exact(&self, strict_mode_config: &StrictModeConfig) -> CollectionResult<()> {
         check_bool_opt(
@@ -148,6 +156,38 @@ pub trait StrictModeVerification {
         Ok(())
     }
 
+    /// Checks the estimated size of vectors returned by the request.
+    #[allow(async_fn_in_trait)]
+    async fn check_response_vectors(
+        &self,
+        collection: &Collection,
+        strict_mode_config: &StrictModeConfig,
+    ) -> CollectionResult<()> {
+        let Some(max_bytes) = strict_mode_config.max_response_vectors_bytes else {
+            return Ok(());
+        };
+        let Some((with_vector, points)) = self.request_vectors() else {
+            return Ok(());
+        };
+        if !with_vector.is_enabled() {
+            return Ok(());
+        }
+
+        let point_bytes =
+            estimate_point_vectors_bytes(with_vector, collection, strict_mode_config).await;
+        let bytes = points.saturating_mul(point_bytes);
+        if bytes > max_bytes {
+            return Err(CollectionError::strict_mode(
+                format!(
+                    "Estimated size of returned vectors exceeds the limit ({bytes} > {max_bytes} bytes)"
+                ),
+                "Request fewer points or vectors, or set with_vector=false.",
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Does the verification of all configured parameters. Only implement this function if you know what
     /// you are doing. In most cases implementing `check_custom` is sufficient.
     #[allow(async_fn_in_trait)]
@@ -162,10 +202,43 @@ pub trait StrictModeVerification {
         self.check_request_exact(strict_mode_config)?;
         self.check_search_params(collection, strict_mode_config)
             .await?;
+        self.check_response_vectors(collection, strict_mode_config)
+            .await?;
         Ok(())
     }
 }
 
+/// Estimate the size of the requested dense vectors of a single point, in bytes.
+///
+/// Vectors are returned as `f32` regardless of the storage datatype. Multivectors are assumed to
+/// have the max number of vectors allowed in strict mode, or a single vector if not limited.
+async fn estimate_point_vectors_bytes(
+    with_vector: &WithVector,
+    collection: &Collection,
+    strict_mode_config: &StrictModeConfig,
+) -> usize {
+    let config = collection.collection_config.read().await;
+
+    config
+        .params
+        .vectors
+        .params_iter()
+        .filter(|(name, _)| match with_vector {
+            WithVector::Bool(enabled) => *enabled,
+            WithVector::Selector(names) => names.iter().any(|selected| selected == name),
+        })
+        .map(|(name, params)| {
+            let vectors_count = params
+                .multivector_config
+                .and_then(|_| strict_mode_config.multivector_config.as_ref())
+                .and_then(|multivector| multivector.config.get(name))
+                .and_then(|multivector| multivector.max_vectors)
+                .unwrap_or(1);
+            params.size.get() as usize * size_of::<f32>() * vectors_count
+        })
+        .sum()
+}
+
 fn check_filter_limits(
     filter: &Filter,
     strict_mode_config: &StrictModeConfig,
@@ -332,8 +405,8 @@ mod test {
     use common::budget::ResourceBudget;
     use common::counter::hardware_accumulator::HwMeasurementAcc;
     use segment::types::{
-        Condition, FieldCondition, Filter, Match, PayloadFieldSchema, PayloadSchemaType,
-        SearchParams, StrictModeConfig, ValueVariants,
+        Condition, Distance, FieldCondition, Filter, Match, PayloadFieldSchema, PayloadSchemaType,
+        PointIdType, SearchParams, StrictModeConfig, ValueVariants,
     };
     use tempfile::Builder;
 
@@ -343,8 +416,10 @@ mod test {
     use crate::operations::point_ops::{FilterSelector, PointsSelector};
     use crate::operations::shared_storage_config::SharedStorageConfig;
     use crate::operations::types::{
-        CollectionError, CountRequestInternal, DiscoverRequestInternal,
+        CollectionError, CountRequestInternal, DiscoverRequestInternal, PointRequestInternal,
+        VectorsConfig,
     };
+    use crate::operations::vector_params_builder::VectorParamsBuilder;
     use crate::optimizers_builder::OptimizersConfig;
     use crate::shards::channel_service::ChannelService;
     use crate::shards::collection_shard_distribution::CollectionShardDistribution;
@@ -362,6 +437,7 @@ mod test {
         test_filter_read(&collection).await;
         test_filter_write(&collection).await;
         test_request_exact(&collection).await;
+        test_response_vectors(&collection).await;
     }
 
     async fn test_query_limit(collection: &Collection) {
@@ -397,12 +473,14 @@ mod test {
         let restricted_request = PointsSelector::FilterSelector(FilterSelector {
             filter: filter_fixture(UNINDEXED_KEY),
             shard_key: None,
+            return_ids: None,
         });
         assert_strict_mode_error(restricted_request, collection).await;
 
         let allowed_request = PointsSelector::FilterSelector(FilterSelector {
             filter: filter_fixture(INDEXED_KEY),
             shard_key: None,
+            return_ids: None,
         });
         assert_strict_mode_success(allowed_request, collection).await;
     }
@@ -421,6 +499,19 @@ mod test {
         assert_strict_mode_success(request, collection).await;
     }
 
+    async fn test_response_vectors(collection: &Collection) {
+        let request = |points: u64, with_vector: bool| PointRequestInternal {
+            ids: (0..points).map(PointIdType::NumId).collect(),
+            with_payload: None,
+            with_vector: with_vector.into(),
+        };
+
+        // 16 bytes of vectors per point
+        assert_strict_mode_success(request(4, true), collection).await;
+        assert_strict_mode_error(request(5, true), collection).await;
+        assert_strict_mode_success(request(5, false), collection).await;
+    }
+
     async fn assert_strict_mode_error<R: StrictModeVerification>(
         request: R,
         collection: &Collection,
@@ -493,6 +584,7 @@ mod test {
             search_max_hnsw_ef: Some(3),
             search_allow_exact: Some(false),
             search_max_oversampling: Some(0.2),
+            max_response_vectors_bytes: Some(64),
             ..Default::default()
         };
 
@@ -501,7 +593,10 @@ mod test {
 
     async fn fixture_collection(strict_mode_config: &StrictModeConfig) -> Collection {
         let wal_config = WalConfig::default();
-        let collection_params = CollectionParams::empty();
+        let collection_params = CollectionParams {
+            vectors: VectorsConfig::Single(VectorParamsBuilder::new(4, Distance::Dot).build()),
+            ..CollectionParams::empty()
+        };
 
         let config = CollectionConfigInternal {
             params: collection_params,
@@ -0,0 +1,26 @@
+use api::rest::QuantilesRequest;
+use segment::types::{Filter, SearchParams};
+
+use super::StrictModeVerification;
+
+impl StrictModeVerification for QuantilesRequest {
+    fn query_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+}
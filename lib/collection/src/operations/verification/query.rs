@@ -1,12 +1,12 @@
 use itertools::Itertools;
 use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
-use segment::types::{Filter, StrictModeConfig};
+use segment::types::{Filter, StrictModeConfig, WithVector};
 
 use super::{StrictModeVerification, check_grouping_field};
 use crate::collection::Collection;
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::universal_query::collection_query::{
-    CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, Query,
+    CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, Query, VectorQuery,
 };
 
 impl Query {
@@ -34,6 +34,26 @@ impl Query {
                 "Create an index for this key or use a different formula expression.",
             ));
         }
+
+        // Candidates are applied as a condition on point ids
+        if let Some(max_condition_size) = strict_mode_config.condition_max_size
+            && let Some(candidates) = self.candidates()
+            && candidates.len() > max_condition_size
+        {
+            return Err(CollectionError::strict_mode(
+                format!(
+                    "Candidates limit reached ({} > {max_condition_size})",
+                    candidates.len(),
+                ),
+                "Reduce the number of candidates.",
+            ));
+        }
+
+        // Selection key is matched by value, same as a grouping field
+        if let Query::VectorSelection(selection) = self {
+            check_grouping_field(&selection.key, collection, strict_mode_config)?;
+        }
+
         Ok(())
     }
 
@@ -49,81 +69,20 @@ impl Query {
         if strict_mode_config.search_allow_exact == Some(false) {
             match &self {
                 Query::Fusion(_) | Query::OrderBy(_) | Query::Formula(_) | Query::Sample(_) => (),
-                Query::Vector(_) => {
-                    let config = collection.collection_config.read().await;
-
-                    // ignore sparse vectors
-                    let query_targets_sparse = config
-                        .params
-                        .sparse_vectors
-                        .as_ref()
-                        .is_some_and(|sparse| sparse.contains_key(using));
-                    if query_targets_sparse {
-                        // sparse vectors are always indexed
-                        return Ok(());
-                    }
-
-                    // check HNSW configuration for vector
-                    let vector_hnsw_config = &config
-                        .params
-                        .vectors
-                        .get_params(using)
-                        .and_then(|param| param.hnsw_config.as_ref());
-
-                    let vector_hnsw_m = vector_hnsw_config
-                        .map(|hnsw_config| hnsw_config.m)
-                        .flatten()
-                        .unwrap_or(config.hnsw_config.m);
-
-                    let vector_hnsw_payload_m = vector_hnsw_config
-                        .map(|hnsw_config| hnsw_config.payload_m)
-                        .flatten()
-                        .unwrap_or_else(|| config.hnsw_config.payload_m.unwrap_or(vector_hnsw_m));
-
-                    // no further check necessary if there is a global HNSW index
-                    if vector_hnsw_m > 0 {
-                        return Ok(());
-                    }
-
-                    // specialized error message if not default vector
-                    let vector_error_label = if using == DEFAULT_VECTOR_NAME {
-                        ""
-                    } else {
-                        &format!(" on '{using}'")
-                    };
-
-                    // check hnsw.payload_m if there is a filter
-                    let uses_multitenant_filter = if let Some(filter) = filter {
-                        filter
-                            .iter_conditions()
-                            .filter_map(|c| c.targeted_key())
-                            .filter_map(|key| collection.payload_key_index_schema(&key))
-                            .any(|index_schema| index_schema.is_tenant())
-                    } else {
-                        false
-                    };
-
-                    if !uses_multitenant_filter {
-                        // HNSW disabled AND no filters
-                        return Err(CollectionError::strict_mode(
-                            format!(
-                                "Request is forbidden{vector_error_label} because global vector indexing is disabled (hnsw_config.m = 0)"
-                            ),
-                            "Use tenant-specific filter, enable global vector indexing or enable strict mode `search_allow_exact` option",
-                        ));
-                    }
-
-                    if vector_hnsw_payload_m == 0 {
-                        // HNSW disabled AND no filters
-                        return Err(CollectionError::strict_mode(
-                            format!(
-                                "Request is forbidden{vector_error_label} because vector indexing is disabled (hnsw_config.m = 0 and hnsw_config.payload_m = 0)"
-                            ),
-                            "Enable vector indexing, use a prefetch query with indexed vectors or enable strict mode `search_allow_exact` option",
-                        ));
+                // Only the candidates are scored
+                Query::Vector(VectorQuery::NearestAmongCandidates(_)) => (),
+                Query::Vector(_) => check_vector_fullscan(using, filter, collection).await?,
+                // Each of the selected vectors is searched with a filter
+                Query::VectorSelection(selection) => {
+                    let selectable = selection
+                        .mapping
+                        .iter()
+                        .map(|(_, vector_name)| vector_name)
+                        .chain(&selection.default)
+                        .unique();
+                    for vector_name in selectable {
+                        check_vector_fullscan(vector_name, filter, collection).await?;
                     }
-
-                    return Ok(());
                 }
             }
         }
@@ -131,6 +90,88 @@ impl Query {
     }
 }
 
+/// Check that a search over the vector does not perform a fullscan
+async fn check_vector_fullscan(
+    using: &str,
+    filter: Option<&Filter>,
+    collection: &Collection,
+) -> CollectionResult<()> {
+    let config = collection.collection_config.read().await;
+
+    // ignore sparse vectors
+    let query_targets_sparse = config
+        .params
+        .sparse_vectors
+        .as_ref()
+        .is_some_and(|sparse| sparse.contains_key(using));
+    if query_targets_sparse {
+        // sparse vectors are always indexed
+        return Ok(());
+    }
+
+    // check HNSW configuration for vector
+    let vector_hnsw_config = &config
+        .params
+        .vectors
+        .get_params(using)
+        .and_then(|param| param.hnsw_config.as_ref());
+
+    let vector_hnsw_m = vector_hnsw_config
+        .map(|hnsw_config| hnsw_config.m)
+        .flatten()
+        .unwrap_or(config.hnsw_config.m);
+
+    let vector_hnsw_payload_m = vector_hnsw_config
+        .map(|hnsw_config| hnsw_config.payload_m)
+        .flatten()
+        .unwrap_or_else(|| config.hnsw_config.payload_m.unwrap_or(vector_hnsw_m));
+
+    // no further check necessary if there is a global HNSW index
+    if vector_hnsw_m > 0 {
+        return Ok(());
+    }
+
+    // specialized error message if not default vector
+    let vector_error_label = if using == DEFAULT_VECTOR_NAME {
+        ""
+    } else {
+        &format!(" on '{using}'")
+    };
+
+    // check hnsw.payload_m if there is a filter
+    let uses_multitenant_filter = if let Some(filter) = filter {
+        filter
+            .iter_conditions()
+            .filter_map(|c| c.targeted_key())
+            .filter_map(|key| collection.payload_key_index_schema(&key))
+            .any(|index_schema| index_schema.is_tenant())
+    } else {
+        false
+    };
+
+    if !uses_multitenant_filter {
+        // HNSW disabled AND no filters
+        return Err(CollectionError::strict_mode(
+            format!(
+                "Request is forbidden{vector_error_label} because global vector indexing is disabled (hnsw_config.m = 0)"
+            ),
+            "Use tenant-specific filter, enable global vector indexing or enable strict mode `search_allow_exact` option",
+        ));
+    }
+
+    if vector_hnsw_payload_m == 0 {
+        // HNSW disabled AND no filters
+        return Err(CollectionError::strict_mode(
+            format!(
+                "Request is forbidden{vector_error_label} because vector indexing is disabled (hnsw_config.m = 0 and hnsw_config.payload_m = 0)"
+            ),
+            "Enable vector indexing, use a prefetch query with indexed vectors or enable strict mode `search_allow_exact` option",
+        ));
+    }
+
+    Ok(())
+}
+
 impl StrictModeVerification for CollectionQueryRequest {
     async fn check_custom(
         &self,
@@ -182,6 +223,10 @@ impl StrictModeVerification for CollectionQueryRequest {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        Some((&self.with_vector, self.limit))
+    }
 }
 
 impl StrictModeVerification for CollectionPrefetch {
@@ -278,4 +323,8 @@ impl StrictModeVerification for CollectionQueryGroupsRequest {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        Some((&self.with_vector, self.limit * self.group_size))
+    }
 }
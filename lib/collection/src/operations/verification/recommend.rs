@@ -1,4 +1,4 @@
-use segment::types::Filter;
+use segment::types::{Filter, WithVector};
 
 use super::StrictModeVerification;
 use crate::operations::types::{RecommendGroupsRequestInternal, RecommendRequestInternal};
@@ -23,6 +23,12 @@ impl StrictModeVerification for RecommendRequestInternal {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        self.with_vector
+            .as_ref()
+            .map(|with_vector| (with_vector, self.limit))
+    }
 }
 
 impl StrictModeVerification for RecommendGroupsRequestInternal {
@@ -45,4 +51,11 @@ impl StrictModeVerification for RecommendGroupsRequestInternal {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        let points = self.group_request.limit as usize * self.group_request.group_size as usize;
+        self.with_vector
+            .as_ref()
+            .map(|with_vector| (with_vector, points))
+    }
 }
@@ -1,5 +1,5 @@
 use api::rest::{SearchGroupsRequestInternal, SearchRequestInternal};
-use segment::types::{Filter, SearchParams, StrictModeConfig};
+use segment::types::{Filter, SearchParams, StrictModeConfig, WithVector};
 
 use super::{StrictModeVerification, check_grouping_field};
 use crate::collection::Collection;
@@ -25,6 +25,12 @@ impl StrictModeVerification for SearchRequestInternal {
     fn request_exact(&self) -> Option<bool> {
         None
     }
+
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        self.with_vector
+            .as_ref()
+            .map(|with_vector| (with_vector, self.limit))
+    }
 }
 
 impl StrictModeVerification for CoreSearchRequest {
@@ -47,6 +53,12 @@ impl StrictModeVerification for CoreSearchRequest {
     fn request_search_params(&self) -> Option<&SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        self.with_vector
+            .as_ref()
+            .map(|with_vector| (with_vector, self.limit))
+    }
 }
 
 impl StrictModeVerification for SearchRequestBatch {
@@ -116,4 +128,11 @@ impl StrictModeVerification for SearchGroupsRequestInternal {
     fn indexed_filter_write(&self) -> Option<&Filter> {
         None
     }
+
+    fn request_vectors(&self) -> Option<(&WithVector, usize)> {
+        let points = self.group_request.limit as usize * self.group_request.group_size as usize;
+        self.with_vector
+            .as_ref()
+            .map(|with_vector| (with_vector, points))
+    }
 }
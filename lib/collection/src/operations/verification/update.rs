@@ -165,12 +165,16 @@ impl StrictModeVerification for PointInsertOperations {
                 shard_key: _,
                 update_filter: _,
                 update_mode: _,
+                sequence_key: _,
+                skip_normalization: _,
             }) => None,
             PointInsertOperations::PointsList(PointsList {
                 points: _,
                 shard_key: _,
                 update_filter: _,
                 update_mode: _,
+                sequence_key: _,
+                skip_normalization: _,
             }) => None,
         }
     }
@@ -225,6 +229,7 @@ impl StrictModeVerification for UpdateVectors {
             // as it is only used on a limited and small subset of points.
             // Reading from payload storage is acceptable in this case.
             update_filter: _,
+            append: _,
         } = self;
         None
     }
@@ -90,6 +90,16 @@ pub struct OptimizersConfig {
     /// Default is disabled.
     #[serde(default)]
     pub prevent_unoptimized: Option<bool>,
+    /// Maximum number of segments per shard.
+    /// If exceeded, the merge optimizer merges segments regardless of `max_segment_size`
+    /// until the number of segments is back within the limit.
+    ///
+    /// Lower values reduce the number of segments each search has to visit,
+    /// at the cost of more indexing work.
+    /// If not set, the number of segments is only driven by `default_segment_number`.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_segment_number: Option<usize>,
 }
 
 impl OptimizersConfig {
@@ -106,6 +116,7 @@ impl OptimizersConfig {
             flush_interval_sec: 60,
             max_optimization_threads: Some(0),
             prevent_unoptimized: None,
+            max_segment_number: None,
         }
     }
 
@@ -184,6 +195,7 @@ pub fn build_optimizers(
     Arc::new(vec![
         Arc::new(MergeOptimizer::new(
             optimizers_config.get_number_segments(),
+            optimizers_config.max_segment_number,
             threshold_config,
             segments_path.clone(),
             temp_segments_path.clone(),
@@ -220,9 +220,13 @@ fn infer_index_from_field_condition(field_condition: &FieldCondition) -> Vec<Fie
         geo_bounding_box,
         geo_radius,
         geo_polygon,
+        geo_shape,
         values_count,
         is_empty,
         is_null,
+        has_field,
+        // Not served by any index, see `update_from_condition`
+        compare: _,
     } = field_condition;
 
     let mut required_indexes = Vec::new();
@@ -232,6 +236,9 @@ fn infer_index_from_field_condition(field_condition: &FieldCondition) -> Vec<Fie
             Match::Value(match_value) => infer_index_from_match_value(match_value),
             Match::Text(_match_text) => vec![FieldIndexType::Text],
             Match::Phrase(_match_text) => vec![FieldIndexType::TextPhrase],
+            Match::Near(_match_near) => vec![FieldIndexType::TextPhrase],
+            Match::Prefix(_match_prefix) => vec![FieldIndexType::KeywordMatch],
+            Match::Regex(_match_regex) => vec![FieldIndexType::KeywordMatch],
             Match::Any(match_any) => infer_index_from_any_variants(&match_any.any),
             Match::Except(match_except) => infer_index_from_any_variants(&match_except.except),
             Match::TextAny(_match_text_any) => vec![FieldIndexType::Text],
@@ -251,7 +258,10 @@ fn infer_index_from_field_condition(field_condition: &FieldCondition) -> Vec<Fie
     if geo_bounding_box.is_some() || geo_radius.is_some() || geo_polygon.is_some() {
         required_indexes.push(FieldIndexType::Geo);
     }
-    if values_count.is_some() || is_empty.is_some() || is_null.is_some() {
+    if geo_shape.is_some() {
+        required_indexes.push(FieldIndexType::GeoShape);
+    }
+    if values_count.is_some() || is_empty.is_some() || is_null.is_some() || has_field.is_some() {
         // Any index will do, let user choose depending on their data type
         required_indexes.extend(all_indexes());
     }
@@ -356,6 +366,10 @@ impl<'a> Extractor<'a> {
 
         match condition {
             Condition::Field(field_condition) => {
+                // Comparison with other fields is checked on the payload, no index can serve it
+                if field_condition.compare.is_some() {
+                    return;
+                }
                 key = &field_condition.key;
                 required_index = infer_index_from_field_condition(field_condition);
             }
@@ -453,6 +467,24 @@ impl<'a> Extractor<'a> {
                 key = variable.clone();
                 required_index = vec![FieldIndexType::DatetimeRange];
             }
+            ExpressionInternal::TextScore {
+                key: text_key,
+                text: _,
+                boosts: _,
+            } => {
+                key = text_key.clone();
+                required_index = vec![FieldIndexType::Text];
+            }
+            ExpressionInternal::Wasm {
+                module: _,
+                args,
+                fuel_limit: _,
+            } => {
+                for expr in args {
+                    self.update_from_expression(expr);
+                }
+                return;
+            }
             ExpressionInternal::Mult(expression_internals) => {
                 for expr in expression_internals {
                     self.update_from_expression(expr);
@@ -542,6 +574,7 @@ enum FieldIndexType {
     UuidRange,
     DatetimeRange,
     Geo,
+    GeoShape,
 }
 
 fn schema_capabilities(value: &PayloadFieldSchema) -> HashSet<FieldIndexType> {
@@ -562,6 +595,7 @@ fn schema_capabilities(value: &PayloadFieldSchema) -> HashSet<FieldIndexType> {
             PayloadSchemaType::Geo => index_types.insert(FieldIndexType::Geo),
             PayloadSchemaType::Text => index_types.insert(FieldIndexType::Text),
             PayloadSchemaType::Datetime => index_types.insert(FieldIndexType::DatetimeRange),
+            PayloadSchemaType::GeoShape => index_types.insert(FieldIndexType::GeoShape),
         },
         PayloadFieldSchema::FieldParams(payload_schema_params) => match payload_schema_params {
             PayloadSchemaParams::Keyword(_) => index_types.insert(FieldIndexType::KeywordMatch),
@@ -595,6 +629,7 @@ fn schema_capabilities(value: &PayloadFieldSchema) -> HashSet<FieldIndexType> {
                 index_types.insert(FieldIndexType::Text)
             }
             PayloadSchemaParams::Datetime(_) => index_types.insert(FieldIndexType::DatetimeRange),
+            PayloadSchemaParams::GeoShape(_) => index_types.insert(FieldIndexType::GeoShape),
         },
     };
 
@@ -625,6 +660,7 @@ impl From<FieldIndexType> for PayloadFieldSchema {
                 PayloadFieldSchema::FieldType(PayloadSchemaType::Datetime)
             }
             FieldIndexType::Geo => PayloadFieldSchema::FieldType(PayloadSchemaType::Geo),
+            FieldIndexType::GeoShape => PayloadFieldSchema::FieldType(PayloadSchemaType::GeoShape),
         }
     }
 }
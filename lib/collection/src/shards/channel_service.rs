@@ -4,7 +4,7 @@ use std::time::Duration;
 
 use api::grpc::qdrant::WaitOnConsensusCommitRequest;
 use api::grpc::qdrant::qdrant_internal_client::QdrantInternalClient;
-use api::grpc::transport_channel_pool::{AddTimeout, TransportChannelPool};
+use api::grpc::transport_channel_pool::{AddTimeout, CompressedClient, TransportChannelPool};
 use futures::Future;
 use futures::future::try_join_all;
 use semver::Version;
@@ -13,6 +13,7 @@ use tonic::transport::{Channel, Uri};
 use tonic::{Request, Status};
 use url::Url;
 
+use crate::config::PlacementConstraints;
 use crate::operations::types::{CollectionError, CollectionResult, PeerMetadata};
 use crate::shards::shard::PeerId;
 
@@ -33,6 +34,9 @@ pub struct ChannelService {
 
     /// Alternative API key, works the same as `api_key`. Intended for rolling key updates.
     pub alt_api_key: Option<String>,
+
+    /// Prefer replicas on peers with the lowest round-trip time for reads.
+    pub latency_aware_routing: bool,
 }
 
 impl ChannelService {
@@ -51,6 +55,7 @@ impl ChannelService {
             rest_tls_enabled,
             api_key,
             alt_api_key,
+            latency_aware_routing: false,
         }
     }
 
@@ -161,9 +166,10 @@ impl ChannelService {
             .get(&peer_id)
             .ok_or_else(|| CollectionError::service_error("Address for peer ID is not found."))?
             .clone();
+        let compression = self.channel_pool.compression();
         self.channel_pool
             .with_channel(&address, |channel| {
-                let client = QdrantInternalClient::new(channel);
+                let client = QdrantInternalClient::new(channel).compressed(compression);
                 let client = client.max_decoding_message_size(usize::MAX);
                 f(client)
             })
@@ -200,6 +206,20 @@ impl ChannelService {
             .is_some_and(|metadata| &metadata.version >= version)
     }
 
+    /// Check whether the specified peer has all labels required by the placement constraints
+    ///
+    /// If the labels are not known for the peer, this returns `false` unless no labels are required.
+    pub fn peer_matches_labels(&self, peer_id: PeerId, constraints: &PlacementConstraints) -> bool {
+        if constraints.require_labels.is_empty() {
+            return true;
+        }
+
+        self.id_to_metadata
+            .read()
+            .get(&peer_id)
+            .is_some_and(|metadata| constraints.matches_labels(metadata.labels()))
+    }
+
     /// Get the REST address for the current peer.
     pub fn current_rest_address(&self, this_peer_id: PeerId) -> CollectionResult<Url> {
         // Get local peer URI
@@ -239,6 +259,38 @@ impl ChannelService {
     pub fn request_timeout(&self) -> Duration {
         self.channel_pool.request_timeout()
     }
+
+    /// Smoothed round-trip time to the given peer, if it was measured
+    pub fn peer_rtt(&self, peer_id: PeerId) -> Option<Duration> {
+        let address = self.id_to_address.read().get(&peer_id).cloned()?;
+        self.channel_pool.rtt(&address)
+    }
+
+    /// Periodically ping all other peers, to keep their round-trip times up to date
+    pub async fn measure_peer_latencies(self, this_peer_id: PeerId, interval: Duration) {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            let addresses: Vec<_> = self
+                .id_to_address
+                .read()
+                .iter()
+                .filter(|(peer_id, _)| **peer_id != this_peer_id)
+                .map(|(peer_id, address)| (*peer_id, address.clone()))
+                .collect();
+
+            let pings = addresses.iter().map(|(peer_id, address)| async move {
+                if let Err(err) = self.channel_pool.ping(address).await {
+                    log::trace!("Failed to measure round-trip time to peer {peer_id}: {err}");
+                }
+            });
+
+            futures::future::join_all(pings).await;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -252,6 +304,7 @@ impl Default for ChannelService {
             rest_tls_enabled: false,
             api_key: None,
             alt_api_key: None,
+            latency_aware_routing: false,
         }
     }
 }
@@ -19,7 +19,7 @@ use crate::operations::conversions::write_ordering_to_proto;
 use crate::operations::payload_ops::{DeletePayloadOp, SetPayloadOp};
 use crate::operations::point_ops::{
     ConditionalInsertOperationInternal, PointInsertOperationsInternal, PointSyncOperation,
-    WriteOrdering,
+    ReturnIds, WriteOrdering,
 };
 use crate::operations::types::CollectionResult;
 use crate::operations::vector_ops::UpdateVectorsOp;
@@ -85,6 +85,8 @@ pub fn internal_upsert_points(
             update_filter: None,
             timeout: wait_timeout,
             update_mode: None, // Default mode (Upsert)
+            sequence_key: None,
+            skip_normalization: None,
         }),
     })
 }
@@ -104,6 +106,8 @@ pub fn internal_conditional_upsert_points(
         points_op: point_insert_operations,
         condition,
         update_mode,
+        sequence_key,
+        skip_normalization,
     } = point_condition_upsert_operations;
 
     let grpc_update_mode = update_mode.map(|mode| match mode {
@@ -130,6 +134,8 @@ pub fn internal_conditional_upsert_points(
             update_filter: Some(api::grpc::Filter::from(condition)),
             timeout: wait_timeout,
             update_mode: grpc_update_mode,
+            sequence_key: sequence_key.map(|key| key.to_string()),
+            skip_normalization,
         }),
     })
 }
@@ -157,15 +163,18 @@ pub fn internal_delete_points(
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
             timeout: wait_timeout,
+            return_ids: None,
         }),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn internal_delete_points_by_filter(
     shard_id: Option<ShardId>,
     clock_tag: Option<ClockTag>,
     collection_name: String,
     filter: Filter,
+    return_ids: Option<ReturnIds>,
     wait: bool,
     wait_timeout: Option<u64>,
     ordering: Option<WriteOrdering>,
@@ -182,15 +191,18 @@ pub fn internal_delete_points_by_filter(
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
             timeout: wait_timeout,
+            return_ids: return_ids.map(Into::into),
         }),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn internal_update_vectors(
     shard_id: Option<ShardId>,
     clock_tag: Option<ClockTag>,
     collection_name: String,
     update_vectors: UpdateVectorsOp,
+    append: bool,
     wait: bool,
     wait_timeout: Option<u64>,
     ordering: Option<WriteOrdering>,
@@ -220,6 +232,7 @@ pub fn internal_update_vectors(
             shard_key_selector: None,
             update_filter: update_filter.map(api::grpc::Filter::from),
             timeout: wait_timeout,
+            append: append.then_some(true),
         }),
     })
 }
@@ -286,11 +299,13 @@ pub fn internal_delete_vectors_by_filter(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn internal_set_payload(
     shard_id: Option<ShardId>,
     clock_tag: Option<ClockTag>,
     collection_name: String,
     set_payload: SetPayloadOp,
+    return_ids: Option<ReturnIds>,
     wait: bool,
     wait_timeout: Option<u64>,
     ordering: Option<WriteOrdering>,
@@ -319,6 +334,7 @@ pub fn internal_set_payload(
             shard_key_selector: None,
             key: set_payload.key.map(|key| key.to_string()),
             timeout: wait_timeout,
+            return_ids: return_ids.map(Into::into),
         }),
     }
 }
@@ -386,15 +402,18 @@ pub fn internal_clear_payload(
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
             timeout: wait_timeout,
+            return_ids: None,
         }),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn internal_clear_payload_by_filter(
     shard_id: Option<ShardId>,
     clock_tag: Option<ClockTag>,
     collection_name: String,
     filter: Filter,
+    return_ids: Option<ReturnIds>,
     wait: bool,
     wait_timeout: Option<u64>,
     ordering: Option<WriteOrdering>,
@@ -411,6 +430,7 @@ pub fn internal_clear_payload_by_filter(
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
             timeout: wait_timeout,
+            return_ids: return_ids.map(Into::into),
         }),
     }
 }
@@ -487,6 +507,7 @@ pub fn try_scored_point_from_grpc(
         vectors,
         shard_key,
         order_value,
+        highlight: _, // Highlights are only computed for external responses
     } = point;
     let id = id
         .ok_or_else(|| Status::invalid_argument("scored point does not have an ID"))?
@@ -58,10 +58,12 @@ impl DummyShard {
             num_vectors: None,
             num_vectors_by_name: None,
             segments: None,
+            access_frequency: None,
             optimizations: Default::default(),
             async_scorer: None,
             indexed_only_excluded_vectors: None,
             update_queue: None,
+            filter_latency: None,
         }
     }
 
@@ -109,6 +111,7 @@ impl ShardOperation for DummyShard {
                 operation_id: None,
                 status: UpdateStatus::Acknowledged,
                 clock_tag: None,
+                affected_ids: None,
             }),
             // Allow (and ignore) staging operations on dummy shards
             #[cfg(feature = "staging")]
@@ -116,6 +119,7 @@ impl ShardOperation for DummyShard {
                 operation_id: None,
                 status: UpdateStatus::Acknowledged,
                 clock_tag: None,
+                affected_ids: None,
             }),
         }
     }
@@ -0,0 +1,229 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use arc_swap::ArcSwapOption;
+use segment::common::BYTES_IN_KB;
+use segment::types::StrictModeConfig;
+
+use super::LocalShard;
+use super::indexed_only::get_largest_unindexed_segment_vector_size;
+use crate::config::CollectionConfigInternal;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+/// Suggested delay before retrying a write, rejected because of a long update queue
+const UPDATE_QUEUE_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Suggested delay before retrying a write, rejected because of unindexed data.
+/// Optimizers need much more time to catch up than the update queue.
+const UNINDEXED_SEGMENT_RETRY_AFTER: Duration = Duration::from_secs(10);
+
+/// How long a measured size of the largest unindexed segment is reused by following writes.
+/// Measuring takes a read lock on every segment, which is too expensive to do on each write.
+const UNINDEXED_SIZE_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Thresholds, above which a replica rejects writes instead of letting them stall
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteBackpressureLimits {
+    pub max_update_queue_length: Option<usize>,
+    pub max_unindexed_segment_size_kb: Option<usize>,
+    /// Segments below this size are never indexed, so they are not a backlog of the optimizers
+    pub indexing_threshold_kb: usize,
+}
+
+impl WriteBackpressureLimits {
+    /// Limits configured in strict mode. `None` if strict mode is disabled or has no limits.
+    pub fn from_strict_mode(
+        config: &StrictModeConfig,
+        indexing_threshold_kb: usize,
+    ) -> Option<Self> {
+        if config.enabled != Some(true) {
+            return None;
+        }
+
+        let limits = Self {
+            max_update_queue_length: config.max_update_queue_length,
+            max_unindexed_segment_size_kb: config.max_unindexed_segment_size_kb,
+            indexing_threshold_kb,
+        };
+
+        (limits.max_update_queue_length.is_some() || limits.max_unindexed_segment_size_kb.is_some())
+            .then_some(limits)
+    }
+
+    pub fn from_config(config: &CollectionConfigInternal) -> Option<Self> {
+        let indexing_threshold_kb = config.optimizer_config.get_indexing_threshold_kb();
+        config
+            .strict_mode_config
+            .as_ref()
+            .and_then(|strict_mode| Self::from_strict_mode(strict_mode, indexing_threshold_kb))
+    }
+
+    fn check_update_queue(&self, update_queue_length: usize) -> CollectionResult<()> {
+        match self.max_update_queue_length {
+            Some(max_length) if update_queue_length > max_length => {
+                Err(CollectionError::RateLimitExceeded {
+                    description: format!(
+                        "Write backpressure: update queue has {update_queue_length} pending operations, \
+                         limit is {max_length}. Retry after {}s",
+                        UPDATE_QUEUE_RETRY_AFTER.as_secs(),
+                    ),
+                    retry_after: Some(UPDATE_QUEUE_RETRY_AFTER),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the size of the largest unindexed segment.
+    ///
+    /// Only segments above the indexing threshold are due for indexing, smaller ones are
+    /// searched without an index and would never shrink. Counting them would reject writes
+    /// forever, if the limit is below the indexing threshold.
+    fn check_unindexed_segment(&self, unindexed_segment_size: usize) -> CollectionResult<()> {
+        let indexing_threshold = self.indexing_threshold_kb.saturating_mul(BYTES_IN_KB);
+        if unindexed_segment_size <= indexing_threshold {
+            return Ok(());
+        }
+
+        match self.max_unindexed_segment_size_kb {
+            Some(max_size_kb)
+                if unindexed_segment_size > max_size_kb.saturating_mul(BYTES_IN_KB) =>
+            {
+                Err(CollectionError::RateLimitExceeded {
+                    description: format!(
+                        "Write backpressure: largest segment due for indexing has {}kB of vectors, \
+                         limit is {max_size_kb}kB. Retry after {}s",
+                        unindexed_segment_size / BYTES_IN_KB,
+                        UNINDEXED_SEGMENT_RETRY_AFTER.as_secs(),
+                    ),
+                    retry_after: Some(UNINDEXED_SEGMENT_RETRY_AFTER),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Write backpressure state of a local shard, so that checking a write is cheap
+#[derive(Default)]
+pub struct WriteBackpressure {
+    /// Limits from the collection config, refreshed on config updates
+    limits: ArcSwapOption<WriteBackpressureLimits>,
+    /// Last measured size of the largest unindexed segment, with the time of the measurement
+    unindexed_segment_size: tokio::sync::Mutex<Option<(Instant, usize)>>,
+}
+
+impl WriteBackpressure {
+    pub fn new(config: &CollectionConfigInternal) -> Self {
+        let backpressure = Self::default();
+        backpressure.update_limits(config);
+        backpressure
+    }
+
+    pub fn update_limits(&self, config: &CollectionConfigInternal) {
+        self.limits
+            .store(WriteBackpressureLimits::from_config(config).map(Arc::new));
+    }
+}
+
+impl LocalShard {
+    /// Check if the shard can take more writes without stalling them.
+    ///
+    /// Returns a rate limit error with a suggested retry delay if the update queue or
+    /// the backlog of the optimizers is above the limits configured in strict mode.
+    pub async fn check_write_backpressure(&self) -> CollectionResult<()> {
+        let Some(limits) = self.write_backpressure.limits.load_full() else {
+            return Ok(());
+        };
+
+        limits.check_update_queue(self.update_queue_length())?;
+
+        if limits.max_unindexed_segment_size_kb.is_some() {
+            let unindexed_segment_size = self.largest_unindexed_segment_size().await?;
+            limits.check_unindexed_segment(unindexed_segment_size)?;
+        }
+
+        Ok(())
+    }
+
+    /// Size of the largest unindexed segment, measured at most once per [`UNINDEXED_SIZE_REFRESH_INTERVAL`]
+    async fn largest_unindexed_segment_size(&self) -> CollectionResult<usize> {
+        let mut cached = self.write_backpressure.unindexed_segment_size.lock().await;
+
+        if let Some((measured_at, size)) = *cached
+            && measured_at.elapsed() < UNINDEXED_SIZE_REFRESH_INTERVAL
+        {
+            return Ok(size);
+        }
+
+        let segments = self.segments.clone();
+        let size = tokio::task::spawn_blocking(move || {
+            get_largest_unindexed_segment_vector_size(&segments.read())
+        })
+        .await?
+        .unwrap_or(0);
+
+        *cached = Some((Instant::now(), size));
+        Ok(size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_backpressure_limits() {
+        let mut config = StrictModeConfig {
+            enabled: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(WriteBackpressureLimits::from_strict_mode(&config, 10), None);
+
+        config.max_update_queue_length = Some(10);
+        config.max_unindexed_segment_size_kb = Some(100);
+        let limits = WriteBackpressureLimits::from_strict_mode(&config, 10).unwrap();
+
+        assert!(limits.check_update_queue(10).is_ok());
+        match limits.check_update_queue(11) {
+            Err(CollectionError::RateLimitExceeded { retry_after, .. }) => {
+                assert_eq!(retry_after, Some(UPDATE_QUEUE_RETRY_AFTER));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        assert!(limits.check_unindexed_segment(100 * BYTES_IN_KB).is_ok());
+        match limits.check_unindexed_segment(100 * BYTES_IN_KB + 1) {
+            Err(CollectionError::RateLimitExceeded { retry_after, .. }) => {
+                assert_eq!(retry_after, Some(UNINDEXED_SEGMENT_RETRY_AFTER));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        // Limits are not applied while strict mode is disabled
+        config.enabled = Some(false);
+        assert_eq!(WriteBackpressureLimits::from_strict_mode(&config, 10), None);
+    }
+
+    #[test]
+    fn test_write_backpressure_below_indexing_threshold() {
+        let config = StrictModeConfig {
+            enabled: Some(true),
+            max_unindexed_segment_size_kb: Some(1),
+            ..Default::default()
+        };
+        let limits = WriteBackpressureLimits::from_strict_mode(&config, 100).unwrap();
+
+        // Segments below the indexing threshold are never indexed, so they don't count
+        assert!(limits.check_unindexed_segment(100 * BYTES_IN_KB).is_ok());
+        assert!(
+            limits
+                .check_unindexed_segment(100 * BYTES_IN_KB + 1)
+                .is_err()
+        );
+
+        // Indexing is disabled, nothing is waiting for it
+        let limits = WriteBackpressureLimits::from_strict_mode(&config, usize::MAX).unwrap();
+        assert!(limits.check_unindexed_segment(usize::MAX).is_ok());
+    }
+}
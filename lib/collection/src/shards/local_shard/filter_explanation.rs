@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use futures::future::try_join_all;
+use segment::data_types::filter_explanation::FilterExplanation;
+use segment::types::Filter;
+use tokio::runtime::Handle;
+use tokio::time::error::Elapsed;
+use tokio_util::task::AbortOnDropHandle;
+
+use super::LocalShard;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+impl LocalShard {
+    /// Explain how points matching the filter are selected in each segment of the shard
+    pub async fn explain_filter(
+        &self,
+        filter: Filter,
+        search_runtime_handle: &Handle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<FilterExplanation> {
+        let segments = self
+            .segments
+            .read()
+            .non_appendable_then_appendable_segments()
+            .collect::<Vec<_>>();
+
+        let filter = Arc::new(filter);
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+
+        let reads = segments.into_iter().map(|segment| {
+            let filter = Arc::clone(&filter);
+            let hw_counter = hw_counter.fork();
+
+            let task = search_runtime_handle.spawn_blocking(move || {
+                let get_segment = segment.get();
+                let read_segment = get_segment.read();
+
+                read_segment.explain_filter(&filter, &hw_counter)
+            });
+            AbortOnDropHandle::new(task)
+        });
+
+        let segment_explanations = tokio::time::timeout(timeout, try_join_all(reads))
+            .await
+            .map_err(|_: Elapsed| CollectionError::timeout(timeout, "explain filter"))??;
+
+        let mut explanation = FilterExplanation::default();
+        for segment_explanation in segment_explanations {
+            explanation.add_segment(segment_explanation);
+        }
+
+        Ok(explanation)
+    }
+}
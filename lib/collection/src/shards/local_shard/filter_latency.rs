@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::types::TelemetryDetail;
+use parking_lot::Mutex;
+use segment::common::operation_time_statistics::OperationDurationsAggregator;
+use segment::types::{Condition, Filter, PayloadFieldSchema, PayloadKeyType};
+
+use super::LocalShard;
+use crate::problems::unindexed_field;
+use crate::shards::telemetry::FilterLatencyTelemetry;
+
+/// Filters with more conditions are considered complex
+const MAX_SIMPLE_CONDITIONS: usize = 4;
+
+/// Filters with nested filters deeper than this are considered complex
+const MAX_SIMPLE_DEPTH: usize = 1;
+
+/// Complexity class of the filters of a request, ordered from the cheapest to the most expensive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FilterComplexity {
+    /// No filter, or a filter without conditions
+    Unfiltered,
+    /// Few conditions on indexed fields, without nested filters
+    Simple,
+    /// Many conditions or nested filters, all on indexed fields
+    Complex,
+    /// At least one condition on a field without a suitable payload index
+    Unindexed,
+}
+
+impl FilterComplexity {
+    pub fn of_filter(
+        filter: &Filter,
+        payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+    ) -> Self {
+        let conditions = filter.total_conditions_count();
+        if conditions == 0 {
+            return Self::Unfiltered;
+        }
+
+        let mut extractor = unindexed_field::Extractor::new(payload_schema);
+        extractor.update_from_filter_once(None, filter);
+        if !extractor.unindexed_schema().is_empty() {
+            return Self::Unindexed;
+        }
+
+        if conditions > MAX_SIMPLE_CONDITIONS || filter_depth(filter) > MAX_SIMPLE_DEPTH {
+            return Self::Complex;
+        }
+
+        Self::Simple
+    }
+
+    /// Class of the most expensive of the filters
+    pub fn of_filters<'a>(
+        filters: impl IntoIterator<Item = Option<&'a Filter>>,
+        payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+    ) -> Self {
+        filters
+            .into_iter()
+            .flatten()
+            .map(|filter| Self::of_filter(filter, payload_schema))
+            .max()
+            .unwrap_or(Self::Unfiltered)
+    }
+}
+
+/// Number of levels of the filter, a filter without nested filters has a single level
+fn filter_depth(filter: &Filter) -> usize {
+    let nested_depth = filter
+        .iter_conditions()
+        .map(|condition| match condition {
+            Condition::Filter(filter) => filter_depth(filter),
+            Condition::Nested(nested) => filter_depth(nested.filter()),
+            Condition::Field(_)
+            | Condition::IsEmpty(_)
+            | Condition::IsNull(_)
+            | Condition::HasId(_)
+            | Condition::HasVector(_)
+            | Condition::CustomIdChecker(_) => 0,
+        })
+        .max()
+        .unwrap_or(0);
+
+    1 + nested_depth
+}
+
+/// Durations of searches and queries of a shard, grouped by complexity class of their filters.
+///
+/// Lets regressions of filtering show up separately from the traffic of unfiltered requests.
+pub struct FilterLatencies {
+    unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
+    simple: Arc<Mutex<OperationDurationsAggregator>>,
+    complex: Arc<Mutex<OperationDurationsAggregator>>,
+    unindexed: Arc<Mutex<OperationDurationsAggregator>>,
+}
+
+impl Default for FilterLatencies {
+    fn default() -> Self {
+        Self {
+            unfiltered: OperationDurationsAggregator::new(),
+            simple: OperationDurationsAggregator::new(),
+            complex: OperationDurationsAggregator::new(),
+            unindexed: OperationDurationsAggregator::new(),
+        }
+    }
+}
+
+impl FilterLatencies {
+    pub fn add(&self, complexity: FilterComplexity, success: bool, duration: Duration) {
+        let aggregator = match complexity {
+            FilterComplexity::Unfiltered => &self.unfiltered,
+            FilterComplexity::Simple => &self.simple,
+            FilterComplexity::Complex => &self.complex,
+            FilterComplexity::Unindexed => &self.unindexed,
+        };
+        aggregator.lock().add_operation_result(success, duration);
+    }
+
+    pub fn get_telemetry_data(&self, detail: TelemetryDetail) -> FilterLatencyTelemetry {
+        FilterLatencyTelemetry {
+            unfiltered: self.unfiltered.lock().get_statistics(detail),
+            simple: self.simple.lock().get_statistics(detail),
+            complex: self.complex.lock().get_statistics(detail),
+            unindexed: self.unindexed.lock().get_statistics(detail),
+        }
+    }
+}
+
+impl LocalShard {
+    /// Complexity class of the most expensive of the filters of a request
+    pub(super) fn filter_complexity<'a>(
+        &self,
+        filters: impl IntoIterator<Item = Option<&'a Filter>>,
+    ) -> FilterComplexity {
+        FilterComplexity::of_filters(filters, &self.payload_index_schema.read().schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::json_path::JsonPath;
+    use segment::types::{ExtendedPointId, FieldCondition, HasIdCondition, PayloadSchemaType};
+
+    use super::*;
+
+    fn city_condition() -> Condition {
+        Condition::Field(FieldCondition::new_match(
+            JsonPath::new("city"),
+            "Berlin".to_string().into(),
+        ))
+    }
+
+    #[test]
+    fn test_filter_complexity() {
+        let payload_schema = HashMap::from([(
+            JsonPath::new("city"),
+            PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword),
+        )]);
+        let complexity =
+            |filter: Option<&Filter>| FilterComplexity::of_filters([filter], &payload_schema);
+
+        assert_eq!(complexity(None), FilterComplexity::Unfiltered);
+        assert_eq!(
+            complexity(Some(&Filter::new())),
+            FilterComplexity::Unfiltered,
+        );
+
+        let simple = Filter::new_must(city_condition());
+        assert_eq!(complexity(Some(&simple)), FilterComplexity::Simple);
+
+        let nested = Filter::new_must(Condition::Filter(Filter::new_should(city_condition())));
+        assert_eq!(complexity(Some(&nested)), FilterComplexity::Complex);
+
+        let many = Filter {
+            must: Some(vec![city_condition(); MAX_SIMPLE_CONDITIONS + 1]),
+            ..Filter::new()
+        };
+        assert_eq!(complexity(Some(&many)), FilterComplexity::Complex);
+
+        let unindexed = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            JsonPath::new("country"),
+            "Germany".to_string().into(),
+        )));
+        assert_eq!(complexity(Some(&unindexed)), FilterComplexity::Unindexed);
+
+        // Conditions on ids don't need payload indexes
+        let has_id_condition: HasIdCondition = [ExtendedPointId::from(1)].into_iter().collect();
+        let has_id = Filter::new_must(Condition::HasId(has_id_condition));
+        assert_eq!(complexity(Some(&has_id)), FilterComplexity::Simple);
+
+        // The most expensive filter of a request determines its class
+        assert_eq!(
+            FilterComplexity::of_filters([None, Some(&simple), Some(&nested)], &payload_schema),
+            FilterComplexity::Complex,
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::types::ScoreType;
@@ -22,14 +22,27 @@ impl LocalShard {
         timeout: Duration,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ScoredPoint>> {
+        let start = Instant::now();
         let stopping_guard = StoppingGuard::new();
 
+        let text_score_stats = SegmentsSearcher::collect_text_score_stats(
+            self.segments.clone(),
+            formula.text_scores.clone(),
+            &self.search_runtime,
+            timeout,
+            &stopping_guard,
+            hw_measurement_acc.clone(),
+        )
+        .await?;
+        let timeout = timeout.saturating_sub(start.elapsed());
+
         let ctx = FormulaContext {
             formula,
             prefetches_results,
             score_threshold,
             limit,
             is_stopped: stopping_guard.get_is_stopped(),
+            text_score_stats,
         };
 
         let arc_ctx = Arc::new(ctx);
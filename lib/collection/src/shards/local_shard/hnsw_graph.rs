@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use segment::types::VectorNameBuf;
+use tokio::runtime::Handle;
+use tokio::time::error::Elapsed;
+use tokio_util::task::AbortOnDropHandle;
+use uuid::Uuid;
+
+use super::LocalShard;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+impl LocalShard {
+    /// HNSW graph of the vector in the segment with the given id, exported as JSON Lines.
+    ///
+    /// Returns `None` if there is no such segment in the shard.
+    pub async fn export_hnsw_graph(
+        &self,
+        segment_id: Uuid,
+        vector_name: VectorNameBuf,
+        search_runtime_handle: &Handle,
+        timeout: Duration,
+    ) -> CollectionResult<Option<Vec<u8>>> {
+        let segment = self
+            .segments
+            .read()
+            .non_appendable_then_appendable_segments()
+            .find(|segment| segment.get().read().segment_uuid() == segment_id);
+
+        let Some(segment) = segment else {
+            return Ok(None);
+        };
+
+        let task = search_runtime_handle.spawn_blocking(move || {
+            let get_segment = segment.get();
+            let read_segment = get_segment.read();
+
+            let mut graph = Vec::new();
+            read_segment.export_hnsw_graph(&vector_name, &mut graph)?;
+            Ok::<_, CollectionError>(graph)
+        });
+
+        let graph = tokio::time::timeout(timeout, AbortOnDropHandle::new(task))
+            .await
+            .map_err(|_: Elapsed| CollectionError::timeout(timeout, "export HNSW graph"))???;
+
+        Ok(Some(graph))
+    }
+}
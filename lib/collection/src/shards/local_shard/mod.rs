@@ -1,15 +1,24 @@
+pub(super) mod backpressure;
 pub mod clock_map;
 pub mod disk_usage_watcher;
 pub(super) mod facet;
+pub(super) mod filter_explanation;
+pub(super) mod filter_latency;
 pub(super) mod formula_rescore;
+pub(super) mod hnsw_graph;
+pub(super) mod payload_index_compaction;
+pub(super) mod quantiles;
 pub(super) mod query;
 pub(super) mod scroll;
+pub(super) mod scroll_views;
 pub(super) mod search;
 pub(super) mod shard_ops;
+pub(super) mod term_stats;
 
 mod snapshot;
 mod telemetry;
 pub(super) mod updaters;
+pub(super) mod vector_stats;
 
 #[cfg(test)]
 mod snapshot_tests;
@@ -20,6 +29,7 @@ pub mod indexed_only;
 pub mod testing;
 mod wal_ops;
 
+use std::collections::hash_map::Entry;
 use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -58,8 +68,11 @@ use tokio::sync::mpsc::Sender;
 use tokio::sync::{Mutex, RwLock as TokioRwLock, mpsc, oneshot};
 use tokio_util::task::AbortOnDropHandle;
 
+use self::backpressure::WriteBackpressure;
 use self::clock_map::{ClockMap, RecoveryPoint};
 use self::disk_usage_watcher::DiskUsageWatcher;
+use self::filter_latency::FilterLatencies;
+use self::scroll_views::ScrollViews;
 use super::update_tracker::UpdateTracker;
 use crate::collection::payload_index_schema::PayloadIndexSchema;
 use crate::collection_manager::collection_updater::CollectionUpdater;
@@ -111,6 +124,11 @@ pub struct LocalShard {
     pub(super) search_runtime: Handle,
     disk_usage_watcher: DiskUsageWatcher,
     read_rate_limiter: Option<ParkingMutex<RateLimiter>>,
+    write_backpressure: WriteBackpressure,
+    /// Segments pinned by consistent scrolls
+    pub(super) scroll_views: ScrollViews,
+    /// Durations of searches and queries, grouped by complexity of their filters
+    pub(super) filter_latencies: FilterLatencies,
 
     is_gracefully_stopped: bool,
 
@@ -300,6 +318,8 @@ impl LocalShard {
                 .map(ParkingMutex::new)
         });
 
+        let write_backpressure = WriteBackpressure::new(&config);
+
         drop(config); // release `shared_config` from borrow checker
 
         Self {
@@ -320,6 +340,9 @@ impl LocalShard {
             total_optimized_points,
             disk_usage_watcher,
             read_rate_limiter,
+            write_backpressure,
+            scroll_views: ScrollViews::default(),
+            filter_latencies: FilterLatencies::default(),
             is_gracefully_stopped: false,
             update_operation_lock: scroll_read_lock,
             applied_seq_handler,
@@ -351,9 +374,12 @@ impl LocalShard {
         let wal_path = Self::wal_path(shard_path);
         let segments_path = Self::segments_path(shard_path);
 
-        let wal: SerdeWal<OperationWithClockTag> =
-            SerdeWal::new(&wal_path, (&collection_config_read.wal_config).into())
-                .map_err(|e| CollectionError::service_error(format!("Wal error: {e}")))?;
+        let wal: SerdeWal<OperationWithClockTag> = SerdeWal::open(
+            &wal_path,
+            (&collection_config_read.wal_config).into(),
+            shared_storage_config.wal_backend,
+        )
+        .map_err(|e| CollectionError::service_error(format!("Wal error: {e}")))?;
 
         // Walk over segments directory and collect all directory entries now
         // Collect now and error early to prevent errors while we've already spawned load threads
@@ -607,6 +633,7 @@ impl LocalShard {
                 vector_data: vector_params.clone(),
                 sparse_vector_data: sparse_vector_params.clone(),
                 payload_storage_type: config.params.payload_storage_type(),
+                defragment_keys: None,
             };
             let segment = thread::Builder::new()
                 .name(format!("shard-build-{collection_id}-{id}"))
@@ -633,8 +660,11 @@ impl LocalShard {
             segment_holder.add_new(segment);
         }
 
-        let wal: SerdeWal<OperationWithClockTag> =
-            SerdeWal::new(&wal_path, (&config.wal_config).into())?;
+        let wal: SerdeWal<OperationWithClockTag> = SerdeWal::open(
+            &wal_path,
+            (&config.wal_config).into(),
+            shared_storage_config.wal_backend,
+        )?;
 
         let optimizers = build_optimizers(
             shard_path,
@@ -677,6 +707,7 @@ impl LocalShard {
                 PointInsertOperationsInternal::from(vec![]),
             )),
             clock_tag: None,
+            return_ids: None,
         };
         self.wal.lock_and_write(&mut operation).await?;
         Ok(())
@@ -702,7 +733,8 @@ impl LocalShard {
             last_wal_index.saturating_sub(update_queue_size as u64 - 1),
         );
 
-        let to = std::cmp::min(to, last_wal_index);
+        // WAL without durable entries starts after the last applied operation after a restart
+        let to = to.clamp(from, last_wal_index);
         let wal_entries_to_replay = to - from;
 
         assert!(
@@ -839,6 +871,7 @@ impl LocalShard {
                     .send(UpdateSignal::Operation(OperationData {
                         op_num,
                         operation: None,
+                        return_ids: None,
                         sender: None,
                         hw_measurements: hw_measurements.clone(),
                     }))
@@ -880,9 +913,12 @@ impl LocalShard {
 
     /// Apply shard's strict mode configuration update
     /// - Update read rate limiter
+    /// - Update write backpressure limits
     pub async fn on_strict_mode_config_update(&mut self) {
         let config = self.collection_config.read().await;
 
+        self.write_backpressure.update_limits(&config);
+
         if let Some(strict_mode_config) = &config.strict_mode_config
             && strict_mode_config.enabled == Some(true)
         {
@@ -1031,10 +1067,12 @@ impl LocalShard {
                 indexed_vectors_count += segment_info.num_indexed_vectors;
                 points_count += segment_info.num_points;
                 for (key, val) in segment_info.index_schema {
-                    schema
-                        .entry(key)
-                        .and_modify(|entry| entry.points += val.points)
-                        .or_insert(val);
+                    match schema.entry(key) {
+                        Entry::Occupied(mut entry) => entry.get_mut().merge(val),
+                        Entry::Vacant(entry) => {
+                            entry.insert(val);
+                        }
+                    }
                 }
             }
             (schema, indexed_vectors_count, points_count, segments_count)
@@ -1238,6 +1276,23 @@ impl LocalShard {
 
         Ok(rx)
     }
+
+    /// Apply all queued updates, then synchronously flush WAL and all segments to disk
+    ///
+    /// Unlike the periodic flush worker, this does not acknowledge WAL entries. It only makes
+    /// sure that nothing has to be recovered from WAL if the process is restarted right after.
+    pub async fn flush(&self) -> CollectionResult<()> {
+        self.plunge_async().await?.await?;
+
+        let wal = self.wal.wal.clone();
+        let segments = self.segments.clone();
+        tokio::task::spawn_blocking(move || -> CollectionResult<()> {
+            wal.blocking_lock().flush()?;
+            segments.read().flush_all(true, true)?;
+            Ok(())
+        })
+        .await?
+    }
 }
 
 /// Return value of [`LocalShard::optimizations`].
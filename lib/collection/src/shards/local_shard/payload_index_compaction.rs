@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use tokio::runtime::Handle;
+use tokio_util::task::AbortOnDropHandle;
+
+use super::LocalShard;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+impl LocalShard {
+    /// Compact storages of payload indexes, which fragmentation ratio reaches the threshold.
+    ///
+    /// Segments wrapped into proxies are skipped, optimizers rebuild them anyway.
+    /// Returns the number of bytes given back to the file system.
+    pub async fn compact_payload_indexes(
+        &self,
+        min_fragmentation_ratio: f64,
+        update_runtime_handle: &Handle,
+    ) -> CollectionResult<usize> {
+        let segments = self
+            .segments
+            .read()
+            .iter_original()
+            .map(|(_, segment)| Arc::clone(segment))
+            .collect::<Vec<_>>();
+
+        let task = update_runtime_handle.spawn_blocking(move || {
+            let mut reclaimed_bytes = 0;
+            for segment in segments {
+                let is_fragmented = segment
+                    .read()
+                    .payload_index
+                    .borrow()
+                    .max_fragmentation_ratio()
+                    .is_some_and(|ratio| ratio >= min_fragmentation_ratio);
+                if !is_fragmented {
+                    continue;
+                }
+
+                // Exclusive lock, so no reader holds the payload index while it is rewritten
+                let write_segment = segment.write();
+                reclaimed_bytes += write_segment
+                    .payload_index
+                    .borrow_mut()
+                    .compact_field_indexes(min_fragmentation_ratio)?;
+            }
+            Ok::<_, CollectionError>(reclaimed_bytes)
+        });
+
+        AbortOnDropHandle::new(task).await?
+    }
+}
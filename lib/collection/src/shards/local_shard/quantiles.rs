@@ -0,0 +1,72 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use futures::future::try_join_all;
+use segment::data_types::quantiles::QuantileSketch;
+use segment::json_path::JsonPath;
+use segment::types::Filter;
+use shard::common::stopping_guard::StoppingGuard;
+use tokio::runtime::Handle;
+use tokio::time::error::Elapsed;
+use tokio_util::task::AbortOnDropHandle;
+
+use super::LocalShard;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+impl LocalShard {
+    /// Distribution of values of the given numeric key, merged from all segments.
+    ///
+    /// Segments are read in parallel, each from its numeric index histogram.
+    pub async fn quantile_sketch(
+        &self,
+        key: JsonPath,
+        filter: Option<Filter>,
+        search_runtime_handle: &Handle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<QuantileSketch> {
+        let stopping_guard = StoppingGuard::new();
+
+        let segments = self
+            .segments
+            .read()
+            .non_appendable_then_appendable_segments()
+            .collect::<Vec<_>>();
+
+        let key = Arc::new(key);
+        let filter = Arc::new(filter);
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+
+        let reads = segments.into_iter().map(|segment| {
+            let key = Arc::clone(&key);
+            let filter = Arc::clone(&filter);
+            let is_stopped = stopping_guard.get_is_stopped();
+            let hw_counter = hw_counter.fork();
+
+            let task = search_runtime_handle.spawn_blocking(move || {
+                let get_segment = segment.get();
+                let read_segment = get_segment.read();
+
+                read_segment.quantile_sketch(
+                    &key,
+                    filter.as_ref().as_ref(),
+                    &is_stopped,
+                    &hw_counter,
+                )
+            });
+            AbortOnDropHandle::new(task)
+        });
+
+        let all_sketches = tokio::time::timeout(timeout, try_join_all(reads))
+            .await
+            .map_err(|_: Elapsed| CollectionError::timeout(timeout, "quantiles"))??;
+
+        let mut sketch = QuantileSketch::default();
+        for segment_sketch in all_sketches {
+            sketch.merge(segment_sketch?);
+        }
+
+        Ok(sketch)
+    }
+}
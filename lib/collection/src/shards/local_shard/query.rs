@@ -12,6 +12,7 @@ use segment::common::reciprocal_rank_fusion::rrf_scoring;
 use segment::common::score_fusion::{ScoreFusion, score_fusion};
 use segment::types::{Filter, HasIdCondition, ScoredPoint, WithPayloadInterface, WithVector};
 use shard::query::planned_query::RescoreStages;
+use shard::query::vector_selection::merge_scoring_passes;
 use shard::search::CoreSearchRequestBatch;
 use tokio::runtime::Handle;
 
@@ -26,6 +27,7 @@ use crate::operations::universal_query::planned_query::{
 };
 use crate::operations::universal_query::shard_query::{
     FusionInternal, MmrInternal, SampleInternal, ScoringQuery, ShardQueryResponse,
+    vector_selection_order,
 };
 
 pub enum FetchedSource {
@@ -414,6 +416,15 @@ impl LocalShard {
                 )
                 .await
             }
+            ScoringQuery::VectorSelection(selection) => {
+                // Each source is a search over one of the selected vectors, restricted to
+                // the points selecting it. Scores are comparable, so the best one is kept per point.
+                let order = vector_selection_order(
+                    &selection,
+                    &self.collection_config.read().await.params,
+                )?;
+                Ok(merge_scoring_passes(sources, order, limit))
+            }
         }
     }
 
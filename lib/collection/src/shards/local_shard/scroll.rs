@@ -15,6 +15,7 @@ use segment::types::{
 };
 use shard::common::stopping_guard::StoppingGuard;
 use shard::retrieve::record_internal::RecordInternal;
+use shard::segment_holder::locked::LockedSegmentHolder;
 use tokio::runtime::Handle;
 use tokio_util::task::AbortOnDropHandle;
 
@@ -80,6 +81,7 @@ impl LocalShard {
         let record_results = match scroll_order {
             ScrollOrder::ById => {
                 self.internal_scroll_by_id(
+                    &self.segments,
                     offset_id,
                     limit,
                     with_payload,
@@ -93,6 +95,7 @@ impl LocalShard {
             }
             ScrollOrder::ByField(order_by) => {
                 self.internal_scroll_by_field(
+                    &self.segments,
                     limit,
                     with_payload,
                     with_vector,
@@ -137,6 +140,7 @@ impl LocalShard {
     #[allow(clippy::too_many_arguments)]
     pub async fn internal_scroll_by_id(
         &self,
+        segments: &LockedSegmentHolder,
         offset: Option<ExtendedPointId>,
         limit: usize,
         with_payload_interface: &WithPayloadInterface,
@@ -149,7 +153,7 @@ impl LocalShard {
         let start = Instant::now();
         let stopping_guard = StoppingGuard::new();
         let update_operation_lock = self.update_operation_lock.read().await;
-        let segments = self.segments.clone();
+        let segments = segments.clone();
         let (non_appendable, appendable) = {
             let Some(segments_guard) = segments.try_read_for(timeout) else {
                 return Err(CollectionError::timeout(timeout, "internal_scroll_by_id"));
@@ -224,6 +228,7 @@ impl LocalShard {
     #[allow(clippy::too_many_arguments)]
     pub async fn internal_scroll_by_field(
         &self,
+        segments: &LockedSegmentHolder,
         limit: usize,
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
@@ -235,7 +240,7 @@ impl LocalShard {
     ) -> CollectionResult<Vec<RecordInternal>> {
         let start = Instant::now();
         let stopping_guard = StoppingGuard::new();
-        let segments = self.segments.clone();
+        let segments = segments.clone();
 
         let update_operation_lock = self.update_operation_lock.read().await;
         let (non_appendable, appendable) = {
@@ -282,17 +287,27 @@ impl LocalShard {
 
         let all_reads = all_reads.into_iter().collect::<Result<Vec<_>, _>>()?;
 
-        let (values, point_ids): (Vec<_>, Vec<_>) = all_reads
+        let merged_reads = all_reads
             .into_iter()
             .kmerge_by(|a, b| match order_by.direction() {
                 Direction::Asc => a <= b,
                 Direction::Desc => a >= b,
             })
-            .dedup()
-            .take(limit)
-            .unzip();
+            .dedup();
+
+        let (values, point_ids): (Vec<_>, Vec<_>) = if order_by.then_by.is_empty() {
+            merged_reads.take(limit).unzip()
+        } else {
+            OrderBy::take_with_ties(merged_reads, limit, |(value, _)| Some(*value))
+                .into_iter()
+                .unzip()
+        };
 
-        let with_payload = WithPayload::from(with_payload_interface);
+        let with_payload = if order_by.then_by.is_empty() {
+            WithPayload::from(with_payload_interface)
+        } else {
+            WithPayload::from(order_by.with_then_by_payload(with_payload_interface))
+        };
 
         // update timeout
         let timeout = timeout.saturating_sub(start.elapsed());
@@ -315,7 +330,7 @@ impl LocalShard {
 
         drop(update_operation_lock);
 
-        let ordered_records = point_ids
+        let ordered_records: Vec<_> = point_ids
             .iter()
             .zip(values)
             .filter_map(|(point_id, value)| {
@@ -325,6 +340,22 @@ impl LocalShard {
             })
             .collect();
 
+        if order_by.then_by.is_empty() {
+            return Ok(ordered_records);
+        }
+
+        let with_payload = WithPayload::from(with_payload_interface);
+        let ordered_records = order_by
+            .sort_by_then_by(ordered_records, limit, |record| {
+                (record.order_value, record.id, record.payload.as_ref())
+            })
+            .into_iter()
+            .map(|mut record| {
+                record.payload = with_payload.select(record.payload.take());
+                record
+            })
+            .collect();
+
         Ok(ordered_records)
     }
 
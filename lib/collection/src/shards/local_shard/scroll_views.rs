@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use shard::segment_holder::locked::LockedSegmentHolder;
+use uuid::Uuid;
+
+use crate::collection_manager::holders::segment_holder::SegmentHolder;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+/// Maximum time a consistent scroll may pin the segments of a shard.
+///
+/// Optimized segments can't be removed while they are pinned, and the optimizer waits for them
+/// to be released. Must stay well below the time the optimizer is willing to wait.
+pub const MAX_SCROLL_VIEW_PIN_DURATION: Duration = Duration::from_secs(10 * 60);
+
+type Views = Mutex<HashMap<Uuid, LockedSegmentHolder>>;
+
+/// Segments pinned by consistent scrolls of a shard.
+///
+/// Each view holds references to the segments the shard had when the scroll started, so the
+/// following pages are read from the same segments, even if optimizations replace them meanwhile.
+/// Views are released once the scroll reaches the end of the shard, or once they expire.
+pub struct ScrollViews {
+    views: Arc<Views>,
+    max_pin_duration: Duration,
+}
+
+impl Default for ScrollViews {
+    fn default() -> Self {
+        Self::new(MAX_SCROLL_VIEW_PIN_DURATION)
+    }
+}
+
+impl ScrollViews {
+    pub fn new(max_pin_duration: Duration) -> Self {
+        Self {
+            views: Arc::new(Mutex::new(HashMap::new())),
+            max_pin_duration,
+        }
+    }
+
+    /// Get segments of the scroll view, pinning the current segments if the scroll starts.
+    ///
+    /// Must be called within a Tokio runtime, which releases the view once it expires.
+    pub fn get_or_pin(
+        &self,
+        view_id: Uuid,
+        is_first_page: bool,
+        segments: &LockedSegmentHolder,
+        timeout: Duration,
+    ) -> CollectionResult<LockedSegmentHolder> {
+        if let Some(view) = self.views.lock().get(&view_id) {
+            return Ok(view.clone());
+        }
+
+        if !is_first_page {
+            return Err(CollectionError::bad_input(format!(
+                "Consistent scroll {view_id} is expired or unknown, the scroll must be restarted",
+            )));
+        }
+
+        let mut pinned = SegmentHolder::default();
+        {
+            let Some(segments_guard) = segments.try_read_for(timeout) else {
+                return Err(CollectionError::timeout(timeout, "pin scroll view"));
+            };
+            for (segment_id, segment) in segments_guard.iter() {
+                pinned.add_existing_locked(segment_id, segment.clone());
+            }
+        }
+
+        let view = self
+            .views
+            .lock()
+            .entry(view_id)
+            .or_insert_with(|| LockedSegmentHolder::new(pinned))
+            .clone();
+
+        let views = Arc::downgrade(&self.views);
+        let max_pin_duration = self.max_pin_duration;
+        tokio::spawn(async move {
+            tokio::time::sleep(max_pin_duration).await;
+            Self::remove(&views, view_id);
+        });
+
+        Ok(view)
+    }
+
+    /// Release the segments pinned by the scroll view
+    pub fn release(&self, view_id: Uuid) {
+        self.views.lock().remove(&view_id);
+    }
+
+    /// Number of currently pinned views
+    pub fn pinned_count(&self) -> usize {
+        self.views.lock().len()
+    }
+
+    fn remove(views: &Weak<Views>, view_id: Uuid) {
+        if let Some(views) = views.upgrade()
+            && views.lock().remove(&view_id).is_some()
+        {
+            log::debug!("Consistent scroll {view_id} expired, releasing its segments");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::collection_manager::fixtures::build_test_holder;
+
+    #[tokio::test]
+    async fn test_pinned_view_outlives_segments_replacement() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segments = build_test_holder(dir.path());
+        let segment_ids = segments.read().segment_ids();
+        let views = ScrollViews::new(Duration::from_secs(60));
+
+        let view_id = Uuid::new_v4();
+        let view = views
+            .get_or_pin(view_id, true, &segments, Duration::from_secs(1))
+            .unwrap();
+
+        // Segments got replaced by an optimization, but the view still reads from them
+        segments.write().remove(&segment_ids);
+        assert!(segments.read().is_empty());
+        assert_eq!(view.read().segment_ids(), segment_ids);
+
+        // Following pages use the same view
+        let view = views
+            .get_or_pin(view_id, false, &segments, Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(view.read().segment_ids(), segment_ids);
+
+        views.release(view_id);
+        assert_eq!(views.pinned_count(), 0);
+        assert!(
+            views
+                .get_or_pin(view_id, false, &segments, Duration::from_secs(1))
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pinned_view_expires() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segments = build_test_holder(dir.path());
+        let views = ScrollViews::new(Duration::from_millis(10));
+
+        let view_id = Uuid::new_v4();
+        views
+            .get_or_pin(view_id, true, &segments, Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(views.pinned_count(), 1);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(views.pinned_count(), 0);
+    }
+}
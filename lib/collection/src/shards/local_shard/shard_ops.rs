@@ -69,6 +69,8 @@ impl ShardOperation for LocalShard {
             ));
         }
 
+        let return_ids = operation.return_ids.take();
+
         let operation_id = {
             let _update_lock = self.update_lock.read().await;
             let pending_operations_count = self.update_queue_length();
@@ -88,6 +90,7 @@ impl ShardOperation for LocalShard {
                         operation_id: None,
                         status: UpdateStatus::ClockRejected,
                         clock_tag: operation.clock_tag,
+                        affected_ids: None,
                     });
                 }
 
@@ -102,6 +105,7 @@ impl ShardOperation for LocalShard {
             channel_permit.send(UpdateSignal::Operation(OperationData {
                 op_num: operation_id,
                 operation,
+                return_ids,
                 sender: callback_sender,
                 hw_measurements: hw_measurement_acc.clone(),
             }));
@@ -112,28 +116,31 @@ impl ShardOperation for LocalShard {
         match (callback_receiver, timeout) {
             // Wait indefinitely
             (Some(receiver), None) => {
-                let _ = receiver.await??;
+                let (_, affected_ids) = receiver.await??;
                 Ok(UpdateResult {
                     operation_id: Some(operation_id),
                     status: UpdateStatus::Completed,
                     clock_tag: operation.clock_tag,
+                    affected_ids,
                 })
             }
             // Wait for timeout
             (Some(receiver), Some(timeout)) => {
                 match tokio::time::timeout(timeout, receiver).await {
                     Ok(res) => {
-                        res??;
+                        let (_, affected_ids) = res??;
                         Ok(UpdateResult {
                             operation_id: Some(operation_id),
                             status: UpdateStatus::Completed,
                             clock_tag: operation.clock_tag,
+                            affected_ids,
                         })
                     }
                     Err(_) => Ok(UpdateResult {
                         operation_id: Some(operation_id),
                         status: UpdateStatus::WaitTimeout,
                         clock_tag: operation.clock_tag,
+                        affected_ids: None,
                     }),
                 }
             }
@@ -142,6 +149,7 @@ impl ShardOperation for LocalShard {
                 operation_id: Some(operation_id),
                 status: UpdateStatus::Acknowledged,
                 clock_tag: operation.clock_tag,
+                affected_ids: None,
             }),
         }
     }
@@ -161,6 +169,8 @@ impl ShardOperation for LocalShard {
             with_payload,
             with_vector,
             order_by,
+            consistent: _,
+            view_id,
         } = request.as_ref();
 
         let default_with_payload = ScrollRequestInternal::default_with_payload();
@@ -183,9 +193,20 @@ impl ShardOperation for LocalShard {
         let limit = limit.unwrap_or(ScrollRequestInternal::default_limit());
         let order_by = order_by.clone().map(OrderBy::from);
         let timeout = self.timeout_or_default_search_timeout(timeout);
+
+        // Consistent scroll reads all of its pages from the segments pinned by its first page
+        let segments = match view_id {
+            Some(view_id) => {
+                self.scroll_views
+                    .get_or_pin(*view_id, offset.is_none(), &self.segments, timeout)?
+            }
+            None => self.segments.clone(),
+        };
+
         let result = match order_by {
             None => {
                 self.internal_scroll_by_id(
+                    &segments,
                     *offset,
                     limit,
                     with_payload.as_ref().unwrap_or(&default_with_payload),
@@ -199,6 +220,7 @@ impl ShardOperation for LocalShard {
             }
             Some(order_by) => {
                 self.internal_scroll_by_field(
+                    &segments,
                     limit,
                     with_payload.as_ref().unwrap_or(&default_with_payload),
                     with_vector,
@@ -212,6 +234,13 @@ impl ShardOperation for LocalShard {
             }
         };
 
+        // No more pages in this shard, the scroll doesn't need its segments anymore
+        if let Some(view_id) = view_id
+            && result.len() < limit
+        {
+            self.scroll_views.release(*view_id);
+        }
+
         let elapsed = start_time.elapsed();
         log_request_to_collector(&self.collection_name, elapsed, || request);
         Ok(result)
@@ -230,6 +259,7 @@ impl ShardOperation for LocalShard {
     ) -> CollectionResult<Vec<RecordInternal>> {
         let timeout = self.timeout_or_default_search_timeout(timeout);
         self.internal_scroll_by_id(
+            &self.segments,
             offset,
             limit,
             with_payload_interface,
@@ -259,9 +289,23 @@ impl ShardOperation for LocalShard {
         self.check_read_rate_limiter(&hw_measurement_acc, "core_search", || {
             request.searches.iter().map(|s| s.search_rate_cost()).sum()
         })?;
+        let start_time = Instant::now();
         let timeout = self.timeout_or_default_search_timeout(timeout);
-        self.do_search(request, search_runtime_handle, timeout, hw_measurement_acc)
-            .await
+        let result = self
+            .do_search(
+                Arc::clone(&request),
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await;
+
+        let filter_complexity =
+            self.filter_complexity(request.searches.iter().map(|search| search.filter.as_ref()));
+        self.filter_latencies
+            .add(filter_complexity, result.is_ok(), start_time.elapsed());
+
+        result
     }
 
     /// This call is rate limited by the read rate limiter.
@@ -281,7 +325,7 @@ impl ShardOperation for LocalShard {
             cost
         })?;
         let start_time = Instant::now();
-        let total_count = if request.exact {
+        let result = if request.exact {
             let timeout = self.timeout_or_default_search_timeout(timeout);
             let all_points = tokio::time::timeout(
                 timeout,
@@ -294,15 +338,17 @@ impl ShardOperation for LocalShard {
             )
             .await
             .map_err(|_: Elapsed| CollectionError::timeout(timeout, "count"))??;
-            all_points.len()
+            CountResult::exact(all_points.len())
         } else {
-            self.estimate_cardinality(request.filter.as_ref(), &hw_measurement_acc)
-                .await?
-                .exp
+            // Answered by payload indexes, without reading points
+            let estimation = self
+                .estimate_cardinality(request.filter.as_ref(), &hw_measurement_acc)
+                .await?;
+            CountResult::approximate(&estimation)
         };
         let elapsed = start_time.elapsed();
         log_request_to_collector(&self.collection_name, elapsed, || request);
-        Ok(CountResult { count: total_count })
+        Ok(result)
     }
 
     /// This call is rate limited by the read rate limiter.
@@ -378,6 +424,10 @@ impl ShardOperation for LocalShard {
             .await;
 
         let elapsed = start_time.elapsed();
+        let filter_complexity =
+            self.filter_complexity(requests.iter().flat_map(ShardQueryRequest::filter_refs));
+        self.filter_latencies
+            .add(filter_complexity, result.is_ok(), elapsed);
         log_request_to_collector(&self.collection_name, elapsed, || requests.remove_details());
 
         result
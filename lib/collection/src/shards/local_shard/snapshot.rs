@@ -19,7 +19,7 @@ use shard::segment_holder::SegmentHolder;
 use shard::segment_holder::locked::LockedSegmentHolder;
 use shard::snapshots::snapshot_manifest::SnapshotManifest;
 use shard::snapshots::snapshot_utils::SnapshotUtils;
-use shard::wal::SerdeWal;
+use shard::wal::{SerdeWal, WalBackendType};
 use tokio::sync::OwnedMutexGuard;
 use tokio_util::task::AbortOnDropHandle;
 use wal::{Wal, WalOptions};
@@ -72,6 +72,9 @@ impl LocalShard {
         let tar = tar.clone();
         let temp_path = temp_path.to_path_buf();
 
+        // Embedded WAL keeps entries in memory, there are no files to archive
+        let save_wal = save_wal && self.shared_storage_config.wal_backend == WalBackendType::File;
+
         let plunger_notify = if !save_wal {
             // If we are not saving WAL, we still need to make sure that all submitted by this point
             // updates have made it to the segments. So we use the Plunger to achieve that.
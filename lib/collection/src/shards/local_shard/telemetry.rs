@@ -11,7 +11,9 @@ use tokio_util::task::AbortOnDropHandle;
 
 use crate::operations::types::{CollectionError, CollectionResult, OptimizersStatus};
 use crate::shards::local_shard::{LocalShard, indexed_only};
-use crate::shards::telemetry::{LocalShardTelemetry, OptimizerTelemetry};
+use crate::shards::telemetry::{
+    LocalShardTelemetry, OptimizationPhasesTelemetry, OptimizerTelemetry,
+};
 
 impl LocalShard {
     pub async fn get_telemetry_data(
@@ -65,6 +67,11 @@ impl LocalShard {
         let (segments, index_only_excluded_vectors) = segments_data?;
         let total_optimized_points = self.total_optimized_points.load(Ordering::Relaxed);
 
+        let access_frequency = segments
+            .iter()
+            .map(|segment| segment.access_frequency)
+            .reduce(|total, stats| total + stats);
+
         let optimizations: OperationDurationStatistics = self
             .optimizers
             .load()
@@ -77,6 +84,17 @@ impl LocalShard {
             })
             .fold(Default::default(), |total, stats| total + stats);
 
+        let phases = self.optimizers.load().iter().fold(
+            OptimizationPhasesTelemetry::default(),
+            |total, optimizer| {
+                let counters = optimizer.get_phase_telemetry_counters();
+                OptimizationPhasesTelemetry {
+                    build: total.build + counters.build.lock().get_statistics(detail),
+                    finalize: total.finalize + counters.finalize.lock().get_statistics(detail),
+                }
+            },
+        );
+
         let status = self
             .get_optimization_status(timeout.saturating_sub(start.elapsed()))
             .await?;
@@ -104,9 +122,11 @@ impl LocalShard {
             } else {
                 Some(segments)
             },
+            access_frequency,
             optimizations: Some(OptimizerTelemetry {
                 status,
                 optimizations,
+                phases,
                 log: (detail.level >= DetailsLevel::Level4)
                     .then(|| self.optimizers_log.lock().to_telemetry()),
             }),
@@ -114,6 +134,7 @@ impl LocalShard {
             indexed_only_excluded_vectors: (!index_only_excluded_vectors.is_empty())
                 .then_some(index_only_excluded_vectors),
             update_queue: Some(self.local_update_queue_info()),
+            filter_latency: Some(self.filter_latencies.get_telemetry_data(detail)),
         })
     }
 
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::try_join_all;
+use segment::data_types::term_stats::TermStatsAggregator;
+use segment::json_path::JsonPath;
+use shard::common::stopping_guard::StoppingGuard;
+use tokio::runtime::Handle;
+use tokio::time::error::Elapsed;
+use tokio_util::task::AbortOnDropHandle;
+
+use super::LocalShard;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+impl LocalShard {
+    /// Term statistics of the given full-text key, merged from all segments.
+    ///
+    /// Segments are read in parallel, each from its full-text index.
+    pub async fn term_stats(
+        &self,
+        key: JsonPath,
+        search_runtime_handle: &Handle,
+        timeout: Duration,
+    ) -> CollectionResult<TermStatsAggregator> {
+        let stopping_guard = StoppingGuard::new();
+
+        let segments = self
+            .segments
+            .read()
+            .non_appendable_then_appendable_segments()
+            .collect::<Vec<_>>();
+
+        let key = Arc::new(key);
+
+        let reads = segments.into_iter().map(|segment| {
+            let key = Arc::clone(&key);
+            let is_stopped = stopping_guard.get_is_stopped();
+
+            let task = search_runtime_handle.spawn_blocking(move || {
+                let get_segment = segment.get();
+                let read_segment = get_segment.read();
+
+                read_segment.term_stats(&key, &is_stopped)
+            });
+            AbortOnDropHandle::new(task)
+        });
+
+        let all_stats = tokio::time::timeout(timeout, try_join_all(reads))
+            .await
+            .map_err(|_: Elapsed| CollectionError::timeout(timeout, "term stats"))??;
+
+        let mut stats = TermStatsAggregator::default();
+        for segment_stats in all_stats {
+            stats.merge(segment_stats?);
+        }
+
+        Ok(stats)
+    }
+}
@@ -38,6 +38,10 @@ impl LocalShard {
     /// This function is **not** cancel safe.
     pub async fn on_optimizer_config_update(&self) -> CollectionResult<()> {
         let config = self.collection_config.read().await;
+
+        // Indexing threshold defines which segments count as unindexed
+        self.write_backpressure.update_limits(&config);
+
         let mut update_handler = self.update_handler.lock().await;
 
         // Signal all workers to stop
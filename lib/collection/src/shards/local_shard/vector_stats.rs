@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use futures::future::try_join_all;
+use segment::data_types::vector_stats::{VectorStatsAccumulator, sample_vector_stats};
+use segment::types::VectorNameBuf;
+use shard::common::stopping_guard::StoppingGuard;
+use tokio::runtime::Handle;
+use tokio::time::error::Elapsed;
+use tokio_util::task::AbortOnDropHandle;
+
+use super::LocalShard;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+impl LocalShard {
+    /// Statistics over a random sample of vectors, collected from all segments in parallel.
+    ///
+    /// Each segment contributes to the sample proportionally to its number of points.
+    pub async fn sample_vector_stats(
+        &self,
+        vector_name: VectorNameBuf,
+        sample_size: usize,
+        search_runtime_handle: &Handle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<VectorStatsAccumulator> {
+        let stopping_guard = StoppingGuard::new();
+
+        let segments = self
+            .segments
+            .read()
+            .non_appendable_then_appendable_segments()
+            .collect::<Vec<_>>();
+
+        let total_points: usize = segments
+            .iter()
+            .map(|segment| segment.get().read().available_point_count())
+            .sum();
+        if total_points == 0 || sample_size == 0 {
+            return Ok(VectorStatsAccumulator::default());
+        }
+
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+
+        let reads = segments.into_iter().map(|segment| {
+            let vector_name = vector_name.clone();
+            let is_stopped = stopping_guard.get_is_stopped();
+            let hw_counter = hw_counter.fork();
+
+            let task = search_runtime_handle.spawn_blocking(move || {
+                let get_segment = segment.get();
+                let read_segment = get_segment.read();
+
+                if !read_segment.vector_names().contains(&vector_name) {
+                    return Ok(VectorStatsAccumulator::default());
+                }
+
+                let points = read_segment.available_point_count();
+                let segment_sample = (sample_size * points).div_ceil(total_points);
+
+                sample_vector_stats(
+                    &*read_segment,
+                    &vector_name,
+                    segment_sample,
+                    &is_stopped,
+                    &hw_counter,
+                )
+            });
+            AbortOnDropHandle::new(task)
+        });
+
+        let all_stats = tokio::time::timeout(timeout, try_join_all(reads))
+            .await
+            .map_err(|_: Elapsed| CollectionError::timeout(timeout, "vector stats"))??;
+
+        let mut stats = VectorStatsAccumulator::default();
+        for segment_stats in all_stats {
+            stats.merge(segment_stats?)?;
+        }
+
+        Ok(stats)
+    }
+}
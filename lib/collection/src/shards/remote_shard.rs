@@ -17,7 +17,7 @@ use api::grpc::qdrant::{
     ScrollPointsInternal, SearchBatchResponse, ShardSnapshotLocation,
     UpdateShardCutoffPointRequest, WaitForShardStateRequest,
 };
-use api::grpc::transport_channel_pool::{AddTimeout, MAX_GRPC_CHANNEL_TIMEOUT};
+use api::grpc::transport_channel_pool::{AddTimeout, CompressedClient, MAX_GRPC_CHANNEL_TIMEOUT};
 use api::grpc::update_operation::Update;
 use api::grpc::{UpdateBatchInternal, UpdateOperation, WithPayloadSelector};
 use async_trait::async_trait;
@@ -136,10 +136,11 @@ impl RemoteShard {
         f: impl Fn(PointsInternalClient<InterceptedService<Channel, AddTimeout>>) -> O,
     ) -> CollectionResult<T> {
         let current_address = self.current_address()?;
+        let compression = self.channel_service.channel_pool.compression();
         self.channel_service
             .channel_pool
             .with_channel(&current_address, |channel| {
-                let client = PointsInternalClient::new(channel);
+                let client = PointsInternalClient::new(channel).compressed(compression);
                 let client = client.max_decoding_message_size(usize::MAX);
                 f(client)
             })
@@ -152,10 +153,11 @@ impl RemoteShard {
         f: impl Fn(CollectionsInternalClient<InterceptedService<Channel, AddTimeout>>) -> O,
     ) -> CollectionResult<T> {
         let current_address = self.current_address()?;
+        let compression = self.channel_service.channel_pool.compression();
         self.channel_service
             .channel_pool
             .with_channel(&current_address, |channel| {
-                let client = CollectionsInternalClient::new(channel);
+                let client = CollectionsInternalClient::new(channel).compressed(compression);
                 let client = client.max_decoding_message_size(usize::MAX);
                 f(client)
             })
@@ -170,12 +172,13 @@ impl RemoteShard {
         retries: usize,
     ) -> CollectionResult<T> {
         let current_address = self.current_address()?;
+        let compression = self.channel_service.channel_pool.compression();
         self.channel_service
             .channel_pool
             .with_channel_timeout(
                 &current_address,
                 |channel| {
-                    let client = ShardSnapshotsClient::new(channel);
+                    let client = ShardSnapshotsClient::new(channel).compressed(compression);
                     let client = client.max_decoding_message_size(usize::MAX);
                     f(client)
                 },
@@ -191,10 +194,11 @@ impl RemoteShard {
         f: impl Fn(QdrantClient<InterceptedService<Channel, AddTimeout>>) -> Fut,
     ) -> CollectionResult<T> {
         let current_address = self.current_address()?;
+        let compression = self.channel_service.channel_pool.compression();
         self.channel_service
             .channel_pool
             .with_channel(&current_address, |channel| {
-                let client = QdrantClient::new(channel);
+                let client = QdrantClient::new(channel).compressed(compression);
                 f(client)
             })
             .await
@@ -294,6 +298,7 @@ impl RemoteShard {
                             operation.clock_tag,
                             collection_name.clone(),
                             filter,
+                            operation.return_ids.clone(),
                             wait,
                             timeout,
                             ordering,
@@ -320,6 +325,20 @@ impl RemoteShard {
                             operation.clock_tag,
                             collection_name.clone(),
                             update_operation,
+                            false,
+                            wait,
+                            timeout,
+                            ordering,
+                        )?;
+                        Update::UpdateVectors(request)
+                    }
+                    VectorOperations::AppendVectors(append_operation) => {
+                        let request = internal_update_vectors(
+                            shard_id,
+                            operation.clock_tag,
+                            collection_name.clone(),
+                            append_operation,
+                            true,
                             wait,
                             timeout,
                             ordering,
@@ -360,6 +379,7 @@ impl RemoteShard {
                             operation.clock_tag,
                             collection_name.clone(),
                             set_payload,
+                            operation.return_ids.clone(),
                             wait,
                             timeout,
                             ordering,
@@ -396,6 +416,7 @@ impl RemoteShard {
                             operation.clock_tag,
                             collection_name.clone(),
                             filter,
+                            operation.return_ids.clone(),
                             wait,
                             timeout,
                             ordering,
@@ -408,6 +429,7 @@ impl RemoteShard {
                             operation.clock_tag,
                             collection_name.clone(),
                             set_payload,
+                            operation.return_ids.clone(),
                             wait,
                             timeout,
                             ordering,
@@ -586,6 +608,7 @@ impl RemoteShard {
                         operation.clock_tag,
                         collection_name,
                         filter,
+                        operation.return_ids.clone(),
                         wait,
                         timeout,
                         ordering,
@@ -620,6 +643,26 @@ impl RemoteShard {
                         operation.clock_tag,
                         collection_name,
                         update_operation,
+                        false,
+                        wait,
+                        timeout,
+                        ordering,
+                    )?;
+                    self.with_points_client(|mut client| async move {
+                        client
+                            .update_vectors(tonic::Request::new(request.clone()))
+                            .await
+                    })
+                    .await?
+                    .into_inner()
+                }
+                VectorOperations::AppendVectors(append_operation) => {
+                    let request = &internal_update_vectors(
+                        shard_id,
+                        operation.clock_tag,
+                        collection_name,
+                        append_operation,
+                        true,
                         wait,
                         timeout,
                         ordering,
@@ -678,6 +721,7 @@ impl RemoteShard {
                         operation.clock_tag,
                         collection_name,
                         set_payload,
+                        operation.return_ids.clone(),
                         wait,
                         timeout,
                         ordering,
@@ -732,6 +776,7 @@ impl RemoteShard {
                         operation.clock_tag,
                         collection_name,
                         filter,
+                        operation.return_ids.clone(),
                         wait,
                         timeout,
                         ordering,
@@ -750,6 +795,7 @@ impl RemoteShard {
                         operation.clock_tag,
                         collection_name,
                         set_payload,
+                        operation.return_ids.clone(),
                         wait,
                         timeout,
                         ordering,
@@ -821,6 +867,7 @@ impl RemoteShard {
                     operation_id: None,
                     status: crate::operations::types::UpdateStatus::Completed,
                     clock_tag: operation.clock_tag,
+                    affected_ids: None,
                 });
             }
         };
@@ -1032,6 +1079,8 @@ impl ShardOperation for RemoteShard {
             with_payload,
             with_vector,
             order_by,
+            consistent,
+            view_id,
         } = request.as_ref();
 
         let with_payload = with_payload
@@ -1054,6 +1103,8 @@ impl ShardOperation for RemoteShard {
             shard_key_selector: None,
             order_by: order_by.map(api::grpc::qdrant::OrderBy::from),
             timeout: processed_timeout.map(|t| t.as_secs()),
+            consistent: *consistent,
+            view_id: view_id.map(|view_id| view_id.to_string()),
         };
         let scroll_request = &ScrollPointsInternal {
             scroll_points: Some(scroll_points),
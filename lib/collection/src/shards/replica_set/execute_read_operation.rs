@@ -1,11 +1,13 @@
 use std::cmp;
 use std::fmt::Write as _;
 use std::ops::Deref as _;
+use std::time::Duration;
 
 use futures::future::{self, BoxFuture};
 use futures::stream::FuturesUnordered;
 use futures::{FutureExt as _, StreamExt as _};
 use rand::seq::SliceRandom as _;
+use segment::common::operation_time_statistics::ScopeDurationMeasurer;
 
 use super::ShardReplicaSet;
 use crate::operations::consistency_params::{ReadConsistency, ReadConsistencyType};
@@ -14,6 +16,13 @@ use crate::shards::remote_shard::RemoteShard;
 use crate::shards::resolve::{Resolve, ResolveCondition};
 use crate::shards::shard_trait::ShardOperation;
 
+/// Peers with a round-trip time within this factor of the closest peer are considered nearby
+const NEARBY_PEER_RTT_FACTOR: u32 = 2;
+
+/// Peers with a round-trip time within this margin of the closest peer are considered nearby,
+/// so that small variations within a single datacenter don't split peers into separate groups
+const NEARBY_PEER_RTT_MARGIN: Duration = Duration::from_millis(5);
+
 impl ShardReplicaSet {
     /// Execute read op. on replica set:
     /// 1 - Prefer local replica
@@ -32,10 +41,14 @@ impl ShardReplicaSet {
             return self.execute_local_read_operation(read_operation).await;
         }
 
+        let mut timer = ScopeDurationMeasurer::new(&self.read_durations);
+        timer.set_success(false);
+
         let mut responses = self
             .execute_cluster_read_operation(read_operation, 1, None)
             .await?;
 
+        timer.set_success(true);
         Ok(responses.pop().unwrap())
     }
 
@@ -53,6 +66,9 @@ impl ShardReplicaSet {
             return self.execute_local_read_operation(read_operation).await;
         }
 
+        let mut timer = ScopeDurationMeasurer::new(&self.read_durations);
+        timer.set_success(false);
+
         let read_consistency = read_consistency.unwrap_or_default();
 
         let local_count = usize::from(self.peer_state(self.this_peer_id()).is_some());
@@ -116,6 +132,8 @@ impl ShardReplicaSet {
             )
             .await?;
 
+        timer.set_success(true);
+
         if responses.is_empty() {
             Ok(Res::default())
         } else if responses.len() == 1 {
@@ -204,6 +222,12 @@ impl ShardReplicaSet {
 
         readable_remotes.shuffle(&mut rand::rng());
 
+        if self.channel_service.latency_aware_routing {
+            prefer_nearby_peers(&mut readable_remotes, |remote| {
+                self.channel_service.peer_rtt(remote.peer_id)
+            });
+        }
+
         let remote_operations = readable_remotes.into_iter().map(|remote| {
             read_operation(remote)
                 .map(|result| (result, false))
@@ -351,3 +375,54 @@ impl ShardReplicaSet {
         }
     }
 }
+
+/// Stable-sort `peers` into nearby peers, peers with unknown round-trip time, and distant peers.
+///
+/// Keeps the existing (shuffled) order within each group, to spread the load between
+/// equally close replicas.
+fn prefer_nearby_peers<T>(peers: &mut [T], rtt: impl Fn(&T) -> Option<Duration>) {
+    let Some(min_rtt) = peers.iter().filter_map(&rtt).min() else {
+        return;
+    };
+
+    let nearby_rtt = min_rtt * NEARBY_PEER_RTT_FACTOR + NEARBY_PEER_RTT_MARGIN;
+
+    peers.sort_by_key(|peer| match rtt(peer) {
+        Some(rtt) if rtt <= nearby_rtt => 0,
+        None => 1,
+        Some(_) => 2,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefer_nearby_peers() {
+        let rtts = [
+            (1, Some(80)),
+            (2, None),
+            (3, Some(2)),
+            (4, Some(95)),
+            (5, Some(4)),
+            (6, Some(12)),
+        ];
+
+        let mut peers: Vec<_> = rtts.iter().map(|(peer_id, _)| *peer_id).collect();
+        prefer_nearby_peers(&mut peers, |peer_id| {
+            rtts.iter()
+                .find(|(id, _)| id == peer_id)
+                .and_then(|(_, rtt)| rtt.map(Duration::from_millis))
+        });
+
+        assert_eq!(peers, vec![3, 5, 2, 1, 4, 6]);
+    }
+
+    #[test]
+    fn test_prefer_nearby_peers_unmeasured() {
+        let mut peers = vec![3, 1, 2];
+        prefer_nearby_peers(&mut peers, |_| None);
+        assert_eq!(peers, vec![3, 1, 2]);
+    }
+}
@@ -21,6 +21,7 @@ use common::rate_limiting::RateLimiter;
 use common::save_on_disk::SaveOnDisk;
 use parking_lot::Mutex as ParkingMutex;
 use replica_set_state::{ReplicaSetState, ReplicaState};
+use segment::common::operation_time_statistics::OperationDurationsAggregator;
 use segment::types::{ExtendedPointId, Filter, SeqNumberType, ShardKey};
 use serde::{Deserialize, Serialize};
 use tokio::runtime::Handle;
@@ -121,6 +122,9 @@ pub struct ShardReplicaSet {
     clock_set: Mutex<ClockSet>,
     write_rate_limiter: Option<parking_lot::Mutex<RateLimiter>>,
     pub partial_snapshot_meta: PartialSnapshotMeta,
+    /// Durations of read operations started on this peer, regardless of the replica serving them.
+    /// Used to estimate the query load on the shard.
+    read_durations: Arc<ParkingMutex<OperationDurationsAggregator>>,
 }
 
 pub type AbortShardTransfer = Arc<dyn Fn(ShardTransfer, &str) + Send + Sync>;
@@ -231,6 +235,7 @@ impl ShardReplicaSet {
             clock_set: Default::default(),
             write_rate_limiter,
             partial_snapshot_meta: PartialSnapshotMeta::default(),
+            read_durations: OperationDurationsAggregator::new(),
         })
     }
 
@@ -373,6 +378,7 @@ impl ShardReplicaSet {
             clock_set: Default::default(),
             write_rate_limiter,
             partial_snapshot_meta: PartialSnapshotMeta::default(),
+            read_durations: OperationDurationsAggregator::new(),
         };
 
         // `active_remote_shards` includes `Active` and `ReshardingScaleDown` replicas!
@@ -997,6 +1003,25 @@ impl ShardReplicaSet {
         Ok(())
     }
 
+    /// Check if the local replica is too far behind with updates or optimizations
+    /// to accept more writes, based on the limits configured in strict mode
+    ///
+    /// Returns a rate limit error with a suggested retry delay if any limit is exceeded.
+    async fn check_write_backpressure(
+        &self,
+        hw_measurement_acc: &HwMeasurementAcc,
+        local: &Shard,
+    ) -> CollectionResult<()> {
+        // Do not apply backpressure to internal operation tagged with disposable measurement
+        if hw_measurement_acc.is_disposable() {
+            return Ok(());
+        }
+        let Some(local_shard) = local.local_shard() else {
+            return Ok(());
+        };
+        local_shard.check_write_backpressure().await
+    }
+
     /// Check if there are any locally disabled peers
     /// And if so, report them to the consensus
     pub fn sync_local_state<F>(&self, get_shard_transfers: F) -> CollectionResult<()>
@@ -1088,6 +1113,7 @@ impl ShardReplicaSet {
                 operation_id: None,
                 status: UpdateStatus::Completed,
                 clock_tag: None,
+                affected_ids: None,
             });
         }
 
@@ -1381,6 +1407,23 @@ impl ShardReplicaSet {
         local.as_ref().and_then(|shard| shard.optimizations())
     }
 
+    /// Compact fragmented storages of payload indexes of the local replica, if present.
+    ///
+    /// Returns the number of bytes given back to the file system.
+    pub async fn compact_local_payload_indexes(
+        &self,
+        min_fragmentation_ratio: f64,
+    ) -> CollectionResult<usize> {
+        let local = self.local.read().await;
+        let Some(local_shard) = local.as_ref().and_then(Shard::local_shard) else {
+            return Ok(0);
+        };
+
+        local_shard
+            .compact_payload_indexes(min_fragmentation_ratio, &self.update_runtime)
+            .await
+    }
+
     /// Truncate unapplied WAL records for the local shard (if present).
     /// Returns amount of removed records.
     pub async fn truncate_unapplied_wal(&self) -> CollectionResult<usize> {
@@ -1401,6 +1444,32 @@ impl ShardReplicaSet {
         }
         Ok(removed_records_count)
     }
+
+    /// Run an aggregation over the local replica of the shard.
+    ///
+    /// Aggregations are not forwarded to remote replicas. To not silently return partial
+    /// results, fail if this peer has no active replica of the shard.
+    pub async fn execute_local_aggregation<Res, F>(&self, aggregation: F) -> CollectionResult<Res>
+    where
+        F: AsyncFnOnce(&LocalShard, &Handle) -> CollectionResult<Res>,
+    {
+        let local = self.local.read().await;
+
+        let local_shard = local
+            .as_ref()
+            .filter(|_| self.peer_is_active(self.this_peer_id()))
+            .and_then(Shard::local_shard);
+
+        let Some(local_shard) = local_shard else {
+            return Err(CollectionError::bad_request(format!(
+                "Shard {} has no active replica on this peer, \
+                 aggregating over remote replicas is not supported",
+                self.shard_id,
+            )));
+        };
+
+        aggregation(local_shard, &self.search_runtime).await
+    }
 }
 
 /// Represents a change in replica set, due to scaling of `replication_factor`
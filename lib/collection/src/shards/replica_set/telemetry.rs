@@ -2,6 +2,7 @@ use std::ops::Deref as _;
 use std::time::Duration;
 
 use common::types::TelemetryDetail;
+use segment::common::operation_time_statistics::OperationDurationStatistics;
 use segment::types::SizeStats;
 
 use crate::operations::types::{CollectionResult, OptimizersStatus};
@@ -66,4 +67,11 @@ impl ShardReplicaSet {
 
         local.get_size_stats(timeout).await
     }
+
+    /// Statistics of read operations started on this peer
+    pub(crate) fn get_read_statistics(&self) -> OperationDurationStatistics {
+        self.read_durations
+            .lock()
+            .get_statistics(TelemetryDetail::default())
+    }
 }
@@ -10,7 +10,7 @@ use tokio::task::yield_now;
 use tokio_util::task::AbortOnDropHandle;
 
 use super::{ShardReplicaSet, clock_set};
-use crate::operations::point_ops::WriteOrdering;
+use crate::operations::point_ops::{ReturnIds, WriteOrdering};
 use crate::operations::types::{CollectionError, CollectionResult, UpdateResult, UpdateStatus};
 use crate::operations::{ClockTag, CollectionUpdateOperations, OperationWithClockTag};
 use crate::shards::replica_set::clock_set::ClockGuard;
@@ -137,6 +137,7 @@ impl ShardReplicaSet {
     pub async fn update_with_consistency(
         &self,
         operation: CollectionUpdateOperations,
+        return_ids: Option<ReturnIds>,
         wait: bool,
         timeout: Option<Duration>,
         ordering: WriteOrdering,
@@ -170,6 +171,7 @@ impl ShardReplicaSet {
 
             self.update(
                 operation,
+                return_ids,
                 wait,
                 timeout,
                 update_only_existing,
@@ -178,8 +180,16 @@ impl ShardReplicaSet {
             .await
         } else {
             // Forward the update to the designated leader
-            self.forward_update(leader_peer, operation, wait, timeout, ordering, hw_measurement_acc)
-                .await
+            self.forward_update(
+                leader_peer,
+                operation,
+                return_ids,
+                wait,
+                timeout,
+                ordering,
+                hw_measurement_acc,
+            )
+            .await
                 .map_err(|err| {
                     if err.is_transient() {
                         // Deactivate the peer if forwarding failed with transient error
@@ -238,6 +248,7 @@ impl ShardReplicaSet {
     async fn update(
         &self,
         operation: CollectionUpdateOperations,
+        return_ids: Option<ReturnIds>,
         wait: bool,
         timeout: Option<Duration>,
         update_only_existing: bool,
@@ -267,6 +278,7 @@ impl ShardReplicaSet {
             let res = self
                 .update_impl(
                     operation.clone(),
+                    return_ids.clone(),
                     wait,
                     timeout,
                     &mut clock,
@@ -309,6 +321,7 @@ impl ShardReplicaSet {
     async fn update_impl(
         &self,
         operation: CollectionUpdateOperations,
+        return_ids: Option<ReturnIds>,
         wait: bool,
         timeout: Option<Duration>,
         clock: &mut clock_set::ClockGuard,
@@ -343,7 +356,8 @@ impl ShardReplicaSet {
 
         let current_clock_tick = clock.tick_once();
         let clock_tag = ClockTag::new(this_peer_id, clock.id() as _, current_clock_tick);
-        let operation = OperationWithClockTag::new(operation, Some(clock_tag));
+        let operation =
+            OperationWithClockTag::new(operation, Some(clock_tag)).with_return_ids(return_ids);
 
         let mut update_futures = Vec::with_capacity(updatable_remote_shards.len() + 1);
 
@@ -360,6 +374,8 @@ impl ShardReplicaSet {
                 // Check write rate limiter before proceeding if replica active
                 self.check_operation_write_rate_limiter(&hw_measurement_acc, local, &operation)
                     .await?;
+                // Reject the write early if the replica can't keep up with updates
+                self.check_write_backpressure(&hw_measurement_acc, local).await?;
             }
 
             let operation = operation.clone();
@@ -712,6 +728,7 @@ impl ShardReplicaSet {
         &self,
         leader_peer: PeerId,
         operation: CollectionUpdateOperations,
+        return_ids: Option<ReturnIds>,
         wait: bool,
         timeout: Option<Duration>,
         ordering: WriteOrdering,
@@ -730,7 +747,7 @@ impl ShardReplicaSet {
 
         remote_leader
             .forward_update(
-                OperationWithClockTag::from(operation),
+                OperationWithClockTag::from(operation).with_return_ids(return_ids),
                 wait,
                 timeout,
                 ordering,
@@ -761,10 +778,18 @@ impl ShardReplicaSet {
         let mut result = successes
             .iter()
             .max_by_key(|(peer_id, _)| *peer_id)
-            .map(|(_, res)| *res)
+            .map(|(_, res)| res.clone())
             .expect("successes is not empty");
 
         result.status = status;
+
+        // All replicas apply the same operation, so any of them may report the affected ids
+        if result.affected_ids.is_none() {
+            result.affected_ids = successes
+                .iter()
+                .find_map(|(_, res)| res.affected_ids.clone());
+        }
+
         result
     }
 
@@ -782,6 +807,14 @@ impl ShardReplicaSet {
             None => Ok(None),
         }
     }
+
+    /// Apply queued updates and flush the local shard to disk, if present
+    pub async fn flush_local(&self) -> CollectionResult<()> {
+        match self.local.read().await.deref() {
+            Some(local) => local.flush().await,
+            None => Ok(()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -818,6 +851,7 @@ mod tests {
                     operation_id: Some(10),
                     status: UpdateStatus::Completed,
                     clock_tag: Some(local_tag),
+                    affected_ids: None,
                 },
             ),
             (
@@ -826,6 +860,7 @@ mod tests {
                     operation_id: Some(20),
                     status: UpdateStatus::WaitTimeout,
                     clock_tag: Some(remote_tag),
+                    affected_ids: None,
                 },
             ),
         ];
@@ -851,6 +886,7 @@ mod tests {
                     operation_id: Some(10),
                     status: UpdateStatus::Acknowledged,
                     clock_tag: Some(local_tag),
+                    affected_ids: None,
                 },
             ),
             (
@@ -859,6 +895,7 @@ mod tests {
                     operation_id: Some(20),
                     status: UpdateStatus::Completed,
                     clock_tag: Some(remote_tag),
+                    affected_ids: None,
                 },
             ),
         ];
@@ -901,6 +938,7 @@ mod tests {
         flush_interval_sec: 30,
         max_optimization_threads: Some(2),
         prevent_unoptimized: None,
+        max_segment_number: None,
     };
 
     async fn new_shard_replica_set(collection_dir: &TempDir) -> ShardReplicaSet {
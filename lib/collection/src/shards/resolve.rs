@@ -35,23 +35,15 @@ pub trait Resolve: Default + Sized {
 impl Resolve for CountResult {
     fn resolve(records: Vec<Self>, condition: ResolveCondition) -> Self {
         match condition {
-            ResolveCondition::All => Self {
-                count: records
-                    .iter()
-                    .map(|result| result.count)
-                    .min()
-                    .unwrap_or_default(),
-            },
+            ResolveCondition::All => records
+                .into_iter()
+                .min_by_key(|result| result.count)
+                .unwrap_or_default(),
             ResolveCondition::Majority => {
-                let mut counts = records
-                    .iter()
-                    .map(|result| result.count)
-                    .collect::<Vec<_>>();
-                counts.sort_unstable();
-                let middle = counts.len() / 2;
-                Self {
-                    count: counts.get(middle).copied().unwrap_or_default(),
-                }
+                let mut records = records;
+                records.sort_unstable_by_key(|result| result.count);
+                let middle = records.len() / 2;
+                records.into_iter().nth(middle).unwrap_or_default()
             }
         }
     }
@@ -87,11 +79,11 @@ impl Resolve for FacetResponse {
                 HashMap::new(),
                 |mut map: HashMap<FacetValue, Vec<CountResult>>, hit| {
                     if let Some(counts) = map.get_mut(&hit.value) {
-                        counts.push(CountResult { count: hit.count });
+                        counts.push(CountResult::exact(hit.count));
                     } else {
                         map.entry(hit.value.clone())
                             .or_insert(Vec::with_capacity(num_replicas))
-                            .push(CountResult { count: hit.count });
+                            .push(CountResult::exact(hit.count));
                     };
                     map
                 },
@@ -864,4 +856,35 @@ mod test {
             Resolver::resolve(values, |val| val.0, PartialEq::eq, condition)
         }
     }
+
+    fn approximate_count(min: usize, exp: usize, max: usize) -> CountResult {
+        CountResult {
+            count: exp,
+            min: Some(min),
+            max: Some(max),
+        }
+    }
+
+    #[test]
+    fn resolve_count_keeps_bounds() {
+        let counts = || {
+            vec![
+                approximate_count(8, 10, 12),
+                approximate_count(5, 7, 9),
+                approximate_count(10, 13, 20),
+            ]
+        };
+
+        let all = CountResult::resolve(counts(), ResolveCondition::All);
+        assert_eq!((all.count, all.min, all.max), (7, Some(5), Some(9)));
+
+        let majority = CountResult::resolve(counts(), ResolveCondition::Majority);
+        assert_eq!(
+            (majority.count, majority.min, majority.max),
+            (10, Some(8), Some(12)),
+        );
+
+        let exact = CountResult::resolve(vec![CountResult::exact(3)], ResolveCondition::All);
+        assert_eq!((exact.count, exact.min, exact.max), (3, None, None));
+    }
 }
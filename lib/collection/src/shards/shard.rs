@@ -485,6 +485,17 @@ impl Shard {
         }
     }
 
+    /// Local shard holding the data, if any
+    pub fn local_shard(&self) -> Option<&LocalShard> {
+        match self {
+            Self::Local(local_shard) => Some(local_shard),
+            Self::Proxy(proxy_shard) => Some(&proxy_shard.wrapped_shard),
+            Self::ForwardProxy(proxy_shard) => Some(&proxy_shard.wrapped_shard),
+            Self::QueueProxy(proxy_shard) => proxy_shard.wrapped_shard(),
+            Self::Dummy(_) => None,
+        }
+    }
+
     pub async fn stop_gracefully(self) {
         match self {
             Shard::Local(local_shard) => local_shard.stop_gracefully().await,
@@ -520,4 +531,20 @@ impl Shard {
             Shard::Dummy(_) => Ok(fake_plunger()),
         }
     }
+
+    /// Apply all queued updates and flush WAL and segments of the wrapped local shard to disk
+    pub async fn flush(&self) -> CollectionResult<()> {
+        match self {
+            Shard::Local(local_shard) => local_shard.flush().await,
+            Shard::Proxy(proxy_shard) => proxy_shard.wrapped_shard.flush().await,
+            Shard::ForwardProxy(forward_proxy_shard) => {
+                forward_proxy_shard.wrapped_shard.flush().await
+            }
+            Shard::QueueProxy(queue_proxy_shard) => match queue_proxy_shard.wrapped_shard() {
+                Some(wrapped_shard) => wrapped_shard.flush().await,
+                None => Ok(()),
+            },
+            Shard::Dummy(_) => Ok(()),
+        }
+    }
 }
@@ -569,6 +569,8 @@ impl OperationsByMode {
                                 points_op: operation,
                                 condition: Filter::new(), // Always true condition
                                 update_mode: Some(UpdateMode::UpdateOnly),
+                                sequence_key: None,
+                                skip_normalization: None,
                             },
                         ),
                     )]
@@ -580,6 +582,8 @@ impl OperationsByMode {
                                 points_op: operation.points_op,
                                 condition: operation.condition,
                                 update_mode: Some(UpdateMode::UpdateOnly),
+                                sequence_key: operation.sequence_key,
+                                skip_normalization: operation.skip_normalization,
                             },
                         ),
                     )]
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use schemars::JsonSchema;
+use segment::common::access_frequency::AccessFrequencyTelemetry;
 use segment::common::anonymize::{Anonymize, anonymize_collection_values};
 use segment::common::operation_time_statistics::OperationDurationStatistics;
 use segment::telemetry::SegmentTelemetry;
@@ -67,6 +68,9 @@ pub struct LocalShardTelemetry {
     pub num_vectors_by_name: Option<HashMap<String, usize>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub segments: Option<Vec<SegmentTelemetry>>,
+    /// Approximate access frequency of points by searches, summed over all segments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_frequency: Option<AccessFrequencyTelemetry>,
     pub optimizations: Option<OptimizerTelemetry>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub async_scorer: Option<bool>,
@@ -75,16 +79,57 @@ pub struct LocalShardTelemetry {
     /// Update queue status
     #[serde(skip_serializing_if = "Option::is_none")]
     pub update_queue: Option<UpdateQueueInfo>,
+    /// Durations of searches and queries, grouped by complexity of their filters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_latency: Option<FilterLatencyTelemetry>,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize, Default)]
 pub struct OptimizerTelemetry {
     pub status: OptimizersStatus,
     pub optimizations: OperationDurationStatistics,
+    pub phases: OptimizationPhasesTelemetry,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub log: Option<Vec<TrackerTelemetry>>,
 }
 
+/// Durations of the individual optimization phases
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize, Default)]
+pub struct OptimizationPhasesTelemetry {
+    /// Building the optimized segment, while updates are written into proxy segments
+    pub build: OperationDurationStatistics,
+    /// Applying pending changes and swapping segments, while updates are blocked
+    pub finalize: OperationDurationStatistics,
+}
+
+/// Durations of searches and queries, grouped by complexity class of their filters.
+///
+/// A request is classified by the most expensive of its filters, including filters of prefetches.
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize, Default)]
+pub struct FilterLatencyTelemetry {
+    /// Requests without filter conditions
+    pub unfiltered: OperationDurationStatistics,
+    /// Requests with a few conditions on indexed fields, without nested filters
+    pub simple: OperationDurationStatistics,
+    /// Requests with many conditions or nested filters, all on indexed fields
+    pub complex: OperationDurationStatistics,
+    /// Requests with conditions on fields without a suitable payload index
+    pub unindexed: OperationDurationStatistics,
+}
+
+impl std::ops::Add for FilterLatencyTelemetry {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            unfiltered: self.unfiltered + other.unfiltered,
+            simple: self.simple + other.simple,
+            complex: self.complex + other.complex,
+            unindexed: self.unindexed + other.unindexed,
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Debug, Serialize, JsonSchema, Anonymize)]
 pub struct PartialSnapshotTelemetry {
     #[anonymize(false)]
@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
+use common::fault_injection::{self, FaultPoint};
 use parking_lot::Mutex;
 use tokio::time::sleep;
 
@@ -13,7 +14,7 @@ use super::transfer_tasks_pool::TransferTaskProgress;
 use super::wal_delta::transfer_wal_delta;
 use super::{ShardTransfer, ShardTransferConsensus, ShardTransferMethod, TransferStage};
 use crate::common::stoppable_task_async::{CancellableAsyncTaskHandle, spawn_async_cancellable};
-use crate::operations::types::CollectionResult;
+use crate::operations::types::{CollectionError, CollectionResult};
 use crate::shards::channel_service::ChannelService;
 use crate::shards::remote_shard::RemoteShard;
 use crate::shards::shard::ShardId;
@@ -57,6 +58,13 @@ pub async fn transfer_shard(
     // Prepare the remote for receiving the shard, waits for the correct state on the remote
     remote_shard.initiate_transfer().await?;
 
+    // Fail with the remote replica left in partial state
+    if fault_injection::should_fail(FaultPoint::CrashShardTransfer, &collection_id) {
+        return Err(CollectionError::service_error(format!(
+            "Transfer of shard {local_shard_id} crashed by injected fault"
+        )));
+    }
+
     match transfer_config.method.unwrap_or_default() {
         // Transfer shard record in batches
         ShardTransferMethod::StreamRecords => {
@@ -9,6 +9,9 @@ use segment::types::{
 use serde::Serialize;
 use uuid::Uuid;
 
+use crate::common::query_cache::QueryCacheTelemetry;
+use crate::common::query_coalescer::QueryCoalescerTelemetry;
+use crate::common::score_drift::ScoreDriftTelemetry;
 use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
 use crate::operations::types::{OptimizersStatus, ReshardingInfo, ShardStatus, ShardTransferInfo};
 use crate::optimizers_builder::OptimizersConfig;
@@ -41,6 +44,16 @@ pub struct CollectionTelemetry {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
     pub shard_clean_tasks: Option<HashMap<ShardId, ShardCleanStatusTelemetry>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_cache: Option<QueryCacheTelemetry>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_coalescer: Option<QueryCoalescerTelemetry>,
+
+    /// Top-1 score statistics of nearest neighbors queries, per vector
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_drift: Option<HashMap<String, ScoreDriftTelemetry>>,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
@@ -409,6 +422,9 @@ mod internal_conversions {
                 transfers,
                 resharding,
                 shard_clean_tasks,
+                query_cache: None,     // Not provided in internal service
+                query_coalescer: None, // Not provided in internal service
+                score_drift: None,     // Not provided in internal service
             })
         }
     }
@@ -423,6 +439,9 @@ mod internal_conversions {
                 transfers,
                 resharding,
                 shard_clean_tasks,
+                query_cache: _,
+                query_coalescer: _,
+                score_drift: _,
             } = value;
 
             grpc::CollectionTelemetry {
@@ -494,11 +513,13 @@ mod internal_conversions {
                 num_points,
                 num_vectors,
                 num_vectors_by_name,
-                segments: _,      // not included in grpc
-                optimizations: _, // not included in grpc
-                async_scorer: _,  // not included in grpc
+                segments: _,         // not included in grpc
+                access_frequency: _, // not included in grpc
+                optimizations: _,    // not included in grpc
+                async_scorer: _,     // not included in grpc
                 indexed_only_excluded_vectors,
-                update_queue: _, // not included in grpc
+                update_queue: _,   // not included in grpc
+                filter_latency: _, // not included in grpc
             } = value;
 
             grpc::LocalShardTelemetry {
@@ -657,9 +678,10 @@ mod internal_conversions {
                         .map(|(k, v)| (k, v as usize))
                         .collect()
                 }),
-                segments: None,      // Not included in grpc
-                async_scorer: None,  // Not included in grpc
-                optimizations: None, // Not included in grpc
+                segments: None,         // Not included in grpc
+                access_frequency: None, // Not included in grpc
+                async_scorer: None,     // Not included in grpc
+                optimizations: None,    // Not included in grpc
                 indexed_only_excluded_vectors: (!indexed_only_excluded_vectors.is_empty()).then(
                     || {
                         indexed_only_excluded_vectors
@@ -668,7 +690,8 @@ mod internal_conversions {
                             .collect()
                     },
                 ),
-                update_queue: None, // Not included in grpc
+                update_queue: None,   // Not included in grpc
+                filter_latency: None, // Not included in grpc
             })
         }
     }
@@ -168,6 +168,8 @@ async fn test_scroll_dedup() {
                 with_payload: Some(false.into()),
                 with_vector: false.into(),
                 order_by: None,
+                consistent: None,
+                view_id: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -196,6 +198,8 @@ async fn test_scroll_dedup() {
                 with_payload: Some(false.into()),
                 with_vector: false.into(),
                 order_by: Some(OrderByInterface::Key("num".parse().unwrap())),
+                consistent: None,
+                view_id: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -121,6 +121,7 @@ async fn fixture() -> Collection {
     collection
         .update_from_client(
             operation,
+            None,
             true,
             None,
             WriteOrdering::Weak,
@@ -71,6 +71,8 @@ fn validate_error_sparse_vector_points_list() {
         shard_key: None,
         update_filter: None,
         update_mode: None,
+        sequence_key: None,
+        skip_normalization: None,
     });
 }
 
@@ -7,8 +7,9 @@ use common::budget::ResourceBudget;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::save_on_disk::SaveOnDisk;
 use parking_lot::Mutex;
-use segment::types::SeqNumberType;
+use segment::types::{PointIdType, SeqNumberType};
 use shard::operations::CollectionUpdateOperations;
+use shard::operations::point_ops::ReturnIds;
 use shard::segment_holder::locked::LockedSegmentHolder;
 use tokio::runtime::Handle;
 use tokio::sync::mpsc::{self, Receiver};
@@ -32,6 +33,9 @@ use crate::wal_delta::LockedWal;
 
 pub type Optimizer = dyn SegmentOptimizer + Sync + Send;
 
+/// Number of updated points, and ids of the points matching the filter of the operation if requested
+pub type OperationFeedback = (usize, Option<Vec<PointIdType>>);
+
 /// Information, required to perform operation and notify regarding the result
 #[derive(Debug)]
 pub struct OperationData {
@@ -39,8 +43,10 @@ pub struct OperationData {
     pub op_num: SeqNumberType,
     /// Operation. If None, then the operation data is read from WAL
     pub operation: Option<Box<CollectionUpdateOperations>>,
+    /// Select ids of the points matching the filter of the operation, while applying it
+    pub return_ids: Option<ReturnIds>,
     /// Callback notification channel
-    pub sender: Option<oneshot::Sender<CollectionResult<usize>>>,
+    pub sender: Option<oneshot::Sender<CollectionResult<OperationFeedback>>>,
     /// Hardware measurement for the operation
     pub hw_measurements: HwMeasurementAcc,
 }
@@ -5,6 +5,7 @@ use cancel::CancellationToken;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use segment::types::SeqNumberType;
 use shard::operations::CollectionUpdateOperations;
+use shard::operations::point_ops::ReturnIds;
 use shard::segment_holder::locked::LockedSegmentHolder;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tokio::sync::{Mutex as TokioMutex, oneshot, watch};
@@ -17,7 +18,7 @@ use crate::profiling::interface::log_request_to_collector;
 use crate::shards::CollectionId;
 use crate::shards::local_shard::indexed_only::get_largest_unindexed_segment_vector_size;
 use crate::shards::update_tracker::UpdateTracker;
-use crate::update_handler::{OperationData, OptimizerSignal, UpdateSignal};
+use crate::update_handler::{OperationData, OperationFeedback, OptimizerSignal, UpdateSignal};
 use crate::update_workers::UpdateWorkers;
 use crate::update_workers::applied_seq::AppliedSeqHandler;
 use crate::wal_delta::LockedWal;
@@ -27,8 +28,8 @@ const BYTES_IN_KB: usize = 1024;
 /// Sends the operation result through the feedback channel if present.
 /// Logs a debug message if the receiver is no longer waiting.
 fn send_feedback(
-    sender: Option<oneshot::Sender<CollectionResult<usize>>>,
-    result: CollectionResult<usize>,
+    sender: Option<oneshot::Sender<CollectionResult<OperationFeedback>>>,
+    result: CollectionResult<OperationFeedback>,
     op_num: SeqNumberType,
 ) {
     if let Some(feedback) = sender {
@@ -73,6 +74,7 @@ impl UpdateWorkers {
                 UpdateSignal::Operation(OperationData {
                     op_num,
                     operation,
+                    return_ids,
                     sender,
                     hw_measurements,
                 }) => {
@@ -139,6 +141,7 @@ impl UpdateWorkers {
                         Self::update_worker_internal(
                             collection_name_clone,
                             operation,
+                            return_ids,
                             op_num,
                             wait,
                             wal_clone,
@@ -251,6 +254,7 @@ impl UpdateWorkers {
     fn update_worker_internal(
         collection_name: CollectionId,
         operation: CollectionUpdateOperations,
+        return_ids: Option<ReturnIds>,
         op_num: SeqNumberType,
         wait: bool,
         wal: LockedWal,
@@ -258,7 +262,7 @@ impl UpdateWorkers {
         update_operation_lock: Arc<tokio::sync::RwLock<()>>,
         update_tracker: UpdateTracker,
         hw_measurements: HwMeasurementAcc,
-    ) -> CollectionResult<usize> {
+    ) -> CollectionResult<OperationFeedback> {
         // If wait flag is set, explicitly flush WAL first
         if wait {
             wal.blocking_lock().flush().map_err(|err| {
@@ -274,10 +278,11 @@ impl UpdateWorkers {
         // Do not use for anything else
         let loggable_operation = operation.remove_details();
 
-        let result = CollectionUpdater::update(
+        let result = CollectionUpdater::update_returning_ids(
             &segments,
             op_num,
             operation,
+            return_ids.as_ref(),
             update_operation_lock.clone(),
             update_tracker.clone(),
             &hw_measurements.get_counter_cell(),
@@ -132,6 +132,8 @@ async fn test_collection_payload_reloading_with_shards(shard_number: u32) {
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vector: true.into(),
                 order_by: None,
+                consistent: None,
+                view_id: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -225,6 +227,8 @@ async fn test_collection_payload_custom_payload_with_shards(shard_number: u32) {
                 with_payload: Some(WithPayloadInterface::Fields(vec![JsonPath::new("k2")])),
                 with_vector: true.into(),
                 order_by: None,
+                consistent: None,
+                view_id: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -264,6 +268,8 @@ async fn test_collection_payload_custom_payload_with_shards(shard_number: u32) {
                 with_payload: Some(PayloadSelectorExclude::new(vec![JsonPath::new("k1")]).into()),
                 with_vector: false.into(),
                 order_by: None,
+                consistent: None,
+                view_id: None,
             },
             None,
             &ShardSelectorInternal::All,
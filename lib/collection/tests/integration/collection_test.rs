@@ -493,6 +493,8 @@ async fn test_read_api_with_shards(shard_number: u32) {
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vector: false.into(),
                 order_by: None,
+                consistent: None,
+                view_id: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -632,7 +634,10 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Asc),
                         start_from: None,
+                        then_by: Vec::new(),
                     })),
+                    consistent: None,
+                    view_id: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -664,7 +669,10 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Desc),
                         start_from: None,
+                        then_by: Vec::new(),
                     })),
+                    consistent: None,
+                    view_id: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -705,7 +713,10 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Asc),
                         start_from: None,
+                        then_by: Vec::new(),
                     })),
+                    consistent: None,
+                    view_id: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -745,7 +756,10 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Desc),
                         start_from: None,
+                        then_by: Vec::new(),
                     })),
+                    consistent: None,
+                    view_id: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -783,6 +797,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vector: false.into(),
                 order_by: Some(OrderByInterface::Key(MULTI_VALUE_KEY.parse().unwrap())),
+                consistent: None,
+                view_id: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -892,6 +908,8 @@ async fn test_collection_delete_points_by_filter_with_shards(shard_number: u32)
                 with_payload: Some(WithPayloadInterface::Bool(false)),
                 with_vector: false.into(),
                 order_by: None,
+                consistent: None,
+                view_id: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -133,6 +133,29 @@ impl ResourceBudget {
         ))
     }
 
+    /// Try to acquire all CPU and IO permits which are currently available, without waiting.
+    ///
+    /// Returns `None` if nothing is available right now.
+    pub fn try_acquire_available(&self) -> Option<ResourcePermit> {
+        let (num_cpus, cpu_permit) = self
+            .try_acquire_cpu(self.cpu_semaphore.available_permits())
+            .unwrap_or((0, None));
+        let (num_io, io_permit) = self
+            .try_acquire_io(self.io_semaphore.available_permits())
+            .unwrap_or((0, None));
+
+        if num_cpus == 0 && num_io == 0 {
+            return None;
+        }
+
+        Some(ResourcePermit::new(
+            num_cpus as u32,
+            cpu_permit,
+            num_io as u32,
+            io_permit,
+        ))
+    }
+
     /// Acquire Resources permit for optimization task from global Resource budget.
     ///
     /// This will wait until the required number of permits are available.
@@ -0,0 +1,183 @@
+//! Fault injection points for chaos testing of distributed operations.
+//!
+//! Faults can only be enabled in builds with the `chaos-testing` feature.
+//! Without it, all injection points are no-ops and are optimized away.
+
+use std::fmt::Display;
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FaultPoint {
+    /// Internal gRPC requests to other peers fail as if the peer was unreachable.
+    /// Target is the URI of the peer.
+    DropInternalRpc,
+    /// Flushing WAL to disk is delayed.
+    /// Target is the path of the WAL.
+    DelayWalFlush,
+    /// Outgoing shard transfers fail right after the receiving replica was prepared.
+    /// Target is the name of the collection.
+    CrashShardTransfer,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FaultConfig {
+    /// Probability to inject the fault at every pass through the injection point, from 0.0 to 1.0
+    #[serde(default = "default_probability")]
+    pub probability: f64,
+    /// Delay to inject in milliseconds, only used by delaying faults
+    #[serde(default)]
+    pub delay_ms: u64,
+    /// Only inject the fault if the target of the injection point contains this string
+    #[serde(default)]
+    pub target: Option<String>,
+}
+
+fn default_probability() -> f64 {
+    1.0
+}
+
+#[cfg(feature = "chaos-testing")]
+mod registry {
+    use std::collections::HashMap;
+    use std::sync::LazyLock;
+
+    use parking_lot::RwLock;
+    use rand::Rng as _;
+
+    use super::*;
+
+    static FAULTS: LazyLock<RwLock<HashMap<FaultPoint, FaultConfig>>> =
+        LazyLock::new(Default::default);
+
+    /// Enable the fault at the injection point, or disable it if `config` is `None`
+    pub fn set_fault(point: FaultPoint, config: Option<FaultConfig>) {
+        let mut faults = FAULTS.write();
+        match config {
+            Some(config) => {
+                log::warn!("Injecting fault {point:?}: {config:?}");
+                faults.insert(point, config);
+            }
+            None => {
+                log::warn!("Disabling injected fault {point:?}");
+                faults.remove(&point);
+            }
+        }
+    }
+
+    pub fn get_faults() -> HashMap<FaultPoint, FaultConfig> {
+        FAULTS.read().clone()
+    }
+
+    pub fn clear_faults() {
+        FAULTS.write().clear();
+    }
+
+    pub(super) fn triggered(point: FaultPoint, target: impl Display) -> Option<FaultConfig> {
+        let faults = FAULTS.read();
+        let config = faults.get(&point)?;
+
+        if let Some(config_target) = &config.target
+            && !target.to_string().contains(config_target.as_str())
+        {
+            return None;
+        }
+
+        if config.probability < 1.0 && !rand::rng().random_bool(config.probability.max(0.0)) {
+            return None;
+        }
+
+        Some(config.clone())
+    }
+}
+
+#[cfg(feature = "chaos-testing")]
+pub use registry::{clear_faults, get_faults, set_fault};
+
+/// Whether to fail the operation at this injection point
+#[cfg(feature = "chaos-testing")]
+pub fn should_fail(point: FaultPoint, target: impl Display) -> bool {
+    let triggered = registry::triggered(point, target).is_some();
+    if triggered {
+        log::debug!("Injected fault {point:?} triggered");
+    }
+    triggered
+}
+
+#[cfg(not(feature = "chaos-testing"))]
+#[inline(always)]
+pub fn should_fail(_point: FaultPoint, _target: impl Display) -> bool {
+    false
+}
+
+/// Delay to inject at this injection point, if any
+#[cfg(feature = "chaos-testing")]
+pub fn injected_delay(point: FaultPoint, target: impl Display) -> Option<Duration> {
+    let config = registry::triggered(point, target)?;
+    log::debug!(
+        "Injected fault {point:?} triggered, delaying by {}ms",
+        config.delay_ms,
+    );
+    Some(Duration::from_millis(config.delay_ms))
+}
+
+#[cfg(not(feature = "chaos-testing"))]
+#[inline(always)]
+pub fn injected_delay(_point: FaultPoint, _target: impl Display) -> Option<Duration> {
+    None
+}
+
+#[cfg(all(test, feature = "chaos-testing"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_injected_faults() {
+        clear_faults();
+        assert!(!should_fail(
+            FaultPoint::DropInternalRpc,
+            "http://peer-1:6335"
+        ));
+
+        set_fault(
+            FaultPoint::DropInternalRpc,
+            Some(FaultConfig {
+                probability: 1.0,
+                delay_ms: 0,
+                target: Some("peer-1".to_string()),
+            }),
+        );
+        assert!(should_fail(
+            FaultPoint::DropInternalRpc,
+            "http://peer-1:6335"
+        ));
+        assert!(!should_fail(
+            FaultPoint::DropInternalRpc,
+            "http://peer-2:6335"
+        ));
+        assert!(!should_fail(FaultPoint::CrashShardTransfer, "peer-1"));
+
+        set_fault(
+            FaultPoint::DelayWalFlush,
+            Some(FaultConfig {
+                probability: 0.0,
+                delay_ms: 100,
+                target: None,
+            }),
+        );
+        assert_eq!(injected_delay(FaultPoint::DelayWalFlush, "wal"), None);
+
+        set_fault(FaultPoint::DropInternalRpc, None);
+        assert!(!should_fail(
+            FaultPoint::DropInternalRpc,
+            "http://peer-1:6335"
+        ));
+        assert_eq!(get_faults().len(), 1);
+
+        clear_faults();
+        assert!(get_faults().is_empty());
+    }
+}
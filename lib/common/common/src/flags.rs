@@ -70,6 +70,12 @@ pub struct FeatureFlags {
     ///
     /// Enabled by default in Qdrant 1.17.1+
     pub single_file_mmap_vector_storage: bool,
+
+    /// Reuse temporary buffers of query execution, such as filtered point ids and candidate heaps,
+    /// across requests instead of allocating them for every search.
+    ///
+    /// First implemented in Qdrant 1.17.1.
+    pub query_arena: bool,
 }
 
 impl Default for FeatureFlags {
@@ -86,6 +92,7 @@ impl Default for FeatureFlags {
             migrate_rocksdb_payload_indices: true,
             appendable_quantization: true,
             single_file_mmap_vector_storage: false,
+            query_arena: false,
         }
     }
 }
@@ -112,6 +119,7 @@ pub fn init_feature_flags(mut flags: FeatureFlags) {
         migrate_rocksdb_payload_indices,
         appendable_quantization,
         single_file_mmap_vector_storage,
+        query_arena,
     } = &mut flags;
 
     // If all is set, explicitly set all feature flags
@@ -126,6 +134,7 @@ pub fn init_feature_flags(mut flags: FeatureFlags) {
         *migrate_rocksdb_payload_indices = true;
         *appendable_quantization = true;
         *single_file_mmap_vector_storage = true;
+        *query_arena = true;
     }
 
     let res = FEATURE_FLAGS.set(flags);
@@ -11,6 +11,7 @@ pub mod delta_pack;
 pub mod disk;
 pub mod either_variant;
 pub mod ext;
+pub mod fault_injection;
 pub mod fixed_length_priority_queue;
 pub mod flags;
 pub mod fs;
@@ -25,8 +26,10 @@ pub mod panic;
 pub mod process_counter;
 pub mod progress_tracker;
 pub mod rate_limiting;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod save_on_disk;
 pub mod scope_tracker;
+pub mod search_concurrency;
 pub mod small_uint;
 pub mod sort_utils;
 pub mod stable_hash;
@@ -38,4 +41,5 @@ pub mod toposort;
 pub mod typelevel;
 pub mod types;
 pub mod validation;
+pub mod varint_links;
 pub mod zeros;
@@ -0,0 +1,265 @@
+use std::cell::Cell;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use parking_lot::{Condvar, Mutex};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Global limiter of concurrent segment searches, initialized when starting Qdrant with
+/// adaptive search concurrency enabled. If not initialized, searches are not limited.
+static SEARCH_CONCURRENCY: OnceLock<SearchConcurrencyLimiter> = OnceLock::new();
+
+thread_local! {
+    /// Whether segment searches on this thread bypass the global limiter
+    static EXEMPT_THREAD: Cell<bool> = const { Cell::new(false) };
+}
+
+/// CPU utilization at which searching more segments concurrently only adds contention
+const CPU_SATURATION_THRESHOLD: f64 = 0.9;
+
+/// Average time searches may wait for a slot, before the limit is raised
+const QUEUE_LATENCY_THRESHOLD: Duration = Duration::from_millis(2);
+
+pub fn init_search_concurrency(limiter: SearchConcurrencyLimiter) {
+    if SEARCH_CONCURRENCY.set(limiter).is_err() {
+        log::warn!("Search concurrency limiter is already initialized");
+    }
+}
+
+pub fn search_concurrency() -> Option<&'static SearchConcurrencyLimiter> {
+    SEARCH_CONCURRENCY.get()
+}
+
+/// Don't limit segment searches running on the current thread.
+///
+/// Used for threads of dedicated search runtimes, which are sized separately.
+pub fn exempt_current_thread() {
+    EXEMPT_THREAD.set(true);
+}
+
+/// Block until another segment search may run, if the global limiter is initialized.
+///
+/// Must be called from a blocking thread.
+pub fn acquire_search_permit() -> Option<SearchPermit<'static>> {
+    if EXEMPT_THREAD.get() {
+        return None;
+    }
+
+    search_concurrency().map(SearchConcurrencyLimiter::acquire)
+}
+
+/// Limit of concurrent segment searches, adjusted to CPU saturation and queueing latency.
+///
+/// - If searches wait for a slot while the CPU has headroom (e.g. they are blocked on disk IO),
+///   the limit is raised by one.
+/// - If the CPU is saturated, more concurrent searches only compete with each other,
+///   so the limit is lowered by an eighth.
+pub struct SearchConcurrencyLimiter {
+    state: Mutex<LimiterState>,
+    slot_released: Condvar,
+    min_limit: usize,
+    max_limit: usize,
+}
+
+struct LimiterState {
+    limit: usize,
+    running: usize,
+    /// Searches started since the last adjustment
+    started: usize,
+    /// Total time searches waited for a slot since the last adjustment
+    queue_wait: Duration,
+    /// Statistics of the last adjustment
+    last_cpu_saturation: f64,
+    last_queue_latency: Duration,
+}
+
+impl SearchConcurrencyLimiter {
+    pub fn new(initial_limit: usize, min_limit: usize, max_limit: usize) -> Self {
+        let min_limit = min_limit.max(1);
+        let max_limit = max_limit.max(min_limit);
+
+        Self {
+            state: Mutex::new(LimiterState {
+                limit: initial_limit.clamp(min_limit, max_limit),
+                running: 0,
+                started: 0,
+                queue_wait: Duration::ZERO,
+                last_cpu_saturation: 0.0,
+                last_queue_latency: Duration::ZERO,
+            }),
+            slot_released: Condvar::new(),
+            min_limit,
+            max_limit,
+        }
+    }
+
+    /// Block until a search slot is free
+    pub fn acquire(&self) -> SearchPermit<'_> {
+        let queued_at = Instant::now();
+
+        let mut state = self.state.lock();
+        while state.running >= state.limit {
+            self.slot_released.wait(&mut state);
+        }
+
+        state.running += 1;
+        state.started += 1;
+        state.queue_wait += queued_at.elapsed();
+
+        SearchPermit { limiter: self }
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock();
+        state.running -= 1;
+        drop(state);
+
+        self.slot_released.notify_one();
+    }
+
+    pub fn limit(&self) -> usize {
+        self.state.lock().limit
+    }
+
+    /// Adjust the limit to the CPU saturation (0.0 - 1.0) observed since the last adjustment.
+    ///
+    /// Returns the new limit.
+    pub fn adjust(&self, cpu_saturation: f64) -> usize {
+        let mut state = self.state.lock();
+
+        let queue_latency = if state.started > 0 {
+            state.queue_wait / state.started as u32
+        } else {
+            Duration::ZERO
+        };
+
+        let new_limit = if cpu_saturation >= CPU_SATURATION_THRESHOLD {
+            state.limit.saturating_sub((state.limit / 8).max(1))
+        } else if queue_latency > QUEUE_LATENCY_THRESHOLD {
+            state.limit + 1
+        } else {
+            state.limit
+        }
+        .clamp(self.min_limit, self.max_limit);
+
+        if new_limit != state.limit {
+            log::debug!(
+                "Adjusting search concurrency from {} to {new_limit} (CPU saturation: {:.0}%, queue latency: {queue_latency:?})",
+                state.limit,
+                cpu_saturation * 100.0,
+            );
+        }
+        let raised = new_limit > state.limit;
+
+        state.limit = new_limit;
+        state.started = 0;
+        state.queue_wait = Duration::ZERO;
+        state.last_cpu_saturation = cpu_saturation;
+        state.last_queue_latency = queue_latency;
+        drop(state);
+
+        if raised {
+            self.slot_released.notify_all();
+        }
+
+        new_limit
+    }
+
+    pub fn get_telemetry_data(&self) -> SearchConcurrencyTelemetry {
+        let state = self.state.lock();
+        SearchConcurrencyTelemetry {
+            limit: state.limit,
+            min_limit: self.min_limit,
+            max_limit: self.max_limit,
+            running: state.running,
+            cpu_saturation: state.last_cpu_saturation,
+            queue_latency_ms: state.last_queue_latency.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+/// Slot of a running segment search, released on drop
+pub struct SearchPermit<'a> {
+    limiter: &'a SearchConcurrencyLimiter,
+}
+
+impl Drop for SearchPermit<'_> {
+    fn drop(&mut self) {
+        self.limiter.release();
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SearchConcurrencyTelemetry {
+    /// Current limit of concurrent segment searches
+    pub limit: usize,
+    pub min_limit: usize,
+    pub max_limit: usize,
+    /// Number of segment searches running right now
+    pub running: usize,
+    /// CPU saturation observed at the last adjustment, from 0.0 to 1.0
+    pub cpu_saturation: f64,
+    /// Average time segment searches waited for a slot before the last adjustment
+    pub queue_latency_ms: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn test_adjust_search_concurrency() {
+        let limiter = SearchConcurrencyLimiter::new(8, 2, 16);
+
+        // Idle: keep the limit
+        assert_eq!(limiter.adjust(0.1), 8);
+
+        // Searches queue up while CPU has headroom: raise the limit
+        limiter.state.lock().started = 10;
+        limiter.state.lock().queue_wait = Duration::from_millis(100);
+        assert_eq!(limiter.adjust(0.5), 9);
+
+        // Queue statistics are reset after every adjustment
+        assert_eq!(limiter.adjust(0.5), 9);
+
+        // CPU is saturated: lower the limit, down to the minimum
+        assert_eq!(limiter.adjust(0.95), 8);
+        for _ in 0..10 {
+            limiter.adjust(1.0);
+        }
+        assert_eq!(limiter.limit(), 2);
+
+        // Never exceed the maximum
+        for _ in 0..20 {
+            limiter.state.lock().started = 1;
+            limiter.state.lock().queue_wait = Duration::from_secs(1);
+            limiter.adjust(0.0);
+        }
+        assert_eq!(limiter.limit(), 16);
+    }
+
+    #[test]
+    fn test_search_permits() {
+        let limiter = Arc::new(SearchConcurrencyLimiter::new(1, 1, 2));
+
+        let permit = limiter.acquire();
+        assert_eq!(limiter.get_telemetry_data().running, 1);
+
+        let waiting = std::thread::spawn({
+            let limiter = limiter.clone();
+            move || {
+                let _permit = limiter.acquire();
+            }
+        });
+
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(!waiting.is_finished());
+
+        drop(permit);
+        waiting.join().unwrap();
+        assert_eq!(limiter.get_telemetry_data().running, 0);
+    }
+}
@@ -0,0 +1,231 @@
+//! Delta + varint encoding of HNSW graph links.
+//!
+//! An alternative to [`crate::bitpacking_links`]. Each node's links are
+//! encoded as a sequence of LEB128 varints, so small deltas between sorted
+//! neighbors take a single byte regardless of the segment size.
+//!
+//! Decoding reads the input a 64-bit word at a time (SWAR): the position of
+//! the terminating byte is found with a single `trailing_zeros`, and 8
+//! consecutive one-byte values are emitted without per-byte branching.
+
+/// Max size of a varint-encoded `u32`.
+const MAX_VARINT_BYTES: usize = 5;
+
+/// High bit of each byte in a word, set for all bytes except the last one of
+/// each varint.
+const CONTINUATION_MASK: u64 = 0x8080_8080_8080_8080;
+
+/// Encode HNSW graph links as delta + varint.
+///
+/// Like [`crate::bitpacking_links::pack_links`], it assumes that the first `m`
+/// (or `m0`) values could be re-ordered for better compression.
+///
+/// Parameters:
+/// - `sorted_count` is `m` (or `m0`) for this layer.
+/// - `raw_links` is in/out parameter. Input: links to pack, output: same links,
+///   but re-ordered.
+pub fn pack_varint_links(links: &mut Vec<u8>, raw_links: &mut [u32], sorted_count: usize) {
+    // 1. First `sorted_count` values, sorted and delta-encoded.
+    let sorted_count = raw_links.len().min(sorted_count);
+    raw_links[..sorted_count].sort_unstable();
+    let mut prev = 0;
+    for &value in &raw_links[..sorted_count] {
+        write_varint(links, value - prev);
+        prev = value;
+    }
+
+    // 2. The rest of the values, unsorted.
+    for &value in &raw_links[sorted_count..] {
+        write_varint(links, value);
+    }
+}
+
+/// Returns an iterator over links packed with [`pack_varint_links`].
+#[inline]
+pub fn iterate_varint_links(links: &[u8], sorted_count: usize) -> VarintLinksIterator<'_> {
+    VarintLinksIterator {
+        data: links,
+        sorted_remaining: sorted_count,
+        current_delta: 0,
+    }
+}
+
+#[inline]
+fn write_varint(out: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Decode a single varint, returns the value and its size in bytes.
+#[inline]
+fn decode_varint(data: &[u8]) -> Option<(u32, usize)> {
+    match data.first_chunk::<8>() {
+        Some(word) => Some(decode_varint_word(u64::from_le_bytes(*word))),
+        None => decode_varint_scalar(data),
+    }
+}
+
+/// Decode a single varint from the first (little-endian) 8 bytes of the input.
+#[inline]
+fn decode_varint_word(word: u64) -> (u32, usize) {
+    let len = ((!word & CONTINUATION_MASK).trailing_zeros() / u8::BITS + 1) as usize;
+    let len = len.min(MAX_VARINT_BYTES);
+    let payload = word & ((1 << (len * 8)) - 1) & 0x7F_7F7F_7F7F;
+
+    // Squeeze out the continuation bits: byte `k` is shifted right by `k`.
+    let value = (payload & 0x7F)
+        | ((payload >> 1) & 0x3F80)
+        | ((payload >> 2) & 0x1F_C000)
+        | ((payload >> 3) & 0x0FE0_0000)
+        | ((payload >> 4) & 0xF_F000_0000);
+    (value as u32, len)
+}
+
+/// Fallback for the tail of the input, shorter than a word.
+#[inline]
+fn decode_varint_scalar(data: &[u8]) -> Option<(u32, usize)> {
+    let mut value = 0u32;
+    for (i, &byte) in data.iter().take(MAX_VARINT_BYTES).enumerate() {
+        value |= u32::from(byte & 0x7F) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+/// Iterator over links packed with [`pack_varint_links`].
+/// Created by [`iterate_varint_links`].
+pub struct VarintLinksIterator<'a> {
+    data: &'a [u8],
+    sorted_remaining: usize,
+    current_delta: u32,
+}
+
+impl VarintLinksIterator<'_> {
+    /// Turn a decoded value into a link, undoing delta-encoding if needed.
+    #[inline]
+    fn apply(&mut self, value: u32) -> u32 {
+        if self.sorted_remaining > 0 {
+            self.sorted_remaining -= 1;
+            self.current_delta = self.current_delta.wrapping_add(value);
+            self.current_delta
+        } else {
+            value
+        }
+    }
+}
+
+impl Iterator for VarintLinksIterator<'_> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<u32> {
+        let (value, len) = decode_varint(self.data)?;
+        self.data = &self.data[len..];
+        Some(self.apply(value))
+    }
+
+    /// Optimized [`Iterator::fold()`]. Should be faster than calling
+    /// [`Iterator::next()`] in a loop.
+    ///
+    /// It is used in a hot loop during HNSW search, so performance is critical.
+    #[inline]
+    fn fold<Acc, F: FnMut(Acc, u32) -> Acc>(mut self, mut acc: Acc, mut f: F) -> Acc {
+        while let Some(word) = self.data.first_chunk::<8>() {
+            let word = u64::from_le_bytes(*word);
+            if word & CONTINUATION_MASK == 0 {
+                // Fast path: 8 one-byte values in a row.
+                for byte in word.to_le_bytes() {
+                    acc = f(acc, self.apply(u32::from(byte)));
+                }
+                self.data = &self.data[8..];
+            } else {
+                let (value, len) = decode_varint_word(word);
+                self.data = &self.data[len..];
+                acc = f(acc, self.apply(value));
+            }
+        }
+
+        while let Some(value) = self.next() {
+            acc = f(acc, value);
+        }
+
+        acc
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each value has exactly one terminating byte.
+        let count = self.data.iter().filter(|&&byte| byte & 0x80 == 0).count();
+        (count, Some(count))
+    }
+}
+
+impl ExactSizeIterator for VarintLinksIterator<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools as _;
+    use rand::rngs::StdRng;
+    use rand::{Rng as _, SeedableRng as _};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::iterator_ext::{check_exact_size_iterator_len, check_iterator_fold};
+
+    #[rstest]
+    #[case::only_unsorted(0, 1..100)]
+    #[case::only_sorted(100, 1..100)]
+    #[case::empty(16, 0..1)]
+    #[case::both(16, 16..116)]
+    fn test_random(#[case] sorted_count: usize, #[case] total_count: std::ops::Range<usize>) {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..1_000 {
+            let total_count = rng.random_range(total_count.clone());
+            let bits = rng.random_range(8..=32);
+
+            let mut raw_links_orig =
+                std::iter::repeat_with(|| rng.random_range(0..1u64 << bits) as u32)
+                    .unique()
+                    .take(total_count)
+                    .collect_vec();
+            let mut raw_links_updated = raw_links_orig.clone();
+            let mut links = Vec::new();
+            pack_varint_links(&mut links, &mut raw_links_updated, sorted_count);
+
+            let unpacked = iterate_varint_links(&links, sorted_count).collect_vec();
+
+            raw_links_orig[..sorted_count.min(raw_links_orig.len())].sort_unstable();
+            assert_eq!(raw_links_orig, unpacked);
+            assert_eq!(raw_links_updated, unpacked);
+
+            check_iterator_fold(|| iterate_varint_links(&links, sorted_count));
+            check_exact_size_iterator_len(iterate_varint_links(&links, sorted_count));
+        }
+    }
+
+    #[test]
+    fn test_decode_word_matches_scalar() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for bits in 0..=32 {
+            for _ in 0..100 {
+                let value = (rng.random::<u64>() & ((1 << bits) - 1)) as u32;
+                let mut buf = Vec::new();
+                write_varint(&mut buf, value);
+                let len = buf.len();
+
+                let scalar = decode_varint_scalar(&buf).unwrap();
+                assert_eq!(scalar, (value, len));
+
+                // Pad with garbage to make a full word.
+                buf.resize_with(8, || rng.random());
+                let word = u64::from_le_bytes(*buf.first_chunk().unwrap());
+                assert_eq!(decode_varint_word(word), (value, len));
+            }
+        }
+    }
+}
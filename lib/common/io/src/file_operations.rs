@@ -2,11 +2,13 @@ use std::io::{self, BufReader, BufWriter, Write};
 use std::path::Path;
 use std::result;
 
+#[cfg(not(target_arch = "wasm32"))]
 use atomicwrites::{AtomicFile, OverwriteBehavior};
 use fs_err::File;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 
+#[cfg(not(target_arch = "wasm32"))]
 #[allow(
     clippy::disallowed_types,
     reason = "can't use `fs_err::File` since `atomicwrites` only provides `&mut std::fs::File`"
@@ -29,6 +31,24 @@ where
     })
 }
 
+/// `atomicwrites` doesn't support WebAssembly, so the file is overwritten in place.
+#[cfg(target_arch = "wasm32")]
+#[allow(
+    clippy::disallowed_types,
+    reason = "keep the same signature as on other platforms"
+)]
+pub fn atomic_save<E, F>(path: &Path, write: F) -> Result<(), E>
+where
+    E: From<io::Error>,
+    F: FnOnce(&mut BufWriter<&mut std::fs::File>) -> Result<(), E>,
+{
+    let mut file = File::create(path)?.into_file();
+    let mut writer = BufWriter::new(&mut file);
+    write(&mut writer)?;
+    writer.flush()?;
+    Ok(())
+}
+
 pub fn atomic_save_bin<T: Serialize>(path: &Path, object: &T) -> Result<()> {
     atomic_save(path, |writer| Ok(bincode::serialize_into(writer, object)?))
 }
@@ -75,6 +95,7 @@ impl Error {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<E> From<atomicwrites::Error<E>> for Error
 where
     Self: From<E>,
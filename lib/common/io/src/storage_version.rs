@@ -1,11 +1,10 @@
-use std::io::{Read, Write};
+use std::io::{self, Read, Write};
 use std::path::Path;
 
-use atomicwrites::{AllowOverwrite, AtomicFile};
 use fs_err::File;
 use semver::Version;
 
-use crate::file_operations::{FileOperationResult, FileStorageError};
+use crate::file_operations::{FileOperationResult, FileStorageError, atomic_save};
 
 pub const VERSION_FILE: &str = "version.info";
 
@@ -43,11 +42,12 @@ pub trait StorageVersion {
 
     fn save(dir_path: &Path) -> FileOperationResult<()> {
         let version_file = dir_path.join(VERSION_FILE);
-        let af = AtomicFile::new(&version_file, AllowOverwrite);
         let current_version = Self::current_raw();
-        af.write(|f| f.write_all(current_version.as_bytes()))
-            .map_err(|err| {
-                FileStorageError::generic(format!("Can't write {version_file:?}, error: {err}"))
-            })
+        atomic_save(&version_file, |writer| {
+            writer.write_all(current_version.as_bytes())
+        })
+        .map_err(|err: io::Error| {
+            FileStorageError::generic(format!("Can't write {version_file:?}, error: {err}"))
+        })
     }
 }
@@ -0,0 +1,97 @@
+/*
+ * C API of Qdrant Edge, to embed shards of segments into other runtimes.
+ *
+ * Memory ownership:
+ * - A shard returned by qdrant_edge_shard_open() is owned by the caller,
+ *   and must be released exactly once with qdrant_edge_shard_close().
+ * - Pointers passed into functions are borrowed for the duration of the call only.
+ * - Output buffers are allocated and owned by the caller.
+ * - The message returned by qdrant_edge_last_error() is owned by the library,
+ *   and stays valid until the next call of this API on the same thread.
+ *
+ * A shard may be used from multiple threads concurrently, except for closing it.
+ */
+
+#ifndef QDRANT_EDGE_H
+#define QDRANT_EDGE_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum QdrantEdgeStatus {
+    QDRANT_EDGE_OK = 0,
+    /* Arguments are NULL or malformed, nothing was changed */
+    QDRANT_EDGE_INVALID_ARGUMENT = 1,
+    /* The operation failed */
+    QDRANT_EDGE_ERROR = 2,
+    /* The operation panicked, the shard might be in an inconsistent state */
+    QDRANT_EDGE_PANIC = 3,
+} QdrantEdgeStatus;
+
+typedef enum QdrantEdgeDistance {
+    QDRANT_EDGE_COSINE = 0,
+    QDRANT_EDGE_EUCLID = 1,
+    QDRANT_EDGE_DOT = 2,
+    QDRANT_EDGE_MANHATTAN = 3,
+} QdrantEdgeDistance;
+
+/* Shard of segments with a single unnamed dense vector */
+typedef struct QdrantEdgeShard QdrantEdgeShard;
+
+typedef struct QdrantEdgeScoredPoint {
+    uint64_t id;
+    float score;
+} QdrantEdgeScoredPoint;
+
+/*
+ * Open the shard in `path`, creating it if it doesn't exist.
+ * If the shard already exists, `dim` and `distance` must match its vector configuration.
+ */
+QdrantEdgeStatus qdrant_edge_shard_open(const char *path,
+                                        size_t dim,
+                                        QdrantEdgeDistance distance,
+                                        QdrantEdgeShard **out_shard);
+
+/* Flush and close the shard. Passing NULL is a no-op. */
+void qdrant_edge_shard_close(QdrantEdgeShard *shard);
+
+/*
+ * Insert or update `num_points` points.
+ * - `ids` holds `num_points` point IDs.
+ * - `vectors` holds `num_points * dim` floats, one vector after another.
+ * - `payloads` is NULL, or holds `num_points` JSON objects, each of which may be NULL.
+ */
+QdrantEdgeStatus qdrant_edge_upsert(const QdrantEdgeShard *shard,
+                                    const uint64_t *ids,
+                                    const float *vectors,
+                                    const char *const *payloads,
+                                    size_t num_points);
+
+/*
+ * Search for up to `limit` points nearest to `vector` of `dim` floats.
+ * `filter` is NULL, or a JSON filter in the format of the Qdrant API.
+ * Results are written into `out_points`, which must have room for `limit` points,
+ * and the number of found points is written into `out_count`.
+ */
+QdrantEdgeStatus qdrant_edge_search(const QdrantEdgeShard *shard,
+                                    const float *vector,
+                                    size_t dim,
+                                    const char *filter,
+                                    size_t limit,
+                                    QdrantEdgeScoredPoint *out_points,
+                                    size_t *out_count);
+
+/*
+ * Message of the last failed call on the current thread, or NULL if the last call succeeded.
+ */
+const char *qdrant_edge_last_error(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* QDRANT_EDGE_H */
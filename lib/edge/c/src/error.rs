@@ -0,0 +1,82 @@
+use std::cell::RefCell;
+use std::ffi::{CString, c_char};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use segment::common::operation_error::OperationError;
+
+thread_local! {
+    /// Message of the last failed call on this thread
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QdrantEdgeStatus {
+    Ok = 0,
+    /// Arguments are NULL or malformed, nothing was changed
+    InvalidArgument = 1,
+    /// The operation failed
+    Error = 2,
+    /// The operation panicked, the shard might be in an inconsistent state
+    Panic = 3,
+}
+
+/// Message of the last failed call on the current thread, or NULL if the last call succeeded.
+///
+/// The message is owned by the library and stays valid until the next call on the current thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn qdrant_edge_last_error() -> *const c_char {
+    LAST_ERROR.with_borrow(|error| error.as_ref().map_or(ptr::null(), |error| error.as_ptr()))
+}
+
+#[derive(Debug)]
+pub struct FfiError {
+    status: QdrantEdgeStatus,
+    message: String,
+}
+
+impl FfiError {
+    pub fn invalid_argument(message: impl Into<String>) -> Self {
+        Self {
+            status: QdrantEdgeStatus::InvalidArgument,
+            message: message.into(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            status: QdrantEdgeStatus::Error,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<OperationError> for FfiError {
+    fn from(err: OperationError) -> Self {
+        Self::error(err.to_string())
+    }
+}
+
+/// Run the body of an API call, recording its error and never unwinding into the caller
+pub fn ffi_call(f: impl FnOnce() -> Result<(), FfiError>) -> QdrantEdgeStatus {
+    let (status, message) = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => (QdrantEdgeStatus::Ok, None),
+        Ok(Err(FfiError { status, message })) => (status, Some(message)),
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            (QdrantEdgeStatus::Panic, Some(message))
+        }
+    };
+
+    // Interior NUL bytes would truncate the message anyway
+    let message =
+        message.map(|message| CString::new(message.replace('\0', "")).unwrap_or_default());
+    LAST_ERROR.set(message);
+
+    status
+}
@@ -0,0 +1,400 @@
+//! C API of Qdrant Edge, to embed shards of segments into other runtimes.
+//!
+//! Memory ownership:
+//! - A shard returned by [`qdrant_edge_shard_open`] is owned by the caller,
+//!   and must be released exactly once with [`qdrant_edge_shard_close`].
+//! - Pointers passed into functions are borrowed for the duration of the call only.
+//! - Output buffers are allocated and owned by the caller.
+//! - The message returned by [`qdrant_edge_last_error`] is owned by the library,
+//!   and stays valid until the next call of this API on the same thread.
+//!
+//! A shard may be used from multiple threads concurrently, except for closing it.
+
+mod error;
+
+use std::collections::HashMap;
+use std::ffi::{CStr, c_char};
+use std::path::Path;
+use std::slice;
+
+use edge::EdgeShard;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorInternal};
+use segment::types::{
+    Distance, ExtendedPointId, Filter, Payload, PayloadStorageType, SegmentConfig,
+    VectorDataConfig, VectorStorageType, WithPayloadInterface, WithVector,
+};
+use shard::operations::CollectionUpdateOperations;
+use shard::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, VectorStructPersisted,
+};
+use shard::query::query_enum::QueryEnum;
+use shard::query::{ScoringQuery, ShardQueryRequest};
+
+use self::error::{FfiError, ffi_call};
+pub use self::error::{QdrantEdgeStatus, qdrant_edge_last_error};
+
+/// Shard of segments with a single unnamed dense vector
+pub struct QdrantEdgeShard {
+    shard: EdgeShard,
+    dim: usize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QdrantEdgeDistance {
+    Cosine = 0,
+    Euclid = 1,
+    Dot = 2,
+    Manhattan = 3,
+}
+
+impl From<QdrantEdgeDistance> for Distance {
+    fn from(distance: QdrantEdgeDistance) -> Self {
+        match distance {
+            QdrantEdgeDistance::Cosine => Distance::Cosine,
+            QdrantEdgeDistance::Euclid => Distance::Euclid,
+            QdrantEdgeDistance::Dot => Distance::Dot,
+            QdrantEdgeDistance::Manhattan => Distance::Manhattan,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QdrantEdgeScoredPoint {
+    pub id: u64,
+    pub score: f32,
+}
+
+/// Open the shard in `path`, creating it if it doesn't exist.
+///
+/// If the shard already exists, `dim` and `distance` must match its vector configuration.
+///
+/// # Safety
+///
+/// `path` must be a valid NUL-terminated string, `out_shard` must be a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qdrant_edge_shard_open(
+    path: *const c_char,
+    dim: usize,
+    distance: QdrantEdgeDistance,
+    out_shard: *mut *mut QdrantEdgeShard,
+) -> QdrantEdgeStatus {
+    ffi_call(|| {
+        let path = unsafe { c_str(path, "path") }?;
+        let out_shard = unsafe { out_pointer(out_shard, "out_shard") }?;
+
+        if dim == 0 {
+            return Err(FfiError::invalid_argument("dim must be greater than 0"));
+        }
+
+        let config = SegmentConfig {
+            vector_data: HashMap::from([(
+                DEFAULT_VECTOR_NAME.to_owned(),
+                VectorDataConfig {
+                    size: dim,
+                    distance: distance.into(),
+                    storage_type: VectorStorageType::ChunkedMmap,
+                    index: Default::default(),
+                    quantization_config: None,
+                    multivector_config: None,
+                    datatype: None,
+                    skip_normalization: None,
+                },
+            )]),
+            sparse_vector_data: HashMap::new(),
+            payload_storage_type: PayloadStorageType::Mmap,
+            defragment_keys: None,
+        };
+
+        let shard = EdgeShard::load(Path::new(path), Some(config))?;
+
+        *out_shard = Box::into_raw(Box::new(QdrantEdgeShard { shard, dim }));
+        Ok(())
+    })
+}
+
+/// Flush and close the shard. Passing NULL is a no-op.
+///
+/// # Safety
+///
+/// `shard` must be NULL or returned by [`qdrant_edge_shard_open`] and not closed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qdrant_edge_shard_close(shard: *mut QdrantEdgeShard) {
+    if shard.is_null() {
+        return;
+    }
+
+    let _ = ffi_call(|| {
+        drop(unsafe { Box::from_raw(shard) });
+        Ok(())
+    });
+}
+
+/// Insert or update `num_points` points.
+///
+/// - `ids` holds `num_points` point IDs.
+/// - `vectors` holds `num_points * dim` floats, one vector after another.
+/// - `payloads` is NULL, or holds `num_points` JSON objects, each of which may be NULL.
+///
+/// # Safety
+///
+/// All pointers must be valid for the described number of elements.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qdrant_edge_upsert(
+    shard: *const QdrantEdgeShard,
+    ids: *const u64,
+    vectors: *const f32,
+    payloads: *const *const c_char,
+    num_points: usize,
+) -> QdrantEdgeStatus {
+    ffi_call(|| {
+        let shard = unsafe { shard.as_ref() }
+            .ok_or_else(|| FfiError::invalid_argument("shard must not be NULL"))?;
+        let ids = unsafe { c_slice(ids, num_points, "ids") }?;
+        let vectors_len = num_points
+            .checked_mul(shard.dim)
+            .ok_or_else(|| FfiError::invalid_argument("num_points is too large"))?;
+        let vectors = unsafe { c_slice(vectors, vectors_len, "vectors") }?;
+        let payloads = if payloads.is_null() {
+            None
+        } else {
+            Some(unsafe { c_slice(payloads, num_points, "payloads") }?)
+        };
+
+        let points = ids
+            .iter()
+            .zip(vectors.chunks_exact(shard.dim))
+            .enumerate()
+            .map(|(i, (&id, vector))| {
+                let payload = match payloads.map(|payloads| payloads[i]) {
+                    Some(payload) if !payload.is_null() => {
+                        let payload = unsafe { c_str(payload, "payload") }?;
+                        let payload: Payload = serde_json::from_str(payload).map_err(|err| {
+                            FfiError::invalid_argument(format!(
+                                "invalid payload of point {id}: {err}"
+                            ))
+                        })?;
+                        Some(payload)
+                    }
+                    _ => None,
+                };
+
+                Ok(PointStructPersisted {
+                    id: ExtendedPointId::NumId(id),
+                    vector: VectorStructPersisted::Single(vector.to_vec()),
+                    payload,
+                })
+            })
+            .collect::<Result<Vec<_>, FfiError>>()?;
+
+        shard
+            .shard
+            .update(CollectionUpdateOperations::PointOperation(
+                PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsList(points)),
+            ))?;
+
+        Ok(())
+    })
+}
+
+/// Search for up to `limit` points nearest to `vector` of `dim` floats.
+///
+/// `filter` is NULL, or a JSON filter in the format of the Qdrant API.
+/// Results are written into `out_points`, which must have room for `limit` points,
+/// and the number of found points is written into `out_count`.
+///
+/// # Safety
+///
+/// All pointers must be valid for the described number of elements.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qdrant_edge_search(
+    shard: *const QdrantEdgeShard,
+    vector: *const f32,
+    dim: usize,
+    filter: *const c_char,
+    limit: usize,
+    out_points: *mut QdrantEdgeScoredPoint,
+    out_count: *mut usize,
+) -> QdrantEdgeStatus {
+    ffi_call(|| {
+        let shard = unsafe { shard.as_ref() }
+            .ok_or_else(|| FfiError::invalid_argument("shard must not be NULL"))?;
+        let out_count = unsafe { out_pointer(out_count, "out_count") }?;
+        *out_count = 0;
+
+        if dim != shard.dim {
+            return Err(FfiError::invalid_argument(format!(
+                "expected vector of {} dimensions, got {dim}",
+                shard.dim,
+            )));
+        }
+        let vector = unsafe { c_slice(vector, dim, "vector") }?;
+
+        let filter = if filter.is_null() {
+            None
+        } else {
+            let filter = unsafe { c_str(filter, "filter") }?;
+            let filter: Filter = serde_json::from_str(filter)
+                .map_err(|err| FfiError::invalid_argument(format!("invalid filter: {err}")))?;
+            Some(filter)
+        };
+
+        if limit == 0 {
+            return Ok(());
+        }
+        if out_points.is_null() {
+            return Err(FfiError::invalid_argument("out_points must not be NULL"));
+        }
+
+        let results = shard.shard.query(ShardQueryRequest {
+            prefetches: vec![],
+            query: Some(ScoringQuery::Vector(QueryEnum::Nearest(NamedQuery {
+                query: VectorInternal::from(vector.to_vec()),
+                using: None,
+            }))),
+            filter,
+            score_threshold: None,
+            limit,
+            offset: 0,
+            params: None,
+            with_vector: WithVector::Bool(false),
+            with_payload: WithPayloadInterface::Bool(false),
+        })?;
+
+        let out_points = unsafe { slice::from_raw_parts_mut(out_points, limit) };
+        let mut count = 0;
+        for (out_point, point) in out_points.iter_mut().zip(&results) {
+            let ExtendedPointId::NumId(id) = point.id else {
+                return Err(FfiError::error("UUID point IDs are not supported"));
+            };
+            *out_point = QdrantEdgeScoredPoint {
+                id,
+                score: point.score,
+            };
+            count += 1;
+        }
+
+        *out_count = count;
+        Ok(())
+    })
+}
+
+/// # Safety
+///
+/// `ptr` must be NULL or a valid NUL-terminated string.
+unsafe fn c_str<'a>(ptr: *const c_char, name: &str) -> Result<&'a str, FfiError> {
+    if ptr.is_null() {
+        return Err(FfiError::invalid_argument(format!(
+            "{name} must not be NULL"
+        )));
+    }
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|err| FfiError::invalid_argument(format!("{name} is not valid UTF-8: {err}")))
+}
+
+/// # Safety
+///
+/// `ptr` must be NULL or valid for `len` elements.
+unsafe fn c_slice<'a, T>(ptr: *const T, len: usize, name: &str) -> Result<&'a [T], FfiError> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if ptr.is_null() {
+        return Err(FfiError::invalid_argument(format!(
+            "{name} must not be NULL"
+        )));
+    }
+    Ok(unsafe { slice::from_raw_parts(ptr, len) })
+}
+
+/// # Safety
+///
+/// `ptr` must be NULL or valid for writes.
+unsafe fn out_pointer<'a, T>(ptr: *mut T, name: &str) -> Result<&'a mut T, FfiError> {
+    unsafe { ptr.as_mut() }
+        .ok_or_else(|| FfiError::invalid_argument(format!("{name} must not be NULL")))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::ptr;
+
+    use super::*;
+
+    #[test]
+    fn test_open_upsert_search() {
+        let dir = tempfile::Builder::new().prefix("edge").tempdir().unwrap();
+        let path = CString::new(dir.path().to_str().unwrap()).unwrap();
+
+        let mut shard = ptr::null_mut();
+        let status = unsafe {
+            qdrant_edge_shard_open(path.as_ptr(), 2, QdrantEdgeDistance::Dot, &mut shard)
+        };
+        assert_eq!(status, QdrantEdgeStatus::Ok);
+
+        let ids = [1, 2, 3];
+        let vectors = [1.0, 0.0, 0.0, 1.0, 0.5, 0.5];
+        let red = CString::new(r#"{"color": "red"}"#).unwrap();
+        let payloads = [red.as_ptr(), ptr::null(), red.as_ptr()];
+        let status = unsafe {
+            qdrant_edge_upsert(shard, ids.as_ptr(), vectors.as_ptr(), payloads.as_ptr(), 3)
+        };
+        assert_eq!(status, QdrantEdgeStatus::Ok);
+
+        let mut points = [QdrantEdgeScoredPoint { id: 0, score: 0.0 }; 3];
+        let mut count = 0;
+        let query = [0.0, 1.0];
+        let status = unsafe {
+            qdrant_edge_search(
+                shard,
+                query.as_ptr(),
+                2,
+                ptr::null(),
+                3,
+                points.as_mut_ptr(),
+                &mut count,
+            )
+        };
+        assert_eq!(status, QdrantEdgeStatus::Ok);
+        assert_eq!(count, 3);
+        assert_eq!(points[0].id, 2);
+
+        let filter =
+            CString::new(r#"{"must": [{"key": "color", "match": {"value": "red"}}]}"#).unwrap();
+        let status = unsafe {
+            qdrant_edge_search(
+                shard,
+                query.as_ptr(),
+                2,
+                filter.as_ptr(),
+                3,
+                points.as_mut_ptr(),
+                &mut count,
+            )
+        };
+        assert_eq!(status, QdrantEdgeStatus::Ok);
+        assert_eq!(count, 2);
+        assert_eq!(points[0].id, 3);
+
+        // Wrong dimensions are reported as errors instead of reading out of bounds
+        let status = unsafe {
+            qdrant_edge_search(
+                shard,
+                query.as_ptr(),
+                3,
+                ptr::null(),
+                3,
+                points.as_mut_ptr(),
+                &mut count,
+            )
+        };
+        assert_eq!(status, QdrantEdgeStatus::InvalidArgument);
+        let error = unsafe { CStr::from_ptr(qdrant_edge_last_error()) };
+        assert!(error.to_str().unwrap().contains("dimensions"));
+
+        unsafe { qdrant_edge_shard_close(shard) };
+    }
+}
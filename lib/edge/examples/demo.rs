@@ -87,6 +87,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         with_payload: Some(WithPayloadInterface::Bool(false)),
         with_vector: WithVector::Bool(false),
         order_by: None,
+        consistent: None,
+        view_id: None,
     })?;
     for r in &records {
         println!("ID: {}", r.id);
@@ -161,12 +163,14 @@ fn load_new_shard() -> Result<EdgeShard, Box<dyn Error>> {
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    skip_normalization: None,
                 },
             );
             m
         },
         sparse_vector_data: HashMap::new(),
         payload_storage_type: PayloadStorageType::Mmap,
+        defragment_keys: None,
     };
 
     Ok(EdgeShard::load(Path::new(DATA_DIR), Some(config))?)
@@ -17,7 +17,9 @@ Payload = Dict[str, Any]
 JsonPath = str
 WithPayloadType = Union[bool, List[str], "PayloadSelector"]
 WithVectorType = Union[bool, List[str]]
-ScoringQueryType = Union["Query", "Fusion", "OrderBy", "Formula", "Sample", "Mmr"]
+ScoringQueryType = Union[
+    "Query", "Fusion", "OrderBy", "Formula", "Sample", "Mmr", "VectorSelection"
+]
 ConditionType = Union[
     "FieldCondition",
     "IsEmptyCondition",
@@ -28,7 +30,15 @@ ConditionType = Union[
     "Filter",
 ]
 MatchType = Union[
-    "MatchValue", "MatchText", "MatchTextAny", "MatchPhrase", "MatchAny", "MatchExcept"
+    "MatchValue",
+    "MatchText",
+    "MatchTextAny",
+    "MatchPhrase",
+    "MatchNear",
+    "MatchPrefix",
+    "MatchRegex",
+    "MatchAny",
+    "MatchExcept",
 ]
 RangeType = Union["RangeFloat", "RangeDateTime"]
 QuantizationConfigType = Union[
@@ -257,6 +267,7 @@ class VectorDataConfig:
             quantization_config: Optional[QuantizationConfigType] = None,
             multivector_config: Optional["MultiVectorConfig"] = None,
             datatype: Optional["VectorStorageDatatype"] = None,
+            skip_normalization: Optional[bool] = None,
     ) -> None:
         """
         Create a VectorDataConfig.
@@ -267,6 +278,7 @@ class VectorDataConfig:
             quantization_config: Optional quantization configuration.
             multivector_config: Optional multi-vector configuration.
             datatype: Optional storage datatype.
+            skip_normalization: Trust vectors to be normalized and skip normalization on upsert.
         """
         ...
 
@@ -305,6 +317,11 @@ class VectorDataConfig:
         """Storage datatype."""
         ...
 
+    @property
+    def skip_normalization(self) -> Optional[bool]:
+        """Whether normalization of upserted vectors is skipped."""
+        ...
+
 
 class SparseVectorDataConfig:
     """Configuration for sparse vector storage."""
@@ -391,6 +408,10 @@ class HnswIndexConfig:
             on_disk: Optional[bool] = None,
             payload_m: Optional[int] = None,
             inline_storage: Optional[bool] = None,
+            varint_links: Optional[bool] = None,
+            m0: Optional[int] = None,
+            ef_construct_upper: Optional[int] = None,
+            neighbor_selection: Optional[HnswNeighborSelection] = None,
     ) -> None:
         """
         Create an HnswIndexConfig.
@@ -402,6 +423,10 @@ class HnswIndexConfig:
             on_disk: Whether to store on disk.
             payload_m: Payload index m value.
             inline_storage: Whether to use inline storage.
+            varint_links: Whether to store graph links varint-encoded.
+            m0: Number of edges per node on level 0.
+            ef_construct_upper: Build-time neighbours to consider on levels above 0.
+            neighbor_selection: Strategy to select neighbours during the build.
         """
         ...
 
@@ -435,6 +460,26 @@ class HnswIndexConfig:
         """Inline storage flag."""
         ...
 
+    @property
+    def varint_links(self) -> Optional[bool]:
+        """Varint links flag."""
+        ...
+
+    @property
+    def m0(self) -> Optional[int]:
+        """Number of edges per node on level 0."""
+        ...
+
+    @property
+    def ef_construct_upper(self) -> Optional[int]:
+        """Build-time neighbours to consider on levels above 0."""
+        ...
+
+    @property
+    def neighbor_selection(self) -> Optional[HnswNeighborSelection]:
+        """Neighbour selection strategy."""
+        ...
+
 
 class MultiVectorConfig:
     """Configuration for multi-vector storage."""
@@ -571,6 +616,14 @@ class Distance(Enum):
     Manhattan = ...
 
 
+class HnswNeighborSelection(Enum):
+    """HNSW neighbour selection strategies."""
+
+    Heuristic = ...
+    HeuristicKeepPruned = ...
+    Nearest = ...
+
+
 class VectorStorageType(Enum):
     """Vector storage types."""
 
@@ -699,6 +752,7 @@ class PayloadSchemaType(Enum):
     Bool = ...
     Datetime = ...
     Uuid = ...
+    GeoShape = ...
 
 
 # ============================================================================
@@ -1312,6 +1366,8 @@ class QuantizationSearchParams:
             ignore: bool = False,
             rescore: Optional[bool] = None,
             oversampling: Optional[float] = None,
+            max_rescore: Optional[int] = None,
+            adaptive_rescore: Optional[bool] = None,
     ) -> None:
         """
         Create QuantizationSearchParams.
@@ -1320,6 +1376,8 @@ class QuantizationSearchParams:
             ignore: Whether to ignore quantization.
             rescore: Whether to rescore with original vectors.
             oversampling: Oversampling factor.
+            max_rescore: Max number of original vectors read for rescoring.
+            adaptive_rescore: Whether to skip rescoring when quantized order is accurate.
         """
         ...
 
@@ -1338,6 +1396,16 @@ class QuantizationSearchParams:
         """Oversampling factor."""
         ...
 
+    @property
+    def max_rescore(self) -> Optional[int]:
+        """Max number of original vectors read for rescoring."""
+        ...
+
+    @property
+    def adaptive_rescore(self) -> Optional[bool]:
+        """Adaptive rescore flag."""
+        ...
+
 
 class AcornSearchParams:
     """Parameters for Acorn filtered search."""
@@ -1459,6 +1527,7 @@ class OrderBy:
             key: JsonPath,
             direction: Optional[Direction] = None,
             start_from: Optional[StartFromType] = None,
+            then_by: Optional[List[ThenBy]] = None,
     ) -> None:
         """
         Create an OrderBy.
@@ -1467,6 +1536,7 @@ class OrderBy:
             key: Payload field path.
             direction: Sort direction.
             start_from: Starting value.
+            then_by: Fields to order results with equal values of `key` by.
         """
         ...
 
@@ -1485,6 +1555,38 @@ class OrderBy:
         """Starting value."""
         ...
 
+    @property
+    def then_by(self) -> List[ThenBy]:
+        """Fields to order results with equal values of `key` by."""
+        ...
+
+
+class ThenBy:
+    """Secondary payload field to order results by."""
+
+    def __init__(
+            self,
+            key: JsonPath,
+            direction: Optional[Direction] = None,
+    ) -> None:
+        """
+        Create a ThenBy.
+
+        Args:
+            key: Payload field path.
+            direction: Sort direction.
+        """
+        ...
+
+    @property
+    def key(self) -> str:
+        """Field key."""
+        ...
+
+    @property
+    def direction(self) -> Optional[Direction]:
+        """Sort direction."""
+        ...
 
 class Mmr:
     """Maximal Marginal Relevance for result diversification."""
@@ -1530,6 +1632,49 @@ class Mmr:
         ...
 
 
+class VectorSelection:
+    """Score each point with the named vector selected by the value of its payload field."""
+
+    def __init__(
+            self,
+            key: JsonPath,
+            mapping: List[Tuple[Union[str, int, bool], str]],
+            queries: Dict[str, NamedVector],
+            default: Optional[str] = None,
+    ) -> None:
+        """
+        Create a vector selection query.
+
+        Args:
+            key: Payload field, which selects the vector.
+            mapping: Pairs of payload value and name of the vector it selects.
+            queries: Query vector for each of the selectable vectors.
+            default: Vector for points without any of the mapped values.
+                If not set, such points are excluded.
+        """
+        ...
+
+    @property
+    def key(self) -> JsonPath:
+        """Payload field."""
+        ...
+
+    @property
+    def mapping(self) -> List[Tuple[Union[str, int, bool], str]]:
+        """Payload value to vector name pairs."""
+        ...
+
+    @property
+    def queries(self) -> Dict[str, NamedVector]:
+        """Query vector for each of the selectable vectors."""
+        ...
+
+    @property
+    def default(self) -> Optional[str]:
+        """Default vector."""
+        ...
+
+
 class RecommendQuery:
     """Query for recommendation based on positive and negative examples."""
 
@@ -1777,6 +1922,16 @@ class Expression(Enum):
         """Create a datetime field expression."""
         ...
 
+    @staticmethod
+    def TextScore(key: JsonPath, text: str, boosts: Optional[Dict[str, float]] = None) -> "Expression":
+        """Create a BM25 relevance expression of a full-text indexed field to the text, with optional per-term weights."""
+        ...
+
+    @staticmethod
+    def Wasm(module: bytes, args: List["Expression"], fuel_limit: Optional[int] = None) -> "Expression":
+        """Create a call of a user-defined WebAssembly function, exporting `score` with one f64 parameter per argument."""
+        ...
+
     @staticmethod
     def Mult(exprs: List["Expression"]) -> "Expression":
         """Create a multiplication expression."""
@@ -1932,6 +2087,8 @@ class FieldCondition:
             values_count: Optional["ValuesCount"] = None,
             is_empty: Optional[bool] = None,
             is_null: Optional[bool] = None,
+            has_field: Optional[bool] = None,
+            compare: Optional["FieldComparison"] = None,
     ) -> None:
         """
         Create a FieldCondition.
@@ -1946,6 +2103,8 @@ class FieldCondition:
             values_count: Values count condition.
             is_empty: Check if empty.
             is_null: Check if null.
+            has_field: Check if the field is present, with any value.
+            compare: Compare the field with other fields of the same point.
         """
         ...
 
@@ -1994,6 +2153,58 @@ class FieldCondition:
         """Is null flag."""
         ...
 
+    @property
+    def has_field(self) -> Optional[bool]:
+        """Has field flag."""
+        ...
+
+    @property
+    def compare(self) -> Optional["FieldComparison"]:
+        """Comparison with other fields."""
+        ...
+
+
+class FieldComparison:
+    """Condition on order of a field and other fields of the same point."""
+
+    def __init__(
+            self,
+            lt: Optional[JsonPath] = None,
+            gt: Optional[JsonPath] = None,
+            lte: Optional[JsonPath] = None,
+            gte: Optional[JsonPath] = None,
+    ) -> None:
+        """
+        Create a FieldComparison.
+
+        Args:
+            lt: Field, the value must be less than.
+            gt: Field, the value must be greater than.
+            lte: Field, the value must be less than or equal to.
+            gte: Field, the value must be greater than or equal to.
+        """
+        ...
+
+    @property
+    def lt(self) -> Optional[str]:
+        """Less than field."""
+        ...
+
+    @property
+    def gt(self) -> Optional[str]:
+        """Greater than field."""
+        ...
+
+    @property
+    def lte(self) -> Optional[str]:
+        """Less than or equal field."""
+        ...
+
+    @property
+    def gte(self) -> Optional[str]:
+        """Greater than or equal field."""
+        ...
+
 
 class IsEmptyCondition:
     """Check if a field is empty."""
@@ -2117,12 +2328,13 @@ class MatchValue:
 class MatchText:
     """Full-text match."""
 
-    def __init__(self, text: str) -> None:
+    def __init__(self, text: str, fuzziness: Optional[int] = None) -> None:
         """
         Create a MatchText.
 
         Args:
             text: Text to search for.
+            fuzziness: Maximum number of edits per token, up to 2. Exact match if not set.
         """
         ...
 
@@ -2131,6 +2343,11 @@ class MatchText:
         """Text."""
         ...
 
+    @property
+    def fuzziness(self) -> Optional[int]:
+        """Fuzziness."""
+        ...
+
 
 class MatchTextAny:
     """Match any of the words in text."""
@@ -2168,6 +2385,66 @@ class MatchPhrase:
         ...
 
 
+class MatchNear:
+    """Match all tokens of the text, close to each other in any order."""
+
+    def __init__(self, near: str, within: int) -> None:
+        """
+        Create a MatchNear.
+
+        Args:
+            near: Text with tokens to match.
+            within: Maximum number of other tokens between the matched tokens.
+        """
+        ...
+
+    @property
+    def near(self) -> str:
+        """Text."""
+        ...
+
+    @property
+    def within(self) -> int:
+        """Maximum number of other tokens between the matched tokens."""
+        ...
+
+
+class MatchPrefix:
+    """Match keywords starting with prefix."""
+
+    def __init__(self, prefix: str) -> None:
+        """
+        Create a MatchPrefix.
+
+        Args:
+            prefix: Prefix of keywords to match.
+        """
+        ...
+
+    @property
+    def prefix(self) -> str:
+        """Prefix."""
+        ...
+
+
+class MatchRegex:
+    """Match keywords containing a match of the regular expression."""
+
+    def __init__(self, regex: str) -> None:
+        """
+        Create a MatchRegex.
+
+        Args:
+            regex: Regular expression to match keywords with.
+        """
+        ...
+
+    @property
+    def regex(self) -> str:
+        """Regular expression."""
+        ...
+
+
 class MatchAny:
     """Match any of the values."""
 
@@ -2520,6 +2797,20 @@ class UpdateOperation:
         """
         ...
 
+    @staticmethod
+    def append_vectors(
+            point_vectors: List[PointVectors],
+            condition: Optional[Filter] = None,
+    ) -> "UpdateOperation":
+        """
+        Append sub-vectors to multivectors of existing points.
+
+        Args:
+            point_vectors: Point IDs with multivectors to append.
+            condition: Optional filter condition.
+        """
+        ...
+
     @staticmethod
     def delete_vectors(
             point_ids: List[PointId],
@@ -35,13 +35,14 @@ impl PyVectorDataConfig {
 #[pymethods]
 impl PyVectorDataConfig {
     #[new]
-    #[pyo3(signature = (size, distance, quantization_config=None, multivector_config=None, datatype=None))]
+    #[pyo3(signature = (size, distance, quantization_config=None, multivector_config=None, datatype=None, skip_normalization=None))]
     pub fn new(
         size: usize,
         distance: PyDistance,
         quantization_config: Option<PyQuantizationConfig>,
         multivector_config: Option<PyMultiVectorConfig>,
         datatype: Option<PyVectorStorageDatatype>,
+        skip_normalization: Option<bool>,
     ) -> Self {
         Self(VectorDataConfig {
             size,
@@ -51,6 +52,7 @@ impl PyVectorDataConfig {
             quantization_config: quantization_config.map(QuantizationConfig::from),
             multivector_config: multivector_config.map(MultiVectorConfig::from),
             datatype: datatype.map(VectorStorageDatatype::from),
+            skip_normalization,
         })
     }
 
@@ -89,6 +91,11 @@ impl PyVectorDataConfig {
         self.0.datatype.map(PyVectorStorageDatatype::from)
     }
 
+    #[getter]
+    pub fn skip_normalization(&self) -> Option<bool> {
+        self.0.skip_normalization
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -105,6 +112,7 @@ impl PyVectorDataConfig {
             quantization_config: _,
             multivector_config: _,
             datatype: _,
+            skip_normalization: _,
         } = self.0;
     }
 }
@@ -303,7 +311,7 @@ pub struct PyHnswIndexConfig(HnswConfig);
 #[pymethods]
 impl PyHnswIndexConfig {
     #[new]
-    #[pyo3(signature = (m, ef_construct, full_scan_threshold, on_disk=None, payload_m=None, inline_storage=None))]
+    #[pyo3(signature = (m, ef_construct, full_scan_threshold, on_disk=None, payload_m=None, inline_storage=None, varint_links=None, m0=None, ef_construct_upper=None, neighbor_selection=None))]
     pub fn new(
         m: usize,
         ef_construct: usize,
@@ -311,6 +319,10 @@ impl PyHnswIndexConfig {
         on_disk: Option<bool>,
         payload_m: Option<usize>,
         inline_storage: Option<bool>,
+        varint_links: Option<bool>,
+        m0: Option<usize>,
+        ef_construct_upper: Option<usize>,
+        neighbor_selection: Option<PyHnswNeighborSelection>,
     ) -> Self {
         Self(HnswConfig {
             m,
@@ -320,6 +332,10 @@ impl PyHnswIndexConfig {
             on_disk,
             payload_m,
             inline_storage,
+            varint_links,
+            m0,
+            ef_construct_upper,
+            neighbor_selection: neighbor_selection.map(HnswNeighborSelection::from),
         })
     }
 
@@ -353,6 +369,26 @@ impl PyHnswIndexConfig {
         self.0.inline_storage
     }
 
+    #[getter]
+    pub fn varint_links(&self) -> Option<bool> {
+        self.0.varint_links
+    }
+
+    #[getter]
+    pub fn m0(&self) -> Option<usize> {
+        self.0.m0
+    }
+
+    #[getter]
+    pub fn ef_construct_upper(&self) -> Option<usize> {
+        self.0.ef_construct_upper
+    }
+
+    #[getter]
+    pub fn neighbor_selection(&self) -> Option<PyHnswNeighborSelection> {
+        self.0.neighbor_selection.map(PyHnswNeighborSelection::from)
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -369,10 +405,65 @@ impl PyHnswIndexConfig {
             on_disk: _,
             payload_m: _,
             inline_storage: _,
+            varint_links: _,
+            m0: _,
+            ef_construct_upper: _,
+            neighbor_selection: _,
         } = self.0;
     }
 }
 
+#[pyclass(name = "HnswNeighborSelection", from_py_object)]
+#[derive(Copy, Clone, Debug)]
+pub enum PyHnswNeighborSelection {
+    Heuristic,
+    HeuristicKeepPruned,
+    Nearest,
+}
+
+#[pymethods]
+impl PyHnswNeighborSelection {
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Repr for PyHnswNeighborSelection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::Heuristic => "Heuristic",
+            Self::HeuristicKeepPruned => "HeuristicKeepPruned",
+            Self::Nearest => "Nearest",
+        };
+
+        f.simple_enum::<Self>(repr)
+    }
+}
+
+impl From<HnswNeighborSelection> for PyHnswNeighborSelection {
+    fn from(selection: HnswNeighborSelection) -> Self {
+        match selection {
+            HnswNeighborSelection::Heuristic => PyHnswNeighborSelection::Heuristic,
+            HnswNeighborSelection::HeuristicKeepPruned => {
+                PyHnswNeighborSelection::HeuristicKeepPruned
+            }
+            HnswNeighborSelection::Nearest => PyHnswNeighborSelection::Nearest,
+        }
+    }
+}
+
+impl From<PyHnswNeighborSelection> for HnswNeighborSelection {
+    fn from(selection: PyHnswNeighborSelection) -> Self {
+        match selection {
+            PyHnswNeighborSelection::Heuristic => HnswNeighborSelection::Heuristic,
+            PyHnswNeighborSelection::HeuristicKeepPruned => {
+                HnswNeighborSelection::HeuristicKeepPruned
+            }
+            PyHnswNeighborSelection::Nearest => HnswNeighborSelection::Nearest,
+        }
+    }
+}
+
 #[pyclass(name = "MultiVectorConfig", from_py_object)]
 #[derive(Copy, Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
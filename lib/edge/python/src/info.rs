@@ -91,6 +91,11 @@ impl PyPayloadIndexInfo {
     pub fn points(&self) -> usize {
         self.0.points
     }
+
+    #[getter]
+    pub fn distinct_values(&self) -> Option<usize> {
+        self.0.distinct_values
+    }
 }
 
 impl PyPayloadIndexInfo {
@@ -100,6 +105,8 @@ impl PyPayloadIndexInfo {
             data_type: _,
             params: _,
             points: _,
+            distinct_values: _,
+            distinct_values_sketch: _,
         } = self.0;
     }
 }
@@ -46,8 +46,9 @@ mod qdrant_edge {
     };
     #[pymodule_export]
     use super::config::vector_data::{
-        PyDistance, PyHnswIndexConfig, PyMultiVectorComparator, PyMultiVectorConfig,
-        PyPlainIndexConfig, PyVectorDataConfig, PyVectorStorageDatatype, PyVectorStorageType,
+        PyDistance, PyHnswIndexConfig, PyHnswNeighborSelection, PyMultiVectorComparator,
+        PyMultiVectorConfig, PyPlainIndexConfig, PyVectorDataConfig, PyVectorStorageDatatype,
+        PyVectorStorageType,
     };
     #[pymodule_export]
     use super::config::{PyEdgeConfig, PyPayloadStorageType};
@@ -57,7 +58,8 @@ mod qdrant_edge {
     use super::facet::{PyFacetHit, PyFacetRequest, PyFacetResponse};
     #[pymodule_export]
     use super::query::{
-        PyDirection, PyFusion, PyMmr, PyOrderBy, PyPrefetch, PyQueryRequest, PySample,
+        PyDirection, PyFusion, PyMmr, PyOrderBy, PyPrefetch, PyQueryRequest, PySample, PyThenBy,
+        PyVectorSelection,
     };
     #[pymodule_export]
     use super::scroll::PyScrollRequest;
@@ -67,10 +69,11 @@ mod qdrant_edge {
     };
     #[pymodule_export]
     use super::types::filter::{
-        PyFieldCondition, PyFilter, PyGeoBoundingBox, PyGeoPoint, PyGeoPolygon, PyGeoRadius,
-        PyHasIdCondition, PyHasVectorCondition, PyIsEmptyCondition, PyIsNullCondition, PyMatchAny,
-        PyMatchExcept, PyMatchPhrase, PyMatchText, PyMatchTextAny, PyMatchValue, PyMinShould,
-        PyNestedCondition, PyRangeDateTime, PyRangeFloat, PyValuesCount,
+        PyFieldComparison, PyFieldCondition, PyFilter, PyGeoBoundingBox, PyGeoPoint, PyGeoPolygon,
+        PyGeoRadius, PyHasIdCondition, PyHasVectorCondition, PyIsEmptyCondition, PyIsNullCondition,
+        PyMatchAny, PyMatchExcept, PyMatchNear, PyMatchPhrase, PyMatchPrefix, PyMatchRegex,
+        PyMatchText, PyMatchTextAny, PyMatchValue, PyMinShould, PyNestedCondition, PyRangeDateTime,
+        PyRangeFloat, PyValuesCount,
     };
     #[pymodule_export]
     use super::types::formula::{PyDecayKind, PyExpressionInterface, PyFormula};
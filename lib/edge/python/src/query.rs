@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt;
 
 use bytemuck::{TransparentWrapper, TransparentWrapperAlloc as _};
@@ -6,11 +7,13 @@ use ordered_float::OrderedFloat;
 use pyo3::IntoPyObjectExt;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use segment::data_types::order_by::{Direction, OrderBy, StartFrom};
-use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorInternal};
+use segment::data_types::order_by::{Direction, OrderBy, StartFrom, ThenBy};
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, Named as _, NamedQuery, VectorInternal};
 use segment::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use segment::json_path::JsonPath;
+use segment::types::ValueVariants;
 use shard::query::query_enum::QueryEnum;
+use shard::query::vector_selection::VectorSelectionInternal;
 use shard::query::*;
 use shard::scroll::OrderByInterface;
 
@@ -244,6 +247,7 @@ impl FromPyObject<'_, '_> for PyScoringQuery {
             Formula(PyFormula),
             Sample(PySample),
             Mmr(PyMmr),
+            VectorSelection(PyVectorSelection),
         }
 
         fn _variants(query: ScoringQuery) {
@@ -254,6 +258,7 @@ impl FromPyObject<'_, '_> for PyScoringQuery {
                 ScoringQuery::Formula(_) => {}
                 ScoringQuery::Sample(_) => {}
                 ScoringQuery::Mmr(_) => {}
+                ScoringQuery::VectorSelection(_) => {}
             }
         }
 
@@ -264,6 +269,9 @@ impl FromPyObject<'_, '_> for PyScoringQuery {
             Helper::Formula(formula) => ScoringQuery::Formula(ParsedFormula::from(formula)),
             Helper::Sample(sample) => ScoringQuery::Sample(SampleInternal::from(sample)),
             Helper::Mmr(mmr) => ScoringQuery::Mmr(MmrInternal::from(mmr)),
+            Helper::VectorSelection(selection) => {
+                ScoringQuery::VectorSelection(VectorSelectionInternal::from(selection))
+            }
         };
 
         Ok(Self(query))
@@ -283,6 +291,9 @@ impl<'py> IntoPyObject<'py> for PyScoringQuery {
             ScoringQuery::Formula(formula) => PyFormula(formula).into_bound_py_any(py),
             ScoringQuery::Sample(sample) => PySample::from(sample).into_bound_py_any(py),
             ScoringQuery::Mmr(mmr) => PyMmr(mmr).into_bound_py_any(py),
+            ScoringQuery::VectorSelection(selection) => {
+                PyVectorSelection(selection).into_bound_py_any(py)
+            }
         }
     }
 }
@@ -306,6 +317,9 @@ impl Repr for PyScoringQuery {
             ScoringQuery::Formula(_formula) => f.unimplemented(), // TODO!
             ScoringQuery::Sample(sample) => PySample::from(*sample).fmt(f),
             ScoringQuery::Mmr(mmr) => PyMmr::wrap_ref(mmr).fmt(f),
+            ScoringQuery::VectorSelection(selection) => {
+                PyVectorSelection::wrap_ref(selection).fmt(f)
+            }
         }
     }
 }
@@ -376,16 +390,18 @@ pub struct PyOrderBy(OrderBy);
 #[pymethods]
 impl PyOrderBy {
     #[new]
-    #[pyo3(signature = (key, direction = None, start_from = None))]
+    #[pyo3(signature = (key, direction = None, start_from = None, then_by = None))]
     pub fn new(
         key: PyJsonPath,
         direction: Option<PyDirection>,
         start_from: Option<PyStartFrom>,
+        then_by: Option<Vec<PyThenBy>>,
     ) -> PyResult<Self> {
         let order_by = OrderBy {
             key: JsonPath::from(key),
             direction: direction.map(Direction::from),
             start_from: start_from.map(StartFrom::from),
+            then_by: PyThenBy::peel_vec(then_by.unwrap_or_default()),
         };
 
         Ok(Self(order_by))
@@ -406,6 +422,11 @@ impl PyOrderBy {
         self.0.start_from.map(PyStartFrom)
     }
 
+    #[getter]
+    pub fn then_by(&self) -> &[PyThenBy] {
+        PyThenBy::wrap_slice(&self.0.then_by)
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -418,6 +439,49 @@ impl PyOrderBy {
             key: _,
             direction: _,
             start_from: _,
+            then_by: _,
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "ThenBy", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyThenBy(ThenBy);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyThenBy {
+    #[new]
+    #[pyo3(signature = (key, direction = None))]
+    pub fn new(key: PyJsonPath, direction: Option<PyDirection>) -> Self {
+        Self(ThenBy {
+            key: JsonPath::from(key),
+            direction: direction.map(Direction::from),
+        })
+    }
+
+    #[getter]
+    pub fn key(&self) -> &PyJsonPath {
+        PyJsonPath::wrap_ref(&self.0.key)
+    }
+
+    #[getter]
+    pub fn direction(&self) -> Option<PyDirection> {
+        self.0.direction.map(PyDirection::from)
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyThenBy {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let ThenBy {
+            key: _,
+            direction: _,
         } = self.0;
     }
 }
@@ -650,3 +714,85 @@ impl PyMmr {
         } = self.0;
     }
 }
+
+#[pyclass(name = "VectorSelection", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyVectorSelection(VectorSelectionInternal);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyVectorSelection {
+    #[new]
+    #[pyo3(signature = (key, mapping, queries, default = None))]
+    pub fn new(
+        key: PyJsonPath,
+        mapping: Vec<(PyValueVariants, String)>,
+        queries: HashMap<String, PyNamedVectorInternal>,
+        default: Option<String>,
+    ) -> Self {
+        let selection = VectorSelectionInternal {
+            key: JsonPath::from(key),
+            mapping: mapping
+                .into_iter()
+                .map(|(value, vector)| (ValueVariants::from(value), vector))
+                .collect(),
+            default,
+            queries: queries
+                .into_iter()
+                .map(|(vector, query)| NamedQuery::new(VectorInternal::from(query), vector))
+                .collect(),
+        };
+
+        Self(selection)
+    }
+
+    #[getter]
+    pub fn key(&self) -> &PyJsonPath {
+        PyJsonPath::wrap_ref(&self.0.key)
+    }
+
+    #[getter]
+    pub fn mapping(&self) -> Vec<(PyValueVariants, String)> {
+        self.0
+            .mapping
+            .iter()
+            .map(|(value, vector)| (PyValueVariants::wrap(value.clone()), vector.clone()))
+            .collect()
+    }
+
+    #[getter]
+    pub fn queries(&self) -> HashMap<String, PyNamedVectorInternal> {
+        self.0
+            .queries
+            .iter()
+            .map(|query| {
+                (
+                    query.get_name().to_string(),
+                    PyNamedVectorInternal(query.query.clone()),
+                )
+            })
+            .collect()
+    }
+
+    #[getter]
+    pub fn default(&self) -> Option<&str> {
+        self.0.default.as_deref()
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyVectorSelection {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let VectorSelectionInternal {
+            key: _,
+            mapping: _,
+            default: _,
+            queries: _,
+        } = self.0;
+    }
+}
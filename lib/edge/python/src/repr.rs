@@ -29,6 +29,12 @@ impl Repr for bool {
     }
 }
 
+impl Repr for u8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
 impl Repr for u32 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{self}")
@@ -89,6 +95,16 @@ impl<T: Repr> Repr for Vec<T> {
     }
 }
 
+impl<A: Repr, B: Repr> Repr for (A, B) {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        self.0.fmt(f)?;
+        write!(f, ", ")?;
+        self.1.fmt(f)?;
+        write!(f, ")")
+    }
+}
+
 impl<K: Repr + ReprStr, V: Repr, S> Repr for HashMap<K, V, S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.map(self)
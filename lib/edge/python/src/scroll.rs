@@ -39,6 +39,8 @@ impl PyScrollRequest {
             with_payload: with_payload.map(WithPayloadInterface::from),
             with_vector: with_vector.map(WithVector::from).unwrap_or_default(),
             order_by: order_by.map(OrderByInterface::from),
+            consistent: None,
+            view_id: None,
         })
     }
 
@@ -87,6 +89,9 @@ impl PyScrollRequest {
             with_payload: _,
             with_vector: _,
             order_by: _,
+            // Consistent scroll is not supported by edge shards
+            consistent: _,
+            view_id: _,
         } = self.0;
     }
 }
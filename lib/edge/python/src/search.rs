@@ -192,12 +192,26 @@ pub struct PyQuantizationSearchParams(QuantizationSearchParams);
 #[pymethods]
 impl PyQuantizationSearchParams {
     #[new]
-    #[pyo3(signature = (ignore = false, rescore = None, oversampling = None))]
-    pub fn new(ignore: bool, rescore: Option<bool>, oversampling: Option<f64>) -> Self {
+    #[pyo3(signature = (
+        ignore = false,
+        rescore = None,
+        oversampling = None,
+        max_rescore = None,
+        adaptive_rescore = None,
+    ))]
+    pub fn new(
+        ignore: bool,
+        rescore: Option<bool>,
+        oversampling: Option<f64>,
+        max_rescore: Option<usize>,
+        adaptive_rescore: Option<bool>,
+    ) -> Self {
         Self(QuantizationSearchParams {
             ignore,
             rescore,
             oversampling,
+            max_rescore,
+            adaptive_rescore,
         })
     }
 
@@ -216,6 +230,16 @@ impl PyQuantizationSearchParams {
         self.0.oversampling
     }
 
+    #[getter]
+    pub fn max_rescore(&self) -> Option<usize> {
+        self.0.max_rescore
+    }
+
+    #[getter]
+    pub fn adaptive_rescore(&self) -> Option<bool> {
+        self.0.adaptive_rescore
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -228,6 +252,8 @@ impl PyQuantizationSearchParams {
             ignore: _,
             rescore: _,
             oversampling: _,
+            max_rescore: _,
+            adaptive_rescore: _,
         } = self.0;
     }
 }
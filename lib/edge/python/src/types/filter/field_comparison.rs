@@ -0,0 +1,68 @@
+use bytemuck::TransparentWrapper;
+use derive_more::Into;
+use pyo3::prelude::*;
+use segment::json_path::JsonPath;
+use segment::types::FieldComparison;
+
+use crate::repr::*;
+use crate::types::*;
+
+#[pyclass(name = "FieldComparison", from_py_object)]
+#[derive(Clone, Debug, Into)]
+pub struct PyFieldComparison(pub FieldComparison);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyFieldComparison {
+    #[new]
+    #[pyo3(signature = (lt=None, gt=None, lte=None, gte=None))]
+    pub fn new(
+        lt: Option<PyJsonPath>,
+        gt: Option<PyJsonPath>,
+        lte: Option<PyJsonPath>,
+        gte: Option<PyJsonPath>,
+    ) -> Self {
+        Self(FieldComparison {
+            lt: lt.map(JsonPath::from),
+            gt: gt.map(JsonPath::from),
+            lte: lte.map(JsonPath::from),
+            gte: gte.map(JsonPath::from),
+        })
+    }
+
+    #[getter]
+    pub fn lt(&self) -> Option<&PyJsonPath> {
+        self.0.lt.as_ref().map(PyJsonPath::wrap_ref)
+    }
+
+    #[getter]
+    pub fn gt(&self) -> Option<&PyJsonPath> {
+        self.0.gt.as_ref().map(PyJsonPath::wrap_ref)
+    }
+
+    #[getter]
+    pub fn lte(&self) -> Option<&PyJsonPath> {
+        self.0.lte.as_ref().map(PyJsonPath::wrap_ref)
+    }
+
+    #[getter]
+    pub fn gte(&self) -> Option<&PyJsonPath> {
+        self.0.gte.as_ref().map(PyJsonPath::wrap_ref)
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyFieldComparison {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let FieldComparison {
+            lt: _,
+            gt: _,
+            lte: _,
+            gte: _,
+        } = self.0;
+    }
+}
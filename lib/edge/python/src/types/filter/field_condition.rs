@@ -26,6 +26,8 @@ impl PyFieldCondition {
         values_count=None,
         is_empty=None,
         is_null=None,
+        has_field=None,
+        compare=None,
     ))]
     #[expect(clippy::too_many_arguments)]
     pub fn new(
@@ -38,6 +40,8 @@ impl PyFieldCondition {
         values_count: Option<PyValuesCount>,
         is_empty: Option<bool>,
         is_null: Option<bool>,
+        has_field: Option<bool>,
+        compare: Option<PyFieldComparison>,
     ) -> Self {
         Self(FieldCondition {
             key: JsonPath::from(key),
@@ -46,9 +50,12 @@ impl PyFieldCondition {
             geo_bounding_box: geo_bounding_box.map(GeoBoundingBox::from),
             geo_radius: geo_radius.map(GeoRadius::from),
             geo_polygon: geo_polygon.map(GeoPolygon::from),
+            geo_shape: None,
             values_count: values_count.map(ValuesCount::from),
             is_empty,
             is_null,
+            has_field,
+            compare: compare.map(FieldComparison::from),
         })
     }
 
@@ -96,6 +103,16 @@ impl PyFieldCondition {
     pub fn is_null(&self) -> Option<bool> {
         self.0.is_null
     }
+
+    #[getter]
+    pub fn has_field(&self) -> Option<bool> {
+        self.0.has_field
+    }
+
+    #[getter]
+    pub fn compare(&self) -> Option<PyFieldComparison> {
+        self.0.compare.clone().map(PyFieldComparison)
+    }
 }
 
 impl PyFieldCondition {
@@ -108,9 +125,12 @@ impl PyFieldCondition {
             geo_bounding_box: _,
             geo_radius: _,
             geo_polygon: _,
+            geo_shape: _,
             values_count: _,
             is_empty: _,
             is_null: _,
+            has_field: _,
+            compare: _,
         } = self.0;
     }
 }
@@ -24,6 +24,9 @@ impl FromPyObject<'_, '_> for PyMatch {
             Text(PyMatchText),
             TextAny(PyMatchTextAny),
             Phrase(PyMatchPhrase),
+            Near(PyMatchNear),
+            Prefix(PyMatchPrefix),
+            Regex(PyMatchRegex),
             Any(PyMatchAny),
             Except(PyMatchExcept),
         }
@@ -34,6 +37,9 @@ impl FromPyObject<'_, '_> for PyMatch {
                 Match::Text(_) => {}
                 Match::TextAny(_) => {}
                 Match::Phrase(_) => {}
+                Match::Near(_) => {}
+                Match::Prefix(_) => {}
+                Match::Regex(_) => {}
                 Match::Any(_) => {}
                 Match::Except(_) => {}
             }
@@ -44,6 +50,9 @@ impl FromPyObject<'_, '_> for PyMatch {
             Helper::Text(text) => Match::Text(MatchText::from(text)),
             Helper::TextAny(text_any) => Match::TextAny(MatchTextAny::from(text_any)),
             Helper::Phrase(phrase) => Match::Phrase(MatchPhrase::from(phrase)),
+            Helper::Near(near) => Match::Near(MatchNear::from(near)),
+            Helper::Prefix(prefix) => Match::Prefix(MatchPrefix::from(prefix)),
+            Helper::Regex(regex) => Match::Regex(MatchRegex::from(regex)),
             Helper::Any(any) => Match::Any(MatchAny::from(any)),
             Helper::Except(except) => Match::Except(MatchExcept::from(except)),
         };
@@ -63,6 +72,9 @@ impl<'py> IntoPyObject<'py> for PyMatch {
             Match::Text(text) => PyMatchText(text).into_bound_py_any(py),
             Match::TextAny(text_any) => PyMatchTextAny(text_any).into_bound_py_any(py),
             Match::Phrase(phrase) => PyMatchPhrase(phrase).into_bound_py_any(py),
+            Match::Near(near) => PyMatchNear(near).into_bound_py_any(py),
+            Match::Prefix(prefix) => PyMatchPrefix(prefix).into_bound_py_any(py),
+            Match::Regex(regex) => PyMatchRegex(regex).into_bound_py_any(py),
             Match::Any(any) => PyMatchAny(any).into_bound_py_any(py),
             Match::Except(except) => PyMatchExcept(except).into_bound_py_any(py),
         }
@@ -76,6 +88,9 @@ impl Repr for PyMatch {
             Match::Text(text) => PyMatchText::wrap_ref(text).fmt(f),
             Match::TextAny(text_any) => PyMatchTextAny::wrap_ref(text_any).fmt(f),
             Match::Phrase(phrase) => PyMatchPhrase::wrap_ref(phrase).fmt(f),
+            Match::Near(near) => PyMatchNear::wrap_ref(near).fmt(f),
+            Match::Prefix(prefix) => PyMatchPrefix::wrap_ref(prefix).fmt(f),
+            Match::Regex(regex) => PyMatchRegex::wrap_ref(regex).fmt(f),
             Match::Any(any) => PyMatchAny::wrap_ref(any).fmt(f),
             Match::Except(except) => PyMatchExcept::wrap_ref(except).fmt(f),
         }
@@ -186,8 +201,19 @@ pub struct PyMatchText(pub MatchText);
 #[pymethods]
 impl PyMatchText {
     #[new]
-    pub fn new(text: String) -> Self {
-        Self(MatchText { text })
+    #[pyo3(signature = (text, fuzziness=None, min_token_len=None, max_token_len=None))]
+    pub fn new(
+        text: String,
+        fuzziness: Option<u8>,
+        min_token_len: Option<usize>,
+        max_token_len: Option<usize>,
+    ) -> Self {
+        Self(MatchText {
+            text,
+            fuzziness,
+            min_token_len,
+            max_token_len,
+        })
     }
 
     #[getter]
@@ -195,6 +221,21 @@ impl PyMatchText {
         &self.0.text
     }
 
+    #[getter]
+    pub fn fuzziness(&self) -> Option<u8> {
+        self.0.fuzziness
+    }
+
+    #[getter]
+    pub fn min_token_len(&self) -> Option<usize> {
+        self.0.min_token_len
+    }
+
+    #[getter]
+    pub fn max_token_len(&self) -> Option<usize> {
+        self.0.max_token_len
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -203,7 +244,12 @@ impl PyMatchText {
 impl PyMatchText {
     fn _getters(self) {
         // Every field should have a getter method
-        let MatchText { text: _ } = self.0;
+        let MatchText {
+            text: _,
+            fuzziness: _,
+            min_token_len: _,
+            max_token_len: _,
+        } = self.0;
     }
 }
 
@@ -267,6 +313,101 @@ impl PyMatchPhrase {
     }
 }
 
+#[pyclass(name = "MatchNear", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyMatchNear(pub MatchNear);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyMatchNear {
+    #[new]
+    pub fn new(near: String, within: u32) -> Self {
+        Self(MatchNear { near, within })
+    }
+
+    #[getter]
+    pub fn near(&self) -> &str {
+        &self.0.near
+    }
+
+    #[getter]
+    pub fn within(&self) -> u32 {
+        self.0.within
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyMatchNear {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let MatchNear { near: _, within: _ } = self.0;
+    }
+}
+
+#[pyclass(name = "MatchPrefix", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyMatchPrefix(pub MatchPrefix);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyMatchPrefix {
+    #[new]
+    pub fn new(prefix: String) -> Self {
+        Self(MatchPrefix { prefix })
+    }
+
+    #[getter]
+    pub fn prefix(&self) -> &str {
+        &self.0.prefix
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyMatchPrefix {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let MatchPrefix { prefix: _ } = self.0;
+    }
+}
+
+#[pyclass(name = "MatchRegex", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyMatchRegex(pub MatchRegex);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyMatchRegex {
+    #[new]
+    pub fn new(regex: String) -> Self {
+        Self(MatchRegex { regex })
+    }
+
+    #[getter]
+    pub fn regex(&self) -> &str {
+        &self.0.regex
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyMatchRegex {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let MatchRegex { regex: _ } = self.0;
+    }
+}
+
 #[pyclass(name = "MatchAny", from_py_object)]
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
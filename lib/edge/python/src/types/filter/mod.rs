@@ -1,4 +1,5 @@
 pub mod condition;
+pub mod field_comparison;
 pub mod field_condition;
 pub mod geo;
 pub mod r#match;
@@ -13,6 +14,7 @@ use pyo3::prelude::*;
 use segment::types::{Filter, MinShould};
 
 pub use self::condition::*;
+pub use self::field_comparison::*;
 pub use self::field_condition::*;
 pub use self::geo::*;
 pub use self::r#match::*;
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt;
 
 use bytemuck::TransparentWrapper;
@@ -37,6 +38,24 @@ impl FromPyObject<'_, '_> for PyExpression {
                 ExpressionInternal::DatetimeKey(path.into())
             }
 
+            PyExpressionInterface::TextScore { key, text, boosts } => {
+                ExpressionInternal::TextScore {
+                    key: key.into(),
+                    text,
+                    boosts: boosts.unwrap_or_default().into_iter().collect(),
+                }
+            }
+
+            PyExpressionInterface::Wasm {
+                module,
+                args,
+                fuel_limit,
+            } => ExpressionInternal::Wasm {
+                module,
+                args: PyExpression::peel_vec(args),
+                fuel_limit,
+            },
+
             PyExpressionInterface::Mult { exprs } => {
                 ExpressionInternal::Mult(PyExpression::peel_vec(exprs))
             }
@@ -115,6 +134,24 @@ impl<'py> IntoPyObject<'py> for PyExpression {
                 path: PyJsonPath(path),
             },
 
+            ExpressionInternal::TextScore { key, text, boosts } => {
+                PyExpressionInterface::TextScore {
+                    key: PyJsonPath(key),
+                    text,
+                    boosts: (!boosts.is_empty()).then(|| boosts.into_iter().collect()),
+                }
+            }
+
+            ExpressionInternal::Wasm {
+                module,
+                args,
+                fuel_limit,
+            } => PyExpressionInterface::Wasm {
+                module,
+                args: PyExpression::wrap_vec(args),
+                fuel_limit,
+            },
+
             ExpressionInternal::Mult(exprs) => PyExpressionInterface::Mult {
                 exprs: PyExpression::wrap_vec(exprs),
             },
@@ -205,6 +242,28 @@ impl Repr for PyExpression {
                 ("DatetimeKey", &[("path", PyJsonPath::wrap_ref(path))])
             }
 
+            ExpressionInternal::TextScore { key, text, boosts } => (
+                "TextScore",
+                &[
+                    ("key", PyJsonPath::wrap_ref(key)),
+                    ("text", text),
+                    ("boosts", &boosts.iter().cloned().collect::<HashMap<_, _>>()),
+                ],
+            ),
+
+            ExpressionInternal::Wasm {
+                module,
+                args,
+                fuel_limit,
+            } => (
+                "Wasm",
+                &[
+                    ("module", module),
+                    ("args", &PyExpression::wrap_slice(args)),
+                    ("fuel_limit", fuel_limit),
+                ],
+            ),
+
             ExpressionInternal::Mult(exprs) => {
                 ("Mult", &[("exprs", &PyExpression::wrap_slice(exprs))])
             }
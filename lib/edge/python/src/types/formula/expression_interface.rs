@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt;
 
 use bytemuck::TransparentWrapper;
@@ -34,6 +35,20 @@ pub enum PyExpressionInterface {
         path: PyJsonPath,
     },
 
+    #[pyo3(constructor = (key, text, boosts = None))]
+    TextScore {
+        key: PyJsonPath,
+        text: String,
+        boosts: Option<HashMap<String, f32>>,
+    },
+
+    #[pyo3(constructor = (module, args, fuel_limit = None))]
+    Wasm {
+        module: Vec<u8>,
+        args: Vec<PyExpression>,
+        fuel_limit: Option<u64>,
+    },
+
     Mult {
         exprs: Vec<PyExpression>,
     },
@@ -102,6 +117,25 @@ impl Repr for PyExpressionInterface {
             }
 
             PyExpressionInterface::DatetimeKey { path } => ("DatetimeKey", &[("path", path)]),
+
+            PyExpressionInterface::TextScore { key, text, boosts } => (
+                "TextScore",
+                &[("key", key), ("text", text), ("boosts", boosts)],
+            ),
+
+            PyExpressionInterface::Wasm {
+                module,
+                args,
+                fuel_limit,
+            } => (
+                "Wasm",
+                &[
+                    ("module", module),
+                    ("args", args),
+                    ("fuel_limit", fuel_limit),
+                ],
+            ),
+
             PyExpressionInterface::Mult { exprs } => ("Mult", &[("exprs", exprs)]),
             PyExpressionInterface::Sum { exprs } => ("Sum", &[("exprs", exprs)]),
             PyExpressionInterface::Neg { expr } => ("Neg", &[("expr", expr)]),
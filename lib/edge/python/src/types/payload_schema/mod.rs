@@ -23,6 +23,7 @@ pub enum PyPayloadSchemaType {
     Bool,
     Datetime,
     Uuid,
+    GeoShape,
 }
 
 impl Repr for PyPayloadSchemaType {
@@ -36,6 +37,7 @@ impl Repr for PyPayloadSchemaType {
             Self::Bool => "Bool",
             Self::Datetime => "Datetime",
             Self::Uuid => "Uuid",
+            Self::GeoShape => "GeoShape",
         };
 
         f.simple_enum::<Self>(repr)
@@ -53,6 +55,7 @@ impl From<PayloadSchemaType> for PyPayloadSchemaType {
             PayloadSchemaType::Bool => PyPayloadSchemaType::Bool,
             PayloadSchemaType::Datetime => PyPayloadSchemaType::Datetime,
             PayloadSchemaType::Uuid => PyPayloadSchemaType::Uuid,
+            PayloadSchemaType::GeoShape => PyPayloadSchemaType::GeoShape,
         }
     }
 }
@@ -68,6 +71,7 @@ impl From<PyPayloadSchemaType> for PayloadSchemaType {
             PyPayloadSchemaType::Bool => PayloadSchemaType::Bool,
             PyPayloadSchemaType::Datetime => PayloadSchemaType::Datetime,
             PyPayloadSchemaType::Uuid => PayloadSchemaType::Uuid,
+            PyPayloadSchemaType::GeoShape => PayloadSchemaType::GeoShape,
         }
     }
 }
@@ -90,6 +94,7 @@ impl FromPyObject<'_, '_> for PyPayloadSchemaParams {
             Bool(PyBoolIndexParams),
             Datetime(PyDatetimeIndexParams),
             Uuid(PyUuidIndexParams),
+            GeoShape(PyGeoShapeIndexParams),
         }
 
         fn _variants(schema_params: PayloadSchemaParams) {
@@ -102,6 +107,7 @@ impl FromPyObject<'_, '_> for PyPayloadSchemaParams {
                 PayloadSchemaParams::Bool(_) => {}
                 PayloadSchemaParams::Datetime(_) => {}
                 PayloadSchemaParams::Uuid(_) => {}
+                PayloadSchemaParams::GeoShape(_) => {}
             }
         }
 
@@ -114,6 +120,7 @@ impl FromPyObject<'_, '_> for PyPayloadSchemaParams {
             Helper::Bool(bool) => PayloadSchemaParams::Bool(bool.into()),
             Helper::Datetime(date_time) => PayloadSchemaParams::Datetime(date_time.into()),
             Helper::Uuid(uuid) => PayloadSchemaParams::Uuid(uuid.into()),
+            Helper::GeoShape(geo_shape) => PayloadSchemaParams::GeoShape(geo_shape.into()),
         };
 
         Ok(Self(schema_params))
@@ -139,6 +146,9 @@ impl<'py> IntoPyObject<'py> for PyPayloadSchemaParams {
                 PyDatetimeIndexParams(date_time).into_bound_py_any(py)
             }
             PayloadSchemaParams::Uuid(uuid) => PyUuidIndexParams(uuid).into_bound_py_any(py),
+            PayloadSchemaParams::GeoShape(geo_shape) => {
+                PyGeoShapeIndexParams(geo_shape).into_bound_py_any(py)
+            }
         }
     }
 }
@@ -166,6 +176,9 @@ impl Repr for PyPayloadSchemaParams {
                 PyDatetimeIndexParams::wrap_ref(date_time).fmt(f)
             }
             PayloadSchemaParams::Uuid(uuid) => PyUuidIndexParams::wrap_ref(uuid).fmt(f),
+            PayloadSchemaParams::GeoShape(geo_shape) => {
+                PyGeoShapeIndexParams::wrap_ref(geo_shape).fmt(f)
+            }
         }
     }
 }
@@ -183,15 +196,50 @@ impl PyKeywordIndexParams {
         self.0.is_tenant
     }
 
+    #[getter]
+    pub fn ascii_folding(&self) -> Option<bool> {
+        self.0.ascii_folding
+    }
+
+    #[getter]
+    pub fn unicode_normalization(&self) -> Option<bool> {
+        self.0.unicode_normalization
+    }
+
+    #[getter]
+    pub fn lowercase(&self) -> Option<bool> {
+        self.0.lowercase
+    }
+
     #[getter]
     pub fn on_disk(&self) -> Option<bool> {
         self.0.on_disk
     }
 
+    #[getter]
+    pub fn populate(&self) -> Option<bool> {
+        self.0.populate
+    }
+
+    #[getter]
+    pub fn bloom_filter(&self) -> Option<bool> {
+        self.0.bloom_filter
+    }
+
     #[getter]
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn tenant_subgraphs(&self) -> Option<bool> {
+        self.0.tenant_subgraphs
+    }
+
+    #[getter]
+    pub fn tenant_subgraphs_max_points(&self) -> Option<usize> {
+        self.0.tenant_subgraphs_max_points
+    }
 }
 
 impl PyKeywordIndexParams {
@@ -200,8 +248,15 @@ impl PyKeywordIndexParams {
         let KeywordIndexParams {
             r#type: _, // not relevant for Qdrant Edge
             is_tenant: _,
+            ascii_folding: _,
+            unicode_normalization: _,
+            lowercase: _,
             on_disk: _,
+            populate: _,
+            bloom_filter: _,
             enable_hnsw: _,
+            tenant_subgraphs: _,
+            tenant_subgraphs_max_points: _,
         } = self.0;
     }
 }
@@ -234,10 +289,25 @@ impl PyIntegerIndexParams {
         self.0.on_disk
     }
 
+    #[getter]
+    pub fn populate(&self) -> Option<bool> {
+        self.0.populate
+    }
+
     #[getter]
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn histogram_buckets(&self) -> Option<usize> {
+        self.0.histogram_buckets
+    }
+
+    #[getter]
+    pub fn histogram_max_bucket_size(&self) -> Option<usize> {
+        self.0.histogram_max_bucket_size
+    }
 }
 
 impl PyIntegerIndexParams {
@@ -249,7 +319,10 @@ impl PyIntegerIndexParams {
             range: _,
             is_principal: _,
             on_disk: _,
+            populate: _,
             enable_hnsw: _,
+            histogram_buckets: _,
+            histogram_max_bucket_size: _,
         } = self.0;
     }
 }
@@ -272,10 +345,25 @@ impl PyFloatIndexParams {
         self.0.on_disk
     }
 
+    #[getter]
+    pub fn populate(&self) -> Option<bool> {
+        self.0.populate
+    }
+
     #[getter]
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn histogram_buckets(&self) -> Option<usize> {
+        self.0.histogram_buckets
+    }
+
+    #[getter]
+    pub fn histogram_max_bucket_size(&self) -> Option<usize> {
+        self.0.histogram_max_bucket_size
+    }
 }
 
 impl PyFloatIndexParams {
@@ -285,7 +373,10 @@ impl PyFloatIndexParams {
             r#type: _, // not relevant for Qdrant Edge
             is_principal: _,
             on_disk: _,
+            populate: _,
             enable_hnsw: _,
+            histogram_buckets: _,
+            histogram_max_bucket_size: _,
         } = self.0;
     }
 }
@@ -303,10 +394,25 @@ impl PyGeoIndexParams {
         self.0.on_disk
     }
 
+    #[getter]
+    pub fn populate(&self) -> Option<bool> {
+        self.0.populate
+    }
+
     #[getter]
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn precision(&self) -> Option<usize> {
+        self.0.precision
+    }
+
+    #[getter]
+    pub fn max_query_cells(&self) -> Option<usize> {
+        self.0.max_query_cells
+    }
 }
 
 impl PyGeoIndexParams {
@@ -315,7 +421,10 @@ impl PyGeoIndexParams {
         let GeoIndexParams {
             r#type: _, // not relevant for Qdrant Edge
             on_disk: _,
+            populate: _,
             enable_hnsw: _,
+            precision: _,
+            max_query_cells: _,
         } = self.0;
     }
 }
@@ -333,6 +442,11 @@ impl PyBoolIndexParams {
         self.0.on_disk
     }
 
+    #[getter]
+    pub fn populate(&self) -> Option<bool> {
+        self.0.populate
+    }
+
     #[getter]
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
@@ -345,6 +459,7 @@ impl PyBoolIndexParams {
         let BoolIndexParams {
             r#type: _, // not relevant for Qdrant Edge
             on_disk: _,
+            populate: _,
             enable_hnsw: _,
         } = self.0;
     }
@@ -368,10 +483,25 @@ impl PyDatetimeIndexParams {
         self.0.on_disk
     }
 
+    #[getter]
+    pub fn populate(&self) -> Option<bool> {
+        self.0.populate
+    }
+
     #[getter]
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn histogram_buckets(&self) -> Option<usize> {
+        self.0.histogram_buckets
+    }
+
+    #[getter]
+    pub fn histogram_max_bucket_size(&self) -> Option<usize> {
+        self.0.histogram_max_bucket_size
+    }
 }
 
 impl PyDatetimeIndexParams {
@@ -381,7 +511,10 @@ impl PyDatetimeIndexParams {
             r#type: _, // not relevant for Qdrant Edge
             is_principal: _,
             on_disk: _,
+            populate: _,
             enable_hnsw: _,
+            histogram_buckets: _,
+            histogram_max_bucket_size: _,
         } = self.0;
     }
 }
@@ -404,10 +537,25 @@ impl PyUuidIndexParams {
         self.0.on_disk
     }
 
+    #[getter]
+    pub fn populate(&self) -> Option<bool> {
+        self.0.populate
+    }
+
     #[getter]
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn tenant_subgraphs(&self) -> Option<bool> {
+        self.0.tenant_subgraphs
+    }
+
+    #[getter]
+    pub fn tenant_subgraphs_max_points(&self) -> Option<usize> {
+        self.0.tenant_subgraphs_max_points
+    }
 }
 
 impl PyUuidIndexParams {
@@ -417,6 +565,33 @@ impl PyUuidIndexParams {
             r#type: _, // not relevant for Qdrant Edge
             is_tenant: _,
             on_disk: _,
+            populate: _,
+            enable_hnsw: _,
+            tenant_subgraphs: _,
+            tenant_subgraphs_max_points: _,
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "GeoShapeIndexParams", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+struct PyGeoShapeIndexParams(GeoShapeIndexParams);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyGeoShapeIndexParams {
+    #[getter]
+    pub fn enable_hnsw(&self) -> Option<bool> {
+        self.0.enable_hnsw
+    }
+}
+
+impl PyGeoShapeIndexParams {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let GeoShapeIndexParams {
+            r#type: _, // not relevant for Qdrant Edge
             enable_hnsw: _,
         } = self.0;
     }
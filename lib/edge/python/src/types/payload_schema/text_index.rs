@@ -42,6 +42,11 @@ impl PyTextIndexParams {
         self.0.ascii_folding
     }
 
+    #[getter]
+    pub fn unicode_normalization(&self) -> Option<bool> {
+        self.0.unicode_normalization
+    }
+
     #[getter]
     pub fn phrase_matching(&self) -> Option<bool> {
         self.0.phrase_matching
@@ -52,16 +57,52 @@ impl PyTextIndexParams {
         self.0.stopwords.as_ref().map(PyStopwords::wrap_ref)
     }
 
+    #[getter]
+    pub fn synonyms(&self) -> Option<&PySynonymsParams> {
+        self.0.synonyms.as_ref().map(PySynonymsParams::wrap_ref)
+    }
+
+    #[getter]
+    pub fn shingles(&self) -> Option<bool> {
+        self.0.shingles
+    }
+
+    #[getter]
+    pub fn max_document_frequency(&self) -> Option<f64> {
+        self.0.max_document_frequency.map(|ratio| ratio.0)
+    }
+
+    #[getter]
+    pub fn filters(&self) -> Option<Vec<PyTokenFilter>> {
+        let filters = self.0.filters.as_ref()?;
+        Some(filters.iter().copied().map(PyTokenFilter::from).collect())
+    }
+
     #[getter]
     pub fn on_disk(&self) -> Option<bool> {
         self.0.on_disk
     }
 
+    #[getter]
+    pub fn populate(&self) -> Option<bool> {
+        self.0.populate
+    }
+
     #[getter]
     pub fn stemmer(&self) -> Option<&PyStemmingAlgorithm> {
         self.0.stemmer.as_ref().map(PyStemmingAlgorithm::wrap_ref)
     }
 
+    #[getter]
+    pub fn language_detection(&self) -> Option<bool> {
+        self.0.language_detection
+    }
+
+    #[getter]
+    pub fn detected_language_key(&self) -> Option<&str> {
+        self.0.detected_language_key.as_deref()
+    }
+
     #[getter]
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
@@ -78,10 +119,18 @@ impl PyTextIndexParams {
             max_token_len: _,
             lowercase: _,
             ascii_folding: _,
+            unicode_normalization: _,
             phrase_matching: _,
             stopwords: _,
+            synonyms: _,
+            shingles: _,
+            max_document_frequency: _,
+            filters: _,
             on_disk: _,
+            populate: _,
             stemmer: _,
+            language_detection: _,
+            detected_language_key: _,
             enable_hnsw: _,
         } = self.0;
     }
@@ -94,6 +143,7 @@ pub enum PyTokenizerType {
     Whitespace,
     Word,
     Multilingual,
+    Cjk,
 }
 
 impl Repr for PyTokenizerType {
@@ -103,6 +153,7 @@ impl Repr for PyTokenizerType {
             Self::Whitespace => "Whitespace",
             Self::Word => "Word",
             Self::Multilingual => "Multilingual",
+            Self::Cjk => "Cjk",
         };
 
         f.simple_enum::<Self>(repr)
@@ -116,6 +167,7 @@ impl From<TokenizerType> for PyTokenizerType {
             TokenizerType::Whitespace => PyTokenizerType::Whitespace,
             TokenizerType::Word => PyTokenizerType::Word,
             TokenizerType::Multilingual => PyTokenizerType::Multilingual,
+            TokenizerType::Cjk => PyTokenizerType::Cjk,
         }
     }
 }
@@ -127,6 +179,55 @@ impl From<PyTokenizerType> for TokenizerType {
             PyTokenizerType::Whitespace => TokenizerType::Whitespace,
             PyTokenizerType::Word => TokenizerType::Word,
             PyTokenizerType::Multilingual => TokenizerType::Multilingual,
+            PyTokenizerType::Cjk => TokenizerType::Cjk,
+        }
+    }
+}
+
+#[pyclass(name = "TokenFilter", from_py_object)]
+#[derive(Copy, Clone, Debug)]
+pub enum PyTokenFilter {
+    Lowercase,
+    AsciiFolding,
+    UnicodeNormalization,
+    Stopwords,
+    Stemmer,
+}
+
+impl Repr for PyTokenFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::Lowercase => "Lowercase",
+            Self::AsciiFolding => "AsciiFolding",
+            Self::UnicodeNormalization => "UnicodeNormalization",
+            Self::Stopwords => "Stopwords",
+            Self::Stemmer => "Stemmer",
+        };
+
+        f.simple_enum::<Self>(repr)
+    }
+}
+
+impl From<TokenFilter> for PyTokenFilter {
+    fn from(filter: TokenFilter) -> Self {
+        match filter {
+            TokenFilter::Lowercase => PyTokenFilter::Lowercase,
+            TokenFilter::AsciiFolding => PyTokenFilter::AsciiFolding,
+            TokenFilter::UnicodeNormalization => PyTokenFilter::UnicodeNormalization,
+            TokenFilter::Stopwords => PyTokenFilter::Stopwords,
+            TokenFilter::Stemmer => PyTokenFilter::Stemmer,
+        }
+    }
+}
+
+impl From<PyTokenFilter> for TokenFilter {
+    fn from(filter: PyTokenFilter) -> Self {
+        match filter {
+            PyTokenFilter::Lowercase => TokenFilter::Lowercase,
+            PyTokenFilter::AsciiFolding => TokenFilter::AsciiFolding,
+            PyTokenFilter::UnicodeNormalization => TokenFilter::UnicodeNormalization,
+            PyTokenFilter::Stopwords => TokenFilter::Stopwords,
+            PyTokenFilter::Stemmer => TokenFilter::Stemmer,
         }
     }
 }
@@ -383,6 +484,51 @@ impl<'py> IntoPyObject<'py> for &PyStopwordsSet {
     }
 }
 
+#[pyclass(name = "SynonymsParams", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PySynonymsParams(SynonymsParams);
+
+#[pyclass_repr]
+#[pymethods]
+impl PySynonymsParams {
+    #[getter]
+    pub fn groups(&self) -> &[Vec<String>] {
+        &self.0.groups
+    }
+
+    #[getter]
+    pub fn file(&self) -> Option<&str> {
+        self.0.file.as_deref()
+    }
+
+    #[getter]
+    pub fn expand_on_index(&self) -> Option<bool> {
+        self.0.expand_on_index
+    }
+}
+
+impl PySynonymsParams {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let SynonymsParams {
+            groups: _,
+            file: _,
+            expand_on_index: _,
+        } = self.0;
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &PySynonymsParams {
+    type Target = PySynonymsParams;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        IntoPyObject::into_pyobject(self.clone(), py)
+    }
+}
+
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
 pub struct PyStemmingAlgorithm(StemmingAlgorithm);
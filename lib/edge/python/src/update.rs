@@ -54,6 +54,8 @@ impl PyUpdateOperation {
                     points_op: points,
                     condition: Filter::from(condition),
                     update_mode: mode,
+                    sequence_key: None,
+                    skip_normalization: None,
                 },
             ),
             (None, Some(mode)) => point_ops::PointOperations::UpsertPointsConditional(
@@ -61,6 +63,8 @@ impl PyUpdateOperation {
                     points_op: points,
                     condition: Filter::default(),
                     update_mode: Some(mode),
+                    sequence_key: None,
+                    skip_normalization: None,
                 },
             ),
             // Default case: regular upsert
@@ -96,6 +100,17 @@ impl PyUpdateOperation {
         Self(CollectionUpdateOperations::VectorOperation(operation))
     }
 
+    #[staticmethod]
+    #[pyo3(signature = (point_vectors, condition=None))]
+    pub fn append_vectors(point_vectors: Vec<PyPointVectors>, condition: Option<PyFilter>) -> Self {
+        let operation = vector_ops::VectorOperations::AppendVectors(vector_ops::UpdateVectorsOp {
+            points: PyPointVectors::peel_vec(point_vectors),
+            update_filter: condition.map(Filter::from),
+        });
+
+        Self(CollectionUpdateOperations::VectorOperation(operation))
+    }
+
     #[staticmethod]
     pub fn delete_vectors(point_ids: Vec<PyPointId>, vector_names: Vec<VectorNameBuf>) -> Self {
         let operation = vector_ops::VectorOperations::DeleteVectors(
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 
 use segment::types::{PayloadIndexInfo, PayloadKeyType};
 
@@ -36,10 +37,12 @@ impl EdgeShard {
             indexed_vectors_count += segment_info.num_indexed_vectors;
 
             for (payload_key, payload_index) in segment_info.index_schema {
-                payload_schema
-                    .entry(payload_key)
-                    .and_modify(|total: &mut PayloadIndexInfo| total.points += payload_index.points)
-                    .or_insert(payload_index);
+                match payload_schema.entry(payload_key) {
+                    Entry::Occupied(mut entry) => entry.get_mut().merge(payload_index),
+                    Entry::Vacant(entry) => {
+                        entry.insert(payload_index);
+                    }
+                }
             }
         }
 
@@ -12,11 +12,14 @@ use segment::common::score_fusion::{ScoreFusion, score_fusion};
 use segment::data_types::query_context::FormulaContext;
 use segment::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use segment::types::{
-    Filter, HasIdCondition, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    Distance, Filter, HasIdCondition, Order, ScoredPoint, WithPayload, WithPayloadInterface,
+    WithVector,
 };
 use shard::query::mmr::mmr_from_points_with_vector;
 use shard::query::planned_query::*;
+use shard::query::query_context::collect_text_score_stats;
 use shard::query::scroll::{QueryScrollRequestInternal, ScrollOrder};
+use shard::query::vector_selection::{VectorSelectionInternal, merge_scoring_passes};
 use shard::query::*;
 use shard::retrieve::retrieve_blocking::retrieve_blocking;
 use shard::search::CoreSearchRequest;
@@ -271,9 +274,35 @@ impl EdgeShard {
             },
 
             ScoringQuery::Mmr(mmr) => self.mmr_rescore(sources, mmr, limit, hw_counter_acc),
+
+            ScoringQuery::VectorSelection(selection) => {
+                let order = self.vector_selection_order(&selection)?;
+                Ok(merge_scoring_passes(sources, order, limit))
+            }
         }
     }
 
+    /// Order of the scores, merged from all vectors of the selection
+    fn vector_selection_order(
+        &self,
+        selection: &VectorSelectionInternal,
+    ) -> OperationResult<Order> {
+        let distances: Vec<_> = selection
+            .selectable_vectors()
+            .map(|vector_name| {
+                if let Some(vector_data_config) = self.config.vector_data.get(vector_name) {
+                    Ok(vector_data_config.distance)
+                } else if self.config.sparse_vector_data.contains_key(vector_name) {
+                    Ok(Distance::Dot)
+                } else {
+                    Err(OperationError::vector_name_not_exists(vector_name))
+                }
+            })
+            .collect::<OperationResult<_>>()?;
+
+        selection.order(distances)
+    }
+
     fn fusion_rescore(
         sources: Vec<Vec<ScoredPoint>>,
         fusion: FusionInternal,
@@ -311,12 +340,23 @@ impl EdgeShard {
         score_threshold: Option<ScoreType>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> OperationResult<Vec<ScoredPoint>> {
+        let is_stopped = Arc::new(AtomicBool::new(false));
+
+        let text_score_stats = collect_text_score_stats(
+            &formula.text_scores,
+            self.segments.clone(),
+            DEFAULT_EDGE_TIMEOUT,
+            &is_stopped,
+            &hw_measurement_acc,
+        )?;
+
         let ctx = FormulaContext {
             formula,
             prefetches_results,
             limit,
             score_threshold,
-            is_stopped: Arc::new(AtomicBool::new(false)),
+            is_stopped,
+            text_score_stats,
         };
 
         let ctx = Arc::new(ctx);
@@ -29,6 +29,9 @@ impl EdgeShard {
             with_payload,
             with_vector,
             order_by,
+            // Segments of an edge shard are not replaced by background optimizations
+            consistent: _,
+            view_id: _,
         } = request;
 
         let limit = limit.unwrap_or(ScrollRequestInternal::default_limit());
@@ -205,27 +208,39 @@ impl EdgeShard {
             })
             .collect::<Result<_, _>>()?;
 
-        let (order_values, point_ids): (Vec<_>, Vec<_>) = read_results
+        let merged_results = read_results
             .into_iter()
             .kmerge_by(|a, b| match order_by.direction() {
                 Direction::Asc => a <= b,
                 Direction::Desc => a >= b,
             })
-            .dedup()
-            .take(limit)
-            .unzip();
+            .dedup();
+
+        let (order_values, point_ids): (Vec<_>, Vec<_>) = if order_by.then_by.is_empty() {
+            merged_results.take(limit).unzip()
+        } else {
+            OrderBy::take_with_ties(merged_results, limit, |(value, _)| Some(*value))
+                .into_iter()
+                .unzip()
+        };
+
+        let with_payload = if order_by.then_by.is_empty() {
+            WithPayload::from(with_payload_interface)
+        } else {
+            WithPayload::from(order_by.with_then_by_payload(with_payload_interface))
+        };
 
         let points = retrieve_blocking(
             self.segments.clone(),
             &point_ids,
-            &WithPayload::from(with_payload_interface),
+            &with_payload,
             with_vector,
             DEFAULT_EDGE_TIMEOUT,
             &AtomicBool::new(false),
             hw_measurement_acc,
         )?;
 
-        let ordered_points = point_ids
+        let ordered_points: Vec<_> = point_ids
             .iter()
             .zip(order_values)
             .filter_map(|(point_id, value)| {
@@ -235,6 +250,22 @@ impl EdgeShard {
             })
             .collect();
 
+        if order_by.then_by.is_empty() {
+            return Ok(ordered_points);
+        }
+
+        let with_payload = WithPayload::from(with_payload_interface);
+        let ordered_points = order_by
+            .sort_by_then_by(ordered_points, limit, |record| {
+                (record.order_value, record.id, record.payload.as_ref())
+            })
+            .into_iter()
+            .map(|mut record| {
+                record.payload = with_payload.select(record.payload.take());
+                record
+            })
+            .collect();
+
         Ok(ordered_points)
     }
 
@@ -74,7 +74,9 @@ impl EdgeShard {
                 filter.as_ref(),
                 offset + limit,
                 params.as_ref(),
-                &context.get_segment_query_context(),
+                &context
+                    .get_segment_query_context()
+                    .with_score_threshold(score_threshold),
             )?;
 
             debug_assert_eq!(batched_points.len(), 1);
@@ -0,0 +1,97 @@
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::types::{CollectionInfo, UpdateResult};
+use segment::json_path::JsonPath;
+use segment::types::PayloadFieldSchema;
+use shard::operations::{CollectionUpdateOperations, CreateIndex, FieldIndexOperations};
+use storage::content_manager::collection_meta_ops::{
+    CollectionMetaOperations, CreateCollection, CreateCollectionOperation, CreatePayloadIndex,
+    DeleteCollectionOperation,
+};
+use storage::content_manager::errors::StorageError;
+use storage::rbac::AccessRequirements;
+
+use crate::{EmbeddedQdrant, FULL_ACCESS};
+
+impl EmbeddedQdrant {
+    /// Create a new collection, returns `false` if it was not created
+    pub fn create_collection(
+        &self,
+        collection_name: &str,
+        create_collection: CreateCollection,
+    ) -> Result<bool, StorageError> {
+        let operation = CollectionMetaOperations::CreateCollection(CreateCollectionOperation::new(
+            collection_name.to_string(),
+            create_collection,
+        )?);
+        self.block_on(
+            self.dispatcher
+                .submit_collection_meta_op(operation, FULL_ACCESS, None),
+        )
+    }
+
+    /// Delete the collection with all its points, returns `false` if it didn't exist
+    pub fn delete_collection(&self, collection_name: &str) -> Result<bool, StorageError> {
+        let operation = CollectionMetaOperations::DeleteCollection(DeleteCollectionOperation(
+            collection_name.to_string(),
+        ));
+        self.block_on(
+            self.dispatcher
+                .submit_collection_meta_op(operation, FULL_ACCESS, None),
+        )
+    }
+
+    /// Names of all collections
+    pub fn list_collections(&self) -> Vec<String> {
+        let auth = FULL_ACCESS;
+        self.block_on(self.toc.all_collections(auth.access("list_collections")))
+            .into_iter()
+            .map(|pass| pass.name().to_string())
+            .collect()
+    }
+
+    pub fn collection_info(&self, collection_name: &str) -> Result<CollectionInfo, StorageError> {
+        let collection_pass = FULL_ACCESS.check_collection_access(
+            collection_name,
+            AccessRequirements::new(),
+            "get_collection",
+        )?;
+
+        self.block_on(async {
+            let collection = self.toc.get_collection(&collection_pass).await?;
+            Ok(collection.info(&ShardSelectorInternal::All).await?)
+        })
+    }
+
+    /// Create a payload index on the field and wait until existing points are indexed
+    pub fn create_payload_index(
+        &self,
+        collection_name: &str,
+        field_name: JsonPath,
+        field_schema: PayloadFieldSchema,
+    ) -> Result<UpdateResult, StorageError> {
+        let operation = CollectionMetaOperations::CreatePayloadIndex(CreatePayloadIndex {
+            collection_name: collection_name.to_string(),
+            field_name: field_name.clone(),
+            field_schema: field_schema.clone(),
+        });
+
+        self.block_on(async {
+            self.dispatcher
+                .submit_collection_meta_op(operation, FULL_ACCESS, None)
+                .await?;
+
+            // Index existing points, like the `wait` parameter of the service API does
+            self.update(
+                collection_name,
+                CollectionUpdateOperations::FieldIndexOperation(FieldIndexOperations::CreateIndex(
+                    CreateIndex {
+                        field_name,
+                        field_schema: Some(field_schema),
+                    },
+                )),
+                ShardSelectorInternal::All,
+            )
+            .await
+        })
+    }
+}
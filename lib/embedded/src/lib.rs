@@ -0,0 +1,277 @@
+//! Qdrant storage embedded into the application process, without HTTP and gRPC servers.
+//!
+//! [`EmbeddedQdrant`] owns a [`TableOfContent`] and exposes collection and point operations
+//! with a small blocking, client-like API. It is meant for desktop applications and tests,
+//! which need the full collection engine (optimizers, payload indexes, universal query)
+//! but not a network service.
+//!
+//! ```no_run
+//! use embedded::EmbeddedQdrant;
+//!
+//! let qdrant = EmbeddedQdrant::open("./storage").unwrap();
+//! for collection in qdrant.list_collections() {
+//!     println!("{collection}");
+//! }
+//! ```
+//!
+//! Embedded storage runs as a single node, distributed deployments are not supported.
+//! Methods block the current thread, so they must not be called from async code.
+
+pub mod collections;
+pub mod points;
+
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use collection::optimizers_builder::OptimizersConfig;
+use collection::shards::channel_service::ChannelService;
+use common::budget::ResourceBudget;
+use common::load_concurrency::LoadConcurrencyConfig;
+use memory::madvise;
+use storage::content_manager::errors::StorageError;
+use storage::content_manager::toc::TableOfContent;
+use storage::dispatcher::Dispatcher;
+use storage::rbac::{Access, Auth};
+use storage::types::{PerformanceConfig, StorageConfig};
+use tokio::runtime::{self, Runtime};
+
+/// Peer ID of the single embedded node
+const EMBEDDED_PEER_ID: u64 = 0;
+
+/// REST port of the node, never listened to by embedded storage
+const UNUSED_REST_PORT: u16 = 6333;
+
+/// The embedding application has full access to its storage
+const FULL_ACCESS: Auth = Auth::new_internal(Access::full("Embedded"));
+
+pub struct EmbeddedQdrant {
+    toc: Arc<TableOfContent>,
+    dispatcher: Dispatcher,
+}
+
+impl EmbeddedQdrant {
+    /// Open storage in `storage_path` with default configuration, creating it if it doesn't exist
+    pub fn open(storage_path: impl AsRef<Path>) -> Result<Self, StorageError> {
+        Self::open_with_config(default_storage_config(storage_path.as_ref()))
+    }
+
+    /// Open storage with custom configuration, creating it if it doesn't exist.
+    ///
+    /// Use [`default_storage_config`] as a base for the configuration.
+    pub fn open_with_config(config: StorageConfig) -> Result<Self, StorageError> {
+        let search_threads =
+            common::defaults::search_thread_count(config.performance.max_search_threads);
+        let num_cpus = common::cpu::get_num_cpus();
+
+        let toc = TableOfContent::new(
+            &config,
+            create_runtime("embedded-search", search_threads)?,
+            create_runtime("embedded-update", num_cpus)?,
+            create_runtime("embedded-general", num_cpus.max(2))?,
+            ResourceBudget::default(),
+            ChannelService::new(UNUSED_REST_PORT, false, None, None),
+            EMBEDDED_PEER_ID,
+            None,
+        )?;
+
+        toc.clear_all_tmp_directories()?;
+
+        let toc = Arc::new(toc);
+        let dispatcher = Dispatcher::new(toc.clone());
+
+        Ok(Self { toc, dispatcher })
+    }
+
+    /// Underlying table of content, for operations not covered by this API
+    pub fn toc(&self) -> &Arc<TableOfContent> {
+        &self.toc
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.toc.general_runtime_handle().block_on(future)
+    }
+}
+
+/// Default storage configuration, same as in `config/config.yaml` of the Qdrant service
+pub fn default_storage_config(storage_path: &Path) -> StorageConfig {
+    StorageConfig {
+        storage_path: storage_path.to_path_buf(),
+        snapshots_path: storage_path.join("snapshots"),
+        snapshots_config: Default::default(),
+        temp_path: None,
+        on_disk_payload: true,
+        optimizers: OptimizersConfig {
+            deleted_threshold: 0.2,
+            vacuum_min_vector_number: 1000,
+            default_segment_number: 0,
+            max_segment_size: None,
+            #[expect(deprecated)]
+            memmap_threshold: None,
+            indexing_threshold: Some(10_000),
+            flush_interval_sec: 5,
+            max_optimization_threads: None,
+            prevent_unoptimized: None,
+            max_segment_number: None,
+        },
+        optimizers_overwrite: None,
+        wal: Default::default(),
+        performance: PerformanceConfig {
+            max_search_threads: 0,
+            max_optimization_runtime_threads: 0,
+            optimizer_cpu_budget: 0,
+            optimizer_io_budget: 0,
+            update_rate_limit: None,
+            search_timeout_sec: None,
+            incoming_shard_transfers_limit: None,
+            outgoing_shard_transfers_limit: None,
+            async_scorer: None,
+            load_concurrency: LoadConcurrencyConfig::default(),
+            dedicated_search_threads: Default::default(),
+            adaptive_search_concurrency: false,
+            coalesce_identical_queries: false,
+        },
+        hnsw_index: Default::default(),
+        hnsw_global_config: Default::default(),
+        mmap_advice: madvise::Advice::Random,
+        node_type: Default::default(),
+        update_queue_size: None,
+        handle_collection_load_errors: false,
+        recovery_mode: None,
+        update_concurrency: None,
+        shard_transfer_method: None,
+        collection: None,
+        max_collections: None,
+        collection_quota: Default::default(),
+        score_drift: Default::default(),
+        wal_backend: Default::default(),
+    }
+}
+
+fn create_runtime(name: &'static str, threads: usize) -> Result<Runtime, StorageError> {
+    runtime::Builder::new_multi_thread()
+        .worker_threads(threads)
+        .enable_all()
+        .thread_name_fn(move || {
+            static ATOMIC_ID: AtomicUsize = AtomicUsize::new(0);
+            let id = ATOMIC_ID.fetch_add(1, Ordering::SeqCst);
+            format!("{name}-{id}")
+        })
+        .build()
+        .map_err(|err| {
+            StorageError::service_error(format!("failed to create {name} runtime: {err}"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use collection::operations::universal_query::collection_query::{
+        CollectionQueryRequest, Query, VectorInputInternal, VectorQuery,
+    };
+    use collection::operations::vector_params_builder::VectorParamsBuilder;
+    use segment::data_types::vectors::VectorInternal;
+    use segment::types::{Distance, PointIdType, WithPayloadInterface};
+    use shard::operations::point_ops::{PointStructPersisted, VectorStructPersisted};
+    use storage::content_manager::collection_meta_ops::CreateCollection;
+    use tempfile::Builder;
+
+    use super::*;
+
+    const COLLECTION: &str = "test";
+
+    fn create_collection() -> CreateCollection {
+        CreateCollection {
+            vectors: VectorParamsBuilder::new(4, Distance::Dot).build().into(),
+            sparse_vectors: None,
+            hnsw_config: None,
+            wal_config: None,
+            optimizers_config: None,
+            shard_number: None,
+            on_disk_payload: None,
+            replication_factor: None,
+            write_consistency_factor: None,
+            quantization_config: None,
+            sharding_method: None,
+            strict_mode_config: None,
+            uuid: None,
+            metadata: None,
+            placement: None,
+        }
+    }
+
+    fn point(id: u64, vector: [f32; 4]) -> PointStructPersisted {
+        PointStructPersisted {
+            id: PointIdType::NumId(id),
+            vector: VectorStructPersisted::Single(vector.to_vec()),
+            payload: None,
+        }
+    }
+
+    #[test]
+    fn test_embedded_storage() {
+        let dir = Builder::new().prefix("storage").tempdir().unwrap();
+
+        let qdrant = EmbeddedQdrant::open(dir.path()).unwrap();
+        assert!(
+            qdrant
+                .create_collection(COLLECTION, create_collection())
+                .unwrap()
+        );
+        assert_eq!(qdrant.list_collections(), vec![COLLECTION.to_string()]);
+
+        qdrant
+            .upsert(
+                COLLECTION,
+                vec![
+                    point(1, [1.0, 0.0, 0.0, 0.0]),
+                    point(2, [0.0, 1.0, 0.0, 0.0]),
+                    point(3, [0.0, 0.0, 1.0, 0.0]),
+                ],
+            )
+            .unwrap();
+        assert_eq!(qdrant.count(COLLECTION, None).unwrap(), 3);
+
+        let results = qdrant
+            .query(
+                COLLECTION,
+                CollectionQueryRequest {
+                    prefetch: vec![],
+                    query: Some(Query::Vector(VectorQuery::Nearest(
+                        VectorInputInternal::Vector(VectorInternal::from(vec![0.0, 1.0, 0.0, 0.0])),
+                    ))),
+                    using: String::new(),
+                    filter: None,
+                    score_threshold: None,
+                    limit: 1,
+                    offset: 0,
+                    params: None,
+                    with_vector: CollectionQueryRequest::DEFAULT_WITH_VECTOR,
+                    with_payload: WithPayloadInterface::Bool(false),
+                    lookup_from: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, PointIdType::NumId(2));
+
+        qdrant
+            .delete_points(COLLECTION, vec![PointIdType::NumId(1)])
+            .unwrap();
+        drop(qdrant);
+
+        // Collections and points are persisted
+        let qdrant = EmbeddedQdrant::open(dir.path()).unwrap();
+        assert_eq!(qdrant.count(COLLECTION, None).unwrap(), 2);
+        let records = qdrant
+            .retrieve(
+                COLLECTION,
+                vec![PointIdType::NumId(1), PointIdType::NumId(2)],
+            )
+            .unwrap();
+        assert_eq!(records.len(), 1);
+
+        assert!(qdrant.delete_collection(COLLECTION).unwrap());
+        assert!(qdrant.list_collections().is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+use collection::operations::point_ops::WriteOrdering;
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::types::{PointRequestInternal, ScrollResult, UpdateResult};
+use collection::operations::universal_query::collection_query::CollectionQueryRequest;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::types::{Filter, PointIdType, ScoredPoint, WithPayloadInterface, WithVector};
+use shard::count::CountRequestInternal;
+use shard::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted,
+};
+use shard::operations::{CollectionUpdateOperations, OperationWithClockTag};
+use shard::retrieve::record_internal::RecordInternal;
+use shard::scroll::ScrollRequestInternal;
+use storage::content_manager::errors::StorageError;
+
+use crate::{EmbeddedQdrant, FULL_ACCESS};
+
+impl EmbeddedQdrant {
+    /// Insert or update points, waiting until the change is applied
+    pub fn upsert(
+        &self,
+        collection_name: &str,
+        points: Vec<PointStructPersisted>,
+    ) -> Result<UpdateResult, StorageError> {
+        let operation = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+            PointInsertOperationsInternal::PointsList(points),
+        ));
+        self.block_on(self.update(collection_name, operation, ShardSelectorInternal::Empty))
+    }
+
+    /// Delete points, waiting until the change is applied
+    pub fn delete_points(
+        &self,
+        collection_name: &str,
+        ids: Vec<PointIdType>,
+    ) -> Result<UpdateResult, StorageError> {
+        let operation =
+            CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints { ids });
+        self.block_on(self.update(collection_name, operation, ShardSelectorInternal::Empty))
+    }
+
+    /// Delete points matching the filter, waiting until the change is applied
+    pub fn delete_points_by_filter(
+        &self,
+        collection_name: &str,
+        filter: Filter,
+    ) -> Result<UpdateResult, StorageError> {
+        let operation = CollectionUpdateOperations::PointOperation(
+            PointOperations::DeletePointsByFilter(filter),
+        );
+        self.block_on(self.update(collection_name, operation, ShardSelectorInternal::Empty))
+    }
+
+    /// Retrieve existing points by IDs, with payload and without vectors
+    pub fn retrieve(
+        &self,
+        collection_name: &str,
+        ids: Vec<PointIdType>,
+    ) -> Result<Vec<RecordInternal>, StorageError> {
+        let request = PointRequestInternal {
+            ids,
+            with_payload: Some(WithPayloadInterface::Bool(true)),
+            with_vector: WithVector::Bool(false),
+        };
+
+        self.block_on(self.toc.retrieve(
+            collection_name,
+            request,
+            None,
+            None,
+            ShardSelectorInternal::All,
+            FULL_ACCESS,
+            HwMeasurementAcc::disposable(),
+        ))
+    }
+
+    pub fn scroll(
+        &self,
+        collection_name: &str,
+        request: ScrollRequestInternal,
+    ) -> Result<ScrollResult, StorageError> {
+        self.block_on(self.toc.scroll(
+            collection_name,
+            request,
+            None,
+            None,
+            ShardSelectorInternal::All,
+            FULL_ACCESS,
+            HwMeasurementAcc::disposable(),
+        ))
+    }
+
+    /// Exact number of points matching the filter
+    pub fn count(
+        &self,
+        collection_name: &str,
+        filter: Option<Filter>,
+    ) -> Result<usize, StorageError> {
+        let request = CountRequestInternal {
+            filter,
+            exact: true,
+        };
+
+        let result = self.block_on(self.toc.count(
+            collection_name,
+            request,
+            None,
+            None,
+            ShardSelectorInternal::All,
+            FULL_ACCESS,
+            HwMeasurementAcc::disposable(),
+        ))?;
+
+        Ok(result.count)
+    }
+
+    /// Universal query, see [`CollectionQueryRequest`]
+    pub fn query(
+        &self,
+        collection_name: &str,
+        request: CollectionQueryRequest,
+    ) -> Result<Vec<ScoredPoint>, StorageError> {
+        let mut results = self.block_on(self.toc.query_batch(
+            collection_name,
+            vec![(request, ShardSelectorInternal::All)],
+            None,
+            FULL_ACCESS,
+            None,
+            HwMeasurementAcc::disposable(),
+        ))?;
+
+        Ok(results.pop().unwrap_or_default())
+    }
+
+    pub(crate) async fn update(
+        &self,
+        collection_name: &str,
+        operation: CollectionUpdateOperations,
+        shard_selector: ShardSelectorInternal,
+    ) -> Result<UpdateResult, StorageError> {
+        self.toc
+            .update(
+                collection_name,
+                OperationWithClockTag::from(operation),
+                true,
+                None,
+                WriteOrdering::default(),
+                shard_selector,
+                FULL_ACCESS,
+                HwMeasurementAcc::disposable(),
+            )
+            .await
+    }
+}
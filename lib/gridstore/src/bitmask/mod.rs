@@ -237,6 +237,16 @@ impl Bitmask {
         bitslice.count_zeros() - bitslice.trailing_zeros()
     }
 
+    /// The amount of blocks covered by the bitmask, used or not.
+    pub(crate) fn total_blocks(&self) -> usize {
+        self.bitslice.len()
+    }
+
+    /// The amount of free blocks, which are followed by used ones in the storage.
+    pub(crate) fn fragmented_blocks(&self) -> usize {
+        self.bitslice.count_zeros() - self.trailing_free_blocks() as usize
+    }
+
     pub(crate) fn find_available_blocks(&self, num_blocks: u32) -> Option<(PageId, BlockOffset)> {
         let region_id_range = self.regions_gaps.find_fitting_gap(num_blocks)?;
         let regions_start_offset = region_id_range.start as usize * self.config.region_size_blocks;
@@ -1,6 +1,6 @@
 use std::cmp::min;
 use std::io::BufReader;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use common::counter::hardware_counter::HardwareCounterCell;
@@ -23,8 +23,33 @@ use crate::tracker::{BlockOffset, PageId, PointOffset, Tracker, ValuePointer};
 
 const CONFIG_FILENAME: &str = "config.json";
 
+/// Suffix of the directory, values are copied to during compaction
+const COMPACTION_SUFFIX: &str = ".compaction";
+
 pub type Flusher = Box<dyn FnOnce() -> std::result::Result<(), GridstoreError> + Send>;
 
+/// Usage of the space allocated by the storage pages
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Fragmentation {
+    /// Size of all pages
+    pub allocated_bytes: usize,
+    /// Size of blocks occupied by values
+    pub used_bytes: usize,
+    /// Size of free blocks in between used ones, left by deleted and updated values
+    pub fragmented_bytes: usize,
+}
+
+impl Fragmentation {
+    /// Share of fragmented space in the space, which is used or fragmented
+    pub fn ratio(&self) -> f64 {
+        let occupied_bytes = self.used_bytes + self.fragmented_bytes;
+        if occupied_bytes == 0 {
+            return 0.0;
+        }
+        self.fragmented_bytes as f64 / occupied_bytes as f64
+    }
+}
+
 /// Storage for values of type `V`.
 ///
 /// Assumes sequential IDs to the values (0, 1, 2, 3, ...)
@@ -113,6 +138,8 @@ impl<V: Blob> Gridstore<V> {
     ///
     /// In case of opening, it ignores the `create_options` parameter.
     pub fn open_or_create(base_path: PathBuf, create_options: StorageOptions) -> Result<Self> {
+        Self::recover_compaction(&base_path)?;
+
         let config_path = base_path.join(CONFIG_FILENAME);
         if config_path.exists() {
             Self::open(base_path)
@@ -170,6 +197,8 @@ impl<V: Blob> Gridstore<V> {
     /// Open an existing storage at the given path
     /// Returns None if the storage does not exist
     pub fn open(base_path: PathBuf) -> Result<Self> {
+        Self::recover_compaction(&base_path)?;
+
         if !base_path.exists() {
             return Err(GridstoreError::service_error(format!(
                 "Path '{base_path:?}' does not exist"
@@ -212,6 +241,37 @@ impl<V: Blob> Gridstore<V> {
         Ok(storage)
     }
 
+    /// Get the path of the directory, values are copied to during compaction
+    fn compaction_path(base_path: &Path) -> PathBuf {
+        let mut path = base_path.as_os_str().to_owned();
+        path.push(COMPACTION_SUFFIX);
+        PathBuf::from(path)
+    }
+
+    /// Finish or clean up a compaction, which was interrupted by a crash.
+    ///
+    /// The compacted storage is complete once the current one starts to be removed, so it replaces
+    /// the current storage if that one has no config anymore. Otherwise it's incomplete and dropped.
+    fn recover_compaction(base_path: &Path) -> Result<()> {
+        let compaction_path = Self::compaction_path(base_path);
+        if !compaction_path.exists() {
+            return Ok(());
+        }
+
+        if base_path.join(CONFIG_FILENAME).exists() {
+            log::warn!("Removing incomplete gridstore compaction at {compaction_path:?}");
+            fs::remove_dir_all(&compaction_path)?;
+            return Ok(());
+        }
+
+        log::warn!("Finishing interrupted gridstore compaction at {base_path:?}");
+        if base_path.exists() {
+            fs::remove_dir_all(base_path)?;
+        }
+        fs::rename(&compaction_path, base_path)?;
+        Ok(())
+    }
+
     /// Get the path for a given page id
     fn page_path(&self, page_id: u32) -> PathBuf {
         self.base_path.join(format!("page_{page_id}.dat"))
@@ -586,6 +646,69 @@ impl<V: Blob> Gridstore<V> {
     pub fn get_storage_size_bytes(&self) -> usize {
         self.bitmask.read().get_storage_size_bytes()
     }
+
+    /// Rewrite all values into new pages, one after another, to give the space of fragmented
+    /// blocks back to the file system.
+    ///
+    /// Values are copied into a new storage next to the current one, which then replaces it.
+    /// Pending flushers of the current storage are cancelled, the new storage is already flushed.
+    pub fn compact(&mut self) -> Result<()> {
+        let create_options = StorageOptions::from(self.config);
+        let base_path = self.base_path.clone();
+        let compaction_path = Self::compaction_path(&base_path);
+
+        // Leftover of a failed compaction
+        if compaction_path.exists() {
+            fs::remove_dir_all(&compaction_path)?;
+        }
+        fs::create_dir_all(&compaction_path).map_err(|err| {
+            GridstoreError::service_error(format!(
+                "Failed to create gridstore compaction directory: {err}"
+            ))
+        })?;
+
+        let mut compacted = Self::new(compaction_path.clone(), create_options)?;
+
+        let hw_counter = HardwareCounterCell::disposable();
+        let hw_counter_ref = hw_counter.ref_payload_io_write_counter();
+        self.iter::<_, GridstoreError>(
+            |point_offset, value| {
+                compacted.put_value(point_offset, &value, hw_counter_ref)?;
+                Ok(true)
+            },
+            hw_counter_ref,
+        )?;
+        compacted.flusher()()?;
+        drop(compacted);
+
+        // Wait for all background flush operations to finish, abort pending flushes
+        self.is_alive_flush_lock.blocking_mark_dead();
+
+        // Swap
+        self.pages.write().clear();
+        fs::remove_dir_all(&base_path).map_err(|err| {
+            GridstoreError::service_error(format!(
+                "Failed to remove gridstore storage directory: {err}"
+            ))
+        })?;
+        fs::rename(&compaction_path, &base_path)?;
+        *self = Self::open(base_path)?;
+
+        Ok(())
+    }
+
+    /// Usage of the space of the pages.
+    ///
+    /// Blocks of deleted and updated values are freed on flush, they're counted as used until then.
+    pub fn fragmentation(&self) -> Fragmentation {
+        let bitmask = self.bitmask.read();
+        let block_size_bytes = self.config.block_size_bytes;
+        Fragmentation {
+            allocated_bytes: bitmask.total_blocks() * block_size_bytes,
+            used_bytes: bitmask.get_storage_size_bytes(),
+            fragmented_bytes: bitmask.fragmented_blocks() * block_size_bytes,
+        }
+    }
 }
 
 impl<V> Gridstore<V> {
@@ -1679,6 +1802,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_compact() {
+        let dir = Builder::new().prefix("test-storage").tempdir().unwrap();
+        let path = dir.path().join("storage");
+        fs::create_dir_all(&path).unwrap();
+        let mut storage = Gridstore::<Payload>::new(path.clone(), Default::default()).unwrap();
+
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(42);
+        let hw_counter = HardwareCounterCell::new();
+        let hw_counter_ref = hw_counter.ref_payload_io_write_counter();
+
+        let payloads = (0..1000).map(|_| random_payload(&mut rng, 2)).collect_vec();
+        for (point_offset, payload) in payloads.iter().enumerate() {
+            storage
+                .put_value(point_offset as PointOffset, payload, hw_counter_ref)
+                .unwrap();
+        }
+        for point_offset in (0..payloads.len() as PointOffset).step_by(2) {
+            storage.delete_value(point_offset);
+        }
+        // Blocks of deleted values are freed on flush
+        storage.flusher()().unwrap();
+
+        let fragmentation = storage.fragmentation();
+        assert!(
+            fragmentation.ratio() > 0.3,
+            "unexpected fragmentation {fragmentation:?}",
+        );
+
+        // Flushers of the storage before compaction must not write anything
+        let flusher = storage.flusher();
+        storage.compact().unwrap();
+        assert!(flusher().is_err_and(|err| matches!(err, GridstoreError::FlushCancelled)));
+
+        let compacted = storage.fragmentation();
+        assert_eq!(compacted.fragmented_bytes, 0);
+        assert_eq!(compacted.used_bytes, fragmentation.used_bytes);
+        assert!(!Gridstore::<Payload>::compaction_path(&path).exists());
+
+        let check_values = |storage: &Gridstore<Payload>| {
+            for (point_offset, payload) in payloads.iter().enumerate() {
+                let stored = storage.get_value::<false>(point_offset as PointOffset, &hw_counter);
+                if point_offset % 2 == 0 {
+                    assert!(stored.is_none());
+                } else {
+                    assert_eq!(stored.as_ref(), Some(payload));
+                }
+            }
+        };
+        check_values(&storage);
+
+        drop(storage);
+        let storage = Gridstore::<Payload>::open(path).unwrap();
+        check_values(&storage);
+    }
+
     /// Test that data is only actually flushed when the Gridstore instance is still valid
     ///
     /// Specifically:
@@ -8,7 +8,7 @@ mod page;
 mod tracker;
 
 pub use blob::Blob;
-pub use gridstore::Gridstore;
+pub use gridstore::{Fragmentation, Gridstore};
 
 use crate::error::GridstoreError;
 
@@ -90,3 +90,88 @@ impl<V: PostingValue> ExactSizeIterator for PostingIterator<'_, V> {
 }
 
 impl<V: PostingValue> FusedIterator for PostingIterator<'_, V> {}
+
+/// Iterator over the ids of a posting list, which never reads the values attached to them.
+///
+/// Cheaper than [`PostingIterator`] when only ids are needed, e.g. to intersect posting lists.
+pub struct PostingIdsIterator<'a, V: PostingValue> {
+    visitor: PostingVisitor<'a, V>,
+    current_id: Option<PointOffsetType>,
+    offset: usize,
+}
+
+impl<'a, V: PostingValue> PostingIdsIterator<'a, V> {
+    pub fn new(visitor: PostingVisitor<'a, V>) -> Self {
+        Self {
+            visitor,
+            current_id: None,
+            offset: 0,
+        }
+    }
+
+    /// Advances the iterator until the current id is greater than or equal to the given id.
+    ///
+    /// Same as [`PostingIterator::advance_until_greater_or_equal`], but returns only the id.
+    pub fn advance_until_greater_or_equal(
+        &mut self,
+        target_id: PointOffsetType,
+    ) -> Option<PointOffsetType> {
+        if let Some(current) = self.current_id
+            && current >= target_id
+        {
+            return Some(current);
+        }
+
+        if self.offset >= self.visitor.len() {
+            return None;
+        }
+
+        let Some(offset) = self
+            .visitor
+            .search_greater_or_equal(target_id, Some(self.offset))
+        else {
+            self.current_id = None;
+            self.offset = self.visitor.len();
+            return None;
+        };
+
+        debug_assert!(offset >= self.offset);
+        let greater_or_equal = self.visitor.get_id_by_offset(offset);
+
+        self.current_id = greater_or_equal;
+        self.offset = offset;
+
+        greater_or_equal
+    }
+}
+
+impl<V: PostingValue> Iterator for PostingIdsIterator<'_, V> {
+    type Item = PointOffsetType;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next_opt = self.visitor.get_id_by_offset(self.offset).inspect(|_| {
+            self.offset += 1;
+        });
+
+        self.current_id = next_opt;
+
+        next_opt
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining_len = self.len();
+        (remaining_len, Some(remaining_len))
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+}
+
+impl<V: PostingValue> ExactSizeIterator for PostingIdsIterator<'_, V> {
+    fn len(&self) -> usize {
+        self.visitor.list.len().saturating_sub(self.offset)
+    }
+}
+
+impl<V: PostingValue> FusedIterator for PostingIdsIterator<'_, V> {}
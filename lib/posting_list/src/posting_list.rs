@@ -5,7 +5,7 @@ use common::types::PointOffsetType;
 use zerocopy::little_endian::U32;
 use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
 
-use crate::iterator::PostingIterator;
+use crate::iterator::{PostingIdsIterator, PostingIterator};
 use crate::value_handler::PostingValue;
 use crate::view::PostingListView;
 use crate::visitor::PostingVisitor;
@@ -113,6 +113,10 @@ impl<V: PostingValue> PostingList<V> {
         self.visitor().into_iter()
     }
 
+    pub fn iter_ids(&self) -> PostingIdsIterator<'_, V> {
+        self.visitor().into_ids_iter()
+    }
+
     pub fn len(&self) -> usize {
         self.chunks.len() * CHUNK_LEN + self.remainders.len()
     }
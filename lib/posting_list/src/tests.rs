@@ -160,15 +160,29 @@ where
     // There is no such id
     assert!(!visitor.contains(postings_count));
 
+    // ids iterator yields the same ids, without reading values
+    let ids = posting_list.iter_ids().collect::<Vec<_>>();
+    let model_ids = model.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+    assert_eq!(ids, model_ids);
+    assert_eq!(posting_list.iter_ids().len(), model.len());
+
     // intersect against all sequential ids in the posting range, model is a hashmap in this case
     let model = model.into_iter().collect::<HashMap<_, _>>();
     let mut intersection_iter = posting_list.iter();
+    let mut ids_intersection_iter = posting_list.iter_ids();
     for seq_id in 0..postings_count {
         let model_contains = model.contains_key(&seq_id);
         let iter_contains = intersection_iter
             .advance_until_greater_or_equal(seq_id)
             .is_some_and(|elem| elem.id == seq_id);
         assert_eq!(model_contains, iter_contains, "Mismatch at seq_id {seq_id}");
+        let ids_iter_contains = ids_intersection_iter
+            .advance_until_greater_or_equal(seq_id)
+            .is_some_and(|id| id == seq_id);
+        assert_eq!(
+            model_contains, ids_iter_contains,
+            "Mismatch at seq_id {seq_id}"
+        );
     }
 
     posting_list
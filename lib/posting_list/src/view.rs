@@ -5,7 +5,7 @@ use bitpacking::BitPacker;
 use common::types::PointOffsetType;
 use zerocopy::little_endian::U32;
 
-use crate::iterator::PostingIterator;
+use crate::iterator::{PostingIdsIterator, PostingIterator};
 use crate::posting_list::RemainderPosting;
 use crate::value_handler::PostingValue;
 use crate::visitor::PostingVisitor;
@@ -43,6 +43,11 @@ impl<'a, V: PostingValue> PostingListView<'a, V> {
         PostingVisitor::new(self)
     }
 
+    /// Iterate over ids only, skipping the attached values
+    pub fn into_ids_iter(self) -> PostingIdsIterator<'a, V> {
+        self.visitor().into_ids_iter()
+    }
+
     // not implemented as ToOwned trait because it requires PostingList's Borrow to return
     // a &PostingListView, which is not possible because it's a non-owning view
     pub fn to_owned(self) -> PostingList<V> {
@@ -1,6 +1,6 @@
 use common::types::PointOffsetType;
 
-use crate::iterator::PostingIterator;
+use crate::iterator::{PostingIdsIterator, PostingIterator};
 use crate::value_handler::{PostingValue, ValueHandler};
 use crate::view::PostingListView;
 use crate::{CHUNK_LEN, PostingElement};
@@ -133,6 +133,23 @@ impl<'a, V: PostingValue> PostingVisitor<'a, V> {
         }
     }
 
+    /// Returns the id at the given offset, without reading the attached value.
+    pub(crate) fn get_id_by_offset(&mut self, offset: usize) -> Option<PointOffsetType> {
+        let chunk_idx = offset / CHUNK_LEN;
+        let local_offset = offset % CHUNK_LEN;
+
+        // bound check
+        if offset >= self.list.len() {
+            return None;
+        }
+
+        if chunk_idx < self.list.chunks_len() {
+            return Some(self.decompressed_chunk(chunk_idx)[local_offset]);
+        }
+
+        self.list.get_remainder(local_offset).map(|e| e.id.get())
+    }
+
     pub(crate) fn get_by_offset(&mut self, offset: usize) -> Option<PostingElement<V>> {
         let chunk_idx = offset / CHUNK_LEN;
         let local_offset = offset % CHUNK_LEN;
@@ -181,6 +198,12 @@ impl<'a, V: PostingValue> PostingVisitor<'a, V> {
     }
 }
 
+impl<'a, V: PostingValue> PostingVisitor<'a, V> {
+    pub fn into_ids_iter(self) -> PostingIdsIterator<'a, V> {
+        PostingIdsIterator::new(self)
+    }
+}
+
 impl<'a, V: PostingValue> IntoIterator for PostingVisitor<'a, V> {
     type Item = PostingElement<V>;
     type IntoIter = PostingIterator<'a, V>;
@@ -373,6 +373,10 @@ fn build_hnsw_index<R: Rng + ?Sized>(
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        varint_links: None,
+        m0: None,
+        ef_construct_upper: None,
+        neighbor_selection: None,
     };
 
     let open_args = HnswIndexOpenArgs {
@@ -0,0 +1,281 @@
+use std::ops::Add;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+use common::types::PointOffsetType;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::common::anonymize::Anonymize;
+
+/// Number of independent hash rows in the sketch
+const SKETCH_DEPTH: usize = 4;
+
+/// Bounds of the number of counters in each row.
+/// Within the bounds, rows have a counter per point of the segment, rounded up to a power of two.
+const MIN_SKETCH_WIDTH: usize = 1 << 12;
+const MAX_SKETCH_WIDTH: usize = 1 << 22;
+
+/// All counters are halved after this many accesses per counter of a row,
+/// so old accesses fade out over time
+const DECAY_PERIOD_FACTOR: u64 = 16;
+
+/// Maximum number of points to check for hot/cold statistics
+const TELEMETRY_SAMPLE_SIZE: usize = 1024;
+
+/// Points with at least this many recent accesses are considered hot
+pub const HOT_ACCESS_THRESHOLD: u32 = 8;
+
+const ROW_SEEDS: [u64; SKETCH_DEPTH] = [
+    0x9E37_79B9_7F4A_7C15,
+    0xC2B2_AE3D_27D4_EB4F,
+    0x1656_67B1_9E37_79F9,
+    0x85EB_CA77_C2B2_AE63,
+];
+
+#[derive(Debug)]
+struct SketchCounters {
+    /// Number of counters in each row, a power of two
+    width: usize,
+    counters: Box<[AtomicU32]>,
+}
+
+/// Approximate per-point access counter, based on a count-min sketch.
+///
+/// Estimations never undercount recent accesses, but may overcount because of hash collisions.
+/// Counters are allocated on the first access, so segments which are never searched cost nothing.
+#[derive(Debug, Default)]
+pub struct AccessFrequencySketch {
+    counters: OnceLock<SketchCounters>,
+    /// Accesses since the last decay
+    since_decay: AtomicU64,
+    /// Accesses since the sketch was created
+    total_accesses: AtomicU64,
+}
+
+#[derive(Serialize, Clone, Copy, Debug, Default, JsonSchema, Anonymize)]
+#[anonymize(false)]
+pub struct AccessFrequencyTelemetry {
+    /// Number of point accesses by searches since the last start
+    pub total_accesses: u64,
+    /// Approximate number of points, frequently returned by recent searches
+    pub hot_points: usize,
+    /// Approximate number of points, not returned by any recent search
+    pub cold_points: usize,
+}
+
+impl Add for AccessFrequencyTelemetry {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            total_accesses: self.total_accesses + other.total_accesses,
+            hot_points: self.hot_points + other.hot_points,
+            cold_points: self.cold_points + other.cold_points,
+        }
+    }
+}
+
+impl SketchCounters {
+    fn new(segment_size: usize) -> Self {
+        let width = segment_size
+            .next_power_of_two()
+            .clamp(MIN_SKETCH_WIDTH, MAX_SKETCH_WIDTH);
+        Self {
+            width,
+            counters: (0..SKETCH_DEPTH * width)
+                .map(|_| AtomicU32::new(0))
+                .collect(),
+        }
+    }
+
+    fn slot(&self, row: usize, point: PointOffsetType) -> usize {
+        // Multiply-xorshift mixing, with a different seed per row
+        let mut hash = u64::from(point).wrapping_add(ROW_SEEDS[row]);
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        hash ^= hash >> 31;
+        row * self.width + (hash as usize & (self.width - 1))
+    }
+
+    fn decay_period(&self) -> u64 {
+        self.width as u64 * DECAY_PERIOD_FACTOR
+    }
+}
+
+impl AccessFrequencySketch {
+    /// Record a single access to the point.
+    ///
+    /// `segment_size` is the number of points in the segment, it defines the size of the sketch
+    /// on the first access.
+    pub fn record(&self, point: PointOffsetType, segment_size: usize) {
+        let sketch = self
+            .counters
+            .get_or_init(|| SketchCounters::new(segment_size));
+
+        for row in 0..SKETCH_DEPTH {
+            sketch.counters[sketch.slot(row, point)].fetch_add(1, Ordering::Relaxed);
+        }
+
+        self.total_accesses.fetch_add(1, Ordering::Relaxed);
+
+        let since_decay = self.since_decay.fetch_add(1, Ordering::Relaxed) + 1;
+        if since_decay >= sketch.decay_period()
+            && self
+                .since_decay
+                .compare_exchange(since_decay, 0, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            // Concurrent increments may be lost or halved twice, which is fine for an estimation
+            for counter in sketch.counters.iter() {
+                let value = counter.load(Ordering::Relaxed);
+                counter.store(value / 2, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Approximate number of recent accesses to the point
+    fn estimate(&self, point: PointOffsetType) -> u32 {
+        let Some(sketch) = self.counters.get() else {
+            return 0;
+        };
+
+        (0..SKETCH_DEPTH)
+            .map(|row| sketch.counters[sketch.slot(row, point)].load(Ordering::Relaxed))
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Estimate hot/cold statistics of the segment.
+    ///
+    /// Checks evenly spread sample of at most [`TELEMETRY_SAMPLE_SIZE`] internal ids
+    /// out of `total_points`, and scales the counts to `available_points`.
+    pub fn get_telemetry_data(
+        &self,
+        total_points: usize,
+        available_points: usize,
+        is_deleted: impl Fn(PointOffsetType) -> bool,
+    ) -> AccessFrequencyTelemetry {
+        let total_accesses = self.total_accesses.load(Ordering::Relaxed);
+
+        if self.counters.get().is_none() {
+            // Never accessed, all points are cold
+            return AccessFrequencyTelemetry {
+                total_accesses,
+                hot_points: 0,
+                cold_points: available_points,
+            };
+        }
+
+        let step = total_points.div_ceil(TELEMETRY_SAMPLE_SIZE).max(1);
+
+        let mut sampled = 0;
+        let mut hot_points = 0;
+        let mut cold_points = 0;
+        for point in (0..total_points).step_by(step) {
+            let point = point as PointOffsetType;
+            if is_deleted(point) {
+                continue;
+            }
+            sampled += 1;
+            match self.estimate(point) {
+                0 => cold_points += 1,
+                count if count >= HOT_ACCESS_THRESHOLD => hot_points += 1,
+                _ => {}
+            }
+        }
+
+        let scale = |count: usize| {
+            if sampled == 0 {
+                0
+            } else {
+                (count as f64 * available_points as f64 / sampled as f64).round() as usize
+            }
+        };
+
+        AccessFrequencyTelemetry {
+            total_accesses,
+            hot_points: scale(hot_points),
+            cold_points: scale(cold_points),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_access_frequency_estimation() {
+        let sketch = AccessFrequencySketch::default();
+        assert_eq!(sketch.estimate(1), 0);
+
+        for _ in 0..10 {
+            sketch.record(1, 100);
+        }
+        sketch.record(2, 100);
+
+        assert!(sketch.estimate(1) >= 10);
+        assert!(sketch.estimate(2) >= 1);
+
+        let telemetry = sketch.get_telemetry_data(100, 100, |_| false);
+        assert_eq!(telemetry.total_accesses, 11);
+        assert!(telemetry.hot_points >= 1);
+        assert!(telemetry.cold_points <= 98);
+        assert!(telemetry.cold_points >= 90);
+    }
+
+    #[test]
+    fn test_access_frequency_decay() {
+        let sketch = AccessFrequencySketch::default();
+        for _ in 0..100 {
+            sketch.record(1, 100);
+        }
+        let before = sketch.estimate(1);
+
+        // Accesses to other points age the old ones
+        let decay_period = MIN_SKETCH_WIDTH as u64 * DECAY_PERIOD_FACTOR;
+        for point in 0..decay_period as PointOffsetType {
+            sketch.record(1_000 + point, 100);
+        }
+
+        assert!(sketch.estimate(1) < before);
+    }
+
+    #[test]
+    fn test_sketch_width_follows_segment_size() {
+        let small = AccessFrequencySketch::default();
+        small.record(0, 10);
+        assert_eq!(small.counters.get().unwrap().width, MIN_SKETCH_WIDTH);
+
+        let large = AccessFrequencySketch::default();
+        large.record(0, 100_000);
+        assert_eq!(large.counters.get().unwrap().width, 1 << 17);
+    }
+
+    #[test]
+    fn test_sampled_telemetry() {
+        let total_points = 1_000_000;
+        let sketch = AccessFrequencySketch::default();
+
+        // Every 10th point is hot
+        for point in (0..total_points).step_by(10) {
+            for _ in 0..HOT_ACCESS_THRESHOLD {
+                sketch.record(point as PointOffsetType, total_points);
+            }
+        }
+
+        // Half of the points are deleted
+        let telemetry =
+            sketch.get_telemetry_data(total_points, total_points / 2, |point| point % 2 == 1);
+
+        assert_eq!(
+            telemetry.total_accesses,
+            100_000 * u64::from(HOT_ACCESS_THRESHOLD)
+        );
+        // 1 of 5 live points is hot, rest are cold, up to hash collisions and sampling error
+        let expected_hot = total_points / 10;
+        assert!(telemetry.hot_points.abs_diff(expected_hot) < expected_hot / 5);
+        assert!(telemetry.hot_points + telemetry.cold_points <= total_points / 2 + 1);
+    }
+}
@@ -0,0 +1,132 @@
+use std::f64::consts::LN_2;
+use std::hash::{Hash, Hasher};
+
+use fnv::FnvHasher;
+use serde::{Deserialize, Serialize};
+
+/// Maximal number of hash functions, more don't reduce false positives noticeably
+const MAX_HASHES: u32 = 16;
+
+/// Probabilistic set of values, tells if a value may be present in it.
+///
+/// There are no false negatives, so a value missing in the filter is known to be missing
+/// without looking it up in the underlying storage.
+/// Values are hashed with FNV, which does not depend on the process, so the filter can be
+/// persisted and loaded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_hashes: u32,
+}
+
+impl BloomFilter {
+    /// Empty filter, sized for the given number of values and rate of false positives
+    pub fn new(num_values: usize, false_positive_rate: f64) -> Self {
+        let num_values = num_values.max(1) as f64;
+
+        let num_bits = (-num_values * false_positive_rate.ln() / (LN_2 * LN_2))
+            .ceil()
+            .max(u64::BITS as f64) as usize;
+        let num_hashes = (num_bits as f64 / num_values * LN_2)
+            .round()
+            .clamp(1.0, f64::from(MAX_HASHES)) as u32;
+
+        Self {
+            bits: vec![0; num_bits.div_ceil(u64::BITS as usize)],
+            num_hashes,
+        }
+    }
+
+    pub fn from_values<'a, T: Hash + ?Sized + 'a>(
+        values: impl ExactSizeIterator<Item = &'a T>,
+        false_positive_rate: f64,
+    ) -> Self {
+        let mut filter = Self::new(values.len(), false_positive_rate);
+        for value in values {
+            filter.insert(value);
+        }
+        filter
+    }
+
+    pub fn insert<T: Hash + ?Sized>(&mut self, value: &T) {
+        let (first, second) = Self::hashes(value);
+        for i in 0..self.num_hashes {
+            let bit = self.bit_index(first, second, i);
+            self.bits[bit / u64::BITS as usize] |= 1 << (bit % u64::BITS as usize);
+        }
+    }
+
+    /// Whether the value may have been inserted, `false` if it certainly was not
+    pub fn may_contain<T: Hash + ?Sized>(&self, value: &T) -> bool {
+        let (first, second) = Self::hashes(value);
+        (0..self.num_hashes).all(|i| {
+            let bit = self.bit_index(first, second, i);
+            self.bits[bit / u64::BITS as usize] & (1 << (bit % u64::BITS as usize)) != 0
+        })
+    }
+
+    /// Size of the filter in bytes
+    pub fn size_bytes(&self) -> usize {
+        size_of_val(self.bits.as_slice())
+    }
+
+    /// Two independent hashes of the value, combined into `num_hashes` bit indices
+    fn hashes<T: Hash + ?Sized>(value: &T) -> (u64, u64) {
+        let mut hasher = FnvHasher::default();
+        value.hash(&mut hasher);
+        let first = mix(hasher.finish());
+        // Odd step never cycles back to the first bit early
+        let second = mix(first) | 1;
+        (first, second)
+    }
+
+    fn bit_index(&self, first: u64, second: u64, i: u32) -> usize {
+        let num_bits = self.bits.len() as u64 * u64::from(u64::BITS);
+        (first.wrapping_add(u64::from(i).wrapping_mul(second)) % num_bits) as usize
+    }
+}
+
+/// Finalizer of MurmurHash3, spreads the entropy of FNV hashes of short values over all bits
+fn mix(mut hash: u64) -> u64 {
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^= hash >> 33;
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bloom_filter() {
+        let values: Vec<_> = (0..10_000).map(|i| format!("value_{i}")).collect();
+        let filter = BloomFilter::from_values(values.iter().map(String::as_str), 0.01);
+
+        // No false negatives
+        assert!(
+            values
+                .iter()
+                .all(|value| filter.may_contain(value.as_str()))
+        );
+
+        let false_positives = (0..10_000)
+            .filter(|i| filter.may_contain(format!("missing_{i}").as_str()))
+            .count();
+        assert!(false_positives < 200, "{false_positives} false positives");
+
+        let empty = BloomFilter::new(0, 0.01);
+        assert!(!empty.may_contain("value"));
+    }
+
+    #[test]
+    fn test_bloom_filter_is_stable() {
+        let filter = BloomFilter::from_values([1i64, 2, 3].iter(), 0.01);
+        let serialized = bincode::serialize(&filter).unwrap();
+        let deserialized: BloomFilter = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, filter);
+        assert!(deserialized.may_contain(&2i64));
+    }
+}
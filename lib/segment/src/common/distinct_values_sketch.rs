@@ -0,0 +1,122 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Number of hash bits selecting the register
+const PRECISION: u32 = 12;
+
+/// Number of registers, the standard error of the estimation is `1.04 / sqrt(REGISTERS)`, ~1.6%
+const REGISTERS: usize = 1 << PRECISION;
+
+/// Approximate counter of distinct values, based on HyperLogLog.
+///
+/// Takes a fixed amount of memory regardless of the number of values.
+/// Sketches of the same field can be merged, so the number of distinct values is estimated
+/// across segments without double-counting values present in several of them.
+/// Values can't be removed, so removed values are still counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistinctValuesSketch {
+    /// Maximum rank of the hashes assigned to each register
+    registers: Box<[u8]>,
+}
+
+impl Default for DistinctValuesSketch {
+    fn default() -> Self {
+        Self {
+            registers: vec![0; REGISTERS].into_boxed_slice(),
+        }
+    }
+}
+
+impl DistinctValuesSketch {
+    pub fn from_values<'a, T: Hash + ?Sized + 'a>(values: impl IntoIterator<Item = &'a T>) -> Self {
+        let mut sketch = Self::default();
+        for value in values {
+            sketch.insert(value);
+        }
+        sketch
+    }
+
+    pub fn insert<T: Hash + ?Sized>(&mut self, value: &T) {
+        // Hasher with fixed keys, so sketches of different segments are comparable
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let register = (hash >> (64 - PRECISION)) as usize;
+        // Marker bit limits the rank, if all the remaining bits are zero
+        let remaining = (hash << PRECISION) | (1 << (PRECISION - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+
+        let current = &mut self.registers[register];
+        *current = (*current).max(rank);
+    }
+
+    /// Combine with a sketch of other values, as if all the values were inserted into this one
+    pub fn merge(&mut self, other: &Self) {
+        for (register, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    /// Approximate number of distinct inserted values
+    pub fn estimate(&self) -> usize {
+        let registers = REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / registers);
+
+        let inverse_sum: f64 = self
+            .registers
+            .iter()
+            .map(|&rank| 2f64.powi(-i32::from(rank)))
+            .sum();
+        let estimate = alpha * registers * registers / inverse_sum;
+
+        // Linear counting is more precise for small cardinalities
+        let empty_registers = self.registers.iter().filter(|&&rank| rank == 0).count();
+        let estimate = if estimate <= 2.5 * registers && empty_registers > 0 {
+            registers * (registers / empty_registers as f64).ln()
+        } else {
+            estimate
+        };
+
+        estimate.round() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(estimate: usize, expected: usize) {
+        let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+        assert!(error < 0.05, "estimate {estimate}, expected {expected}");
+    }
+
+    #[test]
+    fn test_distinct_values_estimation() {
+        assert_eq!(DistinctValuesSketch::default().estimate(), 0);
+
+        let small = DistinctValuesSketch::from_values(["a", "b", "c", "a"]);
+        assert_eq!(small.estimate(), 3);
+
+        let values: Vec<_> = (0..100_000i64).collect();
+        let large = DistinctValuesSketch::from_values(&values);
+        assert_close(large.estimate(), 100_000);
+
+        // Repeated values are not counted
+        let mut repeated = large.clone();
+        for value in &values[..1000] {
+            repeated.insert(value);
+        }
+        assert_eq!(repeated, large);
+    }
+
+    #[test]
+    fn test_merge_overlapping_sketches() {
+        let first = DistinctValuesSketch::from_values(&(0..60_000i64).collect::<Vec<_>>());
+        let second = DistinctValuesSketch::from_values(&(40_000..100_000i64).collect::<Vec<_>>());
+
+        let mut merged = first.clone();
+        merged.merge(&second);
+        assert_close(merged.estimate(), 100_000);
+        assert!(merged.estimate() < first.estimate() + second.estimate());
+    }
+}
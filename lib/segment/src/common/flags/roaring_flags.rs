@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use bitvec::slice::BitSlice;
 use common::types::PointOffsetType;
 use roaring::RoaringBitmap;
 
@@ -60,6 +61,20 @@ impl RoaringFlags {
         self.bitmap.contains(index)
     }
 
+    /// Set bits of `matches` for indices of the batch, which have the flag equal to `value`.
+    ///
+    /// The batch is intersected with the bitmap at once, so that lookups of single indices
+    /// only go through the few containers of the batch.
+    pub fn check_batch(&self, indices: &[PointOffsetType], value: bool, matches: &mut BitSlice) {
+        let batch: RoaringBitmap = indices.iter().copied().collect();
+        let trues = batch & &self.bitmap;
+        for (i, &index) in indices.iter().enumerate() {
+            if trues.contains(index) == value {
+                matches.set(i, true);
+            }
+        }
+    }
+
     pub fn iter_trues(&self) -> impl Iterator<Item = PointOffsetType> {
         self.bitmap.iter()
     }
@@ -115,6 +130,7 @@ impl RoaringFlags {
 
 #[cfg(test)]
 mod tests {
+    use bitvec::vec::BitVec;
     use common::types::PointOffsetType;
 
     use crate::common::flags::dynamic_mmap_flags::DynamicMmapFlags;
@@ -176,4 +192,34 @@ mod tests {
             assert_eq!(all_indices, expected_all);
         }
     }
+
+    #[test]
+    fn test_roaring_flags_check_batch() {
+        let dir = tempfile::Builder::new()
+            .prefix("roaring_flags_check_batch")
+            .tempdir()
+            .unwrap();
+
+        let mmap_flags = DynamicMmapFlags::open(dir.path(), false).unwrap();
+        let mut roaring_flags = RoaringFlags::new(mmap_flags);
+        for i in 0..100 {
+            roaring_flags.set(i, i % 3 == 0);
+        }
+
+        // Unordered batch with a duplicate and an index beyond the flags
+        let batch: Vec<PointOffsetType> = vec![99, 4, 3, 200, 3, 50, 0];
+
+        for value in [true, false] {
+            // Already set bits are kept
+            let mut matches = BitVec::repeat(false, batch.len());
+            matches.set(1, true);
+
+            roaring_flags.check_batch(&batch, value, &mut matches);
+
+            for (i, &index) in batch.iter().enumerate() {
+                let expected = roaring_flags.get(index) == value || i == 1;
+                assert_eq!(matches[i], expected, "index: {index}, value: {value}");
+            }
+        }
+    }
 }
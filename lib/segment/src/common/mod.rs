@@ -1,4 +1,7 @@
+pub mod access_frequency;
 pub mod anonymize;
+pub mod bloom_filter;
+pub mod distinct_values_sketch;
 pub mod error_logging;
 pub mod flags;
 pub mod macros;
@@ -4,6 +4,7 @@ use std::io::{Error as IoError, ErrorKind};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
+#[cfg(not(target_arch = "wasm32"))]
 use atomicwrites::Error as AtomicIoError;
 use gridstore::error::GridstoreError;
 use io::file_operations::FileStorageError;
@@ -66,6 +67,22 @@ pub enum OperationError {
         "No appropriate index for faceting: `{key}`. Please create one to facet on this field. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index to see which payload schemas support Match conditions"
     )]
     MissingMapIndexForFacet { key: String },
+    #[error(
+        "No integer or float index for quantiles key: `{key}`. Please create one to estimate quantiles of this field. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index"
+    )]
+    MissingNumericIndexForQuantiles { key: String },
+    #[error(
+        "No full-text index for term statistics key: `{key}`. Please create one to get term statistics of this field. Check https://qdrant.tech/documentation/concepts/indexing/#full-text-index"
+    )]
+    MissingFullTextIndexForTermStats { key: String },
+    #[error(
+        "No full-text index for text score key: `{key}`. Please create one to score by text relevance. Check https://qdrant.tech/documentation/concepts/indexing/#full-text-index"
+    )]
+    MissingFullTextIndexForTextScore { key: String },
+    #[error(
+        "No HNSW index for vector `{vector_name}`. Only graphs of indexed segments can be exported"
+    )]
+    MissingHnswIndexForGraphExport { vector_name: VectorNameBuf },
     #[error(
         "Expected {expected_type} value for {field_name} in the payload and/or in the formula defaults. Error: {description}"
     )]
@@ -76,6 +93,8 @@ pub enum OperationError {
     },
     #[error("The expression {expression} produced a non-finite number")]
     NonFiniteNumber { expression: String },
+    #[error("Vector `{vector_name}` contains NaN or infinite values")]
+    NonFiniteVector { vector_name: VectorNameBuf },
 
     // ToDo: Remove after RocksDB is deprecated
     #[error("RocksDB column family {name} not found")]
@@ -169,6 +188,7 @@ impl From<serde_cbor::Error> for OperationError {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<E> From<AtomicIoError<E>> for OperationError {
     fn from(err: AtomicIoError<E>) -> Self {
         match err {
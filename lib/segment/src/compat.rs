@@ -56,6 +56,7 @@ impl From<SegmentConfigV5> for SegmentConfig {
                     },
                     multivector_config: None,
                     datatype: None,
+                    skip_normalization: None,
                 };
 
                 (vector_name, new_data)
@@ -76,6 +77,7 @@ impl From<SegmentConfigV5> for SegmentConfig {
             payload_storage_type: old_segment
                 .payload_storage_type
                 .unwrap_or(default_storage_type),
+            defragment_keys: None,
         }
     }
 }
@@ -167,6 +169,10 @@ mod tests {
                             on_disk: None,
                             payload_m: Some(10),
                             inline_storage: None,
+                            varint_links: None,
+                            m0: None,
+                            ef_construct_upper: None,
+                            neighbor_selection: None,
                         }),
                         quantization_config: None,
                         on_disk: None,
@@ -199,6 +205,10 @@ mod tests {
                 on_disk: None,
                 payload_m: None,
                 inline_storage: None,
+                varint_links: None,
+                m0: None,
+                ef_construct_upper: None,
+                neighbor_selection: None,
             }),
             storage_type: StorageTypeV5::InMemory,
             payload_storage_type: None,
@@ -275,6 +285,10 @@ mod tests {
                 on_disk: None,
                 payload_m: None,
                 inline_storage: None,
+                varint_links: None,
+                m0: None,
+                ef_construct_upper: None,
+                neighbor_selection: None,
             }),
             storage_type: StorageTypeV5::InMemory,
             payload_storage_type: None,
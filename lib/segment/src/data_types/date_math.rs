@@ -0,0 +1,306 @@
+//! Date math expressions for datetime range bounds.
+//!
+//! An expression starts with an anchor, either `now` or a datetime followed by `||`, and is
+//! followed by any number of operations:
+//! - `+<N><unit>` / `-<N><unit>` to add or subtract time, e.g. `now-7d`
+//! - `/<unit>` to round down to the start of the unit, e.g. `now/d`
+//!
+//! Supported units are `y` (years), `M` (months), `w` (weeks), `d` (days), `h` (hours),
+//! `m` (minutes) and `s` (seconds).
+//!
+//! By default, calendar arithmetic and rounding happen in UTC. An explicit timezone offset can be
+//! given at the end of the expression, e.g. `now/d@+02:00` is the start of the current day in
+//! UTC+2.
+
+use std::str::FromStr;
+
+use chrono::{
+    DateTime, Datelike, Days, FixedOffset, Months, NaiveDate, TimeDelta, TimeZone, Timelike, Utc,
+};
+
+use crate::types::DateTimePayloadType;
+
+const NOW: &str = "now";
+const ANCHOR_SEPARATOR: &str = "||";
+const TIMEZONE_SEPARATOR: char = '@';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl Unit {
+    fn parse(expression: &str) -> Result<(Self, &str), String> {
+        let mut chars = expression.chars();
+        let unit = match chars.next() {
+            Some('y') => Unit::Year,
+            Some('M') => Unit::Month,
+            Some('w') => Unit::Week,
+            Some('d') => Unit::Day,
+            Some('h') => Unit::Hour,
+            Some('m') => Unit::Minute,
+            Some('s') => Unit::Second,
+            Some(other) => {
+                return Err(format!(
+                    "unknown unit '{other}', expected one of y, M, w, d, h, m, s"
+                ));
+            }
+            None => return Err("missing unit".to_string()),
+        };
+        Ok((unit, chars.as_str()))
+    }
+
+    /// Length of the unit in seconds, for units of a fixed length
+    fn seconds(self) -> Option<i64> {
+        match self {
+            Unit::Year | Unit::Month => None,
+            Unit::Week => Some(7 * 24 * 60 * 60),
+            Unit::Day => Some(24 * 60 * 60),
+            Unit::Hour => Some(60 * 60),
+            Unit::Minute => Some(60),
+            Unit::Second => Some(1),
+        }
+    }
+}
+
+/// Whether the string is a date math expression rather than a plain datetime
+pub fn is_date_math(expression: &str) -> bool {
+    expression.starts_with(NOW) || expression.contains(ANCHOR_SEPARATOR)
+}
+
+/// Resolve a date math expression to an absolute datetime, relative to `now`.
+pub fn resolve_date_math(
+    expression: &str,
+    now: DateTime<Utc>,
+) -> Result<DateTimePayloadType, String> {
+    let (expression, timezone) = match expression.rsplit_once(TIMEZONE_SEPARATOR) {
+        Some((expression, timezone)) => (expression, parse_timezone(timezone)?),
+        None => (expression, FixedOffset::east_opt(0).unwrap()),
+    };
+
+    let (anchor, mut operations) = if let Some(operations) = expression.strip_prefix(NOW) {
+        (now, operations)
+    } else if let Some((datetime, operations)) = expression.split_once(ANCHOR_SEPARATOR) {
+        let anchor = DateTimePayloadType::from_str(datetime)
+            .map_err(|_| format!("invalid anchor datetime '{datetime}'"))?;
+        (anchor.0, operations)
+    } else {
+        return Err(format!(
+            "expression must start with '{NOW}' or a datetime followed by '{ANCHOR_SEPARATOR}'"
+        ));
+    };
+
+    let mut datetime = anchor.with_timezone(&timezone);
+    while let Some(operator) = operations.chars().next() {
+        operations = &operations[operator.len_utf8()..];
+        match operator {
+            '+' | '-' => {
+                let digits_len = operations
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(operations.len());
+                let (digits, rest) = operations.split_at(digits_len);
+                let amount: u32 = digits
+                    .parse()
+                    .map_err(|_| format!("expected a number after '{operator}'"))?;
+                let (unit, rest) = Unit::parse(rest)?;
+                datetime = shift(datetime, unit, amount, operator == '-')
+                    .ok_or_else(|| "datetime is out of range".to_string())?;
+                operations = rest;
+            }
+            '/' => {
+                let (unit, rest) = Unit::parse(operations)?;
+                datetime = round_down(datetime, unit)
+                    .ok_or_else(|| "datetime is out of range".to_string())?;
+                operations = rest;
+            }
+            other => {
+                return Err(format!("unexpected '{other}', expected '+', '-' or '/'"));
+            }
+        }
+    }
+
+    Ok(datetime.with_timezone(&Utc).into())
+}
+
+fn parse_timezone(timezone: &str) -> Result<FixedOffset, String> {
+    if timezone == "Z" || timezone.eq_ignore_ascii_case("utc") {
+        return Ok(FixedOffset::east_opt(0).unwrap());
+    }
+
+    let error = || format!("invalid timezone '{timezone}', expected an offset like '+02:00'");
+
+    let (sign, offset) = if let Some(offset) = timezone.strip_prefix('+') {
+        (1, offset)
+    } else if let Some(offset) = timezone.strip_prefix('-') {
+        (-1, offset)
+    } else {
+        return Err(error());
+    };
+
+    let (hours, minutes) = match offset.split_once(':') {
+        Some(parts) => parts,
+        None if offset.len() == 4 => offset.split_at(2),
+        None => (offset, "0"),
+    };
+    let parse_number = |number: &str| {
+        number
+            .parse::<i32>()
+            .ok()
+            .filter(|_| number.bytes().all(|byte| byte.is_ascii_digit()))
+    };
+    let (Some(hours), Some(minutes)) = (parse_number(hours), parse_number(minutes)) else {
+        return Err(error());
+    };
+    if hours > 23 || minutes > 59 {
+        return Err(error());
+    }
+
+    FixedOffset::east_opt(sign * (hours * 60 * 60 + minutes * 60)).ok_or_else(error)
+}
+
+fn shift(
+    datetime: DateTime<FixedOffset>,
+    unit: Unit,
+    amount: u32,
+    subtract: bool,
+) -> Option<DateTime<FixedOffset>> {
+    match unit.seconds() {
+        Some(seconds) => {
+            let delta = TimeDelta::try_seconds(i64::from(amount) * seconds)?;
+            if subtract {
+                datetime.checked_sub_signed(delta)
+            } else {
+                datetime.checked_add_signed(delta)
+            }
+        }
+        None => {
+            let months = match unit {
+                Unit::Year => amount.checked_mul(12)?,
+                _ => amount,
+            };
+            if subtract {
+                datetime.checked_sub_months(Months::new(months))
+            } else {
+                datetime.checked_add_months(Months::new(months))
+            }
+        }
+    }
+}
+
+fn round_down(datetime: DateTime<FixedOffset>, unit: Unit) -> Option<DateTime<FixedOffset>> {
+    let date = datetime.date_naive();
+    let time = datetime.time();
+
+    let rounded = match unit {
+        Unit::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1)?.and_hms_opt(0, 0, 0)?,
+        Unit::Month => {
+            NaiveDate::from_ymd_opt(date.year(), date.month(), 1)?.and_hms_opt(0, 0, 0)?
+        }
+        Unit::Week => date
+            .checked_sub_days(Days::new(date.weekday().num_days_from_monday().into()))?
+            .and_hms_opt(0, 0, 0)?,
+        Unit::Day => date.and_hms_opt(0, 0, 0)?,
+        Unit::Hour => date.and_hms_opt(time.hour(), 0, 0)?,
+        Unit::Minute => date.and_hms_opt(time.hour(), time.minute(), 0)?,
+        Unit::Second => date.and_hms_opt(time.hour(), time.minute(), time.second())?,
+    };
+
+    datetime.timezone().from_local_datetime(&rounded).single()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(s: &str) -> DateTimePayloadType {
+        DateTimePayloadType::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_date_math() {
+        let now = datetime("2024-03-31T15:45:30.123Z").0;
+
+        let cases = [
+            ("now", "2024-03-31T15:45:30.123Z"),
+            ("now-7d", "2024-03-24T15:45:30.123Z"),
+            ("now+1h-30m", "2024-03-31T16:15:30.123Z"),
+            ("now-1M", "2024-02-29T15:45:30.123Z"),
+            ("now-1y", "2023-03-31T15:45:30.123Z"),
+            ("now-2w", "2024-03-17T15:45:30.123Z"),
+            ("now/s", "2024-03-31T15:45:30Z"),
+            ("now/m", "2024-03-31T15:45:00Z"),
+            ("now/h", "2024-03-31T15:00:00Z"),
+            ("now/d", "2024-03-31T00:00:00Z"),
+            ("now/w", "2024-03-25T00:00:00Z"),
+            ("now/M", "2024-03-01T00:00:00Z"),
+            ("now/y", "2024-01-01T00:00:00Z"),
+            ("now-1d/d", "2024-03-30T00:00:00Z"),
+            ("2024-01-31T00:00:00Z||+1M", "2024-02-29T00:00:00Z"),
+            ("2024-01-31||/y+12h", "2024-01-01T12:00:00Z"),
+        ];
+
+        for (expression, expected) in cases {
+            assert!(is_date_math(expression), "{expression}");
+            assert_eq!(
+                resolve_date_math(expression, now).unwrap(),
+                datetime(expected),
+                "{expression}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve_date_math_with_timezone() {
+        let now = datetime("2024-03-31T23:30:00Z").0;
+
+        // Already the next day in UTC+2
+        assert_eq!(
+            resolve_date_math("now/d@+02:00", now).unwrap(),
+            datetime("2024-03-31T22:00:00Z"),
+        );
+        assert_eq!(
+            resolve_date_math("now/d@-0500", now).unwrap(),
+            datetime("2024-03-31T05:00:00Z"),
+        );
+        assert_eq!(
+            resolve_date_math("now/d@Z", now).unwrap(),
+            datetime("2024-03-31T00:00:00Z"),
+        );
+        assert_eq!(
+            resolve_date_math("now/M@+05", now).unwrap(),
+            datetime("2024-03-31T19:00:00Z"),
+        );
+    }
+
+    #[test]
+    fn test_invalid_date_math() {
+        let now = Utc::now();
+
+        for expression in [
+            "now-",
+            "now-7",
+            "now-d",
+            "now-7x",
+            "now*2d",
+            "now/",
+            "now-7d@",
+            "now-7d@+25:00",
+            "now-7d@Europe/Berlin",
+            "yesterday||-1d",
+            "now-99999999999y",
+        ] {
+            assert!(
+                resolve_date_math(expression, now).is_err(),
+                "{expression} must be rejected",
+            );
+        }
+
+        assert!(!is_date_math("2024-03-31T00:00:00Z"));
+    }
+}
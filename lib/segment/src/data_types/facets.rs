@@ -9,7 +9,9 @@ use uuid::Uuid;
 use validator::Validate;
 
 use crate::json_path::JsonPath;
-use crate::types::{Filter, IntPayloadType, UuidIntType, ValueVariants};
+use crate::types::{
+    DateTimePayloadType, Filter, FloatPayloadType, IntPayloadType, UuidIntType, ValueVariants,
+};
 
 #[derive(Clone, Debug, JsonSchema, Serialize, Deserialize, Validate, Hash)]
 pub struct FacetParams {
@@ -28,6 +30,42 @@ impl FacetParams {
     pub const DEFAULT_EXACT: bool = false;
 }
 
+/// Edge of a facet range: a number for integer and float keys, or a datetime for datetime keys
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum FacetRangeEdge {
+    Number(FloatPayloadType),
+    DateTime(DateTimePayloadType),
+}
+
+/// Ranges to count values of a numeric or datetime key in
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FacetRanges {
+    /// Ascending edges of the ranges. Each pair of adjacent edges makes a range,
+    /// including its lower edge and excluding the upper one.
+    Edges(Vec<FacetRangeEdge>),
+    /// Split values into equal-width ranges, from the lowest value to the highest one.
+    /// The last range includes the highest value.
+    Histogram {
+        /// Number of ranges
+        buckets: usize,
+    },
+}
+
+impl FacetRanges {
+    /// Max number of ranges to count values in
+    pub const MAX_RANGES: usize = 1000;
+}
+
+/// Number of points with values in a range
+#[derive(Clone, Debug, PartialEq)]
+pub struct FacetRangeHit {
+    pub from: FacetRangeEdge,
+    pub to: FacetRangeEdge,
+    pub count: usize,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum FacetValueRef<'a> {
     Keyword(&'a str),
@@ -0,0 +1,102 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::index::field_index::{CardinalityEstimation, PrimaryCondition};
+use crate::types::{FieldCondition, VectorNameBuf};
+
+/// Strategy of selecting points, matching a filter in a segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterPlan {
+    /// Candidate points are read from payload indexes of the primary clauses
+    IndexIteration,
+    /// All points of the segment are checked against the filter
+    FullScan,
+}
+
+/// Condition, which index is used to select candidate points of a filter
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PrimaryClause {
+    /// Condition on an indexed payload field
+    Field(FieldCondition),
+    /// Number of requested point ids, present in the segment
+    Ids(usize),
+    /// Points having the named vector
+    HasVector(VectorNameBuf),
+}
+
+impl From<&PrimaryCondition> for PrimaryClause {
+    fn from(condition: &PrimaryCondition) -> Self {
+        match condition {
+            PrimaryCondition::Condition(condition) => PrimaryClause::Field((**condition).clone()),
+            PrimaryCondition::Ids(ids) => PrimaryClause::Ids(ids.resolved_point_offsets.len()),
+            PrimaryCondition::HasVector(name) => PrimaryClause::HasVector(name.clone()),
+        }
+    }
+}
+
+/// Estimated number of points, matching a filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, JsonSchema)]
+pub struct FilterCardinality {
+    /// Minimal possible number of matching points
+    pub min: usize,
+    /// Expected number of matching points
+    pub exp: usize,
+    /// Maximal possible number of matching points
+    pub max: usize,
+}
+
+impl FilterCardinality {
+    /// Add the estimation of points in other segments
+    pub fn add(&mut self, other: &Self) {
+        self.min += other.min;
+        self.exp += other.exp;
+        self.max += other.max;
+    }
+}
+
+impl From<&CardinalityEstimation> for FilterCardinality {
+    fn from(estimation: &CardinalityEstimation) -> Self {
+        Self {
+            min: estimation.min,
+            exp: estimation.exp,
+            max: estimation.max,
+        }
+    }
+}
+
+/// How a filter is executed in a single segment
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct SegmentFilterExplanation {
+    pub segment_id: String,
+    /// Number of available points in the segment
+    pub available_points: usize,
+    pub cardinality: FilterCardinality,
+    /// Conditions selected to read candidate points from payload indexes
+    pub primary_clauses: Vec<PrimaryClause>,
+    pub plan: FilterPlan,
+    /// Whether selected points are checked against the whole filter
+    pub post_filtering: bool,
+}
+
+/// How a filter is executed in segments of the collection
+#[derive(Debug, Clone, Default, PartialEq, Serialize, JsonSchema)]
+pub struct FilterExplanation {
+    /// Estimated number of matching points over all segments
+    pub cardinality: FilterCardinality,
+    pub segments: Vec<SegmentFilterExplanation>,
+}
+
+impl FilterExplanation {
+    pub fn add_segment(&mut self, segment: SegmentFilterExplanation) {
+        self.cardinality.add(&segment.cardinality);
+        self.segments.push(segment);
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        for segment in other.segments {
+            self.add_segment(segment);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Export format of HNSW graphs of segments.
+//!
+//! A graph is written as [JSON Lines](https://jsonlines.org): the first line is a
+//! [`HnswGraphHeader`], followed by one [`HnswGraphNode`] per point of the graph, ordered by
+//! internal point offsets. Links reference other points by their offsets, so the graph can be
+//! analyzed without resolving external ids.
+//!
+//! ```text
+//! {"segment_id":"...","vector_name":"","num_points":3,"m":16,"m0":32,"entry_point":2,"max_level":1}
+//! {"offset":0,"id":10,"level":0,"links":[[1,2]]}
+//! {"offset":1,"id":11,"level":0,"links":[[0,2]]}
+//! {"offset":2,"id":12,"level":1,"links":[[0,1],[]]}
+//! ```
+
+use std::io::Write;
+
+use common::types::PointOffsetType;
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::OperationResult;
+use crate::types::{PointIdType, VectorNameBuf};
+
+/// First line of an exported graph
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HnswGraphHeader {
+    pub segment_id: String,
+    pub vector_name: VectorNameBuf,
+    /// Number of points in the graph, including deleted ones
+    pub num_points: usize,
+    /// Maximal number of links of a point on levels above zero
+    pub m: usize,
+    /// Maximal number of links of a point on level zero
+    pub m0: usize,
+    /// Offset of the point, where searches start. `None` if the graph is empty
+    pub entry_point: Option<PointOffsetType>,
+    /// Highest level of the graph
+    pub max_level: usize,
+}
+
+/// Line of an exported graph, describing a single point
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HnswGraphNode {
+    /// Internal offset of the point in the segment
+    pub offset: PointOffsetType,
+    /// External id of the point. `None` if the point is deleted
+    pub id: Option<PointIdType>,
+    /// Highest level, the point is linked on
+    pub level: usize,
+    /// Offsets of linked points, for each level from zero to `level`
+    pub links: Vec<Vec<PointOffsetType>>,
+}
+
+/// Write a value of an exported graph as a single line
+pub fn write_line(writer: &mut dyn Write, value: &impl Serialize) -> OperationResult<()> {
+    serde_json::to_writer(&mut *writer, value)?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
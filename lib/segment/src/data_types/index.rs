@@ -2,10 +2,13 @@ use std::collections::BTreeSet;
 use std::fmt;
 use std::str::FromStr;
 
+use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError, ValidationErrors};
 
+use crate::index::field_index::histogram;
+
 // Keyword
 
 #[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
@@ -25,15 +28,53 @@ pub struct KeywordIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_tenant: Option<bool>,
 
+    /// If true, fold accented characters of keywords to ASCII, both on indexing and in conditions,
+    /// so that "café" matches "cafe". Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ascii_folding: Option<bool>,
+
+    /// If true, apply Unicode NFKC normalization to keywords, both on indexing and in conditions.
+    /// Applied before `ascii_folding`. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unicode_normalization: Option<bool>,
+
+    /// If true, lowercase keywords, both on indexing and in conditions,
+    /// so that matching is case-insensitive. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lowercase: Option<bool>,
+
     /// If true, store the index on disk. Default: false.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
 
+    /// If true, populate pages of the index into the page cache on load.
+    /// Only applies to indexes stored on disk. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub populate: Option<bool>,
+
+    /// If true, keep a bloom filter of the indexed keywords in RAM,
+    /// so that conditions on missing keywords don't read the index from disk.
+    /// Only applies to indexes stored on disk. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bloom_filter: Option<bool>,
+
     /// Enable HNSW graph building for this payload field.
     /// If true, builds additional HNSW links (Need payload_m > 0).
     /// Default: true.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_hnsw: Option<bool>,
+
+    /// If true - build a dedicated HNSW subgraph for each value of this tenant field,
+    /// even if the main graph is estimated to stay connected under the filter by this value.
+    /// Improves search with strict tenant filters at the cost of index size and build time.
+    /// Requires `is_tenant` and `payload_m > 0`. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_subgraphs: Option<bool>,
+
+    /// Values with more points than this do not get a dedicated subgraph.
+    /// Only used with `tenant_subgraphs`. Default: no limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_subgraphs_max_points: Option<usize>,
 }
 
 // Integer
@@ -69,11 +110,29 @@ pub struct IntegerIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
 
+    /// If true, populate pages of the index into the page cache on load.
+    /// Only applies to indexes stored on disk. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub populate: Option<bool>,
+
     /// Enable HNSW graph building for this payload field.
     /// If true, builds additional HNSW links (Need payload_m > 0).
     /// Default: true.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_hnsw: Option<bool>,
+
+    /// Target number of buckets in the histogram of values, used to estimate cardinality of
+    /// range filters and quantiles of values. More buckets take more memory, but give more
+    /// accurate estimations on skewed distributions. Minimum is 2. Default: 100.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub histogram_buckets: Option<usize>,
+
+    /// Maximal number of values in a bucket of the histogram of values. Limits the size of
+    /// buckets in large segments, where `histogram_buckets` alone gives too coarse buckets.
+    /// Smaller buckets take more memory, but give more accurate estimations of narrow ranges.
+    /// Minimum is 10. Default: 10000.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub histogram_max_bucket_size: Option<usize>,
 }
 
 impl Validate for IntegerIndexParams {
@@ -84,9 +143,13 @@ impl Validate for IntegerIndexParams {
             range,
             is_principal: _,
             on_disk: _,
+            populate: _,
             enable_hnsw: _,
+            histogram_buckets,
+            histogram_max_bucket_size,
         } = &self;
-        validate_integer_index_params(lookup, range)
+        validate_integer_index_params(lookup, range)?;
+        validate_histogram_params(histogram_buckets, histogram_max_bucket_size)
     }
 }
 
@@ -104,6 +167,30 @@ pub fn validate_integer_index_params(
     Ok(())
 }
 
+pub fn validate_histogram_params(
+    histogram_buckets: &Option<usize>,
+    histogram_max_bucket_size: &Option<usize>,
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    if histogram_buckets.is_some_and(|buckets| buckets < 2) {
+        errors.add(
+            "histogram_buckets",
+            ValidationError::new("histogram must have at least 2 buckets"),
+        );
+    }
+    if histogram_max_bucket_size.is_some_and(|size| size < histogram::MIN_BUCKET_SIZE) {
+        errors.add(
+            "histogram_max_bucket_size",
+            ValidationError::new("histogram buckets must fit at least 10 values"),
+        );
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 // UUID
 
 #[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
@@ -127,11 +214,28 @@ pub struct UuidIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
 
+    /// If true, populate pages of the index into the page cache on load.
+    /// Only applies to indexes stored on disk. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub populate: Option<bool>,
+
     /// Enable HNSW graph building for this payload field.
     /// If true, builds additional HNSW links (Need payload_m > 0).
     /// Default: true.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_hnsw: Option<bool>,
+
+    /// If true - build a dedicated HNSW subgraph for each value of this tenant field,
+    /// even if the main graph is estimated to stay connected under the filter by this value.
+    /// Improves search with strict tenant filters at the cost of index size and build time.
+    /// Requires `is_tenant` and `payload_m > 0`. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_subgraphs: Option<bool>,
+
+    /// Values with more points than this do not get a dedicated subgraph.
+    /// Only used with `tenant_subgraphs`. Default: no limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_subgraphs_max_points: Option<usize>,
 }
 
 // Float
@@ -157,11 +261,29 @@ pub struct FloatIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
 
+    /// If true, populate pages of the index into the page cache on load.
+    /// Only applies to indexes stored on disk. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub populate: Option<bool>,
+
     /// Enable HNSW graph building for this payload field.
     /// If true, builds additional HNSW links (Need payload_m > 0).
     /// Default: true.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_hnsw: Option<bool>,
+
+    /// Target number of buckets in the histogram of values, used to estimate cardinality of
+    /// range filters and quantiles of values. More buckets take more memory, but give more
+    /// accurate estimations on skewed distributions. Minimum is 2. Default: 100.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub histogram_buckets: Option<usize>,
+
+    /// Maximal number of values in a bucket of the histogram of values. Limits the size of
+    /// buckets in large segments, where `histogram_buckets` alone gives too coarse buckets.
+    /// Smaller buckets take more memory, but give more accurate estimations of narrow ranges.
+    /// Minimum is 10. Default: 10000.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub histogram_max_bucket_size: Option<usize>,
 }
 
 // Geo
@@ -183,6 +305,45 @@ pub struct GeoIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
 
+    /// If true, populate pages of the index into the page cache on load.
+    /// Only applies to indexes stored on disk. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub populate: Option<bool>,
+
+    /// Enable HNSW graph building for this payload field.
+    /// If true, builds additional HNSW links (Need payload_m > 0).
+    /// Default: true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_hnsw: Option<bool>,
+
+    /// Length of geohashes stored in the index, from 1 to 12.
+    /// Shorter geohashes take less memory, but cover larger cells, so more points have to be
+    /// checked exactly when filtering. Default: 12.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<usize>,
+
+    /// Maximum number of geohash cells to cover a filter region with.
+    /// More cells fit the region tighter, so fewer points have to be checked exactly,
+    /// at the cost of more index lookups. Default: 12.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_query_cells: Option<usize>,
+}
+
+// Geo shape
+
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GeoShapeIndexType {
+    #[default]
+    GeoShape,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct GeoShapeIndexParams {
+    // Required for OpenAPI schema without anonymous types, versus #[serde(tag = "type")]
+    pub r#type: GeoShapeIndexType,
+
     /// Enable HNSW graph building for this payload field.
     /// If true, builds additional HNSW links (Need payload_m > 0).
     /// Default: true.
@@ -207,6 +368,23 @@ pub enum TokenizerType {
     #[default]
     Word,
     Multilingual,
+    Cjk,
+}
+
+/// Step of the analyzer pipeline, which transforms or drops each token produced by the tokenizer.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenFilter {
+    /// Lowercase the token
+    Lowercase,
+    /// Fold accented characters to ASCII
+    AsciiFolding,
+    /// Apply Unicode NFKC normalization
+    UnicodeNormalization,
+    /// Drop the token, if it is one of the configured `stopwords`
+    Stopwords,
+    /// Stem the token with the configured `stemmer`
+    Stemmer,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
@@ -234,6 +412,11 @@ pub struct TextIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ascii_folding: Option<bool>,
 
+    /// If true, apply Unicode NFKC normalization to tokens (e.g., "ﬁ" -> "fi", "Ｃａｆｅ" -> "Cafe").
+    /// Applied before `ascii_folding`. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unicode_normalization: Option<bool>,
+
     /// If true, support phrase matching. Default: false.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub phrase_matching: Option<bool>,
@@ -242,14 +425,56 @@ pub struct TextIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stopwords: Option<StopwordsInterface>,
 
+    /// Ordered chain of filters, applied to each token both when indexing and querying.
+    /// If set, it replaces `lowercase`, `ascii_folding` and `unicode_normalization` flags,
+    /// and `stopwords` and `stemmer` are only applied at their position in the chain.
+    /// Default: unicode normalization, ASCII folding, lowercase, stopwords, stemmer, as configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<TokenFilter>>,
+
+    /// Groups of words with the same meaning, e.g. "car" and "automobile".
+    /// A word of a query matches any word of its group. Default: disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synonyms: Option<SynonymsParams>,
+
+    /// If true, also index each pair of adjacent words as a single token, e.g. "new york".
+    /// Queries then only match texts, in which their adjacent words are adjacent as well.
+    /// Not applied with `phrase_matching` or the prefix tokenizer. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shingles: Option<bool>,
+
+    /// Ratio of documents in (0, 1], above which a term is too frequent to be used for filtering.
+    /// Such terms are ignored by text queries like stopwords, phrase and proximity queries only use
+    /// them to check matched documents. Only applies to segments of at least 1000 documents.
+    /// Default: disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_document_frequency: Option<OrderedFloat<f64>>,
+
     /// If true, store the index on disk. Default: false.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
 
+    /// If true, populate pages of the index into the page cache on load.
+    /// Only applies to indexes stored on disk. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub populate: Option<bool>,
+
     /// Algorithm for stemming. Default: disabled.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stemmer: Option<StemmingAlgorithm>,
 
+    /// If true, detect the language of each text and apply stemming and stopwords of the detected language.
+    /// Configured `stemmer` and `stopwords` languages are used if the language can't be detected.
+    /// Only applies to the multilingual and cjk tokenizers, the latter only distinguishes Chinese
+    /// and Japanese. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_detection: Option<bool>,
+
+    /// If set, store the ISO 639-1 code of the detected language (e.g. "en") in this payload key of each point.
+    /// Requires `language_detection` to be enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detected_language_key: Option<String>,
+
     /// Enable HNSW graph building for this payload field.
     /// If true, builds additional HNSW links (Need payload_m > 0).
     /// Default: true.
@@ -257,6 +482,47 @@ pub struct TextIndexParams {
     pub enable_hnsw: Option<bool>,
 }
 
+impl Validate for TextIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let TextIndexParams {
+            r#type: _,
+            tokenizer: _,
+            min_token_len: _,
+            max_token_len: _,
+            lowercase: _,
+            ascii_folding: _,
+            unicode_normalization: _,
+            phrase_matching: _,
+            stopwords: _,
+            filters: _,
+            synonyms: _,
+            shingles: _,
+            max_document_frequency,
+            on_disk: _,
+            populate: _,
+            stemmer: _,
+            language_detection: _,
+            detected_language_key: _,
+            enable_hnsw: _,
+        } = &self;
+        validate_max_document_frequency(max_document_frequency)
+    }
+}
+
+pub fn validate_max_document_frequency(
+    max_document_frequency: &Option<OrderedFloat<f64>>,
+) -> Result<(), ValidationErrors> {
+    if max_document_frequency.is_some_and(|OrderedFloat(ratio)| !(ratio > 0.0 && ratio <= 1.0)) {
+        let mut errors = ValidationErrors::new();
+        errors.add(
+            "max_document_frequency",
+            ValidationError::new("max document frequency must be in range (0, 1]"),
+        );
+        return Err(errors);
+    }
+    Ok(())
+}
+
 #[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum Snowball {
@@ -474,6 +740,24 @@ impl FromStr for Language {
     }
 }
 
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct SynonymsParams {
+    /// Groups of synonyms, e.g. `[["car", "automobile", "auto"]]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<Vec<String>>,
+
+    /// Path to a file with more groups of synonyms, one group of comma-separated words per line.
+    /// The file must be present on every node of the cluster.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+
+    /// If true, add synonyms to the tokens of indexed texts instead of expanding queries.
+    /// Ignored if `phrase_matching` is enabled. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expand_on_index: Option<bool>,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
 pub struct StopwordsSet {
     /// Set of languages to use for stopwords.
@@ -507,6 +791,11 @@ pub struct BoolIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
 
+    /// If true, populate pages of the index into the page cache on load.
+    /// Only applies to indexes stored on disk. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub populate: Option<bool>,
+
     /// Enable HNSW graph building for this payload field.
     /// If true, builds additional HNSW links (Need payload_m > 0).
     /// Default: true.
@@ -537,11 +826,29 @@ pub struct DatetimeIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
 
+    /// If true, populate pages of the index into the page cache on load.
+    /// Only applies to indexes stored on disk. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub populate: Option<bool>,
+
     /// Enable HNSW graph building for this payload field.
     /// If true, builds additional HNSW links (Need payload_m > 0).
     /// Default: true.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_hnsw: Option<bool>,
+
+    /// Target number of buckets in the histogram of values, used to estimate cardinality of
+    /// range filters. More buckets take more memory, but give more accurate estimations on
+    /// skewed distributions. Minimum is 2. Default: 100.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub histogram_buckets: Option<usize>,
+
+    /// Maximal number of values in a bucket of the histogram of values. Limits the size of
+    /// buckets in large segments, where `histogram_buckets` alone gives too coarse buckets.
+    /// Smaller buckets take more memory, but give more accurate estimations of narrow ranges.
+    /// Minimum is 10. Default: 10000.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub histogram_max_bucket_size: Option<usize>,
 }
 
 #[cfg(test)]
@@ -1,14 +1,20 @@
 pub mod build_index_result;
 pub mod collection_defaults;
+pub mod date_math;
 pub mod facets;
+pub mod filter_explanation;
 pub mod groups;
+pub mod hnsw_graph;
 pub mod index;
 pub mod manifest;
 pub mod modifier;
 pub mod named_vectors;
 pub mod order_by;
 pub mod primitive;
+pub mod quantiles;
 pub mod query_context;
 pub mod segment_record;
+pub mod term_stats;
 pub mod tiny_map;
+pub mod vector_stats;
 pub mod vectors;
@@ -66,6 +66,8 @@ type TinyMap<'a> = tiny_map::TinyMap<CowKey<'a>, CowVector<'a>>;
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct NamedVectors<'a> {
     map: TinyMap<'a>,
+    /// Dense vectors are stored as provided, regardless of the distance of the vector config
+    skip_normalization: bool,
 }
 
 impl<'a, TElement: PrimitiveVectorElement> CowMultiVector<'a, TElement> {
@@ -225,7 +227,10 @@ impl<'a> NamedVectors<'a> {
     pub fn from_ref(key: &'a VectorName, value: VectorRef<'a>) -> Self {
         let mut map = TinyMap::new();
         map.insert(Cow::Borrowed(key), CowVector::from(value));
-        Self { map }
+        Self {
+            map,
+            skip_normalization: false,
+        }
     }
 
     pub fn from_pairs<const N: usize>(arr: [(VectorNameBuf, DenseVector); N]) -> Self {
@@ -234,6 +239,7 @@ impl<'a> NamedVectors<'a> {
                 .into_iter()
                 .map(|(k, v)| (CowKey::from(k), CowVector::Dense(Cow::Owned(v))))
                 .collect(),
+            skip_normalization: false,
         }
     }
 
@@ -243,6 +249,7 @@ impl<'a> NamedVectors<'a> {
                 .into_iter()
                 .map(|(k, v)| (CowKey::from(k), v.into()))
                 .collect(),
+            skip_normalization: false,
         }
     }
 
@@ -252,9 +259,15 @@ impl<'a> NamedVectors<'a> {
                 .iter()
                 .map(|(k, v)| (CowKey::from(k), CowVector::Dense(Cow::Borrowed(v))))
                 .collect(),
+            skip_normalization: false,
         }
     }
 
+    /// Do not normalize dense vectors on [`Self::preprocess`], the caller guarantees they already are
+    pub fn set_skip_normalization(&mut self, skip_normalization: bool) {
+        self.skip_normalization = skip_normalization;
+    }
+
     pub fn merge(&mut self, other: NamedVectors<'a>) {
         for (key, value) in other {
             self.map.insert(key, value);
@@ -314,6 +327,9 @@ impl<'a> NamedVectors<'a> {
             match vector {
                 CowVector::Dense(v) => {
                     let config = get_vector_data(name.as_ref());
+                    if self.skip_normalization || config.skip_normalization.unwrap_or_default() {
+                        continue;
+                    }
                     let preprocessed_vector = Self::preprocess_dense_vector(v.to_vec(), config);
                     *vector = CowVector::Dense(Cow::Owned(preprocessed_vector))
                 }
@@ -324,6 +340,10 @@ impl<'a> NamedVectors<'a> {
                     }
                 }
                 CowVector::MultiDense(multi_vector) => {
+                    let config = get_vector_data(name.as_ref());
+                    if self.skip_normalization || config.skip_normalization.unwrap_or_default() {
+                        continue;
+                    }
                     // invalid temp value to swap with multi_vector and reduce reallocations
                     let mut tmp_multi_vector = CowMultiVector::Borrowed(TypedMultiDenseVectorRef {
                         flattened_vectors: &[],
@@ -332,7 +352,6 @@ impl<'a> NamedVectors<'a> {
                     // `multi_vector` is empty invalid and `tmp_multi_vector` owns the real data
                     std::mem::swap(&mut tmp_multi_vector, multi_vector);
                     let mut owned_multi_vector = tmp_multi_vector.to_owned();
-                    let config = get_vector_data(name.as_ref());
                     for dense_vector in owned_multi_vector.multi_vectors_mut() {
                         let preprocessed_vector =
                             Self::preprocess_dense_vector(dense_vector.to_vec(), config);
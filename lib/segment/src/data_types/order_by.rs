@@ -1,5 +1,8 @@
+use std::cmp::Ordering;
 use std::hash::Hash;
+use std::str::FromStr;
 
+use itertools::Itertools;
 use num_cmp::NumCmp;
 use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
@@ -8,7 +11,8 @@ use validator::Validate;
 
 use crate::json_path::JsonPath;
 use crate::types::{
-    DateTimePayloadType, FloatPayloadType, IntPayloadType, Order, Range, RangeInterface,
+    DateTimePayloadType, FloatPayloadType, IntPayloadType, Order, Payload, PayloadContainer,
+    PayloadSelector, PointIdType, Range, RangeInterface, WithPayloadInterface,
 };
 
 #[derive(Deserialize, Serialize, JsonSchema, Copy, Clone, Debug, Default, PartialEq, Hash)]
@@ -78,6 +82,46 @@ pub struct OrderBy {
 
     /// Which payload value to start scrolling from. Default is the lowest value for `asc` and the highest for `desc`
     pub start_from: Option<StartFrom>,
+
+    /// Payload keys to order points with equal values of `key` by, in order of priority.
+    /// Points without a value of such key go after the ones having it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub then_by: Vec<ThenBy>,
+}
+
+/// Secondary key of `order_by`
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct ThenBy {
+    /// Payload key to order by
+    pub key: JsonPath,
+
+    /// Direction of ordering: `asc` or `desc`. Default is ascending.
+    pub direction: Option<Direction>,
+}
+
+impl ThenBy {
+    pub fn direction(&self) -> Direction {
+        self.direction.unwrap_or_default()
+    }
+
+    /// Value of the key in the payload, the first one in the order if there are many
+    fn value(&self, payload: &Payload) -> Option<OrderValue> {
+        let values = payload
+            .get_value(&self.key)
+            .into_iter()
+            .filter_map(|value| match value {
+                serde_json::Value::String(datetime) => DateTimePayloadType::from_str(datetime)
+                    .ok()
+                    .map(|datetime| OrderValue::Int(datetime.timestamp())),
+                value => OrderValue::try_from(value.clone()).ok(),
+            });
+
+        match self.direction() {
+            Direction::Asc => values.min(),
+            Direction::Desc => values.max(),
+        }
+    }
 }
 
 impl OrderBy {
@@ -118,6 +162,118 @@ impl OrderBy {
                 Direction::Desc => OrderValue::MAX,
             })
     }
+
+    /// Payload to request from shards, so points with equal values of `key` can be ordered by
+    /// the `then_by` keys
+    pub fn with_then_by_payload(
+        &self,
+        with_payload: &WithPayloadInterface,
+    ) -> WithPayloadInterface {
+        let then_by_keys = self.then_by.iter().map(|then_by| then_by.key.clone());
+
+        let fields = match with_payload {
+            WithPayloadInterface::Bool(true)
+            | WithPayloadInterface::Selector(PayloadSelector::Exclude(_)) => {
+                return WithPayloadInterface::Bool(true);
+            }
+            WithPayloadInterface::Bool(false) => then_by_keys.collect(),
+            WithPayloadInterface::Fields(fields) => fields
+                .iter()
+                .cloned()
+                .chain(then_by_keys)
+                .unique()
+                .collect(),
+            WithPayloadInterface::Selector(PayloadSelector::Include(selector)) => selector
+                .include
+                .iter()
+                .cloned()
+                .chain(then_by_keys)
+                .unique()
+                .collect(),
+        };
+
+        WithPayloadInterface::Fields(fields)
+    }
+
+    /// Order points, already ordered by `key`, with the `then_by` keys and keep `limit` of them.
+    ///
+    /// Points must have the payload of the `then_by` keys, and must include all points with the
+    /// same value of `key` as the last kept one, see [`OrderBy::take_with_ties`].
+    pub fn sort_by_then_by<T>(
+        &self,
+        points: Vec<T>,
+        limit: usize,
+        point: impl Fn(&T) -> (Option<OrderValue>, PointIdType, Option<&Payload>),
+    ) -> Vec<T> {
+        let mut keyed_points = points
+            .into_iter()
+            .map(|item| {
+                let (value, id, payload) = point(&item);
+                let then_by_values = self
+                    .then_by
+                    .iter()
+                    .map(|then_by| payload.and_then(|payload| then_by.value(payload)))
+                    .collect_vec();
+                (value, then_by_values, id, item)
+            })
+            .collect_vec();
+
+        keyed_points.sort_by(
+            |(a_value, a_then_by, a_id, _), (b_value, b_then_by, b_id, _)| {
+                let by_key = match self.direction() {
+                    Direction::Asc => a_value.cmp(b_value),
+                    Direction::Desc => b_value.cmp(a_value),
+                };
+                let by_then_by = || {
+                    self.then_by
+                        .iter()
+                        .zip(a_then_by.iter().zip(b_then_by))
+                        .map(|(then_by, values)| match values {
+                            (Some(a), Some(b)) => match then_by.direction() {
+                                Direction::Asc => a.cmp(b),
+                                Direction::Desc => b.cmp(a),
+                            },
+                            (Some(_), None) => Ordering::Less,
+                            (None, Some(_)) => Ordering::Greater,
+                            (None, None) => Ordering::Equal,
+                        })
+                        .find(|ordering| ordering.is_ne())
+                        .unwrap_or(Ordering::Equal)
+                };
+                let by_id = || match self.direction() {
+                    Direction::Asc => a_id.cmp(b_id),
+                    Direction::Desc => b_id.cmp(a_id),
+                };
+                by_key.then_with(by_then_by).then_with(by_id)
+            },
+        );
+
+        keyed_points
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, _, item)| item)
+            .collect()
+    }
+
+    /// Take `limit` points, ordered by `key`, and all following points with the same value of
+    /// `key` as the last taken one, so they can be ordered with the `then_by` keys.
+    pub fn take_with_ties<T>(
+        points: impl IntoIterator<Item = T>,
+        limit: usize,
+        value: impl Fn(&T) -> Option<OrderValue>,
+    ) -> Vec<T> {
+        let mut points = points.into_iter();
+        let mut taken = points.by_ref().take(limit).collect_vec();
+        if taken.len() < limit {
+            return taken;
+        }
+
+        let Some(last_value) = taken.last().map(&value) else {
+            return taken;
+        };
+        taken.extend(points.take_while(|point| value(point) == last_value));
+        taken
+    }
 }
 
 fn order_value_int_example() -> IntPayloadType {
@@ -227,7 +383,64 @@ impl Ord for OrderValue {
 mod tests {
     use proptest::proptest;
 
-    use crate::data_types::order_by::OrderValue;
+    use crate::data_types::order_by::{Direction, OrderBy, OrderValue, ThenBy};
+    use crate::json_path::JsonPath;
+    use crate::payload_json;
+    use crate::types::{Payload, PointIdType};
+
+    #[test]
+    fn test_sort_by_then_by() {
+        let order_by = OrderBy {
+            key: JsonPath::new("priority"),
+            direction: Some(Direction::Desc),
+            start_from: None,
+            then_by: vec![ThenBy {
+                key: JsonPath::new("created_at"),
+                direction: Some(Direction::Asc),
+            }],
+        };
+
+        let points: Vec<(OrderValue, PointIdType, Payload)> = vec![
+            (
+                OrderValue::Int(3),
+                PointIdType::NumId(1),
+                payload_json! {"created_at": "2024-01-03T00:00:00Z"},
+            ),
+            (OrderValue::Int(2), PointIdType::NumId(2), payload_json! {}),
+            (
+                OrderValue::Int(2),
+                PointIdType::NumId(3),
+                payload_json! {"created_at": "2024-01-02T00:00:00Z"},
+            ),
+            (
+                OrderValue::Int(2),
+                PointIdType::NumId(4),
+                payload_json! {"created_at": "2024-01-01T00:00:00Z"},
+            ),
+            (
+                OrderValue::Int(1),
+                PointIdType::NumId(5),
+                payload_json! {"created_at": "2024-01-01T00:00:00Z"},
+            ),
+        ];
+
+        // All points with the value of the last taken one are kept for ordering
+        let points = OrderBy::take_with_ties(points, 2, |(value, _, _)| Some(*value));
+        assert_eq!(points.len(), 4);
+
+        let sorted = order_by.sort_by_then_by(points, 3, |(value, id, payload)| {
+            (Some(*value), *id, Some(payload))
+        });
+        let ids = sorted.iter().map(|(_, id, _)| *id).collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            vec![
+                PointIdType::NumId(1),
+                PointIdType::NumId(4),
+                PointIdType::NumId(3),
+            ],
+        );
+    }
 
     proptest! {
 
@@ -0,0 +1,194 @@
+use itertools::Itertools;
+use ordered_float::OrderedFloat;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Number of values in a range, assumed to be spread evenly over it
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantileBucket {
+    pub from: f64,
+    /// Equal to `from` if all values of the bucket are the same
+    pub to: f64,
+    pub count: usize,
+}
+
+/// Approximate distribution of values of a numeric field, as buckets of a histogram.
+///
+/// Sketches of different segments can be merged, so quantiles are estimated segment-parallel.
+#[derive(Debug, Clone, Default)]
+pub struct QuantileSketch {
+    buckets: Vec<QuantileBucket>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct QuantileValue {
+    /// Requested quantile, from 0 to 1
+    pub quantile: f64,
+    /// Estimated value at the quantile, missing if there are no values
+    pub value: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct Quantiles {
+    /// Number of values the quantiles are estimated over
+    pub count: usize,
+    pub quantiles: Vec<QuantileValue>,
+}
+
+impl QuantileSketch {
+    pub fn add_bucket(&mut self, from: f64, to: f64, count: usize) {
+        if count == 0 {
+            return;
+        }
+        self.buckets.push(QuantileBucket {
+            from,
+            to: to.max(from),
+            count,
+        });
+    }
+
+    pub fn count(&self) -> usize {
+        self.buckets.iter().map(|bucket| bucket.count).sum()
+    }
+
+    pub fn merge(&mut self, other: QuantileSketch) {
+        self.buckets.extend(other.buckets);
+    }
+
+    /// Estimate values at the given quantiles, each from 0 to 1.
+    ///
+    /// Values are interpolated within buckets, assuming they are spread evenly over the bucket.
+    pub fn estimate(&self, quantiles: &[f64]) -> Vec<Option<f64>> {
+        let total = self.count();
+        if total == 0 {
+            return vec![None; quantiles.len()];
+        }
+
+        // Point masses at the breakpoints, and changes of the CDF slope
+        let mut events: Vec<(f64, f64, f64)> = Vec::with_capacity(self.buckets.len() * 2);
+        for &QuantileBucket { from, to, count } in &self.buckets {
+            let count = count as f64;
+            if to > from {
+                let slope = count / (to - from);
+                events.push((from, 0.0, slope));
+                events.push((to, 0.0, -slope));
+            } else {
+                events.push((from, count, 0.0));
+            }
+        }
+        events.sort_unstable_by_key(|(x, _, _)| OrderedFloat(*x));
+
+        // Process quantiles in increasing order, while sweeping over the CDF
+        let targets = quantiles
+            .iter()
+            .enumerate()
+            .map(|(idx, quantile)| (idx, quantile.clamp(0.0, 1.0) * total as f64))
+            .sorted_by_key(|(_, target)| OrderedFloat(*target))
+            .collect_vec();
+
+        let mut results = vec![None; quantiles.len()];
+        let mut targets = targets.into_iter().peekable();
+
+        let mut cdf = 0.0;
+        let mut slope = 0.0;
+        let mut prev_x = events[0].0;
+        for (x, events_at_x) in &events.into_iter().chunk_by(|(x, _, _)| *x) {
+            // Values spread evenly between the previous breakpoint and this one
+            let next_cdf = cdf + slope * (x - prev_x);
+            while let Some((idx, target)) = targets.next_if(|(_, target)| *target < next_cdf) {
+                results[idx] = Some(prev_x + (target - cdf) / slope);
+            }
+            cdf = next_cdf;
+
+            for (_, mass, slope_delta) in events_at_x {
+                cdf += mass;
+                slope += slope_delta;
+            }
+            while let Some((idx, _)) = targets.next_if(|(_, target)| *target <= cdf) {
+                results[idx] = Some(x);
+            }
+            prev_x = x;
+        }
+
+        // Rounding errors may leave the largest quantiles behind, those are at the maximum
+        for (idx, _) in targets {
+            results[idx] = Some(prev_x);
+        }
+
+        results
+    }
+
+    pub fn finish(self, quantiles: &[f64]) -> Quantiles {
+        let values = self.estimate(quantiles);
+        Quantiles {
+            count: self.count(),
+            quantiles: quantiles
+                .iter()
+                .zip(values)
+                .map(|(&quantile, value)| QuantileValue { quantile, value })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_quantiles() {
+        let mut sketch = QuantileSketch::default();
+        sketch.add_bucket(0.0, 10.0, 10);
+        sketch.add_bucket(10.0, 20.0, 10);
+
+        let values = sketch.estimate(&[0.0, 0.25, 0.5, 0.95, 1.0]);
+        let expected = [0.0, 5.0, 10.0, 19.0, 20.0];
+        for (value, expected) in values.into_iter().zip(expected) {
+            assert!(
+                (value.unwrap() - expected).abs() < 1e-9,
+                "{value:?} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_estimate_point_masses() {
+        let mut sketch = QuantileSketch::default();
+        sketch.add_bucket(1.0, 1.0, 3);
+        sketch.add_bucket(5.0, 5.0, 1);
+
+        assert_eq!(
+            sketch.estimate(&[0.0, 0.5, 0.75, 0.8, 1.0]),
+            vec![Some(1.0), Some(1.0), Some(1.0), Some(5.0), Some(5.0)],
+        );
+    }
+
+    #[test]
+    fn test_merge_overlapping_sketches() {
+        let mut left = QuantileSketch::default();
+        left.add_bucket(0.0, 100.0, 100);
+
+        let mut right = QuantileSketch::default();
+        right.add_bucket(50.0, 150.0, 100);
+
+        left.merge(right);
+        assert_eq!(left.count(), 200);
+
+        // Uniform density of 1 on [0, 50), 2 on [50, 100), 1 on [100, 150]
+        let values = left.estimate(&[0.25, 0.5, 0.75]);
+        let expected = [50.0, 75.0, 100.0];
+        for (value, expected) in values.into_iter().zip(expected) {
+            assert!(
+                (value.unwrap() - expected).abs() < 1e-9,
+                "{value:?} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty_sketch() {
+        let quantiles = QuantileSketch::default().finish(&[0.5, 0.95]);
+        assert_eq!(quantiles.count, 0);
+        assert!(quantiles.quantiles.iter().all(|q| q.value.is_none()));
+    }
+}
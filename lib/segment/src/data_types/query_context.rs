@@ -10,6 +10,7 @@ use common::types::ScoreType;
 use sparse::common::types::{DimId, DimWeight};
 
 use crate::data_types::tiny_map;
+use crate::index::field_index::full_text_index::bm25::Bm25CorpusStats;
 use crate::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use crate::types::{ScoredPoint, VectorName, VectorNameBuf};
 
@@ -112,6 +113,7 @@ impl QueryContext {
         SegmentQueryContext {
             query_context: self,
             deleted_points: None,
+            score_threshold: None,
             hardware_counter: self.hardware_usage_accumulator.get_counter_cell(),
         }
     }
@@ -133,6 +135,9 @@ impl Default for QueryContext {
 pub struct SegmentQueryContext<'a> {
     query_context: &'a QueryContext,
     deleted_points: Option<&'a BitSlice>,
+    /// Threshold of the score of nearest search, in terms of the distance of the searched vector.
+    /// Points which don't satisfy it are not returned.
+    score_threshold: Option<ScoreType>,
     hardware_counter: HardwareCounterCell,
 }
 
@@ -153,6 +158,7 @@ impl<'a> SegmentQueryContext<'a> {
                 .get(vector_name)
                 .copied(),
             deleted_points: self.deleted_points,
+            score_threshold: None,
             hardware_counter: self.hardware_counter.fork(),
         }
     }
@@ -162,6 +168,15 @@ impl<'a> SegmentQueryContext<'a> {
         self
     }
 
+    pub fn with_score_threshold(mut self, score_threshold: Option<ScoreType>) -> Self {
+        self.score_threshold = score_threshold;
+        self
+    }
+
+    pub fn score_threshold(&self) -> Option<ScoreType> {
+        self.score_threshold
+    }
+
     pub fn is_stopped(&self) -> bool {
         self.query_context.is_stopped()
     }
@@ -170,6 +185,7 @@ impl<'a> SegmentQueryContext<'a> {
         Self {
             query_context: self.query_context,
             deleted_points: self.deleted_points,
+            score_threshold: self.score_threshold,
             hardware_counter: self.hardware_counter.fork(),
         }
     }
@@ -190,10 +206,23 @@ pub struct VectorQueryContext<'a> {
 
     deleted_points: Option<&'a BitSlice>,
 
+    /// Threshold of internal scores, see [`crate::types::Distance::preprocess_score_threshold`].
+    /// Search may skip points with scores not above it.
+    score_threshold: Option<ScoreType>,
+
     hardware_counter: HardwareCounterCell,
 }
 
 impl VectorQueryContext<'_> {
+    pub fn with_score_threshold(mut self, score_threshold: Option<ScoreType>) -> Self {
+        self.score_threshold = score_threshold;
+        self
+    }
+
+    pub fn score_threshold(&self) -> Option<ScoreType> {
+        self.score_threshold
+    }
+
     pub fn hardware_counter(&self) -> HardwareCounterCell {
         self.hardware_counter.fork()
     }
@@ -252,6 +281,7 @@ impl Default for VectorQueryContext<'_> {
             idf: None,
             indexed_vectors: None,
             deleted_points: None,
+            score_threshold: None,
             hardware_counter: HardwareCounterCell::new(),
         }
     }
@@ -263,4 +293,6 @@ pub struct FormulaContext {
     pub limit: usize,
     pub score_threshold: Option<ScoreType>,
     pub is_stopped: Arc<AtomicBool>,
+    /// Corpus statistics of each text score of the formula, collected over all segments
+    pub text_score_stats: Vec<Bm25CorpusStats>,
 }
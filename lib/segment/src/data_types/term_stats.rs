@@ -0,0 +1,186 @@
+use std::collections::{BTreeMap, HashMap};
+
+use itertools::Itertools;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Document frequencies of terms and lengths of documents of a full-text index.
+///
+/// Aggregators of different segments can be merged, so statistics are computed segment-parallel.
+#[derive(Debug, Clone, Default)]
+pub struct TermStatsAggregator {
+    documents_count: usize,
+    /// Number of documents containing each term
+    document_frequencies: HashMap<String, usize>,
+    /// Number of documents by the power of two bucket of their number of tokens
+    tokens_buckets: BTreeMap<u32, usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct TermFrequency {
+    pub term: String,
+    /// Number of documents containing the term
+    pub documents: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct TokensCountBucket {
+    /// Lower bound of the number of tokens, inclusive
+    pub from: usize,
+    /// Upper bound of the number of tokens, exclusive
+    pub to: usize,
+    /// Number of documents with the number of tokens in the bucket
+    pub documents: usize,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TermStats {
+    /// Number of documents with at least one token
+    pub documents_count: usize,
+    /// Number of distinct terms in the index
+    pub terms_count: usize,
+    /// Most frequent terms, by the number of documents containing them
+    pub top_terms: Vec<TermFrequency>,
+    /// Distribution of the number of distinct tokens per document, in power of two buckets
+    pub tokens_histogram: Vec<TokensCountBucket>,
+}
+
+impl TermStatsAggregator {
+    pub fn add_term(&mut self, term: &str, documents: usize) {
+        if documents == 0 {
+            return;
+        }
+        *self
+            .document_frequencies
+            .entry(term.to_string())
+            .or_default() += documents;
+    }
+
+    pub fn add_document(&mut self, tokens_count: usize) {
+        if tokens_count == 0 {
+            return;
+        }
+        self.documents_count += 1;
+        *self.tokens_buckets.entry(tokens_count.ilog2()).or_default() += 1;
+    }
+
+    pub fn merge(&mut self, other: TermStatsAggregator) {
+        let TermStatsAggregator {
+            documents_count,
+            document_frequencies,
+            tokens_buckets,
+        } = other;
+
+        self.documents_count += documents_count;
+        for (term, documents) in document_frequencies {
+            *self.document_frequencies.entry(term).or_default() += documents;
+        }
+        for (bucket, documents) in tokens_buckets {
+            *self.tokens_buckets.entry(bucket).or_default() += documents;
+        }
+    }
+
+    /// Statistics with the `limit` most frequent terms, ties broken by term
+    pub fn finish(self, limit: usize) -> TermStats {
+        let TermStatsAggregator {
+            documents_count,
+            document_frequencies,
+            tokens_buckets,
+        } = self;
+
+        let terms_count = document_frequencies.len();
+        let top_terms = document_frequencies
+            .into_iter()
+            .k_smallest_by(limit, |(left_term, left), (right_term, right)| {
+                right.cmp(left).then_with(|| left_term.cmp(right_term))
+            })
+            .map(|(term, documents)| TermFrequency { term, documents })
+            .collect();
+
+        let tokens_histogram = tokens_buckets
+            .into_iter()
+            .map(|(bucket, documents)| TokensCountBucket {
+                from: 1 << bucket,
+                to: 1 << (bucket + 1),
+                documents,
+            })
+            .collect();
+
+        TermStats {
+            documents_count,
+            terms_count,
+            top_terms,
+            tokens_histogram,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_term_stats() {
+        let mut left = TermStatsAggregator::default();
+        left.add_term("the", 3);
+        left.add_term("cat", 1);
+        left.add_term("unused", 0);
+        left.add_document(1);
+        left.add_document(3);
+        left.add_document(0);
+
+        let mut right = TermStatsAggregator::default();
+        right.add_term("the", 2);
+        right.add_term("dog", 2);
+        right.add_document(2);
+        right.add_document(5);
+
+        left.merge(right);
+        let stats = left.finish(2);
+
+        assert_eq!(stats.documents_count, 4);
+        assert_eq!(stats.terms_count, 3);
+        assert_eq!(
+            stats.top_terms,
+            vec![
+                TermFrequency {
+                    term: "the".to_string(),
+                    documents: 5,
+                },
+                TermFrequency {
+                    term: "dog".to_string(),
+                    documents: 2,
+                },
+            ],
+        );
+        assert_eq!(
+            stats.tokens_histogram,
+            vec![
+                TokensCountBucket {
+                    from: 1,
+                    to: 2,
+                    documents: 1,
+                },
+                TokensCountBucket {
+                    from: 2,
+                    to: 4,
+                    documents: 2,
+                },
+                TokensCountBucket {
+                    from: 4,
+                    to: 8,
+                    documents: 1,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_empty_term_stats() {
+        let stats = TermStatsAggregator::default().finish(10);
+        assert_eq!(stats.documents_count, 0);
+        assert_eq!(stats.terms_count, 0);
+        assert!(stats.top_terms.is_empty());
+        assert!(stats.tokens_histogram.is_empty());
+    }
+}
@@ -0,0 +1,278 @@
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::vectors::{VectorElementType, VectorInternal};
+use crate::entry::entry_point::NonAppendableSegmentEntry;
+use crate::types::VectorName;
+
+/// Per-dimension mean and variance, and norms of a sample of dense vectors.
+///
+/// Accumulators of different segments can be merged, so statistics are computed segment-parallel.
+#[derive(Debug, Clone, Default)]
+pub struct VectorStatsAccumulator {
+    count: usize,
+    mean: Vec<f64>,
+    /// Sum of squared differences from the mean, per dimension
+    m2: Vec<f64>,
+    norms: Vec<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct NormHistogramBucket {
+    /// Lower bound of the bucket, inclusive
+    pub from: f32,
+    /// Upper bound of the bucket, exclusive for all buckets except the last one
+    pub to: f32,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct VectorStats {
+    /// Number of sampled vectors
+    pub sample_size: usize,
+    /// Mean value of each dimension
+    pub mean: Vec<f64>,
+    /// Variance of each dimension
+    pub variance: Vec<f64>,
+    /// Distribution of L2 norms of the sampled vectors
+    pub norm_histogram: Vec<NormHistogramBucket>,
+}
+
+impl VectorStatsAccumulator {
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    fn check_dim(&self, dim: usize) -> OperationResult<()> {
+        if self.count > 0 && self.mean.len() != dim {
+            return Err(OperationError::WrongVectorDimension {
+                expected_dim: self.mean.len(),
+                received_dim: dim,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn add(&mut self, vector: &[VectorElementType]) -> OperationResult<()> {
+        self.check_dim(vector.len())?;
+        if self.count == 0 {
+            self.mean = vec![0.0; vector.len()];
+            self.m2 = vec![0.0; vector.len()];
+        }
+
+        self.count += 1;
+        let count = self.count as f64;
+        let mut norm = 0.0;
+        for ((value, mean), m2) in vector.iter().zip(&mut self.mean).zip(&mut self.m2) {
+            let value = f64::from(*value);
+            let delta = value - *mean;
+            *mean += delta / count;
+            *m2 += delta * (value - *mean);
+            norm += value * value;
+        }
+        self.norms.push(norm.sqrt() as f32);
+
+        Ok(())
+    }
+
+    /// Combine statistics of two disjoint samples
+    pub fn merge(&mut self, other: Self) -> OperationResult<()> {
+        if other.count == 0 {
+            return Ok(());
+        }
+        if self.count == 0 {
+            *self = other;
+            return Ok(());
+        }
+        self.check_dim(other.mean.len())?;
+
+        let self_count = self.count as f64;
+        let other_count = other.count as f64;
+        let total = self_count + other_count;
+
+        for (((mean, m2), other_mean), other_m2) in self
+            .mean
+            .iter_mut()
+            .zip(&mut self.m2)
+            .zip(&other.mean)
+            .zip(&other.m2)
+        {
+            let delta = other_mean - *mean;
+            *mean += delta * other_count / total;
+            *m2 += other_m2 + delta * delta * self_count * other_count / total;
+        }
+
+        self.count += other.count;
+        self.norms.extend(other.norms);
+
+        Ok(())
+    }
+
+    pub fn finish(self, histogram_buckets: usize) -> VectorStats {
+        let Self {
+            count,
+            mean,
+            m2,
+            norms,
+        } = self;
+
+        // Sample variance
+        let variance = m2
+            .iter()
+            .map(|m2| {
+                if count > 1 {
+                    m2 / (count - 1) as f64
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        VectorStats {
+            sample_size: count,
+            mean,
+            variance,
+            norm_histogram: norm_histogram(&norms, histogram_buckets),
+        }
+    }
+}
+
+fn norm_histogram(norms: &[f32], buckets: usize) -> Vec<NormHistogramBucket> {
+    if norms.is_empty() || buckets == 0 {
+        return vec![];
+    }
+
+    let min = norms.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = norms.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+
+    // All norms are the same, e.g. normalized vectors
+    if max - min <= f32::EPSILON * max.abs() {
+        return vec![NormHistogramBucket {
+            from: min,
+            to: max,
+            count: norms.len(),
+        }];
+    }
+
+    let width = (max - min) / buckets as f32;
+    let mut counts = vec![0; buckets];
+    for norm in norms {
+        let bucket = (((norm - min) / width) as usize).min(buckets - 1);
+        counts[bucket] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| NormHistogramBucket {
+            from: min + width * i as f32,
+            to: if i + 1 == buckets {
+                max
+            } else {
+                min + width * (i + 1) as f32
+            },
+            count,
+        })
+        .collect()
+}
+
+/// Accumulate statistics over a random sample of vectors of the segment
+pub fn sample_vector_stats(
+    segment: &(impl NonAppendableSegmentEntry + ?Sized),
+    vector_name: &VectorName,
+    sample_size: usize,
+    is_stopped: &AtomicBool,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<VectorStatsAccumulator> {
+    let mut stats = VectorStatsAccumulator::default();
+
+    for point_id in segment.read_random_filtered(sample_size, None, is_stopped, hw_counter) {
+        match segment.vector(vector_name, point_id, hw_counter)? {
+            Some(VectorInternal::Dense(vector)) => stats.add(&vector)?,
+            Some(VectorInternal::MultiDense(multi_vector)) => {
+                for vector in multi_vector.multi_vectors() {
+                    stats.add(vector)?;
+                }
+            }
+            Some(VectorInternal::Sparse(_)) => {
+                return Err(OperationError::validation_error(format!(
+                    "Statistics are not available for sparse vector `{vector_name}`",
+                )));
+            }
+            None => {}
+        }
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merged_stats_match_single_pass() {
+        let vectors: Vec<Vec<f32>> = (0..20)
+            .map(|i| vec![i as f32, (i % 3) as f32 * 2.0, 1.0])
+            .collect();
+
+        let mut single = VectorStatsAccumulator::default();
+        for vector in &vectors {
+            single.add(vector).unwrap();
+        }
+
+        let mut left = VectorStatsAccumulator::default();
+        let mut right = VectorStatsAccumulator::default();
+        for vector in &vectors[..7] {
+            left.add(vector).unwrap();
+        }
+        for vector in &vectors[7..] {
+            right.add(vector).unwrap();
+        }
+        left.merge(right).unwrap();
+
+        let single = single.finish(4);
+        let merged = left.finish(4);
+
+        assert_eq!(single.sample_size, 20);
+        assert_eq!(merged.sample_size, 20);
+        for dim in 0..3 {
+            assert!((single.mean[dim] - merged.mean[dim]).abs() < 1e-9);
+            assert!((single.variance[dim] - merged.variance[dim]).abs() < 1e-9);
+        }
+        assert!((single.mean[0] - 9.5).abs() < 1e-9);
+        assert!((single.variance[0] - 35.0).abs() < 1e-9);
+        assert_eq!(single.variance[2], 0.0);
+        assert_eq!(single.norm_histogram, merged.norm_histogram);
+    }
+
+    #[test]
+    fn test_norm_histogram() {
+        let mut stats = VectorStatsAccumulator::default();
+        for norm in [1.0, 1.5, 2.0, 3.9, 4.0] {
+            stats.add(&[norm, 0.0]).unwrap();
+        }
+        assert!(stats.add(&[1.0, 2.0, 3.0]).is_err());
+
+        let histogram = stats.finish(3).norm_histogram;
+        let counts: Vec<_> = histogram.iter().map(|bucket| bucket.count).collect();
+        assert_eq!(counts, vec![2, 1, 2]);
+        assert_eq!(histogram[0].from, 1.0);
+        assert_eq!(histogram[2].to, 4.0);
+    }
+
+    #[test]
+    fn test_normalized_vectors_histogram() {
+        let mut stats = VectorStatsAccumulator::default();
+        stats.add(&[1.0, 0.0]).unwrap();
+        stats.add(&[0.0, 1.0]).unwrap();
+
+        let histogram = stats.finish(10).norm_histogram;
+        assert_eq!(histogram.len(), 1);
+        assert_eq!(histogram[0].count, 2);
+    }
+}
@@ -80,6 +80,30 @@ impl VectorInternal {
         }
     }
 
+    /// Handle NaN and infinite values of the vector according to the policy
+    pub fn apply_non_finite_policy(
+        &mut self,
+        vector_name: &VectorName,
+        policy: NonFiniteVectorPolicy,
+    ) -> OperationResult<()> {
+        let values = match self {
+            VectorInternal::Dense(dense) => dense.as_mut_slice(),
+            VectorInternal::Sparse(sparse) => sparse.values.as_mut_slice(),
+            VectorInternal::MultiDense(multi_dense) => multi_dense.flattened_vectors.as_mut_slice(),
+        };
+        policy.apply(vector_name, values)
+    }
+
+    /// Append sub-vectors of the `other` multivector to this multivector
+    pub fn append_sub_vectors(&mut self, other: VectorRef) -> OperationResult<()> {
+        match (self, other) {
+            (VectorInternal::MultiDense(multi_vector), VectorRef::MultiDense(other)) => {
+                multi_vector.try_extend(other)
+            }
+            _ => Err(OperationError::WrongMulti),
+        }
+    }
+
     pub fn from_vector_and_indices(vector: DenseVector, indices: Option<Vec<DimId>>) -> Self {
         if let Some(indices) = indices {
             VectorInternal::Sparse(SparseVector {
@@ -92,6 +116,58 @@ impl VectorInternal {
     }
 }
 
+/// How to handle NaN and infinite values in vectors
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NonFiniteVectorPolicy {
+    /// Reject vectors containing NaN or infinite values
+    #[default]
+    Reject,
+    /// Replace NaN and infinite values with zero
+    SanitizeToZero,
+    /// Replace NaN with zero, and infinite values with the largest finite value of the same sign
+    Clamp,
+}
+
+impl NonFiniteVectorPolicy {
+    /// Handle NaN and infinite values of the vector according to the policy.
+    ///
+    /// Returns [`OperationError::NonFiniteVector`] if the vector is rejected.
+    pub fn apply(
+        self,
+        vector_name: &VectorName,
+        values: &mut [VectorElementType],
+    ) -> OperationResult<()> {
+        if values.iter().all(|value| value.is_finite()) {
+            return Ok(());
+        }
+
+        match self {
+            NonFiniteVectorPolicy::Reject => {
+                return Err(OperationError::NonFiniteVector {
+                    vector_name: vector_name.to_owned(),
+                });
+            }
+            NonFiniteVectorPolicy::SanitizeToZero => values
+                .iter_mut()
+                .filter(|value| !value.is_finite())
+                .for_each(|value| *value = 0.0),
+            NonFiniteVectorPolicy::Clamp => values
+                .iter_mut()
+                .filter(|value| !value.is_finite())
+                .for_each(|value| {
+                    *value = if value.is_nan() {
+                        0.0
+                    } else {
+                        value.clamp(VectorElementType::MIN, VectorElementType::MAX)
+                    }
+                }),
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum VectorRef<'a> {
     Dense(&'a [VectorElementType]),
@@ -404,6 +480,18 @@ impl<T: PrimitiveVectorElement> TypedMultiDenseVector<T> {
     pub fn flattened_len(&self) -> usize {
         self.flattened_vectors.len()
     }
+
+    /// Appends sub-vectors of another multi vector of the same dimension
+    pub fn try_extend(&mut self, other: TypedMultiDenseVectorRef<T>) -> OperationResult<()> {
+        if other.dim != self.dim {
+            return Err(OperationError::WrongVectorDimension {
+                expected_dim: self.dim,
+                received_dim: other.dim,
+            });
+        }
+        self.flattened_vectors.extend_from_slice(other.flattened_vectors);
+        Ok(())
+    }
 }
 
 impl<T: PrimitiveVectorElement> TryFrom<Vec<TypedDenseVector<T>>> for TypedMultiDenseVector<T> {
@@ -918,3 +1006,68 @@ impl From<MultiDenseVectorInternal> for QueryVector {
         Self::Nearest(VectorInternal::MultiDense(vec))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_finite_vector_policy() {
+        let vector = [1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY];
+
+        let mut finite = [1.0, -2.0];
+        assert!(NonFiniteVectorPolicy::Reject.apply("", &mut finite).is_ok());
+        assert_eq!(finite, [1.0, -2.0]);
+
+        let mut rejected = vector;
+        assert!(matches!(
+            NonFiniteVectorPolicy::Reject.apply("image", &mut rejected),
+            Err(OperationError::NonFiniteVector { vector_name }) if vector_name == "image",
+        ));
+
+        let mut sanitized = vector;
+        NonFiniteVectorPolicy::SanitizeToZero
+            .apply("", &mut sanitized)
+            .unwrap();
+        assert_eq!(sanitized, [1.0, 0.0, 0.0, 0.0]);
+
+        let mut clamped = vector;
+        NonFiniteVectorPolicy::Clamp
+            .apply("", &mut clamped)
+            .unwrap();
+        assert_eq!(clamped, [1.0, 0.0, f32::MAX, f32::MIN]);
+    }
+
+    #[test]
+    fn test_append_sub_vectors() {
+        let mut vector = VectorInternal::MultiDense(MultiDenseVectorInternal::new_unchecked(vec![
+            vec![1.0, 2.0],
+        ]));
+
+        let sub_vectors =
+            MultiDenseVectorInternal::new_unchecked(vec![vec![3.0, 4.0], vec![5.0, 6.0]]);
+        vector
+            .append_sub_vectors(VectorRef::from(&sub_vectors))
+            .unwrap();
+        assert_eq!(
+            vector,
+            VectorInternal::MultiDense(MultiDenseVectorInternal::new_unchecked(vec![
+                vec![1.0, 2.0],
+                vec![3.0, 4.0],
+                vec![5.0, 6.0],
+            ])),
+        );
+
+        let wrong_dim = MultiDenseVectorInternal::new_unchecked(vec![vec![1.0, 2.0, 3.0]]);
+        assert!(matches!(
+            vector.append_sub_vectors(VectorRef::from(&wrong_dim)),
+            Err(OperationError::WrongVectorDimension {
+                expected_dim: 2,
+                received_dim: 3
+            }),
+        ));
+
+        let dense: DenseVector = vec![1.0, 2.0];
+        assert!(vector.append_sub_vectors(VectorRef::from(&dense)).is_err());
+    }
+}
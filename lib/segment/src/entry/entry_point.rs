@@ -1,4 +1,5 @@
 use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::Write;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
@@ -11,13 +12,18 @@ use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
 use crate::data_types::build_index_result::BuildFieldIndexResult;
 use crate::data_types::facets::{FacetParams, FacetValue};
+use crate::data_types::filter_explanation::SegmentFilterExplanation;
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::order_by::{OrderBy, OrderValue};
+use crate::data_types::quantiles::QuantileSketch;
 use crate::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use crate::data_types::segment_record::SegmentRecord;
+use crate::data_types::term_stats::TermStatsAggregator;
 use crate::data_types::vectors::{QueryVector, VectorInternal};
 use crate::entry::snapshot_entry::SnapshotEntry;
+use crate::index::field_index::full_text_index::bm25::Bm25CorpusStats;
 use crate::index::field_index::{CardinalityEstimation, FieldIndex};
+use crate::index::query_optimization::rescore_formula::parsed_formula::TextScoreQuery;
 use crate::json_path::JsonPath;
 use crate::telemetry::SegmentTelemetry;
 use crate::types::{
@@ -118,6 +124,8 @@ pub trait NonAppendableSegmentEntry: SnapshotEntry {
 
     /// Return points which satisfies filtering condition ordered by the `order_by.key` field,
     /// starting with `order_by.start_from` value including.
+    /// With `order_by.then_by` keys, all points with the value of the last one are returned
+    /// beyond the `limit`, so they can be ordered by these keys.
     ///
     /// Will fail if there is no index for the order_by key.
     /// Cancelled by `is_stopped` flag.
@@ -161,6 +169,22 @@ pub trait NonAppendableSegmentEntry: SnapshotEntry {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<HashMap<FacetValue, usize>>;
 
+    /// Return the distribution of values of the given numeric key, to estimate quantiles over.
+    fn quantile_sketch(
+        &self,
+        key: &JsonPath,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<QuantileSketch>;
+
+    /// Return document frequencies of terms and lengths of documents of the given full-text key.
+    fn term_stats(
+        &self,
+        key: &JsonPath,
+        is_stopped: &AtomicBool,
+    ) -> OperationResult<TermStatsAggregator>;
+
     /// Check if there is point with `point_id` in this segment.
     ///
     /// Soft deleted points are excluded.
@@ -173,6 +197,22 @@ pub trait NonAppendableSegmentEntry: SnapshotEntry {
         hw_counter: &HardwareCounterCell,
     ) -> CardinalityEstimation;
 
+    /// Explain how points matching the filter are selected in this segment.
+    fn explain_filter(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> SegmentFilterExplanation;
+
+    /// Write HNSW graph of the vector in the export format of [`crate::data_types::hnsw_graph`].
+    ///
+    /// Fails, if the vector is not indexed with HNSW.
+    fn export_hnsw_graph(
+        &self,
+        vector_name: &VectorName,
+        writer: &mut dyn Write,
+    ) -> OperationResult<()>;
+
     fn vector_names(&self) -> HashSet<VectorNameBuf>;
 
     /// Whether this segment is completely empty in terms of points
@@ -296,25 +336,31 @@ pub trait NonAppendableSegmentEntry: SnapshotEntry {
             });
         };
 
-        self.delete_field_index_if_incompatible(op_num, key, field_schema)?;
-
-        let (schema, indexes) =
-            match self.build_field_index(op_num, key, field_schema, hw_counter)? {
-                BuildFieldIndexResult::SkippedByVersion => {
-                    return Ok(false);
-                }
-                BuildFieldIndexResult::AlreadyExists => {
-                    return Ok(false);
-                }
-                BuildFieldIndexResult::IncompatibleSchema => {
-                    // This is a service error, as we should have just removed the old index
-                    // So it should not be possible to get this error
-                    return Err(OperationError::service_error(format!(
-                        "Incompatible schema for field index on field {key}",
-                    )));
-                }
-                BuildFieldIndexResult::Built { schema, indexes } => (schema, indexes),
-            };
+        // An index with another schema keeps serving requests, while the new one is built
+        let mut build_result = self.build_field_index(op_num, key, field_schema, hw_counter)?;
+
+        if let BuildFieldIndexResult::IncompatibleSchema = build_result {
+            // The index can't be rebuilt next to the current one, drop it first
+            self.delete_field_index_if_incompatible(op_num, key, field_schema)?;
+            build_result = self.build_field_index(op_num, key, field_schema, hw_counter)?;
+        }
+
+        let (schema, indexes) = match build_result {
+            BuildFieldIndexResult::SkippedByVersion => {
+                return Ok(false);
+            }
+            BuildFieldIndexResult::AlreadyExists => {
+                return Ok(false);
+            }
+            BuildFieldIndexResult::IncompatibleSchema => {
+                // This is a service error, as we should have just removed the old index
+                // So it should not be possible to get this error
+                return Err(OperationError::service_error(format!(
+                    "Incompatible schema for field index on field {key}",
+                )));
+            }
+            BuildFieldIndexResult::Built { schema, indexes } => (schema, indexes),
+        };
 
         self.apply_field_index(op_num, key.to_owned(), schema, indexes)
     }
@@ -336,6 +382,14 @@ pub trait NonAppendableSegmentEntry: SnapshotEntry {
     fn get_telemetry_data(&self, detail: TelemetryDetail) -> SegmentTelemetry;
 
     fn fill_query_context(&self, query_context: &mut QueryContext);
+
+    /// Add the corpus statistics of this segment for each text score of a formula to `stats`.
+    fn fill_text_score_stats(
+        &self,
+        text_scores: &[TextScoreQuery],
+        stats: &mut [Bm25CorpusStats],
+        hw_counter: &HardwareCounterCell,
+    );
 }
 
 /// Define mutable operations which can be performed with Segment or Segment-like entity.
@@ -1,3 +1,4 @@
+use bitvec::slice::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use mutable_bool_index::MutableBoolIndex;
@@ -87,6 +88,26 @@ impl BoolIndex {
         }
     }
 
+    /// Set bits of `matches` for points of the batch, which have the `is_true` value
+    pub fn check_values_any_batch(
+        &self,
+        point_ids: &[PointOffsetType],
+        is_true: bool,
+        matches: &mut BitSlice,
+    ) {
+        match self {
+            #[cfg(feature = "rocksdb")]
+            BoolIndex::Simple(index) => {
+                for (i, &point_id) in point_ids.iter().enumerate() {
+                    if index.check_values_any(point_id, is_true) {
+                        matches.set(i, true);
+                    }
+                }
+            }
+            BoolIndex::Mmap(index) => index.check_values_any_batch(point_ids, is_true, matches),
+        }
+    }
+
     pub fn values_is_empty(&self, point_id: PointOffsetType) -> bool {
         match self {
             #[cfg(feature = "rocksdb")]
@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use bitvec::slice::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::counter::iterator_hw_measurement::HwMeasurementIteratorExt;
 use common::types::PointOffsetType;
@@ -162,6 +163,9 @@ impl MutableBoolIndex {
             points_count: self.indexed_count,
             points_values_count: (self.trues_count + self.falses_count),
             histogram_bucket_size: None,
+            distinct_values: None,
+            full_text: None,
+            fragmentation: None,
             index_type: "mmap_bool",
         }
     }
@@ -180,6 +184,21 @@ impl MutableBoolIndex {
         }
     }
 
+    /// Set bits of `matches` for points of the batch, which have the `is_true` value
+    pub fn check_values_any_batch(
+        &self,
+        point_ids: &[PointOffsetType],
+        is_true: bool,
+        matches: &mut BitSlice,
+    ) {
+        let flags = if is_true {
+            &self.storage.trues_flags
+        } else {
+            &self.storage.falses_flags
+        };
+        flags.check_batch(point_ids, true, matches);
+    }
+
     pub fn values_is_empty(&self, point_id: PointOffsetType) -> bool {
         !self.storage.trues_flags.get(point_id) && !self.storage.falses_flags.get(point_id)
     }
@@ -241,6 +241,9 @@ impl SimpleBoolIndex {
             points_count: self.memory.indexed_count(),
             points_values_count: self.memory.trues_count() + self.memory.falses_count(),
             histogram_bucket_size: None,
+            distinct_values: None,
+            full_text: None,
+            fragmentation: None,
             index_type: "simple_bool",
         }
     }
@@ -3,6 +3,7 @@ use std::path::PathBuf;
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
+use gridstore::Fragmentation;
 use serde_json::Value;
 
 use super::bool_index::BoolIndex;
@@ -11,8 +12,10 @@ use super::facet_index::FacetIndexEnum;
 use super::full_text_index::mmap_text_index::FullTextMmapIndexBuilder;
 use super::full_text_index::text_index::{FullTextGridstoreIndexBuilder, FullTextIndex};
 use super::geo_index::{GeoMapIndexGridstoreBuilder, GeoMapIndexMmapBuilder};
+use super::geo_shape_index::{GeoShapeIndex, GeoShapeIndexGridstoreBuilder};
 #[cfg(feature = "rocksdb")]
 use super::map_index::MapIndexBuilder;
+use super::map_index::keyword_index::{KeywordIndex, KeywordIndexBuilder};
 use super::map_index::{MapIndex, MapIndexGridstoreBuilder, MapIndexMmapBuilder};
 #[cfg(feature = "rocksdb")]
 use super::numeric_index::NumericIndexBuilder;
@@ -20,20 +23,23 @@ use super::numeric_index::{
     NumericIndex, NumericIndexGridstoreBuilder, NumericIndexMmapBuilder, StreamRange,
 };
 use crate::common::Flusher;
+use crate::common::distinct_values_sketch::DistinctValuesSketch;
 use crate::common::operation_error::OperationResult;
 use crate::data_types::order_by::OrderValue;
 use crate::index::field_index::geo_index::GeoMapIndex;
-use crate::index::field_index::null_index::MutableNullIndex;
 use crate::index::field_index::null_index::mutable_null_index::MutableNullIndexBuilder;
+use crate::index::field_index::null_index::nested_null_index::NestedNullIndexBuilder;
+use crate::index::field_index::null_index::{MutableNullIndex, NestedNullIndex};
 use crate::index::field_index::numeric_index::NumericIndexInner;
 use crate::index::field_index::{CardinalityEstimation, PayloadBlockCondition};
 use crate::index::payload_config::{
     FullPayloadIndexType, IndexMutability, PayloadIndexType, StorageType,
 };
+use crate::json_path::JsonPath;
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, Match, MatchPhrase,
-    MatchText, PayloadKeyType, RangeInterface, UuidIntType, UuidPayloadType,
+    DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, Match, MatchNear,
+    MatchPhrase, PayloadKeyType, RangeInterface, UuidIntType, UuidPayloadType,
 };
 
 pub trait PayloadFieldIndex {
@@ -132,14 +138,16 @@ pub enum FieldIndex {
     IntIndex(NumericIndex<IntPayloadType, IntPayloadType>),
     DatetimeIndex(NumericIndex<IntPayloadType, DateTimePayloadType>),
     IntMapIndex(MapIndex<IntPayloadType>),
-    KeywordIndex(MapIndex<str>),
+    KeywordIndex(KeywordIndex),
     FloatIndex(NumericIndex<FloatPayloadType, FloatPayloadType>),
     GeoIndex(GeoMapIndex),
+    GeoShapeIndex(GeoShapeIndex),
     FullTextIndex(FullTextIndex),
     BoolIndex(BoolIndex),
     UuidIndex(NumericIndex<UuidIntType, UuidPayloadType>),
     UuidMapIndex(MapIndex<UuidIntType>),
     NullIndex(MutableNullIndex),
+    NestedNullIndex(NestedNullIndex),
 }
 
 impl std::fmt::Debug for FieldIndex {
@@ -151,11 +159,13 @@ impl std::fmt::Debug for FieldIndex {
             FieldIndex::KeywordIndex(_index) => write!(f, "KeywordIndex"),
             FieldIndex::FloatIndex(_index) => write!(f, "FloatIndex"),
             FieldIndex::GeoIndex(_index) => write!(f, "GeoIndex"),
+            FieldIndex::GeoShapeIndex(_index) => write!(f, "GeoShapeIndex"),
             FieldIndex::BoolIndex(_index) => write!(f, "BoolIndex"),
             FieldIndex::FullTextIndex(_index) => write!(f, "FullTextIndex"),
             FieldIndex::UuidIndex(_index) => write!(f, "UuidIndex"),
             FieldIndex::UuidMapIndex(_index) => write!(f, "UuidMapIndex"),
             FieldIndex::NullIndex(_index) => write!(f, "NullIndex"),
+            FieldIndex::NestedNullIndex(_index) => write!(f, "NestedNullIndex"),
         }
     }
 }
@@ -178,22 +188,41 @@ impl FieldIndex {
             FieldIndex::IntIndex(_) => None,
             FieldIndex::DatetimeIndex(_) => None,
             FieldIndex::IntMapIndex(_) => None,
-            FieldIndex::KeywordIndex(_) => None,
+            FieldIndex::KeywordIndex(keyword_index) => {
+                keyword_index.check_payload_match(condition, payload_value)
+            }
             FieldIndex::FloatIndex(_) => None,
             FieldIndex::GeoIndex(_) => None,
+            FieldIndex::GeoShapeIndex(_) => None,
             FieldIndex::BoolIndex(_) => None,
             FieldIndex::FullTextIndex(full_text_index) => match &condition.r#match {
-                Some(Match::Text(MatchText { text })) => Some(
-                    full_text_index.check_payload_match::<false>(payload_value, text, hw_counter),
-                ),
-                Some(Match::Phrase(MatchPhrase { phrase })) => Some(
-                    full_text_index.check_payload_match::<true>(payload_value, phrase, hw_counter),
-                ),
+                Some(Match::Text(match_text)) => Some(full_text_index.check_payload_match_text(
+                    payload_value,
+                    match_text,
+                    hw_counter,
+                )),
+                Some(Match::Phrase(MatchPhrase { phrase })) => {
+                    Some(full_text_index.check_payload_match::<true>(
+                        payload_value,
+                        phrase,
+                        None,
+                        hw_counter,
+                    ))
+                }
+                Some(Match::Near(MatchNear { near, within })) => {
+                    Some(full_text_index.check_payload_proximity(
+                        payload_value,
+                        near,
+                        *within,
+                        hw_counter,
+                    ))
+                }
                 _ => None,
             },
             FieldIndex::UuidIndex(_) => None,
             FieldIndex::UuidMapIndex(_) => None,
             FieldIndex::NullIndex(_) => None,
+            FieldIndex::NestedNullIndex(_) => None,
         }
     }
 
@@ -205,11 +234,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(payload_field_index) => payload_field_index,
             FieldIndex::FloatIndex(payload_field_index) => payload_field_index.inner(),
             FieldIndex::GeoIndex(payload_field_index) => payload_field_index,
+            FieldIndex::GeoShapeIndex(payload_field_index) => payload_field_index,
             FieldIndex::BoolIndex(payload_field_index) => payload_field_index,
             FieldIndex::FullTextIndex(payload_field_index) => payload_field_index,
             FieldIndex::UuidIndex(payload_field_index) => payload_field_index.inner(),
             FieldIndex::UuidMapIndex(payload_field_index) => payload_field_index,
             FieldIndex::NullIndex(payload_field_index) => payload_field_index,
+            FieldIndex::NestedNullIndex(payload_field_index) => payload_field_index,
         }
     }
 
@@ -221,11 +252,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.wipe(),
             FieldIndex::FloatIndex(index) => index.wipe(),
             FieldIndex::GeoIndex(index) => index.wipe(),
+            FieldIndex::GeoShapeIndex(index) => index.wipe(),
             FieldIndex::BoolIndex(index) => index.wipe(),
             FieldIndex::FullTextIndex(index) => index.wipe(),
             FieldIndex::UuidIndex(index) => index.wipe(),
             FieldIndex::UuidMapIndex(index) => index.wipe(),
             FieldIndex::NullIndex(index) => index.wipe(),
+            FieldIndex::NestedNullIndex(index) => index.wipe(),
         }
     }
 
@@ -296,6 +329,9 @@ impl FieldIndex {
             FieldIndex::GeoIndex(payload_field_index) => {
                 payload_field_index.add_point(id, payload, hw_counter)
             }
+            FieldIndex::GeoShapeIndex(payload_field_index) => {
+                payload_field_index.add_point(id, payload, hw_counter)
+            }
             FieldIndex::BoolIndex(payload_field_index) => {
                 payload_field_index.add_point(id, payload, hw_counter)
             }
@@ -311,6 +347,30 @@ impl FieldIndex {
             FieldIndex::NullIndex(payload_field_index) => {
                 payload_field_index.add_point(id, payload, hw_counter)
             }
+            FieldIndex::NestedNullIndex(payload_field_index) => {
+                payload_field_index.add_point(id, payload, hw_counter)
+            }
+        }
+    }
+
+    /// Path of the payload values, which [`Self::add_point`] expects instead of values of the field.
+    ///
+    /// Only nested null index takes whole elements of the array, the field is in.
+    pub fn elements_path(&self) -> Option<&JsonPath> {
+        match self {
+            FieldIndex::NestedNullIndex(index) => Some(index.elements_path()),
+            FieldIndex::IntIndex(_)
+            | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::IntMapIndex(_)
+            | FieldIndex::KeywordIndex(_)
+            | FieldIndex::FloatIndex(_)
+            | FieldIndex::GeoIndex(_)
+            | FieldIndex::GeoShapeIndex(_)
+            | FieldIndex::BoolIndex(_)
+            | FieldIndex::FullTextIndex(_)
+            | FieldIndex::UuidIndex(_)
+            | FieldIndex::UuidMapIndex(_)
+            | FieldIndex::NullIndex(_) => None,
         }
     }
 
@@ -322,11 +382,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.remove_point(point_id),
             FieldIndex::FloatIndex(index) => index.mut_inner().remove_point(point_id),
             FieldIndex::GeoIndex(index) => index.remove_point(point_id),
+            FieldIndex::GeoShapeIndex(index) => index.remove_point(point_id),
             FieldIndex::BoolIndex(index) => index.remove_point(point_id),
             FieldIndex::FullTextIndex(index) => index.remove_point(point_id),
             FieldIndex::UuidIndex(index) => index.remove_point(point_id),
             FieldIndex::UuidMapIndex(index) => index.remove_point(point_id),
             FieldIndex::NullIndex(index) => index.remove_point(point_id),
+            FieldIndex::NestedNullIndex(index) => index.remove_point(point_id),
         }
     }
 
@@ -338,11 +400,70 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.get_telemetry_data(),
             FieldIndex::FloatIndex(index) => index.get_telemetry_data(),
             FieldIndex::GeoIndex(index) => index.get_telemetry_data(),
+            FieldIndex::GeoShapeIndex(index) => index.get_telemetry_data(),
             FieldIndex::BoolIndex(index) => index.get_telemetry_data(),
             FieldIndex::FullTextIndex(index) => index.get_telemetry_data(),
             FieldIndex::UuidIndex(index) => index.get_telemetry_data(),
             FieldIndex::UuidMapIndex(index) => index.get_telemetry_data(),
             FieldIndex::NullIndex(index) => index.get_telemetry_data(),
+            FieldIndex::NestedNullIndex(index) => index.get_telemetry_data(),
+        }
+    }
+
+    /// Approximate set of distinct values, available for map indexes only
+    pub fn distinct_values_sketch(&self) -> Option<&DistinctValuesSketch> {
+        match self {
+            FieldIndex::IntMapIndex(index) => Some(index.distinct_values_sketch()),
+            FieldIndex::KeywordIndex(index) => Some(index.distinct_values_sketch()),
+            FieldIndex::UuidMapIndex(index) => Some(index.distinct_values_sketch()),
+            FieldIndex::IntIndex(_)
+            | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::FloatIndex(_)
+            | FieldIndex::GeoIndex(_)
+            | FieldIndex::GeoShapeIndex(_)
+            | FieldIndex::BoolIndex(_)
+            | FieldIndex::FullTextIndex(_)
+            | FieldIndex::UuidIndex(_)
+            | FieldIndex::NullIndex(_)
+            | FieldIndex::NestedNullIndex(_) => None,
+        }
+    }
+
+    /// Space usage of the index storage, available for mutable map indexes on Gridstore only
+    pub fn fragmentation(&self) -> Option<Fragmentation> {
+        match self {
+            FieldIndex::IntMapIndex(index) => index.fragmentation(),
+            FieldIndex::KeywordIndex(index) => index.fragmentation(),
+            FieldIndex::UuidMapIndex(index) => index.fragmentation(),
+            FieldIndex::IntIndex(_)
+            | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::FloatIndex(_)
+            | FieldIndex::GeoIndex(_)
+            | FieldIndex::GeoShapeIndex(_)
+            | FieldIndex::BoolIndex(_)
+            | FieldIndex::FullTextIndex(_)
+            | FieldIndex::UuidIndex(_)
+            | FieldIndex::NullIndex(_)
+            | FieldIndex::NestedNullIndex(_) => None,
+        }
+    }
+
+    /// Rewrite the index storage without fragmented space, returns the number of reclaimed bytes
+    pub fn compact(&mut self) -> OperationResult<usize> {
+        match self {
+            FieldIndex::IntMapIndex(index) => index.compact(),
+            FieldIndex::KeywordIndex(index) => index.compact(),
+            FieldIndex::UuidMapIndex(index) => index.compact(),
+            FieldIndex::IntIndex(_)
+            | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::FloatIndex(_)
+            | FieldIndex::GeoIndex(_)
+            | FieldIndex::GeoShapeIndex(_)
+            | FieldIndex::BoolIndex(_)
+            | FieldIndex::FullTextIndex(_)
+            | FieldIndex::UuidIndex(_)
+            | FieldIndex::NullIndex(_)
+            | FieldIndex::NestedNullIndex(_) => Ok(0),
         }
     }
 
@@ -354,11 +475,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.values_count(point_id),
             FieldIndex::FloatIndex(index) => index.values_count(point_id),
             FieldIndex::GeoIndex(index) => index.values_count(point_id),
+            FieldIndex::GeoShapeIndex(index) => index.values_count(point_id),
             FieldIndex::BoolIndex(index) => index.values_count(point_id),
             FieldIndex::FullTextIndex(index) => index.values_count(point_id),
             FieldIndex::UuidIndex(index) => index.values_count(point_id),
             FieldIndex::UuidMapIndex(index) => index.values_count(point_id),
             FieldIndex::NullIndex(index) => index.values_count(point_id),
+            FieldIndex::NestedNullIndex(index) => index.values_count(point_id),
         }
     }
 
@@ -370,11 +493,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.values_is_empty(point_id),
             FieldIndex::FloatIndex(index) => index.values_is_empty(point_id),
             FieldIndex::GeoIndex(index) => index.values_is_empty(point_id),
+            FieldIndex::GeoShapeIndex(index) => index.values_is_empty(point_id),
             FieldIndex::BoolIndex(index) => index.values_is_empty(point_id),
             FieldIndex::FullTextIndex(index) => index.values_is_empty(point_id),
             FieldIndex::UuidIndex(index) => index.values_is_empty(point_id),
             FieldIndex::UuidMapIndex(index) => index.values_is_empty(point_id),
             FieldIndex::NullIndex(index) => index.values_is_empty(point_id),
+            FieldIndex::NestedNullIndex(index) => index.values_is_empty(point_id),
         }
     }
 
@@ -386,11 +511,13 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(_)
             | FieldIndex::KeywordIndex(_)
             | FieldIndex::GeoIndex(_)
+            | FieldIndex::GeoShapeIndex(_)
             | FieldIndex::BoolIndex(_)
             | FieldIndex::UuidMapIndex(_)
             | FieldIndex::UuidIndex(_)
             | FieldIndex::FullTextIndex(_)
-            | FieldIndex::NullIndex(_) => None,
+            | FieldIndex::NullIndex(_)
+            | FieldIndex::NestedNullIndex(_) => None,
         }
     }
 
@@ -405,8 +532,10 @@ impl FieldIndex {
             | FieldIndex::DatetimeIndex(_)
             | FieldIndex::FloatIndex(_)
             | FieldIndex::GeoIndex(_)
+            | FieldIndex::GeoShapeIndex(_)
             | FieldIndex::FullTextIndex(_)
-            | FieldIndex::NullIndex(_) => None,
+            | FieldIndex::NullIndex(_)
+            | FieldIndex::NestedNullIndex(_) => None,
         }
     }
 
@@ -418,11 +547,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.is_on_disk(),
             FieldIndex::FloatIndex(index) => index.is_on_disk(),
             FieldIndex::GeoIndex(index) => index.is_on_disk(),
+            FieldIndex::GeoShapeIndex(index) => index.is_on_disk(),
             FieldIndex::BoolIndex(index) => index.is_on_disk(),
             FieldIndex::FullTextIndex(index) => index.is_on_disk(),
             FieldIndex::UuidIndex(index) => index.is_on_disk(),
             FieldIndex::UuidMapIndex(index) => index.is_on_disk(),
             FieldIndex::NullIndex(index) => index.is_on_disk(),
+            FieldIndex::NestedNullIndex(index) => index.is_on_disk(),
         }
     }
 
@@ -435,11 +566,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.is_rocksdb(),
             FieldIndex::FloatIndex(index) => index.is_rocksdb(),
             FieldIndex::GeoIndex(index) => index.is_rocksdb(),
+            FieldIndex::GeoShapeIndex(_) => false,
             FieldIndex::BoolIndex(index) => index.is_rocksdb(),
             FieldIndex::FullTextIndex(index) => index.is_rocksdb(),
             FieldIndex::UuidIndex(index) => index.is_rocksdb(),
             FieldIndex::UuidMapIndex(index) => index.is_rocksdb(),
             FieldIndex::NullIndex(_) => false,
+            FieldIndex::NestedNullIndex(_) => false,
         }
     }
 
@@ -453,11 +586,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.populate(),
             FieldIndex::FloatIndex(index) => index.populate(),
             FieldIndex::GeoIndex(index) => index.populate(),
+            FieldIndex::GeoShapeIndex(index) => index.populate(),
             FieldIndex::BoolIndex(index) => index.populate(),
             FieldIndex::FullTextIndex(index) => index.populate(),
             FieldIndex::UuidIndex(index) => index.populate(),
             FieldIndex::UuidMapIndex(index) => index.populate(),
             FieldIndex::NullIndex(index) => index.populate(),
+            FieldIndex::NestedNullIndex(index) => index.populate(),
         }
     }
 
@@ -470,11 +605,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.clear_cache(),
             FieldIndex::FloatIndex(index) => index.clear_cache(),
             FieldIndex::GeoIndex(index) => index.clear_cache(),
+            FieldIndex::GeoShapeIndex(index) => index.clear_cache(),
             FieldIndex::BoolIndex(index) => index.clear_cache(),
             FieldIndex::FullTextIndex(index) => index.clear_cache(),
             FieldIndex::UuidIndex(index) => index.clear_cache(),
             FieldIndex::UuidMapIndex(index) => index.clear_cache(),
             FieldIndex::NullIndex(index) => index.clear_cache(),
+            FieldIndex::NestedNullIndex(index) => index.clear_cache(),
         }
     }
 
@@ -486,11 +623,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(_) => PayloadIndexType::KeywordIndex,
             FieldIndex::FloatIndex(_) => PayloadIndexType::FloatIndex,
             FieldIndex::GeoIndex(_) => PayloadIndexType::GeoIndex,
+            FieldIndex::GeoShapeIndex(_) => PayloadIndexType::GeoShapeIndex,
             FieldIndex::FullTextIndex(_) => PayloadIndexType::FullTextIndex,
             FieldIndex::BoolIndex(_) => PayloadIndexType::BoolIndex,
             FieldIndex::UuidIndex(_) => PayloadIndexType::UuidIndex,
             FieldIndex::UuidMapIndex(_) => PayloadIndexType::UuidMapIndex,
             FieldIndex::NullIndex(_) => PayloadIndexType::NullIndex,
+            FieldIndex::NestedNullIndex(_) => PayloadIndexType::NestedNullIndex,
         };
 
         FullPayloadIndexType {
@@ -508,11 +647,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.get_mutability_type(),
             FieldIndex::FloatIndex(index) => index.get_mutability_type(),
             FieldIndex::GeoIndex(index) => index.get_mutability_type(),
+            FieldIndex::GeoShapeIndex(index) => index.get_mutability_type(),
             FieldIndex::FullTextIndex(index) => index.get_mutability_type(),
             FieldIndex::BoolIndex(index) => index.get_mutability_type(),
             FieldIndex::UuidIndex(index) => index.get_mutability_type(),
             FieldIndex::UuidMapIndex(index) => index.get_mutability_type(),
             FieldIndex::NullIndex(index) => index.get_mutability_type(),
+            FieldIndex::NestedNullIndex(index) => index.get_mutability_type(),
         }
     }
 
@@ -524,11 +665,13 @@ impl FieldIndex {
             FieldIndex::KeywordIndex(index) => index.get_storage_type(),
             FieldIndex::FloatIndex(index) => index.get_storage_type(),
             FieldIndex::GeoIndex(index) => index.get_storage_type(),
+            FieldIndex::GeoShapeIndex(index) => index.get_storage_type(),
             FieldIndex::FullTextIndex(index) => index.get_storage_type(),
             FieldIndex::BoolIndex(index) => index.get_storage_type(),
             FieldIndex::UuidIndex(index) => index.get_storage_type(),
             FieldIndex::UuidMapIndex(index) => index.get_storage_type(),
             FieldIndex::NullIndex(index) => index.get_storage_type(),
+            FieldIndex::NestedNullIndex(index) => index.get_storage_type(),
         }
     }
 }
@@ -577,9 +720,9 @@ pub enum FieldIndexBuilder {
     IntMapMmapIndex(MapIndexMmapBuilder<IntPayloadType>),
     IntMapGridstoreIndex(MapIndexGridstoreBuilder<IntPayloadType>),
     #[cfg(feature = "rocksdb")]
-    KeywordIndex(MapIndexBuilder<str>),
-    KeywordMmapIndex(MapIndexMmapBuilder<str>),
-    KeywordGridstoreIndex(MapIndexGridstoreBuilder<str>),
+    KeywordIndex(KeywordIndexBuilder<MapIndexBuilder<str>>),
+    KeywordMmapIndex(KeywordIndexBuilder<MapIndexMmapBuilder<str>>),
+    KeywordGridstoreIndex(KeywordIndexBuilder<MapIndexGridstoreBuilder<str>>),
     #[cfg(feature = "rocksdb")]
     FloatIndex(NumericIndexBuilder<FloatPayloadType, FloatPayloadType>),
     FloatMmapIndex(NumericIndexMmapBuilder<FloatPayloadType, FloatPayloadType>),
@@ -588,6 +731,7 @@ pub enum FieldIndexBuilder {
     GeoIndex(super::geo_index::GeoMapIndexBuilder),
     GeoMmapIndex(GeoMapIndexMmapBuilder),
     GeoGridstoreIndex(GeoMapIndexGridstoreBuilder),
+    GeoShapeIndex(GeoShapeIndexGridstoreBuilder),
     #[cfg(feature = "rocksdb")]
     FullTextIndex(super::full_text_index::text_index::FullTextIndexRocksDbBuilder),
     FullTextMmapIndex(FullTextMmapIndexBuilder),
@@ -600,6 +744,18 @@ pub enum FieldIndexBuilder {
     UuidMmapIndex(MapIndexMmapBuilder<UuidIntType>),
     UuidGridstoreIndex(MapIndexGridstoreBuilder<UuidIntType>),
     NullIndex(MutableNullIndexBuilder),
+    NestedNullIndex(NestedNullIndexBuilder),
+}
+
+impl FieldIndexBuilder {
+    /// Path of the payload values, which [`FieldIndexBuilderTrait::add_point`] expects instead of
+    /// values of the field, see [`FieldIndex::elements_path`].
+    pub fn elements_path(&self) -> Option<&JsonPath> {
+        match self {
+            Self::NestedNullIndex(builder) => Some(builder.elements_path()),
+            _ => None,
+        }
+    }
 }
 
 impl FieldIndexBuilderTrait for FieldIndexBuilder {
@@ -631,6 +787,7 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::GeoIndex(index) => index.init(),
             Self::GeoMmapIndex(index) => index.init(),
             Self::GeoGridstoreIndex(index) => index.init(),
+            Self::GeoShapeIndex(index) => index.init(),
             #[cfg(feature = "rocksdb")]
             Self::BoolIndex(index) => index.init(),
             Self::BoolMmapIndex(index) => index.init(),
@@ -643,6 +800,7 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::UuidMmapIndex(index) => index.init(),
             Self::UuidGridstoreIndex(index) => index.init(),
             Self::NullIndex(index) => index.init(),
+            Self::NestedNullIndex(index) => index.init(),
         }
     }
 
@@ -677,6 +835,7 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::GeoIndex(index) => index.add_point(id, payload, hw_counter),
             Self::GeoMmapIndex(index) => index.add_point(id, payload, hw_counter),
             Self::GeoGridstoreIndex(index) => index.add_point(id, payload, hw_counter),
+            Self::GeoShapeIndex(index) => index.add_point(id, payload, hw_counter),
             #[cfg(feature = "rocksdb")]
             Self::BoolIndex(index) => index.add_point(id, payload, hw_counter),
             Self::BoolMmapIndex(index) => index.add_point(id, payload, hw_counter),
@@ -693,6 +852,7 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::UuidMmapIndex(index) => index.add_point(id, payload, hw_counter),
             Self::UuidGridstoreIndex(index) => index.add_point(id, payload, hw_counter),
             Self::NullIndex(index) => index.add_point(id, payload, hw_counter),
+            Self::NestedNullIndex(index) => index.add_point(id, payload, hw_counter),
         }
     }
 
@@ -722,6 +882,7 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::GeoIndex(index) => FieldIndex::GeoIndex(index.finalize()?),
             Self::GeoMmapIndex(index) => FieldIndex::GeoIndex(index.finalize()?),
             Self::GeoGridstoreIndex(index) => FieldIndex::GeoIndex(index.finalize()?),
+            Self::GeoShapeIndex(index) => FieldIndex::GeoShapeIndex(index.finalize()?),
             #[cfg(feature = "rocksdb")]
             Self::BoolIndex(index) => FieldIndex::BoolIndex(index.finalize()?),
             Self::BoolMmapIndex(index) => FieldIndex::BoolIndex(index.finalize()?),
@@ -734,6 +895,7 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::UuidMmapIndex(index) => FieldIndex::UuidMapIndex(index.finalize()?),
             Self::UuidGridstoreIndex(index) => FieldIndex::UuidMapIndex(index.finalize()?),
             Self::NullIndex(index) => FieldIndex::NullIndex(index.finalize()?),
+            Self::NestedNullIndex(index) => FieldIndex::NestedNullIndex(index.finalize()?),
         })
     }
 }
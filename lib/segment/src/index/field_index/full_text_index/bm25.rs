@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use ahash::{AHashMap, AHashSet};
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+use ordered_float::OrderedFloat;
+use serde::Serialize;
+
+use super::inverted_index::{InvertedIndex, ParsedQuery, TokenId};
+use super::text_index::FullTextIndex;
+
+/// Term frequency saturation of BM25
+const BM25_K1: f64 = 1.2;
+/// Document length normalization of BM25
+const BM25_B: f64 = 0.75;
+
+/// Corpus statistics of a text query, which BM25 weighs the query tokens with.
+///
+/// Collected over all segments of a shard, so that documents get the same score
+/// regardless of the segment they are stored in.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Bm25CorpusStats {
+    /// Number of indexed documents
+    pub points_count: usize,
+    /// Sum of the numbers of unique tokens in all documents
+    pub tokens_count: usize,
+    /// Number of documents containing each of the query tokens
+    pub doc_freqs: HashMap<String, usize>,
+}
+
+/// Weight of a term of a text query in the BM25 score.
+///
+/// The term is tokenized like the query, so each of its tokens gets the boost.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct TermBoost {
+    pub term: String,
+    pub boost: OrderedFloat<f32>,
+}
+
+/// Scores documents of a full-text index by BM25 relevance to a text query.
+///
+/// The index stores documents as sets of tokens, so every matching token occurs once
+/// and the length of a document is the number of its unique tokens.
+pub struct Bm25Scorer<'a> {
+    index: &'a FullTextIndex,
+    /// Query tokens known to this index, each as a single-token query with the token's IDF,
+    /// multiplied by the boost of the token
+    terms: Vec<(ParsedQuery, f64)>,
+    /// Average number of unique tokens in a document
+    avg_doc_len: f64,
+}
+
+impl FullTextIndex {
+    /// Add the statistics of this index for the tokens of `text` to `stats`.
+    pub fn fill_bm25_stats(
+        &self,
+        text: &str,
+        stats: &mut Bm25CorpusStats,
+        hw_counter: &HardwareCounterCell,
+    ) {
+        let mut tokens = AHashSet::new();
+        self.get_tokenizer().tokenize_query(text, |token| {
+            tokens.insert(token.into_owned());
+        });
+
+        for token in tokens {
+            let doc_freq = self
+                .get_token(&token, hw_counter)
+                .and_then(|token_id| self.get_posting_len(token_id, hw_counter))
+                .unwrap_or(0);
+            *stats.doc_freqs.entry(token).or_default() += doc_freq;
+        }
+
+        stats.points_count += self.points_count();
+        stats.tokens_count += self.total_tokens_count();
+    }
+
+    /// Prepare BM25 scoring of documents against `text`, with the given corpus statistics.
+    ///
+    /// Query tokens which are not in the index don't contribute to the score.
+    /// Tokens of `boosts` terms contribute with the boost as a weight, other tokens with weight 1.
+    pub fn bm25_scorer(
+        &self,
+        text: &str,
+        boosts: &[TermBoost],
+        stats: &Bm25CorpusStats,
+        hw_counter: &HardwareCounterCell,
+    ) -> Bm25Scorer<'_> {
+        let mut tokens = AHashMap::new();
+        self.get_tokenizer().tokenize_query(text, |token| {
+            if let Some(token_id) = self.get_token(token.as_ref(), hw_counter) {
+                tokens.insert(token_id, token);
+            }
+        });
+
+        let mut token_boosts = AHashMap::new();
+        for TermBoost { term, boost } in boosts {
+            self.get_tokenizer().tokenize_query(term, |token| {
+                token_boosts.insert(token.into_owned(), f64::from(boost.0));
+            });
+        }
+
+        let points_count = stats.points_count as f64;
+
+        let terms: Vec<_> = tokens
+            .into_iter()
+            .map(|(token_id, token)| {
+                let doc_freq = stats.doc_freqs.get(token.as_ref()).copied().unwrap_or(0) as f64;
+                let idf = (1.0 + (points_count - doc_freq + 0.5) / (doc_freq + 0.5)).ln();
+                let boost = token_boosts.get(token.as_ref()).copied().unwrap_or(1.0);
+                let query = ParsedQuery::AllTokens([token_id].into_iter().collect());
+                (query, idf * boost)
+            })
+            .collect();
+
+        let avg_doc_len = if points_count == 0.0 {
+            1.0
+        } else {
+            (stats.tokens_count as f64 / points_count).max(1.0)
+        };
+
+        Bm25Scorer {
+            index: self,
+            terms,
+            avg_doc_len,
+        }
+    }
+
+    fn get_posting_len(
+        &self,
+        token_id: TokenId,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<usize> {
+        match self {
+            Self::Mutable(index) => index.inverted_index.get_posting_len(token_id, hw_counter),
+            Self::Immutable(index) => index.inverted_index.get_posting_len(token_id, hw_counter),
+            Self::Mmap(index) => index.inverted_index.get_posting_len(token_id, hw_counter),
+        }
+    }
+
+    fn total_tokens_count(&self) -> usize {
+        match self {
+            Self::Mutable(index) => index.inverted_index.total_tokens_count(),
+            Self::Immutable(index) => index.inverted_index.total_tokens_count(),
+            Self::Mmap(index) => index.inverted_index.total_tokens_count(),
+        }
+    }
+}
+
+impl Bm25Scorer<'_> {
+    /// BM25 score of the document, 0 if none of the query tokens are in it
+    pub fn score(&self, point_id: PointOffsetType) -> f64 {
+        if self.terms.is_empty() {
+            return 0.0;
+        }
+
+        let doc_len = self.index.values_count(point_id);
+        if doc_len == 0 {
+            return 0.0;
+        }
+
+        let length_norm = 1.0 - BM25_B + BM25_B * doc_len as f64 / self.avg_doc_len;
+        let term_weight = (BM25_K1 + 1.0) / (1.0 + BM25_K1 * length_norm);
+
+        self.terms
+            .iter()
+            .filter(|(query, _)| self.index.check_match(query, point_id))
+            .map(|(_, weight)| weight * term_weight)
+            .sum()
+    }
+}
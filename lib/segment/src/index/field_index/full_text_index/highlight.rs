@@ -0,0 +1,441 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::tokenizers::Tokenizer;
+use crate::data_types::index::TextIndexParams;
+use crate::json_path::JsonPath;
+use crate::types::{
+    Condition, FieldCondition, Filter, Match, MatchNear, MatchPhrase, MatchText, MatchTextAny,
+    Payload, PayloadContainer, PayloadFieldSchema, PayloadSchemaParams,
+};
+
+pub const DEFAULT_HIGHLIGHT_PRE_TAG: &str = "<em>";
+pub const DEFAULT_HIGHLIGHT_POST_TAG: &str = "</em>";
+
+/// How to present matches of full-text conditions in the payload
+#[derive(Debug, Clone)]
+pub struct HighlightParams {
+    /// Inserted before each match in fragments
+    pub pre_tag: String,
+    /// Inserted after each match in fragments
+    pub post_tag: String,
+    /// Number of characters before and after matches to include into a fragment.
+    /// If `None`, the whole text is returned as a single fragment.
+    pub fragment_context: Option<usize>,
+}
+
+impl Default for HighlightParams {
+    fn default() -> Self {
+        Self {
+            pre_tag: DEFAULT_HIGHLIGHT_PRE_TAG.to_string(),
+            post_tag: DEFAULT_HIGHLIGHT_POST_TAG.to_string(),
+            fragment_context: None,
+        }
+    }
+}
+
+/// Matches of a full-text query in a single text value
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct TextHighlight {
+    /// Parts of the text with matches, wrapped into pre- and post-tags
+    pub fragments: Vec<String>,
+    /// Character offsets `[start, end)` of matches in the original text
+    pub offsets: Vec<[usize; 2]>,
+}
+
+#[derive(Debug)]
+enum TextQuery {
+    /// Any of the tokens is a match
+    Tokens(HashSet<String>),
+    /// Only a sequence of all tokens is a match
+    Phrase(Vec<String>),
+}
+
+/// Locates matches of a full-text condition in text values.
+///
+/// Token positions are not stored in the full-text index,
+/// so texts are tokenized again, with the same parameters as the index uses.
+#[derive(Debug)]
+pub struct TextHighlighter {
+    tokenizer: Tokenizer,
+    query: TextQuery,
+}
+
+impl TextHighlighter {
+    /// Create a highlighter for a full-text match, or `None` for other kinds of matches
+    pub fn new(params: &TextIndexParams, condition: &Match) -> Option<Self> {
+        let tokenizer = Tokenizer::new_from_text_index_params(params);
+
+        let query = match condition {
+            Match::Text(MatchText { text, .. })
+            | Match::TextAny(MatchTextAny { text_any: text }) => {
+                let mut tokens = HashSet::new();
+                tokenizer.tokenize_query(text, |token| {
+                    tokens.extend(tokenizer.query_synonyms(&token).iter().cloned());
+                    tokens.insert(token.into_owned());
+                });
+                TextQuery::Tokens(tokens)
+            }
+            // Proximity is checked by the index, here all its tokens are highlighted
+            Match::Near(MatchNear { near, .. }) => {
+                let mut tokens = HashSet::new();
+                tokenizer.tokenize_doc(near, |token| {
+                    tokens.insert(token.into_owned());
+                });
+                TextQuery::Tokens(tokens)
+            }
+            // Phrases are parsed as documents, to keep all tokens in order
+            Match::Phrase(MatchPhrase { phrase }) => {
+                let mut tokens = Vec::new();
+                tokenizer.tokenize_doc(phrase, |token| tokens.push(token.into_owned()));
+                TextQuery::Phrase(tokens)
+            }
+            Match::Value(_)
+            | Match::Prefix(_)
+            | Match::Regex(_)
+            | Match::Any(_)
+            | Match::Except(_) => return None,
+        };
+
+        let is_empty = match &query {
+            TextQuery::Tokens(tokens) => tokens.is_empty(),
+            TextQuery::Phrase(tokens) => tokens.is_empty(),
+        };
+        if is_empty {
+            return None;
+        }
+
+        Some(Self { tokenizer, query })
+    }
+
+    /// Sorted, non-overlapping byte ranges of matches in the text
+    fn match_ranges(&self, text: &str) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+
+        match &self.query {
+            TextQuery::Tokens(query_tokens) => {
+                self.tokenizer
+                    .tokenize_doc_with_offsets(text, |range, token| {
+                        if query_tokens.contains(token.as_ref()) {
+                            ranges.push(range);
+                        }
+                    });
+            }
+            TextQuery::Phrase(phrase) => {
+                let mut document = Vec::new();
+                self.tokenizer
+                    .tokenize_doc_with_offsets(text, |range, token| {
+                        document.push((range, token.into_owned()))
+                    });
+
+                for window in document.windows(phrase.len()) {
+                    let is_match = window
+                        .iter()
+                        .zip(phrase)
+                        .all(|((_, token), phrase_token)| token == phrase_token);
+                    if is_match {
+                        ranges.push(window[0].0.start..window[phrase.len() - 1].0.end);
+                    }
+                }
+            }
+        }
+
+        merge_ranges(ranges)
+    }
+
+    pub fn highlight(&self, text: &str, params: &HighlightParams) -> Option<TextHighlight> {
+        let ranges = self.match_ranges(text);
+        if ranges.is_empty() {
+            return None;
+        }
+
+        let offsets = ranges
+            .iter()
+            .map(|range| {
+                let start = text[..range.start].chars().count();
+                [start, start + text[range.clone()].chars().count()]
+            })
+            .collect();
+
+        let windows = match params.fragment_context {
+            None => vec![0..text.len()],
+            Some(context) => merge_ranges(
+                ranges
+                    .iter()
+                    .map(|range| {
+                        let start = text[..range.start]
+                            .char_indices()
+                            .rev()
+                            .take(context)
+                            .last()
+                            .map_or(range.start, |(pos, _)| pos);
+                        let end = text[range.end..]
+                            .char_indices()
+                            .nth(context)
+                            .map_or(text.len(), |(pos, _)| range.end + pos);
+                        start..end
+                    })
+                    .collect(),
+            ),
+        };
+
+        let fragments = windows
+            .into_iter()
+            .map(|window| {
+                let mut fragment = String::with_capacity(window.len());
+                let mut position = window.start;
+                for range in ranges
+                    .iter()
+                    .filter(|range| window.start <= range.start && range.end <= window.end)
+                {
+                    fragment.push_str(&text[position..range.start]);
+                    fragment.push_str(&params.pre_tag);
+                    fragment.push_str(&text[range.clone()]);
+                    fragment.push_str(&params.post_tag);
+                    position = range.end;
+                }
+                fragment.push_str(&text[position..window.end]);
+                fragment
+            })
+            .collect();
+
+        Some(TextHighlight { fragments, offsets })
+    }
+}
+
+/// Sort ranges and merge the overlapping and adjacent ones
+fn merge_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Highlights matches of full-text conditions of a filter in the payload.
+///
+/// Only fields with a full-text index are highlighted.
+#[derive(Debug)]
+pub struct PayloadHighlighter {
+    fields: Vec<(JsonPath, TextHighlighter)>,
+    params: HighlightParams,
+}
+
+impl PayloadHighlighter {
+    pub fn new(
+        filter: &Filter,
+        payload_schema: &HashMap<JsonPath, PayloadFieldSchema>,
+        params: HighlightParams,
+    ) -> Self {
+        let mut fields = Vec::new();
+        collect_text_conditions(filter, None, &mut |key, condition| {
+            let Some(field_schema) = payload_schema.get(&key) else {
+                return;
+            };
+            let field_params = field_schema.expand();
+            let PayloadSchemaParams::Text(text_params) = field_params.as_ref() else {
+                return;
+            };
+            if let Some(highlighter) = TextHighlighter::new(text_params, condition) {
+                fields.push((key, highlighter));
+            }
+        });
+
+        Self { fields, params }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Highlights of each matched field. Fields without matches are omitted.
+    pub fn highlight(&self, payload: &Payload) -> HashMap<JsonPath, Vec<TextHighlight>> {
+        let mut highlights: HashMap<JsonPath, Vec<TextHighlight>> = HashMap::new();
+
+        for (key, highlighter) in &self.fields {
+            for value in payload.get_value(key) {
+                let texts = match value {
+                    Value::Array(values) => values.as_slice(),
+                    value => std::slice::from_ref(value),
+                };
+
+                for text in texts.iter().filter_map(Value::as_str) {
+                    if let Some(highlight) = highlighter.highlight(text, &self.params) {
+                        highlights.entry(key.clone()).or_default().push(highlight);
+                    }
+                }
+            }
+        }
+
+        highlights
+    }
+}
+
+/// Visit full-text conditions, which can match points returned with the filter.
+///
+/// Conditions under `must_not` are skipped, as they never match the returned points.
+fn collect_text_conditions(
+    filter: &Filter,
+    prefix: Option<&JsonPath>,
+    callback: &mut impl FnMut(JsonPath, &Match),
+) {
+    let conditions = filter
+        .must
+        .iter()
+        .flatten()
+        .chain(filter.should.iter().flatten())
+        .chain(
+            filter
+                .min_should
+                .iter()
+                .flat_map(|min_should| &min_should.conditions),
+        );
+
+    for condition in conditions {
+        match condition {
+            Condition::Field(FieldCondition {
+                key,
+                r#match: Some(condition),
+                ..
+            }) => callback(JsonPath::extend_or_new(prefix, key), condition),
+            Condition::Filter(filter) => collect_text_conditions(filter, prefix, callback),
+            Condition::Nested(nested) => {
+                let array_key = JsonPath::extend_or_new(prefix, &nested.array_key());
+                collect_text_conditions(nested.filter(), Some(&array_key), callback);
+            }
+            Condition::Field(_)
+            | Condition::IsEmpty(_)
+            | Condition::IsNull(_)
+            | Condition::HasId(_)
+            | Condition::HasVector(_)
+            | Condition::CustomIdChecker(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payload_json;
+    use crate::types::PayloadSchemaType;
+
+    #[test]
+    fn test_highlight_text() {
+        let highlighter =
+            TextHighlighter::new(&TextIndexParams::default(), &Match::new_text("Quick foxes"))
+                .unwrap();
+
+        let text = "The quick brown fox jumps over the quick dog";
+
+        let highlight = highlighter
+            .highlight(text, &HighlightParams::default())
+            .unwrap();
+        assert_eq!(highlight.offsets, vec![[4, 9], [35, 40]]);
+        assert_eq!(
+            highlight.fragments,
+            vec!["The <em>quick</em> brown fox jumps over the <em>quick</em> dog"],
+        );
+
+        let params = HighlightParams {
+            fragment_context: Some(4),
+            ..Default::default()
+        };
+        let highlight = highlighter.highlight(text, &params).unwrap();
+        assert_eq!(
+            highlight.fragments,
+            vec!["The <em>quick</em> bro", "the <em>quick</em> dog"],
+        );
+
+        // Offsets are in characters, not bytes
+        let highlight = highlighter
+            .highlight("Über quick", &HighlightParams::default())
+            .unwrap();
+        assert_eq!(highlight.offsets, vec![[5, 10]]);
+
+        assert!(
+            highlighter
+                .highlight("slow fox", &HighlightParams::default())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_highlight_phrase() {
+        let highlighter =
+            TextHighlighter::new(&TextIndexParams::default(), &Match::new_phrase("brown fox"))
+                .unwrap();
+
+        let highlight = highlighter
+            .highlight("brown dog and brown fox", &HighlightParams::default())
+            .unwrap();
+        assert_eq!(highlight.offsets, vec![[14, 23]]);
+        assert_eq!(
+            highlight.fragments,
+            vec!["brown dog and <em>brown fox</em>"]
+        );
+    }
+
+    #[test]
+    fn test_payload_highlighter() {
+        let title = JsonPath::new("title");
+        let body = JsonPath::new("body");
+
+        let filter = Filter {
+            must: Some(vec![
+                Condition::Field(FieldCondition::new_match(
+                    title.clone(),
+                    Match::new_text("fox"),
+                )),
+                Condition::Field(FieldCondition::new_match(
+                    body.clone(),
+                    Match::new_text("fox"),
+                )),
+            ]),
+            must_not: Some(vec![Condition::Field(FieldCondition::new_match(
+                title.clone(),
+                Match::new_text("dog"),
+            ))]),
+            ..Default::default()
+        };
+
+        // Only fields with full-text index are highlighted
+        let payload_schema = HashMap::from([
+            (
+                title.clone(),
+                PayloadFieldSchema::FieldType(PayloadSchemaType::Text),
+            ),
+            (
+                body,
+                PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword),
+            ),
+        ]);
+
+        let params = HighlightParams {
+            pre_tag: "[".to_string(),
+            post_tag: "]".to_string(),
+            fragment_context: None,
+        };
+        let highlighter = PayloadHighlighter::new(&filter, &payload_schema, params);
+        assert!(!highlighter.is_empty());
+
+        let payload = payload_json! {
+            "title": ["a fox", "a dog"],
+            "body": "fox",
+        };
+        let highlights = highlighter.highlight(&payload);
+
+        assert_eq!(highlights.len(), 1);
+        assert_eq!(highlights[&title].len(), 1);
+        assert_eq!(highlights[&title][0].fragments, vec!["a [fox]"]);
+    }
+}
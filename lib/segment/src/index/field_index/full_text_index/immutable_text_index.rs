@@ -23,6 +23,8 @@ use crate::index::payload_config::StorageType;
 pub struct ImmutableFullTextIndex {
     pub(super) inverted_index: ImmutableInvertedIndex,
     pub(super) tokenizer: Tokenizer,
+    /// Ratio of documents, above which terms are ignored by text queries
+    pub(super) max_document_frequency: Option<f64>,
     // Backing storage, source of state, persists deletions
     pub(super) storage: Storage,
 }
@@ -60,6 +62,7 @@ impl ImmutableFullTextIndex {
         Ok(Some(Self {
             inverted_index: ImmutableInvertedIndex::from(mutable),
             tokenizer,
+            max_document_frequency: config.max_document_frequency.map(|ratio| ratio.0),
             storage: Storage::RocksDb(db_wrapper),
         }))
     }
@@ -71,6 +74,7 @@ impl ImmutableFullTextIndex {
         // ToDo(rocksdb): this is a duplication of tokenizer,
         // ToDo(rocksdb): But once the RocksDB is removed, we can always use the tokenizer from the index.
         let tokenizer = index.tokenizer.clone();
+        let max_document_frequency = index.max_document_frequency;
 
         // Index is now loaded into memory, clear cache of backing mmap storage
         if let Err(err) = index.clear_cache() {
@@ -81,6 +85,7 @@ impl ImmutableFullTextIndex {
             inverted_index,
             storage: Storage::Mmap(Box::new(index)),
             tokenizer,
+            max_document_frequency,
         }
     }
 
@@ -153,7 +158,7 @@ impl ImmutableFullTextIndex {
     pub fn from_rocksdb_mutable(mutable: MutableFullTextIndex) -> Self {
         let MutableFullTextIndex {
             inverted_index,
-            config: _,
+            config,
             tokenizer,
             storage,
         } = mutable;
@@ -167,6 +172,7 @@ impl ImmutableFullTextIndex {
         Self {
             inverted_index: ImmutableInvertedIndex::from(inverted_index),
             tokenizer,
+            max_document_frequency: config.max_document_frequency.map(|ratio| ratio.0),
             storage: Storage::RocksDb(db),
         }
     }
@@ -6,6 +6,7 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use itertools::Either;
 use posting_list::{PostingBuilder, PostingList, PostingListView, PostingValue};
+use rayon::iter::{IntoParallelIterator as _, ParallelIterator as _};
 
 use super::immutable_postings_enum::ImmutablePostings;
 use super::mmap_inverted_index::MmapInvertedIndex;
@@ -15,10 +16,13 @@ use super::positions::Positions;
 use super::postings_iterator::{
     intersect_compressed_postings_iterator, merge_compressed_postings_iterator,
 };
+use super::term_dictionary::TermDictionary;
 use super::{Document, InvertedIndex, ParsedQuery, TokenId, TokenSet};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::field_index::full_text_index::inverted_index::postings_iterator::{
-    check_compressed_postings_phrase, intersect_compressed_postings_phrase_iterator,
+    check_compressed_postings_phrase, check_compressed_postings_proximity,
+    intersect_compressed_postings_phrase_iterator,
+    intersect_compressed_postings_proximity_iterator,
 };
 
 #[cfg_attr(test, derive(Clone))]
@@ -26,8 +30,12 @@ use crate::index::field_index::full_text_index::inverted_index::postings_iterato
 pub struct ImmutableInvertedIndex {
     pub(in crate::index::field_index::full_text_index) postings: ImmutablePostings,
     pub(in crate::index::field_index::full_text_index) vocab: HashMap<String, TokenId>,
+    /// Same terms as in `vocab`, sorted for fuzzy search
+    pub(in crate::index::field_index::full_text_index) terms: TermDictionary,
     pub(in crate::index::field_index::full_text_index) point_to_tokens_count: Vec<usize>,
     pub(in crate::index::field_index::full_text_index) points_count: usize,
+    /// Sum of the numbers of unique tokens in all documents
+    pub(in crate::index::field_index::full_text_index) tokens_count: usize,
 }
 
 impl ImmutableInvertedIndex {
@@ -220,6 +228,60 @@ impl ImmutableInvertedIndex {
             ImmutablePostings::Ids(_postings) => false,
         }
     }
+
+    /// Iterate over point ids whose documents contain all given tokens close to each other
+    pub fn filter_has_proximity<'a>(
+        &'a self,
+        tokens: TokenSet,
+        within: u32,
+    ) -> impl Iterator<Item = PointOffsetType> + 'a {
+        // in case of mmap immutable index, deleted points are still in the postings
+        let is_active = move |idx| {
+            self.point_to_tokens_count
+                .get(idx as usize)
+                .is_some_and(|x| *x > 0)
+        };
+
+        match &self.postings {
+            ImmutablePostings::WithPositions(postings) => {
+                Either::Right(intersect_compressed_postings_proximity_iterator(
+                    tokens,
+                    within,
+                    |token_id| postings.get(*token_id as usize).map(PostingList::view),
+                    is_active,
+                ))
+            }
+            // cannot do proximity matching if there's no positional information
+            ImmutablePostings::Ids(_postings) => Either::Left(std::iter::empty()),
+        }
+    }
+
+    /// Checks if the point document contains all given tokens close to each other
+    pub fn check_has_proximity(
+        &self,
+        tokens: &TokenSet,
+        within: u32,
+        point_id: PointOffsetType,
+    ) -> bool {
+        // in case of mmap immutable index, deleted points are still in the postings
+        if self
+            .point_to_tokens_count
+            .get(point_id as usize)
+            .is_none_or(|x| *x == 0)
+        {
+            return false;
+        }
+
+        match &self.postings {
+            ImmutablePostings::WithPositions(postings) => {
+                check_compressed_postings_proximity(tokens, within, point_id, |token_id| {
+                    postings.get(*token_id as usize).map(PostingList::view)
+                })
+            }
+            // cannot do proximity matching if there's no positional information
+            ImmutablePostings::Ids(_postings) => false,
+        }
+    }
 }
 
 impl InvertedIndex for ImmutableInvertedIndex {
@@ -253,6 +315,7 @@ impl InvertedIndex for ImmutableInvertedIndex {
         if self.values_is_empty(idx) {
             return false; // Already removed or never actually existed
         }
+        self.tokens_count -= self.point_to_tokens_count[idx as usize];
         self.point_to_tokens_count[idx as usize] = 0;
         self.points_count -= 1;
         true
@@ -261,12 +324,18 @@ impl InvertedIndex for ImmutableInvertedIndex {
     fn filter<'a>(
         &'a self,
         query: ParsedQuery,
-        _hw_counter: &'a HardwareCounterCell,
+        hw_counter: &'a HardwareCounterCell,
     ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
         match query {
             ParsedQuery::AllTokens(tokens) => Box::new(self.filter_has_all(tokens)),
             ParsedQuery::Phrase(tokens) => Box::new(self.filter_has_phrase(tokens)),
+            ParsedQuery::Proximity { tokens, within } => {
+                Box::new(self.filter_has_proximity(tokens, within))
+            }
             ParsedQuery::AnyTokens(tokens) => Box::new(self.filter_has_any(tokens)),
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                self.filter_alternatives(token_sets, hw_counter)
+            }
         }
     }
 
@@ -286,7 +355,13 @@ impl InvertedIndex for ImmutableInvertedIndex {
         match parsed_query {
             ParsedQuery::AllTokens(tokens) => self.check_has_subset(tokens, point_id),
             ParsedQuery::Phrase(phrase) => self.check_has_phrase(phrase, point_id),
+            ParsedQuery::Proximity { tokens, within } => {
+                self.check_has_proximity(tokens, *within, point_id)
+            }
             ParsedQuery::AnyTokens(tokens) => self.check_has_any(tokens, point_id),
+            ParsedQuery::TokenAlternatives(token_sets) => token_sets
+                .iter()
+                .all(|tokens| self.check_has_any(tokens, point_id)),
         }
     }
 
@@ -307,9 +382,17 @@ impl InvertedIndex for ImmutableInvertedIndex {
         self.points_count
     }
 
+    fn total_tokens_count(&self) -> usize {
+        self.tokens_count
+    }
+
     fn get_token_id(&self, token: &str, _: &HardwareCounterCell) -> Option<TokenId> {
         self.vocab.get(token).copied()
     }
+
+    fn get_fuzzy_token_ids(&self, token: &str, fuzziness: u8, _: &HardwareCounterCell) -> TokenSet {
+        self.terms.fuzzy_search(token, fuzziness)
+    }
 }
 
 impl From<MutableInvertedIndex> for ImmutableInvertedIndex {
@@ -320,9 +403,11 @@ impl From<MutableInvertedIndex> for ImmutableInvertedIndex {
             point_to_tokens,
             point_to_doc,
             points_count,
+            tokens_count,
         } = index;
 
         let (postings, vocab, orig_to_new_token) = optimized_postings_and_vocab(postings, vocab);
+        let terms = TermDictionary::from_vocab(vocab.iter().map(|(k, v)| (k.as_str(), *v)));
 
         let postings = match point_to_doc {
             None => ImmutablePostings::Ids(create_compressed_postings(postings)),
@@ -338,6 +423,7 @@ impl From<MutableInvertedIndex> for ImmutableInvertedIndex {
         ImmutableInvertedIndex {
             postings,
             vocab,
+            terms,
             point_to_tokens_count: point_to_tokens
                 .iter()
                 .map(|tokenset| {
@@ -348,6 +434,7 @@ impl From<MutableInvertedIndex> for ImmutableInvertedIndex {
                 })
                 .collect(),
             points_count,
+            tokens_count,
         }
     }
 }
@@ -386,11 +473,12 @@ fn optimized_postings_and_vocab(
     (postings, vocab, orig_to_new_token)
 }
 
+/// Postings are compressed in parallel, their order is preserved
 fn create_compressed_postings(
     postings: Vec<super::posting_list::PostingList>,
 ) -> Vec<PostingList<()>> {
     postings
-        .into_iter()
+        .into_par_iter()
         .map(|posting| {
             let mut builder = PostingBuilder::new();
             for id in posting.iter() {
@@ -408,7 +496,7 @@ fn create_compressed_postings_with_positions(
 ) -> Vec<PostingList<Positions>> {
     // precalculate positions for each token in each document
     let mut point_to_tokens_positions: Vec<AHashMap<TokenId, Positions>> = point_to_doc
-        .into_iter()
+        .into_par_iter()
         .map(|doc_opt| {
             let Some(doc) = doc_opt else {
                 return AHashMap::new();
@@ -475,11 +563,15 @@ impl From<&MmapInvertedIndex> for ImmutableInvertedIndex {
             "postings and vocab must be the same size",
         );
 
+        let terms = TermDictionary::from_vocab(vocab.iter().map(|(k, v)| (k.as_str(), *v)));
+
         ImmutableInvertedIndex {
             postings,
             vocab,
+            terms,
             point_to_tokens_count: index.storage.point_to_tokens_count.to_vec(),
             points_count: index.points_count(),
+            tokens_count: index.total_tokens_count(),
         }
     }
 }
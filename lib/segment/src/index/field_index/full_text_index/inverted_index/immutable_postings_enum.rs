@@ -38,13 +38,11 @@ impl ImmutablePostings {
     ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + '_>> {
         match self {
             ImmutablePostings::Ids(postings) => postings.get(token_id as usize).map(|posting| {
-                Box::new(posting.iter().map(|elem| elem.id))
-                    as Box<dyn Iterator<Item = PointOffsetType>>
+                Box::new(posting.iter_ids()) as Box<dyn Iterator<Item = PointOffsetType>>
             }),
             ImmutablePostings::WithPositions(postings) => {
                 postings.get(token_id as usize).map(|posting| {
-                    Box::new(posting.iter().map(|elem| elem.id))
-                        as Box<dyn Iterator<Item = PointOffsetType>>
+                    Box::new(posting.iter_ids()) as Box<dyn Iterator<Item = PointOffsetType>>
                 })
             }
         }
@@ -34,12 +34,10 @@ impl MmapPostingsEnum {
     ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
         match self {
             MmapPostingsEnum::Ids(postings) => postings.get(token_id).map(|view| {
-                Box::new(view.into_iter().map(|elem| elem.id))
-                    as Box<dyn Iterator<Item = PointOffsetType>>
+                Box::new(view.into_ids_iter()) as Box<dyn Iterator<Item = PointOffsetType>>
             }),
             MmapPostingsEnum::WithPositions(postings) => postings.get(token_id).map(|view| {
-                Box::new(view.into_iter().map(|elem| elem.id))
-                    as Box<dyn Iterator<Item = PointOffsetType>>
+                Box::new(view.into_ids_iter()) as Box<dyn Iterator<Item = PointOffsetType>>
             }),
         }
     }
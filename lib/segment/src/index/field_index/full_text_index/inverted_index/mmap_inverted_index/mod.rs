@@ -6,6 +6,7 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::mmap_hashmap::{MmapHashMap, READ_ENTRY_OVERHEAD};
 use common::types::PointOffsetType;
 use itertools::Either;
+use memmap2::Mmap;
 use memory::fadvise::clear_disk_cache;
 use memory::madvise::AdviceSetting;
 use memory::mmap_ops;
@@ -19,13 +20,16 @@ use super::positions::Positions;
 use super::postings_iterator::{
     intersect_compressed_postings_iterator, merge_compressed_postings_iterator,
 };
+use super::term_dictionary::{TermDictionary, fuzzy_scan};
 use super::{InvertedIndex, ParsedQuery, TokenId, TokenSet};
 use crate::common::Flusher;
 use crate::common::mmap_bitslice_buffered_update_wrapper::MmapBitSliceBufferedUpdateWrapper;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::field_index::full_text_index::inverted_index::Document;
 use crate::index::field_index::full_text_index::inverted_index::postings_iterator::{
-    check_compressed_postings_phrase, intersect_compressed_postings_phrase_iterator,
+    check_compressed_postings_phrase, check_compressed_postings_proximity,
+    intersect_compressed_postings_phrase_iterator,
+    intersect_compressed_postings_proximity_iterator,
 };
 
 pub(super) mod mmap_postings;
@@ -33,6 +37,7 @@ pub mod mmap_postings_enum;
 
 const POSTINGS_FILE: &str = "postings.dat";
 const VOCAB_FILE: &str = "vocab.dat";
+const TERMS_FILE: &str = "terms.fst";
 const POINT_TO_TOKENS_COUNT_FILE: &str = "point_to_tokens_count.dat";
 const DELETED_POINTS_FILE: &str = "deleted_points.dat";
 
@@ -41,12 +46,16 @@ pub struct MmapInvertedIndex {
     pub(in crate::index::field_index::full_text_index) storage: Storage,
     /// Number of points which are not deleted
     pub(in crate::index::field_index::full_text_index) active_points_count: usize,
+    /// Sum of the numbers of unique tokens in points which are not deleted
+    pub(in crate::index::field_index::full_text_index) active_tokens_count: usize,
     is_on_disk: bool,
 }
 
 pub(in crate::index::field_index::full_text_index) struct Storage {
     pub(in crate::index::field_index::full_text_index) postings: MmapPostingsEnum,
     pub(in crate::index::field_index::full_text_index) vocab: MmapHashMap<str, TokenId>,
+    /// Sorted terms for fuzzy search, absent in indexes created by older versions
+    pub(in crate::index::field_index::full_text_index) terms: Option<TermDictionary<Mmap>>,
     pub(in crate::index::field_index::full_text_index) point_to_tokens_count: MmapSlice<usize>,
     pub(in crate::index::field_index::full_text_index) deleted_points:
         MmapBitSliceBufferedUpdateWrapper,
@@ -57,6 +66,7 @@ impl MmapInvertedIndex {
         let ImmutableInvertedIndex {
             postings,
             vocab,
+            terms,
             point_to_tokens_count,
             points_count: _,
         } = inverted_index;
@@ -65,6 +75,7 @@ impl MmapInvertedIndex {
 
         let postings_path = path.join(POSTINGS_FILE);
         let vocab_path = path.join(VOCAB_FILE);
+        let terms_path = path.join(TERMS_FILE);
         let point_to_tokens_count_path = path.join(POINT_TO_TOKENS_COUNT_FILE);
         let deleted_points_path = path.join(DELETED_POINTS_FILE);
 
@@ -82,6 +93,8 @@ impl MmapInvertedIndex {
             vocab.iter().map(|(k, v)| (k.as_str(), std::iter::once(*v))),
         )?;
 
+        fs_err::write(&terms_path, terms.as_bytes())?;
+
         // Save point_to_tokens_count, separated into a bitslice for None values and a slice for actual values
         //
         // None values are represented as deleted in the bitslice
@@ -106,6 +119,7 @@ impl MmapInvertedIndex {
     ) -> OperationResult<Option<Self>> {
         let postings_path = path.join(POSTINGS_FILE);
         let vocab_path = path.join(VOCAB_FILE);
+        let terms_path = path.join(TERMS_FILE);
         let point_to_tokens_count_path = path.join(POINT_TO_TOKENS_COUNT_FILE);
         let deleted_points_path = path.join(DELETED_POINTS_FILE);
 
@@ -122,6 +136,10 @@ impl MmapInvertedIndex {
             )?),
         };
         let vocab = MmapHashMap::<str, TokenId>::open(&vocab_path, false)?;
+        let terms = terms_path
+            .is_file()
+            .then(|| TermDictionary::open(&terms_path, populate))
+            .transpose()?;
 
         let point_to_tokens_count = unsafe {
             MmapSlice::try_from(mmap_ops::open_write_mmap(
@@ -136,6 +154,12 @@ impl MmapInvertedIndex {
         let deleted = MmapBitSlice::from(deleted, 0);
 
         let num_deleted_points = deleted.count_ones();
+        let tokens_count: usize = point_to_tokens_count
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !deleted.get(*idx).is_some_and(|is_deleted| *is_deleted))
+            .map(|(_, count)| count)
+            .sum();
         let deleted_points = MmapBitSliceBufferedUpdateWrapper::new(deleted);
         let points_count = point_to_tokens_count.len() - num_deleted_points;
 
@@ -144,10 +168,12 @@ impl MmapInvertedIndex {
             storage: Storage {
                 postings,
                 vocab,
+                terms,
                 point_to_tokens_count,
                 deleted_points,
             },
             active_points_count: points_count,
+            active_tokens_count: tokens_count,
             is_on_disk: !populate,
         }))
     }
@@ -347,21 +373,74 @@ impl MmapInvertedIndex {
         }
     }
 
+    /// Iterate over point ids whose documents contain all given tokens close to each other
+    pub fn filter_has_proximity<'a>(
+        &'a self,
+        tokens: TokenSet,
+        within: u32,
+    ) -> impl Iterator<Item = PointOffsetType> + 'a {
+        // in case of mmap immutable index, deleted points are still in the postings
+        let is_active = move |idx| self.is_active(idx);
+
+        match &self.storage.postings {
+            MmapPostingsEnum::WithPositions(postings) => {
+                Either::Right(intersect_compressed_postings_proximity_iterator(
+                    tokens,
+                    within,
+                    |token_id| postings.get(*token_id),
+                    is_active,
+                ))
+            }
+            // cannot do proximity matching if there's no positional information
+            MmapPostingsEnum::Ids(_postings) => Either::Left(std::iter::empty()),
+        }
+    }
+
+    pub fn check_has_proximity(
+        &self,
+        tokens: &TokenSet,
+        within: u32,
+        point_id: PointOffsetType,
+    ) -> bool {
+        // in case of mmap immutable index, deleted points are still in the postings
+        if !self.is_active(point_id) {
+            return false;
+        }
+
+        match &self.storage.postings {
+            MmapPostingsEnum::WithPositions(postings) => {
+                check_compressed_postings_proximity(tokens, within, point_id, |token_id| {
+                    postings.get(*token_id)
+                })
+            }
+            // cannot do proximity matching if there's no positional information
+            MmapPostingsEnum::Ids(_postings) => false,
+        }
+    }
+
     pub fn files(&self) -> Vec<PathBuf> {
-        vec![
+        let mut files = vec![
             self.path.join(POSTINGS_FILE),
             self.path.join(VOCAB_FILE),
             self.path.join(POINT_TO_TOKENS_COUNT_FILE),
             self.path.join(DELETED_POINTS_FILE),
-        ]
+        ];
+        if self.storage.terms.is_some() {
+            files.push(self.path.join(TERMS_FILE));
+        }
+        files
     }
 
     pub fn immutable_files(&self) -> Vec<PathBuf> {
-        vec![
+        let mut files = vec![
             self.path.join(POSTINGS_FILE),
             self.path.join(VOCAB_FILE),
             self.path.join(POINT_TO_TOKENS_COUNT_FILE),
-        ]
+        ];
+        if self.storage.terms.is_some() {
+            files.push(self.path.join(TERMS_FILE));
+        }
+        files
     }
 
     pub fn flusher(&self) -> Flusher {
@@ -377,6 +456,9 @@ impl MmapInvertedIndex {
     pub fn populate(&self) -> OperationResult<()> {
         self.storage.postings.populate();
         self.storage.vocab.populate()?;
+        if let Some(terms) = &self.storage.terms {
+            terms.populate();
+        }
         self.storage.point_to_tokens_count.populate()?;
         Ok(())
     }
@@ -430,6 +512,7 @@ impl InvertedIndex for MmapInvertedIndex {
 
         self.storage.deleted_points.set(idx as usize, true);
         if let Some(count) = self.storage.point_to_tokens_count.get_mut(idx as usize) {
+            self.active_tokens_count -= *count;
             *count = 0;
 
             // `deleted_points`'s length can be larger than `point_to_tokens_count`'s length.
@@ -443,12 +526,18 @@ impl InvertedIndex for MmapInvertedIndex {
     fn filter<'a>(
         &'a self,
         query: ParsedQuery,
-        _hw_counter: &HardwareCounterCell,
+        hw_counter: &'a HardwareCounterCell,
     ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
         match query {
             ParsedQuery::AllTokens(tokens) => self.filter_has_all(tokens),
             ParsedQuery::Phrase(phrase) => Box::new(self.filter_has_phrase(phrase)),
+            ParsedQuery::Proximity { tokens, within } => {
+                Box::new(self.filter_has_proximity(tokens, within))
+            }
             ParsedQuery::AnyTokens(tokens) => Box::new(self.filter_has_any(tokens)),
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                self.filter_alternatives(token_sets, hw_counter)
+            }
         }
     }
 
@@ -473,7 +562,13 @@ impl InvertedIndex for MmapInvertedIndex {
         match parsed_query {
             ParsedQuery::AllTokens(tokens) => self.check_has_subset(tokens, point_id),
             ParsedQuery::Phrase(phrase) => self.check_has_phrase(phrase, point_id),
+            ParsedQuery::Proximity { tokens, within } => {
+                self.check_has_proximity(tokens, *within, point_id)
+            }
             ParsedQuery::AnyTokens(tokens) => self.check_has_any(tokens, point_id),
+            ParsedQuery::TokenAlternatives(token_sets) => token_sets
+                .iter()
+                .all(|tokens| self.check_has_any(tokens, point_id)),
         }
     }
 
@@ -516,6 +611,10 @@ impl InvertedIndex for MmapInvertedIndex {
         self.active_points_count
     }
 
+    fn total_tokens_count(&self) -> usize {
+        self.active_tokens_count
+    }
+
     fn get_token_id(&self, token: &str, hw_counter: &HardwareCounterCell) -> Option<TokenId> {
         if self.is_on_disk {
             hw_counter.payload_index_io_read_counter().incr_delta(
@@ -531,4 +630,29 @@ impl InvertedIndex for MmapInvertedIndex {
             .and_then(<[TokenId]>::first)
             .copied()
     }
+
+    fn get_fuzzy_token_ids(
+        &self,
+        token: &str,
+        fuzziness: u8,
+        hw_counter: &HardwareCounterCell,
+    ) -> TokenSet {
+        let token_ids = match &self.storage.terms {
+            Some(terms) => terms.fuzzy_search(token, fuzziness),
+            None => fuzzy_scan(
+                self.iter_vocab().map(|(term, &token_id)| (term, token_id)),
+                token,
+                fuzziness,
+            ),
+        };
+
+        if self.is_on_disk {
+            // Only account for the matched terms, like for the exact lookups
+            hw_counter
+                .payload_index_io_read_counter()
+                .incr_delta(token_ids.len() * (READ_ENTRY_OVERHEAD + size_of::<TokenId>()));
+        }
+
+        token_ids
+    }
 }
@@ -6,6 +6,7 @@ pub(super) mod mutable_inverted_index_builder;
 mod positions;
 mod posting_list;
 mod postings_iterator;
+mod term_dictionary;
 
 use std::cmp::min;
 use std::collections::HashMap;
@@ -15,6 +16,7 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use itertools::Itertools;
 
+pub use self::positions::has_all_within;
 use crate::common::operation_error::OperationResult;
 use crate::index::field_index::{CardinalityEstimation, PayloadBlockCondition, PrimaryCondition};
 use crate::index::query_estimator::expected_should_estimation;
@@ -130,6 +132,23 @@ impl Document {
         // simple check for tokens in the same order as phrase
         doc.windows(phrase.len()).any(|window| window == phrase)
     }
+
+    /// Checks if the current document contains all given tokens, with at most `within` other
+    /// tokens between them, in any order.
+    ///
+    /// Returns false if there are no tokens
+    pub fn has_proximity(&self, tokens: &TokenSet, within: u32) -> bool {
+        let occurrences = self
+            .0
+            .iter()
+            .enumerate()
+            .filter_map(|(position, token_id)| {
+                let token_idx = tokens.tokens().binary_search(token_id).ok()?;
+                Some((token_idx, position as u32))
+            })
+            .collect::<Vec<_>>();
+        has_all_within(&occurrences, tokens.len(), within)
+    }
 }
 
 impl IntoIterator for Document {
@@ -161,6 +180,16 @@ pub enum ParsedQuery {
 
     /// All these tokens must be present in the document, in the same order as this query.
     Phrase(Document),
+
+    /// All these tokens must be present in the document, in any order, with at most `within`
+    /// other tokens between them.
+    Proximity { tokens: TokenSet, within: u32 },
+
+    /// At least one token of each set must be present in the document.
+    ///
+    /// Each set contains the alternatives of one token of the query, e.g. similar tokens for fuzzy
+    /// matching, or synonyms.
+    TokenAlternatives(Vec<TokenSet>),
 }
 
 pub trait InvertedIndex {
@@ -216,6 +245,43 @@ pub trait InvertedIndex {
         hw_counter: &'a HardwareCounterCell,
     ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a>;
 
+    /// Iterate over point ids whose documents contain at least one token of each set.
+    ///
+    /// Candidates are taken from the set with the shortest postings, and checked against the rest.
+    fn filter_alternatives<'a>(
+        &'a self,
+        token_sets: Vec<TokenSet>,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
+        let mut queries = token_sets
+            .into_iter()
+            .map(|tokens| {
+                let postings_len: usize = tokens
+                    .tokens()
+                    .iter()
+                    .filter_map(|&token_id| self.get_posting_len(token_id, hw_counter))
+                    .sum();
+                (postings_len, ParsedQuery::AnyTokens(tokens))
+            })
+            .sorted_unstable_by_key(|(postings_len, _)| *postings_len)
+            .map(|(_, query)| query);
+
+        let Some(candidates_query) = queries.next() else {
+            // Empty request -> no matches
+            return Box::new(std::iter::empty());
+        };
+        let queries = queries.collect_vec();
+
+        Box::new(
+            self.filter(candidates_query, hw_counter)
+                .filter(move |&point_id| {
+                    queries
+                        .iter()
+                        .all(|query| self.check_match(query, point_id))
+                }),
+        )
+    }
+
     fn get_posting_len(&self, token_id: TokenId, hw_counter: &HardwareCounterCell)
     -> Option<usize>;
 
@@ -232,9 +298,15 @@ pub trait InvertedIndex {
             ParsedQuery::Phrase(phrase) => {
                 self.estimate_has_phrase_cardinality(phrase, condition, hw_counter)
             }
+            ParsedQuery::Proximity { tokens, within: _ } => {
+                self.estimate_has_proximity_cardinality(tokens, condition, hw_counter)
+            }
             ParsedQuery::AnyTokens(tokens) => {
                 self.estimate_has_any_cardinality(tokens, condition, hw_counter)
             }
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                self.estimate_alternatives_cardinality(token_sets, condition, hw_counter)
+            }
         }
     }
 
@@ -323,6 +395,39 @@ pub trait InvertedIndex {
         }
     }
 
+    fn estimate_alternatives_cardinality(
+        &self,
+        token_sets: &[TokenSet],
+        condition: &FieldCondition,
+        hw_counter: &HardwareCounterCell,
+    ) -> CardinalityEstimation {
+        let points_count = self.points_count();
+
+        // Each set matches like an "any" query, and all of them must match
+        let estimations = token_sets
+            .iter()
+            .map(|tokens| self.estimate_has_any_cardinality(tokens, condition, hw_counter))
+            .collect_vec();
+
+        if estimations.is_empty() || points_count == 0 {
+            return CardinalityEstimation::exact(0)
+                .with_primary_clause(PrimaryCondition::Condition(Box::new(condition.clone())));
+        }
+
+        let max = estimations.iter().map(|estimation| estimation.max).min();
+        let expected_frac: f64 = estimations
+            .iter()
+            .map(|estimation| estimation.exp as f64 / points_count as f64)
+            .product();
+
+        CardinalityEstimation {
+            primary_clauses: vec![PrimaryCondition::Condition(Box::new(condition.clone()))],
+            min: 0,
+            exp: (expected_frac * points_count as f64) as usize,
+            max: max.unwrap_or(0),
+        }
+    }
+
     fn estimate_has_phrase_cardinality(
         &self,
         phrase: &Document,
@@ -350,6 +455,26 @@ pub trait InvertedIndex {
         }
     }
 
+    fn estimate_has_proximity_cardinality(
+        &self,
+        tokens: &TokenSet,
+        condition: &FieldCondition,
+        hw_counter: &HardwareCounterCell,
+    ) -> CardinalityEstimation {
+        // Documents with all tokens are the upper bound, some of them have tokens too far apart
+        let subset_estimation = self.estimate_has_subset_cardinality(tokens, condition, hw_counter);
+        if tokens.len() <= 1 {
+            return subset_estimation;
+        }
+
+        CardinalityEstimation {
+            primary_clauses: vec![PrimaryCondition::Condition(Box::new(condition.clone()))],
+            min: 0,
+            exp: subset_estimation.exp / tokens.len(),
+            max: subset_estimation.max,
+        }
+    }
+
     fn vocab_with_postings_len_iter(&self) -> impl Iterator<Item = (&str, usize)> + '_;
 
     fn payload_blocks(
@@ -382,7 +507,18 @@ pub trait InvertedIndex {
 
     fn points_count(&self) -> usize;
 
+    /// Sum of the numbers of unique tokens in all documents
+    fn total_tokens_count(&self) -> usize;
+
     fn get_token_id(&self, token: &str, hw_counter: &HardwareCounterCell) -> Option<TokenId>;
+
+    /// Ids of all tokens within `fuzziness` edits from the given token, including the token itself
+    fn get_fuzzy_token_ids(
+        &self,
+        token: &str,
+        fuzziness: u8,
+        hw_counter: &HardwareCounterCell,
+    ) -> TokenSet;
 }
 
 #[cfg(test)]
@@ -578,6 +714,12 @@ mod tests {
         // Check same points count
         assert_eq!(immutable.points_count, mmap.active_points_count);
         assert_eq!(immutable.points_count, imm_mmap.points_count);
+
+        // Check same tokens count
+        let expected_tokens_count: usize = immutable.point_to_tokens_count.iter().sum();
+        assert_eq!(immutable.tokens_count, expected_tokens_count);
+        assert_eq!(mmap.active_tokens_count, expected_tokens_count);
+        assert_eq!(imm_mmap.tokens_count, expected_tokens_count);
     }
 
     #[rstest]
@@ -6,6 +6,7 @@ use itertools::Either;
 
 use super::posting_list::PostingList;
 use super::postings_iterator::{intersect_postings_iterator, merge_postings_iterator};
+use super::term_dictionary::fuzzy_scan;
 use super::{Document, InvertedIndex, ParsedQuery, TokenId, TokenSet};
 use crate::common::operation_error::OperationResult;
 
@@ -20,6 +21,8 @@ pub struct MutableInvertedIndex {
     /// Must be enabled explicitly.
     pub point_to_doc: Option<Vec<Option<Document>>>,
     pub(super) points_count: usize,
+    /// Sum of the numbers of unique tokens in all documents
+    pub(super) tokens_count: usize,
 }
 
 impl MutableInvertedIndex {
@@ -31,6 +34,7 @@ impl MutableInvertedIndex {
             point_to_tokens: Vec::new(),
             point_to_doc: with_positions.then_some(Vec::new()),
             points_count: 0,
+            tokens_count: 0,
         }
     }
 
@@ -119,6 +123,27 @@ impl MutableInvertedIndex {
 
         Box::new(iter)
     }
+
+    pub fn filter_has_proximity(
+        &self,
+        tokens: TokenSet,
+        within: u32,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        let Some(point_to_doc) = self.point_to_doc.as_ref() else {
+            // Return empty iterator when not enabled
+            return Box::new(std::iter::empty());
+        };
+
+        let iter = self.filter_has_all(tokens.clone()).filter(move |id| {
+            let doc = point_to_doc[*id as usize]
+                .as_ref()
+                .expect("if it passed the intersection filter, it must exist");
+
+            doc.has_proximity(&tokens, within)
+        });
+
+        Box::new(iter)
+    }
 }
 
 impl InvertedIndex for MutableInvertedIndex {
@@ -133,6 +158,7 @@ impl InvertedIndex for MutableInvertedIndex {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         self.points_count += 1;
+        self.tokens_count += tokens.len();
 
         let mut hw_cell_wb = hw_counter
             .payload_index_io_write_counter()
@@ -212,6 +238,7 @@ impl InvertedIndex for MutableInvertedIndex {
         }
 
         self.points_count -= 1;
+        self.tokens_count -= removed_token_set.len();
 
         for removed_token in removed_token_set.tokens() {
             // unwrap safety: posting list exists and contains the point idx
@@ -222,15 +249,19 @@ impl InvertedIndex for MutableInvertedIndex {
         true
     }
 
-    fn filter(
-        &self,
+    fn filter<'a>(
+        &'a self,
         query: ParsedQuery,
-        _hw_counter: &HardwareCounterCell,
-    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
         match query {
             ParsedQuery::AllTokens(tokens) => Box::new(self.filter_has_all(tokens)),
             ParsedQuery::Phrase(phrase) => self.filter_has_phrase(phrase),
+            ParsedQuery::Proximity { tokens, within } => self.filter_has_proximity(tokens, within),
             ParsedQuery::AnyTokens(tokens) => Box::new(self.filter_has_any(tokens)),
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                self.filter_alternatives(token_sets, hw_counter)
+            }
         }
     }
 
@@ -264,6 +295,14 @@ impl InvertedIndex for MutableInvertedIndex {
                 // Check that all tokens are in document, in order
                 doc.has_phrase(document)
             }
+            ParsedQuery::Proximity { tokens, within } => {
+                let Some(doc) = self.get_document(point_id) else {
+                    return false;
+                };
+
+                // Check that all tokens are in document, close to each other
+                doc.has_proximity(tokens, *within)
+            }
             ParsedQuery::AnyTokens(query) => {
                 let Some(doc) = self.get_tokens(point_id) else {
                     return false;
@@ -272,6 +311,14 @@ impl InvertedIndex for MutableInvertedIndex {
                 // Check that at least one token is in document
                 doc.has_any(query)
             }
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                let Some(doc) = self.get_tokens(point_id) else {
+                    return false;
+                };
+
+                // Check that at least one token of each set is in document
+                token_sets.iter().all(|tokens| doc.has_any(tokens))
+            }
         }
     }
 
@@ -288,7 +335,25 @@ impl InvertedIndex for MutableInvertedIndex {
         self.points_count
     }
 
+    fn total_tokens_count(&self) -> usize {
+        self.tokens_count
+    }
+
     fn get_token_id(&self, token: &str, _hw_counter: &HardwareCounterCell) -> Option<TokenId> {
         self.vocab.get(token).copied()
     }
+
+    /// Mutable index has no sorted term dictionary, so all terms are checked
+    fn get_fuzzy_token_ids(
+        &self,
+        token: &str,
+        fuzziness: u8,
+        _hw_counter: &HardwareCounterCell,
+    ) -> TokenSet {
+        let vocab = self
+            .vocab
+            .iter()
+            .map(|(term, &token_id)| (term.as_str(), token_id));
+        fuzzy_scan(vocab, token, fuzziness)
+    }
 }
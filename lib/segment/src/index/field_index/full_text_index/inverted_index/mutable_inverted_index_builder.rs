@@ -40,6 +40,7 @@ impl MutableInvertedIndexBuilder {
 
         // insert as tokenset
         let tokens_set = TokenSet::from_iter(tokens);
+        self.index.tokens_count += tokens_set.len();
         self.index.point_to_tokens[idx as usize] = Some(tokens_set);
     }
 
@@ -1,7 +1,7 @@
 use posting_list::{PostingValue, UnsizedHandler, UnsizedValue};
 use zerocopy::{FromBytes, IntoBytes};
 
-use crate::index::field_index::full_text_index::inverted_index::{Document, TokenId};
+use crate::index::field_index::full_text_index::inverted_index::{Document, TokenId, TokenSet};
 
 /// Represents a list of positions of a token in a document.
 #[derive(Default, Clone, Debug)]
@@ -92,6 +92,19 @@ impl PartialDocument {
         }
     }
 
+    /// Returns true if all tokens occur with at most `within` other tokens between them, in any order.
+    pub fn has_proximity(&self, tokens: &TokenSet, within: u32) -> bool {
+        let occurrences = self
+            .0
+            .iter()
+            .filter_map(|tok_pos| {
+                let token_idx = tokens.tokens().binary_search(&tok_pos.token_id).ok()?;
+                Some((token_idx, tok_pos.position))
+            })
+            .collect::<Vec<_>>();
+        has_all_within(&occurrences, tokens.len(), within)
+    }
+
     /// Returns an iterator over windows which have sequential sequence of tokens.
     ///
     /// Will only return a window if:
@@ -111,3 +124,92 @@ impl PartialDocument {
         })
     }
 }
+
+/// Checks if all `num_tokens` distinct tokens occur in a window of the document,
+/// which has at most `within` positions not taken by matched tokens.
+///
+/// `occurrences` are pairs of token index, in `0..num_tokens`, and position, sorted by position.
+pub fn has_all_within(occurrences: &[(usize, u32)], num_tokens: usize, within: u32) -> bool {
+    if num_tokens == 0 {
+        return false;
+    }
+
+    // Sliding window over occurrences, shrunk from the left while it covers all tokens
+    let mut counts = vec![0usize; num_tokens];
+    let mut covered = 0;
+    let mut left = 0;
+    for &(token_idx, right_position) in occurrences {
+        if counts[token_idx] == 0 {
+            covered += 1;
+        }
+        counts[token_idx] += 1;
+
+        while covered == num_tokens {
+            let (left_token_idx, left_position) = occurrences[left];
+            let window_len = u64::from(right_position - left_position) + 1;
+            if window_len <= num_tokens as u64 + u64::from(within) {
+                return true;
+            }
+
+            counts[left_token_idx] -= 1;
+            if counts[left_token_idx] == 0 {
+                covered -= 1;
+            }
+            left += 1;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_all_within() {
+        // tokens: 0 = "quick", 1 = "fox"
+        // "the quick brown fox jumps over the lazy quick dog fox"
+        let occurrences = [(0, 1), (1, 3), (0, 8), (1, 10)];
+        assert!(!has_all_within(&occurrences, 2, 0));
+        assert!(has_all_within(&occurrences, 2, 1));
+        assert!(has_all_within(&occurrences, 2, 5));
+
+        // Order doesn't matter
+        let occurrences = [(1, 0), (0, 1)];
+        assert!(has_all_within(&occurrences, 2, 0));
+
+        // Missing token
+        let occurrences = [(0, 0), (0, 1), (2, 2)];
+        assert!(!has_all_within(&occurrences, 3, 10));
+
+        // Single token matches any occurrence
+        assert!(has_all_within(&[(0, 7)], 1, 0));
+        assert!(!has_all_within(&[], 1, 0));
+        assert!(!has_all_within(&[], 0, 0));
+    }
+
+    #[test]
+    fn test_partial_document_proximity() {
+        let document = PartialDocument::new(vec![
+            TokenPosition {
+                token_id: 5,
+                position: 4,
+            },
+            TokenPosition {
+                token_id: 2,
+                position: 0,
+            },
+            TokenPosition {
+                token_id: 9,
+                position: 2,
+            },
+        ]);
+        let tokens = TokenSet::from_iter([2, 5, 9]);
+        assert!(!document.has_proximity(&tokens, 1));
+        assert!(document.has_proximity(&tokens, 2));
+
+        let tokens = TokenSet::from_iter([2, 5]);
+        assert!(!document.has_proximity(&tokens, 2));
+        assert!(document.has_proximity(&tokens, 3));
+    }
+}
@@ -6,7 +6,7 @@ use super::posting_list::PostingList;
 use crate::index::field_index::full_text_index::inverted_index::positions::{
     PartialDocument, Positions, TokenPosition,
 };
-use crate::index::field_index::full_text_index::inverted_index::{Document, TokenId};
+use crate::index::field_index::full_text_index::inverted_index::{Document, TokenId, TokenSet};
 
 pub fn intersect_postings_iterator<'a>(
     mut postings: Vec<&'a PostingList>,
@@ -45,29 +45,25 @@ pub fn intersect_compressed_postings_iterator<'a, V: PostingValue + 'a>(
         .map(|(idx, _posting)| idx)
         .unwrap();
     let smallest_posting = postings.remove(smallest_posting_idx);
-    let smallest_posting_iterator = smallest_posting.into_iter();
 
+    // Only ids are needed, so values (a.k.a. positions) are never read from the posting lists
     let mut posting_iterators = postings
         .into_iter()
-        .map(PostingListView::into_iter)
+        .map(PostingListView::into_ids_iter)
         .collect::<Vec<_>>();
 
-    smallest_posting_iterator
-        .map(|elem| elem.id)
-        .filter(move |id| {
-            is_active(*id)
-                && posting_iterators.iter_mut().all(|posting_iterator| {
-                    // Custom "contains" check, which leverages the fact that smallest posting is sorted,
-                    // so the next id that must be in all postings is strictly greater than the previous one.
-                    //
-                    // This means that the other iterators can remember the last id they returned to avoid extra work
-                    posting_iterator
-                        // potential optimization: Make posting iterator of just ids, without values (a.k.a. positions).
-                        //                         We are discarding them here, thus unnecessarily reading them from the tails of the posting lists.
-                        .advance_until_greater_or_equal(*id)
-                        .is_some_and(|elem| elem.id == *id)
-                })
-        })
+    smallest_posting.into_ids_iter().filter(move |id| {
+        is_active(*id)
+            && posting_iterators.iter_mut().all(|posting_iterator| {
+                // Custom "contains" check, which leverages the fact that smallest posting is sorted,
+                // so the next id that must be in all postings is strictly greater than the previous one.
+                //
+                // This means that the other iterators can remember the last id they returned to avoid extra work
+                posting_iterator
+                    .advance_until_greater_or_equal(*id)
+                    .is_some_and(|next_id| next_id == *id)
+            })
+    })
 }
 
 pub fn merge_compressed_postings_iterator<'a, V: PostingValue + 'a>(
@@ -76,9 +72,7 @@ pub fn merge_compressed_postings_iterator<'a, V: PostingValue + 'a>(
 ) -> impl Iterator<Item = PointOffsetType> + 'a {
     postings
         .into_iter()
-        // potential optimization: Make posting iterator of just ids, without values (a.k.a. positions).
-        //                         We are discarding them here, thus unnecessarily reading them from the tails of the posting lists.
-        .map(|view| view.into_iter().map(|elem| elem.id))
+        .map(PostingListView::into_ids_iter)
         .kmerge_by(|a, b| a < b)
         .dedup()
         .filter(move |id| is_active(*id))
@@ -90,13 +84,44 @@ pub fn intersect_compressed_postings_phrase_iterator<'a>(
     token_to_posting: impl Fn(&TokenId) -> Option<PostingListView<'a, Positions>>,
     is_active: impl Fn(PointOffsetType) -> bool + 'a,
 ) -> impl Iterator<Item = PointOffsetType> + 'a {
-    if phrase.is_empty() {
+    intersect_compressed_postings_positions_iterator(
+        phrase.to_token_set(),
+        token_to_posting,
+        is_active,
+        move |partial_document| partial_document.has_phrase(&phrase),
+    )
+}
+
+/// Returns an iterator over the points that match the given proximity query.
+pub fn intersect_compressed_postings_proximity_iterator<'a>(
+    tokens: TokenSet,
+    within: u32,
+    token_to_posting: impl Fn(&TokenId) -> Option<PostingListView<'a, Positions>>,
+    is_active: impl Fn(PointOffsetType) -> bool + 'a,
+) -> impl Iterator<Item = PointOffsetType> + 'a {
+    let query_tokens = tokens.clone();
+    intersect_compressed_postings_positions_iterator(
+        tokens,
+        token_to_posting,
+        is_active,
+        move |partial_document| partial_document.has_proximity(&query_tokens, within),
+    )
+}
+
+/// Returns an iterator over the points that contain all tokens, and whose partial document
+/// of these tokens satisfies `check_positions`.
+fn intersect_compressed_postings_positions_iterator<'a>(
+    tokens: TokenSet,
+    token_to_posting: impl Fn(&TokenId) -> Option<PostingListView<'a, Positions>>,
+    is_active: impl Fn(PointOffsetType) -> bool + 'a,
+    check_positions: impl Fn(&PartialDocument) -> bool + 'a,
+) -> impl Iterator<Item = PointOffsetType> + 'a {
+    if tokens.is_empty() {
         // Empty request -> no matches
         return Either::Left(std::iter::empty());
     }
 
-    let postings_opt: Option<Vec<_>> = phrase
-        .to_token_set()
+    let postings_opt: Option<Vec<_>> = tokens
         .tokens()
         .iter()
         .map(|token_id| token_to_posting(token_id).map(|posting| (*token_id, posting)))
@@ -121,7 +146,7 @@ pub fn intersect_compressed_postings_phrase_iterator<'a>(
         .map(|(token_id, posting)| (token_id, posting.into_iter()))
         .collect::<Vec<_>>();
 
-    let has_phrase_iter = smallest_posting_iterator
+    let matching_iter = smallest_posting_iterator
         .filter(move |elem| {
             if !is_active(elem.id) {
                 return false;
@@ -129,49 +154,46 @@ pub fn intersect_compressed_postings_phrase_iterator<'a>(
 
             let initial_tokens_positions = elem.value.to_token_positions(smallest_posting_token);
 
-            phrase_in_all_postings(
+            partial_document_from_postings(
                 elem.id,
-                &phrase,
                 initial_tokens_positions,
                 &mut posting_iterators,
             )
+            .is_some_and(|partial_document| check_positions(&partial_document))
         })
         .map(|elem| elem.id);
 
-    Either::Right(has_phrase_iter)
+    Either::Right(matching_iter)
 }
 
 /// Reconstructs a partial document from the posting lists (which contain positions)
 ///
-/// Returns true if the document contains the entire phrase, in the same order.
+/// Returns `None` if the document doesn't contain all the tokens of the postings.
 ///
 /// # Arguments
 ///
 /// - `initial_tokens_positions` - must be prepopulated if iterating over a posting not included in the `posting_iterators`.
-fn phrase_in_all_postings<'a>(
+fn partial_document_from_postings<'a>(
     id: PointOffsetType,
-    phrase: &Document,
     initial_tokens_positions: Vec<TokenPosition>,
     posting_iterators: &mut Vec<(TokenId, PostingIterator<'a, Positions>)>,
-) -> bool {
+) -> Option<PartialDocument> {
     let mut tokens_positions = initial_tokens_positions;
     for (token_id, posting_iterator) in posting_iterators.iter_mut() {
         // Custom "contains" check, which leverages the fact that smallest posting is sorted,
         // so the next id that must be in all postings is strictly greater than the previous one.
         //
         // This means that the other iterators can remember the last id they returned to avoid extra work
-        let Some(other) = posting_iterator.advance_until_greater_or_equal(id) else {
-            return false;
-        };
+        let other = posting_iterator.advance_until_greater_or_equal(id)?;
 
         if id != other.id {
-            return false;
+            return None;
         }
 
         debug_assert!(!other.value.is_empty());
         tokens_positions.extend(other.value.to_token_positions(*token_id))
     }
-    PartialDocument::new(tokens_positions).has_phrase(phrase)
+    Some(PartialDocument::new(tokens_positions))
 }
 
 pub fn check_compressed_postings_phrase<'a>(
@@ -179,18 +201,34 @@ pub fn check_compressed_postings_phrase<'a>(
     point_id: PointOffsetType,
     token_to_posting: impl Fn(&TokenId) -> Option<PostingListView<'a, Positions>>,
 ) -> bool {
-    let Some(mut posting_iterators): Option<Vec<_>> = phrase
-        .to_token_set()
+    check_compressed_postings_positions(&phrase.to_token_set(), point_id, token_to_posting)
+        .is_some_and(|partial_document| partial_document.has_phrase(phrase))
+}
+
+pub fn check_compressed_postings_proximity<'a>(
+    tokens: &TokenSet,
+    within: u32,
+    point_id: PointOffsetType,
+    token_to_posting: impl Fn(&TokenId) -> Option<PostingListView<'a, Positions>>,
+) -> bool {
+    check_compressed_postings_positions(tokens, point_id, token_to_posting)
+        .is_some_and(|partial_document| partial_document.has_proximity(tokens, within))
+}
+
+/// Reconstructs a partial document of the point, if it contains all tokens
+fn check_compressed_postings_positions<'a>(
+    tokens: &TokenSet,
+    point_id: PointOffsetType,
+    token_to_posting: impl Fn(&TokenId) -> Option<PostingListView<'a, Positions>>,
+) -> Option<PartialDocument> {
+    // not all tokens are present in the index -> no document
+    let mut posting_iterators: Vec<_> = tokens
         .tokens()
         .iter()
         .map(|token_id| token_to_posting(token_id).map(|posting| (*token_id, posting.into_iter())))
-        .collect()
-    else {
-        // not all tokens are present in the index
-        return false;
-    };
+        .collect::<Option<_>>()?;
 
-    phrase_in_all_postings(point_id, phrase, Vec::new(), &mut posting_iterators)
+    partial_document_from_postings(point_id, Vec::new(), &mut posting_iterators)
 }
 
 #[cfg(test)]
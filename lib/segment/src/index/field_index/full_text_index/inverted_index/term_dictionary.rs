@@ -0,0 +1,198 @@
+use std::fmt;
+use std::path::Path;
+
+use fst::automaton::Levenshtein;
+use fst::{Automaton, IntoStreamer, Map, Streamer};
+use itertools::Itertools;
+use memmap2::Mmap;
+use memory::madvise::{AdviceSetting, Madviseable};
+use memory::mmap_ops;
+
+use super::{TokenId, TokenSet};
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::types::MAX_TEXT_FUZZINESS;
+
+/// Terms of the inverted index in a sorted FST, mapping each term to its token id.
+///
+/// Unlike a hash map, it allows to search terms by edit distance, without scanning all of them.
+#[cfg_attr(test, derive(Clone))]
+pub struct TermDictionary<D = Vec<u8>> {
+    map: Map<D>,
+}
+
+impl TermDictionary {
+    pub fn from_vocab<'a>(vocab: impl IntoIterator<Item = (&'a str, TokenId)>) -> Self {
+        // FST requires terms to be inserted in lexicographic order
+        let terms = vocab
+            .into_iter()
+            .sorted_unstable_by_key(|(term, _)| *term)
+            .map(|(term, token_id)| (term, u64::from(token_id)));
+
+        // Building in memory fails only on unsorted or duplicate terms, which vocab can't have
+        let map = Map::from_iter(terms).expect("vocab terms are sorted and unique");
+        Self { map }
+    }
+}
+
+impl TermDictionary<Mmap> {
+    pub fn open(path: &Path, populate: bool) -> OperationResult<Self> {
+        let mmap = mmap_ops::open_read_mmap(path, AdviceSetting::Global, populate)?;
+        let map = Map::new(mmap).map_err(|err| {
+            OperationError::service_error(format!("Failed to open term dictionary: {err}"))
+        })?;
+        Ok(Self { map })
+    }
+
+    pub fn populate(&self) {
+        self.map.as_fst().as_inner().populate();
+    }
+}
+
+impl<D: AsRef<[u8]>> TermDictionary<D> {
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.map.as_fst().as_bytes()
+    }
+
+    pub fn get(&self, term: &str) -> Option<TokenId> {
+        self.map.get(term).map(|token_id| token_id as TokenId)
+    }
+
+    /// Token ids of all terms within `fuzziness` edits from the given `term`, including the term itself
+    pub fn fuzzy_search(&self, term: &str, fuzziness: u8) -> TokenSet {
+        let Some(automaton) = levenshtein(term, fuzziness) else {
+            return self.get(term).into_iter().collect();
+        };
+
+        let mut stream = self.map.search(automaton).into_stream();
+        let mut token_ids = Vec::new();
+        while let Some((_, token_id)) = stream.next() {
+            token_ids.push(token_id as TokenId);
+        }
+        token_ids.into_iter().collect()
+    }
+}
+
+impl<D: AsRef<[u8]>> fmt::Debug for TermDictionary<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TermDictionary")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Token ids of all terms within `fuzziness` edits from the given `term`, for vocabularies which are
+/// not stored in a [`TermDictionary`].
+///
+/// Checks every term of the vocabulary, so it is only suitable for small ones.
+pub fn fuzzy_scan<'a>(
+    vocab: impl IntoIterator<Item = (&'a str, TokenId)>,
+    term: &str,
+    fuzziness: u8,
+) -> TokenSet {
+    let Some(automaton) = levenshtein(term, fuzziness) else {
+        return vocab
+            .into_iter()
+            .filter_map(|(candidate, token_id)| (candidate == term).then_some(token_id))
+            .collect();
+    };
+
+    vocab
+        .into_iter()
+        .filter_map(|(candidate, token_id)| {
+            let mut state = automaton.start();
+            for byte in candidate.bytes() {
+                state = automaton.accept(&state, byte);
+                if !automaton.can_match(&state) {
+                    return None;
+                }
+            }
+            automaton.is_match(&state).then_some(token_id)
+        })
+        .collect()
+}
+
+/// Build an automaton, which matches terms within `fuzziness` edits from the `term`.
+///
+/// Levenshtein automatons grow quickly with the distance, so it is capped.
+/// Returns `None` if the automaton gets too large anyway, e.g. for very long terms.
+/// Only exact matches of the term are possible then.
+fn levenshtein(term: &str, fuzziness: u8) -> Option<Levenshtein> {
+    let fuzziness = fuzziness.min(MAX_TEXT_FUZZINESS);
+    match Levenshtein::new(term, u32::from(fuzziness)) {
+        Ok(automaton) => Some(automaton),
+        Err(err) => {
+            log::debug!("Falling back to exact match of term {term:?}: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VOCAB: [(&str, TokenId); 6] = [
+        ("hello", 0),
+        ("help", 1),
+        ("world", 2),
+        ("word", 3),
+        ("hallo", 4),
+        ("yellow", 5),
+    ];
+
+    #[test]
+    fn test_fuzzy_search() {
+        let dictionary = TermDictionary::from_vocab(VOCAB);
+        assert_eq!(dictionary.len(), VOCAB.len());
+        assert_eq!(dictionary.get("world"), Some(2));
+        assert_eq!(dictionary.get("wor"), None);
+
+        assert_eq!(dictionary.fuzzy_search("hello", 0).tokens(), &[0]);
+        assert_eq!(dictionary.fuzzy_search("hello", 1).tokens(), &[0, 4]);
+        assert_eq!(dictionary.fuzzy_search("hello", 2).tokens(), &[0, 1, 4, 5]);
+        assert_eq!(dictionary.fuzzy_search("wrld", 1).tokens(), &[2]);
+        assert_eq!(dictionary.fuzzy_search("wrld", 2).tokens(), &[2, 3]);
+        assert!(dictionary.fuzzy_search("quick", 2).is_empty());
+
+        // Fuzziness is capped
+        assert_eq!(
+            dictionary.fuzzy_search("hello", 5).tokens(),
+            dictionary
+                .fuzzy_search("hello", MAX_TEXT_FUZZINESS)
+                .tokens(),
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_scan_matches_dictionary() {
+        let dictionary = TermDictionary::from_vocab(VOCAB);
+        for term in ["hello", "wrld", "yelow", "word", "help", "xyz"] {
+            for fuzziness in 0..=MAX_TEXT_FUZZINESS {
+                assert_eq!(
+                    fuzzy_scan(VOCAB, term, fuzziness).tokens(),
+                    dictionary.fuzzy_search(term, fuzziness).tokens(),
+                    "term {term}, fuzziness {fuzziness}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_mmap_dictionary() {
+        let dir = tempfile::Builder::new().prefix("terms").tempdir().unwrap();
+        let path = dir.path().join("terms.fst");
+        fs_err::write(&path, TermDictionary::from_vocab(VOCAB).as_bytes()).unwrap();
+
+        let dictionary = TermDictionary::open(&path, false).unwrap();
+        assert_eq!(dictionary.len(), VOCAB.len());
+        assert_eq!(dictionary.fuzzy_search("hallo", 1).tokens(), &[0, 4]);
+    }
+}
@@ -1,8 +1,10 @@
 use std::path::PathBuf;
 
+use ahash::AHashSet;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use fs_err as fs;
+use rayon::iter::{IntoParallelRefIterator as _, ParallelIterator as _};
 use serde_json::Value;
 
 use super::inverted_index::immutable_inverted_index::ImmutableInvertedIndex;
@@ -22,6 +24,8 @@ use crate::index::field_index::{FieldIndexBuilderTrait, ValueIndexer};
 pub struct MmapFullTextIndex {
     pub(super) inverted_index: MmapInvertedIndex,
     pub(super) tokenizer: Tokenizer,
+    /// Ratio of documents, above which terms are ignored by text queries
+    pub(super) max_document_frequency: Option<f64>,
 }
 
 impl MmapFullTextIndex {
@@ -39,6 +43,7 @@ impl MmapFullTextIndex {
         Ok(inverted_index.map(|inverted_index| Self {
             inverted_index,
             tokenizer,
+            max_document_frequency: config.max_document_frequency.map(|ratio| ratio.0),
         }))
     }
 
@@ -92,12 +97,18 @@ impl MmapFullTextIndex {
     }
 }
 
+/// Number of documents, which are tokenized in parallel as a single batch
+const TOKENIZE_BATCH_SIZE: usize = 1024;
+
 pub struct FullTextMmapIndexBuilder {
     path: PathBuf,
     mutable_index: MutableInvertedIndex,
     config: TextIndexParams,
     is_on_disk: bool,
     tokenizer: Tokenizer,
+    /// Values of added documents, which are not tokenized yet
+    pending_documents: Vec<(PointOffsetType, Vec<String>)>,
+    pending_ids: AHashSet<PointOffsetType>,
 }
 
 impl FullTextMmapIndexBuilder {
@@ -110,7 +121,40 @@ impl FullTextMmapIndexBuilder {
             config,
             is_on_disk,
             tokenizer,
+            pending_documents: Vec::with_capacity(TOKENIZE_BATCH_SIZE),
+            pending_ids: AHashSet::with_capacity(TOKENIZE_BATCH_SIZE),
+        }
+    }
+
+    /// Tokenize pending documents in parallel and add their tokens to the index.
+    ///
+    /// Tokens are registered in the order the documents were added, so token ids and the
+    /// resulting index are the same as if documents were indexed one by one.
+    fn index_pending_documents(&mut self, hw_counter: &HardwareCounterCell) -> OperationResult<()> {
+        let pending_documents = std::mem::take(&mut self.pending_documents);
+        self.pending_ids.clear();
+
+        let tokenizer = &self.tokenizer;
+        let documents_tokens: Vec<_> = pending_documents
+            .par_iter()
+            .map(|(_, values)| tokenizer.tokenize_values(values))
+            .collect();
+
+        for ((id, _), str_tokens) in pending_documents.iter().zip(documents_tokens) {
+            let tokens = self.mutable_index.register_tokens(&str_tokens);
+
+            if self.mutable_index.point_to_doc.is_some() {
+                let document = Document::new(tokens.clone());
+                self.mutable_index
+                    .index_document(*id, document, hw_counter)?;
+            }
+
+            let token_set = TokenSet::from_iter(tokens);
+            self.mutable_index
+                .index_tokens(*id, token_set, hw_counter)?;
         }
+
+        Ok(())
     }
 }
 
@@ -134,29 +178,22 @@ impl ValueIndexer for FullTextMmapIndexBuilder {
             return Ok(());
         }
 
-        let mut str_tokens = Vec::new();
+        self.pending_documents.push((id, values));
+        self.pending_ids.insert(id);
 
-        for value in &values {
-            self.tokenizer.tokenize_doc(value, |token| {
-                str_tokens.push(token);
-            });
+        if self.pending_documents.len() >= TOKENIZE_BATCH_SIZE {
+            self.index_pending_documents(hw_counter)?;
         }
 
-        let tokens = self.mutable_index.register_tokens(&str_tokens);
-
-        if self.mutable_index.point_to_doc.is_some() {
-            let document = Document::new(tokens.clone());
-            self.mutable_index
-                .index_document(id, document, hw_counter)?;
-        }
-
-        let token_set = TokenSet::from_iter(tokens);
-        self.mutable_index.index_tokens(id, token_set, hw_counter)?;
-
         Ok(())
     }
 
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        if self.pending_ids.remove(&id) {
+            self.pending_documents
+                .retain(|(pending_id, _)| *pending_id != id);
+        }
+
         self.mutable_index.remove(id);
 
         Ok(())
@@ -179,13 +216,18 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
         ValueIndexer::add_point(self, id, payload, hw_counter)
     }
 
-    fn finalize(self) -> OperationResult<Self::FieldIndexType> {
+    fn finalize(mut self) -> OperationResult<Self::FieldIndexType> {
+        // Index building is internal, the last batch is not measured
+        self.index_pending_documents(&HardwareCounterCell::disposable())?;
+
         let Self {
             path,
             mutable_index,
             config,
             is_on_disk,
             tokenizer,
+            pending_documents: _,
+            pending_ids: _,
         } = self;
 
         let immutable = ImmutableInvertedIndex::from(mutable_index);
@@ -196,6 +238,7 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
 
         let populate = !is_on_disk;
         let has_positions = config.phrase_matching.unwrap_or_default();
+        let max_document_frequency = config.max_document_frequency.map(|ratio| ratio.0);
         let inverted_index =
             MmapInvertedIndex::open(path, populate, has_positions)?.ok_or_else(|| {
                 OperationError::service_error(
@@ -206,6 +249,7 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
         let mmap_index = MmapFullTextIndex {
             inverted_index,
             tokenizer: tokenizer.clone(),
+            max_document_frequency,
         };
 
         let text_index = if is_on_disk {
@@ -214,6 +258,7 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
             FullTextIndex::Immutable(ImmutableFullTextIndex {
                 inverted_index: immutable,
                 tokenizer,
+                max_document_frequency,
                 storage: Storage::Mmap(Box::new(mmap_index)),
             })
         };
@@ -1,3 +1,5 @@
+pub mod bm25;
+pub mod highlight;
 mod immutable_text_index;
 mod inverted_index;
 pub mod mmap_text_index;
@@ -6,5 +8,7 @@ pub mod stop_words;
 pub mod text_index;
 pub mod tokenizers;
 
+pub use inverted_index::has_all_within;
+
 #[cfg(test)]
 mod tests;
@@ -1,4 +1,3 @@
-use std::borrow::Cow;
 use std::path::PathBuf;
 
 use common::counter::hardware_counter::HardwareCounterCell;
@@ -213,13 +212,7 @@ impl MutableFullTextIndex {
             return Ok(());
         }
 
-        let mut str_tokens: Vec<Cow<str>> = Vec::new();
-
-        for value in &values {
-            self.tokenizer.tokenize_doc(value, |token| {
-                str_tokens.push(token);
-            });
-        }
+        let str_tokens = self.tokenizer.tokenize_values(&values);
 
         let tokens = self.inverted_index.register_tokens(&str_tokens);
 
@@ -395,8 +388,16 @@ mod tests {
             on_disk: None,
             stopwords: None,
             stemmer: None,
+            language_detection: None,
+            detected_language_key: None,
+            unicode_normalization: None,
             ascii_folding: None,
             enable_hnsw: None,
+            populate: None,
+            synonyms: None,
+            filters: None,
+            shingles: None,
+            max_document_frequency: None,
         };
 
         {
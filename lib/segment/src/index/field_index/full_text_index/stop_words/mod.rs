@@ -100,6 +100,41 @@ impl StopwordsFilter {
         this
     }
 
+    /// Configured stopwords of the `option`, together with stopwords of the given `language`.
+    pub fn new_with_language(
+        option: &Option<StopwordsInterface>,
+        language: &Language,
+        lowercase: bool,
+    ) -> Self {
+        let mut this = Self::new(option, lowercase);
+        this.add_language_stopwords(language, lowercase);
+        this
+    }
+
+    /// Stopwords of the given `language`, together with custom stopwords of the `option`.
+    /// Languages selected in the `option` are ignored.
+    pub fn new_for_language(
+        option: &Option<StopwordsInterface>,
+        language: Option<&Language>,
+        lowercase: bool,
+    ) -> Self {
+        let mut this = Self::default();
+
+        if let Some(language) = language {
+            this.add_language_stopwords(language, lowercase);
+        }
+
+        if let Some(StopwordsInterface::Set(set)) = option
+            && let Some(custom) = set.custom.as_ref()
+        {
+            for word in custom {
+                this.add_stopword(word, lowercase);
+            }
+        }
+
+        this
+    }
+
     /// Check if a token is a stopword
     pub fn is_stopword(&self, token: &str) -> bool {
         self.stopwords.contains(token)
@@ -176,6 +211,19 @@ mod tests {
         assert!(!filter.is_stopword("hello"));
     }
 
+    #[test]
+    fn test_stopwords_for_language() {
+        let option = Some(StopwordsInterface::new_set(
+            &[Language::English],
+            &["hallo"],
+        ));
+        let filter = StopwordsFilter::new_for_language(&option, Some(&Language::German), true);
+
+        assert!(filter.is_stopword("und"));
+        assert!(filter.is_stopword("hallo"));
+        assert!(!filter.is_stopword("the"));
+    }
+
     #[test]
     fn test_custom_stopwords() {
         let option = Some(StopwordsInterface::new_custom(&["hello", "world"]));
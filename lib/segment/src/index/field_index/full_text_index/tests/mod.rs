@@ -2,11 +2,19 @@ mod test_congruence;
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
+use ordered_float::OrderedFloat;
+use rstest::rstest;
 use tempfile::Builder;
 
-use crate::data_types::index::{TextIndexParams, TextIndexType, TokenizerType};
+use crate::data_types::index::{
+    Snowball, SnowballLanguage, SnowballParams, StemmingAlgorithm, SynonymsParams, TextIndexParams,
+    TextIndexType, TokenizerType,
+};
+use crate::index::field_index::full_text_index::bm25::{Bm25CorpusStats, TermBoost};
 use crate::index::field_index::full_text_index::text_index::FullTextIndex;
-use crate::index::field_index::{FieldIndexBuilderTrait as _, ValueIndexer};
+use crate::index::field_index::{FieldIndexBuilderTrait as _, PayloadFieldIndex, ValueIndexer};
+use crate::json_path::JsonPath;
+use crate::types::MatchText;
 
 fn movie_titles() -> Vec<String> {
     vec![
@@ -164,8 +172,16 @@ fn test_prefix_search() {
         stopwords: None,
         on_disk: None,
         stemmer: None,
+        language_detection: None,
+        detected_language_key: None,
+        unicode_normalization: None,
         ascii_folding: None,
         enable_hnsw: None,
+        populate: None,
+        synonyms: None,
+        filters: None,
+        shingles: None,
+        max_document_frequency: None,
     };
 
     let mut index =
@@ -215,8 +231,16 @@ fn test_phrase_matching() {
         phrase_matching: Some(true), // Enable phrase matching
         stopwords: None,
         stemmer: None,
+        language_detection: None,
+        detected_language_key: None,
+        unicode_normalization: None,
         ascii_folding: None,
         enable_hnsw: None,
+        populate: None,
+        synonyms: None,
+        filters: None,
+        shingles: None,
+        max_document_frequency: None,
     };
 
     let mut mutable_index =
@@ -310,6 +334,100 @@ fn test_phrase_matching() {
     check_matching(mmap_index);
 }
 
+#[test]
+fn test_proximity_matching() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::default(),
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        on_disk: None,
+        phrase_matching: Some(true),
+        stopwords: None,
+        stemmer: None,
+        language_detection: None,
+        detected_language_key: None,
+        unicode_normalization: None,
+        ascii_folding: None,
+        enable_hnsw: None,
+        populate: None,
+        synonyms: None,
+        filters: None,
+        shingles: None,
+        max_document_frequency: None,
+    };
+
+    let mut mutable_index =
+        FullTextIndex::builder_gridstore(temp_dir.path().to_path_buf(), config.clone())
+            .make_empty()
+            .unwrap();
+
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(temp_dir.path().to_path_buf(), config.clone(), true);
+    mmap_builder.init().unwrap();
+
+    let documents = vec![
+        (0, "the quick brown fox jumps over the lazy dog".to_string()),
+        (1, "fox quick".to_string()),
+        (2, "quick and very very slow fox".to_string()),
+        (3, "the lazy dog sleeps peacefully".to_string()),
+    ];
+
+    for (point_id, text) in documents {
+        mutable_index
+            .add_many(point_id, vec![text.clone()], &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(point_id, vec![text], &hw_counter)
+            .unwrap();
+    }
+
+    let mmap_index = mmap_builder.finalize().unwrap();
+
+    let check_matching = |index: FullTextIndex| {
+        let filter = |text: &str, within: u32| {
+            let query = index
+                .parse_proximity_query(text, within, &hw_counter)
+                .unwrap();
+            let mut results: Vec<_> = index.filter_query(query.clone(), &hw_counter).collect();
+            results.sort_unstable();
+
+            // Point checks must agree with filtering
+            for point_id in 0..4 {
+                assert_eq!(
+                    index.check_match(&query, point_id),
+                    results.contains(&point_id),
+                );
+            }
+
+            results
+        };
+
+        // Adjacent tokens in any order
+        assert_eq!(filter("quick fox", 0), vec![1]);
+        // One token in between
+        assert_eq!(filter("quick fox", 1), vec![0, 1]);
+        // Four tokens in between
+        assert_eq!(filter("fox quick", 4), vec![0, 1, 2]);
+        assert_eq!(filter("lazy dog the", 0), vec![0, 3]);
+        assert_eq!(filter("quick dog", 3), Vec::<PointOffsetType>::new());
+
+        // Unknown token can't match anything
+        assert!(
+            index
+                .parse_proximity_query("quick bird", 10, &hw_counter)
+                .is_none()
+        );
+    };
+
+    check_matching(mutable_index);
+    check_matching(mmap_index);
+}
+
 #[test]
 fn test_ascii_folding_in_full_text_index_word() {
     let hw_counter = HardwareCounterCell::default();
@@ -325,8 +443,16 @@ fn test_ascii_folding_in_full_text_index_word() {
         phrase_matching: None,
         stopwords: None,
         stemmer: None,
+        language_detection: None,
+        detected_language_key: None,
+        unicode_normalization: None,
         ascii_folding: Some(true),
         enable_hnsw: None,
+        populate: None,
+        synonyms: None,
+        filters: None,
+        shingles: None,
+        max_document_frequency: None,
     };
     let config_disabled = TextIndexParams {
         ascii_folding: Some(false),
@@ -398,3 +524,698 @@ fn test_ascii_folding_in_full_text_index_word() {
         .collect();
     assert!(results_acento2.contains(&0));
 }
+
+#[test]
+fn test_stemming_in_full_text_index() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        on_disk: None,
+        phrase_matching: Some(true),
+        stopwords: None,
+        stemmer: Some(StemmingAlgorithm::Snowball(SnowballParams {
+            r#type: Snowball::Snowball,
+            language: SnowballLanguage::English,
+        })),
+        language_detection: None,
+        detected_language_key: None,
+        unicode_normalization: None,
+        ascii_folding: None,
+        enable_hnsw: None,
+        populate: None,
+        synonyms: None,
+        filters: None,
+        shingles: None,
+        max_document_frequency: None,
+    };
+
+    let mut mutable_index =
+        FullTextIndex::builder_gridstore(temp_dir.path().to_path_buf(), config.clone())
+            .make_empty()
+            .unwrap();
+
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(temp_dir.path().to_path_buf(), config.clone(), true);
+    mmap_builder.init().unwrap();
+
+    let documents = vec![
+        (0, "The dog is running in the park".to_string()),
+        (1, "Dogs run faster than cats".to_string()),
+        (2, "A cat sleeps on the sofa".to_string()),
+    ];
+
+    for (point_id, text) in documents {
+        mutable_index
+            .add_many(point_id, vec![text.clone()], &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(point_id, vec![text], &hw_counter)
+            .unwrap();
+    }
+
+    let mmap_index = mmap_builder.finalize().unwrap();
+
+    let check_matching = |index: FullTextIndex| {
+        // Query terms are stemmed the same way as indexed ones
+        let query = index.parse_text_query("run", &hw_counter).unwrap();
+        let mut results: Vec<_> = index.filter_query(query, &hw_counter).collect();
+        results.sort_unstable();
+        assert_eq!(results, vec![0, 1]);
+
+        let query = index.parse_text_query("running dogs", &hw_counter).unwrap();
+        assert!(index.check_match(&query, 0));
+        assert!(index.check_match(&query, 1));
+        assert!(!index.check_match(&query, 2));
+
+        let query = index
+            .parse_text_query("sleeping cats", &hw_counter)
+            .unwrap();
+        let results: Vec<_> = index.filter_query(query, &hw_counter).collect();
+        assert_eq!(results, vec![2]);
+
+        // Phrases are matched on stems as well
+        let query = index.parse_phrase_query("dogs runs", &hw_counter).unwrap();
+        let results: Vec<_> = index.filter_query(query, &hw_counter).collect();
+        assert_eq!(results, vec![1]);
+    };
+
+    check_matching(mutable_index);
+    check_matching(mmap_index);
+}
+
+#[test]
+fn test_fuzzy_match_in_full_text_index() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        on_disk: None,
+        phrase_matching: None,
+        stopwords: None,
+        stemmer: None,
+        language_detection: None,
+        detected_language_key: None,
+        unicode_normalization: None,
+        ascii_folding: None,
+        enable_hnsw: None,
+        populate: None,
+        synonyms: None,
+        filters: None,
+        shingles: None,
+        max_document_frequency: None,
+    };
+
+    let documents = [
+        (0, "The quick brown fox"),
+        (1, "A quack from the pond"),
+        (2, "Brown bears are quick"),
+        (3, "Slow green turtle"),
+    ];
+
+    let mut mutable_index =
+        FullTextIndex::builder_gridstore(temp_dir.path().join("mutable"), config.clone())
+            .make_empty()
+            .unwrap();
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(temp_dir.path().join("mmap"), config.clone(), true);
+    mmap_builder.init().unwrap();
+    let mut immutable_builder =
+        FullTextIndex::builder_mmap(temp_dir.path().join("immutable"), config, false);
+    immutable_builder.init().unwrap();
+
+    for (point_id, text) in documents {
+        let values = vec![text.to_string()];
+        mutable_index
+            .add_many(point_id, values.clone(), &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(point_id, values.clone(), &hw_counter)
+            .unwrap();
+        immutable_builder
+            .add_many(point_id, values, &hw_counter)
+            .unwrap();
+    }
+
+    let check_fuzzy_matching = |index: FullTextIndex| {
+        let filter = |text: &str, fuzziness: u8| {
+            let Some(query) = index.parse_fuzzy_text_query(text, fuzziness, &hw_counter) else {
+                return vec![];
+            };
+            let mut results: Vec<_> = index.filter_query(query, &hw_counter).collect();
+            results.sort_unstable();
+            results
+        };
+
+        // Typo is not matched exactly
+        assert!(filter("qick", 0).is_empty());
+        assert_eq!(filter("qick", 1), vec![0, 2]);
+
+        // Each token may match a different term, but all tokens must match
+        assert_eq!(filter("quock brwn", 1), vec![0, 2]);
+        assert_eq!(filter("quick", 2), vec![0, 1, 2]);
+        assert!(filter("quick turtle", 1).is_empty());
+
+        let query = index
+            .parse_fuzzy_text_query("quak pnd", 1, &hw_counter)
+            .unwrap();
+        assert!(index.check_match(&query, 1));
+        assert!(!index.check_match(&query, 0));
+        assert!(!index.check_match(&query, 3));
+
+        // Too far from any term
+        assert!(
+            index
+                .parse_fuzzy_text_query("elephant", 2, &hw_counter)
+                .is_none()
+        );
+    };
+
+    check_fuzzy_matching(mutable_index);
+    check_fuzzy_matching(mmap_builder.finalize().unwrap());
+    check_fuzzy_matching(immutable_builder.finalize().unwrap());
+}
+
+#[rstest]
+fn test_synonyms_in_full_text_index(#[values(false, true)] expand_on_index: bool) {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        on_disk: None,
+        phrase_matching: None,
+        stopwords: None,
+        stemmer: None,
+        language_detection: None,
+        detected_language_key: None,
+        unicode_normalization: None,
+        ascii_folding: None,
+        enable_hnsw: None,
+        populate: None,
+        synonyms: Some(SynonymsParams {
+            groups: vec![vec!["car".to_string(), "automobile".to_string()]],
+            file: None,
+            expand_on_index: Some(expand_on_index),
+        }),
+        filters: None,
+        shingles: None,
+        max_document_frequency: None,
+    };
+
+    let documents = [
+        (0, "A red car"),
+        (1, "An old automobile"),
+        (2, "A red bike"),
+    ];
+
+    let mut mutable_index =
+        FullTextIndex::builder_gridstore(temp_dir.path().join("mutable"), config.clone())
+            .make_empty()
+            .unwrap();
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(temp_dir.path().join("mmap"), config.clone(), true);
+    mmap_builder.init().unwrap();
+
+    for (point_id, text) in documents {
+        let values = vec![text.to_string()];
+        mutable_index
+            .add_many(point_id, values.clone(), &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(point_id, values, &hw_counter)
+            .unwrap();
+    }
+
+    let check_synonyms = |index: FullTextIndex| {
+        let filter = |text: &str| {
+            let Some(query) = index.parse_text_query(text, &hw_counter) else {
+                return vec![];
+            };
+            let mut results: Vec<_> = index.filter_query(query, &hw_counter).collect();
+            results.sort_unstable();
+            results
+        };
+
+        assert_eq!(filter("car"), vec![0, 1]);
+        assert_eq!(filter("automobile"), vec![0, 1]);
+        assert_eq!(filter("red car"), vec![0]);
+        assert_eq!(filter("bike"), vec![2]);
+
+        let query = index
+            .parse_text_any_query("automobile bike", &hw_counter)
+            .unwrap();
+        assert!(index.check_match(&query, 0));
+        assert!(index.check_match(&query, 2));
+
+        let payload = serde_json::json!("A fast automobile");
+        assert!(index.check_payload_match::<false>(&payload, "car", None, &hw_counter));
+    };
+
+    check_synonyms(mutable_index);
+    check_synonyms(mmap_builder.finalize().unwrap());
+}
+
+#[test]
+fn test_shingles_in_full_text_index() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        lowercase: Some(true),
+        shingles: Some(true),
+        ..Default::default()
+    };
+
+    let documents = [
+        (0, "I love New York"),
+        (1, "York is new"),
+        (2, "A new car in York"),
+    ];
+
+    let mut mutable_index =
+        FullTextIndex::builder_gridstore(temp_dir.path().join("mutable"), config.clone())
+            .make_empty()
+            .unwrap();
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(temp_dir.path().join("mmap"), config.clone(), true);
+    mmap_builder.init().unwrap();
+
+    for (point_id, text) in documents {
+        let values = vec![text.to_string()];
+        mutable_index
+            .add_many(point_id, values.clone(), &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(point_id, values, &hw_counter)
+            .unwrap();
+    }
+
+    let check_shingles = |index: FullTextIndex| {
+        let filter = |text: &str| {
+            let Some(query) = index.parse_text_query(text, &hw_counter) else {
+                return vec![];
+            };
+            let mut results: Vec<_> = index.filter_query(query, &hw_counter).collect();
+            results.sort_unstable();
+            results
+        };
+
+        // Adjacent words must be adjacent in the text as well
+        assert_eq!(filter("new york"), vec![0]);
+        assert_eq!(filter("York new"), vec![]);
+        assert_eq!(filter("new"), vec![0, 1, 2]);
+        assert_eq!(filter("york"), vec![0, 1, 2]);
+
+        let payload = serde_json::json!("Flights to New York");
+        assert!(index.check_payload_match::<false>(&payload, "new york", None, &hw_counter));
+        let payload = serde_json::json!("New flights to York");
+        assert!(!index.check_payload_match::<false>(&payload, "new york", None, &hw_counter));
+    };
+
+    check_shingles(mutable_index);
+    check_shingles(mmap_builder.finalize().unwrap());
+}
+
+#[test]
+fn test_ignore_frequent_tokens() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        lowercase: Some(true),
+        phrase_matching: Some(true),
+        max_document_frequency: Some(OrderedFloat(0.5)),
+        ..Default::default()
+    };
+
+    let mut mutable_index =
+        FullTextIndex::builder_gridstore(temp_dir.path().join("mutable"), config.clone())
+            .make_empty()
+            .unwrap();
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(temp_dir.path().join("mmap"), config.clone(), true);
+    mmap_builder.init().unwrap();
+
+    // "the", "blue" and "cat" are in more than half of the documents
+    for point_id in 0..1000 {
+        let text = if point_id % 10 == 0 {
+            format!("The red fox {point_id}")
+        } else {
+            format!("The blue cat {point_id}")
+        };
+        mutable_index
+            .add_many(point_id, vec![text.clone()], &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(point_id, vec![text], &hw_counter)
+            .unwrap();
+    }
+
+    let red_foxes = (0..1000).step_by(10).collect::<Vec<PointOffsetType>>();
+
+    let check_frequent_tokens = |index: FullTextIndex| {
+        let filter = |query: Option<_>| {
+            let Some(query) = query else {
+                return vec![];
+            };
+            let mut results: Vec<_> = index.filter_query(query, &hw_counter).collect();
+            results.sort_unstable();
+            results
+        };
+        let text = |text: &str| filter(index.parse_text_query(text, &hw_counter));
+        let phrase = |phrase: &str| filter(index.parse_phrase_query(phrase, &hw_counter));
+
+        assert_eq!(text("the red fox"), red_foxes);
+        assert_eq!(text("red"), red_foxes);
+        assert!(text("the").is_empty());
+        assert!(text("blue cat").is_empty());
+
+        // Phrases still check positions of frequent tokens
+        assert_eq!(phrase("the red fox"), red_foxes);
+        assert!(phrase("red the fox").is_empty());
+        assert!(phrase("the blue fox").is_empty());
+
+        let payload = serde_json::json!("A red fox");
+        assert!(index.check_payload_match::<false>(&payload, "the red fox", None, &hw_counter));
+        let payload = serde_json::json!("The red cat");
+        assert!(!index.check_payload_match::<false>(&payload, "the red fox", None, &hw_counter));
+
+        let blocks = PayloadFieldIndex::payload_blocks(&index, 1, JsonPath::new("text"))
+            .map(|block| block.cardinality)
+            .collect::<Vec<_>>();
+        assert!(!blocks.is_empty());
+        assert!(blocks.iter().all(|&cardinality| cardinality <= 500));
+    };
+
+    check_frequent_tokens(mutable_index);
+    check_frequent_tokens(mmap_builder.finalize().unwrap());
+}
+
+#[test]
+fn test_bm25_scores() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        lowercase: Some(true),
+        ..Default::default()
+    };
+
+    let mut mutable_index =
+        FullTextIndex::builder_gridstore(temp_dir.path().to_path_buf(), config.clone())
+            .make_empty()
+            .unwrap();
+
+    let mut mmap_builder = FullTextIndex::builder_mmap(temp_dir.path().to_path_buf(), config, true);
+    mmap_builder.init().unwrap();
+
+    let documents = vec![
+        (0, "rust vector search engine".to_string()),
+        (
+            1,
+            "vector database written in rust with filtering and sharding".to_string(),
+        ),
+        (2, "full text search".to_string()),
+        (3, "vector search".to_string()),
+        (4, "cooking recipes".to_string()),
+    ];
+
+    for (point_id, text) in documents {
+        mutable_index
+            .add_many(point_id, vec![text.clone()], &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(point_id, vec![text], &hw_counter)
+            .unwrap();
+    }
+
+    let mmap_index = mmap_builder.finalize().unwrap();
+
+    // Score of the first document with the statistics of the index itself
+    let first_score = |index: &FullTextIndex, text: &str| {
+        let mut stats = Bm25CorpusStats::default();
+        index.fill_bm25_stats(text, &mut stats, &hw_counter);
+        index.bm25_scorer(text, &[], &stats, &hw_counter).score(0)
+    };
+
+    let check_scores = |index: FullTextIndex| {
+        let mut stats = Bm25CorpusStats::default();
+        index.fill_bm25_stats("rust vector", &mut stats, &hw_counter);
+        let scorer = index.bm25_scorer("rust vector", &[], &stats, &hw_counter);
+        let scores: Vec<_> = (0..5).map(|point_id| scorer.score(point_id)).collect();
+
+        // Documents without query tokens are not relevant
+        assert_eq!(scores[2], 0.0);
+        assert_eq!(scores[4], 0.0);
+
+        // More matching tokens score higher
+        assert!(scores[0] > scores[3]);
+        // Shorter documents with the same matches score higher
+        assert!(scores[0] > scores[1]);
+        // Rare tokens weigh more than frequent ones
+        let rare = first_score(&index, "rust");
+        let frequent = first_score(&index, "vector");
+        assert!(rare > frequent);
+
+        // Unknown tokens don't contribute to the score
+        assert_eq!(first_score(&index, "rust vector unknown"), scores[0]);
+        assert_eq!(first_score(&index, "unknown"), 0.0);
+
+        // Boosted terms weigh more, boost is applied to tokens of the term
+        let boosted_score = |boosts: &[(&str, f32)]| {
+            let boosts: Vec<_> = boosts
+                .iter()
+                .map(|&(term, boost)| TermBoost {
+                    term: term.to_string(),
+                    boost: OrderedFloat(boost),
+                })
+                .collect();
+            index
+                .bm25_scorer("rust vector", &boosts, &stats, &hw_counter)
+                .score(0)
+        };
+        assert_eq!(boosted_score(&[]), scores[0]);
+        assert_eq!(boosted_score(&[("Vector", 1.0)]), scores[0]);
+        assert!((boosted_score(&[("vector", 0.0)]) - rare).abs() < 1e-9);
+        let boosted = boosted_score(&[("RUST", 2.0)]);
+        assert!((boosted - (scores[0] + rare)).abs() < 1e-9);
+        // Boosts of terms which are not in the query are ignored
+        assert_eq!(
+            boosted_score(&[("unknown", 3.0), ("cooking", 3.0)]),
+            scores[0]
+        );
+    };
+
+    check_scores(mutable_index);
+    check_scores(mmap_index);
+}
+
+#[test]
+fn test_bm25_scores_with_merged_stats() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        lowercase: Some(true),
+        ..Default::default()
+    };
+
+    let make_index = |name: &str| {
+        FullTextIndex::builder_gridstore(temp_dir.path().join(name), config.clone())
+            .make_empty()
+            .unwrap()
+    };
+
+    let documents = [
+        "rust vector search engine",
+        "vector database written in rust with filtering and sharding",
+        "full text search",
+        "vector search",
+        "cooking recipes",
+        "rust",
+    ];
+
+    // All documents in one index, and the same documents split over two indexes
+    let mut whole_index = make_index("whole");
+    let mut left_index = make_index("left");
+    let mut right_index = make_index("right");
+
+    for (point_id, text) in documents.iter().enumerate() {
+        let point_id = point_id as PointOffsetType;
+        whole_index
+            .add_many(point_id, vec![text.to_string()], &hw_counter)
+            .unwrap();
+        let part = if point_id % 2 == 0 {
+            &mut left_index
+        } else {
+            &mut right_index
+        };
+        part.add_many(point_id, vec![text.to_string()], &hw_counter)
+            .unwrap();
+    }
+
+    // Removed documents are not a part of the statistics
+    whole_index.remove_point(5).unwrap();
+    right_index.remove_point(5).unwrap();
+
+    let text = "rust vector";
+
+    let mut whole_stats = Bm25CorpusStats::default();
+    whole_index.fill_bm25_stats(text, &mut whole_stats, &hw_counter);
+
+    let mut merged_stats = Bm25CorpusStats::default();
+    left_index.fill_bm25_stats(text, &mut merged_stats, &hw_counter);
+    right_index.fill_bm25_stats(text, &mut merged_stats, &hw_counter);
+
+    assert_eq!(merged_stats, whole_stats);
+    assert_eq!(merged_stats.points_count, 5);
+
+    let whole_scorer = whole_index.bm25_scorer(text, &[], &whole_stats, &hw_counter);
+    let left_scorer = left_index.bm25_scorer(text, &[], &merged_stats, &hw_counter);
+    let right_scorer = right_index.bm25_scorer(text, &[], &merged_stats, &hw_counter);
+
+    for point_id in 0..documents.len() as PointOffsetType {
+        let part_scorer = if point_id % 2 == 0 {
+            &left_scorer
+        } else {
+            &right_scorer
+        };
+        let score = part_scorer.score(point_id);
+        let expected = whole_scorer.score(point_id);
+        assert!((score - expected).abs() < 1e-9, "{score} != {expected}");
+    }
+}
+
+#[test]
+fn test_query_token_lengths_override() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Prefix,
+        lowercase: Some(true),
+        min_token_len: Some(1),
+        max_token_len: Some(10),
+        ..Default::default()
+    };
+
+    let documents = [(0, "television"), (1, "telephone"), (2, "tea")];
+
+    let mut index = FullTextIndex::builder_gridstore(temp_dir.path().to_path_buf(), config)
+        .make_empty()
+        .unwrap();
+    for (point_id, text) in documents {
+        index
+            .add_many(point_id, vec![text.to_string()], &hw_counter)
+            .unwrap();
+    }
+
+    let filter = |text: &str, min_token_len: Option<usize>, max_token_len: Option<usize>| {
+        let match_text = MatchText {
+            text: text.to_string(),
+            fuzziness: None,
+            min_token_len,
+            max_token_len,
+        };
+        let Some(query) = index.parse_match_text(&match_text, &hw_counter) else {
+            return vec![];
+        };
+        let mut results: Vec<_> = index.filter_query(query, &hw_counter).collect();
+        results.sort_unstable();
+        results
+    };
+
+    // Index settings apply without overrides
+    assert_eq!(filter("televis", None, None), vec![0]);
+    assert_eq!(filter("tea x", None, None), vec![]);
+
+    // Shorter prefixes loosen the match
+    assert_eq!(filter("televis", None, Some(4)), vec![0, 1]);
+
+    // Longer minimum ignores short query tokens
+    assert_eq!(filter("tea x", Some(2), None), vec![2]);
+
+    let payload = serde_json::json!("telephone");
+    let match_text = MatchText {
+        text: "televis".to_string(),
+        fuzziness: None,
+        min_token_len: None,
+        max_token_len: Some(4),
+    };
+    assert!(index.check_payload_match_text(&payload, &match_text, &hw_counter));
+    assert!(!index.check_payload_match::<false>(&payload, "televis", None, &hw_counter));
+}
+
+#[test]
+fn test_full_text_telemetry() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        lowercase: Some(true),
+        ..Default::default()
+    };
+
+    let documents = [(0, "red apple"), (1, "red car"), (2, "red green car")];
+
+    let mut mutable_index =
+        FullTextIndex::builder_gridstore(temp_dir.path().join("mutable"), config.clone())
+            .make_empty()
+            .unwrap();
+    let mut mmap_builder = FullTextIndex::builder_mmap(temp_dir.path().join("mmap"), config, true);
+    mmap_builder.init().unwrap();
+
+    for (point_id, text) in documents {
+        let values = vec![text.to_string()];
+        mutable_index
+            .add_many(point_id, values.clone(), &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(point_id, values, &hw_counter)
+            .unwrap();
+    }
+
+    let check_telemetry = |index: FullTextIndex| {
+        let telemetry = index.get_telemetry_data().full_text.unwrap();
+        assert_eq!(telemetry.vocabulary_size, 4);
+        assert_eq!(telemetry.total_postings, 7);
+        assert!(telemetry.estimated_size_bytes > 0);
+
+        let top_terms: Vec<_> = telemetry
+            .top_terms
+            .iter()
+            .map(|term| (term.term.as_str(), term.postings))
+            .collect();
+        assert_eq!(top_terms[..2], [("red", 3), ("car", 2)]);
+        assert_eq!(top_terms.len(), 4);
+    };
+
+    check_telemetry(mutable_index);
+    check_telemetry(mmap_builder.finalize().unwrap());
+}
@@ -294,7 +294,8 @@ fn test_congruence(
     #[values(false, true)] phrase_matching: bool,
     #[values(false, true)] reopen: bool,
 ) {
-    const POINT_COUNT: usize = 500;
+    // Spans several tokenization batches of the mmap index builder
+    const POINT_COUNT: usize = 2500;
     const KEYWORD_COUNT: usize = 20;
     const KEYWORD_LEN: usize = 2;
 
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use ahash::AHashSet;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
+use itertools::Itertools;
 #[cfg(feature = "rocksdb")]
 use parking_lot::RwLock;
 #[cfg(feature = "rocksdb")]
@@ -31,8 +32,15 @@ use crate::index::field_index::{
     ValueIndexer,
 };
 use crate::index::payload_config::{IndexMutability, StorageType};
-use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{FieldCondition, Match, MatchPhrase, MatchText, PayloadKeyType};
+use crate::telemetry::{FullTextIndexTelemetry, PayloadIndexTelemetry, TermPostingsTelemetry};
+use crate::types::{FieldCondition, Match, MatchNear, MatchPhrase, MatchText, PayloadKeyType};
+
+/// Number of terms with the longest posting lists reported in telemetry
+const FULL_TEXT_TELEMETRY_TOP_TERMS: usize = 10;
+
+/// Minimal number of documents to ignore frequent tokens,
+/// frequencies of tokens in smaller indexes are not representative
+const MIN_DOCUMENTS_TO_IGNORE_FREQUENT_TOKENS: usize = 1000;
 
 pub enum FullTextIndex {
     Mutable(MutableFullTextIndex),
@@ -156,10 +164,174 @@ impl FullTextIndex {
         }
     }
 
+    pub(super) fn get_fuzzy_tokens(
+        &self,
+        token: &str,
+        fuzziness: u8,
+        hw_counter: &HardwareCounterCell,
+    ) -> TokenSet {
+        match self {
+            Self::Mutable(index) => index
+                .inverted_index
+                .get_fuzzy_token_ids(token, fuzziness, hw_counter),
+            Self::Immutable(index) => index
+                .inverted_index
+                .get_fuzzy_token_ids(token, fuzziness, hw_counter),
+            Self::Mmap(index) => index
+                .inverted_index
+                .get_fuzzy_token_ids(token, fuzziness, hw_counter),
+        }
+    }
+
+    /// Token ids of the `token` and its synonyms, and of tokens similar to them if `fuzziness` > 0
+    fn get_token_alternatives(
+        &self,
+        token: &str,
+        fuzziness: u8,
+        hw_counter: &HardwareCounterCell,
+    ) -> TokenSet {
+        let synonyms = self.get_tokenizer().query_synonyms(token);
+        let mut alternatives = AHashSet::new();
+        for token in std::iter::once(token).chain(synonyms.iter().map(String::as_str)) {
+            if fuzziness == 0 {
+                alternatives.extend(self.get_token(token, hw_counter));
+            } else {
+                let similar_tokens = self.get_fuzzy_tokens(token, fuzziness, hw_counter);
+                alternatives.extend(similar_tokens.tokens().iter().copied());
+            }
+        }
+        TokenSet::from(alternatives)
+    }
+
+    fn get_posting_len(
+        &self,
+        token_id: TokenId,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<usize> {
+        match self {
+            Self::Mutable(index) => index.inverted_index.get_posting_len(token_id, hw_counter),
+            Self::Immutable(index) => index.inverted_index.get_posting_len(token_id, hw_counter),
+            Self::Mmap(index) => index.inverted_index.get_posting_len(token_id, hw_counter),
+        }
+    }
+
+    fn max_document_frequency(&self) -> Option<f64> {
+        match self {
+            Self::Mutable(index) => index.config.max_document_frequency.map(|ratio| ratio.0),
+            Self::Immutable(index) => index.max_document_frequency,
+            Self::Mmap(index) => index.max_document_frequency,
+        }
+    }
+
+    /// Maximal number of documents containing a token, for the token to be used by text queries.
+    /// `None` if frequent tokens are not ignored.
+    fn max_token_documents(&self) -> Option<usize> {
+        let ratio = self.max_document_frequency()?;
+        let points_count = self.points_count();
+        (points_count >= MIN_DOCUMENTS_TO_IGNORE_FREQUENT_TOKENS)
+            .then(|| (ratio * points_count as f64) as usize)
+    }
+
+    fn is_rare_token(
+        &self,
+        token_id: TokenId,
+        max_documents: usize,
+        hw_counter: &HardwareCounterCell,
+    ) -> bool {
+        self.get_posting_len(token_id, hw_counter)
+            .is_none_or(|documents| documents <= max_documents)
+    }
+
+    /// Tokens of the set found in at most `max_documents` documents
+    fn retain_rare_tokens(
+        &self,
+        tokens: &TokenSet,
+        max_documents: usize,
+        hw_counter: &HardwareCounterCell,
+    ) -> TokenSet {
+        tokens
+            .tokens()
+            .iter()
+            .copied()
+            .filter(|&token_id| self.is_rare_token(token_id, max_documents, hw_counter))
+            .collect()
+    }
+
+    /// Drop tokens found in more than `max_document_frequency` of documents from the query,
+    /// as if they were stopwords.
+    ///
+    /// Phrase and proximity queries keep them, to check positions of the other tokens.
+    fn ignore_frequent_tokens(
+        &self,
+        query: ParsedQuery,
+        hw_counter: &HardwareCounterCell,
+    ) -> ParsedQuery {
+        let Some(max_documents) = self.max_token_documents() else {
+            return query;
+        };
+
+        match query {
+            ParsedQuery::AllTokens(tokens) => {
+                ParsedQuery::AllTokens(self.retain_rare_tokens(&tokens, max_documents, hw_counter))
+            }
+            ParsedQuery::AnyTokens(tokens) => {
+                ParsedQuery::AnyTokens(self.retain_rare_tokens(&tokens, max_documents, hw_counter))
+            }
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                // Query token with a frequent alternative matches too many documents as well
+                let token_sets = token_sets
+                    .into_iter()
+                    .filter(|tokens| {
+                        tokens.tokens().iter().all(|&token_id| {
+                            self.is_rare_token(token_id, max_documents, hw_counter)
+                        })
+                    })
+                    .collect_vec();
+                if token_sets.is_empty() {
+                    ParsedQuery::AllTokens(TokenSet::default())
+                } else {
+                    ParsedQuery::TokenAlternatives(token_sets)
+                }
+            }
+            ParsedQuery::Phrase(_) | ParsedQuery::Proximity { .. } => query,
+        }
+    }
+
     pub(super) fn filter_query<'a>(
         &'a self,
         query: ParsedQuery,
         hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
+        let query = self.ignore_frequent_tokens(query, hw_counter);
+
+        let positional_tokens = match &query {
+            ParsedQuery::Phrase(phrase) => Some(phrase.to_token_set()),
+            ParsedQuery::Proximity { tokens, within: _ } => Some(tokens.clone()),
+            ParsedQuery::AllTokens(_)
+            | ParsedQuery::AnyTokens(_)
+            | ParsedQuery::TokenAlternatives(_) => None,
+        };
+
+        // Candidates are filtered by rare tokens, frequent ones are only checked for positions
+        if let Some(tokens) = positional_tokens
+            && let Some(max_documents) = self.max_token_documents()
+        {
+            let rare_tokens = self.retain_rare_tokens(&tokens, max_documents, hw_counter);
+            if !rare_tokens.is_empty() && rare_tokens.len() < tokens.len() {
+                return Box::new(
+                    self.filter_inverted_index(ParsedQuery::AllTokens(rare_tokens), hw_counter)
+                        .filter(move |&point_id| self.check_match(&query, point_id)),
+                );
+            }
+        }
+
+        self.filter_inverted_index(query, hw_counter)
+    }
+
+    fn filter_inverted_index<'a>(
+        &'a self,
+        query: ParsedQuery,
+        hw_counter: &'a HardwareCounterCell,
     ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
         match self {
             Self::Mutable(index) => index.inverted_index.filter(query, hw_counter),
@@ -168,7 +340,7 @@ impl FullTextIndex {
         }
     }
 
-    fn get_tokenizer(&self) -> &Tokenizer {
+    pub(super) fn get_tokenizer(&self) -> &Tokenizer {
         match self {
             Self::Mutable(index) => &index.tokenizer,
             Self::Immutable(index) => &index.tokenizer,
@@ -181,10 +353,28 @@ impl FullTextIndex {
         threshold: usize,
         key: PayloadKeyType,
     ) -> Box<dyn Iterator<Item = PayloadBlockCondition> + '_> {
-        match self {
+        let blocks: Box<dyn Iterator<Item = PayloadBlockCondition> + '_> = match self {
             Self::Mutable(index) => Box::new(index.inverted_index.payload_blocks(threshold, key)),
             Self::Immutable(index) => Box::new(index.inverted_index.payload_blocks(threshold, key)),
             Self::Mmap(index) => Box::new(index.inverted_index.payload_blocks(threshold, key)),
+        };
+
+        // Conditions on frequent tokens match nothing
+        match self.max_token_documents() {
+            Some(max_documents) => {
+                Box::new(blocks.filter(move |block| block.cardinality <= max_documents))
+            }
+            None => blocks,
+        }
+    }
+
+    /// Terms of the index with the number of documents containing them.
+    /// Documents of deleted points may still be counted.
+    pub fn terms_with_documents_count(&self) -> Box<dyn Iterator<Item = (&str, usize)> + '_> {
+        match self {
+            Self::Mutable(index) => Box::new(index.inverted_index.vocab_with_postings_len_iter()),
+            Self::Immutable(index) => Box::new(index.inverted_index.vocab_with_postings_len_iter()),
+            Self::Mmap(index) => Box::new(index.inverted_index.vocab_with_postings_len_iter()),
         }
     }
 
@@ -194,16 +384,17 @@ impl FullTextIndex {
         condition: &FieldCondition,
         hw_counter: &HardwareCounterCell,
     ) -> CardinalityEstimation {
+        let query = self.ignore_frequent_tokens(query.clone(), hw_counter);
         match self {
             Self::Mutable(index) => index
                 .inverted_index
-                .estimate_cardinality(query, condition, hw_counter),
+                .estimate_cardinality(&query, condition, hw_counter),
             Self::Immutable(index) => index
                 .inverted_index
-                .estimate_cardinality(query, condition, hw_counter),
+                .estimate_cardinality(&query, condition, hw_counter),
             Self::Mmap(index) => index
                 .inverted_index
-                .estimate_cardinality(query, condition, hw_counter),
+                .estimate_cardinality(&query, condition, hw_counter),
         }
     }
 
@@ -275,6 +466,48 @@ impl FullTextIndex {
             points_values_count: self.points_count(),
             points_count: self.points_count(),
             histogram_bucket_size: None,
+            distinct_values: None,
+            full_text: Some(self.full_text_telemetry()),
+            fragmentation: None,
+        }
+    }
+
+    /// Vocabulary and postings statistics, to estimate the cost of indexing the field
+    fn full_text_telemetry(&self) -> FullTextIndexTelemetry {
+        let mut vocabulary_size = 0;
+        let mut total_postings = 0;
+        let mut vocabulary_bytes = 0;
+        for (term, postings) in self.terms_with_documents_count() {
+            vocabulary_size += 1;
+            total_postings += postings;
+            vocabulary_bytes += term.len() + size_of::<TokenId>();
+        }
+
+        let total_tokens = match self {
+            Self::Mutable(index) => index.inverted_index.total_tokens_count(),
+            Self::Immutable(index) => index.inverted_index.total_tokens_count(),
+            Self::Mmap(index) => index.inverted_index.total_tokens_count(),
+        };
+
+        // Posting lists, plus the tokens of each document kept for point removal
+        let estimated_size_bytes = vocabulary_bytes
+            + total_postings * size_of::<PointOffsetType>()
+            + total_tokens * size_of::<TokenId>();
+
+        let top_terms = self
+            .terms_with_documents_count()
+            .k_largest_by_key(FULL_TEXT_TELEMETRY_TOP_TERMS, |(_, postings)| *postings)
+            .map(|(term, postings)| TermPostingsTelemetry {
+                term: term.to_string(),
+                postings,
+            })
+            .collect();
+
+        FullTextIndexTelemetry {
+            vocabulary_size,
+            total_postings,
+            estimated_size_bytes,
+            top_terms,
         }
     }
 
@@ -290,22 +523,148 @@ impl FullTextIndex {
         Some(ParsedQuery::Phrase(document))
     }
 
+    /// Tries to parse a proximity query. If there are any unseen tokens, returns `None`
+    ///
+    /// Tokens are parsed the same way as documents, so their positions are comparable
+    pub fn parse_proximity_query(
+        &self,
+        text: &str,
+        within: u32,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<ParsedQuery> {
+        let document = self.parse_document(text, hw_counter)?;
+        Some(ParsedQuery::Proximity {
+            tokens: document.to_token_set(),
+            within,
+        })
+    }
+
     /// Tries to parse a query. If there are any unseen tokens, returns `None`
     ///
-    /// Tokens are made unique
+    /// Tokens are made unique. If synonyms are configured, tokens also match their synonyms.
     pub fn parse_text_query(
         &self,
         text: &str,
         hw_counter: &HardwareCounterCell,
     ) -> Option<ParsedQuery> {
+        self.parse_text_query_with(self.get_tokenizer(), text, hw_counter)
+    }
+
+    fn parse_text_query_with(
+        &self,
+        tokenizer: &Tokenizer,
+        text: &str,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<ParsedQuery> {
+        if tokenizer.has_query_synonyms() {
+            return self.parse_token_alternatives_query(tokenizer, text, 0, hw_counter);
+        }
+
+        let mut query_tokens = Vec::new();
+        tokenizer.tokenize_query(text, |token| query_tokens.push(token));
+        let shingles = tokenizer.shingles(&query_tokens);
+
         let mut tokens = AHashSet::new();
-        self.get_tokenizer().tokenize_query(text, |token| {
-            tokens.insert(self.get_token(token.as_ref(), hw_counter));
-        });
+        for token in query_tokens
+            .iter()
+            .map(Cow::as_ref)
+            .chain(shingles.iter().map(String::as_str))
+        {
+            tokens.insert(self.get_token(token, hw_counter));
+        }
         let tokens = tokens.into_iter().collect::<Option<TokenSet>>()?;
         Some(ParsedQuery::AllTokens(tokens))
     }
 
+    /// Tries to parse a query, matching each token to similar tokens within `fuzziness` edits.
+    /// If there are no similar tokens to any query token, returns `None`
+    pub fn parse_fuzzy_text_query(
+        &self,
+        text: &str,
+        fuzziness: u8,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<ParsedQuery> {
+        self.parse_fuzzy_text_query_with(self.get_tokenizer(), text, fuzziness, hw_counter)
+    }
+
+    fn parse_fuzzy_text_query_with(
+        &self,
+        tokenizer: &Tokenizer,
+        text: &str,
+        fuzziness: u8,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<ParsedQuery> {
+        if fuzziness == 0 {
+            return self.parse_text_query_with(tokenizer, text, hw_counter);
+        }
+        self.parse_token_alternatives_query(tokenizer, text, fuzziness, hw_counter)
+    }
+
+    /// Tries to parse a query, matching each token to its alternatives:
+    /// similar tokens within `fuzziness` edits and synonyms.
+    /// If there are no alternatives to any query token, returns `None`
+    fn parse_token_alternatives_query(
+        &self,
+        tokenizer: &Tokenizer,
+        text: &str,
+        fuzziness: u8,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<ParsedQuery> {
+        let mut ordered_tokens = Vec::new();
+        tokenizer.tokenize_query(text, |token| ordered_tokens.push(token));
+        let shingles = tokenizer.shingles(&ordered_tokens);
+        let query_tokens: AHashSet<String> = ordered_tokens
+            .into_iter()
+            .map(Cow::into_owned)
+            .chain(shingles)
+            .collect();
+
+        let token_sets = query_tokens
+            .iter()
+            .map(|token| {
+                let tokens = self.get_token_alternatives(token, fuzziness, hw_counter);
+                (!tokens.is_empty()).then_some(tokens)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        // Without alternatives, the query matches exact tokens only
+        if token_sets.iter().all(|tokens| tokens.len() == 1) {
+            let tokens = token_sets
+                .iter()
+                .flat_map(|tokens| tokens.tokens())
+                .copied();
+            return Some(ParsedQuery::AllTokens(tokens.collect()));
+        }
+
+        Some(ParsedQuery::TokenAlternatives(token_sets))
+    }
+
+    /// Parse a full-text match condition, with fuzzy matching if requested
+    ///
+    /// Token lengths given in the condition override the ones configured for the index
+    pub fn parse_match_text(
+        &self,
+        match_text: &MatchText,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<ParsedQuery> {
+        let MatchText {
+            text,
+            fuzziness,
+            min_token_len,
+            max_token_len,
+        } = match_text;
+        let fuzziness = fuzziness.unwrap_or(0);
+
+        if min_token_len.is_none() && max_token_len.is_none() {
+            return self.parse_fuzzy_text_query(text, fuzziness, hw_counter);
+        }
+
+        let tokenizer = self
+            .get_tokenizer()
+            .with_token_lengths(*min_token_len, *max_token_len);
+        self.parse_fuzzy_text_query_with(&tokenizer, text, fuzziness, hw_counter)
+    }
+
     pub fn parse_text_any_query(
         &self,
         text: &str,
@@ -313,21 +672,25 @@ impl FullTextIndex {
     ) -> Option<ParsedQuery> {
         let mut tokens = AHashSet::new();
         self.get_tokenizer().tokenize_query(text, |token| {
-            if let Some(token_id) = self.get_token(token.as_ref(), hw_counter) {
-                tokens.insert(token_id);
-            }
+            let token_alternatives = self.get_token_alternatives(token.as_ref(), 0, hw_counter);
+            tokens.extend(token_alternatives.tokens().iter().copied());
         });
         let tokens = tokens.into_iter().collect::<TokenSet>();
         Some(ParsedQuery::AnyTokens(tokens))
     }
 
     pub fn parse_tokenset(&self, text: &str, hw_counter: &HardwareCounterCell) -> TokenSet {
-        let mut tokenset = AHashSet::new();
-        self.get_tokenizer().tokenize_doc(text, |token| {
-            if let Some(token_id) = self.get_token(token.as_ref(), hw_counter) {
-                tokenset.insert(token_id);
-            }
-        });
+        let mut tokens = Vec::new();
+        self.get_tokenizer()
+            .tokenize_doc(text, |token| tokens.push(token));
+        let shingles = self.get_tokenizer().shingles(&tokens);
+        tokens.extend(shingles.into_iter().map(Cow::Owned));
+        self.get_tokenizer().add_index_synonyms(&mut tokens);
+
+        let tokenset = tokens
+            .iter()
+            .filter_map(|token| self.get_token(token.as_ref(), hw_counter))
+            .collect::<AHashSet<_>>();
         TokenSet::from(tokenset)
     }
 
@@ -365,21 +728,57 @@ impl FullTextIndex {
     }
 
     /// Checks the text directly against the payload value
+    ///
+    /// `fuzziness` only applies to non-phrase queries
     pub fn check_payload_match<const IS_PHRASE: bool>(
         &self,
         payload_value: &serde_json::Value,
         text: &str,
+        fuzziness: Option<u8>,
         hw_counter: &HardwareCounterCell,
     ) -> bool {
         let query_opt = if IS_PHRASE {
             self.parse_phrase_query(text, hw_counter)
         } else {
-            self.parse_text_query(text, hw_counter)
+            let fuzziness = fuzziness.unwrap_or(0);
+            self.parse_fuzzy_text_query(text, fuzziness, hw_counter)
         };
+        self.check_payload_query(payload_value, query_opt, hw_counter)
+    }
+
+    /// Checks the full-text match condition directly against the payload value
+    pub fn check_payload_match_text(
+        &self,
+        payload_value: &serde_json::Value,
+        match_text: &MatchText,
+        hw_counter: &HardwareCounterCell,
+    ) -> bool {
+        let query_opt = self.parse_match_text(match_text, hw_counter);
+        self.check_payload_query(payload_value, query_opt, hw_counter)
+    }
+
+    /// Checks the proximity query directly against the payload value
+    pub fn check_payload_proximity(
+        &self,
+        payload_value: &serde_json::Value,
+        text: &str,
+        within: u32,
+        hw_counter: &HardwareCounterCell,
+    ) -> bool {
+        let query_opt = self.parse_proximity_query(text, within, hw_counter);
+        self.check_payload_query(payload_value, query_opt, hw_counter)
+    }
 
+    fn check_payload_query(
+        &self,
+        payload_value: &serde_json::Value,
+        query_opt: Option<ParsedQuery>,
+        hw_counter: &HardwareCounterCell,
+    ) -> bool {
         let Some(query) = query_opt else {
             return false;
         };
+        let query = self.ignore_frequent_tokens(query, hw_counter);
 
         FullTextIndex::get_values(payload_value)
             .iter()
@@ -392,10 +791,18 @@ impl FullTextIndex {
                     let document = self.parse_document(value, hw_counter);
                     document.map(|doc| doc.has_phrase(query)).unwrap_or(false)
                 }
+                ParsedQuery::Proximity { tokens, within } => {
+                    let document = self.parse_document(value, hw_counter);
+                    document.is_some_and(|doc| doc.has_proximity(tokens, *within))
+                }
                 ParsedQuery::AnyTokens(query) => {
                     let tokenset = self.parse_tokenset(value, hw_counter);
                     tokenset.has_any(query)
                 }
+                ParsedQuery::TokenAlternatives(token_sets) => {
+                    let tokenset = self.parse_tokenset(value, hw_counter);
+                    token_sets.iter().all(|tokens| tokenset.has_any(tokens))
+                }
             })
     }
 
@@ -596,10 +1003,13 @@ impl PayloadFieldIndex for FullTextIndex {
         hw_counter: &'a HardwareCounterCell,
     ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
         let parsed_query_opt = match &condition.r#match {
-            Some(Match::Text(MatchText { text })) => self.parse_text_query(text, hw_counter),
+            Some(Match::Text(match_text)) => self.parse_match_text(match_text, hw_counter),
             Some(Match::Phrase(MatchPhrase { phrase })) => {
                 self.parse_phrase_query(phrase, hw_counter)
             }
+            Some(Match::Near(MatchNear { near, within })) => {
+                self.parse_proximity_query(near, *within, hw_counter)
+            }
             _ => return None,
         };
 
@@ -616,10 +1026,13 @@ impl PayloadFieldIndex for FullTextIndex {
         hw_counter: &HardwareCounterCell,
     ) -> Option<CardinalityEstimation> {
         let parsed_query_opt = match &condition.r#match {
-            Some(Match::Text(MatchText { text })) => self.parse_text_query(text, hw_counter),
+            Some(Match::Text(match_text)) => self.parse_match_text(match_text, hw_counter),
             Some(Match::Phrase(MatchPhrase { phrase })) => {
                 self.parse_phrase_query(phrase, hw_counter)
             }
+            Some(Match::Near(MatchNear { near, within })) => {
+                self.parse_proximity_query(near, *within, hw_counter)
+            }
             _ => return None,
         };
 
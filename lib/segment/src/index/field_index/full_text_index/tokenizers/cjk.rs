@@ -0,0 +1,147 @@
+use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::{Arc, LazyLock};
+
+use jieba_rs::Jieba;
+
+use super::{TokensProcessor, japanese, word_range};
+use crate::data_types::index::{Language, StopwordsInterface};
+use crate::index::field_index::full_text_index::stop_words::StopwordsFilter;
+
+// Global initialization of the Chinese dictionary, loading it takes a while.
+static GLOBAL_JIEBA: LazyLock<Jieba> = LazyLock::new(Jieba::new);
+
+/// Tokenizer for Chinese and Japanese text, which segments words using a dictionary
+/// instead of splitting text into single characters.
+///
+/// Japanese text is segmented with vaporetto, any other text with jieba.
+/// Words of other scripts within the text are kept as a whole.
+pub struct CjkTokenizer;
+
+impl CjkTokenizer {
+    pub fn tokenize<'a, C: FnMut(Cow<'a, str>)>(
+        input: &'a str,
+        config: &'a TokensProcessor,
+        languages: Option<&'a CjkProcessors>,
+        mut cb: C,
+    ) {
+        Self::tokenize_with_offsets(input, config, languages, |_, token| cb(token));
+    }
+
+    /// Same as [`Self::tokenize`], but also provides the byte range of each token in the `input`.
+    pub fn tokenize_with_offsets<'a, C: FnMut(Range<usize>, Cow<'a, str>)>(
+        input: &'a str,
+        config: &'a TokensProcessor,
+        languages: Option<&'a CjkProcessors>,
+        mut cb: C,
+    ) {
+        if is_japanese(input) {
+            let config = languages.map_or(config, |languages| &languages.japanese);
+            japanese::tokenize_with_offsets(input, config, cb);
+            return;
+        }
+
+        let config = languages.map_or(config, |languages| &languages.chinese);
+        for word in GLOBAL_JIEBA.cut(input, true) {
+            if word.chars().all(|char| !char.is_alphabetic()) {
+                // Skip whitespace, punctuation and numbers
+                continue;
+            }
+
+            if let Some(token) = config.process_token_cow(Cow::Borrowed(word), true) {
+                cb(word_range(input, word), token);
+            }
+        }
+    }
+}
+
+/// Tokens processors with stopwords of Chinese and Japanese, used if language detection is enabled.
+#[derive(Debug, Default)]
+pub struct CjkProcessors {
+    chinese: TokensProcessor,
+    japanese: TokensProcessor,
+}
+
+impl CjkProcessors {
+    /// Derive processors from the `base` one. Configured stopwords are applied to both languages.
+    pub fn new(base: &TokensProcessor, stopwords: &Option<StopwordsInterface>) -> Self {
+        let processor = |language| {
+            let stopwords_filter = StopwordsFilter::new_with_language(
+                stopwords,
+                &language,
+                base.lowercase_stopwords(),
+            );
+            base.with_stopwords(Arc::new(stopwords_filter))
+        };
+
+        Self {
+            chinese: processor(Language::Chinese),
+            japanese: processor(Language::Japanese),
+        }
+    }
+}
+
+/// Kana only occur in Japanese text, while Han characters are shared with Chinese.
+fn is_japanese(input: &str) -> bool {
+    input.chars().any(|char| {
+        matches!(
+            char,
+            '\u{3040}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}'
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokenize(input: &str, languages: Option<&CjkProcessors>) -> Vec<String> {
+        let tokens_processor = TokensProcessor::default();
+        let mut out = vec![];
+        CjkTokenizer::tokenize(input, &tokens_processor, languages, |token| {
+            out.push(token.to_string())
+        });
+        out
+    }
+
+    #[test]
+    fn test_chinese_segmentation() {
+        let out = tokenize("我们在北京大学学习自然语言处理。", None);
+        assert!(out.contains(&"北京大学".to_string()));
+        assert!(out.contains(&"我们".to_string()));
+        // Words are not split into single characters
+        assert!(!out.contains(&"北".to_string()));
+
+        let out = tokenize("Qdrant 是一个向量数据库", None);
+        assert!(out.contains(&"Qdrant".to_string()));
+        assert!(out.contains(&"数据库".to_string()));
+    }
+
+    #[test]
+    fn test_japanese_segmentation() {
+        let out = tokenize("これはテストです", None);
+        assert_eq!(out, vec!["これ", "は", "テスト", "です"]);
+    }
+
+    #[test]
+    fn test_cjk_stopwords() {
+        let languages = CjkProcessors::new(&TokensProcessor::default(), &None);
+
+        let out = tokenize("我们在北京大学学习", Some(&languages));
+        assert!(out.contains(&"北京大学".to_string()));
+        assert!(!out.contains(&"我们".to_string()));
+
+        let out = tokenize("これはテストです", Some(&languages));
+        assert!(out.contains(&"テスト".to_string()));
+        assert!(!out.contains(&"これ".to_string()));
+    }
+
+    #[test]
+    fn test_cjk_offsets() {
+        let input = "我们在北京大学学习";
+        let tokens_processor = TokensProcessor::default();
+        CjkTokenizer::tokenize_with_offsets(input, &tokens_processor, None, |range, token| {
+            assert_eq!(&input[range], token.as_ref());
+        });
+    }
+}
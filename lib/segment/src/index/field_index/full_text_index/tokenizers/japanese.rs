@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::LazyLock;
 
 use vaporetto::{Model, Predictor, Sentence};
@@ -37,7 +38,7 @@ impl JapaneseTokenizer {
         Self { predictor }
     }
 
-    fn tokenize<'a, C: FnMut(Cow<'a, str>)>(
+    fn tokenize<'a, C: FnMut(Range<usize>, Cow<'a, str>)>(
         &self,
         input: &'a str,
         tokens_processor: &TokensProcessor,
@@ -49,10 +50,19 @@ impl JapaneseTokenizer {
 
         self.predictor.predict(&mut s);
 
+        // Surfaces follow each other in the input, so each one is searched after the previous one
+        let mut offset = 0;
+
         // TODO(multilingual): Implement similar method to `iter_tokens()` that allows returning borrowed Cows instead of needlessly cloning here.
         for i in s.iter_tokens() {
             let surface = i.surface();
 
+            let range = match input[offset..].find(surface) {
+                Some(pos) => offset + pos..offset + pos + surface.len(),
+                None => offset..offset,
+            };
+            offset = range.end;
+
             // Skip if all characters are not alphanumeric or if the surface is empty.
             if tokens_processor.is_stopword(surface)
                 || surface.chars().all(|char| !char.is_alphabetic())
@@ -65,13 +75,22 @@ impl JapaneseTokenizer {
             } else {
                 Cow::Owned(surface.to_string())
             };
-            cb(surface);
+            cb(range, surface);
         }
     }
 }
 
 /// Tokenizes the given `input` of Japanese text and calls `cb` with each tokens.
-pub fn tokenize<'a, C: FnMut(Cow<'a, str>)>(input: &'a str, config: &TokensProcessor, cb: C) {
+pub fn tokenize<'a, C: FnMut(Cow<'a, str>)>(input: &'a str, config: &TokensProcessor, mut cb: C) {
+    GLOBAL_JAPANESE_TOKENIZER.tokenize(input, config, |_, token| cb(token));
+}
+
+/// Same as [`tokenize`], but also provides the byte range of each token in the `input`.
+pub fn tokenize_with_offsets<'a, C: FnMut(Range<usize>, Cow<'a, str>)>(
+    input: &'a str,
+    config: &TokensProcessor,
+    cb: C,
+) {
     GLOBAL_JAPANESE_TOKENIZER.tokenize(input, config, cb);
 }
 
@@ -1,13 +1,21 @@
 use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::Arc;
-mod ascii_folding;
+pub(crate) mod ascii_folding;
+mod cjk;
 mod japanese;
 mod multilingual;
+pub(crate) mod nfkc;
 mod stemmer;
+mod synonyms;
 pub mod tokens_processor;
 
-use multilingual::MultilingualTokenizer;
+use cjk::{CjkProcessors, CjkTokenizer};
+use itertools::Itertools;
+pub use multilingual::detect_language_code;
+use multilingual::{LanguageProcessors, MultilingualTokenizer};
 pub use stemmer::Stemmer;
+use synonyms::Synonyms;
 pub use tokens_processor::TokensProcessor;
 
 use crate::data_types::index::{TextIndexParams, TokenizerType};
@@ -104,15 +112,7 @@ impl PrefixTokenizer {
         text.split(|c| !char::is_alphanumeric(c))
             .filter(|token| !token.is_empty())
             .for_each(|word| {
-                // Apply ASCII folding if enabled
-                let mut word_cow = tokens_processor.fold_if_enabled(Cow::Borrowed(word));
-
-                // Handle lowercase
-                if tokens_processor.lowercase {
-                    word_cow = Cow::Owned(word_cow.to_lowercase());
-                }
-
-                let word_cow = tokens_processor.stem_if_enabled(word_cow);
+                let word_cow = tokens_processor.apply_filters_keep_stopwords(Cow::Borrowed(word));
 
                 if tokens_processor
                     .min_token_len
@@ -153,10 +153,24 @@ fn truncate_cow_ref<'a>(inp: &Cow<'a, str>, len: usize) -> Cow<'a, str> {
     }
 }
 
+/// Byte range of `word` in `text`, where `word` is a subslice of `text`
+fn word_range(text: &str, word: &str) -> Range<usize> {
+    let start = word.as_ptr() as usize - text.as_ptr() as usize;
+    start..start + word.len()
+}
+
 #[derive(Debug, Clone)]
 pub struct Tokenizer {
     tokenizer_type: TokenizerType,
     tokens_processor: TokensProcessor,
+    /// Processors of detected languages, if language detection is enabled
+    language_processors: Option<Arc<LanguageProcessors>>,
+    /// Processors of Chinese and Japanese, if language detection is enabled
+    cjk_processors: Option<Arc<CjkProcessors>>,
+    /// Synonyms of tokens, if configured
+    synonyms: Option<Arc<Synonyms>>,
+    /// Whether pairs of adjacent tokens are added as single tokens
+    shingles: bool,
 }
 
 impl Tokenizer {
@@ -168,34 +182,163 @@ impl Tokenizer {
             max_token_len,
             lowercase,
             ascii_folding,
+            unicode_normalization,
             on_disk: _,
-            phrase_matching: _,
+            phrase_matching,
             stopwords,
             stemmer,
+            language_detection,
+            detected_language_key: _,
             enable_hnsw: _,
+            populate: _,
+            synonyms,
+            shingles,
+            max_document_frequency: _,
+            filters,
         } = params;
 
-        let lowercase = lowercase.unwrap_or(true);
-        let ascii_folding = ascii_folding.unwrap_or(false);
-        let stopwords_filter = Arc::new(StopwordsFilter::new(stopwords, lowercase));
-
-        let tokens_processor = TokensProcessor::new(
-            lowercase,
-            ascii_folding,
-            stopwords_filter,
+        let mut tokens_processor = TokensProcessor::new(
+            lowercase.unwrap_or(true),
+            ascii_folding.unwrap_or(false),
+            unicode_normalization.unwrap_or(false),
+            Default::default(),
             stemmer.as_ref().map(Stemmer::from_algorithm),
             *min_token_len,
             *max_token_len,
         );
+        if let Some(filters) = filters {
+            tokens_processor = tokens_processor.with_filters(filters);
+        }
+        let stopwords_filter =
+            StopwordsFilter::new(stopwords, tokens_processor.lowercase_stopwords());
+        tokens_processor.set_stopwords(Arc::new(stopwords_filter));
+
+        let mut this = Self::new(*tokenizer, tokens_processor);
+
+        if *tokenizer == TokenizerType::Multilingual && language_detection.unwrap_or(false) {
+            this.language_processors = Some(Arc::new(LanguageProcessors::new(
+                &this.tokens_processor,
+                stopwords,
+            )));
+        }
+
+        if *tokenizer == TokenizerType::Cjk && language_detection.unwrap_or(false) {
+            this.cjk_processors = Some(Arc::new(CjkProcessors::new(
+                &this.tokens_processor,
+                stopwords,
+            )));
+        }
 
-        Self::new(*tokenizer, tokens_processor)
+        if let Some(synonyms) = synonyms {
+            let synonyms = Synonyms::new(
+                synonyms,
+                &this.tokens_processor,
+                phrase_matching.unwrap_or_default(),
+            );
+            this.synonyms = (!synonyms.is_empty()).then(|| Arc::new(synonyms));
+        }
+
+        // Phrase matching covers adjacent words already, and prefixes of queries differ from the
+        // indexed ones
+        this.shingles = shingles.unwrap_or(false)
+            && !phrase_matching.unwrap_or_default()
+            && *tokenizer != TokenizerType::Prefix;
+
+        this
     }
 
     pub fn new(tokenizer_type: TokenizerType, tokens_processor: TokensProcessor) -> Self {
         Self {
             tokenizer_type,
             tokens_processor,
+            language_processors: None,
+            cjk_processors: None,
+            synonyms: None,
+            shingles: false,
+        }
+    }
+
+    /// Copy of this tokenizer with token lengths overridden where given, to parse queries
+    /// stricter or looser than the index is configured. Processors of detected languages keep
+    /// the configured lengths.
+    pub fn with_token_lengths(
+        &self,
+        min_token_len: Option<usize>,
+        max_token_len: Option<usize>,
+    ) -> Self {
+        let mut tokenizer = self.clone();
+        if min_token_len.is_some() {
+            tokenizer.tokens_processor.min_token_len = min_token_len;
+        }
+        if max_token_len.is_some() {
+            tokenizer.tokens_processor.max_token_len = max_token_len;
+        }
+        tokenizer
+    }
+
+    /// Tokens of a document, consisting of the given text values, as they are indexed:
+    /// together with shingles of each value and index synonyms.
+    pub fn tokenize_values<'a>(&'a self, values: &'a [String]) -> Vec<Cow<'a, str>> {
+        let mut tokens = Vec::new();
+
+        for value in values {
+            let value_start = tokens.len();
+            self.tokenize_doc(value, |token| tokens.push(token));
+            let shingles = self.shingles(&tokens[value_start..]);
+            tokens.extend(shingles.into_iter().map(Cow::Owned));
+        }
+
+        self.add_index_synonyms(&mut tokens);
+        tokens
+    }
+
+    /// Pairs of adjacent `tokens` of a single text, joined by a space.
+    /// Empty if shingles are disabled.
+    pub fn shingles(&self, tokens: &[Cow<'_, str>]) -> Vec<String> {
+        if !self.shingles {
+            return Vec::new();
         }
+        tokens
+            .iter()
+            .tuple_windows()
+            .map(|(first, second)| format!("{first} {second}"))
+            .collect()
+    }
+
+    /// Synonyms to match along with a query token, empty if synonyms are expanded at index time
+    pub fn query_synonyms(&self, token: &str) -> &[String] {
+        self.synonyms
+            .as_ref()
+            .map_or(&[], |synonyms| synonyms.query_synonyms(token))
+    }
+
+    /// Whether any query token may have synonyms
+    pub fn has_query_synonyms(&self) -> bool {
+        self.synonyms
+            .as_ref()
+            .is_some_and(|synonyms| !synonyms.expands_on_index())
+    }
+
+    /// Add synonyms of the document `tokens` to them, if synonyms are expanded at index time
+    pub fn add_index_synonyms(&self, tokens: &mut Vec<Cow<'_, str>>) {
+        let Some(synonyms) = &self.synonyms else {
+            return;
+        };
+        if !synonyms.expands_on_index() {
+            return;
+        }
+
+        let mut index_synonyms = Vec::new();
+        for token in tokens.iter() {
+            index_synonyms.extend(
+                synonyms
+                    .index_synonyms(token.as_ref())
+                    .iter()
+                    .cloned()
+                    .map(Cow::Owned),
+            );
+        }
+        tokens.extend(index_synonyms);
     }
 
     pub fn tokenize_doc<'a, C: FnMut(Cow<'a, str>)>(&'a self, text: &'a str, callback: C) {
@@ -204,24 +347,102 @@ impl Tokenizer {
                 WhiteSpaceTokenizer::tokenize(text, &self.tokens_processor, callback)
             }
             TokenizerType::Word => WordTokenizer::tokenize(text, &self.tokens_processor, callback),
-            TokenizerType::Multilingual => {
-                MultilingualTokenizer::tokenize(text, &self.tokens_processor, callback)
-            }
+            TokenizerType::Multilingual => match &self.language_processors {
+                Some(languages) => MultilingualTokenizer::tokenize_detected(
+                    text,
+                    &self.tokens_processor,
+                    languages,
+                    callback,
+                ),
+                None => MultilingualTokenizer::tokenize(text, &self.tokens_processor, callback),
+            },
+            TokenizerType::Cjk => CjkTokenizer::tokenize(
+                text,
+                &self.tokens_processor,
+                self.cjk_processors.as_deref(),
+                callback,
+            ),
             TokenizerType::Prefix => {
                 PrefixTokenizer::tokenize(text, &self.tokens_processor, callback)
             }
         }
     }
 
+    /// Same as [`Self::tokenize_doc`], but also provides the byte range of the word in `text`,
+    /// which the token was produced from.
+    pub fn tokenize_doc_with_offsets<'a, C: FnMut(Range<usize>, Cow<'a, str>)>(
+        &'a self,
+        text: &'a str,
+        mut callback: C,
+    ) {
+        match self.tokenizer_type {
+            TokenizerType::Whitespace => {
+                for word in text.split_whitespace() {
+                    let range = word_range(text, word);
+                    WhiteSpaceTokenizer::tokenize(word, &self.tokens_processor, |token| {
+                        callback(range.clone(), token)
+                    });
+                }
+            }
+            TokenizerType::Word => {
+                for word in text.split(|c| !char::is_alphanumeric(c)) {
+                    let range = word_range(text, word);
+                    WordTokenizer::tokenize(word, &self.tokens_processor, |token| {
+                        callback(range.clone(), token)
+                    });
+                }
+            }
+            TokenizerType::Multilingual => match &self.language_processors {
+                Some(languages) => MultilingualTokenizer::tokenize_detected_with_offsets(
+                    text,
+                    &self.tokens_processor,
+                    languages,
+                    callback,
+                ),
+                None => MultilingualTokenizer::tokenize_with_offsets(
+                    text,
+                    &self.tokens_processor,
+                    callback,
+                ),
+            },
+            TokenizerType::Cjk => CjkTokenizer::tokenize_with_offsets(
+                text,
+                &self.tokens_processor,
+                self.cjk_processors.as_deref(),
+                callback,
+            ),
+            TokenizerType::Prefix => {
+                for word in text.split(|c| !char::is_alphanumeric(c)) {
+                    let range = word_range(text, word);
+                    PrefixTokenizer::tokenize(word, &self.tokens_processor, |token| {
+                        callback(range.clone(), token)
+                    });
+                }
+            }
+        }
+    }
+
     pub fn tokenize_query<'a, C: FnMut(Cow<'a, str>)>(&'a self, text: &'a str, callback: C) {
         match self.tokenizer_type {
             TokenizerType::Whitespace => {
                 WhiteSpaceTokenizer::tokenize(text, &self.tokens_processor, callback)
             }
             TokenizerType::Word => WordTokenizer::tokenize(text, &self.tokens_processor, callback),
-            TokenizerType::Multilingual => {
-                MultilingualTokenizer::tokenize(text, &self.tokens_processor, callback)
-            }
+            TokenizerType::Multilingual => match &self.language_processors {
+                Some(languages) => MultilingualTokenizer::tokenize_detected(
+                    text,
+                    &self.tokens_processor,
+                    languages,
+                    callback,
+                ),
+                None => MultilingualTokenizer::tokenize(text, &self.tokens_processor, callback),
+            },
+            TokenizerType::Cjk => CjkTokenizer::tokenize(
+                text,
+                &self.tokens_processor,
+                self.cjk_processors.as_deref(),
+                callback,
+            ),
             TokenizerType::Prefix => {
                 PrefixTokenizer::tokenize_query(text, &self.tokens_processor, callback)
             }
@@ -238,7 +459,7 @@ mod tests {
     use super::*;
     use crate::data_types::index::{
         Language, Snowball, SnowballLanguage, SnowballParams, StemmingAlgorithm,
-        StopwordsInterface, TextIndexType,
+        StopwordsInterface, TextIndexType, TokenFilter,
     };
 
     fn make_stemmer(language: SnowballLanguage) -> Stemmer {
@@ -286,8 +507,15 @@ mod tests {
     #[test]
     fn test_prefix_tokenizer() {
         let text = "hello, мир!";
-        let tokens_processor =
-            TokensProcessor::new(true, false, Default::default(), None, Some(1), Some(4));
+        let tokens_processor = TokensProcessor::new(
+            true,
+            false,
+            false,
+            Default::default(),
+            None,
+            Some(1),
+            Some(4),
+        );
 
         let mut tokens = Vec::new();
         PrefixTokenizer::tokenize(text, &tokens_processor, |token| tokens.push(token));
@@ -306,7 +534,7 @@ mod tests {
     fn test_prefix_query_tokenizer() {
         let text = "hello, мир!";
         let tokens_processor =
-            TokensProcessor::new(true, false, Default::default(), None, None, Some(4));
+            TokensProcessor::new(true, false, false, Default::default(), None, None, Some(4));
 
         let mut tokens = Vec::new();
         PrefixTokenizer::tokenize_query(text, &tokens_processor, |token| tokens.push(token));
@@ -335,7 +563,7 @@ mod tests {
         let filter =
             StopwordsFilter::new(&Some(StopwordsInterface::new_custom(&["の", "は"])), false);
         let tokens_processor =
-            TokensProcessor::new(true, false, Arc::new(filter), None, None, None);
+            TokensProcessor::new(true, false, false, Arc::new(filter), None, None, None);
         MultilingualTokenizer::tokenize(text, &tokens_processor, |token| tokens.push(token));
         eprintln!("tokens = {tokens:#?}");
         assert_eq!(tokens.len(), 2);
@@ -361,7 +589,7 @@ mod tests {
         // Test stopwords getting applied
         let filter = StopwordsFilter::new(&Some(StopwordsInterface::new_custom(&["是"])), false);
         let tokens_processor =
-            TokensProcessor::new(true, false, Arc::new(filter), None, None, None);
+            TokensProcessor::new(true, false, false, Arc::new(filter), None, None, None);
         MultilingualTokenizer::tokenize(text, &tokens_processor, |token| tokens.push(token));
         eprintln!("tokens = {tokens:#?}");
         assert_eq!(tokens.len(), 3);
@@ -414,7 +642,15 @@ mod tests {
             phrase_matching: None,
             stopwords: None,
             stemmer: None,
+            language_detection: None,
+            detected_language_key: None,
+            unicode_normalization: None,
             enable_hnsw: None,
+            populate: None,
+            synonyms: None,
+            filters: None,
+            shingles: None,
+            max_document_frequency: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -447,7 +683,15 @@ mod tests {
             phrase_matching: None,
             stopwords: Some(StopwordsInterface::Language(Language::English)),
             stemmer: None,
+            language_detection: None,
+            detected_language_key: None,
+            unicode_normalization: None,
             enable_hnsw: None,
+            populate: None,
+            synonyms: None,
+            filters: None,
+            shingles: None,
+            max_document_frequency: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -491,7 +735,15 @@ mod tests {
                 phrase_matching: None,
                 stopwords: Some(StopwordsInterface::Language(Language::English)),
                 stemmer: None,
+                language_detection: None,
+                detected_language_key: None,
+                unicode_normalization: None,
                 enable_hnsw: None,
+                populate: None,
+                synonyms: None,
+                filters: None,
+                shingles: None,
+                max_document_frequency: None,
             };
 
             let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -528,7 +780,15 @@ mod tests {
                 &["quick", "fox"],
             )),
             stemmer: None,
+            language_detection: None,
+            detected_language_key: None,
+            unicode_normalization: None,
             enable_hnsw: None,
+            populate: None,
+            synonyms: None,
+            filters: None,
+            shingles: None,
+            max_document_frequency: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -565,7 +825,15 @@ mod tests {
             phrase_matching: None,
             stopwords: Some(StopwordsInterface::new_custom(&["as", "the", "a"])),
             stemmer: None,
+            language_detection: None,
+            detected_language_key: None,
+            unicode_normalization: None,
             enable_hnsw: None,
+            populate: None,
+            synonyms: None,
+            filters: None,
+            shingles: None,
+            max_document_frequency: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -605,7 +873,15 @@ mod tests {
             phrase_matching: None,
             stopwords: Some(StopwordsInterface::Language(Language::English)),
             stemmer: None,
+            language_detection: None,
+            detected_language_key: None,
+            unicode_normalization: None,
             enable_hnsw: None,
+            populate: None,
+            synonyms: None,
+            filters: None,
+            shingles: None,
+            max_document_frequency: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -645,7 +921,15 @@ mod tests {
                 &["I'd"],
             )),
             stemmer: None,
+            language_detection: None,
+            detected_language_key: None,
+            unicode_normalization: None,
             enable_hnsw: None,
+            populate: None,
+            synonyms: None,
+            filters: None,
+            shingles: None,
+            max_document_frequency: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -688,7 +972,15 @@ mod tests {
             phrase_matching: None,
             stopwords: Some(StopwordsInterface::new_custom(&["the", "The", "LAZY"])),
             stemmer: None,
+            language_detection: None,
+            detected_language_key: None,
+            unicode_normalization: None,
             enable_hnsw: None,
+            populate: None,
+            synonyms: None,
+            filters: None,
+            shingles: None,
+            max_document_frequency: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -737,7 +1029,15 @@ mod tests {
             phrase_matching: None,
             stopwords: None,
             stemmer: None,
+            language_detection: None,
+            detected_language_key: None,
+            unicode_normalization: None,
             enable_hnsw: None,
+            populate: None,
+            synonyms: None,
+            filters: None,
+            shingles: None,
+            max_document_frequency: None,
         };
         let tokenizer_disabled = Tokenizer::new_from_text_index_params(&params_disabled);
         let mut tokens_disabled = Vec::new();
@@ -756,7 +1056,15 @@ mod tests {
             phrase_matching: None,
             stopwords: None,
             stemmer: None,
+            language_detection: None,
+            detected_language_key: None,
+            unicode_normalization: None,
             enable_hnsw: None,
+            populate: None,
+            synonyms: None,
+            filters: None,
+            shingles: None,
+            max_document_frequency: None,
         };
         let tokenizer_enabled = Tokenizer::new_from_text_index_params(&params_enabled);
         let mut tokens_enabled = Vec::new();
@@ -768,8 +1076,15 @@ mod tests {
     fn test_ascii_folding_prefix_tokenizer() {
         let text = "ação";
         // With folding disabled: prefixes should preserve accents
-        let tokens_processor_disabled =
-            TokensProcessor::new(true, false, Default::default(), None, Some(1), Some(4));
+        let tokens_processor_disabled = TokensProcessor::new(
+            true,
+            false,
+            false,
+            Default::default(),
+            None,
+            Some(1),
+            Some(4),
+        );
         let mut tokens_disabled = Vec::new();
         PrefixTokenizer::tokenize(text, &tokens_processor_disabled, |t| {
             tokens_disabled.push(t.to_string())
@@ -785,8 +1100,15 @@ mod tests {
         );
 
         // With folding enabled: prefixes should be ASCII-only (acao, acao prefixes)
-        let tokens_processor_enabled =
-            TokensProcessor::new(true, true, Default::default(), None, Some(1), Some(4));
+        let tokens_processor_enabled = TokensProcessor::new(
+            true,
+            true,
+            false,
+            Default::default(),
+            None,
+            Some(1),
+            Some(4),
+        );
         let mut tokens_enabled = Vec::new();
         PrefixTokenizer::tokenize(text, &tokens_processor_enabled, |t| {
             tokens_enabled.push(t.to_string())
@@ -805,6 +1127,7 @@ mod tests {
         let mut tokens_processor = TokensProcessor::new(
             true,
             false,
+            false,
             Default::default(),
             Some(make_stemmer(SnowballLanguage::English)),
             None,
@@ -829,4 +1152,47 @@ mod tests {
         PrefixTokenizer::tokenize(input, &tokens_processor, |i| out.push(i.to_string()));
         assert_eq!(out, vec!["int", "inte", "pro", "proc", "liv", "live"]);
     }
+
+    #[test]
+    fn test_filters_pipeline() {
+        let text = "The Cafés are Running";
+        let params = |filters: Vec<TokenFilter>| TextIndexParams {
+            stopwords: Some(StopwordsInterface::new_custom(&["the", "are"])),
+            stemmer: Some(StemmingAlgorithm::Snowball(SnowballParams {
+                r#type: Snowball::Snowball,
+                language: SnowballLanguage::English,
+            })),
+            filters: Some(filters),
+            ..Default::default()
+        };
+        let tokenize = |params: &TextIndexParams| {
+            let tokenizer = Tokenizer::new_from_text_index_params(params);
+            let mut doc_tokens = Vec::new();
+            tokenizer.tokenize_doc(text, |token| doc_tokens.push(token.into_owned()));
+            let mut query_tokens = Vec::new();
+            tokenizer.tokenize_query(text, |token| query_tokens.push(token.into_owned()));
+            assert_eq!(doc_tokens, query_tokens);
+            doc_tokens
+        };
+
+        // Lowercase before stopwords drops them regardless of case
+        let tokens = tokenize(&params(vec![
+            TokenFilter::Lowercase,
+            TokenFilter::Stopwords,
+            TokenFilter::AsciiFolding,
+            TokenFilter::Stemmer,
+        ]));
+        assert_eq!(tokens, ["cafe", "run"]);
+
+        // Stopwords before lowercase are case-sensitive, filters out of the chain are not applied
+        let tokens = tokenize(&params(vec![
+            TokenFilter::Stopwords,
+            TokenFilter::Lowercase,
+        ]));
+        assert_eq!(tokens, ["the", "cafés", "running"]);
+
+        // Empty chain keeps tokens as they are
+        let tokens = tokenize(&params(vec![]));
+        assert_eq!(tokens, ["The", "Cafés", "are", "Running"]);
+    }
 }
@@ -1,9 +1,15 @@
 use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::Arc;
 
 use charabia::normalizer::{ClassifierOption, NormalizedTokenIter, NormalizerOption};
 use charabia::{Language, Script, Segment, StrDetection};
 
-use super::{TokensProcessor, japanese};
+use super::{Stemmer, TokensProcessor, japanese};
+use crate::data_types::index::{
+    Language as StopwordsLanguage, SnowballLanguage, StopwordsInterface,
+};
+use crate::index::field_index::full_text_index::stop_words::StopwordsFilter;
 
 /// Default normalizer options from charabia(https://github.com/meilisearch/charabia/blob/main/charabia/src/normalizer/mod.rs#L82) used
 /// in `str::tokenize()`.
@@ -42,12 +48,76 @@ impl MultilingualTokenizer {
         Self::tokenize_charabia(input, config, cb);
     }
 
+    /// Same as [`Self::tokenize`], but also provides the byte range of each token in the `input`.
+    pub fn tokenize_with_offsets<'a, C: FnMut(Range<usize>, Cow<'a, str>)>(
+        input: &'a str,
+        config: &'a TokensProcessor,
+        cb: C,
+    ) {
+        let script = detect_script_of_language(input);
+
+        if !script_is_latin(script) && detect_language(input) == Some(Language::Jpn) {
+            japanese::tokenize_with_offsets(input, config, cb);
+            return;
+        }
+
+        Self::tokenize_charabia_with_offsets(input, config, cb);
+    }
+
+    /// Same as [`Self::tokenize`], but applies stemming and stopwords of the detected language of the `input`.
+    /// Falls back to the `config` if the language can't be detected.
+    pub fn tokenize_detected<'a, C: FnMut(Cow<'a, str>)>(
+        input: &'a str,
+        config: &'a TokensProcessor,
+        languages: &'a LanguageProcessors,
+        cb: C,
+    ) {
+        let language = detect_language(input);
+        let config = languages.get(language).unwrap_or(config);
+
+        if language == Some(Language::Jpn) {
+            japanese::tokenize(input, config, cb);
+            return;
+        }
+
+        Self::tokenize_charabia(input, config, cb);
+    }
+
+    /// Same as [`Self::tokenize_detected`], but also provides the byte range of each token in the `input`.
+    pub fn tokenize_detected_with_offsets<'a, C: FnMut(Range<usize>, Cow<'a, str>)>(
+        input: &'a str,
+        config: &'a TokensProcessor,
+        languages: &'a LanguageProcessors,
+        cb: C,
+    ) {
+        let language = detect_language(input);
+        let config = languages.get(language).unwrap_or(config);
+
+        if language == Some(Language::Jpn) {
+            japanese::tokenize_with_offsets(input, config, cb);
+            return;
+        }
+
+        Self::tokenize_charabia_with_offsets(input, config, cb);
+    }
+
     // Tokenize input using charabia. Automatically applies stemming and filters stopwords if configured.
     fn tokenize_charabia<'a, C>(input: &'a str, tokens_processor: &'a TokensProcessor, mut cb: C)
     where
         C: FnMut(Cow<'a, str>),
+    {
+        Self::tokenize_charabia_with_offsets(input, tokens_processor, |_, token| cb(token));
+    }
+
+    fn tokenize_charabia_with_offsets<'a, C>(
+        input: &'a str,
+        tokens_processor: &'a TokensProcessor,
+        mut cb: C,
+    ) where
+        C: FnMut(Range<usize>, Cow<'a, str>),
     {
         for token in charabia_token_iter(input) {
+            let range = token.byte_start..token.byte_end;
             let lemma = token.lemma;
 
             if lemma.chars().all(|char| !char.is_alphabetic()) {
@@ -56,7 +126,7 @@ impl MultilingualTokenizer {
             }
 
             if let Some(processed_token) = tokens_processor.process_token_cow(lemma, true) {
-                cb(processed_token);
+                cb(range, processed_token);
             }
         }
     }
@@ -78,6 +148,116 @@ fn detect_language(input: &str) -> Option<charabia::Language> {
     StrDetection::new(input, Some(SUPPORTED_LANGUAGES)).language()
 }
 
+/// ISO 639-1 code of the language of the given input, if it can be detected.
+pub fn detect_language_code(input: &str) -> Option<&'static str> {
+    detect_language(input).and_then(language_code)
+}
+
+fn language_code(language: Language) -> Option<&'static str> {
+    let code = match language {
+        Language::Eng => "en",
+        Language::Rus => "ru",
+        Language::Por => "pt",
+        Language::Ita => "it",
+        Language::Deu => "de",
+        Language::Ara => "ar",
+        Language::Dan => "da",
+        Language::Swe => "sv",
+        Language::Fin => "fi",
+        Language::Tur => "tr",
+        Language::Nld => "nl",
+        Language::Hun => "hu",
+        Language::Ell => "el",
+        Language::Tam => "ta",
+        Language::Ron => "ro",
+        Language::Cmn => "zh",
+        Language::Jpn => "ja",
+        _ => return None,
+    };
+    Some(code)
+}
+
+fn stemmer_language(language: Language) -> Option<SnowballLanguage> {
+    let stemmer_language = match language {
+        Language::Eng => SnowballLanguage::English,
+        Language::Rus => SnowballLanguage::Russian,
+        Language::Por => SnowballLanguage::Portuguese,
+        Language::Ita => SnowballLanguage::Italian,
+        Language::Deu => SnowballLanguage::German,
+        Language::Ara => SnowballLanguage::Arabic,
+        Language::Dan => SnowballLanguage::Danish,
+        Language::Swe => SnowballLanguage::Swedish,
+        Language::Fin => SnowballLanguage::Finnish,
+        Language::Tur => SnowballLanguage::Turkish,
+        Language::Nld => SnowballLanguage::Dutch,
+        Language::Hun => SnowballLanguage::Hungarian,
+        Language::Ell => SnowballLanguage::Greek,
+        Language::Tam => SnowballLanguage::Tamil,
+        Language::Ron => SnowballLanguage::Romanian,
+        _ => return None,
+    };
+    Some(stemmer_language)
+}
+
+fn stopwords_language(language: Language) -> Option<StopwordsLanguage> {
+    let stopwords_language = match language {
+        Language::Eng => StopwordsLanguage::English,
+        Language::Rus => StopwordsLanguage::Russian,
+        Language::Por => StopwordsLanguage::Portuguese,
+        Language::Ita => StopwordsLanguage::Italian,
+        Language::Deu => StopwordsLanguage::German,
+        Language::Ara => StopwordsLanguage::Arabic,
+        Language::Dan => StopwordsLanguage::Danish,
+        Language::Swe => StopwordsLanguage::Swedish,
+        Language::Fin => StopwordsLanguage::Finnish,
+        Language::Tur => StopwordsLanguage::Turkish,
+        Language::Nld => StopwordsLanguage::Dutch,
+        Language::Hun => StopwordsLanguage::Hungarian,
+        Language::Ell => StopwordsLanguage::Greek,
+        Language::Ron => StopwordsLanguage::Romanian,
+        Language::Cmn => StopwordsLanguage::Chinese,
+        Language::Jpn => StopwordsLanguage::Japanese,
+        _ => return None,
+    };
+    Some(stopwords_language)
+}
+
+/// Tokens processors with stemming and stopwords of each of the [`SUPPORTED_LANGUAGES`].
+#[derive(Debug, Default)]
+pub struct LanguageProcessors {
+    processors: Vec<(Language, TokensProcessor)>,
+}
+
+impl LanguageProcessors {
+    /// Derive processors from the `base` one. Custom stopwords are applied to all languages.
+    pub fn new(base: &TokensProcessor, stopwords: &Option<StopwordsInterface>) -> Self {
+        let processors = SUPPORTED_LANGUAGES
+            .iter()
+            .map(|&language| {
+                let stopwords_filter = StopwordsFilter::new_for_language(
+                    stopwords,
+                    stopwords_language(language).as_ref(),
+                    base.lowercase_stopwords(),
+                );
+                let stemmer = stemmer_language(language).map(Stemmer::from_snowball_language);
+                let processor =
+                    base.with_stopwords_and_stemmer(Arc::new(stopwords_filter), stemmer);
+                (language, processor)
+            })
+            .collect();
+
+        Self { processors }
+    }
+
+    fn get(&self, language: Option<Language>) -> Option<&TokensProcessor> {
+        let language = language?;
+        self.processors
+            .iter()
+            .find(|(processor_language, _)| *processor_language == language)
+            .map(|(_, processor)| processor)
+    }
+}
+
 /// Returns `true` if the given `script` is latin.
 #[inline]
 fn script_is_latin(script: Script) -> bool {
@@ -182,6 +362,7 @@ mod test {
         let tokens_processor = TokensProcessor::new(
             true,
             false,
+            false,
             Default::default(),
             Some(Stemmer::from_algorithm(&StemmingAlgorithm::Snowball(
                 SnowballParams {
@@ -198,4 +379,33 @@ mod test {
         MultilingualTokenizer::tokenize(input, &tokens_processor, |i| out.push(i.to_string()));
         assert_eq!(out, vec!["test", "this"]);
     }
+
+    #[test]
+    fn test_multilingual_detected_language() {
+        let tokens_processor =
+            TokensProcessor::new(true, false, false, Default::default(), None, None, None);
+        let languages = LanguageProcessors::new(&tokens_processor, &None);
+
+        let tokenize = |input| {
+            let mut out = vec![];
+            MultilingualTokenizer::tokenize_detected(input, &tokens_processor, &languages, |i| {
+                out.push(i.to_string())
+            });
+            out
+        };
+
+        let input = "Die Katzen spielen im Garten und die Hunde schlafen in der Sonne.";
+        assert_eq!(detect_language_code(input), Some("de"));
+        let out = tokenize(input);
+        assert!(out.contains(&"katz".to_string()));
+        assert!(out.contains(&"hund".to_string()));
+        assert!(!out.contains(&"und".to_string()));
+
+        let input = "The cats are playing in the garden and the dogs are sleeping in the sun.";
+        assert_eq!(detect_language_code(input), Some("en"));
+        let out = tokenize(input);
+        assert!(out.contains(&"cat".to_string()));
+        assert!(out.contains(&"play".to_string()));
+        assert!(!out.contains(&"the".to_string()));
+    }
 }
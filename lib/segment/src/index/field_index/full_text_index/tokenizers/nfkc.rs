@@ -0,0 +1,43 @@
+use std::borrow::Cow;
+
+use unicode_normalization::{IsNormalized, UnicodeNormalization, is_nfkc_quick};
+
+/// Applies Unicode compatibility normalization (NFKC), e.g. "ﬁ" -> "fi" or "Ｃａｆｅ" -> "Cafe".
+///
+/// Also composes characters with combining marks into a single character where possible,
+/// so that ASCII folding can handle them afterwards.
+pub fn normalize_nfkc_cow<'a>(input: Cow<'a, str>) -> Cow<'a, str> {
+    // Cheap check if already normalized
+    if input.is_ascii() || is_nfkc_quick(input.chars()) == IsNormalized::Yes {
+        return input;
+    }
+
+    Cow::Owned(input.nfkc().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_nfkc() {
+        let cases = [
+            ("cafe", "cafe"),
+            ("café", "café"),
+            // "e" followed by combining acute accent
+            ("cafe\u{0301}", "café"),
+            ("Ｃａｆｅ", "Cafe"),
+            ("ﬁle", "file"),
+            ("①", "1"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(normalize_nfkc_cow(Cow::Borrowed(input)), expected);
+        }
+
+        assert!(matches!(
+            normalize_nfkc_cow(Cow::Borrowed("café")),
+            Cow::Borrowed(_),
+        ));
+    }
+}
@@ -38,8 +38,13 @@ impl Stemmer {
     pub fn try_default_from_language(language: &str) -> Option<Self> {
         let language = SnowballLanguage::from_str(language).ok()?;
 
-        Some(Self::Snowball(Arc::new(rust_stemmers::Stemmer::create(
-            Algorithm::from(language),
+        Some(Self::from_snowball_language(language))
+    }
+
+    /// Construct snowball stemmer for a given language.
+    pub fn from_snowball_language(language: SnowballLanguage) -> Self {
+        Self::Snowball(Arc::new(rust_stemmers::Stemmer::create(Algorithm::from(
+            language,
         ))))
     }
 
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use super::TokensProcessor;
+use crate::data_types::index::SynonymsParams;
+
+/// Synonyms of tokens, processed in the same way as the tokens of texts,
+/// so that they are lowercased, stemmed and folded consistently with the index.
+#[derive(Debug)]
+pub struct Synonyms {
+    /// Synonyms of each token, not including the token itself
+    synonyms: HashMap<String, Vec<String>>,
+    /// Whether synonyms are added to the tokens of indexed texts, instead of to the query tokens
+    expand_on_index: bool,
+}
+
+impl Synonyms {
+    pub fn new(
+        params: &SynonymsParams,
+        tokens_processor: &TokensProcessor,
+        phrase_matching: bool,
+    ) -> Self {
+        let SynonymsParams {
+            groups,
+            file,
+            expand_on_index,
+        } = params;
+
+        let file_groups = file.as_deref().map(read_groups).unwrap_or_default();
+
+        let mut synonyms: HashMap<String, Vec<String>> = HashMap::new();
+        for group in groups.iter().chain(&file_groups) {
+            let tokens = group
+                .iter()
+                .filter_map(|word| tokens_processor.process_token(word.trim(), false))
+                .map(|token| token.into_owned())
+                .unique()
+                .collect_vec();
+
+            for token in &tokens {
+                let entry = synonyms.entry(token.clone()).or_default();
+                entry.extend(tokens.iter().filter(|other| *other != token).cloned());
+            }
+        }
+
+        // A token may be in several groups
+        for token_synonyms in synonyms.values_mut() {
+            token_synonyms.sort_unstable();
+            token_synonyms.dedup();
+        }
+
+        // Documents with positions must keep the original tokens only, to match phrases
+        let expand_on_index = expand_on_index.unwrap_or(false) && !phrase_matching;
+
+        Self {
+            synonyms,
+            expand_on_index,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.synonyms.is_empty()
+    }
+
+    pub fn expands_on_index(&self) -> bool {
+        self.expand_on_index
+    }
+
+    fn get(&self, token: &str) -> &[String] {
+        self.synonyms.get(token).map_or(&[], Vec::as_slice)
+    }
+
+    /// Synonyms to add to a query token, if synonyms are expanded at query time
+    pub fn query_synonyms(&self, token: &str) -> &[String] {
+        if self.expand_on_index {
+            return &[];
+        }
+        self.get(token)
+    }
+
+    /// Synonyms to add to a document token, if synonyms are expanded at index time
+    pub fn index_synonyms(&self, token: &str) -> &[String] {
+        if !self.expand_on_index {
+            return &[];
+        }
+        self.get(token)
+    }
+}
+
+/// Read groups of synonyms from a file, one group of comma-separated words per line.
+///
+/// Empty lines and lines starting with `#` are skipped.
+/// If the file can't be read, it is ignored, so that the index can still be loaded.
+fn read_groups(path: &str) -> Vec<Vec<String>> {
+    let content = match fs_err::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) => {
+            log::warn!("Failed to read synonyms file, ignoring it: {err}");
+            return Vec::new();
+        }
+    };
+
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split(',').map(str::to_string).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(groups: &[&[&str]]) -> SynonymsParams {
+        SynonymsParams {
+            groups: groups
+                .iter()
+                .map(|group| group.iter().map(|word| word.to_string()).collect())
+                .collect(),
+            file: None,
+            expand_on_index: None,
+        }
+    }
+
+    #[test]
+    fn test_synonyms() {
+        let tokens_processor =
+            TokensProcessor::new(true, false, false, Default::default(), None, None, None);
+        let params = params(&[&["Car", "automobile", "auto"], &["auto", "automatic"]]);
+        let synonyms = Synonyms::new(&params, &tokens_processor, false);
+
+        assert_eq!(synonyms.query_synonyms("car"), ["auto", "automobile"]);
+        assert_eq!(
+            synonyms.query_synonyms("auto"),
+            ["automatic", "automobile", "car"],
+        );
+        assert_eq!(synonyms.query_synonyms("automatic"), ["auto"]);
+        assert!(synonyms.query_synonyms("bike").is_empty());
+        assert!(synonyms.index_synonyms("car").is_empty());
+    }
+
+    #[test]
+    fn test_synonyms_on_index() {
+        let tokens_processor = TokensProcessor::default();
+        let mut params = params(&[&["car", "automobile"]]);
+        params.expand_on_index = Some(true);
+
+        let synonyms = Synonyms::new(&params, &tokens_processor, false);
+        assert_eq!(synonyms.index_synonyms("car"), ["automobile"]);
+        assert!(synonyms.query_synonyms("car").is_empty());
+
+        // Phrase matching requires original tokens in the index
+        let synonyms = Synonyms::new(&params, &tokens_processor, true);
+        assert!(synonyms.index_synonyms("car").is_empty());
+        assert_eq!(synonyms.query_synonyms("car"), ["automobile"]);
+    }
+
+    #[test]
+    fn test_synonyms_file() {
+        let dir = tempfile::Builder::new()
+            .prefix("synonyms")
+            .tempdir()
+            .unwrap();
+        let path = dir.path().join("synonyms.txt");
+        fs_err::write(&path, "# vehicles\ncar, automobile\n\nbike,bicycle\n").unwrap();
+
+        let params = SynonymsParams {
+            groups: vec![],
+            file: Some(path.to_string_lossy().into_owned()),
+            expand_on_index: None,
+        };
+        let synonyms = Synonyms::new(&params, &TokensProcessor::default(), false);
+        assert_eq!(synonyms.query_synonyms("automobile"), ["car"]);
+        assert_eq!(synonyms.query_synonyms("bicycle"), ["bike"]);
+
+        // Missing file is ignored
+        let params = SynonymsParams {
+            file: Some(
+                dir.path()
+                    .join("missing.txt")
+                    .to_string_lossy()
+                    .into_owned(),
+            ),
+            ..params
+        };
+        assert!(Synonyms::new(&params, &TokensProcessor::default(), false).is_empty());
+    }
+}
@@ -1,24 +1,42 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
+use itertools::Either;
+
 use super::stemmer::Stemmer;
+use crate::data_types::index::TokenFilter;
 use crate::index::field_index::full_text_index::stop_words::StopwordsFilter;
 
+/// Order of filters, if no pipeline is configured explicitly.
+///
+/// Unicode normalization goes before folding to compose combining accents.
+const DEFAULT_FILTERS: [TokenFilter; 5] = [
+    TokenFilter::UnicodeNormalization,
+    TokenFilter::AsciiFolding,
+    TokenFilter::Lowercase,
+    TokenFilter::Stopwords,
+    TokenFilter::Stemmer,
+];
+
 // TODO(rocksdb): Remove `Clone` once rocksdb has been removed!
 #[derive(Debug, Clone, Default)]
 pub struct TokensProcessor {
     pub lowercase: bool,
     pub ascii_folding: bool,
+    pub unicode_normalization: bool,
     stopwords_filter: Arc<StopwordsFilter>, // TDOO(rocksdb): Remove once rocksdb has been removed!
     stemmer: Option<Stemmer>,
     pub min_token_len: Option<usize>,
     pub max_token_len: Option<usize>,
+    /// Explicit order of filters, overrides the flags above if set
+    filters: Option<Arc<[TokenFilter]>>,
 }
 
 impl TokensProcessor {
     pub fn new(
         lowercase: bool,
         ascii_folding: bool,
+        unicode_normalization: bool,
         stopwords_filter: Arc<StopwordsFilter>,
         stemmer: Option<Stemmer>,
         min_token_len: Option<usize>,
@@ -27,14 +45,93 @@ impl TokensProcessor {
         Self {
             lowercase,
             ascii_folding,
+            unicode_normalization,
             stopwords_filter,
             stemmer,
             min_token_len,
             max_token_len,
+            filters: None,
+        }
+    }
+
+    /// Same processor, but applying exactly the given `filters` in the given order.
+    pub fn with_filters(self, filters: &[TokenFilter]) -> Self {
+        Self {
+            lowercase: filters.contains(&TokenFilter::Lowercase),
+            ascii_folding: filters.contains(&TokenFilter::AsciiFolding),
+            unicode_normalization: filters.contains(&TokenFilter::UnicodeNormalization),
+            filters: Some(filters.into()),
+            ..self
+        }
+    }
+
+    /// Whether tokens are lowercased before they are checked against stopwords,
+    /// so stopwords should be lowercased as well
+    pub fn lowercase_stopwords(&self) -> bool {
+        self.filters()
+            .take_while(|filter| *filter != TokenFilter::Stopwords)
+            .any(|filter| filter == TokenFilter::Lowercase)
+    }
+
+    /// Filters to apply to each token, in order
+    fn filters(&self) -> impl Iterator<Item = TokenFilter> + '_ {
+        match &self.filters {
+            Some(filters) => Either::Left(filters.iter().copied()),
+            None => Either::Right(DEFAULT_FILTERS.into_iter().filter(|filter| match filter {
+                TokenFilter::Lowercase => self.lowercase,
+                TokenFilter::AsciiFolding => self.ascii_folding,
+                TokenFilter::UnicodeNormalization => self.unicode_normalization,
+                TokenFilter::Stopwords | TokenFilter::Stemmer => true,
+            })),
+        }
+    }
+
+    /// Applies a single filter to the token. Returns `None` if the token is dropped.
+    fn apply_filter<'a>(&self, filter: TokenFilter, token: Cow<'a, str>) -> Option<Cow<'a, str>> {
+        match filter {
+            TokenFilter::Stopwords => (!self.stopwords_filter.is_stopword(&token)).then_some(token),
+            _ => Some(self.transform(filter, token)),
+        }
+    }
+
+    /// Applies a single filter, which changes the token. Stopwords filter keeps the token as is.
+    fn transform<'a>(&self, filter: TokenFilter, token: Cow<'a, str>) -> Cow<'a, str> {
+        match filter {
+            TokenFilter::Lowercase => Cow::Owned(token.to_lowercase()),
+            TokenFilter::AsciiFolding => super::ascii_folding::fold_to_ascii_cow(token),
+            TokenFilter::UnicodeNormalization => super::nfkc::normalize_nfkc_cow(token),
+            TokenFilter::Stemmer => self.stem_if_enabled(token),
+            TokenFilter::Stopwords => token,
+        }
+    }
+
+    /// Applies all filters except stopwords, which can't drop partial words of prefix queries.
+    pub fn apply_filters_keep_stopwords<'a>(&self, token: Cow<'a, str>) -> Cow<'a, str> {
+        self.filters()
+            .fold(token, |token, filter| self.transform(filter, token))
+    }
+
+    /// Same processor, but with different stopwords.
+    pub fn with_stopwords(&self, stopwords_filter: Arc<StopwordsFilter>) -> Self {
+        Self {
+            stopwords_filter,
+            ..self.clone()
+        }
+    }
+
+    /// Same processor, but with different stopwords and stemmer.
+    pub fn with_stopwords_and_stemmer(
+        &self,
+        stopwords_filter: Arc<StopwordsFilter>,
+        stemmer: Option<Stemmer>,
+    ) -> Self {
+        Self {
+            stopwords_filter,
+            stemmer,
+            ..self.clone()
         }
     }
 
-    #[cfg(test)]
     pub fn set_stopwords(&mut self, stopwords_filter: Arc<StopwordsFilter>) {
         self.stopwords_filter = stopwords_filter;
     }
@@ -49,15 +146,6 @@ impl TokensProcessor {
         stemmer.stem(input)
     }
 
-    /// Applies ASCII folding if enabled. Converts accented characters to their ASCII equivalents.
-    pub fn fold_if_enabled<'a>(&self, input: Cow<'a, str>) -> Cow<'a, str> {
-        if self.ascii_folding {
-            super::ascii_folding::fold_to_ascii_cow(input)
-        } else {
-            input
-        }
-    }
-
     pub fn is_stopword(&self, token: &str) -> bool {
         self.stopwords_filter.is_stopword(token)
     }
@@ -68,38 +156,24 @@ impl TokensProcessor {
         check_max_len: bool,
     ) -> Option<Cow<'a, str>> {
         let Self {
-            lowercase,
-            stopwords_filter,
-            stemmer,
+            lowercase: _,
+            ascii_folding: _,
+            unicode_normalization: _,
+            stopwords_filter: _,
+            stemmer: _,
             min_token_len,
             max_token_len,
-            ascii_folding,
+            filters: _,
         } = self;
 
         if token_cow.is_empty() {
             return None;
         }
 
-        // Handle ASCII folding (normalize accents)
-        if *ascii_folding {
-            token_cow = super::ascii_folding::fold_to_ascii_cow(token_cow);
-        }
-
-        // Handle lowercase
-        if *lowercase {
-            token_cow = Cow::Owned(token_cow.to_lowercase());
-        }
-
-        // Handle stopwords
-        if stopwords_filter.is_stopword(&token_cow) {
-            return None;
+        for filter in self.filters() {
+            token_cow = self.apply_filter(filter, token_cow)?;
         }
 
-        // Handle stemming
-        if let Some(stemmer) = stemmer.as_ref() {
-            token_cow = stemmer.stem(token_cow);
-        };
-
         // Handle token length
         if min_token_len.is_some_and(|min_len| token_cow.chars().count() < min_len)
             || (check_max_len
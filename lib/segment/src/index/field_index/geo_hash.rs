@@ -2,13 +2,13 @@ use std::fmt::Display;
 use std::ops::{Index, Range};
 
 use ecow::EcoString;
-use geo::{Coord, Distance, Haversine, Intersects, LineString, Point, Polygon};
+use geo::{Contains, Coord, Distance, Haversine, Intersects, LineString, Point, Polygon, Rect};
 use geohash::{Direction, GeohashError, decode, decode_bbox, encode};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::types::{GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius};
+use crate::types::{GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, PolygonWrapper};
 
 /// Packed representation of a geohash string.
 ///
@@ -380,14 +380,92 @@ fn check_circle_intersection(geohash: &str, circle: &GeoRadius) -> bool {
 }
 
 /// Check if geohash tile intersects the polygon
-fn check_polygon_intersection(geohash: &str, polygon: &Polygon) -> bool {
+fn check_polygon_intersection(geohash: &str, polygon: &PolygonWrapper) -> bool {
     let precision = geohash.len();
     if precision == 0 {
         return true;
     }
     let rect = decode_bbox(geohash).unwrap();
 
-    rect.intersects(polygon)
+    // Tiles away from the bounding box can't intersect, skip the exact check for them
+    polygon.bounding_rect_intersects(&rect) && rect.intersects(&polygon.polygon)
+}
+
+/// Check if the `inner` rectangle lies within the `outer` one, boundaries included
+fn rect_within(inner: &Rect, outer: &Rect) -> bool {
+    outer.min().x <= inner.min().x
+        && inner.max().x <= outer.max().x
+        && outer.min().y <= inner.min().y
+        && inner.max().y <= outer.max().y
+}
+
+/// Check if geohash tile is fully inside the rectangle, so every point of the tile is in it
+pub fn rectangle_contains_geohash(rectangle: &GeoBoundingBox, geohash: GeoHash) -> bool {
+    if geohash.is_empty() {
+        return false;
+    }
+    let tile = geo_hash_to_box(geohash);
+
+    let latitude_check = rectangle.bottom_right.lat < tile.bottom_right.lat
+        && tile.top_left.lat < rectangle.top_left.lat;
+
+    let longitude_check = if rectangle.top_left.lon > rectangle.bottom_right.lon {
+        // Handle antimeridian crossing, tile must be fully on one side of it
+        tile.top_left.lon > rectangle.top_left.lon
+            || tile.bottom_right.lon < rectangle.bottom_right.lon
+    } else {
+        rectangle.top_left.lon < tile.top_left.lon
+            && tile.bottom_right.lon < rectangle.bottom_right.lon
+    };
+
+    latitude_check && longitude_check
+}
+
+/// Check if geohash tile is fully inside the circle, so every point of the tile is in it
+pub fn circle_contains_geohash(circle: &GeoRadius, geohash: GeoHash) -> bool {
+    if geohash.is_empty() {
+        return false;
+    }
+    let rect = decode_bbox(EcoString::from(geohash).as_str()).unwrap();
+    let tile_center = rect.center();
+
+    // Tiles are small enough for the farthest point of a tile to be one of its corners
+    let tile_radius = rect
+        .to_polygon()
+        .exterior()
+        .points()
+        .map(|corner| Haversine.distance(Point(tile_center), corner))
+        .fold(0.0, f64::max);
+
+    let center_distance = Haversine.distance(Point(tile_center), Point::from(circle.center));
+    center_distance + tile_radius < circle.radius.0
+}
+
+/// Check if geohash tile is fully inside the polygon, so every point of the tile is in it
+pub fn polygon_contains_geohash(polygon_wrapper: &PolygonWrapper, geohash: GeoHash) -> bool {
+    if geohash.is_empty() {
+        return false;
+    }
+    let tile_rect = decode_bbox(EcoString::from(geohash).as_str()).unwrap();
+
+    // Tiles sticking out of the bounding box can't be inside the polygon
+    let within_bounding_rect = polygon_wrapper
+        .bounding_rect
+        .is_some_and(|bounding_rect| rect_within(&tile_rect, &bounding_rect));
+    if !within_bounding_rect {
+        return false;
+    }
+
+    let polygon = &polygon_wrapper.polygon;
+    let tile = tile_rect.to_polygon();
+
+    // Points on the boundary of the polygon don't match, so the tile must not touch it
+    polygon.contains(&tile)
+        && !polygon.exterior().intersects(&tile)
+        && !polygon
+            .interiors()
+            .iter()
+            .any(|interior| interior.intersects(&tile))
 }
 
 fn create_hashes(
@@ -457,7 +535,7 @@ pub fn rectangle_hashes(
 fn boundary_hashes(boundary: &LineString, max_regions: usize) -> OperationResult<Vec<GeoHash>> {
     let geo_bounding_box = minimum_bounding_rectangle_for_boundary(boundary);
     let full_geohash_bounding_box: GeohashBoundingBox = geo_bounding_box.into();
-    let polygon = Polygon::new(boundary.clone(), vec![]);
+    let polygon = PolygonWrapper::new(Polygon::new(boundary.clone(), vec![]));
 
     let mapping_fn = |precision| {
         full_geohash_bounding_box
@@ -504,8 +582,9 @@ pub fn polygon_hashes(polygon: &GeoPolygon, max_regions: usize) -> OperationResu
             "max_regions cannot be equal to zero",
         ));
     }
-    let polygon_wrapper = polygon.convert().polygon;
-    let geo_bounding_box = minimum_bounding_rectangle_for_boundary(polygon_wrapper.exterior());
+    let polygon_wrapper = polygon.convert();
+    let geo_bounding_box =
+        minimum_bounding_rectangle_for_boundary(polygon_wrapper.polygon.exterior());
     let full_geohash_bounding_box: GeohashBoundingBox = geo_bounding_box.into();
 
     let mapping_fn = |precision| {
@@ -976,10 +1055,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_polygon_bounding_rect_prefilter() {
+        let polygon = build_polygon_with_interiors(
+            vec![
+                (-10.0, -10.0),
+                (10.0, -10.0),
+                (25.0, 5.0),
+                (5.0, 30.0),
+                (-10.0, -10.0),
+            ],
+            vec![vec![
+                (0.0, 5.0),
+                (5.0, 5.0),
+                (5.0, 10.0),
+                (0.0, 10.0),
+                (0.0, 5.0),
+            ]],
+        );
+        let polygon_wrapper = polygon.convert();
+        assert_eq!(
+            polygon_wrapper.bounding_rect,
+            Some(Rect::new(
+                Coord { x: -10.0, y: -10.0 },
+                Coord { x: 25.0, y: 30.0 },
+            )),
+        );
+
+        // Pre-filter doesn't change the result of exact checks
+        let mut rnd = StdRng::seed_from_u64(42);
+        for _ in 0..10_000 {
+            let point = GeoPoint::new_unchecked(
+                rnd.random_range(-30.0..40.0),
+                rnd.random_range(-30.0..40.0),
+            );
+            let exact = polygon_wrapper
+                .polygon
+                .contains(&Point::new(point.lon.0, point.lat.0));
+            assert_eq!(polygon_wrapper.check_point(&point), exact);
+        }
+
+        // Tiles outside of the bounding box are rejected without exact checks
+        let outside = GeoHash::try_from("kz".to_string()).unwrap();
+        assert!(!check_polygon_intersection("kz", &polygon_wrapper));
+        assert!(!polygon_contains_geohash(&polygon_wrapper, outside));
+
+        let inside = encode_max_precision(8.0, 20.0).unwrap();
+        let inside = inside.truncate(6);
+        assert!(check_polygon_intersection(
+            EcoString::from(inside).as_str(),
+            &polygon_wrapper,
+        ));
+        assert!(polygon_contains_geohash(&polygon_wrapper, inside));
+    }
+
     #[test]
     fn test_check_polygon_intersection() {
         fn check_intersection(geohash: &str, polygon: &GeoPolygon, expected: bool) {
-            let intersect = check_polygon_intersection(geohash, &polygon.convert().polygon);
+            let intersect = check_polygon_intersection(geohash, &polygon.convert());
             assert_eq!(intersect, expected);
         }
 
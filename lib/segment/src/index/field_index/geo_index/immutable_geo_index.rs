@@ -7,6 +7,7 @@ use parking_lot::RwLock;
 #[cfg(feature = "rocksdb")]
 use rocksdb::DB;
 
+use super::GeoHashConfig;
 use super::mmap_geo_index::MmapGeoMapIndex;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -14,7 +15,9 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::rocksdb_buffered_delete_wrapper::DatabaseColumnScheduledDeleteWrapper;
 #[cfg(feature = "rocksdb")]
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
-use crate::index::field_index::geo_hash::{GeoHash, encode_max_precision};
+use crate::index::field_index::geo_hash::GeoHash;
+#[cfg(feature = "rocksdb")]
+use crate::index::field_index::geo_hash::encode_max_precision;
 use crate::index::field_index::immutable_point_to_values::ImmutablePointToValues;
 use crate::index::payload_config::StorageType;
 use crate::types::GeoPoint;
@@ -49,6 +52,7 @@ pub struct ImmutableGeoMapIndex {
     points_count: usize,
     points_values_count: usize,
     max_values_per_point: usize,
+    config: GeoHashConfig,
     // Backing s torage, source of state, persists deletions
     storage: Storage,
 }
@@ -65,6 +69,7 @@ impl ImmutableGeoMapIndex {
     pub fn open_rocksdb(
         db: std::sync::Arc<RwLock<DB>>,
         store_cf_name: &str,
+        config: GeoHashConfig,
     ) -> OperationResult<Option<Self>> {
         use std::collections::BTreeMap;
 
@@ -81,6 +86,7 @@ impl ImmutableGeoMapIndex {
             db_wrapper.get_database(),
             db_wrapper.get_column_name(),
             false,
+            config,
         )?
         else {
             // Column family doesn't exist, cannot load
@@ -131,6 +137,7 @@ impl ImmutableGeoMapIndex {
             points_count,
             points_values_count,
             max_values_per_point,
+            config,
             storage: Storage::RocksDb(db_wrapper),
         }))
     }
@@ -211,6 +218,7 @@ impl ImmutableGeoMapIndex {
             points_count: index.points_count(),
             points_values_count: index.points_values_count(),
             max_values_per_point: index.max_values_per_point(),
+            config: index.config(),
             storage: Storage::Mmap(Box::new(index)),
         };
 
@@ -221,8 +229,8 @@ impl ImmutableGeoMapIndex {
                 .saturating_sub(removed_geo_points.len());
 
             let removed_geo_hashes: Vec<_> = removed_geo_points
-                .into_iter()
-                .map(|geo_point| encode_max_precision(geo_point.lon.0, geo_point.lat.0).unwrap())
+                .iter()
+                .map(|geo_point| index.config.encode(geo_point).unwrap())
                 .collect();
             for removed_geo_hash in &removed_geo_hashes {
                 index.decrement_hash_value_counts(removed_geo_hash);
@@ -302,6 +310,10 @@ impl ImmutableGeoMapIndex {
         self.max_values_per_point
     }
 
+    pub fn config(&self) -> GeoHashConfig {
+        self.config
+    }
+
     pub fn check_values_any(
         &self,
         idx: PointOffsetType,
@@ -358,14 +370,17 @@ impl ImmutableGeoMapIndex {
         let mut removed_geo_hashes = Vec::with_capacity(removed_geo_points.len());
 
         for removed_geo_point in removed_geo_points {
-            let removed_geo_hash: GeoHash =
-                encode_max_precision(removed_geo_point.lon.0, removed_geo_point.lat.0).unwrap();
+            let removed_geo_hash = self.config.encode(&removed_geo_point)?;
             removed_geo_hashes.push(removed_geo_hash);
 
             match self.storage {
                 #[cfg(feature = "rocksdb")]
                 Storage::RocksDb(ref db_wrapper) => {
-                    let key = super::GeoMapIndex::encode_db_key(removed_geo_hash, idx);
+                    // Keys are stored with full precision
+                    let key_geo_hash =
+                        encode_max_precision(removed_geo_point.lon.0, removed_geo_point.lat.0)
+                            .unwrap();
+                    let key = super::GeoMapIndex::encode_db_key(key_geo_hash, idx);
                     db_wrapper.remove(&key)?;
                 }
                 Storage::Mmap(ref mut index) => {
@@ -12,6 +12,7 @@ use memory::mmap_ops::{create_and_ensure_length, open_write_mmap};
 use memory::mmap_type::{MmapBitSlice, MmapSlice};
 use serde::{Deserialize, Serialize};
 
+use super::GeoHashConfig;
 use super::mutable_geo_index::InMemoryGeoMapIndex;
 use crate::common::Flusher;
 use crate::common::mmap_bitslice_buffered_update_wrapper::MmapBitSliceBufferedUpdateWrapper;
@@ -63,6 +64,7 @@ pub struct MmapGeoMapIndex {
     points_values_count: usize,
     max_values_per_point: usize,
     is_on_disk: bool,
+    config: GeoHashConfig,
 }
 
 pub(super) struct Storage {
@@ -195,12 +197,16 @@ impl MmapGeoMapIndex {
             },
         )?;
 
-        Self::open(path, is_on_disk)?.ok_or_else(|| {
+        Self::open(path, is_on_disk, dynamic_index.config)?.ok_or_else(|| {
             OperationError::service_error("Failed to open MmapGeoMapIndex after building it")
         })
     }
 
-    pub fn open(path: &Path, is_on_disk: bool) -> OperationResult<Option<Self>> {
+    pub fn open(
+        path: &Path,
+        is_on_disk: bool,
+        config: GeoHashConfig,
+    ) -> OperationResult<Option<Self>> {
         let deleted_path = path.join(DELETED_PATH);
         let stats_path = path.join(STATS_PATH);
         let counts_per_hash_path = path.join(COUNTS_PER_HASH);
@@ -254,6 +260,7 @@ impl MmapGeoMapIndex {
             points_values_count: stats.points_values_count,
             max_values_per_point: stats.max_values_per_point,
             is_on_disk,
+            config,
         }))
     }
 
@@ -427,6 +434,10 @@ impl MmapGeoMapIndex {
         self.points_values_count
     }
 
+    pub fn config(&self) -> GeoHashConfig {
+        self.config
+    }
+
     pub fn max_values_per_point(&self) -> usize {
         self.max_values_per_point
     }
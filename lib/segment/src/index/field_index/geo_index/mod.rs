@@ -25,9 +25,11 @@ use self::mutable_geo_index::MutableGeoMapIndex;
 use super::FieldIndexBuilderTrait;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::index::GeoIndexParams;
 use crate::index::field_index::geo_hash::{
-    GeoHash, circle_hashes, common_hash_prefix, geo_hash_to_box, polygon_hashes,
-    polygon_hashes_estimation, rectangle_hashes,
+    GEOHASH_MAX_LENGTH, GeoHash, circle_contains_geohash, circle_hashes, common_hash_prefix,
+    encode_max_precision, geo_hash_to_box, polygon_contains_geohash, polygon_hashes,
+    polygon_hashes_estimation, rectangle_contains_geohash, rectangle_hashes,
 };
 use crate::index::field_index::stat_tools::estimate_multi_value_selection_cardinality;
 use crate::index::field_index::{
@@ -41,10 +43,71 @@ pub mod immutable_geo_index;
 pub mod mmap_geo_index;
 pub mod mutable_geo_index;
 
-/// Max number of sub-regions computed for an input geo query
-// TODO discuss value, should it be dynamically computed?
+/// Default max number of sub-regions computed for an input geo query
 const GEO_QUERY_MAX_REGION: usize = 12;
 
+/// Geohash settings of the index, resolved from [`GeoIndexParams`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeoHashConfig {
+    /// Length of geohashes stored in the index
+    pub precision: usize,
+    /// Max number of geohash cells a query region is covered with
+    pub max_query_cells: usize,
+}
+
+impl Default for GeoHashConfig {
+    fn default() -> Self {
+        Self {
+            precision: GEOHASH_MAX_LENGTH,
+            max_query_cells: GEO_QUERY_MAX_REGION,
+        }
+    }
+}
+
+impl From<&GeoIndexParams> for GeoHashConfig {
+    fn from(params: &GeoIndexParams) -> Self {
+        let default = Self::default();
+        Self {
+            precision: params.precision.map_or(default.precision, |precision| {
+                precision.clamp(1, GEOHASH_MAX_LENGTH)
+            }),
+            max_query_cells: params
+                .max_query_cells
+                .map_or(default.max_query_cells, |max_query_cells| {
+                    max_query_cells.max(1)
+                }),
+        }
+    }
+}
+
+impl GeoHashConfig {
+    /// Geohash of the cell the point is stored in
+    pub fn encode(&self, geo_point: &GeoPoint) -> OperationResult<GeoHash> {
+        encode_max_precision(geo_point.lon.0, geo_point.lat.0)
+            .map(|geo_hash| geo_hash.truncate(self.precision))
+            .map_err(|e| OperationError::service_error(format!("Malformed geo points: {e}")))
+    }
+
+    /// Bring geohashes of a query region to the precision of the index.
+    ///
+    /// Stored geohashes can't be longer than the index precision, so longer query geohashes
+    /// are replaced with their prefix.
+    fn query_hashes(&self, geo_hashes: Vec<GeoHash>) -> Vec<GeoHash> {
+        if geo_hashes
+            .iter()
+            .all(|geo_hash| geo_hash.len() <= self.precision)
+        {
+            return geo_hashes;
+        }
+        geo_hashes
+            .into_iter()
+            .map(|geo_hash| geo_hash.truncate(self.precision))
+            .sorted()
+            .dedup()
+            .collect()
+    }
+}
+
 pub enum GeoMapIndex {
     Mutable(MutableGeoMapIndex),
     Immutable(ImmutableGeoMapIndex),
@@ -58,19 +121,25 @@ impl GeoMapIndex {
         field: &str,
         is_appendable: bool,
         create_if_missing: bool,
+        config: GeoHashConfig,
     ) -> OperationResult<Option<Self>> {
         let store_cf_name = GeoMapIndex::storage_cf_name(field);
         let index = if is_appendable {
-            MutableGeoMapIndex::open_rocksdb(db, &store_cf_name, create_if_missing)?
+            MutableGeoMapIndex::open_rocksdb(db, &store_cf_name, create_if_missing, config)?
                 .map(GeoMapIndex::Mutable)
         } else {
-            ImmutableGeoMapIndex::open_rocksdb(db, &store_cf_name)?.map(GeoMapIndex::Immutable)
+            ImmutableGeoMapIndex::open_rocksdb(db, &store_cf_name, config)?
+                .map(GeoMapIndex::Immutable)
         };
         Ok(index)
     }
 
-    pub fn new_mmap(path: &Path, is_on_disk: bool) -> OperationResult<Option<Self>> {
-        let Some(mmap_index) = MmapGeoMapIndex::open(path, is_on_disk)? else {
+    pub fn new_mmap(
+        path: &Path,
+        is_on_disk: bool,
+        config: GeoHashConfig,
+    ) -> OperationResult<Option<Self>> {
+        let Some(mmap_index) = MmapGeoMapIndex::open(path, is_on_disk, config)? else {
             // Files don't exist, cannot load
             return Ok(None);
         };
@@ -84,13 +153,24 @@ impl GeoMapIndex {
         Ok(Some(index))
     }
 
-    pub fn new_gridstore(dir: PathBuf, create_if_missing: bool) -> OperationResult<Option<Self>> {
-        Ok(MutableGeoMapIndex::open_gridstore(dir, create_if_missing)?.map(GeoMapIndex::Mutable))
+    pub fn new_gridstore(
+        dir: PathBuf,
+        create_if_missing: bool,
+        config: GeoHashConfig,
+    ) -> OperationResult<Option<Self>> {
+        Ok(
+            MutableGeoMapIndex::open_gridstore(dir, create_if_missing, config)?
+                .map(GeoMapIndex::Mutable),
+        )
     }
 
     #[cfg(feature = "rocksdb")]
-    pub fn builder(db: Arc<RwLock<DB>>, field: &str) -> OperationResult<GeoMapIndexBuilder> {
-        let index = Self::new_memory(db, field, true, true)?.ok_or_else(|| {
+    pub fn builder(
+        db: Arc<RwLock<DB>>,
+        field: &str,
+        config: GeoHashConfig,
+    ) -> OperationResult<GeoMapIndexBuilder> {
+        let index = Self::new_memory(db, field, true, true, config)?.ok_or_else(|| {
             OperationError::service_error("Failed to open GeoMapIndex after creating it")
         })?;
         Ok(GeoMapIndexBuilder(index))
@@ -100,8 +180,9 @@ impl GeoMapIndex {
     pub fn builder_immutable(
         db: Arc<RwLock<DB>>,
         field: &str,
+        config: GeoHashConfig,
     ) -> OperationResult<GeoMapImmutableIndexBuilder> {
-        let index = Self::new_memory(db.clone(), field, true, true)?.ok_or_else(|| {
+        let index = Self::new_memory(db.clone(), field, true, true, config)?.ok_or_else(|| {
             OperationError::service_error("Failed to open GeoMapIndex after creating it")
         })?;
         Ok(GeoMapImmutableIndexBuilder {
@@ -111,16 +192,28 @@ impl GeoMapIndex {
         })
     }
 
-    pub fn builder_mmap(path: &Path, is_on_disk: bool) -> GeoMapIndexMmapBuilder {
+    pub fn builder_mmap(
+        path: &Path,
+        is_on_disk: bool,
+        config: GeoHashConfig,
+    ) -> GeoMapIndexMmapBuilder {
         GeoMapIndexMmapBuilder {
             path: path.to_owned(),
-            in_memory_index: InMemoryGeoMapIndex::new(),
+            in_memory_index: InMemoryGeoMapIndex::new(config),
             is_on_disk,
         }
     }
 
-    pub fn builder_gridstore(dir: PathBuf) -> GeoMapIndexGridstoreBuilder {
-        GeoMapIndexGridstoreBuilder::new(dir)
+    pub fn builder_gridstore(dir: PathBuf, config: GeoHashConfig) -> GeoMapIndexGridstoreBuilder {
+        GeoMapIndexGridstoreBuilder::new(dir, config)
+    }
+
+    pub fn config(&self) -> GeoHashConfig {
+        match self {
+            GeoMapIndex::Mutable(index) => index.config(),
+            GeoMapIndex::Immutable(index) => index.config(),
+            GeoMapIndex::Mmap(index) => index.config(),
+        }
     }
 
     fn points_count(&self) -> usize {
@@ -324,6 +417,9 @@ impl GeoMapIndex {
             points_count: self.points_count(),
             points_values_count: self.points_values_count(),
             histogram_bucket_size: None,
+            distinct_values: None,
+            full_text: None,
+            fragmentation: None,
             index_type: match self {
                 GeoMapIndex::Mutable(_) => "mutable_geo",
                 GeoMapIndex::Immutable(_) => "immutable_geo",
@@ -355,6 +451,29 @@ impl GeoMapIndex {
         }
     }
 
+    /// Iterate over points in the given geohash cells of a query region.
+    ///
+    /// Points of cells fully inside the region match without checking. Only points of cells
+    /// on the boundary of the region are checked exactly with `check_point`.
+    fn filter_cells<'a>(
+        &'a self,
+        geo_hashes: Vec<GeoHash>,
+        is_inner_cell: impl Fn(GeoHash) -> bool,
+        check_point: impl Fn(&GeoPoint) -> bool + 'a,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
+        let geo_hashes = self.config().query_hashes(geo_hashes);
+        let (inner_hashes, boundary_hashes): (Vec<_>, Vec<_>) = geo_hashes
+            .into_iter()
+            .partition(|geo_hash| is_inner_cell(*geo_hash));
+
+        let inner_points = self.iterator(inner_hashes);
+        let boundary_points = self.iterator(boundary_hashes).filter(move |point| {
+            self.check_values_any(*point, hw_counter, |geo_point| check_point(geo_point))
+        });
+        Box::new(inner_points.chain(boundary_points).unique())
+    }
+
     /// Get iterator over smallest geo-hash regions larger than `threshold` points
     fn large_hashes(&self, threshold: usize) -> impl Iterator<Item = (GeoHash, usize)> + '_ {
         let filter_condition =
@@ -521,8 +640,9 @@ impl FieldIndexBuilderTrait for GeoMapImmutableIndexBuilder {
     }
 
     fn finalize(self) -> OperationResult<Self::FieldIndexType> {
+        let config = self.index.config();
         drop(self.index);
-        let immutable_index = GeoMapIndex::new_memory(self.db, &self.field, false, false)?
+        let immutable_index = GeoMapIndex::new_memory(self.db, &self.field, false, false, config)?
             .ok_or_else(|| {
                 OperationError::service_error("Failed to open GeoMapIndex after creating it")
             })?;
@@ -615,12 +735,17 @@ impl ValueIndexer for GeoMapIndex {
 
 pub struct GeoMapIndexGridstoreBuilder {
     dir: PathBuf,
+    config: GeoHashConfig,
     index: Option<GeoMapIndex>,
 }
 
 impl GeoMapIndexGridstoreBuilder {
-    fn new(dir: PathBuf) -> Self {
-        Self { dir, index: None }
+    fn new(dir: PathBuf, config: GeoHashConfig) -> Self {
+        Self {
+            dir,
+            config,
+            index: None,
+        }
     }
 }
 
@@ -633,7 +758,7 @@ impl FieldIndexBuilderTrait for GeoMapIndexGridstoreBuilder {
             "index must be initialized exactly once",
         );
         self.index.replace(
-            GeoMapIndex::new_gridstore(self.dir.clone(), true)?.ok_or_else(|| {
+            GeoMapIndex::new_gridstore(self.dir.clone(), true, self.config)?.ok_or_else(|| {
                 OperationError::service_error("Failed to open GeoMapIndex after creating it")
             })?,
         );
@@ -707,34 +832,40 @@ impl PayloadFieldIndex for GeoMapIndex {
         condition: &FieldCondition,
         hw_counter: &'a HardwareCounterCell,
     ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        let config = self.config();
+
         if let Some(geo_bounding_box) = &condition.geo_bounding_box {
-            let geo_hashes = rectangle_hashes(geo_bounding_box, GEO_QUERY_MAX_REGION).ok()?;
+            let geo_hashes = rectangle_hashes(geo_bounding_box, config.max_query_cells).ok()?;
             let geo_condition_copy = *geo_bounding_box;
-            return Some(Box::new(self.iterator(geo_hashes).filter(move |point| {
-                self.check_values_any(*point, hw_counter, |geo_point| {
-                    geo_condition_copy.check_point(geo_point)
-                })
-            })));
+            return Some(self.filter_cells(
+                geo_hashes,
+                |geo_hash| rectangle_contains_geohash(&geo_condition_copy, geo_hash),
+                move |geo_point| geo_condition_copy.check_point(geo_point),
+                hw_counter,
+            ));
         }
 
         if let Some(geo_radius) = &condition.geo_radius {
-            let geo_hashes = circle_hashes(geo_radius, GEO_QUERY_MAX_REGION).ok()?;
+            let geo_hashes = circle_hashes(geo_radius, config.max_query_cells).ok()?;
             let geo_condition_copy = *geo_radius;
-            return Some(Box::new(self.iterator(geo_hashes).filter(move |point| {
-                self.check_values_any(*point, hw_counter, |geo_point| {
-                    geo_condition_copy.check_point(geo_point)
-                })
-            })));
+            return Some(self.filter_cells(
+                geo_hashes,
+                |geo_hash| circle_contains_geohash(&geo_condition_copy, geo_hash),
+                move |geo_point| geo_condition_copy.check_point(geo_point),
+                hw_counter,
+            ));
         }
 
         if let Some(geo_polygon) = &condition.geo_polygon {
-            let geo_hashes = polygon_hashes(geo_polygon, GEO_QUERY_MAX_REGION).ok()?;
+            let geo_hashes = polygon_hashes(geo_polygon, config.max_query_cells).ok()?;
             let geo_condition_copy = geo_polygon.convert();
-            return Some(Box::new(self.iterator(geo_hashes).filter(move |point| {
-                self.check_values_any(*point, hw_counter, |geo_point| {
-                    geo_condition_copy.check_point(geo_point)
-                })
-            })));
+            let polygon = geo_condition_copy.clone();
+            return Some(self.filter_cells(
+                geo_hashes,
+                |geo_hash| polygon_contains_geohash(&polygon, geo_hash),
+                move |geo_point| geo_condition_copy.check_point(geo_point),
+                hw_counter,
+            ));
         }
 
         None
@@ -745,8 +876,11 @@ impl PayloadFieldIndex for GeoMapIndex {
         condition: &FieldCondition,
         hw_counter: &HardwareCounterCell,
     ) -> Option<CardinalityEstimation> {
+        let config = self.config();
+
         if let Some(geo_bounding_box) = &condition.geo_bounding_box {
-            let geo_hashes = rectangle_hashes(geo_bounding_box, GEO_QUERY_MAX_REGION).ok()?;
+            let geo_hashes = rectangle_hashes(geo_bounding_box, config.max_query_cells).ok()?;
+            let geo_hashes = config.query_hashes(geo_hashes);
             let mut estimation = self.match_cardinality(&geo_hashes, hw_counter);
             estimation
                 .primary_clauses
@@ -755,7 +889,8 @@ impl PayloadFieldIndex for GeoMapIndex {
         }
 
         if let Some(geo_radius) = &condition.geo_radius {
-            let geo_hashes = circle_hashes(geo_radius, GEO_QUERY_MAX_REGION).ok()?;
+            let geo_hashes = circle_hashes(geo_radius, config.max_query_cells).ok()?;
+            let geo_hashes = config.query_hashes(geo_hashes);
             let mut estimation = self.match_cardinality(&geo_hashes, hw_counter);
             estimation
                 .primary_clauses
@@ -765,7 +900,12 @@ impl PayloadFieldIndex for GeoMapIndex {
 
         if let Some(geo_polygon) = &condition.geo_polygon {
             let (exterior_hashes, interior_hashes) =
-                polygon_hashes_estimation(geo_polygon, GEO_QUERY_MAX_REGION);
+                polygon_hashes_estimation(geo_polygon, config.max_query_cells);
+            let exterior_hashes = config.query_hashes(exterior_hashes);
+            let interior_hashes = interior_hashes
+                .into_iter()
+                .map(|hashes| config.query_hashes(hashes))
+                .collect_vec();
             // The polygon cardinality estimation should consider its exterior and interiors.
             // Therefore, we compute exterior estimation first and then subtract all interior estimation.
             let mut exterior_estimation = self.match_cardinality(&exterior_hashes, hw_counter);
@@ -926,6 +1066,14 @@ mod tests {
 
     #[cfg(feature = "testing")]
     fn create_builder(index_type: IndexType) -> (IndexBuilder, TempDir, Database) {
+        create_builder_with_config(index_type, GeoHashConfig::default())
+    }
+
+    #[cfg(feature = "testing")]
+    fn create_builder_with_config(
+        index_type: IndexType,
+        config: GeoHashConfig,
+    ) -> (IndexBuilder, TempDir, Database) {
         let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
 
         #[cfg(feature = "rocksdb")]
@@ -936,18 +1084,20 @@ mod tests {
         let mut builder = match index_type {
             #[cfg(feature = "rocksdb")]
             IndexType::Mutable => {
-                IndexBuilder::Mutable(GeoMapIndex::builder(db.clone(), FIELD_NAME).unwrap())
+                IndexBuilder::Mutable(GeoMapIndex::builder(db.clone(), FIELD_NAME, config).unwrap())
             }
             IndexType::MutableGridstore => IndexBuilder::MutableGridstore(
-                GeoMapIndex::builder_gridstore(temp_dir.path().to_path_buf()),
+                GeoMapIndex::builder_gridstore(temp_dir.path().to_path_buf(), config),
             ),
             #[cfg(feature = "rocksdb")]
             IndexType::Immutable => IndexBuilder::Immutable(
-                GeoMapIndex::builder_immutable(db.clone(), FIELD_NAME).unwrap(),
+                GeoMapIndex::builder_immutable(db.clone(), FIELD_NAME, config).unwrap(),
             ),
-            IndexType::Mmap => IndexBuilder::Mmap(GeoMapIndex::builder_mmap(temp_dir.path(), true)),
+            IndexType::Mmap => {
+                IndexBuilder::Mmap(GeoMapIndex::builder_mmap(temp_dir.path(), true, config))
+            }
             IndexType::RamMmap => {
-                IndexBuilder::RamMmap(GeoMapIndex::builder_mmap(temp_dir.path(), false))
+                IndexBuilder::RamMmap(GeoMapIndex::builder_mmap(temp_dir.path(), false, config))
             }
         };
         match &mut builder {
@@ -1224,6 +1374,89 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[cfg_attr(feature = "rocksdb", case(IndexType::Mutable))]
+    #[case(IndexType::MutableGridstore)]
+    #[cfg_attr(feature = "rocksdb", case(IndexType::Immutable))]
+    #[case(IndexType::Mmap)]
+    #[case(IndexType::RamMmap)]
+    fn geo_filtering_with_precision(
+        #[case] index_type: IndexType,
+        #[values((1, 1), (4, 4), (7, 32), (GEOHASH_MAX_LENGTH, GEO_QUERY_MAX_REGION))]
+        (precision, max_query_cells): (usize, usize),
+    ) {
+        let config = GeoHashConfig {
+            precision,
+            max_query_cells,
+        };
+        let mut rnd = StdRng::seed_from_u64(42);
+        let (mut builder, _temp_dir, _db) = create_builder_with_config(index_type, config);
+        let num_points = 1000;
+        for idx in 0..num_points {
+            let geo_points = random_geo_payload(&mut rnd, 2..=2);
+            builder
+                .add_point(
+                    idx,
+                    &[&Value::Array(geo_points)],
+                    &HardwareCounterCell::new(),
+                )
+                .unwrap();
+        }
+        let field_index = builder.finalize().unwrap();
+        assert_eq!(field_index.config(), config);
+
+        let hw_counter = HardwareCounterCell::new();
+        let check_filter = |condition: FieldCondition, check_fn: &dyn Fn(&GeoPoint) -> bool| {
+            let expected = (0..num_points)
+                .filter(|&idx| field_index.check_values_any(idx, &hw_counter, check_fn))
+                .collect_vec();
+            assert!(!expected.is_empty());
+
+            let mut matched = field_index
+                .filter(&condition, &hw_counter)
+                .unwrap()
+                .collect_vec();
+            matched.sort_unstable();
+            assert_eq!(matched, expected);
+
+            let estimation = field_index
+                .estimate_cardinality(&condition, &hw_counter)
+                .unwrap();
+            assert!(estimation.min <= expected.len());
+            assert!(expected.len() <= estimation.max);
+        };
+
+        let geo_radius = GeoRadius {
+            center: NYC,
+            radius: OrderedFloat(3_000_000.0),
+        };
+        check_filter(condition_for_geo_radius("test", geo_radius), &|geo_point| {
+            geo_radius.check_point(geo_point)
+        });
+
+        let geo_bounding_box = GeoBoundingBox {
+            top_left: GeoPoint::new_unchecked(170.0, 50.0),
+            bottom_right: GeoPoint::new_unchecked(-150.0, 10.0),
+        };
+        check_filter(
+            condition_for_geo_box("test", geo_bounding_box),
+            &|geo_point| geo_bounding_box.check_point(geo_point),
+        );
+
+        let geo_polygon = build_polygon(vec![
+            (-60.0, 10.0),
+            (-60.0, 45.0),
+            (-10.0, 45.0),
+            (-10.0, 10.0),
+            (-60.0, 10.0),
+        ]);
+        let polygon_wrapper = geo_polygon.convert();
+        check_filter(
+            condition_for_geo_polygon("test", geo_polygon),
+            &|geo_point| polygon_wrapper.check_point(geo_point),
+        );
+    }
+
     #[rstest]
     #[cfg_attr(feature = "rocksdb", case(IndexType::Mutable))]
     #[case(IndexType::MutableGridstore)]
@@ -1422,23 +1655,31 @@ mod tests {
         let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
         let new_index = match index_type {
             #[cfg(feature = "rocksdb")]
-            IndexType::Mutable => GeoMapIndex::new_memory(db, FIELD_NAME, true, true)
-                .unwrap()
-                .unwrap(),
-            IndexType::MutableGridstore => {
-                GeoMapIndex::new_gridstore(temp_dir.path().to_path_buf(), true)
+            IndexType::Mutable => {
+                GeoMapIndex::new_memory(db, FIELD_NAME, true, true, GeoHashConfig::default())
                     .unwrap()
                     .unwrap()
             }
+            IndexType::MutableGridstore => GeoMapIndex::new_gridstore(
+                temp_dir.path().to_path_buf(),
+                true,
+                GeoHashConfig::default(),
+            )
+            .unwrap()
+            .unwrap(),
             #[cfg(feature = "rocksdb")]
-            IndexType::Immutable => GeoMapIndex::new_memory(db, FIELD_NAME, false, true)
-                .unwrap()
-                .unwrap(),
-            IndexType::Mmap => GeoMapIndex::new_mmap(temp_dir.path(), false)
-                .unwrap()
-                .unwrap(),
+            IndexType::Immutable => {
+                GeoMapIndex::new_memory(db, FIELD_NAME, false, true, GeoHashConfig::default())
+                    .unwrap()
+                    .unwrap()
+            }
+            IndexType::Mmap => {
+                GeoMapIndex::new_mmap(temp_dir.path(), false, GeoHashConfig::default())
+                    .unwrap()
+                    .unwrap()
+            }
             IndexType::RamMmap => GeoMapIndex::Immutable(ImmutableGeoMapIndex::open_mmap(
-                MmapGeoMapIndex::open(temp_dir.path(), false)
+                MmapGeoMapIndex::open(temp_dir.path(), false, GeoHashConfig::default())
                     .unwrap()
                     .unwrap(),
             )),
@@ -1512,23 +1753,31 @@ mod tests {
         let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
         let new_index = match index_type {
             #[cfg(feature = "rocksdb")]
-            IndexType::Mutable => GeoMapIndex::new_memory(db, FIELD_NAME, true, true)
-                .unwrap()
-                .unwrap(),
-            IndexType::MutableGridstore => {
-                GeoMapIndex::new_gridstore(temp_dir.path().to_path_buf(), true)
+            IndexType::Mutable => {
+                GeoMapIndex::new_memory(db, FIELD_NAME, true, true, GeoHashConfig::default())
                     .unwrap()
                     .unwrap()
             }
+            IndexType::MutableGridstore => GeoMapIndex::new_gridstore(
+                temp_dir.path().to_path_buf(),
+                true,
+                GeoHashConfig::default(),
+            )
+            .unwrap()
+            .unwrap(),
             #[cfg(feature = "rocksdb")]
-            IndexType::Immutable => GeoMapIndex::new_memory(db, FIELD_NAME, false, true)
-                .unwrap()
-                .unwrap(),
-            IndexType::Mmap => GeoMapIndex::new_mmap(temp_dir.path(), false)
-                .unwrap()
-                .unwrap(),
+            IndexType::Immutable => {
+                GeoMapIndex::new_memory(db, FIELD_NAME, false, true, GeoHashConfig::default())
+                    .unwrap()
+                    .unwrap()
+            }
+            IndexType::Mmap => {
+                GeoMapIndex::new_mmap(temp_dir.path(), false, GeoHashConfig::default())
+                    .unwrap()
+                    .unwrap()
+            }
             IndexType::RamMmap => GeoMapIndex::Immutable(ImmutableGeoMapIndex::open_mmap(
-                MmapGeoMapIndex::open(temp_dir.path(), false)
+                MmapGeoMapIndex::open(temp_dir.path(), false, GeoHashConfig::default())
                     .unwrap()
                     .unwrap(),
             )),
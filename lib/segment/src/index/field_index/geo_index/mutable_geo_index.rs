@@ -15,6 +15,7 @@ use parking_lot::RwLock;
 #[cfg(feature = "rocksdb")]
 use rocksdb::DB;
 
+use super::GeoHashConfig;
 #[cfg(feature = "rocksdb")]
 use super::GeoMapIndex;
 use crate::common::Flusher;
@@ -23,7 +24,9 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::rocksdb_buffered_delete_wrapper::DatabaseColumnScheduledDeleteWrapper;
 #[cfg(feature = "rocksdb")]
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
-use crate::index::field_index::geo_hash::{GeoHash, encode_max_precision};
+use crate::index::field_index::geo_hash::GeoHash;
+#[cfg(feature = "rocksdb")]
+use crate::index::field_index::geo_hash::encode_max_precision;
 use crate::index::payload_config::StorageType;
 use crate::types::{GeoPoint, RawGeoPoint};
 
@@ -75,6 +78,7 @@ pub struct InMemoryGeoMapIndex {
     pub points_count: usize,
     pub points_values_count: usize,
     pub max_values_per_point: usize,
+    pub config: GeoHashConfig,
 }
 
 impl MutableGeoMapIndex {
@@ -84,6 +88,7 @@ impl MutableGeoMapIndex {
         db: Arc<RwLock<DB>>,
         store_cf_name: &str,
         create_if_missing: bool,
+        config: GeoHashConfig,
     ) -> OperationResult<Option<Self>> {
         let db_wrapper = DatabaseColumnScheduledDeleteWrapper::new(DatabaseColumnWrapper::new(
             db,
@@ -100,11 +105,13 @@ impl MutableGeoMapIndex {
         };
 
         // Load in-memory index from RocksDB
-        let mut in_memory_index = InMemoryGeoMapIndex::new();
+        let mut in_memory_index = InMemoryGeoMapIndex::new(config);
         let mut points_to_hashes: BTreeMap<PointOffsetType, Vec<GeoHash>> = Default::default();
 
         for (key, value) in db_wrapper.lock_db().iter()? {
             let (geo_hash, idx) = GeoMapIndex::decode_db_key(key)?;
+            // Keys are stored with full precision
+            let geo_hash = geo_hash.truncate(config.precision);
             let geo_point = GeoMapIndex::decode_db_value(value)?;
 
             if in_memory_index.point_to_values.len() <= idx as usize {
@@ -149,7 +156,11 @@ impl MutableGeoMapIndex {
     /// The `create_if_missing` parameter indicates whether to create a new Gridstore if it does
     /// not exist. If false and files don't exist, the load function will indicate nothing could be
     /// loaded.
-    pub fn open_gridstore(path: PathBuf, create_if_missing: bool) -> OperationResult<Option<Self>> {
+    pub fn open_gridstore(
+        path: PathBuf,
+        create_if_missing: bool,
+        config: GeoHashConfig,
+    ) -> OperationResult<Option<Self>> {
         let store = if create_if_missing {
             Gridstore::open_or_create(path, GRIDSTORE_OPTIONS).map_err(|err| {
                 OperationError::service_error(format!(
@@ -168,7 +179,7 @@ impl MutableGeoMapIndex {
         };
 
         // Load in-memory index from Gridstore
-        let mut in_memory_index = InMemoryGeoMapIndex::new();
+        let mut in_memory_index = InMemoryGeoMapIndex::new(config);
         let hw_counter = HardwareCounterCell::disposable();
         let hw_counter_ref = hw_counter.ref_payload_index_io_write_counter();
         store
@@ -177,11 +188,7 @@ impl MutableGeoMapIndex {
                     let geo_points = values.into_iter().map(GeoPoint::from).collect::<Vec<_>>();
                     let geo_hashes = geo_points
                         .iter()
-                        .map(|geo_point| {
-                            encode_max_precision(geo_point.lon.0, geo_point.lat.0).map_err(|e| {
-                                OperationError::service_error(format!("Malformed geo points: {e}"))
-                            })
-                        })
+                        .map(|geo_point| config.encode(geo_point))
                         .collect::<Result<Vec<_>, _>>()?;
 
                     for geo_point in geo_points {
@@ -385,6 +392,10 @@ impl MutableGeoMapIndex {
         self.in_memory_index
     }
 
+    pub fn config(&self) -> GeoHashConfig {
+        self.in_memory_index.config
+    }
+
     pub fn get_values(&self, idx: u32) -> Option<impl Iterator<Item = &GeoPoint> + '_> {
         self.in_memory_index
             .point_to_values
@@ -425,12 +436,12 @@ impl MutableGeoMapIndex {
 
 impl Default for InMemoryGeoMapIndex {
     fn default() -> Self {
-        Self::new()
+        Self::new(GeoHashConfig::default())
     }
 }
 
 impl InMemoryGeoMapIndex {
-    pub fn new() -> Self {
+    pub fn new(config: GeoHashConfig) -> Self {
         Self {
             points_per_hash: Default::default(),
             values_per_hash: Default::default(),
@@ -439,6 +450,7 @@ impl InMemoryGeoMapIndex {
             points_count: 0,
             points_values_count: 0,
             max_values_per_point: 0,
+            config,
         }
     }
 
@@ -490,10 +502,7 @@ impl InMemoryGeoMapIndex {
         let mut removed_geo_hashes = Vec::with_capacity(removed_geo_points.len());
 
         for removed_geo_point in removed_geo_points {
-            let removed_geo_hash: GeoHash =
-                encode_max_precision(removed_geo_point.lon.0, removed_geo_point.lat.0).map_err(
-                    |e| OperationError::service_error(format!("Malformed geo points: {e}")),
-                )?;
+            let removed_geo_hash = self.config.encode(&removed_geo_point)?;
             removed_geo_hashes.push(removed_geo_hash);
 
             let is_last = if let Some(hash_ids) = self.points_map.get_mut(&removed_geo_hash) {
@@ -539,10 +548,7 @@ impl InMemoryGeoMapIndex {
             .write_back_counter();
 
         for added_point in values {
-            let added_geo_hash: GeoHash =
-                encode_max_precision(added_point.lon.0, added_point.lat.0).map_err(|e| {
-                    OperationError::service_error(format!("Malformed geo points: {e}"))
-                })?;
+            let added_geo_hash = self.config.encode(added_point)?;
 
             hw_cell_wb.incr_delta(size_of_val(&added_geo_hash));
 
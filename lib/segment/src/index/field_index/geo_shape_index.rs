@@ -0,0 +1,560 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use ahash::AHashSet;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+use geo::{BoundingRect, Geometry};
+use gridstore::config::StorageOptions;
+use gridstore::{Blob, Gridstore};
+use itertools::Itertools;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::geo_hash::{GeoHash, polygon_hashes, rectangle_hashes};
+use super::{
+    CardinalityEstimation, FieldIndexBuilderTrait, PayloadBlockCondition, PayloadFieldIndex,
+    PrimaryCondition,
+};
+use crate::common::Flusher;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::index::payload_config::{IndexMutability, StorageType};
+use crate::telemetry::PayloadIndexTelemetry;
+use crate::types::{
+    FieldCondition, GeoBoundingBox, GeoPoint, GeoShape, GeoShapeCondition, PayloadKeyType,
+};
+
+/// Maximum number of geohash cells to cover the bounding box of a stored shape with
+const MAX_SHAPE_CELLS: usize = 4;
+
+/// Maximum number of geohash cells to cover the region of a query with
+const MAX_QUERY_CELLS: usize = 12;
+
+/// Default options for Gridstore storage
+const GRIDSTORE_OPTIONS: StorageOptions = StorageOptions {
+    compression: Some(gridstore::config::Compression::None),
+    page_size_bytes: None,
+    block_size_bytes: None,
+    region_size_blocks: None,
+};
+
+/// Geo shapes of a single point, as persisted in Gridstore
+struct StoredGeoShapes(Vec<GeoShape>);
+
+impl Blob for StoredGeoShapes {
+    fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(&self.0).unwrap()
+    }
+
+    fn from_bytes(data: &[u8]) -> Self {
+        Self(serde_json::from_slice(data).unwrap())
+    }
+}
+
+/// Index of geo shapes, such as polygons and line strings, stored in payload in GeoJSON format.
+///
+/// Each shape is registered in the geohash cells covering its bounding box. Filtering selects
+/// shapes from the cells overlapping the query region first, and checks the exact spatial
+/// relation for them only.
+///
+/// Shapes are kept in memory, and persisted in Gridstore.
+pub struct GeoShapeIndex {
+    /// Shapes of each point
+    point_to_shapes: Vec<Vec<Geometry>>,
+    /// Points with at least one shape, which bounding box overlaps the cell
+    cell_to_points: BTreeMap<GeoHash, AHashSet<PointOffsetType>>,
+    points_count: usize,
+    points_values_count: usize,
+    storage: Gridstore<StoredGeoShapes>,
+}
+
+impl GeoShapeIndex {
+    /// Open and load geo shape index from Gridstore storage
+    ///
+    /// The `create_if_missing` parameter indicates whether to create a new Gridstore if it does
+    /// not exist. If false and files don't exist, the load function will indicate nothing could be
+    /// loaded.
+    pub fn open_gridstore(path: PathBuf, create_if_missing: bool) -> OperationResult<Option<Self>> {
+        let store = if create_if_missing {
+            Gridstore::open_or_create(path, GRIDSTORE_OPTIONS).map_err(|err| {
+                OperationError::service_error(format!(
+                    "failed to open geo shape index on gridstore: {err}"
+                ))
+            })?
+        } else if path.exists() {
+            Gridstore::open(path).map_err(|err| {
+                OperationError::service_error(format!(
+                    "failed to open geo shape index on gridstore: {err}"
+                ))
+            })?
+        } else {
+            // Files don't exist, cannot load
+            return Ok(None);
+        };
+
+        let mut index = Self {
+            point_to_shapes: vec![],
+            cell_to_points: BTreeMap::new(),
+            points_count: 0,
+            points_values_count: 0,
+            storage: store,
+        };
+
+        // Load in-memory index from Gridstore
+        let mut loaded = vec![];
+        let hw_counter = HardwareCounterCell::disposable();
+        let hw_counter_ref = hw_counter.ref_payload_index_io_write_counter();
+        index
+            .storage
+            .iter::<_, OperationError>(
+                |idx, StoredGeoShapes(shapes)| {
+                    let shapes = shapes
+                        .iter()
+                        .map(GeoShape::to_geometry)
+                        .collect::<OperationResult<Vec<_>>>()?;
+                    loaded.push((idx, shapes));
+                    Ok(true)
+                },
+                hw_counter_ref,
+            )
+            .map_err(|err| {
+                OperationError::service_error(format!(
+                    "Failed to load geo shape index from gridstore: {err}"
+                ))
+            })?;
+
+        for (idx, shapes) in loaded {
+            index.add_to_memory(idx, shapes);
+        }
+
+        Ok(Some(index))
+    }
+
+    pub fn builder_gridstore(dir: PathBuf) -> GeoShapeIndexGridstoreBuilder {
+        GeoShapeIndexGridstoreBuilder { dir, index: None }
+    }
+
+    pub fn add_point(
+        &mut self,
+        id: PointOffsetType,
+        payload: &[&Value],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        self.remove_point(id)?;
+
+        // Shapes which are not valid GeoJSON are not indexed, same as malformed geo points
+        let (shapes, geometries): (Vec<_>, Vec<_>) = payload
+            .iter()
+            .flat_map(|value| match value {
+                Value::Array(values) => values.iter().collect_vec(),
+                _ => vec![*value],
+            })
+            .filter_map(|value| {
+                let shape = GeoShape::deserialize(value).ok()?;
+                let geometry = shape.to_geometry().ok()?;
+                Some((shape, geometry))
+            })
+            .unzip();
+
+        if shapes.is_empty() {
+            return Ok(());
+        }
+
+        let hw_counter_ref = hw_counter.ref_payload_index_io_write_counter();
+        self.storage
+            .put_value(id, &StoredGeoShapes(shapes), hw_counter_ref)
+            .map_err(|err| {
+                OperationError::service_error(format!(
+                    "failed to put value in geo shape index gridstore: {err}"
+                ))
+            })?;
+
+        self.add_to_memory(id, geometries);
+        Ok(())
+    }
+
+    pub fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        let Some(shapes) = self.point_to_shapes.get_mut(id as usize) else {
+            return Ok(());
+        };
+        if shapes.is_empty() {
+            return Ok(());
+        }
+        let shapes = std::mem::take(shapes);
+
+        self.storage.delete_value(id);
+
+        for cell in shapes.iter().flat_map(shape_cells).unique() {
+            if let Some(points) = self.cell_to_points.get_mut(&cell) {
+                points.remove(&id);
+                if points.is_empty() {
+                    self.cell_to_points.remove(&cell);
+                }
+            }
+        }
+
+        self.points_count -= 1;
+        self.points_values_count -= shapes.len();
+        Ok(())
+    }
+
+    fn add_to_memory(&mut self, id: PointOffsetType, shapes: Vec<Geometry>) {
+        if shapes.is_empty() {
+            return;
+        }
+
+        for cell in shapes.iter().flat_map(shape_cells) {
+            self.cell_to_points.entry(cell).or_default().insert(id);
+        }
+
+        if self.point_to_shapes.len() <= id as usize {
+            self.point_to_shapes.resize_with(id as usize + 1, Vec::new);
+        }
+        self.points_count += 1;
+        self.points_values_count += shapes.len();
+        self.point_to_shapes[id as usize] = shapes;
+    }
+
+    pub fn check_values_any(
+        &self,
+        id: PointOffsetType,
+        check_fn: impl Fn(&Geometry) -> bool,
+    ) -> bool {
+        self.point_to_shapes
+            .get(id as usize)
+            .is_some_and(|shapes| shapes.iter().any(check_fn))
+    }
+
+    pub fn values_count(&self, id: PointOffsetType) -> usize {
+        self.point_to_shapes.get(id as usize).map_or(0, Vec::len)
+    }
+
+    pub fn values_is_empty(&self, id: PointOffsetType) -> bool {
+        self.values_count(id) == 0
+    }
+
+    /// Points with a shape overlapping any of the cells, sorted and deduplicated
+    fn candidates(&self, cells: &[GeoHash]) -> Vec<PointOffsetType> {
+        let mut candidates = vec![];
+        for &cell in cells {
+            // Shapes in cells within the query cell
+            candidates.extend(
+                self.cell_to_points
+                    .range(cell..)
+                    .take_while(|(stored, _)| stored.starts_with(cell))
+                    .flat_map(|(_, points)| points.iter().copied()),
+            );
+            // Shapes in cells enclosing the query cell
+            for len in 0..cell.len() {
+                if let Some(points) = self.cell_to_points.get(&cell.truncate(len)) {
+                    candidates.extend(points.iter().copied());
+                }
+            }
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+    }
+
+    pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
+        PayloadIndexTelemetry {
+            field_name: None,
+            points_count: self.points_count,
+            points_values_count: self.points_values_count,
+            histogram_bucket_size: None,
+            distinct_values: None,
+            full_text: None,
+            fragmentation: None,
+            index_type: "geo_shape",
+        }
+    }
+
+    pub fn populate(&self) -> OperationResult<()> {
+        Ok(()) // Not a mmap
+    }
+
+    pub fn is_on_disk(&self) -> bool {
+        false
+    }
+
+    /// Clear cache
+    ///
+    /// Only clears cache of Gridstore storage. Does not clear in-memory representation of index.
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        self.storage.clear_cache().map_err(|err| {
+            OperationError::service_error(format!(
+                "Failed to clear geo shape index gridstore cache: {err}"
+            ))
+        })
+    }
+
+    pub fn get_mutability_type(&self) -> IndexMutability {
+        IndexMutability::Mutable
+    }
+
+    pub fn get_storage_type(&self) -> StorageType {
+        StorageType::Gridstore
+    }
+}
+
+/// Geohash cells covering the bounding box of the shape
+fn shape_cells(shape: &Geometry) -> Vec<GeoHash> {
+    let Some(rect) = shape.bounding_rect() else {
+        return vec![];
+    };
+    let bounding_box = GeoBoundingBox {
+        top_left: GeoPoint::new_unchecked(rect.min().x, rect.max().y),
+        bottom_right: GeoPoint::new_unchecked(rect.max().x, rect.min().y),
+    };
+    // The root cell covers any shape
+    rectangle_hashes(&bounding_box, MAX_SHAPE_CELLS).unwrap_or_else(|_| vec![GeoHash::default()])
+}
+
+/// Geohash cells covering the region of the condition
+fn query_cells(condition: &GeoShapeCondition) -> OperationResult<Vec<GeoHash>> {
+    let mut cells = vec![];
+    if let Some(geo_bounding_box) = &condition.geo_bounding_box {
+        cells.extend(rectangle_hashes(geo_bounding_box, MAX_QUERY_CELLS)?);
+    }
+    if let Some(geo_polygon) = &condition.geo_polygon {
+        cells.extend(polygon_hashes(geo_polygon, MAX_QUERY_CELLS)?);
+    }
+    Ok(cells)
+}
+
+impl PayloadFieldIndex for GeoShapeIndex {
+    fn count_indexed_points(&self) -> usize {
+        self.points_count
+    }
+
+    fn wipe(self) -> OperationResult<()> {
+        self.storage.wipe().map_err(|err| {
+            OperationError::service_error(format!("Failed to wipe geo shape index: {err}"))
+        })
+    }
+
+    fn flusher(&self) -> Flusher {
+        let storage_flusher = self.storage.flusher();
+        Box::new(move || {
+            storage_flusher().map_err(|err| {
+                OperationError::service_error(format!(
+                    "Failed to flush geo shape index gridstore: {err}"
+                ))
+            })
+        })
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        self.storage.files()
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+
+    fn filter<'a>(
+        &'a self,
+        condition: &'a FieldCondition,
+        _hw_counter: &'a HardwareCounterCell,
+    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        let geo_shape = condition.geo_shape.as_ref()?;
+        let cells = query_cells(geo_shape).ok()?;
+        let condition = geo_shape.convert();
+        Some(Box::new(self.candidates(&cells).into_iter().filter(
+            move |&id| self.check_values_any(id, |shape| condition.check_shape(shape)),
+        )))
+    }
+
+    fn estimate_cardinality(
+        &self,
+        condition: &FieldCondition,
+        _hw_counter: &HardwareCounterCell,
+    ) -> Option<CardinalityEstimation> {
+        let geo_shape = condition.geo_shape.as_ref()?;
+        let cells = query_cells(geo_shape).ok()?;
+        let candidates = self.candidates(&cells).len();
+        Some(CardinalityEstimation {
+            primary_clauses: vec![PrimaryCondition::from(condition.clone())],
+            min: 0,
+            // Bounding boxes overlapping the region are a rough proxy for the shapes doing so
+            exp: candidates / 2,
+            max: candidates,
+        })
+    }
+
+    fn payload_blocks(
+        &self,
+        _threshold: usize,
+        _key: PayloadKeyType,
+    ) -> Box<dyn Iterator<Item = PayloadBlockCondition> + '_> {
+        // No payload blocks
+        Box::new(std::iter::empty())
+    }
+}
+
+pub struct GeoShapeIndexGridstoreBuilder {
+    dir: PathBuf,
+    index: Option<GeoShapeIndex>,
+}
+
+impl FieldIndexBuilderTrait for GeoShapeIndexGridstoreBuilder {
+    type FieldIndexType = GeoShapeIndex;
+
+    fn init(&mut self) -> OperationResult<()> {
+        assert!(
+            self.index.is_none(),
+            "index must be initialized exactly once",
+        );
+        self.index.replace(
+            GeoShapeIndex::open_gridstore(self.dir.clone(), true)?.ok_or_else(|| {
+                OperationError::service_error("Failed to open GeoShapeIndex after creating it")
+            })?,
+        );
+        Ok(())
+    }
+
+    fn add_point(
+        &mut self,
+        id: PointOffsetType,
+        payload: &[&Value],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let Some(index) = &mut self.index else {
+            return Err(OperationError::service_error(
+                "GeoShapeIndexGridstoreBuilder: index must be initialized before adding points",
+            ));
+        };
+        index.add_point(id, payload, hw_counter)
+    }
+
+    fn finalize(mut self) -> OperationResult<Self::FieldIndexType> {
+        let Some(index) = self.index.take() else {
+            return Err(OperationError::service_error(
+                "GeoShapeIndexGridstoreBuilder: index must be initialized to finalize",
+            ));
+        };
+        index.flusher()()?;
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::counter::hardware_accumulator::HwMeasurementAcc;
+    use serde_json::json;
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::json_path::JsonPath;
+    use crate::types::GeoShapeRelation;
+    use crate::types::test_utils::build_polygon;
+
+    fn condition(relation: GeoShapeRelation, geo_bounding_box: GeoBoundingBox) -> FieldCondition {
+        FieldCondition::new_geo_shape(
+            JsonPath::new("area"),
+            GeoShapeCondition {
+                relation,
+                geo_bounding_box: Some(geo_bounding_box),
+                geo_polygon: None,
+            },
+        )
+    }
+
+    fn bounding_box(min_lon: f64, min_lat: f64, max_lon: f64, max_lat: f64) -> GeoBoundingBox {
+        GeoBoundingBox {
+            top_left: GeoPoint::new_unchecked(min_lon, max_lat),
+            bottom_right: GeoPoint::new_unchecked(max_lon, min_lat),
+        }
+    }
+
+    fn filter(index: &GeoShapeIndex, condition: &FieldCondition) -> Vec<PointOffsetType> {
+        let hw_acc = HwMeasurementAcc::new();
+        let hw_counter = hw_acc.get_counter_cell();
+        index
+            .filter(condition, &hw_counter)
+            .unwrap()
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn test_geo_shape_index_relations() {
+        let dir = Builder::new().prefix("geo_shape_index").tempdir().unwrap();
+        let mut builder = GeoShapeIndex::builder_gridstore(dir.path().to_path_buf());
+        builder.init().unwrap();
+
+        let hw_counter = HardwareCounterCell::new();
+        let payloads = [
+            // Berlin area
+            json!({"type": "Polygon", "coordinates": [[[13.0, 52.3], [13.8, 52.3], [13.8, 52.7], [13.0, 52.7], [13.0, 52.3]]]}),
+            // Line from Berlin to Potsdam
+            json!({"type": "LineString", "coordinates": [[13.4, 52.5], [13.06, 52.4]]}),
+            // Far away, in Paris
+            json!([{"type": "LineString", "coordinates": [[2.3, 48.8], [2.4, 48.9]]}]),
+            // Not a geo shape
+            json!({"lon": 13.4, "lat": 52.5}),
+        ];
+        for (id, payload) in payloads.iter().enumerate() {
+            builder
+                .add_point(id as PointOffsetType, &[payload], &hw_counter)
+                .unwrap();
+        }
+        let mut index = builder.finalize().unwrap();
+        assert_eq!(index.count_indexed_points(), 3);
+
+        // Around the center of Berlin
+        let center = bounding_box(13.3, 52.45, 13.5, 52.55);
+        assert_eq!(
+            filter(&index, &condition(GeoShapeRelation::Intersects, center)),
+            vec![0, 1],
+        );
+        assert_eq!(
+            filter(&index, &condition(GeoShapeRelation::Contains, center)),
+            vec![0],
+        );
+        assert!(filter(&index, &condition(GeoShapeRelation::Within, center)).is_empty());
+
+        // Whole Germany
+        let germany = bounding_box(5.8, 47.2, 15.1, 55.1);
+        assert_eq!(
+            filter(&index, &condition(GeoShapeRelation::Within, germany)),
+            vec![0, 1],
+        );
+
+        // Polygon region
+        let polygon_condition = FieldCondition::new_geo_shape(
+            JsonPath::new("area"),
+            GeoShapeCondition {
+                relation: GeoShapeRelation::Intersects,
+                geo_bounding_box: None,
+                geo_polygon: Some(build_polygon(vec![
+                    (2.0, 48.0),
+                    (3.0, 48.0),
+                    (3.0, 49.0),
+                    (2.0, 49.0),
+                    (2.0, 48.0),
+                ])),
+            },
+        );
+        assert_eq!(filter(&index, &polygon_condition), vec![2]);
+
+        // Removed shapes are not found anymore
+        index.remove_point(0).unwrap();
+        assert_eq!(
+            filter(&index, &condition(GeoShapeRelation::Intersects, center)),
+            vec![1],
+        );
+
+        // Shapes are loaded back from storage
+        index.flusher()().unwrap();
+        drop(index);
+        let index = GeoShapeIndex::open_gridstore(dir.path().to_path_buf(), false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(index.count_indexed_points(), 2);
+        assert_eq!(
+            filter(&index, &condition(GeoShapeRelation::Within, germany)),
+            vec![1],
+        );
+    }
+}
@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 use crate::common::operation_error::OperationResult;
 use crate::index::field_index::utils::check_boundaries;
 
-const MIN_BUCKET_SIZE: usize = 10;
+pub(crate) const MIN_BUCKET_SIZE: usize = 10;
 const CONFIG_PATH: &str = "histogram_config.json";
 const BORDERS_PATH: &str = "histogram_borders.bin";
 
@@ -213,6 +213,31 @@ impl<T: Numericable + Serialize + DeserializeOwned> Histogram<T> {
         &self.borders
     }
 
+    pub fn max_bucket_size(&self) -> usize {
+        self.max_bucket_size
+    }
+
+    pub fn precision(&self) -> f64 {
+        self.precision
+    }
+
+    /// Iterate over buckets of the histogram, as `(from, to, count)`.
+    ///
+    /// Each bucket covers values from its border up to the next one, the last bucket only
+    /// contains the maximal value.
+    pub fn buckets(&self) -> impl Iterator<Item = (T, T, usize)> + '_ {
+        let last = self
+            .borders
+            .last_key_value()
+            .map(|(border, _)| (border.val, border.val, 1));
+
+        self.borders
+            .iter()
+            .tuple_windows()
+            .map(|((a, a_count), (b, _))| (a.val, b.val, a_count.right + 1))
+            .chain(last)
+    }
+
     pub fn current_bucket_size(&self) -> usize {
         let bucket_size = (self.total_count as f64 * self.precision) as usize;
         bucket_size.clamp(MIN_BUCKET_SIZE, self.max_bucket_size)
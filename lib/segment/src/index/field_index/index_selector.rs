@@ -8,12 +8,15 @@ use super::bool_index::BoolIndex;
 use super::bool_index::mutable_bool_index::MutableBoolIndex;
 #[cfg(feature = "rocksdb")]
 use super::bool_index::simple_bool_index::SimpleBoolIndex;
-use super::geo_index::{GeoMapIndexGridstoreBuilder, GeoMapIndexMmapBuilder};
+use super::geo_index::{GeoHashConfig, GeoMapIndexGridstoreBuilder, GeoMapIndexMmapBuilder};
+use super::geo_shape_index::GeoShapeIndex;
 use super::histogram::Numericable;
+use super::map_index::keyword_index::{KeywordIndex, KeywordIndexBuilder, KeywordNormalizer};
 use super::map_index::{MapIndex, MapIndexGridstoreBuilder, MapIndexKey, MapIndexMmapBuilder};
 use super::mmap_point_to_values::MmapValue;
 use super::numeric_index::{
-    Encodable, NumericIndexGridstoreBuilder, NumericIndexIntoInnerValue, NumericIndexMmapBuilder,
+    Encodable, NumericHistogramConfig, NumericIndexGridstoreBuilder, NumericIndexIntoInnerValue,
+    NumericIndexMmapBuilder,
 };
 use super::{FieldIndexBuilder, ValueIndexer};
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -21,7 +24,7 @@ use crate::data_types::index::TextIndexParams;
 use crate::index::field_index::FieldIndex;
 use crate::index::field_index::full_text_index::text_index::FullTextIndex;
 use crate::index::field_index::geo_index::GeoMapIndex;
-use crate::index::field_index::null_index::MutableNullIndex;
+use crate::index::field_index::null_index::{MutableNullIndex, NestedNullIndex};
 use crate::index::field_index::numeric_index::NumericIndex;
 use crate::index::payload_config::{FullPayloadIndexType, PayloadIndexType};
 use crate::json_path::JsonPath;
@@ -80,7 +83,7 @@ impl IndexSelector<'_> {
                     );
                 }
 
-                self.numeric_new(field, create_if_missing)?
+                self.numeric_new(field, params.into(), create_if_missing)?
                     .map(FieldIndex::IntIndex)
             }
             (PayloadIndexType::IntMapIndex, PayloadSchemaParams::Integer(params)) => {
@@ -97,22 +100,31 @@ impl IndexSelector<'_> {
                 self.map_new(field, create_if_missing)?
                     .map(FieldIndex::IntMapIndex)
             }
-            (PayloadIndexType::DatetimeIndex, PayloadSchemaParams::Datetime(_)) => self
-                .numeric_new(field, create_if_missing)?
+            (PayloadIndexType::DatetimeIndex, PayloadSchemaParams::Datetime(params)) => self
+                .numeric_new(field, params.into(), create_if_missing)?
                 .map(FieldIndex::DatetimeIndex),
 
-            (PayloadIndexType::KeywordIndex, PayloadSchemaParams::Keyword(_)) => self
-                .map_new(field, create_if_missing)?
-                .map(FieldIndex::KeywordIndex),
+            (PayloadIndexType::KeywordIndex, PayloadSchemaParams::Keyword(params)) => {
+                self.map_new(field, create_if_missing)?.map(|index| {
+                    FieldIndex::KeywordIndex(KeywordIndex::new(
+                        index,
+                        KeywordNormalizer::new(params),
+                    ))
+                })
+            }
 
-            (PayloadIndexType::FloatIndex, PayloadSchemaParams::Float(_)) => self
-                .numeric_new(field, create_if_missing)?
+            (PayloadIndexType::FloatIndex, PayloadSchemaParams::Float(params)) => self
+                .numeric_new(field, params.into(), create_if_missing)?
                 .map(FieldIndex::FloatIndex),
 
-            (PayloadIndexType::GeoIndex, PayloadSchemaParams::Geo(_)) => self
-                .geo_new(field, create_if_missing)?
+            (PayloadIndexType::GeoIndex, PayloadSchemaParams::Geo(params)) => self
+                .geo_new(field, params.into(), create_if_missing)?
                 .map(FieldIndex::GeoIndex),
 
+            (PayloadIndexType::GeoShapeIndex, PayloadSchemaParams::GeoShape(_)) => self
+                .geo_shape_new(field, create_if_missing)?
+                .map(FieldIndex::GeoShapeIndex),
+
             (PayloadIndexType::FullTextIndex, PayloadSchemaParams::Text(params)) => self
                 .text_new(field, params.clone(), create_if_missing)?
                 .map(FieldIndex::FullTextIndex),
@@ -136,6 +148,11 @@ impl IndexSelector<'_> {
             )?
             .map(FieldIndex::NullIndex),
 
+            (PayloadIndexType::NestedNullIndex, _) => {
+                NestedNullIndex::open(&nested_null_dir(path, field), field, create_if_missing)?
+                    .map(FieldIndex::NestedNullIndex)
+            }
+
             // Storage inconsistency. Should never happen.
             (index_type, schema) => {
                 return Err(OperationError::service_error(format!(
@@ -155,9 +172,14 @@ impl IndexSelector<'_> {
         create_if_missing: bool,
     ) -> OperationResult<Option<Vec<FieldIndex>>> {
         let indexes = match payload_schema.expand().as_ref() {
-            PayloadSchemaParams::Keyword(_) => self
-                .map_new(field, create_if_missing)?
-                .map(|index| vec![FieldIndex::KeywordIndex(index)]),
+            PayloadSchemaParams::Keyword(params) => {
+                self.map_new(field, create_if_missing)?.map(|index| {
+                    vec![FieldIndex::KeywordIndex(KeywordIndex::new(
+                        index,
+                        KeywordNormalizer::new(params),
+                    ))]
+                })
+            }
             PayloadSchemaParams::Integer(integer_params) => {
                 let use_lookup = integer_params.lookup.unwrap_or(true);
                 let use_range = integer_params.range.unwrap_or(true);
@@ -171,7 +193,7 @@ impl IndexSelector<'_> {
                     None
                 };
                 let range = if use_range {
-                    match self.numeric_new(field, create_if_missing)? {
+                    match self.numeric_new(field, integer_params.into(), create_if_missing)? {
                         Some(index) => Some(FieldIndex::IntIndex(index)),
                         None => return Ok(None),
                     }
@@ -181,20 +203,23 @@ impl IndexSelector<'_> {
 
                 Some(lookup.into_iter().chain(range).collect())
             }
-            PayloadSchemaParams::Float(_) => self
-                .numeric_new(field, create_if_missing)?
+            PayloadSchemaParams::Float(float_params) => self
+                .numeric_new(field, float_params.into(), create_if_missing)?
                 .map(|index| vec![FieldIndex::FloatIndex(index)]),
-            PayloadSchemaParams::Geo(_) => self
-                .geo_new(field, create_if_missing)?
+            PayloadSchemaParams::Geo(params) => self
+                .geo_new(field, params.into(), create_if_missing)?
                 .map(|index| vec![FieldIndex::GeoIndex(index)]),
+            PayloadSchemaParams::GeoShape(_) => self
+                .geo_shape_new(field, create_if_missing)?
+                .map(|index| vec![FieldIndex::GeoShapeIndex(index)]),
             PayloadSchemaParams::Text(text_index_params) => self
                 .text_new(field, text_index_params.clone(), create_if_missing)?
                 .map(|index| vec![FieldIndex::FullTextIndex(index)]),
             PayloadSchemaParams::Bool(_) => self
                 .bool_new(field, create_if_missing)?
                 .map(|index| vec![FieldIndex::BoolIndex(index)]),
-            PayloadSchemaParams::Datetime(_) => self
-                .numeric_new(field, create_if_missing)?
+            PayloadSchemaParams::Datetime(datetime_params) => self
+                .numeric_new(field, datetime_params.into(), create_if_missing)?
                 .map(|index| vec![FieldIndex::DatetimeIndex(index)]),
             PayloadSchemaParams::Uuid(_) => self
                 .map_new(field, create_if_missing)?
@@ -211,13 +236,11 @@ impl IndexSelector<'_> {
         payload_schema: &PayloadFieldSchema,
     ) -> OperationResult<Vec<FieldIndexBuilder>> {
         let builders = match payload_schema.expand().as_ref() {
-            PayloadSchemaParams::Keyword(_) => {
-                vec![self.map_builder(
+            PayloadSchemaParams::Keyword(params) => {
+                vec![self.keyword_builder(
                     field,
-                    #[cfg(feature = "rocksdb")]
-                    FieldIndexBuilder::KeywordIndex,
-                    FieldIndexBuilder::KeywordMmapIndex,
-                    FieldIndexBuilder::KeywordGridstoreIndex,
+                    KeywordNormalizer::new(params),
+                    params.bloom_filter.unwrap_or_default(),
                 )?]
             }
             PayloadSchemaParams::Integer(integer_params) => {
@@ -239,6 +262,7 @@ impl IndexSelector<'_> {
                 let range = if use_range {
                     Some(self.numeric_builder(
                         field,
+                        integer_params.into(),
                         #[cfg(feature = "rocksdb")]
                         FieldIndexBuilder::IntIndex,
                         FieldIndexBuilder::IntMmapIndex,
@@ -250,33 +274,39 @@ impl IndexSelector<'_> {
 
                 lookup.into_iter().chain(range).collect()
             }
-            PayloadSchemaParams::Float(_) => {
+            PayloadSchemaParams::Float(float_params) => {
                 vec![self.numeric_builder(
                     field,
+                    float_params.into(),
                     #[cfg(feature = "rocksdb")]
                     FieldIndexBuilder::FloatIndex,
                     FieldIndexBuilder::FloatMmapIndex,
                     FieldIndexBuilder::FloatGridstoreIndex,
                 )?]
             }
-            PayloadSchemaParams::Geo(_) => {
+            PayloadSchemaParams::Geo(params) => {
                 vec![self.geo_builder(
                     field,
+                    params.into(),
                     #[cfg(feature = "rocksdb")]
                     FieldIndexBuilder::GeoIndex,
                     FieldIndexBuilder::GeoMmapIndex,
                     FieldIndexBuilder::GeoGridstoreIndex,
                 )?]
             }
+            PayloadSchemaParams::GeoShape(_) => {
+                vec![self.geo_shape_builder(field)?]
+            }
             PayloadSchemaParams::Text(text_index_params) => {
                 vec![self.text_builder(field, text_index_params.clone())?]
             }
             PayloadSchemaParams::Bool(_) => {
                 vec![self.bool_builder(field)?]
             }
-            PayloadSchemaParams::Datetime(_) => {
+            PayloadSchemaParams::Datetime(datetime_params) => {
                 vec![self.numeric_builder(
                     field,
+                    datetime_params.into(),
                     #[cfg(feature = "rocksdb")]
                     FieldIndexBuilder::DatetimeIndex,
                     FieldIndexBuilder::DatetimeMmapIndex,
@@ -351,9 +381,41 @@ impl IndexSelector<'_> {
         })
     }
 
+    #[cfg_attr(not(feature = "rocksdb"), expect(clippy::unnecessary_wraps))]
+    fn keyword_builder(
+        &self,
+        field: &JsonPath,
+        normalizer: KeywordNormalizer,
+        bloom_filter: bool,
+    ) -> OperationResult<FieldIndexBuilder> {
+        Ok(match self {
+            #[cfg(feature = "rocksdb")]
+            IndexSelector::RocksDb(IndexSelectorRocksDb { db, .. }) => {
+                FieldIndexBuilder::KeywordIndex(KeywordIndexBuilder::new(
+                    MapIndex::builder_rocksdb(Arc::clone(db), &field.to_string())?,
+                    normalizer,
+                ))
+            }
+            IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => {
+                FieldIndexBuilder::KeywordMmapIndex(KeywordIndexBuilder::new(
+                    MapIndex::builder_mmap(&map_dir(dir, field), *is_on_disk)
+                        .with_bloom_filter(bloom_filter && *is_on_disk),
+                    normalizer,
+                ))
+            }
+            IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
+                FieldIndexBuilder::KeywordGridstoreIndex(KeywordIndexBuilder::new(
+                    MapIndex::builder_gridstore(map_dir(dir, field)),
+                    normalizer,
+                ))
+            }
+        })
+    }
+
     fn numeric_new<T: Encodable + Numericable + MmapValue + Send + Sync + Default, P>(
         &self,
         field: &JsonPath,
+        histogram_config: NumericHistogramConfig,
         create_if_missing: bool,
     ) -> OperationResult<Option<NumericIndex<T, P>>>
     where
@@ -367,13 +429,19 @@ impl IndexSelector<'_> {
                     &field.to_string(),
                     *is_appendable,
                     create_if_missing,
+                    histogram_config,
                 )?
             }
+            // Mmap index keeps the histogram settings it was built with
             IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => {
                 NumericIndex::new_mmap(&numeric_dir(dir, field), *is_on_disk)?
             }
             IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
-                NumericIndex::new_gridstore(numeric_dir(dir, field), create_if_missing)?
+                NumericIndex::new_gridstore(
+                    numeric_dir(dir, field),
+                    create_if_missing,
+                    histogram_config,
+                )?
             }
         })
     }
@@ -382,6 +450,7 @@ impl IndexSelector<'_> {
     fn numeric_builder<T: Encodable + Numericable + MmapValue + Send + Sync + Default, P>(
         &self,
         field: &JsonPath,
+        histogram_config: NumericHistogramConfig,
         #[cfg(feature = "rocksdb")] make_rocksdb: fn(
             super::numeric_index::NumericIndexBuilder<T, P>,
         ) -> FieldIndexBuilder,
@@ -400,12 +469,13 @@ impl IndexSelector<'_> {
             }) => Ok(make_rocksdb(NumericIndex::builder_rocksdb(
                 Arc::clone(db),
                 &field.to_string(),
+                histogram_config,
             )?)),
             IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => Ok(make_mmap(
-                NumericIndex::builder_mmap(&numeric_dir(dir, field), *is_on_disk),
+                NumericIndex::builder_mmap(&numeric_dir(dir, field), *is_on_disk, histogram_config),
             )),
             IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => Ok(make_gridstore(
-                NumericIndex::builder_gridstore(numeric_dir(dir, field)),
+                NumericIndex::builder_gridstore(numeric_dir(dir, field), histogram_config),
             )),
         }
     }
@@ -413,6 +483,7 @@ impl IndexSelector<'_> {
     fn geo_new(
         &self,
         field: &JsonPath,
+        config: GeoHashConfig,
         create_if_missing: bool,
     ) -> OperationResult<Option<GeoMapIndex>> {
         Ok(match self {
@@ -423,13 +494,14 @@ impl IndexSelector<'_> {
                     &field.to_string(),
                     *is_appendable,
                     create_if_missing,
+                    config,
                 )?
             }
             IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => {
-                GeoMapIndex::new_mmap(&map_dir(dir, field), *is_on_disk)?
+                GeoMapIndex::new_mmap(&map_dir(dir, field), *is_on_disk, config)?
             }
             IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
-                GeoMapIndex::new_gridstore(map_dir(dir, field), create_if_missing)?
+                GeoMapIndex::new_gridstore(map_dir(dir, field), create_if_missing, config)?
             }
         })
     }
@@ -438,6 +510,7 @@ impl IndexSelector<'_> {
     fn geo_builder(
         &self,
         field: &JsonPath,
+        config: GeoHashConfig,
         #[cfg(feature = "rocksdb")] make_rocksdb: fn(
             super::geo_index::GeoMapIndexBuilder,
         ) -> FieldIndexBuilder,
@@ -446,18 +519,52 @@ impl IndexSelector<'_> {
     ) -> OperationResult<FieldIndexBuilder> {
         Ok(match self {
             #[cfg(feature = "rocksdb")]
-            IndexSelector::RocksDb(IndexSelectorRocksDb { db, .. }) => {
-                make_rocksdb(GeoMapIndex::builder(Arc::clone(db), &field.to_string())?)
-            }
-            IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => {
-                make_mmap(GeoMapIndex::builder_mmap(&map_dir(dir, field), *is_on_disk))
-            }
+            IndexSelector::RocksDb(IndexSelectorRocksDb { db, .. }) => make_rocksdb(
+                GeoMapIndex::builder(Arc::clone(db), &field.to_string(), config)?,
+            ),
+            IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => make_mmap(
+                GeoMapIndex::builder_mmap(&map_dir(dir, field), *is_on_disk, config),
+            ),
             IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
-                make_gridstore(GeoMapIndex::builder_gridstore(map_dir(dir, field)))
+                make_gridstore(GeoMapIndex::builder_gridstore(map_dir(dir, field), config))
             }
         })
     }
 
+    /// Geo shape index is always kept in memory and persisted in Gridstore
+    fn geo_shape_new(
+        &self,
+        field: &JsonPath,
+        create_if_missing: bool,
+    ) -> OperationResult<Option<GeoShapeIndex>> {
+        match self {
+            #[cfg(feature = "rocksdb")]
+            IndexSelector::RocksDb(_) => Err(OperationError::service_error(
+                "geo shape index is not supported on RocksDB storage",
+            )),
+            IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk: _ })
+            | IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
+                GeoShapeIndex::open_gridstore(geo_shape_dir(dir, field), create_if_missing)
+            }
+        }
+    }
+
+    #[cfg_attr(not(feature = "rocksdb"), expect(clippy::unnecessary_wraps))]
+    fn geo_shape_builder(&self, field: &JsonPath) -> OperationResult<FieldIndexBuilder> {
+        match self {
+            #[cfg(feature = "rocksdb")]
+            IndexSelector::RocksDb(_) => Err(OperationError::service_error(
+                "geo shape index is not supported on RocksDB storage",
+            )),
+            IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk: _ })
+            | IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
+                Ok(FieldIndexBuilder::GeoShapeIndex(
+                    GeoShapeIndex::builder_gridstore(geo_shape_dir(dir, field)),
+                ))
+            }
+        }
+    }
+
     pub fn null_builder(dir: &Path, field: &JsonPath) -> OperationResult<FieldIndexBuilder> {
         // null index is always on disk and appendable
         Ok(FieldIndexBuilder::NullIndex(MutableNullIndex::builder(
@@ -465,6 +572,23 @@ impl IndexSelector<'_> {
         )?))
     }
 
+    /// Builder of the nested null index, complementing null index of fields inside array elements.
+    ///
+    /// Returns `None` if the field is not inside array elements.
+    pub fn nested_null_builder(
+        dir: &Path,
+        field: &JsonPath,
+    ) -> OperationResult<Option<FieldIndexBuilder>> {
+        if NestedNullIndex::split_path(field).is_none() {
+            return Ok(None);
+        }
+
+        // nested null index is always on disk and appendable
+        Ok(Some(FieldIndexBuilder::NestedNullIndex(
+            NestedNullIndex::builder(&nested_null_dir(dir, field), field)?,
+        )))
+    }
+
     pub fn new_null_index(
         dir: &Path,
         field: &JsonPath,
@@ -595,6 +719,10 @@ fn numeric_dir(dir: &Path, field: &JsonPath) -> PathBuf {
     dir.join(format!("{}-numeric", &field.filename()))
 }
 
+fn geo_shape_dir(dir: &Path, field: &JsonPath) -> PathBuf {
+    dir.join(format!("{}-geo_shape", &field.filename()))
+}
+
 fn text_dir(dir: &Path, field: &JsonPath) -> PathBuf {
     dir.join(format!("{}-text", &field.filename()))
 }
@@ -606,3 +734,7 @@ fn bool_dir(dir: &Path, field: &JsonPath) -> PathBuf {
 fn null_dir(dir: &Path, field: &JsonPath) -> PathBuf {
     dir.join(format!("{}-null", &field.filename()))
 }
+
+fn nested_null_dir(dir: &Path, field: &JsonPath) -> PathBuf {
+    dir.join(format!("{}-nested-null", &field.filename()))
+}
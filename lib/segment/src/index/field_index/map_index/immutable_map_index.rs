@@ -20,6 +20,7 @@ use super::MapIndex;
 use super::mmap_map_index::MmapMapIndex;
 use super::{IdIter, MapIndexKey};
 use crate::common::Flusher;
+use crate::common::distinct_values_sketch::DistinctValuesSketch;
 use crate::common::operation_error::OperationResult;
 #[cfg(feature = "rocksdb")]
 use crate::common::rocksdb_buffered_delete_wrapper::DatabaseColumnScheduledDeleteWrapper;
@@ -39,6 +40,8 @@ pub struct ImmutableMapIndex<N: MapIndexKey + Key + ?Sized> {
     /// Amount of point which have at least one indexed payload value
     indexed_points: usize,
     values_count: usize,
+    /// Distinct values of the points at load time
+    distinct_values: DistinctValuesSketch,
     // Backing storage, source of state, persists deletions
     storage: Storage<N>,
 }
@@ -85,6 +88,7 @@ where
             point_to_values,
             indexed_points,
             values_count,
+            distinct_values,
             ..
         } = mutable;
 
@@ -133,6 +137,7 @@ where
             point_to_values: ImmutablePointToValues::new(point_to_values),
             indexed_points,
             values_count,
+            distinct_values,
             storage: Storage::RocksDb(db_wrapper),
         }))
     }
@@ -212,6 +217,13 @@ where
 
         debug_assert_eq!(indexed_points, index.get_indexed_points());
 
+        let distinct_values = DistinctValuesSketch::from_values::<N>(
+            value_to_points
+                .iter()
+                .filter(|(_, segment)| segment.count > 0)
+                .map(|(value, _)| value.borrow()),
+        );
+
         // Index is now loaded into memory, clear cache of backing mmap storage
         if let Err(err) = index.clear_cache() {
             log::warn!("Failed to clear mmap cache of ram mmap map index: {err}");
@@ -224,6 +236,7 @@ where
             point_to_values,
             indexed_points,
             values_count,
+            distinct_values,
             storage: Storage::Mmap(Box::new(index)),
         }
     }
@@ -443,6 +456,10 @@ where
         self.value_to_points.len()
     }
 
+    pub fn distinct_values_sketch(&self) -> &DistinctValuesSketch {
+        &self.distinct_values
+    }
+
     pub fn get_count_for_value(&self, value: &N) -> Option<usize> {
         self.value_to_points
             .get(value)
@@ -509,3 +526,28 @@ where
         }
     }
 }
+
+impl ImmutableMapIndex<str> {
+    pub fn iter_values_with_prefix<'a>(
+        &'a self,
+        prefix: &str,
+    ) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        match &self.storage {
+            // Legacy storage has no sorted dictionary, scan all values
+            #[cfg(feature = "rocksdb")]
+            Storage::RocksDb(_) => {
+                let prefix = prefix.to_owned();
+                Box::new(
+                    self.iter_values()
+                        .filter(move |value| value.starts_with(prefix.as_str())),
+                )
+            }
+            // Values of removed points are still in the backing dictionary, skip them
+            Storage::Mmap(index) => Box::new(
+                index
+                    .iter_values_with_prefix(prefix)
+                    .filter(|value| self.value_to_points.contains_key(*value)),
+            ),
+        }
+    }
+}
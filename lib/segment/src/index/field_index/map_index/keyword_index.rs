@@ -0,0 +1,840 @@
+use std::borrow::Cow;
+use std::ops::Deref;
+use std::path::PathBuf;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+use fnv::FnvBuildHasher;
+use indexmap::IndexSet;
+use itertools::Itertools;
+use regex::Regex;
+use serde_json::Value;
+
+use super::MapIndex;
+use crate::common::Flusher;
+use crate::common::operation_error::OperationResult;
+use crate::data_types::index::KeywordIndexParams;
+use crate::index::field_index::full_text_index::tokenizers::ascii_folding::fold_to_ascii_cow;
+use crate::index::field_index::full_text_index::tokenizers::nfkc::normalize_nfkc_cow;
+use crate::index::field_index::{
+    CardinalityEstimation, FieldIndexBuilderTrait, PayloadBlockCondition, PayloadFieldIndex,
+    PrimaryCondition, ValueIndexer,
+};
+use crate::index::query_estimator::combine_should_estimations;
+use crate::payload_storage::condition_checker::ValueChecker;
+use crate::types::{
+    AnyVariants, FieldCondition, Match, MatchAny, MatchExcept, MatchPrefix, MatchRegex, MatchValue,
+    PayloadKeyType, ValueVariants,
+};
+
+/// Largest number of distinct keywords matched against a regex to estimate its cardinality.
+/// Larger dictionaries are sampled instead of a full scan on every query planning.
+const REGEX_ESTIMATION_MAX_TERMS: usize = 10_000;
+
+/// Normalization applied to keywords, both on indexing and in match conditions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct KeywordNormalizer {
+    ascii_folding: bool,
+    unicode_normalization: bool,
+    lowercase: bool,
+}
+
+impl KeywordNormalizer {
+    pub fn new(params: &KeywordIndexParams) -> Self {
+        Self {
+            ascii_folding: params.ascii_folding.unwrap_or(false),
+            unicode_normalization: params.unicode_normalization.unwrap_or(false),
+            lowercase: params.lowercase.unwrap_or(false),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.ascii_folding || self.unicode_normalization || self.lowercase
+    }
+
+    pub fn normalize<'a>(&self, keyword: &'a str) -> Cow<'a, str> {
+        let keyword = self.normalize_characters(keyword);
+        if self.lowercase {
+            lowercase_cow(keyword)
+        } else {
+            keyword
+        }
+    }
+
+    /// Apply Unicode normalization and ASCII folding, keeping the case of characters
+    fn normalize_characters<'a>(&self, keyword: &'a str) -> Cow<'a, str> {
+        let mut keyword = Cow::Borrowed(keyword);
+        if self.unicode_normalization {
+            keyword = normalize_nfkc_cow(keyword);
+        }
+        if self.ascii_folding {
+            keyword = fold_to_ascii_cow(keyword);
+        }
+        keyword
+    }
+
+    fn normalize_owned(&self, keyword: String) -> String {
+        match self.normalize(&keyword) {
+            Cow::Borrowed(_) => None,
+            Cow::Owned(normalized) => Some(normalized),
+        }
+        .unwrap_or(keyword)
+    }
+
+    pub fn normalize_set(
+        &self,
+        keywords: &IndexSet<String, FnvBuildHasher>,
+    ) -> IndexSet<String, FnvBuildHasher> {
+        keywords
+            .iter()
+            .map(|keyword| self.normalize(keyword).into_owned())
+            .collect()
+    }
+
+    /// Normalize strings of a payload value, including strings inside of arrays
+    fn normalize_value(&self, value: &Value) -> Value {
+        match value {
+            Value::String(keyword) => Value::String(self.normalize(keyword).into_owned()),
+            Value::Array(values) => Value::Array(
+                values
+                    .iter()
+                    .map(|value| self.normalize_value(value))
+                    .collect(),
+            ),
+            _ => value.clone(),
+        }
+    }
+
+    /// Normalize literal characters of the regex, so it can match normalized keywords.
+    ///
+    /// Lowercased keywords are matched case-insensitively instead of lowercasing the regex,
+    /// which would change the meaning of escapes like `\D` or `\W`.
+    pub fn normalize_regex(&self, match_regex: &MatchRegex) -> MatchRegex {
+        let regex = self.normalize_characters(&match_regex.regex);
+        let regex = if self.lowercase {
+            format!("(?i){regex}")
+        } else {
+            regex.into_owned()
+        };
+        MatchRegex { regex }
+    }
+
+    /// Normalize keyword match, returns `None` if the match is not keyword-based
+    fn normalize_match(&self, r#match: &Match) -> Option<Match> {
+        let normalized = match r#match {
+            Match::Value(MatchValue {
+                value: ValueVariants::String(keyword),
+            }) => Match::Value(MatchValue {
+                value: ValueVariants::String(self.normalize(keyword).into_owned()),
+            }),
+            Match::Any(MatchAny {
+                any: AnyVariants::Strings(keywords),
+            }) => Match::Any(MatchAny {
+                any: AnyVariants::Strings(self.normalize_set(keywords)),
+            }),
+            Match::Except(MatchExcept {
+                except: AnyVariants::Strings(keywords),
+            }) => Match::Except(MatchExcept {
+                except: AnyVariants::Strings(self.normalize_set(keywords)),
+            }),
+            Match::Prefix(MatchPrefix { prefix }) => Match::Prefix(MatchPrefix {
+                prefix: self.normalize(prefix).into_owned(),
+            }),
+            Match::Regex(match_regex) => Match::Regex(self.normalize_regex(match_regex)),
+            _ => return None,
+        };
+        Some(normalized)
+    }
+
+    /// Normalize keywords of the condition, to be checked against normalized index values
+    pub fn normalize_condition<'a>(
+        &self,
+        condition: &'a FieldCondition,
+    ) -> Cow<'a, FieldCondition> {
+        if !self.is_enabled() {
+            return Cow::Borrowed(condition);
+        }
+
+        match condition
+            .r#match
+            .as_ref()
+            .and_then(|r#match| self.normalize_match(r#match))
+        {
+            Some(r#match) => Cow::Owned(FieldCondition {
+                r#match: Some(r#match),
+                ..condition.clone()
+            }),
+            None => Cow::Borrowed(condition),
+        }
+    }
+}
+
+/// Lowercase the keyword, borrowing it if it has no characters to change
+fn lowercase_cow(keyword: Cow<'_, str>) -> Cow<'_, str> {
+    if keyword
+        .chars()
+        .all(|c| c.to_lowercase().eq(std::iter::once(c)))
+    {
+        keyword
+    } else {
+        Cow::Owned(keyword.to_lowercase())
+    }
+}
+
+/// Keyword index, which normalizes keywords before indexing and querying them
+pub struct KeywordIndex {
+    index: MapIndex<str>,
+    normalizer: KeywordNormalizer,
+}
+
+impl KeywordIndex {
+    pub fn new(index: MapIndex<str>, normalizer: KeywordNormalizer) -> Self {
+        Self { index, normalizer }
+    }
+
+    fn prefix_filter<'a>(
+        &'a self,
+        prefix: &str,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
+        Box::new(
+            self.index
+                .iter_values_with_prefix(prefix)
+                .flat_map(move |keyword| self.index.get_iterator(keyword, hw_counter))
+                .unique(),
+        )
+    }
+
+    /// Estimate from counts of points of each keyword in the range of the prefix
+    fn prefix_cardinality(
+        &self,
+        prefix: &str,
+        hw_counter: &HardwareCounterCell,
+    ) -> CardinalityEstimation {
+        let estimations = self
+            .index
+            .iter_values_with_prefix(prefix)
+            .map(|keyword| self.index.match_cardinality(keyword, hw_counter))
+            .collect::<Vec<_>>();
+        if estimations.is_empty() {
+            CardinalityEstimation::exact(0)
+        } else {
+            combine_should_estimations(&estimations, self.index.get_indexed_points())
+        }
+    }
+
+    /// Scan the dictionary of indexed keywords with the regex, compiled once per query
+    fn regex_filter<'a>(
+        &'a self,
+        match_regex: &MatchRegex,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
+        let Ok(regex) = match_regex.compile() else {
+            return Box::new(std::iter::empty());
+        };
+        Box::new(
+            self.index
+                .iter_values()
+                .filter(move |keyword| regex.is_match(keyword))
+                .flat_map(move |keyword| self.index.get_iterator(keyword, hw_counter))
+                .unique(),
+        )
+    }
+
+    /// Estimate from counts of points of each matching keyword.
+    ///
+    /// Samples the dictionary if it is too large to scan, or falls back to a worst-case
+    /// estimation if the dictionary is on disk.
+    fn regex_cardinality(
+        &self,
+        match_regex: &MatchRegex,
+        hw_counter: &HardwareCounterCell,
+    ) -> CardinalityEstimation {
+        let Ok(regex) = match_regex.compile() else {
+            return CardinalityEstimation::exact(0);
+        };
+        let indexed_points = self.index.get_indexed_points();
+        let unique_values = self.index.get_unique_values_count();
+        if unique_values > REGEX_ESTIMATION_MAX_TERMS {
+            if self.index.is_on_disk() {
+                return CardinalityEstimation::unknown(indexed_points);
+            }
+            return self.sampled_regex_cardinality(&regex, unique_values, hw_counter);
+        }
+
+        let estimations = self
+            .index
+            .iter_counts_per_value()
+            .filter(|(keyword, _)| regex.is_match(keyword))
+            .map(|(_, count)| CardinalityEstimation::exact(count))
+            .collect::<Vec<_>>();
+        if estimations.is_empty() {
+            CardinalityEstimation::exact(0)
+        } else {
+            combine_should_estimations(&estimations, indexed_points)
+        }
+    }
+
+    /// Estimate from an evenly spread sample of the dictionary, extrapolated to the approximate
+    /// number of distinct keywords.
+    ///
+    /// Points of the sampled matching keywords are the lower bound, all points are the upper one.
+    fn sampled_regex_cardinality(
+        &self,
+        regex: &Regex,
+        unique_values: usize,
+        hw_counter: &HardwareCounterCell,
+    ) -> CardinalityEstimation {
+        let indexed_points = self.index.get_indexed_points();
+        let step = unique_values.div_ceil(REGEX_ESTIMATION_MAX_TERMS);
+
+        let mut sampled = 0;
+        let estimations = self
+            .index
+            .iter_values()
+            .step_by(step)
+            .inspect(|_| sampled += 1)
+            .filter(|keyword| regex.is_match(keyword))
+            .map(|keyword| self.index.match_cardinality(keyword, hw_counter))
+            .collect::<Vec<_>>();
+
+        let (min, exp) = if estimations.is_empty() {
+            (0, 0)
+        } else {
+            let sample = combine_should_estimations(&estimations, indexed_points);
+            let distinct_values = self.index.distinct_values_sketch().estimate();
+            let scale = distinct_values as f64 / sampled as f64;
+            let exp = (sample.exp as f64 * scale).round() as usize;
+            (sample.min, exp.clamp(sample.min, indexed_points))
+        };
+
+        CardinalityEstimation {
+            primary_clauses: vec![],
+            min,
+            exp,
+            max: indexed_points,
+        }
+    }
+
+    pub fn normalizer(&self) -> KeywordNormalizer {
+        self.normalizer
+    }
+
+    pub fn into_inner(self) -> MapIndex<str> {
+        self.index
+    }
+
+    /// Check keyword match condition against a payload value, normalized the same way as the index.
+    ///
+    /// Returns `None` if no normalization is configured, or the condition is not a keyword match.
+    pub fn compact(&mut self) -> OperationResult<usize> {
+        self.index.compact()
+    }
+
+    pub fn check_payload_match(
+        &self,
+        condition: &FieldCondition,
+        payload_value: &Value,
+    ) -> Option<bool> {
+        if !self.normalizer.is_enabled() {
+            return None;
+        }
+
+        let r#match = condition.r#match.as_ref()?;
+        let normalized_match = self.normalizer.normalize_match(r#match)?;
+        Some(normalized_match.check(&self.normalizer.normalize_value(payload_value)))
+    }
+}
+
+impl Deref for KeywordIndex {
+    type Target = MapIndex<str>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.index
+    }
+}
+
+impl PayloadFieldIndex for KeywordIndex {
+    fn count_indexed_points(&self) -> usize {
+        self.index.count_indexed_points()
+    }
+
+    fn wipe(self) -> OperationResult<()> {
+        self.index.wipe()
+    }
+
+    fn flusher(&self) -> Flusher {
+        self.index.flusher()
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        self.index.files()
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        self.index.immutable_files()
+    }
+
+    fn filter<'a>(
+        &'a self,
+        condition: &'a FieldCondition,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        let condition = match self.normalizer.normalize_condition(condition) {
+            Cow::Borrowed(condition) => match &condition.r#match {
+                Some(Match::Prefix(MatchPrefix { prefix })) => {
+                    return Some(self.prefix_filter(prefix, hw_counter));
+                }
+                Some(Match::Regex(match_regex)) => {
+                    return Some(self.regex_filter(match_regex, hw_counter));
+                }
+                _ => return self.index.filter(condition, hw_counter),
+            },
+            Cow::Owned(condition) => condition,
+        };
+
+        // Normalized keywords are owned by the iterator, so it can't borrow the condition
+        let index = &self.index;
+        match condition.r#match? {
+            Match::Value(MatchValue {
+                value: ValueVariants::String(keyword),
+            }) => Some(index.get_iterator(keyword.as_str(), hw_counter)),
+            Match::Any(MatchAny {
+                any: AnyVariants::Strings(keywords),
+            }) => Some(Box::new(
+                keywords
+                    .into_iter()
+                    .flat_map(move |keyword| index.get_iterator(keyword.as_str(), hw_counter))
+                    .unique(),
+            )),
+            Match::Prefix(MatchPrefix { prefix }) => Some(self.prefix_filter(&prefix, hw_counter)),
+            Match::Regex(match_regex) => Some(self.regex_filter(&match_regex, hw_counter)),
+            Match::Except(MatchExcept {
+                except: AnyVariants::Strings(keywords),
+            }) => Some(Box::new(
+                index
+                    .iter_values()
+                    .filter(move |keyword| !keywords.contains(*keyword))
+                    .flat_map(move |keyword| index.get_iterator(keyword, hw_counter))
+                    .unique(),
+            )),
+            _ => None,
+        }
+    }
+
+    fn estimate_cardinality(
+        &self,
+        condition: &FieldCondition,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<CardinalityEstimation> {
+        let condition = self.normalizer.normalize_condition(condition);
+        let estimation = match &condition.r#match {
+            Some(Match::Prefix(MatchPrefix { prefix })) => {
+                self.prefix_cardinality(prefix, hw_counter)
+            }
+            Some(Match::Regex(match_regex)) => self.regex_cardinality(match_regex, hw_counter),
+            _ => return self.index.estimate_cardinality(&condition, hw_counter),
+        };
+        Some(
+            estimation.with_primary_clause(PrimaryCondition::Condition(Box::new(
+                condition.into_owned(),
+            ))),
+        )
+    }
+
+    fn payload_blocks(
+        &self,
+        threshold: usize,
+        key: PayloadKeyType,
+    ) -> Box<dyn Iterator<Item = PayloadBlockCondition> + '_> {
+        self.index.payload_blocks(threshold, key)
+    }
+}
+
+impl ValueIndexer for KeywordIndex {
+    type ValueType = String;
+
+    fn add_many(
+        &mut self,
+        id: PointOffsetType,
+        values: Vec<String>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let values: Vec<_> = if self.normalizer.is_enabled() {
+            values
+                .into_iter()
+                .map(|value| self.normalizer.normalize_owned(value))
+                .collect()
+        } else {
+            values
+        };
+        self.index.add_many(id, values, hw_counter)
+    }
+
+    fn get_value(value: &Value) -> Option<String> {
+        MapIndex::<str>::get_value(value)
+    }
+
+    fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        self.index.remove_point(id)
+    }
+}
+
+pub struct KeywordIndexBuilder<B> {
+    builder: B,
+    normalizer: KeywordNormalizer,
+}
+
+impl<B> KeywordIndexBuilder<B> {
+    pub fn new(builder: B, normalizer: KeywordNormalizer) -> Self {
+        Self {
+            builder,
+            normalizer,
+        }
+    }
+}
+
+impl<B> FieldIndexBuilderTrait for KeywordIndexBuilder<B>
+where
+    B: FieldIndexBuilderTrait<FieldIndexType = MapIndex<str>>,
+{
+    type FieldIndexType = KeywordIndex;
+
+    fn init(&mut self) -> OperationResult<()> {
+        self.builder.init()
+    }
+
+    fn add_point(
+        &mut self,
+        id: PointOffsetType,
+        payload: &[&Value],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        if !self.normalizer.is_enabled() {
+            return self.builder.add_point(id, payload, hw_counter);
+        }
+
+        let normalized = payload
+            .iter()
+            .map(|value| self.normalizer.normalize_value(value))
+            .collect::<Vec<_>>();
+        let normalized = normalized.iter().collect::<Vec<_>>();
+        self.builder.add_point(id, &normalized, hw_counter)
+    }
+
+    fn finalize(self) -> OperationResult<Self::FieldIndexType> {
+        Ok(KeywordIndex::new(self.builder.finalize()?, self.normalizer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use serde_json::json;
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::data_types::index::KeywordIndexType;
+
+    fn normalizer() -> KeywordNormalizer {
+        KeywordNormalizer::new(&KeywordIndexParams {
+            r#type: KeywordIndexType::Keyword,
+            is_tenant: None,
+            ascii_folding: Some(true),
+            unicode_normalization: Some(true),
+            lowercase: None,
+            on_disk: None,
+            enable_hnsw: None,
+            populate: None,
+            bloom_filter: None,
+            tenant_subgraphs: None,
+            tenant_subgraphs_max_points: None,
+        })
+    }
+
+    #[test]
+    fn test_keyword_normalization() {
+        let normalizer = normalizer();
+
+        assert_eq!(normalizer.normalize("café"), "cafe");
+        // "e" followed by combining acute accent
+        assert_eq!(normalizer.normalize("cafe\u{0301}"), "cafe");
+        assert_eq!(normalizer.normalize("Ｃａｆé"), "Cafe");
+        assert!(matches!(normalizer.normalize("cafe"), Cow::Borrowed(_)));
+
+        assert!(!KeywordNormalizer::default().is_enabled());
+        assert_eq!(KeywordNormalizer::default().normalize("café"), "café");
+    }
+
+    #[test]
+    fn test_normalize_condition() {
+        let normalizer = normalizer();
+        let key = "keyword".parse::<PayloadKeyType>().unwrap();
+
+        let condition = FieldCondition::new_match(key.clone(), "café".to_string().into());
+        let normalized = normalizer.normalize_condition(&condition);
+        assert_eq!(
+            normalized.r#match,
+            Some(Match::new_value(ValueVariants::String("cafe".to_string()))),
+        );
+
+        // Nothing to normalize in non-keyword conditions
+        let condition = FieldCondition::new_match(key, 42_i64.into());
+        assert!(matches!(
+            normalizer.normalize_condition(&condition),
+            Cow::Borrowed(_),
+        ));
+    }
+
+    fn build_index<B>(mut builder: KeywordIndexBuilder<B>, data: &[Vec<&str>]) -> KeywordIndex
+    where
+        B: FieldIndexBuilderTrait<FieldIndexType = MapIndex<str>>,
+    {
+        let hw_counter = HardwareCounterCell::new();
+        builder.init().unwrap();
+        for (idx, keywords) in data.iter().enumerate() {
+            let values = keywords
+                .iter()
+                .map(|keyword| json!(keyword))
+                .collect::<Vec<_>>();
+            let values = values.iter().collect::<Vec<_>>();
+            builder
+                .add_point(idx as PointOffsetType, &values, &hw_counter)
+                .unwrap();
+        }
+        builder.finalize().unwrap()
+    }
+
+    fn filter_prefix(index: &KeywordIndex, prefix: &str) -> HashSet<PointOffsetType> {
+        let hw_counter = HardwareCounterCell::new();
+        let key = "keyword".parse::<PayloadKeyType>().unwrap();
+        let condition = FieldCondition::new_match(key, Match::new_prefix(prefix));
+
+        let estimation = index.estimate_cardinality(&condition, &hw_counter).unwrap();
+        let points = index
+            .filter(&condition, &hw_counter)
+            .unwrap()
+            .collect::<HashSet<_>>();
+        assert!(estimation.min <= points.len() && points.len() <= estimation.max);
+        points
+    }
+
+    #[test]
+    fn test_prefix_match() {
+        let data = [
+            vec!["apple"],
+            vec!["application", "banana"],
+            vec!["apricot"],
+            vec!["app"],
+            vec!["äppel"],
+            vec!["band"],
+        ];
+
+        let gridstore_dir = Builder::new().prefix("gridstore_index").tempdir().unwrap();
+        let mmap_dir = Builder::new().prefix("mmap_index").tempdir().unwrap();
+        let indexes = [
+            build_index(
+                KeywordIndexBuilder::new(
+                    MapIndex::builder_gridstore(gridstore_dir.path().to_path_buf()),
+                    normalizer(),
+                ),
+                &data,
+            ),
+            build_index(
+                KeywordIndexBuilder::new(
+                    MapIndex::builder_mmap(mmap_dir.path(), false),
+                    normalizer(),
+                ),
+                &data,
+            ),
+        ];
+
+        for index in &indexes {
+            assert_eq!(filter_prefix(index, "app"), HashSet::from([0, 1, 3, 4]));
+            assert_eq!(filter_prefix(index, "äp"), HashSet::from([0, 1, 2, 3, 4]));
+            assert_eq!(filter_prefix(index, "ban"), HashSet::from([1, 5]));
+            assert_eq!(filter_prefix(index, "banana"), HashSet::from([1]));
+            assert!(filter_prefix(index, "cherry").is_empty());
+        }
+    }
+
+    fn filter_regex(index: &KeywordIndex, regex: &str) -> HashSet<PointOffsetType> {
+        let hw_counter = HardwareCounterCell::new();
+        let key = "keyword".parse::<PayloadKeyType>().unwrap();
+        let condition = FieldCondition::new_match(key, Match::new_regex(regex));
+
+        let estimation = index.estimate_cardinality(&condition, &hw_counter).unwrap();
+        let points = index
+            .filter(&condition, &hw_counter)
+            .unwrap()
+            .collect::<HashSet<_>>();
+        assert!(estimation.min <= points.len() && points.len() <= estimation.max);
+        points
+    }
+
+    #[test]
+    fn test_regex_match() {
+        let data = [
+            vec!["apple"],
+            vec!["application", "banana"],
+            vec!["apricot"],
+            vec!["app"],
+            vec!["äppel"],
+            vec!["band"],
+        ];
+
+        let gridstore_dir = Builder::new().prefix("gridstore_index").tempdir().unwrap();
+        let mmap_dir = Builder::new().prefix("mmap_index").tempdir().unwrap();
+        let indexes = [
+            build_index(
+                KeywordIndexBuilder::new(
+                    MapIndex::builder_gridstore(gridstore_dir.path().to_path_buf()),
+                    normalizer(),
+                ),
+                &data,
+            ),
+            build_index(
+                KeywordIndexBuilder::new(
+                    MapIndex::builder_mmap(mmap_dir.path(), false),
+                    normalizer(),
+                ),
+                &data,
+            ),
+        ];
+
+        for index in &indexes {
+            assert_eq!(filter_regex(index, "^app"), HashSet::from([0, 1, 3, 4]));
+            assert_eq!(filter_regex(index, "^äp+[el]+$"), HashSet::from([0, 4]));
+            assert_eq!(filter_regex(index, "an"), HashSet::from([1, 5]));
+            assert_eq!(filter_regex(index, "^(band|app)$"), HashSet::from([3, 5]));
+            assert!(filter_regex(index, "cherry").is_empty());
+            assert!(filter_regex(index, "app(le").is_empty());
+        }
+    }
+
+    #[test]
+    fn test_sampled_regex_cardinality() {
+        let keywords = (0..3 * REGEX_ESTIMATION_MAX_TERMS)
+            .map(|i| match i % 4 {
+                0 => format!("red-{i}"),
+                _ => format!("blue-{i}"),
+            })
+            .collect::<Vec<_>>();
+        let data = keywords
+            .iter()
+            .map(|keyword| vec![keyword.as_str()])
+            .collect::<Vec<_>>();
+
+        let dir = Builder::new().prefix("gridstore_index").tempdir().unwrap();
+        let index = build_index(
+            KeywordIndexBuilder::new(
+                MapIndex::builder_gridstore(dir.path().to_path_buf()),
+                normalizer(),
+            ),
+            &data,
+        );
+
+        let matching = filter_regex(&index, "^red-").len();
+        assert_eq!(matching, keywords.len() / 4);
+
+        let match_regex = MatchRegex {
+            regex: "^red-".to_string(),
+        };
+        let estimation = index.regex_cardinality(&match_regex, &HardwareCounterCell::new());
+        let error = (estimation.exp as f64 - matching as f64).abs() / matching as f64;
+        assert!(
+            error < 0.1,
+            "expected {matching}, estimated {}",
+            estimation.exp
+        );
+    }
+
+    #[test]
+    fn test_prefix_match_after_update() {
+        let dir = Builder::new().prefix("gridstore_index").tempdir().unwrap();
+        let mut index = build_index(
+            KeywordIndexBuilder::new(
+                MapIndex::builder_gridstore(dir.path().to_path_buf()),
+                normalizer(),
+            ),
+            &[vec!["apple"], vec!["apricot"]],
+        );
+        assert_eq!(filter_prefix(&index, "ap"), HashSet::from([0, 1]));
+
+        let hw_counter = HardwareCounterCell::new();
+        index.remove_point(1).unwrap();
+        assert!(filter_prefix(&index, "apr").is_empty());
+
+        index
+            .add_point(2, &[&json!("Äpricot")], &hw_counter)
+            .unwrap();
+        index.add_point(3, &[&json!("apex")], &hw_counter).unwrap();
+        assert_eq!(filter_prefix(&index, "Apr"), HashSet::from([2]));
+        assert_eq!(filter_prefix(&index, "ap"), HashSet::from([0, 3]));
+    }
+
+    #[test]
+    fn test_lowercase_match() {
+        let normalizer = KeywordNormalizer::new(&KeywordIndexParams {
+            lowercase: Some(true),
+            ..Default::default()
+        });
+        assert_eq!(normalizer.normalize("ÄPfel"), "äpfel");
+        assert!(matches!(normalizer.normalize("apfel"), Cow::Borrowed(_)));
+        // Escapes of the regex are kept, matching is case-insensitive instead
+        let match_regex = MatchRegex {
+            regex: "^\\D".to_string(),
+        };
+        assert_eq!(normalizer.normalize_regex(&match_regex).regex, "(?i)^\\D");
+
+        let data = [
+            vec!["Apple"],
+            vec!["APPLE", "Banana"],
+            vec!["apricot"],
+            vec!["BAND"],
+        ];
+
+        let gridstore_dir = Builder::new().prefix("gridstore_index").tempdir().unwrap();
+        let immutable_dir = Builder::new().prefix("immutable_index").tempdir().unwrap();
+        let mmap_dir = Builder::new().prefix("mmap_index").tempdir().unwrap();
+        let indexes = [
+            build_index(
+                KeywordIndexBuilder::new(
+                    MapIndex::builder_gridstore(gridstore_dir.path().to_path_buf()),
+                    normalizer,
+                ),
+                &data,
+            ),
+            build_index(
+                KeywordIndexBuilder::new(
+                    MapIndex::builder_mmap(immutable_dir.path(), false),
+                    normalizer,
+                ),
+                &data,
+            ),
+            build_index(
+                KeywordIndexBuilder::new(MapIndex::builder_mmap(mmap_dir.path(), true), normalizer),
+                &data,
+            ),
+        ];
+
+        let hw_counter = HardwareCounterCell::new();
+        let key = "keyword".parse::<PayloadKeyType>().unwrap();
+        for index in &indexes {
+            let condition = FieldCondition::new_match(key.clone(), "aPPle".to_string().into());
+            let points = index
+                .filter(&condition, &hw_counter)
+                .unwrap()
+                .collect::<HashSet<_>>();
+            assert_eq!(points, HashSet::from([0, 1]));
+            assert_eq!(
+                index.check_payload_match(&condition, &json!("APPLE")),
+                Some(true),
+            );
+
+            assert_eq!(filter_prefix(index, "BAN"), HashSet::from([1, 3]));
+            assert_eq!(filter_regex(index, "^Ap"), HashSet::from([0, 1, 2]));
+        }
+    }
+}
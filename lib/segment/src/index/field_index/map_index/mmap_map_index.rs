@@ -2,6 +2,7 @@ use std::borrow::Borrow;
 use std::iter;
 use std::mem::size_of;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use ahash::HashMap;
 use common::counter::conditioned_counter::ConditionedCounter;
@@ -10,24 +11,31 @@ use common::counter::iterator_hw_measurement::HwMeasurementIteratorExt;
 use common::mmap_hashmap::{Key, MmapHashMap, READ_ENTRY_OVERHEAD};
 use common::types::PointOffsetType;
 use fs_err as fs;
-use io::file_operations::{atomic_save_json, read_json};
+use io::file_operations::{atomic_save_bin, atomic_save_json, read_bin, read_json};
 use itertools::Itertools;
 use memmap2::MmapMut;
 use memory::fadvise::clear_disk_cache;
 use memory::madvise::AdviceSetting;
 use memory::mmap_ops::{self, create_and_ensure_length};
-use memory::mmap_type::MmapBitSlice;
+use memory::mmap_type::{MmapBitSlice, MmapSlice};
 use serde::{Deserialize, Serialize};
 
 use super::{IdIter, MapIndexKey};
 use crate::common::Flusher;
+use crate::common::bloom_filter::BloomFilter;
+use crate::common::distinct_values_sketch::DistinctValuesSketch;
 use crate::common::mmap_bitslice_buffered_update_wrapper::MmapBitSliceBufferedUpdateWrapper;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::field_index::mmap_point_to_values::MmapPointToValues;
 
 const DELETED_PATH: &str = "deleted.bin";
 const HASHMAP_PATH: &str = "values_to_points.bin";
+pub(super) const SORTED_BUCKETS_PATH: &str = "sorted_values.bin";
 const CONFIG_PATH: &str = "mmap_field_index_config.json";
+pub(super) const BLOOM_FILTER_PATH: &str = "values_bloom_filter.bin";
+
+/// Rate of missing values, which are not filtered out by the bloom filter
+const BLOOM_FILTER_FALSE_POSITIVE_RATE: f64 = 0.01;
 
 pub struct MmapMapIndex<N: MapIndexKey + Key + ?Sized> {
     path: PathBuf,
@@ -38,12 +46,19 @@ pub struct MmapMapIndex<N: MapIndexKey + Key + ?Sized> {
     deleted_count: usize,
     total_key_value_pairs: usize,
     is_on_disk: bool,
+    /// Built on the first request, to not read all values on load
+    distinct_values: OnceLock<DistinctValuesSketch>,
+    /// Indexed values, to look up missing values without reading the storage
+    bloom_filter: Option<BloomFilter>,
 }
 
 pub(super) struct Storage<N: MapIndexKey + Key + ?Sized> {
     pub(super) value_to_points: MmapHashMap<N, PointOffsetType>,
     point_to_values: MmapPointToValues<N>,
     pub(super) deleted: MmapBitSliceBufferedUpdateWrapper,
+    /// Buckets of `value_to_points` in the order of their values, to look up ranges of values.
+    /// Missing in indexes built before it was introduced.
+    sorted_buckets: Option<MmapSlice<u32>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,20 +85,42 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
         let hashmap = MmapHashMap::open(&hashmap_path, do_populate)?;
         let point_to_values = MmapPointToValues::open(path, do_populate)?;
 
+        let sorted_buckets_path = path.join(SORTED_BUCKETS_PATH);
+        let sorted_buckets = if sorted_buckets_path.is_file() {
+            let mmap = mmap_ops::open_write_mmap(
+                &sorted_buckets_path,
+                AdviceSetting::Global,
+                do_populate,
+            )?;
+            Some(unsafe { MmapSlice::try_from(mmap)? })
+        } else {
+            None
+        };
+
         let deleted = mmap_ops::open_write_mmap(&deleted_path, AdviceSetting::Global, do_populate)?;
         let deleted = MmapBitSlice::from(deleted, 0);
         let deleted_count = deleted.count_ones();
 
+        let bloom_filter_path = path.join(BLOOM_FILTER_PATH);
+        let bloom_filter = if bloom_filter_path.is_file() {
+            Some(read_bin(&bloom_filter_path)?)
+        } else {
+            None
+        };
+
         Ok(Some(Self {
             path: path.to_path_buf(),
             storage: Storage {
                 value_to_points: hashmap,
                 point_to_values,
                 deleted: MmapBitSliceBufferedUpdateWrapper::new(deleted),
+                sorted_buckets,
             },
             deleted_count,
             total_key_value_pairs: config.total_key_value_pairs,
             is_on_disk,
+            distinct_values: OnceLock::new(),
+            bloom_filter,
         }))
     }
 
@@ -92,10 +129,12 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
         point_to_values: Vec<Vec<N::Owned>>,
         values_to_points: HashMap<N::Owned, Vec<PointOffsetType>>,
         is_on_disk: bool,
+        bloom_filter: bool,
     ) -> OperationResult<Self> {
         fs::create_dir_all(path)?;
 
         let hashmap_path = path.join(HASHMAP_PATH);
+        let sorted_buckets_path = path.join(SORTED_BUCKETS_PATH);
         let deleted_path = path.join(DELETED_PATH);
         let config_path = path.join(CONFIG_PATH);
 
@@ -106,6 +145,14 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
             },
         )?;
 
+        if bloom_filter {
+            let bloom_filter = BloomFilter::from_values(
+                values_to_points.keys().map(Borrow::<N>::borrow),
+                BLOOM_FILTER_FALSE_POSITIVE_RATE,
+            );
+            atomic_save_bin(&path.join(BLOOM_FILTER_PATH), &bloom_filter)?;
+        }
+
         MmapHashMap::create(
             &hashmap_path,
             values_to_points
@@ -113,6 +160,24 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
                 .map(|(value, ids)| (value.borrow(), ids.iter().copied())),
         )?;
 
+        {
+            let hashmap = MmapHashMap::<N, PointOffsetType>::open(&hashmap_path, false)?;
+            let sorted_buckets = (0..hashmap.keys_count())
+                .filter_map(|bucket| Some((hashmap.key_at(bucket)?, bucket as u32)))
+                .sorted_unstable_by(|(left, _), (right, _)| left.cmp(right))
+                .map(|(_, bucket)| bucket)
+                .collect_vec();
+
+            let sorted_buckets_file = create_and_ensure_length(
+                &sorted_buckets_path,
+                sorted_buckets.len() * size_of::<u32>(),
+            )?;
+            let sorted_buckets_mmap = unsafe { MmapMut::map_mut(&sorted_buckets_file)? };
+            let mut sorted_buckets_slice =
+                unsafe { MmapSlice::<u32>::try_from(sorted_buckets_mmap)? };
+            sorted_buckets_slice.copy_from_slice(&sorted_buckets);
+        }
+
         MmapPointToValues::<N>::from_iter(
             path,
             point_to_values.iter().enumerate().map(|(idx, values)| {
@@ -168,12 +233,24 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
             self.path.join(DELETED_PATH),
             self.path.join(CONFIG_PATH),
         ];
+        if self.storage.sorted_buckets.is_some() {
+            files.push(self.path.join(SORTED_BUCKETS_PATH));
+        }
+        if self.bloom_filter.is_some() {
+            files.push(self.path.join(BLOOM_FILTER_PATH));
+        }
         files.extend(self.storage.point_to_values.files());
         files
     }
 
     pub fn immutable_files(&self) -> Vec<PathBuf> {
         let mut files = vec![self.path.join(HASHMAP_PATH), self.path.join(CONFIG_PATH)];
+        if self.storage.sorted_buckets.is_some() {
+            files.push(self.path.join(SORTED_BUCKETS_PATH));
+        }
+        if self.bloom_filter.is_some() {
+            files.push(self.path.join(BLOOM_FILTER_PATH));
+        }
         files.extend(self.storage.point_to_values.immutable_files());
         files
     }
@@ -253,11 +330,23 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
         self.storage.value_to_points.keys_count()
     }
 
+    /// Whether the value may be indexed, `false` if the bloom filter tells it is missing
+    fn may_contain(&self, value: &N) -> bool {
+        self.bloom_filter
+            .as_ref()
+            .is_none_or(|bloom_filter| bloom_filter.may_contain(value))
+    }
+
     pub fn get_count_for_value(
         &self,
         value: &N,
         hw_counter: &HardwareCounterCell,
     ) -> Option<usize> {
+        // Missing value, no need to read the storage
+        if !self.may_contain(value) {
+            return None;
+        }
+
         let hw_counter = self.make_conditioned_counter(hw_counter);
 
         // Since `value_to_points.get` doesn't actually force read from disk for all values
@@ -281,6 +370,10 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
     }
 
     pub fn get_iterator(&self, value: &N, hw_counter: &HardwareCounterCell) -> IdIter<'_> {
+        if !self.may_contain(value) {
+            return Box::new(iter::empty());
+        }
+
         let hw_counter = self.make_conditioned_counter(hw_counter);
 
         match self.storage.value_to_points.get(value) {
@@ -319,6 +412,12 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
         self.storage.value_to_points.keys()
     }
 
+    /// Sketch of the distinct values, including the ones of points deleted after the index was built
+    pub fn distinct_values_sketch(&self) -> &DistinctValuesSketch {
+        self.distinct_values
+            .get_or_init(|| DistinctValuesSketch::from_values(self.iter_values()))
+    }
+
     pub fn iter_counts_per_value(&self) -> impl Iterator<Item = (&N, usize)> + '_ {
         self.storage.value_to_points.iter().map(|(k, v)| {
             let count = v
@@ -373,6 +472,9 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
     pub fn populate(&self) -> OperationResult<()> {
         self.storage.value_to_points.populate()?;
         self.storage.point_to_values.populate();
+        if let Some(sorted_buckets) = &self.storage.sorted_buckets {
+            sorted_buckets.populate()?;
+        }
         Ok(())
     }
 
@@ -383,8 +485,39 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
 
         clear_disk_cache(&value_to_points_path)?;
         clear_disk_cache(&deleted_path)?;
+        if self.storage.sorted_buckets.is_some() {
+            clear_disk_cache(&self.path.join(SORTED_BUCKETS_PATH))?;
+        }
 
         self.storage.point_to_values.clear_cache()?;
         Ok(())
     }
 }
+
+impl MmapMapIndex<str> {
+    pub fn iter_values_with_prefix<'a>(
+        &'a self,
+        prefix: &str,
+    ) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        let value_to_points = &self.storage.value_to_points;
+        let Some(sorted_buckets) = &self.storage.sorted_buckets else {
+            // No sorted dictionary, scan all values
+            let prefix = prefix.to_owned();
+            return Box::new(
+                value_to_points
+                    .keys()
+                    .filter(move |value| value.starts_with(prefix.as_str())),
+            );
+        };
+
+        let value_at = |bucket: &u32| value_to_points.key_at(*bucket as usize).unwrap_or_default();
+        let start = sorted_buckets.partition_point(|bucket| value_at(bucket) < prefix);
+        let prefix = prefix.to_owned();
+        Box::new(
+            sorted_buckets[start..]
+                .iter()
+                .map(value_at)
+                .take_while(move |value| value.starts_with(prefix.as_str())),
+        )
+    }
+}
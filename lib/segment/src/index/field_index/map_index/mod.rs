@@ -13,7 +13,7 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::mmap_hashmap::Key;
 use common::types::PointOffsetType;
 use ecow::EcoString;
-use gridstore::Blob;
+use gridstore::{Blob, Fragmentation};
 use indexmap::IndexSet;
 use itertools::Itertools;
 use mmap_map_index::MmapMapIndex;
@@ -30,6 +30,7 @@ use super::FieldIndexBuilderTrait;
 use super::facet_index::FacetIndex;
 use super::mmap_point_to_values::MmapValue;
 use crate::common::Flusher;
+use crate::common::distinct_values_sketch::DistinctValuesSketch;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::facets::{FacetHit, FacetValueRef};
 use crate::index::field_index::stat_tools::number_of_selected_points;
@@ -39,13 +40,14 @@ use crate::index::field_index::{
 };
 use crate::index::payload_config::{IndexMutability, StorageType};
 use crate::index::query_estimator::combine_should_estimations;
-use crate::telemetry::PayloadIndexTelemetry;
+use crate::telemetry::{PayloadIndexTelemetry, StorageFragmentationTelemetry};
 use crate::types::{
     AnyVariants, FieldCondition, IntPayloadType, Match, MatchAny, MatchExcept, MatchValue,
     PayloadKeyType, UuidIntType, ValueVariants,
 };
 
 pub mod immutable_map_index;
+pub mod keyword_index;
 pub mod mmap_map_index;
 pub mod mutable_map_index;
 
@@ -58,8 +60,8 @@ pub(super) const BLOCK_SIZE_KEYWORD: usize = 16;
 pub type IdRefIter<'a> = Box<dyn Iterator<Item = &'a PointOffsetType> + 'a>;
 pub type IdIter<'a> = Box<dyn Iterator<Item = PointOffsetType> + 'a>;
 
-pub trait MapIndexKey: Key + MmapValue + Eq + Display + Debug {
-    type Owned: Borrow<Self> + Hash + Eq + Clone + FromStr + Default + 'static;
+pub trait MapIndexKey: Key + MmapValue + Ord + Display + Debug {
+    type Owned: Borrow<Self> + Hash + Ord + Clone + FromStr + Default + 'static;
 
     fn to_owned(&self) -> Self::Owned;
 
@@ -166,6 +168,7 @@ where
             point_to_values: Default::default(),
             values_to_points: Default::default(),
             is_on_disk,
+            bloom_filter: false,
         }
     }
 
@@ -233,6 +236,33 @@ where
         }
     }
 
+    /// Approximate set of the distinct indexed values, which can be merged across segments
+    pub fn distinct_values_sketch(&self) -> &DistinctValuesSketch {
+        match self {
+            MapIndex::Mutable(index) => index.distinct_values_sketch(),
+            MapIndex::Immutable(index) => index.distinct_values_sketch(),
+            MapIndex::Mmap(index) => index.distinct_values_sketch(),
+        }
+    }
+
+    /// Space usage of the storage, available for mutable indexes on Gridstore only
+    pub fn fragmentation(&self) -> Option<Fragmentation> {
+        match self {
+            MapIndex::Mutable(index) => index.fragmentation(),
+            MapIndex::Immutable(_) | MapIndex::Mmap(_) => None,
+        }
+    }
+
+    /// Rewrite the storage without fragmented space, returns the number of reclaimed bytes.
+    ///
+    /// Immutable indexes are written at once, so they are never fragmented.
+    pub fn compact(&mut self) -> OperationResult<usize> {
+        match self {
+            MapIndex::Mutable(index) => index.compact(),
+            MapIndex::Immutable(_) | MapIndex::Mmap(_) => Ok(0),
+        }
+    }
+
     fn get_count_for_value(&self, value: &N, hw_counter: &HardwareCounterCell) -> Option<usize> {
         match self {
             MapIndex::Mutable(index) => index.get_count_for_value(value),
@@ -304,6 +334,11 @@ where
             points_count: self.get_indexed_points(),
             points_values_count: self.get_values_count(),
             histogram_bucket_size: None,
+            distinct_values: Some(self.distinct_values_sketch().estimate()),
+            full_text: None,
+            fragmentation: self
+                .fragmentation()
+                .map(StorageFragmentationTelemetry::from),
             index_type: match self {
                 MapIndex::Mutable(_) => "mutable_map",
                 MapIndex::Immutable(_) => "immutable_map",
@@ -561,6 +596,20 @@ where
     }
 }
 
+impl MapIndex<str> {
+    /// Indexed keywords starting with the prefix, looked up in the sorted dictionary of the storage
+    pub fn iter_values_with_prefix<'a>(
+        &'a self,
+        prefix: &str,
+    ) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        match self {
+            MapIndex::Mutable(index) => Box::new(index.iter_values_with_prefix(prefix)),
+            MapIndex::Immutable(index) => index.iter_values_with_prefix(prefix),
+            MapIndex::Mmap(index) => index.iter_values_with_prefix(prefix),
+        }
+    }
+}
+
 pub struct MapIndexBuilder<N: MapIndexKey + ?Sized>(MapIndex<N>)
 where
     Vec<N::Owned>: Blob + Send + Sync;
@@ -599,6 +648,15 @@ pub struct MapIndexMmapBuilder<N: MapIndexKey + ?Sized> {
     point_to_values: Vec<Vec<N::Owned>>,
     values_to_points: HashMap<N::Owned, Vec<PointOffsetType>>,
     is_on_disk: bool,
+    bloom_filter: bool,
+}
+
+impl<N: MapIndexKey + ?Sized> MapIndexMmapBuilder<N> {
+    /// Build a bloom filter of the indexed values, to look up missing values without disk reads
+    pub fn with_bloom_filter(mut self, bloom_filter: bool) -> Self {
+        self.bloom_filter = bloom_filter;
+        self
+    }
 }
 
 impl<N: MapIndexKey + ?Sized> FieldIndexBuilderTrait for MapIndexMmapBuilder<N>
@@ -657,6 +715,7 @@ where
             self.point_to_values,
             self.values_to_points,
             self.is_on_disk,
+            self.bloom_filter,
         )?)))
     }
 }
@@ -1595,6 +1654,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_compact_gridstore_map_index() {
+        let data = (0..1000)
+            .map(|i: IntPayloadType| vec![i, i + 1000])
+            .collect_vec();
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        let path = temp_dir.path().join("index");
+        let index_type = IndexType::MutableGridstore;
+        save_map_index::<IntPayloadType>(&data, &path, index_type, |v| (*v).into());
+        let mut index = load_map_index::<IntPayloadType>(&data, &path, index_type);
+
+        let mut remaining = data.clone();
+        for idx in (0..data.len()).step_by(2) {
+            index.remove_point(idx as PointOffsetType).unwrap();
+            remaining[idx].clear();
+        }
+        // Blocks of removed values are freed on flush
+        index.flusher()().unwrap();
+        assert!(index.fragmentation().unwrap().ratio() > 0.3);
+
+        index.compact().unwrap();
+        assert_eq!(index.fragmentation().unwrap().fragmented_bytes, 0);
+
+        drop(index);
+        load_map_index::<IntPayloadType>(&remaining, &path, index_type);
+    }
+
     #[rstest]
     #[cfg_attr(feature = "rocksdb", case(IndexType::Mutable))]
     #[case(IndexType::MutableGridstore)]
@@ -1617,4 +1704,99 @@ mod tests {
                 .equals_min_exp_max(&CardinalityEstimation::exact(0))
         );
     }
+
+    #[rstest]
+    #[cfg_attr(feature = "rocksdb", case(IndexType::Mutable))]
+    #[case(IndexType::MutableGridstore)]
+    #[case(IndexType::Mmap)]
+    #[case(IndexType::RamMmap)]
+    fn test_values_with_prefix(#[case] index_type: IndexType) {
+        let data = [
+            vec!["apple", "banana"],
+            vec!["application"],
+            vec!["apricot", "app"],
+            vec!["band", "apple"],
+            vec![],
+        ]
+        .map(|values| values.into_iter().map(EcoString::from).collect_vec());
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<str>(&data, temp_dir.path(), index_type, |v| v.to_string().into());
+        let index = load_map_index::<str>(&data, temp_dir.path(), index_type);
+
+        // Values are looked up in sorted order
+        let with_prefix = |prefix: &str| index.iter_values_with_prefix(prefix).collect_vec();
+        assert_eq!(with_prefix("app"), ["app", "apple", "application"]);
+        assert_eq!(with_prefix("ban"), ["banana", "band"]);
+        assert_eq!(with_prefix("banana"), ["banana"]);
+        assert_eq!(with_prefix("").len(), 6);
+        assert!(with_prefix("cherry").is_empty());
+    }
+
+    #[test]
+    fn test_values_with_prefix_without_sorted_dictionary() {
+        let data = [vec!["apple", "banana"], vec!["apricot"], vec!["band"]]
+            .map(|values| values.into_iter().map(EcoString::from).collect_vec());
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<str>(&data, temp_dir.path(), IndexType::Mmap, |v| {
+            v.to_string().into()
+        });
+
+        // Indexes built before the sorted dictionary are scanned instead
+        fs_err::remove_file(temp_dir.path().join(mmap_map_index::SORTED_BUCKETS_PATH)).unwrap();
+        let index = load_map_index::<str>(&data, temp_dir.path(), IndexType::Mmap);
+
+        let values = index.iter_values_with_prefix("ap").sorted().collect_vec();
+        assert_eq!(values, ["apple", "apricot"]);
+    }
+
+    #[test]
+    fn test_mmap_index_bloom_filter() {
+        let data = [
+            vec!["apple", "banana"],
+            vec!["apricot"],
+            vec!["band", "apple"],
+        ]
+        .map(|values| values.into_iter().map(EcoString::from).collect_vec());
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+
+        let mut builder =
+            MapIndex::<str>::builder_mmap(temp_dir.path(), true).with_bloom_filter(true);
+        builder.init().unwrap();
+        for (idx, values) in data.iter().enumerate() {
+            let values: Vec<Value> = values.iter().map(|v| v.to_string().into()).collect();
+            let values: Vec<_> = values.iter().collect();
+            builder
+                .add_point(idx as PointOffsetType, &values, &hw_counter)
+                .unwrap();
+        }
+        let index = builder.finalize().unwrap();
+        assert!(
+            index
+                .files()
+                .contains(&temp_dir.path().join(mmap_map_index::BLOOM_FILTER_PATH))
+        );
+        drop(index);
+
+        // Bloom filter is persisted with the index
+        let index = load_map_index::<str>(&data, temp_dir.path(), IndexType::Mmap);
+
+        assert_eq!(index.get_count_for_value("apple", &hw_counter), Some(2));
+        assert_eq!(
+            index
+                .get_iterator("apple", &hw_counter)
+                .sorted()
+                .collect_vec(),
+            [0, 2],
+        );
+
+        let hw_counter = HardwareCounterCell::new();
+        assert_eq!(index.get_count_for_value("cherry", &hw_counter), None);
+        assert_eq!(index.get_iterator("cherry", &hw_counter).count(), 0);
+        // Missing value is not looked up on disk
+        assert_eq!(hw_counter.payload_index_io_read_counter().get(), 0);
+    }
 }
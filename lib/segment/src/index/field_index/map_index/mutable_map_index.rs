@@ -1,6 +1,7 @@
 use std::borrow::Borrow;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::iter;
+use std::ops::Bound;
 use std::path::PathBuf;
 #[cfg(feature = "rocksdb")]
 use std::sync::Arc;
@@ -8,7 +9,8 @@ use std::sync::Arc;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use gridstore::config::StorageOptions;
-use gridstore::{Blob, Gridstore};
+use gridstore::error::GridstoreError;
+use gridstore::{Blob, Fragmentation, Gridstore};
 #[cfg(feature = "rocksdb")]
 use parking_lot::RwLock;
 use roaring::RoaringBitmap;
@@ -19,6 +21,7 @@ use rocksdb::DB;
 use super::MapIndex;
 use super::{IdIter, MapIndexKey};
 use crate::common::Flusher;
+use crate::common::distinct_values_sketch::DistinctValuesSketch;
 use crate::common::operation_error::{OperationError, OperationResult};
 #[cfg(feature = "rocksdb")]
 use crate::common::rocksdb_buffered_delete_wrapper::DatabaseColumnScheduledDeleteWrapper;
@@ -41,11 +44,14 @@ pub struct MutableMapIndex<N: MapIndexKey + ?Sized>
 where
     Vec<N::Owned>: Blob + Send + Sync,
 {
-    pub(super) map: HashMap<N::Owned, RoaringBitmap>,
+    /// Sorted by value, so the map is also the dictionary for range lookups
+    pub(super) map: BTreeMap<N::Owned, RoaringBitmap>,
     pub(super) point_to_values: Vec<Vec<N::Owned>>,
     /// Amount of point which have at least one indexed payload value
     pub(super) indexed_points: usize,
     pub(super) values_count: usize,
+    /// Distinct values ever added, including the ones of removed points
+    pub(super) distinct_values: DistinctValuesSketch,
     storage: Storage<N::Owned>,
 }
 
@@ -92,7 +98,7 @@ where
         };
 
         // Load in-memory index from RocksDB
-        let mut map = HashMap::<_, RoaringBitmap>::new();
+        let mut map = BTreeMap::<_, RoaringBitmap>::new();
         let mut point_to_values = Vec::new();
         let mut indexed_points = 0;
         let mut values_count = 0;
@@ -116,11 +122,15 @@ where
             map.entry(value).or_default().insert(idx);
         }
 
+        let distinct_values =
+            DistinctValuesSketch::from_values::<N>(map.keys().map(Borrow::borrow));
+
         Ok(Some(Self {
             map,
             point_to_values,
             indexed_points,
             values_count,
+            distinct_values,
             storage: Storage::RocksDb(db_wrapper),
         }))
     }
@@ -150,7 +160,7 @@ where
         };
 
         // Load in-memory index from Gridstore
-        let mut map = HashMap::<_, RoaringBitmap>::new();
+        let mut map = BTreeMap::<_, RoaringBitmap>::new();
         let mut point_to_values = Vec::new();
         let mut indexed_points = 0;
         let mut values_count = 0;
@@ -182,11 +192,15 @@ where
             // unwrap safety: never returns an error
             .unwrap();
 
+        let distinct_values =
+            DistinctValuesSketch::from_values::<N>(map.keys().map(Borrow::borrow));
+
         Ok(Some(Self {
             map,
             point_to_values,
             indexed_points,
             values_count,
+            distinct_values,
             storage: Storage::Gridstore(store),
         }))
     }
@@ -247,6 +261,10 @@ where
             }
         }
 
+        for value in &self.point_to_values[idx as usize] {
+            self.distinct_values.insert::<N>(value.borrow());
+        }
+
         self.indexed_points += 1;
         Ok(())
     }
@@ -340,16 +358,46 @@ where
             Storage::Gridstore(store) => {
                 let storage_flusher = store.flusher();
                 Box::new(move || {
-                    storage_flusher().map_err(|err| {
-                        OperationError::service_error(format!(
+                    storage_flusher().map_err(|err| match err {
+                        // Storage was compacted or cleared after creating the flusher
+                        GridstoreError::FlushCancelled => OperationError::from(err),
+                        _ => OperationError::service_error(format!(
                             "Failed to flush mutable map index gridstore: {err}"
-                        ))
+                        )),
                     })
                 })
             }
         }
     }
 
+    /// Space usage of the Gridstore storage, `None` for other storages
+    pub fn fragmentation(&self) -> Option<Fragmentation> {
+        match &self.storage {
+            #[cfg(feature = "rocksdb")]
+            Storage::RocksDb(_) => None,
+            Storage::Gridstore(store) => Some(store.fragmentation()),
+        }
+    }
+
+    /// Rewrite the Gridstore storage without fragmented space.
+    ///
+    /// Returns the number of bytes given back to the file system, does nothing for other storages.
+    pub fn compact(&mut self) -> OperationResult<usize> {
+        match &mut self.storage {
+            #[cfg(feature = "rocksdb")]
+            Storage::RocksDb(_) => Ok(0),
+            Storage::Gridstore(store) => {
+                let allocated_bytes = store.fragmentation().allocated_bytes;
+                store.compact().map_err(|err| {
+                    OperationError::service_error(format!(
+                        "Failed to compact mutable map index gridstore: {err}"
+                    ))
+                })?;
+                Ok(allocated_bytes.saturating_sub(store.fragmentation().allocated_bytes))
+            }
+        }
+    }
+
     pub fn check_values_any(&self, idx: PointOffsetType, check_fn: impl Fn(&N) -> bool) -> bool {
         self.point_to_values
             .get(idx as usize)
@@ -382,6 +430,10 @@ where
         self.map.len()
     }
 
+    pub fn distinct_values_sketch(&self) -> &DistinctValuesSketch {
+        &self.distinct_values
+    }
+
     pub fn get_count_for_value(&self, value: &N) -> Option<usize> {
         self.map.get(value).map(|p| p.len() as usize)
     }
@@ -423,3 +475,18 @@ where
         }
     }
 }
+
+impl MutableMapIndex<str> {
+    pub fn iter_values_with_prefix<'a>(
+        &'a self,
+        prefix: &str,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        let range = self
+            .map
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded));
+        let prefix = prefix.to_owned();
+        range
+            .map(|(value, _)| value.as_str())
+            .take_while(move |value| value.starts_with(prefix.as_str()))
+    }
+}
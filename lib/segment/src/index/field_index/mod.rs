@@ -11,7 +11,8 @@ mod field_index_base;
 pub mod full_text_index;
 pub mod geo_hash;
 pub mod geo_index;
-mod histogram;
+pub mod geo_shape_index;
+pub(crate) mod histogram;
 mod immutable_point_to_values;
 pub mod index_selector;
 pub mod map_index;
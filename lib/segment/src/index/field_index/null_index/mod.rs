@@ -1,3 +1,5 @@
 pub mod mutable_null_index;
+pub mod nested_null_index;
 
 pub use mutable_null_index::MutableNullIndex;
+pub use nested_null_index::NestedNullIndex;
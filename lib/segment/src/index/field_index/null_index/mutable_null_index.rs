@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use bitvec::slice::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use fs_err as fs;
@@ -19,6 +20,7 @@ use crate::types::{FieldCondition, PayloadKeyType};
 
 const HAS_VALUES_DIRNAME: &str = "has_values";
 const IS_NULL_DIRNAME: &str = "is_null";
+const HAS_FIELD_DIRNAME: &str = "has_field";
 
 /// Mutable variant of null index that uses roaring bitmaps for in-memory operations
 /// and buffers updates before persisting them to DynamicMmapFlags.
@@ -33,6 +35,11 @@ struct Storage {
     has_values_flags: RoaringFlags,
     /// Points which have null values
     is_null_flags: RoaringFlags,
+    /// Points which have the field, with any value including `null` and `[]`.
+    ///
+    /// Not tracked by indexes created before `has_field` condition was supported,
+    /// the condition is checked against the payload until the index is rebuilt.
+    has_field_flags: Option<RoaringFlags>,
 }
 
 impl MutableNullIndex {
@@ -76,6 +83,7 @@ impl MutableNullIndex {
         })?;
 
         let has_values_path = path.join(HAS_VALUES_DIRNAME);
+        let has_values_exists = has_values_path.is_dir();
         let has_values_mmap = DynamicMmapFlags::open(&has_values_path, false)?;
         let has_values_flags = RoaringFlags::new(has_values_mmap);
 
@@ -83,9 +91,19 @@ impl MutableNullIndex {
         let is_null_mmap = DynamicMmapFlags::open(&is_null_path, false)?;
         let is_null_flags = RoaringFlags::new(is_null_mmap);
 
+        // Existing index without presence flags can't be backfilled, it has no payload
+        let has_field_path = path.join(HAS_FIELD_DIRNAME);
+        let has_field_flags = if has_field_path.is_dir() || !has_values_exists {
+            let has_field_mmap = DynamicMmapFlags::open(&has_field_path, false)?;
+            Some(RoaringFlags::new(has_field_mmap))
+        } else {
+            None
+        };
+
         let storage = Storage {
             has_values_flags,
             is_null_flags,
+            has_field_flags,
         };
 
         Ok(Self {
@@ -137,6 +155,9 @@ impl MutableNullIndex {
 
         self.storage.has_values_flags.set(id, has_values);
         self.storage.is_null_flags.set(id, is_null);
+        if let Some(has_field_flags) = &mut self.storage.has_field_flags {
+            has_field_flags.set(id, !payload.is_empty());
+        }
 
         // Bump total points
         self.total_point_count = std::cmp::max(self.total_point_count, id as usize + 1);
@@ -151,6 +172,9 @@ impl MutableNullIndex {
         // Update bitmaps immediately
         self.storage.has_values_flags.set(id, false);
         self.storage.is_null_flags.set(id, false);
+        if let Some(has_field_flags) = &mut self.storage.has_field_flags {
+            has_field_flags.set(id, false);
+        }
 
         // Bump total points
         // We MUST bump the total point count when removing a point too
@@ -178,6 +202,55 @@ impl MutableNullIndex {
         self.storage.is_null_flags.get(id)
     }
 
+    /// Whether the index tracks presence of the field, required by [`Self::has_field`]
+    pub fn tracks_field_presence(&self) -> bool {
+        self.storage.has_field_flags.is_some()
+    }
+
+    /// Whether the point has the field, with any value including `null` and `[]`
+    pub fn has_field(&self, id: PointOffsetType) -> bool {
+        self.storage
+            .has_field_flags
+            .as_ref()
+            .is_some_and(|has_field_flags| has_field_flags.get(id))
+    }
+
+    /// Set bits of `matches` for points of the batch, for which [`Self::values_is_empty`] is `is_empty`
+    pub fn check_is_empty_batch(
+        &self,
+        ids: &[PointOffsetType],
+        is_empty: bool,
+        matches: &mut BitSlice,
+    ) {
+        self.storage
+            .has_values_flags
+            .check_batch(ids, !is_empty, matches);
+    }
+
+    /// Set bits of `matches` for points of the batch, for which [`Self::values_is_null`] is `is_null`
+    pub fn check_is_null_batch(
+        &self,
+        ids: &[PointOffsetType],
+        is_null: bool,
+        matches: &mut BitSlice,
+    ) {
+        self.storage
+            .is_null_flags
+            .check_batch(ids, is_null, matches);
+    }
+
+    /// Set bits of `matches` for points of the batch, for which [`Self::has_field`] is `has_field`
+    pub fn check_has_field_batch(
+        &self,
+        ids: &[PointOffsetType],
+        has_field: bool,
+        matches: &mut BitSlice,
+    ) {
+        if let Some(has_field_flags) = &self.storage.has_field_flags {
+            has_field_flags.check_batch(ids, has_field, matches);
+        }
+    }
+
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
         let points_count = self.storage.has_values_flags.len();
 
@@ -186,6 +259,9 @@ impl MutableNullIndex {
             points_count,
             points_values_count: points_count,
             histogram_bucket_size: None,
+            distinct_values: None,
+            full_text: None,
+            fragmentation: None,
             index_type: "mutable_null_index",
         }
     }
@@ -200,6 +276,9 @@ impl MutableNullIndex {
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         self.storage.is_null_flags.clear_cache()?;
+        if let Some(has_field_flags) = &self.storage.has_field_flags {
+            has_field_flags.clear_cache()?;
+        }
         self.storage.has_values_flags.clear_cache()
     }
 
@@ -232,10 +311,18 @@ impl PayloadFieldIndex for MutableNullIndex {
     fn flusher(&self) -> Flusher {
         let flush_has_values = self.storage.has_values_flags.flusher();
         let flush_is_null = self.storage.is_null_flags.flusher();
+        let flush_has_field = self
+            .storage
+            .has_field_flags
+            .as_ref()
+            .map(RoaringFlags::flusher);
 
         Box::new(move || {
             flush_has_values()?;
             flush_is_null()?;
+            if let Some(flush_has_field) = flush_has_field {
+                flush_has_field()?;
+            }
             Ok(())
         })
     }
@@ -243,6 +330,9 @@ impl PayloadFieldIndex for MutableNullIndex {
     fn files(&self) -> Vec<PathBuf> {
         let mut files = self.storage.has_values_flags.files();
         files.extend(self.storage.is_null_flags.files());
+        if let Some(has_field_flags) = &self.storage.has_field_flags {
+            files.extend(has_field_flags.files());
+        }
         files
     }
 
@@ -262,9 +352,12 @@ impl PayloadFieldIndex for MutableNullIndex {
             geo_bounding_box: _,
             geo_radius: _,
             geo_polygon: _,
+            geo_shape: _,
             values_count: _,
             is_empty,
             is_null,
+            has_field,
+            compare: _,
         } = condition;
 
         if let Some(is_empty) = is_empty {
@@ -287,6 +380,15 @@ impl PayloadFieldIndex for MutableNullIndex {
                 let iter = self.storage.is_null_flags.iter_falses();
                 Some(Box::new(iter))
             }
+        } else if let Some(has_field) = has_field {
+            let has_field_flags = self.storage.has_field_flags.as_ref()?;
+            if *has_field {
+                // Return points that have the field
+                Some(Box::new(has_field_flags.iter_trues()))
+            } else {
+                // Return points that don't have the field
+                Some(Box::new(has_field_flags.iter_falses()))
+            }
         } else {
             None
         }
@@ -304,9 +406,12 @@ impl PayloadFieldIndex for MutableNullIndex {
             geo_bounding_box: _,
             geo_radius: _,
             geo_polygon: _,
+            geo_shape: _,
             values_count: _,
             is_empty,
             is_null,
+            has_field,
+            compare: _,
         } = condition;
 
         if let Some(is_empty) = is_empty {
@@ -349,6 +454,27 @@ impl PayloadFieldIndex for MutableNullIndex {
                     ))],
                 })
             }
+        } else if let Some(has_field) = has_field {
+            let has_field_count = self.storage.has_field_flags.as_ref()?.count_trues();
+            let primary_clause =
+                PrimaryCondition::from(FieldCondition::new_has_field(key.clone(), *has_field));
+
+            if *has_field {
+                Some(
+                    CardinalityEstimation::exact(has_field_count)
+                        .with_primary_clause(primary_clause),
+                )
+            } else {
+                // Deleted points don't have the field either
+                let estimated = self.total_point_count.saturating_sub(has_field_count);
+
+                Some(CardinalityEstimation {
+                    min: 0,
+                    exp: 2 * estimated / 3, // assuming 1/3 of the points are deleted
+                    max: estimated,
+                    primary_clauses: vec![primary_clause],
+                })
+            }
         } else {
             None
         }
@@ -393,6 +519,7 @@ impl FieldIndexBuilderTrait for MutableNullIndexBuilder {
 #[cfg(test)]
 mod tests {
     use common::counter::hardware_accumulator::HwMeasurementAcc;
+    use serde_json::json;
     use tempfile::TempDir;
 
     use super::*;
@@ -438,9 +565,12 @@ mod tests {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             is_empty: Some(false),
             is_null: None,
+            has_field: None,
+            compare: None,
         };
 
         let hw_acc = HwMeasurementAcc::new();
@@ -512,6 +642,60 @@ mod tests {
         assert_eq!(non_empty_cardinality.exp, 50);
     }
 
+    #[test]
+    fn test_has_field() {
+        let dir = TempDir::with_prefix("test_has_field").unwrap();
+        let mut builder = MutableNullIndex::builder(dir.path()).unwrap();
+        let hw_counter = HardwareCounterCell::new();
+
+        let values = [json!(null), json!([]), json!([null]), json!(true)];
+        for (id, value) in values.iter().enumerate() {
+            builder
+                .add_point(id as PointOffsetType, &[value], &hw_counter)
+                .unwrap();
+        }
+        // Point without the field
+        builder.add_point(4, &[], &hw_counter).unwrap();
+        let null_index = builder.finalize().unwrap();
+
+        let key = JsonPath::new("test");
+        let has_field = FieldCondition::new_has_field(key.clone(), true);
+        let has_no_field = FieldCondition::new_has_field(key, false);
+
+        let points: Vec<_> = null_index
+            .filter(&has_field, &hw_counter)
+            .unwrap()
+            .collect();
+        assert_eq!(points, vec![0, 1, 2, 3]);
+        let points: Vec<_> = null_index
+            .filter(&has_no_field, &hw_counter)
+            .unwrap()
+            .collect();
+        assert_eq!(points, vec![4]);
+        assert!(null_index.has_field(1));
+        assert!(!null_index.has_field(4));
+
+        let cardinality = null_index
+            .estimate_cardinality(&has_field, &hw_counter)
+            .unwrap();
+        assert_eq!(cardinality.exp, 4);
+        assert_eq!(cardinality.min, cardinality.max);
+
+        // Index created before presence of the field was tracked
+        drop(null_index);
+        fs::remove_dir_all(dir.path().join(HAS_FIELD_DIRNAME)).unwrap();
+        let null_index = MutableNullIndex::open(dir.path(), 5, false)
+            .unwrap()
+            .unwrap();
+        assert!(!null_index.tracks_field_presence());
+        assert!(null_index.filter(&has_field, &hw_counter).is_none());
+        assert!(
+            null_index
+                .estimate_cardinality(&has_field, &hw_counter)
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_manual_buffer_flushing() {
         let dir = TempDir::with_prefix("test_manual_buffer_flushing").unwrap();
@@ -0,0 +1,450 @@
+use std::path::{Path, PathBuf};
+
+use bitvec::slice::BitSlice;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+use fs_err as fs;
+use serde_json::Value;
+
+use crate::common::Flusher;
+use crate::common::flags::dynamic_mmap_flags::DynamicMmapFlags;
+use crate::common::flags::roaring_flags::RoaringFlags;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::utils::{check_is_empty, check_is_null};
+use crate::index::field_index::{
+    CardinalityEstimation, FieldIndexBuilderTrait, PayloadBlockCondition, PayloadFieldIndex,
+};
+use crate::index::payload_config::{IndexMutability, StorageType};
+use crate::json_path::{JsonPath, JsonPathItem};
+use crate::telemetry::PayloadIndexTelemetry;
+use crate::types::{FieldCondition, PayloadKeyType};
+
+const HAS_EMPTY_ELEMENT_DIRNAME: &str = "has_empty_element";
+const HAS_NULL_ELEMENT_DIRNAME: &str = "has_null_element";
+
+/// Null index of a field inside array elements, like `a.b[].c`.
+///
+/// Flattened values of such field can't tell, whether a single element of the array has the
+/// field empty or null, which is what `is_empty` and `is_null` conditions of a nested filter on
+/// `a.b` check. This index keeps that per point, so these conditions don't read the payload.
+///
+/// Unlike other indexes, it takes whole elements of the array (values of `a.b[]`), see
+/// [`Self::elements_path`].
+pub struct NestedNullIndex {
+    base_dir: PathBuf,
+    /// Path of the array elements, e.g. `a.b[]`
+    elements_path: JsonPath,
+    /// Path of the field within an element, e.g. `c`
+    element_key: JsonPath,
+    storage: Storage,
+}
+
+/// Condition of a nested filter, answered by [`NestedNullIndex`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NestedNullCheck {
+    /// Any element has the field empty
+    IsEmpty,
+    /// Any element has the field null
+    IsNull,
+}
+
+struct Storage {
+    /// Points with an element, in which the field is empty
+    has_empty_element_flags: RoaringFlags,
+    /// Points with an element, in which the field is null
+    has_null_element_flags: RoaringFlags,
+}
+
+impl NestedNullIndex {
+    /// Split the path of the field at the last array into the path of the array elements and
+    /// the path of the field within an element, e.g. `a.b[].c` -> (`a.b[]`, `c`).
+    ///
+    /// Returns `None` if the field is not inside array elements.
+    pub fn split_path(field: &JsonPath) -> Option<(JsonPath, JsonPath)> {
+        let position = field
+            .rest
+            .iter()
+            .rposition(|item| *item == JsonPathItem::WildcardIndex)?;
+        let (array, element) = field.rest.split_at(position + 1);
+        let (JsonPathItem::Key(first_key), rest) = element.split_first()? else {
+            return None;
+        };
+
+        let elements_path = JsonPath {
+            first_key: field.first_key.clone(),
+            rest: array.to_vec(),
+        };
+        let element_key = JsonPath {
+            first_key: first_key.clone(),
+            rest: rest.to_vec(),
+        };
+        Some((elements_path, element_key))
+    }
+
+    pub fn builder(path: &Path, field: &JsonPath) -> OperationResult<NestedNullIndexBuilder> {
+        Ok(NestedNullIndexBuilder(
+            Self::open(path, field, true)?.ok_or_else(|| {
+                OperationError::service_error(format!(
+                    "Failed to create and open nested null index for field `{field}` at path: {}",
+                    path.display(),
+                ))
+            })?,
+        ))
+    }
+
+    /// Open and load or create a nested null index at the given path.
+    ///
+    /// # Arguments
+    /// - `path` - The directory where the index files should live, must be exclusive to this index.
+    /// - `field` - Indexed field, must be inside array elements.
+    /// - `create_if_missing` - If true, creates the index if it doesn't exist.
+    pub fn open(
+        path: &Path,
+        field: &JsonPath,
+        create_if_missing: bool,
+    ) -> OperationResult<Option<Self>> {
+        let Some((elements_path, element_key)) = Self::split_path(field) else {
+            return Ok(None);
+        };
+
+        let has_empty_element_dir = path.join(HAS_EMPTY_ELEMENT_DIRNAME);
+
+        // If has empty element directory doesn't exist, assume the index doesn't exist on disk
+        if !has_empty_element_dir.is_dir() && !create_if_missing {
+            return Ok(None);
+        }
+
+        fs::create_dir_all(path).map_err(|err| {
+            OperationError::service_error(format!(
+                "Failed to create nested-null-index directory: {err}, path: {path:?}"
+            ))
+        })?;
+
+        let has_empty_element_mmap = DynamicMmapFlags::open(&has_empty_element_dir, false)?;
+        let has_null_element_mmap =
+            DynamicMmapFlags::open(&path.join(HAS_NULL_ELEMENT_DIRNAME), false)?;
+
+        let storage = Storage {
+            has_empty_element_flags: RoaringFlags::new(has_empty_element_mmap),
+            has_null_element_flags: RoaringFlags::new(has_null_element_mmap),
+        };
+
+        Ok(Some(Self {
+            base_dir: path.to_path_buf(),
+            elements_path,
+            element_key,
+            storage,
+        }))
+    }
+
+    /// Path of the array elements, which values are expected by [`Self::add_point`]
+    pub fn elements_path(&self) -> &JsonPath {
+        &self.elements_path
+    }
+
+    /// Index the point by elements of its array, values of [`Self::elements_path`]
+    pub fn add_point(
+        &mut self,
+        id: PointOffsetType,
+        elements: &[&Value],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let mut has_empty_element = false;
+        let mut has_null_element = false;
+
+        // Same as nested filters, only objects are considered elements
+        for element in elements {
+            let Value::Object(object) = element else {
+                continue;
+            };
+            let values = self.element_key.value_get(object);
+            has_empty_element |= check_is_empty(values.iter().copied());
+            has_null_element |= check_is_null(values.iter().copied());
+            if has_empty_element && has_null_element {
+                break;
+            }
+        }
+
+        self.storage
+            .has_empty_element_flags
+            .set(id, has_empty_element);
+        self.storage
+            .has_null_element_flags
+            .set(id, has_null_element);
+
+        // Account for I/O cost as if we were writing to disk now
+        hw_counter.payload_index_io_write_counter().incr_delta(2);
+
+        Ok(())
+    }
+
+    pub fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        self.storage.has_empty_element_flags.set(id, false);
+        self.storage.has_null_element_flags.set(id, false);
+
+        // Account for I/O cost as if we were writing to disk now
+        let hw_counter = HardwareCounterCell::disposable();
+        hw_counter.payload_index_io_write_counter().incr_delta(2);
+
+        Ok(())
+    }
+
+    /// Index doesn't store values of the field
+    pub fn values_count(&self, _id: PointOffsetType) -> usize {
+        0
+    }
+
+    /// Index doesn't store values of the field
+    pub fn values_is_empty(&self, _id: PointOffsetType) -> bool {
+        true
+    }
+
+    fn flags(&self, check: NestedNullCheck) -> &RoaringFlags {
+        match check {
+            NestedNullCheck::IsEmpty => &self.storage.has_empty_element_flags,
+            NestedNullCheck::IsNull => &self.storage.has_null_element_flags,
+        }
+    }
+
+    /// Whether the point matches the nested filter of the `check` condition
+    pub fn check(&self, check: NestedNullCheck, id: PointOffsetType) -> bool {
+        self.flags(check).get(id)
+    }
+
+    /// Set bits of `matches` for points of the batch, which match the nested filter of the `check` condition
+    pub fn check_batch(
+        &self,
+        check: NestedNullCheck,
+        ids: &[PointOffsetType],
+        matches: &mut BitSlice,
+    ) {
+        self.flags(check).check_batch(ids, true, matches);
+    }
+
+    /// Number of points, which match the nested filter of the `check` condition
+    pub fn count(&self, check: NestedNullCheck) -> usize {
+        self.flags(check).count_trues()
+    }
+
+    pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
+        let points_count = self.storage.has_empty_element_flags.len();
+
+        PayloadIndexTelemetry {
+            field_name: None,
+            points_count,
+            points_values_count: points_count,
+            histogram_bucket_size: None,
+            distinct_values: None,
+            full_text: None,
+            fragmentation: None,
+            index_type: "nested_null_index",
+        }
+    }
+
+    pub fn populate(&self) -> OperationResult<()> {
+        Ok(())
+    }
+
+    pub fn is_on_disk(&self) -> bool {
+        false
+    }
+
+    /// Drop disk cache.
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        self.storage.has_empty_element_flags.clear_cache()?;
+        self.storage.has_null_element_flags.clear_cache()
+    }
+
+    pub fn get_mutability_type(&self) -> IndexMutability {
+        IndexMutability::Mutable
+    }
+
+    pub fn get_storage_type(&self) -> StorageType {
+        StorageType::Mmap {
+            is_on_disk: self.is_on_disk(),
+        }
+    }
+}
+
+impl PayloadFieldIndex for NestedNullIndex {
+    fn count_indexed_points(&self) -> usize {
+        self.storage.has_empty_element_flags.len()
+    }
+
+    fn wipe(self) -> OperationResult<()> {
+        let base_dir = self.base_dir.clone();
+        // drop mmap handles before deleting files
+        drop(self);
+        if base_dir.is_dir() {
+            fs::remove_dir_all(&base_dir)?;
+        }
+        Ok(())
+    }
+
+    fn flusher(&self) -> Flusher {
+        let flush_has_empty_element = self.storage.has_empty_element_flags.flusher();
+        let flush_has_null_element = self.storage.has_null_element_flags.flusher();
+
+        Box::new(move || {
+            flush_has_empty_element()?;
+            flush_has_null_element()?;
+            Ok(())
+        })
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.storage.has_empty_element_flags.files();
+        files.extend(self.storage.has_null_element_flags.files());
+        files
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        Vec::new() // everything is mutable
+    }
+
+    fn filter<'a>(
+        &'a self,
+        _condition: &'a FieldCondition,
+        _hw_counter: &'a HardwareCounterCell,
+    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        // Conditions on flattened values of the field are answered by `MutableNullIndex`
+        None
+    }
+
+    fn estimate_cardinality(
+        &self,
+        _condition: &FieldCondition,
+        _hw_counter: &HardwareCounterCell,
+    ) -> Option<CardinalityEstimation> {
+        // Conditions on flattened values of the field are answered by `MutableNullIndex`
+        None
+    }
+
+    fn payload_blocks(
+        &self,
+        _threshold: usize,
+        _key: PayloadKeyType,
+    ) -> Box<dyn Iterator<Item = PayloadBlockCondition> + '_> {
+        // No payload blocks
+        Box::new(std::iter::empty())
+    }
+}
+
+pub struct NestedNullIndexBuilder(NestedNullIndex);
+
+impl NestedNullIndexBuilder {
+    /// Path of the array elements, which values are expected by [`Self::add_point`]
+    pub fn elements_path(&self) -> &JsonPath {
+        self.0.elements_path()
+    }
+}
+
+impl FieldIndexBuilderTrait for NestedNullIndexBuilder {
+    type FieldIndexType = NestedNullIndex;
+
+    fn init(&mut self) -> OperationResult<()> {
+        // After Self is created, it is already initialized
+        Ok(())
+    }
+
+    fn add_point(
+        &mut self,
+        id: PointOffsetType,
+        payload: &[&Value],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        self.0.add_point(id, payload, hw_counter)
+    }
+
+    fn finalize(self) -> OperationResult<Self::FieldIndexType> {
+        self.0.flusher()()?;
+        Ok(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_split_path() {
+        let split = |path: &str| {
+            NestedNullIndex::split_path(&JsonPath::new(path))
+                .map(|(elements, key)| (elements.to_string(), key.to_string()))
+        };
+
+        assert_eq!(split("a.b[].c"), Some(("a.b[]".into(), "c".into())));
+        assert_eq!(split("a[].b[].c.d"), Some(("a[].b[]".into(), "c.d".into())));
+        assert_eq!(split("a.b[]"), None);
+        assert_eq!(split("a.b"), None);
+    }
+
+    #[test]
+    fn test_nested_null_index() {
+        let dir = TempDir::with_prefix("test_nested_null_index").unwrap();
+        let field = JsonPath::new("a.b[].c");
+        let mut builder = NestedNullIndex::builder(dir.path(), &field).unwrap();
+        let hw_counter = HardwareCounterCell::new();
+
+        let payloads = [
+            // Second element has no `c`
+            json!({"a": {"b": [{"c": 1}, {"d": 2}]}}),
+            // Second element has `c` null
+            json!({"a": {"b": [{"c": 1}, {"c": null}]}}),
+            // Second element has `c` empty, `null` inside of an array is not a null value
+            json!({"a": {"b": [{"c": [1, null]}, {"c": []}]}}),
+            // All elements have `c`
+            json!({"a": {"b": [{"c": 1}, {"c": [2]}]}}),
+            // Elements which are not objects are ignored
+            json!({"a": {"b": [null, 1]}}),
+            // No array
+            json!({"d": 1}),
+        ];
+
+        for (id, payload) in payloads.iter().enumerate() {
+            let payload = payload.as_object().unwrap();
+            let elements = builder.elements_path().value_get(payload);
+            builder
+                .add_point(id as PointOffsetType, &elements, &hw_counter)
+                .unwrap();
+        }
+        let mut index = builder.finalize().unwrap();
+
+        let has_empty_element: Vec<_> = (0..payloads.len() as PointOffsetType)
+            .filter(|&id| index.check(NestedNullCheck::IsEmpty, id))
+            .collect();
+        assert_eq!(has_empty_element, vec![0, 1, 2]);
+        assert_eq!(index.count(NestedNullCheck::IsEmpty), 3);
+
+        let has_null_element: Vec<_> = (0..payloads.len() as PointOffsetType)
+            .filter(|&id| index.check(NestedNullCheck::IsNull, id))
+            .collect();
+        assert_eq!(has_null_element, vec![1]);
+        assert_eq!(index.count(NestedNullCheck::IsNull), 1);
+
+        // Flattened conditions are left to other indexes
+        let condition = FieldCondition::new_is_empty(field.clone(), true);
+        assert!(index.filter(&condition, &hw_counter).is_none());
+
+        // Elements are updated
+        index
+            .add_point(0, &[&json!({"c": 1}), &json!({"c": 2})], &hw_counter)
+            .unwrap();
+        assert!(!index.check(NestedNullCheck::IsEmpty, 0));
+        index.remove_point(1).unwrap();
+        assert!(!index.check(NestedNullCheck::IsNull, 1));
+
+        // Reload from disk
+        index.flusher()().unwrap();
+        drop(index);
+        let index = NestedNullIndex::open(dir.path(), &field, false)
+            .unwrap()
+            .unwrap();
+        assert!(!index.check(NestedNullCheck::IsEmpty, 0));
+        assert!(index.check(NestedNullCheck::IsEmpty, 2));
+        assert_eq!(index.count(NestedNullCheck::IsNull), 0);
+    }
+}
@@ -13,9 +13,11 @@ use parking_lot::RwLock;
 #[cfg(feature = "rocksdb")]
 use rocksdb::DB;
 
-use super::Encodable;
+#[cfg(feature = "rocksdb")]
+use super::NumericHistogramConfig;
 use super::mmap_numeric_index::MmapNumericIndex;
 use super::mutable_numeric_index::InMemoryNumericIndex;
+use super::{Encodable, live_values_estimation};
 use crate::common::Flusher;
 use crate::common::operation_error::OperationResult;
 #[cfg(feature = "rocksdb")]
@@ -163,7 +165,11 @@ where
 {
     /// Open and load immutable numeric index from RocksDB storage
     #[cfg(feature = "rocksdb")]
-    pub(super) fn open_rocksdb(db: Arc<RwLock<DB>>, field: &str) -> OperationResult<Option<Self>> {
+    pub(super) fn open_rocksdb(
+        db: Arc<RwLock<DB>>,
+        field: &str,
+        histogram_config: NumericHistogramConfig,
+    ) -> OperationResult<Option<Self>> {
         use crate::index::field_index::numeric_index::mutable_numeric_index::MutableNumericIndex;
 
         let store_cf_name = super::numeric_index_storage_cf_name(field);
@@ -173,8 +179,11 @@ where
         ));
 
         // Load through mutable numeric index structure
-        let Some(mutable) =
-            MutableNumericIndex::<T>::open_rocksdb_db_wrapper(db_wrapper.clone(), false)?
+        let Some(mutable) = MutableNumericIndex::<T>::open_rocksdb_db_wrapper(
+            db_wrapper.clone(),
+            false,
+            histogram_config,
+        )?
         else {
             // Column family doesn't exist, cannot load
             return Ok(None);
@@ -314,6 +323,16 @@ where
         iterator.end_index - iterator.start_index
     }
 
+    /// Count values in range using binary search over the sorted values, as `(min, exp, max)`.
+    pub(super) fn values_range_count(
+        &self,
+        start_bound: Bound<Point<T>>,
+        end_bound: Bound<Point<T>>,
+    ) -> (usize, usize, usize) {
+        let count = self.values_range_size(start_bound, end_bound);
+        live_values_estimation(count, self.map.deleted_count, self.map.data.len())
+    }
+
     pub(super) fn values_range(
         &self,
         start_bound: Bound<Point<T>>,
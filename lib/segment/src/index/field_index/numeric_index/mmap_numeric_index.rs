@@ -1,3 +1,4 @@
+use std::cmp::min;
 use std::ops::Bound;
 use std::path::{Path, PathBuf};
 
@@ -14,8 +15,8 @@ use memory::mmap_ops::{self, create_and_ensure_length};
 use memory::mmap_type::{MmapBitSlice, MmapSlice};
 use serde::{Deserialize, Serialize};
 
-use super::Encodable;
 use super::mutable_numeric_index::InMemoryNumericIndex;
+use super::{Encodable, live_values_estimation};
 use crate::common::Flusher;
 use crate::common::mmap_bitslice_buffered_update_wrapper::MmapBitSliceBufferedUpdateWrapper;
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -330,6 +331,21 @@ impl<T: Encodable + Numericable + Default + MmapValue> MmapNumericIndex<T> {
         iter.end_index - iter.start_index
     }
 
+    /// Count values in range using binary search over the sorted pairs, as `(min, exp, max)`.
+    ///
+    /// Deleted points are only marked, so their values are still in the pairs array.
+    /// Their number is bounded by `max_values_per_point` per deleted point.
+    pub(super) fn values_range_count(
+        &self,
+        start_bound: Bound<Point<T>>,
+        end_bound: Bound<Point<T>>,
+    ) -> (usize, usize, usize) {
+        let count = self.values_range_size(start_bound, end_bound);
+        let total = self.storage.pairs.len();
+        let deleted = min(total, self.deleted_count * self.max_values_per_point);
+        live_values_estimation(count, deleted, total)
+    }
+
     // get iterator
     fn values_range_iterator(
         &self,
@@ -38,7 +38,9 @@ use super::mmap_point_to_values::MmapValue;
 use super::utils::{check_boundaries, value_to_integer};
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::index::field_index::histogram::{Histogram, Numericable};
+use crate::data_types::index::{DatetimeIndexParams, FloatIndexParams, IntegerIndexParams};
+use crate::data_types::quantiles::QuantileSketch;
+use crate::index::field_index::histogram::{self, Histogram, Numericable};
 use crate::index::field_index::stat_tools::estimate_multi_value_selection_cardinality;
 use crate::index::field_index::{
     CardinalityEstimation, PayloadBlockCondition, PayloadFieldIndex, PrimaryCondition, ValueIndexer,
@@ -57,6 +59,61 @@ use crate::types::{
 const HISTOGRAM_MAX_BUCKET_SIZE: usize = 10_000;
 const HISTOGRAM_PRECISION: f64 = 0.01;
 
+/// Histogram settings of the index, resolved from numeric index params
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericHistogramConfig {
+    /// Max number of values in a histogram bucket
+    pub max_bucket_size: usize,
+    /// Fraction of all values in a histogram bucket
+    pub precision: f64,
+}
+
+impl NumericHistogramConfig {
+    fn from_params(
+        histogram_buckets: Option<usize>,
+        histogram_max_bucket_size: Option<usize>,
+    ) -> Self {
+        let default = Self::default();
+        Self {
+            max_bucket_size: histogram_max_bucket_size.map_or(default.max_bucket_size, |size| {
+                // Histogram clamps bucket size to at least the minimal one
+                size.max(histogram::MIN_BUCKET_SIZE)
+            }),
+            precision: histogram_buckets.map_or(default.precision, |buckets| {
+                // Histogram requires precision in (0, 1)
+                1.0 / buckets.max(2) as f64
+            }),
+        }
+    }
+}
+
+impl Default for NumericHistogramConfig {
+    fn default() -> Self {
+        Self {
+            max_bucket_size: HISTOGRAM_MAX_BUCKET_SIZE,
+            precision: HISTOGRAM_PRECISION,
+        }
+    }
+}
+
+impl From<&IntegerIndexParams> for NumericHistogramConfig {
+    fn from(params: &IntegerIndexParams) -> Self {
+        Self::from_params(params.histogram_buckets, params.histogram_max_bucket_size)
+    }
+}
+
+impl From<&FloatIndexParams> for NumericHistogramConfig {
+    fn from(params: &FloatIndexParams) -> Self {
+        Self::from_params(params.histogram_buckets, params.histogram_max_bucket_size)
+    }
+}
+
+impl From<&DatetimeIndexParams> for NumericHistogramConfig {
+    fn from(params: &DatetimeIndexParams) -> Self {
+        Self::from_params(params.histogram_buckets, params.histogram_max_bucket_size)
+    }
+}
+
 pub trait StreamRange<T> {
     fn stream_range(
         &self,
@@ -184,14 +241,18 @@ where
         field: &str,
         is_appendable: bool,
         create_if_missing: bool,
+        histogram_config: NumericHistogramConfig,
     ) -> OperationResult<Option<Self>> {
         if is_appendable {
             Ok(
-                MutableNumericIndex::open_rocksdb(db, field, create_if_missing)?
+                MutableNumericIndex::open_rocksdb(db, field, create_if_missing, histogram_config)?
                     .map(NumericIndexInner::Mutable),
             )
         } else {
-            Ok(ImmutableNumericIndex::open_rocksdb(db, field)?.map(NumericIndexInner::Immutable))
+            Ok(
+                ImmutableNumericIndex::open_rocksdb(db, field, histogram_config)?
+                    .map(NumericIndexInner::Immutable),
+            )
         }
     }
 
@@ -213,9 +274,15 @@ where
         }
     }
 
-    pub fn new_gridstore(dir: PathBuf, create_if_missing: bool) -> OperationResult<Option<Self>> {
-        Ok(MutableNumericIndex::open_gridstore(dir, create_if_missing)?
-            .map(NumericIndexInner::Mutable))
+    pub fn new_gridstore(
+        dir: PathBuf,
+        create_if_missing: bool,
+        histogram_config: NumericHistogramConfig,
+    ) -> OperationResult<Option<Self>> {
+        Ok(
+            MutableNumericIndex::open_gridstore(dir, create_if_missing, histogram_config)?
+                .map(NumericIndexInner::Mutable),
+        )
     }
 
     fn get_histogram(&self) -> &Histogram<T> {
@@ -319,6 +386,31 @@ where
         }
     }
 
+    /// Estimate the number of values in range as `(min, exp, max)`.
+    ///
+    /// Immutable and mmap indexes keep values sorted, so the range is counted with two binary
+    /// searches instead of relying on the histogram.
+    fn estimate_values_in_range(&self, from: Bound<T>, to: Bound<T>) -> (usize, usize, usize) {
+        let start_bound = || match from {
+            Included(val) => Included(Point::new(val, PointOffsetType::MIN)),
+            Excluded(val) => Excluded(Point::new(val, PointOffsetType::MAX)),
+            Unbounded => Unbounded,
+        };
+        let end_bound = || match to {
+            Included(val) => Included(Point::new(val, PointOffsetType::MAX)),
+            Excluded(val) => Excluded(Point::new(val, PointOffsetType::MIN)),
+            Unbounded => Unbounded,
+        };
+
+        match self {
+            NumericIndexInner::Mutable(index) => index.get_histogram().estimate(from, to),
+            NumericIndexInner::Immutable(index) => {
+                index.values_range_count(start_bound(), end_bound())
+            }
+            NumericIndexInner::Mmap(index) => index.values_range_count(start_bound(), end_bound()),
+        }
+    }
+
     fn range_cardinality(&self, range: &RangeInterface) -> CardinalityEstimation {
         let max_values_per_point = self.max_values_per_point();
         if max_values_per_point == 0 {
@@ -348,7 +440,12 @@ where
             Unbounded
         };
 
-        let histogram_estimation = self.get_histogram().estimate(gbound, lbound);
+        // Bounds may be inverted, e.g. when relative datetime bounds resolve to an empty range
+        if !check_boundaries(&gbound, &lbound) {
+            return CardinalityEstimation::exact(0);
+        }
+
+        let histogram_estimation = self.estimate_values_in_range(gbound, lbound);
         let min_estimation = histogram_estimation.0;
         let max_estimation = histogram_estimation.2;
 
@@ -393,6 +490,9 @@ where
             points_count: self.get_points_count(),
             points_values_count: self.get_histogram().get_total_count(),
             histogram_bucket_size: Some(self.get_histogram().current_bucket_size()),
+            distinct_values: None,
+            full_text: None,
+            fragmentation: None,
             index_type: match self {
                 NumericIndexInner::Mutable(_) => "mutable_numeric",
                 NumericIndexInner::Immutable(_) => "immutable_numeric",
@@ -405,6 +505,57 @@ where
         self.values_count(idx) == 0
     }
 
+    /// Distribution of all values in the index, as buckets of the histogram
+    pub fn quantile_sketch(&self) -> QuantileSketch {
+        let mut sketch = QuantileSketch::default();
+        for (from, to, count) in self.get_histogram().buckets() {
+            sketch.add_bucket(from.to_f64(), to.to_f64(), count);
+        }
+        sketch
+    }
+
+    /// Distribution of values of the given points.
+    ///
+    /// Values are grouped by buckets of the histogram, each group is narrowed down
+    /// to the actual min and max value of the points in it.
+    pub fn points_quantile_sketch(
+        &self,
+        points: impl Iterator<Item = PointOffsetType>,
+    ) -> QuantileSketch {
+        let bucket_starts = self
+            .get_histogram()
+            .buckets()
+            .map(|(from, _, _)| from)
+            .collect::<Vec<_>>();
+
+        // Min, max and count of values in each bucket
+        let mut buckets: Vec<Option<(T, T, usize)>> = vec![None; bucket_starts.len().max(1)];
+        for idx in points {
+            let Some(values) = self.get_values(idx) else {
+                continue;
+            };
+            for value in values {
+                let bucket = bucket_starts
+                    .partition_point(|start| *start <= value)
+                    .saturating_sub(1);
+                buckets[bucket] = Some(match buckets[bucket] {
+                    None => (value, value, 1),
+                    Some((min, max, count)) => (
+                        Numericable::min(min, value),
+                        Numericable::max(max, value),
+                        count + 1,
+                    ),
+                });
+            }
+        }
+
+        let mut sketch = QuantileSketch::default();
+        for (min, max, count) in buckets.into_iter().flatten() {
+            sketch.add_bucket(min.to_f64(), max.to_f64(), count);
+        }
+        sketch
+    }
+
     pub fn point_ids_by_value<'a>(
         &'a self,
         value: T,
@@ -525,15 +676,19 @@ where
         field: &str,
         is_appendable: bool,
         create_if_missing: bool,
+        histogram_config: NumericHistogramConfig,
     ) -> OperationResult<Option<Self>> {
-        Ok(
-            NumericIndexInner::new_rocksdb(db, field, is_appendable, create_if_missing)?.map(
-                |inner| Self {
-                    inner,
-                    _phantom: PhantomData,
-                },
-            ),
-        )
+        Ok(NumericIndexInner::new_rocksdb(
+            db,
+            field,
+            is_appendable,
+            create_if_missing,
+            histogram_config,
+        )?
+        .map(|inner| Self {
+            inner,
+            _phantom: PhantomData,
+        }))
     }
 
     /// Load immutable mmap based index, either in RAM or on disk
@@ -546,8 +701,12 @@ where
         }))
     }
 
-    pub fn new_gridstore(dir: PathBuf, create_if_missing: bool) -> OperationResult<Option<Self>> {
-        let index = NumericIndexInner::new_gridstore(dir, create_if_missing)?;
+    pub fn new_gridstore(
+        dir: PathBuf,
+        create_if_missing: bool,
+        histogram_config: NumericHistogramConfig,
+    ) -> OperationResult<Option<Self>> {
+        let index = NumericIndexInner::new_gridstore(dir, create_if_missing, histogram_config)?;
 
         Ok(index.map(|inner| Self {
             inner,
@@ -559,12 +718,13 @@ where
     pub fn builder_rocksdb(
         db: Arc<RwLock<DB>>,
         field: &str,
+        histogram_config: NumericHistogramConfig,
     ) -> OperationResult<NumericIndexBuilder<T, P>>
     where
         Self: ValueIndexer<ValueType = P>,
     {
         Ok(NumericIndexBuilder(
-            Self::new_rocksdb(db, field, true, true)?.ok_or_else(|| {
+            Self::new_rocksdb(db, field, true, true, histogram_config)?.ok_or_else(|| {
                 OperationError::service_error(format!(
                     "Failed to create and load mutable numeric index builder for field '{field}'",
                 ))
@@ -576,37 +736,46 @@ where
     pub fn builder_rocksdb_immutable(
         db: Arc<RwLock<DB>>,
         field: &str,
+        histogram_config: NumericHistogramConfig,
     ) -> NumericIndexImmutableBuilder<T, P>
     where
         Self: ValueIndexer<ValueType = P>,
     {
         NumericIndexImmutableBuilder {
-            index: Self::new_rocksdb(db.clone(), field, true, true)
+            index: Self::new_rocksdb(db.clone(), field, true, true, histogram_config)
                 // unwrap safety: only used in testing
                 .unwrap()
                 .unwrap(),
             field: field.to_owned(),
             db,
+            histogram_config,
         }
     }
 
-    pub fn builder_mmap(path: &Path, is_on_disk: bool) -> NumericIndexMmapBuilder<T, P>
+    pub fn builder_mmap(
+        path: &Path,
+        is_on_disk: bool,
+        histogram_config: NumericHistogramConfig,
+    ) -> NumericIndexMmapBuilder<T, P>
     where
         Self: ValueIndexer<ValueType = P> + NumericIndexIntoInnerValue<T, P>,
     {
         NumericIndexMmapBuilder {
             path: path.to_owned(),
-            in_memory_index: InMemoryNumericIndex::default(),
+            in_memory_index: InMemoryNumericIndex::new(histogram_config),
             is_on_disk,
             _phantom: PhantomData,
         }
     }
 
-    pub fn builder_gridstore(dir: PathBuf) -> NumericIndexGridstoreBuilder<T, P>
+    pub fn builder_gridstore(
+        dir: PathBuf,
+        histogram_config: NumericHistogramConfig,
+    ) -> NumericIndexGridstoreBuilder<T, P>
     where
         Self: ValueIndexer<ValueType = P>,
     {
-        NumericIndexGridstoreBuilder::new(dir)
+        NumericIndexGridstoreBuilder::new(dir, histogram_config)
     }
 
     pub fn inner(&self) -> &NumericIndexInner<T> {
@@ -706,6 +875,7 @@ pub struct NumericIndexImmutableBuilder<
     index: NumericIndex<T, P>,
     field: String,
     db: Arc<RwLock<DB>>,
+    histogram_config: NumericHistogramConfig,
 }
 
 #[cfg(all(test, feature = "rocksdb"))]
@@ -737,10 +907,15 @@ where
     fn finalize(self) -> OperationResult<Self::FieldIndexType> {
         self.index.inner.flusher()()?;
         drop(self.index);
-        let inner: NumericIndexInner<T> =
-            NumericIndexInner::new_rocksdb(self.db, &self.field, false, false)?
-                // unwrap safety: only used in testing
-                .unwrap();
+        let inner: NumericIndexInner<T> = NumericIndexInner::new_rocksdb(
+            self.db,
+            &self.field,
+            false,
+            false,
+            self.histogram_config,
+        )?
+        // unwrap safety: only used in testing
+        .unwrap();
         Ok(NumericIndex {
             inner,
             _phantom: PhantomData,
@@ -814,6 +989,7 @@ pub struct NumericIndexGridstoreBuilder<
     Vec<T>: Blob,
 {
     dir: PathBuf,
+    histogram_config: NumericHistogramConfig,
     index: Option<NumericIndex<T, P>>,
 }
 
@@ -823,8 +999,12 @@ where
     NumericIndex<T, P>: ValueIndexer<ValueType = P>,
     Vec<T>: Blob,
 {
-    fn new(dir: PathBuf) -> Self {
-        Self { dir, index: None }
+    fn new(dir: PathBuf, histogram_config: NumericHistogramConfig) -> Self {
+        Self {
+            dir,
+            histogram_config,
+            index: None,
+        }
     }
 }
 
@@ -842,7 +1022,7 @@ where
             "index must be initialized exactly once",
         );
         self.index.replace(
-            NumericIndex::new_gridstore(self.dir.clone(), true)?
+            NumericIndex::new_gridstore(self.dir.clone(), true, self.histogram_config)?
                 // unwrap safety: cannot fail because create_if_missing is true
                 .unwrap(),
         );
@@ -1248,3 +1428,17 @@ where
 fn numeric_index_storage_cf_name(field: &str) -> String {
     format!("{field}_numeric")
 }
+
+/// Estimate the number of live values among `count` values of a sorted range, if up to
+/// `deleted` out of `total` stored values may belong to deleted points.
+///
+/// Returns `(min, exp, max)`, with deletions assumed to be spread evenly for `exp`.
+fn live_values_estimation(count: usize, deleted: usize, total: usize) -> (usize, usize, usize) {
+    if deleted == 0 || total == 0 {
+        return (count, count, count);
+    }
+    let min_count = count.saturating_sub(deleted);
+    let live_fraction = total.saturating_sub(deleted) as f64 / total as f64;
+    let exp_count = (count as f64 * live_fraction).round() as usize;
+    (min_count, exp_count.clamp(min_count, count), count)
+}
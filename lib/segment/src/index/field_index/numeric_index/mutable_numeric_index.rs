@@ -10,12 +10,14 @@ use common::types::PointOffsetType;
 use gridstore::config::StorageOptions;
 use gridstore::{Blob, Gridstore};
 #[cfg(feature = "rocksdb")]
+use itertools::Itertools;
+#[cfg(feature = "rocksdb")]
 use parking_lot::RwLock;
 #[cfg(feature = "rocksdb")]
 use rocksdb::DB;
 
 use super::mmap_numeric_index::MmapNumericIndex;
-use super::{Encodable, HISTOGRAM_MAX_BUCKET_SIZE, HISTOGRAM_PRECISION};
+use super::{Encodable, NumericHistogramConfig};
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
 #[cfg(feature = "rocksdb")]
@@ -69,9 +71,27 @@ pub struct InMemoryNumericIndex<T: Encodable + Numericable> {
 
 impl<T: Encodable + Numericable> Default for InMemoryNumericIndex<T> {
     fn default() -> Self {
+        Self::new(NumericHistogramConfig::default())
+    }
+}
+
+impl<T: Encodable + Numericable + Default> FromIterator<(PointOffsetType, T)>
+    for InMemoryNumericIndex<T>
+{
+    fn from_iter<I: IntoIterator<Item = (PointOffsetType, T)>>(iter: I) -> Self {
+        Self::from_iter_with_config(iter, NumericHistogramConfig::default())
+    }
+}
+
+impl<T: Encodable + Numericable> InMemoryNumericIndex<T> {
+    pub fn new(histogram_config: NumericHistogramConfig) -> Self {
+        let NumericHistogramConfig {
+            max_bucket_size,
+            precision,
+        } = histogram_config;
         Self {
             map: BTreeSet::new(),
-            histogram: Histogram::new(HISTOGRAM_MAX_BUCKET_SIZE, HISTOGRAM_PRECISION),
+            histogram: Histogram::new(max_bucket_size, precision),
             points_count: 0,
             max_values_per_point: 0,
             point_to_values: Default::default(),
@@ -79,11 +99,12 @@ impl<T: Encodable + Numericable> Default for InMemoryNumericIndex<T> {
     }
 }
 
-impl<T: Encodable + Numericable + Default> FromIterator<(PointOffsetType, T)>
-    for InMemoryNumericIndex<T>
-{
-    fn from_iter<I: IntoIterator<Item = (PointOffsetType, T)>>(iter: I) -> Self {
-        let mut index = InMemoryNumericIndex::default();
+impl<T: Encodable + Numericable + Default> InMemoryNumericIndex<T> {
+    pub fn from_iter_with_config(
+        iter: impl IntoIterator<Item = (PointOffsetType, T)>,
+        histogram_config: NumericHistogramConfig,
+    ) -> Self {
+        let mut index = InMemoryNumericIndex::new(histogram_config);
         for pair in iter {
             let (idx, value) = pair;
 
@@ -117,10 +138,17 @@ impl<T: Encodable + Numericable + Default + MmapValue> InMemoryNumericIndex<T> {
     pub(super) fn from_mmap(mmap_index: &MmapNumericIndex<T>) -> Self {
         let point_count = mmap_index.storage.point_to_values.len();
 
-        (0..point_count as PointOffsetType)
+        // Keep histogram settings the mmap index was built with
+        let histogram = mmap_index.get_histogram();
+        let histogram_config = NumericHistogramConfig {
+            max_bucket_size: histogram.max_bucket_size(),
+            precision: histogram.precision(),
+        };
+
+        let pairs = (0..point_count as PointOffsetType)
             .filter_map(|idx| mmap_index.get_values(idx).map(|values| (idx, values)))
-            .flat_map(|(idx, values)| values.into_iter().map(move |value| (idx, value)))
-            .collect::<InMemoryNumericIndex<T>>()
+            .flat_map(|(idx, values)| values.into_iter().map(move |value| (idx, value)));
+        Self::from_iter_with_config(pairs, histogram_config)
     }
 }
 
@@ -252,19 +280,21 @@ where
         db: Arc<RwLock<DB>>,
         field: &str,
         create_if_missing: bool,
+        histogram_config: NumericHistogramConfig,
     ) -> OperationResult<Option<Self>> {
         let store_cf_name = super::numeric_index_storage_cf_name(field);
         let db_wrapper = DatabaseColumnScheduledDeleteWrapper::new(DatabaseColumnWrapper::new(
             db,
             &store_cf_name,
         ));
-        Self::open_rocksdb_db_wrapper(db_wrapper, create_if_missing)
+        Self::open_rocksdb_db_wrapper(db_wrapper, create_if_missing, histogram_config)
     }
 
     #[cfg(feature = "rocksdb")]
     pub fn open_rocksdb_db_wrapper(
         db_wrapper: DatabaseColumnScheduledDeleteWrapper,
         create_if_missing: bool,
+        histogram_config: NumericHistogramConfig,
     ) -> OperationResult<Option<Self>> {
         if !db_wrapper.has_column_family()? {
             if create_if_missing {
@@ -292,7 +322,9 @@ where
                 }
                 Ok((idx, value))
             })
-            .collect::<Result<InMemoryNumericIndex<_>, OperationError>>()?;
+            .process_results(|pairs| {
+                InMemoryNumericIndex::from_iter_with_config(pairs, histogram_config)
+            })?;
 
         Ok(Some(Self {
             storage: Storage::RocksDb(db_wrapper),
@@ -305,7 +337,11 @@ where
     /// The `create_if_missing` parameter indicates whether to create a new Gridstore if it does
     /// not exist. If false and files don't exist, this will return `None` to indicate nothing
     /// could be loaded.
-    pub fn open_gridstore(path: PathBuf, create_if_missing: bool) -> OperationResult<Option<Self>> {
+    pub fn open_gridstore(
+        path: PathBuf,
+        create_if_missing: bool,
+        histogram_config: NumericHistogramConfig,
+    ) -> OperationResult<Option<Self>> {
         let store = if create_if_missing {
             let options = default_gridstore_options::<T>();
             Gridstore::open_or_create(path, options).map_err(|err| {
@@ -325,7 +361,7 @@ where
         };
 
         // Load in-memory index from Gridstore
-        let mut in_memory_index = InMemoryNumericIndex::default();
+        let mut in_memory_index = InMemoryNumericIndex::new(histogram_config);
         let hw_counter = HardwareCounterCell::disposable();
         let hw_counter_ref = hw_counter.ref_payload_index_io_write_counter();
         store
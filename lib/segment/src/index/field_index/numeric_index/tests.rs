@@ -63,6 +63,13 @@ impl IndexBuilder {
 }
 
 fn get_index_builder(index_type: IndexType) -> (TempDir, IndexBuilder) {
+    get_index_builder_with_config(index_type, NumericHistogramConfig::default())
+}
+
+fn get_index_builder_with_config(
+    index_type: IndexType,
+    histogram_config: NumericHistogramConfig,
+) -> (TempDir, IndexBuilder) {
     let temp_dir = Builder::new()
         .prefix("test_numeric_index")
         .tempdir()
@@ -72,27 +79,34 @@ fn get_index_builder(index_type: IndexType) -> (TempDir, IndexBuilder) {
     let mut builder = match index_type {
         #[cfg(feature = "rocksdb")]
         IndexType::Mutable => IndexBuilder::Mutable(
-            NumericIndex::<FloatPayloadType, FloatPayloadType>::builder_rocksdb(db, COLUMN_NAME)
-                .unwrap(),
+            NumericIndex::<FloatPayloadType, FloatPayloadType>::builder_rocksdb(
+                db,
+                COLUMN_NAME,
+                histogram_config,
+            )
+            .unwrap(),
         ),
         IndexType::MutableGridstore => IndexBuilder::MutableGridstore(NumericIndex::<
             FloatPayloadType,
             FloatPayloadType,
         >::builder_gridstore(
             temp_dir.path().to_path_buf(),
+            histogram_config,
         )),
         #[cfg(feature = "rocksdb")]
         IndexType::Immutable => IndexBuilder::Immutable(NumericIndex::<
             FloatPayloadType,
             FloatPayloadType,
         >::builder_rocksdb_immutable(
-            db, COLUMN_NAME
+            db, COLUMN_NAME, histogram_config
         )),
         IndexType::Mmap | IndexType::RamMmap => IndexBuilder::Mmap(NumericIndex::<
             FloatPayloadType,
             FloatPayloadType,
         >::builder_mmap(
-            temp_dir.path(), false
+            temp_dir.path(),
+            false,
+            histogram_config,
         )),
     };
     match &mut builder {
@@ -393,14 +407,19 @@ fn test_numeric_index_load_from_disk(#[case] index_type: IndexType) {
 
     let new_index = match index_type {
         #[cfg(feature = "rocksdb")]
-        IndexType::Mutable => {
-            NumericIndexInner::<FloatPayloadType>::new_rocksdb(db.unwrap(), COLUMN_NAME, true, true)
-                .unwrap()
-                .unwrap()
-        }
+        IndexType::Mutable => NumericIndexInner::<FloatPayloadType>::new_rocksdb(
+            db.unwrap(),
+            COLUMN_NAME,
+            true,
+            true,
+            NumericHistogramConfig::default(),
+        )
+        .unwrap()
+        .unwrap(),
         IndexType::MutableGridstore => NumericIndexInner::<FloatPayloadType>::new_gridstore(
             temp_dir.path().to_path_buf(),
             true,
+            NumericHistogramConfig::default(),
         )
         .unwrap()
         .unwrap(),
@@ -410,6 +429,7 @@ fn test_numeric_index_load_from_disk(#[case] index_type: IndexType) {
             COLUMN_NAME,
             false,
             true,
+            NumericHistogramConfig::default(),
         )
         .unwrap()
         .unwrap(),
@@ -638,3 +658,131 @@ fn test_empty_cardinality(#[case] index_type: IndexType) {
         HwMeasurementAcc::new(),
     );
 }
+
+#[rstest]
+#[cfg_attr(feature = "rocksdb", case(IndexType::Mutable))]
+#[case(IndexType::MutableGridstore)]
+#[cfg_attr(feature = "rocksdb", case(IndexType::Immutable))]
+#[case(IndexType::Mmap)]
+#[case(IndexType::RamMmap)]
+fn test_inverted_range_cardinality(#[case] index_type: IndexType) {
+    let (_temp_dir, index) = random_index(1000, 2, index_type);
+
+    let estimation = cardinality_request(
+        &index,
+        Range {
+            lt: Some(10.0),
+            gt: None,
+            gte: Some(60.0),
+            lte: None,
+        },
+        HwMeasurementAcc::new(),
+    );
+    assert_eq!(estimation.max, 0);
+}
+
+#[rstest]
+#[cfg_attr(feature = "rocksdb", case(IndexType::Immutable))]
+#[case(IndexType::Mmap)]
+#[case(IndexType::RamMmap)]
+fn test_sorted_range_cardinality(#[case] index_type: IndexType) {
+    let (_temp_dir, mut index) = random_index(1000, 1, index_type);
+
+    let query = Range {
+        lt: Some(60.0),
+        gt: None,
+        gte: Some(10.0),
+        lte: None,
+    };
+
+    // Values are counted by binary search, so a single value per point gives exact estimation
+    let estimation = cardinality_request(&index, query, HwMeasurementAcc::new());
+    assert_eq!(estimation.min, estimation.max);
+    assert_eq!(estimation.exp, estimation.max);
+
+    for idx in (0..1000).step_by(7) {
+        index.remove_point(idx).unwrap();
+    }
+
+    // Deleted values are still in sorted storage, the estimation must bound them
+    let estimation_after_delete = cardinality_request(&index, query, HwMeasurementAcc::new());
+    assert!(estimation_after_delete.max <= estimation.max);
+    assert!(estimation_after_delete.exp < estimation.exp);
+}
+
+#[rstest]
+#[cfg_attr(feature = "rocksdb", case(IndexType::Mutable))]
+#[case(IndexType::MutableGridstore)]
+#[cfg_attr(feature = "rocksdb", case(IndexType::Immutable))]
+#[case(IndexType::Mmap)]
+fn test_quantile_sketch(#[case] index_type: IndexType) {
+    let num_points = 1000;
+    let histogram_config = NumericHistogramConfig::from_params(Some(20), None);
+    let (_temp_dir, mut index_builder) =
+        get_index_builder_with_config(index_type, histogram_config);
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let hw_counter = HardwareCounterCell::new();
+    let mut values = Vec::with_capacity(num_points);
+    for i in 0..num_points {
+        let value = rng.random_range(0.0..100.0);
+        values.push(value);
+        index_builder
+            .add_point(i as PointOffsetType, &[&Value::from(value)], &hw_counter)
+            .unwrap();
+    }
+    let index = index_builder.finalize().unwrap();
+
+    // 20 buckets of 1000 values
+    assert_eq!(index.inner().get_histogram().current_bucket_size(), 50);
+
+    let quantiles = [0.05, 0.5, 0.95];
+    let check_quantiles = |sketch: QuantileSketch, mut expected_values: Vec<f64>| {
+        assert_eq!(sketch.count(), expected_values.len());
+        expected_values.sort_by(|a, b| a.total_cmp(b));
+        let estimates = sketch.estimate(&quantiles);
+        for (quantile, estimate) in quantiles.iter().zip(estimates) {
+            let exact =
+                expected_values[(quantile * (expected_values.len() - 1) as f64).round() as usize];
+            let estimate = estimate.unwrap();
+            // Error is bounded by the width of a bucket, up to twice the target size
+            assert!(
+                (estimate - exact).abs() < 10.0,
+                "quantile {quantile}: estimate {estimate}, exact {exact}",
+            );
+        }
+    };
+
+    check_quantiles(index.inner().quantile_sketch(), values.clone());
+
+    let even_points = (0..num_points as PointOffsetType).step_by(2);
+    let even_values = values.iter().copied().step_by(2).collect_vec();
+    check_quantiles(
+        index.inner().points_quantile_sketch(even_points),
+        even_values,
+    );
+}
+
+#[rstest]
+#[cfg_attr(feature = "rocksdb", case(IndexType::Mutable))]
+#[case(IndexType::MutableGridstore)]
+#[case(IndexType::Mmap)]
+fn test_histogram_max_bucket_size(#[case] index_type: IndexType) {
+    let num_points = 1000;
+    let histogram_config = NumericHistogramConfig::from_params(Some(2), Some(100));
+    let (_temp_dir, mut index_builder) =
+        get_index_builder_with_config(index_type, histogram_config);
+
+    let hw_counter = HardwareCounterCell::new();
+    for i in 0..num_points {
+        index_builder
+            .add_point(i as PointOffsetType, &[&Value::from(i as f64)], &hw_counter)
+            .unwrap();
+    }
+    let index = index_builder.finalize().unwrap();
+
+    // 2 buckets of 1000 values, but limited to 100 values each
+    let histogram = index.inner().get_histogram();
+    assert_eq!(histogram.max_bucket_size(), 100);
+    assert_eq!(histogram.current_bucket_size(), 100);
+}
@@ -4,10 +4,12 @@ use io::file_operations::{atomic_save_json, read_json};
 use serde::{Deserialize, Serialize};
 
 use crate::common::operation_error::OperationResult;
+use crate::telemetry::HnswBuildStats;
+use crate::types::{HnswConfig, HnswNeighborSelection};
 
 pub const HNSW_INDEX_CONFIG_FILE: &str = "hnsw_config.json";
 
-#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq)]
 pub struct HnswGraphConfig {
     pub m: usize,
     /// Requested M
@@ -29,20 +31,40 @@ pub struct HnswGraphConfig {
     pub payload_m0: Option<usize>,
     #[serde(default)]
     pub indexed_vector_count: Option<usize>,
+    /// Number of neighbours to search on construction on levels above 0.
+    /// If not set, `ef_construct` is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ef_construct_upper: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neighbor_selection: Option<HnswNeighborSelection>,
+    /// Statistics of the last graph build, absent for indexes built by older versions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_stats: Option<HnswBuildStats>,
 }
 
 impl HnswGraphConfig {
     pub fn new(
-        m: usize,
-        ef_construct: usize,
+        hnsw_config: &HnswConfig,
         full_scan_threshold: usize,
-        max_indexing_threads: usize,
-        payload_m: Option<usize>,
         indexed_vector_count: usize,
     ) -> Self {
+        let HnswConfig {
+            m,
+            ef_construct,
+            full_scan_threshold: _, // converted from KiloBytes by the caller
+            max_indexing_threads,
+            on_disk: _,
+            payload_m,
+            inline_storage: _,
+            varint_links: _,
+            m0,
+            ef_construct_upper,
+            neighbor_selection,
+        } = *hnsw_config;
+
         HnswGraphConfig {
             m,
-            m0: m * 2,
+            m0: m0.unwrap_or(m * 2),
             ef_construct,
             ef: ef_construct,
             full_scan_threshold,
@@ -50,6 +72,9 @@ impl HnswGraphConfig {
             payload_m,
             payload_m0: payload_m.map(|v| v * 2),
             indexed_vector_count: Some(indexed_vector_count),
+            ef_construct_upper,
+            neighbor_selection,
+            build_stats: None,
         }
     }
 
@@ -26,6 +26,7 @@
 
 use std::borrow::Cow;
 use std::cmp::max;
+use std::io::Write;
 use std::ops::ControlFlow;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
@@ -44,10 +45,13 @@ use crate::common::operation_error::{
     CancellableResult, OperationError, OperationResult, check_process_stopped,
 };
 use crate::common::utils::rev_range;
+use crate::data_types::hnsw_graph::{self, HnswGraphHeader, HnswGraphNode};
 use crate::index::hnsw_index::graph_links::{GraphLinksFormatParam, serialize_graph_links};
 use crate::index::hnsw_index::point_scorer::{FilteredBytesScorer, FilteredScorer, ScorerFilters};
 use crate::index::hnsw_index::search_context::SearchContext;
+use crate::index::query_arena::get_query_buffers;
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
+use crate::types::{PointIdType, VectorNameBuf};
 use crate::vector_storage::RawScorer;
 use crate::vector_storage::query_scorer::QueryScorerBytes;
 
@@ -59,6 +63,7 @@ pub const HNSW_GRAPH_FILE: &str = "graph.bin";
 pub const HNSW_LINKS_FILE: &str = "links.bin";
 pub const COMPRESSED_HNSW_LINKS_FILE: &str = "links_compressed.bin";
 pub const COMPRESSED_WITH_VECTORS_HNSW_LINKS_FILE: &str = "links_comp_vec.bin";
+pub const COMPRESSED_VARINT_HNSW_LINKS_FILE: &str = "links_comp_varint.bin";
 
 /// Contents of the `graph.bin` file.
 #[derive(Deserialize, Serialize, Debug)]
@@ -116,7 +121,10 @@ pub trait GraphLayersBase {
         let mut visited_list = self.get_visited_list_from_pool();
         visited_list.check_and_update_visited(level_entry.idx);
 
-        let mut search_context = SearchContext::new(ef);
+        let mut query_buffers = get_query_buffers();
+        let mut search_context =
+            SearchContext::with_candidates(ef, query_buffers.take_candidates())
+                .with_score_threshold(points_scorer.score_threshold());
         search_context.process_candidate(level_entry);
 
         let limit = self.get_m(level);
@@ -144,6 +152,7 @@ pub trait GraphLayersBase {
                 });
         }
 
+        query_buffers.return_candidates(search_context.candidates);
         Ok(search_context.nearest)
     }
 
@@ -171,7 +180,10 @@ pub trait GraphLayersBase {
         // 1-hop neighbor.
         let mut hop2_visited_list = self.get_visited_list_from_pool();
 
-        let mut search_context = SearchContext::new(ef);
+        let mut query_buffers = get_query_buffers();
+        let mut search_context =
+            SearchContext::with_candidates(ef, query_buffers.take_candidates())
+                .with_score_threshold(points_scorer.score_threshold());
         search_context.process_candidate(level_entry);
 
         // Limits are per every explored 1-hop or 2-hop neighbors, not total.
@@ -239,6 +251,7 @@ pub trait GraphLayersBase {
                 .for_each(|score_point| search_context.process_candidate(score_point));
         }
 
+        query_buffers.return_candidates(search_context.candidates);
         Ok(search_context.nearest)
     }
 
@@ -606,6 +619,7 @@ impl GraphLayers {
             GraphLinksFormat::CompressedWithVectors => {
                 path.join(COMPRESSED_WITH_VECTORS_HNSW_LINKS_FILE)
             }
+            GraphLinksFormat::CompressedVarint => path.join(COMPRESSED_VARINT_HNSW_LINKS_FILE),
         }
     }
 
@@ -619,6 +633,43 @@ impl GraphLayers {
     pub fn num_points(&self) -> usize {
         self.links.num_points()
     }
+
+    /// Write the graph in the export format of [`crate::data_types::hnsw_graph`]
+    pub fn export(
+        &self,
+        segment_id: String,
+        vector_name: VectorNameBuf,
+        external_id: impl Fn(PointOffsetType) -> Option<PointIdType>,
+        writer: &mut dyn Write,
+    ) -> OperationResult<()> {
+        let entry_point = self.entry_points.get_entry_point(|_| true);
+
+        let header = HnswGraphHeader {
+            segment_id,
+            vector_name,
+            num_points: self.num_points(),
+            m: self.hnsw_m.m,
+            m0: self.hnsw_m.m0,
+            entry_point: entry_point.as_ref().map(|entry| entry.point_id),
+            max_level: entry_point.map_or(0, |entry| entry.level),
+        };
+        hnsw_graph::write_line(writer, &header)?;
+
+        for offset in 0..self.num_points() as PointOffsetType {
+            let level = self.point_level(offset);
+            let node = HnswGraphNode {
+                offset,
+                id: external_id(offset),
+                level,
+                links: (0..=level)
+                    .map(|level| self.links.links(offset, level).collect())
+                    .collect(),
+            };
+            hnsw_graph::write_line(writer, &node)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl GraphLayers {
@@ -640,6 +691,7 @@ impl GraphLayers {
     fn load_links(dir: &Path, on_disk: bool) -> OperationResult<GraphLinks> {
         for format in [
             GraphLinksFormat::CompressedWithVectors,
+            GraphLinksFormat::CompressedVarint,
             GraphLinksFormat::Compressed,
             GraphLinksFormat::Plain,
         ] {
@@ -661,8 +713,12 @@ impl GraphLayers {
         let compressed_path = Self::get_links_path(dir, GraphLinksFormat::Compressed);
         let compressed_with_vectors_path =
             Self::get_links_path(dir, GraphLinksFormat::CompressedWithVectors);
+        let compressed_varint_path = Self::get_links_path(dir, GraphLinksFormat::CompressedVarint);
 
-        if compressed_path.exists() || compressed_with_vectors_path.exists() {
+        if compressed_path.exists()
+            || compressed_with_vectors_path.exists()
+            || compressed_varint_path.exists()
+        {
             return Ok(());
         }
 
@@ -756,6 +812,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::compressed_varint(GraphLinksFormat::CompressedVarint)]
     fn test_search_on_level(#[case] format: GraphLinksFormat) {
         let dim = 8;
         let hnsw_m = HnswM::new2(8);
@@ -818,6 +875,7 @@ mod tests {
     #[case::compressed((GraphLinksFormat::Compressed, false))]
     #[case::recompressed((GraphLinksFormat::Compressed, true))]
     #[case::compressed_with_vectors((GraphLinksFormat::CompressedWithVectors, false))]
+    #[case::compressed_varint((GraphLinksFormat::CompressedVarint, false))]
     fn test_save_and_load(#[case] (initial_format, compress): (GraphLinksFormat, bool)) {
         let distance = Distance::Cosine;
         let num_vectors = 100;
@@ -866,6 +924,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::compressed_varint(GraphLinksFormat::CompressedVarint)]
     fn test_add_points(#[case] format: GraphLinksFormat) {
         type M = CosineMetric;
         let distance = <M as Metric<VectorElementType>>::distance();
@@ -921,4 +980,67 @@ mod tests {
 
         assert_eq!(reference_top.into_sorted_vec(), graph_search);
     }
+
+    #[rstest]
+    #[case::uncompressed(GraphLinksFormat::Plain)]
+    #[case::compressed(GraphLinksFormat::Compressed)]
+    fn test_export(#[case] format: GraphLinksFormat) {
+        let num_vectors = 100;
+        let dim = 8;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let (_vector_holder, graph_layers) = create_graph_layer_fixture(
+            num_vectors,
+            M,
+            dim,
+            format,
+            false,
+            false,
+            Distance::Cosine,
+            &mut rng,
+        );
+
+        let mut exported = Vec::new();
+        graph_layers
+            .export(
+                "segment".to_string(),
+                "vector".to_string(),
+                |offset| Some(PointIdType::NumId(u64::from(offset) + 1000)),
+                &mut exported,
+            )
+            .unwrap();
+
+        let mut lines = exported
+            .split(|&byte| byte == b'\n')
+            .filter(|line| !line.is_empty());
+
+        let header: HnswGraphHeader = serde_json::from_slice(lines.next().unwrap()).unwrap();
+        let main_entry = graph_layers.entry_points.get_entry_point(|_| true).unwrap();
+        assert_eq!(header.segment_id, "segment");
+        assert_eq!(header.vector_name, "vector");
+        assert_eq!(header.num_points, num_vectors);
+        assert_eq!((header.m, header.m0), (M, M * 2));
+        assert_eq!(header.entry_point, Some(main_entry.point_id));
+        assert_eq!(header.max_level, main_entry.level);
+
+        let nodes: Vec<HnswGraphNode> = lines
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+        assert_eq!(nodes.len(), num_vectors);
+
+        for (offset, node) in nodes.iter().enumerate() {
+            let offset = offset as PointOffsetType;
+            assert_eq!(node.offset, offset);
+            assert_eq!(node.id, Some(PointIdType::NumId(u64::from(offset) + 1000)));
+            assert_eq!(node.level, graph_layers.point_level(offset));
+            assert_eq!(node.links.len(), node.level + 1);
+            for (level, links) in node.links.iter().enumerate() {
+                assert_eq!(
+                    links,
+                    &graph_layers.links.links(offset, level).collect::<Vec<_>>(),
+                );
+            }
+        }
+    }
 }
@@ -36,10 +36,14 @@ pub struct GraphLayersBuilder {
     max_level: AtomicUsize,
     hnsw_m: HnswM,
     ef_construct: usize,
+    // Same as `ef_construct`, but for levels above 0
+    ef_construct_upper: usize,
     // Factor of level probability
     level_factor: f64,
     // Exclude points according to "not closer than base" heuristic?
     use_heuristic: bool,
+    // Fill free links of a new point with candidates excluded by the heuristic?
+    keep_pruned: bool,
     links_layers: Vec<LockedLayersContainer>,
     entry_points: Mutex<EntryPoints>,
 
@@ -306,8 +310,10 @@ impl GraphLayersBuilder {
             max_level: AtomicUsize::new(0),
             hnsw_m,
             ef_construct,
+            ef_construct_upper: ef_construct,
             level_factor: 1.0 / (max(hnsw_m.m, 2) as f64).ln(),
             use_heuristic,
+            keep_pruned: false,
             links_layers,
             entry_points: Mutex::new(EntryPoints::new(entry_points_num)),
             visited_pool: VisitedPool::new(),
@@ -332,6 +338,17 @@ impl GraphLayersBuilder {
         )
     }
 
+    /// Use a different `ef_construct` for levels above 0.
+    pub fn set_ef_construct_upper(&mut self, ef_construct_upper: usize) {
+        self.ef_construct_upper = ef_construct_upper;
+    }
+
+    /// Fill free links of a new point with the closest candidates excluded by the heuristic.
+    /// Has no effect if the heuristic is not used.
+    pub fn set_keep_pruned(&mut self, keep_pruned: bool) {
+        self.keep_pruned = keep_pruned;
+    }
+
     pub fn merge_from_other(&mut self, other: GraphLayersBuilder) {
         self.max_level = AtomicUsize::new(max(
             self.max_level.load(std::sync::atomic::Ordering::Relaxed),
@@ -495,11 +512,16 @@ impl GraphLayersBuilder {
         points_scorer: &mut FilteredScorer,
         mut level_entry: ScoredPointOffset,
     ) -> ScoredPointOffset {
+        let ef_construct = if curr_level == 0 {
+            self.ef_construct
+        } else {
+            self.ef_construct_upper
+        };
         let nearest = self
             .search_on_level(
                 level_entry,
                 curr_level,
-                self.ef_construct,
+                ef_construct,
                 points_scorer,
                 &AtomicBool::new(false),
             )
@@ -529,9 +551,16 @@ impl GraphLayersBuilder {
         let scorer = |a, b| points_scorer.score_internal(a, b);
 
         let selected_nearest = {
-            let iter = nearest.into_iter_sorted();
+            let candidates = nearest.into_sorted_vec();
             let mut existing_links = self.links_layers[point_id as usize][curr_level].write();
-            existing_links.fill_from_sorted_with_heuristic(iter, level_m, scorer);
+            existing_links.fill_from_sorted_with_heuristic(
+                candidates.iter().copied(),
+                level_m,
+                scorer,
+            );
+            if self.keep_pruned {
+                existing_links.fill_from_sorted_keep_pruned(candidates.into_iter(), level_m);
+            }
             existing_links.links().to_vec()
         };
 
@@ -700,6 +729,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::compressed_varint(GraphLinksFormat::CompressedVarint)]
     fn test_parallel_graph_build(#[case] format: GraphLinksFormat) {
         let distance = Distance::Cosine;
         let num_vectors = 1000;
@@ -781,6 +811,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::compressed_varint(GraphLinksFormat::CompressedVarint)]
     fn test_add_points(#[case] format: GraphLinksFormat) {
         let distance = Distance::Cosine;
         let num_vectors = 1000;
@@ -824,7 +855,9 @@ mod tests {
             let link_container_from_builder = links_builder.links().to_vec();
             let m = match format {
                 GraphLinksFormat::Plain => 0,
-                GraphLinksFormat::Compressed | GraphLinksFormat::CompressedWithVectors => M * 2,
+                GraphLinksFormat::Compressed
+                | GraphLinksFormat::CompressedWithVectors
+                | GraphLinksFormat::CompressedVarint => M * 2,
             };
             assert_eq!(
                 normalize_links(m, links_orig.clone()),
@@ -893,6 +926,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::compressed_varint(GraphLinksFormat::CompressedVarint)]
     fn test_hnsw_graph_properties(#[case] format: GraphLinksFormat) {
         const NUM_VECTORS: usize = 5_000;
         const DIM: usize = 16;
@@ -58,6 +58,9 @@ pub enum GraphLinksFormat {
     Plain,
     Compressed,
     CompressedWithVectors,
+    /// Like `Compressed`, but links are delta + varint encoded instead of
+    /// bitpacked.
+    CompressedVarint,
 }
 
 /// Similar to [`GraphLinksFormat`], won't let you use `CompressedWithVectors`
@@ -67,6 +70,7 @@ pub enum GraphLinksFormatParam<'a> {
     Plain,
     Compressed,
     CompressedWithVectors(&'a dyn GraphLinksVectors),
+    CompressedVarint,
 }
 
 /// This trait lets the [`serialize_graph_links`] to access vector values.
@@ -151,6 +155,7 @@ impl GraphLinksFormat {
         match self {
             GraphLinksFormat::Plain => GraphLinksFormatParam::Plain,
             GraphLinksFormat::Compressed => GraphLinksFormatParam::Compressed,
+            GraphLinksFormat::CompressedVarint => GraphLinksFormatParam::CompressedVarint,
             GraphLinksFormat::CompressedWithVectors => match vectors {
                 Some(v) => GraphLinksFormatParam::CompressedWithVectors(v),
                 None => panic!(),
@@ -169,6 +174,7 @@ impl GraphLinksFormat {
         match self {
             GraphLinksFormat::Plain => GraphLinksFormatParam::Plain,
             GraphLinksFormat::Compressed => GraphLinksFormatParam::Compressed,
+            GraphLinksFormat::CompressedVarint => GraphLinksFormatParam::CompressedVarint,
             GraphLinksFormat::CompressedWithVectors => match vectors {
                 Some(v) => GraphLinksFormatParam::CompressedWithVectors(v),
                 None => GraphLinksFormatParam::Compressed,
@@ -178,7 +184,9 @@ impl GraphLinksFormat {
 
     pub fn is_with_vectors(&self) -> bool {
         match self {
-            GraphLinksFormat::Plain | GraphLinksFormat::Compressed => false,
+            GraphLinksFormat::Plain
+            | GraphLinksFormat::Compressed
+            | GraphLinksFormat::CompressedVarint => false,
             GraphLinksFormat::CompressedWithVectors => true,
         }
     }
@@ -192,6 +200,7 @@ impl<'a> GraphLinksFormatParam<'a> {
             GraphLinksFormatParam::CompressedWithVectors(_) => {
                 GraphLinksFormat::CompressedWithVectors
             }
+            GraphLinksFormatParam::CompressedVarint => GraphLinksFormat::CompressedVarint,
         }
     }
 }
@@ -263,6 +272,7 @@ impl GraphLinks {
             CompressionInfo::CompressedWithVectors { .. } => {
                 GraphLinksFormat::CompressedWithVectors
             }
+            CompressionInfo::CompressedVarint { .. } => GraphLinksFormat::CompressedVarint,
         }
     }
 
@@ -462,6 +472,7 @@ mod tests {
     #[rstest]
     #[case::plain(GraphLinksFormat::Plain, 8, 8)]
     #[case::compressed(GraphLinksFormat::Compressed, 8, 8)]
+    #[case::compressed_varint(GraphLinksFormat::CompressedVarint, 8, 8)]
     #[case::comp_vec_1_16(GraphLinksFormat::CompressedWithVectors, 1, 16)]
     #[case::comp_vec_4_1(GraphLinksFormat::CompressedWithVectors, 4, 1)]
     #[case::comp_vec_4_16(GraphLinksFormat::CompressedWithVectors, 4, 16)]
@@ -496,6 +507,7 @@ mod tests {
     #[case::uncompressed(GraphLinksFormat::Plain)]
     #[case::compressed(GraphLinksFormat::Compressed)]
     #[case::compressed_with_vectors(GraphLinksFormat::CompressedWithVectors)]
+    #[case::compressed_varint(GraphLinksFormat::CompressedVarint)]
     fn test_graph_links_construction(#[case] format: GraphLinksFormat) {
         let hnsw_m = HnswM::new2(8);
 
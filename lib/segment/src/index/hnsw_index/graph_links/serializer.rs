@@ -6,6 +6,7 @@ use common::bitpacking::packed_bits;
 use common::bitpacking_links::{MIN_BITS_PER_VALUE, pack_links};
 use common::bitpacking_ordered;
 use common::types::PointOffsetType;
+use common::varint_links::pack_varint_links;
 use common::zeros::WriteZerosExt;
 use integer_encoding::{VarInt, VarIntWriter};
 use itertools::Either;
@@ -13,7 +14,9 @@ use zerocopy::IntoBytes as AsBytes;
 use zerocopy::little_endian::U64 as LittleU64;
 
 use super::GraphLinksFormatParam;
-use super::header::{HEADER_VERSION_COMPRESSED, HeaderCompressed, HeaderPlain};
+use super::header::{
+    HEADER_VERSION_COMPRESSED, HEADER_VERSION_COMPRESSED_VARINT, HeaderCompressed, HeaderPlain,
+};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::hnsw_index::HnswM;
 use crate::index::hnsw_index::graph_links::header::{
@@ -32,6 +35,7 @@ pub fn serialize_graph_links<W: Write + Seek>(
     let vectors_layout = match format_param {
         GraphLinksFormatParam::Plain => None,
         GraphLinksFormatParam::Compressed => None,
+        GraphLinksFormatParam::CompressedVarint => None,
         GraphLinksFormatParam::CompressedWithVectors(v) => {
             let vectors_layout = v.vectors_layout();
             if vectors_layout.base.size() % vectors_layout.base.align() != 0 {
@@ -62,7 +66,9 @@ pub fn serialize_graph_links<W: Write + Seek>(
     // 1. Write header (placeholder, will be rewritten later)
     writer.write_zeros(match &format_param {
         GraphLinksFormatParam::Plain => size_of::<HeaderPlain>(),
-        GraphLinksFormatParam::Compressed => size_of::<HeaderCompressed>(),
+        GraphLinksFormatParam::Compressed | GraphLinksFormatParam::CompressedVarint => {
+            size_of::<HeaderCompressed>()
+        }
         GraphLinksFormatParam::CompressedWithVectors(_) => size_of::<HeaderCompressedWithVectors>(),
     })?;
 
@@ -96,7 +102,7 @@ pub fn serialize_graph_links<W: Write + Seek>(
 
     // 5. Write neighbors (and calculate `offsets`)
     let mut links_buf = Vec::new();
-    let mut offset = 0; // elements for Plain, bytes for Compressed/CompressedWithVectors
+    let mut offset = 0; // elements for Plain, bytes for other formats
     let mut offsets = Vec::with_capacity(total_offsets_len as usize);
     offsets.push(0);
 
@@ -121,6 +127,11 @@ pub fn serialize_graph_links<W: Write + Seek>(
                     writer.write_all(&links_buf)?;
                     offset += links_buf.len();
                 }
+                GraphLinksFormatParam::CompressedVarint => {
+                    pack_varint_links(&mut links_buf, &mut raw_links, level_m);
+                    writer.write_all(&links_buf)?;
+                    offset += links_buf.len();
+                }
                 GraphLinksFormatParam::CompressedWithVectors(vectors) => {
                     // Unwrap safety: `vectors_layout` is `Some` for `CompressedWithVectors`.
                     let vectors_layout = vectors_layout.as_ref().unwrap();
@@ -184,7 +195,9 @@ pub fn serialize_graph_links<W: Write + Seek>(
             writer.write_all(offsets.as_bytes())?;
             (Some(offsets_padding), None)
         }
-        GraphLinksFormatParam::Compressed | GraphLinksFormatParam::CompressedWithVectors(_) => {
+        GraphLinksFormatParam::Compressed
+        | GraphLinksFormatParam::CompressedWithVectors(_)
+        | GraphLinksFormatParam::CompressedVarint => {
             let (compressed_offsets, offsets_parameters) = bitpacking_ordered::compress(&offsets);
             writer.write_all(&compressed_offsets)?;
             (None, Some(offsets_parameters))
@@ -205,9 +218,13 @@ pub fn serialize_graph_links<W: Write + Seek>(
             };
             writer.write_all(header.as_bytes())?;
         }
-        GraphLinksFormatParam::Compressed => {
+        GraphLinksFormatParam::Compressed | GraphLinksFormatParam::CompressedVarint => {
+            let version = match format_param {
+                GraphLinksFormatParam::CompressedVarint => HEADER_VERSION_COMPRESSED_VARINT,
+                _ => HEADER_VERSION_COMPRESSED,
+            };
             let header = HeaderCompressed {
-                version: LittleU64::from(HEADER_VERSION_COMPRESSED),
+                version: LittleU64::from(version),
                 point_count: LittleU64::new(edges.len() as u64),
                 total_neighbors_bytes: LittleU64::new(offset as u64),
                 offsets_parameters: offsets_parameters.unwrap(),
@@ -8,13 +8,16 @@ use common::bitpacking_links::{
 };
 use common::bitpacking_ordered;
 use common::types::PointOffsetType;
+use common::varint_links::{VarintLinksIterator, iterate_varint_links};
 use integer_encoding::VarInt as _;
 use itertools::{Either, Itertools as _};
 use zerocopy::native_endian::U64 as NativeU64;
 use zerocopy::{FromBytes, Immutable};
 
 use super::GraphLinksFormat;
-use super::header::{HEADER_VERSION_COMPRESSED, HeaderCompressed, HeaderPlain};
+use super::header::{
+    HEADER_VERSION_COMPRESSED, HEADER_VERSION_COMPRESSED_VARINT, HeaderCompressed, HeaderPlain,
+};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::hnsw_index::HnswM;
 use crate::index::hnsw_index::graph_links::header::{
@@ -35,7 +38,10 @@ pub(super) struct GraphLinksView<'a> {
 }
 
 /// An iterator type returned by [`GraphLinksView::links`].
-pub type LinksIterator<'a> = Either<Copied<std::slice::Iter<'a, u32>>, PackedLinksIterator<'a>>;
+pub type LinksIterator<'a> = Either<
+    Copied<std::slice::Iter<'a, u32>>,
+    Either<PackedLinksIterator<'a>, VarintLinksIterator<'a>>,
+>;
 
 /// An iterator type returned by [`super::GraphLinks::links_with_vectors`].
 /// Iterates over pairs of ([`PointOffsetType`], `&[u8]`). The second element is
@@ -104,6 +110,23 @@ pub(super) enum CompressionInfo<'a> {
         link_vector_size: NonZero<usize>,
         link_vector_alignment: u8,
     },
+    CompressedVarint {
+        /// Delta + varint encoded links.
+        ///
+        /// Same layout as [`CompressionInfo::Compressed`], but each node's
+        /// links are encoded by [`common::varint_links::pack_varint_links`].
+        ///
+        /// ```text
+        /// [vvvvvvvvvvvvvvvvvvvv][vvvvvvvvvvvvvvvvvvvv][vvvvvvvvvvvvvvvvvvvv]...
+        /// [neighbors for node 0][neighbors for node 1][neighbors for node 2]...
+        /// ```
+        /// Where:
+        /// 1. `v` are varint-encoded links, the first `m` of them are sorted
+        ///    and delta-encoded.
+        neighbors: &'a [u8],
+        offsets: bitpacking_ordered::Reader<'a>,
+        hnsw_m: HnswM,
+    },
 }
 
 impl GraphLinksView<'_> {
@@ -112,7 +135,9 @@ impl GraphLinksView<'_> {
         format: GraphLinksFormat,
     ) -> OperationResult<GraphLinksView<'_>> {
         match format {
-            GraphLinksFormat::Compressed => Self::load_compressed(data),
+            GraphLinksFormat::Compressed | GraphLinksFormat::CompressedVarint => {
+                Self::load_compressed(data, format)
+            }
             GraphLinksFormat::Plain => Self::load_plain(data),
             GraphLinksFormat::CompressedWithVectors => Self::load_compressed_with_vectors(data),
         }
@@ -134,10 +159,19 @@ impl GraphLinksView<'_> {
         })
     }
 
-    fn load_compressed(data: &[u8]) -> OperationResult<GraphLinksView<'_>> {
+    fn load_compressed(
+        data: &[u8],
+        format: GraphLinksFormat,
+    ) -> OperationResult<GraphLinksView<'_>> {
         let (header, data) =
             HeaderCompressed::ref_from_prefix(data).map_err(|_| error_unsufficent_size())?;
-        debug_assert_eq!(header.version.get(), HEADER_VERSION_COMPRESSED);
+        debug_assert_eq!(
+            header.version.get(),
+            match format {
+                GraphLinksFormat::CompressedVarint => HEADER_VERSION_COMPRESSED_VARINT,
+                _ => HEADER_VERSION_COMPRESSED,
+            },
+        );
         let (level_offsets, data) = read_level_offsets(
             data,
             header.levels_count.get(),
@@ -149,18 +183,27 @@ impl GraphLinksView<'_> {
             .map_err(|e| {
                 OperationError::service_error(format!("Can't create decompressor: {e}"))
             })?;
-        Ok(GraphLinksView {
-            reindex,
-            compression: CompressionInfo::Compressed {
+        let hnsw_m = HnswM::new(header.m.get() as usize, header.m0.get() as usize);
+        let compression = match format {
+            GraphLinksFormat::CompressedVarint => CompressionInfo::CompressedVarint {
                 neighbors,
                 offsets,
-                hnsw_m: HnswM::new(header.m.get() as usize, header.m0.get() as usize),
+                hnsw_m,
+            },
+            _ => CompressionInfo::Compressed {
+                neighbors,
+                offsets,
+                hnsw_m,
                 bits_per_unsorted: MIN_BITS_PER_VALUE.max(packed_bits(
                     u32::try_from(header.point_count.get().saturating_sub(1)).map_err(|_| {
                         OperationError::service_error("Too many points in GraphLinks file")
                     })?,
                 )),
             },
+        };
+        Ok(GraphLinksView {
+            reindex,
+            compression,
             level_offsets,
         })
     }
@@ -230,7 +273,8 @@ impl GraphLinksView<'_> {
             CompressionInfo::Uncompressed { offsets, .. } => {
                 offsets[idx].get() == offsets[idx + 1].get()
             }
-            CompressionInfo::Compressed { ref offsets, .. } => {
+            CompressionInfo::Compressed { ref offsets, .. }
+            | CompressionInfo::CompressedVarint { ref offsets, .. } => {
                 offsets.get(idx + 1).unwrap() == offsets.get(idx).unwrap()
             }
             CompressionInfo::CompressedWithVectors { .. } => {
@@ -255,15 +299,27 @@ impl GraphLinksView<'_> {
             } => {
                 let neighbors_range =
                     offsets.get(idx).unwrap() as usize..offsets.get(idx + 1).unwrap() as usize;
-                Either::Right(iterate_packed_links(
+                Either::Right(Either::Left(iterate_packed_links(
                     &neighbors[neighbors_range],
                     bits_per_unsorted,
                     hnsw_m.level_m(level),
-                ))
+                )))
             }
             CompressionInfo::CompressedWithVectors { .. } => {
                 // Not intended to be used outside of tests.
-                Either::Right(self.links_with_vectors(point_id, level).1)
+                Either::Right(Either::Left(self.links_with_vectors(point_id, level).1))
+            }
+            CompressionInfo::CompressedVarint {
+                neighbors,
+                ref offsets,
+                ref hnsw_m,
+            } => {
+                let neighbors_range =
+                    offsets.get(idx).unwrap() as usize..offsets.get(idx + 1).unwrap() as usize;
+                Either::Right(Either::Right(iterate_varint_links(
+                    &neighbors[neighbors_range],
+                    hnsw_m.level_m(level),
+                )))
             }
         }
     }
@@ -292,6 +348,7 @@ impl GraphLinksView<'_> {
         match self.compression {
             CompressionInfo::Uncompressed { .. } => unimplemented!(),
             CompressionInfo::Compressed { .. } => unimplemented!(),
+            CompressionInfo::CompressedVarint { .. } => unimplemented!(),
             CompressionInfo::CompressedWithVectors {
                 neighbors,
                 ref offsets,
@@ -380,6 +437,7 @@ impl GraphLinksView<'_> {
             CompressionInfo::Uncompressed { .. } => 0,
             CompressionInfo::Compressed { hnsw_m, .. } => hnsw_m.level_m(level),
             CompressionInfo::CompressedWithVectors { hnsw_m, .. } => hnsw_m.level_m(level),
+            CompressionInfo::CompressedVarint { hnsw_m, .. } => hnsw_m.level_m(level),
         }
     }
 }
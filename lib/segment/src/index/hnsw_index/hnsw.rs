@@ -1,3 +1,4 @@
+use std::io::Write;
 use std::ops::Deref as _;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -14,7 +15,7 @@ use common::cpu::linux_low_thread_priority;
 use common::ext::BitSliceExt as _;
 use common::flags::FeatureFlags;
 use common::progress_tracker::ProgressTracker;
-use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset, TelemetryDetail};
 use fs_err as fs;
 use itertools::EitherOrBoth;
 use log::{debug, trace};
@@ -52,6 +53,7 @@ use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
 use crate::index::hnsw_index::graph_layers_healer::GraphLayersHealer;
 use crate::index::hnsw_index::graph_links::{GraphLinksFormatParam, StorageGraphLinksVectors};
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::query_arena::get_query_buffers;
 use crate::index::query_estimator::adjust_to_available_vectors;
 use crate::index::sample_estimation::sample_check_cardinality;
 use crate::index::struct_payload_index::StructPayloadIndex;
@@ -63,17 +65,16 @@ use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
 use crate::json_path::JsonPath;
 use crate::payload_storage::FilterContext;
 use crate::segment_constructor::VectorIndexBuildArgs;
-use crate::telemetry::VectorIndexSearchesTelemetry;
+use crate::telemetry::{HnswBuildStats, VectorIndexSearchesTelemetry};
 use crate::types::Condition::Field;
 use crate::types::{
     ACORN_MAX_SELECTIVITY_DEFAULT, FieldCondition, Filter, HnswConfig, HnswGlobalConfig,
-    QuantizationSearchParams, SearchParams,
+    QuantizationSearchParams, SearchParams, VectorNameBuf,
 };
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::query::DiscoveryQuery;
 use crate::vector_storage::{VectorStorage, VectorStorageEnum, new_raw_scorer};
 
-const HNSW_USE_HEURISTIC: bool = true;
 const FINISH_MAIN_GRAPH_LOG_MESSAGE: &str = "Finish main graph in time";
 
 /// Build first N points in HNSW graph using only a single thread, to avoid
@@ -160,14 +161,7 @@ impl HNSWIndex {
                 })
                 .unwrap_or(1);
 
-            HnswGraphConfig::new(
-                hnsw_config.m,
-                hnsw_config.ef_construct,
-                full_scan_threshold,
-                hnsw_config.max_indexing_threads,
-                hnsw_config.payload_m,
-                available_vectors,
-            )
+            HnswGraphConfig::new(&hnsw_config, full_scan_threshold, available_vectors)
         };
 
         let do_convert = LINK_COMPRESSION_CONVERT_EXISTING;
@@ -238,6 +232,8 @@ impl HNSWIndex {
 
         fs::create_dir_all(path)?;
 
+        let build_timer = std::time::Instant::now();
+
         let id_tracker_ref = id_tracker.borrow();
         let vector_storage_ref = vector_storage.borrow();
         let quantized_vectors_ref = quantized_vectors.borrow();
@@ -256,14 +252,8 @@ impl HNSWIndex {
             })
             .unwrap_or(1);
 
-        let mut config = HnswGraphConfig::new(
-            hnsw_config.m,
-            hnsw_config.ef_construct,
-            full_scan_threshold,
-            hnsw_config.max_indexing_threads,
-            hnsw_config.payload_m,
-            total_vector_count,
-        );
+        let mut config =
+            HnswGraphConfig::new(&hnsw_config, full_scan_threshold, total_vector_count);
 
         #[allow(unused_mut)]
         let mut build_main_graph = config.m > 0;
@@ -276,6 +266,9 @@ impl HNSWIndex {
             config.payload_m0.unwrap_or(config.m0),
         );
 
+        let neighbor_selection = config.neighbor_selection.unwrap_or_default();
+        let ef_construct_upper = config.ef_construct_upper.unwrap_or(config.ef_construct);
+
         // Progress subtasks
         let progress_migrate = build_main_graph.then(|| progress.subtask("migrate"));
         let progress_main_graph = build_main_graph.then(|| progress.subtask("main_graph"));
@@ -345,8 +338,10 @@ impl HNSWIndex {
             HnswM::new(config.m, config.m0),
             config.ef_construct,
             num_entries,
-            HNSW_USE_HEURISTIC,
+            neighbor_selection.use_heuristic(),
         );
+        graph_layers_builder.set_ef_construct_upper(ef_construct_upper);
+        graph_layers_builder.set_keep_pruned(neighbor_selection.keep_pruned());
 
         let pool = rayon::ThreadPoolBuilder::new()
             .thread_name(|idx| format!("hnsw-build-{idx}"))
@@ -581,7 +576,12 @@ impl HNSWIndex {
                 // So blocks larger than $1/m$ are not needed.
                 // We add multiplier for the extra safety.
                 let percolation_multiplier = 4;
-                let max_block_size = if config.m > 0 {
+                let max_block_size = if let Some(max_points) =
+                    payload_index_ref.tenant_subgraphs_max_points(&field)
+                {
+                    // Explicitly requested subgraph for each tenant, within the configured limit
+                    max_points
+                } else if config.m > 0 {
                     total_vector_count / average_links_per_0_level_int * percolation_multiplier
                 } else {
                     usize::MAX
@@ -627,9 +627,11 @@ impl HNSWIndex {
                         payload_m,
                         config.ef_construct,
                         1,
-                        HNSW_USE_HEURISTIC,
+                        neighbor_selection.use_heuristic(),
                         false,
                     );
+                    additional_graph.set_ef_construct_upper(ef_construct_upper);
+                    additional_graph.set_keep_pruned(neighbor_selection.keep_pruned());
 
                     Self::build_filtered_graph(
                         id_tracker_ref.deref(),
@@ -677,9 +679,14 @@ impl HNSWIndex {
             .flatten();
         let format_param = match graph_links_vectors.as_ref() {
             Some(v) => GraphLinksFormatParam::CompressedWithVectors(v),
+            None if hnsw_config.varint_links.unwrap_or_default() => {
+                GraphLinksFormatParam::CompressedVarint
+            }
             None => GraphLinksFormatParam::Compressed,
         };
 
+        let avg_links_level_0 = graph_layers_builder.get_average_connectivity_on_level(0);
+
         let graph: GraphLayers =
             graph_layers_builder.into_graph_layers(path, format_param, is_on_disk)?;
 
@@ -696,6 +703,10 @@ impl HNSWIndex {
 
         debug!("finish additional payload field indexing");
 
+        config.build_stats = Some(HnswBuildStats {
+            build_time_ms: build_timer.elapsed().as_millis() as u64,
+            avg_links_level_0,
+        });
         config.save(&HnswGraphConfig::get_config_path(path))?;
 
         drop(id_tracker_ref);
@@ -1117,6 +1128,7 @@ impl HNSWIndex {
                 params,
                 vector_query_context.hardware_counter(),
                 filter_context,
+                vector_query_context.score_threshold(),
             )?;
 
             let search_result = self.graph.search(
@@ -1202,6 +1214,7 @@ impl HNSWIndex {
             params,
             vector_query_context.hardware_counter(),
             None,
+            vector_query_context.score_threshold(),
         )?;
         let mut search_results = batch_filtered_searcher.peek_top_iter(points, &is_stopped)?;
         for (search_result, query_vector) in search_results.iter_mut().zip(query_vectors) {
@@ -1257,12 +1270,15 @@ impl HNSWIndex {
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
         let payload_index = self.payload_index.borrow();
-        let filtered_points = payload_index.query_points(
+        let mut query_buffers = get_query_buffers();
+        let filtered_points = &mut query_buffers.point_ids;
+        payload_index.query_points_into(
             filter,
             &vector_query_context.hardware_counter(),
             &vector_query_context.is_stopped(),
+            filtered_points,
         );
-        self.search_plain_batched(vectors, &filtered_points, top, params, vector_query_context)
+        self.search_plain_batched(vectors, filtered_points, top, params, vector_query_context)
     }
 
     fn discovery_search_with_graph(
@@ -1302,6 +1318,7 @@ impl HNSWIndex {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn construct_search_scorer<'a>(
         vector: &QueryVector,
         vector_storage: &'a VectorStorageEnum,
@@ -1310,16 +1327,19 @@ impl HNSWIndex {
         params: Option<&SearchParams>,
         hardware_counter: HardwareCounterCell,
         filter_context: Option<Box<dyn FilterContext + 'a>>,
+        score_threshold: Option<ScoreType>,
     ) -> OperationResult<FilteredScorer<'a>> {
         let quantization_enabled = is_quantized_search(quantized_storage, params);
-        FilteredScorer::new(
+        let scorer = FilteredScorer::new(
             vector.to_owned(),
             vector_storage,
             quantization_enabled.then_some(quantized_storage).flatten(),
             filter_context.map(BoxCow::Owned),
             deleted_points,
             hardware_counter,
-        )
+        )?;
+        // Quantized scores are approximate, threshold is applied after rescoring
+        Ok(scorer.with_score_threshold(score_threshold.filter(|_| !quantization_enabled)))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1332,9 +1352,10 @@ impl HNSWIndex {
         params: Option<&SearchParams>,
         hardware_counter: HardwareCounterCell,
         filter_context: Option<Box<dyn FilterContext + 'a>>,
+        score_threshold: Option<ScoreType>,
     ) -> OperationResult<BatchFilteredSearcher<'a>> {
         let quantization_enabled = is_quantized_search(quantized_storage, params);
-        BatchFilteredSearcher::new(
+        let searcher = BatchFilteredSearcher::new(
             vectors,
             vector_storage,
             quantization_enabled.then_some(quantized_storage).flatten(),
@@ -1342,7 +1363,9 @@ impl HNSWIndex {
             top,
             deleted_points,
             hardware_counter,
-        )
+        )?;
+        // Quantized scores are approximate, threshold is applied after rescoring
+        Ok(searcher.with_score_threshold(score_threshold.filter(|_| !quantization_enabled)))
     }
 
     /// Read underlying data from disk into disk cache.
@@ -1357,6 +1380,22 @@ impl HNSWIndex {
         }
         Ok(())
     }
+
+    /// Write the graph with external ids of its points, see [`crate::data_types::hnsw_graph`]
+    pub fn export_graph(
+        &self,
+        segment_id: String,
+        vector_name: VectorNameBuf,
+        writer: &mut dyn Write,
+    ) -> OperationResult<()> {
+        let id_tracker = self.id_tracker.borrow();
+        self.graph.export(
+            segment_id,
+            vector_name,
+            |offset| id_tracker.external_id(offset),
+            writer,
+        )
+    }
 }
 
 impl VectorIndex for HNSWIndex {
@@ -1385,6 +1424,8 @@ impl VectorIndex for HNSWIndex {
                     ignore: true,
                     rescore: Some(false),
                     oversampling: None,
+                    max_rescore: None,
+                    adaptive_rescore: None,
                 }); // disable quantization for exact search
                 params
             })
@@ -1522,6 +1563,7 @@ impl VectorIndex for HNSWIndex {
             filtered_sparse: Default::default(),
             unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
             unfiltered_sparse: Default::default(),
+            hnsw_build: self.config.build_stats,
         }
     }
 
@@ -1616,6 +1658,8 @@ impl<'a> OldIndexCandidate<'a> {
         let configuration_mismatch = config.m != old_index.config.m
             || config.m0 != old_index.config.m0
             || config.ef_construct != old_index.config.ef_construct
+            || config.ef_construct_upper != old_index.config.ef_construct_upper
+            || config.neighbor_selection != old_index.config.neighbor_selection
             || new_quantization_config != old_quantization_config;
         // If old graph has vectors, reusing it will cause a lot of random reads,
         // making it slower than building from scratch.
@@ -70,6 +70,26 @@ impl LinksContainer {
         self.processed_by_heuristic = self.links.len() as u32;
     }
 
+    /// Fill the free slots with the closest candidates rejected by the heuristic.
+    ///
+    /// Expected to be called right after [`Self::fill_from_sorted_with_heuristic`]
+    /// with the same candidates. Links selected by the heuristic stay in front,
+    /// so they remain marked as processed by it.
+    pub fn fill_from_sorted_keep_pruned(
+        &mut self,
+        candidates: impl Iterator<Item = ScoredPointOffset>,
+        level_m: usize,
+    ) {
+        for candidate in candidates {
+            if self.links.len() >= level_m {
+                break;
+            }
+            if !self.links.contains(&candidate.idx) {
+                self.links.push(candidate.idx);
+            }
+        }
+    }
+
     /// Connect new point to links, so that links contains only closest points.
     pub fn connect(
         &mut self,
@@ -375,11 +395,17 @@ mod tests {
             });
         }
 
+        let candidates = candidates.into_sorted_vec();
+
         let mut res = LinksContainer::with_capacity(m);
-        res.fill_from_sorted_with_heuristic(candidates.into_iter_sorted(), m, scorer);
+        res.fill_from_sorted_with_heuristic(candidates.iter().copied(), m, scorer);
 
         assert_eq!(&res.links(), &[1, 3, 6]);
 
+        // Pruned candidates fill the free slots in order of distance to the target
+        res.fill_from_sorted_keep_pruned(candidates.into_iter(), m);
+        assert_eq!(&res.links(), &[1, 3, 6, 2, 4, 5]);
+
         let mut rng = StdRng::seed_from_u64(42);
 
         let mut links_container = LinksContainer::with_capacity(m);
@@ -54,6 +54,8 @@ pub struct FilteredScorer<'a> {
     filters: ScorerFilters<'a>,
     /// Temporary buffer for scores.
     scores_buffer: Vec<ScoreType>,
+    /// Search may stop expanding candidates with scores not above this threshold.
+    score_threshold: Option<ScoreType>,
 }
 
 pub struct ScorerFilters<'a> {
@@ -78,6 +80,18 @@ impl<'a> ScorerFilters<'a> {
                 .is_none_or(|f| f.check(point_id))
     }
 
+    /// Retain only points which satisfy current search context, preserving their order.
+    ///
+    /// Same as [`ScorerFilters::check_vector`], but checks the whole batch at once.
+    pub fn retain_vectors(&self, point_ids: &mut Vec<PointOffsetType>) {
+        point_ids.retain(|&point_id| {
+            check_deleted_condition(point_id, self.vec_deleted, self.point_deleted)
+        });
+        if let Some(filter_context) = &self.filter_context {
+            filter_context.retain_matching(point_ids);
+        }
+    }
+
     fn as_borrowed(&'a self) -> Self {
         ScorerFilters {
             filter_context: self.filter_context.as_ref().map(BoxCow::as_borrowed),
@@ -134,6 +148,7 @@ impl<'a> FilteredScorer<'a> {
                 vec_deleted: vectors.deleted_vector_bitslice(),
             },
             scores_buffer: Vec::new(),
+            score_threshold: None,
         })
     }
 
@@ -171,6 +186,7 @@ impl<'a> FilteredScorer<'a> {
                 vec_deleted: vectors.deleted_vector_bitslice(),
             },
             scores_buffer: Vec::new(),
+            score_threshold: None,
         })
     }
 
@@ -193,9 +209,23 @@ impl<'a> FilteredScorer<'a> {
                 vec_deleted: vector_storage.deleted_vector_bitslice(),
             },
             scores_buffer: Vec::new(),
+            score_threshold: None,
         }
     }
 
+    /// Set threshold of the scores, below which the points are not interesting for the search.
+    ///
+    /// Should only be used with exact scores, approximate scores of quantized vectors
+    /// may be lower than the threshold for points which satisfy it.
+    pub fn with_score_threshold(mut self, score_threshold: Option<ScoreType>) -> Self {
+        self.score_threshold = score_threshold;
+        self
+    }
+
+    pub fn score_threshold(&self) -> Option<ScoreType> {
+        self.score_threshold
+    }
+
     pub fn raw_scorer(&self) -> &dyn RawScorer {
         self.raw_scorer.as_ref()
     }
@@ -227,7 +257,7 @@ impl<'a> FilteredScorer<'a> {
         point_ids: &mut Vec<PointOffsetType>,
         limit: usize,
     ) -> impl Iterator<Item = ScoredPointOffset> {
-        point_ids.retain(|point_id| self.filters.check_vector(*point_id));
+        self.filters.retain_vectors(point_ids);
         if limit != 0 {
             point_ids.truncate(limit);
         }
@@ -268,6 +298,8 @@ struct BatchSearch<'a> {
 pub struct BatchFilteredSearcher<'a> {
     scorer_batch: SmallVec<[BatchSearch<'a>; 1]>,
     filters: ScorerFilters<'a>,
+    /// Points with scores not above this threshold are not collected.
+    score_threshold: Option<ScoreType>,
 }
 
 impl<'a> BatchFilteredSearcher<'a> {
@@ -306,6 +338,7 @@ impl<'a> BatchFilteredSearcher<'a> {
         Ok(Self {
             scorer_batch,
             filters,
+            score_threshold: None,
         })
     }
 
@@ -343,9 +376,19 @@ impl<'a> BatchFilteredSearcher<'a> {
                 point_deleted,
                 vec_deleted: vector_storage.deleted_vector_bitslice(),
             },
+            score_threshold: None,
         }
     }
 
+    /// Skip points with scores not above the threshold, instead of collecting them into the top.
+    ///
+    /// Should only be used with exact scores, approximate scores of quantized vectors
+    /// may be lower than the threshold for points which satisfy it.
+    pub fn with_score_threshold(mut self, score_threshold: Option<ScoreType>) -> Self {
+        self.score_threshold = score_threshold;
+        self
+    }
+
     pub fn peek_top_all(
         self,
         is_stopped: &AtomicBool,
@@ -363,6 +406,8 @@ impl<'a> BatchFilteredSearcher<'a> {
         mut points: impl Iterator<Item = PointOffsetType>,
         is_stopped: &AtomicBool,
     ) -> CancellableResult<Vec<Vec<ScoredPointOffset>>> {
+        let score_threshold = self.score_threshold;
+
         // Reuse the same buffer for all chunks, to avoid reallocation
         let mut chunk = [0; VECTOR_READ_BATCH_SIZE];
         let mut scores_buffer = [0.0; VECTOR_READ_BATCH_SIZE];
@@ -392,6 +437,10 @@ impl<'a> BatchFilteredSearcher<'a> {
                 raw_scorer.score_points(&chunk[..chunk_size], &mut scores_buffer[..chunk_size]);
 
                 for i in 0..chunk_size {
+                    // Points which can't satisfy the threshold don't need to compete for the top
+                    if score_threshold.is_some_and(|threshold| scores_buffer[i] <= threshold) {
+                        continue;
+                    }
                     pq.push(ScoredPointOffset {
                         idx: chunk[i],
                         score: scores_buffer[i],
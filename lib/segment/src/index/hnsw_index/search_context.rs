@@ -10,26 +10,54 @@ pub struct SearchContext {
     pub nearest: FixedLengthPriorityQueue<ScoredPointOffset>,
     /// Current candidates to process
     pub candidates: BinaryHeap<ScoredPointOffset>,
+    /// Points with scores not above this threshold are not interesting for the search
+    score_threshold: Option<ScoreType>,
+    /// Whether any point above the `score_threshold` was found
+    threshold_reached: bool,
 }
 
 impl SearchContext {
     pub fn new(ef: usize) -> Self {
+        Self::with_candidates(ef, BinaryHeap::new())
+    }
+
+    /// Create search context, which reuses memory of the given empty `candidates` heap
+    pub fn with_candidates(ef: usize, candidates: BinaryHeap<ScoredPointOffset>) -> Self {
+        debug_assert!(candidates.is_empty());
         SearchContext {
             nearest: FixedLengthPriorityQueue::new(ef),
-            candidates: BinaryHeap::new(),
+            candidates,
+            score_threshold: None,
+            threshold_reached: false,
         }
     }
 
+    /// Stop expanding candidates below the threshold, once the search reached points above it.
+    ///
+    /// Points below the threshold may still be on the way to the matching area of the graph,
+    /// so they are only discarded after the area is found.
+    pub fn with_score_threshold(mut self, score_threshold: Option<ScoreType>) -> Self {
+        self.score_threshold = score_threshold;
+        self
+    }
+
     pub fn lower_bound(&self) -> ScoreType {
-        match self.nearest.top() {
+        let lower_bound = match self.nearest.top() {
             None => ScoreType::min_value(),
             Some(worst_of_the_best) => worst_of_the_best.score,
+        };
+        match self.score_threshold {
+            Some(score_threshold) if self.threshold_reached => lower_bound.max(score_threshold),
+            _ => lower_bound,
         }
     }
 
     /// Updates search context with new scored point.
     /// If it is closer than existing - also add it to candidates for further search
     pub fn process_candidate(&mut self, score_point: ScoredPointOffset) {
+        if let Some(score_threshold) = self.score_threshold {
+            self.threshold_reached |= score_point.score > score_threshold;
+        }
         let was_added = match self.nearest.push(score_point) {
             None => true,
             Some(removed) => removed.idx != score_point.idx,
@@ -5,6 +5,7 @@ pub mod payload_config;
 mod payload_index_base;
 pub mod plain_payload_index;
 pub mod plain_vector_index;
+mod query_arena;
 pub mod query_estimator;
 pub mod query_optimization;
 mod sample_estimation;
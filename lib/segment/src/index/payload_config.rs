@@ -166,11 +166,13 @@ pub enum PayloadIndexType {
     KeywordIndex,
     FloatIndex,
     GeoIndex,
+    GeoShapeIndex,
     FullTextIndex,
     BoolIndex,
     UuidIndex,
     UuidMapIndex,
     NullIndex,
+    NestedNullIndex,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -89,7 +89,21 @@ pub trait PayloadIndex {
         query: &Filter,
         hw_counter: &HardwareCounterCell,
         is_stopped: &AtomicBool,
-    ) -> Vec<PointOffsetType>;
+    ) -> Vec<PointOffsetType> {
+        let mut points = Vec::new();
+        self.query_points_into(query, hw_counter, is_stopped, &mut points);
+        points
+    }
+
+    /// Same as [`PayloadIndex::query_points`], but appends point ids to the given buffer,
+    /// which allows to reuse its memory.
+    fn query_points_into(
+        &self,
+        query: &Filter,
+        hw_counter: &HardwareCounterCell,
+        is_stopped: &AtomicBool,
+        points: &mut Vec<PointOffsetType>,
+    );
 
     /// Return number of points, indexed by this field
     fn indexed_points(&self, field: PayloadKeyTypeRef) -> usize;
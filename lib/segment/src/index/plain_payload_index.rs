@@ -161,19 +161,21 @@ impl PayloadIndex for PlainPayloadIndex {
         self.estimate_cardinality(query, hw_counter)
     }
 
-    fn query_points(
+    fn query_points_into(
         &self,
         query: &Filter,
         hw_counter: &HardwareCounterCell,
         is_stopped: &AtomicBool,
-    ) -> Vec<PointOffsetType> {
+        points: &mut Vec<PointOffsetType>,
+    ) {
         let filter_context = self.filter_context(query, hw_counter);
         let id_tracker = self.id_tracker.borrow();
         let all_points_iter = id_tracker.iter_internal();
-        all_points_iter
-            .stop_if(is_stopped)
-            .filter(|id| filter_context.check(*id))
-            .collect()
+        points.extend(
+            all_points_iter
+                .stop_if(is_stopped)
+                .filter(|id| filter_context.check(*id)),
+        );
     }
 
     fn indexed_points(&self, _field: PayloadKeyTypeRef) -> usize {
@@ -15,6 +15,7 @@ use crate::common::operation_time_statistics::{
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorRef};
 use crate::id_tracker::IdTrackerSS;
+use crate::index::query_arena::get_query_buffers;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::vector_index_search_common::{
     get_oversampled_top, is_quantized_search, postprocess_search_result,
@@ -123,6 +124,10 @@ impl VectorIndex for PlainVectorIndex {
             .then_some(quantized_storage.as_ref())
             .flatten();
         let oversampled_top = get_oversampled_top(quantized_storage.as_ref(), params, top);
+        // Quantized scores are approximate, threshold is applied after rescoring
+        let score_threshold = query_context
+            .score_threshold()
+            .filter(|_| !quantization_enabled);
         let batch_searcher = BatchFilteredSearcher::new(
             query_vectors,
             &vector_storage,
@@ -131,12 +136,15 @@ impl VectorIndex for PlainVectorIndex {
             oversampled_top,
             deleted_points,
             query_context.hardware_counter(),
-        )?;
+        )?
+        .with_score_threshold(score_threshold);
 
         let mut search_results = match filter {
             Some(filter) => {
                 let payload_index = self.payload_index.borrow();
-                let filtered_ids_vec = payload_index.query_points(filter, &hw_counter, &is_stopped);
+                let mut query_buffers = get_query_buffers();
+                let filtered_ids_vec = &mut query_buffers.point_ids;
+                payload_index.query_points_into(filter, &hw_counter, &is_stopped, filtered_ids_vec);
                 batch_searcher.peek_top_iter(&mut filtered_ids_vec.iter().copied(), &is_stopped)?
             }
             None => batch_searcher.peek_top_all(&is_stopped)?,
@@ -175,6 +183,7 @@ impl VectorIndex for PlainVectorIndex {
             filtered_sparse: Default::default(),
             unfiltered_exact: OperationDurationStatistics::default(),
             unfiltered_sparse: OperationDurationStatistics::default(),
+            hnsw_build: None,
         }
     }
 
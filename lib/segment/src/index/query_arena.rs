@@ -0,0 +1,196 @@
+//! Reusable memory for temporary buffers of query execution
+//!
+//! Every search allocates a few short-lived buffers, such as the list of points matching a filter
+//! or the heap of graph candidates. Under high load, these allocations put pressure on the
+//! allocator and fragment the memory. The arena keeps the buffers of finished queries, so the
+//! following queries can reuse them.
+
+use std::collections::BinaryHeap;
+use std::ops::{Deref, DerefMut};
+use std::sync::LazyLock;
+
+use common::defaults::POOL_KEEP_LIMIT;
+use common::types::{PointOffsetType, ScoredPointOffset};
+use parking_lot::Mutex;
+
+/// Buffers which grew larger than this number of elements are not kept in the arena,
+/// so that a few exceptionally large queries don't hold on to memory.
+const MAX_RETAINED_CAPACITY: usize = 1 << 20;
+
+/// Global arena, only present if enabled with the `query_arena` feature flag
+static QUERY_ARENA: LazyLock<Option<QueryArena>> = LazyLock::new(|| {
+    common::flags::feature_flags()
+        .query_arena
+        .then(QueryArena::new)
+});
+
+/// Get temporary buffers for a single query.
+///
+/// Buffers are taken from the global arena if it is enabled, otherwise they are allocated for
+/// this query only.
+pub fn get_query_buffers() -> QueryBuffersHandle<'static> {
+    match QUERY_ARENA.as_ref() {
+        Some(arena) => arena.get(),
+        None => QueryBuffersHandle::new(None, QueryBuffers::default()),
+    }
+}
+
+/// Temporary buffers of a single query, all of them are empty when handed out
+#[derive(Debug, Default)]
+pub struct QueryBuffers {
+    /// Ids of points, e.g. the ones matching a filter
+    pub point_ids: Vec<PointOffsetType>,
+    /// Backing storage for a heap of candidates
+    candidates: Vec<ScoredPointOffset>,
+}
+
+impl QueryBuffers {
+    /// Take an empty heap of candidates, backed by the buffer of the arena.
+    ///
+    /// Give it back with [`QueryBuffers::return_candidates`] to reuse its memory.
+    pub fn take_candidates(&mut self) -> BinaryHeap<ScoredPointOffset> {
+        BinaryHeap::from(std::mem::take(&mut self.candidates))
+    }
+
+    pub fn return_candidates(&mut self, candidates: BinaryHeap<ScoredPointOffset>) {
+        let mut candidates = candidates.into_vec();
+        candidates.clear();
+        self.candidates = candidates;
+    }
+
+    fn clear(&mut self) {
+        let Self {
+            point_ids,
+            candidates,
+        } = self;
+        point_ids.clear();
+        candidates.clear();
+    }
+
+    fn is_retainable(&self) -> bool {
+        let Self {
+            point_ids,
+            candidates,
+        } = self;
+        point_ids.capacity() <= MAX_RETAINED_CAPACITY
+            && candidates.capacity() <= MAX_RETAINED_CAPACITY
+    }
+}
+
+/// Owner of the `QueryBuffers`, which returns them back to the arena on drop
+#[derive(Debug)]
+pub struct QueryBuffersHandle<'a> {
+    arena: Option<&'a QueryArena>,
+    buffers: QueryBuffers,
+}
+
+impl<'a> QueryBuffersHandle<'a> {
+    fn new(arena: Option<&'a QueryArena>, buffers: QueryBuffers) -> Self {
+        QueryBuffersHandle { arena, buffers }
+    }
+}
+
+impl Deref for QueryBuffersHandle<'_> {
+    type Target = QueryBuffers;
+
+    fn deref(&self) -> &Self::Target {
+        &self.buffers
+    }
+}
+
+impl DerefMut for QueryBuffersHandle<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffers
+    }
+}
+
+impl Drop for QueryBuffersHandle<'_> {
+    fn drop(&mut self) {
+        if let Some(arena) = self.arena {
+            arena.return_back(std::mem::take(&mut self.buffers));
+        }
+    }
+}
+
+/// Keeps a list of `QueryBuffers` which could be requested and released from multiple threads
+///
+/// If there are more requests than buffers - creates new buffers, but only keeps max defined amount.
+#[derive(Debug)]
+pub struct QueryArena {
+    pool: Mutex<Vec<QueryBuffers>>,
+}
+
+impl QueryArena {
+    pub fn new() -> Self {
+        QueryArena {
+            pool: Mutex::new(Vec::with_capacity(*POOL_KEEP_LIMIT)),
+        }
+    }
+
+    pub fn get(&self) -> QueryBuffersHandle<'_> {
+        let buffers = self.pool.lock().pop().unwrap_or_default();
+        QueryBuffersHandle::new(Some(self), buffers)
+    }
+
+    fn return_back(&self, mut buffers: QueryBuffers) {
+        if !buffers.is_retainable() {
+            return;
+        }
+        buffers.clear();
+
+        let mut pool = self.pool.lock();
+        if pool.len() < *POOL_KEEP_LIMIT {
+            pool.push(buffers);
+        }
+    }
+}
+
+impl Default for QueryArena {
+    fn default() -> Self {
+        QueryArena::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_arena_reuses_buffers() {
+        let arena = QueryArena::new();
+
+        {
+            let mut buffers = arena.get();
+            buffers.point_ids.extend(0..100);
+
+            let mut candidates = buffers.take_candidates();
+            candidates.extend((0..100).map(|idx| ScoredPointOffset {
+                idx,
+                score: idx as f32,
+            }));
+            assert_eq!(candidates.pop().unwrap().idx, 99);
+            buffers.return_candidates(candidates);
+        }
+
+        let mut buffers = arena.get();
+        assert!(buffers.point_ids.is_empty());
+        assert!(buffers.point_ids.capacity() >= 100);
+
+        let candidates = buffers.take_candidates();
+        assert!(candidates.is_empty());
+        assert!(candidates.capacity() >= 99);
+    }
+
+    #[test]
+    fn test_query_arena_drops_large_buffers() {
+        let arena = QueryArena::new();
+
+        {
+            let mut buffers = arena.get();
+            buffers.point_ids.reserve(MAX_RETAINED_CAPACITY + 1);
+        }
+
+        let buffers = arena.get();
+        assert_eq!(buffers.point_ids.capacity(), 0);
+    }
+}
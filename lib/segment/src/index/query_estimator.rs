@@ -303,7 +303,10 @@ mod tests {
             values_count: None,
             is_empty: None,
             geo_polygon: None,
+            geo_shape: None,
             is_null: None,
+            has_field: None,
+            compare: None,
         })
     }
 
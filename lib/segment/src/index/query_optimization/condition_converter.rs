@@ -1,6 +1,8 @@
 use std::collections::HashMap;
+use std::rc::Rc;
 
 use ahash::AHashSet;
+use bitvec::vec::BitVec;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
@@ -10,16 +12,20 @@ use serde_json::Value;
 
 use crate::index::field_index::FieldIndex;
 use crate::index::field_index::null_index::MutableNullIndex;
-use crate::index::query_optimization::optimized_filter::ConditionCheckerFn;
+use crate::index::query_optimization::optimized_filter::{
+    BatchConditionChecker, ConditionCheckerFn,
+};
 use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::payload_storage::condition_checker::ValueChecker;
 use crate::payload_storage::query_checker::{
     check_field_condition, check_is_empty_condition, check_is_null_condition, check_payload,
     select_nested_indexes,
 };
 use crate::types::{
     Condition, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoPolygon,
-    GeoRadius, IntPayloadType, OwnedPayloadRef, PayloadContainer, Range, RangeInterface,
+    GeoRadius, GeoShapeCondition, IntPayloadType, Match, MatchRegex, MatchValue, OwnedPayloadRef,
+    PayloadContainer, Range, RangeInterface, ValueVariants,
 };
 use crate::vector_storage::VectorStorage;
 
@@ -45,6 +51,25 @@ impl StructPayloadIndex {
                 })
                 .unwrap_or_else(|| {
                     let hw = hw_counter.fork();
+                    if let Some(match_regex) = regex_only_condition(field_condition) {
+                        // Compile the regex once, instead of for every checked value
+                        let regex = match_regex.compile().ok();
+                        return Box::new(move |point_id| {
+                            let Some(regex) = &regex else {
+                                return false;
+                            };
+                            payload_provider.with_payload(
+                                point_id,
+                                |payload| {
+                                    payload
+                                        .get_value(&field_condition.key)
+                                        .into_iter()
+                                        .any(|value| regex.check(value))
+                                },
+                                &hw,
+                            )
+                        });
+                    }
                     Box::new(move |point_id| {
                         payload_provider.with_payload(
                             point_id,
@@ -128,6 +153,11 @@ impl StructPayloadIndex {
                 }
             }
             Condition::Nested(nested) => {
+                // Use dedicated nested null index for `is_empty` and `is_null` of elements
+                if let Some((nested_null_index, check)) = self.nested_null_index(nested, None) {
+                    return Box::new(move |point_id| nested_null_index.check(check, point_id));
+                }
+
                 // Select indexes for nested fields. Trim nested part from key, so
                 // that nested condition can address fields without nested part.
 
@@ -195,6 +225,206 @@ impl StructPayloadIndex {
             Condition::Filter(_) => unreachable!(),
         }
     }
+
+    /// Convert condition into a checker, which can also check a whole batch of points at once.
+    ///
+    /// Only conditions, answered by a bitslice-backed index or by internal ids, are supported.
+    /// Returns `None` for other conditions, those should be converted with [`Self::condition_converter`].
+    pub fn batch_condition_converter<'a>(
+        &'a self,
+        condition: &'a Condition,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<BatchConditionChecker<'a>> {
+        let field_indexes = &self.field_indexes;
+        match condition {
+            Condition::Field(field_condition) => field_indexes
+                .get(&field_condition.key)?
+                .iter()
+                .find_map(|index| {
+                    let hw_acc = hw_counter.new_accumulator();
+                    field_condition_batch_checker(index, field_condition, hw_acc)
+                }),
+            Condition::IsEmpty(is_empty) => {
+                let (primary_null_index, _) =
+                    get_is_empty_indexes(field_indexes.get(&is_empty.is_empty.key)?);
+                primary_null_index
+                    .map(|null_index| get_null_index_is_empty_batch_checker(null_index, true))
+            }
+            Condition::IsNull(is_null) => field_indexes
+                .get(&is_null.is_null.key)?
+                .iter()
+                .find_map(|index| get_is_null_batch_checker(index, true)),
+            Condition::HasId(has_id) => {
+                let id_tracker = self.id_tracker.borrow();
+                let segment_ids = has_id
+                    .has_id
+                    .iter()
+                    .filter_map(|external_id| id_tracker.internal_id(*external_id));
+                Some(get_internal_ids_batch_checker(segment_ids))
+            }
+            Condition::HasVector(has_vector) => {
+                let vector_storage = self.vector_storages.get(&has_vector.has_vector)?;
+                Some(BatchConditionChecker {
+                    check: Box::new(move |point_id| {
+                        !vector_storage.borrow().is_deleted_vector(point_id)
+                    }),
+                    check_batch: Box::new(move |point_ids, matches| {
+                        let vector_storage = vector_storage.borrow();
+                        let deleted = vector_storage.deleted_vector_bitslice();
+                        for (i, &point_id) in point_ids.iter().enumerate() {
+                            // Bitslice may be shorter than the storage, check such points one by one
+                            let is_deleted = match deleted.get(point_id as usize) {
+                                Some(is_deleted) => *is_deleted,
+                                None => vector_storage.is_deleted_vector(point_id),
+                            };
+                            if !is_deleted {
+                                matches.set(i, true);
+                            }
+                        }
+                    }),
+                })
+            }
+            Condition::CustomIdChecker(cond) => {
+                let id_tracker = self.id_tracker.borrow();
+                let segment_ids = id_tracker
+                    .iter_external()
+                    .filter(|&point_id| cond.0.check(point_id))
+                    .filter_map(|external_id| id_tracker.internal_id(external_id));
+                Some(get_internal_ids_batch_checker(segment_ids))
+            }
+            Condition::Nested(nested) => {
+                let (nested_null_index, check) = self.nested_null_index(nested, None)?;
+                Some(BatchConditionChecker {
+                    check: Box::new(move |point_id| nested_null_index.check(check, point_id)),
+                    check_batch: Box::new(move |point_ids, matches| {
+                        nested_null_index.check_batch(check, point_ids, matches)
+                    }),
+                })
+            }
+            Condition::Filter(_) => unreachable!(),
+        }
+    }
+}
+
+/// Regex of the condition, if it is the only check of the condition
+fn regex_only_condition(field_condition: &FieldCondition) -> Option<&MatchRegex> {
+    match field_condition {
+        FieldCondition {
+            key: _,
+            r#match: Some(Match::Regex(match_regex)),
+            range: None,
+            geo_radius: None,
+            geo_bounding_box: None,
+            geo_polygon: None,
+            geo_shape: None,
+            values_count: None,
+            is_empty: None,
+            is_null: None,
+            has_field: None,
+            compare: None,
+        } => Some(match_regex),
+        _ => None,
+    }
+}
+
+/// Get a batch checker for a field condition, if the index answers it with a bitslice.
+///
+/// Conditions are matched in the same order, as in [`field_condition_index`].
+fn field_condition_batch_checker<'a>(
+    index: &'a FieldIndex,
+    field_condition: &FieldCondition,
+    hw_acc: HwMeasurementAcc,
+) -> Option<BatchConditionChecker<'a>> {
+    match field_condition {
+        FieldCondition {
+            r#match:
+                Some(Match::Value(MatchValue {
+                    value: ValueVariants::Bool(is_true),
+                })),
+            ..
+        } => {
+            let FieldIndex::BoolIndex(bool_index) = index else {
+                return None;
+            };
+            let is_true = *is_true;
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(BatchConditionChecker {
+                check: Box::new(move |point_id| {
+                    bool_index.check_values_any(point_id, is_true, &hw_counter)
+                }),
+                check_batch: Box::new(move |point_ids, matches| {
+                    bool_index.check_values_any_batch(point_ids, is_true, matches)
+                }),
+            })
+        }
+        FieldCondition {
+            r#match: Some(_), ..
+        }
+        | FieldCondition { range: Some(_), .. }
+        | FieldCondition {
+            geo_radius: Some(_),
+            ..
+        }
+        | FieldCondition {
+            geo_bounding_box: Some(_),
+            ..
+        }
+        | FieldCondition {
+            geo_polygon: Some(_),
+            ..
+        }
+        | FieldCondition {
+            geo_shape: Some(_), ..
+        } => None,
+        FieldCondition {
+            is_empty: Some(is_empty),
+            ..
+        } => match index {
+            FieldIndex::NullIndex(null_index) => {
+                Some(get_null_index_is_empty_batch_checker(null_index, *is_empty))
+            }
+            _ => None,
+        },
+        FieldCondition {
+            is_null: Some(is_null),
+            ..
+        } => get_is_null_batch_checker(index, *is_null),
+        FieldCondition {
+            has_field: Some(has_field),
+            ..
+        } => get_has_field_batch_checker(index, *has_field),
+        FieldCondition { .. } => None,
+    }
+}
+
+/// Batch checker for a set of internal ids, stored as a bitvec
+fn get_internal_ids_batch_checker<'a>(
+    internal_ids: impl IntoIterator<Item = PointOffsetType>,
+) -> BatchConditionChecker<'a> {
+    let mut ids = BitVec::new();
+    for internal_id in internal_ids {
+        let index = internal_id as usize;
+        if index >= ids.len() {
+            ids.resize(index + 1, false);
+        }
+        ids.set(index, true);
+    }
+
+    let ids = Rc::new(ids);
+    let point_ids_set = ids.clone();
+    let contains = |ids: &BitVec, point_id: PointOffsetType| {
+        ids.get(point_id as usize).is_some_and(|bit| *bit)
+    };
+    BatchConditionChecker {
+        check: Box::new(move |point_id| contains(&point_ids_set, point_id)),
+        check_batch: Box::new(move |point_ids, matches| {
+            for (i, &point_id) in point_ids.iter().enumerate() {
+                if contains(&ids, point_id) {
+                    matches.set(i, true);
+                }
+            }
+        }),
+    }
 }
 
 pub fn field_condition_index<'a>(
@@ -227,6 +457,11 @@ pub fn field_condition_index<'a>(
             ..
         } => get_geo_polygon_checkers(index, geo_polygon.clone(), hw_acc),
 
+        FieldCondition {
+            geo_shape: Some(geo_shape),
+            ..
+        } => get_geo_shape_checkers(index, geo_shape),
+
         FieldCondition {
             is_empty: Some(is_empty),
             ..
@@ -237,6 +472,11 @@ pub fn field_condition_index<'a>(
             ..
         } => get_is_null_checker(index, *is_null),
 
+        FieldCondition {
+            has_field: Some(has_field),
+            ..
+        } => get_has_field_checker(index, *has_field),
+
         FieldCondition {
             key: _,
             r#match: None,
@@ -244,6 +484,7 @@ pub fn field_condition_index<'a>(
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
+            geo_shape: None,
             // We can't use index for this condition, since some indices don't count values,
             // like boolean index, where [true, true, true] is the same as [true]. Count should be 3 but they think is 1.
             //
@@ -251,6 +492,9 @@ pub fn field_condition_index<'a>(
             values_count: _,
             is_empty: None,
             is_null: None,
+            has_field: None,
+            // Comparison with other fields is checked on the payload
+            compare: _,
         } => None,
     }
 }
@@ -272,12 +516,40 @@ pub fn get_geo_polygon_checkers(
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::FloatIndex(_)
         | FieldIndex::FullTextIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::KeywordIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_)
+        | FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_) => None,
+    }
+}
+
+pub fn get_geo_shape_checkers<'a>(
+    index: &'a FieldIndex,
+    geo_shape: &GeoShapeCondition,
+) -> Option<ConditionCheckerFn<'a>> {
+    let condition = geo_shape.convert();
+    match index {
+        FieldIndex::GeoShapeIndex(geo_shape_index) => {
+            Some(Box::new(move |point_id: PointOffsetType| {
+                geo_shape_index.check_values_any(point_id, |shape| condition.check_shape(shape))
+            }))
+        }
+        FieldIndex::BoolIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::GeoIndex(_)
         | FieldIndex::IntIndex(_)
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
         | FieldIndex::UuidMapIndex(_)
-        | FieldIndex::NullIndex(_) => None,
+        | FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_) => None,
     }
 }
 
@@ -295,12 +567,14 @@ pub fn get_geo_radius_checkers(
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::FloatIndex(_)
         | FieldIndex::FullTextIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
         | FieldIndex::IntIndex(_)
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
         | FieldIndex::UuidMapIndex(_)
-        | FieldIndex::NullIndex(_) => None,
+        | FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_) => None,
     }
 }
 
@@ -320,12 +594,14 @@ pub fn get_geo_bounding_box_checkers(
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::FloatIndex(_)
         | FieldIndex::FullTextIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
         | FieldIndex::IntIndex(_)
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
         | FieldIndex::UuidMapIndex(_)
-        | FieldIndex::NullIndex(_) => None,
+        | FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_) => None,
     }
 }
 
@@ -364,11 +640,13 @@ pub fn get_float_range_checkers(
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::GeoIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
         | FieldIndex::UuidMapIndex(_)
-        | FieldIndex::NullIndex(_) => None,
+        | FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_) => None,
     }
 }
 
@@ -389,12 +667,14 @@ pub fn get_datetime_range_checkers(
         | FieldIndex::FloatIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::GeoIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
         | FieldIndex::IntIndex(_)
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
         | FieldIndex::UuidMapIndex(_)
-        | FieldIndex::NullIndex(_) => None,
+        | FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_) => None,
     }
 }
 
@@ -409,6 +689,8 @@ fn get_is_empty_indexes(
             FieldIndex::NullIndex(null_index) => {
                 primary_null_index = Some(null_index);
             }
+            // Doesn't know values of the field
+            FieldIndex::NestedNullIndex(_) => {}
             _ => {
                 fallback_index = Some(index);
             }
@@ -425,6 +707,18 @@ fn get_null_index_is_empty_checker(
     Box::new(move |point_id: PointOffsetType| null_index.values_is_empty(point_id) == is_empty)
 }
 
+fn get_null_index_is_empty_batch_checker(
+    null_index: &MutableNullIndex,
+    is_empty: bool,
+) -> BatchConditionChecker<'_> {
+    BatchConditionChecker {
+        check: get_null_index_is_empty_checker(null_index, is_empty),
+        check_batch: Box::new(move |point_ids, matches| {
+            null_index.check_is_empty_batch(point_ids, is_empty, matches)
+        }),
+    }
+}
+
 fn get_fallback_is_empty_checker<'a>(
     index: &'a FieldIndex,
     is_empty: bool,
@@ -451,12 +745,14 @@ fn get_is_empty_checker(index: &FieldIndex, is_empty: bool) -> Option<ConditionC
         FieldIndex::NullIndex(null_index) => {
             Some(get_null_index_is_empty_checker(null_index, is_empty))
         }
-        FieldIndex::IntIndex(_)
+        FieldIndex::NestedNullIndex(_)
+        | FieldIndex::IntIndex(_)
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::FloatIndex(_)
         | FieldIndex::GeoIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::BoolIndex(_)
         | FieldIndex::UuidIndex(_)
@@ -469,12 +765,96 @@ fn get_is_null_checker(index: &FieldIndex, is_null: bool) -> Option<ConditionChe
         FieldIndex::NullIndex(null_index) => Some(Box::new(move |point_id: PointOffsetType| {
             null_index.values_is_null(point_id) == is_null
         })),
-        FieldIndex::IntIndex(_)
+        FieldIndex::NestedNullIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::KeywordIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::BoolIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_) => None,
+    }
+}
+
+fn get_is_null_batch_checker(
+    index: &FieldIndex,
+    is_null: bool,
+) -> Option<BatchConditionChecker<'_>> {
+    match index {
+        FieldIndex::NullIndex(null_index) => Some(BatchConditionChecker {
+            check: Box::new(move |point_id: PointOffsetType| {
+                null_index.values_is_null(point_id) == is_null
+            }),
+            check_batch: Box::new(move |point_ids, matches| {
+                null_index.check_is_null_batch(point_ids, is_null, matches)
+            }),
+        }),
+        FieldIndex::NestedNullIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::KeywordIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::BoolIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_) => None,
+    }
+}
+
+fn get_has_field_checker(index: &FieldIndex, has_field: bool) -> Option<ConditionCheckerFn<'_>> {
+    match index {
+        FieldIndex::NullIndex(null_index) if null_index.tracks_field_presence() => {
+            Some(Box::new(move |point_id: PointOffsetType| {
+                null_index.has_field(point_id) == has_field
+            }))
+        }
+        FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::KeywordIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::BoolIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_) => None,
+    }
+}
+
+fn get_has_field_batch_checker(
+    index: &FieldIndex,
+    has_field: bool,
+) -> Option<BatchConditionChecker<'_>> {
+    match index {
+        FieldIndex::NullIndex(null_index) if null_index.tracks_field_presence() => {
+            Some(BatchConditionChecker {
+                check: Box::new(move |point_id: PointOffsetType| {
+                    null_index.has_field(point_id) == has_field
+                }),
+                check_batch: Box::new(move |point_ids, matches| {
+                    null_index.check_has_field_batch(point_ids, has_field, matches)
+                }),
+            })
+        }
+        FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_)
+        | FieldIndex::IntIndex(_)
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::FloatIndex(_)
         | FieldIndex::GeoIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::BoolIndex(_)
         | FieldIndex::UuidIndex(_)
@@ -7,8 +7,8 @@ use crate::index::field_index::FieldIndex;
 use crate::index::query_optimization::optimized_filter::ConditionCheckerFn;
 use crate::payload_storage::condition_checker::INDEXSET_ITER_THRESHOLD;
 use crate::types::{
-    AnyVariants, Match, MatchAny, MatchExcept, MatchPhrase, MatchText, MatchTextAny, MatchValue,
-    ValueVariants,
+    AnyVariants, Match, MatchAny, MatchExcept, MatchNear, MatchPhrase, MatchPrefix, MatchRegex,
+    MatchText, MatchTextAny, MatchValue, ValueVariants,
 };
 
 pub fn get_match_checkers(
@@ -18,15 +18,32 @@ pub fn get_match_checkers(
 ) -> Option<ConditionCheckerFn<'_>> {
     match cond_match {
         Match::Value(MatchValue { value }) => get_match_value_checker(value, index, hw_acc),
-        Match::Text(MatchText { text }) => {
-            get_match_text_checker(text, TextQueryType::Text, index, hw_acc)
-        }
+        Match::Text(MatchText {
+            text,
+            fuzziness,
+            min_token_len,
+            max_token_len,
+        }) => get_match_text_checker(
+            text,
+            TextQueryType::Text {
+                fuzziness,
+                min_token_len,
+                max_token_len,
+            },
+            index,
+            hw_acc,
+        ),
         Match::TextAny(MatchTextAny { text_any }) => {
             get_match_text_checker(text_any, TextQueryType::TextAny, index, hw_acc)
         }
         Match::Phrase(MatchPhrase { phrase }) => {
             get_match_text_checker(phrase, TextQueryType::Phrase, index, hw_acc)
         }
+        Match::Near(MatchNear { near, within }) => {
+            get_match_text_checker(near, TextQueryType::Near(within), index, hw_acc)
+        }
+        Match::Prefix(MatchPrefix { prefix }) => get_match_prefix_checker(prefix, index, hw_acc),
+        Match::Regex(match_regex) => get_match_regex_checker(match_regex, index, hw_acc),
         Match::Any(MatchAny { any }) => get_match_any_checker(any, index, hw_acc),
         Match::Except(MatchExcept { except }) => get_match_except_checker(except, index, hw_acc),
     }
@@ -39,6 +56,7 @@ fn get_match_value_checker(
 ) -> Option<ConditionCheckerFn<'_>> {
     match (value_variant, index) {
         (ValueVariants::String(keyword), FieldIndex::KeywordIndex(index)) => {
+            let keyword = index.normalizer().normalize(&keyword).into_owned();
             let hw_counter = hw_acc.get_counter_cell();
             Some(Box::new(move |point_id: PointOffsetType| {
                 index.check_values_any(point_id, &hw_counter, |k| k == keyword)
@@ -67,31 +85,37 @@ fn get_match_value_checker(
         | (ValueVariants::Bool(_), FieldIndex::FloatIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::FullTextIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::GeoIndex(_))
+        | (ValueVariants::Bool(_), FieldIndex::GeoShapeIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::IntIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::IntMapIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::KeywordIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::UuidIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::UuidMapIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::NullIndex(_))
+        | (ValueVariants::Bool(_), FieldIndex::NestedNullIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::BoolIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::DatetimeIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::FloatIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::FullTextIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::GeoIndex(_))
+        | (ValueVariants::Integer(_), FieldIndex::GeoShapeIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::IntIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::KeywordIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::UuidIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::UuidMapIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::NullIndex(_))
+        | (ValueVariants::Integer(_), FieldIndex::NestedNullIndex(_))
         | (ValueVariants::String(_), FieldIndex::BoolIndex(_))
         | (ValueVariants::String(_), FieldIndex::DatetimeIndex(_))
         | (ValueVariants::String(_), FieldIndex::FloatIndex(_))
         | (ValueVariants::String(_), FieldIndex::FullTextIndex(_))
         | (ValueVariants::String(_), FieldIndex::GeoIndex(_))
+        | (ValueVariants::String(_), FieldIndex::GeoShapeIndex(_))
         | (ValueVariants::String(_), FieldIndex::IntIndex(_))
         | (ValueVariants::String(_), FieldIndex::IntMapIndex(_))
         | (ValueVariants::String(_), FieldIndex::UuidIndex(_))
-        | (ValueVariants::String(_), FieldIndex::NullIndex(_)) => None,
+        | (ValueVariants::String(_), FieldIndex::NullIndex(_))
+        | (ValueVariants::String(_), FieldIndex::NestedNullIndex(_)) => None,
     }
 }
 
@@ -102,6 +126,7 @@ fn get_match_any_checker(
 ) -> Option<ConditionCheckerFn<'_>> {
     match (any_variant, index) {
         (AnyVariants::Strings(list), FieldIndex::KeywordIndex(index)) => {
+            let list = index.normalizer().normalize_set(&list);
             if list.len() < INDEXSET_ITER_THRESHOLD {
                 let hw_counter = hw_acc.get_counter_cell();
                 Some(Box::new(move |point_id: PointOffsetType| {
@@ -154,20 +179,24 @@ fn get_match_any_checker(
         | (AnyVariants::Integers(_), FieldIndex::FloatIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::FullTextIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::GeoIndex(_))
+        | (AnyVariants::Integers(_), FieldIndex::GeoShapeIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::IntIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::KeywordIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::UuidIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::UuidMapIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::NullIndex(_))
+        | (AnyVariants::Integers(_), FieldIndex::NestedNullIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::BoolIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::DatetimeIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::FloatIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::FullTextIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::GeoIndex(_))
+        | (AnyVariants::Strings(_), FieldIndex::GeoShapeIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::IntIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::IntMapIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::UuidIndex(_))
-        | (AnyVariants::Strings(_), FieldIndex::NullIndex(_)) => None,
+        | (AnyVariants::Strings(_), FieldIndex::NullIndex(_))
+        | (AnyVariants::Strings(_), FieldIndex::NestedNullIndex(_)) => None,
     }
 }
 
@@ -178,6 +207,7 @@ fn get_match_except_checker(
 ) -> Option<ConditionCheckerFn<'_>> {
     let checker: Option<ConditionCheckerFn> = match (except, index) {
         (AnyVariants::Strings(list), FieldIndex::KeywordIndex(index)) => {
+            let list = index.normalizer().normalize_set(&list);
             let hw_counter = hw_acc.get_counter_cell();
             if list.len() < INDEXSET_ITER_THRESHOLD {
                 Some(Box::new(move |point_id: PointOffsetType| {
@@ -229,20 +259,24 @@ fn get_match_except_checker(
         | (AnyVariants::Strings(_), FieldIndex::IntMapIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::FloatIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::GeoIndex(_))
+        | (AnyVariants::Strings(_), FieldIndex::GeoShapeIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::FullTextIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::BoolIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::UuidIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::NullIndex(_))
+        | (AnyVariants::Strings(_), FieldIndex::NestedNullIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::IntIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::DatetimeIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::KeywordIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::FloatIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::GeoIndex(_))
+        | (AnyVariants::Integers(_), FieldIndex::GeoShapeIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::FullTextIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::BoolIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::UuidIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::UuidMapIndex(_))
-        | (AnyVariants::Integers(_), FieldIndex::NullIndex(_)) => None,
+        | (AnyVariants::Integers(_), FieldIndex::NullIndex(_))
+        | (AnyVariants::Integers(_), FieldIndex::NestedNullIndex(_)) => None,
     };
 
     if checker.is_none() {
@@ -255,9 +289,79 @@ fn get_match_except_checker(
     checker
 }
 
+fn get_match_prefix_checker(
+    prefix: String,
+    index: &FieldIndex,
+    hw_acc: HwMeasurementAcc,
+) -> Option<ConditionCheckerFn<'_>> {
+    match index {
+        FieldIndex::KeywordIndex(index) => {
+            let prefix = index.normalizer().normalize(&prefix).into_owned();
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_values_any(point_id, &hw_counter, |value| {
+                    value.starts_with(prefix.as_str())
+                })
+            }))
+        }
+        FieldIndex::BoolIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_)
+        | FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_) => None,
+    }
+}
+
+fn get_match_regex_checker(
+    match_regex: MatchRegex,
+    index: &FieldIndex,
+    hw_acc: HwMeasurementAcc,
+) -> Option<ConditionCheckerFn<'_>> {
+    match index {
+        FieldIndex::KeywordIndex(index) => {
+            let regex = index.normalizer().normalize_regex(&match_regex).compile();
+            let Ok(regex) = regex else {
+                return Some(Box::new(|_point_id: PointOffsetType| false));
+            };
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_values_any(point_id, &hw_counter, |value| regex.is_match(value))
+            }))
+        }
+        // Text index only stores tokens, while the regex must match the whole value,
+        // so the condition is checked against the payload instead
+        FieldIndex::FullTextIndex(_) => None,
+        FieldIndex::BoolIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_)
+        | FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_) => None,
+    }
+}
+
 enum TextQueryType {
     Phrase,
-    Text,
+    /// With maximum number of tokens between the matched ones
+    Near(u32),
+    /// With optional fuzziness and overrides of token lengths
+    Text {
+        fuzziness: Option<u8>,
+        min_token_len: Option<usize>,
+        max_token_len: Option<usize>,
+    },
     TextAny,
 }
 
@@ -272,7 +376,22 @@ fn get_match_text_checker(
         FieldIndex::FullTextIndex(full_text_index) => {
             let query_opt = match query_type {
                 TextQueryType::Phrase => full_text_index.parse_phrase_query(&text, &hw_counter),
-                TextQueryType::Text => full_text_index.parse_text_query(&text, &hw_counter),
+                TextQueryType::Near(within) => {
+                    full_text_index.parse_proximity_query(&text, within, &hw_counter)
+                }
+                TextQueryType::Text {
+                    fuzziness,
+                    min_token_len,
+                    max_token_len,
+                } => {
+                    let match_text = MatchText {
+                        text,
+                        fuzziness,
+                        min_token_len,
+                        max_token_len,
+                    };
+                    full_text_index.parse_match_text(&match_text, &hw_counter)
+                }
                 TextQueryType::TextAny => full_text_index.parse_text_any_query(&text, &hw_counter),
             };
 
@@ -288,11 +407,13 @@ fn get_match_text_checker(
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::FloatIndex(_)
         | FieldIndex::GeoIndex(_)
+        | FieldIndex::GeoShapeIndex(_)
         | FieldIndex::IntIndex(_)
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
         | FieldIndex::UuidMapIndex(_)
-        | FieldIndex::NullIndex(_) => None,
+        | FieldIndex::NullIndex(_)
+        | FieldIndex::NestedNullIndex(_) => None,
     }
 }
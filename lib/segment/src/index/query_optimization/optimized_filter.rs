@@ -1,9 +1,21 @@
+use bitvec::prelude::{BitSlice, BitVec};
 use common::types::PointOffsetType;
 
 pub type ConditionCheckerFn<'a> = Box<dyn Fn(PointOffsetType) -> bool + 'a>;
 
+/// Sets bits of the points of a batch which satisfy the condition, other bits are left untouched
+pub type BatchCheckerFn<'a> = Box<dyn Fn(&[PointOffsetType], &mut BitSlice) + 'a>;
+
+/// Condition which can be checked over a whole batch of points at once,
+/// with a columnar scan of a payload index or a bitslice
+pub struct BatchConditionChecker<'a> {
+    pub check: ConditionCheckerFn<'a>,
+    pub check_batch: BatchCheckerFn<'a>,
+}
+
 pub enum OptimizedCondition<'a> {
     Checker(ConditionCheckerFn<'a>),
+    BatchChecker(BatchConditionChecker<'a>),
     /// Nested filter
     Filter(OptimizedFilter<'a>),
 }
@@ -35,6 +47,7 @@ pub fn check_condition(condition: &OptimizedCondition, point_id: PointOffsetType
     match condition {
         OptimizedCondition::Filter(filter) => check_optimized_filter(filter, point_id),
         OptimizedCondition::Checker(checker) => checker(point_id),
+        OptimizedCondition::BatchChecker(checker) => (checker.check)(point_id),
     }
 }
 
@@ -79,3 +92,200 @@ fn check_must_not(must: &Option<Vec<OptimizedCondition>>, point_id: PointOffsetT
         Some(conditions) => conditions.iter().all(check),
     }
 }
+
+/// Retain only points of the batch which satisfy the filter, preserving their order.
+///
+/// Equivalent to [`check_optimized_filter`] for each point, but conditions are evaluated one by
+/// one over the whole batch. Each condition is only checked for points which are not decided yet,
+/// and the data it accesses stays hot in cache while going through the batch.
+pub fn retain_optimized_filter(filter: &OptimizedFilter, point_ids: &mut Vec<PointOffsetType>) {
+    let OptimizedFilter {
+        should,
+        min_should,
+        must,
+        must_not,
+    } = filter;
+
+    // Start with `must` and `must_not`, they usually narrow down the batch the most
+    if let Some(conditions) = must {
+        for condition in conditions {
+            if point_ids.is_empty() {
+                return;
+            }
+            retain_condition(condition, point_ids);
+        }
+    }
+
+    if let Some(conditions) = must_not {
+        let mut counts = vec![0; point_ids.len()];
+        for condition in conditions {
+            count_matching(condition, point_ids, &mut counts, 1);
+        }
+        retain_by_counts(point_ids, counts, |count| count == 0);
+    }
+
+    if let Some(conditions) = should {
+        retain_min_matching(conditions, 1, point_ids);
+    }
+
+    if let Some(OptimizedMinShould {
+        conditions,
+        min_count,
+    }) = min_should
+    {
+        retain_min_matching(conditions, *min_count, point_ids);
+    }
+}
+
+fn retain_condition(condition: &OptimizedCondition, point_ids: &mut Vec<PointOffsetType>) {
+    match condition {
+        OptimizedCondition::Checker(checker) => point_ids.retain(|&point_id| checker(point_id)),
+        OptimizedCondition::BatchChecker(checker) => {
+            let mut matches = check_batch(checker, point_ids).into_iter();
+            point_ids.retain(|_| matches.next().unwrap_or(false));
+        }
+        OptimizedCondition::Filter(filter) => retain_optimized_filter(filter, point_ids),
+    }
+}
+
+/// Bit `i` is set if `point_ids[i]` satisfies the condition
+fn check_batch(checker: &BatchConditionChecker, point_ids: &[PointOffsetType]) -> BitVec {
+    let mut matches = BitVec::repeat(false, point_ids.len());
+    (checker.check_batch)(point_ids, &mut matches);
+    matches
+}
+
+fn retain_min_matching(
+    conditions: &[OptimizedCondition],
+    min_count: usize,
+    point_ids: &mut Vec<PointOffsetType>,
+) {
+    let mut counts = vec![0; point_ids.len()];
+    for condition in conditions {
+        count_matching(condition, point_ids, &mut counts, min_count);
+    }
+    retain_by_counts(point_ids, counts, |count| count >= min_count);
+}
+
+fn retain_by_counts(
+    point_ids: &mut Vec<PointOffsetType>,
+    counts: Vec<usize>,
+    predicate: impl Fn(usize) -> bool,
+) {
+    let mut counts = counts.into_iter();
+    point_ids.retain(|_| counts.next().is_some_and(&predicate));
+}
+
+/// Increment `counts` of points which satisfy the `condition`.
+///
+/// Points which already reached `max_count` are decided, so they are not checked again.
+fn count_matching(
+    condition: &OptimizedCondition,
+    point_ids: &[PointOffsetType],
+    counts: &mut [usize],
+    max_count: usize,
+) {
+    match condition {
+        OptimizedCondition::Checker(checker) => {
+            for (&point_id, count) in point_ids.iter().zip(counts.iter_mut()) {
+                if *count < max_count && checker(point_id) {
+                    *count += 1;
+                }
+            }
+        }
+        OptimizedCondition::BatchChecker(checker) => {
+            // Columnar check of the whole batch is cheaper than picking undecided points
+            let matches = check_batch(checker, point_ids);
+            for (count, is_match) in counts.iter_mut().zip(matches) {
+                if *count < max_count && is_match {
+                    *count += 1;
+                }
+            }
+        }
+        OptimizedCondition::Filter(filter) => {
+            let mut matching: Vec<_> = point_ids
+                .iter()
+                .zip(counts.iter())
+                .filter(|(_, count)| **count < max_count)
+                .map(|(&point_id, _)| point_id)
+                .collect();
+            retain_optimized_filter(filter, &mut matching);
+
+            // Matching points are an ordered subsequence of the undecided ones
+            let mut matching = matching.into_iter().peekable();
+            for (&point_id, count) in point_ids.iter().zip(counts.iter_mut()) {
+                if *count < max_count && matching.next_if_eq(&point_id).is_some() {
+                    *count += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checker<'a>(check: impl Fn(PointOffsetType) -> bool + 'a) -> OptimizedCondition<'a> {
+        OptimizedCondition::Checker(Box::new(check))
+    }
+
+    fn batch_checker<'a>(
+        check: impl Fn(PointOffsetType) -> bool + Clone + 'a,
+    ) -> OptimizedCondition<'a> {
+        OptimizedCondition::BatchChecker(BatchConditionChecker {
+            check: Box::new(check.clone()),
+            check_batch: Box::new(move |point_ids, matches| {
+                for (i, &point_id) in point_ids.iter().enumerate() {
+                    if check(point_id) {
+                        matches.set(i, true);
+                    }
+                }
+            }),
+        })
+    }
+
+    #[test]
+    fn test_retain_optimized_filter_equivalence() {
+        let nested = OptimizedFilter {
+            should: Some(vec![checker(|p| p % 3 == 0), batch_checker(|p| p % 5 == 0)]),
+            min_should: None,
+            must: None,
+            must_not: None,
+        };
+        let filter = OptimizedFilter {
+            should: Some(vec![
+                checker(|p| p < 80),
+                OptimizedCondition::Filter(nested),
+            ]),
+            min_should: Some(OptimizedMinShould {
+                conditions: vec![
+                    checker(|p| p % 2 == 0),
+                    batch_checker(|p| p % 7 == 0),
+                    checker(|p| p > 20),
+                ],
+                min_count: 2,
+            }),
+            must: Some(vec![batch_checker(|p| p != 42)]),
+            must_not: Some(vec![OptimizedCondition::Filter(OptimizedFilter {
+                should: None,
+                min_should: None,
+                must: Some(vec![batch_checker(|p| p % 11 == 0)]),
+                must_not: None,
+            })]),
+        };
+
+        // Unordered batch, with a duplicate
+        let batch: Vec<PointOffsetType> = (0..100).rev().chain([10, 30]).collect();
+
+        let expected: Vec<_> = batch
+            .iter()
+            .copied()
+            .filter(|&point_id| check_optimized_filter(&filter, point_id))
+            .collect();
+
+        let mut point_ids = batch;
+        retain_optimized_filter(&filter, &mut point_ids);
+        assert_eq!(point_ids, expected);
+    }
+}
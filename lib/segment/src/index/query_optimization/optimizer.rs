@@ -127,9 +127,16 @@ impl StructPayloadIndex {
                 }
                 _ => {
                     let estimation = self.condition_cardinality(condition, None, hw_counter);
-                    let condition_checker =
-                        self.condition_converter(condition, payload_provider.clone(), hw_counter);
-                    (OptimizedCondition::Checker(condition_checker), estimation)
+                    let optimized_condition =
+                        match self.batch_condition_converter(condition, hw_counter) {
+                            Some(batch_checker) => OptimizedCondition::BatchChecker(batch_checker),
+                            None => OptimizedCondition::Checker(self.condition_converter(
+                                condition,
+                                payload_provider.clone(),
+                                hw_counter,
+                            )),
+                        };
+                    (optimized_condition, estimation)
                 }
             })
             .collect()
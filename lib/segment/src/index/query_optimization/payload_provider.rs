@@ -34,7 +34,7 @@ impl PayloadProvider {
     {
         let payload_storage_guard = self.payload_storage.borrow();
         let payload_ptr_opt = match payload_storage_guard.deref() {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => {
                 s.payload_ptr(point_id).map(OwnedPayloadRef::from)
             }
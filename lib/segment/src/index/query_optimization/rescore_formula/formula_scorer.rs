@@ -1,3 +1,5 @@
+#[cfg(feature = "wasm-scoring")]
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ops::Neg;
 
@@ -8,10 +10,16 @@ use geo::{Distance, Haversine};
 use serde_json::Value;
 
 use super::parsed_formula::{
-    DatetimeExpression, DecayKind, ParsedExpression, ParsedFormula, PreciseScore, VariableId,
+    DatetimeExpression, DecayKind, ParsedExpression, ParsedFormula, PreciseScore, TextScoreQuery,
+    VariableId, WasmFunctionId,
 };
 use super::value_retriever::VariableRetrieverFn;
+#[cfg(feature = "wasm-scoring")]
+use super::wasm_scorer::WasmScorer;
 use crate::common::operation_error::{OperationError, OperationResult};
+use crate::index::field_index::FieldIndex;
+use crate::index::field_index::full_text_index::bm25::{Bm25CorpusStats, Bm25Scorer};
+use crate::index::field_index::full_text_index::text_index::FullTextIndex;
 use crate::index::query_optimization::optimized_filter::{OptimizedCondition, check_condition};
 use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::index::struct_payload_index::StructPayloadIndex;
@@ -31,6 +39,13 @@ pub struct FormulaScorer<'a> {
     payload_retrievers: HashMap<JsonPath, VariableRetrieverFn<'a>>,
     /// Condition id -> checker function
     condition_checkers: Vec<OptimizedCondition<'a>>,
+    /// Text score id -> BM25 scorer, if the field has a full-text index
+    text_scorers: Vec<Option<Bm25Scorer<'a>>>,
+    /// Text score id -> key of the field
+    text_score_keys: Vec<JsonPath>,
+    /// WASM function id -> sandboxed instance of the function
+    #[cfg(feature = "wasm-scoring")]
+    wasm_scorers: Vec<RefCell<WasmScorer>>,
     /// Default values for all variables
     defaults: HashMap<VariableId, Value>,
 }
@@ -58,18 +73,54 @@ impl FriendlyName for DateTimePayloadType {
 }
 
 impl StructPayloadIndex {
+    /// Add the corpus statistics of this segment for each text score of a formula to `stats`.
+    pub fn fill_text_score_stats(
+        &self,
+        text_scores: &[TextScoreQuery],
+        stats: &mut [Bm25CorpusStats],
+        hw_counter: &HardwareCounterCell,
+    ) {
+        for (
+            TextScoreQuery {
+                key,
+                text,
+                boosts: _,
+            },
+            stats,
+        ) in text_scores.iter().zip(stats)
+        {
+            if let Some(index) = self.full_text_index(key) {
+                index.fill_bm25_stats(text, stats, hw_counter);
+            }
+        }
+    }
+
+    fn full_text_index(&self, key: &JsonPath) -> Option<&FullTextIndex> {
+        self.field_indexes
+            .get(key)?
+            .iter()
+            .find_map(|index| match index {
+                FieldIndex::FullTextIndex(index) => Some(index),
+                _ => None,
+            })
+    }
+
+    /// Text scores use the given corpus statistics, or the statistics of this segment if absent.
     pub fn formula_scorer<'s, 'q>(
         &'s self,
         parsed_formula: &'q ParsedFormula,
         prefetches_scores: &'q [AHashMap<PointOffsetType, ScoreType>],
+        text_score_stats: &[Bm25CorpusStats],
         hw_counter: &'q HardwareCounterCell,
-    ) -> FormulaScorer<'q>
+    ) -> OperationResult<FormulaScorer<'q>>
     where
         's: 'q,
     {
         let ParsedFormula {
             payload_vars,
             conditions,
+            text_scores,
+            wasm_functions,
             defaults,
             formula,
         } = parsed_formula;
@@ -84,13 +135,44 @@ impl StructPayloadIndex {
             .map(|(checker, _estimation)| checker)
             .collect();
 
-        FormulaScorer {
+        let text_scorers = text_scores
+            .iter()
+            .enumerate()
+            .map(|(id, TextScoreQuery { key, text, boosts })| {
+                let index = self.full_text_index(key)?;
+                let scorer = match text_score_stats.get(id) {
+                    Some(stats) => index.bm25_scorer(text, boosts, stats, hw_counter),
+                    None => {
+                        let mut stats = Bm25CorpusStats::default();
+                        index.fill_bm25_stats(text, &mut stats, hw_counter);
+                        index.bm25_scorer(text, boosts, &stats, hw_counter)
+                    }
+                };
+                Some(scorer)
+            })
+            .collect();
+        let text_score_keys = text_scores.iter().map(|query| query.key.clone()).collect();
+
+        // Instances are not shared between scorers, so that calls don't need synchronization
+        #[cfg(feature = "wasm-scoring")]
+        let wasm_scorers = wasm_functions
+            .iter()
+            .map(|function| function.compiled().instantiate().map(RefCell::new))
+            .collect::<OperationResult<_>>()?;
+        #[cfg(not(feature = "wasm-scoring"))]
+        debug_assert!(wasm_functions.is_empty(), "WASM functions can't be parsed");
+
+        Ok(FormulaScorer {
             formula: formula.clone(),
             prefetches_scores,
             payload_retrievers,
             condition_checkers,
+            text_scorers,
+            text_score_keys,
+            #[cfg(feature = "wasm-scoring")]
+            wasm_scorers,
             defaults: defaults.clone(),
-        }
+        })
     }
 }
 
@@ -174,6 +256,19 @@ impl FormulaScorer<'_> {
 
                 Ok(float_seconds)
             }
+            ParsedExpression::TextScore(id) => match &self.text_scorers[*id] {
+                Some(scorer) => Ok(scorer.score(point_id)),
+                None => Err(OperationError::MissingFullTextIndexForTextScore {
+                    key: self.text_score_keys[*id].to_string(),
+                }),
+            },
+            ParsedExpression::Wasm { function, args } => {
+                let args = args
+                    .iter()
+                    .map(|arg| self.eval_expression(arg, point_id))
+                    .collect::<OperationResult<Vec<_>>>()?;
+                self.call_wasm(*function, &args)
+            }
             ParsedExpression::Mult(expressions) => {
                 let mut product = 1.0;
                 for expr in expressions {
@@ -302,6 +397,26 @@ impl FormulaScorer<'_> {
         }
     }
 
+    #[cfg(feature = "wasm-scoring")]
+    fn call_wasm(
+        &self,
+        function: WasmFunctionId,
+        args: &[PreciseScore],
+    ) -> OperationResult<PreciseScore> {
+        self.wasm_scorers[function].borrow_mut().call(args)
+    }
+
+    #[cfg(not(feature = "wasm-scoring"))]
+    fn call_wasm(
+        &self,
+        _function: WasmFunctionId,
+        _args: &[PreciseScore],
+    ) -> OperationResult<PreciseScore> {
+        Err(OperationError::service_error(
+            "WASM scoring functions are not supported without `wasm-scoring` feature",
+        ))
+    }
+
     fn get_payload_value(&self, json_path: &JsonPath, point_id: PointOffsetType) -> Option<Value> {
         self.payload_retrievers
             .get(json_path)
@@ -374,6 +489,10 @@ mod tests {
 
     use super::*;
     use crate::index::query_optimization::rescore_formula::parsed_formula::PreciseScoreOrdered;
+    #[cfg(feature = "wasm-scoring")]
+    use crate::index::query_optimization::rescore_formula::parsed_formula::WasmFunction;
+    #[cfg(feature = "wasm-scoring")]
+    use crate::index::query_optimization::rescore_formula::wasm_scorer::tests::ADD_WASM;
     use crate::json_path::JsonPath;
 
     const FIELD_NAME: &str = "number";
@@ -383,6 +502,7 @@ mod tests {
     const GEO_FIELD_NAME: &str = "geo_point";
     const NO_VALUE_GEO_POINT: &str = "no_value_geo_point";
     const NO_VALUE_DATETIME: &str = "no_value_datetime";
+    const NO_INDEX_TEXT_FIELD_NAME: &str = "no_index_text";
 
     // self_cell just to be able to create FormulaScorer with a "reference" to fixture scores
     self_cell::self_cell!(
@@ -430,6 +550,16 @@ mod tests {
                 prefetches_scores,
                 payload_retrievers,
                 condition_checkers,
+                text_scorers: vec![None],
+                text_score_keys: vec![JsonPath::new(NO_INDEX_TEXT_FIELD_NAME)],
+                #[cfg(feature = "wasm-scoring")]
+                wasm_scorers: vec![RefCell::new(
+                    WasmFunction::new(ADD_WASM.to_vec(), 2, None)
+                        .unwrap()
+                        .compiled()
+                        .instantiate()
+                        .unwrap(),
+                )],
                 defaults: defaults.clone(),
             }
         })
@@ -491,6 +621,8 @@ mod tests {
         ParsedExpression::new_ln(ParsedExpression::Constant(PreciseScoreOrdered::from(0.0))),
         0.0
     )]
+    #[should_panic(expected = r#"MissingFullTextIndexForTextScore { key: "no_index_text" }"#)]
+    #[case(ParsedExpression::TextScore(0), 0.0)]
     #[test]
     fn test_evaluation(#[case] expr: ParsedExpression, #[case] expected: PreciseScore) {
         let defaults = HashMap::new();
@@ -501,6 +633,38 @@ mod tests {
         assert_eq!(scorer.eval_expression(&expr, 0).unwrap(), expected);
     }
 
+    #[cfg(feature = "wasm-scoring")]
+    #[test]
+    fn test_wasm_evaluation() {
+        let defaults = HashMap::new();
+        let scorer_fixture = make_formula_scorer(&defaults);
+
+        let scorer = scorer_fixture.borrow_dependent();
+
+        // Arguments are evaluated before calling the function
+        let expr = ParsedExpression::Wasm {
+            function: 0,
+            args: vec![
+                ParsedExpression::new_score_id(0),
+                ParsedExpression::new_payload_id(JsonPath::new(FIELD_NAME)),
+            ],
+        };
+        assert_eq!(scorer.eval_expression(&expr, 0).unwrap(), 1.0 + 85.0);
+
+        // Errors of the arguments are propagated
+        let expr = ParsedExpression::Wasm {
+            function: 0,
+            args: vec![
+                ParsedExpression::new_score_id(0),
+                ParsedExpression::new_payload_id(JsonPath::new("missing_field")),
+            ],
+        };
+        assert!(matches!(
+            scorer.eval_expression(&expr, 0),
+            Err(OperationError::VariableTypeError { .. }),
+        ));
+    }
+
     // Default values
     #[rstest]
     // Defined default score
@@ -1,3 +1,5 @@
 mod formula_scorer;
 pub mod parsed_formula;
 mod value_retriever;
+#[cfg(feature = "wasm-scoring")]
+pub mod wasm_scorer;
@@ -1,14 +1,18 @@
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
+use std::sync::Arc;
 
 use common::types::ScoreType;
 use ordered_float::OrderedFloat;
 use serde::Serialize;
 use serde_json::Value;
 
+#[cfg(feature = "wasm-scoring")]
+use super::wasm_scorer::WasmScoringFunction;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::unordered_hash_unique;
+use crate::index::field_index::full_text_index::bm25::TermBoost;
 use crate::json_path::{JsonPath, JsonPathItem};
 use crate::types::{Condition, DateTimePayloadType, GeoPoint};
 
@@ -16,7 +20,14 @@ const SCORE_KEYWORD: &str = "score";
 const DEFAULT_DECAY_MIDPOINT: f32 = 0.5;
 const DEFAULT_DECAY_SCALE: f32 = 1.0;
 
+/// Fuel available to a WASM scoring function for a single point, if not specified explicitly
+pub const DEFAULT_WASM_FUEL_LIMIT: u64 = 100_000;
+/// Upper bound of the fuel a WASM scoring function may request for a single point
+pub const MAX_WASM_FUEL_LIMIT: u64 = 10_000_000;
+
 pub type ConditionId = usize;
+pub type TextScoreId = usize;
+pub type WasmFunctionId = usize;
 pub type PreciseScore = f64;
 pub type PreciseScoreOrdered = OrderedFloat<PreciseScore>;
 
@@ -28,6 +39,12 @@ pub struct ParsedFormula {
     /// Conditions used in the formula. Their index in the array is used as a variable id
     pub conditions: Vec<Condition>,
 
+    /// Full-text relevance scores used in the formula. Their index in the array is used as an id
+    pub text_scores: Vec<TextScoreQuery>,
+
+    /// User-defined WASM functions used in the formula. Their index in the array is used as an id
+    pub wasm_functions: Vec<WasmFunction>,
+
     /// Defaults to use when variable is not found
     pub defaults: HashMap<VariableId, Value>,
 
@@ -40,12 +57,16 @@ impl Hash for ParsedFormula {
         let Self {
             payload_vars,
             conditions,
+            text_scores,
+            wasm_functions,
             defaults,
             formula,
         } = self;
 
         unordered_hash_unique(state, payload_vars.iter());
         conditions.hash(state);
+        text_scores.hash(state);
+        wasm_functions.hash(state);
         unordered_hash_unique(state, defaults.iter());
         formula.hash(state);
     }
@@ -61,8 +82,13 @@ pub enum ParsedExpression {
         key: JsonPath,
     },
     Datetime(DatetimeExpression),
+    TextScore(TextScoreId),
 
     // Nested
+    Wasm {
+        function: WasmFunctionId,
+        args: Vec<ParsedExpression>,
+    },
     Mult(Vec<ParsedExpression>),
     Sum(Vec<ParsedExpression>),
     Div {
@@ -121,6 +147,101 @@ impl VariableId {
     }
 }
 
+/// BM25 relevance of the values of a full-text indexed field to a text
+#[derive(Debug, Clone, PartialEq, Hash, Serialize)]
+pub struct TextScoreQuery {
+    pub key: JsonPath,
+    pub text: String,
+    /// Weights of individual terms of the text
+    pub boosts: Vec<TermBoost>,
+}
+
+impl TextScoreQuery {
+    pub fn new(key: JsonPath, text: String, boosts: Vec<(String, f32)>) -> OperationResult<Self> {
+        let boosts = boosts
+            .into_iter()
+            .map(|(term, boost)| {
+                if !boost.is_finite() || boost < 0.0 {
+                    return Err(OperationError::validation_error(format!(
+                        "Boost of term \"{term}\" should be a non-negative number, got {boost}."
+                    )));
+                }
+                Ok(TermBoost {
+                    term,
+                    boost: OrderedFloat(boost),
+                })
+            })
+            .collect::<OperationResult<_>>()?;
+
+        Ok(Self { key, text, boosts })
+    }
+}
+
+/// User-defined scoring function, compiled to WebAssembly and executed in a sandbox
+#[derive(Debug, Clone, Serialize)]
+pub struct WasmFunction {
+    /// Binary of the WebAssembly module, as provided by the user
+    #[serde(skip)]
+    pub module: Arc<[u8]>,
+    /// Fuel available to the function for scoring a single point
+    pub fuel_limit: u64,
+    #[cfg(feature = "wasm-scoring")]
+    #[serde(skip)]
+    compiled: Arc<WasmScoringFunction>,
+}
+
+impl WasmFunction {
+    /// Validate and compile the module, which is going to be called with `arity` arguments
+    #[cfg(feature = "wasm-scoring")]
+    pub fn new(module: Vec<u8>, arity: usize, fuel_limit: Option<u64>) -> OperationResult<Self> {
+        let fuel_limit = Self::check_fuel_limit(fuel_limit)?;
+        let compiled = WasmScoringFunction::new(&module, arity, fuel_limit)?;
+
+        Ok(Self {
+            module: module.into(),
+            fuel_limit,
+            compiled: Arc::new(compiled),
+        })
+    }
+
+    #[cfg(not(feature = "wasm-scoring"))]
+    pub fn new(_module: Vec<u8>, _arity: usize, fuel_limit: Option<u64>) -> OperationResult<Self> {
+        Self::check_fuel_limit(fuel_limit)?;
+        Err(OperationError::validation_error(
+            "WASM scoring functions are not supported, Qdrant is built without `wasm-scoring` feature",
+        ))
+    }
+
+    fn check_fuel_limit(fuel_limit: Option<u64>) -> OperationResult<u64> {
+        let fuel_limit = fuel_limit.unwrap_or(DEFAULT_WASM_FUEL_LIMIT);
+        if fuel_limit == 0 || fuel_limit > MAX_WASM_FUEL_LIMIT {
+            return Err(OperationError::validation_error(format!(
+                "WASM fuel limit should be in the range [1, {MAX_WASM_FUEL_LIMIT}], got {fuel_limit}."
+            )));
+        }
+        Ok(fuel_limit)
+    }
+
+    #[cfg(feature = "wasm-scoring")]
+    pub fn compiled(&self) -> &WasmScoringFunction {
+        &self.compiled
+    }
+}
+
+impl PartialEq for WasmFunction {
+    fn eq(&self, other: &Self) -> bool {
+        // Compiled module is derived from the binary
+        self.module == other.module && self.fuel_limit == other.fuel_limit
+    }
+}
+
+impl Hash for WasmFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.module.hash(state);
+        self.fuel_limit.hash(state);
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Hash, Serialize)]
 pub enum DatetimeExpression {
     Constant(DateTimePayloadType),
@@ -361,6 +482,39 @@ mod tests {
         assert!(VariableId::from_str("").is_err());
     }
 
+    #[test]
+    fn test_text_score_query_boosts() {
+        let new_query = |boost: f32| {
+            TextScoreQuery::new(
+                JsonPath::new("text"),
+                "rust vector".to_string(),
+                vec![("rust".to_string(), boost)],
+            )
+        };
+
+        let query = new_query(2.0).unwrap();
+        assert_eq!(query.boosts[0].term, "rust");
+        assert_eq!(query.boosts[0].boost, OrderedFloat(2.0));
+        assert!(new_query(0.0).is_ok());
+
+        assert!(new_query(-1.0).is_err());
+        assert!(new_query(f32::NAN).is_err());
+        assert!(new_query(f32::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_wasm_function_fuel_limit() {
+        let new_function = |fuel_limit| WasmFunction::new(Vec::new(), 0, Some(fuel_limit));
+
+        for fuel_limit in [0, MAX_WASM_FUEL_LIMIT + 1] {
+            let err = new_function(fuel_limit).unwrap_err();
+            assert!(err.to_string().contains("fuel limit"), "{err}");
+        }
+
+        // Valid limit, but not a valid module
+        assert!(new_function(DEFAULT_WASM_FUEL_LIMIT).is_err());
+    }
+
     /// Tests that lambda can be communicated to peers in the form of its components, and be recalculated appropriately
     fn check_lambda_round_trip(lambda: PreciseScore, kind: DecayKind) {
         let (midpoint, scale) = ParsedExpression::decay_lambda_to_params(lambda, kind);
@@ -207,7 +207,9 @@ fn indexed_variable_retriever(index: &FieldIndex) -> Option<VariableRetrieverFn<
             Some(Box::new(extract_fn))
         }
         FieldIndex::FullTextIndex(_) => None, // Better get it from the payload
+        FieldIndex::GeoShapeIndex(_) => None, // Better get it from the payload
         FieldIndex::NullIndex(_) => None,     // There should be other index for the same field
+        FieldIndex::NestedNullIndex(_) => None, // There should be other index for the same field
     }
 }
 
@@ -222,8 +224,8 @@ mod tests {
     use serde_json::{Value, from_value, json};
 
     use crate::common::utils::MultiValue;
-    use crate::index::field_index::geo_index::GeoMapIndex;
-    use crate::index::field_index::numeric_index::NumericIndex;
+    use crate::index::field_index::geo_index::{GeoHashConfig, GeoMapIndex};
+    use crate::index::field_index::numeric_index::{NumericHistogramConfig, NumericIndex};
     use crate::index::field_index::{FieldIndex, FieldIndexBuilderTrait};
     use crate::index::query_optimization::payload_provider::PayloadProvider;
     use crate::index::query_optimization::rescore_formula::value_retriever::variable_retriever;
@@ -338,7 +340,8 @@ mod tests {
 
         // Create a field index for a number.
         let dir = tempfile::tempdir().unwrap();
-        let mut builder = NumericIndex::builder_mmap(dir.path(), false);
+        let mut builder =
+            NumericIndex::builder_mmap(dir.path(), false, NumericHistogramConfig::default());
         builder.add_point(0, &[&42.into()], &hw_counter).unwrap();
         builder.add_point(1, &[], &hw_counter).unwrap();
         builder
@@ -349,7 +352,7 @@ mod tests {
 
         // Create a field index for a geo point.
         let dir = tempfile::tempdir().unwrap();
-        let mut builder = GeoMapIndex::builder_mmap(dir.path(), false);
+        let mut builder = GeoMapIndex::builder_mmap(dir.path(), false, GeoHashConfig::default());
 
         builder.add_point(0, &[], &hw_counter).unwrap();
         builder
@@ -363,7 +366,8 @@ mod tests {
 
         // Create a field index for datetime
         let dir = tempfile::tempdir().unwrap();
-        let mut builder = NumericIndex::builder_mmap(dir.path(), false);
+        let mut builder =
+            NumericIndex::builder_mmap(dir.path(), false, NumericHistogramConfig::default());
 
         builder
             .add_point(0, &[&json!("2023-01-01T00:00:00Z")], &hw_counter)
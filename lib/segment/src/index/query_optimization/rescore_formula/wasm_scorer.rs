@@ -0,0 +1,227 @@
+//! Sandbox for user-defined scoring functions compiled to WebAssembly.
+//!
+//! A scoring module must export a function named [`SCORE_FUNCTION_NAME`], which takes one `f64`
+//! per argument of the `wasm` formula expression, and returns the result as `f64`.
+//!
+//! Modules can't import anything from the host, and every call is limited by a fuel budget,
+//! so a misbehaving function can neither escape the sandbox nor stall the search.
+
+use wasmi::core::{TrapCode, ValType};
+use wasmi::{Config, Engine, ExternType, Func, Linker, Module, Store, Val};
+
+use super::parsed_formula::PreciseScore;
+use crate::common::operation_error::{OperationError, OperationResult};
+
+/// Name of the function the scoring module must export
+pub const SCORE_FUNCTION_NAME: &str = "score";
+
+/// A compiled and validated scoring module
+#[derive(Debug)]
+pub struct WasmScoringFunction {
+    engine: Engine,
+    module: Module,
+    /// Number of `f64` arguments the function takes
+    arity: usize,
+    /// Fuel available for a single call of the function
+    fuel_limit: u64,
+}
+
+impl WasmScoringFunction {
+    pub fn new(wasm: &[u8], arity: usize, fuel_limit: u64) -> OperationResult<Self> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+
+        let module = Module::new(&engine, wasm).map_err(|err| {
+            OperationError::validation_error(format!("Invalid scoring module: {err}"))
+        })?;
+
+        if let Some(import) = module.imports().next() {
+            return Err(OperationError::validation_error(format!(
+                "Scoring module must not import anything, found import `{}::{}`",
+                import.module(),
+                import.name(),
+            )));
+        }
+
+        let func_type = module
+            .exports()
+            .find(|export| export.name() == SCORE_FUNCTION_NAME)
+            .and_then(|export| match export.ty() {
+                ExternType::Func(func_type) => Some(func_type.clone()),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                OperationError::validation_error(format!(
+                    "Scoring module must export a `{SCORE_FUNCTION_NAME}` function",
+                ))
+            })?;
+
+        let params_ok = func_type.params().len() == arity
+            && func_type.params().iter().all(|ty| *ty == ValType::F64);
+        let results_ok = func_type.results() == [ValType::F64];
+        if !params_ok || !results_ok {
+            return Err(OperationError::validation_error(format!(
+                "Function `{SCORE_FUNCTION_NAME}` must take {arity} f64 parameters \
+                 and return a single f64",
+            )));
+        }
+
+        Ok(Self {
+            engine,
+            module,
+            arity,
+            fuel_limit,
+        })
+    }
+
+    /// Create a fresh instance of the module, which can be used to score many points
+    pub fn instantiate(&self) -> OperationResult<WasmScorer> {
+        let mut store = Store::new(&self.engine, ());
+        // Start function is limited by the same budget as a single call
+        set_fuel(&mut store, self.fuel_limit)?;
+
+        let linker = Linker::<()>::new(&self.engine);
+        let instance = linker
+            .instantiate(&mut store, &self.module)
+            .and_then(|pre| pre.start(&mut store))
+            .map_err(|err| wasm_runtime_error(&err))?;
+
+        let func = instance
+            .get_func(&store, SCORE_FUNCTION_NAME)
+            .ok_or_else(|| {
+                OperationError::service_error(format!(
+                    "validated scoring module has no `{SCORE_FUNCTION_NAME}` function",
+                ))
+            })?;
+
+        Ok(WasmScorer {
+            fuel_limit: self.fuel_limit,
+            store,
+            func,
+            params: Vec::with_capacity(self.arity),
+        })
+    }
+}
+
+/// An instance of a scoring module, reused between calls
+pub struct WasmScorer {
+    fuel_limit: u64,
+    store: Store<()>,
+    func: Func,
+    params: Vec<Val>,
+}
+
+impl WasmScorer {
+    /// Call the scoring function with the given arguments
+    pub fn call(&mut self, args: &[PreciseScore]) -> OperationResult<PreciseScore> {
+        set_fuel(&mut self.store, self.fuel_limit)?;
+
+        self.params.clear();
+        self.params
+            .extend(args.iter().map(|value| Val::F64((*value).into())));
+
+        let mut results = [Val::F64(0.0.into())];
+        self.func
+            .call(&mut self.store, &self.params, &mut results)
+            .map_err(|err| wasm_runtime_error(&err))?;
+
+        let value = results[0]
+            .f64()
+            .map(|value| value.to_float())
+            .ok_or_else(|| {
+                OperationError::service_error("scoring function returned non-f64 value")
+            })?;
+
+        if !value.is_finite() {
+            return Err(OperationError::NonFiniteNumber {
+                expression: format!("{SCORE_FUNCTION_NAME}({args:?}) = {value}"),
+            });
+        }
+        Ok(value)
+    }
+}
+
+fn set_fuel(store: &mut Store<()>, fuel: u64) -> OperationResult<()> {
+    store
+        .set_fuel(fuel)
+        .map_err(|err| OperationError::service_error(format!("failed to set fuel: {err}")))
+}
+
+fn wasm_runtime_error(err: &wasmi::Error) -> OperationError {
+    match err.as_trap_code() {
+        Some(TrapCode::OutOfFuel) => {
+            OperationError::validation_error("Scoring function exceeded its fuel limit")
+        }
+        _ => OperationError::validation_error(format!("Scoring function failed: {err}")),
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use crate::index::query_optimization::rescore_formula::parsed_formula::DEFAULT_WASM_FUEL_LIMIT;
+
+    /// `(module (func (export "score") (param f64 f64) (result f64) local.get 0 local.get 1 f64.add))`
+    pub const ADD_WASM: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x07, 0x01, 0x60, 0x02, 0x7c, 0x7c, 0x01, 0x7c, // type section
+        0x03, 0x02, 0x01, 0x00, // function section
+        0x07, 0x09, 0x01, 0x05, b's', b'c', b'o', b'r', b'e', 0x00, 0x00, // export section
+        0x0a, 0x09, 0x01, 0x07, 0x00, 0x20, 0x00, 0x20, 0x01, 0xa0, 0x0b, // code section
+    ];
+
+    /// `(module (func (export "score") (param f64) (result f64) (loop br 0) f64.const 0))`
+    const INFINITE_LOOP_WASM: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x06, 0x01, 0x60, 0x01, 0x7c, 0x01, 0x7c, // type section
+        0x03, 0x02, 0x01, 0x00, // function section
+        0x07, 0x09, 0x01, 0x05, b's', b'c', b'o', b'r', b'e', 0x00, 0x00, // export section
+        0x0a, 0x12, 0x01, 0x10, 0x00, 0x03, 0x40, 0x0c, 0x00, 0x0b, 0x44, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0b, // code section
+    ];
+
+    #[test]
+    fn test_wasm_scoring_function() {
+        let function = WasmScoringFunction::new(ADD_WASM, 2, DEFAULT_WASM_FUEL_LIMIT).unwrap();
+
+        let mut scorer = function.instantiate().unwrap();
+        assert_eq!(scorer.call(&[1.5, 2.0]).unwrap(), 3.5);
+        assert_eq!(scorer.call(&[-1.0, 0.25]).unwrap(), -0.75);
+
+        assert!(matches!(
+            scorer.call(&[1.0, PreciseScore::INFINITY]),
+            Err(OperationError::NonFiniteNumber { .. }),
+        ));
+    }
+
+    #[test]
+    fn test_wasm_scoring_function_signature_mismatch() {
+        // Function takes two arguments, but three are passed
+        let result = WasmScoringFunction::new(ADD_WASM, 3, DEFAULT_WASM_FUEL_LIMIT);
+        assert!(matches!(
+            result,
+            Err(OperationError::ValidationError { .. })
+        ));
+
+        let result = WasmScoringFunction::new(b"not a wasm module", 0, DEFAULT_WASM_FUEL_LIMIT);
+        assert!(matches!(
+            result,
+            Err(OperationError::ValidationError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_wasm_scoring_function_out_of_fuel() {
+        let function =
+            WasmScoringFunction::new(INFINITE_LOOP_WASM, 1, DEFAULT_WASM_FUEL_LIMIT).unwrap();
+
+        let mut scorer = function.instantiate().unwrap();
+        let err = scorer.call(&[1.0]).unwrap_err();
+        assert!(err.to_string().contains("fuel limit"), "{err}");
+
+        // Fuel is refilled for every call, so the instance stays usable
+        let err = scorer.call(&[1.0]).unwrap_err();
+        assert!(err.to_string().contains("fuel limit"), "{err}");
+    }
+}
@@ -307,7 +307,8 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             top,
             deleted_point_bitslice,
             vector_query_context.hardware_counter(),
-        )?;
+        )?
+        .with_score_threshold(vector_query_context.score_threshold());
         let hw_counter = vector_query_context.hardware_counter();
         let mut results = match filter {
             Some(filter) => {
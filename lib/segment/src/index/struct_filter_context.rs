@@ -1,6 +1,8 @@
 use common::types::PointOffsetType;
 
-use crate::index::query_optimization::optimized_filter::{OptimizedFilter, check_optimized_filter};
+use crate::index::query_optimization::optimized_filter::{
+    OptimizedFilter, check_optimized_filter, retain_optimized_filter,
+};
 use crate::payload_storage::FilterContext;
 
 pub struct StructFilterContext<'a> {
@@ -17,4 +19,8 @@ impl FilterContext for StructFilterContext<'_> {
     fn check(&self, point_id: PointOffsetType) -> bool {
         check_optimized_filter(&self.optimized_filter, point_id)
     }
+
+    fn retain_matching(&self, point_ids: &mut Vec<PointOffsetType>) {
+        retain_optimized_filter(&self.optimized_filter, point_ids);
+    }
 }
@@ -21,9 +21,14 @@ use super::field_index::index_selector::{
 use super::field_index::{FieldIndexBuilderTrait as _, ResolvedHasId};
 use super::payload_config::{FullPayloadIndexType, PayloadFieldSchemaWithIndexType};
 use crate::common::Flusher;
+use crate::common::distinct_values_sketch::DistinctValuesSketch;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::IndexesMap;
+use crate::data_types::facets::FacetValueRef;
+use crate::data_types::filter_explanation::FilterPlan;
 use crate::id_tracker::IdTrackerSS;
+use crate::index::field_index::null_index::NestedNullIndex;
+use crate::index::field_index::null_index::nested_null_index::NestedNullCheck;
 use crate::index::field_index::{
     CardinalityEstimation, FieldIndex, PayloadBlockCondition, PrimaryCondition,
 };
@@ -38,11 +43,14 @@ use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::payload_storage::{FilterContext, PayloadStorage};
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    Condition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition, Payload,
+    Condition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition, NestedCondition, Payload,
     PayloadContainer, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, VectorNameBuf,
 };
 use crate::vector_storage::{VectorStorage, VectorStorageEnum};
 
+/// Directory inside the payload index dir, where indexes of fields with a changed schema are built
+const REBUILD_DIR: &str = "rebuild";
+
 #[derive(Debug)]
 #[allow(clippy::enum_variant_names)]
 enum StorageType {
@@ -106,6 +114,12 @@ impl StructPayloadIndex {
         nested_path: Option<&JsonPath>,
         hw_counter: &HardwareCounterCell,
     ) -> Option<CardinalityEstimation> {
+        if condition.compare.is_some() {
+            // Indexes hold values of a single field, so they can't tell how values of
+            // different fields relate
+            return Some(CardinalityEstimation::unknown(self.available_point_count()));
+        }
+
         let full_path = JsonPath::extend_or_new(nested_path, &condition.key);
         self.field_indexes.get(&full_path).and_then(|indexes| {
             // rewrite condition with fullpath to enable cardinality estimation
@@ -120,6 +134,47 @@ impl StructPayloadIndex {
         })
     }
 
+    /// Nested null index, which answers the nested condition without reading the payload.
+    ///
+    /// Only nested filters of a single `is_empty` or `is_null` condition are answered by it.
+    pub(super) fn nested_null_index(
+        &self,
+        nested: &NestedCondition,
+        nested_path: Option<&JsonPath>,
+    ) -> Option<(&NestedNullIndex, NestedNullCheck)> {
+        let Filter {
+            should: None,
+            min_should: None,
+            must: Some(must),
+            must_not: None,
+        } = nested.filter()
+        else {
+            return None;
+        };
+
+        let (key, check) = match must.as_slice() {
+            [Condition::IsEmpty(IsEmptyCondition { is_empty })] => {
+                (&is_empty.key, NestedNullCheck::IsEmpty)
+            }
+            [Condition::IsNull(IsNullCondition { is_null })] => {
+                (&is_null.key, NestedNullCheck::IsNull)
+            }
+            _ => return None,
+        };
+
+        let elements_path = JsonPath::extend_or_new(nested_path, &nested.array_key());
+        let full_path = elements_path.extend(key);
+        self.field_indexes
+            .get(&full_path)?
+            .iter()
+            .find_map(|index| match index {
+                FieldIndex::NestedNullIndex(index) if *index.elements_path() == elements_path => {
+                    Some((index, check))
+                }
+                _ => None,
+            })
+    }
+
     fn query_field<'a>(
         &'a self,
         condition: &'a PrimaryCondition,
@@ -158,6 +213,14 @@ impl StructPayloadIndex {
         for (field, payload_schema) in indices.iter_mut() {
             let (field_index, dirty) =
                 self.load_from_db(field, payload_schema, create_if_missing)?;
+
+            // Prefault pages of the on-disk indexes, if configured for the field
+            if payload_schema.schema.populate() {
+                for index in &field_index {
+                    index.populate()?;
+                }
+            }
+
             field_indexes.insert(field.clone(), field_index);
             is_dirty |= dirty;
         }
@@ -451,16 +514,35 @@ impl StructPayloadIndex {
         field: PayloadKeyTypeRef,
         payload_schema: &PayloadFieldSchema,
         hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<FieldIndex>> {
+        self.build_field_indexes_in(
+            self.selector(payload_schema),
+            &self.path,
+            field,
+            payload_schema,
+            hw_counter,
+        )
+    }
+
+    /// Build indexes of the field with the given selector, with special indexes stored in `dir`
+    fn build_field_indexes_in(
+        &self,
+        selector: IndexSelector<'_>,
+        dir: &Path,
+        field: PayloadKeyTypeRef,
+        payload_schema: &PayloadFieldSchema,
+        hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<FieldIndex>> {
         let payload_storage = self.payload.borrow();
-        let mut builders = self
-            .selector(payload_schema)
-            .index_builder(field, payload_schema)?;
+        let mut builders = selector.index_builder(field, payload_schema)?;
 
         // Special null index complements every index.
-        let null_index = IndexSelector::null_builder(&self.path, field)?;
+        let null_index = IndexSelector::null_builder(dir, field)?;
         builders.push(null_index);
 
+        // Fields inside array elements are also checked per element by nested filters
+        builders.extend(IndexSelector::nested_null_builder(dir, field)?);
+
         for index in &mut builders {
             index.init()?;
         }
@@ -469,7 +551,12 @@ impl StructPayloadIndex {
             |point_id, point_payload| {
                 let field_value = &point_payload.get_value(field);
                 for builder in builders.iter_mut() {
-                    builder.add_point(point_id, field_value, hw_counter)?;
+                    if let Some(elements_path) = builder.elements_path() {
+                        let elements = point_payload.get_value(elements_path);
+                        builder.add_point(point_id, &elements, hw_counter)?;
+                    } else {
+                        builder.add_point(point_id, field_value, hw_counter)?;
+                    }
                 }
                 Ok(true)
             },
@@ -515,6 +602,10 @@ impl StructPayloadIndex {
         match condition {
             Condition::Filter(_) => panic!("Unexpected branching"),
             Condition::Nested(nested) => {
+                if let Some((index, check)) = self.nested_null_index(nested, nested_path) {
+                    return CardinalityEstimation::exact(index.count(check));
+                }
+
                 // propagate complete nested path in case of multiple nested layers
                 let full_path = JsonPath::extend_or_new(nested_path, &nested.array_key());
                 self.estimate_nested_cardinality(nested.filter(), &full_path, hw_counter)
@@ -613,6 +704,49 @@ impl StructPayloadIndex {
             .unwrap_or(false)
     }
 
+    /// Approximate set of distinct values of the field, if it has a map index
+    pub fn distinct_values_sketch(
+        &self,
+        field: PayloadKeyTypeRef,
+    ) -> Option<&DistinctValuesSketch> {
+        self.field_indexes
+            .get(field)?
+            .iter()
+            .find_map(|index| index.distinct_values_sketch())
+    }
+
+    pub fn tenant_subgraphs_max_points(&self, field: &PayloadKeyType) -> Option<usize> {
+        self.config
+            .indices
+            .get(field)
+            .and_then(|indexed_field| indexed_field.schema.tenant_subgraphs_max_points())
+    }
+
+    /// Strategy [`Self::iter_filtered_points`] uses for the filter, and whether points selected
+    /// by the primary clauses are checked against the whole filter
+    pub fn filter_plan(
+        &self,
+        filter: &Filter,
+        query_cardinality: &CardinalityEstimation,
+        hw_counter: &HardwareCounterCell,
+    ) -> (FilterPlan, bool) {
+        let primary_clauses_are_indexed = !query_cardinality.primary_clauses.is_empty()
+            && query_cardinality
+                .primary_clauses
+                .iter()
+                .all(|clause| self.query_field(clause, hw_counter).is_some());
+
+        if !primary_clauses_are_indexed {
+            return (FilterPlan::FullScan, true);
+        }
+
+        let all_conditions_are_primary = filter
+            .iter_conditions()
+            .all(|condition| query_cardinality.is_primary(condition));
+
+        (FilterPlan::IndexIteration, !all_conditions_are_primary)
+    }
+
     pub fn iter_filtered_points<'a>(
         &'a self,
         filter: &'a Filter,
@@ -721,6 +855,70 @@ impl StructPayloadIndex {
         }
     }
 
+    /// Selector for a new version of indexes, built in `dir` next to the current version.
+    ///
+    /// Returns `None` for indexes stored in RocksDB, which can't hold two versions of an index.
+    fn rebuild_selector<'a>(
+        &'a self,
+        dir: &'a Path,
+        payload_schema: &PayloadFieldSchema,
+    ) -> Option<IndexSelector<'a>> {
+        match self.selector(payload_schema) {
+            #[cfg(feature = "rocksdb")]
+            IndexSelector::RocksDb(_) => None,
+            IndexSelector::Mmap(IndexSelectorMmap { dir: _, is_on_disk }) => {
+                Some(IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }))
+            }
+            IndexSelector::Gridstore(IndexSelectorGridstore { dir: _ }) => {
+                Some(IndexSelector::Gridstore(IndexSelectorGridstore { dir }))
+            }
+        }
+    }
+
+    /// Directory, where indexes of the field are rebuilt while the current ones serve requests
+    fn rebuild_dir(&self, field: PayloadKeyTypeRef) -> PathBuf {
+        self.path.join(REBUILD_DIR).join(field.filename())
+    }
+
+    /// Replace the current indexes of the field with the indexes, rebuilt in its rebuild dir.
+    ///
+    /// Files of the rebuilt indexes are moved in place of the current ones, and loaded from there.
+    fn swap_rebuilt_indexes(
+        &mut self,
+        field: PayloadKeyTypeRef,
+        payload_schema: &PayloadFieldSchema,
+        current_indexes: Vec<FieldIndex>,
+        rebuilt_indexes: Vec<FieldIndex>,
+    ) -> OperationResult<Vec<FieldIndex>> {
+        let index_types = rebuilt_indexes
+            .iter()
+            .map(|index| index.get_full_index_type())
+            .collect();
+
+        // Persist and close rebuilt indexes before moving their files
+        for index in &rebuilt_indexes {
+            index.flusher()()?;
+        }
+        drop(rebuilt_indexes);
+
+        for index in current_indexes {
+            index.wipe()?;
+        }
+
+        let rebuild_dir = self.rebuild_dir(field);
+        for entry in fs::read_dir(&rebuild_dir)? {
+            let entry = entry?;
+            fs::rename(entry.path(), self.path.join(entry.file_name()))?;
+        }
+        fs::remove_dir_all(&rebuild_dir)?;
+
+        let mut payload_schema =
+            PayloadFieldSchemaWithIndexType::new(payload_schema.clone(), index_types);
+        let (indexes, _) = self.load_from_db(field, &mut payload_schema, false)?;
+
+        Ok(indexes)
+    }
+
     fn selector_with_type(
         &self,
         index_type: &FullPayloadIndexType,
@@ -783,6 +981,100 @@ impl StructPayloadIndex {
             })
     }
 
+    /// Find indexes which cover all of the given payload `keys`, if there are any.
+    ///
+    /// Values of covered keys can be read from the indexes directly, without touching the
+    /// payload storage. Only top-level keys with a keyword or integer map index qualify.
+    pub fn covering_indexes<'a>(
+        &'a self,
+        keys: &'a [PayloadKeyType],
+    ) -> Option<Vec<(&'a JsonPath, FacetIndexEnum<'a>)>> {
+        if keys.is_empty() {
+            return None;
+        }
+
+        keys.iter()
+            .map(|key| {
+                if !key.rest.is_empty() {
+                    return None;
+                }
+                let index = self.field_indexes.get(key)?.iter().find_map(|index| {
+                    match index.as_facet_index()? {
+                        index @ (FacetIndexEnum::Keyword(_) | FacetIndexEnum::Int(_)) => {
+                            Some(index)
+                        }
+                        FacetIndexEnum::Uuid(_) | FacetIndexEnum::Bool(_) => None,
+                    }
+                })?;
+                Some((key, index))
+            })
+            .collect()
+    }
+
+    /// Build the payload of a point from the values of the covering indexes.
+    ///
+    /// Keys with a single value are returned as that value, keys with multiple values as an
+    /// array. Keys without indexed values are omitted.
+    pub fn payload_from_indexes(
+        covering_indexes: &[(&JsonPath, FacetIndexEnum<'_>)],
+        point_offset: PointOffsetType,
+    ) -> Payload {
+        let mut payload = Payload::default();
+        for (key, index) in covering_indexes {
+            let mut values = index
+                .get_point_values(point_offset)
+                .map(|value| match value {
+                    FacetValueRef::Keyword(keyword) => Value::from(keyword),
+                    FacetValueRef::Int(int) => Value::from(*int),
+                    FacetValueRef::Uuid(uuid) => {
+                        Value::from(uuid::Uuid::from_u128(*uuid).to_string())
+                    }
+                    FacetValueRef::Bool(bool) => Value::from(bool),
+                })
+                .collect::<Vec<_>>();
+
+            let value = match values.len() {
+                0 => continue,
+                1 => values.pop().unwrap(),
+                _ => Value::Array(values),
+            };
+            payload.0.insert(key.first_key.clone(), value);
+        }
+        payload
+    }
+
+    /// Highest fragmentation ratio among storages of the field indexes, if any reports it
+    pub fn max_fragmentation_ratio(&self) -> Option<f64> {
+        self.field_indexes
+            .values()
+            .flatten()
+            .filter_map(|index| index.fragmentation())
+            .map(|fragmentation| fragmentation.ratio())
+            .max_by(f64::total_cmp)
+    }
+
+    /// Compact storages of the field indexes, which fragmentation ratio reaches the threshold.
+    ///
+    /// Returns the number of bytes given back to the file system.
+    pub fn compact_field_indexes(
+        &mut self,
+        min_fragmentation_ratio: f64,
+    ) -> OperationResult<usize> {
+        let mut reclaimed_bytes = 0;
+        for (_, field_indexes) in self.field_indexes.iter_mut() {
+            for index in field_indexes {
+                let is_fragmented = index.fragmentation().is_some_and(|fragmentation| {
+                    fragmentation.fragmented_bytes > 0
+                        && fragmentation.ratio() >= min_fragmentation_ratio
+                });
+                if is_fragmented {
+                    reclaimed_bytes += index.compact()?;
+                }
+            }
+        }
+        Ok(reclaimed_bytes)
+    }
+
     pub fn populate(&self) -> OperationResult<()> {
         for (_, field_indexes) in self.field_indexes.iter() {
             for index in field_indexes {
@@ -802,7 +1094,17 @@ impl StructPayloadIndex {
     }
 
     pub fn clear_cache_if_on_disk(&self) -> OperationResult<()> {
-        for (_, field_indexes) in self.field_indexes.iter() {
+        for (field, field_indexes) in self.field_indexes.iter() {
+            // Keep pages of the indexes, which are configured to be populated
+            let populate = self
+                .config
+                .indices
+                .get(field)
+                .is_some_and(|index| index.schema.populate());
+            if populate {
+                continue;
+            }
+
             for index in field_indexes {
                 if index.is_on_disk() {
                     index.clear_cache()?;
@@ -827,11 +1129,31 @@ impl PayloadIndex for StructPayloadIndex {
         if let Some(prev_schema) = self.config.indices.get(field) {
             // the field is already indexed with the same schema
             // no need to rebuild index and to save the config
-            return if prev_schema.schema == *payload_schema {
-                Ok(BuildIndexResult::AlreadyBuilt)
-            } else {
-                Ok(BuildIndexResult::IncompatibleSchema)
+            if prev_schema.schema == *payload_schema {
+                return Ok(BuildIndexResult::AlreadyBuilt);
+            }
+
+            // Build the new indexes next to the current ones, which keep serving requests
+            // until the new indexes are applied
+            let rebuild_dir = self.rebuild_dir(field);
+            let Some(selector) = self.rebuild_selector(&rebuild_dir, payload_schema) else {
+                return Ok(BuildIndexResult::IncompatibleSchema);
             };
+
+            // Leftovers of an interrupted rebuild
+            if rebuild_dir.exists() {
+                fs::remove_dir_all(&rebuild_dir)?;
+            }
+            fs::create_dir_all(&rebuild_dir)?;
+
+            let indexes = self.build_field_indexes_in(
+                selector,
+                &rebuild_dir,
+                field,
+                payload_schema,
+                hw_counter,
+            )?;
+            return Ok(BuildIndexResult::Built(indexes));
         }
         let indexes = self.build_field_indexes(field, payload_schema, hw_counter)?;
         Ok(BuildIndexResult::Built(indexes))
@@ -843,6 +1165,21 @@ impl PayloadIndex for StructPayloadIndex {
         payload_schema: PayloadFieldSchema,
         field_index: Vec<FieldIndex>,
     ) -> OperationResult<()> {
+        // Indexes of an already indexed field are rebuilt next to the current ones
+        let field_index = if self.config.indices.contains_key(&field) {
+            let current_indexes = self.field_indexes.remove(&field).unwrap_or_default();
+            self.swap_rebuilt_indexes(&field, &payload_schema, current_indexes, field_index)?
+        } else {
+            field_index
+        };
+
+        // Prefault pages of new on-disk indexes, the same as of the loaded ones
+        if payload_schema.populate() {
+            for index in &field_index {
+                index.populate()?;
+            }
+        }
+
         let index_types: Vec<_> = field_index
             .iter()
             .map(|i| i.get_full_index_type())
@@ -867,9 +1204,16 @@ impl PayloadIndex for StructPayloadIndex {
     ) -> OperationResult<()> {
         let payload_schema = payload_schema.into();
 
-        self.drop_index_if_incompatible(field, &payload_schema)?;
+        // An index with another schema keeps serving requests, while the new one is built
+        let mut build_result = self.build_index(field, &payload_schema, hw_counter)?;
 
-        let field_index = match self.build_index(field, &payload_schema, hw_counter)? {
+        if let BuildIndexResult::IncompatibleSchema = build_result {
+            // The index can't be rebuilt next to the current one, drop it first
+            self.drop_index_if_incompatible(field, &payload_schema)?;
+            build_result = self.build_index(field, &payload_schema, hw_counter)?;
+        }
+
+        let field_index = match build_result {
             BuildIndexResult::Built(field_index) => field_index,
             BuildIndexResult::AlreadyBuilt => {
                 // Index already built, no need to do anything
@@ -948,23 +1292,23 @@ impl PayloadIndex for StructPayloadIndex {
         estimate_filter(&estimator, query, available_points)
     }
 
-    fn query_points(
+    fn query_points_into(
         &self,
         query: &Filter,
         hw_counter: &HardwareCounterCell,
         is_stopped: &AtomicBool,
-    ) -> Vec<PointOffsetType> {
+        points: &mut Vec<PointOffsetType>,
+    ) {
         // Assume query is already estimated to be small enough so we can iterate over all matched ids
         let query_cardinality = self.estimate_cardinality(query, hw_counter);
         let id_tracker = self.id_tracker.borrow();
-        self.iter_filtered_points(
+        points.extend(self.iter_filtered_points(
             query,
             &*id_tracker,
             &query_cardinality,
             hw_counter,
             is_stopped,
-        )
-        .collect()
+        ));
     }
 
     fn indexed_points(&self, field: PayloadKeyTypeRef) -> usize {
@@ -1015,16 +1359,7 @@ impl PayloadIndex for StructPayloadIndex {
             .overwrite(point_id, payload, hw_counter)?;
 
         for (field, field_index) in &mut self.field_indexes {
-            let field_value = payload.get_value(field);
-            if !field_value.is_empty() {
-                for index in field_index {
-                    index.add_point(point_id, &field_value, hw_counter)?;
-                }
-            } else {
-                for index in field_index {
-                    index.remove_point(point_id)?;
-                }
-            }
+            update_field_indexes(point_id, field, field_index, payload, hw_counter)?;
         }
         Ok(())
     }
@@ -1051,16 +1386,7 @@ impl PayloadIndex for StructPayloadIndex {
             if !field.is_affected_by_value_set(&payload.0, key.as_ref()) {
                 continue;
             }
-            let field_value = updated_payload.get_value(field);
-            if !field_value.is_empty() {
-                for index in field_index {
-                    index.add_point(point_id, &field_value, hw_counter)?;
-                }
-            } else {
-                for index in field_index {
-                    index.remove_point(point_id)?;
-                }
-            }
+            update_field_indexes(point_id, field, field_index, &updated_payload, hw_counter)?;
         }
         Ok(())
     }
@@ -1169,6 +1495,29 @@ impl PayloadIndex for StructPayloadIndex {
     }
 }
 
+/// Update indexes of the field with the new payload of the point
+fn update_field_indexes(
+    point_id: PointOffsetType,
+    field: &JsonPath,
+    field_indexes: &mut [FieldIndex],
+    payload: &Payload,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<()> {
+    let field_value = payload.get_value(field);
+    for index in field_indexes {
+        if let Some(elements_path) = index.elements_path() {
+            // Elements without the field are indexed too
+            let elements = payload.get_value(elements_path);
+            index.add_point(point_id, &elements, hw_counter)?;
+        } else if !field_value.is_empty() {
+            index.add_point(point_id, &field_value, hw_counter)?;
+        } else {
+            index.remove_point(point_id)?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -1181,9 +1530,10 @@ mod tests {
     use crate::data_types::vectors::only_default_vector;
     use crate::entry::{NonAppendableSegmentEntry, SegmentEntry};
     use crate::index::payload_config::{IndexMutability, PayloadIndexType};
+    use crate::segment::Segment;
     use crate::segment_constructor::load_segment;
     use crate::segment_constructor::simple_segment_constructor::build_simple_segment;
-    use crate::types::{Distance, PayloadSchemaType};
+    use crate::types::{Distance, Match, PayloadField, PayloadSchemaType};
 
     #[test]
     fn test_load_payload_index() {
@@ -1255,4 +1605,160 @@ mod tests {
         let schema = payload_config.indices.get(&key).unwrap();
         check_index_types(&schema.types);
     }
+
+    #[test]
+    fn test_nested_null_index_conditions() {
+        let dir = Builder::new().prefix("payload_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+        let is_stopped = AtomicBool::new(false);
+
+        let mut segment = build_simple_segment(dir.path(), 2, Distance::Dot).unwrap();
+        let payloads = [
+            r#"{"a": [{"c": "x"}, {"d": 1}]}"#,
+            r#"{"a": [{"c": "x"}, {"c": null}]}"#,
+            r#"{"a": [{"c": "x"}, {"c": ["y"]}]}"#,
+            r#"{"b": 1}"#,
+        ];
+        for (id, payload) in payloads.iter().enumerate() {
+            let id = id as u64;
+            segment
+                .upsert_point(0, id.into(), only_default_vector(&[1.0, 1.0]), &hw_counter)
+                .unwrap();
+            let payload: Payload = serde_json::from_str(payload).unwrap();
+            segment
+                .set_full_payload(0, id.into(), &payload, &hw_counter)
+                .unwrap();
+        }
+
+        let key = JsonPath::from_str("a[].c").unwrap();
+        segment
+            .create_field_index(
+                0,
+                &key,
+                Some(&PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword)),
+                &hw_counter,
+            )
+            .unwrap();
+
+        let element_key = JsonPath::from_str("c").unwrap();
+        let is_empty = Filter::new_must(Condition::new_nested(
+            JsonPath::from_str("a").unwrap(),
+            Filter::new_must(Condition::IsEmpty(IsEmptyCondition {
+                is_empty: PayloadField {
+                    key: element_key.clone(),
+                },
+            })),
+        ));
+        let is_null = Filter::new_must(Condition::new_nested(
+            JsonPath::from_str("a").unwrap(),
+            Filter::new_must(Condition::IsNull(IsNullCondition {
+                is_null: PayloadField { key: element_key },
+            })),
+        ));
+
+        // Conditions are answered by the nested null index
+        for filter in [&is_empty, &is_null] {
+            let Some(Condition::Nested(nested)) = filter.iter_conditions().next() else {
+                unreachable!();
+            };
+            assert!(
+                segment
+                    .payload_index
+                    .borrow()
+                    .nested_null_index(nested, None)
+                    .is_some()
+            );
+        }
+
+        let read = |filter: &Filter| {
+            segment.read_filtered(None, None, Some(filter), &is_stopped, &hw_counter)
+        };
+        assert_eq!(read(&is_empty), vec![0.into(), 1.into()]);
+        assert_eq!(read(&is_null), vec![1.into()]);
+
+        // Index follows updates of the elements without the field
+        let payload: Payload = serde_json::from_str(r#"{"a": [{"d": 1}]}"#).unwrap();
+        segment
+            .set_full_payload(0, 3.into(), &payload, &hw_counter)
+            .unwrap();
+        let payload: Payload = serde_json::from_str(r#"{"a": [{"c": "x"}]}"#).unwrap();
+        segment
+            .set_full_payload(0, 1.into(), &payload, &hw_counter)
+            .unwrap();
+        assert_eq!(read(&is_empty), vec![0.into(), 3.into()]);
+        assert!(read(&is_null).is_empty());
+    }
+
+    #[test]
+    fn test_rebuild_index_with_new_schema() {
+        let dir = Builder::new().prefix("payload_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+        let is_stopped = AtomicBool::new(false);
+
+        let mut segment = build_simple_segment(dir.path(), 2, Distance::Dot).unwrap();
+        for (id, city) in ["Berlin", "Moscow", "Berlin Mitte"].into_iter().enumerate() {
+            let id = id as u64;
+            segment
+                .upsert_point(0, id.into(), only_default_vector(&[1.0, 1.0]), &hw_counter)
+                .unwrap();
+            let payload: Payload =
+                serde_json::from_value(serde_json::json!({ "city": city })).unwrap();
+            segment
+                .set_full_payload(0, id.into(), &payload, &hw_counter)
+                .unwrap();
+        }
+
+        let key = JsonPath::from_str("city").unwrap();
+        segment
+            .create_field_index(
+                1,
+                &key,
+                Some(&PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword)),
+                &hw_counter,
+            )
+            .unwrap();
+
+        // Replace the keyword index with a full-text index
+        let text_schema = PayloadFieldSchema::FieldType(PayloadSchemaType::Text);
+        segment
+            .create_field_index(2, &key, Some(&text_schema), &hw_counter)
+            .unwrap();
+
+        let index_types = |segment: &Segment| -> Vec<PayloadIndexType> {
+            segment.payload_index.borrow().field_indexes[&key]
+                .iter()
+                .map(|index| index.get_full_index_type().index_type)
+                .collect()
+        };
+        assert_eq!(
+            index_types(&segment),
+            vec![PayloadIndexType::FullTextIndex, PayloadIndexType::NullIndex],
+        );
+        assert_eq!(segment.get_indexed_fields()[&key], text_schema);
+
+        let payload_index_path = segment.segment_path.join("payload_index");
+        assert!(!payload_index_path.join(REBUILD_DIR).join("city").exists());
+
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            key.clone(),
+            Match::new_text("Berlin"),
+        )));
+        assert_eq!(
+            segment.read_filtered(None, None, Some(&filter), &is_stopped, &hw_counter),
+            vec![0.into(), 2.into()],
+        );
+
+        // Rebuilt index is loaded from its new location
+        let segment_path = segment.segment_path.clone();
+        drop(segment);
+        let segment = load_segment(&segment_path, Uuid::nil(), &is_stopped).unwrap();
+        assert_eq!(
+            index_types(&segment),
+            vec![PayloadIndexType::FullTextIndex, PayloadIndexType::NullIndex],
+        );
+        assert_eq!(
+            segment.read_filtered(None, None, Some(&filter), &is_stopped, &hw_counter),
+            vec![0.into(), 2.into()],
+        );
+    }
 }
@@ -36,11 +36,20 @@ pub fn get_oversampled_top(
         .map(|q| q.oversampling)
         .unwrap_or(default_quantization_oversampling_value());
 
-    match oversampling_value {
+    let oversampled_top = match oversampling_value {
         Some(oversampling) if quantization_enabled && oversampling > 1.0 => {
             (oversampling * top as f64) as usize
         }
         _ => top,
+    };
+
+    // Don't pre-select more vectors than allowed to re-score
+    match params
+        .and_then(|p| p.quantization)
+        .and_then(|q| q.max_rescore)
+    {
+        Some(max_rescore) => oversampled_top.min(max_rescore.max(top)),
+        None => oversampled_top,
     }
 }
 
@@ -56,17 +65,30 @@ pub fn postprocess_search_result(
     hardware_counter: HardwareCounterCell,
 ) -> OperationResult<Vec<ScoredPointOffset>> {
     let quantization_enabled = is_quantized_search(quantized_vectors, params);
+    let quantization_params = params.and_then(|p| p.quantization);
 
     let default_rescoring = quantized_vectors
         .as_ref()
         .map(|q| q.default_rescoring())
         .unwrap_or(false);
     let rescore = quantization_enabled
-        && params
-            .and_then(|p| p.quantization)
+        && quantization_params
             .and_then(|q| q.rescore)
             .unwrap_or(default_rescoring);
-    if rescore {
+
+    // Re-scoring would read more original vectors than allowed
+    let within_rescore_budget = quantization_params
+        .and_then(|q| q.max_rescore)
+        .is_none_or(|max_rescore| search_result.len() <= max_rescore);
+
+    let adaptive_rescoring = quantized_vectors
+        .filter(|_| quantization_params.and_then(|q| q.adaptive_rescore) == Some(true))
+        .map(|q| q.adaptive_rescoring());
+
+    if rescore
+        && within_rescore_budget
+        && adaptive_rescoring.is_none_or(|adaptive| adaptive.should_rescore())
+    {
         let mut scorer = FilteredScorer::new(
             vector.to_owned(),
             vector_storage,
@@ -76,11 +98,18 @@ pub fn postprocess_search_result(
             hardware_counter,
         )?;
 
-        search_result = scorer
+        let mut rescored: Vec<_> = scorer
             .score_points(&mut search_result.iter().map(|x| x.idx).collect_vec(), 0)
             .collect();
-        search_result.sort_unstable();
-        search_result.reverse();
+        rescored.sort_unstable();
+        rescored.reverse();
+
+        if let Some(adaptive) = adaptive_rescoring {
+            search_result.truncate(top);
+            adaptive.record(&search_result, &rescored[..rescored.len().min(top)]);
+        }
+
+        search_result = rescored;
     }
     search_result.truncate(top);
     Ok(search_result)
@@ -1,14 +1,19 @@
 //! Contains functions for interpreting filter queries and defining if given points pass the conditions
 
+use std::cmp::Ordering;
 use std::str::FromStr;
 
+use itertools::{Either, Itertools};
 use ordered_float::OrderedFloat;
 use serde_json::Value;
 
+use crate::index::field_index::full_text_index::has_all_within;
+use crate::json_path::JsonPath;
 use crate::types::{
-    AnyVariants, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoPoint,
-    GeoPolygon, GeoRadius, Match, MatchAny, MatchExcept, MatchPhrase, MatchText, MatchTextAny,
-    MatchValue, Range, RangeInterface, ValueVariants, ValuesCount,
+    AnyVariants, DateTimePayloadType, FieldComparison, FieldCondition, FloatPayloadType,
+    GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, GeoShape, GeoShapeCondition, Match, MatchAny,
+    MatchExcept, MatchNear, MatchPhrase, MatchPrefix, MatchText, MatchTextAny, MatchValue,
+    PayloadContainer, Range, RangeInterface, ValueVariants, ValuesCount,
 };
 
 /// Threshold representing the point to which iterating through an IndexSet is more efficient than using hashing.
@@ -69,10 +74,14 @@ impl ValueChecker for FieldCondition {
             geo_radius,
             geo_bounding_box,
             geo_polygon,
+            geo_shape,
             values_count,
             key: _,
             is_empty,
             is_null,
+            has_field,
+            // Compared with other fields in `FieldComparison::check`, having the whole payload
+            compare: _,
         } = self;
 
         r#match
@@ -93,11 +102,15 @@ impl ValueChecker for FieldCondition {
             || geo_polygon
                 .as_ref()
                 .is_some_and(|condition| condition.check_match(payload))
+            || geo_shape
+                .as_ref()
+                .is_some_and(|condition| condition.check_match(payload))
             || values_count
                 .as_ref()
                 .is_some_and(|condition| condition.check_match(payload))
             || is_empty.is_some_and(|is_empty| check_is_empty(is_empty, payload))
             || is_null.is_some_and(|is_null| check_is_null(is_null, payload))
+            || has_field.is_some_and(|has_field| has_field)
     }
 
     fn check(&self, payload: &Value) -> bool {
@@ -107,10 +120,13 @@ impl ValueChecker for FieldCondition {
             geo_radius: _,
             geo_bounding_box: _,
             geo_polygon: _,
+            geo_shape: _,
             values_count,
             key: _,
             is_empty,
             is_null,
+            has_field,
+            compare: _,
         } = self;
 
         if values_count.is_some() {
@@ -122,6 +138,9 @@ impl ValueChecker for FieldCondition {
             check_is_empty(is_empty.unwrap(), payload)
         } else if is_null.is_some() {
             check_is_null(is_null.unwrap(), payload)
+        } else if let Some(has_field) = has_field {
+            // Any value, including `null` and `[]`, means the field is present
+            *has_field
         } else {
             self._check(payload)
         }
@@ -134,10 +153,13 @@ impl ValueChecker for FieldCondition {
             geo_radius: _,
             geo_bounding_box: _,
             geo_polygon: _,
+            geo_shape: _,
             values_count: _,
             key: _,
             is_empty,
             is_null,
+            has_field,
+            compare: _,
         } = self;
         if let Some(is_empty) = is_empty {
             return *is_empty;
@@ -145,10 +167,58 @@ impl ValueChecker for FieldCondition {
         if let Some(is_null) = is_null {
             return !*is_null;
         }
+        if let Some(has_field) = has_field {
+            return !*has_field;
+        }
         false
     }
 }
 
+impl FieldComparison {
+    /// Check if any value of the `key` field is in the given order with values of all
+    /// compared fields of the same payload
+    pub fn check(&self, key: &JsonPath, payload: &impl PayloadContainer) -> bool {
+        let bounds = self
+            .bounds()
+            .map(|(path, accept)| (payload.get_value(path), accept))
+            .collect_vec();
+
+        flatten_values(payload.get_value(key)).any(|value| {
+            bounds.iter().all(|(others, accept)| {
+                flatten_values(others.iter().copied())
+                    .any(|other| compare_values(value, other).is_some_and(accept))
+            })
+        })
+    }
+}
+
+fn flatten_values<'a>(
+    values: impl IntoIterator<Item = &'a Value>,
+) -> impl Iterator<Item = &'a Value> {
+    values.into_iter().flat_map(|value| match value {
+        Value::Array(values) => Either::Left(values.iter()),
+        value => Either::Right(std::iter::once(value)),
+    })
+}
+
+/// Order of two payload values, if they are comparable
+fn compare_values(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => {
+            if let (Some(left), Some(right)) = (left.as_i64(), right.as_i64()) {
+                return Some(left.cmp(&right));
+            }
+            left.as_f64()?.partial_cmp(&right.as_f64()?)
+        }
+        (Value::String(left), Value::String(right)) => {
+            let left = DateTimePayloadType::from_str(left).ok()?;
+            let right = DateTimePayloadType::from_str(right).ok()?;
+            left.partial_cmp(&right)
+        }
+        _ => None,
+    }
+}
+
 impl ValueChecker for Match {
     fn check_match(&self, payload: &Value) -> bool {
         match self {
@@ -160,12 +230,37 @@ impl ValueChecker for Match {
                 }
                 _ => false,
             },
-            Match::Text(MatchText { text }) | Match::Phrase(MatchPhrase { phrase: text }) => {
+            // Without an index there is no tokenization, so fuzziness doesn't apply
+            Match::Text(MatchText { text, .. }) | Match::Phrase(MatchPhrase { phrase: text }) => {
                 match payload {
                     Value::String(stored) => stored.contains(text),
                     _ => false,
                 }
             }
+            // Without an index, tokens are the whitespace-separated words
+            Match::Near(MatchNear { near, within }) => match payload {
+                Value::String(stored) => {
+                    let query_words = near.split_whitespace().sorted().dedup().collect_vec();
+                    let occurrences = stored
+                        .split_whitespace()
+                        .enumerate()
+                        .filter_map(|(position, word)| {
+                            let word_idx = query_words.binary_search(&word).ok()?;
+                            Some((word_idx, position as u32))
+                        })
+                        .collect_vec();
+                    has_all_within(&occurrences, query_words.len(), *within)
+                }
+                _ => false,
+            },
+            Match::Prefix(MatchPrefix { prefix }) => match payload {
+                Value::String(stored) => stored.starts_with(prefix.as_str()),
+                _ => false,
+            },
+            Match::Regex(match_regex) => match payload {
+                Value::String(stored) => match_regex.is_match(stored),
+                _ => false,
+            },
             Match::TextAny(MatchTextAny { text_any }) => match payload {
                 Value::String(stored) => text_any
                     .split_whitespace()
@@ -221,6 +316,16 @@ impl ValueChecker for Match {
     }
 }
 
+/// Compiled regex of a [`Match::Regex`] condition
+impl ValueChecker for regex::Regex {
+    fn check_match(&self, payload: &Value) -> bool {
+        match payload {
+            Value::String(stored) => self.is_match(stored),
+            _ => false,
+        }
+    }
+}
+
 impl ValueChecker for Range<OrderedFloat<FloatPayloadType>> {
     fn check_match(&self, payload: &Value) -> bool {
         match payload {
@@ -295,6 +400,13 @@ impl ValueChecker for GeoPolygon {
     }
 }
 
+impl ValueChecker for GeoShapeCondition {
+    fn check_match(&self, payload: &Value) -> bool {
+        GeoShape::geometry_from_payload(payload)
+            .is_some_and(|shape| self.convert().check_shape(&shape))
+    }
+}
+
 impl ValueChecker for ValuesCount {
     fn check_match(&self, payload: &Value) -> bool {
         self.check_count_from(payload)
@@ -315,7 +427,7 @@ mod tests {
 
     use super::*;
     use crate::json_path::JsonPath;
-    use crate::types::GeoPoint;
+    use crate::types::{GeoPoint, MatchRegex};
 
     #[test]
     fn test_geo_matching() {
@@ -379,6 +491,17 @@ mod tests {
         assert!(gte_two_countries_query.check(&countries));
     }
 
+    #[test]
+    fn test_compiled_regex_matching() {
+        let regex = MatchRegex::from("^wor.d$").compile().unwrap();
+
+        assert!(regex.check(&json!("world")));
+        assert!(regex.check(&json!(["hello", "worxd"])));
+        assert!(!regex.check(&json!(["hello", "worlds"])));
+        assert!(!regex.check(&json!(42)));
+        assert!(!regex.check(&json!(null)));
+    }
+
     #[test]
     fn test_value_checker_for_null_or_empty() {
         let array = json!([]);
@@ -398,10 +521,13 @@ mod tests {
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             key: key.clone(),
             is_empty: Some(true),
             is_null: None,
+            has_field: None,
+            compare: None,
         };
 
         let is_not_empty = FieldCondition {
@@ -410,10 +536,13 @@ mod tests {
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             key: key.clone(),
             is_empty: Some(false),
             is_null: None,
+            has_field: None,
+            compare: None,
         };
 
         let is_null = FieldCondition {
@@ -422,10 +551,13 @@ mod tests {
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             key: key.clone(),
             is_empty: None,
             is_null: Some(true),
+            has_field: None,
+            compare: None,
         };
 
         let is_not_null = FieldCondition {
@@ -434,10 +566,13 @@ mod tests {
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             key: key.clone(),
             is_empty: None,
             is_null: Some(false),
+            has_field: None,
+            compare: None,
         };
 
         assert!(is_empty.check(&array));
@@ -475,5 +610,64 @@ mod tests {
         assert!(is_not_null.check(&string));
         assert!(is_not_null.check(&number));
         assert!(is_not_null.check(&bool));
+
+        let has_field = FieldCondition::new_has_field(key.clone(), true);
+        let has_no_field = FieldCondition::new_has_field(key, false);
+        for value in [
+            &array,
+            &array_with_null,
+            &array_with_null_and_something,
+            &object,
+            &json!(null),
+        ] {
+            assert!(has_field.check(value));
+            assert!(!has_no_field.check(value));
+        }
+        assert!(!has_field.check_empty());
+        assert!(has_no_field.check_empty());
+    }
+
+    #[test]
+    fn test_field_comparison() {
+        let key = JsonPath::new("price");
+        let lt_budget = FieldComparison {
+            lt: Some(JsonPath::new("budget")),
+            gt: None,
+            gte: None,
+            lte: None,
+        };
+        let between = FieldComparison {
+            lt: None,
+            gt: Some(JsonPath::new("min")),
+            gte: None,
+            lte: Some(JsonPath::new("max")),
+        };
+
+        let payload = |value: Value| value.as_object().unwrap().clone();
+
+        assert!(lt_budget.check(&key, &payload(json!({"price": 10, "budget": 20}))));
+        assert!(!lt_budget.check(&key, &payload(json!({"price": 20, "budget": 20}))));
+        assert!(lt_budget.check(&key, &payload(json!({"price": 10.5, "budget": 11}))));
+        // Any pair of values may match
+        assert!(lt_budget.check(&key, &payload(json!({"price": [30, 10], "budget": 20}))));
+        assert!(!lt_budget.check(
+            &key,
+            &payload(json!({"price": [30, 40], "budget": [5, 20]}))
+        ));
+        // Datetimes are compared with datetimes only
+        assert!(lt_budget.check(
+            &key,
+            &payload(json!({"price": "2024-01-01T00:00:00Z", "budget": "2024-06-01"})),
+        ));
+        assert!(!lt_budget.check(&key, &payload(json!({"price": "2024-01-01", "budget": 20}))));
+        assert!(!lt_budget.check(&key, &payload(json!({"price": "a", "budget": "b"}))));
+        // Missing fields never match
+        assert!(!lt_budget.check(&key, &payload(json!({"price": 10}))));
+        assert!(!lt_budget.check(&key, &payload(json!({"budget": 10}))));
+
+        // All bounds must hold for the same value
+        assert!(between.check(&key, &payload(json!({"price": 5, "min": 1, "max": 5}))));
+        assert!(!between.check(&key, &payload(json!({"price": 1, "min": 1, "max": 5}))));
+        assert!(!between.check(&key, &payload(json!({"price": [0, 6], "min": 1, "max": 5}))));
     }
 }
@@ -1,7 +1,7 @@
 pub mod condition_checker;
-#[cfg(feature = "testing")]
+#[cfg(any(feature = "testing", target_arch = "wasm32"))]
 pub mod in_memory_payload_storage;
-#[cfg(feature = "testing")]
+#[cfg(any(feature = "testing", target_arch = "wasm32"))]
 pub mod in_memory_payload_storage_impl;
 pub mod mmap_payload_storage;
 #[cfg(feature = "rocksdb")]
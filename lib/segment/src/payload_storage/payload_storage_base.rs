@@ -102,6 +102,14 @@ pub trait ConditionChecker {
 pub trait FilterContext {
     /// Check if point satisfies filter condition. Return true if satisfies
     fn check(&self, point_id: PointOffsetType) -> bool;
+
+    /// Retain only points which satisfy filter condition, preserving their order.
+    ///
+    /// Implementations may check the whole batch at once, which is faster than checking
+    /// points one by one.
+    fn retain_matching(&self, point_ids: &mut Vec<PointOffsetType>) {
+        point_ids.retain(|&point_id| self.check(point_id));
+    }
 }
 
 pub type PayloadStorageSS = dyn PayloadStorage + Sync + Send;
@@ -8,7 +8,7 @@ use crate::common::Flusher;
 use crate::common::operation_error::OperationResult;
 use crate::json_path::JsonPath;
 use crate::payload_storage::PayloadStorage;
-#[cfg(feature = "testing")]
+#[cfg(any(feature = "testing", target_arch = "wasm32"))]
 use crate::payload_storage::in_memory_payload_storage::InMemoryPayloadStorage;
 use crate::payload_storage::mmap_payload_storage::MmapPayloadStorage;
 #[cfg(feature = "rocksdb")]
@@ -19,7 +19,7 @@ use crate::types::Payload;
 
 #[derive(Debug)]
 pub enum PayloadStorageEnum {
-    #[cfg(feature = "testing")]
+    #[cfg(any(feature = "testing", target_arch = "wasm32"))]
     InMemoryPayloadStorage(InMemoryPayloadStorage),
     #[cfg(feature = "rocksdb")]
     SimplePayloadStorage(SimplePayloadStorage),
@@ -28,7 +28,7 @@ pub enum PayloadStorageEnum {
     MmapPayloadStorage(MmapPayloadStorage),
 }
 
-#[cfg(feature = "testing")]
+#[cfg(any(feature = "testing", target_arch = "wasm32"))]
 impl From<InMemoryPayloadStorage> for PayloadStorageEnum {
     fn from(a: InMemoryPayloadStorage) -> Self {
         PayloadStorageEnum::InMemoryPayloadStorage(a)
@@ -63,7 +63,7 @@ impl PayloadStorage for PayloadStorageEnum {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => {
                 s.overwrite(point_id, payload, hw_counter)
             }
@@ -86,7 +86,7 @@ impl PayloadStorage for PayloadStorageEnum {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.set(point_id, payload, hw_counter),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.set(point_id, payload, hw_counter),
@@ -104,7 +104,7 @@ impl PayloadStorage for PayloadStorageEnum {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => {
                 s.set_by_key(point_id, payload, key, hw_counter)
             }
@@ -128,7 +128,7 @@ impl PayloadStorage for PayloadStorageEnum {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Payload> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.get(point_id, hw_counter),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.get(point_id, hw_counter),
@@ -144,7 +144,7 @@ impl PayloadStorage for PayloadStorageEnum {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Payload> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.get_sequential(point_id, hw_counter),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.get_sequential(point_id, hw_counter),
@@ -161,7 +161,7 @@ impl PayloadStorage for PayloadStorageEnum {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<Value>> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.delete(point_id, key, hw_counter),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.delete(point_id, key, hw_counter),
@@ -177,7 +177,7 @@ impl PayloadStorage for PayloadStorageEnum {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Option<Payload>> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.clear(point_id, hw_counter),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.clear(point_id, hw_counter),
@@ -190,7 +190,7 @@ impl PayloadStorage for PayloadStorageEnum {
     #[cfg(test)]
     fn clear_all(&mut self, hw_counter: &HardwareCounterCell) -> OperationResult<()> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.clear_all(hw_counter),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.clear_all(hw_counter),
@@ -202,7 +202,7 @@ impl PayloadStorage for PayloadStorageEnum {
 
     fn flusher(&self) -> Flusher {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.flusher(),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.flusher(),
@@ -217,7 +217,7 @@ impl PayloadStorage for PayloadStorageEnum {
         F: FnMut(PointOffsetType, &Payload) -> OperationResult<bool>,
     {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.iter(callback, hw_counter),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.iter(callback, hw_counter),
@@ -229,7 +229,7 @@ impl PayloadStorage for PayloadStorageEnum {
 
     fn files(&self) -> Vec<PathBuf> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.files(),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.files(),
@@ -241,7 +241,7 @@ impl PayloadStorage for PayloadStorageEnum {
 
     fn immutable_files(&self) -> Vec<PathBuf> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.immutable_files(),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.immutable_files(),
@@ -253,7 +253,7 @@ impl PayloadStorage for PayloadStorageEnum {
 
     fn get_storage_size_bytes(&self) -> OperationResult<usize> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.get_storage_size_bytes(),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.get_storage_size_bytes(),
@@ -265,7 +265,7 @@ impl PayloadStorage for PayloadStorageEnum {
 
     fn is_on_disk(&self) -> bool {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(s) => s.is_on_disk(),
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(s) => s.is_on_disk(),
@@ -281,7 +281,7 @@ impl PayloadStorageEnum {
     /// Block until all pages are populated.
     pub fn populate(&self) -> OperationResult<()> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(_) => {}
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(_) => {}
@@ -295,7 +295,7 @@ impl PayloadStorageEnum {
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         match self {
-            #[cfg(feature = "testing")]
+            #[cfg(any(feature = "testing", target_arch = "wasm32"))]
             PayloadStorageEnum::InMemoryPayloadStorage(_) => {}
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::SimplePayloadStorage(_) => {}
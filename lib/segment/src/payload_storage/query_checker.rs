@@ -193,6 +193,11 @@ pub fn check_field_condition<R>(
 where
     R: AsRef<Vec<FieldIndex>>,
 {
+    // Comparison depends on other fields, so neither indexes nor values of the key alone decide it
+    if let Some(compare) = &field_condition.compare {
+        return compare.check(&field_condition.key, payload);
+    }
+
     let field_values = payload.get_value(&field_condition.key);
     let field_indexes = field_indexes.get(&field_condition.key);
 
@@ -1,11 +1,12 @@
 use std::collections::{HashMap, HashSet};
+use std::io::Write;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
 use ahash::AHashMap;
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::TelemetryDetail;
+use common::types::{PointOffsetType, TelemetryDetail};
 use io::safe_delete::safe_delete_with_suffix;
 use uuid::Uuid;
 
@@ -16,23 +17,29 @@ use crate::common::{
 };
 use crate::data_types::build_index_result::BuildFieldIndexResult;
 use crate::data_types::facets::{FacetParams, FacetValue};
+use crate::data_types::filter_explanation::SegmentFilterExplanation;
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::order_by::{OrderBy, OrderValue};
+use crate::data_types::quantiles::QuantileSketch;
 use crate::data_types::query_context::{
     FormulaContext, QueryContext, QueryIdfStats, SegmentQueryContext,
 };
 use crate::data_types::segment_record::{NamedVectorsOwned, SegmentRecord};
+use crate::data_types::term_stats::TermStatsAggregator;
 use crate::data_types::vectors::{QueryVector, VectorInternal};
 use crate::entry::entry_point::{NonAppendableSegmentEntry, SegmentEntry};
+use crate::index::field_index::full_text_index::bm25::Bm25CorpusStats;
 use crate::index::field_index::{CardinalityEstimation, FieldIndex};
+use crate::index::query_optimization::rescore_formula::parsed_formula::TextScoreQuery;
+use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::{BuildIndexResult, PayloadIndex, VectorIndex};
 use crate::json_path::JsonPath;
 use crate::payload_storage::PayloadStorage;
 use crate::telemetry::SegmentTelemetry;
 use crate::types::{
     Filter, Payload, PayloadFieldSchema, PayloadIndexInfo, PayloadKeyType, PayloadKeyTypeRef,
-    PointIdType, ScoredPoint, SearchParams, SegmentConfig, SegmentInfo, SegmentType, SeqNumberType,
-    VectorDataInfo, VectorName, VectorNameBuf, WithPayload, WithVector,
+    PayloadSelector, PointIdType, ScoredPoint, SearchParams, SegmentConfig, SegmentInfo,
+    SegmentType, SeqNumberType, VectorDataInfo, VectorName, VectorNameBuf, WithPayload, WithVector,
 };
 use crate::vector_storage::VectorStorage;
 
@@ -74,8 +81,15 @@ impl NonAppendableSegmentEntry for Segment {
             .vector_data
             .get(vector_name)
             .ok_or_else(|| OperationError::vector_name_not_exists(vector_name))?;
-        let vector_query_context = query_context.get_vector_context(vector_name);
-        let internal_results = vector_data.vector_index.borrow().search(
+        let score_threshold = self.internal_score_threshold(
+            vector_name,
+            query_vectors,
+            query_context.score_threshold(),
+        );
+        let vector_query_context = query_context
+            .get_vector_context(vector_name)
+            .with_score_threshold(score_threshold);
+        let mut internal_results = vector_data.vector_index.borrow().search(
             query_vectors,
             filter,
             top,
@@ -83,6 +97,15 @@ impl NonAppendableSegmentEntry for Segment {
             &vector_query_context,
         )?;
 
+        // Results are sorted, so the ones below the threshold are at the end
+        if let Some(score_threshold) = score_threshold {
+            for internal_result in &mut internal_results {
+                let above_threshold =
+                    internal_result.partition_point(|point| point.score > score_threshold);
+                internal_result.truncate(above_threshold);
+            }
+        }
+
         check_stopped(&vector_query_context.is_stopped())?;
 
         let hw_counter = vector_query_context.hardware_counter();
@@ -112,11 +135,13 @@ impl NonAppendableSegmentEntry for Segment {
             limit,
             score_threshold,
             is_stopped,
+            text_score_stats,
         } = &*ctx;
 
         let internal_results = self.do_rescore_with_formula(
             formula,
             prefetches_results,
+            text_score_stats,
             *limit,
             *score_threshold,
             is_stopped,
@@ -228,20 +253,60 @@ impl NonAppendableSegmentEntry for Segment {
             }
         }
 
-        for &point_id in point_ids {
-            let payload = if with_payload.enable {
-                if let Some(selector) = &with_payload.payload_selector {
-                    Some(selector.process(self.payload(point_id, hw_counter)?))
-                } else {
-                    Some(self.payload(point_id, hw_counter)?)
+        if with_payload.enable {
+            let mut point_offsets = point_ids
+                .iter()
+                .map(|&point_id| self.lookup_internal_id(point_id))
+                .collect::<OperationResult<Vec<_>>>()?;
+
+            let id_tracker = self.id_tracker.borrow();
+            let mut update_record_payload = |point_offset: PointOffsetType, payload: Payload| {
+                let Some(point_id) = id_tracker.external_id(point_offset) else {
+                    return;
+                };
+                let point_record = records
+                    .entry(point_id)
+                    .or_insert_with(|| SegmentRecord::empty(point_id));
+                point_record.payload = Some(payload);
+            };
+
+            let payload_index = self.payload_index.borrow();
+            let covering_indexes = match &with_payload.payload_selector {
+                Some(PayloadSelector::Include(selector)) => {
+                    payload_index.covering_indexes(&selector.include)
                 }
-            } else {
-                None
+                Some(PayloadSelector::Exclude(_)) | None => None,
             };
-            let point_record = records
-                .entry(point_id)
-                .or_insert_with(|| SegmentRecord::empty(point_id));
-            point_record.payload = payload;
+
+            if let Some(covering_indexes) = covering_indexes {
+                // All requested keys are indexed, serve values without reading payload storage
+                for point_offset in point_offsets {
+                    check_stopped(is_stopped)?;
+                    let payload =
+                        StructPayloadIndex::payload_from_indexes(&covering_indexes, point_offset);
+                    update_record_payload(point_offset, payload);
+                }
+            } else {
+                self.payloads_by_offsets(
+                    &mut point_offsets,
+                    hw_counter,
+                    is_stopped,
+                    |point_offset, payload| {
+                        let payload = match &with_payload.payload_selector {
+                            Some(selector) => selector.process(payload),
+                            None => payload,
+                        };
+                        update_record_payload(point_offset, payload);
+                    },
+                )?;
+            }
+        } else {
+            for &point_id in point_ids {
+                let point_record = records
+                    .entry(point_id)
+                    .or_insert_with(|| SegmentRecord::empty(point_id));
+                point_record.payload = None;
+            }
         }
 
         Ok(records.into_values().collect())
@@ -285,7 +350,7 @@ impl NonAppendableSegmentEntry for Segment {
         is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
-        match filter {
+        let mut reads = match filter {
             None => {
                 self.filtered_read_by_value_stream(order_by, limit, None, is_stopped, hw_counter)
             }
@@ -304,7 +369,21 @@ impl NonAppendableSegmentEntry for Segment {
                     )
                 }
             }
+        }?;
+
+        // Points with the same value as the last read one may go before it by `then_by` keys
+        if let Some(limit) = limit
+            && !order_by.then_by.is_empty()
+            && reads.len() >= limit
+            && let Some(&(last_value, _)) = reads.last()
+        {
+            let ties = self
+                .filtered_read_value_ties(order_by, last_value, filter, is_stopped, hw_counter)?;
+            reads.retain(|(value, _)| *value != last_value);
+            reads.extend(ties);
         }
+
+        Ok(reads)
     }
 
     fn read_random_filtered(
@@ -386,6 +465,22 @@ impl NonAppendableSegmentEntry for Segment {
         }
     }
 
+    fn explain_filter(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> SegmentFilterExplanation {
+        self.explain_filter_plan(filter, hw_counter)
+    }
+
+    fn export_hnsw_graph(
+        &self,
+        vector_name: &VectorName,
+        writer: &mut dyn Write,
+    ) -> OperationResult<()> {
+        self.write_hnsw_graph(vector_name, writer)
+    }
+
     fn unique_values(
         &self,
         key: &JsonPath,
@@ -405,6 +500,24 @@ impl NonAppendableSegmentEntry for Segment {
         self.approximate_facet(request, is_stopped, hw_counter)
     }
 
+    fn quantile_sketch(
+        &self,
+        key: &JsonPath,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<QuantileSketch> {
+        self.approximate_quantile_sketch(key, filter, is_stopped, hw_counter)
+    }
+
+    fn term_stats(
+        &self,
+        key: &JsonPath,
+        is_stopped: &AtomicBool,
+    ) -> OperationResult<TermStatsAggregator> {
+        self.aggregate_term_stats(key, is_stopped)
+    }
+
     fn segment_uuid(&self) -> Uuid {
         self.uuid
     }
@@ -494,7 +607,10 @@ impl NonAppendableSegmentEntry for Segment {
             .into_iter()
             .map(|(key, index_schema)| {
                 let points_count = payload_index.indexed_points(&key);
-                let index_info = PayloadIndexInfo::new(index_schema, points_count);
+                let index_info = PayloadIndexInfo::new(index_schema, points_count)
+                    .with_distinct_values_sketch(
+                        payload_index.distinct_values_sketch(&key).cloned(),
+                    );
                 (key, index_info)
             })
             .collect();
@@ -838,6 +954,14 @@ impl NonAppendableSegmentEntry for Segment {
             config: self.config().clone(),
             vector_index_searches,
             payload_field_indices: self.payload_index.borrow().get_telemetry_data(),
+            access_frequency: {
+                let id_tracker = self.id_tracker.borrow();
+                self.access_frequency.get_telemetry_data(
+                    id_tracker.total_point_count(),
+                    id_tracker.available_point_count(),
+                    |point| id_tracker.is_deleted_point(point),
+                )
+            },
         }
     }
 
@@ -867,6 +991,17 @@ impl NonAppendableSegmentEntry for Segment {
             }
         }
     }
+
+    fn fill_text_score_stats(
+        &self,
+        text_scores: &[TextScoreQuery],
+        stats: &mut [Bm25CorpusStats],
+        hw_counter: &HardwareCounterCell,
+    ) {
+        self.payload_index
+            .borrow()
+            .fill_text_score_stats(text_scores, stats, hw_counter);
+    }
 }
 
 impl SegmentEntry for Segment {
@@ -0,0 +1,34 @@
+use common::counter::hardware_counter::HardwareCounterCell;
+
+use super::Segment;
+use crate::data_types::filter_explanation::{
+    FilterCardinality, PrimaryClause, SegmentFilterExplanation,
+};
+use crate::index::PayloadIndex;
+use crate::types::Filter;
+
+impl Segment {
+    pub(super) fn explain_filter_plan(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> SegmentFilterExplanation {
+        let payload_index = self.payload_index.borrow();
+
+        let cardinality = payload_index.estimate_cardinality(filter, hw_counter);
+        let (plan, post_filtering) = payload_index.filter_plan(filter, &cardinality, hw_counter);
+
+        SegmentFilterExplanation {
+            segment_id: self.uuid.to_string(),
+            available_points: payload_index.available_point_count(),
+            cardinality: FilterCardinality::from(&cardinality),
+            primary_clauses: cardinality
+                .primary_clauses
+                .iter()
+                .map(PrimaryClause::from)
+                .collect(),
+            plan,
+            post_filtering,
+        }
+    }
+}
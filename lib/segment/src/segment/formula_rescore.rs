@@ -8,6 +8,7 @@ use itertools::{Either, Itertools};
 
 use super::Segment;
 use crate::common::operation_error::OperationResult;
+use crate::index::field_index::full_text_index::bm25::Bm25CorpusStats;
 use crate::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use crate::types::ScoredPoint;
 
@@ -17,6 +18,7 @@ impl Segment {
         &self,
         formula: &ParsedFormula,
         prefetches_scores: &[Vec<ScoredPoint>],
+        text_score_stats: &[Bm25CorpusStats],
         limit: usize,
         score_threshold: Option<ScoreType>,
         is_stopped: &AtomicBool,
@@ -46,7 +48,8 @@ impl Segment {
             .collect::<Vec<_>>();
 
         let index_ref = self.payload_index.borrow();
-        let scorer = index_ref.formula_scorer(formula, &prefetches_scores, hw_counter);
+        let scorer =
+            index_ref.formula_scorer(formula, &prefetches_scores, text_score_stats, hw_counter)?;
 
         // Perform rescoring
         let mut error = None;
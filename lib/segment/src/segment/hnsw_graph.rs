@@ -0,0 +1,28 @@
+use std::io::Write;
+
+use super::Segment;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::index::VectorIndexEnum;
+use crate::types::VectorName;
+
+impl Segment {
+    pub(super) fn write_hnsw_graph(
+        &self,
+        vector_name: &VectorName,
+        writer: &mut dyn Write,
+    ) -> OperationResult<()> {
+        let vector_data = self
+            .vector_data
+            .get(vector_name)
+            .ok_or_else(|| OperationError::vector_name_not_exists(vector_name))?;
+
+        let vector_index = vector_data.vector_index.borrow();
+        let VectorIndexEnum::Hnsw(index) = &*vector_index else {
+            return Err(OperationError::MissingHnswIndexForGraphExport {
+                vector_name: vector_name.to_owned(),
+            });
+        };
+
+        index.export_graph(self.uuid.to_string(), vector_name.to_owned(), writer)
+    }
+}
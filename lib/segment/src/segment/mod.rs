@@ -1,11 +1,15 @@
 mod entry;
 mod facet;
+mod filter_explanation;
 mod formula_rescore;
+mod hnsw_graph;
 mod order_by;
+mod quantiles;
 mod sampling;
 mod scroll;
 mod search;
 mod segment_ops;
+mod term_stats;
 mod version_tracker;
 
 pub mod snapshot;
@@ -28,6 +32,7 @@ use rocksdb::DB;
 use uuid::Uuid;
 
 use self::version_tracker::VersionTracker;
+use crate::common::access_frequency::AccessFrequencySketch;
 use crate::common::operation_error::SegmentFailedState;
 use crate::id_tracker::IdTrackerSS;
 use crate::index::VectorIndexEnum;
@@ -91,6 +96,8 @@ pub struct Segment {
     /// Last unhandled error
     /// If not None, all update operations will be aborted until original operation is performed properly
     pub error_status: Option<SegmentFailedState>,
+    /// Approximate number of recent accesses to each point by searches, not persisted
+    pub access_frequency: AccessFrequencySketch,
     #[cfg(feature = "rocksdb")]
     pub database: Option<Arc<parking_lot::RwLock<DB>>>,
 }
@@ -7,7 +7,7 @@ use itertools::Either;
 
 use super::Segment;
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::data_types::order_by::{Direction, OrderBy, OrderValue};
+use crate::data_types::order_by::{Direction, OrderBy, OrderValue, StartFrom};
 use crate::index::PayloadIndex;
 use crate::index::field_index::numeric_index::StreamRange;
 use crate::spaces::tools::{peek_top_largest_iterable, peek_top_smallest_iterable};
@@ -91,6 +91,47 @@ impl Segment {
         filter: Option<&Filter>,
         is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
+        self.read_by_value_stream(order_by, filter, is_stopped, hw_counter, |reads| {
+            reads.take(limit.unwrap_or(usize::MAX)).collect()
+        })
+    }
+
+    /// Read all points with the given value of the `order_by` key
+    pub fn filtered_read_value_ties(
+        &self,
+        order_by: &OrderBy,
+        value: OrderValue,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
+        let start_from = match value {
+            OrderValue::Int(value) => StartFrom::Integer(value),
+            OrderValue::Float(value) => StartFrom::Float(value),
+        };
+        let order_by = OrderBy {
+            start_from: Some(start_from),
+            ..order_by.clone()
+        };
+
+        self.read_by_value_stream(&order_by, filter, is_stopped, hw_counter, |reads| {
+            reads
+                .skip_while(|(read_value, _)| *read_value != value)
+                .take_while(|(read_value, _)| *read_value == value)
+                .collect()
+        })
+    }
+
+    fn read_by_value_stream(
+        &self,
+        order_by: &OrderBy,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+        read: impl FnOnce(
+            &mut dyn Iterator<Item = (OrderValue, PointIdType)>,
+        ) -> Vec<(OrderValue, PointIdType)>,
     ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
         let payload_index = self.payload_index.borrow();
 
@@ -123,15 +164,13 @@ impl Segment {
             }
         };
 
-        let reads = filtered_iter
+        let mut reads = filtered_iter
             .stop_if(is_stopped)
             .filter_map(|(value, internal_id)| {
                 id_tracker
                     .external_id(internal_id)
                     .map(|external_id| (value, external_id))
-            })
-            .take(limit.unwrap_or(usize::MAX))
-            .collect();
-        Ok(reads)
+            });
+        Ok(read(&mut reads))
     }
 }
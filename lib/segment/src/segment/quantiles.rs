@@ -0,0 +1,69 @@
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::iterator_ext::IteratorExt;
+
+use super::Segment;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::quantiles::QuantileSketch;
+use crate::index::PayloadIndex;
+use crate::index::field_index::FieldIndex;
+use crate::json_path::JsonPath;
+use crate::types::Filter;
+
+impl Segment {
+    pub(super) fn approximate_quantile_sketch(
+        &self,
+        key: &JsonPath,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<QuantileSketch> {
+        let payload_index = self.payload_index.borrow();
+
+        // Datetime index is also numeric, but its values are not meaningful as quantiles
+        let index = payload_index
+            .field_indexes
+            .get(key)
+            .and_then(|indexes| {
+                indexes.iter().find(|index| {
+                    matches!(index, FieldIndex::IntIndex(_) | FieldIndex::FloatIndex(_))
+                })
+            })
+            .ok_or_else(|| OperationError::MissingNumericIndexForQuantiles {
+                key: key.to_string(),
+            })?;
+
+        let Some(filter) = filter else {
+            // Read the distribution of all values from the histogram
+            let sketch = match index {
+                FieldIndex::IntIndex(index) => index.inner().quantile_sketch(),
+                FieldIndex::FloatIndex(index) => index.inner().quantile_sketch(),
+                _ => unreachable!("only numeric indexes are selected"),
+            };
+            return Ok(sketch);
+        };
+
+        let id_tracker = self.id_tracker.borrow();
+        let filter_cardinality = payload_index.estimate_cardinality(filter, hw_counter);
+
+        let points = payload_index
+            .iter_filtered_points(
+                filter,
+                &*id_tracker,
+                &filter_cardinality,
+                hw_counter,
+                is_stopped,
+            )
+            .filter(|point_id| !id_tracker.is_deleted_point(*point_id))
+            .stop_if(is_stopped);
+
+        let sketch = match index {
+            FieldIndex::IntIndex(index) => index.inner().points_quantile_sketch(points),
+            FieldIndex::FloatIndex(index) => index.inner().points_quantile_sketch(points),
+            _ => unreachable!("only numeric indexes are selected"),
+        };
+
+        Ok(sketch)
+    }
+}
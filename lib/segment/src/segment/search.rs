@@ -9,15 +9,11 @@ use crate::common::operation_error::{OperationError, OperationResult};
 #[cfg(feature = "testing")]
 use crate::data_types::query_context::QueryContext;
 use crate::data_types::segment_record::SegmentRecord;
-#[cfg(feature = "testing")]
-use crate::data_types::vectors::QueryVector;
-use crate::data_types::vectors::VectorStructInternal;
+use crate::data_types::vectors::{QueryVector, VectorStructInternal};
 use crate::entry::entry_point::NonAppendableSegmentEntry;
-#[cfg(feature = "testing")]
-use crate::types::VectorName;
+use crate::types::{Distance, ScoreType, ScoredPoint, VectorName, WithPayload, WithVector};
 #[cfg(feature = "testing")]
 use crate::types::{Filter, SearchParams};
-use crate::types::{ScoredPoint, WithPayload, WithVector};
 
 impl Segment {
     /// Converts raw ScoredPointOffset search result into ScoredPoint result
@@ -78,6 +74,9 @@ impl Segment {
                 continue;
             };
 
+            self.access_frequency
+                .record(point_offset, id_tracker.total_point_count());
+
             let point_version = id_tracker.internal_version(point_offset).ok_or_else(|| {
                 OperationError::service_error(format!(
                     "Corrupter id_tracker, no version for point {point_id}"
@@ -104,6 +103,34 @@ impl Segment {
         Ok(results)
     }
 
+    /// Convert score threshold of the query into the space of internal scores of the vector.
+    ///
+    /// Threshold is only applicable to nearest search, custom scores of other queries are not
+    /// comparable with the distance.
+    pub(super) fn internal_score_threshold(
+        &self,
+        vector_name: &VectorName,
+        query_vectors: &[&QueryVector],
+        score_threshold: Option<ScoreType>,
+    ) -> Option<ScoreType> {
+        let score_threshold = score_threshold?;
+        if !query_vectors
+            .iter()
+            .all(|query| matches!(query, QueryVector::Nearest(_)))
+        {
+            return None;
+        }
+
+        // Sparse vectors are always scored with dot product
+        let distance = self
+            .segment_config
+            .vector_data
+            .get(vector_name)
+            .map_or(Distance::Dot, |config| config.distance);
+
+        Some(distance.preprocess_score_threshold(score_threshold))
+    }
+
     /// This function is a simplified version of `search_batch` intended for testing purposes.
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "testing")]
@@ -1,6 +1,7 @@
 use std::cmp::max;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::AtomicBool;
 
 use bitvec::prelude::BitVec;
 use common::counter::hardware_counter::HardwareCounterCell;
@@ -13,7 +14,7 @@ use super::{SEGMENT_STATE_FILE, SNAPSHOT_FILES_PATH, SNAPSHOT_PATH, Segment};
 use crate::common::operation_error::{
     OperationError, OperationResult, SegmentFailedState, get_service_error,
 };
-use crate::common::{check_named_vectors, check_vector_name};
+use crate::common::{check_named_vectors, check_stopped, check_vector_name};
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::vectors::VectorInternal;
 use crate::entry::entry_point::NonAppendableSegmentEntry;
@@ -25,6 +26,10 @@ use crate::types::{
 use crate::utils;
 use crate::vector_storage::{Random, VectorStorage};
 
+/// Minimal number of payloads to read at once, for which reading them in the order of
+/// offsets is worth sorting
+const SEQUENTIAL_PAYLOAD_READ_THRESHOLD: usize = 64;
+
 impl Segment {
     /// Replace vectors in-place
     ///
@@ -452,6 +457,40 @@ impl Segment {
             .get_payload(point_offset, hw_counter)
     }
 
+    /// Read payloads of multiple points.
+    ///
+    /// Large batches are read in the order of offsets, so that the storage is accessed
+    /// sequentially instead of jumping between random locations.
+    /// Payloads are passed to the `callback` in the order they are read.
+    pub(super) fn payloads_by_offsets(
+        &self,
+        point_offsets: &mut [PointOffsetType],
+        hw_counter: &HardwareCounterCell,
+        is_stopped: &AtomicBool,
+        mut callback: impl FnMut(PointOffsetType, Payload),
+    ) -> OperationResult<()> {
+        let payload_index = self.payload_index.borrow();
+
+        if point_offsets.len() < SEQUENTIAL_PAYLOAD_READ_THRESHOLD {
+            for &point_offset in point_offsets.iter() {
+                check_stopped(is_stopped)?;
+                callback(
+                    point_offset,
+                    payload_index.get_payload(point_offset, hw_counter)?,
+                );
+            }
+            return Ok(());
+        }
+
+        point_offsets.sort_unstable();
+        for &point_offset in point_offsets.iter() {
+            check_stopped(is_stopped)?;
+            let payload = payload_index.get_payload_sequential(point_offset, hw_counter)?;
+            callback(point_offset, payload);
+        }
+        Ok(())
+    }
+
     pub fn save_current_state(&self) -> OperationResult<()> {
         Self::save_state(&self.get_state(), &self.segment_path)
     }
@@ -0,0 +1,45 @@
+use std::sync::atomic::AtomicBool;
+
+use common::iterator_ext::IteratorExt;
+
+use super::Segment;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::term_stats::TermStatsAggregator;
+use crate::index::field_index::FieldIndex;
+use crate::json_path::JsonPath;
+
+impl Segment {
+    pub(super) fn aggregate_term_stats(
+        &self,
+        key: &JsonPath,
+        is_stopped: &AtomicBool,
+    ) -> OperationResult<TermStatsAggregator> {
+        let payload_index = self.payload_index.borrow();
+
+        let index = payload_index
+            .field_indexes
+            .get(key)
+            .and_then(|indexes| {
+                indexes.iter().find_map(|index| match index {
+                    FieldIndex::FullTextIndex(index) => Some(index),
+                    _ => None,
+                })
+            })
+            .ok_or_else(|| OperationError::MissingFullTextIndexForTermStats {
+                key: key.to_string(),
+            })?;
+
+        let mut stats = TermStatsAggregator::default();
+
+        for (term, documents) in index.terms_with_documents_count().stop_if(is_stopped) {
+            stats.add_term(term, documents);
+        }
+
+        let id_tracker = self.id_tracker.borrow();
+        for point_id in id_tracker.iter_internal().stop_if(is_stopped) {
+            stats.add_document(index.values_count(point_id));
+        }
+
+        Ok(stats)
+    }
+}
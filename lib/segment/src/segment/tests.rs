@@ -11,16 +11,22 @@ use tempfile::Builder;
 use super::*;
 use crate::common::operation_error::OperationError::PointIdError;
 use crate::common::{check_named_vectors, check_vector, check_vector_name};
+use crate::data_types::filter_explanation::{FilterPlan, PrimaryClause};
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::query_context::QueryContext;
 use crate::data_types::vectors::{DEFAULT_VECTOR_NAME, only_default_vector};
 use crate::entry::SnapshotEntry as _;
 use crate::entry::entry_point::{NonAppendableSegmentEntry as _, SegmentEntry as _};
+use crate::json_path::JsonPath;
+use crate::payload_json;
 use crate::segment_constructor::load_segment;
 use crate::segment_constructor::simple_segment_constructor::{
     VECTOR1_NAME, VECTOR2_NAME, build_multivec_segment, build_simple_segment,
 };
-use crate::types::{Distance, Filter, Payload, SnapshotFormat, WithPayload, WithVector};
+use crate::types::{
+    Condition, Distance, FieldCondition, Filter, Payload, PayloadSchemaType, PayloadSelector,
+    PointIdType, SnapshotFormat, WithPayload, WithVector,
+};
 
 #[test]
 fn test_search_batch_equivalence_single() {
@@ -402,6 +408,201 @@ fn test_point_vector_count() {
     // assert_eq!(segment_info.num_vectors, 1);
 }
 
+#[test]
+fn test_retrieve_payloads_batch() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut segment = build_simple_segment(dir.path(), 1, Distance::Dot).unwrap();
+    let num_points = 200u64;
+    for id in 0..num_points {
+        segment
+            .upsert_point(id, id.into(), only_default_vector(&[1.0]), &hw_counter)
+            .unwrap();
+        let payload = payload_json! {"id": id, "other": "value"};
+        segment
+            .set_full_payload(id, id.into(), &payload, &hw_counter)
+            .unwrap();
+    }
+
+    let with_payload = WithPayload {
+        enable: true,
+        payload_selector: Some(PayloadSelector::new_include(vec![JsonPath::new("id")])),
+    };
+    let is_stopped = AtomicBool::new(false);
+
+    // Few points are read one by one, many points are read in the order of offsets
+    for count in [3, num_points] {
+        let point_ids: Vec<PointIdType> = (0..count).rev().map(PointIdType::from).collect();
+        let records = segment
+            .retrieve(
+                &point_ids,
+                &with_payload,
+                &WithVector::Bool(false),
+                &hw_counter,
+                &is_stopped,
+            )
+            .unwrap();
+        assert_eq!(records.len(), point_ids.len());
+
+        for record in records {
+            let PointIdType::NumId(id) = record.id else {
+                panic!("unexpected point id {:?}", record.id);
+            };
+            assert_eq!(record.payload, Some(payload_json! {"id": id}));
+            assert!(record.vectors.is_none());
+        }
+    }
+}
+
+#[test]
+fn test_retrieve_payloads_from_indexes() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut segment = build_simple_segment(dir.path(), 1, Distance::Dot).unwrap();
+    segment
+        .create_field_index(
+            0,
+            &JsonPath::new("color"),
+            Some(&PayloadSchemaType::Keyword.into()),
+            &hw_counter,
+        )
+        .unwrap();
+    segment
+        .create_field_index(
+            0,
+            &JsonPath::new("count"),
+            Some(&PayloadSchemaType::Integer.into()),
+            &hw_counter,
+        )
+        .unwrap();
+
+    for id in 0..10u64 {
+        segment
+            .upsert_point(1, id.into(), only_default_vector(&[1.0]), &hw_counter)
+            .unwrap();
+        let payload = payload_json! {
+            "color": ["red", "blue"],
+            "count": id,
+            "other": "value",
+        };
+        segment
+            .set_full_payload(1, id.into(), &payload, &hw_counter)
+            .unwrap();
+    }
+
+    let point_ids: Vec<PointIdType> = (0..10u64).map(PointIdType::from).collect();
+    let is_stopped = AtomicBool::new(false);
+    let retrieve = |keys: &[&str]| {
+        let with_payload = WithPayload {
+            enable: true,
+            payload_selector: Some(PayloadSelector::new_include(
+                keys.iter().map(|key| JsonPath::new(key)).collect(),
+            )),
+        };
+        segment
+            .retrieve(
+                &point_ids,
+                &with_payload,
+                &WithVector::Bool(false),
+                &hw_counter,
+                &is_stopped,
+            )
+            .unwrap()
+    };
+
+    // Both keys are indexed, values are served by the indexes
+    for record in retrieve(&["color", "count"]) {
+        let PointIdType::NumId(id) = record.id else {
+            panic!("unexpected point id {:?}", record.id);
+        };
+        let payload = record.payload.unwrap();
+        assert_eq!(payload.0["count"], serde_json::json!(id));
+        let mut colors = payload.0["color"].as_array().unwrap().clone();
+        colors.sort_by_key(|color| color.to_string());
+        assert_eq!(
+            colors,
+            vec![serde_json::json!("blue"), serde_json::json!("red")]
+        );
+    }
+
+    // Non-indexed key requires reading payload storage
+    for record in retrieve(&["count", "other"]) {
+        let PointIdType::NumId(id) = record.id else {
+            panic!("unexpected point id {:?}", record.id);
+        };
+        assert_eq!(
+            record.payload,
+            Some(payload_json! {"count": id, "other": "value"}),
+        );
+    }
+}
+
+#[test]
+fn test_explain_filter() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut segment = build_simple_segment(dir.path(), 1, Distance::Dot).unwrap();
+    segment
+        .create_field_index(
+            0,
+            &JsonPath::new("color"),
+            Some(&PayloadSchemaType::Keyword.into()),
+            &hw_counter,
+        )
+        .unwrap();
+
+    for id in 0..10u64 {
+        segment
+            .upsert_point(1, id.into(), only_default_vector(&[1.0]), &hw_counter)
+            .unwrap();
+        let color = if id < 3 { "red" } else { "blue" };
+        let payload = payload_json! {"color": color, "other": "value"};
+        segment
+            .set_full_payload(1, id.into(), &payload, &hw_counter)
+            .unwrap();
+    }
+
+    let color_red = FieldCondition::new_match(JsonPath::new("color"), "red".to_string().into());
+    let other_value = FieldCondition::new_match(JsonPath::new("other"), "value".to_string().into());
+
+    // Indexed condition only, no need to check points against the filter
+    let filter = Filter::new_must(Condition::Field(color_red.clone()));
+    let explanation = segment.explain_filter(&filter, &hw_counter);
+    assert_eq!(explanation.plan, FilterPlan::IndexIteration);
+    assert!(!explanation.post_filtering);
+    assert_eq!(
+        explanation.primary_clauses,
+        vec![PrimaryClause::Field(color_red.clone())],
+    );
+    assert_eq!(explanation.cardinality.exp, 3);
+    assert_eq!(explanation.available_points, 10);
+
+    // Unindexed condition is checked on points selected by the index
+    let filter = Filter {
+        must: Some(vec![
+            Condition::Field(color_red.clone()),
+            Condition::Field(other_value.clone()),
+        ]),
+        ..Default::default()
+    };
+    let explanation = segment.explain_filter(&filter, &hw_counter);
+    assert_eq!(explanation.plan, FilterPlan::IndexIteration);
+    assert!(explanation.post_filtering);
+    assert_eq!(
+        explanation.primary_clauses,
+        vec![PrimaryClause::Field(color_red)],
+    );
+
+    // Nothing is indexed, all points are scanned
+    let filter = Filter::new_must(Condition::Field(other_value));
+    let explanation = segment.explain_filter(&filter, &hw_counter);
+    assert_eq!(explanation.plan, FilterPlan::FullScan);
+    assert!(explanation.primary_clauses.is_empty());
+}
+
 #[test]
 fn test_point_vector_count_multivec() {
     let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
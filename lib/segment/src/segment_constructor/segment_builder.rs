@@ -19,6 +19,7 @@ use fs_err as fs;
 use io::storage_version::StorageVersion;
 use itertools::Itertools;
 use rand::Rng;
+use rayon::iter::{IntoParallelIterator as _, ParallelIterator as _};
 use tempfile::TempDir;
 use uuid::Uuid;
 
@@ -39,7 +40,7 @@ use crate::id_tracker::{IdTracker, IdTrackerEnum, for_each_unique_point};
 use crate::index::field_index::FieldIndex;
 use crate::index::sparse_index::sparse_vector_index::SparseVectorIndexOpenArgs;
 use crate::index::struct_payload_index::StructPayloadIndex;
-use crate::index::{PayloadIndex, VectorIndexEnum};
+use crate::index::{BuildIndexResult, PayloadIndex, VectorIndexEnum};
 use crate::payload_storage::PayloadStorage;
 use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::segment::{Segment, SegmentVersion};
@@ -263,9 +264,11 @@ impl SegmentBuilder {
                     break;
                 }
                 FieldIndex::GeoIndex(_) => {}
+                FieldIndex::GeoShapeIndex(_) => {}
                 FieldIndex::FullTextIndex(_) => {}
                 FieldIndex::BoolIndex(_) => {}
                 FieldIndex::NullIndex(_) => {}
+                FieldIndex::NestedNullIndex(_) => {}
             }
         }
         ordering
@@ -492,13 +495,16 @@ impl SegmentBuilder {
                 id_tracker,
                 payload_storage,
                 mut vector_data,
-                segment_config,
+                mut segment_config,
                 hnsw_global_config,
                 temp_dir,
                 indexed_fields,
-                defragment_keys: _,
+                defragment_keys,
             } = self;
 
+            // Remember the keys points are grouped by, to find segments built before a key was set
+            segment_config.defragment_keys = Some(defragment_keys.into_iter().sorted().collect());
+
             let progress_quantization = progress_segment.subtask("quantization");
             let progress_payload_index = progress_segment.subtask("payload_index");
             let indexed_fields = indexed_fields
@@ -590,11 +596,13 @@ impl SegmentBuilder {
                 appendable_flag,
                 true,
             )?;
-            for (field, payload_schema, progress) in indexed_fields {
-                progress.start();
-                payload_index.set_indexed(&field, payload_schema, hw_counter)?;
-                check_process_stopped(stopped)?;
-            }
+            Self::build_payload_indexes(
+                &mut payload_index,
+                indexed_fields,
+                &permit,
+                stopped,
+                hw_counter,
+            )?;
             drop(progress_payload_index);
 
             payload_index.flusher()()?;
@@ -787,6 +795,71 @@ impl SegmentBuilder {
         Ok(quantized_vectors_map)
     }
 
+    /// Build field indexes of all indexed fields.
+    ///
+    /// Indexes of different fields are independent, so they are built in parallel,
+    /// using up to the number of CPUs of the permit.
+    fn build_payload_indexes(
+        payload_index: &mut StructPayloadIndex,
+        indexed_fields: Vec<(PayloadKeyType, PayloadFieldSchema, ProgressTracker)>,
+        permit: &ResourcePermit,
+        stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let num_threads = cmp::min(permit.num_cpus as usize, indexed_fields.len());
+
+        if num_threads <= 1 {
+            for (field, payload_schema, progress) in indexed_fields {
+                progress.start();
+                payload_index.set_indexed(&field, payload_schema, hw_counter)?;
+                check_process_stopped(stopped)?;
+            }
+            return Ok(());
+        }
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .thread_name(|idx| format!("payload-index-build-{idx}"))
+            .num_threads(num_threads)
+            .build()?;
+
+        // Counter cells can't be shared between threads, each build counts into its own one
+        let hw_acc = hw_counter.new_accumulator();
+
+        let index_builds = {
+            let payload_index = &*payload_index;
+            pool.install(|| {
+                indexed_fields
+                    .into_par_iter()
+                    .map(|(field, payload_schema, progress)| {
+                        check_process_stopped(stopped)?;
+                        progress.start();
+                        let hw_counter = HardwareCounterCell::new_with_accumulator(hw_acc.clone());
+                        let result =
+                            payload_index.build_index(&field, &payload_schema, &hw_counter)?;
+                        Ok((field, payload_schema, result))
+                    })
+                    .collect::<OperationResult<Vec<_>>>()
+            })?
+        };
+        check_process_stopped(stopped)?;
+
+        for (field, payload_schema, result) in index_builds {
+            match result {
+                BuildIndexResult::Built(field_index) => {
+                    payload_index.apply_index(field, payload_schema, field_index)?;
+                }
+                BuildIndexResult::AlreadyBuilt => {}
+                BuildIndexResult::IncompatibleSchema => {
+                    return Err(OperationError::service_error(format!(
+                        "Incompatible schema for field `{field}` in a new segment"
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Populate cache of all vector storages, so it will be faster to build index
     pub fn populate_vector_storages(&self) -> OperationResult<()> {
         for vector_data in self.vector_data.values() {
@@ -171,6 +171,7 @@ fn open_chunked_mmap_vector_storage(
     }
 }
 
+#[cfg_attr(target_arch = "wasm32", allow(unreachable_code))]
 pub(crate) fn open_vector_storage(
     #[cfg(feature = "rocksdb")] db_builder: &mut RocksDbBuilder,
     vector_config: &VectorDataConfig,
@@ -178,6 +179,12 @@ pub(crate) fn open_vector_storage(
     vector_storage_path: &Path,
     #[cfg(feature = "rocksdb")] vector_name: &VectorName,
 ) -> OperationResult<VectorStorageEnum> {
+    // Neither mmap nor RocksDB are available in WebAssembly, keep all vectors in memory
+    #[cfg(target_arch = "wasm32")]
+    {
+        return open_volatile_vector_storage(vector_config);
+    }
+
     match vector_config.storage_type {
         // In memory - RocksDB disabled
         #[cfg(not(feature = "rocksdb"))]
@@ -243,11 +250,47 @@ pub(crate) fn open_vector_storage(
     }
 }
 
+/// Create an empty vector storage, which is not persisted.
+///
+/// Only float vectors are supported.
+#[cfg(target_arch = "wasm32")]
+fn open_volatile_vector_storage(
+    vector_config: &VectorDataConfig,
+) -> OperationResult<VectorStorageEnum> {
+    use crate::vector_storage::dense::volatile_dense_vector_storage::new_volatile_dense_vector_storage;
+    use crate::vector_storage::multi_dense::volatile_multi_dense_vector_storage::new_volatile_multi_dense_vector_storage;
+
+    let datatype = vector_config.datatype.unwrap_or_default();
+    if datatype != VectorStorageDatatype::Float32 {
+        return Err(OperationError::service_error(format!(
+            "Vector datatype {datatype:?} is not supported in WebAssembly",
+        )));
+    }
+
+    let storage = match vector_config.multivector_config {
+        Some(multi_vec_config) => new_volatile_multi_dense_vector_storage(
+            vector_config.size,
+            vector_config.distance,
+            multi_vec_config,
+        ),
+        None => new_volatile_dense_vector_storage(vector_config.size, vector_config.distance),
+    };
+    Ok(storage)
+}
+
+#[cfg_attr(target_arch = "wasm32", allow(unreachable_code))]
 pub(crate) fn create_payload_storage(
     #[cfg(feature = "rocksdb")] db_builder: &mut RocksDbBuilder,
     segment_path: &Path,
     config: &SegmentConfig,
 ) -> OperationResult<PayloadStorageEnum> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use crate::payload_storage::in_memory_payload_storage::InMemoryPayloadStorage;
+
+        return Ok(PayloadStorageEnum::from(InMemoryPayloadStorage::default()));
+    }
+
     let payload_storage = match config.payload_storage_type {
         #[cfg(feature = "rocksdb")]
         PayloadStorageType::InMemory => {
@@ -429,6 +472,7 @@ pub(crate) fn create_sparse_vector_index(
     Ok(vector_index)
 }
 
+#[cfg_attr(target_arch = "wasm32", allow(unreachable_code))]
 pub(crate) fn create_sparse_vector_storage(
     #[cfg(feature = "rocksdb")] db_builder: &mut RocksDbBuilder,
     path: &Path,
@@ -436,6 +480,13 @@ pub(crate) fn create_sparse_vector_storage(
     storage_type: &SparseVectorStorageType,
     #[cfg(feature = "rocksdb")] stopped: &AtomicBool,
 ) -> OperationResult<VectorStorageEnum> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use crate::vector_storage::sparse::volatile_sparse_vector_storage::new_volatile_sparse_vector_storage;
+
+        return Ok(new_volatile_sparse_vector_storage());
+    }
+
     match storage_type {
         #[cfg(feature = "rocksdb")]
         SparseVectorStorageType::OnDisk => {
@@ -645,16 +696,27 @@ fn create_segment(
         payload_storage,
         segment_config: config.clone(),
         error_status: None,
+        access_frequency: Default::default(),
         #[cfg(feature = "rocksdb")]
         database: db_builder.build(),
     })
 }
 
+#[cfg_attr(target_arch = "wasm32", allow(unreachable_code))]
 fn create_segment_id_tracker(
     mutable_id_tracker: bool,
     segment_path: &Path,
     #[cfg(feature = "rocksdb")] db_builder: &mut RocksDbBuilder,
 ) -> OperationResult<Arc<AtomicRefCell<IdTrackerEnum>>> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use crate::id_tracker::in_memory_id_tracker::InMemoryIdTracker;
+
+        return Ok(sp(IdTrackerEnum::InMemoryIdTracker(
+            InMemoryIdTracker::new(),
+        )));
+    }
+
     if !mutable_id_tracker {
         return Ok(sp(IdTrackerEnum::ImmutableIdTracker(
             create_immutable_id_tracker(segment_path)?,
@@ -1455,7 +1517,7 @@ fn migrate_rocksdb_payload_storage(
         PayloadStorageEnum::MmapPayloadStorage(_) => {
             unreachable!("unexpected payload storage type")
         }
-        #[cfg(feature = "testing")]
+        #[cfg(any(feature = "testing", target_arch = "wasm32"))]
         PayloadStorageEnum::InMemoryPayloadStorage(_) => {
             unreachable!("unexpected payload storage type")
         }
@@ -37,10 +37,12 @@ pub fn build_simple_segment(
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    skip_normalization: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
             payload_storage_type: Default::default(),
+            defragment_keys: None,
         },
         true,
     )
@@ -65,10 +67,12 @@ pub fn build_simple_segment_with_payload_storage(
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    skip_normalization: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
             payload_storage_type,
+            defragment_keys: None,
         },
         true,
     )
@@ -91,6 +95,7 @@ pub fn build_multivec_segment(
             quantization_config: None,
             multivector_config: None,
             datatype: None,
+            skip_normalization: None,
         },
     );
     vectors_config.insert(
@@ -103,6 +108,7 @@ pub fn build_multivec_segment(
             quantization_config: None,
             multivector_config: None,
             datatype: None,
+            skip_normalization: None,
         },
     );
 
@@ -112,6 +118,7 @@ pub fn build_multivec_segment(
             vector_data: vectors_config,
             sparse_vector_data: Default::default(),
             payload_storage_type: Default::default(),
+            defragment_keys: None,
         },
         true,
     )
@@ -1,6 +1,8 @@
+use gridstore::Fragmentation;
 use schemars::JsonSchema;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
+use crate::common::access_frequency::AccessFrequencyTelemetry;
 use crate::common::anonymize::Anonymize;
 use crate::common::operation_time_statistics::OperationDurationStatistics;
 use crate::types::{SegmentConfig, SegmentInfo, VectorNameBuf};
@@ -11,6 +13,7 @@ pub struct SegmentTelemetry {
     pub config: SegmentConfig,
     pub vector_index_searches: Vec<VectorIndexSearchesTelemetry>,
     pub payload_field_indices: Vec<PayloadIndexTelemetry>,
+    pub access_frequency: AccessFrequencyTelemetry,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
@@ -30,6 +33,74 @@ pub struct PayloadIndexTelemetry {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
     pub histogram_bucket_size: Option<usize>,
+
+    /// Approximate number of distinct indexed values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distinct_values: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_text: Option<FullTextIndexTelemetry>,
+
+    /// Space usage of the index storage, reported for mutable map indexes on Gridstore
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fragmentation: Option<StorageFragmentationTelemetry>,
+}
+
+/// Vocabulary and postings statistics of a full-text index
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
+pub struct FullTextIndexTelemetry {
+    /// Number of unique terms in the index
+    pub vocabulary_size: usize,
+
+    /// Total number of entries in all posting lists
+    pub total_postings: usize,
+
+    /// Estimated size of the vocabulary and posting lists, in bytes
+    pub estimated_size_bytes: usize,
+
+    /// Terms with the longest posting lists, longest first
+    pub top_terms: Vec<TermPostingsTelemetry>,
+}
+
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
+pub struct TermPostingsTelemetry {
+    pub term: String,
+
+    /// Number of documents containing the term
+    pub postings: usize,
+}
+
+/// Space usage of an index storage, which gets fragmented by deletes and updates
+#[derive(Serialize, Clone, Copy, Debug, JsonSchema, Anonymize)]
+#[anonymize(false)]
+pub struct StorageFragmentationTelemetry {
+    /// Size of the space allocated by the storage, in bytes
+    pub allocated_bytes: usize,
+
+    /// Size of the space occupied by values, in bytes
+    pub used_bytes: usize,
+
+    /// Size of the free space in between occupied one, in bytes
+    pub fragmented_bytes: usize,
+
+    /// Share of fragmented space in the occupied and fragmented space
+    pub fragmentation_ratio: f64,
+}
+
+impl From<Fragmentation> for StorageFragmentationTelemetry {
+    fn from(fragmentation: Fragmentation) -> Self {
+        let Fragmentation {
+            allocated_bytes,
+            used_bytes,
+            fragmented_bytes,
+        } = fragmentation;
+        Self {
+            allocated_bytes,
+            used_bytes,
+            fragmented_bytes,
+            fragmentation_ratio: fragmentation.ratio(),
+        }
+    }
 }
 
 impl PayloadIndexTelemetry {
@@ -71,4 +142,17 @@ pub struct VectorIndexSearchesTelemetry {
 
     #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
     pub unfiltered_exact: OperationDurationStatistics,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hnsw_build: Option<HnswBuildStats>,
+}
+
+/// Build-time effects of the HNSW parameters
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema, Anonymize)]
+#[anonymize(false)]
+pub struct HnswBuildStats {
+    /// Time spent on building the graph, in milliseconds
+    pub build_time_ms: u64,
+    /// Average number of links per node on level 0, including payload-aware links
+    pub avg_links_level_0: f32,
 }
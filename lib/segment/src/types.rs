@@ -14,7 +14,10 @@ use common::stable_hash::StableHash;
 use common::types::ScoreType;
 use ecow::EcoString;
 use fnv::FnvBuildHasher;
-use geo::{Contains, Coord, Distance as GeoDistance, Haversine, LineString, Point, Polygon};
+use geo::{
+    BoundingRect, Contains, Coord, Distance as GeoDistance, Geometry, Haversine, Intersects,
+    LineString, MultiPolygon, Point, Polygon, Rect, Relate,
+};
 use indexmap::IndexSet;
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
@@ -27,11 +30,14 @@ use validator::{Validate, ValidationError, ValidationErrors};
 use zerocopy::native_endian::U64;
 
 use crate::common::anonymize::Anonymize;
+use crate::common::distinct_values_sketch::DistinctValuesSketch;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::{self, MaybeOneOrMany, MultiValue};
+use crate::data_types::date_math;
 use crate::data_types::index::{
-    BoolIndexParams, DatetimeIndexParams, FloatIndexParams, GeoIndexParams, IntegerIndexParams,
-    KeywordIndexParams, TextIndexParams, UuidIndexParams,
+    BoolIndexParams, DatetimeIndexParams, FloatIndexParams, GeoIndexParams, GeoShapeIndexParams,
+    IntegerIndexParams, KeywordIndexParams, TextIndexParams, UuidIndexParams,
+    validate_histogram_params,
 };
 use crate::data_types::modifier::Modifier;
 use crate::data_types::order_by::OrderValue;
@@ -360,6 +366,21 @@ impl Distance {
             Order::SmallBetter => score < threshold,
         }
     }
+
+    /// Converts score threshold into the space of internal scores, which are not post-processed.
+    ///
+    /// Internal scores are always ordered from large to small, so internal `score` satisfies the
+    /// threshold if `score > preprocess_score_threshold(threshold)`, same as
+    /// `check_threshold(postprocess_score(score), threshold)`.
+    pub fn preprocess_score_threshold(&self, threshold: ScoreType) -> ScoreType {
+        match self {
+            Distance::Cosine | Distance::Dot => threshold,
+            // Internal score is negative squared distance
+            Distance::Euclid => -threshold.max(0.0).powi(2),
+            // Internal score is negative distance
+            Distance::Manhattan => -threshold,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -434,6 +455,14 @@ pub struct PayloadIndexInfo {
     pub params: Option<PayloadSchemaParams>,
     /// Number of points indexed with this index
     pub points: usize,
+    /// Approximate number of distinct indexed values, for keyword, integer and uuid indexes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distinct_values: Option<usize>,
+    /// Sketch of the distinct values, to combine the estimation of several segments
+    #[serde(skip)]
+    #[schemars(skip)]
+    #[anonymize(false)]
+    pub distinct_values_sketch: Option<DistinctValuesSketch>,
 }
 
 impl PayloadIndexInfo {
@@ -443,14 +472,49 @@ impl PayloadIndexInfo {
                 data_type,
                 params: None,
                 points: points_count,
+                distinct_values: None,
+                distinct_values_sketch: None,
             },
             PayloadFieldSchema::FieldParams(schema_params) => PayloadIndexInfo {
                 data_type: schema_params.kind(),
                 params: Some(schema_params),
                 points: points_count,
+                distinct_values: None,
+                distinct_values_sketch: None,
             },
         }
     }
+
+    pub fn with_distinct_values_sketch(mut self, sketch: Option<DistinctValuesSketch>) -> Self {
+        self.distinct_values = sketch.as_ref().map(DistinctValuesSketch::estimate);
+        self.distinct_values_sketch = sketch;
+        self
+    }
+
+    /// Combine info of the same index in another segment or shard.
+    ///
+    /// Distinct values are merged if both have sketches, otherwise they are summed, which
+    /// overcounts the values present in both.
+    pub fn merge(&mut self, other: PayloadIndexInfo) {
+        self.points += other.points;
+
+        match (
+            &mut self.distinct_values_sketch,
+            &other.distinct_values_sketch,
+        ) {
+            (Some(sketch), Some(other_sketch)) => {
+                sketch.merge(other_sketch);
+                self.distinct_values = Some(sketch.estimate());
+            }
+            _ => {
+                self.distinct_values_sketch = None;
+                self.distinct_values = match (self.distinct_values, other.distinct_values) {
+                    (Some(distinct), Some(other)) => Some(distinct + other),
+                    (distinct, other) => distinct.or(other),
+                };
+            }
+        }
+    }
 }
 
 #[derive(Debug, Serialize, JsonSchema, Anonymize, Clone, PartialEq, Eq)]
@@ -518,18 +582,40 @@ pub struct QuantizationSearchParams {
     #[validate(range(min = 1.0))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub oversampling: Option<f64>,
+
+    /// Max number of original vectors, read to re-score results in a segment.
+    ///
+    /// Limits the number of pre-selected vectors, regardless of `oversampling`.
+    /// If `limit` is larger, results are not re-scored.
+    /// If also set in the collection default search params, the smaller value is used.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_rescore: Option<usize>,
+
+    /// If true, re-scoring is skipped, when quantized and re-scored orders of results agree
+    /// on sampled queries. Reduces reads of original vectors, stored on disk. Default is false.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adaptive_rescore: Option<bool>,
 }
 
+impl Eq for QuantizationSearchParams {}
+
 impl Hash for QuantizationSearchParams {
     fn hash<H: Hasher>(&self, state: &mut H) {
         let Self {
             ignore,
             rescore,
             oversampling,
+            max_rescore,
+            adaptive_rescore,
         } = self;
         ignore.hash(state);
         rescore.hash(state);
         oversampling.map(OrderedFloat).hash(state);
+        max_rescore.hash(state);
+        adaptive_rescore.hash(state);
     }
 }
 
@@ -548,7 +634,7 @@ pub const ACORN_MAX_SELECTIVITY_DEFAULT: f64 = 0.4;
 
 /// ACORN-related search parameters
 #[derive(
-    Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Copy, PartialEq, Default, Hash,
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Copy, PartialEq, Eq, Default, Hash,
 )]
 #[serde(rename_all = "snake_case")]
 pub struct AcornSearchParams {
@@ -575,7 +661,7 @@ pub struct AcornSearchParams {
 
 /// Additional parameters of the search
 #[derive(
-    Debug, Deserialize, Serialize, JsonSchema, Validate, Copy, Clone, PartialEq, Default, Hash,
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Copy, Clone, PartialEq, Eq, Default, Hash,
 )]
 #[serde(rename_all = "snake_case")]
 pub struct SearchParams {
@@ -681,6 +767,58 @@ pub struct HnswConfig {
     /// Requires quantized vectors to be enabled. Multi-vectors are not supported.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inline_storage: Option<bool>,
+    /// Store HNSW graph links delta-encoded with varints instead of bitpacking. Default: false.
+    /// Reduces the size of the graph of large segments, at a small cost of decoding speed.
+    /// Has no effect if `inline_storage` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub varint_links: Option<bool>,
+    /// Number of edges per node on level 0 of the index graph. If not set, `2 * m` is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, max = 2048))]
+    pub m0: Option<usize>,
+    /// Number of neighbours to consider during the index building on levels above 0.
+    /// If not set, `ef_construct` is used.
+    /// Upper levels are only used to find entry points, so a smaller value speeds up building
+    /// without much effect on the search quality.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 4, max = 10000))]
+    pub ef_construct_upper: Option<usize>,
+    /// Strategy to select neighbours of a node among the candidates found during the index
+    /// building. Default: `heuristic`.
+    /// Not applied when the index is built on GPU.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neighbor_selection: Option<HnswNeighborSelection>,
+}
+
+/// Strategy to select neighbours of a node during HNSW index building
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HnswNeighborSelection {
+    /// Skip candidates which are closer to an already selected neighbour than to the node itself.
+    /// Produces a sparse graph of diverse links.
+    #[default]
+    Heuristic,
+    /// Same as `heuristic`, but fill the remaining free links of a new node with the skipped
+    /// candidates. Prunes less aggressively: higher recall at the cost of a denser graph.
+    HeuristicKeepPruned,
+    /// Link to the closest candidates, without pruning.
+    Nearest,
+}
+
+impl HnswNeighborSelection {
+    pub fn use_heuristic(self) -> bool {
+        match self {
+            HnswNeighborSelection::Heuristic | HnswNeighborSelection::HeuristicKeepPruned => true,
+            HnswNeighborSelection::Nearest => false,
+        }
+    }
+
+    pub fn keep_pruned(self) -> bool {
+        match self {
+            HnswNeighborSelection::HeuristicKeepPruned => true,
+            HnswNeighborSelection::Heuristic | HnswNeighborSelection::Nearest => false,
+        }
+    }
 }
 
 impl HnswConfig {
@@ -701,6 +839,10 @@ impl HnswConfig {
             payload_m,
             on_disk,
             inline_storage,
+            varint_links,
+            m0,
+            ef_construct_upper,
+            neighbor_selection,
         } = *self;
 
         m != other.m
@@ -712,6 +854,10 @@ impl HnswConfig {
             // to flip this flag
             || on_disk != other.on_disk
             || inline_storage != other.inline_storage
+            || varint_links != other.varint_links
+            || m0 != other.m0
+            || ef_construct_upper != other.ef_construct_upper
+            || neighbor_selection != other.neighbor_selection
     }
 }
 
@@ -1112,6 +1258,32 @@ pub struct StrictModeConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0))]
     pub max_payload_index_count: Option<usize>,
+
+    // Vectors in responses
+    /// Whether to return vectors by APIs, which return them unless requested otherwise, like
+    /// retrieving a single point by ID. If false, vectors are only returned when requested with
+    /// `with_vector`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_vectors_by_default: Option<bool>,
+
+    /// Max estimated size of dense vectors returned by a single read request, in bytes.
+    /// Estimated by the number of requested points and the dimensions of the requested vectors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_response_vectors_bytes: Option<usize>,
+
+    // Backpressure
+    /// Max number of pending operations in the update queue of a replica.
+    /// While exceeded, writes are rejected with a hint to retry later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_update_queue_length: Option<usize>,
+
+    /// Max size of vectors in a single unindexed segment of a replica, in kilobytes.
+    /// Segments below the indexing threshold are not counted, as they are never indexed.
+    /// While exceeded, writes are rejected with a hint to retry once optimizers catch up.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_unindexed_segment_size_kb: Option<usize>,
 }
 
 impl Eq for StrictModeConfig {}
@@ -1139,6 +1311,10 @@ impl Hash for StrictModeConfig {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            read_vectors_by_default,
+            max_response_vectors_bytes,
+            max_update_queue_length,
+            max_unindexed_segment_size_kb,
         } = self;
         enabled.hash(state);
         max_query_limit.hash(state);
@@ -1158,6 +1334,10 @@ impl Hash for StrictModeConfig {
         multivector_config.hash(state);
         sparse_config.hash(state);
         max_payload_index_count.hash(state);
+        read_vectors_by_default.hash(state);
+        max_response_vectors_bytes.hash(state);
+        max_update_queue_length.hash(state);
+        max_unindexed_segment_size_kb.hash(state);
     }
 }
 
@@ -1256,6 +1436,27 @@ pub struct StrictModeConfigOutput {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0))]
     pub max_payload_index_count: Option<usize>,
+
+    /// Whether to return vectors by APIs, which return them unless requested otherwise, like
+    /// retrieving a single point by ID. If false, vectors are only returned when requested with
+    /// `with_vector`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_vectors_by_default: Option<bool>,
+
+    /// Max estimated size of dense vectors returned by a single read request, in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub max_response_vectors_bytes: Option<usize>,
+
+    /// Max number of pending operations in the update queue of a replica.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub max_update_queue_length: Option<usize>,
+
+    /// Max size of vectors in a single unindexed segment of a replica, in kilobytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub max_unindexed_segment_size_kb: Option<usize>,
 }
 
 impl From<StrictModeConfig> for StrictModeConfigOutput {
@@ -1280,6 +1481,10 @@ impl From<StrictModeConfig> for StrictModeConfigOutput {
             multivector_config,
             sparse_config,
             max_payload_index_count,
+            read_vectors_by_default,
+            max_response_vectors_bytes,
+            max_update_queue_length,
+            max_unindexed_segment_size_kb,
         } = config;
 
         Self {
@@ -1302,6 +1507,10 @@ impl From<StrictModeConfig> for StrictModeConfigOutput {
             multivector_config: multivector_config.map(StrictModeMultivectorConfigOutput::from),
             sparse_config: sparse_config.map(StrictModeSparseConfigOutput::from),
             max_payload_index_count,
+            read_vectors_by_default,
+            max_response_vectors_bytes,
+            max_update_queue_length,
+            max_unindexed_segment_size_kb,
         }
     }
 }
@@ -1318,6 +1527,10 @@ impl Default for HnswConfig {
             on_disk: Some(false),
             payload_m: None,
             inline_storage: None,
+            varint_links: None,
+            m0: None,
+            ef_construct_upper: None,
+            neighbor_selection: None,
         }
     }
 }
@@ -1375,6 +1588,10 @@ pub struct SegmentConfig {
     pub sparse_vector_data: HashMap<VectorNameBuf, SparseVectorDataConfig>,
     /// Defines payload storage type
     pub payload_storage_type: PayloadStorageType,
+    /// Payload keys, by which values points of the segment are grouped together.
+    /// Not known for segments built before the keys were recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defragment_keys: Option<Vec<PayloadKeyType>>,
 }
 
 impl SegmentConfig {
@@ -1447,6 +1664,7 @@ impl SegmentConfig {
             vector_data: _,
             sparse_vector_data: _,
             payload_storage_type: _,
+            defragment_keys: _,
         } = self;
 
         let is_vector_config_compatible = is_map_compatible(
@@ -1594,6 +1812,9 @@ pub struct VectorDataConfig {
     /// Vector specific configuration to set specific storage element type
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub datatype: Option<VectorStorageDatatype>,
+    /// Trust vectors to be normalized by the client and skip normalization on ingest
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_normalization: Option<bool>,
 }
 
 impl VectorDataConfig {
@@ -2039,6 +2260,7 @@ pub enum PayloadSchemaType {
     Bool,
     Datetime,
     Uuid,
+    GeoShape,
 }
 
 impl PayloadSchemaType {
@@ -2057,6 +2279,7 @@ impl PayloadSchemaType {
             Self::Bool => PayloadSchemaParams::Bool(BoolIndexParams::default()),
             Self::Datetime => PayloadSchemaParams::Datetime(DatetimeIndexParams::default()),
             Self::Uuid => PayloadSchemaParams::Uuid(UuidIndexParams::default()),
+            Self::GeoShape => PayloadSchemaParams::GeoShape(GeoShapeIndexParams::default()),
         }
     }
 }
@@ -2074,6 +2297,7 @@ pub enum PayloadSchemaParams {
     Bool(BoolIndexParams),
     Datetime(DatetimeIndexParams),
     Uuid(UuidIndexParams),
+    GeoShape(GeoShapeIndexParams),
 }
 
 impl PayloadSchemaParams {
@@ -2092,6 +2316,7 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Bool(_) => PayloadSchemaType::Bool,
             PayloadSchemaParams::Datetime(_) => PayloadSchemaType::Datetime,
             PayloadSchemaParams::Uuid(_) => PayloadSchemaType::Uuid,
+            PayloadSchemaParams::GeoShape(_) => PayloadSchemaType::GeoShape,
         }
     }
 
@@ -2104,10 +2329,29 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Uuid(uuid) => uuid.is_tenant.unwrap_or_default(),
             PayloadSchemaParams::Geo(_)
             | PayloadSchemaParams::Text(_)
-            | PayloadSchemaParams::Bool(_) => false,
+            | PayloadSchemaParams::Bool(_)
+            | PayloadSchemaParams::GeoShape(_) => false,
         }
     }
 
+    /// Max number of points with a single value to build a dedicated HNSW subgraph for it.
+    /// `None` if dedicated subgraphs are not enabled for this tenant field.
+    pub fn tenant_subgraphs_max_points(&self) -> Option<usize> {
+        let (tenant_subgraphs, max_points) = match self {
+            PayloadSchemaParams::Keyword(i) => (i.tenant_subgraphs, i.tenant_subgraphs_max_points),
+            PayloadSchemaParams::Uuid(i) => (i.tenant_subgraphs, i.tenant_subgraphs_max_points),
+            PayloadSchemaParams::Integer(_)
+            | PayloadSchemaParams::Float(_)
+            | PayloadSchemaParams::Geo(_)
+            | PayloadSchemaParams::Text(_)
+            | PayloadSchemaParams::Bool(_)
+            | PayloadSchemaParams::Datetime(_)
+            | PayloadSchemaParams::GeoShape(_) => return None,
+        };
+        (self.tenant_optimization() && tenant_subgraphs.unwrap_or_default())
+            .then(|| max_points.unwrap_or(usize::MAX))
+    }
+
     pub fn is_on_disk(&self) -> bool {
         match self {
             PayloadSchemaParams::Keyword(i) => i.on_disk.unwrap_or_default(),
@@ -2118,7 +2362,25 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Text(i) => i.on_disk.unwrap_or_default(),
             PayloadSchemaParams::Geo(i) => i.on_disk.unwrap_or_default(),
             PayloadSchemaParams::Bool(i) => i.on_disk.unwrap_or_default(),
-        }
+            // Geo shapes are always kept in memory
+            PayloadSchemaParams::GeoShape(_) => false,
+        }
+    }
+
+    /// Whether pages of the on-disk index should be populated on load
+    pub fn populate(&self) -> bool {
+        let populate = match self {
+            PayloadSchemaParams::Keyword(i) => i.populate,
+            PayloadSchemaParams::Integer(i) => i.populate,
+            PayloadSchemaParams::Float(i) => i.populate,
+            PayloadSchemaParams::Datetime(i) => i.populate,
+            PayloadSchemaParams::Uuid(i) => i.populate,
+            PayloadSchemaParams::Text(i) => i.populate,
+            PayloadSchemaParams::Geo(i) => i.populate,
+            PayloadSchemaParams::Bool(i) => i.populate,
+            PayloadSchemaParams::GeoShape(_) => None,
+        };
+        self.is_on_disk() && populate.unwrap_or_default()
     }
 
     pub fn enable_hnsw(&self) -> bool {
@@ -2131,6 +2393,7 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Text(params) => params.enable_hnsw.unwrap_or(true),
             PayloadSchemaParams::Geo(params) => params.enable_hnsw.unwrap_or(true),
             PayloadSchemaParams::Bool(params) => params.enable_hnsw.unwrap_or(true),
+            PayloadSchemaParams::GeoShape(params) => params.enable_hnsw.unwrap_or(true),
         }
     }
 }
@@ -2140,12 +2403,19 @@ impl Validate for PayloadSchemaParams {
         match self {
             PayloadSchemaParams::Keyword(_) => Ok(()),
             PayloadSchemaParams::Integer(integer_index_params) => integer_index_params.validate(),
-            PayloadSchemaParams::Float(_) => Ok(()),
+            PayloadSchemaParams::Float(float_index_params) => validate_histogram_params(
+                &float_index_params.histogram_buckets,
+                &float_index_params.histogram_max_bucket_size,
+            ),
             PayloadSchemaParams::Geo(_) => Ok(()),
-            PayloadSchemaParams::Text(_) => Ok(()),
+            PayloadSchemaParams::Text(text_index_params) => text_index_params.validate(),
             PayloadSchemaParams::Bool(_) => Ok(()),
-            PayloadSchemaParams::Datetime(_) => Ok(()),
+            PayloadSchemaParams::Datetime(datetime_index_params) => validate_histogram_params(
+                &datetime_index_params.histogram_buckets,
+                &datetime_index_params.histogram_max_bucket_size,
+            ),
             PayloadSchemaParams::Uuid(_) => Ok(()),
+            PayloadSchemaParams::GeoShape(_) => Ok(()),
         }
     }
 }
@@ -2198,7 +2468,8 @@ impl Display for PayloadFieldSchema {
                 | PayloadSchemaParams::Geo(_)
                 | PayloadSchemaParams::Bool(_)
                 | PayloadSchemaParams::Datetime(_)
-                | PayloadSchemaParams::Uuid(_) => write!(f, "{}", params.name()),
+                | PayloadSchemaParams::Uuid(_)
+                | PayloadSchemaParams::GeoShape(_) => write!(f, "{}", params.name()),
                 PayloadSchemaParams::Integer(integer_params) => {
                     let range = integer_params.range.unwrap_or(true);
                     let lookup = integer_params.lookup.unwrap_or(true);
@@ -2243,6 +2514,13 @@ impl PayloadFieldSchema {
         }
     }
 
+    pub fn tenant_subgraphs_max_points(&self) -> Option<usize> {
+        match self {
+            PayloadFieldSchema::FieldType(_) => None,
+            PayloadFieldSchema::FieldParams(params) => params.tenant_subgraphs_max_points(),
+        }
+    }
+
     pub fn is_on_disk(&self) -> bool {
         match self {
             PayloadFieldSchema::FieldType(_) => false,
@@ -2250,6 +2528,13 @@ impl PayloadFieldSchema {
         }
     }
 
+    pub fn populate(&self) -> bool {
+        match self {
+            PayloadFieldSchema::FieldType(_) => false,
+            PayloadFieldSchema::FieldParams(params) => params.populate(),
+        }
+    }
+
     pub fn kind(&self) -> PayloadSchemaType {
         match self {
             PayloadFieldSchema::FieldType(t) => *t,
@@ -2269,6 +2554,7 @@ impl PayloadFieldSchema {
                 PayloadSchemaType::Geo => false,
                 PayloadSchemaType::Text => false,
                 PayloadSchemaType::Datetime => false,
+                PayloadSchemaType::GeoShape => false,
             },
             PayloadFieldSchema::FieldParams(payload_schema_params) => match payload_schema_params {
                 PayloadSchemaParams::Keyword(_) => true,
@@ -2281,6 +2567,7 @@ impl PayloadFieldSchema {
                 PayloadSchemaParams::Geo(_) => false,
                 PayloadSchemaParams::Text(_) => false,
                 PayloadSchemaParams::Datetime(_) => false,
+                PayloadSchemaParams::GeoShape(_) => false,
             },
         }
     }
@@ -2307,6 +2594,8 @@ impl TryFrom<PayloadIndexInfo> for PayloadFieldSchema {
             data_type,
             params,
             points: _,
+            distinct_values: _,
+            distinct_values_sketch: _,
         } = index_info;
 
         match params {
@@ -2417,11 +2706,29 @@ pub struct MatchValue {
     pub value: ValueVariants,
 }
 
+/// Maximum edit distance of fuzzy full-text match
+pub const MAX_TEXT_FUZZINESS: u8 = 2;
+
+/// Limit of the compiled size of regular expressions in match conditions
+pub const MAX_REGEX_SIZE: usize = 1 << 20;
+
 /// Full-text match of the strings.
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub struct MatchText {
     pub text: String,
+    /// Match tokens within this number of edits (insertions, deletions or substitutions of a character)
+    /// from the tokens of the text. Maximum is 2. Default is 0, exact match of tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fuzziness: Option<u8>,
+    /// Overrides the minimum token length of the index for this query.
+    /// Shorter query tokens are ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_token_len: Option<usize>,
+    /// Overrides the maximum token length of the index for this query.
+    /// With the prefix tokenizer, lower values match shorter prefixes of the indexed words.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_token_len: Option<usize>,
 }
 
 /// Full-text match of at least one token of the string.
@@ -2433,7 +2740,12 @@ pub struct MatchTextAny {
 
 impl<S: Into<String>> From<S> for MatchText {
     fn from(text: S) -> Self {
-        MatchText { text: text.into() }
+        MatchText {
+            text: text.into(),
+            fuzziness: None,
+            min_token_len: None,
+            max_token_len: None,
+        }
     }
 }
 
@@ -2452,6 +2764,65 @@ impl<S: Into<String>> From<S> for MatchPhrase {
     }
 }
 
+/// Full-text match of all tokens of the text, close to each other in any order.
+///
+/// Requires `phrase_matching` to be enabled in the full-text index.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchNear {
+    pub near: String,
+    /// Maximum number of other tokens between the matched tokens
+    pub within: u32,
+}
+
+/// Match keywords starting with the given prefix
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchPrefix {
+    pub prefix: String,
+}
+
+impl<S: Into<String>> From<S> for MatchPrefix {
+    fn from(prefix: S) -> Self {
+        MatchPrefix {
+            prefix: prefix.into(),
+        }
+    }
+}
+
+/// Match keywords containing a match of the given regular expression
+///
+/// Evaluated against keyword index values or raw payload strings. Full-text indexes only store
+/// tokens of the text, so they are not used for this condition.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchRegex {
+    pub regex: String,
+}
+
+impl MatchRegex {
+    pub fn compile(&self) -> Result<regex::Regex, regex::Error> {
+        regex::RegexBuilder::new(&self.regex)
+            .size_limit(MAX_REGEX_SIZE)
+            .build()
+    }
+
+    /// Check a single value, compiling the regex on each call.
+    ///
+    /// To check many values, compile the regex once with [`Self::compile`].
+    pub fn is_match(&self, value: &str) -> bool {
+        self.compile().is_ok_and(|regex| regex.is_match(value))
+    }
+}
+
+impl<S: Into<String>> From<S> for MatchRegex {
+    fn from(regex: S) -> Self {
+        MatchRegex {
+            regex: regex.into(),
+        }
+    }
+}
+
 /// Exact match on any of the given values
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
@@ -2474,6 +2845,9 @@ pub enum MatchInterface {
     Text(MatchText),
     TextAny(MatchTextAny),
     Phrase(MatchPhrase),
+    Near(MatchNear),
+    Prefix(MatchPrefix),
+    Regex(MatchRegex),
     Any(MatchAny),
     Except(MatchExcept),
 }
@@ -2486,6 +2860,9 @@ pub enum Match {
     Text(MatchText),
     TextAny(MatchTextAny),
     Phrase(MatchPhrase),
+    Near(MatchNear),
+    Prefix(MatchPrefix),
+    Regex(MatchRegex),
     Any(MatchAny),
     Except(MatchExcept),
 }
@@ -2496,7 +2873,16 @@ impl Match {
     }
 
     pub fn new_text(text: &str) -> Self {
-        Self::Text(MatchText { text: text.into() })
+        Self::Text(MatchText::from(text))
+    }
+
+    pub fn new_fuzzy_text(text: &str, fuzziness: u8) -> Self {
+        Self::Text(MatchText {
+            text: text.into(),
+            fuzziness: Some(fuzziness),
+            min_token_len: None,
+            max_token_len: None,
+        })
     }
 
     pub fn new_phrase(phrase: &str) -> Self {
@@ -2505,6 +2891,25 @@ impl Match {
         })
     }
 
+    pub fn new_near(near: &str, within: u32) -> Self {
+        Self::Near(MatchNear {
+            near: near.into(),
+            within,
+        })
+    }
+
+    pub fn new_prefix(prefix: &str) -> Self {
+        Self::Prefix(MatchPrefix {
+            prefix: prefix.into(),
+        })
+    }
+
+    pub fn new_regex(regex: &str) -> Self {
+        Self::Regex(MatchRegex {
+            regex: regex.into(),
+        })
+    }
+
     pub fn new_any(any: AnyVariants) -> Self {
         Self::Any(MatchAny { any })
     }
@@ -2524,7 +2929,7 @@ impl From<MatchInterface> for Match {
     fn from(value: MatchInterface) -> Self {
         match value {
             MatchInterface::Value(value) => Self::Value(MatchValue { value: value.value }),
-            MatchInterface::Text(text) => Self::Text(MatchText { text: text.text }),
+            MatchInterface::Text(text) => Self::Text(text),
             MatchInterface::TextAny(text_any) => Self::TextAny(MatchTextAny {
                 text_any: text_any.text_any,
             }),
@@ -2533,6 +2938,9 @@ impl From<MatchInterface> for Match {
                 except: except.except,
             }),
             MatchInterface::Phrase(MatchPhrase { phrase }) => Self::Phrase(MatchPhrase { phrase }),
+            MatchInterface::Near(near) => Self::Near(near),
+            MatchInterface::Prefix(MatchPrefix { prefix }) => Self::Prefix(MatchPrefix { prefix }),
+            MatchInterface::Regex(MatchRegex { regex }) => Self::Regex(MatchRegex { regex }),
         }
     }
 }
@@ -2650,6 +3058,9 @@ impl<'de> serde::Deserialize<'de> for RangeInterface {
     /// Parses range bounds, treating string bounds as RFC3339 datetimes for REST/JSON `datetime_range` filters.
     /// Preserves clear user-facing errors when datetime formats are invalid.
     /// Example accepted datetime bound: `2014-01-01T00:00:00Z`.
+    ///
+    /// Datetime bounds may also be date math expressions, like `now-7d` or `now/d@+02:00`, which are
+    /// resolved to absolute datetimes at parse time. See [`date_math`].
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
@@ -2671,6 +3082,8 @@ impl<'de> serde::Deserialize<'de> for RangeInterface {
                 .any(|k| obj.get(*k).map(|v| v.is_string()).unwrap_or(false));
 
             if has_string_bound {
+                let value = resolve_date_math_bounds(value, chrono::Utc::now())
+                    .map_err(serde::de::Error::custom)?;
                 return serde_json::from_value::<Range<DateTimePayloadType>>(value)
                     .map(RangeInterface::DateTime)
                     .map_err(serde::de::Error::custom);
@@ -2688,6 +3101,31 @@ impl<'de> serde::Deserialize<'de> for RangeInterface {
     }
 }
 
+/// Resolve date math bounds, like `now-7d`, to absolute datetimes.
+///
+/// All bounds are resolved against the same `now`, so that relative bounds of one range stay consistent.
+fn resolve_date_math_bounds(
+    mut value: Value,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<Value, String> {
+    let Some(obj) = value.as_object_mut() else {
+        return Ok(value);
+    };
+
+    for key in ["lt", "gt", "lte", "gte"] {
+        if let Some(Value::String(bound)) = obj.get_mut(key)
+            && date_math::is_date_math(bound)
+        {
+            let resolved = date_math::resolve_date_math(bound, now).map_err(|err| {
+                format!("'{bound}' is not a valid date math expression in `{key}`: {err}")
+            })?;
+            *bound = resolved.0.to_rfc3339();
+        }
+    }
+
+    Ok(value)
+}
+
 type OrderedFloatPayloadType = OrderedFloat<FloatPayloadType>;
 
 /// Range filter request
@@ -2743,6 +3181,38 @@ pub struct ValuesCount {
     pub lte: Option<usize>,
 }
 
+/// Comparison of a field with other fields of the same point.
+///
+/// Numbers are compared with numbers and datetime strings with datetime strings,
+/// values of other types never match.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct FieldComparison {
+    /// point.key < point.{compare.lt}
+    pub lt: Option<JsonPath>,
+    /// point.key > point.{compare.gt}
+    pub gt: Option<JsonPath>,
+    /// point.key >= point.{compare.gte}
+    pub gte: Option<JsonPath>,
+    /// point.key <= point.{compare.lte}
+    pub lte: Option<JsonPath>,
+}
+
+impl FieldComparison {
+    /// Paths of compared fields, with orderings of the field value accepted by each of them
+    pub fn bounds(&self) -> impl Iterator<Item = (&JsonPath, fn(Ordering) -> bool)> {
+        let Self { lt, gt, gte, lte } = self;
+        [
+            (lt, Ordering::is_lt as fn(Ordering) -> bool),
+            (gt, Ordering::is_gt),
+            (gte, Ordering::is_ge),
+            (lte, Ordering::is_le),
+        ]
+        .into_iter()
+        .filter_map(|(path, accept)| Some((path.as_ref()?, accept)))
+    }
+}
+
 impl ValuesCount {
     pub fn check_count(&self, count: usize) -> bool {
         let Self { lt, gt, gte, lte } = self;
@@ -2836,14 +3306,33 @@ pub struct GeoPolygonShadow {
     pub interiors: Option<Vec<GeoLineString>>,
 }
 
+#[derive(Debug, Clone)]
 pub struct PolygonWrapper {
     pub polygon: Polygon,
+    /// Bounding box of the polygon, a cheap pre-filter before exact point-in-polygon checks
+    pub bounding_rect: Option<Rect>,
 }
 
 impl PolygonWrapper {
+    pub fn new(polygon: Polygon) -> Self {
+        let bounding_rect = polygon.bounding_rect();
+        Self {
+            polygon,
+            bounding_rect,
+        }
+    }
+
+    /// Check if the rectangle may intersect the polygon, judging by the bounding box only
+    pub fn bounding_rect_intersects(&self, rect: &Rect) -> bool {
+        self.bounding_rect
+            .is_some_and(|bounding_rect| bounding_rect.intersects(rect))
+    }
+
     pub fn check_point(&self, point: &GeoPoint) -> bool {
         let point_new = Point::new(point.lon.0, point.lat.0);
-        self.polygon.contains(&point_new)
+        self.bounding_rect
+            .is_some_and(|bounding_rect| bounding_rect.intersects(&point_new))
+            && self.polygon.contains(&point_new)
     }
 }
 
@@ -2919,9 +3408,7 @@ impl GeoPolygon {
                 .map(LineString)
                 .collect(),
         };
-        PolygonWrapper {
-            polygon: Polygon::new(exterior_line, interior_lines),
-        }
+        PolygonWrapper::new(Polygon::new(exterior_line, interior_lines))
     }
 }
 
@@ -2948,6 +3435,185 @@ impl TryFrom<GeoPolygonShadow> for GeoPolygon {
     }
 }
 
+/// Position in GeoJSON format, as `[lon, lat]`
+pub type GeoJsonPosition = [f64; 2];
+
+/// Geo shape stored in payload, in GeoJSON format
+///
+/// Example: `{"type": "LineString", "coordinates": [[13.37, 52.51], [13.40, 52.52]]}`
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(tag = "type", content = "coordinates")]
+pub enum GeoShape {
+    /// Line through at least 2 positions
+    LineString(Vec<GeoJsonPosition>),
+    /// Surface bounded by the exterior ring, optionally followed by rings bounding holes.
+    /// Each ring must consist of at least 4 positions, and the first and last positions must be
+    /// the same.
+    Polygon(Vec<Vec<GeoJsonPosition>>),
+}
+
+impl GeoShape {
+    /// Parse a valid geo shape from a payload value
+    pub fn geometry_from_payload(value: &Value) -> Option<Geometry> {
+        GeoShape::deserialize(value).ok()?.to_geometry().ok()
+    }
+
+    /// Convert to a geometry of the geo crate, validating coordinates on the way
+    pub fn to_geometry(&self) -> OperationResult<Geometry> {
+        let line_string = |positions: &[GeoJsonPosition]| {
+            positions
+                .iter()
+                .map(|&[lon, lat]| {
+                    GeoPoint::validate(lon, lat).map_err(|err| {
+                        OperationError::ValidationError {
+                            description: err.to_string(),
+                        }
+                    })?;
+                    Ok(Coord { x: lon, y: lat })
+                })
+                .collect::<OperationResult<Vec<_>>>()
+                .map(LineString)
+        };
+
+        match self {
+            GeoShape::LineString(positions) => {
+                if positions.len() < 2 {
+                    return Err(OperationError::ValidationError {
+                        description: format!(
+                            "line string invalid, the size must be at least 2, got {}",
+                            positions.len(),
+                        ),
+                    });
+                }
+                Ok(Geometry::LineString(line_string(positions)?))
+            }
+            GeoShape::Polygon(rings) => {
+                let mut rings = rings
+                    .iter()
+                    .map(|ring| {
+                        let ring = line_string(ring)?;
+                        if ring.0.len() <= 3 {
+                            return Err(OperationError::ValidationError {
+                                description: format!(
+                                    "polygon invalid, the size must be at least 4, got {}",
+                                    ring.0.len(),
+                                ),
+                            });
+                        }
+                        if !ring.is_closed() {
+                            return Err(OperationError::ValidationError {
+                                description: String::from(
+                                    "polygon invalid, the first and the last points should be the same to form a closed line",
+                                ),
+                            });
+                        }
+                        Ok(ring)
+                    })
+                    .collect::<OperationResult<Vec<_>>>()?;
+
+                if rings.is_empty() {
+                    return Err(OperationError::ValidationError {
+                        description: String::from("polygon invalid, the exterior ring is missing"),
+                    });
+                }
+                let exterior = rings.remove(0);
+                Ok(Geometry::Polygon(Polygon::new(exterior, rings)))
+            }
+        }
+    }
+}
+
+/// Spatial relation between a geo shape in payload and the region of a filter
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum GeoShapeRelation {
+    /// Shape has at least one point in common with the region
+    #[default]
+    Intersects,
+    /// Shape lies entirely within the region
+    Within,
+    /// Shape entirely contains the region
+    Contains,
+}
+
+/// Geo filter request
+///
+/// Matches geo shapes, stored in payload in GeoJSON format, which are in the given `relation`
+/// to the region. The region is defined by either `geo_bounding_box` or `geo_polygon`.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct GeoShapeCondition {
+    /// Relation of the shapes to the region. Default: intersects
+    #[serde(default)]
+    pub relation: GeoShapeRelation,
+    /// Region as a rectangle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geo_bounding_box: Option<GeoBoundingBox>,
+    /// Region as a polygon
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geo_polygon: Option<GeoPolygon>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GeoShapeConditionWrapper {
+    pub relation: GeoShapeRelation,
+    pub region: MultiPolygon,
+}
+
+impl GeoShapeCondition {
+    /// Convert the region to geo crate geometry, for checking many shapes against it
+    pub fn convert(&self) -> GeoShapeConditionWrapper {
+        let mut polygons = vec![];
+
+        if let Some(GeoBoundingBox {
+            top_left,
+            bottom_right,
+        }) = &self.geo_bounding_box
+        {
+            let rect = |min_lon: f64, max_lon: f64| {
+                Rect::new(
+                    Coord {
+                        x: min_lon,
+                        y: bottom_right.lat.0,
+                    },
+                    Coord {
+                        x: max_lon,
+                        y: top_left.lat.0,
+                    },
+                )
+                .to_polygon()
+            };
+            if top_left.lon > bottom_right.lon {
+                // Split the rectangle crossing the antimeridian
+                polygons.push(rect(top_left.lon.0, 180.0));
+                polygons.push(rect(-180.0, bottom_right.lon.0));
+            } else {
+                polygons.push(rect(top_left.lon.0, bottom_right.lon.0));
+            }
+        }
+
+        if let Some(geo_polygon) = &self.geo_polygon {
+            polygons.push(geo_polygon.convert().polygon);
+        }
+
+        GeoShapeConditionWrapper {
+            relation: self.relation,
+            region: MultiPolygon::new(polygons),
+        }
+    }
+}
+
+impl GeoShapeConditionWrapper {
+    pub fn check_shape(&self, shape: &Geometry) -> bool {
+        let matrix = shape.relate(&self.region);
+        match self.relation {
+            GeoShapeRelation::Intersects => matrix.is_intersects(),
+            GeoShapeRelation::Within => matrix.is_within(),
+            GeoShapeRelation::Contains => matrix.is_contains(),
+        }
+    }
+}
+
 /// All possible payload filtering conditions
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
 #[validate(schema(function = "validate_field_condition"))]
@@ -2970,6 +3636,9 @@ pub struct FieldCondition {
     /// Check if geo point is within a given polygon
     #[serde(skip_serializing_if = "Option::is_none")]
     pub geo_polygon: Option<GeoPolygon>,
+    /// Check if geo shape relates to a given area
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geo_shape: Option<GeoShapeCondition>,
     /// Check number of values of the field
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values_count: Option<ValuesCount>,
@@ -2979,6 +3648,13 @@ pub struct FieldCondition {
     /// Check that the field is null, alternative syntax for `is_null: "field_name"`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_null: Option<bool>,
+    /// Check that the field is present in the payload, with any value including `null` and `[]`.
+    /// If false, check that the field is absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_field: Option<bool>,
+    /// Check that the field is in the given order with other fields of the same point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compare: Option<FieldComparison>,
 }
 
 impl FieldCondition {
@@ -2990,9 +3666,12 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             is_empty: None,
             is_null: None,
+            has_field: None,
+            compare: None,
         }
     }
 
@@ -3004,9 +3683,12 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             is_empty: None,
             is_null: None,
+            has_field: None,
+            compare: None,
         }
     }
 
@@ -3021,9 +3703,12 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             is_empty: None,
             is_null: None,
+            has_field: None,
+            compare: None,
         }
     }
 
@@ -3035,9 +3720,12 @@ impl FieldCondition {
             geo_bounding_box: Some(geo_bounding_box),
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             is_empty: None,
             is_null: None,
+            has_field: None,
+            compare: None,
         }
     }
 
@@ -3049,9 +3737,12 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: Some(geo_radius),
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             is_empty: None,
             is_null: None,
+            has_field: None,
+            compare: None,
         }
     }
 
@@ -3063,9 +3754,29 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: Some(geo_polygon),
+            geo_shape: None,
             values_count: None,
             is_empty: None,
             is_null: None,
+            has_field: None,
+            compare: None,
+        }
+    }
+
+    pub fn new_geo_shape(key: PayloadKeyType, geo_shape: GeoShapeCondition) -> Self {
+        Self {
+            key,
+            r#match: None,
+            range: None,
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            geo_shape: Some(geo_shape),
+            values_count: None,
+            is_empty: None,
+            is_null: None,
+            has_field: None,
+            compare: None,
         }
     }
 
@@ -3077,9 +3788,12 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: Some(values_count),
             is_empty: None,
             is_null: None,
+            has_field: None,
+            compare: None,
         }
     }
 
@@ -3091,9 +3805,12 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             is_empty: Some(is_empty),
             is_null: None,
+            has_field: None,
+            compare: None,
         }
     }
 
@@ -3105,9 +3822,46 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
             is_empty: None,
             is_null: Some(is_null),
+            has_field: None,
+            compare: None,
+        }
+    }
+
+    pub fn new_has_field(key: PayloadKeyType, has_field: bool) -> Self {
+        Self {
+            key,
+            r#match: None,
+            range: None,
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            geo_shape: None,
+            values_count: None,
+            is_empty: None,
+            is_null: None,
+            has_field: Some(has_field),
+            compare: None,
+        }
+    }
+
+    pub fn new_compare(key: PayloadKeyType, compare: FieldComparison) -> Self {
+        Self {
+            key,
+            r#match: None,
+            range: None,
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            geo_shape: None,
+            values_count: None,
+            is_empty: None,
+            is_null: None,
+            has_field: None,
+            compare: Some(compare),
         }
     }
 
@@ -3120,10 +3874,13 @@ impl FieldCondition {
                 geo_bounding_box: None,
                 geo_radius: None,
                 geo_polygon: None,
+                geo_shape: None,
                 values_count: None,
                 key: _,
                 is_empty: None,
                 is_null: None,
+                has_field: None,
+                compare: None,
             }
         )
     }
@@ -3139,6 +3896,9 @@ impl FieldCondition {
             Match::Value(_) => 0,
             Match::Text(_) => 0,
             Match::Phrase(_) => 0,
+            Match::Near(_) => 0,
+            Match::Prefix(_) => 0,
+            Match::Regex(_) => 0,
             Match::TextAny(_) => 0,
         }
     }
@@ -3146,12 +3906,71 @@ impl FieldCondition {
 
 pub fn validate_field_condition(field_condition: &FieldCondition) -> Result<(), ValidationError> {
     if field_condition.all_fields_none() {
-        Err(ValidationError::new(
+        return Err(ValidationError::new(
             "At least one field condition must be specified",
-        ))
-    } else {
-        Ok(())
+        ));
+    }
+
+    if let Some(Match::Text(MatchText {
+        fuzziness: Some(fuzziness),
+        ..
+    })) = &field_condition.r#match
+        && *fuzziness > MAX_TEXT_FUZZINESS
+    {
+        return Err(ValidationError::new("fuzziness").with_message(
+            format!("fuzziness must not be greater than {MAX_TEXT_FUZZINESS}, got {fuzziness}")
+                .into(),
+        ));
+    }
+
+    if let Some(Match::Text(MatchText {
+        min_token_len: Some(min_token_len),
+        max_token_len: Some(max_token_len),
+        ..
+    })) = &field_condition.r#match
+        && min_token_len > max_token_len
+    {
+        return Err(ValidationError::new("min_token_len").with_message(
+            format!(
+                "min_token_len must not be greater than max_token_len, got {min_token_len} > {max_token_len}"
+            )
+            .into(),
+        ));
+    }
+
+    if let Some(Match::Regex(match_regex)) = &field_condition.r#match
+        && let Err(err) = match_regex.compile()
+    {
+        return Err(ValidationError::new("regex")
+            .with_message(format!("invalid regular expression: {err}").into()));
     }
+
+    if let Some(geo_shape) = &field_condition.geo_shape
+        && geo_shape.geo_bounding_box.is_some() == geo_shape.geo_polygon.is_some()
+    {
+        return Err(ValidationError::new("geo_shape").with_message(
+            "exactly one of geo_bounding_box or geo_polygon must be specified".into(),
+        ));
+    }
+
+    if let Some(compare) = &field_condition.compare {
+        if compare.bounds().next().is_none() {
+            return Err(ValidationError::new("compare")
+                .with_message("at least one of lt, gt, gte or lte must be specified".into()));
+        }
+
+        let only_compare = FieldCondition {
+            compare: None,
+            ..field_condition.clone()
+        }
+        .all_fields_none();
+        if !only_compare {
+            return Err(ValidationError::new("compare")
+                .with_message("compare can't be combined with other field conditions".into()));
+        }
+    }
+
+    Ok(())
 }
 
 /// Payload field
@@ -3690,6 +4509,19 @@ pub struct WithPayload {
     pub payload_selector: Option<PayloadSelector>,
 }
 
+impl WithPayload {
+    /// Keep the part of the payload, selected by these options
+    pub fn select(&self, payload: Option<Payload>) -> Option<Payload> {
+        if !self.enable {
+            return None;
+        }
+        match &self.payload_selector {
+            Some(selector) => payload.map(|payload| selector.process(payload)),
+            None => payload,
+        }
+    }
+}
+
 #[derive(
     Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Default, Hash,
 )]
@@ -4082,6 +4914,51 @@ mod tests {
         assert!(err.contains("Example"), "err was: {err}");
     }
 
+    #[test]
+    fn test_datetime_range_date_math() {
+        let json = r#"{
+            "key": "created_at",
+            "range": {
+                "gte": "now-7d/d",
+                "lt": "2024-01-31T00:00:00Z||+1M@+02:00"
+            }
+        }"#;
+
+        let before = chrono::Utc::now();
+        let condition: Condition = serde_json::from_str(json).unwrap();
+        let after = chrono::Utc::now();
+
+        let Condition::Field(FieldCondition {
+            range: Some(RangeInterface::DateTime(range)),
+            ..
+        }) = condition
+        else {
+            panic!("expected datetime range condition, got {condition:?}");
+        };
+
+        let gte = range.gte.unwrap().0;
+        assert!(gte <= before - chrono::TimeDelta::days(7));
+        assert!(gte > after - chrono::TimeDelta::days(8));
+        assert_eq!(gte.time(), chrono::NaiveTime::MIN);
+        assert_eq!(
+            range.lt,
+            Some(DateTimePayloadType::from_str("2024-02-29T00:00:00Z").unwrap()),
+        );
+
+        let json = r#"{
+            "key": "created_at",
+            "range": {
+                "gte": "now-7x"
+            }
+        }"#;
+
+        let err = serde_json::from_str::<Condition>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("now-7x"), "err was: {err}");
+        assert!(err.contains("unknown unit"), "err was: {err}");
+    }
+
     /// Regression test: DateTimePayloadType binary serialization roundtrip.
     /// Ensures DateTimePayloadType parses binary-encoded RFC3339 strings.
     #[test]
@@ -4176,6 +5053,24 @@ mod tests {
         });
     }
 
+    #[rstest]
+    #[case::cosine(Distance::Cosine, &[-0.5, 0.0, 0.25, 0.5, 1.0])]
+    #[case::dot(Distance::Dot, &[-4.0, -1.0, 0.0, 0.5, 2.0, 4.0])]
+    #[case::euclid(Distance::Euclid, &[-9.0, -4.0, -1.0, -0.25, 0.0])]
+    #[case::manhattan(Distance::Manhattan, &[-3.0, -2.0, -1.0, -0.5, 0.0])]
+    fn test_preprocess_score_threshold(#[case] distance: Distance, #[case] scores: &[ScoreType]) {
+        for threshold in [-1.0, 0.0, 0.5, 1.0, 2.0] {
+            let internal_threshold = distance.preprocess_score_threshold(threshold);
+            for &score in scores {
+                assert_eq!(
+                    score > internal_threshold,
+                    distance.check_threshold(distance.postprocess_score(score), threshold),
+                    "{distance:?}: score {score}, threshold {threshold}",
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_geo_radius_check_point() {
         let radius = GeoRadius {
@@ -4319,6 +5214,49 @@ mod tests {
         assert!(filter.is_err())
     }
 
+    #[test]
+    fn test_payload_schema_populate() {
+        let schema: PayloadFieldSchema =
+            serde_json::from_str(r#"{"type": "keyword", "on_disk": true, "populate": true}"#)
+                .unwrap();
+        assert!(schema.populate());
+
+        // In-RAM indexes are loaded into memory anyway
+        let schema: PayloadFieldSchema =
+            serde_json::from_str(r#"{"type": "bool", "populate": true}"#).unwrap();
+        assert!(!schema.populate());
+
+        let schema: PayloadFieldSchema =
+            serde_json::from_str(r#"{"type": "integer", "on_disk": true}"#).unwrap();
+        assert!(!schema.populate());
+
+        assert!(!PayloadFieldSchema::FieldType(PayloadSchemaType::Geo).populate());
+    }
+
+    #[test]
+    fn test_payload_schema_tenant_subgraphs() {
+        let schema: PayloadFieldSchema = serde_json::from_str(
+            r#"{"type": "keyword", "is_tenant": true, "tenant_subgraphs": true}"#,
+        )
+        .unwrap();
+        assert_eq!(schema.tenant_subgraphs_max_points(), Some(usize::MAX));
+
+        let schema: PayloadFieldSchema = serde_json::from_str(
+            r#"{"type": "uuid", "is_tenant": true, "tenant_subgraphs": true, "tenant_subgraphs_max_points": 1000}"#,
+        )
+        .unwrap();
+        assert_eq!(schema.tenant_subgraphs_max_points(), Some(1000));
+
+        // Only applies to tenant fields
+        let schema: PayloadFieldSchema =
+            serde_json::from_str(r#"{"type": "keyword", "tenant_subgraphs": true}"#).unwrap();
+        assert_eq!(schema.tenant_subgraphs_max_points(), None);
+
+        let schema: PayloadFieldSchema =
+            serde_json::from_str(r#"{"type": "keyword", "is_tenant": true}"#).unwrap();
+        assert_eq!(schema.tenant_subgraphs_max_points(), None);
+    }
+
     #[test]
     fn test_parse_match_query() {
         let query = r#"
@@ -4365,6 +5303,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_match_near() {
+        let query = r#"
+        {
+            "key": "hello",
+            "match": { "near": "quick fox", "within": 2 }
+        }
+        "#;
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        assert_eq!(condition.r#match.unwrap(), Match::new_near("quick fox", 2));
+    }
+
+    #[test]
+    fn test_parse_match_prefix() {
+        let query = r#"
+        {
+            "key": "hello",
+            "match": { "prefix": "wor" }
+        }
+        "#;
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        assert_eq!(condition.r#match.unwrap(), Match::new_prefix("wor"));
+    }
+
+    #[test]
+    fn test_parse_match_regex() {
+        let query = r#"
+        {
+            "key": "hello",
+            "match": { "regex": "^wor.d$" }
+        }
+        "#;
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        assert_eq!(condition.r#match.unwrap(), Match::new_regex("^wor.d$"));
+    }
+
+    #[test]
+    fn test_validate_match_regex() {
+        let key = JsonPath::new("hello");
+        let condition = FieldCondition::new_match(key.clone(), Match::new_regex("^wor.d$"));
+        assert!(condition.validate().is_ok());
+
+        let condition = FieldCondition::new_match(key, Match::new_regex("wor(ld"));
+        assert!(condition.validate().is_err());
+
+        let match_regex = MatchRegex::from("^wor.d$");
+        assert!(match_regex.is_match("world"));
+        assert!(!match_regex.is_match("worlds"));
+        assert!(!MatchRegex::from("wor(ld").is_match("wor(ld"));
+    }
+
     #[test]
     fn test_parse_match_any() {
         let query = r#"
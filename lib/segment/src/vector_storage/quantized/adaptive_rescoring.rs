@@ -0,0 +1,98 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::types::ScoredPointOffset;
+
+/// Number of sampled queries, required to trust the agreement rate
+const MIN_SAMPLES: usize = 32;
+
+/// Share of sampled queries, which orders must agree to skip rescoring
+const AGREEMENT_THRESHOLD: f64 = 0.95;
+
+/// Once rescoring is skipped, every n-th query is still rescored to keep the statistics fresh
+const SAMPLE_INTERVAL: usize = 16;
+
+/// Number of samples, after which the statistics are halved, so they follow changes of the data
+const SAMPLES_WINDOW: usize = 1024;
+
+/// Statistics of agreement between quantized and rescored orders of search results.
+///
+/// Used by the adaptive rescoring to skip reading original vectors, if quantized scores
+/// already give the same top results for the sampled queries.
+#[derive(Debug, Default)]
+pub struct AdaptiveRescoring {
+    queries: AtomicUsize,
+    sampled: AtomicUsize,
+    agreed: AtomicUsize,
+}
+
+impl AdaptiveRescoring {
+    /// Whether the current query should be rescored
+    pub fn should_rescore(&self) -> bool {
+        let query = self.queries.fetch_add(1, Ordering::Relaxed);
+        if query % SAMPLE_INTERVAL == 0 {
+            return true;
+        }
+
+        let sampled = self.sampled.load(Ordering::Relaxed);
+        if sampled < MIN_SAMPLES {
+            return true;
+        }
+
+        let agreed = self.agreed.load(Ordering::Relaxed);
+        (agreed as f64) < sampled as f64 * AGREEMENT_THRESHOLD
+    }
+
+    /// Record, whether top results of a rescored query are in the same order as quantized ones
+    pub fn record(&self, quantized: &[ScoredPointOffset], rescored: &[ScoredPointOffset]) {
+        let agreed = quantized.len() == rescored.len()
+            && quantized
+                .iter()
+                .zip(rescored)
+                .all(|(quantized, rescored)| quantized.idx == rescored.idx);
+
+        if agreed {
+            self.agreed.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let sampled = self.sampled.fetch_add(1, Ordering::Relaxed) + 1;
+        if sampled >= SAMPLES_WINDOW {
+            // Concurrent updates may be lost here, which only slightly skews the statistics
+            self.sampled.store(sampled / 2, Ordering::Relaxed);
+            let agreed = self.agreed.load(Ordering::Relaxed);
+            self.agreed.store(agreed / 2, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(ids: &[u32]) -> Vec<ScoredPointOffset> {
+        ids.iter()
+            .map(|&idx| ScoredPointOffset { idx, score: 0.0 })
+            .collect()
+    }
+
+    #[test]
+    fn test_adaptive_rescoring() {
+        let adaptive = AdaptiveRescoring::default();
+
+        for _ in 0..MIN_SAMPLES {
+            assert!(adaptive.should_rescore());
+            adaptive.record(&points(&[1, 2, 3]), &points(&[1, 2, 3]));
+        }
+
+        // Orders agree, only every n-th query is rescored
+        let rescored = (0..SAMPLE_INTERVAL * 4)
+            .filter(|_| adaptive.should_rescore())
+            .count();
+        assert_eq!(rescored, 4);
+
+        // Orders disagree, all queries are rescored again
+        for _ in 0..MIN_SAMPLES {
+            adaptive.record(&points(&[1, 2, 3]), &points(&[2, 1, 3]));
+        }
+        assert!((0..SAMPLE_INTERVAL).all(|_| adaptive.should_rescore()));
+    }
+}
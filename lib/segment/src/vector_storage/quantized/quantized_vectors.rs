@@ -12,6 +12,7 @@ use quantization::encoded_vectors_u8::ScalarQuantizationMethod;
 use quantization::{EncodedVectors, EncodedVectorsPQ, EncodedVectorsU8};
 use serde::{Deserialize, Serialize};
 
+use super::adaptive_rescoring::AdaptiveRescoring;
 use super::quantized_multivector_storage::{
     MultivectorOffset, MultivectorOffsetsStorageMmap, QuantizedMultivectorStorage,
 };
@@ -184,6 +185,8 @@ pub struct QuantizedVectors {
     path: PathBuf,
     distance: Distance,
     datatype: VectorStorageDatatype,
+    /// Statistics of rescoring, not persisted
+    adaptive_rescoring: AdaptiveRescoring,
 }
 
 impl QuantizedVectors {
@@ -191,6 +194,10 @@ impl QuantizedVectors {
         &self.config
     }
 
+    pub fn adaptive_rescoring(&self) -> &AdaptiveRescoring {
+        &self.adaptive_rescoring
+    }
+
     pub fn default_rescoring(&self) -> bool {
         match self.storage_impl {
             QuantizedVectorStorage::ScalarRam(_) => false,
@@ -724,6 +731,7 @@ impl QuantizedVectors {
             path: path.to_path_buf(),
             distance,
             datatype,
+            adaptive_rescoring: AdaptiveRescoring::default(),
         };
 
         atomic_save_json(&path.join(QUANTIZED_CONFIG_PATH), &quantized_vectors.config)?;
@@ -827,6 +835,7 @@ impl QuantizedVectors {
             path: path.to_path_buf(),
             distance,
             datatype,
+            adaptive_rescoring: AdaptiveRescoring::default(),
         };
 
         atomic_save_json(&path.join(QUANTIZED_CONFIG_PATH), &quantized_vectors.config)?;
@@ -914,6 +923,7 @@ impl QuantizedVectors {
             path: path.to_path_buf(),
             distance,
             datatype,
+            adaptive_rescoring: AdaptiveRescoring::default(),
         })
     }
 
@@ -144,6 +144,10 @@ fn test_batch_and_single_request_equivalency() {
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        varint_links: None,
+        m0: None,
+        ef_construct_upper: None,
+        neighbor_selection: None,
     };
 
     let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
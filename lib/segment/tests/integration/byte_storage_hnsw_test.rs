@@ -90,10 +90,12 @@ fn test_byte_storage_hnsw(
                 quantization_config: None,
                 multivector_config: None,
                 datatype: Some(storage_data_type),
+                skip_normalization: None,
             },
         )]),
         sparse_vector_data: Default::default(),
         payload_storage_type: Default::default(),
+        defragment_keys: None,
     };
 
     let int_key = "int";
@@ -175,6 +177,10 @@ fn test_byte_storage_hnsw(
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        varint_links: None,
+        m0: None,
+        ef_construct_upper: None,
+        neighbor_selection: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
@@ -117,6 +117,10 @@ fn hnsw_quantized_search_test(
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        varint_links: None,
+        m0: None,
+        ef_construct_upper: None,
+        neighbor_selection: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
@@ -185,6 +189,10 @@ fn hnsw_quantized_search_test(
     }
     check_rescoring(&query_vectors, &hnsw_index, None, ef, top);
     check_rescoring(&query_vectors, &hnsw_index, Some(&filter), ef, top);
+
+    // check that rescoring is limited
+    // results are rescored only if there are no more than `max_rescore` of them
+    check_max_rescore(&query_vectors, &hnsw_index, ef, top);
 }
 
 pub fn check_matches(
@@ -317,6 +325,47 @@ fn check_rescoring(
     }
 }
 
+fn check_max_rescore(query_vectors: &[QueryVector], hnsw_index: &HNSWIndex, ef: usize, top: usize) {
+    let search = |query: &QueryVector, max_rescore: usize| {
+        hnsw_index
+            .search(
+                &[query],
+                None,
+                top,
+                Some(&SearchParams {
+                    hnsw_ef: Some(ef),
+                    quantization: Some(QuantizationSearchParams {
+                        rescore: Some(true),
+                        oversampling: Some(4.0),
+                        max_rescore: Some(max_rescore),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+
+    for query in query_vectors {
+        let within_budget = search(query, top);
+        assert_eq!(within_budget.len(), top);
+        for result in &within_budget {
+            assert!(result.score < ScoreType::EPSILON);
+        }
+
+        let over_budget = search(query, top - 1);
+        assert_eq!(over_budget.len(), top);
+        assert!(
+            over_budget
+                .iter()
+                .any(|result| result.score.abs() > ScoreType::EPSILON)
+        );
+    }
+}
+
 #[test]
 fn hnsw_quantized_search_cosine_test() {
     hnsw_quantized_search_test(
@@ -424,6 +473,10 @@ fn test_build_hnsw_using_quantization() {
         on_disk: Some(false),
         payload_m: None,
         inline_storage: None,
+        varint_links: None,
+        m0: None,
+        ef_construct_upper: None,
+        neighbor_selection: None,
     });
 
     let mut builder =
@@ -154,6 +154,9 @@ impl TestSegments {
                         is_principal: None,
                         on_disk: None,
                         enable_hnsw: None,
+                        populate: None,
+                        histogram_buckets: None,
+                        histogram_max_bucket_size: None,
                     },
                 ))),
                 &hw_counter,
@@ -171,6 +174,9 @@ impl TestSegments {
                         is_principal: None,
                         on_disk: None,
                         enable_hnsw: None,
+                        populate: None,
+                        histogram_buckets: None,
+                        histogram_max_bucket_size: None,
                     },
                 ))),
                 &hw_counter,
@@ -269,10 +275,12 @@ impl TestSegments {
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    skip_normalization: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
             payload_storage_type: Default::default(),
+            defragment_keys: None,
         };
         assert_eq!(conf.is_appendable(), appendable);
         conf
@@ -303,8 +311,15 @@ impl TestSegments {
                     KeywordIndexParams {
                         r#type: KeywordIndexType::Keyword,
                         is_tenant: None,
+                        ascii_folding: None,
+                        unicode_normalization: None,
+                        lowercase: None,
                         on_disk: Some(true),
                         enable_hnsw: None,
+                        populate: None,
+                        bloom_filter: Some(true),
+                        tenant_subgraphs: None,
+                        tenant_subgraphs_max_points: None,
                     },
                 ))),
                 &hw_counter,
@@ -322,6 +337,9 @@ impl TestSegments {
                         is_principal: None,
                         on_disk: Some(true),
                         enable_hnsw: None,
+                        populate: None,
+                        histogram_buckets: None,
+                        histogram_max_bucket_size: None,
                     },
                 ))),
                 &hw_counter,
@@ -339,6 +357,9 @@ impl TestSegments {
                         is_principal: None,
                         on_disk: Some(true),
                         enable_hnsw: None,
+                        populate: None,
+                        histogram_buckets: None,
+                        histogram_max_bucket_size: None,
                     },
                 ))),
                 &hw_counter,
@@ -356,6 +377,9 @@ impl TestSegments {
                         is_principal: None,
                         on_disk: Some(true),
                         enable_hnsw: None,
+                        populate: None,
+                        histogram_buckets: None,
+                        histogram_max_bucket_size: None,
                     },
                 ))),
                 &hw_counter,
@@ -370,6 +394,9 @@ impl TestSegments {
                     is_principal: None,
                     on_disk: Some(true),
                     enable_hnsw: None,
+                    populate: None,
+                    histogram_buckets: None,
+                    histogram_max_bucket_size: None,
                 }))),
                 &hw_counter,
             )
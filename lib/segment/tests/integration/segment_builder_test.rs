@@ -179,6 +179,12 @@ fn test_building_new_defragmented_segment() {
     if let Err(err) = check_points_defragmented(&merged_segment, &defragment_key) {
         panic!("{err}");
     }
+
+    // Defragmentation key is persisted in the segment config
+    assert_eq!(
+        merged_segment.config().defragment_keys,
+        Some(vec![defragment_key]),
+    );
 }
 
 /// Iterates over the internal point ids of the merged segment and checks that the
@@ -320,10 +326,12 @@ fn estimate_build_time(segment: &Segment, stop_delay_millis: Option<u64>) -> (u6
                 quantization_config: None,
                 multivector_config: None,
                 datatype: None,
+                skip_normalization: None,
             },
         )]),
         sparse_vector_data: Default::default(),
         payload_storage_type: Default::default(),
+        defragment_keys: None,
     };
 
     let mut builder = SegmentBuilder::new(
@@ -80,8 +80,15 @@ fn test_on_disk_segment_snapshot(#[case] format: SnapshotFormat) {
                 PayloadSchemaParams::Keyword(KeywordIndexParams {
                     r#type: segment::data_types::index::KeywordIndexType::Keyword,
                     is_tenant: None,
+                    ascii_folding: None,
+                    unicode_normalization: None,
+                    lowercase: None,
                     on_disk: Some(true),
                     enable_hnsw: None,
+                    populate: None,
+                    bloom_filter: None,
+                    tenant_subgraphs: None,
+                    tenant_subgraphs_max_points: None,
                 }),
             )),
             &hw_counter,
@@ -99,6 +106,9 @@ fn test_on_disk_segment_snapshot(#[case] format: SnapshotFormat) {
                     is_principal: None,
                     on_disk: Some(true),
                     enable_hnsw: None,
+                    populate: None,
+                    histogram_buckets: None,
+                    histogram_max_bucket_size: None,
                 }),
             )),
             &hw_counter,
@@ -120,14 +130,20 @@ fn test_on_disk_segment_snapshot(#[case] format: SnapshotFormat) {
                     on_disk: Some(true), // mmap index
                     payload_m: None,
                     inline_storage: None,
+                    varint_links: None,
+                    m0: None,
+                    ef_construct_upper: None,
+                    neighbor_selection: None,
                 }),
                 quantization_config: None,
                 multivector_config: None,
                 datatype: None,
+                skip_normalization: None,
             },
         )]),
         sparse_vector_data: Default::default(),
         payload_storage_type: PayloadStorageType::Mmap,
+        defragment_keys: None,
     };
 
     let segment_base_dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
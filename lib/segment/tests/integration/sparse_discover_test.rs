@@ -134,6 +134,7 @@ fn sparse_index_discover_test() {
             },
         )]),
         payload_storage_type: Default::default(),
+        defragment_keys: None,
     };
     let dense_config = SegmentConfig {
         vector_data: HashMap::from([(
@@ -146,10 +147,12 @@ fn sparse_index_discover_test() {
                 quantization_config: None,
                 multivector_config: None,
                 datatype: None,
+                skip_normalization: None,
             },
         )]),
         payload_storage_type: Default::default(),
         sparse_vector_data: Default::default(),
+        defragment_keys: None,
     };
 
     let mut sparse_segment = build_segment(dir.path(), &sparse_config, true).unwrap();
@@ -275,6 +278,7 @@ fn sparse_index_hardware_measurement_test() {
             },
         )]),
         payload_storage_type: Default::default(),
+        defragment_keys: None,
     };
 
     let mut sparse_segment = build_segment(dir.path(), &sparse_config, true).unwrap();
@@ -597,6 +597,7 @@ fn sparse_vector_index_persistence_test() {
             },
         )]),
         payload_storage_type: Default::default(),
+        defragment_keys: None,
     };
     let mut segment = build_segment(dir.path(), &config, true).unwrap();
 
@@ -769,6 +770,7 @@ fn sparse_vector_test_large_index() {
             },
         )]),
         payload_storage_type: Default::default(),
+        defragment_keys: None,
     };
     let mut segment = build_segment(dir.path(), &config, true).unwrap();
 
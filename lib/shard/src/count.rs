@@ -13,7 +13,8 @@ pub struct CountRequestInternal {
     #[validate(nested)]
     pub filter: Option<Filter>,
     /// If true, count exact number of points. If false, count approximate number of points faster.
-    /// Approximate count might be unreliable during the indexing process. Default: true
+    /// Approximate count is estimated from payload indexes, without reading points, and comes
+    /// with its lower and upper bounds. It might be unreliable during the indexing process. Default: true
     #[serde(default = "CountRequestInternal::default_exact")]
     pub exact: bool,
 }
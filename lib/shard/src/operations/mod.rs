@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 use strum::{EnumDiscriminants, EnumIter};
 
 use crate::PeerId;
-use crate::operations::point_ops::PointOperations;
+use crate::operations::point_ops::{PointOperations, ReturnIds};
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, EnumDiscriminants, Hash)]
 #[strum_discriminants(derive(EnumIter))]
@@ -111,6 +111,11 @@ pub struct OperationWithClockTag {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub clock_tag: Option<ClockTag>,
+
+    /// Report ids of the points matching the filter of the operation, when it is applied.
+    /// Only relevant to the sender of the operation, so it is not persisted.
+    #[serde(skip)]
+    pub return_ids: Option<ReturnIds>,
 }
 
 impl OperationWithClockTag {
@@ -121,8 +126,14 @@ impl OperationWithClockTag {
         Self {
             operation: operation.into(),
             clock_tag,
+            return_ids: None,
         }
     }
+
+    pub fn with_return_ids(mut self, return_ids: Option<ReturnIds>) -> Self {
+        self.return_ids = return_ids;
+        self
+    }
 }
 
 impl From<CollectionUpdateOperations> for OperationWithClockTag {
@@ -333,6 +344,11 @@ mod tests {
                 update_filter: None,
             });
 
+            let append = Self::AppendVectors(UpdateVectorsOp {
+                points: Vec::new(),
+                update_filter: None,
+            });
+
             let delete = Self::DeleteVectors(
                 PointIdsList {
                     points: Vec::new(),
@@ -351,7 +367,13 @@ mod tests {
                 Vec::new(),
             );
 
-            prop_oneof![Just(update), Just(delete), Just(delete_by_filter),].boxed()
+            prop_oneof![
+                Just(update),
+                Just(append),
+                Just(delete),
+                Just(delete_by_filter),
+            ]
+            .boxed()
         }
     }
 
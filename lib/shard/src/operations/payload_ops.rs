@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use strum::{EnumDiscriminants, EnumIter};
 use validator::Validate;
 
+use super::point_ops::ReturnIds;
+
 /// Define operations description for point payloads manipulation
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, EnumDiscriminants, Hash)]
 #[strum_discriminants(derive(EnumIter))]
@@ -74,6 +76,10 @@ pub struct SetPayload {
     pub shard_key: Option<ShardKeySelector>,
     /// Assigns payload to each point that satisfy this path of property
     pub key: Option<JsonPath>,
+    /// Return ids of the points matching the filter, which payload is assigned to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub return_ids: Option<ReturnIds>,
 }
 
 /// This data structure is used inside shard operations queue
@@ -129,6 +135,7 @@ struct SetPayloadShadow {
     pub filter: Option<Filter>,
     pub shard_key: Option<ShardKeySelector>,
     pub key: Option<JsonPath>,
+    pub return_ids: Option<ReturnIds>,
 }
 
 impl TryFrom<SetPayloadShadow> for SetPayload {
@@ -141,6 +148,7 @@ impl TryFrom<SetPayloadShadow> for SetPayload {
             filter,
             shard_key,
             key,
+            return_ids,
         } = value;
 
         if points.is_some() || filter.is_some() {
@@ -150,6 +158,7 @@ impl TryFrom<SetPayloadShadow> for SetPayload {
                 filter,
                 shard_key,
                 key,
+                return_ids,
             })
         } else {
             Err(PointsSelectorValidationError)
@@ -19,6 +19,7 @@ use segment::data_types::vectors::{
     BatchVectorStructInternal, DEFAULT_VECTOR_NAME, MultiDenseVectorInternal, VectorInternal,
     VectorStructInternal,
 };
+use segment::json_path::JsonPath;
 use segment::types::{Filter, Payload, PointIdType, VectorNameBuf};
 use serde::{Deserialize, Serialize};
 use sparse::common::types::{DimId, DimWeight};
@@ -60,6 +61,22 @@ impl From<Vec<PointIdType>> for PointIdsList {
     }
 }
 
+/// Return ids of the points affected by an update, which selects points by filter.
+///
+/// Update is applied to all points matching the filter,
+/// ids of them are returned page by page.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema, Validate, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct ReturnIds {
+    /// Max number of ids to return.
+    /// Remaining ids are returned by repeating the request with `offset` of the next page.
+    #[validate(range(min = 1, max = 10000))]
+    pub limit: usize,
+    /// Id of the first point to return, `next_page_offset` of the previous page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<PointIdType>,
+}
+
 // General idea of having an extra layer of data structures after REST and gRPC
 // is to ensure that all vectors are inferenced and validated before they are persisted.
 //
@@ -259,6 +276,14 @@ pub struct ConditionalInsertOperationInternal {
     /// Mode of the upsert operation. If None, defaults to Upsert behavior.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_mode: Option<UpdateMode>,
+    /// Payload key with client-provided sequence number of each point.
+    /// If set, points are not written over stored points with the same or higher sequence number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence_key: Option<JsonPath>,
+    /// If true, dense vectors are stored as provided, without normalization.
+    /// The client is responsible for normalizing vectors of cosine distance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_normalization: Option<bool>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Hash)]
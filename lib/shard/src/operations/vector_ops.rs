@@ -10,6 +10,8 @@ use super::point_ops::{PointIdsList, VectorStructPersisted};
 pub enum VectorOperations {
     /// Update vectors
     UpdateVectors(UpdateVectorsOp),
+    /// Append sub-vectors to the existing multivectors of points
+    AppendVectors(UpdateVectorsOp),
     /// Delete vectors if exists
     DeleteVectors(PointIdsList, Vec<VectorNameBuf>),
     /// Delete vectors by given filter criteria
@@ -19,7 +21,9 @@ pub enum VectorOperations {
 impl VectorOperations {
     pub fn point_ids(&self) -> Option<Vec<PointIdType>> {
         match self {
-            Self::UpdateVectors(op) => Some(op.points.iter().map(|point| point.id).collect()),
+            Self::UpdateVectors(op) | Self::AppendVectors(op) => {
+                Some(op.points.iter().map(|point| point.id).collect())
+            }
             Self::DeleteVectors(points, _) => Some(points.points.clone()),
             Self::DeleteVectorsByFilter(_, _) => None,
         }
@@ -30,7 +34,9 @@ impl VectorOperations {
         F: Fn(&PointIdType) -> bool,
     {
         match self {
-            Self::UpdateVectors(op) => op.points.retain(|point| filter(&point.id)),
+            Self::UpdateVectors(op) | Self::AppendVectors(op) => {
+                op.points.retain(|point| filter(&point.id))
+            }
             Self::DeleteVectors(points, _) => points.points.retain(filter),
             Self::DeleteVectorsByFilter(_, _) => (),
         }
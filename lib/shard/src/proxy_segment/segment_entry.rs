@@ -1,5 +1,6 @@
 use std::cmp;
 use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::Write;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
@@ -10,13 +11,18 @@ use segment::common::Flusher;
 use segment::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
 use segment::data_types::build_index_result::BuildFieldIndexResult;
 use segment::data_types::facets::{FacetParams, FacetValue};
+use segment::data_types::filter_explanation::SegmentFilterExplanation;
 use segment::data_types::named_vectors::NamedVectors;
 use segment::data_types::order_by::OrderValue;
+use segment::data_types::quantiles::QuantileSketch;
 use segment::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use segment::data_types::segment_record::SegmentRecord;
+use segment::data_types::term_stats::TermStatsAggregator;
 use segment::data_types::vectors::{QueryVector, VectorInternal};
 use segment::entry::entry_point::{NonAppendableSegmentEntry, SegmentEntry};
+use segment::index::field_index::full_text_index::bm25::Bm25CorpusStats;
 use segment::index::field_index::{CardinalityEstimation, FieldIndex};
+use segment::index::query_optimization::rescore_formula::parsed_formula::TextScoreQuery;
 use segment::json_path::JsonPath;
 use segment::telemetry::SegmentTelemetry;
 use segment::types::*;
@@ -377,6 +383,45 @@ impl NonAppendableSegmentEntry for ProxySegment {
         Ok(hits)
     }
 
+    fn quantile_sketch(
+        &self,
+        key: &JsonPath,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<QuantileSketch> {
+        if self.deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .quantile_sketch(key, filter, is_stopped, hw_counter)
+        } else {
+            let wrapped_filter = Self::add_deleted_points_condition_to_filter(
+                filter,
+                self.deleted_points.keys().copied(),
+            );
+            self.wrapped_segment.get().read().quantile_sketch(
+                key,
+                Some(&wrapped_filter),
+                is_stopped,
+                hw_counter,
+            )
+        }
+    }
+
+    fn term_stats(
+        &self,
+        key: &JsonPath,
+        is_stopped: &AtomicBool,
+    ) -> OperationResult<TermStatsAggregator> {
+        // Term statistics are read from the index directly, points deleted in the proxy
+        // are counted until the optimization finishes
+        self.wrapped_segment
+            .get()
+            .read()
+            .term_stats(key, is_stopped)
+    }
+
     fn has_point(&self, point_id: PointIdType) -> bool {
         !self.deleted_points.contains_key(&point_id)
             && self.wrapped_segment.get().read().has_point(point_id)
@@ -455,6 +500,40 @@ impl NonAppendableSegmentEntry for ProxySegment {
         }
     }
 
+    fn explain_filter(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> SegmentFilterExplanation {
+        if self.deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .explain_filter(filter, hw_counter)
+        } else {
+            let wrapped_filter = Self::add_deleted_points_condition_to_filter(
+                Some(filter),
+                self.deleted_points.keys().copied(),
+            );
+            self.wrapped_segment
+                .get()
+                .read()
+                .explain_filter(&wrapped_filter, hw_counter)
+        }
+    }
+
+    fn export_hnsw_graph(
+        &self,
+        vector_name: &VectorName,
+        writer: &mut dyn Write,
+    ) -> OperationResult<()> {
+        // Graph is not changed by the proxy, deleted points are still linked in it
+        self.wrapped_segment
+            .get()
+            .read()
+            .export_hnsw_graph(vector_name, writer)
+    }
+
     fn segment_uuid(&self) -> Uuid {
         self.wrapped_segment.get().read().segment_uuid()
     }
@@ -701,6 +780,19 @@ impl NonAppendableSegmentEntry for ProxySegment {
             .read()
             .fill_query_context(query_context)
     }
+
+    fn fill_text_score_stats(
+        &self,
+        text_scores: &[TextScoreQuery],
+        stats: &mut [Bm25CorpusStats],
+        hw_counter: &HardwareCounterCell,
+    ) {
+        // Same as for query context, the temporary segment is not too important for statistics
+        self.wrapped_segment
+            .get()
+            .read()
+            .fill_text_score_stats(text_scores, stats, hw_counter)
+    }
 }
 
 impl SegmentEntry for ProxySegment {
@@ -27,8 +27,15 @@ impl TryFrom<FormulaInternal> for ParsedFormula {
 
         let mut payload_vars = HashSet::new();
         let mut conditions = Vec::new();
+        let mut text_scores = Vec::new();
+        let mut wasm_functions = Vec::new();
 
-        let parsed_expression = formula.parse_and_convert(&mut payload_vars, &mut conditions)?;
+        let parsed_expression = formula.parse_and_convert(
+            &mut payload_vars,
+            &mut conditions,
+            &mut text_scores,
+            &mut wasm_functions,
+        )?;
 
         let defaults = defaults
             .into_iter()
@@ -45,6 +52,8 @@ impl TryFrom<FormulaInternal> for ParsedFormula {
             formula: parsed_expression,
             payload_vars,
             conditions,
+            text_scores,
+            wasm_functions,
             defaults,
         })
     }
@@ -61,6 +70,16 @@ pub enum ExpressionInternal {
     },
     Datetime(String),
     DatetimeKey(JsonPath),
+    TextScore {
+        key: JsonPath,
+        text: String,
+        boosts: Vec<(String, f32)>,
+    },
+    Wasm {
+        module: Vec<u8>,
+        args: Vec<ExpressionInternal>,
+        fuel_limit: Option<u64>,
+    },
     Mult(Vec<ExpressionInternal>),
     Sum(Vec<ExpressionInternal>),
     Neg(Box<ExpressionInternal>),
@@ -92,6 +111,8 @@ impl ExpressionInternal {
         self,
         payload_vars: &mut HashSet<JsonPath>,
         conditions: &mut Vec<Condition>,
+        text_scores: &mut Vec<TextScoreQuery>,
+        wasm_functions: &mut Vec<WasmFunction>,
     ) -> OperationResult<ParsedExpression> {
         let expr = match self {
             ExpressionInternal::Constant(c) => {
@@ -126,49 +147,131 @@ impl ExpressionInternal {
                 payload_vars.insert(json_path.clone());
                 ParsedExpression::Datetime(DatetimeExpression::PayloadVariable(json_path))
             }
+            ExpressionInternal::TextScore { key, text, boosts } => {
+                let text_score_id = text_scores.len();
+                text_scores.push(TextScoreQuery::new(key, text, boosts)?);
+                ParsedExpression::TextScore(text_score_id)
+            }
+            ExpressionInternal::Wasm {
+                module,
+                args,
+                fuel_limit,
+            } => {
+                let function = WasmFunction::new(module, args.len(), fuel_limit)?;
+                let function_id = wasm_functions.len();
+                wasm_functions.push(function);
+                ParsedExpression::Wasm {
+                    function: function_id,
+                    args: args
+                        .into_iter()
+                        .map(|arg| {
+                            arg.parse_and_convert(
+                                payload_vars,
+                                conditions,
+                                text_scores,
+                                wasm_functions,
+                            )
+                        })
+                        .try_collect()?,
+                }
+            }
             ExpressionInternal::Mult(internal_expressions) => ParsedExpression::Mult(
                 internal_expressions
                     .into_iter()
-                    .map(|expr| expr.parse_and_convert(payload_vars, conditions))
+                    .map(|expr| {
+                        expr.parse_and_convert(
+                            payload_vars,
+                            conditions,
+                            text_scores,
+                            wasm_functions,
+                        )
+                    })
                     .try_collect()?,
             ),
             ExpressionInternal::Sum(expression_internals) => ParsedExpression::Sum(
                 expression_internals
                     .into_iter()
-                    .map(|expr| expr.parse_and_convert(payload_vars, conditions))
+                    .map(|expr| {
+                        expr.parse_and_convert(
+                            payload_vars,
+                            conditions,
+                            text_scores,
+                            wasm_functions,
+                        )
+                    })
                     .try_collect()?,
             ),
-            ExpressionInternal::Neg(expression_internal) => ParsedExpression::new_neg(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
-            ),
+            ExpressionInternal::Neg(expression_internal) => {
+                ParsedExpression::new_neg(expression_internal.parse_and_convert(
+                    payload_vars,
+                    conditions,
+                    text_scores,
+                    wasm_functions,
+                )?)
+            }
             ExpressionInternal::Div {
                 left,
                 right,
                 by_zero_default,
             } => ParsedExpression::new_div(
-                left.parse_and_convert(payload_vars, conditions)?,
-                right.parse_and_convert(payload_vars, conditions)?,
+                left.parse_and_convert(payload_vars, conditions, text_scores, wasm_functions)?,
+                right.parse_and_convert(payload_vars, conditions, text_scores, wasm_functions)?,
                 by_zero_default.map(PreciseScore::from),
             ),
-            ExpressionInternal::Sqrt(expression_internal) => ParsedExpression::Sqrt(Box::new(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
-            )),
+            ExpressionInternal::Sqrt(expression_internal) => {
+                ParsedExpression::Sqrt(Box::new(expression_internal.parse_and_convert(
+                    payload_vars,
+                    conditions,
+                    text_scores,
+                    wasm_functions,
+                )?))
+            }
             ExpressionInternal::Pow { base, exponent } => ParsedExpression::Pow {
-                base: Box::new(base.parse_and_convert(payload_vars, conditions)?),
-                exponent: Box::new(exponent.parse_and_convert(payload_vars, conditions)?),
+                base: Box::new(base.parse_and_convert(
+                    payload_vars,
+                    conditions,
+                    text_scores,
+                    wasm_functions,
+                )?),
+                exponent: Box::new(exponent.parse_and_convert(
+                    payload_vars,
+                    conditions,
+                    text_scores,
+                    wasm_functions,
+                )?),
             },
-            ExpressionInternal::Exp(expression_internal) => ParsedExpression::Exp(Box::new(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
-            )),
-            ExpressionInternal::Log10(expression_internal) => ParsedExpression::Log10(Box::new(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
-            )),
-            ExpressionInternal::Ln(expression_internal) => ParsedExpression::Ln(Box::new(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
-            )),
-            ExpressionInternal::Abs(expression_internal) => ParsedExpression::Abs(Box::new(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
-            )),
+            ExpressionInternal::Exp(expression_internal) => {
+                ParsedExpression::Exp(Box::new(expression_internal.parse_and_convert(
+                    payload_vars,
+                    conditions,
+                    text_scores,
+                    wasm_functions,
+                )?))
+            }
+            ExpressionInternal::Log10(expression_internal) => {
+                ParsedExpression::Log10(Box::new(expression_internal.parse_and_convert(
+                    payload_vars,
+                    conditions,
+                    text_scores,
+                    wasm_functions,
+                )?))
+            }
+            ExpressionInternal::Ln(expression_internal) => {
+                ParsedExpression::Ln(Box::new(expression_internal.parse_and_convert(
+                    payload_vars,
+                    conditions,
+                    text_scores,
+                    wasm_functions,
+                )?))
+            }
+            ExpressionInternal::Abs(expression_internal) => {
+                ParsedExpression::Abs(Box::new(expression_internal.parse_and_convert(
+                    payload_vars,
+                    conditions,
+                    text_scores,
+                    wasm_functions,
+                )?))
+            }
             ExpressionInternal::Decay {
                 kind,
                 x,
@@ -178,10 +281,13 @@ impl ExpressionInternal {
             } => {
                 let lambda = ParsedExpression::decay_params_to_lambda(midpoint, scale, kind)?;
 
-                let x = x.parse_and_convert(payload_vars, conditions)?;
+                let x =
+                    x.parse_and_convert(payload_vars, conditions, text_scores, wasm_functions)?;
 
                 let target = target
-                    .map(|t| t.parse_and_convert(payload_vars, conditions))
+                    .map(|t| {
+                        t.parse_and_convert(payload_vars, conditions, text_scores, wasm_functions)
+                    })
                     .transpose()?
                     .map(Box::new);
 
@@ -256,6 +362,28 @@ impl From<rest::Expression> for ExpressionInternal {
             rest::Expression::DatetimeKey(rest::DatetimeKeyExpression { datetime_key }) => {
                 ExpressionInternal::DatetimeKey(datetime_key)
             }
+            rest::Expression::TextScore(rest::TextScoreExpression {
+                text_score: rest::TextScoreParams { key, text, boosts },
+            }) => ExpressionInternal::TextScore {
+                key,
+                text,
+                boosts: boosts
+                    .into_iter()
+                    .map(|rest::TermBoost { term, boost }| (term, boost))
+                    .collect(),
+            },
+            rest::Expression::Wasm(rest::WasmExpression {
+                wasm:
+                    rest::WasmParams {
+                        module,
+                        args,
+                        fuel_limit,
+                    },
+            }) => ExpressionInternal::Wasm {
+                module,
+                args: args.into_iter().map(ExpressionInternal::from).collect(),
+                fuel_limit,
+            },
             rest::Expression::Mult(rest::MultExpression { mult: exprs }) => {
                 ExpressionInternal::Mult(exprs.into_iter().map(ExpressionInternal::from).collect())
             }
@@ -383,6 +511,31 @@ impl TryFrom<grpc::Expression> for ExpressionInternal {
                     .map_err(|_| tonic::Status::invalid_argument("invalid payload key"))?;
                 ExpressionInternal::DatetimeKey(json_path)
             }
+            Variant::TextScore(grpc::TextScore { key, text, boosts }) => {
+                let key = key
+                    .parse()
+                    .map_err(|_| tonic::Status::invalid_argument("invalid payload key"))?;
+                let boosts = boosts
+                    .into_iter()
+                    .map(|grpc::TermBoost { term, boost }| (term, boost))
+                    .collect();
+                ExpressionInternal::TextScore { key, text, boosts }
+            }
+            Variant::Wasm(grpc::WasmExpression {
+                module,
+                args,
+                fuel_limit,
+            }) => {
+                let args = args
+                    .into_iter()
+                    .map(ExpressionInternal::try_from)
+                    .try_collect()?;
+                ExpressionInternal::Wasm {
+                    module,
+                    args,
+                    fuel_limit,
+                }
+            }
             Variant::Mult(grpc::MultExpression { mult }) => {
                 let mult = mult
                     .into_iter()
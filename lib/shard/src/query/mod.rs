@@ -4,6 +4,7 @@ pub mod planned_query;
 pub mod query_enum;
 pub mod scroll;
 mod validation;
+pub mod vector_selection;
 
 pub mod query_context;
 #[cfg(test)]
@@ -24,6 +25,7 @@ use serde::Serialize;
 
 use self::formula::*;
 use self::query_enum::*;
+use self::vector_selection::VectorSelectionInternal;
 use crate::search::CoreSearchRequest;
 
 /// Internal response type for a universal query request.
@@ -36,7 +38,7 @@ pub type ShardQueryResponse = Vec<Vec<ScoredPoint>>;
 /// Direct translation of the user-facing request, but with all point ids substituted with their corresponding vectors.
 ///
 /// For the case of formula queries, it collects conditions and variables too.
-#[derive(Clone, Debug, Hash, Serialize)]
+#[derive(Clone, Debug, PartialEq, Hash, Serialize)]
 pub struct ShardQueryRequest {
     pub prefetches: Vec<ShardPrefetch>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -75,7 +77,7 @@ impl ShardQueryRequest {
     }
 }
 
-#[derive(Clone, Debug, Hash, Serialize)]
+#[derive(Clone, Debug, PartialEq, Hash, Serialize)]
 pub struct ShardPrefetch {
     pub prefetches: Vec<ShardPrefetch>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -138,6 +140,12 @@ pub enum ScoringQuery {
     ///   1. Performs search all the way down to segments.
     ///   2. MMR gets calculated once results reach collection level.
     Mmr(MmrInternal),
+
+    /// Score each point with the vector selected by a value of its payload field
+    ///
+    /// It is planned as one filtered search per selectable vector,
+    /// results of these searches are merged at shard level.
+    VectorSelection(VectorSelectionInternal),
 }
 
 impl ScoringQuery {
@@ -154,7 +162,11 @@ impl ScoringQuery {
             },
             // MMR is a nearest neighbors search before computing diversity at collection level
             Self::Mmr(_) => false,
-            Self::Vector(_) | Self::OrderBy(_) | Self::Formula(_) | Self::Sample(_) => false,
+            Self::Vector(_)
+            | Self::OrderBy(_)
+            | Self::Formula(_)
+            | Self::Sample(_)
+            | Self::VectorSelection(_) => false,
         }
     }
 
@@ -567,6 +579,9 @@ impl ScoringQuery {
                     tonic::Status::invalid_argument(format!("failed to parse formula: {e}"))
                 })?,
             ),
+            grpc::query_shard_points::query::Score::VectorSelection(selection) => {
+                ScoringQuery::VectorSelection(VectorSelectionInternal::try_from(selection)?)
+            }
             grpc::query_shard_points::query::Score::Mmr(grpc::MmrInternal {
                 vector,
                 lambda,
@@ -618,6 +633,11 @@ impl From<ScoringQuery> for grpc::query_shard_points::Query {
                     candidates_limit: candidates_limit as u32,
                 })),
             },
+            ScoringQuery::VectorSelection(selection) => Self {
+                score: Some(Score::VectorSelection(grpc::VectorSelectionInternal::from(
+                    selection,
+                ))),
+            },
         }
     }
 }
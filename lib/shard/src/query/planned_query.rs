@@ -6,6 +6,7 @@ use segment::types::{Filter, SearchParams, WithPayloadInterface, WithVector};
 
 use super::query_enum::QueryEnum;
 use super::scroll::{QueryScrollRequestInternal, ScrollOrder};
+use super::vector_selection::ScoringPass;
 use super::*;
 use crate::search::CoreSearchRequest;
 
@@ -137,7 +138,8 @@ impl PlannedQuery {
             | Some(ScoringQuery::Fusion(_))
             | Some(ScoringQuery::OrderBy(_))
             | Some(ScoringQuery::Formula(_))
-            | Some(ScoringQuery::Sample(_)) => with_vector,
+            | Some(ScoringQuery::Sample(_))
+            | Some(ScoringQuery::VectorSelection(_)) => with_vector,
             Some(ScoringQuery::Mmr(mmr)) => with_vector.merge(&WithVector::from(mmr.using.clone())),
         };
 
@@ -187,6 +189,7 @@ impl PlannedQuery {
             Some(ScoringQuery::OrderBy(_)) => None,
             Some(ScoringQuery::Formula(_)) => None,
             Some(ScoringQuery::Sample(_)) => None,
+            Some(ScoringQuery::VectorSelection(_)) => None, // Passes are merged within the source
             Some(ScoringQuery::Mmr(_)) => Some(RescoreStages::collection_level(RescoreParams {
                 rescore: query.clone().unwrap(),
                 limit,
@@ -284,6 +287,7 @@ impl PlannedQuery {
                     params,
                 }))
             }
+            ScoringQuery::VectorSelection(_) => return Err(vector_selection_over_prefetches()),
         };
 
         let merge_plan = MergePlan::new(sources, rescore_stages)?;
@@ -343,6 +347,10 @@ fn recurse_prefetches(
                 )
             })?;
 
+            if matches!(rescore, ScoringQuery::VectorSelection(_)) {
+                return Err(vector_selection_over_prefetches());
+            }
+
             // Even if this is a fusion request, it can only be executed at shard level here,
             // because we can't forward the inner results to the collection level without
             // materializing them first.
@@ -363,10 +371,16 @@ fn recurse_prefetches(
     Ok(sources)
 }
 
+/// Vector selection runs its own searches, so it can't score prefetched points
+fn vector_selection_over_prefetches() -> OperationError {
+    OperationError::validation_error("cannot apply VectorSelection over prefetches".to_string())
+}
+
 /// Crafts a "leaf source" from a scoring query. This means that the scoring query
 /// does not act over prefetched points and will be executed over the segments directly.
 ///
-/// Only `Source::SearchesIdx` or `Source::ScrollsIdx` variants are returned.
+/// Only `Source::SearchesIdx` or `Source::ScrollsIdx` variants are returned,
+/// except for vector selection, which is a `Source::Prefetch` merging its scoring passes.
 fn leaf_source_from_scoring_query(
     core_searches: &mut Vec<CoreSearchRequest>,
     scrolls: &mut Vec<QueryScrollRequestInternal>,
@@ -456,6 +470,40 @@ fn leaf_source_from_scoring_query(
 
             Source::SearchesIdx(idx)
         }
+        Some(ScoringQuery::VectorSelection(selection)) => {
+            // One search per selected vector, restricted to the points which select it
+            let passes = selection
+                .plan(filter.as_ref())?
+                .into_iter()
+                .map(|ScoringPass { query, filter }| {
+                    let core_search = CoreSearchRequest {
+                        query,
+                        filter: Some(filter),
+                        params,
+                        limit,
+                        offset: 0,
+                        with_vector: Some(WithVector::from(false)),
+                        with_payload: Some(WithPayloadInterface::from(false)),
+                        score_threshold,
+                    };
+
+                    let idx = core_searches.len();
+                    core_searches.push(core_search);
+
+                    Source::SearchesIdx(idx)
+                })
+                .collect();
+
+            // Merge the passes at shard level, keeping the best score of each point
+            let rescore_stages = RescoreStages::shard_level(RescoreParams {
+                rescore: ScoringQuery::VectorSelection(selection),
+                limit,
+                score_threshold: score_threshold.map(OrderedFloat),
+                params,
+            });
+
+            Source::Prefetch(Box::new(MergePlan::new(passes, Some(rescore_stages))?))
+        }
         None => {
             let scroll = QueryScrollRequestInternal {
                 scroll_order: Default::default(),
@@ -5,6 +5,8 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::iterator_ext::IteratorExt;
 use segment::common::operation_error::{OperationError, OperationResult};
 use segment::data_types::query_context::QueryContext;
+use segment::index::field_index::full_text_index::bm25::Bm25CorpusStats;
+use segment::index::query_optimization::rescore_formula::parsed_formula::TextScoreQuery;
 use segment::types::VectorName;
 
 use crate::common::stopping_guard::StoppingGuard;
@@ -66,3 +68,40 @@ pub fn fill_query_context(
     }
     Ok(Some(query_context))
 }
+
+/// Collect corpus statistics of each text score of a formula over all segments,
+/// so that BM25 scores are comparable between segments.
+pub fn collect_text_score_stats(
+    text_scores: &[TextScoreQuery],
+    segments: LockedSegmentHolder,
+    timeout: Duration,
+    is_stopped: &AtomicBool,
+    hw_measurement_acc: &HwMeasurementAcc,
+) -> OperationResult<Vec<Bm25CorpusStats>> {
+    let mut stats = vec![Bm25CorpusStats::default(); text_scores.len()];
+    if text_scores.is_empty() {
+        return Ok(stats);
+    }
+
+    let start = std::time::Instant::now();
+    let hw_counter = hw_measurement_acc.get_counter_cell();
+
+    let segments: Vec<_> = {
+        let Some(holder_guard) = segments.try_read_for(timeout) else {
+            return Err(OperationError::timeout(timeout, "collect text score stats"));
+        };
+        holder_guard
+            .non_appendable_then_appendable_segments()
+            .collect()
+    };
+
+    for locked_segment in segments.into_iter().stop_if(is_stopped) {
+        let segment = locked_segment.get();
+        let timeout = timeout.saturating_sub(start.elapsed());
+        let Some(segment_guard) = segment.try_read_for(timeout) else {
+            return Err(OperationError::timeout(timeout, "collect text score stats"));
+        };
+        segment_guard.fill_text_score_stats(text_scores, &mut stats, &hw_counter);
+    }
+    Ok(stats)
+}
@@ -1,4 +1,5 @@
 use api::grpc;
+use segment::common::operation_error::OperationResult;
 use segment::data_types::vectors::*;
 use segment::types::{VectorName, VectorNameBuf};
 use segment::vector_storage::query::*;
@@ -86,6 +87,50 @@ impl QueryEnum {
         }
     }
 
+    /// Handle NaN and infinite values of all query vectors according to the policy
+    pub fn apply_non_finite_policy(self, policy: NonFiniteVectorPolicy) -> OperationResult<Self> {
+        let vector_name = self.get_vector_name().to_owned();
+        let apply = |mut vector: VectorInternal| -> OperationResult<VectorInternal> {
+            vector.apply_non_finite_policy(&vector_name, policy)?;
+            Ok(vector)
+        };
+
+        let query = match self {
+            QueryEnum::Nearest(NamedQuery { query, using }) => QueryEnum::Nearest(NamedQuery {
+                query: apply(query)?,
+                using,
+            }),
+            QueryEnum::RecommendBestScore(NamedQuery { query, using }) => {
+                QueryEnum::RecommendBestScore(NamedQuery {
+                    query: query.transform(apply)?,
+                    using,
+                })
+            }
+            QueryEnum::RecommendSumScores(NamedQuery { query, using }) => {
+                QueryEnum::RecommendSumScores(NamedQuery {
+                    query: query.transform(apply)?,
+                    using,
+                })
+            }
+            QueryEnum::Discover(NamedQuery { query, using }) => QueryEnum::Discover(NamedQuery {
+                query: query.transform(apply)?,
+                using,
+            }),
+            QueryEnum::Context(NamedQuery { query, using }) => QueryEnum::Context(NamedQuery {
+                query: query.transform(apply)?,
+                using,
+            }),
+            QueryEnum::FeedbackNaive(NamedQuery { query, using }) => {
+                QueryEnum::FeedbackNaive(NamedQuery {
+                    query: query.transform(apply)?,
+                    using,
+                })
+            }
+        };
+
+        Ok(query)
+    }
+
     /// Returns the estimated cost of using this query in terms of number of vectors.
     /// The cost approximates how many similarity comparisons this query will make against one point.
     pub fn search_cost(&self) -> usize {
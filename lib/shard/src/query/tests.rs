@@ -306,6 +306,100 @@ fn test_try_from_rrf_without_source() {
     assert!(planned_query.is_err())
 }
 
+fn vector_selection() -> vector_selection::VectorSelectionInternal {
+    let query = |using: &str| NamedQuery::new(VectorInternal::Dense(vec![1.0, 0.0]), using);
+    vector_selection::VectorSelectionInternal {
+        key: JsonPath::new("lang"),
+        mapping: vec![
+            (
+                ValueVariants::String("en".to_string()),
+                "english".to_string(),
+            ),
+            (
+                ValueVariants::String("de".to_string()),
+                "german".to_string(),
+            ),
+        ],
+        default: None,
+        queries: vec![query("english"), query("german")],
+    }
+}
+
+#[test]
+fn test_try_from_vector_selection() {
+    let selection = vector_selection();
+    let request = ShardQueryRequest {
+        prefetches: vec![],
+        query: Some(ScoringQuery::VectorSelection(selection.clone())),
+        filter: None,
+        score_threshold: None,
+        limit: 10,
+        offset: 5,
+        params: None,
+        with_vector: WithVector::Bool(false),
+        with_payload: WithPayloadInterface::Bool(true),
+    };
+
+    let planned_query = PlannedQuery::try_from(vec![request]).unwrap();
+
+    // One search per selected vector
+    let searched_vectors = planned_query
+        .searches
+        .iter()
+        .map(|search| (search.query.get_vector_name(), search.limit))
+        .collect::<Vec<_>>();
+    assert_eq!(searched_vectors, vec![("english", 15), ("german", 15)]);
+
+    assert_eq!(
+        planned_query.root_plans,
+        vec![RootPlan {
+            with_payload: WithPayloadInterface::Bool(true),
+            with_vector: WithVector::Bool(false),
+            merge_plan: MergePlan {
+                sources: vec![Source::Prefetch(Box::new(MergePlan {
+                    sources: vec![Source::SearchesIdx(0), Source::SearchesIdx(1)],
+                    rescore_stages: Some(RescoreStages::shard_level(RescoreParams {
+                        rescore: ScoringQuery::VectorSelection(selection),
+                        limit: 15,
+                        score_threshold: None,
+                        params: None,
+                    })),
+                }))],
+                rescore_stages: None,
+            },
+        }]
+    );
+}
+
+#[test]
+fn test_try_from_vector_selection_over_prefetches() {
+    let request = ShardQueryRequest {
+        prefetches: vec![ShardPrefetch {
+            prefetches: vec![],
+            query: None,
+            limit: 100,
+            params: None,
+            filter: None,
+            score_threshold: None,
+        }],
+        query: Some(ScoringQuery::VectorSelection(vector_selection())),
+        filter: None,
+        score_threshold: None,
+        limit: 10,
+        offset: 0,
+        params: None,
+        with_vector: WithVector::Bool(false),
+        with_payload: WithPayloadInterface::Bool(false),
+    };
+
+    let planned_query = PlannedQuery::try_from(vec![request]);
+
+    assert!(matches!(
+        planned_query,
+        Err(OperationError::ValidationError { .. }),
+    ));
+}
+
 #[test]
 fn test_base_params_mapping_in_try_from() {
     let dummy_vector = vec![1.0, 2.0, 3.0];
@@ -0,0 +1,399 @@
+use ahash::AHashMap;
+use api::grpc;
+use itertools::Itertools;
+use segment::common::operation_error::{OperationError, OperationResult};
+use segment::data_types::vectors::{NamedQuery, VectorInternal};
+use segment::json_path::JsonPath;
+use segment::types::{
+    Condition, Distance, FieldCondition, Filter, Order, ScoredPoint, ValueVariants, VectorName,
+    VectorNameBuf,
+};
+use serde::Serialize;
+
+use super::query_enum::QueryEnum;
+
+/// Selection of the named vector used for scoring a point, based on a value of its payload field.
+///
+/// Useful when points keep embeddings from different spaces under different named vectors,
+/// e.g. one vector per language, and the payload tells which one is meaningful for the point.
+///
+/// Values selecting the same vector are grouped, so that each vector is scored in a single
+/// filtered search, see [`VectorSelectionInternal::plan`].
+#[derive(Clone, Debug, PartialEq, Hash, Serialize)]
+pub struct VectorSelectionInternal {
+    /// Payload field which decides the vector to score a point with
+    pub key: JsonPath,
+    /// Payload value -> name of the vector to score points having this value
+    pub mapping: Vec<(ValueVariants, VectorNameBuf)>,
+    /// Vector to score points which don't have any of the mapped values.
+    /// If not set, such points are excluded from the results.
+    pub default: Option<VectorNameBuf>,
+    /// Query to score each of the selectable vectors with
+    pub queries: Vec<NamedQuery<VectorInternal>>,
+}
+
+/// Single search over one named vector, restricted to the points which select this vector
+#[derive(Debug, PartialEq)]
+pub struct ScoringPass {
+    pub query: QueryEnum,
+    pub filter: Filter,
+}
+
+impl VectorSelectionInternal {
+    /// Names of all vectors which can be selected, in order of the first appearance
+    pub fn selectable_vectors(&self) -> impl Iterator<Item = &VectorName> {
+        self.mapping
+            .iter()
+            .map(|(_, vector_name)| vector_name.as_str())
+            .chain(self.default.as_deref())
+            .unique()
+    }
+
+    /// Order of the merged scores, given the distances of the [selectable vectors](Self::selectable_vectors).
+    ///
+    /// Scores of all passes are merged into a single list,
+    /// so all selectable vectors must order their scores the same way.
+    pub fn order(&self, distances: impl IntoIterator<Item = Distance>) -> OperationResult<Order> {
+        let orders = distances
+            .into_iter()
+            .map(|distance| distance.distance_order())
+            .dedup()
+            .collect_vec();
+
+        match orders.as_slice() {
+            [] => Ok(Order::LargeBetter),
+            [order] => Ok(*order),
+            _ => Err(OperationError::validation_error(format!(
+                "Vectors selected by field `{}` must all use distances with the same order of scores",
+                self.key,
+            ))),
+        }
+    }
+
+    fn value_condition(&self, value: &ValueVariants) -> Condition {
+        Condition::Field(FieldCondition::new_match(
+            self.key.clone(),
+            value.clone().into(),
+        ))
+    }
+
+    fn query_for(&self, vector_name: &VectorName) -> OperationResult<QueryEnum> {
+        self.queries
+            .iter()
+            .find(|query| query.using.as_deref() == Some(vector_name))
+            .map(|query| QueryEnum::Nearest(query.clone()))
+            .ok_or_else(|| {
+                OperationError::validation_error(format!(
+                    "No query provided for vector `{vector_name}` selected by field `{}`",
+                    self.key,
+                ))
+            })
+    }
+
+    /// Group mapped values by the vector they select, so each vector is scored in a single pass.
+    ///
+    /// Each pass is restricted by the original `filter` and by the values selecting its vector.
+    pub fn plan(&self, filter: Option<&Filter>) -> OperationResult<Vec<ScoringPass>> {
+        let mut values_by_vector: AHashMap<&VectorName, Vec<&ValueVariants>> = AHashMap::new();
+        for (value, vector_name) in &self.mapping {
+            values_by_vector
+                .entry(vector_name.as_str())
+                .or_default()
+                .push(value);
+        }
+
+        // Keep passes in order of the first appearance in the mapping, for deterministic results
+        let vector_names = self
+            .mapping
+            .iter()
+            .map(|(_, vector_name)| vector_name.as_str())
+            .unique();
+
+        let mut passes = Vec::with_capacity(values_by_vector.len() + 1);
+
+        for vector_name in vector_names {
+            let selecting = Filter {
+                should: Some(
+                    values_by_vector[vector_name]
+                        .iter()
+                        .map(|value| self.value_condition(value))
+                        .collect(),
+                ),
+                min_should: None,
+                must: None,
+                must_not: None,
+            };
+
+            passes.push(ScoringPass {
+                query: self.query_for(vector_name)?,
+                filter: restrict(filter, selecting),
+            });
+        }
+
+        if let Some(vector_name) = &self.default {
+            let not_selecting = Filter {
+                should: None,
+                min_should: None,
+                must: None,
+                must_not: Some(
+                    self.mapping
+                        .iter()
+                        .map(|(value, _)| self.value_condition(value))
+                        .collect(),
+                ),
+            };
+
+            passes.push(ScoringPass {
+                query: self.query_for(vector_name)?,
+                filter: restrict(filter, not_selecting),
+            });
+        }
+
+        Ok(passes)
+    }
+}
+
+impl TryFrom<grpc::VectorSelectionInternal> for VectorSelectionInternal {
+    type Error = tonic::Status;
+
+    fn try_from(selection: grpc::VectorSelectionInternal) -> Result<Self, Self::Error> {
+        let grpc::VectorSelectionInternal {
+            key,
+            mapping,
+            default,
+            queries,
+        } = selection;
+
+        let key = key
+            .parse()
+            .map_err(|_| tonic::Status::invalid_argument("invalid payload key"))?;
+
+        let mapping = mapping.into_iter().map(TryFrom::try_from).try_collect()?;
+
+        let queries = queries
+            .into_iter()
+            .map(|grpc::vector_selection_internal::Query { vector, query }| {
+                let query = query.ok_or_else(|| {
+                    tonic::Status::invalid_argument("missing field: vector_selection.queries.query")
+                })?;
+                Ok::<_, tonic::Status>(NamedQuery::new(VectorInternal::try_from(query)?, vector))
+            })
+            .try_collect()?;
+
+        Ok(Self {
+            key,
+            mapping,
+            default,
+            queries,
+        })
+    }
+}
+
+impl From<VectorSelectionInternal> for grpc::VectorSelectionInternal {
+    fn from(selection: VectorSelectionInternal) -> Self {
+        let VectorSelectionInternal {
+            key,
+            mapping,
+            default,
+            queries,
+        } = selection;
+
+        Self {
+            key: key.to_string(),
+            mapping: mapping
+                .into_iter()
+                .map(grpc::VectorSelectionMapping::from)
+                .collect(),
+            default,
+            queries: queries
+                .into_iter()
+                .map(
+                    |NamedQuery { query, using }| grpc::vector_selection_internal::Query {
+                        vector: using.unwrap_or_default(),
+                        query: Some(grpc::RawVector::from(query)),
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+/// Combine the original filter with the pass filter, without mixing their `should` clauses
+fn restrict(filter: Option<&Filter>, pass_filter: Filter) -> Filter {
+    let pass_filter = Filter::new_must(Condition::Filter(pass_filter));
+    match filter {
+        Some(filter) => pass_filter.merge_owned(filter.clone()),
+        None => pass_filter,
+    }
+}
+
+/// Merge results of the scoring passes into a single list of the best `limit` points.
+///
+/// If a point selects several vectors, e.g. with an array value, its best score is used.
+pub fn merge_scoring_passes(
+    passes: Vec<Vec<ScoredPoint>>,
+    order: Order,
+    limit: usize,
+) -> Vec<ScoredPoint> {
+    let mut best: AHashMap<_, ScoredPoint> = AHashMap::new();
+
+    for point in passes.into_iter().flatten() {
+        match best.get(&point.id) {
+            Some(existing) if !is_better(&point, existing, order) => {}
+            _ => {
+                best.insert(point.id, point);
+            }
+        }
+    }
+
+    let mut results = best.into_values().collect_vec();
+    match order {
+        Order::LargeBetter => results.sort_unstable_by(|a, b| b.cmp(a)),
+        Order::SmallBetter => results.sort_unstable(),
+    }
+    results.truncate(limit);
+
+    results
+}
+
+fn is_better(point: &ScoredPoint, other: &ScoredPoint, order: Order) -> bool {
+    match order {
+        Order::LargeBetter => point > other,
+        Order::SmallBetter => point < other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::PointIdType;
+
+    use super::*;
+
+    fn selection(default: Option<&str>) -> VectorSelectionInternal {
+        let query = |using: &str| NamedQuery::new(VectorInternal::from(vec![1.0, 0.0]), using);
+        VectorSelectionInternal {
+            key: JsonPath::new("lang"),
+            mapping: vec![
+                (ValueVariants::String("en".into()), "english".into()),
+                (ValueVariants::String("de".into()), "german".into()),
+                (ValueVariants::String("us".into()), "english".into()),
+            ],
+            default: default.map(Into::into),
+            queries: vec![query("english"), query("german"), query("multilingual")],
+        }
+    }
+
+    fn pass_filter(pass: &ScoringPass) -> &Filter {
+        match &pass.filter.must.as_ref().unwrap()[0] {
+            Condition::Filter(filter) => filter,
+            condition => panic!("unexpected condition {condition:?}"),
+        }
+    }
+
+    #[test]
+    fn test_plan_groups_values_by_vector() {
+        let selection = selection(None);
+        let passes = selection.plan(None).unwrap();
+
+        let names = passes
+            .iter()
+            .map(|pass| pass.query.get_vector_name())
+            .collect_vec();
+        assert_eq!(names, vec!["english", "german"]);
+
+        assert_eq!(pass_filter(&passes[0]).should.as_ref().unwrap().len(), 2);
+        assert_eq!(pass_filter(&passes[1]).should.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_plan_default_vector_and_filter() {
+        let selection = selection(Some("multilingual"));
+        let user_filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            JsonPath::new("published"),
+            ValueVariants::Bool(true).into(),
+        )));
+        let passes = selection.plan(Some(&user_filter)).unwrap();
+
+        assert_eq!(passes.len(), 3);
+        let default_pass = &passes[2];
+        assert_eq!(default_pass.query.get_vector_name(), "multilingual");
+        assert_eq!(
+            pass_filter(default_pass).must_not.as_ref().unwrap().len(),
+            3
+        );
+
+        // Original filter is applied to every pass
+        for pass in &passes {
+            let must = pass.filter.must.as_ref().unwrap();
+            assert_eq!(must[1], user_filter.must.as_ref().unwrap()[0]);
+        }
+    }
+
+    #[test]
+    fn test_plan_missing_query() {
+        let mut selection = selection(None);
+        selection
+            .queries
+            .retain(|query| query.using.as_deref() != Some("german"));
+        assert!(matches!(
+            selection.plan(None),
+            Err(OperationError::ValidationError { .. }),
+        ));
+    }
+
+    #[test]
+    fn test_order_of_selected_vectors() {
+        let selection = selection(None);
+        assert_eq!(
+            selection.order([Distance::Cosine, Distance::Dot]).unwrap(),
+            Order::LargeBetter,
+        );
+        assert_eq!(
+            selection
+                .order([Distance::Euclid, Distance::Manhattan])
+                .unwrap(),
+            Order::SmallBetter,
+        );
+        assert!(
+            selection
+                .order([Distance::Cosine, Distance::Euclid])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_merge_scoring_passes() {
+        let point = |id, score| ScoredPoint {
+            id: PointIdType::NumId(id),
+            version: 0,
+            score,
+            payload: None,
+            vector: None,
+            shard_key: None,
+            order_value: None,
+        };
+
+        // Point 2 selects both vectors, e.g. with `lang: ["en", "de"]`
+        let passes = || {
+            vec![
+                vec![point(1, 0.9), point(2, 0.5)],
+                vec![point(2, 0.7), point(3, 0.1)],
+            ]
+        };
+
+        let merged = merge_scoring_passes(passes(), Order::LargeBetter, 2);
+        let scores = merged.iter().map(|p| (p.id, p.score)).collect_vec();
+        assert_eq!(
+            scores,
+            vec![(PointIdType::NumId(1), 0.9), (PointIdType::NumId(2), 0.7)],
+        );
+
+        // Distances, smaller is better
+        let merged = merge_scoring_passes(passes(), Order::SmallBetter, 2);
+        let scores = merged.iter().map(|p| (p.id, p.score)).collect_vec();
+        assert_eq!(
+            scores,
+            vec![(PointIdType::NumId(3), 0.1), (PointIdType::NumId(2), 0.5)],
+        );
+    }
+}
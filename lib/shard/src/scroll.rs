@@ -2,6 +2,7 @@ pub use api::rest::OrderByInterface;
 use schemars::JsonSchema;
 use segment::types::{Filter, PointIdType, WithPayloadInterface, WithVector};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 use validator::Validate;
 
 /// Scroll request - paginate over all points which matches given condition
@@ -28,6 +29,17 @@ pub struct ScrollRequestInternal {
 
     /// Order the records by a payload field.
     pub order_by: Option<OrderByInterface>,
+
+    /// If true, all pages of the scroll read points from the same set of segments, so optimizations
+    /// running during the scroll can't affect its results. The first page must be requested
+    /// without `offset`. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consistent: Option<bool>,
+
+    /// Identifier of a consistent scroll, returned with its first page.
+    /// Must be specified to request the following pages of the scroll.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_id: Option<Uuid>,
 }
 
 impl Default for ScrollRequestInternal {
@@ -39,6 +51,8 @@ impl Default for ScrollRequestInternal {
             with_payload: Some(Self::default_with_payload()),
             with_vector: Self::default_with_vector(),
             order_by: None,
+            consistent: None,
+            view_id: None,
         }
     }
 }
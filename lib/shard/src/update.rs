@@ -8,19 +8,22 @@ use parking_lot::RwLockWriteGuard;
 use segment::common::operation_error::{OperationError, OperationResult};
 use segment::data_types::build_index_result::BuildFieldIndexResult;
 use segment::data_types::named_vectors::NamedVectors;
+use segment::data_types::vectors::VectorRef;
 use segment::entry::entry_point::SegmentEntry;
 use segment::json_path::JsonPath;
 use segment::types::{
-    Condition, Filter, Payload, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PointIdType,
-    SeqNumberType, VectorNameBuf, WithPayload, WithVector,
+    Condition, Filter, Payload, PayloadContainer, PayloadFieldSchema, PayloadKeyType,
+    PayloadKeyTypeRef, PointIdType, SeqNumberType, VectorName, VectorNameBuf, WithPayload,
+    WithVector,
 };
 
-use crate::operations::FieldIndexOperations;
-use crate::operations::payload_ops::PayloadOps;
+use crate::operations::payload_ops::{PayloadOps, SetPayloadOp};
 use crate::operations::point_ops::{
-    ConditionalInsertOperationInternal, PointOperations, PointStructPersisted, UpdateMode,
+    ConditionalInsertOperationInternal, PointOperations, PointStructPersisted, ReturnIds,
+    UpdateMode,
 };
 use crate::operations::vector_ops::{PointVectorsPersisted, UpdateVectorsOp, VectorOperations};
+use crate::operations::{CollectionUpdateOperations, FieldIndexOperations};
 use crate::segment_holder::SegmentHolder;
 
 pub fn process_point_operation(
@@ -84,6 +87,9 @@ pub fn process_vector_operation(
         VectorOperations::UpdateVectors(update_vectors) => {
             update_vectors_conditional(segments, op_num, update_vectors, hw_counter)
         }
+        VectorOperations::AppendVectors(append_vectors) => {
+            append_vectors_conditional(segments, op_num, append_vectors, hw_counter)
+        }
         VectorOperations::DeleteVectors(ids, vector_names) => {
             delete_vectors(segments, op_num, &ids.points, &vector_names, hw_counter)
         }
@@ -181,6 +187,20 @@ pub fn upsert_points<'a, T>(
     points: T,
     hw_counter: &HardwareCounterCell,
 ) -> OperationResult<usize>
+where
+    T: IntoIterator<Item = &'a PointStructPersisted>,
+{
+    upsert_points_impl(segments, op_num, points, false, hw_counter)
+}
+
+/// Same as [`upsert_points`], optionally storing dense vectors without normalization.
+fn upsert_points_impl<'a, T>(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    points: T,
+    skip_normalization: bool,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<usize>
 where
     T: IntoIterator<Item = &'a PointStructPersisted>,
 {
@@ -196,11 +216,13 @@ where
             ids_chunk,
             |id, write_segment| {
                 let point = points_map[&id];
+                let mut vectors = point.get_vectors();
+                vectors.set_skip_normalization(skip_normalization);
                 upsert_with_payload(
                     write_segment,
                     op_num,
                     id,
-                    point.get_vectors(),
+                    vectors,
                     point.payload.as_ref(),
                     hw_counter,
                 )
@@ -210,6 +232,8 @@ where
                 for (name, vec) in point.get_vectors() {
                     vectors.insert(name.into(), vec.to_owned());
                 }
+                // Vectors of the moved point are normalized already, if needed
+                vectors.set_skip_normalization(skip_normalization);
                 if let Some(payload) = &point.payload {
                     *old_payload = payload.clone();
                 }
@@ -236,11 +260,13 @@ where
             let mut write_segment = segment_arc.write();
             for point_id in new_point_ids {
                 let point = points_map[&point_id];
+                let mut vectors = point.get_vectors();
+                vectors.set_skip_normalization(skip_normalization);
                 res += usize::from(upsert_with_payload(
                     &mut write_segment,
                     op_num,
                     point_id,
-                    point.get_vectors(),
+                    vectors,
                     point.payload.as_ref(),
                     hw_counter,
                 )?);
@@ -265,6 +291,8 @@ pub fn conditional_upsert(
         mut points_op,
         condition,
         update_mode,
+        sequence_key,
+        skip_normalization,
     } = operation;
 
     let point_ids = points_op.point_ids();
@@ -294,8 +322,20 @@ pub fn conditional_upsert(
         }
     }
 
-    let points = points_op.into_point_vec();
-    let upserted_points = upsert_points(segments, op_num, points.iter(), hw_counter)?;
+    let mut points = points_op.into_point_vec();
+    if let Some(sequence_key) = &sequence_key {
+        let stale_points =
+            select_stale_by_sequence_ids(segments, &points, sequence_key, hw_counter)?;
+        points.retain(|point| !stale_points.contains(&point.id));
+    }
+
+    let upserted_points = upsert_points_impl(
+        segments,
+        op_num,
+        points.iter(),
+        skip_normalization.unwrap_or_default(),
+        hw_counter,
+    )?;
 
     if upserted_points == 0 {
         // In case we didn't hit any points, we suggest this op_num to the segment-holder to make WAL acknowledge this operation.
@@ -563,6 +603,102 @@ fn update_vectors(
     Ok(total_updated_points)
 }
 
+pub fn append_vectors_conditional(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    points: UpdateVectorsOp,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<usize> {
+    let UpdateVectorsOp {
+        mut points,
+        update_filter,
+    } = points;
+
+    if let Some(filter_condition) = update_filter {
+        let point_ids: Vec<_> = points.iter().map(|point| point.id).collect();
+        let points_to_exclude =
+            select_excluded_by_filter_ids(segments, point_ids, filter_condition, hw_counter)?;
+        points.retain(|p| !points_to_exclude.contains(&p.id));
+    }
+
+    append_vectors(segments, op_num, points, hw_counter)
+}
+
+/// Append sub-vectors to the existing multivectors of a point, keeping other vectors intact.
+///
+/// If a point has no multivector of the given name yet, the sub-vectors become its multivector.
+fn append_vectors(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    points: Vec<PointVectorsPersisted>,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<usize> {
+    // Build a map of sub-vectors to append per point, concatenate appends to the same point
+    let mut points_map: AHashMap<PointIdType, NamedVectors> = AHashMap::new();
+    for point in points {
+        let PointVectorsPersisted { id, vector } = point;
+        let entry = points_map.entry(id).or_default();
+        for (name, sub_vectors) in NamedVectors::from(vector).iter() {
+            append_sub_vectors(entry, name, sub_vectors)?;
+        }
+    }
+
+    let ids: Vec<PointIdType> = points_map.keys().copied().collect();
+
+    let mut total_updated_points = 0;
+    for batch in ids.chunks(VECTOR_OP_BATCH_SIZE) {
+        let updated_points = segments.apply_points_with_conditional_move(
+            op_num,
+            batch,
+            |id, write_segment| {
+                let mut vectors = NamedVectors::default();
+                for (name, sub_vectors) in points_map[&id].iter() {
+                    if let Some(stored) = write_segment.vector(name, id, hw_counter)? {
+                        vectors.insert(name.to_owned(), stored);
+                    }
+                    append_sub_vectors(&mut vectors, name, sub_vectors)?;
+                }
+                write_segment.update_vectors(op_num, id, vectors, hw_counter)
+            },
+            |id, owned_vectors, _| {
+                for (name, sub_vectors) in points_map[&id].iter() {
+                    // Stored multivectors always match the vector config, so the merge only fails
+                    // for sub-vectors of a wrong dimension, which are rejected on upsert anyway
+                    if append_sub_vectors(owned_vectors, name, sub_vectors).is_err() {
+                        owned_vectors.insert(name.to_owned(), sub_vectors.to_owned());
+                    }
+                }
+            },
+            hw_counter,
+        )?;
+        check_unprocessed_points(batch, &updated_points)?;
+        total_updated_points += updated_points.len();
+    }
+
+    Ok(total_updated_points)
+}
+
+/// Append sub-vectors to the multivector of the given name, or insert them if there is none
+fn append_sub_vectors(
+    vectors: &mut NamedVectors,
+    name: &VectorName,
+    sub_vectors: VectorRef,
+) -> OperationResult<()> {
+    let merged = match vectors.get(name) {
+        Some(stored) => {
+            let mut merged = stored.to_owned();
+            merged.append_sub_vectors(sub_vectors)?;
+            merged
+        }
+        None => match sub_vectors {
+            VectorRef::MultiDense(_) => sub_vectors.to_owned(),
+            VectorRef::Dense(_) | VectorRef::Sparse(_) => return Err(OperationError::WrongMulti),
+        },
+    };
+    vectors.insert(name.to_owned(), merged);
+    Ok(())
+}
+
 /// Delete the given named vectors for the given points, keeping other vectors intact.
 pub fn delete_vectors(
     segments: &SegmentHolder,
@@ -828,27 +964,35 @@ pub fn create_field_index(
     };
 
     segments.apply_segments(|write_segment| {
-        write_segment.with_upgraded(|segment| {
-            segment.delete_field_index_if_incompatible(op_num, field_name, field_schema)
-        })?;
+        // An index with another schema keeps serving requests, while the new one is built
+        let mut build_result =
+            write_segment.build_field_index(op_num, field_name, field_schema, hw_counter)?;
+
+        if let BuildFieldIndexResult::IncompatibleSchema = build_result {
+            // The index can't be rebuilt next to the current one, drop it first
+            write_segment.with_upgraded(|segment| {
+                segment.delete_field_index_if_incompatible(op_num, field_name, field_schema)
+            })?;
+            build_result =
+                write_segment.build_field_index(op_num, field_name, field_schema, hw_counter)?;
+        }
 
-        let (schema, indexes) =
-            match write_segment.build_field_index(op_num, field_name, field_schema, hw_counter)? {
-                BuildFieldIndexResult::SkippedByVersion => {
-                    return Ok(false);
-                }
-                BuildFieldIndexResult::AlreadyExists => {
-                    return Ok(false);
-                }
-                BuildFieldIndexResult::IncompatibleSchema => {
-                    // This is a service error, as we should have just removed the old index
-                    // So it should not be possible to get this error
-                    return Err(OperationError::service_error(format!(
-                        "Incompatible schema for field index on field {field_name}",
-                    )));
-                }
-                BuildFieldIndexResult::Built { schema, indexes } => (schema, indexes),
-            };
+        let (schema, indexes) = match build_result {
+            BuildFieldIndexResult::SkippedByVersion => {
+                return Ok(false);
+            }
+            BuildFieldIndexResult::AlreadyExists => {
+                return Ok(false);
+            }
+            BuildFieldIndexResult::IncompatibleSchema => {
+                // This is a service error, as we should have just removed the old index
+                // So it should not be possible to get this error
+                return Err(OperationError::service_error(format!(
+                    "Incompatible schema for field index on field {field_name}",
+                )));
+            }
+            BuildFieldIndexResult::Built { schema, indexes } => (schema, indexes),
+        };
 
         write_segment.with_upgraded(|segment| {
             segment.apply_field_index(op_num, field_name.to_owned(), schema, indexes)
@@ -881,6 +1025,65 @@ fn select_excluded_by_filter_ids(
         .collect())
 }
 
+/// Sequence number of the point, stored in the payload under the given key
+fn sequence_number(payload: &Payload, sequence_key: &JsonPath) -> Option<u64> {
+    payload
+        .get_value(sequence_key)
+        .iter()
+        .find_map(|value| value.as_u64())
+}
+
+/// Select points, which are stale: have a sequence number lower or equal to the one of the stored point.
+///
+/// Points without a sequence number are stale if the stored point has one.
+/// Stored points without a sequence number can be overwritten by any point.
+fn select_stale_by_sequence_ids(
+    segments: &SegmentHolder,
+    points: &[PointStructPersisted],
+    sequence_key: &JsonPath,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<AHashSet<PointIdType>> {
+    let point_ids: Vec<_> = points.iter().map(|point| point.id).collect();
+
+    // Point may be present in multiple segments, the latest version is the actual one
+    let mut stored_sequences: AHashMap<PointIdType, (SeqNumberType, Option<u64>)> = AHashMap::new();
+    // we don’t want to cancel this read
+    let is_stopped = AtomicBool::new(false);
+    segments.read_points(&point_ids, &is_stopped, |ids, segment| {
+        for &id in ids {
+            let Some(version) = segment.point_version(id) else {
+                continue;
+            };
+            if stored_sequences
+                .get(&id)
+                .is_some_and(|(stored_version, _)| *stored_version >= version)
+            {
+                continue;
+            }
+            let payload = segment.payload(id, hw_counter)?;
+            stored_sequences.insert(id, (version, sequence_number(&payload, sequence_key)));
+        }
+        Ok(ids.len())
+    })?;
+
+    let stale_points = points
+        .iter()
+        .filter(|point| {
+            let Some((_, Some(stored_sequence))) = stored_sequences.get(&point.id) else {
+                return false;
+            };
+            let sequence = point
+                .payload
+                .as_ref()
+                .and_then(|payload| sequence_number(payload, sequence_key));
+            sequence.is_none_or(|sequence| sequence <= *stored_sequence)
+        })
+        .map(|point| point.id)
+        .collect();
+
+    Ok(stale_points)
+}
+
 fn points_by_filter(
     segments: &SegmentHolder,
     filter: &Filter,
@@ -897,6 +1100,48 @@ fn points_by_filter(
     Ok(affected_points)
 }
 
+/// Select a page of ids of the points matching the filter of the operation.
+///
+/// Must be called under the same update lock as the operation itself,
+/// so that the ids are exactly the points affected by it.
+/// Returns up to `limit + 1` sorted ids starting from `offset`, the extra id starts the next page.
+pub fn select_affected_ids(
+    segments: &SegmentHolder,
+    operation: &CollectionUpdateOperations,
+    return_ids: &ReturnIds,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<Vec<PointIdType>> {
+    let filter = match operation {
+        CollectionUpdateOperations::PointOperation(PointOperations::DeletePointsByFilter(
+            filter,
+        ))
+        | CollectionUpdateOperations::PayloadOperation(
+            PayloadOps::ClearPayloadByFilter(filter)
+            | PayloadOps::SetPayload(SetPayloadOp {
+                points: None,
+                filter: Some(filter),
+                ..
+            })
+            | PayloadOps::OverwritePayload(SetPayloadOp {
+                points: None,
+                filter: Some(filter),
+                ..
+            }),
+        ) => filter,
+        _ => return Ok(Vec::new()),
+    };
+
+    let ReturnIds { limit, offset } = return_ids;
+
+    // Points may be present in multiple segments, while they are being optimized
+    let mut ids = points_by_filter(segments, filter, hw_counter)?;
+    ids.sort_unstable();
+    ids.dedup();
+
+    let start = offset.map_or(0, |offset| ids.partition_point(|id| *id < offset));
+    Ok(ids.into_iter().skip(start).take(limit + 1).collect())
+}
+
 fn check_unprocessed_points(
     points: &[PointIdType],
     processed: &AHashSet<PointIdType>,
@@ -915,10 +1160,21 @@ mod test {
 
     use common::counter::hardware_counter::HardwareCounterCell;
     use parking_lot::RwLock;
-    use segment::types::{Condition, FieldCondition, Filter, Match, MatchValue, ValueVariants};
+    use segment::data_types::vectors::{
+        DEFAULT_VECTOR_NAME, DenseVector, MultiDenseVectorInternal,
+    };
+    use segment::payload_json;
+    use segment::segment_constructor::build_segment;
+    use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+    use segment::types::{
+        Condition, Distance, FieldCondition, Filter, Indexes, Match, MatchValue, MultiVectorConfig,
+        SegmentConfig, ValueVariants, VectorDataConfig, VectorStorageType,
+    };
     use tempfile::Builder;
 
+    use super::*;
     use crate::fixtures::{build_segment_1, build_segment_2};
+    use crate::operations::point_ops::{PointInsertOperationsInternal, VectorStructPersisted};
     use crate::segment_holder::SegmentHolder;
     use crate::update::delete_points_by_filter;
 
@@ -972,4 +1228,246 @@ mod test {
         assert_eq!(old_version + 1, new_version);
         assert_eq!(new_version, DELETE_OP_NUM);
     }
+
+    fn sequenced_upsert(
+        segments: &SegmentHolder,
+        op_num: SeqNumberType,
+        points: &[(u64, Option<u64>)],
+    ) -> usize {
+        let points = points
+            .iter()
+            .map(|&(id, sequence)| PointStructPersisted {
+                id: id.into(),
+                vector: vec![1.0, 0.0, 1.0, 1.0].into(),
+                payload: Some(match sequence {
+                    Some(sequence) => payload_json! { "seq": sequence, "op": op_num },
+                    None => payload_json! { "op": op_num },
+                }),
+            })
+            .collect();
+        let operation = ConditionalInsertOperationInternal {
+            points_op: PointInsertOperationsInternal::PointsList(points),
+            condition: Filter::default(),
+            update_mode: None,
+            sequence_key: Some("seq".parse().unwrap()),
+        };
+        conditional_upsert(segments, op_num, operation, &HardwareCounterCell::new()).unwrap()
+    }
+
+    /// Number of the operation, which wrote the current version of the point
+    fn written_by(segments: &SegmentHolder, id: u64) -> Option<u64> {
+        let hw_counter = HardwareCounterCell::new();
+        let is_stopped = AtomicBool::new(false);
+        let mut latest = None;
+        segments
+            .read_points(&[id.into()], &is_stopped, |ids, segment| {
+                let version = segment.point_version(ids[0]);
+                if latest.is_none_or(|(latest_version, _)| version > latest_version) {
+                    let payload = segment.payload(ids[0], &hw_counter)?;
+                    latest = Some((version, sequence_number(&payload, &"op".parse().unwrap())));
+                }
+                Ok(1)
+            })
+            .unwrap();
+        latest.and_then(|(_, op)| op)
+    }
+
+    #[test]
+    fn test_upsert_ordered_by_sequence() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+        let mut holder = SegmentHolder::default();
+        holder.add_new(build_segment_1(dir.path()));
+        holder.add_new(build_segment_2(dir.path()));
+
+        // Stored points without sequence numbers are overwritten
+        assert_eq!(sequenced_upsert(&holder, 20, &[(1, Some(5)), (2, None)]), 2);
+        assert_eq!(written_by(&holder, 1), Some(20));
+
+        // Stale and equal sequence numbers are rejected
+        assert_eq!(sequenced_upsert(&holder, 21, &[(1, Some(3))]), 0);
+        assert_eq!(sequenced_upsert(&holder, 22, &[(1, Some(5))]), 0);
+        assert_eq!(written_by(&holder, 1), Some(20));
+
+        // Missing sequence number can't overwrite a sequenced point
+        assert_eq!(sequenced_upsert(&holder, 23, &[(1, None), (2, None)]), 1);
+        assert_eq!(written_by(&holder, 1), Some(20));
+        assert_eq!(written_by(&holder, 2), Some(23));
+
+        // Newer sequence numbers and new points are written, only updated points are counted
+        assert_eq!(
+            sequenced_upsert(&holder, 24, &[(1, Some(6)), (100, Some(1))]),
+            1
+        );
+        assert_eq!(written_by(&holder, 1), Some(24));
+        assert_eq!(written_by(&holder, 100), Some(24));
+    }
+
+    #[test]
+    fn test_upsert_skip_normalization() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+
+        let mut holder = SegmentHolder::default();
+        holder.add_new(build_simple_segment(dir.path(), 2, Distance::Cosine).unwrap());
+
+        let upsert = |op_num, id: u64, skip_normalization| {
+            let operation = ConditionalInsertOperationInternal {
+                points_op: PointInsertOperationsInternal::PointsList(vec![PointStructPersisted {
+                    id: id.into(),
+                    vector: vec![3.0, 4.0].into(),
+                    payload: None,
+                }]),
+                condition: Filter::default(),
+                update_mode: None,
+                sequence_key: None,
+                skip_normalization,
+            };
+            conditional_upsert(&holder, op_num, operation, &hw_counter).unwrap();
+        };
+        let stored_vector = |id: u64| {
+            let segment = holder.iter().next().unwrap().1.get();
+            let vector = segment
+                .read()
+                .vector(DEFAULT_VECTOR_NAME, id.into(), &hw_counter)
+                .unwrap()
+                .unwrap();
+            DenseVector::try_from(vector).unwrap()
+        };
+
+        upsert(1, 1, Some(true));
+        upsert(2, 2, None);
+        assert_eq!(stored_vector(1), vec![3.0, 4.0]);
+        let normalized = stored_vector(2);
+        assert!((normalized[0] - 0.6).abs() < 1e-6 && (normalized[1] - 0.8).abs() < 1e-6);
+
+        // Updates of existing points skip normalization as well
+        upsert(3, 2, Some(true));
+        assert_eq!(stored_vector(2), vec![3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_append_vectors() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+
+        let config = SegmentConfig {
+            vector_data: [(
+                DEFAULT_VECTOR_NAME.into(),
+                VectorDataConfig {
+                    size: 2,
+                    distance: Distance::Dot,
+                    storage_type: VectorStorageType::default(),
+                    index: Indexes::Plain {},
+                    quantization_config: None,
+                    multivector_config: Some(MultiVectorConfig::default()),
+                    datatype: None,
+                    skip_normalization: None,
+                },
+            )]
+            .into(),
+            sparse_vector_data: Default::default(),
+            payload_storage_type: Default::default(),
+            defragment_keys: None,
+        };
+        let mut holder = SegmentHolder::default();
+        holder.add_new(build_segment(dir.path(), &config, true).unwrap());
+
+        let point = PointStructPersisted {
+            id: 1.into(),
+            vector: VectorStructPersisted::MultiDense(vec![vec![1.0, 0.0]]),
+            payload: None,
+        };
+        upsert_points(&holder, 1, [&point], &hw_counter).unwrap();
+
+        let append = |op_num, id: u64, sub_vectors: Vec<Vec<f32>>| {
+            let operation = UpdateVectorsOp {
+                points: vec![PointVectorsPersisted {
+                    id: id.into(),
+                    vector: VectorStructPersisted::MultiDense(sub_vectors),
+                }],
+                update_filter: None,
+            };
+            append_vectors_conditional(&holder, op_num, operation, &hw_counter)
+        };
+        let stored_vectors = || {
+            let segment = holder.iter().next().unwrap().1.get();
+            let vector = segment
+                .read()
+                .vector(DEFAULT_VECTOR_NAME, 1.into(), &hw_counter)
+                .unwrap()
+                .unwrap();
+            MultiDenseVectorInternal::try_from(vector)
+                .unwrap()
+                .into_multi_vectors()
+        };
+
+        assert_eq!(
+            append(2, 1, vec![vec![0.0, 1.0], vec![1.0, 1.0]]).unwrap(),
+            1
+        );
+        assert_eq!(
+            stored_vectors(),
+            vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 1.0]],
+        );
+
+        // Sub-vectors of a different dimension are rejected
+        assert!(append(3, 1, vec![vec![1.0, 2.0, 3.0]]).is_err());
+        assert_eq!(stored_vectors().len(), 3);
+
+        // Sub-vectors can't be appended to missing points
+        assert!(append(4, 2, vec![vec![1.0, 0.0]]).is_err());
+    }
+    #[test]
+    fn test_select_affected_ids_pages() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+
+        // Points 4 and 5 are present in both segments
+        let mut holder = SegmentHolder::default();
+        holder.add_new(build_segment_1(dir.path()));
+        holder.add_new(build_segment_2(dir.path()));
+
+        let operation = CollectionUpdateOperations::PointOperation(
+            PointOperations::DeletePointsByFilter(Filter::default()),
+        );
+        let select = |limit, offset: Option<u64>| {
+            let return_ids = ReturnIds {
+                limit,
+                offset: offset.map(Into::into),
+            };
+            select_affected_ids(&holder, &operation, &return_ids, &hw_counter)
+                .unwrap()
+                .into_iter()
+                .map(|id| match id {
+                    PointIdType::NumId(id) => id,
+                    PointIdType::Uuid(_) => unreachable!(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Extra id starts the next page
+        assert_eq!(select(3, None), vec![1, 2, 3, 4]);
+        assert_eq!(select(3, Some(4)), vec![4, 5, 11, 12]);
+        assert_eq!(select(3, Some(14)), vec![14, 15]);
+        assert_eq!(select(3, Some(16)), Vec::<u64>::new());
+
+        // Operations without filter don't select anything
+        let by_ids = CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints {
+            ids: vec![1.into()],
+        });
+        let return_ids = ReturnIds {
+            limit: 3,
+            offset: None,
+        };
+        assert!(
+            select_affected_ids(&holder, &by_ids, &return_ids, &hw_counter)
+                .unwrap()
+                .is_empty()
+        );
+
+        // Paging the returned ids doesn't limit the update itself
+        delete_points_by_filter(&holder, 20, &Filter::default(), &hw_counter).unwrap();
+        assert!(select(10, None).is_empty());
+    }
 }
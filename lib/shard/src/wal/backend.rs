@@ -0,0 +1,326 @@
+use std::collections::VecDeque;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread::JoinHandle;
+
+use ::io::file_operations::{atomic_save_json, read_json};
+use serde::{Deserialize, Serialize};
+use wal::{Wal, WalOptions};
+
+/// File of the [`EmbeddedWal`] with the indices it has reserved
+const EMBEDDED_WAL_STATE_FILE: &str = "embedded-wal.json";
+
+/// Number of indices the [`EmbeddedWal`] reserves at once,
+/// so that the state file is not written on every append
+const EMBEDDED_WAL_INDEX_BLOCK: u64 = 1024;
+
+/// Backend to store WAL entries with, configured per node
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WalBackendType {
+    /// Segmented WAL in memory-mapped files
+    #[default]
+    File,
+    /// Experimental, entries are kept in memory only, see [`EmbeddedWal`]
+    Embedded,
+}
+
+/// Storage of raw WAL entries, addressed by sequential indices
+pub trait WalBackend {
+    /// Append an entry, returns its index
+    fn append(&mut self, entry: &[u8]) -> io::Result<u64>;
+
+    /// Access the entry at `index`, if it is stored
+    fn with_entry<T>(&self, index: u64, f: impl FnOnce(&[u8]) -> T) -> Option<T>;
+
+    /// Index of the first stored entry
+    fn first_index(&self) -> u64;
+
+    /// Index of the last stored entry, equal to `first_index` if there are no entries
+    fn last_index(&self) -> u64;
+
+    fn num_entries(&self) -> u64;
+
+    /// Remove entries before `until_index`.
+    ///
+    /// Backends may keep some of these entries, see [`WalBackend::first_index`].
+    fn prefix_truncate(&mut self, until_index: u64) -> io::Result<()>;
+
+    /// Remove entries starting from `from_index`
+    fn truncate(&mut self, from_index: u64) -> io::Result<()>;
+
+    /// Make all appended entries durable
+    fn flush(&mut self) -> io::Result<()>;
+
+    fn flush_async(&mut self) -> JoinHandle<io::Result<()>>;
+
+    /// Number of closed segments to keep after prefix truncation, if the backend has segments
+    fn set_retention(&mut self, retain_closed: usize);
+
+    /// Directory with the files of this WAL
+    fn path(&self) -> &Path;
+}
+
+/// Segmented WAL in memory-mapped files
+impl WalBackend for Wal {
+    fn append(&mut self, entry: &[u8]) -> io::Result<u64> {
+        Wal::append(self, &entry)
+    }
+
+    fn with_entry<T>(&self, index: u64, f: impl FnOnce(&[u8]) -> T) -> Option<T> {
+        self.entry(index).map(|entry| f(&entry))
+    }
+
+    fn first_index(&self) -> u64 {
+        Wal::first_index(self)
+    }
+
+    fn last_index(&self) -> u64 {
+        Wal::last_index(self)
+    }
+
+    fn num_entries(&self) -> u64 {
+        Wal::num_entries(self)
+    }
+
+    fn prefix_truncate(&mut self, until_index: u64) -> io::Result<()> {
+        Wal::prefix_truncate(self, until_index)
+    }
+
+    fn truncate(&mut self, from_index: u64) -> io::Result<()> {
+        Wal::truncate(self, from_index)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_open_segment()
+    }
+
+    fn flush_async(&mut self) -> JoinHandle<io::Result<()>> {
+        self.flush_open_segment_async()
+    }
+
+    fn set_retention(&mut self, retain_closed: usize) {
+        Wal::set_retention(self, retain_closed);
+    }
+
+    fn path(&self) -> &Path {
+        Wal::path(self)
+    }
+}
+
+/// Experimental WAL backend, which keeps entries in memory only.
+///
+/// Meant for deployments in which operations are already durable in another log,
+/// e.g. embedded into entries of the Raft log. Operations since the last flush of segments
+/// must then be replayed from that log after a restart, as this WAL starts empty.
+///
+/// Indices are reserved on disk in blocks, so that indices assigned after a restart are
+/// always higher than versions of the operations, which made it into segments before.
+#[derive(Debug)]
+pub struct EmbeddedWal {
+    /// Directory for WAL metadata, no entries are stored there
+    dir: PathBuf,
+    first_index: u64,
+    entries: VecDeque<Vec<u8>>,
+    /// Indices below this one may have been assigned, including before a restart
+    reserved_until: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct EmbeddedWalState {
+    reserved_until: u64,
+}
+
+impl EmbeddedWal {
+    pub fn new(dir: &Path) -> io::Result<Self> {
+        fs_err::create_dir_all(dir)?;
+
+        let state_path = dir.join(EMBEDDED_WAL_STATE_FILE);
+        let reserved_until = if state_path.exists() {
+            read_json::<EmbeddedWalState>(&state_path)
+                .map_err(|err| io::Error::other(err.to_string()))?
+                .reserved_until
+        } else {
+            0
+        };
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            first_index: reserved_until,
+            entries: VecDeque::new(),
+            reserved_until,
+        })
+    }
+
+    fn next_index(&self) -> u64 {
+        self.first_index + self.entries.len() as u64
+    }
+
+    /// Persist a new block of indices, if `index` is not reserved yet
+    fn reserve(&mut self, index: u64) -> io::Result<()> {
+        if index < self.reserved_until {
+            return Ok(());
+        }
+
+        let reserved_until = index + EMBEDDED_WAL_INDEX_BLOCK;
+        atomic_save_json(
+            &self.dir.join(EMBEDDED_WAL_STATE_FILE),
+            &EmbeddedWalState { reserved_until },
+        )
+        .map_err(|err| io::Error::other(err.to_string()))?;
+        self.reserved_until = reserved_until;
+
+        Ok(())
+    }
+}
+
+impl WalBackend for EmbeddedWal {
+    fn append(&mut self, entry: &[u8]) -> io::Result<u64> {
+        let index = self.next_index();
+        self.reserve(index)?;
+        self.entries.push_back(entry.to_vec());
+        Ok(index)
+    }
+
+    fn with_entry<T>(&self, index: u64, f: impl FnOnce(&[u8]) -> T) -> Option<T> {
+        let offset = index.checked_sub(self.first_index)?;
+        self.entries
+            .get(usize::try_from(offset).ok()?)
+            .map(|entry| f(entry))
+    }
+
+    fn first_index(&self) -> u64 {
+        self.first_index
+    }
+
+    fn last_index(&self) -> u64 {
+        self.next_index().saturating_sub(1).max(self.first_index)
+    }
+
+    fn num_entries(&self) -> u64 {
+        self.entries.len() as u64
+    }
+
+    fn prefix_truncate(&mut self, until_index: u64) -> io::Result<()> {
+        let until_index = until_index.min(self.next_index());
+        let removed = until_index.saturating_sub(self.first_index) as usize;
+        self.entries.drain(..removed);
+        self.first_index += removed as u64;
+        Ok(())
+    }
+
+    fn truncate(&mut self, from_index: u64) -> io::Result<()> {
+        let keep = from_index.saturating_sub(self.first_index) as usize;
+        self.entries.truncate(keep);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn flush_async(&mut self) -> JoinHandle<io::Result<()>> {
+        std::thread::spawn(|| Ok(()))
+    }
+
+    fn set_retention(&mut self, _retain_closed: usize) {}
+
+    fn path(&self) -> &Path {
+        &self.dir
+    }
+}
+
+/// WAL backend, selected when opening the WAL
+#[derive(Debug)]
+pub enum WalBackendEnum {
+    File(Wal),
+    Embedded(EmbeddedWal),
+}
+
+impl WalBackendEnum {
+    pub fn open(dir: &Path, options: &WalOptions, backend: WalBackendType) -> io::Result<Self> {
+        match backend {
+            WalBackendType::File => Wal::with_options(dir, options).map(Self::File),
+            WalBackendType::Embedded => EmbeddedWal::new(dir).map(Self::Embedded),
+        }
+    }
+}
+
+impl WalBackend for WalBackendEnum {
+    fn append(&mut self, entry: &[u8]) -> io::Result<u64> {
+        match self {
+            Self::File(wal) => WalBackend::append(wal, entry),
+            Self::Embedded(wal) => wal.append(entry),
+        }
+    }
+
+    fn with_entry<T>(&self, index: u64, f: impl FnOnce(&[u8]) -> T) -> Option<T> {
+        match self {
+            Self::File(wal) => wal.with_entry(index, f),
+            Self::Embedded(wal) => wal.with_entry(index, f),
+        }
+    }
+
+    fn first_index(&self) -> u64 {
+        match self {
+            Self::File(wal) => WalBackend::first_index(wal),
+            Self::Embedded(wal) => wal.first_index(),
+        }
+    }
+
+    fn last_index(&self) -> u64 {
+        match self {
+            Self::File(wal) => WalBackend::last_index(wal),
+            Self::Embedded(wal) => wal.last_index(),
+        }
+    }
+
+    fn num_entries(&self) -> u64 {
+        match self {
+            Self::File(wal) => WalBackend::num_entries(wal),
+            Self::Embedded(wal) => wal.num_entries(),
+        }
+    }
+
+    fn prefix_truncate(&mut self, until_index: u64) -> io::Result<()> {
+        match self {
+            Self::File(wal) => WalBackend::prefix_truncate(wal, until_index),
+            Self::Embedded(wal) => wal.prefix_truncate(until_index),
+        }
+    }
+
+    fn truncate(&mut self, from_index: u64) -> io::Result<()> {
+        match self {
+            Self::File(wal) => WalBackend::truncate(wal, from_index),
+            Self::Embedded(wal) => wal.truncate(from_index),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::File(wal) => WalBackend::flush(wal),
+            Self::Embedded(wal) => wal.flush(),
+        }
+    }
+
+    fn flush_async(&mut self) -> JoinHandle<io::Result<()>> {
+        match self {
+            Self::File(wal) => WalBackend::flush_async(wal),
+            Self::Embedded(wal) => wal.flush_async(),
+        }
+    }
+
+    fn set_retention(&mut self, retain_closed: usize) {
+        match self {
+            Self::File(wal) => WalBackend::set_retention(wal, retain_closed),
+            Self::Embedded(wal) => wal.set_retention(retain_closed),
+        }
+    }
+
+    fn path(&self) -> &Path {
+        match self {
+            Self::File(wal) => WalBackend::path(wal),
+            Self::Embedded(wal) => wal.path(),
+        }
+    }
+}
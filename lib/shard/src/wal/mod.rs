@@ -4,11 +4,16 @@ use std::path::Path;
 use std::result;
 use std::thread::JoinHandle;
 
+use common::fault_injection::{self, FaultPoint};
 use io::file_operations::{atomic_save_json, read_json};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use wal::{Wal, WalOptions};
+use wal::WalOptions;
+
+pub use self::backend::{EmbeddedWal, WalBackend, WalBackendEnum, WalBackendType};
+
+mod backend;
 
 /// Write-Ahead-Log wrapper with built-in type parsing.
 /// Stores sequences of records of type `R` in binary files.
@@ -17,8 +22,8 @@ use wal::{Wal, WalOptions};
 /// Sequential number can be used to read stored records starting from some IDs,
 /// for removing old, no longer required, records.
 #[derive(Debug)]
-pub struct SerdeWal<R> {
-    wal: Wal,
+pub struct SerdeWal<R, W = WalBackendEnum> {
+    wal: W,
     options: WalOptions,
     /// First index of our logical WAL.
     first_index: Option<u64>,
@@ -74,9 +79,27 @@ impl<R: DeserializeOwned + Serialize> WalRawRecord<R> {
 
 impl<R: DeserializeOwned + Serialize> SerdeWal<R> {
     pub fn new(dir: &Path, wal_options: WalOptions) -> Result<SerdeWal<R>> {
-        let wal = Wal::with_options(dir, &wal_options)
+        Self::open(dir, wal_options, WalBackendType::File)
+    }
+
+    /// Open WAL with the given backend.
+    ///
+    /// See [`EmbeddedWal`] for the durability requirements of the experimental embedded backend.
+    pub fn open(
+        dir: &Path,
+        wal_options: WalOptions,
+        backend: WalBackendType,
+    ) -> Result<SerdeWal<R>> {
+        let wal = WalBackendEnum::open(dir, &wal_options, backend)
             .map_err(|err| WalError::InitWalError(format!("{err:?}")))?;
 
+        Self::with_backend(dir, wal, wal_options)
+    }
+}
+
+impl<R: DeserializeOwned + Serialize, W: WalBackend> SerdeWal<R, W> {
+    /// Open WAL on top of an already opened backend, which stores its entries in `dir`
+    pub fn with_backend(dir: &Path, wal: W, wal_options: WalOptions) -> Result<SerdeWal<R, W>> {
         let first_index_path = dir.join(FIRST_INDEX_FILE);
 
         let first_index = if first_index_path.exists() {
@@ -120,14 +143,10 @@ impl<R: DeserializeOwned + Serialize> SerdeWal<R> {
     }
 
     pub fn read_raw_record(&self, idx: u64) -> Option<WalRawRecord<R>> {
-        if let Some(entry) = self.wal.entry(idx) {
-            Some(WalRawRecord::<R> {
-                record: entry.to_vec(),
-                _phantom: PhantomData,
-            })
-        } else {
-            None
-        }
+        self.wal.with_entry(idx, |entry| WalRawRecord::<R> {
+            record: entry.to_vec(),
+            _phantom: PhantomData,
+        })
     }
 
     pub fn read(&self, from: u64) -> impl DoubleEndedIterator<Item = (u64, R)> + '_ {
@@ -141,12 +160,10 @@ impl<R: DeserializeOwned + Serialize> SerdeWal<R> {
 
     pub fn read_range(&self, range: Range<u64>) -> impl DoubleEndedIterator<Item = (u64, R)> + '_ {
         range.map(move |idx| {
-            let record_bin = self
+            let record: R = self
                 .wal
-                .entry(idx)
-                .unwrap_or_else(|| panic!("Can't read entry {idx} from WAL"));
-
-            let record: R = WalRawRecord::deserialize_from(&record_bin)
+                .with_entry(idx, WalRawRecord::deserialize_from)
+                .unwrap_or_else(|| panic!("Can't read entry {idx} from WAL"))
                 .expect("Can't deserialize entry, probably corrupted WAL or version mismatch");
 
             (idx, record)
@@ -235,13 +252,23 @@ impl<R: DeserializeOwned + Serialize> SerdeWal<R> {
     }
 
     pub fn flush(&mut self) -> Result<()> {
+        self.inject_flush_delay();
         self.wal
-            .flush_open_segment()
+            .flush()
             .map_err(|err| WalError::WriteWalError(format!("{err:?}")))
     }
 
     pub fn flush_async(&mut self) -> JoinHandle<std::io::Result<()>> {
-        self.wal.flush_open_segment_async()
+        self.inject_flush_delay();
+        self.wal.flush_async()
+    }
+
+    fn inject_flush_delay(&self) {
+        if let Some(delay) =
+            fault_injection::injected_delay(FaultPoint::DelayWalFlush, self.path().display())
+        {
+            std::thread::sleep(delay);
+        }
     }
 
     pub fn path(&self) -> &Path {
@@ -455,4 +482,87 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_embedded_wal() {
+        let dir = Builder::new().prefix("wal_test").tempdir().unwrap();
+
+        let mut serde_wal: SerdeWal<TestRecord> =
+            SerdeWal::open(dir.path(), WalOptions::default(), WalBackendType::Embedded).unwrap();
+        assert!(serde_wal.is_empty());
+
+        for i in 0..10 {
+            let record = TestRecord::Struct1(TestInternalStruct1 { data: i });
+            serde_wal
+                .write(&WalRawRecord::new(&record).unwrap())
+                .expect("Can't write");
+        }
+        assert_eq!(serde_wal.len(false), 10);
+        assert_eq!(serde_wal.last_index(), 9);
+
+        // Acknowledged records are removed right away, there are no segments to keep
+        serde_wal.ack(4).unwrap();
+        assert_eq!(serde_wal.first_index(), 4);
+        assert_eq!(serde_wal.first_closed_index(), 4);
+        assert_eq!(serde_wal.len(true), 6);
+        assert!(serde_wal.read_raw_record(3).is_none());
+
+        serde_wal.drop_from(8).expect("Can't drop WAL from index");
+
+        let indices: Vec<_> = serde_wal
+            .read(serde_wal.first_index())
+            .map(|(idx, record)| {
+                let expected = TestRecord::Struct1(TestInternalStruct1 { data: idx as usize });
+                assert_eq!(record, expected);
+                idx
+            })
+            .collect();
+        assert_eq!(indices, vec![4, 5, 6, 7]);
+
+        // New records continue the sequence
+        let record = TestRecord::Struct2(TestInternalStruct2 { a: 1, b: 2 });
+        let idx = serde_wal
+            .write(&WalRawRecord::new(&record).unwrap())
+            .expect("Can't write");
+        assert_eq!(idx, 8);
+
+        // Only metadata is stored on disk
+        serde_wal.flush().unwrap();
+        assert!(dir.path().join(FIRST_INDEX_FILE).exists());
+        assert!(!dir.path().join("open-1").exists());
+    }
+
+    #[test]
+    fn test_embedded_wal_reopen() {
+        let dir = Builder::new().prefix("wal_test").tempdir().unwrap();
+        let open = || {
+            SerdeWal::<TestRecord>::open(
+                dir.path(),
+                WalOptions::default(),
+                WalBackendType::Embedded,
+            )
+            .unwrap()
+        };
+
+        let mut serde_wal = open();
+        for i in 0..3 {
+            let record = TestRecord::Struct1(TestInternalStruct1 { data: i });
+            serde_wal
+                .write(&WalRawRecord::new(&record).unwrap())
+                .expect("Can't write");
+        }
+        serde_wal.ack(1).unwrap();
+        drop(serde_wal);
+
+        // Entries are lost on restart, but indices are never assigned twice
+        let mut serde_wal = open();
+        assert!(serde_wal.is_empty());
+
+        let record = TestRecord::Struct2(TestInternalStruct2 { a: 1, b: 2 });
+        let idx = serde_wal
+            .write(&WalRawRecord::new(&record).unwrap())
+            .expect("Can't write");
+        assert!(idx > 2);
+        assert_eq!(serde_wal.read(serde_wal.first_index()).count(), 1);
+    }
 }
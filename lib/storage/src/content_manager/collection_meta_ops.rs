@@ -1,6 +1,8 @@
 use std::collections::BTreeMap;
 
-use collection::config::{CollectionConfigInternal, CollectionParams, ShardingMethod};
+use collection::config::{
+    CollectionConfigInternal, CollectionParams, PlacementConstraints, ShardingMethod,
+};
 use collection::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
@@ -177,6 +179,10 @@ pub struct CreateCollection {
     /// such as creation time, migration data, inference model info, etc.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Payload>,
+    /// Constraints on which peers may hold replicas of this collection.
+    /// Honored when placing shards and shard keys, and when adding replicas automatically.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement: Option<PlacementConstraints>,
 }
 
 /// Operation for creating new collection and (optionally) specify index params
@@ -444,8 +450,13 @@ impl From<CollectionConfigInternal> for CreateCollection {
             write_consistency_factor,
             read_fan_out_factor: _,
             read_fan_out_delay_ms: _,
+            query_cache_max_entries: _,
+            query_cache_ttl_sec: _,
             on_disk_payload,
             sparse_vectors,
+            placement,
+            non_finite_vector_policy: _,
+            default_search_params: _,
         } = params;
 
         Self {
@@ -463,6 +474,7 @@ impl From<CollectionConfigInternal> for CreateCollection {
             strict_mode_config,
             uuid,
             metadata,
+            placement,
         }
     }
 }
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use collection::operations::verification::{
     StrictModeVerification, VerificationPass, check_timeout, new_unchecked_verification_pass,
 };
+use segment::types::StrictModeConfig;
 
 use super::errors::StorageError;
 use super::toc::TableOfContent;
@@ -63,6 +64,8 @@ where
     I: StrictModeVerification + 'a,
 {
     let toc = get_toc_without_verification_pass(dispatcher, auth);
+    // Only client requests come through the dispatcher, internal ones must reach standby peers
+    toc.check_not_standby()?;
     check_strict_mode_toc_batch(requests, timeout, collection_name, toc, auth).await
 }
 
@@ -103,12 +106,13 @@ pub async fn check_strict_mode_timeout(
     dispatcher: &Dispatcher,
     auth: &Auth,
 ) -> Result<VerificationPass, StorageError> {
+    let toc = get_toc_without_verification_pass(dispatcher, auth);
+    toc.check_not_standby()?;
+
     let Some(timeout) = timeout else {
         return Ok(new_unchecked_verification_pass());
     };
 
-    let toc = get_toc_without_verification_pass(dispatcher, auth);
-
     // Check access here first since strict-mode gets checked before `access`.
     // If we simply bypassed here, requests to a collection a user doesn't has access to could leak
     // information, like existence, strict mode config, payload indices, ...
@@ -129,6 +133,27 @@ pub async fn check_strict_mode_timeout(
     Ok(new_unchecked_verification_pass())
 }
 
+/// Returns the strict mode config of the collection, if strict mode is enabled for it.
+///
+/// Use it to apply strict mode defaults to a request before checking it.
+pub async fn enabled_strict_mode_config(
+    collection_name: &str,
+    dispatcher: &Dispatcher,
+    auth: &Auth,
+) -> Result<Option<StrictModeConfig>, StorageError> {
+    let toc = get_toc_without_verification_pass(dispatcher, auth);
+
+    let collection_pass = auth
+        .unlogged_access() // expected for strict mode check
+        .check_collection_access(collection_name, AccessRequirements::new())?;
+    let collection = toc.get_collection(&collection_pass).await?;
+
+    Ok(collection
+        .strict_mode_config()
+        .await
+        .filter(|config| config.enabled.unwrap_or_default()))
+}
+
 /// Returns the `TableOfContent` from `dispatcher` without needing a validity check.
 /// Caution: Do only use this to obtain a `VerificationPass`!
 /// Don't make public!
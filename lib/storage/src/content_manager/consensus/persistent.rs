@@ -311,11 +311,11 @@ impl Persistent {
         self.peer_metadata_by_id.read().clone()
     }
 
-    pub fn is_our_metadata_outdated(&self) -> bool {
+    pub fn is_our_metadata_outdated(&self, current: &PeerMetadata) -> bool {
         self.peer_metadata_by_id
             .read()
             .get(&self.this_peer_id())
-            .is_none_or(|metadata| metadata.is_different_version())
+            .is_none_or(|metadata| metadata != current)
     }
 
     pub fn this_peer_id(&self) -> PeerId {
@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::error::Error;
 use std::fmt::Display;
 use std::future::Future;
@@ -102,6 +102,8 @@ pub struct ConsensusManager<C: CollectionContainer> {
     message_send_failures: RwLock<HashMap<String, MessageSendErrors>>,
     /// Last time we attempted to update the peer metadata
     next_peer_metadata_update_attempt: Mutex<Instant>,
+    /// Labels of this peer, shared with other peers through peer metadata
+    node_labels: BTreeMap<String, String>,
 }
 
 impl<C: CollectionContainer> ConsensusManager<C> {
@@ -145,9 +147,16 @@ impl<C: CollectionContainer> ConsensusManager<C> {
             }),
             message_send_failures: Default::default(),
             next_peer_metadata_update_attempt: Mutex::new(Instant::now()),
+            node_labels: BTreeMap::new(),
         })
     }
 
+    /// Set labels of this peer, used by other peers to honor placement constraints
+    pub fn with_node_labels(mut self, node_labels: BTreeMap<String, String>) -> Self {
+        self.node_labels = node_labels;
+        self
+    }
+
     pub fn report_snapshot(
         &self,
         peer_id: u64,
@@ -206,6 +215,24 @@ impl<C: CollectionContainer> ConsensusManager<C> {
         self.persistent.read().this_peer_id
     }
 
+    /// Whether this peer is the current Raft leader
+    pub fn is_leader(&self) -> bool {
+        self.soft_state
+            .read()
+            .as_ref()
+            .is_some_and(|state| state.raft_state == raft::StateRole::Leader)
+    }
+
+    /// Peers which are voting members of consensus
+    pub fn voters(&self) -> Vec<PeerId> {
+        self.persistent
+            .read()
+            .state
+            .conf_state
+            .get_voters()
+            .to_vec()
+    }
+
     pub fn peers(&self) -> Vec<PeerId> {
         self.persistent
             .read()
@@ -545,7 +572,9 @@ impl<C: CollectionContainer> ConsensusManager<C> {
                 Ok(true)
             }
 
-            ConsensusOperations::RequestSnapshot | ConsensusOperations::ReportSnapshot { .. } => {
+            ConsensusOperations::RequestSnapshot
+            | ConsensusOperations::TransferLeader(_)
+            | ConsensusOperations::ReportSnapshot { .. } => {
                 unreachable!()
             }
         };
@@ -860,7 +889,8 @@ impl<C: CollectionContainer> ConsensusManager<C> {
             return;
         }
 
-        if !self.persistent.read().is_our_metadata_outdated() {
+        let metadata = PeerMetadata::current(self.node_labels.clone());
+        if !self.persistent.read().is_our_metadata_outdated(&metadata) {
             return;
         }
 
@@ -869,7 +899,7 @@ impl<C: CollectionContainer> ConsensusManager<C> {
             .propose_sender
             .send(ConsensusOperations::UpdatePeerMetadata {
                 peer_id: self.this_peer_id(),
-                metadata: PeerMetadata::current(),
+                metadata,
             });
         if let Err(err) = result {
             log::error!("Failed to propose consensus peer metadata update for this peer: {err}");
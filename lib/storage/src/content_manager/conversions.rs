@@ -4,6 +4,7 @@ use std::str::FromStr;
 use api::conversions::json;
 use api::grpc::qdrant as grpc;
 use chrono::{DateTime, Utc};
+use collection::config::PlacementConstraints;
 use collection::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
 };
@@ -50,6 +51,8 @@ impl From<StorageError> for Status {
                 tonic::Code::ResourceExhausted
             }
             StorageError::ShardUnavailable { .. } => tonic::Code::Unavailable,
+            StorageError::QuotaExceeded { .. } => tonic::Code::ResourceExhausted,
+            StorageError::NodeStandby { .. } => tonic::Code::Unavailable,
             StorageError::EmptyPartialSnapshot { .. } => tonic::Code::FailedPrecondition,
         };
         let mut status = Status::new(error_code, format!("{error}"));
@@ -85,6 +88,7 @@ impl TryFrom<grpc::CreateCollection> for CollectionMetaOperations {
             sparse_vectors_config,
             strict_mode_config,
             metadata,
+            placement,
         } = value;
         let op = CreateCollectionOperation::new(
             collection_name,
@@ -115,6 +119,7 @@ impl TryFrom<grpc::CreateCollection> for CollectionMetaOperations {
                 } else {
                     Some(json::proto_to_payloads(metadata)?)
                 },
+                placement: placement.map(PlacementConstraints::from),
             },
         )?;
         Ok(CollectionMetaOperations::CreateCollection(op))
@@ -142,6 +147,10 @@ pub fn strict_mode_from_api(value: grpc::StrictModeConfig) -> StrictModeConfig {
         multivector_config,
         sparse_config,
         max_payload_index_count,
+        read_vectors_by_default,
+        max_response_vectors_bytes,
+        max_update_queue_length,
+        max_unindexed_segment_size_kb,
     } = value;
     StrictModeConfig {
         enabled,
@@ -163,6 +172,10 @@ pub fn strict_mode_from_api(value: grpc::StrictModeConfig) -> StrictModeConfig {
         multivector_config: multivector_config.map(StrictModeMultivectorConfig::from),
         sparse_config: sparse_config.map(StrictModeSparseConfig::from),
         max_payload_index_count: max_payload_index_count.map(|i| i as usize),
+        read_vectors_by_default,
+        max_response_vectors_bytes: max_response_vectors_bytes.map(|i| i as usize),
+        max_update_queue_length: max_update_queue_length.map(|i| i as usize),
+        max_unindexed_segment_size_kb: max_unindexed_segment_size_kb.map(|i| i as usize),
     }
 }
 
@@ -45,6 +45,10 @@ pub enum StorageError {
     },
     #[error("Shard temporarily unavailable: {description}")]
     ShardUnavailable { description: String },
+    #[error("Quota exceeded: {description}")]
+    QuotaExceeded { description: String },
+    #[error("Node is in standby: {description}")]
+    NodeStandby { description: String },
     #[error("Partial snapshot for shard {shard_id} contains no changes")]
     EmptyPartialSnapshot { shard_id: ShardId },
 }
@@ -94,6 +98,12 @@ impl StorageError {
         }
     }
 
+    pub fn node_standby(description: impl Into<String>) -> Self {
+        Self::NodeStandby {
+            description: description.into(),
+        }
+    }
+
     pub fn forbidden(description: impl Into<String>) -> Self {
         Self::Forbidden {
             description: description.into(),
@@ -185,6 +195,9 @@ impl StorageError {
             CollectionError::ShardUnavailable { .. } => StorageError::ShardUnavailable {
                 description: overriding_description,
             },
+            CollectionError::QuotaExceeded { .. } => StorageError::QuotaExceeded {
+                description: overriding_description,
+            },
         }
     }
 }
@@ -247,6 +260,9 @@ impl From<CollectionError> for StorageError {
             CollectionError::ShardUnavailable { description } => {
                 StorageError::ShardUnavailable { description }
             }
+            CollectionError::QuotaExceeded { description } => {
+                StorageError::QuotaExceeded { description }
+            }
         }
     }
 }
@@ -53,6 +53,7 @@ pub mod consensus_ops {
             value: serde_json::Value,
         },
         RequestSnapshot,
+        TransferLeader(PeerId),
         ReportSnapshot {
             peer_id: PeerId,
             status: SnapshotStatus,
@@ -174,6 +175,10 @@ pub mod consensus_ops {
             Self::RequestSnapshot
         }
 
+        pub fn transfer_leader(peer_id: PeerId) -> Self {
+            Self::TransferLeader(peer_id)
+        }
+
         pub fn report_snapshot(peer_id: PeerId, status: impl Into<SnapshotStatus>) -> Self {
             Self::ReportSnapshot {
                 peer_id,
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use collection::collection::Collection;
 use collection::collection_state;
+use collection::operations::types::NodeType;
 use collection::shards::CollectionId;
 use collection::shards::collection_shard_distribution::CollectionShardDistribution;
 use collection::shards::replica_set::replica_set_state::ReplicaState;
@@ -69,6 +70,14 @@ impl CollectionContainer for TableOfContent {
     fn sync_local_state(&self) -> Result<(), StorageError> {
         self.general_runtime.block_on(async {
             let collections = self.collections.read().await;
+
+            // Standby peer keeps its replicas as listeners, so they are not used for reads
+            let node_type = if self.is_standby() {
+                NodeType::Listener
+            } else {
+                self.storage_config.node_type
+            };
+
             let transfer_failure_callback =
                 Self::on_transfer_failure_callback(self.consensus_proposal_sender.clone());
             let transfer_success_callback =
@@ -96,6 +105,7 @@ impl CollectionContainer for TableOfContent {
 
                 collection
                     .sync_local_state(
+                        node_type,
                         transfer_failure_callback.clone(),
                         transfer_success_callback.clone(),
                         finish_shard_initialize,
@@ -201,7 +211,7 @@ impl TableOfContent {
                             self.consensus_proposal_sender.clone(),
                             id.clone(),
                         ),
-                        Some(self.search_runtime.handle().clone()),
+                        Some(self.search_runtime_handle(id)),
                         Some(self.update_runtime.handle().clone()),
                         self.optimizer_resource_budget.clone(),
                         self.storage_config.optimizers_overwrite.clone(),
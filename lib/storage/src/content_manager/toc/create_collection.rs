@@ -43,6 +43,7 @@ impl TableOfContent {
             strict_mode_config,
             uuid,
             metadata,
+            placement,
         } = operation;
 
         {
@@ -148,6 +149,11 @@ impl TableOfContent {
             )?,
             read_fan_out_factor: None,
             read_fan_out_delay_ms: None,
+            query_cache_max_entries: None,
+            query_cache_ttl_sec: None,
+            placement,
+            non_finite_vector_policy: None,
+            default_search_params: None,
         };
         let wal_config = self.storage_config.wal.update_opt(wal_config_diff.as_ref());
 
@@ -230,7 +236,7 @@ impl TableOfContent {
                 self.consensus_proposal_sender.clone(),
                 collection_name.to_string(),
             ),
-            Some(self.search_runtime.handle().clone()),
+            Some(self.search_runtime_handle(collection_name)),
             Some(self.update_runtime.handle().clone()),
             self.optimizer_resource_budget.clone(),
             self.storage_config.optimizers_overwrite.clone(),
@@ -2,6 +2,7 @@ mod collection_container;
 mod collection_meta_ops;
 mod create_collection;
 pub mod dispatcher;
+mod placement;
 mod point_ops;
 mod point_ops_internal;
 pub mod request_hw_counter;
@@ -12,9 +13,10 @@ pub mod transfer;
 
 use std::cmp::max;
 use std::collections::{HashMap, HashSet};
-use std::num::NonZeroU32;
+use std::num::{NonZeroU32, NonZeroUsize};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use api::rest::models::HardwareUsage;
@@ -28,9 +30,10 @@ use collection::shards::replica_set::AbortShardTransfer;
 use collection::shards::replica_set::replica_set_state::ReplicaState;
 use collection::shards::shard::{PeerId, ShardId};
 use collection::shards::{CollectionId, replica_set};
-use common::budget::ResourceBudget;
+use common::budget::{ResourceBudget, ResourcePermit};
 use common::counter::hardware_accumulator::HwSharedDrain;
 use common::cpu::get_num_cpus;
+use common::save_on_disk::SaveOnDisk;
 use dashmap::DashMap;
 use fs_err as fs;
 use fs_err::tokio as tokio_fs;
@@ -55,6 +58,7 @@ use crate::types::StorageConfig;
 pub const ALIASES_PATH: &str = "aliases";
 pub const COLLECTIONS_DIR: &str = "collections";
 pub const FULL_SNAPSHOT_FILE_NAME: &str = "full-snapshot";
+pub const STANDBY_FILE: &str = "standby.json";
 
 /// How long to wait till deleted collection is released from previous operations
 pub const COLLECTION_DELETE_WAIT_TIMEOUT: Duration = Duration::from_secs(60 * 10); // 10 mins
@@ -68,12 +72,20 @@ pub struct TableOfContent {
     collections: Arc<RwLock<Collections>>,
     pub(crate) storage_config: Arc<StorageConfig>,
     search_runtime: Runtime,
+    /// Search runtimes of high-priority collections, which don't share `search_runtime`
+    dedicated_search_runtimes: HashMap<CollectionId, Runtime>,
     update_runtime: Runtime,
     general_runtime: Runtime,
     /// Global CPU budget in number of cores for all optimization tasks.
     /// Assigns CPU permits to tasks to limit overall resource utilization.
     optimizer_resource_budget: ResourceBudget,
+    /// Optimizer resources taken away from the budget while preparing for a restart.
+    /// If set, no new optimizations are started on this peer.
+    paused_optimizer_permit: parking_lot::Mutex<Option<ResourcePermit>>,
     alias_persistence: RwLock<AliasPersistence>,
+    /// Cold standby mode: this peer keeps its replicas in sync as listeners,
+    /// but refuses client read and write requests until promoted.
+    standby: SaveOnDisk<bool>,
     pub this_peer_id: PeerId,
     channel_service: ChannelService,
     /// Backlink to the consensus, if none - single node mode
@@ -107,7 +119,7 @@ impl TableOfContent {
         channel_service: ChannelService,
         this_peer_id: PeerId,
         consensus_proposal_sender: Option<OperationSender>,
-    ) -> Self {
+    ) -> Result<Self, StorageError> {
         let collections_path = storage_config.storage_path.join(COLLECTIONS_DIR);
         fs::create_dir_all(&collections_path).expect("Can't create Collections directory");
         if let Some(path) = storage_config.temp_path.as_deref() {
@@ -116,6 +128,8 @@ impl TableOfContent {
         let collection_paths =
             fs::read_dir(&collections_path).expect("Can't read Collections directory");
         let is_distributed = consensus_proposal_sender.is_some();
+        let dedicated_search_runtimes =
+            create_dedicated_search_runtimes(&storage_config.performance.dedicated_search_threads)?;
 
         // Collect valid collection paths for loading
         let mut collection_load_tasks = Vec::new();
@@ -145,7 +159,13 @@ impl TableOfContent {
             let consensus_proposal_sender = consensus_proposal_sender.clone();
             let channel_service = channel_service.clone();
             let storage_config = storage_config.clone();
-            let search_runtime_handle = search_runtime.handle().clone();
+            let search_runtime_handle = select_search_runtime(
+                &dedicated_search_runtimes,
+                &search_runtime,
+                &collection_name,
+            )
+            .handle()
+            .clone();
             let update_runtime_handle = update_runtime.handle().clone();
             let optimizer_resource_budget = optimizer_resource_budget.clone();
 
@@ -209,6 +229,13 @@ impl TableOfContent {
         let alias_persistence = AliasPersistence::open(&alias_path)
             .expect("Can't open database by the provided config");
 
+        let standby =
+            SaveOnDisk::load_or_init_default(storage_config.storage_path.join(STANDBY_FILE))
+                .expect("Can't read standby state");
+        if *standby.read() {
+            log::info!("Node is in standby mode, client requests are refused until promoted");
+        }
+
         let rate_limiter = match storage_config.performance.update_rate_limit {
             Some(limit) => Some(Semaphore::new(limit)),
             None => {
@@ -226,14 +253,17 @@ impl TableOfContent {
             }
         };
 
-        TableOfContent {
+        Ok(TableOfContent {
             collections: Arc::new(RwLock::new(collections)),
             storage_config: Arc::new(storage_config.clone()),
             search_runtime,
+            dedicated_search_runtimes,
             update_runtime,
             general_runtime,
             optimizer_resource_budget,
+            paused_optimizer_permit: Default::default(),
             alias_persistence: RwLock::new(alias_persistence),
+            standby,
             this_peer_id,
             channel_service,
             consensus_proposal_sender,
@@ -242,7 +272,7 @@ impl TableOfContent {
             collection_create_lock: Default::default(),
             collection_hw_metrics: DashMap::new(),
             telemetry,
-        }
+        })
     }
 
     /// Return `true` if service is working in distributed mode.
@@ -410,12 +440,12 @@ impl TableOfContent {
         Ok(aliases)
     }
 
-    pub fn suggest_shard_distribution(
+    pub async fn suggest_shard_distribution(
         &self,
         op: &CreateCollectionOperation,
         collection_defaults: Option<&CollectionConfigDefaults>,
         number_of_peers: usize,
-    ) -> ShardDistributionProposal {
+    ) -> Result<ShardDistributionProposal, StorageError> {
         let non_zero_number_of_peers =
             NonZeroU32::new(number_of_peers as u32).expect("NUmber of peers must be at least 1");
 
@@ -438,7 +468,11 @@ impl TableOfContent {
             .copied()
             .collect();
         known_peers_set.insert(self.this_peer_id());
-        let known_peers: Vec<_> = known_peers_set.into_iter().collect();
+        let mut known_peers: Vec<_> = known_peers_set.into_iter().collect();
+
+        if let Some(placement) = &op.create_collection.placement {
+            known_peers = self.filter_placement_peers(known_peers, placement).await?;
+        }
 
         let suggested_replication_factor = collection_defaults
             .and_then(|cd| cd.replication_factor)
@@ -461,7 +495,7 @@ impl TableOfContent {
             known_peers.len(),
             shard_distribution.distribution
         );
-        shard_distribution
+        Ok(shard_distribution)
     }
 
     /// Initiate receiving shard.
@@ -492,6 +526,94 @@ impl TableOfContent {
         Ok(())
     }
 
+    /// Ask consensus to hand over Raft leadership to the given peer.
+    ///
+    /// Only has effect if this peer is currently the leader.
+    pub fn transfer_leader(&self, peer_id: PeerId) -> Result<(), StorageError> {
+        self.get_consensus_proposal_sender()?
+            .send(ConsensusOperations::transfer_leader(peer_id))?;
+
+        Ok(())
+    }
+
+    /// Take over optimizer resource budget, so that no new optimizations are started on this peer.
+    ///
+    /// Optimizations which are already running keep their resources. Whatever they release is
+    /// taken over on subsequent calls.
+    ///
+    /// Returns `true` once the whole budget is held, meaning no optimization is running anymore.
+    pub fn pause_optimizers(&self) -> bool {
+        let mut paused_permit = self.paused_optimizer_permit.lock();
+
+        if let Some(permit) = self.optimizer_resource_budget.try_acquire_available() {
+            match paused_permit.as_mut() {
+                Some(paused_permit) => paused_permit.merge(permit),
+                None => *paused_permit = Some(permit),
+            }
+        }
+
+        paused_permit.as_ref().is_some_and(|permit| {
+            permit.num_cpus as usize >= self.optimizer_resource_budget.available_cpu_budget()
+                && permit.num_io as usize >= self.optimizer_resource_budget.available_io_budget()
+        })
+    }
+
+    /// Give resources taken by [`Self::pause_optimizers`] back to the optimizer budget.
+    pub fn resume_optimizers(&self) {
+        // Dropping the permit releases resources back into the budget
+        self.paused_optimizer_permit.lock().take();
+    }
+
+    /// Whether this peer is a cold standby, refusing client requests
+    pub fn is_standby(&self) -> bool {
+        *self.standby.read()
+    }
+
+    /// Switch cold standby mode of this peer.
+    ///
+    /// Replicas are converted from and to listeners on the next local state sync with consensus.
+    pub fn set_standby(&self, standby: bool) -> Result<(), StorageError> {
+        if !self.is_distributed() {
+            return Err(StorageError::bad_request(
+                "Standby mode is only available in distributed mode",
+            ));
+        }
+
+        self.standby
+            .write(|current| *current = standby)
+            .map_err(|err| {
+                StorageError::service_error(format!("Failed to persist standby state: {err}"))
+            })?;
+
+        if standby {
+            log::info!("Node switched to standby mode, client requests are refused");
+        } else {
+            log::info!("Node promoted from standby mode, serving client requests");
+        }
+
+        Ok(())
+    }
+
+    /// Refuse client requests to this peer if it is a cold standby
+    pub fn check_not_standby(&self) -> Result<(), StorageError> {
+        if self.is_standby() {
+            return Err(StorageError::node_standby(format!(
+                "peer {} only replicates data and does not serve requests until promoted",
+                self.this_peer_id,
+            )));
+        }
+        Ok(())
+    }
+
+    /// Apply queued updates and flush WAL and segments of all local shards to disk.
+    pub async fn flush_all_local_shards(&self) -> Result<(), StorageError> {
+        let collections: Vec<_> = self.collections.read().await.values().cloned().collect();
+        for collection in collections {
+            collection.flush_local_shards().await?;
+        }
+        Ok(())
+    }
+
     pub async fn update_cluster_metadata(
         &self,
         key: String,
@@ -753,4 +875,164 @@ impl TableOfContent {
     pub fn general_runtime_handle(&self) -> &Handle {
         self.general_runtime.handle()
     }
+
+    /// Runtime to run searches of the given collection on
+    fn search_runtime_handle(&self, collection_name: &str) -> Handle {
+        select_search_runtime(
+            &self.dedicated_search_runtimes,
+            &self.search_runtime,
+            collection_name,
+        )
+        .handle()
+        .clone()
+    }
+}
+
+/// Dedicated runtime of the collection, if configured, or the shared search runtime otherwise
+fn select_search_runtime<'a>(
+    dedicated_search_runtimes: &'a HashMap<CollectionId, Runtime>,
+    search_runtime: &'a Runtime,
+    collection_name: &str,
+) -> &'a Runtime {
+    dedicated_search_runtimes
+        .get(collection_name)
+        .unwrap_or(search_runtime)
+}
+
+fn create_dedicated_search_runtimes(
+    dedicated_search_threads: &HashMap<CollectionId, NonZeroUsize>,
+) -> Result<HashMap<CollectionId, Runtime>, StorageError> {
+    dedicated_search_threads
+        .iter()
+        .map(|(collection_name, num_threads)| {
+            log::info!(
+                "Using {num_threads} dedicated search threads for collection {collection_name}",
+            );
+
+            let thread_name_prefix = format!("search-{collection_name}");
+            // Threads are numbered per runtime, so names don't depend on other collections
+            let next_thread_id = AtomicUsize::new(0);
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(num_threads.get())
+                .max_blocking_threads(num_threads.get())
+                .enable_all()
+                .thread_name_fn(move || {
+                    let id = next_thread_id.fetch_add(1, Ordering::Relaxed);
+                    format!("{thread_name_prefix}-{id}")
+                })
+                .on_thread_start(common::search_concurrency::exempt_current_thread)
+                .build()
+                .map_err(|err| {
+                    StorageError::service_error(format!(
+                        "Can't create dedicated search runtime for collection {collection_name}: {err}",
+                    ))
+                })?;
+
+            Ok((collection_name.clone(), runtime))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use collection::optimizers_builder::OptimizersConfig;
+    use common::load_concurrency::LoadConcurrencyConfig;
+    use memory::madvise;
+
+    use super::*;
+    use crate::types::PerformanceConfig;
+
+    #[test]
+    fn test_dedicated_search_runtime() {
+        let storage_dir = tempfile::Builder::new()
+            .prefix("storage")
+            .tempdir()
+            .unwrap();
+
+        let config = StorageConfig {
+            storage_path: storage_dir.path().to_path_buf(),
+            snapshots_path: storage_dir.path().join("snapshots"),
+            snapshots_config: Default::default(),
+            temp_path: None,
+            on_disk_payload: false,
+            optimizers: OptimizersConfig {
+                deleted_threshold: 0.5,
+                vacuum_min_vector_number: 100,
+                default_segment_number: 2,
+                max_segment_size: None,
+                #[expect(deprecated)]
+                memmap_threshold: None,
+                indexing_threshold: None,
+                flush_interval_sec: 2,
+                max_optimization_threads: Some(1),
+                prevent_unoptimized: None,
+                max_segment_number: None,
+            },
+            optimizers_overwrite: None,
+            wal: Default::default(),
+            performance: PerformanceConfig {
+                max_search_threads: 1,
+                max_optimization_runtime_threads: 1,
+                optimizer_cpu_budget: 0,
+                optimizer_io_budget: 0,
+                update_rate_limit: None,
+                search_timeout_sec: None,
+                incoming_shard_transfers_limit: None,
+                outgoing_shard_transfers_limit: None,
+                async_scorer: None,
+                load_concurrency: LoadConcurrencyConfig::default(),
+                dedicated_search_threads: HashMap::from([(
+                    "dedicated".to_string(),
+                    NonZeroUsize::new(1).unwrap(),
+                )]),
+                adaptive_search_concurrency: false,
+                coalesce_identical_queries: false,
+            },
+            hnsw_index: Default::default(),
+            hnsw_global_config: Default::default(),
+            mmap_advice: madvise::Advice::Random,
+            node_type: Default::default(),
+            update_queue_size: Default::default(),
+            handle_collection_load_errors: false,
+            recovery_mode: None,
+            update_concurrency: None,
+            shard_transfer_method: None,
+            collection: None,
+            max_collections: None,
+            collection_quota: Default::default(),
+            score_drift: Default::default(),
+            wal_backend: Default::default(),
+        };
+
+        let toc = TableOfContent::new(
+            &config,
+            Runtime::new().unwrap(),
+            Runtime::new().unwrap(),
+            Runtime::new().unwrap(),
+            ResourceBudget::default(),
+            ChannelService::new(6333, false, None, None),
+            0,
+            None,
+        )
+        .unwrap();
+
+        // Name of the thread, the search of the collection runs on
+        let search_thread_name = |collection_name: &str| {
+            let task = toc
+                .search_runtime_handle(collection_name)
+                .spawn(async { std::thread::current().name().map(ToString::to_string) });
+            futures::executor::block_on(task)
+                .unwrap()
+                .unwrap_or_default()
+        };
+
+        let dedicated_thread = search_thread_name("dedicated");
+        assert!(
+            dedicated_thread.starts_with("search-dedicated-"),
+            "{dedicated_thread}",
+        );
+
+        let shared_thread = search_thread_name("other");
+        assert!(!shared_thread.starts_with("search-"), "{shared_thread}");
+    }
 }
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use collection::config::PlacementConstraints;
+use collection::shards::shard::PeerId;
+
+use super::TableOfContent;
+use crate::content_manager::errors::StorageError;
+
+impl TableOfContent {
+    /// Select the peers satisfying the placement constraints
+    ///
+    /// Fails if no peer satisfies them, so shards are never placed in violation of the constraints.
+    pub async fn filter_placement_peers(
+        &self,
+        peers: Vec<PeerId>,
+        constraints: &PlacementConstraints,
+    ) -> Result<Vec<PeerId>, StorageError> {
+        let excluded_peers = self.anti_affinity_peers(&constraints.anti_affinity).await;
+
+        let allowed_peers: Vec<_> = peers
+            .into_iter()
+            .filter(|peer_id| {
+                !excluded_peers.contains(peer_id)
+                    && self
+                        .channel_service
+                        .peer_matches_labels(*peer_id, constraints)
+            })
+            .collect();
+
+        if allowed_peers.is_empty() {
+            return Err(StorageError::bad_request(format!(
+                "No peer satisfies placement constraints {constraints:?}",
+            )));
+        }
+
+        Ok(allowed_peers)
+    }
+
+    /// Peers holding replicas of any of the given collections
+    ///
+    /// Collections that don't exist hold no replicas, so they are ignored.
+    async fn anti_affinity_peers(&self, collection_names: &[String]) -> HashSet<PeerId> {
+        let mut peers = HashSet::new();
+
+        for collection_name in collection_names {
+            let Ok(collection) = self.get_collection_unchecked(collection_name).await else {
+                continue;
+            };
+
+            for shard_info in collection.state().await.shards.into_values() {
+                peers.extend(shard_info.replicas.into_keys());
+            }
+        }
+
+        peers
+    }
+}
@@ -1,32 +1,48 @@
 use std::time::Duration;
 
 use collection::collection::Collection;
+use collection::collection::deduplication::{CollectionDeduplicationRequest, DeduplicationStatus};
 use collection::collection::distance_matrix::{
     CollectionSearchMatrixRequest, CollectionSearchMatrixResponse,
 };
+use collection::collection::facet::CollectionFacetRangesRequest;
+use collection::collection::payload_index_compaction::PayloadIndexCompaction;
+use collection::collection::payload_migration::PayloadMigrationStatus;
+use collection::collection::quantiles::CollectionQuantilesRequest;
+use collection::collection::term_stats::CollectionTermStatsRequest;
+use collection::collection::vector_stats::CollectionVectorStatsRequest;
 use collection::config::ShardingMethod;
 use collection::grouping::GroupBy;
 use collection::grouping::group_by::GroupRequest;
 use collection::operations::consistency_params::ReadConsistency;
-use collection::operations::point_ops::WriteOrdering;
+use collection::operations::point_ops::{ReturnIds, WriteOrdering};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::*;
 use collection::operations::universal_query::collection_query::CollectionQueryRequest;
 use collection::operations::{CollectionUpdateOperations, OperationWithClockTag};
+use collection::shards::shard::ShardId;
 use collection::{discovery, recommendations};
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::TryStreamExt as _;
 use futures::stream::FuturesUnordered;
-use segment::data_types::facets::{FacetParams, FacetResponse};
-use segment::types::{ScoredPoint, ShardKey};
+use segment::data_types::facets::{FacetParams, FacetRangeHit, FacetResponse};
+use segment::data_types::filter_explanation::FilterExplanation;
+use segment::data_types::quantiles::Quantiles;
+use segment::data_types::term_stats::TermStats;
+use segment::data_types::vector_stats::VectorStats;
+use segment::index::field_index::full_text_index::highlight::{
+    HighlightParams, PayloadHighlighter,
+};
+use segment::types::{Filter, ScoredPoint, ShardKey, VectorNameBuf};
 use shard::retrieve::record_internal::RecordInternal;
 use shard::scroll::ScrollRequestInternal;
 use shard::search::CoreSearchRequestBatch;
+use uuid::Uuid;
 
 use super::TableOfContent;
 use crate::content_manager::errors::{StorageError, StorageResult};
-use crate::rbac::Auth;
 use crate::rbac::auditable_operation::AuditableOperation;
+use crate::rbac::{AccessRequirements, Auth};
 
 impl TableOfContent {
     /// Recommend points using positive and negative example from the request
@@ -43,14 +59,14 @@ impl TableOfContent {
     pub async fn recommend(
         &self,
         collection_name: &str,
-        request: RecommendRequestInternal,
+        mut request: RecommendRequestInternal,
         read_consistency: Option<ReadConsistency>,
         shard_selector: ShardSelectorInternal,
         auth: Auth,
         timeout: Option<Duration>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> StorageResult<Vec<ScoredPoint>> {
-        let collection_pass = auth.check_point_op(collection_name, &request, "recommend")?;
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "recommend")?;
 
         let collection = self.get_collection(&collection_pass).await?;
         recommendations::recommend_by(
@@ -170,14 +186,14 @@ impl TableOfContent {
     pub async fn count(
         &self,
         collection_name: &str,
-        request: CountRequestInternal,
+        mut request: CountRequestInternal,
         read_consistency: Option<ReadConsistency>,
         timeout: Option<Duration>,
         shard_selection: ShardSelectorInternal,
         auth: Auth,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> StorageResult<CountResult> {
-        let collection_pass = auth.check_point_op(collection_name, &request, "count")?;
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "count")?;
 
         let collection = self.get_collection(&collection_pass).await?;
         collection
@@ -207,14 +223,14 @@ impl TableOfContent {
     pub async fn retrieve(
         &self,
         collection_name: &str,
-        request: PointRequestInternal,
+        mut request: PointRequestInternal,
         read_consistency: Option<ReadConsistency>,
         timeout: Option<Duration>,
         shard_selection: ShardSelectorInternal,
         auth: Auth,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> StorageResult<Vec<RecordInternal>> {
-        let collection_pass = auth.check_point_op(collection_name, &request, "retrieve")?;
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "retrieve")?;
 
         let collection = self.get_collection(&collection_pass).await?;
         collection
@@ -233,14 +249,14 @@ impl TableOfContent {
     pub async fn group(
         &self,
         collection_name: &str,
-        request: GroupRequest,
+        mut request: GroupRequest,
         read_consistency: Option<ReadConsistency>,
         shard_selection: ShardSelectorInternal,
         auth: Auth,
         timeout: Option<Duration>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> StorageResult<GroupsResult> {
-        let collection_pass = auth.check_point_op(collection_name, &request, "group")?;
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "group")?;
 
         let collection = self.get_collection(&collection_pass).await?;
 
@@ -262,14 +278,14 @@ impl TableOfContent {
     pub async fn discover(
         &self,
         collection_name: &str,
-        request: DiscoverRequestInternal,
+        mut request: DiscoverRequestInternal,
         read_consistency: Option<ReadConsistency>,
         shard_selector: ShardSelectorInternal,
         auth: Auth,
         timeout: Option<Duration>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> StorageResult<Vec<ScoredPoint>> {
-        let collection_pass = auth.check_point_op(collection_name, &request, "discover")?;
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "discover")?;
 
         let collection = self.get_collection(&collection_pass).await?;
         discovery::discover(
@@ -332,14 +348,14 @@ impl TableOfContent {
     pub async fn scroll(
         &self,
         collection_name: &str,
-        request: ScrollRequestInternal,
+        mut request: ScrollRequestInternal,
         read_consistency: Option<ReadConsistency>,
         timeout: Option<Duration>,
         shard_selection: ShardSelectorInternal,
         auth: Auth,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> StorageResult<ScrollResult> {
-        let collection_pass = auth.check_point_op(collection_name, &request, "scroll")?;
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "scroll")?;
 
         let collection = self.get_collection(&collection_pass).await?;
         collection
@@ -391,14 +407,14 @@ impl TableOfContent {
     pub async fn facet(
         &self,
         collection_name: &str,
-        request: FacetParams,
+        mut request: FacetParams,
         shard_selection: ShardSelectorInternal,
         read_consistency: Option<ReadConsistency>,
         auth: Auth,
         timeout: Option<Duration>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> StorageResult<FacetResponse> {
-        let collection_pass = auth.check_point_op(collection_name, &request, "facet")?;
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "facet")?;
 
         let collection = self.get_collection(&collection_pass).await?;
 
@@ -414,11 +430,39 @@ impl TableOfContent {
             .map_err(StorageError::from)
     }
 
+    /// Count points with values of a numeric or datetime payload key in each of the ranges
+    #[allow(clippy::too_many_arguments)]
+    pub async fn facet_ranges(
+        &self,
+        collection_name: &str,
+        mut request: CollectionFacetRangesRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<Vec<FacetRangeHit>> {
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "facet_ranges")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        collection
+            .facet_ranges(
+                request,
+                &shard_selection,
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn search_points_matrix(
         &self,
         collection_name: &str,
-        request: CollectionSearchMatrixRequest,
+        mut request: CollectionSearchMatrixRequest,
         read_consistency: Option<ReadConsistency>,
         shard_selection: ShardSelectorInternal,
         auth: Auth,
@@ -426,7 +470,7 @@ impl TableOfContent {
         hw_measurement_acc: HwMeasurementAcc,
     ) -> Result<CollectionSearchMatrixResponse, StorageError> {
         let collection_pass =
-            auth.check_point_op(collection_name, &request, "search_points_matrix")?;
+            auth.check_point_op(collection_name, &mut request, "search_points_matrix")?;
 
         let collection = self.get_collection(&collection_pass).await?;
 
@@ -442,6 +486,119 @@ impl TableOfContent {
             .map_err(StorageError::from)
     }
 
+    /// Statistics over a random sample of vectors of the collection, stored on this peer
+    pub async fn vector_stats(
+        &self,
+        collection_name: &str,
+        mut request: CollectionVectorStatsRequest,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<VectorStats> {
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "vector_stats")?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .vector_stats(request, timeout, hw_measurement_acc)
+            .await
+            .map_err(StorageError::from)
+    }
+
+    /// Estimate quantiles of a numeric payload field of the collection, stored on this peer
+    pub async fn quantiles(
+        &self,
+        collection_name: &str,
+        mut request: CollectionQuantilesRequest,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<Quantiles> {
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "quantiles")?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .quantiles(request, timeout, hw_measurement_acc)
+            .await
+            .map_err(StorageError::from)
+    }
+
+    /// Explain how the filter is executed in segments of the collection, stored on this peer
+    pub async fn explain_filter(
+        &self,
+        collection_name: &str,
+        mut filter: Filter,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<FilterExplanation> {
+        let collection_pass =
+            auth.check_point_op(collection_name, &mut filter, "explain_filter")?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .explain_filter(filter, timeout, hw_measurement_acc)
+            .await
+            .map_err(StorageError::from)
+    }
+
+    /// HNSW graph of the vector in a segment of the collection, stored on this peer
+    pub async fn export_hnsw_graph(
+        &self,
+        collection_name: &str,
+        shard_id: ShardId,
+        segment_id: Uuid,
+        vector_name: VectorNameBuf,
+        auth: Auth,
+        timeout: Option<Duration>,
+    ) -> StorageResult<Vec<u8>> {
+        let collection_pass = auth.check_collection_access(
+            collection_name,
+            AccessRequirements::new().manage(),
+            "export_hnsw_graph",
+        )?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .export_hnsw_graph(shard_id, segment_id, vector_name, timeout)
+            .await
+            .map_err(StorageError::from)
+    }
+
+    /// Term statistics of a full-text payload field of the collection, stored on this peer
+    pub async fn term_stats(
+        &self,
+        collection_name: &str,
+        mut request: CollectionTermStatsRequest,
+        auth: Auth,
+        timeout: Option<Duration>,
+    ) -> StorageResult<TermStats> {
+        let collection_pass = auth.check_point_op(collection_name, &mut request, "term_stats")?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .term_stats(request, timeout)
+            .await
+            .map_err(StorageError::from)
+    }
+
+    /// Highlighter of full-text matches of the filter in payloads of the collection
+    pub async fn payload_highlighter(
+        &self,
+        collection_name: &str,
+        filter: &Filter,
+        params: HighlightParams,
+        auth: Auth,
+    ) -> StorageResult<PayloadHighlighter> {
+        let mut filter = filter.clone();
+        let collection_pass =
+            auth.check_point_op(collection_name, &mut filter, "payload_highlighter")?;
+
+        Ok(self
+            .get_collection(&collection_pass)
+            .await?
+            .payload_highlighter(&filter, params))
+    }
+
     /// # Cancel safety
     ///
     /// This method is cancel safe.
@@ -452,6 +609,7 @@ impl TableOfContent {
         collection: &Collection,
         shard_keys: Vec<ShardKey>,
         operation: CollectionUpdateOperations,
+        return_ids: Option<ReturnIds>,
         wait: bool,
         timeout: Option<Duration>,
         ordering: WriteOrdering,
@@ -464,6 +622,7 @@ impl TableOfContent {
             .map(|shard_key| {
                 collection.update_from_client(
                     operation.clone(),
+                    return_ids.clone(),
                     wait,
                     timeout,
                     ordering,
@@ -476,10 +635,22 @@ impl TableOfContent {
         // `Collection::update_from_client` is cancel safe, so it's safe to use `TryStreamExt::try_collect`
         let results: Vec<_> = updates.try_collect().await?;
 
-        results
-            .into_iter()
+        let mut results = results.into_iter();
+        let mut result = results
             .next()
-            .ok_or_else(|| StorageError::bad_input("Empty shard keys selection"))
+            .ok_or_else(|| StorageError::bad_input("Empty shard keys selection"))?;
+
+        // Each shard key holds different points, so affected ids of all of them are collected
+        for other in results {
+            if let Some(affected_ids) = other.affected_ids {
+                result
+                    .affected_ids
+                    .get_or_insert_default()
+                    .extend(affected_ids);
+            }
+        }
+
+        Ok(result)
     }
 
     /// # Cancel safety
@@ -489,7 +660,7 @@ impl TableOfContent {
     pub async fn update(
         &self,
         collection_name: &str,
-        operation: OperationWithClockTag,
+        mut operation: OperationWithClockTag,
         wait: bool,
         timeout: Option<Duration>,
         ordering: WriteOrdering,
@@ -497,11 +668,9 @@ impl TableOfContent {
         auth: Auth,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> StorageResult<UpdateResult> {
-        let collection_pass = auth.check_point_op(
-            collection_name,
-            &operation.operation,
-            operation.operation.operation_name(),
-        )?;
+        let method = operation.operation.operation_name();
+        let collection_pass =
+            auth.check_point_op(collection_name, &mut operation.operation, method)?;
 
         // `TableOfContent::_update_shard_keys` and `Collection::update_from_*` are cancel safe,
         // so this method is cancel safe.
@@ -555,6 +724,7 @@ impl TableOfContent {
                 collection
                     .update_from_client(
                         operation.operation,
+                        operation.return_ids,
                         wait,
                         timeout,
                         ordering,
@@ -575,12 +745,14 @@ impl TableOfContent {
                                 operation_id: None,
                                 status: UpdateStatus::Acknowledged,
                                 clock_tag: operation.clock_tag,
+                                affected_ids: None,
                             });
                         }
                         ShardingMethod::Auto => {
                             collection
                                 .update_from_client(
                                     operation.operation,
+                                    operation.return_ids,
                                     wait,
                                     timeout,
                                     ordering,
@@ -595,6 +767,7 @@ impl TableOfContent {
                         &collection,
                         shard_keys,
                         operation.operation,
+                        operation.return_ids,
                         wait,
                         timeout,
                         ordering,
@@ -608,6 +781,7 @@ impl TableOfContent {
                 collection
                     .update_from_client(
                         operation.operation,
+                        operation.return_ids,
                         wait,
                         timeout,
                         ordering,
@@ -622,6 +796,7 @@ impl TableOfContent {
                     &collection,
                     shard_keys,
                     operation.operation,
+                    operation.return_ids,
                     wait,
                     timeout,
                     ordering,
@@ -644,6 +819,7 @@ impl TableOfContent {
                     &collection,
                     shard_keys,
                     operation.operation,
+                    operation.return_ids,
                     wait,
                     timeout,
                     ordering,
@@ -667,4 +843,127 @@ impl TableOfContent {
 
         Ok(res)
     }
+
+    /// Start a background search for near-duplicate points in the collection
+    pub async fn start_deduplication(
+        &self,
+        collection_name: &str,
+        request: CollectionDeduplicationRequest,
+        auth: Auth,
+    ) -> StorageResult<DeduplicationStatus> {
+        let collection_pass = auth.check_collection_access(
+            collection_name,
+            AccessRequirements::new().manage(),
+            "start_deduplication",
+        )?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .start_deduplication(request)
+            .map_err(Into::into)
+    }
+
+    /// Status of the last started deduplication in the collection
+    pub async fn deduplication_status(
+        &self,
+        collection_name: &str,
+        auth: Auth,
+    ) -> StorageResult<DeduplicationStatus> {
+        let collection_pass = auth.check_collection_access(
+            collection_name,
+            AccessRequirements::new().manage(),
+            "deduplication_status",
+        )?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .deduplication_status()
+            .ok_or_else(|| StorageError::NotFound {
+                description: format!(
+                    "No deduplication was started in collection `{collection_name}`"
+                ),
+            })
+    }
+
+    /// Cancel the running deduplication in the collection, if any
+    pub async fn cancel_deduplication(
+        &self,
+        collection_name: &str,
+        auth: Auth,
+    ) -> StorageResult<()> {
+        let collection_pass = auth.check_collection_access(
+            collection_name,
+            AccessRequirements::new().manage(),
+            "cancel_deduplication",
+        )?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .cancel_deduplication()
+            .await;
+        Ok(())
+    }
+
+    /// Status of the last started payload migration in the collection
+    pub async fn payload_migration_status(
+        &self,
+        collection_name: &str,
+        auth: Auth,
+    ) -> StorageResult<PayloadMigrationStatus> {
+        let collection_pass = auth.check_collection_access(
+            collection_name,
+            AccessRequirements::new().manage(),
+            "payload_migration_status",
+        )?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .payload_migration_status()
+            .ok_or_else(|| StorageError::NotFound {
+                description: format!(
+                    "No payload migration was started in collection `{collection_name}`"
+                ),
+            })
+    }
+
+    /// Cancel the running payload migration in the collection, if any.
+    ///
+    /// Already rewritten points are kept as they are.
+    pub async fn cancel_payload_migration(
+        &self,
+        collection_name: &str,
+        auth: Auth,
+    ) -> StorageResult<()> {
+        let collection_pass = auth.check_collection_access(
+            collection_name,
+            AccessRequirements::new().manage(),
+            "cancel_payload_migration",
+        )?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .cancel_payload_migration()
+            .await;
+        Ok(())
+    }
+
+    /// Compact fragmented payload index storages of the collection, stored on this peer
+    pub async fn compact_payload_indexes(
+        &self,
+        collection_name: &str,
+        min_fragmentation_ratio: f64,
+        auth: Auth,
+    ) -> StorageResult<PayloadIndexCompaction> {
+        let collection_pass = auth.check_collection_access(
+            collection_name,
+            AccessRequirements::new().manage(),
+            "compact_payload_indexes",
+        )?;
+
+        self.get_collection(&collection_pass)
+            .await?
+            .compact_payload_indexes(min_fragmentation_ratio)
+            .await
+            .map_err(StorageError::from)
+    }
 }
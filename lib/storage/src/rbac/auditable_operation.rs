@@ -21,6 +21,7 @@ impl AuditableOperation for CollectionUpdateOperations {
             },
             CollectionUpdateOperations::VectorOperation(op) => match op {
                 VectorOperations::UpdateVectors(_) => "update_vectors",
+                VectorOperations::AppendVectors(_) => "append_vectors",
                 VectorOperations::DeleteVectors(_, _) => "delete_vectors",
                 VectorOperations::DeleteVectorsByFilter(_, _) => "delete_vectors_by_filter",
             },
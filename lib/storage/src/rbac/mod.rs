@@ -13,6 +13,12 @@ mod ops_checks;
 
 pub use auth::Auth;
 
+/// Payload key with the access tags of a point.
+///
+/// Collection access restricted to a set of tags only sees and modifies points that have at least
+/// one of the allowed tags under this key.
+pub const ACCESS_TAGS_KEY: &str = "access_tags";
+
 /// How the request was authenticated.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 pub enum AuthType {
@@ -59,6 +65,13 @@ pub struct CollectionAccess {
     #[deprecated(since = "1.15.0")]
     #[validate(custom(function = "validate_payload_empty"))]
     pub payload: Option<Value>, // Value is a placeholder for a now removed type
+
+    /// Access tags of the points that are allowed to be accessed.
+    /// If set, reads only return points tagged with any of these tags under the
+    /// [`ACCESS_TAGS_KEY`] payload key, and writes may only assign these tags.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    pub tags: Option<Vec<String>>,
 }
 
 fn validate_payload_empty(_payload: &Value) -> Result<(), ValidationError> {
@@ -76,6 +89,7 @@ impl CollectionAccess {
         CollectionAccessView {
             collection: &self.collection,
             access: self.access,
+            tags: self.tags.as_deref(),
         }
     }
 }
@@ -177,10 +191,11 @@ impl CollectionAccessList {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 struct CollectionAccessView<'a> {
     pub collection: &'a str,
     pub access: CollectionAccessMode,
+    pub tags: Option<&'a [String]>,
 }
 
 impl CollectionAccessView<'_> {
@@ -373,9 +388,18 @@ impl AccessCollectionBuilder {
             },
             #[expect(deprecated)]
             payload: None,
+            tags: None,
         });
         self
     }
+
+    pub(self) fn add_tagged(mut self, name: &str, write: bool, tags: &[&str]) -> Self {
+        self = self.add(name, write);
+        if let Some(access) = self.0.last_mut() {
+            access.tags = Some(tags.iter().map(|tag| tag.to_string()).collect());
+        }
+        self
+    }
 }
 
 #[cfg(test)]
@@ -1,31 +1,49 @@
 use std::borrow::Cow;
+use std::mem;
 
+use ahash::AHashSet;
 use api::rest::{LookupLocation, SearchRequestInternal};
 use collection::collection::distance_matrix::CollectionSearchMatrixRequest;
+use collection::collection::facet::CollectionFacetRangesRequest;
+use collection::collection::quantiles::CollectionQuantilesRequest;
+use collection::collection::term_stats::CollectionTermStatsRequest;
+use collection::collection::vector_stats::CollectionVectorStatsRequest;
 use collection::grouping::group_by::{GroupRequest, SourceRequest};
 use collection::lookup::WithLookup;
 use collection::operations::CollectionUpdateOperations;
+use collection::operations::point_ops::{
+    ConditionalInsertOperationInternal, PointInsertOperationsInternal, PointOperations,
+};
 use collection::operations::types::{
     CoreSearchRequest, CountRequestInternal, DiscoverRequestInternal, PointRequestInternal,
-    RecommendRequestInternal,
+    RecommendExample, RecommendRequestInternal,
 };
 use collection::operations::universal_query::collection_query::{
-    CollectionPrefetch, CollectionQueryRequest,
+    CollectionPrefetch, CollectionQueryRequest, Query,
 };
+use collection::operations::vector_ops::VectorOperations;
 use segment::data_types::facets::FacetParams;
+use segment::json_path::JsonPath;
+use segment::types::{Condition, FieldCondition, Filter, Payload, PointIdType};
+use serde_json::Value;
+use shard::operations::payload_ops::{PayloadOps, SetPayloadOp};
 use shard::scroll::ScrollRequestInternal;
 
-use super::{Access, AccessRequirements, CollectionAccessList, CollectionPass};
+use super::{
+    ACCESS_TAGS_KEY, Access, AccessRequirements, CollectionAccessList, CollectionAccessView,
+    CollectionPass,
+};
 use crate::content_manager::collection_meta_ops::CollectionMetaOperations;
 use crate::content_manager::errors::{StorageError, StorageResult};
 use crate::rbac::auditable_operation::AuditableOperation;
 
 impl Access {
+    /// Check access to the operation, and restrict it to the points allowed by the access tags.
     #[allow(private_bounds)]
     pub(crate) fn check_point_op<'a>(
         &self,
         collection_name: &'a str,
-        op: &impl CheckableCollectionOperation,
+        op: &mut impl CheckableCollectionOperation,
     ) -> Result<CollectionPass<'a>, StorageError> {
         let requirements = op.access_requirements();
         match self {
@@ -33,7 +51,7 @@ impl Access {
             Access::Collection(list) => {
                 let view = list.find_view(collection_name)?;
                 view.meets_requirements(requirements)?;
-                op.check_access(list)?;
+                op.check_access(view, list)?;
             }
         }
         Ok(CollectionPass(Cow::Borrowed(collection_name)))
@@ -81,7 +99,13 @@ trait CheckableCollectionOperation {
     /// Used to distinguish whether the operation is read-only or read-write.
     fn access_requirements(&self) -> AccessRequirements;
 
-    fn check_access(&self, access: &CollectionAccessList) -> Result<(), StorageError>;
+    /// Check access to the collections and points referenced by the operation, and restrict it
+    /// to the points allowed by `view`.
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        access: &CollectionAccessList,
+    ) -> Result<(), StorageError>;
 }
 
 impl CollectionAccessList {
@@ -90,17 +114,147 @@ impl CollectionAccessList {
         lookup_location: &Option<LookupLocation>,
     ) -> Result<(), StorageError> {
         if let Some(lookup_location) = lookup_location {
-            self.find_view(&lookup_location.collection)?;
+            self.find_view(&lookup_location.collection)?
+                .check_whole_access()?;
         }
         Ok(())
     }
 
     fn check_with_lookup(&self, with_lookup: &Option<WithLookup>) -> Result<(), StorageError> {
         if let Some(with_lookup) = with_lookup {
-            self.find_view(&with_lookup.collection_name)?;
+            self.find_view(&with_lookup.collection_name)?
+                .check_whole_access()?;
+        }
+        Ok(())
+    }
+}
+
+impl CollectionAccessView<'_> {
+    /// Forbid operations that can't be restricted to the allowed access tags, e.g. reading points
+    /// by ID.
+    fn check_whole_access(&self) -> Result<(), StorageError> {
+        if self.tags.is_some() {
+            return Err(StorageError::forbidden(format!(
+                "Only access to tagged points is allowed for collection {}",
+                self.collection,
+            )));
+        }
+        Ok(())
+    }
+
+    /// Filter matching points with any of the allowed access tags.
+    fn tags_filter(&self) -> Option<Filter> {
+        self.tags.map(|tags| {
+            Filter::new_must(Condition::Field(FieldCondition::new_match(
+                JsonPath::new(ACCESS_TAGS_KEY),
+                tags.to_vec().into(),
+            )))
+        })
+    }
+
+    fn apply_filter(&self, filter: &mut Option<Filter>) {
+        if let Some(tags_filter) = self.tags_filter() {
+            *filter = Filter::merge_opts(filter.take(), Some(tags_filter));
+        }
+    }
+
+    fn check_example(&self, example: &RecommendExample) -> Result<(), StorageError> {
+        if example.as_point_id().is_some() {
+            self.check_whole_access()?;
+        }
+        Ok(())
+    }
+
+    fn check_query(&self, query: &Option<Query>) -> Result<(), StorageError> {
+        if query
+            .as_ref()
+            .is_some_and(|query| !query.get_referenced_ids().is_empty())
+        {
+            self.check_whole_access()?;
         }
         Ok(())
     }
+
+    /// Check that the payload assigns only allowed access tags.
+    ///
+    /// If `required` is set, the payload must assign at least one tag, so the point stays
+    /// accessible.
+    fn check_payload_tags(
+        &self,
+        payload: Option<&Payload>,
+        required: bool,
+    ) -> Result<(), StorageError> {
+        let Some(allowed) = self.tags else {
+            return Ok(());
+        };
+
+        let is_allowed = |tag: &Value| {
+            tag.as_str()
+                .is_some_and(|tag| allowed.iter().any(|allowed| allowed == tag))
+        };
+        let valid = match payload.and_then(|payload| payload.0.get(ACCESS_TAGS_KEY)) {
+            Some(Value::Array(tags)) => !tags.is_empty() && tags.iter().all(is_allowed),
+            Some(tag) => is_allowed(tag),
+            None => !required,
+        };
+
+        if !valid {
+            return Err(StorageError::forbidden(format!(
+                "Points in collection {} must be tagged with at least one of the allowed access tags under '{ACCESS_TAGS_KEY}', and no other",
+                self.collection,
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_points_tags(
+        &self,
+        points: &PointInsertOperationsInternal,
+    ) -> Result<(), StorageError> {
+        match points {
+            PointInsertOperationsInternal::PointsBatch(batch) => {
+                (0..batch.ids.len()).try_for_each(|i| {
+                    let payload = batch
+                        .payloads
+                        .as_ref()
+                        .and_then(|payloads| payloads.get(i))
+                        .and_then(Option::as_ref);
+                    self.check_payload_tags(payload, true)
+                })
+            }
+            PointInsertOperationsInternal::PointsList(points) => points
+                .iter()
+                .try_for_each(|point| self.check_payload_tags(point.payload.as_ref(), true)),
+        }
+    }
+
+    fn check_set_payload(&self, op: &SetPayloadOp, overwrite: bool) -> Result<(), StorageError> {
+        match &op.key {
+            Some(key) if key.first_key == ACCESS_TAGS_KEY => self.check_whole_access(),
+            Some(_) => Ok(()),
+            None => self.check_payload_tags(Some(&op.payload), overwrite),
+        }
+    }
+}
+
+/// Filter matching the given points.
+fn ids_filter(ids: Vec<PointIdType>) -> Filter {
+    let ids: AHashSet<_> = ids.into_iter().collect();
+    Filter::new_must(Condition::HasId(ids.into()))
+}
+
+/// Restrict points selected either by IDs or by filter to the points matching `tags_filter`.
+fn restrict_selection(
+    points: &mut Option<Vec<PointIdType>>,
+    filter: &mut Option<Filter>,
+    tags_filter: Filter,
+) {
+    // Point IDs take precedence over the filter
+    if let Some(ids) = points.take() {
+        *filter = Some(ids_filter(ids).merge_owned(tags_filter));
+    } else if let Some(selected) = filter.take() {
+        *filter = Some(selected.merge_owned(tags_filter));
+    }
 }
 
 impl CheckableCollectionOperation for SearchRequestInternal {
@@ -112,7 +266,12 @@ impl CheckableCollectionOperation for SearchRequestInternal {
         }
     }
 
-    fn check_access(&self, _access: &CollectionAccessList) -> Result<(), StorageError> {
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        view.apply_filter(&mut self.filter);
         Ok(())
     }
 }
@@ -126,7 +285,15 @@ impl CheckableCollectionOperation for RecommendRequestInternal {
         }
     }
 
-    fn check_access(&self, access: &CollectionAccessList) -> Result<(), StorageError> {
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        for example in self.positive.iter().chain(&self.negative) {
+            view.check_example(example)?;
+        }
+        view.apply_filter(&mut self.filter);
         access.check_lookup_from(&self.lookup_from)?;
         Ok(())
     }
@@ -141,8 +308,12 @@ impl CheckableCollectionOperation for PointRequestInternal {
         }
     }
 
-    fn check_access(&self, _access: &CollectionAccessList) -> Result<(), StorageError> {
-        Ok(())
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        view.check_whole_access()
     }
 }
 
@@ -155,7 +326,12 @@ impl CheckableCollectionOperation for CoreSearchRequest {
         }
     }
 
-    fn check_access(&self, _access: &CollectionAccessList) -> Result<(), StorageError> {
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        view.apply_filter(&mut self.filter);
         Ok(())
     }
 }
@@ -169,7 +345,12 @@ impl CheckableCollectionOperation for CountRequestInternal {
         }
     }
 
-    fn check_access(&self, _access: &CollectionAccessList) -> Result<(), StorageError> {
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        view.apply_filter(&mut self.filter);
         Ok(())
     }
 }
@@ -183,11 +364,15 @@ impl CheckableCollectionOperation for GroupRequest {
         }
     }
 
-    fn check_access(&self, access: &CollectionAccessList) -> Result<(), StorageError> {
-        match &self.source {
-            SourceRequest::Search(s) => s.check_access(access)?,
-            SourceRequest::Recommend(r) => r.check_access(access)?,
-            SourceRequest::Query(q) => q.check_access(access)?,
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        match &mut self.source {
+            SourceRequest::Search(s) => s.check_access(view, access)?,
+            SourceRequest::Recommend(r) => r.check_access(view, access)?,
+            SourceRequest::Query(q) => q.check_access(view, access)?,
         }
         access.check_with_lookup(&self.with_lookup)?;
         Ok(())
@@ -203,7 +388,19 @@ impl CheckableCollectionOperation for DiscoverRequestInternal {
         }
     }
 
-    fn check_access(&self, access: &CollectionAccessList) -> Result<(), StorageError> {
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        if let Some(target) = &self.target {
+            view.check_example(target)?;
+        }
+        for pair in self.context.iter().flatten() {
+            view.check_example(&pair.positive)?;
+            view.check_example(&pair.negative)?;
+        }
+        view.apply_filter(&mut self.filter);
         access.check_lookup_from(&self.lookup_from)?;
         Ok(())
     }
@@ -218,7 +415,12 @@ impl CheckableCollectionOperation for ScrollRequestInternal {
         }
     }
 
-    fn check_access(&self, _access: &CollectionAccessList) -> Result<(), StorageError> {
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        view.apply_filter(&mut self.filter);
         Ok(())
     }
 }
@@ -232,11 +434,17 @@ impl CheckableCollectionOperation for CollectionQueryRequest {
         }
     }
 
-    fn check_access(&self, access: &CollectionAccessList) -> Result<(), StorageError> {
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        view.check_query(&self.query)?;
+        view.apply_filter(&mut self.filter);
         access.check_lookup_from(&self.lookup_from)?;
 
-        for prefetch_query in self.prefetch.iter() {
-            check_access_for_prefetch(prefetch_query, access)?;
+        for prefetch_query in self.prefetch.iter_mut() {
+            check_access_for_prefetch(prefetch_query, view, access)?;
         }
 
         Ok(())
@@ -244,14 +452,17 @@ impl CheckableCollectionOperation for CollectionQueryRequest {
 }
 
 fn check_access_for_prefetch(
-    prefetch: &CollectionPrefetch,
+    prefetch: &mut CollectionPrefetch,
+    view: CollectionAccessView<'_>,
     access: &CollectionAccessList,
 ) -> Result<(), StorageError> {
+    view.check_query(&prefetch.query)?;
+    view.apply_filter(&mut prefetch.filter);
     access.check_lookup_from(&prefetch.lookup_from)?;
 
     // Recurse inner prefetches
-    for prefetch_query in prefetch.prefetch.iter() {
-        check_access_for_prefetch(prefetch_query, access)?;
+    for prefetch_query in prefetch.prefetch.iter_mut() {
+        check_access_for_prefetch(prefetch_query, view, access)?;
     }
 
     Ok(())
@@ -266,7 +477,31 @@ impl CheckableCollectionOperation for FacetParams {
         }
     }
 
-    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> StorageResult<()> {
+        view.apply_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl CheckableCollectionOperation for CollectionFacetRangesRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> StorageResult<()> {
+        view.apply_filter(&mut self.filter);
         Ok(())
     }
 }
@@ -280,7 +515,91 @@ impl CheckableCollectionOperation for CollectionSearchMatrixRequest {
         }
     }
 
-    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> StorageResult<()> {
+        view.apply_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl CheckableCollectionOperation for CollectionQuantilesRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> StorageResult<()> {
+        view.apply_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+/// Statistics over a random sample of vectors, can't be restricted to the allowed access tags
+impl CheckableCollectionOperation for CollectionVectorStatsRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> StorageResult<()> {
+        view.check_whole_access()
+    }
+}
+
+/// Statistics of the whole full-text index, can't be restricted to the allowed access tags
+impl CheckableCollectionOperation for CollectionTermStatsRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> StorageResult<()> {
+        view.check_whole_access()
+    }
+}
+
+/// Filter, which is explained or highlighted on its own
+impl CheckableCollectionOperation for Filter {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> StorageResult<()> {
+        if let Some(tags_filter) = view.tags_filter() {
+            *self = mem::take(self).merge_owned(tags_filter);
+        }
         Ok(())
     }
 }
@@ -309,7 +628,87 @@ impl CheckableCollectionOperation for CollectionUpdateOperations {
         }
     }
 
-    fn check_access(&self, _access: &CollectionAccessList) -> Result<(), StorageError> {
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        let Some(tags_filter) = view.tags_filter() else {
+            return Ok(());
+        };
+
+        match self {
+            CollectionUpdateOperations::PointOperation(op) => match op {
+                PointOperations::UpsertPoints(points) => {
+                    view.check_points_tags(points)?;
+                    // Only overwrite existing points with the allowed tags
+                    let points_op = mem::replace(
+                        points,
+                        PointInsertOperationsInternal::PointsList(Vec::new()),
+                    );
+                    *op = PointOperations::UpsertPointsConditional(
+                        ConditionalInsertOperationInternal {
+                            points_op,
+                            condition: tags_filter,
+                            update_mode: None,
+                            sequence_key: None,
+                            skip_normalization: None,
+                        },
+                    );
+                }
+                PointOperations::UpsertPointsConditional(op) => {
+                    view.check_points_tags(&op.points_op)?;
+                    op.condition = mem::take(&mut op.condition).merge_owned(tags_filter);
+                }
+                PointOperations::DeletePoints { ids } => {
+                    *op = PointOperations::DeletePointsByFilter(
+                        ids_filter(mem::take(ids)).merge_owned(tags_filter),
+                    );
+                }
+                PointOperations::DeletePointsByFilter(filter) => {
+                    *filter = mem::take(filter).merge_owned(tags_filter);
+                }
+                PointOperations::SyncPoints(_) => view.check_whole_access()?,
+            },
+            CollectionUpdateOperations::VectorOperation(op) => match op {
+                VectorOperations::UpdateVectors(op) | VectorOperations::AppendVectors(op) => {
+                    op.update_filter =
+                        Filter::merge_opts(op.update_filter.take(), Some(tags_filter));
+                }
+                VectorOperations::DeleteVectors(ids, vector_names) => {
+                    *op = VectorOperations::DeleteVectorsByFilter(
+                        ids_filter(mem::take(&mut ids.points)).merge_owned(tags_filter),
+                        mem::take(vector_names),
+                    );
+                }
+                VectorOperations::DeleteVectorsByFilter(filter, _) => {
+                    *filter = mem::take(filter).merge_owned(tags_filter);
+                }
+            },
+            CollectionUpdateOperations::PayloadOperation(op) => match op {
+                PayloadOps::SetPayload(op) => {
+                    view.check_set_payload(op, false)?;
+                    restrict_selection(&mut op.points, &mut op.filter, tags_filter);
+                }
+                PayloadOps::OverwritePayload(op) => {
+                    view.check_set_payload(op, op.key.is_none())?;
+                    restrict_selection(&mut op.points, &mut op.filter, tags_filter);
+                }
+                PayloadOps::DeletePayload(op) => {
+                    if op.keys.iter().any(|key| key.first_key == ACCESS_TAGS_KEY) {
+                        view.check_whole_access()?;
+                    }
+                    restrict_selection(&mut op.points, &mut op.filter, tags_filter);
+                }
+                // Would remove the access tags of the points
+                PayloadOps::ClearPayload { .. } | PayloadOps::ClearPayloadByFilter(_) => {
+                    view.check_whole_access()?
+                }
+            },
+            CollectionUpdateOperations::FieldIndexOperation(_) => view.check_whole_access()?,
+            #[cfg(feature = "staging")]
+            CollectionUpdateOperations::StagingOperation(_) => view.check_whole_access()?,
+        }
         Ok(())
     }
 }
@@ -327,7 +726,7 @@ impl Auth {
     pub(crate) fn check_point_op<'a>(
         &self,
         collection_name: &'a str,
-        op: &impl CheckableCollectionOperation,
+        op: &mut impl CheckableCollectionOperation,
         method: &str,
     ) -> Result<CollectionPass<'a>, StorageError> {
         let result = self.unlogged_access().check_point_op(collection_name, op);
@@ -372,10 +771,7 @@ mod tests_ops {
         FieldIndexOperationsDiscriminants,
     };
     use segment::data_types::vectors::NamedQuery;
-    use segment::types::{
-        Condition, ExtendedPointId, Filter, Payload, PointIdType, SearchParams,
-        WithPayloadInterface, WithVector,
-    };
+    use segment::types::{ExtendedPointId, SearchParams, WithPayloadInterface, WithVector};
     use shard::operations::payload_ops::{DeletePayloadOp, PayloadOps, SetPayloadOp};
     use shard::operations::point_ops::{PointIdsList, PointOperations};
     use shard::operations::vector_ops::VectorOperations;
@@ -399,7 +795,24 @@ mod tests_ops {
         op: &Op,
         access: &Access,
     ) {
-        access.check_point_op("col", op).expect("Should be allowed");
+        let mut op_actual = op.clone();
+        access
+            .check_point_op("col", &mut op_actual)
+            .expect("Should be allowed");
+        assert_eq!(op, &op_actual, "Expected no rewrite");
+    }
+
+    /// Operation is allowed with the given access, and is rewritten into the expected one.
+    fn assert_rewritten<Op: Debug + Clone + PartialEq + CheckableCollectionOperation>(
+        op: &Op,
+        access: &Access,
+        expected: &Op,
+    ) {
+        let mut op_actual = op.clone();
+        access
+            .check_point_op("col", &mut op_actual)
+            .expect("Should be allowed");
+        assert_eq!(expected, &op_actual);
     }
 
     /// Operation is forbidden with the given access.
@@ -408,7 +821,7 @@ mod tests_ops {
         access: &Access,
     ) {
         access
-            .check_point_op("col", op)
+            .check_point_op("col", &mut op.clone())
             .expect_err("should be forbidden");
     }
 
@@ -662,6 +1075,8 @@ mod tests_ops {
             with_payload: Some(WithPayloadInterface::Bool(true)),
             with_vector: WithVector::Bool(true),
             order_by: Some(OrderByInterface::Key("path".parse().unwrap())),
+            consistent: None,
+            view_id: None,
         };
 
         assert_allowed(&op, &Access::Global(GlobalAccessMode::Manage));
@@ -673,6 +1088,269 @@ mod tests_ops {
         );
     }
 
+    /// Access restricted to points tagged with `a` or `b`.
+    fn tagged_access(write: bool) -> Access {
+        AccessCollectionBuilder::new()
+            .add_tagged("col", write, &["a", "b"])
+            .into()
+    }
+
+    fn make_tags_filter() -> Filter {
+        Filter::new_must(Condition::Field(FieldCondition::new_match(
+            JsonPath::new(ACCESS_TAGS_KEY),
+            vec!["a".to_string(), "b".to_string()].into(),
+        )))
+    }
+
+    #[test]
+    fn test_access_tags_read() {
+        let op = ScrollRequestInternal {
+            offset: None,
+            limit: Some(100),
+            filter: Some(make_filter_from_ids(vec![ExtendedPointId::NumId(12345)])),
+            with_payload: Some(WithPayloadInterface::Bool(true)),
+            with_vector: WithVector::Bool(false),
+            order_by: None,
+            consistent: None,
+            view_id: None,
+        };
+        assert_rewritten(
+            &op,
+            &tagged_access(false),
+            &ScrollRequestInternal {
+                filter: Some(
+                    make_filter_from_ids(vec![ExtendedPointId::NumId(12345)])
+                        .merge_owned(make_tags_filter()),
+                ),
+                ..op.clone()
+            },
+        );
+
+        let op = CountRequestInternal {
+            filter: None,
+            exact: true,
+        };
+        assert_rewritten(
+            &op,
+            &tagged_access(false),
+            &CountRequestInternal {
+                filter: Some(make_tags_filter()),
+                ..op.clone()
+            },
+        );
+
+        // Points can't be read by ID
+        assert_forbidden(
+            &PointRequestInternal {
+                ids: vec![PointIdType::NumId(12345)],
+                with_payload: None,
+                with_vector: WithVector::Bool(true),
+            },
+            &tagged_access(false),
+        );
+
+        // Tagged collection can't be used for lookups
+        let op = GroupRequest {
+            source: SourceRequest::Search(SearchRequestInternal {
+                vector: rest::NamedVectorStruct::Default(vec![0.0, 1.0, 2.0]),
+                filter: None,
+                params: None,
+                limit: 100,
+                offset: None,
+                with_payload: None,
+                with_vector: None,
+                score_threshold: None,
+            }),
+            group_by: "path".parse().unwrap(),
+            group_size: 100,
+            limit: 100,
+            with_lookup: Some(WithLookup {
+                collection_name: "col2".to_string(),
+                with_payload: None,
+                with_vectors: None,
+            }),
+        };
+        assert_forbidden(
+            &op,
+            &AccessCollectionBuilder::new()
+                .add("col", false)
+                .add_tagged("col2", false, &["a"])
+                .into(),
+        );
+    }
+
+    #[test]
+    fn test_access_tags_stats() {
+        // Statistics over the filtered points only include points with allowed tags
+        let op = CollectionQuantilesRequest {
+            key: JsonPath::new("price"),
+            quantiles: vec![0.5],
+            filter: None,
+        };
+        assert_allowed(
+            &op,
+            &AccessCollectionBuilder::new().add("col", false).into(),
+        );
+        assert_rewritten(
+            &op,
+            &tagged_access(false),
+            &CollectionQuantilesRequest {
+                filter: Some(make_tags_filter()),
+                ..op.clone()
+            },
+        );
+
+        let filter = make_filter_from_ids(vec![ExtendedPointId::NumId(12345)]);
+        assert_allowed(
+            &filter,
+            &AccessCollectionBuilder::new().add("col", false).into(),
+        );
+        assert_rewritten(
+            &filter,
+            &tagged_access(false),
+            &filter.clone().merge_owned(make_tags_filter()),
+        );
+
+        // Statistics over the whole collection can't be restricted to the allowed tags
+        let op = CollectionVectorStatsRequest::default();
+        assert_allowed(
+            &op,
+            &AccessCollectionBuilder::new().add("col", false).into(),
+        );
+        assert_forbidden(&op, &tagged_access(false));
+
+        let op = CollectionTermStatsRequest {
+            key: JsonPath::new("text"),
+            limit: 10,
+        };
+        assert_allowed(
+            &op,
+            &AccessCollectionBuilder::new().add("col", false).into(),
+        );
+        assert_forbidden(&op, &tagged_access(false));
+    }
+
+    #[test]
+    fn test_access_tags_upsert() {
+        let points = |payload: Option<Payload>| {
+            PointInsertOperationsInternal::PointsList(vec![PointStructPersisted {
+                id: ExtendedPointId::NumId(12345),
+                vector: VectorStructPersisted::Single(vec![0.0, 1.0, 2.0]),
+                payload,
+            }])
+        };
+        let upsert = |payload: Option<Payload>| {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(points(
+                payload,
+            )))
+        };
+
+        // Only existing points with allowed tags may be overwritten
+        let payload = segment::payload_json! { "access_tags": ["a", "b"] };
+        assert_rewritten(
+            &upsert(Some(payload.clone())),
+            &tagged_access(true),
+            &CollectionUpdateOperations::PointOperation(PointOperations::UpsertPointsConditional(
+                ConditionalInsertOperationInternal {
+                    points_op: points(Some(payload)),
+                    condition: make_tags_filter(),
+                    update_mode: None,
+                    sequence_key: None,
+                    skip_normalization: None,
+                },
+            )),
+        );
+        assert_forbidden(
+            &upsert(Some(segment::payload_json! { "access_tags": "a" })),
+            &tagged_access(false),
+        );
+
+        // Points must be tagged with allowed tags only
+        for payload in [
+            None,
+            Some(segment::payload_json! { "city": "Berlin" }),
+            Some(segment::payload_json! { "access_tags": [] }),
+            Some(segment::payload_json! { "access_tags": ["a", "c"] }),
+            Some(segment::payload_json! { "access_tags": 1 }),
+        ] {
+            assert_forbidden(&upsert(payload), &tagged_access(true));
+        }
+    }
+
+    #[test]
+    fn test_access_tags_update() {
+        let ids = vec![ExtendedPointId::NumId(12345)];
+
+        // Deleting by IDs only deletes points with allowed tags
+        assert_rewritten(
+            &CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints {
+                ids: ids.clone(),
+            }),
+            &tagged_access(true),
+            &CollectionUpdateOperations::PointOperation(PointOperations::DeletePointsByFilter(
+                make_filter_from_ids(ids.clone()).merge_owned(make_tags_filter()),
+            )),
+        );
+
+        let set_payload = |payload: Payload| SetPayloadOp {
+            payload,
+            points: Some(ids.clone()),
+            filter: None,
+            key: None,
+        };
+        assert_rewritten(
+            &CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(set_payload(
+                segment::payload_json! { "city": "Berlin" },
+            ))),
+            &tagged_access(true),
+            &CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(SetPayloadOp {
+                points: None,
+                filter: Some(make_filter_from_ids(ids.clone()).merge_owned(make_tags_filter())),
+                ..set_payload(segment::payload_json! { "city": "Berlin" })
+            })),
+        );
+
+        // Tags can't be changed to disallowed ones, nor removed
+        assert_forbidden(
+            &CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(set_payload(
+                segment::payload_json! { "access_tags": "c" },
+            ))),
+            &tagged_access(true),
+        );
+        assert_forbidden(
+            &CollectionUpdateOperations::PayloadOperation(PayloadOps::OverwritePayload(
+                set_payload(segment::payload_json! { "city": "Berlin" }),
+            )),
+            &tagged_access(true),
+        );
+        assert_forbidden(
+            &CollectionUpdateOperations::PayloadOperation(PayloadOps::DeletePayload(
+                DeletePayloadOp {
+                    keys: vec![JsonPath::new(ACCESS_TAGS_KEY)],
+                    points: Some(ids.clone()),
+                    filter: None,
+                },
+            )),
+            &tagged_access(true),
+        );
+        assert_forbidden(
+            &CollectionUpdateOperations::PayloadOperation(PayloadOps::ClearPayload {
+                points: ids.clone(),
+            }),
+            &tagged_access(true),
+        );
+        assert_forbidden(
+            &CollectionUpdateOperations::PointOperation(PointOperations::SyncPoints(
+                PointSyncOperation {
+                    from_id: None,
+                    to_id: None,
+                    points: Vec::new(),
+                },
+            )),
+            &tagged_access(true),
+        );
+    }
+
     #[test]
     fn test_collection_update_operations() {
         CollectionUpdateOperationsDiscriminants::iter().for_each(|discr| match discr {
@@ -743,6 +1421,8 @@ mod tests_ops {
                         points_op: inner,
                         condition: filter,
                         update_mode: None,
+                        sequence_key: None,
+                        skip_normalization: None,
                     }),
                 );
 
@@ -804,6 +1484,18 @@ mod tests_ops {
                 );
                 assert_requires_whole_write_access(&op);
             }
+            VectorOperationsDiscriminants::AppendVectors => {
+                let op = CollectionUpdateOperations::VectorOperation(
+                    VectorOperations::AppendVectors(UpdateVectorsOp {
+                        points: vec![PointVectorsPersisted {
+                            id: ExtendedPointId::NumId(12345),
+                            vector: VectorStructPersisted::MultiDense(vec![vec![0.0, 1.0]]),
+                        }],
+                        update_filter: None,
+                    }),
+                );
+                assert_requires_whole_write_access(&op);
+            }
             VectorOperationsDiscriminants::DeleteVectors => {
                 let op =
                     CollectionUpdateOperations::VectorOperation(VectorOperations::DeleteVectors(
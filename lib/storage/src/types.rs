@@ -4,6 +4,8 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use collection::collection::quota::CollectionQuotaConfig;
+use collection::common::score_drift::ScoreDriftConfig;
 use collection::common::snapshots_manager::SnapshotsConfig;
 use collection::config::{WalConfig, default_on_disk_payload};
 use collection::operations::config_diff::OptimizersConfigDiff;
@@ -21,6 +23,7 @@ use segment::common::anonymize::{Anonymize, anonymize_collection_values};
 use segment::data_types::collection_defaults::CollectionConfigDefaults;
 use segment::types::{HnswConfig, HnswGlobalConfig};
 use serde::{Deserialize, Serialize};
+use shard::wal::WalBackendType;
 use tonic::transport::Uri;
 use validator::{Validate, ValidationError};
 
@@ -56,6 +59,18 @@ pub struct PerformanceConfig {
     pub async_scorer: Option<bool>,
     #[serde(default, flatten)]
     pub load_concurrency: LoadConcurrencyConfig,
+    /// Dedicated search threads for high-priority collections, by collection name.
+    /// Searches in these collections don't compete for threads with other collections.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub dedicated_search_threads: HashMap<String, NonZeroUsize>,
+    /// Adjust the number of concurrent segment searches to CPU saturation and queueing latency,
+    /// instead of running at most `max_search_threads` of them.
+    #[serde(default)]
+    pub adaptive_search_concurrency: bool,
+    /// Execute identical concurrent queries to the same collection only once, and share the
+    /// result between all of their callers.
+    #[serde(default)]
+    pub coalesce_identical_queries: bool,
 }
 
 const fn default_io_shard_transfers_limit() -> Option<usize> {
@@ -115,6 +130,15 @@ pub struct StorageConfig {
     /// Maximum number of collections to allow in the cluster.
     #[serde(default)]
     pub max_collections: Option<usize>,
+    /// Limits on the size of each collection, which can't be changed through the API.
+    #[serde(default)]
+    pub collection_quota: CollectionQuotaConfig,
+    /// Detection of sharp changes in top-1 search scores.
+    #[serde(default)]
+    pub score_drift: ScoreDriftConfig,
+    /// Experimental: backend to store WAL entries of local shards with.
+    #[serde(default)]
+    pub wal_backend: WalBackendType,
 }
 
 impl StorageConfig {
@@ -137,6 +161,10 @@ impl StorageConfig {
             self.hnsw_global_config.clone(),
             self.performance.load_concurrency.clone(),
             common::defaults::search_thread_count(self.performance.max_search_threads),
+            self.score_drift.clone(),
+            self.performance.coalesce_identical_queries,
+            self.wal_backend,
+            self.collection_quota,
         )
     }
 }
@@ -44,6 +44,7 @@ fn test_alias_operation() {
             flush_interval_sec: 2,
             max_optimization_threads: Some(2),
             prevent_unoptimized: None,
+            max_segment_number: None,
         },
         optimizers_overwrite: None,
         wal: Default::default(),
@@ -58,6 +59,9 @@ fn test_alias_operation() {
             outgoing_shard_transfers_limit: Some(1),
             async_scorer: None,
             load_concurrency: LoadConcurrencyConfig::default(),
+            dedicated_search_threads: Default::default(),
+            adaptive_search_concurrency: false,
+            coalesce_identical_queries: false,
         },
         hnsw_index: Default::default(),
         hnsw_global_config: Default::default(),
@@ -71,6 +75,9 @@ fn test_alias_operation() {
         shard_transfer_method: None,
         collection: None,
         max_collections: None,
+        collection_quota: Default::default(),
+        score_drift: Default::default(),
+        wal_backend: Default::default(),
     };
 
     let search_runtime = Runtime::new().unwrap();
@@ -83,16 +90,19 @@ fn test_alias_operation() {
     let (propose_sender, _propose_receiver) = std::sync::mpsc::channel();
     let propose_operation_sender = OperationSender::new(propose_sender);
 
-    let toc = Arc::new(TableOfContent::new(
-        &config,
-        search_runtime,
-        update_runtime,
-        general_runtime,
-        ResourceBudget::default(),
-        ChannelService::new(6333, false, None, None),
-        0,
-        Some(propose_operation_sender),
-    ));
+    let toc = Arc::new(
+        TableOfContent::new(
+            &config,
+            search_runtime,
+            update_runtime,
+            general_runtime,
+            ResourceBudget::default(),
+            ChannelService::new(6333, false, None, None),
+            0,
+            Some(propose_operation_sender),
+        )
+        .unwrap(),
+    );
     let dispatcher = Dispatcher::new(toc);
 
     handle
@@ -118,6 +128,7 @@ fn test_alias_operation() {
                             strict_mode_config: None,
                             uuid: None,
                             metadata: None,
+                            placement: None,
                         },
                     )
                     .unwrap(),
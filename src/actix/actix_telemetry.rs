@@ -1,18 +1,30 @@
+use std::cell::Cell;
 use std::future::{Ready, ready};
+use std::pin::Pin;
+use std::rc::Rc;
 use std::sync::Arc;
+use std::task::{Context, Poll};
 
 use actix_web::Error;
-use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::body::{BodySize, BoxBody, MessageBody};
+use actix_web::dev::{Payload, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::web::Bytes;
+use futures_util::TryStreamExt;
 use futures_util::future::LocalBoxFuture;
 use parking_lot::Mutex;
 
 use crate::common::telemetry_ops::requests_telemetry::{
-    ActixTelemetryCollector, ActixWorkerTelemetryCollector,
+    ActixTelemetryCollector, ActixWorkerTelemetryCollector, InFlightRequests, NO_COLLECTION,
+    TransferCounters,
 };
 
+/// Path parameters, which hold the collection name of a request
+const COLLECTION_PATH_PARAMS: &[&str] = &["name", "collection"];
+
 pub struct ActixTelemetryService<S> {
     service: S,
     telemetry_data: Arc<Mutex<ActixWorkerTelemetryCollector>>,
+    in_flight: InFlightRequests,
 }
 
 pub struct ActixTelemetryTransform {
@@ -27,33 +39,92 @@ impl<S, B> Service<ServiceRequest> for ActixTelemetryService<S>
 where
     S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
     S::Future: 'static,
-    B: 'static,
+    B: MessageBody + 'static,
 {
-    type Response = ServiceResponse<B>;
+    type Response = ServiceResponse<CountingBody>;
     type Error = Error;
     type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
 
     actix_web::dev::forward_ready!(service);
 
-    fn call(&self, request: ServiceRequest) -> Self::Future {
+    fn call(&self, mut request: ServiceRequest) -> Self::Future {
         let match_pattern = request
             .match_pattern()
             .unwrap_or_else(|| "unknown".to_owned());
         let request_key = format!("{} {}", request.method(), match_pattern);
+        let in_flight_guard = self.in_flight.start_request();
+
+        // Count the request body as it is read by the handler
+        let request_bytes = Rc::new(Cell::new(0));
+        let payload = request.take_payload().inspect_ok({
+            let request_bytes = request_bytes.clone();
+            move |chunk| request_bytes.set(request_bytes.get() + chunk.len() as u64)
+        });
+        request.set_payload(Payload::Stream {
+            payload: Box::pin(payload),
+        });
+
         let future = self.service.call(request);
         let telemetry_data = self.telemetry_data.clone();
         Box::pin(async move {
+            let _in_flight_guard = in_flight_guard;
             let instant = std::time::Instant::now();
             let response = future.await?;
-            let status = response.response().status().as_u16();
-            telemetry_data
-                .lock()
-                .add_response(request_key, status, instant);
-            Ok(response)
+            let status = response.response().status();
+
+            // Routing is done by now, so path parameters are available.
+            // Failed requests are not attributed to a collection, the collection may not exist.
+            let collection = if status.is_client_error() || status.is_server_error() {
+                None
+            } else {
+                let match_info = response.request().match_info();
+                COLLECTION_PATH_PARAMS
+                    .iter()
+                    .find_map(|param| match_info.get(param))
+            };
+
+            let counters = {
+                let mut telemetry_data = telemetry_data.lock();
+                let counters = telemetry_data
+                    .transfer_counters(&request_key, collection.unwrap_or(NO_COLLECTION));
+                telemetry_data.add_response(request_key, status.as_u16(), instant);
+                counters
+            };
+            counters.add_request_bytes(request_bytes.get());
+
+            Ok(response.map_body(|_, body| CountingBody {
+                body: body.boxed(),
+                counters,
+            }))
         })
     }
 }
 
+/// Response body, which counts its size as it is sent
+pub struct CountingBody {
+    body: BoxBody,
+    counters: Arc<TransferCounters>,
+}
+
+impl MessageBody for CountingBody {
+    type Error = Box<dyn std::error::Error>;
+
+    fn size(&self) -> BodySize {
+        self.body.size()
+    }
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Bytes, Self::Error>>> {
+        let chunk = Pin::new(&mut self.body).poll_next(cx);
+        if let Poll::Ready(Some(Ok(bytes))) = &chunk {
+            self.counters.add_response_bytes(bytes.len() as u64);
+        }
+        chunk
+    }
+}
+
 impl ActixTelemetryTransform {
     pub fn new(telemetry_collector: Arc<Mutex<ActixTelemetryCollector>>) -> Self {
         Self {
@@ -70,21 +141,20 @@ impl<S, B> Transform<S, ServiceRequest> for ActixTelemetryTransform
 where
     S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
     S::Future: 'static,
-    B: 'static,
+    B: MessageBody + 'static,
 {
-    type Response = ServiceResponse<B>;
+    type Response = ServiceResponse<CountingBody>;
     type Error = Error;
     type Transform = ActixTelemetryService<S>;
     type InitError = ();
     type Future = Ready<Result<Self::Transform, Self::InitError>>;
 
     fn new_transform(&self, service: S) -> Self::Future {
+        let mut telemetry_collector = self.telemetry_collector.lock();
         ready(Ok(ActixTelemetryService {
             service,
-            telemetry_data: self
-                .telemetry_collector
-                .lock()
-                .create_web_worker_telemetry(),
+            telemetry_data: telemetry_collector.create_web_worker_telemetry(),
+            in_flight: telemetry_collector.in_flight.clone(),
         }))
     }
 }
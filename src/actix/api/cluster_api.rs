@@ -1,4 +1,5 @@
 use std::future::Future;
+use std::time::Duration;
 
 use actix_web::{HttpResponse, delete, get, post, put, web};
 use actix_web_validator::Query;
@@ -13,17 +14,22 @@ use storage::content_manager::consensus_ops::ConsensusOperations;
 use storage::content_manager::errors::StorageError;
 use storage::dispatcher::Dispatcher;
 use storage::rbac::{Access, AccessRequirements};
+use tokio::sync::Mutex;
 use validator::Validate;
 
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers;
-use crate::common::telemetry::TelemetryData;
+use crate::common::restart;
+use crate::common::telemetry::{TelemetryCollector, TelemetryData};
 use crate::common::telemetry_ops::distributed_telemetry::DistributedTelemetryData;
 
 /// For now, we only handle details_level >= 2
 /// TODO(cluster telemetry): Handle lower levels
 const MIN_CLUSTER_TELEMETRY_DETAILS_LEVEL: u32 = 2;
 
+/// Default time to wait for optimizations and requests to finish before restart
+const DEFAULT_PREPARE_RESTART_TIMEOUT: Duration = Duration::from_secs(30);
+
 #[derive(Debug, Deserialize, Validate)]
 struct QueryParams {
     #[serde(default)]
@@ -39,6 +45,18 @@ pub struct MetadataParams {
     pub wait: bool,
 }
 
+#[derive(Deserialize, Serialize, JsonSchema, Validate)]
+pub struct StandbyParams {
+    /// If true - this peer only replicates data and refuses client requests until promoted
+    pub standby: bool,
+}
+
+#[derive(Deserialize, JsonSchema, Validate)]
+pub struct PrepareRestartParams {
+    #[validate(range(min = 1))]
+    timeout: Option<u64>,
+}
+
 #[derive(Deserialize, JsonSchema, Validate)]
 pub struct ClusterTelemetryParams {
     details_level: Option<u32>,
@@ -72,6 +90,93 @@ fn recover_current_peer(
     })
 }
 
+#[get("/cluster/standby")]
+fn get_standby(
+    dispatcher: web::Data<Dispatcher>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Future<Output = HttpResponse> {
+    // Not a collection level request.
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(async move {
+        auth.check_global_access(AccessRequirements::new(), "get_standby")?;
+        Ok(StandbyParams {
+            standby: dispatcher.toc(&auth, &pass).is_standby(),
+        })
+    })
+}
+
+#[put("/cluster/standby")]
+fn set_standby(
+    dispatcher: web::Data<Dispatcher>,
+    params: web::Json<StandbyParams>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Future<Output = HttpResponse> {
+    // Not a collection level request.
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(async move {
+        auth.check_global_access(AccessRequirements::new().manage(), "set_standby")?;
+        dispatcher
+            .toc(&auth, &pass)
+            .set_standby(params.into_inner().standby)?;
+        Ok(true)
+    })
+}
+
+#[post("/cluster/prepare_restart")]
+fn prepare_restart(
+    dispatcher: web::Data<Dispatcher>,
+    telemetry_collector: web::Data<Mutex<TelemetryCollector>>,
+    Query(params): Query<PrepareRestartParams>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Future<Output = HttpResponse> {
+    // Not a collection level request.
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(async move {
+        auth.check_global_access(AccessRequirements::new().manage(), "prepare_restart")?;
+
+        let in_flight = {
+            let telemetry_collector = telemetry_collector.lock().await;
+            [
+                telemetry_collector
+                    .actix_telemetry_collector
+                    .lock()
+                    .in_flight
+                    .clone(),
+                telemetry_collector
+                    .tonic_telemetry_collector
+                    .lock()
+                    .in_flight
+                    .clone(),
+            ]
+        };
+
+        let timeout = params
+            .timeout
+            .map_or(DEFAULT_PREPARE_RESTART_TIMEOUT, Duration::from_secs);
+
+        let toc = dispatcher.toc(&auth, &pass);
+        restart::prepare_restart(&dispatcher, toc, &in_flight, timeout).await
+    })
+}
+
+#[delete("/cluster/prepare_restart")]
+fn cancel_restart(
+    dispatcher: web::Data<Dispatcher>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Future<Output = HttpResponse> {
+    // Not a collection level request.
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(async move {
+        auth.check_global_access(AccessRequirements::new().manage(), "cancel_restart")?;
+        restart::cancel_restart(dispatcher.toc(&auth, &pass));
+        Ok(true)
+    })
+}
+
 #[delete("/cluster/peer/{peer_id}")]
 fn remove_peer(
     dispatcher: web::Data<Dispatcher>,
@@ -294,6 +399,10 @@ pub fn config_cluster_api(cfg: &mut web::ServiceConfig) {
     cfg.service(cluster_status)
         .service(remove_peer)
         .service(recover_current_peer)
+        .service(prepare_restart)
+        .service(get_standby)
+        .service(set_standby)
+        .service(cancel_restart)
         .service(get_cluster_telemetry)
         .service(get_cluster_metadata_keys)
         .service(get_cluster_metadata_key)
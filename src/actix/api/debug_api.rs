@@ -126,6 +126,61 @@ mod staging {
     }
 }
 
+#[cfg(feature = "chaos-testing")]
+mod chaos {
+    use actix_web::{delete, put};
+    use common::fault_injection::{self, FaultConfig, FaultPoint};
+
+    use super::*;
+    use crate::actix::helpers;
+
+    #[get("/debugger/faults")]
+    pub async fn get_faults(ActixAuth(auth): ActixAuth) -> impl Responder {
+        helpers::time(async move {
+            auth.check_global_access(AccessRequirements::new().manage(), "get_faults")?;
+            Ok(fault_injection::get_faults())
+        })
+        .await
+    }
+
+    #[put("/debugger/faults/{point}")]
+    pub async fn set_fault(
+        ActixAuth(auth): ActixAuth,
+        point: web::Path<FaultPoint>,
+        config: web::Json<FaultConfig>,
+    ) -> impl Responder {
+        helpers::time(async move {
+            auth.check_global_access(AccessRequirements::new().manage(), "set_fault")?;
+            fault_injection::set_fault(point.into_inner(), Some(config.into_inner()));
+            Ok(true)
+        })
+        .await
+    }
+
+    #[delete("/debugger/faults/{point}")]
+    pub async fn delete_fault(
+        ActixAuth(auth): ActixAuth,
+        point: web::Path<FaultPoint>,
+    ) -> impl Responder {
+        helpers::time(async move {
+            auth.check_global_access(AccessRequirements::new().manage(), "delete_fault")?;
+            fault_injection::set_fault(point.into_inner(), None);
+            Ok(true)
+        })
+        .await
+    }
+
+    #[delete("/debugger/faults")]
+    pub async fn clear_faults(ActixAuth(auth): ActixAuth) -> impl Responder {
+        helpers::time(async move {
+            auth.check_global_access(AccessRequirements::new().manage(), "clear_faults")?;
+            fault_injection::clear_faults();
+            Ok(true)
+        })
+        .await
+    }
+}
+
 // Configure services
 pub fn config_debugger_api(cfg: &mut web::ServiceConfig) {
     cfg.service(get_debugger_config)
@@ -134,4 +189,10 @@ pub fn config_debugger_api(cfg: &mut web::ServiceConfig) {
     #[cfg(feature = "staging")]
     cfg.service(staging::get_shard_wal)
         .service(staging::get_shard_recovery_point);
+
+    #[cfg(feature = "chaos-testing")]
+    cfg.service(chaos::get_faults)
+        .service(chaos::set_fault)
+        .service(chaos::delete_fault)
+        .service(chaos::clear_faults);
 }
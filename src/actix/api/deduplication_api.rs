@@ -0,0 +1,69 @@
+use actix_web::{Responder, delete, get, post, web};
+use actix_web_validator::{Json, Path};
+use api::rest::DeduplicationRequest;
+use collection::operations::verification::new_unchecked_verification_pass;
+use storage::dispatcher::Dispatcher;
+
+use crate::actix::api::CollectionPath;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers;
+
+#[post("/collections/{name}/deduplication")]
+async fn start_deduplication(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<DeduplicationRequest>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    // Deduplication is a management operation, not subject to strict mode
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(dispatcher.toc(&auth, &pass).start_deduplication(
+        &collection.name,
+        request.into_inner().into(),
+        auth,
+    ))
+    .await
+}
+
+#[get("/collections/{name}/deduplication")]
+async fn get_deduplication_status(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    // No request to verify
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(
+        dispatcher
+            .toc(&auth, &pass)
+            .deduplication_status(&collection.name, auth),
+    )
+    .await
+}
+
+#[delete("/collections/{name}/deduplication")]
+async fn cancel_deduplication(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    // No request to verify
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(async move {
+        dispatcher
+            .toc(&auth, &pass)
+            .cancel_deduplication(&collection.name, auth)
+            .await?;
+        Ok(true)
+    })
+    .await
+}
+
+pub fn config_deduplication_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(start_deduplication)
+        .service(get_deduplication_status)
+        .service(cancel_deduplication);
+}
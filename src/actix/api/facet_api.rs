@@ -1,7 +1,9 @@
 use actix_web::{Responder, post, web};
 use actix_web_validator::{Json, Path, Query};
-use api::rest::{FacetRequest, FacetResponse};
+use api::rest::{FacetRequest, FacetRequestInternal, FacetResponse};
+use collection::collection::facet::CollectionFacetRangesRequest;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use segment::data_types::facets::FacetParams;
 use storage::content_manager::collection_verification::check_strict_mode;
 use storage::dispatcher::Dispatcher;
 use tokio::time::Instant;
@@ -43,8 +45,6 @@ async fn facet(
         Err(err) => return process_response_error(err, timing, None),
     };
 
-    let facet_params = From::from(facet_request);
-
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => shard_keys.into(),
@@ -57,19 +57,50 @@ async fn facet(
         None,
     );
 
-    let response = dispatcher
-        .toc(&auth, &pass)
-        .facet(
-            &collection.name,
-            facet_params,
-            shard_selection,
-            params.consistency,
-            auth,
-            params.timeout(),
-            request_hw_counter.get_counter(),
-        )
-        .await
-        .map(FacetResponse::from);
+    let toc = dispatcher.toc(&auth, &pass);
+
+    let response = match facet_request.ranges {
+        Some(ranges) => {
+            let FacetRequestInternal {
+                key,
+                limit: _,
+                filter,
+                exact,
+                ranges: _,
+            } = facet_request;
+
+            let ranges_request = CollectionFacetRangesRequest {
+                key,
+                ranges,
+                filter,
+                exact: exact.unwrap_or(FacetParams::DEFAULT_EXACT),
+            };
+
+            toc.facet_ranges(
+                &collection.name,
+                ranges_request,
+                shard_selection,
+                params.consistency,
+                auth,
+                params.timeout(),
+                request_hw_counter.get_counter(),
+            )
+            .await
+            .map(FacetResponse::from)
+        }
+        None => toc
+            .facet(
+                &collection.name,
+                From::from(facet_request),
+                shard_selection,
+                params.consistency,
+                auth,
+                params.timeout(),
+                request_hw_counter.get_counter(),
+            )
+            .await
+            .map(FacetResponse::from),
+    };
 
     process_response(response, timing, request_hw_counter.to_rest_api())
 }
@@ -0,0 +1,70 @@
+use actix_web::{HttpResponse, Responder, get, web};
+use collection::operations::verification::new_unchecked_verification_pass;
+use collection::shards::shard::ShardId;
+use segment::types::VectorNameBuf;
+use serde::Deserialize;
+use storage::dispatcher::Dispatcher;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+use crate::actix::api::read_params::ReadParams;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers::process_response_error;
+
+#[derive(Debug, Deserialize)]
+struct SegmentPath {
+    collection_name: String,
+    shard_id: ShardId,
+    segment_id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+struct HnswGraphParams {
+    /// Name of the vector, which graph is exported. Default vector, if not set
+    #[serde(default)]
+    vector: VectorNameBuf,
+}
+
+/// Export HNSW graph of a segment as JSON Lines, see `segment::data_types::hnsw_graph`
+#[get("/collections/{collection_name}/shards/{shard_id}/segments/{segment_id}/hnsw_graph")]
+async fn export_hnsw_graph(
+    dispatcher: web::Data<Dispatcher>,
+    path: web::Path<SegmentPath>,
+    graph_params: web::Query<HnswGraphParams>,
+    read_params: web::Query<ReadParams>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    // No strict mode verification needed
+    let pass = new_unchecked_verification_pass();
+
+    let SegmentPath {
+        collection_name,
+        shard_id,
+        segment_id,
+    } = path.into_inner();
+
+    let response = dispatcher
+        .toc(&auth, &pass)
+        .export_hnsw_graph(
+            &collection_name,
+            shard_id,
+            segment_id,
+            graph_params.into_inner().vector,
+            auth,
+            read_params.timeout(),
+        )
+        .await;
+
+    match response {
+        Ok(graph) => HttpResponse::Ok()
+            .content_type("application/x-ndjson")
+            .body(graph),
+        Err(err) => process_response_error(err, timing, None),
+    }
+}
+
+pub fn config_hnsw_graph_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(export_hnsw_graph);
+}
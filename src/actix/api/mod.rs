@@ -6,11 +6,17 @@ pub mod cluster_api;
 pub mod collections_api;
 pub mod count_api;
 pub mod debug_api;
+pub mod deduplication_api;
 pub mod discovery_api;
+pub mod explain_filter_api;
 pub mod facet_api;
+pub mod hnsw_graph_api;
 pub mod issues_api;
 pub mod local_shard_api;
+pub mod payload_index_compaction_api;
+pub mod payload_migration_api;
 pub mod profiler_api;
+pub mod quantiles_api;
 pub mod query_api;
 pub mod read_params;
 pub mod recommend_api;
@@ -19,7 +25,9 @@ pub mod search_api;
 pub mod service_api;
 pub mod shards_api;
 pub mod snapshot_api;
+pub mod term_stats_api;
 pub mod update_api;
+pub mod vector_stats_api;
 
 /// A collection path with stricter validation
 ///
@@ -0,0 +1,41 @@
+use actix_web::{Responder, post, web};
+use actix_web_validator::{Path, Query};
+use collection::operations::verification::new_unchecked_verification_pass;
+use serde::Deserialize;
+use storage::dispatcher::Dispatcher;
+use validator::Validate;
+
+use crate::actix::api::CollectionPath;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers;
+
+#[derive(Debug, Deserialize, Validate)]
+struct CompactionParams {
+    /// Compact only storages, which share of fragmented space reaches this ratio.
+    /// Any fragmented storage is compacted, if not set
+    #[validate(range(min = 0.0, max = 1.0))]
+    min_fragmentation_ratio: Option<f64>,
+}
+
+/// Compact fragmented payload index storages of the collection replicas, located on this peer
+#[post("/collections/{name}/index/compact")]
+async fn compact_payload_indexes(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    params: Query<CompactionParams>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    // Compaction is a management operation, not subject to strict mode
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(dispatcher.toc(&auth, &pass).compact_payload_indexes(
+        &collection.name,
+        params.min_fragmentation_ratio.unwrap_or(0.0),
+        auth,
+    ))
+    .await
+}
+
+pub fn config_payload_index_compaction_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(compact_payload_indexes);
+}
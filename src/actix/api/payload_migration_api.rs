@@ -0,0 +1,68 @@
+use actix_web::{Responder, delete, get, post, web};
+use actix_web_validator::{Json, Path};
+use api::rest::PayloadMigrationRequest;
+use collection::operations::verification::new_unchecked_verification_pass;
+use storage::dispatcher::Dispatcher;
+
+use crate::actix::api::CollectionPath;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers;
+use crate::common::update::do_start_payload_migration;
+
+#[post("/collections/{name}/payload/migration")]
+async fn start_payload_migration(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<PayloadMigrationRequest>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    helpers::time(do_start_payload_migration(
+        dispatcher.get_ref(),
+        &collection.name,
+        request.into_inner().into(),
+        auth,
+    ))
+    .await
+}
+
+#[get("/collections/{name}/payload/migration")]
+async fn get_payload_migration_status(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    // No request to verify
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(
+        dispatcher
+            .toc(&auth, &pass)
+            .payload_migration_status(&collection.name, auth),
+    )
+    .await
+}
+
+#[delete("/collections/{name}/payload/migration")]
+async fn cancel_payload_migration(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    // No request to verify
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(async move {
+        dispatcher
+            .toc(&auth, &pass)
+            .cancel_payload_migration(&collection.name, auth)
+            .await?;
+        Ok(true)
+    })
+    .await
+}
+
+pub fn config_payload_migration_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(start_payload_migration)
+        .service(get_payload_migration_status)
+        .service(cancel_payload_migration);
+}
@@ -0,0 +1,65 @@
+use actix_web::{Responder, post, web};
+use actix_web_validator::{Json, Path, Query};
+use api::rest::QuantilesRequest;
+use storage::content_manager::collection_verification::check_strict_mode;
+use storage::dispatcher::Dispatcher;
+use tokio::time::Instant;
+
+use crate::actix::api::CollectionPath;
+use crate::actix::api::read_params::ReadParams;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers::{
+    get_request_hardware_counter, process_response, process_response_error,
+};
+use crate::settings::ServiceConfig;
+
+#[post("/collections/{name}/quantiles")]
+async fn quantiles(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<QuantilesRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let request = request.into_inner();
+
+    let pass = match check_strict_mode(
+        &request,
+        params.timeout_as_secs(),
+        &collection.name,
+        &dispatcher,
+        &auth,
+    )
+    .await
+    {
+        Ok(pass) => pass,
+        Err(err) => return process_response_error(err, timing, None),
+    };
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+
+    let response = dispatcher
+        .toc(&auth, &pass)
+        .quantiles(
+            &collection.name,
+            request.into(),
+            auth,
+            params.timeout(),
+            request_hw_counter.get_counter(),
+        )
+        .await;
+
+    process_response(response, timing, request_hw_counter.to_rest_api())
+}
+
+pub fn config_quantiles_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(quantiles);
+}
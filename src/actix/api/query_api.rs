@@ -4,6 +4,7 @@ use api::rest::models::InferenceUsage;
 use api::rest::{QueryGroupsRequest, QueryRequest, QueryRequestBatch, QueryResponse};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use itertools::Itertools;
+use segment::index::field_index::full_text_index::highlight::PayloadHighlighter;
 use storage::content_manager::collection_verification::{
     check_strict_mode, check_strict_mode_batch,
 };
@@ -38,8 +39,11 @@ async fn query_points(
     let QueryRequest {
         internal: query_request,
         shard_key,
+        highlight,
     } = request.into_inner();
 
+    let highlight = highlight.zip(query_request.filter.clone());
+
     let request_hw_counter = get_request_hardware_counter(
         &dispatcher,
         collection.name.clone(),
@@ -72,7 +76,17 @@ async fn query_points(
         )
         .await?;
 
-        let points = dispatcher
+        let highlighter = match highlight {
+            Some((highlight, filter)) => Some(
+                dispatcher
+                    .toc(&auth, &pass)
+                    .payload_highlighter(&collection.name, &filter, highlight.into(), auth.clone())
+                    .await?,
+            ),
+            None => None,
+        };
+
+        let mut points = dispatcher
             .toc(&auth, &pass)
             .query_batch(
                 &collection.name,
@@ -91,6 +105,10 @@ async fn query_points(
             .map(api::rest::ScoredPoint::from)
             .collect_vec();
 
+        if let Some(highlighter) = &highlighter {
+            highlight_points(highlighter, &mut points);
+        }
+
         Ok(QueryResponse { points })
     }
     .await;
@@ -131,13 +149,17 @@ async fn query_points_batch(
 
     let result = async {
         let mut batch = Vec::with_capacity(searches.len());
+        let mut highlights = Vec::with_capacity(searches.len());
 
         for request_item in searches {
             let QueryRequest {
                 internal,
                 shard_key,
+                highlight,
             } = request_item;
 
+            highlights.push(highlight.zip(internal.filter.clone()));
+
             let CollectionQueryRequestWithUsage { request, usage } =
                 convert_query_request_from_rest(internal, &inference_params).await?;
 
@@ -160,6 +182,25 @@ async fn query_points_batch(
         )
         .await?;
 
+        let mut highlighters = Vec::with_capacity(highlights.len());
+        for highlight in highlights {
+            let highlighter = match highlight {
+                Some((highlight, filter)) => Some(
+                    dispatcher
+                        .toc(&auth, &pass)
+                        .payload_highlighter(
+                            &collection.name,
+                            &filter,
+                            highlight.into(),
+                            auth.clone(),
+                        )
+                        .await?,
+                ),
+                None => None,
+            };
+            highlighters.push(highlighter);
+        }
+
         let res = dispatcher
             .toc(&auth, &pass)
             .query_batch(
@@ -172,11 +213,16 @@ async fn query_points_batch(
             )
             .await?
             .into_iter()
-            .map(|response| QueryResponse {
-                points: response
+            .zip(highlighters)
+            .map(|(response, highlighter)| {
+                let mut points = response
                     .into_iter()
                     .map(api::rest::ScoredPoint::from)
-                    .collect_vec(),
+                    .collect_vec();
+                if let Some(highlighter) = &highlighter {
+                    highlight_points(highlighter, &mut points);
+                }
+                QueryResponse { points }
             })
             .collect_vec();
         Ok(res)
@@ -261,6 +307,25 @@ async fn query_points_groups(
     )
 }
 
+/// Fill in matches of full-text conditions, found in the payload of each point
+fn highlight_points(highlighter: &PayloadHighlighter, points: &mut [api::rest::ScoredPoint]) {
+    for point in points {
+        let Some(payload) = &point.payload else {
+            continue;
+        };
+
+        let highlight = highlighter.highlight(payload);
+        if !highlight.is_empty() {
+            point.highlight = Some(
+                highlight
+                    .into_iter()
+                    .map(|(key, highlights)| (key.to_string(), highlights))
+                    .collect(),
+            );
+        }
+    }
+}
+
 pub fn config_query_api(cfg: &mut web::ServiceConfig) {
     cfg.service(query_points);
     cfg.service(query_points_batch);
@@ -12,7 +12,7 @@ use segment::types::{PointIdType, WithPayloadInterface};
 use serde::Deserialize;
 use shard::retrieve::record_internal::RecordInternal;
 use storage::content_manager::collection_verification::{
-    check_strict_mode, check_strict_mode_timeout,
+    check_strict_mode, enabled_strict_mode_config,
 };
 use storage::content_manager::errors::StorageError;
 use storage::content_manager::toc::TableOfContent;
@@ -40,18 +40,12 @@ struct PointPath {
 async fn do_get_point(
     toc: &TableOfContent,
     collection_name: &str,
-    point_id: PointIdType,
+    request: PointRequestInternal,
     read_consistency: Option<ReadConsistency>,
     timeout: Option<Duration>,
     auth: Auth,
     hw_counter: HwMeasurementAcc,
 ) -> Result<Option<RecordInternal>, StorageError> {
-    let request = PointRequestInternal {
-        ids: vec![point_id],
-        with_payload: Some(WithPayloadInterface::Bool(true)),
-        with_vector: true.into(),
-    };
-
     let shard_selection = ShardSelectorInternal::All;
 
     toc.retrieve(
@@ -76,7 +70,29 @@ async fn get_point(
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
-    let pass = match check_strict_mode_timeout(
+    // Vectors are returned by default, unless disabled in strict mode
+    let with_vector = match enabled_strict_mode_config(&collection.name, &dispatcher, &auth).await {
+        Ok(config) => config
+            .and_then(|config| config.read_vectors_by_default)
+            .unwrap_or(true),
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
+    let Ok(point_id) = point.id.parse::<PointIdType>() else {
+        let err = StorageError::BadInput {
+            description: format!("Can not recognize \"{}\" as point id", point.id),
+        };
+        return process_response_error(err, Instant::now(), None);
+    };
+
+    let request = PointRequestInternal {
+        ids: vec![point_id],
+        with_payload: Some(WithPayloadInterface::Bool(true)),
+        with_vector: with_vector.into(),
+    };
+
+    let pass = match check_strict_mode(
+        &request,
         params.timeout_as_secs(),
         &collection.name,
         &dispatcher,
@@ -88,13 +104,6 @@ async fn get_point(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
-    let Ok(point_id) = point.id.parse::<PointIdType>() else {
-        let err = StorageError::BadInput {
-            description: format!("Can not recognize \"{}\" as point id", point.id),
-        };
-        return process_response_error(err, Instant::now(), None);
-    };
-
     let request_hw_counter = get_request_hardware_counter(
         &dispatcher,
         collection.name.clone(),
@@ -106,7 +115,7 @@ async fn get_point(
     let res = do_get_point(
         dispatcher.toc(&auth, &pass),
         &collection.name,
-        point_id,
+        request,
         params.consistency,
         params.timeout(),
         auth,
@@ -132,7 +141,13 @@ async fn get_points(
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
-    let pass = match check_strict_mode_timeout(
+    let PointRequest {
+        point_request,
+        shard_key,
+    } = request.into_inner();
+
+    let pass = match check_strict_mode(
+        &point_request,
         params.timeout_as_secs(),
         &collection.name,
         &dispatcher,
@@ -144,11 +159,6 @@ async fn get_points(
         Err(err) => return process_response_error(err, Instant::now(), None),
     };
 
-    let PointRequest {
-        point_request,
-        shard_key,
-    } = request.into_inner();
-
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
         Some(shard_keys) => ShardSelectorInternal::from(shard_keys),
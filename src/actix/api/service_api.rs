@@ -1,5 +1,4 @@
 use std::future::Future;
-use std::sync::Arc;
 use std::time::Duration;
 
 use actix_web::http::StatusCode;
@@ -22,7 +21,7 @@ use validator::Validate;
 use super::CollectionPath;
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{self, process_response_error};
-use crate::common::health;
+use crate::common::health_report::{HealthLevel, HealthReporter};
 use crate::common::metrics::MetricsData;
 use crate::common::stacktrace::get_stack_trace;
 use crate::common::telemetry::TelemetryCollector;
@@ -147,22 +146,89 @@ async fn healthz() -> impl Responder {
     kubernetes_healthz()
 }
 
+#[derive(Deserialize, Serialize, JsonSchema, Validate)]
+pub struct HealthParams {
+    /// If true, report status and latency of each sub-check as JSON
+    pub verbose: Option<bool>,
+}
+
 #[get("/livez")]
-async fn livez() -> impl Responder {
-    kubernetes_healthz()
+async fn livez(
+    health_reporter: web::Data<HealthReporter>,
+    params: Query<HealthParams>,
+) -> impl Responder {
+    health_response(
+        &health_reporter,
+        HealthLevel::Live,
+        &params,
+        "healthz check passed",
+    )
+    .await
+}
+
+#[get("/startupz")]
+async fn startupz(
+    health_reporter: web::Data<HealthReporter>,
+    params: Query<HealthParams>,
+) -> impl Responder {
+    health_response(
+        &health_reporter,
+        HealthLevel::Startup,
+        &params,
+        "node is started",
+    )
+    .await
 }
 
 #[get("/readyz")]
-async fn readyz(health_checker: web::Data<Option<Arc<health::HealthChecker>>>) -> impl Responder {
-    let is_ready = match health_checker.as_ref() {
-        Some(health_checker) => health_checker.check_ready().await,
-        None => true,
+async fn readyz(
+    health_reporter: web::Data<HealthReporter>,
+    params: Query<HealthParams>,
+) -> impl Responder {
+    // Standby peer refuses client requests, it must only be added to load balancing once promoted
+    if health_reporter.is_standby() {
+        return HttpResponse::build(StatusCode::SERVICE_UNAVAILABLE)
+            .content_type(ContentType::plaintext())
+            .body("node is in standby");
+    }
+
+    health_response(
+        &health_reporter,
+        HealthLevel::Ready,
+        &params,
+        "all shards are ready",
+    )
+    .await
+}
+
+async fn health_response(
+    health_reporter: &HealthReporter,
+    level: HealthLevel,
+    params: &HealthParams,
+    healthy_message: &str,
+) -> HttpResponse {
+    let report = health_reporter.report(level).await;
+
+    let status = if report.healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
     };
 
-    let (status, body) = if is_ready {
-        (StatusCode::OK, "all shards are ready")
+    if params.verbose.unwrap_or(false) {
+        return HttpResponse::build(status).json(report);
+    }
+
+    let body = if report.healthy {
+        healthy_message.to_string()
     } else {
-        (StatusCode::SERVICE_UNAVAILABLE, "some shards are not ready")
+        // Reason of the first failed required check
+        report
+            .checks
+            .iter()
+            .filter(|check| check.required)
+            .find_map(|check| check.message.clone())
+            .unwrap_or_default()
     };
 
     HttpResponse::build(status)
@@ -259,6 +325,7 @@ pub fn config_service_api(cfg: &mut web::ServiceConfig) {
         .service(get_stacktrace)
         .service(healthz)
         .service(livez)
+        .service(startupz)
         .service(readyz)
         .service(get_logger_config)
         .service(update_logger_config)
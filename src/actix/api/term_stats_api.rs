@@ -0,0 +1,41 @@
+use actix_web::{Responder, post, web};
+use actix_web_validator::{Json, Path, Query};
+use api::rest::TermStatsRequest;
+use collection::operations::verification::new_unchecked_verification_pass;
+use storage::dispatcher::Dispatcher;
+use tokio::time::Instant;
+
+use crate::actix::api::CollectionPath;
+use crate::actix::api::read_params::ReadParams;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers::process_response;
+
+#[post("/collections/{name}/terms/stats")]
+async fn term_stats(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<TermStatsRequest>,
+    params: Query<ReadParams>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    // Statistics are read from the full-text index only, no strict mode checks apply
+    let pass = new_unchecked_verification_pass();
+
+    let response = dispatcher
+        .toc(&auth, &pass)
+        .term_stats(
+            &collection.name,
+            request.into_inner().into(),
+            auth,
+            params.timeout(),
+        )
+        .await;
+
+    process_response(response, timing, None)
+}
+
+pub fn config_term_stats_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(term_stats);
+}
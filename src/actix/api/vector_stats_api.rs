@@ -0,0 +1,51 @@
+use actix_web::{Responder, post, web};
+use actix_web_validator::{Json, Path, Query};
+use api::rest::VectorStatsRequest;
+use collection::operations::verification::new_unchecked_verification_pass;
+use storage::dispatcher::Dispatcher;
+use tokio::time::Instant;
+
+use crate::actix::api::CollectionPath;
+use crate::actix::api::read_params::ReadParams;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers::{get_request_hardware_counter, process_response};
+use crate::settings::ServiceConfig;
+
+#[post("/collections/{name}/vectors/stats")]
+async fn vector_stats(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<VectorStatsRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    // Sampling is bounded by request validation, no strict mode checks apply
+    let pass = new_unchecked_verification_pass();
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+
+    let response = dispatcher
+        .toc(&auth, &pass)
+        .vector_stats(
+            &collection.name,
+            request.into_inner().into(),
+            auth,
+            params.timeout(),
+            request_hw_counter.get_counter(),
+        )
+        .await;
+
+    process_response(response, timing, request_hw_counter.to_rest_api())
+}
+
+pub fn config_vector_stats_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(vector_stats);
+}
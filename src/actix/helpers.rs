@@ -233,6 +233,8 @@ impl HttpError {
             StorageError::PreconditionFailed { .. } => {}
             StorageError::InferenceError { .. } => {}
             StorageError::ShardUnavailable { .. } => {}
+            StorageError::QuotaExceeded { .. } => {}
+            StorageError::NodeStandby { .. } => {}
             StorageError::EmptyPartialSnapshot { .. } => {}
         }
         headers
@@ -255,6 +257,8 @@ impl ResponseError for HttpError {
             StorageError::InferenceError { .. } => http::StatusCode::BAD_REQUEST,
             StorageError::RateLimitExceeded { .. } => http::StatusCode::TOO_MANY_REQUESTS,
             StorageError::ShardUnavailable { .. } => http::StatusCode::SERVICE_UNAVAILABLE,
+            StorageError::QuotaExceeded { .. } => http::StatusCode::TOO_MANY_REQUESTS,
+            StorageError::NodeStandby { .. } => http::StatusCode::SERVICE_UNAVAILABLE,
             StorageError::EmptyPartialSnapshot { .. } => http::StatusCode::NOT_MODIFIED,
         }
     }
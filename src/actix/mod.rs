@@ -27,10 +27,16 @@ use crate::actix::api::cluster_api::config_cluster_api;
 use crate::actix::api::collections_api::config_collections_api;
 use crate::actix::api::count_api::count_points;
 use crate::actix::api::debug_api::config_debugger_api;
+use crate::actix::api::deduplication_api::config_deduplication_api;
 use crate::actix::api::discovery_api::config_discovery_api;
+use crate::actix::api::explain_filter_api::config_explain_filter_api;
+use crate::actix::api::hnsw_graph_api::config_hnsw_graph_api;
 use crate::actix::api::issues_api::config_issues_api;
 use crate::actix::api::local_shard_api::config_local_shard_api;
+use crate::actix::api::payload_index_compaction_api::config_payload_index_compaction_api;
+use crate::actix::api::payload_migration_api::config_payload_migration_api;
 use crate::actix::api::profiler_api::config_profiler_api;
+use crate::actix::api::quantiles_api::config_quantiles_api;
 use crate::actix::api::query_api::config_query_api;
 use crate::actix::api::recommend_api::config_recommend_api;
 use crate::actix::api::retrieve_api::{get_point, get_points, scroll_points};
@@ -38,13 +44,17 @@ use crate::actix::api::search_api::config_search_api;
 use crate::actix::api::service_api::config_service_api;
 use crate::actix::api::shards_api::config_shards_api;
 use crate::actix::api::snapshot_api::config_snapshots_api;
+use crate::actix::api::term_stats_api::config_term_stats_api;
 use crate::actix::api::update_api::config_update_api;
+use crate::actix::api::vector_stats_api::config_vector_stats_api;
 use crate::actix::auth::{AuthTransform, WhitelistItem};
 use crate::actix::web_ui::{WEB_UI_PATH, web_ui_factory, web_ui_folder};
 use crate::common::auth::AuthKeys;
 use crate::common::debugger::DebuggerState;
 use crate::common::health;
+use crate::common::health_report::HealthReporter;
 use crate::common::http_client::HttpClient;
+use crate::common::shutdown::wait_stop_signal;
 use crate::common::telemetry::TelemetryCollector;
 use crate::settings::{Settings, max_web_workers};
 use crate::tracing::LoggerHandle;
@@ -78,7 +88,13 @@ pub fn init(
         let telemetry_collector_data = web::Data::from(telemetry_collector);
         let logger_handle_data = web::Data::new(logger_handle);
         let http_client = web::Data::new(HttpClient::from_settings(&settings)?);
-        let health_checker = web::Data::new(health_checker);
+        let health_reporter = web::Data::new(HealthReporter::new(
+            dispatcher_data.toc(&auth, &pass).clone(),
+            dispatcher_data.consensus_state().cloned(),
+            health_checker,
+            settings.gpu.as_ref().is_some_and(|gpu| gpu.indexing),
+            settings.service.readiness_strictness,
+        ));
         let web_ui_available = web_ui_folder(&settings);
         let service_config = web::Data::new(settings.service.clone());
 
@@ -87,6 +103,7 @@ pub fn init(
             WhitelistItem::exact("/healthz"),
             WhitelistItem::prefix("/readyz"),
             WhitelistItem::prefix("/livez"),
+            WhitelistItem::prefix("/startupz"),
         ];
         if web_ui_available.is_some() {
             api_key_whitelist.push(WhitelistItem::prefix(WEB_UI_PATH));
@@ -123,7 +140,8 @@ pub fn init(
                         .exclude("/telemetry")
                         .exclude("/healthz")
                         .exclude("/readyz")
-                        .exclude("/livez"),
+                        .exclude("/livez")
+                        .exclude("/startupz"),
                 )
                 .wrap(actix_telemetry::ActixTelemetryTransform::new(
                     actix_telemetry_collector.clone(),
@@ -133,7 +151,7 @@ pub fn init(
                 .app_data(logger_handle_data.clone())
                 .app_data(http_client.clone())
                 .app_data(debugger_state.clone())
-                .app_data(health_checker.clone())
+                .app_data(health_reporter.clone())
                 .app_data(validate_path_config)
                 .app_data(validate_query_config)
                 .app_data(validate_json_config)
@@ -151,11 +169,19 @@ pub fn init(
                 .configure(config_discovery_api)
                 .configure(config_query_api)
                 .configure(config_facet_api)
+                .configure(config_vector_stats_api)
+                .configure(config_quantiles_api)
+                .configure(config_explain_filter_api)
+                .configure(config_term_stats_api)
+                .configure(config_deduplication_api)
+                .configure(config_payload_migration_api)
+                .configure(config_payload_index_compaction_api)
                 .configure(config_shards_api)
                 .configure(config_issues_api)
                 .configure(config_debugger_api)
                 .configure(config_profiler_api)
                 .configure(config_local_shard_api)
+                .configure(config_hnsw_graph_api)
                 // Ordering of services is important for correct path pattern matching
                 // See: <https://github.com/qdrant/qdrant/issues/3543>
                 .service(scroll_points)
@@ -169,7 +195,10 @@ pub fn init(
 
             app
         })
-        .workers(max_web_workers(&settings));
+        .workers(max_web_workers(&settings))
+        .shutdown_timeout(settings.service.shutdown_grace_period().as_secs())
+        // Stop signals are handled below, to also react on CTRL_CLOSE on Windows
+        .disable_signals();
 
         let port = settings.service.http_port;
         let bind_addr = format!("{}:{}", settings.service.host, port);
@@ -196,7 +225,16 @@ pub fn init(
         };
 
         log::info!("Qdrant HTTP listening on {port}");
-        server.run().await
+        let server = server.run();
+
+        let server_handle = server.handle();
+        actix_web::rt::spawn(async move {
+            wait_stop_signal("REST API").await;
+            // Stop accepting new connections and wait for in-flight requests
+            server_handle.stop(true).await;
+        });
+
+        server.await
     })
 }
 
@@ -82,6 +82,7 @@ mod tests {
                 access: CollectionAccessMode::ReadWrite,
                 #[expect(deprecated)]
                 payload: None,
+                tags: None,
             }])),
             value_exists: None,
             subject: None,
@@ -113,6 +114,7 @@ mod tests {
                     "field2": 42,
                     "field3": true,
                 })),
+                tags: None,
             }])),
             value_exists: None,
             subject: None,
@@ -170,6 +170,8 @@ impl AuthKeys {
             with_payload: Some(WithPayloadInterface::Bool(false)),
             with_vector: WithVector::Bool(false),
             order_by: None,
+            consistent: None,
+            view_id: None,
         };
 
         let res = self
@@ -0,0 +1,501 @@
+use std::collections::{HashMap, HashSet};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use collection::collection_state::State;
+use collection::operations::cluster_ops::{
+    ClusterOperations, ReplicateShard, ReplicateShardOperation,
+};
+use collection::operations::config_diff::CollectionParamsDiff;
+use collection::operations::verification::new_unchecked_verification_pass;
+use collection::shards::replica_set;
+use collection::shards::replica_set::replica_set_state::ReplicaState;
+use collection::shards::shard::{PeerId, ShardId};
+use itertools::Itertools;
+use segment::common::operation_time_statistics::OperationDurationStatistics;
+use storage::content_manager::collection_meta_ops::{
+    CollectionMetaOperations, UpdateCollectionOperation,
+};
+use storage::content_manager::errors::StorageResult;
+use storage::dispatcher::Dispatcher;
+use storage::rbac::{Access, Auth, AuthType};
+use tokio::time::MissedTickBehavior;
+
+use crate::common::collections::do_update_collection_cluster;
+use crate::settings::AutoReplicaScalingConfig;
+
+const AUTO_SCALING_ACCESS: Access = Access::full("Auto replica scaling");
+
+/// Subject of the audit log entries for operations issued by the controller
+const AUTO_SCALING_SUBJECT: &str = "auto_replica_scaling";
+
+fn auto_scaling_auth() -> Auth {
+    Auth::new(
+        AUTO_SCALING_ACCESS,
+        Some(AUTO_SCALING_SUBJECT.to_string()),
+        None,
+        AuthType::Internal,
+    )
+}
+
+/// Controller raising or lowering the replication factor of collections, based on the query load.
+///
+/// Runs on every peer, but only the consensus leader makes scaling decisions,
+/// so that peers with different views of the load don't contradict each other.
+pub struct AutoReplicaScaling {
+    config: AutoReplicaScalingConfig,
+    dispatcher: Arc<Dispatcher>,
+    collections: HashMap<String, CollectionLoad>,
+}
+
+/// Load of a collection, as seen on the previous check
+#[derive(Default)]
+struct CollectionLoad {
+    /// Total number of reads per shard
+    reads: HashMap<ShardId, usize>,
+    measured_at: Option<Instant>,
+    last_scaled_at: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ObservedLoad {
+    /// Reads per second of the busiest shard
+    reads_per_sec: f64,
+    /// Highest average read latency among shards
+    latency: Option<Duration>,
+}
+
+impl CollectionLoad {
+    /// Record the current read statistics, and return the load since the previous check
+    fn observe(
+        &mut self,
+        statistics: &[(ShardId, OperationDurationStatistics)],
+        now: Instant,
+    ) -> Option<ObservedLoad> {
+        let reads = statistics
+            .iter()
+            .map(|(shard_id, stats)| (*shard_id, stats.count))
+            .collect();
+        let previous_reads = std::mem::replace(&mut self.reads, reads);
+        let elapsed = now.duration_since(self.measured_at.replace(now)?);
+
+        if elapsed.is_zero() {
+            return None;
+        }
+
+        let reads_per_sec = statistics
+            .iter()
+            .map(|(shard_id, stats)| {
+                let previous = previous_reads.get(shard_id).copied().unwrap_or_default();
+                stats.count.saturating_sub(previous) as f64 / elapsed.as_secs_f64()
+            })
+            .fold(0.0, f64::max);
+
+        let latency = statistics
+            .iter()
+            .filter_map(|(_, stats)| stats.avg_duration_micros)
+            .map(|micros| Duration::from_micros(micros as u64))
+            .max();
+
+        Some(ObservedLoad {
+            reads_per_sec,
+            latency,
+        })
+    }
+
+    fn in_cooldown(&self, now: Instant, cooldown: Duration) -> bool {
+        self.last_scaled_at
+            .is_some_and(|scaled_at| now.duration_since(scaled_at) < cooldown)
+    }
+}
+
+impl AutoReplicaScaling {
+    pub async fn run(config: AutoReplicaScalingConfig, dispatcher: Arc<Dispatcher>) {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.check_interval_sec));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut controller = Self {
+            config,
+            dispatcher,
+            collections: HashMap::new(),
+        };
+
+        loop {
+            interval.tick().await;
+            if let Err(err) = controller.check().await {
+                log::error!("Auto replica scaling check failed: {err}");
+            }
+        }
+    }
+
+    async fn check(&mut self) -> StorageResult<()> {
+        let Some(consensus_state) = self.dispatcher.consensus_state() else {
+            return Ok(());
+        };
+
+        if !consensus_state.is_leader() {
+            // Load observed before becoming leader again would be outdated
+            self.collections.clear();
+            return Ok(());
+        }
+
+        let peers = consensus_state.peers();
+        let cooldown = Duration::from_secs(self.config.cooldown_sec);
+        let now = Instant::now();
+
+        let auth = auto_scaling_auth();
+        let pass = new_unchecked_verification_pass();
+        let toc = self.dispatcher.toc(&auth, &pass).clone();
+
+        let mut seen_collections = HashSet::new();
+
+        for collection_pass in toc.all_collections(&AUTO_SCALING_ACCESS).await {
+            let collection_name = collection_pass.name().to_string();
+            let Ok(collection) = toc.get_collection(&collection_pass).await else {
+                continue;
+            };
+            seen_collections.insert(collection_name.clone());
+
+            let statistics = collection.get_read_statistics().await;
+            let collection_load = self.collections.entry(collection_name.clone()).or_default();
+
+            let Some(load) = collection_load.observe(&statistics, now) else {
+                continue;
+            };
+
+            if collection_load.in_cooldown(now, cooldown) {
+                continue;
+            }
+
+            let state = collection.state().await;
+
+            // Let ongoing replications and resharding settle before changing anything
+            if !state.transfers.is_empty() || state.resharding.is_some() {
+                continue;
+            }
+
+            // Only peers satisfying the placement constraints may hold replicas
+            let allowed_peers = match &state.config.params.placement {
+                Some(placement) => {
+                    match toc.filter_placement_peers(peers.clone(), placement).await {
+                        Ok(allowed_peers) => allowed_peers,
+                        Err(err) => {
+                            log::warn!(
+                                "Auto replica scaling: skipping collection {collection_name}: {err}"
+                            );
+                            continue;
+                        }
+                    }
+                }
+                None => peers.clone(),
+            };
+
+            let current = state.config.params.replication_factor.get();
+            let Some(target) =
+                decide_replication_factor(&self.config, load, current, allowed_peers.len())
+            else {
+                continue;
+            };
+
+            log::info!(
+                "Auto replica scaling: changing replication factor of collection {collection_name} \
+                 from {current} to {target}, reads per second: {:.1}, latency: {:?}",
+                load.reads_per_sec,
+                load.latency,
+            );
+
+            // Start the cooldown even if scaling fails, to not retry on every check
+            collection_load.last_scaled_at = Some(now);
+
+            if let Err(err) = scale_collection(
+                &self.dispatcher,
+                &collection_name,
+                &state,
+                target,
+                &allowed_peers,
+            )
+            .await
+            {
+                log::error!(
+                    "Auto replica scaling: failed to change replication factor of collection {collection_name}: {err}",
+                );
+            }
+        }
+
+        self.collections
+            .retain(|collection_name, _| seen_collections.contains(collection_name));
+
+        Ok(())
+    }
+}
+
+/// Decide on the new replication factor for the observed load.
+///
+/// Returns `None` if the replication factor should stay the same.
+fn decide_replication_factor(
+    config: &AutoReplicaScalingConfig,
+    load: ObservedLoad,
+    current: u32,
+    peer_count: usize,
+) -> Option<u32> {
+    let upper_bound = config.max_replication_factor.min(peer_count as u32);
+    let lower_bound = config.min_replication_factor.min(upper_bound);
+
+    let has_reads = load.reads_per_sec > 0.0;
+
+    let too_many_reads = config
+        .scale_up_reads_per_sec
+        .is_some_and(|limit| load.reads_per_sec > limit);
+
+    let too_slow = config.scale_up_latency_ms.is_some_and(|limit| {
+        load.latency
+            .is_some_and(|latency| latency > Duration::from_millis(limit))
+    });
+
+    let too_few_reads = config
+        .scale_down_reads_per_sec
+        .is_some_and(|limit| load.reads_per_sec < limit);
+
+    let target = if has_reads && (too_many_reads || too_slow) {
+        current + 1
+    } else if too_few_reads {
+        current.saturating_sub(1)
+    } else {
+        current
+    };
+
+    let target = target.clamp(lower_bound, upper_bound);
+    (target != current).then_some(target)
+}
+
+/// Change the replication factor of the collection, and add or remove replicas to match it
+async fn scale_collection(
+    dispatcher: &Dispatcher,
+    collection_name: &str,
+    state: &State,
+    target: u32,
+    peers: &[PeerId],
+) -> StorageResult<()> {
+    // Number of replicas of this collection on each peer, used to balance the placement
+    let mut peer_load: HashMap<PeerId, usize> = peers.iter().map(|&peer_id| (peer_id, 0)).collect();
+    for shard_info in state.shards.values() {
+        for peer_id in shard_info.replicas.keys() {
+            *peer_load.entry(*peer_id).or_default() += 1;
+        }
+    }
+
+    let mut update_operation = UpdateCollectionOperation::new_empty(collection_name.to_string());
+    update_operation.update_collection.params = Some(CollectionParamsDiff {
+        replication_factor: NonZeroU32::new(target),
+        write_consistency_factor: None,
+        read_fan_out_factor: None,
+        read_fan_out_delay_ms: None,
+        query_cache_max_entries: None,
+        query_cache_ttl_sec: None,
+        on_disk_payload: None,
+        non_finite_vector_policy: None,
+        default_search_params: None,
+    });
+
+    let mut replications = Vec::new();
+    let mut removals = Vec::new();
+
+    for (&shard_id, shard_info) in state.shards.iter().sorted_by_key(|(id, _)| **id) {
+        let replicas = &shard_info.replicas;
+        let replica_count = replicas.len() as u32;
+
+        if replica_count < target {
+            let Some(&from_peer_id) = replicas
+                .iter()
+                .filter(|(_, replica_state)| **replica_state == ReplicaState::Active)
+                .map(|(peer_id, _)| peer_id)
+                .min_by_key(|peer_id| peer_load[*peer_id])
+            else {
+                continue;
+            };
+
+            let candidates = peer_load
+                .keys()
+                .copied()
+                .filter(|peer_id| peers.contains(peer_id) && !replicas.contains_key(peer_id))
+                .sorted_by_key(|peer_id| (peer_load[peer_id], *peer_id))
+                .take((target - replica_count) as usize)
+                .collect_vec();
+
+            for to_peer_id in candidates {
+                *peer_load.entry(to_peer_id).or_default() += 1;
+                replications.push(ReplicateShard {
+                    shard_id,
+                    to_shard_id: None,
+                    to_peer_id,
+                    from_peer_id,
+                    method: None,
+                });
+            }
+        } else if replica_count > target {
+            // Remove non-active replicas first, then the ones on the busiest peers,
+            // but always keep at least one active replica
+            let mut remaining_active = replicas
+                .values()
+                .filter(|replica_state| **replica_state == ReplicaState::Active)
+                .count();
+
+            let candidates = replicas
+                .iter()
+                .sorted_by_key(|(peer_id, replica_state)| {
+                    (
+                        **replica_state == ReplicaState::Active,
+                        std::cmp::Reverse(peer_load[*peer_id]),
+                        **peer_id,
+                    )
+                })
+                .take((replica_count - target) as usize);
+
+            for (&peer_id, replica_state) in candidates {
+                if *replica_state == ReplicaState::Active {
+                    if remaining_active <= 1 {
+                        break;
+                    }
+                    remaining_active -= 1;
+                }
+
+                *peer_load.entry(peer_id).or_default() -= 1;
+                removals.push(replica_set::Change::Remove(shard_id, peer_id));
+            }
+        }
+    }
+
+    update_operation.set_shard_replica_changes(removals);
+
+    dispatcher
+        .submit_collection_meta_op(
+            CollectionMetaOperations::UpdateCollection(update_operation),
+            auto_scaling_auth(),
+            None,
+        )
+        .await?;
+
+    for replicate_shard in replications {
+        let ReplicateShard {
+            shard_id,
+            to_peer_id,
+            ..
+        } = replicate_shard;
+
+        let result = do_update_collection_cluster(
+            dispatcher,
+            collection_name.to_string(),
+            ClusterOperations::ReplicateShard(ReplicateShardOperation { replicate_shard }),
+            auto_scaling_auth(),
+            None,
+        )
+        .await;
+
+        if let Err(err) = result {
+            log::warn!(
+                "Auto replica scaling: failed to replicate shard {collection_name}:{shard_id} to peer {to_peer_id}: {err}",
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> AutoReplicaScalingConfig {
+        AutoReplicaScalingConfig {
+            enabled: true,
+            min_replication_factor: 1,
+            max_replication_factor: 3,
+            scale_up_reads_per_sec: Some(100.0),
+            scale_up_latency_ms: Some(500),
+            scale_down_reads_per_sec: Some(10.0),
+            check_interval_sec: 30,
+            cooldown_sec: 600,
+        }
+    }
+
+    fn load(reads_per_sec: f64, latency_ms: u64) -> ObservedLoad {
+        ObservedLoad {
+            reads_per_sec,
+            latency: Some(Duration::from_millis(latency_ms)),
+        }
+    }
+
+    #[test]
+    fn test_decide_replication_factor() {
+        let config = config();
+
+        // Too many reads
+        assert_eq!(
+            decide_replication_factor(&config, load(200.0, 10), 1, 5),
+            Some(2),
+        );
+        // Too slow
+        assert_eq!(
+            decide_replication_factor(&config, load(50.0, 1000), 2, 5),
+            Some(3),
+        );
+        // Stale latency without reads is ignored
+        assert_eq!(
+            decide_replication_factor(&config, load(0.0, 1000), 2, 5),
+            Some(1),
+        );
+        // Within bounds
+        assert_eq!(
+            decide_replication_factor(&config, load(50.0, 10), 2, 5),
+            None
+        );
+        // Upper bound by config
+        assert_eq!(
+            decide_replication_factor(&config, load(200.0, 10), 3, 5),
+            None,
+        );
+        // Upper bound by number of peers
+        assert_eq!(
+            decide_replication_factor(&config, load(200.0, 10), 2, 2),
+            None,
+        );
+        // Lower bound
+        assert_eq!(
+            decide_replication_factor(&config, load(1.0, 10), 1, 5),
+            None
+        );
+        assert_eq!(
+            decide_replication_factor(&config, load(1.0, 10), 3, 5),
+            Some(2),
+        );
+    }
+
+    #[test]
+    fn test_observe_collection_load() {
+        let stats = |count| OperationDurationStatistics {
+            count,
+            avg_duration_micros: Some(1000.0),
+            ..Default::default()
+        };
+
+        let start = Instant::now();
+        let mut collection_load = CollectionLoad::default();
+
+        // First observation only records the baseline
+        assert!(
+            collection_load
+                .observe(&[(0, stats(100)), (1, stats(50))], start)
+                .is_none(),
+        );
+
+        let load = collection_load
+            .observe(
+                &[(0, stats(300)), (1, stats(450))],
+                start + Duration::from_secs(10),
+            )
+            .unwrap();
+        assert_eq!(load.reads_per_sec, 40.0);
+        assert_eq!(load.latency, Some(Duration::from_millis(1)));
+    }
+}
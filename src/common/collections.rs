@@ -541,7 +541,7 @@ pub async fn do_update_collection_cluster(
                 });
             }
 
-            let peers_pool: Vec<_> = if let Some(placement) = create_sharding_key.placement {
+            let mut peers_pool: Vec<_> = if let Some(placement) = create_sharding_key.placement {
                 if placement.is_empty() {
                     return Err(StorageError::BadRequest {
                         description: format!(
@@ -559,6 +559,18 @@ pub async fn do_update_collection_cluster(
                 get_all_peer_ids()
             };
 
+            // Honor placement constraints of both the collection and the shard key
+            let toc = dispatcher.toc(&auth, &pass);
+            for constraints in [
+                state.config.params.placement.as_ref(),
+                create_sharding_key.placement_constraints.as_ref(),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                peers_pool = toc.filter_placement_peers(peers_pool, constraints).await?;
+            }
+
             let exact_placement =
                 generate_even_placement(peers_pool, shard_number, replication_factor);
 
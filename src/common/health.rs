@@ -301,45 +301,46 @@ impl Task {
             .unwrap_or(0)
     }
 
-    /// List shards that are unhealthy, which may undergo automatic recovery.
-    ///
-    /// Shards in resharding state are not considered unhealthy and are excluded here.
-    /// They require an external driver to make them active or to drop them.
     async fn unhealthy_shards(&self) -> HashSet<Shard> {
-        let this_peer_id = self.toc.this_peer_id;
-        let collections = self
-            .toc
-            .all_collections(&Access::full("For health check"))
-            .await;
+        unhealthy_shards(&self.toc).await
+    }
 
-        let mut unhealthy_shards = HashSet::new();
+    fn set_ready(&self) {
+        self.is_ready.store(true, atomic::Ordering::Relaxed);
+        self.is_ready_signal.notify_waiters();
+    }
+}
 
-        for collection_pass in &collections {
-            let state = match self.toc.get_collection(collection_pass).await {
-                Ok(collection) => collection.state().await,
-                Err(_) => continue,
-            };
+/// List local shards that are unhealthy, which may undergo automatic recovery.
+///
+/// Shards in resharding state are not considered unhealthy and are excluded here.
+/// They require an external driver to make them active or to drop them.
+pub async fn unhealthy_shards(toc: &TableOfContent) -> HashSet<Shard> {
+    let this_peer_id = toc.this_peer_id;
+    let collections = toc.all_collections(&Access::full("For health check")).await;
 
-            for (&shard, info) in state.shards.iter() {
-                let Some(state) = info.replicas.get(&this_peer_id) else {
-                    continue;
-                };
+    let mut unhealthy_shards = HashSet::new();
 
-                if state.is_healthy() {
-                    continue;
-                }
+    for collection_pass in &collections {
+        let state = match toc.get_collection(collection_pass).await {
+            Ok(collection) => collection.state().await,
+            Err(_) => continue,
+        };
 
-                unhealthy_shards.insert(Shard::new(collection_pass.name(), shard));
+        for (&shard, info) in state.shards.iter() {
+            let Some(state) = info.replicas.get(&this_peer_id) else {
+                continue;
+            };
+
+            if state.is_healthy() {
+                continue;
             }
-        }
 
-        unhealthy_shards
+            unhealthy_shards.insert(Shard::new(collection_pass.name(), shard));
+        }
     }
 
-    fn set_ready(&self) {
-        self.is_ready.store(true, atomic::Ordering::Relaxed);
-        self.is_ready_signal.notify_waiters();
-    }
+    unhealthy_shards
 }
 
 fn get_consensus_commit<'a>(
@@ -365,7 +366,7 @@ type GetConsensusCommitResult = Result<
 >;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-struct Shard {
+pub struct Shard {
     collection: CollectionId,
     shard: ShardId,
 }
@@ -0,0 +1,286 @@
+use std::future::Future;
+use std::io::Write as _;
+use std::sync::Arc;
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use storage::content_manager::consensus_manager::ConsensusStateRef;
+use storage::content_manager::toc::TableOfContent;
+use storage::types::{ClusterStatus, ConsensusThreadStatus};
+use tokio::time::{self, Instant};
+
+use crate::common::health::{self, HealthChecker};
+use crate::settings::ReadinessStrictness;
+
+/// Maximal duration of a single health sub-check, slower checks are failed
+const HEALTH_SUB_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Level of the health endpoint, defines which sub-checks are run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthLevel {
+    /// `/livez`: the process is up and serves requests, dependencies are not checked
+    Live,
+    /// `/startupz`: the node caught up with the cluster and recovered its shards after start
+    Startup,
+    /// `/readyz`: the node is started and its dependencies are available
+    Ready,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckName {
+    /// Node caught up with the cluster and recovered its shards after start
+    Startup,
+    /// Consensus thread is running and the Raft leader is known
+    Consensus,
+    /// Files can be written to the storage directory
+    Storage,
+    /// All local shards are active
+    Collections,
+    /// GPU device is available for indexing, if GPU indexing is enabled
+    Gpu,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckStatus {
+    Ok,
+    Failed,
+    /// Check does not apply to this node, e.g. consensus of a single node deployment
+    Skipped,
+}
+
+/// Result of a single health sub-check
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct HealthCheckReport {
+    pub name: HealthCheckName,
+    pub status: HealthCheckStatus,
+    /// Whether failure of this check fails the endpoint
+    pub required: bool,
+    /// Duration of the check in milliseconds
+    pub latency_ms: f64,
+    /// Reason of the failure
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Detailed report of a health endpoint
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct HealthReport {
+    /// Whether all required checks passed
+    pub healthy: bool,
+    pub checks: Vec<HealthCheckReport>,
+}
+
+/// Outcome of a sub-check, `None` if the check was skipped
+type CheckOutcome = Option<Result<(), String>>;
+
+/// Runs health sub-checks of the `/livez`, `/startupz` and `/readyz` endpoints
+pub struct HealthReporter {
+    toc: Arc<TableOfContent>,
+    consensus_state: Option<ConsensusStateRef>,
+    /// Tracks startup of the node in distributed deployment
+    health_checker: Option<Arc<HealthChecker>>,
+    gpu_indexing: bool,
+    strictness: ReadinessStrictness,
+}
+
+impl HealthReporter {
+    pub fn new(
+        toc: Arc<TableOfContent>,
+        consensus_state: Option<ConsensusStateRef>,
+        health_checker: Option<Arc<HealthChecker>>,
+        gpu_indexing: bool,
+        strictness: ReadinessStrictness,
+    ) -> Self {
+        Self {
+            toc,
+            consensus_state,
+            health_checker,
+            gpu_indexing,
+            strictness,
+        }
+    }
+
+    pub fn is_standby(&self) -> bool {
+        self.toc.is_standby()
+    }
+
+    pub async fn report(&self, level: HealthLevel) -> HealthReport {
+        // Sub-checks of dependencies only fail readiness in strict mode
+        let strict = self.strictness == ReadinessStrictness::Strict;
+
+        let checks = match level {
+            HealthLevel::Live => Vec::new(),
+            HealthLevel::Startup => {
+                vec![run_check(HealthCheckName::Startup, true, self.check_startup()).await]
+            }
+            HealthLevel::Ready => {
+                let (startup, consensus, storage, collections, gpu) = tokio::join!(
+                    run_check(HealthCheckName::Startup, true, self.check_startup()),
+                    run_check(HealthCheckName::Consensus, strict, async {
+                        self.check_consensus()
+                    }),
+                    run_check(HealthCheckName::Storage, strict, self.check_storage()),
+                    run_check(
+                        HealthCheckName::Collections,
+                        strict,
+                        self.check_collections()
+                    ),
+                    run_check(HealthCheckName::Gpu, strict, async { self.check_gpu() }),
+                );
+                vec![startup, consensus, storage, collections, gpu]
+            }
+        };
+
+        let healthy = checks
+            .iter()
+            .all(|check| !check.required || check.status != HealthCheckStatus::Failed);
+
+        HealthReport { healthy, checks }
+    }
+
+    async fn check_startup(&self) -> CheckOutcome {
+        // Single node deployment is started, once it serves requests
+        let is_ready = match &self.health_checker {
+            Some(health_checker) => health_checker.check_ready().await,
+            None => true,
+        };
+
+        if is_ready {
+            Some(Ok(()))
+        } else {
+            Some(Err("some shards are not ready".to_string()))
+        }
+    }
+
+    fn check_consensus(&self) -> CheckOutcome {
+        let ClusterStatus::Enabled(info) = self.consensus_state.as_ref()?.cluster_status() else {
+            return None;
+        };
+
+        let outcome = match info.consensus_thread_status {
+            ConsensusThreadStatus::Working { .. } => {
+                // Raft uses 0 as the id of an unknown leader
+                if info.raft_info.leader.is_some_and(|leader| leader != 0) {
+                    Ok(())
+                } else {
+                    Err("Raft leader is unknown".to_string())
+                }
+            }
+            ConsensusThreadStatus::Stopped => Err("consensus thread is stopped".to_string()),
+            ConsensusThreadStatus::StoppedWithErr { err } => {
+                Err(format!("consensus thread stopped with error: {err}"))
+            }
+        };
+        Some(outcome)
+    }
+
+    async fn check_storage(&self) -> CheckOutcome {
+        let storage_path = self.toc.storage_path().to_path_buf();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let mut file = tempfile::Builder::new()
+                .prefix(".health_check")
+                .tempfile_in(&storage_path)?;
+            file.write_all(b"health check")?;
+            file.as_file().sync_all()?;
+            file.close()
+        })
+        .await;
+
+        let outcome = match result {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(err)) => Err(format!("storage is not writable: {err}")),
+            Err(err) => Err(format!("storage check failed: {err}")),
+        };
+        Some(outcome)
+    }
+
+    async fn check_collections(&self) -> CheckOutcome {
+        let unhealthy_shards = health::unhealthy_shards(&self.toc).await;
+
+        if unhealthy_shards.is_empty() {
+            Some(Ok(()))
+        } else {
+            Some(Err(format!(
+                "{} local shards are not active",
+                unhealthy_shards.len(),
+            )))
+        }
+    }
+
+    fn check_gpu(&self) -> CheckOutcome {
+        if !self.gpu_indexing {
+            return None;
+        }
+
+        #[cfg(feature = "gpu")]
+        {
+            let has_devices = segment::index::hnsw_index::gpu::GPU_DEVICES_MANAGER
+                .read()
+                .as_ref()
+                .is_some_and(|manager| !manager.all_found_device_names().is_empty());
+
+            if has_devices {
+                Some(Ok(()))
+            } else {
+                Some(Err("no GPU device is available for indexing".to_string()))
+            }
+        }
+
+        #[cfg(not(feature = "gpu"))]
+        Some(Err(
+            "GPU indexing is enabled, but Qdrant is built without GPU support".to_string(),
+        ))
+    }
+}
+
+async fn run_check(
+    name: HealthCheckName,
+    required: bool,
+    check: impl Future<Output = CheckOutcome>,
+) -> HealthCheckReport {
+    let start = Instant::now();
+
+    let outcome = time::timeout(HEALTH_SUB_CHECK_TIMEOUT, check)
+        .await
+        .unwrap_or_else(|_| Some(Err("check timed out".to_string())));
+
+    let (status, message) = match outcome {
+        None => (HealthCheckStatus::Skipped, None),
+        Some(Ok(())) => (HealthCheckStatus::Ok, None),
+        Some(Err(message)) => (HealthCheckStatus::Failed, Some(message)),
+    };
+
+    HealthCheckReport {
+        name,
+        status,
+        required,
+        latency_ms: start.elapsed().as_secs_f64() * 1000.0,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_check() {
+        let report = run_check(HealthCheckName::Storage, true, async { Some(Ok(())) }).await;
+        assert_eq!(report.status, HealthCheckStatus::Ok);
+        assert!(report.message.is_none());
+
+        let report = run_check(HealthCheckName::Gpu, false, async { None }).await;
+        assert_eq!(report.status, HealthCheckStatus::Skipped);
+
+        let report = run_check(HealthCheckName::Consensus, true, async {
+            Some(Err("Raft leader is unknown".to_string()))
+        })
+        .await;
+        assert_eq!(report.status, HealthCheckStatus::Failed);
+        assert_eq!(report.message.as_deref(), Some("Raft leader is unknown"));
+    }
+}
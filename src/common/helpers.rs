@@ -7,13 +7,23 @@ use tokio::runtime;
 use tokio::runtime::Runtime;
 use tonic::transport::{Certificate, ClientTlsConfig, Identity, ServerTlsConfig};
 
+use crate::common::search_concurrency;
 use crate::settings::{Settings, TlsConfig};
 
-pub fn create_search_runtime(max_search_threads: usize) -> io::Result<Runtime> {
+pub fn create_search_runtime(
+    max_search_threads: usize,
+    adaptive_search_concurrency: bool,
+) -> io::Result<Runtime> {
     let num_threads = common::defaults::search_thread_count(max_search_threads);
+    // With adaptive concurrency, the number of concurrent segment searches is limited separately
+    let max_blocking_threads = if adaptive_search_concurrency {
+        num_threads * search_concurrency::MAX_SEARCH_CONCURRENCY_FACTOR
+    } else {
+        num_threads
+    };
     runtime::Builder::new_multi_thread()
         .worker_threads(num_threads)
-        .max_blocking_threads(num_threads)
+        .max_blocking_threads(max_blocking_threads)
         .enable_all()
         .thread_name_fn(|| {
             static ATOMIC_ID: AtomicUsize = AtomicUsize::new(0);
@@ -108,6 +108,11 @@ fn collect_query(query: &Query, batch: &mut BatchAccum) {
         Query::RelevanceFeedback(relevance_feedback) => {
             collect_feedback_input(&relevance_feedback.relevance_feedback, batch)
         }
+        Query::VectorSelection(selection) => {
+            for vector in selection.vector_selection.queries.values() {
+                collect_vector_input(vector, batch);
+            }
+        }
         Query::OrderBy(_)
         | Query::Fusion(_)
         | Query::Rrf(_)
@@ -330,6 +335,7 @@ mod tests {
             query: Some(QueryInterface::Query(Query::Nearest(NearestQuery {
                 nearest: VectorInput::Document(create_test_document("test")),
                 mmr: None,
+                candidates: None,
             }))),
             prefetch: Some(vec![Prefetch {
                 query: Some(QueryInterface::Query(Query::Discover(DiscoverQuery {
@@ -194,7 +194,23 @@ pub(crate) fn collect_query(query: &Query, batch: &mut BatchAccumGrpc) -> Result
                 .map(|vector| collect_vector_input(vector, batch))
                 .transpose()?;
         }
+        query::Variant::NearestWithCandidates(nearest_with_candidates) => {
+            nearest_with_candidates
+                .nearest
+                .as_ref()
+                .map(|vector| collect_vector_input(vector, batch))
+                .transpose()?;
+        }
         query::Variant::RelevanceFeedback(feedback) => collect_feedback_input(feedback, batch)?,
+        query::Variant::VectorSelection(selection) => {
+            for vector in selection
+                .queries
+                .iter()
+                .filter_map(|query| query.query.as_ref())
+            {
+                collect_vector_input(vector, batch)?;
+            }
+        }
     }
 
     Ok(())
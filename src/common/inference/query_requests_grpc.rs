@@ -5,7 +5,8 @@ use api::grpc::{InferenceUsage, qdrant as grpc};
 use api::rest::{self, LookupLocation, RecommendStrategy};
 use collection::operations::universal_query::collection_query::{
     CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, FeedbackInternal,
-    FeedbackStrategy, Mmr, NearestWithMmr, Query, VectorInputInternal, VectorQuery,
+    FeedbackStrategy, Mmr, NearestAmongCandidates, NearestWithMmr, Query, VectorInputInternal,
+    VectorQuery, VectorSelection,
 };
 use collection::operations::universal_query::formula::FormulaInternal;
 use collection::operations::universal_query::shard_query::{FusionInternal, SampleInternal};
@@ -127,6 +128,7 @@ pub async fn convert_query_points_from_grpc(
         shard_key_selector: _,
         lookup_from,
         timeout: _,
+        highlight: _,
     } = query;
 
     let mut batch = BatchAccumGrpc::new();
@@ -313,6 +315,39 @@ fn convert_query_with_inferred(
 
             Query::Vector(VectorQuery::NearestWithMmr(NearestWithMmr { nearest, mmr }))
         }
+        Variant::NearestWithCandidates(grpc::NearestInputWithCandidates {
+            nearest,
+            candidates,
+            mmr,
+        }) => {
+            let nearest =
+                nearest.ok_or_else(|| Status::invalid_argument("nearest vector is missing"))?;
+            let nearest = convert_vector_input_with_inferred(nearest, inferred)?;
+
+            let candidates = candidates
+                .into_iter()
+                .map(PointIdType::try_from)
+                .collect::<Result<_, _>>()?;
+
+            let mmr = mmr.map(|mmr| {
+                let grpc::Mmr {
+                    diversity,
+                    candidates_limit,
+                } = mmr;
+                Mmr {
+                    diversity,
+                    candidates_limit: candidates_limit.map(|x| x as usize),
+                }
+            });
+
+            Query::Vector(VectorQuery::NearestAmongCandidates(
+                NearestAmongCandidates {
+                    nearest,
+                    candidates,
+                    mmr,
+                },
+            ))
+        }
         Variant::RelevanceFeedback(feedback) => {
             let grpc::RelevanceFeedbackInput {
                 target,
@@ -350,6 +385,38 @@ fn convert_query_with_inferred(
                 strategy,
             }))
         }
+        Variant::VectorSelection(selection) => {
+            let grpc::VectorSelectionInput {
+                key,
+                mapping,
+                default,
+                queries,
+            } = selection;
+
+            let key = json_path_from_proto(&key)?;
+
+            let mapping = mapping
+                .into_iter()
+                .map(TryFrom::try_from)
+                .collect::<Result<_, _>>()?;
+
+            let queries = queries
+                .into_iter()
+                .map(|grpc::VectorSelectionQuery { vector, query }| {
+                    let query = query.ok_or_else(|| {
+                        Status::invalid_argument("vector selection query is missing")
+                    })?;
+                    Ok((vector, convert_vector_input_with_inferred(query, inferred)?))
+                })
+                .collect::<Result<_, Status>>()?;
+
+            Query::VectorSelection(VectorSelection {
+                key,
+                mapping,
+                default,
+                queries,
+            })
+        }
     };
 
     Ok(query)
@@ -3,7 +3,8 @@ use api::rest::schema as rest;
 use collection::lookup::WithLookup;
 use collection::operations::universal_query::collection_query::{
     CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, FeedbackInternal,
-    FeedbackStrategy, Mmr, NearestWithMmr, Query, VectorInputInternal, VectorQuery,
+    FeedbackStrategy, Mmr, NearestAmongCandidates, NearestWithMmr, Query, VectorInputInternal,
+    VectorQuery, VectorSelection,
 };
 use collection::operations::universal_query::formula::FormulaInternal;
 use collection::operations::universal_query::shard_query::{FusionInternal, SampleInternal};
@@ -200,20 +201,33 @@ fn convert_query_with_inferred(
 ) -> StorageResult<Query> {
     let query = rest::Query::from(query);
     match query {
-        rest::Query::Nearest(rest::NearestQuery { nearest, mmr }) => {
+        rest::Query::Nearest(rest::NearestQuery {
+            nearest,
+            mmr,
+            candidates,
+        }) => {
             let vector = convert_vector_input_with_inferred(nearest, inferred)?;
 
-            if let Some(mmr) = mmr {
-                let mmr = Mmr {
-                    diversity: mmr.diversity,
-                    candidates_limit: mmr.candidates_limit,
-                };
-                Ok(Query::Vector(VectorQuery::NearestWithMmr(NearestWithMmr {
-                    nearest: vector,
-                    mmr,
-                })))
-            } else {
-                Ok(Query::Vector(VectorQuery::Nearest(vector)))
+            let mmr = mmr.map(|mmr| Mmr {
+                diversity: mmr.diversity,
+                candidates_limit: mmr.candidates_limit,
+            });
+
+            match (candidates, mmr) {
+                (Some(candidates), mmr) => Ok(Query::Vector(VectorQuery::NearestAmongCandidates(
+                    NearestAmongCandidates {
+                        nearest: vector,
+                        candidates,
+                        mmr,
+                    },
+                ))),
+                (None, Some(mmr)) => {
+                    Ok(Query::Vector(VectorQuery::NearestWithMmr(NearestWithMmr {
+                        nearest: vector,
+                        mmr,
+                    })))
+                }
+                (None, None) => Ok(Query::Vector(VectorQuery::Nearest(vector))),
             }
         }
         rest::Query::Recommend(recommend) => {
@@ -299,6 +313,36 @@ fn convert_query_with_inferred(
                 strategy,
             })))
         }
+        rest::Query::VectorSelection(selection) => {
+            let rest::VectorSelection {
+                key,
+                mapping,
+                default,
+                queries,
+            } = selection.vector_selection;
+
+            let mapping = mapping
+                .into_iter()
+                .map(|rest::VectorSelectionMapping { value, vector }| (value, vector))
+                .collect();
+
+            let queries = queries
+                .into_iter()
+                .map(|(vector_name, query)| {
+                    Ok((
+                        vector_name,
+                        convert_vector_input_with_inferred(query, inferred)?,
+                    ))
+                })
+                .collect::<StorageResult<Vec<_>>>()?;
+
+            Ok(Query::VectorSelection(VectorSelection {
+                key,
+                mapping,
+                default,
+                queries,
+            }))
+        }
     }
 }
 
@@ -476,6 +520,7 @@ mod tests {
         let nearest = NearestQuery {
             nearest: rest::VectorInput::Document(create_test_document("test")),
             mmr: None,
+            candidates: None,
         };
         let query = rest::QueryInterface::Query(rest::Query::Nearest(nearest));
 
@@ -7,6 +7,8 @@ use collection::operations::point_ops::{
     VectorStructPersisted,
 };
 use collection::operations::vector_ops::PointVectorsPersisted;
+use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+use segment::types::VectorName;
 use storage::content_manager::errors::StorageError;
 
 use crate::common::inference::batch_processing::BatchAccum;
@@ -14,6 +16,49 @@ use crate::common::inference::infer_processing::BatchAccumInferred;
 use crate::common::inference::params::InferenceParams;
 use crate::common::inference::service::{InferenceData, InferenceType};
 
+/// Embedding models of the inference inputs, by the name of the vector they are inferred for
+pub fn inference_models(vector: &VectorStruct) -> Vec<(&VectorName, &str)> {
+    match vector {
+        VectorStruct::Named(named) => named
+            .iter()
+            .filter_map(|(name, vector)| Some((name.as_str(), vector_inference_model(vector)?)))
+            .collect(),
+        VectorStruct::Document(doc) => vec![(DEFAULT_VECTOR_NAME, doc.model.as_str())],
+        VectorStruct::Image(img) => vec![(DEFAULT_VECTOR_NAME, img.model.as_str())],
+        VectorStruct::Object(obj) => vec![(DEFAULT_VECTOR_NAME, obj.model.as_str())],
+        VectorStruct::MultiDense(_) | VectorStruct::Single(_) => vec![],
+    }
+}
+
+/// Embedding models of the inference inputs of the batch, by the name of the vector
+pub fn batch_inference_models(vectors: &BatchVectorStruct) -> Vec<(&VectorName, &str)> {
+    match vectors {
+        BatchVectorStruct::Named(named) => named
+            .iter()
+            .flat_map(|(name, vectors)| {
+                vectors
+                    .iter()
+                    .filter_map(|vector| Some((name.as_str(), vector_inference_model(vector)?)))
+            })
+            .collect(),
+        // Not supported for inference, rejected on conversion
+        BatchVectorStruct::Document(_)
+        | BatchVectorStruct::Image(_)
+        | BatchVectorStruct::Object(_)
+        | BatchVectorStruct::Single(_)
+        | BatchVectorStruct::MultiDense(_) => vec![],
+    }
+}
+
+fn vector_inference_model(vector: &Vector) -> Option<&str> {
+    match vector {
+        Vector::Document(doc) => Some(&doc.model),
+        Vector::Image(img) => Some(&img.model),
+        Vector::Object(obj) => Some(&obj.model),
+        Vector::Dense(_) | Vector::Sparse(_) | Vector::MultiDense(_) => None,
+    }
+}
+
 pub async fn convert_point_struct(
     point_structs: Vec<PointStruct>,
     inference_type: InferenceType,
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use api::rest::models::HardwareUsage;
 use collection::shards::replica_set::replica_set_state::ReplicaState;
+use collection::shards::telemetry::FilterLatencyTelemetry;
 use itertools::Itertools;
 use prometheus::TextEncoder;
 use prometheus::proto::{Counter, Gauge, LabelPair, Metric, MetricFamily, MetricType};
@@ -18,7 +19,7 @@ use crate::common::telemetry_ops::collections_telemetry::{
 };
 use crate::common::telemetry_ops::memory_telemetry::MemoryTelemetry;
 use crate::common::telemetry_ops::requests_telemetry::{
-    GrpcTelemetry, RequestsTelemetry, WebApiTelemetry,
+    GrpcTelemetry, RequestsTelemetry, TransferredBytes, WebApiTelemetry,
 };
 
 /// Whitelist for REST endpoints in metrics output.
@@ -232,6 +233,20 @@ impl CollectionsTelemetry {
         // Update queue
         let mut update_queue_length = Vec::with_capacity(num_collections);
 
+        // Query cache
+        let mut query_cache_hits = Vec::with_capacity(num_collections);
+        let mut query_cache_misses = Vec::with_capacity(num_collections);
+
+        // Query coalescing
+        let mut queries_executed = Vec::with_capacity(num_collections);
+        let mut queries_coalesced = Vec::with_capacity(num_collections);
+
+        // Score drift
+        let mut score_drift_alerts = Vec::new();
+
+        // Search and query durations by filter complexity
+        let mut filter_latency = OperationDurationMetricsBuilder::default();
+
         for collection in self.collections.iter().flatten() {
             let collection = match collection {
                 CollectionTelemetryEnum::Full(collection_telemetry) => collection_telemetry,
@@ -378,6 +393,61 @@ impl CollectionsTelemetry {
                 .sum();
 
             update_queue_length.push(gauge(total_queue_length as f64, &[("id", &collection.id)]));
+
+            if let Some(query_cache) = &collection.query_cache {
+                query_cache_hits.push(counter(query_cache.hits as f64, &[("id", &collection.id)]));
+                query_cache_misses.push(counter(
+                    query_cache.misses as f64,
+                    &[("id", &collection.id)],
+                ));
+            }
+
+            if let Some(query_coalescer) = &collection.query_coalescer {
+                queries_executed.push(counter(
+                    query_coalescer.executed as f64,
+                    &[("id", &collection.id)],
+                ));
+                queries_coalesced.push(counter(
+                    query_coalescer.coalesced as f64,
+                    &[("id", &collection.id)],
+                ));
+            }
+
+            for (vector_name, score_drift) in collection.score_drift.iter().flatten() {
+                score_drift_alerts.push(counter(
+                    score_drift.alerts as f64,
+                    &[("collection", &collection.id), ("vector", vector_name)],
+                ));
+            }
+
+            let collection_filter_latency = collection
+                .shards
+                .iter()
+                .flatten()
+                .filter_map(|shard| shard.local.as_ref())
+                .filter_map(|local| local.filter_latency.clone())
+                .reduce(|total, latency| total + latency);
+
+            if let Some(FilterLatencyTelemetry {
+                unfiltered,
+                simple,
+                complex,
+                unindexed,
+            }) = collection_filter_latency
+            {
+                for (complexity, stats) in [
+                    ("unfiltered", unfiltered),
+                    ("simple", simple),
+                    ("complex", complex),
+                    ("unindexed", unindexed),
+                ] {
+                    filter_latency.add(
+                        &stats,
+                        &[("id", &collection.id), ("filter", complexity)],
+                        true,
+                    );
+                }
+            }
         }
 
         for snapshot_telemetry in self.snapshots.iter().flatten() {
@@ -527,6 +597,48 @@ impl CollectionsTelemetry {
             update_queue_length,
             prefix,
         ));
+
+        metrics.push_metric(metric_family(
+            "collection_query_cache_hits_total",
+            "total number of queries served from the query cache per collection",
+            MetricType::COUNTER,
+            query_cache_hits,
+            prefix,
+        ));
+
+        metrics.push_metric(metric_family(
+            "collection_query_cache_misses_total",
+            "total number of cacheable queries not found in the query cache per collection",
+            MetricType::COUNTER,
+            query_cache_misses,
+            prefix,
+        ));
+
+        metrics.push_metric(metric_family(
+            "collection_coalescable_queries_executed_total",
+            "total number of executed queries, which identical concurrent queries could wait for, per collection",
+            MetricType::COUNTER,
+            queries_executed,
+            prefix,
+        ));
+
+        metrics.push_metric(metric_family(
+            "collection_queries_coalesced_total",
+            "total number of queries which got the result of an identical concurrent query per collection",
+            MetricType::COUNTER,
+            queries_coalesced,
+            prefix,
+        ));
+
+        metrics.push_metric(metric_family(
+            "collection_score_drift_alerts_total",
+            "number of detected top-1 score distribution drifts grouped by vector name",
+            MetricType::COUNTER,
+            score_drift_alerts,
+            prefix,
+        ));
+
+        filter_latency.build(prefix, "collection_search", metrics);
     }
 }
 
@@ -666,6 +778,28 @@ impl MetricsProvider for WebApiTelemetry {
             }
         }
         builder.build(prefix, "rest", metrics);
+
+        let mut transferred = TransferredBytesMetricsBuilder::default();
+        for (endpoint, collections) in &self.transferred {
+            let Some((method, endpoint)) = endpoint.split_once(' ') else {
+                continue;
+            };
+            // Endpoint must be whitelisted
+            if REST_ENDPOINT_WHITELIST.binary_search(&endpoint).is_err() {
+                continue;
+            }
+            for (collection, bytes) in collections {
+                transferred.add(
+                    bytes,
+                    &[
+                        ("method", method),
+                        ("endpoint", endpoint),
+                        ("collection", collection),
+                    ],
+                );
+            }
+        }
+        transferred.build(prefix, "rest", metrics);
     }
 }
 
@@ -692,6 +826,24 @@ impl MetricsProvider for GrpcTelemetry {
             }
         }
         builder.build(prefix, "grpc", metrics);
+
+        let mut transferred = TransferredBytesMetricsBuilder::default();
+        for (endpoint, collections) in &self.transferred {
+            // Endpoint must be whitelisted
+            if GRPC_ENDPOINT_WHITELIST
+                .binary_search(&endpoint.as_str())
+                .is_err()
+            {
+                continue;
+            }
+            for (collection, bytes) in collections {
+                transferred.add(
+                    bytes,
+                    &[("endpoint", endpoint.as_str()), ("collection", collection)],
+                );
+            }
+        }
+        transferred.build(prefix, "grpc", metrics);
     }
 }
 
@@ -822,6 +974,40 @@ impl MetricsProvider for HardwareTelemetry {
     }
 }
 
+/// Builder for request and response size metrics
+#[derive(Default)]
+struct TransferredBytesMetricsBuilder {
+    request_bytes: Vec<Metric>,
+    response_bytes: Vec<Metric>,
+}
+
+impl TransferredBytesMetricsBuilder {
+    fn add(&mut self, bytes: &TransferredBytes, labels: &[(&str, &str)]) {
+        self.request_bytes
+            .push(counter(bytes.request_bytes as f64, labels));
+        self.response_bytes
+            .push(counter(bytes.response_bytes as f64, labels));
+    }
+
+    /// Build metrics and add them to the provided vector.
+    fn build(self, global_prefix: Option<&str>, prefix: &str, metrics: &mut MetricsData) {
+        metrics.push_metric(metric_family(
+            &format!("{prefix}_request_bytes_total"),
+            "total size of request bodies, per endpoint and collection",
+            MetricType::COUNTER,
+            self.request_bytes,
+            global_prefix,
+        ));
+        metrics.push_metric(metric_family(
+            &format!("{prefix}_response_bytes_total"),
+            "total size of response bodies, per endpoint and collection",
+            MetricType::COUNTER,
+            self.response_bytes,
+            global_prefix,
+        ));
+    }
+}
+
 /// A helper struct to build a vector of [`MetricFamily`] out of a collection of
 /// [`OperationDurationStatistics`].
 #[derive(Default)]
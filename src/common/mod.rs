@@ -1,15 +1,20 @@
 pub mod audit;
 pub mod auth;
+pub mod auto_replica_scaling;
 pub mod collections;
 pub mod debugger;
 pub mod error_reporting;
 pub mod health;
+pub mod health_report;
 pub mod helpers;
 pub mod http_client;
 pub mod inference;
 pub mod metrics;
 pub mod pyroscope_state;
 pub mod query;
+pub mod restart;
+pub mod search_concurrency;
+pub mod shutdown;
 pub mod snapshots;
 pub mod stacktrace;
 pub mod strict_mode;
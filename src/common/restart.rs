@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use storage::content_manager::errors::StorageResult;
+use storage::content_manager::toc::TableOfContent;
+use storage::dispatcher::Dispatcher;
+use tokio::time::Instant;
+
+use crate::common::telemetry_ops::requests_telemetry::InFlightRequests;
+
+/// Interval for re-checking whether the peer has settled down
+const RESTART_READINESS_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Readiness of this peer to be restarted
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RestartReadiness {
+    /// No new optimizations are started, and none is running on this peer anymore
+    pub optimizers_paused: bool,
+    /// Whether this peer is still the Raft leader, restarting it would trigger a new election
+    pub is_leader: bool,
+    /// Number of REST and gRPC requests this peer is still processing
+    pub in_flight_requests: usize,
+    /// WAL and segments of all local shards are flushed to disk
+    pub flushed: bool,
+    /// Whether the peer can be restarted now
+    pub ready: bool,
+}
+
+/// Prepare this peer for a planned restart, so that it recovers quickly afterwards and
+/// the rest of the cluster is affected as little as possible.
+///
+/// 1. Stop starting new optimizations
+/// 2. Hand over Raft leadership to another voter, if this peer is the leader
+/// 3. Wait for running optimizations and in-flight requests to finish, up to `timeout`
+/// 4. Flush WAL and segments of all local shards, so that no WAL has to be replayed on startup
+///
+/// Optimizers stay paused until [`cancel_restart`] is called or the process is restarted.
+pub async fn prepare_restart(
+    dispatcher: &Dispatcher,
+    toc: &TableOfContent,
+    in_flight: &[InFlightRequests],
+    timeout: Duration,
+) -> StorageResult<RestartReadiness> {
+    let deadline = Instant::now() + timeout;
+    let consensus_state = dispatcher.consensus_state();
+
+    toc.pause_optimizers();
+
+    if let Some(consensus_state) = consensus_state
+        && consensus_state.is_leader()
+    {
+        let this_peer_id = consensus_state.this_peer_id();
+        match consensus_state
+            .voters()
+            .into_iter()
+            .find(|&peer_id| peer_id != this_peer_id)
+        {
+            Some(peer_id) => toc.transfer_leader(peer_id)?,
+            None => log::warn!("No other voter to transfer Raft leadership to before restart"),
+        }
+    }
+
+    // The request preparing the restart is in-flight itself
+    let in_flight_requests = || {
+        in_flight
+            .iter()
+            .map(InFlightRequests::count)
+            .sum::<usize>()
+            .saturating_sub(1)
+    };
+
+    let (optimizers_paused, is_leader, in_flight_requests) = loop {
+        let optimizers_paused = toc.pause_optimizers();
+        let is_leader = consensus_state.is_some_and(|state| state.is_leader());
+        let in_flight_requests = in_flight_requests();
+
+        let settled = optimizers_paused && !is_leader && in_flight_requests == 0;
+        if settled || Instant::now() >= deadline {
+            break (optimizers_paused, is_leader, in_flight_requests);
+        }
+
+        tokio::time::sleep(RESTART_READINESS_CHECK_INTERVAL).await;
+    };
+
+    toc.flush_all_local_shards().await?;
+
+    Ok(RestartReadiness {
+        optimizers_paused,
+        is_leader,
+        in_flight_requests,
+        flushed: true,
+        ready: optimizers_paused && !is_leader && in_flight_requests == 0,
+    })
+}
+
+/// Revert [`prepare_restart`], if the peer is not going to be restarted after all
+pub fn cancel_restart(toc: &TableOfContent) {
+    toc.resume_optimizers();
+}
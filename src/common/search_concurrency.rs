@@ -0,0 +1,101 @@
+use std::time::{Duration, Instant};
+
+use common::search_concurrency::{
+    SearchConcurrencyLimiter, init_search_concurrency, search_concurrency,
+};
+use tokio::time::MissedTickBehavior;
+
+/// How far concurrent segment searches may exceed the number of search threads,
+/// to keep CPUs busy while searches are blocked on disk IO
+pub const MAX_SEARCH_CONCURRENCY_FACTOR: usize = 4;
+
+/// How often to adjust the limit of concurrent segment searches
+const ADJUSTMENT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Initialize the global adaptive limit of concurrent segment searches
+pub fn init(search_threads: usize) {
+    let min_limit = (search_threads / 2).max(1);
+    let max_limit = search_threads * MAX_SEARCH_CONCURRENCY_FACTOR;
+
+    log::info!(
+        "Adaptive search concurrency enabled, between {min_limit} and {max_limit} concurrent segment searches",
+    );
+
+    init_search_concurrency(SearchConcurrencyLimiter::new(
+        search_threads,
+        min_limit,
+        max_limit,
+    ));
+}
+
+/// Periodically adjust the limit of concurrent segment searches to the CPU saturation
+pub async fn run_controller() {
+    let Some(limiter) = search_concurrency() else {
+        return;
+    };
+
+    let num_cpus = common::cpu::get_num_cpus();
+    let mut cpu_usage = CpuUsage::new();
+
+    let mut interval = tokio::time::interval(ADJUSTMENT_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        let Some(cpu_saturation) = cpu_usage.saturation(num_cpus) else {
+            continue;
+        };
+
+        limiter.adjust(cpu_saturation);
+    }
+}
+
+/// Measures CPU time spent by this process between calls
+struct CpuUsage {
+    last: Option<(Instant, Duration)>,
+}
+
+impl CpuUsage {
+    fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Fraction of available CPU time this process used since the previous call
+    fn saturation(&mut self, num_cpus: usize) -> Option<f64> {
+        let now = Instant::now();
+        let cpu_time = match process_cpu_time() {
+            Some(cpu_time) => cpu_time,
+            // Fallback to system load, where process CPU time is not available
+            None => return load_average_saturation(num_cpus),
+        };
+
+        let saturation = self.last.map(|(last_time, last_cpu_time)| {
+            let elapsed = now.duration_since(last_time).as_secs_f64() * num_cpus as f64;
+            let used = cpu_time.saturating_sub(last_cpu_time).as_secs_f64();
+            (used / elapsed).clamp(0.0, 1.0)
+        });
+
+        self.last = Some((now, cpu_time));
+        saturation
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn process_cpu_time() -> Option<Duration> {
+    let stat = procfs::process::Process::myself().ok()?.stat().ok()?;
+    let ticks = stat.utime + stat.stime;
+    Some(Duration::from_secs_f64(
+        ticks as f64 / procfs::ticks_per_second() as f64,
+    ))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn process_cpu_time() -> Option<Duration> {
+    None
+}
+
+fn load_average_saturation(num_cpus: usize) -> Option<f64> {
+    let load = sys_info::loadavg().ok()?;
+    Some((load.one / num_cpus as f64).clamp(0.0, 1.0))
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use storage::content_manager::toc::TableOfContent;
+use storage::dispatcher::Dispatcher;
+use tokio::signal;
+use tokio::time::Instant;
+
+use crate::common::telemetry_ops::requests_telemetry::InFlightRequests;
+
+/// How long to wait for in-flight requests on shutdown, if not configured explicitly
+pub const DEFAULT_SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(30);
+
+/// Interval for re-checking whether in-flight requests are finished
+const DRAIN_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Interval for reporting the number of requests, which are still in-flight
+const DRAIN_REPORT_INTERVAL: Duration = Duration::from_secs(5);
+
+#[cfg(windows)]
+pub async fn wait_stop_signal(for_what: &str) {
+    let mut close = signal::windows::ctrl_close().unwrap();
+    let mut shutdown = signal::windows::ctrl_shutdown().unwrap();
+
+    tokio::select! {
+        _ = signal::ctrl_c() => log::debug!("Stopping {for_what} on CTRL_C"),
+        _ = close.recv() => log::debug!("Stopping {for_what} on CTRL_CLOSE"),
+        _ = shutdown.recv() => log::debug!("Stopping {for_what} on CTRL_SHUTDOWN"),
+    }
+}
+
+#[cfg(unix)]
+pub async fn wait_stop_signal(for_what: &str) {
+    let mut term = signal::unix::signal(signal::unix::SignalKind::terminate()).unwrap();
+    let mut inrt = signal::unix::signal(signal::unix::SignalKind::interrupt()).unwrap();
+
+    tokio::select! {
+        _ = term.recv() => log::debug!("Stopping {for_what} on SIGTERM"),
+        _ = inrt.recv() => log::debug!("Stopping {for_what} on SIGINT"),
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+pub async fn wait_stop_signal(for_what: &str) {
+    signal::ctrl_c().await.unwrap();
+    log::debug!("Stopping {for_what} on SIGINT");
+}
+
+/// Bring this peer down in an orderly way, once API servers stopped accepting new requests.
+///
+/// 1. Wait for in-flight requests to finish, up to `grace_period`
+/// 2. Stop starting new optimizations
+/// 3. Flush WAL and segments of all local shards, so that no WAL has to be replayed on startup
+/// 4. Persist consensus state
+///
+/// Failures are logged and don't prevent the following steps.
+pub async fn graceful_shutdown(
+    dispatcher: &Dispatcher,
+    toc: &TableOfContent,
+    in_flight: &[InFlightRequests],
+    grace_period: Duration,
+) {
+    log::info!(
+        "Shutting down gracefully, waiting up to {}s for in-flight requests",
+        grace_period.as_secs(),
+    );
+
+    let deadline = Instant::now() + grace_period;
+    let mut next_report = Instant::now() + DRAIN_REPORT_INTERVAL;
+
+    loop {
+        let in_flight_requests = in_flight.iter().map(InFlightRequests::count).sum::<usize>();
+        if in_flight_requests == 0 {
+            log::info!("All in-flight requests are finished");
+            break;
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            log::warn!("Grace period is over, abandoning {in_flight_requests} in-flight requests");
+            break;
+        }
+
+        if now >= next_report {
+            log::info!("Waiting for {in_flight_requests} in-flight requests to finish");
+            next_report = now + DRAIN_REPORT_INTERVAL;
+        }
+
+        tokio::time::sleep(DRAIN_CHECK_INTERVAL).await;
+    }
+
+    // Running optimizations are not awaited, they are resumed from scratch on startup anyway
+    toc.pause_optimizers();
+
+    log::info!("Flushing WAL and segments of all local shards");
+    match toc.flush_all_local_shards().await {
+        Ok(()) => log::info!("Flushed all local shards"),
+        Err(err) => log::error!("Failed to flush local shards on shutdown: {err}"),
+    }
+
+    if let Some(consensus_state) = dispatcher.consensus_state() {
+        log::info!("Saving consensus state");
+        match consensus_state.persistent.read().save() {
+            Ok(()) => log::info!("Saved consensus state"),
+            Err(err) => log::error!("Failed to save consensus state on shutdown: {err}"),
+        }
+    }
+
+    log::info!("Graceful shutdown complete");
+}
@@ -75,9 +75,11 @@ impl TelemetryCollector {
             app_telemetry_collector: AppBuildTelemetryCollector::new(),
             actix_telemetry_collector: Arc::new(Mutex::new(ActixTelemetryCollector {
                 workers: Vec::new(),
+                in_flight: Default::default(),
             })),
             tonic_telemetry_collector: Arc::new(Mutex::new(TonicTelemetryCollector {
                 workers: Vec::new(),
+                in_flight: Default::default(),
             })),
         }
     }
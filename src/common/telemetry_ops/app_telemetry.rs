@@ -2,6 +2,7 @@ use std::path::Path;
 
 use chrono::{DateTime, SubsecRound, Utc};
 use common::flags::FeatureFlags;
+use common::search_concurrency::{SearchConcurrencyTelemetry, search_concurrency};
 use common::types::{DetailsLevel, TelemetryDetail};
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
@@ -30,6 +31,7 @@ pub struct AppFeaturesTelemetry {
     pub gpu: bool,
     pub rocksdb: bool,
     pub staging: bool,
+    pub chaos_testing: bool,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
@@ -70,6 +72,9 @@ pub struct AppBuildTelemetry {
     pub jwt_rbac: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hide_jwt_dashboard: Option<bool>,
+    #[anonymize(false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_concurrency: Option<SearchConcurrencyTelemetry>,
     pub startup: DateTime<Utc>,
 }
 
@@ -89,6 +94,7 @@ impl AppBuildTelemetry {
                 gpu: cfg!(feature = "gpu"),
                 rocksdb: cfg!(feature = "rocksdb"),
                 staging: cfg!(feature = "staging"),
+                chaos_testing: cfg!(feature = "chaos-testing"),
             }),
             runtime_features: (detail.level >= DetailsLevel::Level1)
                 .then(common::flags::feature_flags),
@@ -97,6 +103,7 @@ impl AppBuildTelemetry {
             system: (detail.level >= DetailsLevel::Level1).then(get_system_data),
             jwt_rbac: settings.service.jwt_rbac,
             hide_jwt_dashboard: settings.service.hide_jwt_dashboard,
+            search_concurrency: search_concurrency().map(|limiter| limiter.get_telemetry_data()),
             startup: collector.startup,
         }
     }
@@ -22,6 +22,10 @@ use crate::common::telemetry_ops::collections_telemetry::CollectionTelemetryEnum
 pub struct DistributedTelemetryData {
     collections: HashMap<String, DistributedCollectionTelemetry>,
 
+    /// Total usage of all collections across the cluster
+    #[serde(skip_serializing_if = "Option::is_none")]
+    usage: Option<DistributedUsageTelemetry>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     cluster: Option<DistributedClusterTelemetry>,
 }
@@ -42,6 +46,55 @@ pub struct DistributedCollectionTelemetry {
     /// Ongoing shard transfers
     #[serde(skip_serializing_if = "Option::is_none")]
     shard_transfers: Option<Vec<ShardTransferInfo>>,
+
+    /// Total usage of the collection across the cluster
+    #[serde(skip_serializing_if = "Option::is_none")]
+    usage: Option<DistributedUsageTelemetry>,
+}
+
+/// Cluster-wide usage, counting each shard once regardless of its replication factor.
+///
+/// Shards without any reporting active replica are not included.
+#[derive(Serialize, JsonSchema, Default, Clone, Copy)]
+pub struct DistributedUsageTelemetry {
+    /// Approximate number of points
+    points_count: usize,
+
+    /// Estimated vectors size in bytes
+    vectors_size_bytes: usize,
+
+    /// Estimated payloads size in bytes
+    payloads_size_bytes: usize,
+}
+
+impl DistributedUsageTelemetry {
+    fn from_replica(replica: &DistributedReplicaTelemetry) -> Option<Self> {
+        Some(Self {
+            points_count: replica.num_points?,
+            vectors_size_bytes: replica.vectors_size_bytes.unwrap_or_default(),
+            payloads_size_bytes: replica.payloads_size_bytes.unwrap_or_default(),
+        })
+    }
+
+    /// Usage of a single shard, taken from its most complete active replica
+    fn from_shard(shard: &DistributedShardTelemetry) -> Option<Self> {
+        shard
+            .replicas
+            .iter()
+            .filter(|replica| replica.state == ReplicaState::Active)
+            .filter_map(Self::from_replica)
+            .max_by_key(|usage| usage.points_count)
+    }
+}
+
+impl std::iter::Sum for DistributedUsageTelemetry {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, usage| Self {
+            points_count: acc.points_count + usage.points_count,
+            vectors_size_bytes: acc.vectors_size_bytes + usage.vectors_size_bytes,
+            payloads_size_bytes: acc.payloads_size_bytes + usage.payloads_size_bytes,
+        })
+    }
 }
 
 #[derive(Serialize, JsonSchema)]
@@ -183,8 +236,16 @@ impl DistributedTelemetryData {
         let collections =
             aggregate_collections(telemetry_by_peer, base_telemetry).unwrap_or_default();
 
+        let usage = (!collections.is_empty()).then(|| {
+            collections
+                .values()
+                .filter_map(|collection| collection.usage)
+                .sum()
+        });
+
         Ok(DistributedTelemetryData {
             collections,
+            usage,
             cluster,
         })
     }
@@ -216,6 +277,13 @@ fn aggregate_collections(
         // Aggregate shards from all peers
         let shards = aggregate_shards(telemetry_by_peer, collection, &collection_id);
 
+        let usage = shards.as_ref().map(|shards| {
+            shards
+                .iter()
+                .filter_map(DistributedUsageTelemetry::from_shard)
+                .sum()
+        });
+
         collections.insert(
             collection_id.clone(),
             DistributedCollectionTelemetry {
@@ -223,6 +291,7 @@ fn aggregate_collections(
                 shards,
                 shard_transfers: (!transfers.is_empty()).then_some(transfers),
                 reshardings: (!reshardings.is_empty()).then_some(reshardings),
+                usage,
             },
         );
     }
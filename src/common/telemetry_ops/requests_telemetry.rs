@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
 use common::types::TelemetryDetail;
 use parking_lot::Mutex;
@@ -15,32 +16,188 @@ pub type HttpStatusCode = u16;
 
 pub type GrpcStatusCode = i32;
 
+/// Requests which are not bound to a collection, or which failed, are recorded under this name
+pub const NO_COLLECTION: &str = "";
+
 #[derive(Serialize, Clone, Default, Debug, JsonSchema)]
 pub struct WebApiTelemetry {
     pub responses: HashMap<String, HashMap<HttpStatusCode, OperationDurationStatistics>>,
+    /// Transferred bytes, by endpoint and collection
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub transferred: HashMap<String, HashMap<String, TransferredBytes>>,
 }
 
 #[derive(Serialize, Clone, Default, Debug, JsonSchema)]
 pub struct GrpcTelemetry {
     pub responses: HashMap<String, HashMap<GrpcStatusCode, OperationDurationStatistics>>,
+    /// Transferred bytes, by endpoint and collection
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub transferred: HashMap<String, HashMap<String, TransferredBytes>>,
+}
+
+#[derive(Serialize, Clone, Copy, Default, Debug, PartialEq, Eq, JsonSchema, Anonymize)]
+#[anonymize(false)]
+pub struct TransferredBytes {
+    /// Total size of request bodies, as received
+    pub request_bytes: u64,
+    /// Total size of response bodies, as sent
+    pub response_bytes: u64,
 }
 
+/// Counters of transferred bytes of an endpoint and a collection.
+///
+/// Response bodies are streamed after the request is handled, so their size is counted as they
+/// are sent.
+#[derive(Default, Debug)]
+pub struct TransferCounters {
+    request_bytes: AtomicU64,
+    response_bytes: AtomicU64,
+}
+
+type TransferCountersMap = HashMap<String, HashMap<String, Arc<TransferCounters>>>;
+
 pub struct ActixTelemetryCollector {
     pub workers: Vec<Arc<Mutex<ActixWorkerTelemetryCollector>>>,
+    pub in_flight: InFlightRequests,
 }
 
 #[derive(Default)]
 pub struct ActixWorkerTelemetryCollector {
     methods: HashMap<String, HashMap<HttpStatusCode, Arc<Mutex<OperationDurationsAggregator>>>>,
+    transferred: TransferCountersMap,
 }
 
 pub struct TonicTelemetryCollector {
     pub workers: Vec<Arc<Mutex<TonicWorkerTelemetryCollector>>>,
+    pub in_flight: InFlightRequests,
+}
+
+/// Number of requests, which are currently being processed, shared between all workers
+#[derive(Clone, Default, Debug)]
+pub struct InFlightRequests {
+    count: Arc<AtomicUsize>,
+}
+
+/// Marks a request as in-flight until dropped
+pub struct InFlightRequestGuard {
+    count: Arc<AtomicUsize>,
+}
+
+impl InFlightRequests {
+    pub fn start_request(&self) -> InFlightRequestGuard {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        InFlightRequestGuard {
+            count: self.count.clone(),
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for InFlightRequestGuard {
+    fn drop(&mut self) {
+        self.count.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl TransferCounters {
+    pub fn add_request_bytes(&self, bytes: u64) {
+        self.request_bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub fn add_response_bytes(&self, bytes: u64) {
+        self.response_bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> TransferredBytes {
+        TransferredBytes {
+            request_bytes: self.request_bytes.load(Ordering::Relaxed),
+            response_bytes: self.response_bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl std::ops::AddAssign for TransferredBytes {
+    fn add_assign(&mut self, other: Self) {
+        self.request_bytes += other.request_bytes;
+        self.response_bytes += other.response_bytes;
+    }
+}
+
+fn transfer_counters(
+    transferred: &mut TransferCountersMap,
+    method: &str,
+    collection: &str,
+) -> Arc<TransferCounters> {
+    let collections = match transferred.get_mut(method) {
+        Some(collections) => collections,
+        None => transferred.entry(method.to_string()).or_default(),
+    };
+    match collections.get(collection) {
+        Some(counters) => counters.clone(),
+        None => collections
+            .entry(collection.to_string())
+            .or_default()
+            .clone(),
+    }
+}
+
+fn get_transferred(
+    transferred: &TransferCountersMap,
+) -> HashMap<String, HashMap<String, TransferredBytes>> {
+    transferred
+        .iter()
+        .map(|(method, collections)| {
+            let collections = collections
+                .iter()
+                .map(|(collection, counters)| (collection.clone(), counters.get()))
+                .collect();
+            (method.clone(), collections)
+        })
+        .collect()
+}
+
+fn merge_transferred(
+    transferred: &mut HashMap<String, HashMap<String, TransferredBytes>>,
+    other: &HashMap<String, HashMap<String, TransferredBytes>>,
+) {
+    for (method, collections) in other {
+        let collections_map = transferred.entry(method.clone()).or_default();
+        for (collection, bytes) in collections {
+            *collections_map.entry(collection.clone()).or_default() += *bytes;
+        }
+    }
+}
+
+/// Collection names are anonymized, requests without a collection are kept as is
+fn anonymize_transferred(
+    transferred: &HashMap<String, HashMap<String, TransferredBytes>>,
+) -> HashMap<String, HashMap<String, TransferredBytes>> {
+    transferred
+        .iter()
+        .map(|(method, collections)| {
+            let collections = collections
+                .iter()
+                .map(|(collection, bytes)| {
+                    let collection = if collection == NO_COLLECTION {
+                        collection.clone()
+                    } else {
+                        collection.anonymize()
+                    };
+                    (collection, *bytes)
+                })
+                .collect();
+            (method.clone(), collections)
+        })
+        .collect()
 }
 
 #[derive(Default)]
 pub struct TonicWorkerTelemetryCollector {
     methods: HashMap<String, HashMap<GrpcStatusCode, Arc<Mutex<OperationDurationsAggregator>>>>,
+    transferred: TransferCountersMap,
 }
 
 impl ActixTelemetryCollector {
@@ -93,6 +250,11 @@ impl TonicWorkerTelemetryCollector {
         ScopeDurationMeasurer::new_with_instant(aggregator, instant);
     }
 
+    /// Counters of transferred bytes of the method and the collection
+    pub fn transfer_counters(&mut self, method: &str, collection: &str) -> Arc<TransferCounters> {
+        transfer_counters(&mut self.transferred, method, collection)
+    }
+
     pub fn get_telemetry_data(&self, detail: TelemetryDetail) -> GrpcTelemetry {
         let mut responses = HashMap::new();
         for (method, status_codes) in &self.methods {
@@ -102,7 +264,10 @@ impl TonicWorkerTelemetryCollector {
             }
             responses.insert(method.clone(), status_codes_map);
         }
-        GrpcTelemetry { responses }
+        GrpcTelemetry {
+            responses,
+            transferred: get_transferred(&self.transferred),
+        }
     }
 }
 
@@ -122,6 +287,11 @@ impl ActixWorkerTelemetryCollector {
         ScopeDurationMeasurer::new_with_instant(aggregator, instant);
     }
 
+    /// Counters of transferred bytes of the endpoint and the collection
+    pub fn transfer_counters(&mut self, method: &str, collection: &str) -> Arc<TransferCounters> {
+        transfer_counters(&mut self.transferred, method, collection)
+    }
+
     pub fn get_telemetry_data(&self, detail: TelemetryDetail) -> WebApiTelemetry {
         let mut responses = HashMap::new();
         for (method, status_codes) in &self.methods {
@@ -131,7 +301,10 @@ impl ActixWorkerTelemetryCollector {
             }
             responses.insert(method.clone(), status_codes_map);
         }
-        WebApiTelemetry { responses }
+        WebApiTelemetry {
+            responses,
+            transferred: get_transferred(&self.transferred),
+        }
     }
 }
 
@@ -144,6 +317,7 @@ impl GrpcTelemetry {
                 *status_entry = status_entry.clone() + statistics.clone();
             }
         }
+        merge_transferred(&mut self.transferred, &other.transferred);
     }
 }
 
@@ -156,6 +330,7 @@ impl WebApiTelemetry {
                 *entry = entry.clone() + statistics.clone();
             }
         }
+        merge_transferred(&mut self.transferred, &other.transferred);
     }
 }
 
@@ -194,7 +369,10 @@ impl Anonymize for WebApiTelemetry {
             .map(|(key, value)| (key.clone(), anonymize_collection_values(value)))
             .collect();
 
-        WebApiTelemetry { responses }
+        WebApiTelemetry {
+            responses,
+            transferred: anonymize_transferred(&self.transferred),
+        }
     }
 }
 
@@ -206,6 +384,9 @@ impl Anonymize for GrpcTelemetry {
             .map(|(key, value)| (key.clone(), anonymize_collection_values(value)))
             .collect();
 
-        GrpcTelemetry { responses }
+        GrpcTelemetry {
+            responses,
+            transferred: anonymize_transferred(&self.transferred),
+        }
     }
 }
@@ -4,17 +4,20 @@ use std::time::Duration;
 use api::rest::models::InferenceUsage;
 use api::rest::*;
 use collection::collection::Collection;
+use collection::collection::payload_migration::{PayloadMigration, PayloadMigrationStatus};
 use collection::operations::conversions::write_ordering_from_proto;
 use collection::operations::point_ops::*;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
-use collection::operations::types::{CollectionError, CollectionResult, UpdateResult};
+use collection::operations::types::{
+    CollectionError, CollectionResult, UpdateResult, UpdateResultWithIds,
+};
 use collection::operations::vector_ops::*;
 use collection::operations::verification::*;
 use collection::shards::shard::ShardId;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use schemars::JsonSchema;
 use segment::json_path::JsonPath;
-use segment::types::{Filter, PayloadFieldSchema, PayloadKeyType, StrictModeConfig};
+use segment::types::{Filter, PayloadFieldSchema, PayloadKeyType, StrictModeConfig, VectorName};
 use serde::{Deserialize, Serialize};
 use serde_with::DurationSeconds;
 use shard::operations::payload_ops::*;
@@ -24,7 +27,7 @@ use storage::content_manager::collection_verification::check_strict_mode;
 use storage::content_manager::errors::StorageError;
 use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
-use storage::rbac::{Access, Auth, AuthType};
+use storage::rbac::{Access, AccessRequirements, Auth, AuthType};
 use validator::Validate;
 
 use crate::common::inference::params::InferenceParams;
@@ -83,6 +86,7 @@ impl InternalUpdateParams {
 
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct UpdateOperations {
+    /// Operations to apply, in the given order
     #[validate(nested)]
     pub operations: Vec<UpdateOperation>,
 }
@@ -284,6 +288,27 @@ pub struct CreateFieldIndex {
     pub field_schema: Option<PayloadFieldSchema>,
 }
 
+/// Check inference inputs to use the embedding models configured for their vectors
+async fn check_inference_models<'a>(
+    toc: &TableOfContent,
+    collection_name: &str,
+    auth: &Auth,
+    models: impl IntoIterator<Item = (&'a VectorName, &'a str)>,
+) -> Result<(), StorageError> {
+    let mut models = models.into_iter().peekable();
+    if models.peek().is_none() {
+        return Ok(());
+    }
+
+    // Access is checked and logged by the update itself
+    let collection_pass = auth
+        .unlogged_access()
+        .check_collection_access(collection_name, AccessRequirements::new().write())?;
+    let collection = toc.get_collection(&collection_pass).await?;
+    collection.check_embedding_models(models).await?;
+    Ok(())
+}
+
 #[expect(clippy::too_many_arguments)]
 pub async fn do_upsert_points(
     toc_provider: impl CheckedTocProvider,
@@ -307,56 +332,111 @@ pub async fn do_upsert_points(
         )
         .await?;
 
-    let (operation, shard_key, usage, update_filter, update_mode) = match operation {
-        PointInsertOperations::PointsBatch(batch) => {
-            let PointsBatch {
-                batch,
-                shard_key,
-                update_filter,
-                update_mode,
-            } = batch;
-            let (batch, usage) = convert_batch(batch, inference_params).await?;
-            let operation = PointInsertOperationsInternal::PointsBatch(batch);
-            let update_mode = update_mode.map(rest_update_mode_to_internal);
-            (operation, shard_key, usage, update_filter, update_mode)
-        }
-        PointInsertOperations::PointsList(list) => {
-            let PointsList {
-                points,
-                shard_key,
-                update_filter,
-                update_mode,
-            } = list;
-            let (list, usage) =
-                convert_point_struct(points, InferenceType::Update, inference_params).await?;
-            let operation = PointInsertOperationsInternal::PointsList(list);
-            let update_mode = update_mode.map(rest_update_mode_to_internal);
-            (operation, shard_key, usage, update_filter, update_mode)
-        }
-    };
+    let (operation, shard_key, usage, update_filter, update_mode, sequence_key, skip_normalization) =
+        match operation {
+            PointInsertOperations::PointsBatch(batch) => {
+                let PointsBatch {
+                    batch,
+                    shard_key,
+                    update_filter,
+                    update_mode,
+                    sequence_key,
+                    skip_normalization,
+                } = batch;
+                check_inference_models(
+                    toc,
+                    &collection_name,
+                    &auth,
+                    batch_inference_models(&batch.vectors),
+                )
+                .await?;
+                let (batch, usage) = convert_batch(batch, inference_params).await?;
+                let operation = PointInsertOperationsInternal::PointsBatch(batch);
+                let update_mode = update_mode.map(rest_update_mode_to_internal);
+                (
+                    operation,
+                    shard_key,
+                    usage,
+                    update_filter,
+                    update_mode,
+                    sequence_key,
+                    skip_normalization,
+                )
+            }
+            PointInsertOperations::PointsList(list) => {
+                let PointsList {
+                    points,
+                    shard_key,
+                    update_filter,
+                    update_mode,
+                    sequence_key,
+                    skip_normalization,
+                } = list;
+                check_inference_models(
+                    toc,
+                    &collection_name,
+                    &auth,
+                    points
+                        .iter()
+                        .flat_map(|point| inference_models(&point.vector)),
+                )
+                .await?;
+                let (list, usage) =
+                    convert_point_struct(points, InferenceType::Update, inference_params).await?;
+                let operation = PointInsertOperationsInternal::PointsList(list);
+                let update_mode = update_mode.map(rest_update_mode_to_internal);
+                (
+                    operation,
+                    shard_key,
+                    usage,
+                    update_filter,
+                    update_mode,
+                    sequence_key,
+                    skip_normalization,
+                )
+            }
+        };
+
+    // Explicitly disabled normalization is the default behavior
+    let skip_normalization = skip_normalization.filter(|skip| *skip);
 
-    // Decide which operation to use based on update_filter and update_mode
-    let operation = match (update_filter, update_mode) {
+    // Decide which operation to use based on update_filter, update_mode, sequence_key
+    // and skip_normalization
+    let operation = match (update_filter, update_mode, sequence_key, skip_normalization) {
         // If update_filter is provided, always use conditional upsert
-        (Some(condition), mode) => CollectionUpdateOperations::PointOperation(
-            PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
-                points_op: operation,
-                condition,
-                update_mode: mode,
-            }),
-        ),
-        // If update_mode is InsertOnly or UpdateOnly, use conditional upsert with empty filter
-        (None, Some(UpdateMode::InsertOnly)) | (None, Some(UpdateMode::UpdateOnly)) => {
+        (Some(condition), mode, sequence_key, skip_normalization) => {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPointsConditional(
+                ConditionalInsertOperationInternal {
+                    points_op: operation,
+                    condition,
+                    update_mode: mode,
+                    sequence_key,
+                    skip_normalization,
+                },
+            ))
+        }
+        // If update_mode is InsertOnly or UpdateOnly, points are sequenced,
+        // or normalization is skipped, use conditional upsert with empty filter
+        (
+            None,
+            mode @ Some(UpdateMode::InsertOnly | UpdateMode::UpdateOnly),
+            sequence_key,
+            skip_normalization,
+        )
+        | (None, mode, sequence_key @ Some(_), skip_normalization)
+        | (None, mode, sequence_key, skip_normalization @ Some(_)) => {
             CollectionUpdateOperations::PointOperation(PointOperations::UpsertPointsConditional(
                 ConditionalInsertOperationInternal {
                     points_op: operation,
                     condition: Filter::default(), // Empty filter matches all existing points
-                    update_mode,
+                    update_mode: mode,
+                    sequence_key,
+                    skip_normalization,
                 },
             ))
         }
         // Default: regular upsert
-        (None, None) | (None, Some(UpdateMode::Upsert)) => {
+        (None, None | Some(UpdateMode::Upsert), None, None) => {
             CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(operation))
         }
     };
@@ -393,26 +473,35 @@ pub async fn do_delete_points(
     params: UpdateParams,
     auth: Auth,
     hw_measurement_acc: HwMeasurementAcc,
-) -> Result<UpdateResult, StorageError> {
+) -> Result<UpdateResultWithIds, StorageError> {
     let toc = toc_provider
         .check_strict_mode(&points, &collection_name, params.timeout_as_secs(), &auth)
         .await?;
 
-    let (operation, shard_key) = match points {
-        PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
-            (PointOperations::DeletePoints { ids: points }, shard_key)
-        }
-        PointsSelector::FilterSelector(FilterSelector { filter, shard_key }) => {
-            (PointOperations::DeletePointsByFilter(filter), shard_key)
-        }
+    let (operation, shard_key, return_ids) = match points {
+        PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => (
+            PointOperations::DeletePoints { ids: points },
+            shard_key,
+            None,
+        ),
+        PointsSelector::FilterSelector(FilterSelector {
+            filter,
+            shard_key,
+            return_ids,
+        }) => (
+            PointOperations::DeletePointsByFilter(filter),
+            shard_key,
+            return_ids,
+        ),
     };
 
     let operation = CollectionUpdateOperations::PointOperation(operation);
 
-    update(
+    update_returning_ids(
         toc,
         &collection_name,
         operation,
+        return_ids,
         internal_params,
         params,
         shard_key,
@@ -446,17 +535,31 @@ pub async fn do_update_vectors(
         points,
         shard_key,
         update_filter,
+        append,
     } = operation;
 
+    check_inference_models(
+        toc,
+        &collection_name,
+        &auth,
+        points
+            .iter()
+            .flat_map(|point| inference_models(&point.vector)),
+    )
+    .await?;
+
     let (points, usage) =
         convert_point_vectors(points, InferenceType::Update, inference_params).await?;
 
-    let operation = CollectionUpdateOperations::VectorOperation(VectorOperations::UpdateVectors(
-        UpdateVectorsOp {
-            points,
-            update_filter,
-        },
-    ));
+    let update_vectors = UpdateVectorsOp {
+        points,
+        update_filter,
+    };
+    let operation = CollectionUpdateOperations::VectorOperation(if append.unwrap_or_default() {
+        VectorOperations::AppendVectors(update_vectors)
+    } else {
+        VectorOperations::UpdateVectors(update_vectors)
+    });
 
     let result = update(
         toc,
@@ -555,7 +658,7 @@ pub async fn do_set_payload(
     params: UpdateParams,
     auth: Auth,
     hw_measurement_acc: HwMeasurementAcc,
-) -> Result<UpdateResult, StorageError> {
+) -> Result<UpdateResultWithIds, StorageError> {
     let toc = toc_provider
         .check_strict_mode(
             &operation,
@@ -571,8 +674,15 @@ pub async fn do_set_payload(
         filter,
         shard_key,
         key,
+        return_ids,
     } = operation;
 
+    if return_ids.is_some() && (points.is_some() || filter.is_none()) {
+        return Err(StorageError::bad_input(
+            "`return_ids` is only supported for points selected by filter",
+        ));
+    }
+
     let operation =
         CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(SetPayloadOp {
             payload,
@@ -581,10 +691,11 @@ pub async fn do_set_payload(
             key,
         }));
 
-    update(
+    update_returning_ids(
         toc,
         &collection_name,
         operation,
+        return_ids,
         internal_params,
         params,
         shard_key,
@@ -602,7 +713,7 @@ pub async fn do_overwrite_payload(
     params: UpdateParams,
     auth: Auth,
     hw_measurement_acc: HwMeasurementAcc,
-) -> Result<UpdateResult, StorageError> {
+) -> Result<UpdateResultWithIds, StorageError> {
     let toc = toc_provider
         .check_strict_mode(
             &operation,
@@ -618,8 +729,15 @@ pub async fn do_overwrite_payload(
         filter,
         shard_key,
         key: _,
+        return_ids,
     } = operation;
 
+    if return_ids.is_some() && (points.is_some() || filter.is_none()) {
+        return Err(StorageError::bad_input(
+            "`return_ids` is only supported for points selected by filter",
+        ));
+    }
+
     let operation =
         CollectionUpdateOperations::PayloadOperation(PayloadOps::OverwritePayload(SetPayloadOp {
             payload,
@@ -629,10 +747,11 @@ pub async fn do_overwrite_payload(
             key: None,
         }));
 
-    update(
+    update_returning_ids(
         toc,
         &collection_name,
         operation,
+        return_ids,
         internal_params,
         params,
         shard_key,
@@ -695,26 +814,33 @@ pub async fn do_clear_payload(
     params: UpdateParams,
     auth: Auth,
     hw_measurement_acc: HwMeasurementAcc,
-) -> Result<UpdateResult, StorageError> {
+) -> Result<UpdateResultWithIds, StorageError> {
     let toc = toc_provider
         .check_strict_mode(&points, &collection_name, params.timeout_as_secs(), &auth)
         .await?;
 
-    let (point_operation, shard_key) = match points {
+    let (point_operation, shard_key, return_ids) = match points {
         PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
-            (PayloadOps::ClearPayload { points }, shard_key)
-        }
-        PointsSelector::FilterSelector(FilterSelector { filter, shard_key }) => {
-            (PayloadOps::ClearPayloadByFilter(filter), shard_key)
+            (PayloadOps::ClearPayload { points }, shard_key, None)
         }
+        PointsSelector::FilterSelector(FilterSelector {
+            filter,
+            shard_key,
+            return_ids,
+        }) => (
+            PayloadOps::ClearPayloadByFilter(filter),
+            shard_key,
+            return_ids,
+        ),
     };
 
     let operation = CollectionUpdateOperations::PayloadOperation(point_operation);
 
-    update(
+    update_returning_ids(
         toc,
         &collection_name,
         operation,
+        return_ids,
         internal_params,
         params,
         shard_key,
@@ -785,6 +911,7 @@ pub async fn do_batch_update_points(
                     hw_measurement_acc.clone(),
                 )
                 .await?
+                .result
             }
             UpdateOperation::SetPayload(operation) => {
                 do_set_payload(
@@ -797,6 +924,7 @@ pub async fn do_batch_update_points(
                     hw_measurement_acc.clone(),
                 )
                 .await?
+                .result
             }
             UpdateOperation::OverwritePayload(operation) => {
                 do_overwrite_payload(
@@ -809,6 +937,7 @@ pub async fn do_batch_update_points(
                     hw_measurement_acc.clone(),
                 )
                 .await?
+                .result
             }
             UpdateOperation::DeletePayload(operation) => {
                 do_delete_payload(
@@ -833,6 +962,7 @@ pub async fn do_batch_update_points(
                     hw_measurement_acc.clone(),
                 )
                 .await?
+                .result
             }
             UpdateOperation::UpdateVectors(operation) => {
                 let (result, usage) = do_update_vectors(
@@ -1023,6 +1153,61 @@ pub async fn do_delete_index_internal(
     .await
 }
 
+/// Start a background rename or removal of a payload key across all points.
+///
+/// Payload index of the key, if any, is moved to the new key or dropped through consensus,
+/// once the migration is started.
+pub async fn do_start_payload_migration(
+    dispatcher: &Dispatcher,
+    collection_name: &str,
+    migration: PayloadMigration,
+    auth: Auth,
+) -> Result<PayloadMigrationStatus, StorageError> {
+    // Payload migration is a management operation, not subject to strict mode
+    let pass = new_unchecked_verification_pass();
+
+    let collection_pass = auth.check_collection_access(
+        collection_name,
+        AccessRequirements::new().manage(),
+        "start_payload_migration",
+    )?;
+    let collection = dispatcher
+        .toc(&auth, &pass)
+        .get_collection(&collection_pass)
+        .await?;
+
+    let source_key = migration.source_key().clone();
+    let field_schema = collection.payload_key_index_schema(&source_key);
+    let status = collection.start_payload_migration(migration.clone())?;
+    drop(collection);
+
+    let Some(field_schema) = field_schema else {
+        return Ok(status);
+    };
+
+    // Index the new key before the old one is dropped, so filters keep being served by an index
+    if let PayloadMigration::RenameKey { from: _, to } = migration {
+        let consensus_op = CollectionMetaOperations::CreatePayloadIndex(CreatePayloadIndex {
+            collection_name: collection_name.to_string(),
+            field_name: to,
+            field_schema,
+        });
+        dispatcher
+            .submit_collection_meta_op(consensus_op, auth.clone(), None)
+            .await?;
+    }
+
+    let consensus_op = CollectionMetaOperations::DropPayloadIndex(DropPayloadIndex {
+        collection_name: collection_name.to_string(),
+        field_name: source_key,
+    });
+    dispatcher
+        .submit_collection_meta_op(consensus_op, auth, None)
+        .await?;
+
+    Ok(status)
+}
+
 #[expect(clippy::too_many_arguments)]
 pub async fn update(
     toc: &TableOfContent,
@@ -1034,6 +1219,37 @@ pub async fn update(
     auth: Auth,
     hw_measurement_acc: HwMeasurementAcc,
 ) -> Result<UpdateResult, StorageError> {
+    update_returning_ids(
+        toc,
+        collection_name,
+        operation,
+        None,
+        internal_params,
+        params,
+        shard_key,
+        auth,
+        hw_measurement_acc,
+    )
+    .await
+    .map(|result| result.result)
+}
+
+/// Apply the update, returning ids of the points matching its filter if requested.
+///
+/// Ids are selected by the local shards atomically with the update itself,
+/// while the update is still applied to all points matching the filter.
+#[expect(clippy::too_many_arguments)]
+pub async fn update_returning_ids(
+    toc: &TableOfContent,
+    collection_name: &str,
+    operation: CollectionUpdateOperations,
+    return_ids: Option<ReturnIds>,
+    internal_params: InternalUpdateParams,
+    params: UpdateParams,
+    shard_key: Option<ShardKeySelector>,
+    auth: Auth,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<UpdateResultWithIds, StorageError> {
     let InternalUpdateParams {
         shard_id,
         clock_tag,
@@ -1045,6 +1261,12 @@ pub async fn update(
         timeout: _,
     } = params;
 
+    if return_ids.is_some() && !wait {
+        return Err(StorageError::bad_input(
+            "`return_ids` requires waiting for the update to be applied",
+        ));
+    }
+
     let shard_selector = match operation {
         CollectionUpdateOperations::PointOperation(point_ops::PointOperations::SyncPoints(_)) => {
             debug_assert_eq!(
@@ -1076,17 +1298,38 @@ pub async fn update(
         _ => get_shard_selector_for_update(shard_id, shard_key),
     };
 
-    toc.update(
-        collection_name,
-        OperationWithClockTag::new(operation, clock_tag),
-        wait,
-        params.timeout,
-        ordering,
-        shard_selector,
-        auth,
-        hw_measurement_acc,
-    )
-    .await
+    let is_internal = shard_id.is_some();
+
+    let mut result = toc
+        .update(
+            collection_name,
+            OperationWithClockTag::new(operation, clock_tag).with_return_ids(return_ids.clone()),
+            wait,
+            params.timeout,
+            ordering,
+            shard_selector,
+            auth,
+            hw_measurement_acc,
+        )
+        .await?;
+
+    // Internal requests return ids of the shard as is, those are paginated by the requesting peer
+    let Some(ReturnIds { limit, offset: _ }) = return_ids.filter(|_| !is_internal) else {
+        return Ok(UpdateResultWithIds::from(result));
+    };
+
+    // Shards return up to `limit + 1` ids each, the extra one is the start of the next page
+    let mut affected_ids = result.affected_ids.take().unwrap_or_default();
+    affected_ids.sort_unstable();
+    affected_ids.dedup();
+    let next_page_offset = affected_ids.get(limit).copied();
+    affected_ids.truncate(limit);
+
+    Ok(UpdateResultWithIds {
+        result,
+        affected_ids: Some(affected_ids),
+        next_page_offset,
+    })
 }
 
 /// Converts a pair of parameters into a shard selector
@@ -6,7 +6,7 @@ use std::time::{Duration, Instant};
 use std::{cmp, fmt, thread};
 
 use anyhow::{Context as _, anyhow};
-use api::grpc::dynamic_channel_pool::make_grpc_channel;
+use api::grpc::dynamic_channel_pool::{Http2Settings, make_grpc_channel};
 use api::grpc::qdrant::raft_client::RaftClient;
 use api::grpc::qdrant::{AllPeers, PeerId as GrpcPeerId, RaftMessage as GrpcRaftMessage};
 use api::grpc::transport_channel_pool::TransportChannelPool;
@@ -339,6 +339,7 @@ impl Consensus {
             Duration::from_secs(config.bootstrap_timeout_sec),
             cluster_uri,
             tls_config,
+            Http2Settings::default(),
         )
         .await
         .map_err(|err| anyhow!("Failed to create timeout channel: {err}"))?;
@@ -687,6 +688,11 @@ impl Consensus {
                     .context("failed to request snapshot")?;
             }
 
+            Message::FromClient(ConsensusOperations::TransferLeader(peer_id)) => {
+                log::info!("Transferring Raft leadership to peer {peer_id}");
+                self.node.transfer_leader(peer_id);
+            }
+
             Message::FromClient(ConsensusOperations::ReportSnapshot { peer_id, status }) => {
                 self.node.report_snapshot(peer_id, status.into());
             }
@@ -1414,6 +1420,7 @@ impl RaftMessageSender {
             bootstrap_timeout,
             bootstrap_uri,
             self.tls_config.clone(),
+            Http2Settings::default(),
         )
         .await
         .map_err(|err| anyhow::format_err!("Failed to create who-is channel: {err}"))?;
@@ -1467,7 +1474,7 @@ mod tests {
         settings.storage.storage_path = storage_dir.path().to_path_buf();
         tracing_subscriber::fmt::init();
         let search_runtime =
-            crate::create_search_runtime(settings.storage.performance.max_search_threads)
+            crate::create_search_runtime(settings.storage.performance.max_search_threads, false)
                 .expect("Can't create search runtime.");
         let update_runtime =
             crate::create_update_runtime(settings.storage.performance.max_search_threads)
@@ -1493,7 +1500,8 @@ mod tests {
             ),
             persistent_state.this_peer_id(),
             Some(operation_sender.clone()),
-        );
+        )
+        .unwrap();
         let toc_arc = Arc::new(toc);
         let storage_path = toc_arc.storage_path();
         let consensus_state: ConsensusStateRef = ConsensusManager::new(
@@ -1572,6 +1580,7 @@ mod tests {
                                 strict_mode_config: None,
                                 uuid: None,
                                 metadata: None,
+                                placement: None,
                             },
                         )
                         .unwrap(),
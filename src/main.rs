@@ -43,6 +43,7 @@ use storage::rbac::Access;
 ))]
 use tikv_jemallocator::Jemalloc;
 
+use crate::common::auto_replica_scaling::AutoReplicaScaling;
 use crate::common::helpers::{
     create_general_purpose_runtime, create_search_runtime, create_update_runtime,
     load_tls_client_config,
@@ -50,6 +51,7 @@ use crate::common::helpers::{
 use crate::common::inference::service::InferenceService;
 use crate::common::telemetry::TelemetryCollector;
 use crate::common::telemetry_reporting::TelemetryReporter;
+use crate::common::{search_concurrency, shutdown};
 use crate::greeting::welcome;
 use crate::migrations::single_to_cluster::handle_existing_collections;
 use crate::settings::Settings;
@@ -319,8 +321,17 @@ fn main() -> anyhow::Result<()> {
 
     // Create and own search runtime out of the scope of async context to ensure correct
     // destruction of it
-    let search_runtime = create_search_runtime(settings.storage.performance.max_search_threads)
-        .expect("Can't search create runtime.");
+    let search_runtime = create_search_runtime(
+        settings.storage.performance.max_search_threads,
+        settings.storage.performance.adaptive_search_concurrency,
+    )
+    .expect("Can't search create runtime.");
+
+    if settings.storage.performance.adaptive_search_concurrency {
+        search_concurrency::init(::common::defaults::search_thread_count(
+            settings.storage.performance.max_search_threads,
+        ));
+    }
 
     let update_runtime = create_update_runtime(
         settings
@@ -334,6 +345,10 @@ fn main() -> anyhow::Result<()> {
         create_general_purpose_runtime().expect("Can't optimizer general purpose runtime.");
     let runtime_handle = general_runtime.handle().clone();
 
+    if settings.storage.performance.adaptive_search_concurrency {
+        runtime_handle.spawn(search_concurrency::run_controller());
+    }
+
     // Use global CPU budget for optimizations based on settings
     let cpu_budget = get_cpu_budget(settings.storage.performance.optimizer_cpu_budget);
     let io_budget = get_io_budget(settings.storage.performance.optimizer_io_budget, cpu_budget);
@@ -372,9 +387,20 @@ fn main() -> anyhow::Result<()> {
             connection_timeout,
             settings.cluster.p2p.connection_pool_size,
             tls_config,
+            settings.cluster.p2p.channel_tuning(),
         ));
         channel_service.id_to_address = persistent_consensus_state.peer_address_by_id.clone();
         channel_service.id_to_metadata = persistent_consensus_state.peer_metadata_by_id.clone();
+
+        if settings.cluster.p2p.latency_aware_routing {
+            log::info!("Latency-aware routing enabled");
+
+            channel_service.latency_aware_routing = true;
+            runtime_handle.spawn(channel_service.clone().measure_peer_latencies(
+                persistent_consensus_state.this_peer_id(),
+                Duration::from_secs(settings.cluster.p2p.latency_probe_interval_sec),
+            ));
+        }
     }
 
     // Table of content manages the list of collections.
@@ -388,7 +414,7 @@ fn main() -> anyhow::Result<()> {
         channel_service.clone(),
         persistent_consensus_state.this_peer_id(),
         propose_operation_sender.clone(),
-    );
+    )?;
 
     toc.clear_all_tmp_directories()?;
 
@@ -418,6 +444,7 @@ fn main() -> anyhow::Result<()> {
             storage_path,
         )
         .expect("initialize consensus manager")
+        .with_node_labels(settings.cluster.node_labels.clone())
         .into();
         let is_new_deployment = consensus_state.is_new_deployment();
 
@@ -485,6 +512,15 @@ fn main() -> anyhow::Result<()> {
             }
         });
 
+        if settings.cluster.auto_replica_scaling.enabled {
+            log::info!("Auto replica scaling enabled");
+
+            runtime_handle.spawn(AutoReplicaScaling::run(
+                settings.cluster.auto_replica_scaling.clone(),
+                dispatcher_arc.clone(),
+            ));
+        }
+
         // TODO(resharding): Remove resharding driver?
         //
         // runtime_handle.block_on(async {
@@ -560,6 +596,52 @@ fn main() -> anyhow::Result<()> {
         log::error!("Inference service init failed: {err}");
     }
 
+    //
+    // Graceful shutdown
+    //
+
+    {
+        let dispatcher_arc = dispatcher_arc.clone();
+        let toc_arc = toc_arc.clone();
+        let telemetry_collector = telemetry_collector.clone();
+        let grace_period = settings.service.shutdown_grace_period();
+        let runtime_handle = runtime_handle.clone();
+        let handle = thread::Builder::new()
+            .name("shutdown".to_string())
+            .spawn(move || {
+                runtime_handle.block_on(async {
+                    shutdown::wait_stop_signal("Qdrant").await;
+
+                    let in_flight = {
+                        let telemetry_collector = telemetry_collector.lock().await;
+                        [
+                            telemetry_collector
+                                .actix_telemetry_collector
+                                .lock()
+                                .in_flight
+                                .clone(),
+                            telemetry_collector
+                                .tonic_telemetry_collector
+                                .lock()
+                                .in_flight
+                                .clone(),
+                        ]
+                    };
+
+                    shutdown::graceful_shutdown(
+                        &dispatcher_arc,
+                        &toc_arc,
+                        &in_flight,
+                        grace_period,
+                    )
+                    .await;
+                });
+                Ok(())
+            })
+            .unwrap();
+        handles.push(handle);
+    }
+
     //
     // REST API server
     //
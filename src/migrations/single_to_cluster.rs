@@ -79,6 +79,7 @@ pub async fn handle_existing_collections(
                 strict_mode_config,
                 uuid,
                 metadata,
+                placement: params.placement,
             },
         )
         .expect("Failed to create collection operation");
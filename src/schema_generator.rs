@@ -3,10 +3,14 @@
 use api::rest::models::{CollectionsResponse, ShardKeysResponse, Usage, VersionInfo};
 use api::rest::schema::PointInsertOperations;
 use api::rest::{
-    FacetRequest, FacetResponse, QueryGroupsRequest, QueryRequest, QueryRequestBatch,
+    DeduplicationRequest, ExplainFilterRequest, FacetRequest, FacetResponse,
+    PayloadMigrationRequest, QuantilesRequest, QueryGroupsRequest, QueryRequest, QueryRequestBatch,
     QueryResponse, Record, ScoredPoint, SearchMatrixOffsetsResponse, SearchMatrixPairsResponse,
-    SearchMatrixRequest, UpdateVectors,
+    SearchMatrixRequest, TermStatsRequest, UpdateVectors, VectorStatsRequest,
 };
+use collection::collection::deduplication::DeduplicationStatus;
+use collection::collection::payload_index_compaction::PayloadIndexCompaction;
+use collection::collection::payload_migration::PayloadMigrationStatus;
 use collection::operations::cluster_ops::ClusterOperations;
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::payload_ops::{DeletePayload, SetPayload};
@@ -19,17 +23,24 @@ use collection::operations::types::{
     CollectionsAliasesResponse, CountRequest, CountResult, DiscoverRequest, DiscoverRequestBatch,
     GroupsResult, OptimizationsResponse, PointGroup, PointRequest, RecommendGroupsRequest,
     RecommendRequest, RecommendRequestBatch, ScrollRequest, ScrollResult, SearchGroupsRequest,
-    SearchRequest, SearchRequestBatch, UpdateResult,
+    SearchRequest, SearchRequestBatch, UpdateResult, UpdateResultWithIds,
 };
 use collection::operations::vector_ops::DeleteVectors;
 use schemars::JsonSchema;
 use schemars::r#gen::SchemaSettings;
+use segment::data_types::filter_explanation::FilterExplanation;
+use segment::data_types::quantiles::Quantiles;
+use segment::data_types::term_stats::TermStats;
+use segment::data_types::vector_stats::VectorStats;
 use serde::Serialize;
 use storage::content_manager::collection_meta_ops::{
     ChangeAliasesOperation, CreateCollection, UpdateCollection,
 };
 use storage::types::ClusterStatus;
 
+use crate::actix::api::cluster_api::StandbyParams;
+use crate::common::health_report::HealthReport;
+use crate::common::restart::RestartReadiness;
 use crate::common::telemetry::TelemetryData;
 use crate::common::telemetry_ops::distributed_telemetry::DistributedTelemetryData;
 use crate::common::update::{CreateFieldIndex, UpdateOperations};
@@ -100,6 +111,23 @@ struct AllDefinitions {
     bo: ShardKeysResponse,
     bp: OptimizationsResponse,
     bq: DistributedTelemetryData,
+    br: VectorStatsRequest,
+    bs: VectorStats,
+    bt: UpdateResultWithIds,
+    bu: RestartReadiness,
+    bv: StandbyParams,
+    bw: QuantilesRequest,
+    bx: Quantiles,
+    by: DeduplicationRequest,
+    bz: DeduplicationStatus,
+    ca: TermStatsRequest,
+    cb: TermStats,
+    cc: PayloadMigrationRequest,
+    cd: PayloadMigrationStatus,
+    ce: HealthReport,
+    cf: ExplainFilterRequest,
+    cg: FilterExplanation,
+    ch: PayloadIndexCompaction,
 }
 
 fn save_schema<T: JsonSchema>() {
@@ -1,8 +1,12 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::time::Duration;
 use std::{env, io};
 
+use api::grpc::dynamic_channel_pool::Http2Settings;
 use api::grpc::transport_channel_pool::{
-    DEFAULT_CONNECT_TIMEOUT, DEFAULT_GRPC_TIMEOUT, DEFAULT_POOL_SIZE,
+    ChannelTuning, DEFAULT_CONNECT_TIMEOUT, DEFAULT_GRPC_TIMEOUT,
+    DEFAULT_MAX_CONNECTIONS_PER_CHANNEL, DEFAULT_POOL_SIZE,
 };
 use collection::operations::validation;
 use collection::shards::shard::PeerId;
@@ -10,11 +14,13 @@ use common::flags::FeatureFlags;
 use config::{Config, ConfigError, Environment, File, FileFormat, Source};
 use serde::Deserialize;
 use storage::types::StorageConfig;
+use tonic::codec::CompressionEncoding;
 use validator::{Validate, ValidationError};
 
 use crate::common::audit::AuditConfig;
 use crate::common::debugger::DebuggerConfig;
 use crate::common::inference::config::InferenceConfig;
+use crate::common::shutdown::DEFAULT_SHUTDOWN_GRACE_PERIOD;
 use crate::tracing;
 
 const MAX_PEER_ID: u64 = (1 << 53) - 1;
@@ -75,9 +81,33 @@ pub struct ServiceConfig {
     #[serde(default)]
     #[validate(custom(function = validate_metrics_prefix))]
     pub metrics_prefix: Option<String>,
+
+    /// Which failed sub-checks make `/readyz` report the node as not ready.
+    #[serde(default)]
+    pub readiness_strictness: ReadinessStrictness,
+
+    /// How long to wait for in-flight requests on SIGTERM or CTRL_CLOSE, before data is flushed
+    /// and the process exits. Default: 30 seconds.
+    #[serde(default)]
+    pub shutdown_grace_period_sec: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessStrictness {
+    /// Only the startup check, other sub-checks are reported but don't fail readiness
+    #[default]
+    Relaxed,
+    /// Any failed sub-check, e.g. non-writable storage or unknown Raft leader
+    Strict,
 }
 
 impl ServiceConfig {
+    pub fn shutdown_grace_period(&self) -> Duration {
+        self.shutdown_grace_period_sec
+            .map_or(DEFAULT_SHUTDOWN_GRACE_PERIOD, Duration::from_secs)
+    }
+
     pub fn hardware_reporting(&self) -> bool {
         self.hardware_reporting.unwrap_or_default()
     }
@@ -103,6 +133,12 @@ pub struct ClusterConfig {
     pub consensus: ConsensusConfig,
     #[serde(default)]
     pub resharding_enabled: bool, // disabled by default
+    #[serde(default)]
+    #[validate(nested)]
+    pub auto_replica_scaling: AutoReplicaScalingConfig,
+    /// Labels of this peer, used to honor placement constraints of collections
+    #[serde(default)]
+    pub node_labels: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Validate)]
@@ -114,6 +150,67 @@ pub struct P2pConfig {
     pub connection_pool_size: usize,
     #[serde(default)]
     pub enable_tls: bool,
+    /// Measure round-trip times to other peers, and prefer replicas on the closest peers for reads
+    #[serde(default)]
+    pub latency_aware_routing: bool,
+    /// How often to measure round-trip times to other peers
+    #[serde(default = "default_latency_probe_interval_sec")]
+    #[validate(range(min = 1))]
+    pub latency_probe_interval_sec: u64,
+    /// Compression of gRPC messages between peers
+    #[serde(default)]
+    pub compression: P2pCompression,
+    /// Number of concurrent requests multiplexed over a single connection to a peer,
+    /// before another connection is opened
+    #[serde(default = "default_max_concurrent_streams")]
+    #[validate(range(min = 1))]
+    pub max_concurrent_streams: usize,
+    /// Initial HTTP/2 window size of a single stream, in bytes
+    #[serde(default)]
+    pub initial_stream_window_size: Option<u32>,
+    /// Initial HTTP/2 window size of a whole connection, in bytes
+    #[serde(default)]
+    pub initial_connection_window_size: Option<u32>,
+    /// Adjust HTTP/2 window sizes to the measured bandwidth-delay product
+    #[serde(default)]
+    pub adaptive_window: bool,
+}
+
+impl P2pConfig {
+    pub fn http2_settings(&self) -> Http2Settings {
+        Http2Settings {
+            initial_stream_window_size: self.initial_stream_window_size,
+            initial_connection_window_size: self.initial_connection_window_size,
+            adaptive_window: self.adaptive_window,
+        }
+    }
+
+    pub fn channel_tuning(&self) -> ChannelTuning {
+        ChannelTuning {
+            compression: self.compression.encoding(),
+            max_connections_per_channel: self.max_concurrent_streams,
+            http2: self.http2_settings(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum P2pCompression {
+    #[default]
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl P2pCompression {
+    pub fn encoding(self) -> Option<CompressionEncoding> {
+        match self {
+            P2pCompression::None => None,
+            P2pCompression::Gzip => Some(CompressionEncoding::Gzip),
+            P2pCompression::Zstd => Some(CompressionEncoding::Zstd),
+        }
+    }
 }
 
 impl Default for P2pConfig {
@@ -122,6 +219,13 @@ impl Default for P2pConfig {
             port: None,
             connection_pool_size: default_connection_pool_size(),
             enable_tls: false,
+            latency_aware_routing: false,
+            latency_probe_interval_sec: default_latency_probe_interval_sec(),
+            compression: P2pCompression::default(),
+            max_concurrent_streams: default_max_concurrent_streams(),
+            initial_stream_window_size: None,
+            initial_connection_window_size: None,
+            adaptive_window: false,
         }
     }
 }
@@ -156,6 +260,58 @@ impl Default for ConsensusConfig {
     }
 }
 
+/// Automatic scaling of the replication factor of collections, based on the query load.
+///
+/// The load is measured on the consensus leader, which is the only peer making scaling decisions.
+/// With requests balanced across peers, thresholds therefore apply to the load of a single peer.
+#[derive(Debug, Deserialize, Clone, Validate)]
+pub struct AutoReplicaScalingConfig {
+    #[serde(default)]
+    pub enabled: bool, // disabled by default
+    /// Replication factor is never lowered below this value
+    #[serde(default = "default_auto_scaling_min_replication_factor")]
+    #[validate(range(min = 1))]
+    pub min_replication_factor: u32,
+    /// Replication factor is never raised above this value, nor above the number of peers
+    #[serde(default = "default_auto_scaling_max_replication_factor")]
+    #[validate(range(min = 1))]
+    pub max_replication_factor: u32,
+    /// Raise the replication factor if the busiest shard of a collection gets more reads per second
+    #[serde(default)]
+    #[validate(range(min = 0.0))]
+    pub scale_up_reads_per_sec: Option<f64>,
+    /// Raise the replication factor if the average read latency of any shard exceeds this value
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub scale_up_latency_ms: Option<u64>,
+    /// Lower the replication factor if the busiest shard of a collection gets fewer reads per second
+    #[serde(default)]
+    #[validate(range(min = 0.0))]
+    pub scale_down_reads_per_sec: Option<f64>,
+    /// How often the query load is evaluated
+    #[serde(default = "default_auto_scaling_check_interval_sec")]
+    #[validate(range(min = 1))]
+    pub check_interval_sec: u64,
+    /// Minimal time between two scaling decisions for the same collection
+    #[serde(default = "default_auto_scaling_cooldown_sec")]
+    pub cooldown_sec: u64,
+}
+
+impl Default for AutoReplicaScalingConfig {
+    fn default() -> Self {
+        AutoReplicaScalingConfig {
+            enabled: false,
+            min_replication_factor: default_auto_scaling_min_replication_factor(),
+            max_replication_factor: default_auto_scaling_max_replication_factor(),
+            scale_up_reads_per_sec: None,
+            scale_up_latency_ms: None,
+            scale_down_reads_per_sec: None,
+            check_interval_sec: default_auto_scaling_check_interval_sec(),
+            cooldown_sec: default_auto_scaling_cooldown_sec(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Validate)]
 pub struct TlsConfig {
     pub cert: String,
@@ -429,6 +585,14 @@ const fn default_connection_pool_size() -> usize {
     DEFAULT_POOL_SIZE
 }
 
+const fn default_latency_probe_interval_sec() -> u64 {
+    10
+}
+
+const fn default_max_concurrent_streams() -> usize {
+    DEFAULT_MAX_CONNECTIONS_PER_CHANNEL
+}
+
 const fn default_message_timeout_tics() -> u64 {
     10
 }
@@ -437,6 +601,22 @@ const fn default_compact_wal_entries() -> u64 {
     128
 }
 
+const fn default_auto_scaling_min_replication_factor() -> u32 {
+    1
+}
+
+const fn default_auto_scaling_max_replication_factor() -> u32 {
+    3
+}
+
+const fn default_auto_scaling_check_interval_sec() -> u64 {
+    30
+}
+
+const fn default_auto_scaling_cooldown_sec() -> u64 {
+    600
+}
+
 #[allow(clippy::unnecessary_wraps)] // Used as serde default
 const fn default_tls_cert_ttl() -> Option<u64> {
     // Default one hour
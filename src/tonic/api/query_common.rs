@@ -8,7 +8,7 @@ use api::grpc::qdrant::{
     QueryResponse, ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse,
     RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
     ScrollPoints, ScrollResponse, SearchBatchResponse, SearchGroupsResponse, SearchMatrixPoints,
-    SearchPointGroups, SearchPoints, SearchResponse,
+    SearchPointGroups, SearchPoints, SearchResponse, TextHighlights,
 };
 use api::grpc::{InferenceUsage, Usage};
 use api::rest::OrderByInterface;
@@ -24,6 +24,8 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use segment::data_types::facets::FacetParams;
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorInternal};
+use segment::index::field_index::full_text_index::highlight::PayloadHighlighter;
+use segment::types::ScoredPoint;
 use shard::count::CountRequestInternal;
 use shard::query::query_enum::QueryEnum;
 use shard::scroll::ScrollRequestInternal;
@@ -32,6 +34,7 @@ use storage::content_manager::toc::TableOfContent;
 use storage::content_manager::toc::request_hw_counter::RequestHwCounter;
 use storage::rbac::Auth;
 use tonic::{Response, Status};
+use uuid::Uuid;
 
 use crate::common::inference::params::InferenceParams;
 use crate::common::inference::query_requests_grpc::{
@@ -589,8 +592,18 @@ pub async fn scroll(
         shard_key_selector,
         order_by,
         timeout,
+        consistent,
+        view_id,
     } = scroll_points;
 
+    let view_id = view_id
+        .map(|view_id| {
+            Uuid::parse_str(&view_id).map_err(|_err| {
+                Status::invalid_argument(format!("Unable to parse UUID: {view_id}"))
+            })
+        })
+        .transpose()?;
+
     let scroll_request = ScrollRequestInternal {
         offset: offset.map(|o| o.try_into()).transpose()?,
         limit: limit.map(|l| l as usize),
@@ -603,6 +616,8 @@ pub async fn scroll(
             .map(OrderBy::try_from)
             .transpose()?
             .map(OrderByInterface::Struct),
+        consistent,
+        view_id,
     };
 
     let toc = toc_provider
@@ -643,6 +658,7 @@ pub async fn scroll(
     let response = ScrollResponse {
         next_page_offset: scrolled_points.next_page_offset.map(|n| n.into()),
         result: points,
+        view_id: scrolled_points.view_id.map(|view_id| view_id.to_string()),
         time: timing.elapsed().as_secs_f64(),
         usage: Usage::from_hardware_usage(request_hw_counter.to_grpc_api()).into_non_empty(),
     };
@@ -790,8 +806,10 @@ pub async fn query(
         .transpose()?;
     let collection_name = query_points.collection_name.clone();
     let timeout = query_points.timeout;
+    let highlight = query_points.highlight.clone();
     let (request, inference_usage) =
         convert_query_points_from_grpc(query_points, inference_params).await?;
+    let highlight = highlight.zip(request.filter.clone());
 
     let toc = toc_provider
         .check_strict_mode(
@@ -802,6 +820,14 @@ pub async fn query(
         )
         .await?;
 
+    let highlighter = match highlight {
+        Some((highlight, filter)) => Some(
+            toc.payload_highlighter(&collection_name, &filter, highlight.into(), auth.clone())
+                .await?,
+        ),
+        None => None,
+    };
+
     let timeout = timeout.map(Duration::from_secs);
 
     let timing = Instant::now();
@@ -818,10 +844,7 @@ pub async fn query(
     .await?;
 
     let response = QueryResponse {
-        result: scored_points
-            .into_iter()
-            .map(|point| point.into())
-            .collect(),
+        result: convert_query_points(scored_points, highlighter.as_ref()),
         time: timing.elapsed().as_secs_f64(),
         usage: Usage::new(request_hw_counter.to_grpc_api(), Some(inference_usage)).into_non_empty(),
     };
@@ -843,13 +866,16 @@ pub async fn query_batch(
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
     let mut requests = Vec::with_capacity(points.len());
     let mut total_inference_usage = InferenceUsage::default();
+    let mut highlights = Vec::with_capacity(points.len());
 
     for query_points in points {
         let shard_key_selector = query_points.shard_key_selector.clone();
         let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
+        let highlight = query_points.highlight.clone();
         let (request, usage) =
             convert_query_points_from_grpc(query_points, inference_params.clone()).await?;
         total_inference_usage.merge(usage);
+        highlights.push(highlight.zip(request.filter.clone()));
         requests.push((request, shard_selector));
     }
 
@@ -863,6 +889,18 @@ pub async fn query_batch(
         )
         .await?;
 
+    let mut highlighters = Vec::with_capacity(highlights.len());
+    for highlight in highlights {
+        let highlighter = match highlight {
+            Some((highlight, filter)) => Some(
+                toc.payload_highlighter(collection_name, &filter, highlight.into(), auth.clone())
+                    .await?,
+            ),
+            None => None,
+        };
+        highlighters.push(highlighter);
+    }
+
     let timing = Instant::now();
     let scored_points = do_query_batch_points(
         toc,
@@ -878,8 +916,9 @@ pub async fn query_batch(
     let response = QueryBatchResponse {
         result: scored_points
             .into_iter()
-            .map(|points| BatchResult {
-                result: points.into_iter().map(|p| p.into()).collect(),
+            .zip(highlighters)
+            .map(|(points, highlighter)| BatchResult {
+                result: convert_query_points(points, highlighter.as_ref()),
             })
             .collect(),
         time: timing.elapsed().as_secs_f64(),
@@ -893,6 +932,34 @@ pub async fn query_batch(
     Ok(Response::new(response))
 }
 
+/// Convert points into gRPC, with matches of full-text conditions found in the payload of each point
+fn convert_query_points(
+    points: Vec<ScoredPoint>,
+    highlighter: Option<&PayloadHighlighter>,
+) -> Vec<api::grpc::qdrant::ScoredPoint> {
+    points
+        .into_iter()
+        .map(|point| {
+            let highlight = highlighter
+                .zip(point.payload.as_ref())
+                .map(|(highlighter, payload)| highlighter.highlight(payload))
+                .unwrap_or_default();
+
+            let mut point = api::grpc::qdrant::ScoredPoint::from(point);
+            point.highlight = highlight
+                .into_iter()
+                .map(|(key, highlights)| {
+                    let highlights = TextHighlights {
+                        highlights: highlights.into_iter().map(From::from).collect(),
+                    };
+                    (key.to_string(), highlights)
+                })
+                .collect();
+            point
+        })
+        .collect()
+}
+
 pub async fn query_groups(
     toc_provider: impl CheckedTocProvider,
     query_points: QueryPointGroups,
@@ -1075,3 +1142,95 @@ pub async fn search_points_matrix(
 
     Ok(search_matrix_response)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use api::grpc::qdrant::TextOffset;
+    use segment::index::field_index::full_text_index::highlight::HighlightParams;
+    use segment::json_path::JsonPath;
+    use segment::payload_json;
+    use segment::types::{
+        Condition, FieldCondition, Filter, Match, PayloadFieldSchema, PayloadSchemaType,
+    };
+
+    use super::*;
+
+    fn scored_point(id: u64, payload: Option<segment::types::Payload>) -> ScoredPoint {
+        ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score: 1.0,
+            payload,
+            vector: None,
+            shard_key: None,
+            order_value: None,
+        }
+    }
+
+    #[test]
+    fn test_convert_query_points_highlights() {
+        let title = JsonPath::new("title");
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            title.clone(),
+            Match::new_text("quick"),
+        )));
+        let payload_schema = HashMap::from([(
+            title,
+            PayloadFieldSchema::FieldType(PayloadSchemaType::Text),
+        )]);
+        let highlighter =
+            PayloadHighlighter::new(&filter, &payload_schema, HighlightParams::default());
+
+        let points = vec![
+            scored_point(
+                1,
+                Some(payload_json! { "title": ["Über quick fox", "slow dog", "quick"] }),
+            ),
+            scored_point(2, Some(payload_json! { "title": "slow dog" })),
+            scored_point(3, None),
+        ];
+
+        let grpc_points = convert_query_points(points.clone(), Some(&highlighter));
+
+        // Offsets are in characters, as in REST
+        let highlights = &grpc_points[0].highlight["title"].highlights;
+        assert_eq!(highlights.len(), 2);
+        assert_eq!(highlights[0].fragments, vec!["Über <em>quick</em> fox"]);
+        assert_eq!(
+            highlights[0].offsets,
+            vec![TextOffset { start: 5, end: 10 }]
+        );
+        assert_eq!(highlights[1].offsets, vec![TextOffset { start: 0, end: 5 }]);
+
+        // Points without matches or payload have no highlights
+        assert!(grpc_points[1].highlight.is_empty());
+        assert!(grpc_points[2].highlight.is_empty());
+
+        // Same as REST response converted into gRPC
+        for (point, grpc_point) in points.into_iter().zip(grpc_points) {
+            let highlight = point
+                .payload
+                .as_ref()
+                .map(|payload| highlighter.highlight(payload))
+                .filter(|highlight| !highlight.is_empty())
+                .map(|highlight| {
+                    highlight
+                        .into_iter()
+                        .map(|(key, highlights)| (key.to_string(), highlights))
+                        .collect()
+                });
+            let rest_point = api::rest::ScoredPoint {
+                highlight,
+                ..api::rest::ScoredPoint::from(point)
+            };
+            let converted = api::grpc::qdrant::ScoredPoint::try_from(rest_point).unwrap();
+            assert_eq!(converted, grpc_point);
+        }
+
+        // Without a highlighter, points are converted as is
+        let grpc_points = convert_query_points(vec![scored_point(4, None)], None);
+        assert!(grpc_points[0].highlight.is_empty());
+    }
+}
@@ -54,6 +54,8 @@ pub async fn upsert(
         update_filter,
         timeout,
         update_mode,
+        sequence_key,
+        skip_normalization,
     } = upsert_points;
 
     let points: Result<_, _> = points.into_iter().map(PointStruct::try_from).collect();
@@ -67,6 +69,10 @@ pub async fn upsert(
             .map(segment::types::Filter::try_from)
             .transpose()?,
         update_mode: update_mode.map(grpc_update_mode_to_rest),
+        sequence_key: sequence_key
+            .map(|key| json_path_from_proto(&key))
+            .transpose()?,
+        skip_normalization,
     });
 
     let timing = Instant::now();
@@ -114,12 +120,14 @@ pub async fn delete(
         ordering,
         shard_key_selector,
         timeout,
+        return_ids,
     } = delete_points;
 
     let points_selector = match points {
         None => return Err(Status::invalid_argument("PointSelector is missing")),
         Some(p) => try_points_selector_from_grpc(p, shard_key_selector)?,
     };
+    let points_selector = with_return_ids(points_selector, return_ids)?;
 
     let timing = Instant::now();
     let result = do_delete_points(
@@ -154,6 +162,7 @@ pub async fn update_vectors(
         shard_key_selector,
         update_filter,
         timeout,
+        append,
     } = update_point_vectors;
 
     // Build list of operation points
@@ -178,6 +187,7 @@ pub async fn update_vectors(
         update_filter: update_filter
             .map(segment::types::Filter::try_from)
             .transpose()?,
+        append,
     };
 
     let timing = Instant::now();
@@ -267,6 +277,7 @@ pub async fn set_payload(
         shard_key_selector,
         key,
         timeout,
+        return_ids,
     } = set_payload_points;
     let key = key.map(|k| json_path_from_proto(&k)).transpose()?;
 
@@ -279,6 +290,7 @@ pub async fn set_payload(
             .map(ShardKeySelector::try_from)
             .transpose()?,
         key,
+        return_ids: return_ids.map(TryInto::try_into).transpose()?,
     };
 
     let timing = Instant::now();
@@ -313,6 +325,7 @@ pub async fn overwrite_payload(
         ordering,
         shard_key_selector,
         timeout,
+        return_ids,
         ..
     } = set_payload_points;
 
@@ -326,6 +339,7 @@ pub async fn overwrite_payload(
             .transpose()?,
         // overwrite operation don't support indicate path of property
         key: None,
+        return_ids: return_ids.map(TryInto::try_into).transpose()?,
     };
 
     let timing = Instant::now();
@@ -404,12 +418,14 @@ pub async fn clear_payload(
         ordering,
         shard_key_selector,
         timeout,
+        return_ids,
     } = clear_payload_points;
 
     let points_selector = match points {
         None => return Err(Status::invalid_argument("PointSelector is missing")),
         Some(p) => try_points_selector_from_grpc(p, shard_key_selector)?,
     };
+    let points_selector = with_return_ids(points_selector, return_ids)?;
 
     let timing = Instant::now();
     let result = do_clear_payload(
@@ -460,6 +476,8 @@ pub async fn update_batch(
                 shard_key_selector,
                 update_filter,
                 update_mode,
+                sequence_key,
+                skip_normalization,
             }) => {
                 upsert(
                     StrictModeCheckedTocProvider::new(dispatcher),
@@ -472,6 +490,8 @@ pub async fn update_batch(
                         update_filter,
                         timeout,
                         update_mode,
+                        sequence_key,
+                        skip_normalization,
                     },
                     internal_params,
                     auth.clone(),
@@ -490,6 +510,7 @@ pub async fn update_batch(
                         ordering,
                         shard_key_selector: None,
                         timeout,
+                        return_ids: None,
                     },
                     internal_params,
                     auth.clone(),
@@ -516,6 +537,7 @@ pub async fn update_batch(
                         shard_key_selector,
                         key,
                         timeout,
+                        return_ids: None,
                     },
                     internal_params,
                     auth.clone(),
@@ -543,6 +565,7 @@ pub async fn update_batch(
                         // overwrite operation doesn't support it
                         key: None,
                         timeout,
+                        return_ids: None,
                     },
                     internal_params,
                     auth.clone(),
@@ -587,6 +610,7 @@ pub async fn update_batch(
                         ordering,
                         shard_key_selector,
                         timeout,
+                        return_ids: None,
                     },
                     internal_params,
                     auth.clone(),
@@ -599,6 +623,7 @@ pub async fn update_batch(
                     points,
                     shard_key_selector,
                     update_filter,
+                    append,
                 },
             ) => {
                 update_vectors(
@@ -611,6 +636,7 @@ pub async fn update_batch(
                         shard_key_selector,
                         update_filter,
                         timeout,
+                        append,
                     },
                     internal_params,
                     auth.clone(),
@@ -653,6 +679,7 @@ pub async fn update_batch(
                         ordering,
                         shard_key_selector: None,
                         timeout,
+                        return_ids: None,
                     },
                     internal_params,
                     auth.clone(),
@@ -673,6 +700,7 @@ pub async fn update_batch(
                         ordering,
                         shard_key_selector,
                         timeout,
+                        return_ids: None,
                     },
                     internal_params,
                     auth.clone(),
@@ -892,7 +920,7 @@ pub async fn sync(
 
 pub fn points_operation_response_internal_with_inference_usage(
     timing: Instant,
-    update_result: collection::operations::types::UpdateResult,
+    update_result: impl Into<grpc::UpdateResultInternal>,
     hardware_usage: Option<HardwareUsage>,
     inference_usage: Option<InferenceUsage>,
 ) -> PointsOperationResponseInternal {
@@ -906,7 +934,7 @@ pub fn points_operation_response_internal_with_inference_usage(
 
 pub fn points_operation_response_internal(
     timing: Instant,
-    update_result: collection::operations::types::UpdateResult,
+    update_result: impl Into<grpc::UpdateResultInternal>,
     hardware_usage: Option<HardwareUsage>,
 ) -> PointsOperationResponseInternal {
     points_operation_response_internal_with_inference_usage(
@@ -917,6 +945,27 @@ pub fn points_operation_response_internal(
     )
 }
 
+fn with_return_ids(
+    points_selector: point_ops::PointsSelector,
+    return_ids: Option<grpc::ReturnIds>,
+) -> Result<point_ops::PointsSelector, Status> {
+    let Some(return_ids) = return_ids else {
+        return Ok(points_selector);
+    };
+
+    match points_selector {
+        point_ops::PointsSelector::FilterSelector(filter_selector) => Ok(
+            point_ops::PointsSelector::FilterSelector(point_ops::FilterSelector {
+                return_ids: Some(return_ids.try_into()?),
+                ..filter_selector
+            }),
+        ),
+        point_ops::PointsSelector::PointIdsSelector(_) => Err(Status::invalid_argument(
+            "return_ids is only supported for points selected by filter",
+        )),
+    }
+}
+
 fn extract_points_selector(
     points_selector: Option<PointsSelector>,
 ) -> Result<(Option<Vec<ExtendedPointId>>, Option<Filter>), Status> {
@@ -993,6 +1042,12 @@ fn convert_field_type(
                         TryFrom::try_from(uuid_index_params).map(PayloadSchemaParams::Uuid)
                     })
                 }
+                // Parameterized GeoShape type
+                IndexParams::GeoShapeIndexParams(geo_shape_index_params) => {
+                    matches!(field_type, FieldType::GeoShape).then(|| {
+                        TryFrom::try_from(geo_shape_index_params).map(PayloadSchemaParams::GeoShape)
+                    })
+                }
             }
             .ok_or_else(|| {
                 Status::invalid_argument(format!(
@@ -1012,6 +1067,7 @@ fn convert_field_type(
             FieldType::Bool => Some(PayloadSchemaType::Bool.into()),
             FieldType::Datetime => Some(PayloadSchemaType::Datetime.into()),
             FieldType::Uuid => Some(PayloadSchemaType::Uuid.into()),
+            FieldType::GeoShape => Some(PayloadSchemaType::GeoShape.into()),
         },
         (None, Some(_)) => return Err(Status::invalid_argument("field type is missing")),
         (None, None) => None,
@@ -31,7 +31,6 @@ use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
 use storage::rbac::{Access, Auth};
 use tokio::runtime::Handle;
-use tokio::signal;
 use tonic::codec::CompressionEncoding;
 use tonic::transport::{Server, ServerTlsConfig};
 use tonic::{Request, Response, Status};
@@ -39,6 +38,7 @@ use tonic::{Request, Response, Status};
 use crate::common::auth::AuthKeys;
 use crate::common::helpers;
 use crate::common::http_client::HttpClient;
+use crate::common::shutdown::wait_stop_signal;
 use crate::common::telemetry::TelemetryCollector;
 use crate::common::telemetry_ops::requests_telemetry::TonicTelemetryCollector;
 use crate::settings::Settings;
@@ -80,23 +80,6 @@ impl Health for HealthService {
     }
 }
 
-#[cfg(not(unix))]
-async fn wait_stop_signal(for_what: &str) {
-    signal::ctrl_c().await.unwrap();
-    log::debug!("Stopping {for_what} on SIGINT");
-}
-
-#[cfg(unix)]
-async fn wait_stop_signal(for_what: &str) {
-    let mut term = signal::unix::signal(signal::unix::SignalKind::terminate()).unwrap();
-    let mut inrt = signal::unix::signal(signal::unix::SignalKind::interrupt()).unwrap();
-
-    tokio::select! {
-        _ = term.recv() => log::debug!("Stopping {for_what} on SIGTERM"),
-        _ = inrt.recv() => log::debug!("Stopping {for_what} on SIGINT"),
-    }
-}
-
 pub fn init(
     dispatcher: Arc<Dispatcher>,
     telemetry_collector: Arc<parking_lot::Mutex<TonicTelemetryCollector>>,
@@ -222,6 +205,7 @@ pub fn init_internal(
     use crate::tonic::api::raft_api::RaftService;
 
     let http_client = HttpClient::from_settings(&settings)?;
+    let http2 = settings.cluster.p2p.http2_settings();
 
     runtime
         .block_on(async {
@@ -247,7 +231,10 @@ pub fn init_internal(
                 // We prefer to keep more pending reset streams even though this may be expensive,
                 // versus an internal error that is very hard to handle.
                 // More info: <https://github.com/qdrant/qdrant/issues/1907>
-                .http2_max_pending_accept_reset_streams(Some(1024));
+                .http2_max_pending_accept_reset_streams(Some(1024))
+                .initial_stream_window_size(http2.initial_stream_window_size)
+                .initial_connection_window_size(http2.initial_connection_window_size)
+                .http2_adaptive_window(Some(http2.adaptive_window));
 
             if let Some(config) = tls_config {
                 log::info!("TLS enabled for internal gRPC API (TTL not supported)");
@@ -271,36 +258,48 @@ pub fn init_internal(
                     QdrantServer::new(qdrant_service)
                         .send_compressed(CompressionEncoding::Gzip)
                         .accept_compressed(CompressionEncoding::Gzip)
+                        .send_compressed(CompressionEncoding::Zstd)
+                        .accept_compressed(CompressionEncoding::Zstd)
                         .max_decoding_message_size(usize::MAX),
                 )
                 .add_service(
                     QdrantInternalServer::new(qdrant_internal_service)
                         .send_compressed(CompressionEncoding::Gzip)
                         .accept_compressed(CompressionEncoding::Gzip)
+                        .send_compressed(CompressionEncoding::Zstd)
+                        .accept_compressed(CompressionEncoding::Zstd)
                         .max_decoding_message_size(usize::MAX),
                 )
                 .add_service(
                     CollectionsInternalServer::new(collections_internal_service)
                         .send_compressed(CompressionEncoding::Gzip)
                         .accept_compressed(CompressionEncoding::Gzip)
+                        .send_compressed(CompressionEncoding::Zstd)
+                        .accept_compressed(CompressionEncoding::Zstd)
                         .max_decoding_message_size(usize::MAX),
                 )
                 .add_service(
                     PointsInternalServer::new(points_internal_service)
                         .send_compressed(CompressionEncoding::Gzip)
                         .accept_compressed(CompressionEncoding::Gzip)
+                        .send_compressed(CompressionEncoding::Zstd)
+                        .accept_compressed(CompressionEncoding::Zstd)
                         .max_decoding_message_size(usize::MAX),
                 )
                 .add_service(
                     ShardSnapshotsServer::new(shard_snapshots_service)
                         .send_compressed(CompressionEncoding::Gzip)
                         .accept_compressed(CompressionEncoding::Gzip)
+                        .send_compressed(CompressionEncoding::Zstd)
+                        .accept_compressed(CompressionEncoding::Zstd)
                         .max_decoding_message_size(usize::MAX),
                 )
                 .add_service(
                     RaftServer::new(raft_service)
                         .send_compressed(CompressionEncoding::Gzip)
                         .accept_compressed(CompressionEncoding::Gzip)
+                        .send_compressed(CompressionEncoding::Zstd)
+                        .accept_compressed(CompressionEncoding::Zstd)
                         .max_decoding_message_size(usize::MAX),
                 )
                 .serve_with_shutdown(socket, async {
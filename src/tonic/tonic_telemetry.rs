@@ -1,13 +1,16 @@
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
 use std::task::{Context, Poll};
 
+use futures_util::TryStreamExt;
 use futures_util::future::BoxFuture;
 use tonic::body::BoxBody;
+use tonic::codegen::Body as _;
 use tower::Service;
 use tower_layer::Layer;
 
 use crate::common::telemetry_ops::requests_telemetry::{
-    TonicTelemetryCollector, TonicWorkerTelemetryCollector,
+    InFlightRequests, NO_COLLECTION, TonicTelemetryCollector, TonicWorkerTelemetryCollector,
 };
 
 /// Based on https://grpc.io/docs/guides/status-codes/
@@ -20,6 +23,12 @@ const DEFAULT_FAILURE_GRPC_STATUS_CODE: i32 = 2;
 
 const GRPC_STATUS_HEADER: &str = "grpc-status";
 
+/// All requests of this service have the collection name as the first field
+const COLLECTION_SERVICE_PREFIX: &str = "/qdrant.Points/";
+
+/// Protobuf tag of the `collection_name = 1` string field
+const COLLECTION_NAME_TAG: u8 = (1 << 3) | 2;
+
 type Request = tonic::codegen::http::Request<tonic::transport::Body>;
 type Response = tonic::codegen::http::Response<BoxBody>;
 
@@ -27,6 +36,7 @@ type Response = tonic::codegen::http::Response<BoxBody>;
 pub struct TonicTelemetryService<T> {
     service: T,
     telemetry_data: Arc<parking_lot::Mutex<TonicWorkerTelemetryCollector>>,
+    in_flight: InFlightRequests,
 }
 
 #[derive(Clone)]
@@ -49,9 +59,27 @@ where
 
     fn call(&mut self, request: Request) -> Self::Future {
         let method_name = request.uri().path().to_string();
+        let in_flight_guard = self.in_flight.start_request();
+
+        // Count the request body as it is read, and peek the collection name from the first frame
+        let request_bytes = Arc::new(AtomicU64::new(0));
+        let collection = Arc::new(OnceLock::new());
+        let parse_collection = method_name.starts_with(COLLECTION_SERVICE_PREFIX);
+        let request = request.map(|body| {
+            let request_bytes = request_bytes.clone();
+            let collection = collection.clone();
+            tonic::transport::Body::wrap_stream(body.inspect_ok(move |chunk| {
+                if parse_collection && request_bytes.load(Ordering::Relaxed) == 0 {
+                    collection.get_or_init(|| parse_collection_name(chunk));
+                }
+                request_bytes.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+            }))
+        });
+
         let future = self.service.call(request);
         let telemetry_data = self.telemetry_data.clone();
         Box::pin(async move {
+            let _in_flight_guard = in_flight_guard;
             let instant = std::time::Instant::now();
             let response = future.await?;
 
@@ -70,14 +98,60 @@ where
                     }
                 });
 
-            telemetry_data
-                .lock()
-                .add_response(method_name, instant, status_code);
-            Ok(response)
+            // Failed requests are not attributed to a collection, the collection may not exist.
+            // Unary requests are read completely by the time the response is ready.
+            let collection = collection
+                .get()
+                .and_then(Option::as_deref)
+                .filter(|_| status_code == DEFAULT_SUCCESS_GRPC_STATUS_CODE)
+                .unwrap_or(NO_COLLECTION);
+
+            let counters = {
+                let mut telemetry_data = telemetry_data.lock();
+                let counters = telemetry_data.transfer_counters(&method_name, collection);
+                telemetry_data.add_response(method_name, instant, status_code);
+                counters
+            };
+            counters.add_request_bytes(request_bytes.load(Ordering::Relaxed));
+
+            // Count the response body as it is sent
+            Ok(response.map(|body| {
+                tonic::body::boxed(body.map_data(move |chunk| {
+                    counters.add_response_bytes(chunk.len() as u64);
+                    chunk
+                }))
+            }))
         })
     }
 }
 
+/// Parse the collection name from the beginning of a gRPC request body.
+///
+/// Compressed messages are not inspected, and the collection name must be within the first chunk.
+fn parse_collection_name(body: &[u8]) -> Option<String> {
+    // Frame header: compression flag and message length
+    let [0, _, _, _, _, COLLECTION_NAME_TAG, message @ ..] = body else {
+        return None;
+    };
+
+    // Varint length of the collection name, which is short
+    let mut length = 0;
+    let mut consumed = 0;
+    for (i, byte) in message.iter().take(2).enumerate() {
+        length |= usize::from(byte & 0x7F) << (7 * i);
+        if byte & 0x80 == 0 {
+            consumed = i + 1;
+            break;
+        }
+    }
+    if consumed == 0 {
+        return None;
+    }
+
+    let name = message.get(consumed..consumed + length)?;
+    std::str::from_utf8(name).ok().map(ToOwned::to_owned)
+}
+
 impl TonicTelemetryLayer {
     pub fn new(
         telemetry_collector: Arc<parking_lot::Mutex<TonicTelemetryCollector>>,
@@ -92,12 +166,51 @@ impl<S> Layer<S> for TonicTelemetryLayer {
     type Service = TonicTelemetryService<S>;
 
     fn layer(&self, service: S) -> Self::Service {
+        let mut telemetry_collector = self.telemetry_collector.lock();
         TonicTelemetryService {
             service,
-            telemetry_data: self
-                .telemetry_collector
-                .lock()
-                .create_grpc_telemetry_collector(),
+            telemetry_data: telemetry_collector.create_grpc_telemetry_collector(),
+            in_flight: telemetry_collector.in_flight.clone(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(message: &[u8]) -> Vec<u8> {
+        let mut frame = vec![0];
+        frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+        frame.extend_from_slice(message);
+        frame
+    }
+
+    #[test]
+    fn test_parse_collection_name() {
+        // `collection_name: "books"` followed by another field
+        let message = [&[COLLECTION_NAME_TAG, 5][..], b"books", &[0x10, 0x01]].concat();
+        assert_eq!(
+            parse_collection_name(&frame(&message)).as_deref(),
+            Some("books"),
+        );
+
+        // Two bytes varint length
+        let name = "a".repeat(200);
+        let message = [&[COLLECTION_NAME_TAG, 0xC8, 0x01][..], name.as_bytes()].concat();
+        assert_eq!(parse_collection_name(&frame(&message)), Some(name));
+
+        // Compressed frame
+        let mut compressed = frame(&[COLLECTION_NAME_TAG, 1, b'a']);
+        compressed[0] = 1;
+        assert_eq!(parse_collection_name(&compressed), None);
+
+        // Truncated name, and a different first field
+        assert_eq!(
+            parse_collection_name(&frame(&[COLLECTION_NAME_TAG, 5, b'a'])),
+            None
+        );
+        assert_eq!(parse_collection_name(&frame(&[0x10, 0x01])), None);
+        assert_eq!(parse_collection_name(&[]), None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 use tracing_subscriber::fmt;
@@ -46,6 +46,16 @@ pub enum LogFormat {
     Json,
 }
 
+/// Time-based rotation of the log-file
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogRotation {
+    #[default]
+    Never,
+    Hourly,
+    Daily,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum SpanEvent {
@@ -80,6 +90,26 @@ impl From<SpanEvent> for fmt::format::FmtSpan {
     }
 }
 
+/// Log levels of individual modules, e.g. `raft: warn` or `segment: debug`.
+/// Override the common `log_level` for the matching targets.
+pub type ModuleLevels = BTreeMap<String, String>;
+
+/// Combine common log level and per-module overrides into a single filter directive
+pub fn log_directives(log_level: Option<&str>, module_levels: Option<&ModuleLevels>) -> String {
+    let module_directives = module_levels
+        .into_iter()
+        .flatten()
+        .map(|(module, level)| format!("{module}={level}"));
+
+    log_level
+        .filter(|log_level| !log_level.is_empty())
+        .map(str::to_string)
+        .into_iter()
+        .chain(module_directives)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum Color {
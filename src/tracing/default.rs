@@ -10,6 +10,7 @@ use super::*;
 #[serde(default)]
 pub struct Config {
     pub log_level: Option<String>,
+    pub module_levels: Option<config::ModuleLevels>,
     pub span_events: Option<HashSet<config::SpanEvent>>,
     pub format: Option<config::LogFormat>,
     pub color: Option<config::Color>,
@@ -19,12 +20,14 @@ impl Config {
     pub fn merge(&mut self, other: Self) {
         let Self {
             log_level,
+            module_levels,
             span_events,
             format,
             color,
         } = other;
 
         self.log_level.replace_if_some(log_level);
+        self.module_levels.replace_if_some(module_levels);
         self.span_events.replace_if_some(span_events);
         self.format.replace_if_some(format);
         self.color.replace_if_some(color);
@@ -57,5 +60,8 @@ where
 }
 
 pub fn new_filter(config: &Config) -> filter::EnvFilter {
-    filter(config.log_level.as_deref().unwrap_or(""))
+    filter(&config::log_directives(
+        config.log_level.as_deref(),
+        config.module_levels.as_ref(),
+    ))
 }
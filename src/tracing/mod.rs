@@ -2,6 +2,7 @@ pub mod config;
 pub mod default;
 pub mod handle;
 pub mod on_disk;
+mod rotation;
 
 #[cfg(test)]
 mod test;
@@ -1,10 +1,8 @@
 use std::collections::HashSet;
-use std::io;
 use std::sync::Mutex;
 
 use anyhow::Context as _;
 use common::ext::OptionExt;
-use fs_err as fs;
 use serde::{Deserialize, Serialize};
 use tracing_subscriber::{Layer, fmt, registry};
 
@@ -16,9 +14,16 @@ pub struct Config {
     pub enabled: Option<bool>,
     pub log_file: Option<String>,
     pub log_level: Option<String>,
+    pub module_levels: Option<config::ModuleLevels>,
     pub format: Option<config::LogFormat>,
     pub span_events: Option<HashSet<config::SpanEvent>>,
     pub buffer_size_bytes: Option<usize>,
+    /// Rotate the log-file once it grows larger than this
+    pub max_file_size_bytes: Option<u64>,
+    /// Rotate the log-file every hour or every day
+    pub rotation: Option<config::LogRotation>,
+    /// Number of rotated log-files to keep
+    pub max_files: Option<usize>,
 }
 
 impl Config {
@@ -27,17 +32,26 @@ impl Config {
             enabled,
             log_file,
             log_level,
+            module_levels,
             span_events,
             format,
             buffer_size_bytes,
+            max_file_size_bytes,
+            rotation,
+            max_files,
         } = other;
 
         self.enabled.replace_if_some(enabled);
         self.log_file.replace_if_some(log_file);
         self.log_level.replace_if_some(log_level);
+        self.module_levels.replace_if_some(module_levels);
         self.span_events.replace_if_some(span_events);
         self.format.replace_if_some(format);
         self.buffer_size_bytes.replace_if_some(buffer_size_bytes);
+        self.max_file_size_bytes
+            .replace_if_some(max_file_size_bytes);
+        self.rotation.replace_if_some(rotation);
+        self.max_files.replace_if_some(max_files);
     }
 }
 
@@ -76,17 +90,17 @@ where
         ));
     };
 
-    let writer = fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(log_file)
-        .with_context(|| format!("failed to open log file {log_file}"))?;
+    let writer = rotation::RotatingWriter::new(
+        log_file,
+        config.buffer_size_bytes.unwrap_or(8192),
+        config.max_file_size_bytes,
+        config.rotation.unwrap_or_default(),
+        config.max_files.unwrap_or(rotation::DEFAULT_MAX_FILES),
+    )
+    .with_context(|| format!("failed to open log file {log_file}"))?;
 
     let layer = fmt::Layer::default()
-        .with_writer(Mutex::new(io::BufWriter::with_capacity(
-            config.buffer_size_bytes.unwrap_or(8192),
-            writer,
-        )))
+        .with_writer(Mutex::new(writer))
         .with_span_events(config::SpanEvent::unwrap_or_default_config(
             &config.span_events,
         ))
@@ -101,5 +115,8 @@ where
 }
 
 pub fn new_filter(config: &Config) -> filter::EnvFilter {
-    filter(config.log_level.as_deref().unwrap_or(""))
+    filter(&config::log_directives(
+        config.log_level.as_deref(),
+        config.module_levels.as_ref(),
+    ))
 }
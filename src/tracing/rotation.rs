@@ -0,0 +1,192 @@
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use fs_err as fs;
+
+use super::*;
+
+/// Number of rotated log-files kept on disk, if not configured explicitly
+pub const DEFAULT_MAX_FILES: usize = 5;
+
+/// Log-file writer, that moves the current log-file aside once it grows too large or
+/// the rotation period is over.
+///
+/// Rotated files are named `<log_file>.1`, `<log_file>.2`, ... from the newest to the oldest.
+/// Only `max_files` rotated files are kept, older ones are removed.
+pub struct RotatingWriter {
+    path: PathBuf,
+    writer: io::BufWriter<fs::File>,
+    buffer_size: usize,
+    /// Bytes written into the current log-file, including the existing content
+    file_size: u64,
+    max_file_size: Option<u64>,
+    rotation: config::LogRotation,
+    /// Rotation period, the current log-file was opened in
+    period: Option<u64>,
+    max_files: usize,
+}
+
+impl RotatingWriter {
+    pub fn new(
+        path: impl Into<PathBuf>,
+        buffer_size: usize,
+        max_file_size: Option<u64>,
+        rotation: config::LogRotation,
+        max_files: usize,
+    ) -> io::Result<Self> {
+        let path = path.into();
+        let file = open_log_file(&path)?;
+        let file_size = file.metadata()?.len();
+        let period = rotation.current_period();
+
+        Ok(Self {
+            path,
+            writer: io::BufWriter::with_capacity(buffer_size, file),
+            buffer_size,
+            file_size,
+            max_file_size,
+            rotation,
+            period,
+            max_files,
+        })
+    }
+
+    fn should_rotate(&self, incoming: usize) -> bool {
+        // Never rotate an empty file, otherwise a single huge line would rotate on every write
+        if self.file_size == 0 {
+            return false;
+        }
+
+        let size_exceeded = self
+            .max_file_size
+            .is_some_and(|max_size| self.file_size + incoming as u64 > max_size);
+
+        let period_over = self.rotation.current_period() != self.period;
+
+        size_exceeded || period_over
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+
+        // Shift rotated files by one, dropping the oldest one
+        if self.max_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let oldest = rotated_path(&self.path, self.max_files);
+            if oldest.exists() {
+                fs::remove_file(&oldest)?;
+            }
+
+            for index in (1..self.max_files).rev() {
+                let from = rotated_path(&self.path, index);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, index + 1))?;
+                }
+            }
+
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+
+        self.writer = io::BufWriter::with_capacity(self.buffer_size, open_log_file(&self.path)?);
+        self.file_size = 0;
+        self.period = self.rotation.current_period();
+
+        Ok(())
+    }
+}
+
+impl io::Write for RotatingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // `fmt::Layer` writes each log-line with a single `write_all` call, so log-lines are
+        // never split between log-files
+        if self.should_rotate(buf.len()) {
+            self.rotate()?;
+        }
+
+        let written = self.writer.write(buf)?;
+        self.file_size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl config::LogRotation {
+    /// Index of the rotation period, that the current moment belongs to
+    fn current_period(self) -> Option<u64> {
+        let period_secs = match self {
+            Self::Never => return None,
+            Self::Hourly => 60 * 60,
+            Self::Daily => 24 * 60 * 60,
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        Some(now / period_secs)
+    }
+}
+
+fn open_log_file(path: &Path) -> io::Result<fs::File> {
+    fs::OpenOptions::new().create(true).append(true).open(path)
+}
+
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(format!(".{index}"));
+    PathBuf::from(rotated)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write as _;
+
+    use super::*;
+
+    #[test]
+    fn test_size_based_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_file = dir.path().join("qdrant.log");
+
+        let mut writer =
+            RotatingWriter::new(&log_file, 16, Some(10), config::LogRotation::Never, 2).unwrap();
+
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            writer.write_all(line.as_bytes()).unwrap();
+        }
+        writer.flush().unwrap();
+
+        let read = |path: &Path| fs::read_to_string(path).unwrap();
+
+        assert_eq!(read(&log_file), "fourth\n");
+        assert_eq!(read(&rotated_path(&log_file, 1)), "third\n");
+        assert_eq!(read(&rotated_path(&log_file, 2)), "second\n");
+
+        // Only `max_files` rotated files are kept
+        assert!(!rotated_path(&log_file, 3).exists());
+    }
+
+    #[test]
+    fn test_existing_file_size_is_accounted() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_file = dir.path().join("qdrant.log");
+        fs::write(&log_file, "previous run\n").unwrap();
+
+        let mut writer =
+            RotatingWriter::new(&log_file, 16, Some(20), config::LogRotation::Never, 1).unwrap();
+        writer.write_all(b"current run\n").unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&log_file).unwrap(), "current run\n");
+        assert_eq!(
+            fs::read_to_string(rotated_path(&log_file, 1)).unwrap(),
+            "previous run\n",
+        );
+    }
+}
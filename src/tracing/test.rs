@@ -25,6 +25,7 @@ fn deserialize_logger_config() {
     let expected = LoggerConfig {
         default: default::Config {
             log_level: Some("debug".into()),
+            module_levels: None,
             span_events: Some(HashSet::from([
                 config::SpanEvent::New,
                 config::SpanEvent::Close,
@@ -37,12 +38,16 @@ fn deserialize_logger_config() {
             enabled: Some(true),
             log_file: Some("/logs/qdrant".into()),
             log_level: Some("tracing".into()),
+            module_levels: None,
             span_events: Some(HashSet::from([
                 config::SpanEvent::New,
                 config::SpanEvent::Close,
             ])),
             format: None,
             buffer_size_bytes: Some(1024),
+            max_file_size_bytes: None,
+            rotation: None,
+            max_files: None,
         },
     };
 
@@ -72,6 +77,7 @@ fn deserialize_json_logger_config() {
     let expected = LoggerConfig {
         default: default::Config {
             log_level: Some("debug".into()),
+            module_levels: None,
             span_events: Some(HashSet::from([
                 config::SpanEvent::New,
                 config::SpanEvent::Close,
@@ -84,18 +90,62 @@ fn deserialize_json_logger_config() {
             enabled: Some(true),
             log_file: Some("/logs/qdrant".into()),
             log_level: Some("tracing".into()),
+            module_levels: None,
             span_events: Some(HashSet::from([
                 config::SpanEvent::New,
                 config::SpanEvent::Close,
             ])),
             format: Some(config::LogFormat::Text),
             buffer_size_bytes: Some(1024),
+            max_file_size_bytes: None,
+            rotation: None,
+            max_files: None,
         },
     };
 
     assert_eq!(config, expected);
 }
 
+#[test]
+fn deserialize_rotation_and_module_levels() {
+    let json = json!({
+        "log_level": "info",
+        "module_levels": {
+            "raft": "warn",
+            "segment": "debug",
+        },
+
+        "on_disk": {
+            "enabled": true,
+            "log_file": "/logs/qdrant",
+            "max_file_size_bytes": 1048576,
+            "rotation": "daily",
+            "max_files": 3,
+        }
+    });
+
+    let config = deserialize_config(json);
+
+    assert_eq!(
+        config.default.module_levels,
+        Some(config::ModuleLevels::from([
+            ("raft".into(), "warn".into()),
+            ("segment".into(), "debug".into()),
+        ])),
+    );
+    assert_eq!(
+        config::log_directives(
+            config.default.log_level.as_deref(),
+            config.default.module_levels.as_ref(),
+        ),
+        "info,raft=warn,segment=debug",
+    );
+
+    assert_eq!(config.on_disk.max_file_size_bytes, Some(1048576));
+    assert_eq!(config.on_disk.rotation, Some(config::LogRotation::Daily));
+    assert_eq!(config.on_disk.max_files, Some(3));
+}
+
 #[test]
 fn deserialize_empty_config() {
     let config = deserialize_config(json!({}));
@@ -112,6 +162,7 @@ fn deserialize_config_with_empty_on_disk() {
 fn deseriailze_config_with_explicit_nulls() {
     let json = json!({
         "log_level": null,
+        "module_levels": null,
         "span_events": null,
         "format": null,
         "color": null,
@@ -120,9 +171,13 @@ fn deseriailze_config_with_explicit_nulls() {
             "enabled": null,
             "log_file": null,
             "log_level": null,
+            "module_levels": null,
             "span_events": null,
             "format": null,
             "buffer_size_bytes": null,
+            "max_file_size_bytes": null,
+            "rotation": null,
+            "max_files": null,
         }
     });
 
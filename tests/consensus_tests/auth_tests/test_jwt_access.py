@@ -145,6 +145,42 @@ ACTION_ACCESS = {
         True,
         "GET /collections/{collection_name}/optimizations",
     ),
+    "start_deduplication": EndpointAccess(
+        False,
+        False,
+        True,
+        "POST /collections/{collection_name}/deduplication",
+    ),
+    "get_deduplication_status": EndpointAccess(
+        False,
+        False,
+        True,
+        "GET /collections/{collection_name}/deduplication",
+    ),
+    "cancel_deduplication": EndpointAccess(
+        False,
+        False,
+        True,
+        "DELETE /collections/{collection_name}/deduplication",
+    ),
+    "start_payload_migration": EndpointAccess(
+        False,
+        False,
+        True,
+        "POST /collections/{collection_name}/payload/migration",
+    ),
+    "get_payload_migration_status": EndpointAccess(
+        False,
+        False,
+        True,
+        "GET /collections/{collection_name}/payload/migration",
+    ),
+    "cancel_payload_migration": EndpointAccess(
+        False,
+        False,
+        True,
+        "DELETE /collections/{collection_name}/payload/migration",
+    ),
     "replicate_shard_operation": EndpointAccess(
         False,
         False,
@@ -279,6 +315,12 @@ ACTION_ACCESS = {
         "qdrant.Points/DeleteFieldIndex",
         coll_prw=False,
     ),
+    "compact_payload_indexes": EndpointAccess(
+        False,
+        False,
+        True,
+        "POST /collections/{collection_name}/index/compact",
+    ),
     ### Collection Snapshots ###
     "list_collection_snapshots": EndpointAccess(
         True,
@@ -358,6 +400,13 @@ ACTION_ACCESS = {
         True,
         "GET /collections/{collection_name}/shards/{shard_id}/snapshots/{snapshot_name}",
     ),
+    ### Segments ###
+    "export_hnsw_graph": EndpointAccess(
+        False,
+        False,
+        True,
+        "GET /collections/{collection_name}/shards/{shard_id}/segments/{segment_id}/hnsw_graph",
+    ),
     ### Full Snapshots ###
     "list_full_snapshots": EndpointAccess(
         True,
@@ -572,6 +621,18 @@ ACTION_ACCESS = {
     "facet": EndpointAccess(
         True, True, True, "POST /collections/{collection_name}/facet", "qdrant.Points/Facet"
     ),
+    "vector_stats": EndpointAccess(
+        True, True, True, "POST /collections/{collection_name}/vectors/stats"
+    ),
+    "quantiles": EndpointAccess(
+        True, True, True, "POST /collections/{collection_name}/quantiles"
+    ),
+    "term_stats": EndpointAccess(
+        True, True, True, "POST /collections/{collection_name}/terms/stats"
+    ),
+    "explain_filter": EndpointAccess(
+        True, True, True, "POST /collections/{collection_name}/filter/explain"
+    ),
     ### Service ###
     "root": EndpointAccess(True, True, True, "GET /", "qdrant.Qdrant/HealthCheck", everything=True),
     "readyz": EndpointAccess(True, True, True, "GET /readyz", "grpc.health.v1.Health/Check", everything=True),
@@ -993,6 +1054,51 @@ def test_get_optimizations():
     )
 
 
+def test_start_deduplication():
+    check_access(
+        "start_deduplication",
+        rest_request={"score_threshold": 0.99},
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
+def test_get_deduplication_status():
+    check_access(
+        "get_deduplication_status",
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
+def test_cancel_deduplication():
+    check_access(
+        "cancel_deduplication",
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
+def test_start_payload_migration():
+    check_access(
+        "start_payload_migration",
+        # Key without payload index, to keep the indexes of the test collection intact
+        rest_request={"drop_field": {"key": "unknown_field"}},
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
+def test_get_payload_migration_status():
+    check_access(
+        "get_payload_migration_status",
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
+def test_cancel_payload_migration():
+    check_access(
+        "cancel_payload_migration",
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
 def test_replicate_shard_operation():
     peer_ids = [PEER_ID + 5, PEER_ID + 3]
     replicate_shard = {
@@ -1241,6 +1347,13 @@ def test_delete_index():
     )
 
 
+def test_compact_payload_indexes():
+    check_access(
+        "compact_payload_indexes",
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
 def test_list_collection_snapshots():
     check_access(
         "list_collection_snapshots",
@@ -1400,6 +1513,17 @@ def test_download_shard_snapshot(shard_snapshot_name: str):
     )
 
 
+def test_export_hnsw_graph():
+    check_access(
+        "export_hnsw_graph",
+        path_params={
+            "collection_name": COLL_NAME,
+            "shard_id": SHARD_ID,
+            "segment_id": "00000000-0000-0000-0000-000000000000",
+        },
+    )
+
+
 def test_list_full_snapshots():
     check_access("list_full_snapshots")
 
@@ -1870,6 +1994,38 @@ def test_facet():
     )
 
 
+def test_vector_stats():
+    check_access(
+        "vector_stats",
+        path_params={"collection_name": COLL_NAME},
+        rest_request={"sample": 10},
+    )
+
+
+def test_quantiles():
+    check_access(
+        "quantiles",
+        path_params={"collection_name": COLL_NAME},
+        rest_request={"key": FIELD_NAME},
+    )
+
+
+def test_term_stats():
+    check_access(
+        "term_stats",
+        path_params={"collection_name": COLL_NAME},
+        rest_request={"key": FIELD_NAME},
+    )
+
+
+def test_explain_filter():
+    check_access(
+        "explain_filter",
+        path_params={"collection_name": COLL_NAME},
+        rest_request={"filter": {"must": [{"key": FIELD_NAME, "match": {"value": "value"}}]}},
+    )
+
+
 def test_root():
     check_access("root")
 
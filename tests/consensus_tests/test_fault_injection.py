@@ -0,0 +1,52 @@
+import pathlib
+
+from .fixtures import create_collection, upsert_random_points
+from .utils import *
+
+N_PEERS = 3
+COLLECTION_NAME = "test_collection"
+
+
+def set_fault(peer_api_uri: str, point: str, config: dict):
+    r = requests.put(f"{peer_api_uri}/debugger/faults/{point}", json=config)
+    assert_http_ok(r)
+
+
+def clear_faults(peer_api_uri: str):
+    r = requests.delete(f"{peer_api_uri}/debugger/faults")
+    assert_http_ok(r)
+
+
+def test_dropped_rpc_and_crashed_transfer(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, _peer_dirs, _bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    # Fault injection is only available in builds with the `chaos-testing` feature
+    skip_if_no_feature(peer_api_uris[0], "chaos_testing")
+
+    create_collection(peer_api_uris[0], shard_number=1, replication_factor=N_PEERS)
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLLECTION_NAME, peer_api_uris=peer_api_uris)
+    upsert_random_points(peer_api_uris[0], 100)
+
+    # Drop all internal requests from the first peer to the last one
+    last_peer_id = get_cluster_info(peer_api_uris[-1])["peer_id"]
+    last_peer_p2p_uri = get_cluster_info(peer_api_uris[0])["peers"][str(last_peer_id)]["uri"].rstrip("/")
+    set_fault(peer_api_uris[0], "drop_internal_rpc", {"target": last_peer_p2p_uri})
+
+    # Updates can't reach the replica on the last peer, so it is marked as dead
+    upsert_random_points(peer_api_uris[0], 100, offset=100)
+    wait_for_some_replicas_not_active(peer_api_uris[0], COLLECTION_NAME)
+    clear_faults(peer_api_uris[0])
+
+    # Transfers to recover the dead replica crash, so it can't become active
+    for uri in peer_api_uris:
+        set_fault(uri, "crash_shard_transfer", {"target": COLLECTION_NAME})
+    time.sleep(5)
+    assert check_some_replicas_not_active(peer_api_uris[0], COLLECTION_NAME)
+
+    # Once faults are gone, the replica recovers all points
+    for uri in peer_api_uris:
+        clear_faults(uri)
+    wait_for_all_replicas_active(peer_api_uris[0], COLLECTION_NAME)
+    wait_for(check_collection_local_shards_point_count, peer_api_uris[-1], COLLECTION_NAME, 200)
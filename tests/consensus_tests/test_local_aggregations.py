@@ -0,0 +1,76 @@
+import requests
+
+from .assertions import assert_http_ok
+from .fixtures import create_collection, upsert_random_points
+from .utils import every_test, start_cluster, wait_collection_exists_and_active_on_all_peers
+
+COLL_NAME = "test_collection"
+NUM_POINTS = 100
+
+
+def setup_collection(tmp_path, replication_factor, port_seed):
+    peer_api_uris, _peer_dirs, _bootstrap_uri = start_cluster(tmp_path, num_peers=2, port_seed=port_seed)
+
+    create_collection(
+        peer_api_uris[0],
+        collection=COLL_NAME,
+        shard_number=2,
+        replication_factor=replication_factor,
+    )
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLL_NAME, peer_api_uris=peer_api_uris)
+
+    requests.put(
+        f"{peer_api_uris[0]}/collections/{COLL_NAME}/index?wait=true",
+        json={"field_name": "price", "field_schema": "float"},
+    ).raise_for_status()
+
+    upsert_random_points(peer_api_uris[0], NUM_POINTS, collection_name=COLL_NAME, extra_payload={"price": 10.0})
+
+    return peer_api_uris
+
+
+def vector_stats(uri):
+    return requests.post(f"{uri}/collections/{COLL_NAME}/vectors/stats", json={"sample": NUM_POINTS})
+
+
+def quantiles(uri):
+    return requests.post(f"{uri}/collections/{COLL_NAME}/quantiles", json={"key": "price"})
+
+
+def explain_filter(uri):
+    return requests.post(
+        f"{uri}/collections/{COLL_NAME}/filter/explain",
+        json={"filter": {"must": [{"key": "price", "range": {"gte": 5.0}}]}},
+    )
+
+
+def test_aggregations_fail_with_remote_shards(tmp_path, every_test):
+    # Each peer holds one of the two shards
+    peer_api_uris = setup_collection(tmp_path, replication_factor=1, port_seed=10000)
+
+    for uri in peer_api_uris:
+        for res in (vector_stats(uri), quantiles(uri), explain_filter(uri)):
+            assert res.status_code == 400, res.text
+            assert "no active replica on this peer" in res.json()["status"]["error"]
+
+
+def test_aggregations_over_local_replicas(tmp_path, every_test):
+    # Each peer holds a replica of both shards
+    peer_api_uris = setup_collection(tmp_path, replication_factor=2, port_seed=10100)
+
+    for uri in peer_api_uris:
+        res = vector_stats(uri)
+        assert_http_ok(res)
+        assert 0 < res.json()["result"]["sample_size"] <= NUM_POINTS
+
+        res = quantiles(uri)
+        assert_http_ok(res)
+        result = res.json()["result"]
+        assert result["count"] == NUM_POINTS
+        assert all(abs(quantile["value"] - 10.0) < 1e-6 for quantile in result["quantiles"])
+
+        res = explain_filter(uri)
+        assert_http_ok(res)
+        result = res.json()["result"]
+        assert result["cardinality"]["max"] == NUM_POINTS
+        assert all(segment["plan"] in ("index_iteration", "full_scan") for segment in result["segments"])
@@ -0,0 +1,42 @@
+import pathlib
+
+from .fixtures import create_collection, upsert_random_points
+from .utils import *
+
+N_PEERS = 3
+COLLECTION_NAME = "test_collection"
+
+
+def test_prepare_restart_of_leader(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, _peer_dirs, _bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    create_collection(peer_api_uris[0], shard_number=N_PEERS, replication_factor=2)
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLLECTION_NAME, peer_api_uris=peer_api_uris)
+    upsert_random_points(peer_api_uris[0], 100)
+
+    leader = get_leader(peer_api_uris[0])
+    leader_uri = next(uri for uri in peer_api_uris if get_cluster_info(uri)["peer_id"] == leader)
+
+    r = requests.post(f"{leader_uri}/cluster/prepare_restart", params={"timeout": 10})
+    assert_http_ok(r)
+    readiness = r.json()["result"]
+
+    assert readiness["optimizers_paused"]
+    assert readiness["flushed"]
+    assert not readiness["is_leader"]
+    assert readiness["ready"]
+
+    # Leadership is handed over to another peer
+    new_leader = get_leader(leader_uri)
+    assert new_leader != leader
+    wait_for_uniform_cluster_status(peer_api_uris, new_leader)
+
+    # Preparing again is idempotent
+    r = requests.post(f"{leader_uri}/cluster/prepare_restart", params={"timeout": 10})
+    assert_http_ok(r)
+    assert r.json()["result"]["ready"]
+
+    r = requests.delete(f"{leader_uri}/cluster/prepare_restart")
+    assert_http_ok(r)
@@ -0,0 +1,63 @@
+import pathlib
+
+from .fixtures import create_collection, upsert_random_points, random_dense_vector
+from .utils import *
+
+N_PEERS = 3
+N_SHARDS = 2
+COLLECTION_NAME = "test_collection"
+
+
+def local_replica_states(peer_api_uri: str) -> set[str]:
+    info = get_collection_cluster_info(peer_api_uri, COLLECTION_NAME)
+    return {shard["state"] for shard in info["local_shards"]}
+
+
+def check_local_replicas_in_state(peer_api_uri: str, state: str) -> bool:
+    return local_replica_states(peer_api_uri) == {state}
+
+
+def search_status(peer_api_uri: str) -> int:
+    r = requests.post(
+        f"{peer_api_uri}/collections/{COLLECTION_NAME}/points/search",
+        json={"vector": random_dense_vector(), "limit": 10},
+    )
+    return r.status_code
+
+
+def test_standby_node(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, _peer_dirs, _bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+    standby_uri = peer_api_uris[-1]
+
+    create_collection(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=N_PEERS)
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLLECTION_NAME, peer_api_uris=peer_api_uris)
+    upsert_random_points(peer_api_uris[0], 100)
+
+    # Put the last peer into standby
+    r = requests.put(f"{standby_uri}/cluster/standby", json={"standby": True})
+    assert_http_ok(r)
+
+    r = requests.get(f"{standby_uri}/cluster/standby")
+    assert_http_ok(r)
+    assert r.json()["result"]["standby"]
+
+    # Replicas are kept in sync as listeners
+    wait_for(check_local_replicas_in_state, standby_uri, "Listener")
+
+    # Standby peer refuses client requests and is not ready for load balancing
+    assert search_status(standby_uri) == 503
+    assert requests.get(f"{standby_uri}/readyz").status_code == 503
+
+    # Other peers keep serving, and updates are replicated to the standby peer
+    assert search_status(peer_api_uris[0]) == 200
+    upsert_random_points(peer_api_uris[0], 100, offset=100)
+
+    # Promote the standby peer
+    r = requests.put(f"{standby_uri}/cluster/standby", json={"standby": False})
+    assert_http_ok(r)
+
+    wait_for(check_local_replicas_in_state, standby_uri, "Active")
+    assert search_status(standby_uri) == 200
+    assert get_collection_point_count(standby_uri, COLLECTION_NAME, exact=True) == 200
@@ -458,3 +458,45 @@ def test_with_vectors_alias_of_with_vector(collection_name):
 
     scroll_with_vector("with_vector")
     scroll_with_vector("with_vectors")
+
+
+def test_consistent_scroll(collection_name):
+    def scroll(body):
+        return request_with_validation(
+            api='/collections/{collection_name}/points/scroll',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body=body,
+        )
+
+    response = scroll({"limit": 100, "with_payload": False})
+    assert response.ok
+    expected_ids = [point['id'] for point in response.json()['result']['points']]
+
+    response = scroll({"limit": 3, "with_payload": False, "consistent": True})
+    assert response.ok
+    result = response.json()['result']
+    view_id = result['view_id']
+    ids = [point['id'] for point in result['points']]
+
+    while result['next_page_offset'] is not None:
+        response = scroll({
+            "offset": result['next_page_offset'],
+            "limit": 3,
+            "with_payload": False,
+            "view_id": view_id,
+        })
+        assert response.ok
+        result = response.json()['result']
+        assert result['view_id'] == view_id
+        ids += [point['id'] for point in result['points']]
+
+    assert ids == expected_ids
+
+    # Consistent scroll must start from the beginning
+    response = scroll({"offset": 2, "limit": 3, "consistent": True})
+    assert response.status_code == 400
+
+    # Finished scroll released its view
+    response = scroll({"offset": 2, "limit": 3, "view_id": view_id})
+    assert response.status_code == 400
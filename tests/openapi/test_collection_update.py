@@ -190,3 +190,77 @@ def test_edit_collection_params(on_disk_vectors, on_disk_payload, collection_nam
     assert config["quantization_config"]["scalar"]["type"] == "int8"
     assert config["quantization_config"]["scalar"]["quantile"] == 0.99
     assert config["quantization_config"]["scalar"]["always_ram"]
+
+
+def test_edit_non_finite_vector_policy(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    assert "non_finite_vector_policy" not in response.json()["result"]["config"]["params"]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "params": {
+                "non_finite_vector_policy": "sanitize_to_zero",
+            },
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    config = response.json()["result"]["config"]
+    assert config["params"]["non_finite_vector_policy"] == "sanitize_to_zero"
+
+
+def test_edit_default_search_params(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "params": {
+                "default_search_params": {
+                    "hnsw_ef": 256,
+                    "quantization": {
+                        "rescore": True,
+                        "oversampling": 2.0,
+                    },
+                },
+            },
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    default_search_params = response.json()["result"]["config"]["params"]["default_search_params"]
+    assert default_search_params["hnsw_ef"] == 256
+    assert default_search_params["quantization"]["oversampling"] == 2.0
+
+    # Requests without search params use the defaults
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/query',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "query": [0.2, 0.1, 0.9, 0.7],
+            "limit": 3,
+        }
+    )
+    assert response.ok
+    assert len(response.json()["result"]["points"]) == 3
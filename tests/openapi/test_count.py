@@ -67,3 +67,19 @@ def test_approx_count_search(collection_name):
     assert response.ok
     assert response.json()['result']['count'] < 10
     assert response.json()['result']['count'] > 0
+    assert response.json()['result']['min'] <= response.json()['result']['count']
+    assert response.json()['result']['count'] <= response.json()['result']['max']
+
+
+def test_exact_count_has_no_bounds(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/count',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "exact": True
+        }
+    )
+    assert response.ok
+    assert 'min' not in response.json()['result']
+    assert 'max' not in response.json()['result']
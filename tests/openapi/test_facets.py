@@ -230,3 +230,64 @@ def test_boolean_facet(collection_name):
             {"value": False, "count": 3},
         ]
     }
+
+
+def test_integer_range_facet(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/facet",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "key": "integer",
+            "ranges": {"edges": [0, 2, 4]},
+        },
+    )
+
+    assert response.ok, response.json()
+
+    range_facet = response.json()["result"]
+    assert range_facet == {
+        "hits": [
+            # In order of the ranges, upper edge is excluded
+            {"value": {"from": 0, "to": 2}, "count": 3},
+            {"value": {"from": 2, "to": 4}, "count": 5},
+        ]
+    }
+
+
+def test_integer_histogram_facet(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/facet",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "key": "integer",
+            "ranges": {"histogram": {"buckets": 3}},
+        },
+    )
+
+    assert response.ok, response.json()
+
+    range_facet = response.json()["result"]
+    assert range_facet == {
+        "hits": [
+            {"value": {"from": 0, "to": 1}, "count": 2},
+            {"value": {"from": 1, "to": 2}, "count": 1},
+            # Last range includes the highest value
+            {"value": {"from": 2, "to": 3}, "count": 5},
+        ]
+    }
+
+
+def test_range_facet_requires_numeric_index(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/facet",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "key": "city",
+            "ranges": {"edges": [0, 1]},
+        },
+    )
+
+    assert response.status_code == 400, response.json()
@@ -0,0 +1,64 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+
+    for point_id, payload in [
+        (1, {"price": 10, "budget": 20}),
+        (2, {"price": 20, "budget": 20}),
+        (3, {"price": 30.5, "budget": 20}),
+        (4, {"price": [40, 5], "budget": 20}),
+        (5, {"price": 10}),
+    ]:
+        response = request_with_validation(
+            api='/collections/{collection_name}/points/payload',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            query_params={'wait': 'true'},
+            body={"payload": payload, "points": [point_id]},
+        )
+        assert response.ok
+
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def matching_ids(collection_name, compare):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {"must": [{"key": "price", "compare": compare}]},
+            "limit": 10,
+        },
+    )
+    assert response.ok
+    return sorted(point['id'] for point in response.json()['result']['points'])
+
+
+def test_filter_field_comparison(collection_name):
+    assert matching_ids(collection_name, {"lt": "budget"}) == [1, 4]
+    assert matching_ids(collection_name, {"lte": "budget"}) == [1, 2, 4]
+    assert matching_ids(collection_name, {"gt": "budget"}) == [3, 4]
+    assert matching_ids(collection_name, {"gte": "budget", "lte": "budget"}) == [2]
+
+
+def test_filter_field_comparison_validation(collection_name):
+    for condition in [
+        {"key": "price", "compare": {}},
+        {"key": "price", "compare": {"lt": "budget"}, "range": {"gt": 1}},
+    ]:
+        response = request_with_validation(
+            api='/collections/{collection_name}/points/scroll',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={"filter": {"must": [condition]}},
+        )
+        assert response.status_code == 422
+        assert "compare" in response.json()["status"]["error"]
@@ -20,3 +20,28 @@ def test_k8s_ready(endpoint):
     )
     assert response.ok
     assert response.text == "all shards are ready"
+
+
+def test_k8s_startup():
+    response = request_with_validation(
+        api="/startupz",
+        method="GET",
+    )
+    assert response.ok
+    assert response.text == "node is started"
+
+
+def test_k8s_ready_verbose():
+    response = request_with_validation(
+        api="/readyz",
+        method="GET",
+        query_params={"verbose": True},
+    )
+    assert response.ok
+    report = response.json()
+    assert report["healthy"]
+    checks = {check["name"]: check for check in report["checks"]}
+    assert checks["startup"]["status"] == "ok"
+    assert checks["startup"]["required"]
+    assert checks["storage"]["status"] == "ok"
+    assert checks["gpu"]["status"] == "skipped"
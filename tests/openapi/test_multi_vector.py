@@ -485,4 +485,81 @@ def test_multi_with_euclidean(collection_name):
     assert response.json()['result']['points'][0]['score'] == 0.0
 
     assert response.json()['result']['points'][1]['id'] == 2
-    assert response.json()['result']['points'][1]['score'] == 4.358899 # see `score_max_similarity` for details
\ No newline at end of file
+    assert response.json()['result']['points'][1]['score'] == 4.358899 # see `score_max_similarity` for details
+
+def test_append_multi_vector(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 1,
+                    "vector": {
+                        "my-multivec": [
+                            [0.05, 0.61, 0.76, 0.74]
+                        ]
+                    }
+                }
+            ]
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/vectors',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 1,
+                    "vector": {
+                        "my-multivec": [
+                            [0.19, 0.81, 0.75, 0.11],
+                            [0.36, 0.55, 0.47, 0.94]
+                        ]
+                    }
+                }
+            ],
+            "append": True
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 1},
+    )
+    assert response.ok
+    assert response.json()['result']['vector']['my-multivec'] == [
+        [0.05, 0.61, 0.76, 0.74],
+        [0.19, 0.81, 0.75, 0.11],
+        [0.36, 0.55, 0.47, 0.94]
+    ]
+
+    # sub-vectors of a different dimension are rejected
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/vectors',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 1,
+                    "vector": {
+                        "my-multivec": [
+                            [0.19, 0.81, 0.75]
+                        ]
+                    }
+                }
+            ],
+            "append": True
+        }
+    )
+    assert not response.ok
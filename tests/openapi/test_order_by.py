@@ -334,3 +334,53 @@ def test_cannot_use_offset_with_order_by(collection_name):
     )
     assert not response.ok
     assert response.status_code == 400
+
+
+def scroll_all_payloads(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/scroll",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={"limit": total_points, "with_payload": True},
+    )
+    assert response.ok, response.json()
+    return {point["id"]: point["payload"] for point in response.json()["result"]["points"]}
+
+
+def test_order_by_then_by(collection_name):
+    payloads = scroll_all_payloads(collection_name)
+    expected_ids = sorted(
+        payloads,
+        key=lambda id_: (payloads[id_]["maybe_repeated_float"], -payloads[id_]["payload_id"]),
+    )[:20]
+
+    order_by = {
+        "key": "maybe_repeated_float",
+        "direction": "asc",
+        "then_by": [{"key": "payload_id", "direction": "desc"}],
+    }
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/scroll",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={"order_by": order_by, "limit": 20, "with_payload": False},
+    )
+    assert response.ok, response.json()
+
+    points = response.json()["result"]["points"]
+    assert [point["id"] for point in points] == expected_ids
+    # Payload of `then_by` keys is only used for ordering
+    assert all(point.get("payload") is None for point in points)
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={"query": {"order_by": order_by}, "limit": 20, "with_payload": ["city"]},
+    )
+    assert response.ok, response.json()
+
+    points = response.json()["result"]["points"]
+    assert [point["id"] for point in points] == expected_ids
+    assert all(list(point["payload"].keys()) == ["city"] for point in points)
@@ -70,6 +70,8 @@ def test_payload_indexing_operations(collection_name):
     assert response.ok
     assert response.json()[
         'result']['payload_schema']['test_payload']['data_type'] == "keyword"
+    assert response.json()[
+        'result']['payload_schema']['test_payload']['distinct_values'] == 1
 
     # Delete index
     response = request_with_validation(
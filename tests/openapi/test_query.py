@@ -624,6 +624,132 @@ def test_nearest_with_mmr(collection_name):
     assert mmr_result == mmr_default_result, "MMR with explicit vs implicit defaults should produce the same output"
 
 
+def test_nearest_among_candidates(collection_name):
+    query_vector = [0.35, 0.08, 0.11, 0.47]
+    candidates = [1, 3, 5, 7, 42]
+
+    # Exact search, limited to the candidates with a filter
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "query": query_vector,
+            "filter": {"must": [{"has_id": candidates}]},
+            "params": {"exact": True},
+        },
+    )
+    assert response.ok, response.json()
+    expected = response.json()["result"]["points"]
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "query": {
+                "nearest": query_vector,
+                "candidates": candidates,
+            },
+        },
+    )
+    assert response.ok, response.json()
+    result = response.json()["result"]["points"]
+
+    # Only existing candidates are returned, ranked by score
+    assert len(result) == 4
+    assert result == expected
+
+    # Candidates can't be empty
+    response = requests.post(f"{QDRANT_HOST}/collections/{collection_name}/points/query",
+        headers=qdrant_host_headers(),
+        json={
+            "query": {
+                "nearest": query_vector,
+                "candidates": [],
+            },
+        },
+    )
+    assert response.status_code == 422
+
+
+def test_vector_selection(collection_name):
+    query_vector = [0.35, 0.08, 0.11, 0.47]
+
+    def query_points(body):
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/query",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={"params": {"exact": True}, "limit": 10, **body},
+        )
+        assert response.ok, response.json()
+        return [(point["id"], point["score"]) for point in response.json()["result"]["points"]]
+
+    # Points without a mapped value are excluded
+    expected = query_points({
+        "query": query_vector,
+        "filter": {"must": [{"key": "city", "match": {"any": ["Berlin", "London"]}}]},
+    })
+    result = query_points({
+        "query": {
+            "vector_selection": {
+                "key": "city",
+                "mapping": [
+                    {"value": "Berlin", "vector": ""},
+                    {"value": "London", "vector": ""},
+                ],
+                "queries": {"": query_vector},
+            },
+        },
+    })
+    assert result == expected
+
+    # Points without a mapped value are scored with the default vector
+    expected = query_points({"query": query_vector})
+    result = query_points({
+        "query": {
+            "vector_selection": {
+                "key": "city",
+                "mapping": [{"value": "Berlin", "vector": ""}],
+                "default": "",
+                "queries": {"": query_vector},
+            },
+        },
+    })
+    assert result == expected
+
+    # Every selectable vector needs a query
+    response = requests.post(f"{QDRANT_HOST}/collections/{collection_name}/points/query",
+        headers=qdrant_host_headers(),
+        json={
+            "query": {
+                "vector_selection": {
+                    "key": "city",
+                    "mapping": [{"value": "Berlin", "vector": "image"}],
+                    "queries": {"": query_vector},
+                },
+            },
+        },
+    )
+    assert response.status_code == 400, response.json()
+
+    # Either a mapping or a default vector is required
+    response = requests.post(f"{QDRANT_HOST}/collections/{collection_name}/points/query",
+        headers=qdrant_host_headers(),
+        json={
+            "query": {
+                "vector_selection": {
+                    "key": "city",
+                    "mapping": [],
+                    "queries": {"": query_vector},
+                },
+            },
+        },
+    )
+    assert response.status_code == 422
+
+
 @pytest.mark.parametrize("body", [
     {
         "prefetch": [
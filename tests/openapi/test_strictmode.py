@@ -1,3 +1,5 @@
+import time
+
 import pytest
 from requests import Response
 
@@ -2564,4 +2566,108 @@ def test_strict_mode_payload_index_count(collection_name):
                 "type": "integer",
             }
         }
-    ).raise_for_status()
\ No newline at end of file
+    ).raise_for_status()
+
+def test_strict_mode_response_vectors(collection_name):
+    def scroll(limit, with_vector):
+        return request_with_validation(
+            api='/collections/{collection_name}/points/scroll',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={
+                "limit": limit,
+                "with_vector": with_vector,
+            }
+        )
+
+    def get_point():
+        response = request_with_validation(
+            api='/collections/{collection_name}/points/{id}',
+            method="GET",
+            path_params={'collection_name': collection_name, 'id': 1},
+        )
+        response.raise_for_status()
+        return response.json()['result']
+
+    assert get_point()['vector'] is not None
+
+    # 16 bytes of vectors per point
+    set_strict_mode(collection_name, {
+        "enabled": True,
+        "max_response_vectors_bytes": 64,
+        "read_vectors_by_default": False,
+    })
+
+    scroll(4, True).raise_for_status()
+    scroll(5, False).raise_for_status()
+
+    response = scroll(5, True)
+    assert not response.ok
+    assert response.status_code == 400
+    assert "Estimated size of returned vectors exceeds the limit (80 > 64 bytes)" in response.json()['status']['error']
+
+    # Vectors must be requested explicitly
+    assert get_point().get('vector') is None
+
+
+def test_strict_mode_write_backpressure(collection_name):
+    set_strict_mode(collection_name, {
+        "enabled": True,
+        "max_update_queue_length": 100,
+        "max_unindexed_segment_size_kb": 1,
+    })
+
+    strict_mode_config = get_strict_mode(collection_name)
+    assert strict_mode_config['max_update_queue_length'] == 100
+    assert strict_mode_config['max_unindexed_segment_size_kb'] == 1
+
+    def upsert(offset, count):
+        return request_with_validation(
+            api='/collections/{collection_name}/points',
+            method="PUT",
+            path_params={'collection_name': collection_name},
+            query_params={'wait': 'true'},
+            body={
+                "points": [
+                    {
+                        "id": offset + i,
+                        "vector": [0.05, 0.61, 0.76, 0.74],
+                    }
+                    for i in range(count)
+                ]
+            }
+        )
+
+    # 1600 bytes of vectors are above the limit, but below the default indexing threshold.
+    # Such segment is never indexed, so it doesn't block writes.
+    upsert(1000, 100).raise_for_status()
+    upsert(2000, 1).raise_for_status()
+
+    # Make the segment due for indexing, but never let the optimizers index it
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "optimizers_config": {
+                "indexing_threshold": 1,
+                "max_optimization_threads": 0,
+            },
+        }
+    )
+    assert response.ok
+
+    # Size of the unindexed segment is measured at most once per second
+    time.sleep(1.5)
+
+    response = upsert(3000, 1)
+    assert response.status_code == 429
+    assert "Write backpressure: largest segment due for indexing" in response.json()['status']['error']
+    assert int(response.headers['Retry-After']) == 10
+
+    # Writes are accepted again without the limit
+    set_strict_mode(collection_name, {
+        "enabled": False,
+    })
+
+    upsert(3000, 1).raise_for_status()